@@ -5,6 +5,7 @@ mod xof;
 
 use criterion::{Criterion, criterion_group, criterion_main};
 
+#[allow(unused_variables)]
 fn all_benches(c: &mut Criterion) {
     #[cfg(feature = "aes-gcm")]
     aead::aes_gcm::bench_aes_gcm(c);
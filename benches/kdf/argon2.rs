@@ -1,7 +1,7 @@
 #![cfg(feature = "argon2")]
 
 use criterion::{Criterion, criterion_group};
-use seal_crypto::{prelude::*, schemes::kdf::argon2::Argon2Scheme};
+use seal_crypto::{prelude::*, schemes::kdf::argon2::Argon2};
 use secrecy::SecretBox;
 use std::hint::black_box;
 
@@ -18,7 +18,7 @@ pub fn bench_argon2(c: &mut Criterion) {
     let salt = b"salt-for-benchmarking";
     let output_len = 32;
 
-    let scheme = Argon2Scheme::new(BENCH_M_COST, BENCH_T_COST, BENCH_P_COST);
+    let scheme = Argon2::new(BENCH_M_COST, BENCH_T_COST, BENCH_P_COST);
     let bench_name = format!(
         "Argon2id (m={}, t={}, p={})",
         BENCH_M_COST, BENCH_T_COST, BENCH_P_COST
@@ -1,18 +1,21 @@
-//! An example demonstrating hybrid encryption using Kyber for KEM
-//! and AES-256-GCM for symmetric encryption.
+//! An example demonstrating hybrid encryption using Kyber for KEM, HKDF to derive
+//! the key and AES-256-GCM for symmetric encryption.
 //!
-//! 一个演示混合加密的示例，其中 KEM 使用 Kyber，对称加密使用 AES-256-GCM。
+//! 一个演示混合加密的示例，其中 KEM 使用 Kyber，使用 HKDF 派生密钥，对称加密使用 AES-256-GCM。
 
 use seal_crypto::prelude::*;
 use seal_crypto::schemes::{
     asymmetric::post_quantum::kyber::{Kyber1024Params, KyberScheme},
     aead::aes_gcm::Aes256Gcm,
+    kdf::hkdf::HkdfSha256,
 };
 
 fn main() -> Result<(), CryptoError> {
     // Define the concrete schemes we want to use.
     type MyKEM = KyberScheme<Kyber1024Params>;
     type MyAEAD = Aes256Gcm;
+    let kdf = HkdfSha256::default();
+    let info = b"hybrid encryption example";
 
     println!("Running hybrid encryption example... / 正在运行混合加密示例...");
 
@@ -60,10 +63,11 @@ fn main() -> Result<(), CryptoError> {
     let (shared_secret, encapsulated_key) = MyKEM::encapsulate(&public_key)?;
     println!("  - Shared secret generated and encapsulated. / 已生成并封装共享密钥。");
 
-    // The sender uses the shared secret to encrypt the message with AES-256-GCM.
+    // The sender derives an AES-256-GCM key from the shared secret and encrypts the message.
     // A nonce must be unique for each encryption with the same key.
-    // 发送方使用共享密钥通过 AES-256-GCM 加密消息。
+    // 发送方从共享密钥派生 AES-256-GCM 密钥并加密消息。
     // 对于使用相同密钥的每次加密，nonce 都必须是唯一的。
+    let key = shared_secret.expand_key_for::<_, MyAEAD>(&kdf, info)?;
     let nonce = vec![0u8; <MyAEAD as AeadCipher>::NONCE_SIZE];
     // In a real application, you would use a secure random number generator
     // to create a unique nonce for each encryption.
@@ -71,12 +75,7 @@ fn main() -> Result<(), CryptoError> {
     // 在实际应用中，您应该使用一个安全的随机数生成器为每次加密创建唯一的 nonce。
     // 例如：use rand::{RngCore, OsRng}; OsRng.fill_bytes(&mut nonce);
 
-    let ciphertext = MyAEAD::encrypt(
-        &shared_secret,
-        &nonce,
-        secret_message,
-        Some(associated_data),
-    )?;
+    let ciphertext = MyAEAD::encrypt(&key, &nonce, secret_message, Some(associated_data))?;
     println!(
         "  - Message successfully encrypted with AES-256-GCM. / 消息已通过 AES-256-GCM 成功加密。"
     );
@@ -105,14 +104,10 @@ fn main() -> Result<(), CryptoError> {
     let decrypted_shared_secret = MyKEM::decapsulate(&private_key, &encapsulated_key)?;
     println!("  - Shared secret successfully decapsulated. / 共享密钥解封装成功。");
 
-    // The recipient uses the decapsulated secret to decrypt the ciphertext.
-    // 接收方使用解封装后的密钥来解密密文。
-    let decrypted_plaintext = MyAEAD::decrypt(
-        &decrypted_shared_secret,
-        &nonce,
-        &ciphertext,
-        Some(associated_data),
-    )?;
+    // The recipient derives the same key from the decapsulated secret and decrypts the ciphertext.
+    // 接收方从解封装后的密钥派生出相同的密钥并解密密文。
+    let key = decrypted_shared_secret.expand_key_for::<_, MyAEAD>(&kdf, info)?;
+    let decrypted_plaintext = MyAEAD::decrypt(&key, &nonce, &ciphertext, Some(associated_data))?;
     println!(
         "  - Ciphertext successfully decrypted with AES-256-GCM. / 密文已通过 AES-256-GCM 成功解密。"
     );
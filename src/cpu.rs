@@ -0,0 +1,234 @@
+//! Runtime CPU feature detection.
+//!
+//! The backends behind this crate pick an accelerated implementation when the running CPU
+//! supports it and fall back to portable code otherwise, so one binary can be shipped to
+//! machines with different instruction sets without compiling with `-C target-feature`.
+//! [`cpu_features`] reports what was detected, for logging or for choosing between algorithms
+//! with different hardware support, such as AES-GCM and ChaCha20-Poly1305.
+//!
+//! | Feature    | Used by                                                        |
+//! |------------|----------------------------------------------------------------|
+//! | `aes`      | AES in AES-GCM, AES-CCM and CMAC                                |
+//! | `clmul`    | GHASH in AES-GCM                                                |
+//! | `sse2`     | ChaCha20 and BLAKE3                                             |
+//! | `sse41`    | BLAKE2 and BLAKE3                                               |
+//! | `avx2`     | ChaCha20, BLAKE2, BLAKE3 and, with `kyber-avx2`/`dilithium-avx2`, Kyber and Dilithium |
+//! | `avx512`   | BLAKE3                                                          |
+//! | `sha`      | SHA-224 and SHA-256                                             |
+//! | `neon`     | ChaCha20 and BLAKE3                                             |
+//!
+//! Runtime detection is done once and cached. BLAKE2 and BLAKE3 only detect at runtime with
+//! the `std` feature; under `no_std` they use whatever the target enables at compile time.
+//! On aarch64 the AES, GHASH and ChaCha20 backends also need the `aes_armv8`,
+//! `polyval_armv8` and `chacha20_force_neon` cfgs, which this repository sets in
+//! `.cargo/config.toml`; crates depending on seal-crypto have to set them as well.
+//!
+//! 运行时 CPU 特性检测。
+//!
+//! 本 crate 背后的后端会在运行的 CPU 支持时选择加速实现，否则回退到可移植代码，因此同一个二进制
+//! 文件可以分发到指令集不同的机器上，而无需使用 `-C target-feature` 编译。[`cpu_features`]
+//! 报告检测到的特性，可用于记录日志，或在硬件支持不同的算法（例如 AES-GCM 和
+//! ChaCha20-Poly1305）之间进行选择。
+//!
+//! | 特性       | 使用者                                                          |
+//! |------------|-----------------------------------------------------------------|
+//! | `aes`      | AES-GCM、AES-CCM 和 CMAC 中的 AES                                |
+//! | `clmul`    | AES-GCM 中的 GHASH                                               |
+//! | `sse2`     | ChaCha20 和 BLAKE3                                               |
+//! | `sse41`    | BLAKE2 和 BLAKE3                                                 |
+//! | `avx2`     | ChaCha20、BLAKE2、BLAKE3，以及启用 `kyber-avx2`/`dilithium-avx2` 时的 Kyber 和 Dilithium |
+//! | `avx512`   | BLAKE3                                                           |
+//! | `sha`      | SHA-224 和 SHA-256                                               |
+//! | `neon`     | ChaCha20 和 BLAKE3                                               |
+//!
+//! 运行时检测只进行一次并被缓存。BLAKE2 和 BLAKE3 只有在启用 `std` 特性时才在运行时检测；
+//! 在 `no_std` 下，它们使用目标在编译时启用的特性。在 aarch64 上，AES、GHASH 和 ChaCha20
+//! 的后端还需要 `aes_armv8`、`polyval_armv8` 和 `chacha20_force_neon` cfg，本仓库在
+//! `.cargo/config.toml` 中设置了它们；依赖 seal-crypto 的 crate 也需要设置。
+
+/// CPU features relevant to the crate's accelerated backends, as detected on the running CPU.
+/// Features that do not exist on the current architecture are `false`.
+///
+/// 与本 crate 的加速后端相关、在运行的 CPU 上检测到的 CPU 特性。当前架构上不存在的特性为 `false`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct CpuFeatures {
+    /// AES-NI on x86, or the ARMv8 AES instructions on aarch64.
+    ///
+    /// x86 上的 AES-NI，或 aarch64 上的 ARMv8 AES 指令。
+    pub aes: bool,
+    /// PCLMULQDQ on x86, or PMULL on aarch64.
+    ///
+    /// x86 上的 PCLMULQDQ，或 aarch64 上的 PMULL。
+    pub clmul: bool,
+    /// SSE2 on x86.
+    ///
+    /// x86 上的 SSE2。
+    pub sse2: bool,
+    /// SSE4.1 on x86.
+    ///
+    /// x86 上的 SSE4.1。
+    pub sse41: bool,
+    /// AVX2 on x86.
+    ///
+    /// x86 上的 AVX2。
+    pub avx2: bool,
+    /// AVX-512F and AVX-512VL on x86.
+    ///
+    /// x86 上的 AVX-512F 和 AVX-512VL。
+    pub avx512: bool,
+    /// The SHA extensions on x86, or the ARMv8 SHA-1/SHA-256 instructions on aarch64.
+    ///
+    /// x86 上的 SHA 扩展，或 aarch64 上的 ARMv8 SHA-1/SHA-256 指令。
+    pub sha: bool,
+    /// NEON (Advanced SIMD) on aarch64.
+    ///
+    /// aarch64 上的 NEON（高级 SIMD）。
+    pub neon: bool,
+}
+
+impl CpuFeatures {
+    /// Returns `true` if AES-GCM runs on hardware AES and carry-less multiplication. Without
+    /// them, ChaCha20-Poly1305 is usually faster and its portable code is still constant-time.
+    ///
+    /// 如果 AES-GCM 在硬件 AES 和无进位乘法上运行，则返回 `true`。没有它们时，
+    /// ChaCha20-Poly1305 通常更快，并且其可移植代码仍然是常量时间的。
+    pub fn has_aes_gcm_acceleration(&self) -> bool {
+        self.aes && self.clmul
+    }
+
+    /// Returns the names of the detected features, in the order of the fields.
+    ///
+    /// 按字段顺序返回检测到的特性名称。
+    pub fn names(&self) -> impl Iterator<Item = &'static str> {
+        [
+            ("aes", self.aes),
+            ("clmul", self.clmul),
+            ("sse2", self.sse2),
+            ("sse41", self.sse41),
+            ("avx2", self.avx2),
+            ("avx512", self.avx512),
+            ("sha", self.sha),
+            ("neon", self.neon),
+        ]
+        .into_iter()
+        .filter_map(|(name, detected)| detected.then_some(name))
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod detect {
+    cpufeatures::new!(cpuid_aes, "aes");
+    cpufeatures::new!(cpuid_clmul, "pclmulqdq");
+    cpufeatures::new!(cpuid_sse2, "sse2");
+    cpufeatures::new!(cpuid_sse41, "sse4.1");
+    cpufeatures::new!(cpuid_avx2, "avx2");
+    cpufeatures::new!(cpuid_avx512, "avx512f", "avx512vl");
+    cpufeatures::new!(cpuid_sha, "sha", "sse2", "ssse3", "sse4.1");
+
+    pub(super) fn detect() -> super::CpuFeatures {
+        super::CpuFeatures {
+            aes: cpuid_aes::get(),
+            clmul: cpuid_clmul::get(),
+            sse2: cpuid_sse2::get(),
+            sse41: cpuid_sse41::get(),
+            avx2: cpuid_avx2::get(),
+            avx512: cpuid_avx512::get(),
+            sha: cpuid_sha::get(),
+            neon: false,
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod detect {
+    // cpufeatures' `aes` also requires PMULL.
+    // cpufeatures 的 `aes` 同时要求 PMULL。
+    cpufeatures::new!(hwcap_aes, "aes");
+    cpufeatures::new!(hwcap_sha2, "sha2");
+
+    pub(super) fn detect() -> super::CpuFeatures {
+        let aes = hwcap_aes::get();
+        super::CpuFeatures {
+            aes,
+            clmul: aes,
+            sha: hwcap_sha2::get(),
+            neon: cfg!(target_feature = "neon"),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+mod detect {
+    pub(super) fn detect() -> super::CpuFeatures {
+        super::CpuFeatures::default()
+    }
+}
+
+/// Detects the CPU features used by the crate's accelerated backends. The first call queries
+/// the CPU or the operating system, later calls read the cached result.
+///
+/// 检测本 crate 的加速后端所使用的 CPU 特性。首次调用会查询 CPU 或操作系统，之后的调用读取缓存的结果。
+///
+/// # Examples
+///
+/// ```
+/// use seal_crypto::cpu::cpu_features;
+///
+/// let features = cpu_features();
+/// let names: Vec<&str> = features.names().collect();
+/// println!("accelerated: {}", names.join(", "));
+/// ```
+pub fn cpu_features() -> CpuFeatures {
+    detect::detect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_features_is_stable() {
+        assert_eq!(cpu_features(), cpu_features());
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_cpu_features_match_std_detection() {
+        let features = cpu_features();
+        assert_eq!(features.aes, std::is_x86_feature_detected!("aes"));
+        assert_eq!(features.clmul, std::is_x86_feature_detected!("pclmulqdq"));
+        assert_eq!(features.sse2, std::is_x86_feature_detected!("sse2"));
+        assert_eq!(features.sse41, std::is_x86_feature_detected!("sse4.1"));
+        assert_eq!(features.avx2, std::is_x86_feature_detected!("avx2"));
+        assert!(!features.neon);
+        assert_eq!(
+            features.has_aes_gcm_acceleration(),
+            features.aes && features.clmul
+        );
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[test]
+    fn test_cpu_features_match_std_detection() {
+        let features = cpu_features();
+        assert_eq!(features.neon, std::arch::is_aarch64_feature_detected!("neon"));
+        assert_eq!(features.aes, std::arch::is_aarch64_feature_detected!("aes"));
+        assert_eq!(features.sha, std::arch::is_aarch64_feature_detected!("sha2"));
+    }
+
+    #[test]
+    fn test_names() {
+        let features = CpuFeatures {
+            aes: true,
+            clmul: true,
+            avx2: true,
+            ..Default::default()
+        };
+        let names: Vec<&str> = features.names().collect();
+        assert_eq!(names, ["aes", "clmul", "avx2"]);
+        assert!(features.has_aes_gcm_acceleration());
+        assert_eq!(CpuFeatures::default().names().count(), 0);
+    }
+}
@@ -0,0 +1,67 @@
+//! Constant-time comparison utilities.
+//!
+//! Comparing secrets with `==` on byte slices returns as soon as the first byte differs,
+//! which can leak how much of a guessed key or tag was correct. This module re-exports
+//! [`subtle`]'s [`ConstantTimeEq`] and [`Choice`] and provides the [`ct_eq`] helper.
+//!
+//! [`ConstantTimeEq`] is implemented for the crate's secret types, such as private keys,
+//! [`SymmetricKey`](crate::prelude::SymmetricKey), [`DerivedKey`](crate::prelude::DerivedKey)
+//! and [`SharedSecret`](crate::prelude::SharedSecret), and their `PartialEq` implementations
+//! are constant-time as well. [`Signature`](crate::prelude::Signature) is an alias that
+//! dereferences to `[u8]`, so either call [`ct_eq`] on it or bring [`ConstantTimeEq`] into
+//! scope and call `a.ct_eq(&b)` directly. Its `==` is **not** constant-time.
+//!
+//! Only the contents are compared in constant time; the lengths are not considered secret.
+//!
+//! 常量时间比较工具。
+//!
+//! 对字节切片使用 `==` 比较秘密时，一旦遇到第一个不同的字节就会返回，这可能泄露猜测的密钥或
+//! 标签有多少部分是正确的。本模块重新导出 [`subtle`] 的 [`ConstantTimeEq`] 和 [`Choice`]，
+//! 并提供 [`ct_eq`] 辅助函数。
+//!
+//! 本 crate 的秘密类型（例如私钥、[`SymmetricKey`](crate::prelude::SymmetricKey)、
+//! [`DerivedKey`](crate::prelude::DerivedKey) 和 [`SharedSecret`](crate::prelude::SharedSecret)）
+//! 实现了 [`ConstantTimeEq`]，其 `PartialEq` 实现同样是常量时间的。
+//! [`Signature`](crate::prelude::Signature) 是解引用为 `[u8]` 的类型别名，因此可以对它调用
+//! [`ct_eq`]，或者引入 [`ConstantTimeEq`] 后直接调用 `a.ct_eq(&b)`。它的 `==` **不是**常量时间的。
+//!
+//! 只有内容以常量时间比较；长度不被视为秘密。
+
+pub use subtle::{Choice, ConstantTimeEq};
+
+/// Compares two byte strings in constant time with respect to their contents.
+/// Returns `false` immediately if the lengths differ.
+///
+/// 以与内容无关的常量时间比较两个字节串。如果长度不同，立即返回 `false`。
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    bool::from(a.ct_eq(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(b"secret", b"secret"));
+        assert!(!ct_eq(b"secret", b"secreT"));
+        assert!(!ct_eq(b"secret", b"secret!"));
+        assert!(ct_eq(b"", b""));
+
+        let key: SymmetricKey = SymmetricKey::new(vec![7u8; 32]);
+        let same: SymmetricKey = SymmetricKey::new(vec![7u8; 32]);
+        assert!(bool::from(key.ct_eq(&same)));
+        assert!(ct_eq(&key, &same));
+    }
+
+    #[test]
+    fn test_derived_key_ct_eq() {
+        let a = DerivedKey::new(vec![1u8, 2, 3]);
+        let b = DerivedKey::new(vec![1u8, 2, 3]);
+        let c = DerivedKey::new(vec![1u8, 2, 4]);
+        assert!(bool::from(a.ct_eq(&b)));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+}
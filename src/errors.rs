@@ -2,7 +2,13 @@
 //!
 //! 为 `seal-crypto` crate 定义了顶层错误类型。
 
+#[cfg(feature = "kat")]
+use crate::kat::KatError;
+use crate::policy::PolicyError;
+use crate::self_test::SelfTestError;
+use crate::traits::hash::HashError;
 use crate::traits::kdf::KdfError;
+use crate::traits::mac::MacError;
 use crate::traits::asymmetric::KemError;
 use crate::traits::asymmetric::KeyAgreementError;
 use crate::traits::key::KeyError;
@@ -59,6 +65,37 @@ pub enum Error {
     #[cfg_attr(feature = "std", error("KDF error"))]
     Kdf(#[cfg_attr(feature = "std", from)] KdfError),
 
+    /// An error occurred while configuring a hash function.
+    ///
+    /// 在配置哈希函数时发生错误。
+    #[cfg_attr(feature = "std", error("Hash function error"))]
+    Hash(#[cfg_attr(feature = "std", from)] HashError),
+
+    /// An error occurred during a message authentication code (MAC) operation.
+    ///
+    /// 在消息认证码 (MAC) 操作期间发生错误。
+    #[cfg_attr(feature = "std", error("MAC operation failed"))]
+    Mac(#[cfg_attr(feature = "std", from)] MacError),
+
+    /// The operation is not allowed by the installed or given [`crate::policy::CryptoPolicy`].
+    ///
+    /// 该操作不被已安装或给定的 [`crate::policy::CryptoPolicy`] 允许。
+    #[cfg_attr(feature = "std", error("Operation violates the crypto policy"))]
+    PolicyViolation(#[cfg_attr(feature = "std", from)] PolicyError),
+
+    /// A power-on self-test of a scheme failed.
+    ///
+    /// 方案的上电自检失败。
+    #[cfg_attr(feature = "std", error("Cryptographic self-test failed"))]
+    SelfTest(#[cfg_attr(feature = "std", from)] SelfTestError),
+
+    /// A known-answer test vector file could not be run.
+    ///
+    /// 无法运行已知答案测试向量文件。
+    #[cfg(feature = "kat")]
+    #[error("Test vector error")]
+    Kat(#[from] KatError),
+
 }
 
 // Manual From impls for no_std
@@ -103,3 +140,56 @@ impl From<KdfError> for Error {
         Error::Kdf(e)
     }
 }
+
+#[cfg(not(feature = "std"))]
+impl From<HashError> for Error {
+    fn from(e: HashError) -> Self {
+        Error::Hash(e)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<MacError> for Error {
+    fn from(e: MacError) -> Self {
+        Error::Mac(e)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<PolicyError> for Error {
+    fn from(e: PolicyError) -> Self {
+        Error::PolicyViolation(e)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<SelfTestError> for Error {
+    fn from(e: SelfTestError) -> Self {
+        Error::SelfTest(e)
+    }
+}
+
+// Without `thiserror`, implement `Display` and `core::error::Error` by hand so the error can
+// still be reported, e.g. by serde, and boxed.
+// 没有 `thiserror` 时，手动实现 `Display` 和 `core::error::Error`，使该错误仍然可以被报告
+// （例如由 serde 报告）和装箱。
+#[cfg(not(feature = "std"))]
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Error::Key(_) => "Key operation failed",
+            Error::Kem(_) => "KEM operation failed",
+            Error::Signature(_) => "Signature operation failed",
+            Error::Symmetric(_) => "Aead encryption/decryption error",
+            Error::KeyAgreement(_) => "Key agreement operation failed",
+            Error::Kdf(_) => "KDF error",
+            Error::Hash(_) => "Hash function error",
+            Error::Mac(_) => "MAC operation failed",
+            Error::PolicyViolation(_) => "Operation violates the crypto policy",
+            Error::SelfTest(_) => "Cryptographic self-test failed",
+        })
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for Error {}
@@ -0,0 +1,394 @@
+//! Runs Wycheproof and ACVP known-answer test vector files against the crate's schemes.
+//!
+//! [`wycheproof::run`] accepts a Wycheproof test vector file and [`acvp::run`] accepts an ACVP
+//! vector set with its expected results (an `internalProjection.json` file, or a response that
+//! contains the expected results). Both return a [`KatReport`] with one [`CaseResult`] per test
+//! case, so a CI job can check that every valid case is accepted with the expected output and
+//! every invalid case is rejected:
+//!
+//! ```no_run
+//! let json = std::fs::read_to_string("aes_gcm_test.json").unwrap();
+//! let report = seal_crypto::kat::wycheproof::run(&json).unwrap();
+//! for case in report.failures() {
+//!     eprintln!("{} #{}: {:?}", report.algorithm, case.id, case.outcome);
+//! }
+//! assert!(report.passed());
+//! ```
+//!
+//! Cases whose parameters are outside what the crate implements, such as 64-bit GCM nonces or
+//! ECDSA with a hash the scheme does not use, are reported as [`CaseOutcome::Skipped`].
+//!
+//! 针对本 crate 的方案运行 Wycheproof 和 ACVP 已知答案测试向量文件。
+//!
+//! [`wycheproof::run`] 接受 Wycheproof 测试向量文件，[`acvp::run`] 接受带有预期结果的 ACVP
+//! 向量集（`internalProjection.json` 文件，或包含预期结果的响应）。两者都返回一个
+//! [`KatReport`]，每个测试用例对应一个 [`CaseResult`]，因此 CI 任务可以检查每个有效用例都以
+//! 预期输出被接受，而每个无效用例都被拒绝。
+//!
+//! 参数超出本 crate 实现范围的用例（例如 64 位 GCM nonce，或使用方案不使用的哈希的 ECDSA）
+//! 会被报告为 [`CaseOutcome::Skipped`]。
+
+pub mod acvp;
+pub mod wycheproof;
+
+use crate::errors::Error;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Errors that prevent a vector file from being run.
+///
+/// 阻止向量文件运行的错误。
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum KatError {
+    /// The file is not valid JSON or does not follow the expected schema.
+    ///
+    /// 文件不是有效的 JSON 或不符合预期的模式。
+    #[error("Invalid test vector file")]
+    InvalidFormat,
+
+    /// A field that should contain hex is not valid hex.
+    ///
+    /// 应包含十六进制的字段不是有效的十六进制。
+    #[error("Invalid hex in test vector")]
+    InvalidHex,
+
+    /// The file tests an algorithm that is not implemented or not enabled.
+    ///
+    /// 文件测试的算法未实现或未启用。
+    #[error("Unsupported test vector algorithm")]
+    UnsupportedAlgorithm,
+
+    /// An operation succeeded but produced a different output than the vector expects.
+    ///
+    /// 操作成功但产生的输出与向量预期的不同。
+    #[error("Output does not match the test vector")]
+    OutputMismatch,
+}
+
+/// What a test vector expects the implementation to do with a case.
+///
+/// 测试向量期望实现对某个用例的处理方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Expectation {
+    /// The case must be accepted and produce the expected output.
+    ///
+    /// 用例必须被接受并产生预期输出。
+    Valid,
+    /// The case must be rejected.
+    ///
+    /// 用例必须被拒绝。
+    Invalid,
+    /// The case may be either accepted or rejected.
+    ///
+    /// 用例可以被接受也可以被拒绝。
+    Acceptable,
+}
+
+/// The outcome of running one test case.
+///
+/// 运行单个测试用例的结果。
+#[derive(Debug, PartialEq, Eq)]
+pub enum CaseOutcome {
+    /// The implementation behaved as the vector expects.
+    ///
+    /// 实现的行为符合向量的预期。
+    Passed,
+    /// The implementation rejected a valid case with the given error, or accepted an invalid
+    /// case (`None`).
+    ///
+    /// 实现以给定错误拒绝了有效用例，或接受了无效用例 (`None`)。
+    Failed(Option<Error>),
+    /// The case was not run because its parameters are not supported by the crate.
+    ///
+    /// 用例未运行，因为本 crate 不支持其参数。
+    Skipped(&'static str),
+}
+
+/// The result of one test case.
+///
+/// 单个测试用例的结果。
+#[derive(Debug, PartialEq, Eq)]
+pub struct CaseResult {
+    /// The 1-based index of the test group in Wycheproof files, or the `tgId` in ACVP files.
+    ///
+    /// Wycheproof 文件中测试组从 1 开始的索引，或 ACVP 文件中的 `tgId`。
+    pub group: u64,
+    /// The `tcId` of the test case.
+    ///
+    /// 测试用例的 `tcId`。
+    pub id: u64,
+    /// The comment of the test case, empty if it has none.
+    ///
+    /// 测试用例的注释，如果没有则为空。
+    pub comment: String,
+    /// What the vector expects.
+    ///
+    /// 向量的预期。
+    pub expected: Expectation,
+    /// What happened.
+    ///
+    /// 实际发生的情况。
+    pub outcome: CaseOutcome,
+}
+
+// Only used by the runners of the enabled algorithm features.
+#[allow(dead_code)]
+impl CaseResult {
+    /// Judges the result of running a case: `Ok` means the case was accepted with the
+    /// expected output, `Err` that it was rejected.
+    ///
+    /// 判定运行用例的结果：`Ok` 表示用例以预期输出被接受，`Err` 表示被拒绝。
+    fn judge(
+        group: u64,
+        id: u64,
+        comment: String,
+        expected: Expectation,
+        result: Result<(), Error>,
+    ) -> Self {
+        let outcome = match (expected, result) {
+            (Expectation::Acceptable, _)
+            | (Expectation::Valid, Ok(()))
+            | (Expectation::Invalid, Err(_)) => CaseOutcome::Passed,
+            (Expectation::Valid, Err(e)) => CaseOutcome::Failed(Some(e)),
+            (Expectation::Invalid, Ok(())) => CaseOutcome::Failed(None),
+        };
+        Self {
+            group,
+            id,
+            comment,
+            expected,
+            outcome,
+        }
+    }
+
+    fn skipped(
+        group: u64,
+        id: u64,
+        comment: String,
+        expected: Expectation,
+        reason: &'static str,
+    ) -> Self {
+        Self {
+            group,
+            id,
+            comment,
+            expected,
+            outcome: CaseOutcome::Skipped(reason),
+        }
+    }
+}
+
+/// The results of running a vector file.
+///
+/// 运行向量文件的结果。
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KatReport {
+    /// The algorithm named by the file.
+    ///
+    /// 文件所指明的算法。
+    pub algorithm: String,
+    /// The result of every case, in file order.
+    ///
+    /// 每个用例的结果，按文件顺序排列。
+    pub cases: Vec<CaseResult>,
+}
+
+impl KatReport {
+    /// Returns `true` if no case failed. Skipped cases do not count as failures.
+    ///
+    /// 如果没有用例失败则返回 `true`。跳过的用例不算作失败。
+    pub fn passed(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Returns the cases that failed.
+    ///
+    /// 返回失败的用例。
+    pub fn failures(&self) -> impl Iterator<Item = &CaseResult> {
+        self.cases
+            .iter()
+            .filter(|case| matches!(case.outcome, CaseOutcome::Failed(_)))
+    }
+
+    /// Returns the cases that were skipped.
+    ///
+    /// 返回被跳过的用例。
+    pub fn skipped(&self) -> impl Iterator<Item = &CaseResult> {
+        self.cases
+            .iter()
+            .filter(|case| matches!(case.outcome, CaseOutcome::Skipped(_)))
+    }
+}
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// Runs one AEAD case: key, nonce, AAD, the plaintext to encrypt (if encryption is checked)
+/// and the expected ciphertext with its tag appended.
+///
+/// 运行单个 AEAD 用例：密钥、nonce、AAD、要加密的明文（如果检查加密）以及附加了标签的预期密文。
+#[allow(dead_code)]
+type AeadCase = fn(Vec<u8>, &[u8], &[u8], Option<&[u8]>, &[u8]) -> Result<(), Error>;
+
+/// Runs one signature case: SubjectPublicKeyInfo DER, message and signature.
+///
+/// 运行单个签名用例：SubjectPublicKeyInfo DER、消息和签名。
+#[allow(dead_code)]
+type VerifyCase = fn(&[u8], &[u8], Vec<u8>) -> Result<(), Error>;
+
+fn parse<'a, T: Deserialize<'a>>(json: &'a str) -> Result<T, Error> {
+    serde_json::from_str(json).map_err(|_| Error::Kat(KatError::InvalidFormat))
+}
+
+/// Decodes a hex field, accepting both the lowercase hex of Wycheproof and the uppercase hex
+/// of ACVP.
+///
+/// 解码十六进制字段，同时接受 Wycheproof 的小写十六进制和 ACVP 的大写十六进制。
+fn decode_hex(hex: &str) -> Result<Vec<u8>, Error> {
+    fn nibble(digit: u8) -> Result<u8, Error> {
+        match digit {
+            b'0'..=b'9' => Ok(digit - b'0'),
+            b'a'..=b'f' => Ok(digit - b'a' + 10),
+            b'A'..=b'F' => Ok(digit - b'A' + 10),
+            _ => Err(Error::Kat(KatError::InvalidHex)),
+        }
+    }
+    if !hex.len().is_multiple_of(2) {
+        return Err(Error::Kat(KatError::InvalidHex));
+    }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?))
+        .collect()
+}
+
+/// Left-pads or strips leading zero bytes so that a big-endian integer is exactly `len`
+/// bytes. Returns the input unchanged if it does not fit, so that the scheme rejects it.
+///
+/// 对大端整数左侧补零或去除前导零字节，使其恰好为 `len` 字节。如果放不下则原样返回输入，
+/// 由方案拒绝它。
+#[allow(dead_code)]
+fn fixed_width(bytes: Vec<u8>, len: usize) -> Vec<u8> {
+    let significant = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    let trimmed = &bytes[significant..];
+    if trimmed.len() > len {
+        return bytes;
+    }
+    let mut out = vec![0u8; len - trimmed.len()];
+    out.extend_from_slice(trimmed);
+    out
+}
+
+#[allow(dead_code)]
+fn check_output(actual: &[u8], expected: &[u8]) -> Result<(), Error> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(Error::Kat(KatError::OutputMismatch))
+    }
+}
+
+/// Encrypts and decrypts one AEAD case. `Ok` means encryption produced `ciphertext` and
+/// decryption recovered `plaintext`; `Err` means the scheme rejected the case.
+///
+/// 加密并解密单个 AEAD 用例。`Ok` 表示加密产生了 `ciphertext` 且解密恢复了 `plaintext`；
+/// `Err` 表示方案拒绝了该用例。
+#[cfg(any(feature = "aes-gcm-default", feature = "chacha20-poly1305-default"))]
+fn aead_case<
+    A: crate::prelude::AeadScheme
+        + crate::prelude::SymmetricKeySet<Key = crate::prelude::SymmetricKey>,
+>(
+    key: Vec<u8>,
+    nonce: &[u8],
+    aad: &[u8],
+    plaintext: Option<&[u8]>,
+    ciphertext: &[u8],
+) -> Result<(), Error> {
+    let key = crate::prelude::SymmetricKey::new(key);
+    if let Some(plaintext) = plaintext {
+        check_output(&A::encrypt(&key, nonce, plaintext, Some(aad))?, ciphertext)?;
+    }
+    let decrypted = A::decrypt(&key, nonce, ciphertext, Some(aad))?;
+    match plaintext {
+        Some(plaintext) => check_output(&decrypted, plaintext),
+        None => Ok(()),
+    }
+}
+
+/// Verifies a signature with a public key given as SubjectPublicKeyInfo DER.
+///
+/// 使用以 SubjectPublicKeyInfo DER 给出的公钥验证签名。
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+fn verify_case<S: crate::prelude::Verifier>(
+    public_key: &[u8],
+    message: &[u8],
+    signature: Vec<u8>,
+) -> Result<(), Error> {
+    use crate::prelude::Key;
+    let public_key = S::PublicKey::from_bytes(public_key)?;
+    S::verify(&public_key, message, &signature)
+}
+
+/// Computes an ECDH P-256 shared secret and compares it with `shared`.
+///
+/// 计算 ECDH P-256 共享密钥并与 `shared` 比较。
+#[cfg(feature = "ecdh-default")]
+fn ecdh_p256_case(
+    private_key: Vec<u8>,
+    public_key: Result<
+        crate::systems::asymmetric::traditional::ecdh::EcdhPublicKey<
+            crate::systems::asymmetric::traditional::ecdh::EcdhP256Params,
+        >,
+        Error,
+    >,
+    shared: &[u8],
+) -> Result<(), Error> {
+    use crate::prelude::KeyAgreement;
+    use crate::systems::asymmetric::traditional::ecdh::{EcdhP256, EcdhPrivateKey};
+
+    let private_key = EcdhPrivateKey::from_raw_bytes(&fixed_width(private_key, 32))?;
+    let shared_secret = EcdhP256::agree(&private_key, &public_key?)?;
+    check_output(shared_secret.expose_raw(), shared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("00ffAb"), Ok(vec![0x00, 0xff, 0xab]));
+        assert_eq!(decode_hex("abc"), Err(Error::Kat(KatError::InvalidHex)));
+        assert_eq!(decode_hex("zz"), Err(Error::Kat(KatError::InvalidHex)));
+    }
+
+    #[test]
+    fn test_fixed_width() {
+        assert_eq!(fixed_width(vec![0, 0, 1, 2], 3), vec![0, 1, 2]);
+        assert_eq!(fixed_width(vec![1, 2], 3), vec![0, 1, 2]);
+        assert_eq!(fixed_width(vec![1, 2, 3, 4], 3), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_judge() {
+        let judge =
+            |expected, result| CaseResult::judge(1, 1, String::new(), expected, result).outcome;
+        let rejected = || Err(Error::Kat(KatError::OutputMismatch));
+        assert_eq!(judge(Expectation::Valid, Ok(())), CaseOutcome::Passed);
+        assert_eq!(
+            judge(Expectation::Valid, rejected()),
+            CaseOutcome::Failed(Some(Error::Kat(KatError::OutputMismatch)))
+        );
+        assert_eq!(judge(Expectation::Invalid, rejected()), CaseOutcome::Passed);
+        assert_eq!(
+            judge(Expectation::Invalid, Ok(())),
+            CaseOutcome::Failed(None)
+        );
+        assert_eq!(judge(Expectation::Acceptable, Ok(())), CaseOutcome::Passed);
+        assert_eq!(
+            judge(Expectation::Acceptable, rejected()),
+            CaseOutcome::Passed
+        );
+    }
+}
@@ -0,0 +1,723 @@
+//! Runs ACVP vector sets that include their expected results.
+//!
+//! | `algorithm` / `mode` | Schemes |
+//! |---|---|
+//! | `ACVP-AES-GCM` | AES-128-GCM, AES-256-GCM with 96-bit IVs and 128-bit tags |
+//! | `ECDSA` / `sigVer` | ECDSA P-256 with SHA2-256 |
+//! | `EDDSA` / `sigVer` | Ed25519 |
+//! | `RSA` / `sigVer` | RSA-2048 and RSA-4096 PSS with MGF1 and a salt as long as the hash |
+//! | `KAS-ECC` / `CDH-Component`, `KAS-ECC-CDH-Component` | ECDH P-256 |
+//!
+//! 运行包含预期结果的 ACVP 向量集。支持的向量集见上表。
+
+// Which runners are compiled depends on the enabled algorithm features.
+#![allow(dead_code, unused_imports)]
+
+use super::{CaseResult, Expectation, KatError, KatReport, decode_hex};
+use crate::errors::Error;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Header {
+    algorithm: String,
+    #[serde(default)]
+    mode: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VectorSet<G> {
+    test_groups: Vec<G>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AesGcmGroup {
+    tg_id: u64,
+    key_len: usize,
+    iv_len: usize,
+    tag_len: usize,
+    tests: Vec<AesGcmTest>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AesGcmTest {
+    tc_id: u64,
+    key: String,
+    iv: String,
+    #[serde(default)]
+    aad: String,
+    #[serde(default)]
+    pt: Option<String>,
+    #[serde(default)]
+    ct: Option<String>,
+    #[serde(default)]
+    tag: Option<String>,
+    #[serde(default)]
+    test_passed: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SigVerGroup {
+    tg_id: u64,
+    #[serde(default)]
+    curve: Option<String>,
+    #[serde(default)]
+    hash_alg: Option<String>,
+    #[serde(default)]
+    sig_type: Option<String>,
+    #[serde(default)]
+    modulo: Option<usize>,
+    #[serde(default)]
+    salt_len: Option<usize>,
+    #[serde(default)]
+    mask_function: Option<String>,
+    #[serde(default)]
+    n: Option<String>,
+    #[serde(default)]
+    e: Option<String>,
+    tests: Vec<SigVerTest>,
+}
+
+// Depending on the algorithm and revision, the public key is given per group or per test.
+// 根据算法和修订版本，公钥按组或按测试给出。
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SigVerTest {
+    tc_id: u64,
+    message: String,
+    #[serde(default)]
+    signature: Option<String>,
+    #[serde(default)]
+    r: Option<String>,
+    #[serde(default)]
+    s: Option<String>,
+    #[serde(default)]
+    qx: Option<String>,
+    #[serde(default)]
+    qy: Option<String>,
+    #[serde(default)]
+    q: Option<String>,
+    #[serde(default)]
+    n: Option<String>,
+    #[serde(default)]
+    e: Option<String>,
+    #[serde(default)]
+    test_passed: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CdhGroup {
+    tg_id: u64,
+    curve: String,
+    tests: Vec<CdhTest>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CdhTest {
+    tc_id: u64,
+    public_server_x: String,
+    public_server_y: String,
+    private_iut: String,
+    #[serde(default)]
+    z: Option<String>,
+    #[serde(default)]
+    test_passed: Option<bool>,
+}
+
+/// Runs every case of an ACVP vector set.
+///
+/// `json` is either the vector set object or the array sent by an ACVP server, whose first
+/// element holds the protocol version. Only cases that carry their expected result (`testPassed`,
+/// or the expected output) can be run; the others are skipped.
+///
+/// Fails with `KatError::InvalidFormat` if the vector set cannot be parsed, with
+/// `KatError::UnsupportedAlgorithm` if its algorithm is not supported by the enabled features,
+/// and with `KatError::InvalidHex` if a case contains malformed hex.
+///
+/// 运行 ACVP 向量集的每个用例。
+///
+/// `json` 可以是向量集对象，也可以是 ACVP 服务器发送的数组（其第一个元素包含协议版本）。
+/// 只有带有预期结果（`testPassed` 或预期输出）的用例才能运行，其余用例会被跳过。
+///
+/// 如果向量集无法解析，则以 `KatError::InvalidFormat` 失败；如果其算法不受已启用特性支持，
+/// 则以 `KatError::UnsupportedAlgorithm` 失败；如果用例包含格式错误的十六进制，则以
+/// `KatError::InvalidHex` 失败。
+pub fn run(json: &str) -> Result<KatReport, Error> {
+    let mut vector_set: Value = super::parse(json)?;
+    if let Value::Array(elements) = vector_set {
+        vector_set = elements
+            .into_iter()
+            .find(|element| element.get("algorithm").is_some())
+            .ok_or(Error::Kat(KatError::InvalidFormat))?;
+    }
+    let header: Header = from_value(&vector_set)?;
+    let cases = match (header.algorithm.as_str(), header.mode.as_deref()) {
+        #[cfg(feature = "aes-gcm-default")]
+        ("ACVP-AES-GCM", _) => run_aes_gcm(from_value(&vector_set)?),
+        #[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+        ("ECDSA" | "EDDSA" | "RSA", Some("sigVer")) => {
+            run_sig_ver(&header.algorithm, from_value(&vector_set)?)
+        }
+        #[cfg(feature = "ecdh-default")]
+        ("KAS-ECC", Some("CDH-Component")) | ("KAS-ECC-CDH-Component", _) => {
+            run_cdh(from_value(&vector_set)?)
+        }
+        _ => Err(Error::Kat(KatError::UnsupportedAlgorithm)),
+    }?;
+    Ok(KatReport {
+        algorithm: header.algorithm,
+        cases,
+    })
+}
+
+fn from_value<T: DeserializeOwned>(value: &Value) -> Result<T, Error> {
+    T::deserialize(value).map_err(|_| Error::Kat(KatError::InvalidFormat))
+}
+
+/// Maps `testPassed` to an expectation. Cases without it are valid if they carry an expected
+/// output.
+///
+/// 将 `testPassed` 映射为预期。没有该字段的用例如果带有预期输出则为有效。
+fn expectation(test_passed: Option<bool>) -> Expectation {
+    match test_passed {
+        Some(false) => Expectation::Invalid,
+        _ => Expectation::Valid,
+    }
+}
+
+fn skipped(group: u64, id: u64, expected: Expectation, reason: &'static str) -> CaseResult {
+    CaseResult::skipped(group, id, String::new(), expected, reason)
+}
+
+// ------------------- AES-GCM -------------------
+// ------------------- AES-GCM -------------------
+
+#[cfg(feature = "aes-gcm-default")]
+fn run_aes_gcm(vector_set: VectorSet<AesGcmGroup>) -> Result<Vec<CaseResult>, Error> {
+    use crate::systems::aead::aes_gcm::{Aes128Gcm, Aes256Gcm};
+
+    let mut results = Vec::new();
+    for group in vector_set.test_groups {
+        let scheme: Result<super::AeadCase, &'static str> =
+            match (group.key_len, group.iv_len, group.tag_len) {
+                (128, 96, 128) => Ok(super::aead_case::<Aes128Gcm>),
+                (256, 96, 128) => Ok(super::aead_case::<Aes256Gcm>),
+                _ => Err("unsupported key, IV or tag length"),
+            };
+        for test in group.tests {
+            let expected = expectation(test.test_passed);
+            let case = match scheme {
+                Ok(case) => case,
+                Err(reason) => {
+                    results.push(skipped(group.tg_id, test.tc_id, expected, reason));
+                    continue;
+                }
+            };
+            // Both directions are checked by encrypting the plaintext (if the case is valid) and
+            // decrypting the ciphertext, so both need to be present.
+            // 两个方向都通过加密明文（如果用例有效）和解密密文来检查，因此两者都必须存在。
+            let (Some(ct), Some(tag)) = (&test.ct, &test.tag) else {
+                results.push(skipped(
+                    group.tg_id,
+                    test.tc_id,
+                    expected,
+                    "missing expected result",
+                ));
+                continue;
+            };
+            let plaintext = match (&test.pt, expected) {
+                (_, Expectation::Invalid) => None,
+                (Some(pt), _) => Some(decode_hex(pt)?),
+                (None, _) => {
+                    results.push(skipped(
+                        group.tg_id,
+                        test.tc_id,
+                        expected,
+                        "missing expected result",
+                    ));
+                    continue;
+                }
+            };
+            let mut ciphertext = decode_hex(ct)?;
+            ciphertext.extend(decode_hex(tag)?);
+            let result = case(
+                decode_hex(&test.key)?,
+                &decode_hex(&test.iv)?,
+                &decode_hex(&test.aad)?,
+                plaintext.as_deref(),
+                &ciphertext,
+            );
+            results.push(CaseResult::judge(
+                group.tg_id,
+                test.tc_id,
+                String::new(),
+                expected,
+                result,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+// ------------------- Signature Verification -------------------
+// ------------------- 签名验证 -------------------
+
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+fn sig_ver_scheme(algorithm: &str, group: &SigVerGroup) -> Result<super::VerifyCase, &'static str> {
+    let hash = group.hash_alg.as_deref();
+    match algorithm {
+        #[cfg(feature = "ecc-default")]
+        "ECDSA" => match (group.curve.as_deref(), hash) {
+            (Some("P-256"), Some("SHA2-256")) => {
+                Ok(super::verify_case::<crate::systems::asymmetric::traditional::ecc::EcdsaP256>)
+            }
+            _ => Err("unsupported curve or hash"),
+        },
+        #[cfg(feature = "ecc-default")]
+        "EDDSA" => match group.curve.as_deref() {
+            Some("ED-25519") => {
+                Ok(super::verify_case::<crate::systems::asymmetric::traditional::ecc::Ed25519>)
+            }
+            _ => Err("unsupported curve"),
+        },
+        #[cfg(feature = "rsa-default")]
+        "RSA" => {
+            use crate::prelude::{Sha256, Sha384, Sha512};
+            use crate::systems::asymmetric::traditional::rsa::{Rsa2048, Rsa4096};
+
+            if group.sig_type.as_deref() != Some("pss")
+                || group
+                    .mask_function
+                    .as_deref()
+                    .is_some_and(|mask| mask != "mgf1")
+            {
+                return Err("unsupported signature type");
+            }
+            match (group.modulo, hash, group.salt_len) {
+                (Some(2048), Some("SHA2-256"), Some(32)) => {
+                    Ok(super::verify_case::<Rsa2048<Sha256>>)
+                }
+                (Some(2048), Some("SHA2-384"), Some(48)) => {
+                    Ok(super::verify_case::<Rsa2048<Sha384>>)
+                }
+                (Some(2048), Some("SHA2-512"), Some(64)) => {
+                    Ok(super::verify_case::<Rsa2048<Sha512>>)
+                }
+                (Some(4096), Some("SHA2-256"), Some(32)) => {
+                    Ok(super::verify_case::<Rsa4096<Sha256>>)
+                }
+                (Some(4096), Some("SHA2-384"), Some(48)) => {
+                    Ok(super::verify_case::<Rsa4096<Sha384>>)
+                }
+                (Some(4096), Some("SHA2-512"), Some(64)) => {
+                    Ok(super::verify_case::<Rsa4096<Sha512>>)
+                }
+                _ => Err("unsupported modulus, hash or salt length"),
+            }
+        }
+        _ => Err("unsupported algorithm"),
+    }
+}
+
+/// Builds the SubjectPublicKeyInfo DER of a sigVer case from the key fields ACVP uses.
+///
+/// 根据 ACVP 使用的密钥字段构建 sigVer 用例的 SubjectPublicKeyInfo DER。
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+#[cfg_attr(not(feature = "rsa-default"), allow(unused_variables))]
+fn sig_ver_public_key(
+    algorithm: &str,
+    group: &SigVerGroup,
+    test: &SigVerTest,
+) -> Result<Result<Vec<u8>, Error>, Error> {
+    let field = |test: &Option<String>, group: &Option<String>| {
+        test.as_ref()
+            .or(group.as_ref())
+            .map(|hex| decode_hex(hex))
+            .transpose()?
+            .ok_or(Error::Kat(KatError::InvalidFormat))
+    };
+    match algorithm {
+        #[cfg(feature = "ecc-default")]
+        "ECDSA" => {
+            use crate::prelude::Key;
+            use crate::systems::asymmetric::traditional::ecc::{EccPublicKey, EcdsaP256Params};
+
+            let mut point = vec![0x04];
+            point.extend(super::fixed_width(field(&test.qx, &None)?, 32));
+            point.extend(super::fixed_width(field(&test.qy, &None)?, 32));
+            Ok(EccPublicKey::<EcdsaP256Params>::from_sec1_bytes(&point)
+                .and_then(|key| key.to_bytes()))
+        }
+        #[cfg(feature = "ecc-default")]
+        "EDDSA" => {
+            // SubjectPublicKeyInfo prefix of an Ed25519 key (RFC 8410)
+            let mut spki = decode_hex("302a300506032b6570032100")?;
+            spki.extend(field(&test.q, &None)?);
+            Ok(Ok(spki))
+        }
+        #[cfg(feature = "rsa-default")]
+        "RSA" => {
+            use rsa::BigUint;
+            use rsa::pkcs8::EncodePublicKey;
+
+            let n = field(&test.n, &group.n)?;
+            let e = field(&test.e, &group.e)?;
+            Ok(
+                rsa::RsaPublicKey::new(BigUint::from_bytes_be(&n), BigUint::from_bytes_be(&e))
+                    .and_then(|key| key.to_public_key_der().map_err(|_| rsa::Error::Internal))
+                    .map(|der| der.as_bytes().to_vec())
+                    .map_err(|_| Error::Key(crate::prelude::KeyError::InvalidEncoding)),
+            )
+        }
+        _ => Err(Error::Kat(KatError::UnsupportedAlgorithm)),
+    }
+}
+
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+fn run_sig_ver(
+    algorithm: &str,
+    vector_set: VectorSet<SigVerGroup>,
+) -> Result<Vec<CaseResult>, Error> {
+    let mut results = Vec::new();
+    for group in vector_set.test_groups {
+        let scheme = sig_ver_scheme(algorithm, &group);
+        for test in &group.tests {
+            let expected = expectation(test.test_passed);
+            let verify = match (scheme, test.test_passed) {
+                (Ok(verify), Some(_)) => verify,
+                (Ok(_), None) => {
+                    results.push(skipped(
+                        group.tg_id,
+                        test.tc_id,
+                        expected,
+                        "missing expected result",
+                    ));
+                    continue;
+                }
+                (Err(reason), _) => {
+                    results.push(skipped(group.tg_id, test.tc_id, expected, reason));
+                    continue;
+                }
+            };
+            let signature = match (&test.signature, &test.r, &test.s) {
+                (Some(signature), _, _) => decode_hex(signature)?,
+                (None, Some(r), Some(s)) => {
+                    let mut signature = super::fixed_width(decode_hex(r)?, 32);
+                    signature.extend(super::fixed_width(decode_hex(s)?, 32));
+                    signature
+                }
+                _ => return Err(Error::Kat(KatError::InvalidFormat)),
+            };
+            let message = decode_hex(&test.message)?;
+            let result = sig_ver_public_key(algorithm, &group, test)?
+                .and_then(|public_key| verify(&public_key, &message, signature));
+            results.push(CaseResult::judge(
+                group.tg_id,
+                test.tc_id,
+                String::new(),
+                expected,
+                result,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+// ------------------- ECC CDH -------------------
+// ------------------- ECC CDH -------------------
+
+#[cfg(feature = "ecdh-default")]
+fn run_cdh(vector_set: VectorSet<CdhGroup>) -> Result<Vec<CaseResult>, Error> {
+    use crate::systems::asymmetric::traditional::ecdh::EcdhPublicKey;
+
+    let mut results = Vec::new();
+    for group in vector_set.test_groups {
+        for test in group.tests {
+            let expected = expectation(test.test_passed);
+            if group.curve != "P-256" {
+                results.push(skipped(
+                    group.tg_id,
+                    test.tc_id,
+                    expected,
+                    "unsupported curve",
+                ));
+                continue;
+            }
+            let Some(z) = &test.z else {
+                results.push(skipped(
+                    group.tg_id,
+                    test.tc_id,
+                    expected,
+                    "missing expected result",
+                ));
+                continue;
+            };
+            let mut point = vec![0x04];
+            point.extend(super::fixed_width(decode_hex(&test.public_server_x)?, 32));
+            point.extend(super::fixed_width(decode_hex(&test.public_server_y)?, 32));
+            let result = super::ecdh_p256_case(
+                decode_hex(&test.private_iut)?,
+                EcdhPublicKey::from_sec1_bytes(&point),
+                &super::fixed_width(decode_hex(z)?, 32),
+            );
+            results.push(CaseResult::judge(
+                group.tg_id,
+                test.tc_id,
+                String::new(),
+                expected,
+                result,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "aes-gcm-default", feature = "ecdh-default"))]
+    use crate::kat::CaseOutcome;
+
+    #[test]
+    fn test_unsupported_algorithm() {
+        let json =
+            r#"{"vsId": 1, "algorithm": "ACVP-AES-XTS", "revision": "2.0", "testGroups": []}"#;
+        assert_eq!(run(json), Err(Error::Kat(KatError::UnsupportedAlgorithm)));
+        assert_eq!(run("[]"), Err(Error::Kat(KatError::InvalidFormat)));
+    }
+
+    #[test]
+    #[cfg(feature = "aes-gcm-default")]
+    fn test_aes_gcm() -> Result<(), Error> {
+        let json = r#"[
+            {"acvVersion": "1.0"},
+            {
+                "vsId": 1, "algorithm": "ACVP-AES-GCM", "revision": "1.0", "isSample": true,
+                "testGroups": [
+                    {
+                        "tgId": 1, "testType": "AFT", "direction": "encrypt", "keyLen": 128,
+                        "ivLen": 96, "ivGen": "external", "payloadLen": 248, "aadLen": 200,
+                        "tagLen": 128,
+                        "tests": [
+                            {
+                                "tcId": 1,
+                                "key": "000102030405060708090A0B0C0D0E0F",
+                                "iv": "404142434445464748494A4B",
+                                "aad": "7365616C2D63727970746F2073656C662D7465737420616164",
+                                "pt": "7365616C2D63727970746F2073656C662D7465737420706C61696E74657874",
+                                "ct": "4A4C08A640288BC9FCCD2C3F8D42119E6109DC1869F2E5F2DA29A65519A477",
+                                "tag": "97818A1F5DC193E7ACD054CC4B212D43"
+                            }
+                        ]
+                    },
+                    {
+                        "tgId": 2, "testType": "AFT", "direction": "decrypt", "keyLen": 128,
+                        "ivLen": 96, "ivGen": "external", "payloadLen": 248, "aadLen": 200,
+                        "tagLen": 128,
+                        "tests": [
+                            {
+                                "tcId": 2,
+                                "key": "000102030405060708090A0B0C0D0E0F",
+                                "iv": "404142434445464748494A4B",
+                                "aad": "7365616C2D63727970746F2073656C662D7465737420616164",
+                                "ct": "4A4C08A640288BC9FCCD2C3F8D42119E6109DC1869F2E5F2DA29A65519A477",
+                                "tag": "97818A1F5DC193E7ACD054CC4B212D44",
+                                "testPassed": false
+                            },
+                            {
+                                "tcId": 3,
+                                "key": "000102030405060708090A0B0C0D0E0F",
+                                "iv": "404142434445464748494A4B",
+                                "aad": "7365616C2D63727970746F2073656C662D7465737420616164",
+                                "ct": "4A4C08A640288BC9FCCD2C3F8D42119E6109DC1869F2E5F2DA29A65519A477",
+                                "tag": "97818A1F5DC193E7ACD054CC4B212D43"
+                            }
+                        ]
+                    },
+                    {
+                        "tgId": 3, "testType": "AFT", "direction": "encrypt", "keyLen": 192,
+                        "ivLen": 96, "ivGen": "external", "payloadLen": 0, "aadLen": 0,
+                        "tagLen": 128,
+                        "tests": [{"tcId": 4, "key": "", "iv": "", "pt": "", "ct": "", "tag": ""}]
+                    }
+                ]
+            }
+        ]"#;
+        let report = run(json)?;
+        assert_eq!(report.algorithm, "ACVP-AES-GCM");
+        assert!(report.passed(), "{:?}", report.cases);
+        let outcomes: Vec<_> = report.cases.iter().map(|case| &case.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                &CaseOutcome::Passed,
+                &CaseOutcome::Passed,
+                &CaseOutcome::Skipped("missing expected result"),
+                &CaseOutcome::Skipped("unsupported key, IV or tag length")
+            ]
+        );
+        assert_eq!(report.cases[1].expected, Expectation::Invalid);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ecc-default")]
+    fn test_ecdsa_sig_ver() -> Result<(), Error> {
+        let json = r#"{
+            "vsId": 1, "algorithm": "ECDSA", "mode": "sigVer", "revision": "FIPS186-5",
+            "testGroups": [
+                {
+                    "tgId": 1, "testType": "AFT", "curve": "P-256", "hashAlg": "SHA2-256",
+                    "tests": [
+                        {
+                            "tcId": 1,
+                            "message": "7365616C2D63727970746F2073656C662D74657374206D657373616765",
+                            "qx": "217D40D5DC03226FEA8833C8B0C1553BB467BD3B7344B729B439C63A65B721EA",
+                            "qy": "D3F82588C91285CFE4B6F740939BFC5C0DA207EC6EDDC5682732B83C6F8E5D87",
+                            "r": "F3CFD0C4E209D3DC03B5697021F99E2509877F025E309E869C0C01C9C71A4F62",
+                            "s": "02D5F88865607396B46E041BF05DF6BAA262049E50C75935339E20E3B08F2A47",
+                            "testPassed": true
+                        },
+                        {
+                            "tcId": 2,
+                            "message": "7365616C2D63727970746F2073656C662D74657374206D657373616766",
+                            "qx": "217D40D5DC03226FEA8833C8B0C1553BB467BD3B7344B729B439C63A65B721EA",
+                            "qy": "D3F82588C91285CFE4B6F740939BFC5C0DA207EC6EDDC5682732B83C6F8E5D87",
+                            "r": "F3CFD0C4E209D3DC03B5697021F99E2509877F025E309E869C0C01C9C71A4F62",
+                            "s": "02D5F88865607396B46E041BF05DF6BAA262049E50C75935339E20E3B08F2A47",
+                            "testPassed": false
+                        }
+                    ]
+                },
+                {
+                    "tgId": 2, "testType": "AFT", "curve": "P-384", "hashAlg": "SHA2-384",
+                    "tests": [{"tcId": 3, "message": "", "testPassed": true}]
+                }
+            ]
+        }"#;
+        let report = run(json)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(
+            report.skipped().map(|case| case.id).collect::<Vec<_>>(),
+            [3]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ecc-default")]
+    fn test_eddsa_sig_ver() -> Result<(), Error> {
+        let json = r#"{
+            "vsId": 1, "algorithm": "EDDSA", "mode": "sigVer", "revision": "1.0",
+            "testGroups": [
+                {
+                    "tgId": 1, "testType": "AFT", "curve": "ED-25519", "preHash": false,
+                    "tests": [
+                        {
+                            "tcId": 1, "message": "",
+                            "q": "D75A980182B10AB7D54BFED3C964073A0EE172F3DAA62325AF021A68F707511A",
+                            "signature": "E5564300C360AC729086E2CC806E828A84877F1EB8E5D974D873E065224901555FB8821590A33BACC61E39701CF9B46BD25BF5F0595BBE24655141438E7A100B",
+                            "testPassed": true
+                        },
+                        {
+                            "tcId": 2, "message": "00",
+                            "q": "D75A980182B10AB7D54BFED3C964073A0EE172F3DAA62325AF021A68F707511A",
+                            "signature": "E5564300C360AC729086E2CC806E828A84877F1EB8E5D974D873E065224901555FB8821590A33BACC61E39701CF9B46BD25BF5F0595BBE24655141438E7A100B",
+                            "testPassed": false
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let report = run(json)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(report.skipped().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rsa-default")]
+    fn test_rsa_sig_ver() -> Result<(), Error> {
+        let json = r#"{
+            "vsId": 1, "algorithm": "RSA", "mode": "sigVer", "revision": "FIPS186-4",
+            "testGroups": [
+                {
+                    "tgId": 1, "testType": "GDT", "sigType": "pss", "modulo": 2048,
+                    "hashAlg": "SHA2-256", "saltLen": 32,
+                    "n": "97107AB14CA81580D5D3A226CD058559110D227618A7850E94FBA0DDF638F6539707EC4B42B6B105549E98B176A9ACDCD3C4964EA0DC6E4CB736A976FC879FB100162E077E9808CADE34E4AFA58D0216E68A696F0706E9DFBE813F9D8686119C7D90B104A64B6D7C2DFC1B917B82A1CB5B5A1C1A8B4991FC4F4445D9186ECD4C3E0E92C0A68BF5BBE8DEAC65F714F506BC827656544A8C2EAD22A8505B9A8F940B842F5EB38C76974D7904B8C1F4B9C9DBCABEDE55DA81E93986BD3973B1CBE29D3B09F2A509ECB27E1156B670C118DC049885AAAF98B528D84DD9F554C4BCB356AE64CAE1B554AC3ED256C90FAD8D325EB5641D47C3A677C7D4E21C58818C0F",
+                    "e": "010001",
+                    "tests": [
+                        {
+                            "tcId": 1,
+                            "message": "7365616C2D63727970746F2073656C662D74657374206D657373616765",
+                            "signature": "3532F21B9568A26620D7FCC4DA7AACF7CDFB6F96F5BA72FD200A596ACEDDD9C63874480EB10BE3CEBAB3867ECE66A051035B7CC43F081F3502DEE70FC89D689B62B77870F23185A6EF6F66BA5A2CFC0EC23B5FC06F963BB3EFE33854B4388531A14F953B8554732113BFCEF4279A40C18EF07D535AD4D7CDF3DBCA6D2CBC163623B19D2A8E582837555A32603CAECF136471F7A90582E06BFC7266CEE9F127C7F472DF97F5BAA4E8451D637F5D55A952E483E7DA2985E0BFFFE6212D75BC6853782F104EA4296A8983DAEE27312621CE3F0047D965AF9728AC87CB82DEB23ACFED999C3AF92E2963D8A570099A5AFFA9E4AAE09CC7915AEAE749A59D492062C8",
+                            "testPassed": true
+                        },
+                        {
+                            "tcId": 2,
+                            "message": "7365616C2D63727970746F2073656C662D74657374206D657373616765",
+                            "signature": "4532F21B9568A26620D7FCC4DA7AACF7CDFB6F96F5BA72FD200A596ACEDDD9C63874480EB10BE3CEBAB3867ECE66A051035B7CC43F081F3502DEE70FC89D689B62B77870F23185A6EF6F66BA5A2CFC0EC23B5FC06F963BB3EFE33854B4388531A14F953B8554732113BFCEF4279A40C18EF07D535AD4D7CDF3DBCA6D2CBC163623B19D2A8E582837555A32603CAECF136471F7A90582E06BFC7266CEE9F127C7F472DF97F5BAA4E8451D637F5D55A952E483E7DA2985E0BFFFE6212D75BC6853782F104EA4296A8983DAEE27312621CE3F0047D965AF9728AC87CB82DEB23ACFED999C3AF92E2963D8A570099A5AFFA9E4AAE09CC7915AEAE749A59D492062C8",
+                            "testPassed": false
+                        }
+                    ]
+                },
+                {
+                    "tgId": 2, "testType": "GDT", "sigType": "pkcs1v1.5", "modulo": 2048,
+                    "hashAlg": "SHA2-256",
+                    "tests": [{"tcId": 3, "message": "", "signature": "", "testPassed": true}]
+                }
+            ]
+        }"#;
+        let report = run(json)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(
+            report.skipped().map(|case| case.id).collect::<Vec<_>>(),
+            [3]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ecdh-default")]
+    fn test_cdh_component() -> Result<(), Error> {
+        let json = r#"{
+            "vsId": 1, "algorithm": "KAS-ECC", "mode": "CDH-Component", "revision": "1.0",
+            "testGroups": [
+                {
+                    "tgId": 1, "testType": "AFT", "curve": "P-256",
+                    "tests": [
+                        {
+                            "tcId": 1,
+                            "publicServerX": "46D49908CB42076A345A5934D4882CC750B8470396552C2392AA6945737182DC",
+                            "publicServerY": "9E4A4010DD859D46053104966F2A68A7E6C9332B54322513023535EC4A840AD4",
+                            "privateIut": "234CCC9865444311E3A2D0B0D7593730D6CC016962FFB24EBC219B4898845790",
+                            "z": "F8715C1E54D326BBF99310F103145BB879102146970611E2206F090807D194F6"
+                        },
+                        {
+                            "tcId": 2,
+                            "publicServerX": "46D49908CB42076A345A5934D4882CC750B8470396552C2392AA6945737182DC",
+                            "publicServerY": "9E4A4010DD859D46053104966F2A68A7E6C9332B54322513023535EC4A840AD4",
+                            "privateIut": "234CCC9865444311E3A2D0B0D7593730D6CC016962FFB24EBC219B4898845790",
+                            "z": "F8715C1E54D326BBF99310F103145BB879102146970611E2206F090807D194F7"
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let report = run(json)?;
+        assert_eq!(report.cases[0].outcome, CaseOutcome::Passed);
+        assert_eq!(
+            report.cases[1].outcome,
+            CaseOutcome::Failed(Some(Error::Kat(KatError::OutputMismatch)))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,722 @@
+//! Runs Wycheproof test vector files.
+//!
+//! | `algorithm` | Schemes |
+//! |---|---|
+//! | `AES-GCM` | AES-128-GCM, AES-256-GCM |
+//! | `CHACHA20-POLY1305`, `XCHACHA20-POLY1305` | ChaCha20-Poly1305, XChaCha20-Poly1305 |
+//! | `ECDH` (`asn` and `ecpoint` encodings) | ECDH P-256 |
+//! | `ECDSA` (DER and P1363 signatures) | ECDSA P-256 and secp256k1 with SHA-256 |
+//! | `EDDSA` | Ed25519 |
+//! | `RSASSA-PSS` | RSA-2048 and RSA-4096 with MGF1 and a salt as long as the hash |
+//!
+//! 运行 Wycheproof 测试向量文件。支持的文件见上表。
+
+// Which runners are compiled depends on the enabled algorithm features.
+#![allow(dead_code, unused_imports)]
+
+use super::{CaseResult, Expectation, KatError, KatReport, decode_hex, parse};
+use crate::errors::Error;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Header {
+    algorithm: String,
+    #[serde(default)]
+    schema: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct File<G> {
+    test_groups: Vec<G>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AeadGroup {
+    key_size: usize,
+    iv_size: usize,
+    tag_size: usize,
+    tests: Vec<AeadTest>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AeadTest {
+    tc_id: u64,
+    #[serde(default)]
+    comment: String,
+    key: String,
+    iv: String,
+    aad: String,
+    msg: String,
+    ct: String,
+    tag: String,
+    result: Expectation,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EcdhGroup {
+    curve: String,
+    #[serde(default)]
+    encoding: Option<String>,
+    tests: Vec<EcdhTest>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EcdhTest {
+    tc_id: u64,
+    #[serde(default)]
+    comment: String,
+    public: String,
+    private: String,
+    shared: String,
+    result: Expectation,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GroupKey {
+    #[serde(default)]
+    curve: Option<String>,
+}
+
+// Older files name the key fields `key` and `keyDer`.
+// 较旧的文件将密钥字段命名为 `key` 和 `keyDer`。
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignatureGroup {
+    #[serde(alias = "keyDer")]
+    public_key_der: String,
+    #[serde(default, alias = "key")]
+    public_key: Option<GroupKey>,
+    #[serde(default)]
+    sha: Option<String>,
+    #[serde(default)]
+    mgf: Option<String>,
+    #[serde(default)]
+    mgf_sha: Option<String>,
+    #[serde(default)]
+    s_len: Option<usize>,
+    #[serde(default)]
+    key_size: Option<usize>,
+    tests: Vec<SignatureTest>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignatureTest {
+    tc_id: u64,
+    #[serde(default)]
+    comment: String,
+    msg: String,
+    sig: String,
+    result: Expectation,
+}
+
+/// Runs every case of a Wycheproof test vector file.
+///
+/// Fails with `KatError::InvalidFormat` if the file cannot be parsed, with
+/// `KatError::UnsupportedAlgorithm` if its algorithm is not supported by the enabled features,
+/// and with `KatError::InvalidHex` if a case contains malformed hex.
+///
+/// 运行 Wycheproof 测试向量文件的每个用例。
+///
+/// 如果文件无法解析，则以 `KatError::InvalidFormat` 失败；如果其算法不受已启用特性支持，
+/// 则以 `KatError::UnsupportedAlgorithm` 失败；如果用例包含格式错误的十六进制，则以
+/// `KatError::InvalidHex` 失败。
+pub fn run(json: &str) -> Result<KatReport, Error> {
+    let header: Header = parse(json)?;
+    let cases = match header.algorithm.as_str() {
+        #[cfg(any(feature = "aes-gcm-default", feature = "chacha20-poly1305-default"))]
+        "AES-GCM" | "CHACHA20-POLY1305" | "XCHACHA20-POLY1305" => {
+            run_aead(&header.algorithm, parse(json)?)
+        }
+        #[cfg(feature = "ecdh-default")]
+        "ECDH" => run_ecdh(&header.schema, parse(json)?),
+        #[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+        "ECDSA" | "EDDSA" | "RSASSA-PSS" => {
+            run_signature(&header.algorithm, &header.schema, parse(json)?)
+        }
+        _ => Err(Error::Kat(KatError::UnsupportedAlgorithm)),
+    }?;
+    Ok(KatReport {
+        algorithm: header.algorithm,
+        cases,
+    })
+}
+
+// ------------------- AEAD -------------------
+// ------------------- AEAD -------------------
+
+#[cfg(any(feature = "aes-gcm-default", feature = "chacha20-poly1305-default"))]
+fn aead_scheme(algorithm: &str, group: &AeadGroup) -> Result<super::AeadCase, &'static str> {
+    if group.tag_size != 128 {
+        return Err("unsupported tag size");
+    }
+    let (iv_size, case): (usize, super::AeadCase) = match (algorithm, group.key_size) {
+        #[cfg(feature = "aes-gcm-default")]
+        ("AES-GCM", 128) => (
+            96,
+            super::aead_case::<crate::systems::aead::aes_gcm::Aes128Gcm>,
+        ),
+        #[cfg(feature = "aes-gcm-default")]
+        ("AES-GCM", 256) => (
+            96,
+            super::aead_case::<crate::systems::aead::aes_gcm::Aes256Gcm>,
+        ),
+        #[cfg(feature = "chacha20-poly1305-default")]
+        ("CHACHA20-POLY1305", 256) => (
+            96,
+            super::aead_case::<crate::systems::aead::chacha20_poly1305::ChaCha20Poly1305>,
+        ),
+        #[cfg(feature = "chacha20-poly1305-default")]
+        ("XCHACHA20-POLY1305", 256) => (
+            192,
+            super::aead_case::<crate::systems::aead::chacha20_poly1305::XChaCha20Poly1305>,
+        ),
+        _ => return Err("unsupported key size"),
+    };
+    if group.iv_size != iv_size {
+        return Err("unsupported nonce size");
+    }
+    Ok(case)
+}
+
+#[cfg(any(feature = "aes-gcm-default", feature = "chacha20-poly1305-default"))]
+fn run_aead(algorithm: &str, file: File<AeadGroup>) -> Result<Vec<CaseResult>, Error> {
+    let mut results = Vec::new();
+    for (group_id, group) in (1..).zip(file.test_groups) {
+        let scheme = aead_scheme(algorithm, &group);
+        for test in group.tests {
+            let case = match scheme {
+                Ok(case) => case,
+                Err(reason) => {
+                    results.push(CaseResult::skipped(
+                        group_id,
+                        test.tc_id,
+                        test.comment,
+                        test.result,
+                        reason,
+                    ));
+                    continue;
+                }
+            };
+            let mut ciphertext = decode_hex(&test.ct)?;
+            ciphertext.extend(decode_hex(&test.tag)?);
+            let plaintext = decode_hex(&test.msg)?;
+            // Encrypting an invalid case would fail on the ciphertext mismatch alone, so only
+            // its decryption is checked.
+            // 加密无效用例仅因密文不匹配就会失败，因此只检查其解密。
+            let plaintext = match test.result {
+                Expectation::Invalid => None,
+                _ => Some(plaintext.as_slice()),
+            };
+            let result = case(
+                decode_hex(&test.key)?,
+                &decode_hex(&test.iv)?,
+                &decode_hex(&test.aad)?,
+                plaintext,
+                &ciphertext,
+            );
+            results.push(CaseResult::judge(
+                group_id,
+                test.tc_id,
+                test.comment,
+                test.result,
+                result,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+// ------------------- ECDH -------------------
+// ------------------- ECDH -------------------
+
+#[cfg(feature = "ecdh-default")]
+fn run_ecdh(schema: &str, file: File<EcdhGroup>) -> Result<Vec<CaseResult>, Error> {
+    use crate::prelude::Key;
+    use crate::systems::asymmetric::traditional::ecdh::EcdhPublicKey;
+
+    let mut results = Vec::new();
+    for (group_id, group) in (1..).zip(file.test_groups) {
+        let ecpoint = match group.encoding.as_deref() {
+            Some(encoding) => encoding == "ecpoint",
+            None => schema.contains("ecpoint"),
+        };
+        for test in group.tests {
+            if group.curve != "secp256r1" {
+                results.push(CaseResult::skipped(
+                    group_id,
+                    test.tc_id,
+                    test.comment,
+                    test.result,
+                    "unsupported curve",
+                ));
+                continue;
+            }
+            let public = decode_hex(&test.public)?;
+            let public_key = if ecpoint {
+                EcdhPublicKey::from_sec1_bytes(&public)
+            } else {
+                EcdhPublicKey::from_bytes(&public)
+            };
+            let result = super::ecdh_p256_case(
+                decode_hex(&test.private)?,
+                public_key,
+                &decode_hex(&test.shared)?,
+            );
+            results.push(CaseResult::judge(
+                group_id,
+                test.tc_id,
+                test.comment,
+                test.result,
+                result,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+// ------------------- Signatures -------------------
+// ------------------- 签名 -------------------
+
+/// Converts a DER-encoded ECDSA signature into the fixed-size `r || s` form the schemes use.
+///
+/// 将 DER 编码的 ECDSA 签名转换为方案使用的固定大小 `r || s` 形式。
+#[cfg(feature = "ecc-default")]
+macro_rules! ecdsa_der {
+    ($curve:ty) => {
+        |signature: Vec<u8>| {
+            ecdsa::Signature::<$curve>::from_der(&signature)
+                .map(|signature| signature.to_vec())
+                .map_err(|_| Error::Signature(crate::prelude::SignatureError::InvalidSignature))
+        }
+    };
+}
+
+/// Converts a signature from the file's encoding into the one the scheme verifies.
+///
+/// 将签名从文件的编码转换为方案验证所用的编码。
+type DecodeSignature = fn(Vec<u8>) -> Result<Vec<u8>, Error>;
+
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+#[cfg_attr(not(feature = "ecc-default"), allow(unused_variables))]
+fn signature_scheme(
+    algorithm: &str,
+    schema: &str,
+    group: &SignatureGroup,
+) -> Result<(super::VerifyCase, DecodeSignature), &'static str> {
+    let raw: DecodeSignature = Ok;
+    let curve = group
+        .public_key
+        .as_ref()
+        .and_then(|key| key.curve.as_deref());
+    let sha = group.sha.as_deref();
+    match algorithm {
+        #[cfg(feature = "ecc-default")]
+        "ECDSA" => {
+            use crate::systems::asymmetric::traditional::ecc;
+
+            if sha != Some("SHA-256") {
+                return Err("unsupported hash");
+            }
+            let p1363 = schema.contains("p1363");
+            match curve {
+                Some("secp256r1") => Ok((
+                    super::verify_case::<ecc::EcdsaP256>,
+                    if p1363 {
+                        raw
+                    } else {
+                        ecdsa_der!(p256::NistP256)
+                    },
+                )),
+                #[cfg(not(feature = "fips"))]
+                Some("secp256k1") => Ok((
+                    super::verify_case::<ecc::EcdsaSecp256k1>,
+                    if p1363 {
+                        raw
+                    } else {
+                        ecdsa_der!(k256::Secp256k1)
+                    },
+                )),
+                _ => Err("unsupported curve"),
+            }
+        }
+        #[cfg(feature = "ecc-default")]
+        "EDDSA" => match curve {
+            Some("edwards25519") => Ok((
+                super::verify_case::<crate::systems::asymmetric::traditional::ecc::Ed25519>,
+                raw,
+            )),
+            _ => Err("unsupported curve"),
+        },
+        #[cfg(feature = "rsa-default")]
+        "RSASSA-PSS" => {
+            use crate::prelude::{Sha256, Sha384, Sha512};
+            use crate::systems::asymmetric::traditional::rsa::{Rsa2048, Rsa4096};
+
+            if group.mgf.as_deref() != Some("MGF1") || group.mgf_sha.as_deref() != sha {
+                return Err("unsupported mask generation function");
+            }
+            let verify: super::VerifyCase = match (group.key_size, sha, group.s_len) {
+                (Some(2048), Some("SHA-256"), Some(32)) => super::verify_case::<Rsa2048<Sha256>>,
+                (Some(2048), Some("SHA-384"), Some(48)) => super::verify_case::<Rsa2048<Sha384>>,
+                (Some(2048), Some("SHA-512"), Some(64)) => super::verify_case::<Rsa2048<Sha512>>,
+                (Some(4096), Some("SHA-256"), Some(32)) => super::verify_case::<Rsa4096<Sha256>>,
+                (Some(4096), Some("SHA-384"), Some(48)) => super::verify_case::<Rsa4096<Sha384>>,
+                (Some(4096), Some("SHA-512"), Some(64)) => super::verify_case::<Rsa4096<Sha512>>,
+                _ => return Err("unsupported key size, hash or salt length"),
+            };
+            Ok((verify, raw))
+        }
+        _ => Err("unsupported algorithm"),
+    }
+}
+
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+fn run_signature(
+    algorithm: &str,
+    schema: &str,
+    file: File<SignatureGroup>,
+) -> Result<Vec<CaseResult>, Error> {
+    let mut results = Vec::new();
+    for (group_id, group) in (1..).zip(file.test_groups) {
+        let scheme = signature_scheme(algorithm, schema, &group);
+        let public_key = decode_hex(&group.public_key_der)?;
+        for test in group.tests {
+            let (verify, decode_signature) = match scheme {
+                Ok(scheme) => scheme,
+                Err(reason) => {
+                    results.push(CaseResult::skipped(
+                        group_id,
+                        test.tc_id,
+                        test.comment,
+                        test.result,
+                        reason,
+                    ));
+                    continue;
+                }
+            };
+            let message = decode_hex(&test.msg)?;
+            let result = decode_signature(decode_hex(&test.sig)?)
+                .and_then(|signature| verify(&public_key, &message, signature));
+            results.push(CaseResult::judge(
+                group_id,
+                test.tc_id,
+                test.comment,
+                test.result,
+                result,
+            ));
+        }
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "aes-gcm-default")]
+    use crate::kat::CaseOutcome;
+
+    #[test]
+    fn test_unsupported_algorithm() {
+        let json = r#"{"algorithm": "SIPHASH", "testGroups": []}"#;
+        assert_eq!(run(json), Err(Error::Kat(KatError::UnsupportedAlgorithm)));
+        assert_eq!(run("{}"), Err(Error::Kat(KatError::InvalidFormat)));
+    }
+
+    #[test]
+    #[cfg(feature = "aes-gcm-default")]
+    fn test_aes_gcm() -> Result<(), Error> {
+        let json = r#"{
+            "algorithm": "AES-GCM",
+            "schema": "aead_test_schema.json",
+            "testGroups": [
+                {
+                    "ivSize": 96, "keySize": 128, "tagSize": 128, "type": "AeadTest",
+                    "tests": [
+                        {
+                            "tcId": 1, "comment": "", "flags": [],
+                            "key": "000102030405060708090a0b0c0d0e0f",
+                            "iv": "404142434445464748494a4b",
+                            "aad": "7365616c2d63727970746f2073656c662d746573742061616",
+                            "msg": "", "ct": "", "tag": "", "result": "valid"
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        assert_eq!(run(json), Err(Error::Kat(KatError::InvalidHex)));
+
+        let json = r#"{
+            "algorithm": "AES-GCM",
+            "schema": "aead_test_schema.json",
+            "testGroups": [
+                {
+                    "ivSize": 96, "keySize": 128, "tagSize": 128, "type": "AeadTest",
+                    "tests": [
+                        {
+                            "tcId": 1, "comment": "", "flags": [],
+                            "key": "000102030405060708090a0b0c0d0e0f",
+                            "iv": "404142434445464748494a4b",
+                            "aad": "7365616c2d63727970746f2073656c662d7465737420616164",
+                            "msg": "7365616c2d63727970746f2073656c662d7465737420706c61696e74657874",
+                            "ct": "4a4c08a640288bc9fccd2c3f8d42119e6109dc1869f2e5f2da29a65519a477",
+                            "tag": "97818a1f5dc193e7acd054cc4b212d43",
+                            "result": "valid"
+                        },
+                        {
+                            "tcId": 2, "comment": "Flipped bit 0 in tag", "flags": [],
+                            "key": "000102030405060708090a0b0c0d0e0f",
+                            "iv": "404142434445464748494a4b",
+                            "aad": "7365616c2d63727970746f2073656c662d7465737420616164",
+                            "msg": "7365616c2d63727970746f2073656c662d7465737420706c61696e74657874",
+                            "ct": "4a4c08a640288bc9fccd2c3f8d42119e6109dc1869f2e5f2da29a65519a477",
+                            "tag": "96818a1f5dc193e7acd054cc4b212d43",
+                            "result": "invalid"
+                        }
+                    ]
+                },
+                {
+                    "ivSize": 64, "keySize": 128, "tagSize": 128, "type": "AeadTest",
+                    "tests": [
+                        {
+                            "tcId": 3, "comment": "", "flags": [],
+                            "key": "000102030405060708090a0b0c0d0e0f",
+                            "iv": "4041424344454647", "aad": "", "msg": "", "ct": "",
+                            "tag": "00000000000000000000000000000000", "result": "valid"
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let report = run(json)?;
+        assert_eq!(report.algorithm, "AES-GCM");
+        assert!(report.passed());
+        let outcomes: Vec<_> = report.cases.iter().map(|case| &case.outcome).collect();
+        assert_eq!(
+            outcomes,
+            [
+                &CaseOutcome::Passed,
+                &CaseOutcome::Passed,
+                &CaseOutcome::Skipped("unsupported nonce size")
+            ]
+        );
+        assert_eq!(report.cases[2].group, 2);
+
+        // Claiming the forged tag is valid must be reported as a failure.
+        let forged = json.replacen(r#""invalid""#, r#""valid""#, 1);
+        let report = run(&forged)?;
+        assert_eq!(
+            report.failures().map(|case| case.id).collect::<Vec<_>>(),
+            [2]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ecdh-default")]
+    fn test_ecdh() -> Result<(), Error> {
+        let json = r#"{
+            "algorithm": "ECDH",
+            "schema": "ecdh_ecpoint_test_schema.json",
+            "testGroups": [
+                {
+                    "curve": "secp256r1", "encoding": "ecpoint", "type": "EcdhEcpointTest",
+                    "tests": [
+                        {
+                            "tcId": 1, "comment": "compressed public key", "flags": [],
+                            "public": "0246d49908cb42076a345a5934d4882cc750b8470396552c2392aa6945737182dc",
+                            "private": "00234ccc9865444311e3a2d0b0d7593730d6cc016962ffb24ebc219b4898845790",
+                            "shared": "f8715c1e54d326bbf99310f103145bb879102146970611e2206f090807d194f6",
+                            "result": "acceptable"
+                        },
+                        {
+                            "tcId": 2, "comment": "", "flags": [],
+                            "public": "0446d49908cb42076a345a5934d4882cc750b8470396552c2392aa6945737182dc9e4a4010dd859d46053104966f2a68a7e6c9332b54322513023535ec4a840ad4",
+                            "private": "234ccc9865444311e3a2d0b0d7593730d6cc016962ffb24ebc219b4898845790",
+                            "shared": "f8715c1e54d326bbf99310f103145bb879102146970611e2206f090807d194f6",
+                            "result": "valid"
+                        },
+                        {
+                            "tcId": 3, "comment": "point is not on curve", "flags": [],
+                            "public": "0446d49908cb42076a345a5934d4882cc750b8470396552c2392aa6945737182dc9e4a4010dd859d46053104966f2a68a7e6c9332b54322513023535ec4a840ad5",
+                            "private": "234ccc9865444311e3a2d0b0d7593730d6cc016962ffb24ebc219b4898845790",
+                            "shared": "",
+                            "result": "invalid"
+                        }
+                    ]
+                },
+                {
+                    "curve": "brainpoolP256r1", "encoding": "ecpoint", "type": "EcdhEcpointTest",
+                    "tests": [
+                        {
+                            "tcId": 4, "comment": "", "flags": [], "public": "",
+                            "private": "", "shared": "", "result": "valid"
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let report = run(json)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(
+            report.skipped().map(|case| case.id).collect::<Vec<_>>(),
+            [4]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "ecc-default")]
+    fn test_ecdsa_and_eddsa() -> Result<(), Error> {
+        let p1363 = r#"{
+            "algorithm": "ECDSA",
+            "schema": "ecdsa_p1363_verify_schema.json",
+            "testGroups": [
+                {
+                    "publicKey": {"curve": "secp256r1", "type": "EcPublicKey"},
+                    "publicKeyDer": "3059301306072a8648ce3d020106082a8648ce3d03010703420004217d40d5dc03226fea8833c8b0c1553bb467bd3b7344b729b439c63a65b721ead3f82588c91285cfe4b6f740939bfc5c0da207ec6eddc5682732b83c6f8e5d87",
+                    "sha": "SHA-256",
+                    "type": "EcdsaP1363Verify",
+                    "tests": [
+                        {
+                            "tcId": 1, "comment": "", "flags": [],
+                            "msg": "7365616c2d63727970746f2073656c662d74657374206d657373616765",
+                            "sig": "f3cfd0c4e209d3dc03b5697021f99e2509877f025e309e869c0c01c9c71a4f6202d5f88865607396b46e041bf05df6baa262049e50c75935339e20e3b08f2a47",
+                            "result": "valid"
+                        },
+                        {
+                            "tcId": 2, "comment": "r is zero", "flags": [],
+                            "msg": "7365616c2d63727970746f2073656c662d74657374206d657373616765",
+                            "sig": "000000000000000000000000000000000000000000000000000000000000000002d5f88865607396b46e041bf05df6baa262049e50c75935339e20e3b08f2a47",
+                            "result": "invalid"
+                        }
+                    ]
+                },
+                {
+                    "publicKey": {"curve": "secp256r1", "type": "EcPublicKey"},
+                    "publicKeyDer": "3059301306072a8648ce3d020106082a8648ce3d03010703420004217d40d5dc03226fea8833c8b0c1553bb467bd3b7344b729b439c63a65b721ead3f82588c91285cfe4b6f740939bfc5c0da207ec6eddc5682732b83c6f8e5d87",
+                    "sha": "SHA-512",
+                    "type": "EcdsaP1363Verify",
+                    "tests": [
+                        {"tcId": 3, "comment": "", "flags": [], "msg": "", "sig": "", "result": "valid"}
+                    ]
+                }
+            ]
+        }"#;
+        let report = run(p1363)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(
+            report.skipped().map(|case| case.id).collect::<Vec<_>>(),
+            [3]
+        );
+
+        // The same signature in DER, and with a non-minimal BER length, which must be rejected.
+        let der = r#"{
+            "algorithm": "ECDSA",
+            "schema": "ecdsa_verify_schema.json",
+            "testGroups": [
+                {
+                    "key": {"curve": "secp256r1", "type": "EcPublicKey"},
+                    "keyDer": "3059301306072a8648ce3d020106082a8648ce3d03010703420004217d40d5dc03226fea8833c8b0c1553bb467bd3b7344b729b439c63a65b721ead3f82588c91285cfe4b6f740939bfc5c0da207ec6eddc5682732b83c6f8e5d87",
+                    "sha": "SHA-256",
+                    "type": "EcdsaVerify",
+                    "tests": [
+                        {
+                            "tcId": 1, "comment": "", "flags": [],
+                            "msg": "7365616c2d63727970746f2073656c662d74657374206d657373616765",
+                            "sig": "3045022100f3cfd0c4e209d3dc03b5697021f99e2509877f025e309e869c0c01c9c71a4f62022002d5f88865607396b46e041bf05df6baa262049e50c75935339e20e3b08f2a47",
+                            "result": "valid"
+                        },
+                        {
+                            "tcId": 2, "comment": "long form encoding of length of sequence", "flags": ["BerEncodedSignature"],
+                            "msg": "7365616c2d63727970746f2073656c662d74657374206d657373616765",
+                            "sig": "308145022100f3cfd0c4e209d3dc03b5697021f99e2509877f025e309e869c0c01c9c71a4f62022002d5f88865607396b46e041bf05df6baa262049e50c75935339e20e3b08f2a47",
+                            "result": "invalid"
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let report = run(der)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(report.skipped().count(), 0);
+
+        // RFC 8032, section 7.1, test 1
+        let eddsa = r#"{
+            "algorithm": "EDDSA",
+            "schema": "eddsa_verify_schema.json",
+            "testGroups": [
+                {
+                    "publicKey": {"curve": "edwards25519", "keySize": 255, "pk": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a", "type": "EDDSAPublicKey"},
+                    "publicKeyDer": "302a300506032b6570032100d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                    "type": "EddsaVerify",
+                    "tests": [
+                        {
+                            "tcId": 1, "comment": "", "flags": [], "msg": "",
+                            "sig": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+                            "result": "valid"
+                        },
+                        {
+                            "tcId": 2, "comment": "message changed", "flags": [], "msg": "00",
+                            "sig": "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+                            "result": "invalid"
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let report = run(eddsa)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(report.cases.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "rsa-default")]
+    fn test_rsa_pss() -> Result<(), Error> {
+        let json = r#"{
+            "algorithm": "RSASSA-PSS",
+            "schema": "rsassa_pss_verify_schema.json",
+            "testGroups": [
+                {
+                    "keySize": 2048, "sha": "SHA-256", "mgf": "MGF1", "mgfSha": "SHA-256", "sLen": 32,
+                    "keyDer": "30820122300d06092a864886f70d01010105000382010f003082010a028201010097107ab14ca81580d5d3a226cd058559110d227618a7850e94fba0ddf638f6539707ec4b42b6b105549e98b176a9acdcd3c4964ea0dc6e4cb736a976fc879fb100162e077e9808cade34e4afa58d0216e68a696f0706e9dfbe813f9d8686119c7d90b104a64b6d7c2dfc1b917b82a1cb5b5a1c1a8b4991fc4f4445d9186ecd4c3e0e92c0a68bf5bbe8deac65f714f506bc827656544a8c2ead22a8505b9a8f940b842f5eb38c76974d7904b8c1f4b9c9dbcabede55da81e93986bd3973b1cbe29d3b09f2a509ecb27e1156b670c118dc049885aaaf98b528d84dd9f554c4bcb356ae64cae1b554ac3ed256c90fad8d325eb5641d47c3a677c7d4e21c58818c0f0203010001",
+                    "type": "RsassaPssVerify",
+                    "tests": [
+                        {
+                            "tcId": 1, "comment": "", "flags": [],
+                            "msg": "7365616c2d63727970746f2073656c662d74657374206d657373616765",
+                            "sig": "3532f21b9568a26620d7fcc4da7aacf7cdfb6f96f5ba72fd200a596aceddd9c63874480eb10be3cebab3867ece66a051035b7cc43f081f3502dee70fc89d689b62b77870f23185a6ef6f66ba5a2cfc0ec23b5fc06f963bb3efe33854b4388531a14f953b8554732113bfcef4279a40c18ef07d535ad4d7cdf3dbca6d2cbc163623b19d2a8e582837555a32603caecf136471f7a90582e06bfc7266cee9f127c7f472df97f5baa4e8451d637f5d55a952e483e7da2985e0bfffe6212d75bc6853782f104ea4296a8983daee27312621ce3f0047d965af9728ac87cb82deb23acfed999c3af92e2963d8a570099a5affa9e4aae09cc7915aeae749a59d492062c8",
+                            "result": "valid"
+                        },
+                        {
+                            "tcId": 2, "comment": "truncated signature", "flags": [],
+                            "msg": "7365616c2d63727970746f2073656c662d74657374206d657373616765",
+                            "sig": "3532f21b9568a26620d7fcc4da7aacf7",
+                            "result": "invalid"
+                        }
+                    ]
+                },
+                {
+                    "keySize": 2048, "sha": "SHA-256", "mgf": "MGF1", "mgfSha": "SHA-1", "sLen": 20,
+                    "keyDer": "", "type": "RsassaPssVerify",
+                    "tests": [{"tcId": 3, "comment": "", "flags": [], "msg": "", "sig": "", "result": "valid"}]
+                }
+            ]
+        }"#;
+        let report = run(json)?;
+        assert!(report.passed(), "{:?}", report.cases);
+        assert_eq!(
+            report.skipped().map(|case| case.id).collect::<Vec<_>>(),
+            [3]
+        );
+        Ok(())
+    }
+}
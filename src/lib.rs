@@ -1,4 +1,5 @@
-#![forbid(unsafe_code)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![deny(unsafe_code)]
 
 //! The `seal-crypto` library provides a set of pure, trait-based cryptographic
 //! capability abstractions and implementations.
@@ -52,7 +53,7 @@
 //! // Symmetric encryption example
 //! # #[cfg(feature = "aes-gcm-default")]
 //! # {
-//! use seal_crypto::schemes::symmetric::aes_gcm::*;
+//! use seal_crypto::schemes::aead::aes_gcm::*;
 //! let scheme = Aes256Gcm::default();
 //! let key = Aes256Gcm::generate_key().unwrap();
 //! let nonce = [0u8; 12]; // In practice, use a random nonce
@@ -65,12 +66,16 @@
 //!
 //! # Feature Flags
 //! The library uses feature flags to enable specific algorithms and reduce binary size:
-//! - `std`: Enable standard library support (enabled by default)
+//! - `std`: Enable standard library support (enabled by default); without it the crate is
+//!   `no_std` and only needs `alloc`
 //! - `aes-gcm-default`: Enable AES-GCM implementations
 //! - `chacha20-poly1305-default`: Enable ChaCha20-Poly1305 implementations
+//! - `sm-default`: Enable the SM2, SM3, SM4-GCM and SM4-CCM implementations
 //! - `rsa-default`: Enable RSA implementations
 //! - `kyber-default`: Enable Kyber post-quantum KEM
 //! - `dilithium-default`: Enable Dilithium post-quantum signatures
+//! - `fips`: Route randomness through an SP 800-90A HMAC_DRBG and reject non-approved algorithms
+//! - `kat`: Run Wycheproof and ACVP test vector files against the enabled schemes
 //! - And many more...
 //!
 //! `seal-crypto` 库提供了一套纯粹的、基于 Trait 的加密能力抽象和实现。
@@ -123,7 +128,7 @@
 //! // 对称加密示例
 //! # #[cfg(feature = "aes-gcm-default")]
 //! # {
-//! use seal_crypto::schemes::symmetric::aes_gcm::*;
+//! use seal_crypto::schemes::aead::aes_gcm::*;
 //! let scheme = Aes256Gcm::default();
 //! let key = Aes256Gcm::generate_key().unwrap();
 //! let nonce = [0u8; 12]; // 实际使用中，请使用随机 nonce
@@ -136,17 +141,30 @@
 //!
 //! # 特性标志
 //! 库使用特性标志来启用特定算法并减少二进制大小：
-//! - `std`: 启用标准库支持（默认启用）
+//! - `std`: 启用标准库支持（默认启用）；没有它时本 crate 是 `no_std` 的，只需要 `alloc`
 //! - `aes-gcm-default`: 启用 AES-GCM 实现
 //! - `chacha20-poly1305-default`: 启用 ChaCha20-Poly1305 实现
+//! - `sm-default`: 启用 SM2、SM3、SM4-GCM 和 SM4-CCM 实现
 //! - `rsa-default`: 启用 RSA 实现
 //! - `kyber-default`: 启用 Kyber 后量子 KEM
 //! - `dilithium-default`: 启用 Dilithium 后量子签名
+//! - `fips`: 通过 SP 800-90A HMAC_DRBG 生成随机数，并拒绝未经批准的算法
+//! - `kat`: 针对已启用的方案运行 Wycheproof 和 ACVP 测试向量文件
 //! - 以及更多...
 
+extern crate alloc;
+
+pub mod cpu;
+pub mod ct;
 pub mod errors;
+#[cfg(feature = "kat")]
+pub mod kat;
+pub mod policy;
 pub mod prelude;
+pub mod registry;
+pub mod rng;
 pub mod schemes;
+pub mod self_test;
 
 pub(crate) mod systems;
 pub(crate) mod traits;
@@ -155,3 +173,58 @@ pub use ::zeroize;
 
 #[cfg(feature = "secrecy")]
 pub use ::secrecy;
+
+// With `fips`, non-approved algorithms must not even be reachable, so enabling a feature that
+// only provides such algorithms is a build error rather than a runtime check.
+// 启用 `fips` 时，未经批准的算法甚至不能是可达的，因此启用仅提供此类算法的特性会导致构建错误，
+// 而不是运行时检查。
+macro_rules! deny_with_fips {
+    ($($feature:literal),* $(,)?) => {
+        $(
+            #[cfg(all(feature = "fips", feature = $feature))]
+            compile_error!(concat!(
+                "the `", $feature, "` feature provides algorithms that are not FIPS 140-3 ",
+                "approved and cannot be combined with the `fips` feature"
+            ));
+        )*
+    };
+}
+
+deny_with_fips!(
+    "argon2-default",
+    "blake2-default",
+    "blake3-default",
+    "chacha20-poly1305-default",
+    "dilithium-default",
+    "hd-default",
+    "insecure-legacy-hashes",
+    "kyber-default",
+    "pkcs12-default",
+    "scrypt-default",
+    "sm-default",
+    "threshold-default",
+    "vrf-default",
+    "spake2-default",
+    "srp-default",
+    "oprf-default",
+    "opaque-default",
+    "x3dh-default",
+    "ratchet-default",
+    "crypto-box-default",
+    "sealed-box-default",
+    "fast-rng-default",
+);
+
+// The Kyber and Dilithium backends are C libraries that Cargo.toml does not build for
+// `wasm32-unknown-unknown`, so requesting them there is a build error with a clear message.
+// Kyber 和 Dilithium 的后端是 C 库，Cargo.toml 不会为 `wasm32-unknown-unknown` 构建它们，
+// 因此在该目标上请求它们会导致一个带有明确信息的构建错误。
+#[cfg(all(
+    target_arch = "wasm32",
+    target_os = "unknown",
+    any(feature = "kyber-default", feature = "dilithium-default")
+))]
+compile_error!(
+    "Kyber and Dilithium are not available on `wasm32-unknown-unknown`; use the `wasm-full` \
+     feature instead of `full`, or disable the `kyber` and `dilithium` features"
+);
@@ -0,0 +1,356 @@
+//! Runtime restrictions on which algorithms and parameters may be used.
+//!
+//! A [`CryptoPolicy`] states the minimum [`SecurityLevel`] an algorithm must reach, whether
+//! legacy algorithms are allowed, individual algorithm IDs to deny, and lower bounds for cost
+//! parameters such as PBKDF2 iterations. A policy can be checked explicitly, passed to the
+//! `*_with_policy` lookups of [`crate::registry`] and the `*_with_policy` methods of the key
+//! ring and signcryption envelopes, or installed process-wide with [`CryptoPolicy::install`],
+//! also under `no_std`. The installed policy is enforced by:
+//!
+//! - every fallible operation of the boxed algorithms in [`crate::registry`],
+//! - key generation, signing, verification, encapsulation and key agreement of the
+//!   asymmetric schemes,
+//! - key generation, encryption and decryption of the AEADs,
+//! - MAC computation, including HMAC over legacy hash functions, and OTP codes,
+//! - every KDF and XOF derivation, including the PBKDF2 iteration count,
+//! - hash commitments.
+//!
+//! Disallowed operations fail with [`Error::PolicyViolation`]. Hash functions, including SHA-1
+//! and MD5, cannot fail, so they are not checked when used; check their ID with
+//! [`CryptoPolicy::check_id`] before looking them up.
+//!
+//! 对可使用的算法和参数的运行时限制。
+//!
+//! [`CryptoPolicy`] 规定了算法必须达到的最低 [`SecurityLevel`]、是否允许旧算法、要拒绝的
+//! 单个算法 ID，以及 PBKDF2 迭代次数等成本参数的下限。策略可以显式检查，可以传给
+//! [`crate::registry`] 的 `*_with_policy` 查找以及密钥环和签密信封的 `*_with_policy` 方法，
+//! 也可以通过 [`CryptoPolicy::install`] 在进程范围内安装（`no_std` 下同样可用）。安装后的策略会在
+//! 以下位置强制执行：
+//!
+//! - [`crate::registry`] 中装箱算法的所有可失败操作，
+//! - 非对称方案的密钥生成、签名、验证、封装和密钥协商，
+//! - AEAD 的密钥生成、加密和解密，
+//! - MAC 计算（包括基于旧哈希函数的 HMAC）以及 OTP 码，
+//! - 所有 KDF 和 XOF 派生，包括 PBKDF2 迭代次数，
+//! - 哈希承诺。
+//!
+//! 不允许的操作会以 [`Error::PolicyViolation`] 失败。哈希函数（包括 SHA-1 和 MD5）不会失败，
+//! 因此使用时不做检查；请在查找前使用 [`CryptoPolicy::check_id`] 检查其 ID。
+
+use alloc::{sync::Arc, vec::Vec};
+use crate::errors::Error;
+use crate::traits::algorithm::{Algorithm, AlgorithmId, SecurityLevel};
+use spin::RwLock;
+
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+/// The reasons an operation can violate a [`CryptoPolicy`].
+///
+/// 操作违反 [`CryptoPolicy`] 的原因。
+#[cfg_attr(feature = "std", derive(Error))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum PolicyError {
+    /// The algorithm is explicitly denied.
+    ///
+    /// 该算法被明确拒绝。
+    #[cfg_attr(feature = "std", error("Algorithm is denied by the policy"))]
+    AlgorithmDenied,
+
+    /// The algorithm does not reach the required classical or quantum security level.
+    ///
+    /// 该算法未达到所要求的经典或量子安全级别。
+    #[cfg_attr(
+        feature = "std",
+        error("Algorithm is below the required security level")
+    )]
+    InsufficientSecurity,
+
+    /// The algorithm is a legacy or broken algorithm, and the policy does not allow them.
+    ///
+    /// 该算法是旧算法或已被攻破的算法，而策略不允许此类算法。
+    #[cfg_attr(
+        feature = "std",
+        error("Legacy algorithms are not allowed by the policy")
+    )]
+    LegacyAlgorithm,
+
+    /// The algorithm ID has no recorded security level, so the policy cannot allow it.
+    ///
+    /// 该算法 ID 没有记录的安全级别，因此策略无法允许它。
+    #[cfg_attr(feature = "std", error("Unknown algorithm ID"))]
+    UnknownAlgorithm,
+
+    /// A cost parameter, such as an iteration count, is below the policy's minimum.
+    ///
+    /// 某个成本参数（例如迭代次数）低于策略的最小值。
+    #[cfg_attr(feature = "std", error("Parameter is weaker than the policy allows"))]
+    WeakParameter,
+}
+
+/// A set of restrictions on algorithms and parameters.
+///
+/// The default policy allows everything. Restrictions are added with the `with_*` methods:
+///
+/// ```
+/// use seal_crypto::policy::CryptoPolicy;
+///
+/// // Deny RSA-2048 (112-bit), SHA-1 and PBKDF2 below 600,000 iterations.
+/// let policy = CryptoPolicy::default()
+///     .with_min_classical_bits(128)
+///     .with_legacy_denied()
+///     .with_min_pbkdf2_iterations(600_000);
+/// assert!(policy.check_id(0x01_01_01_01).is_err());
+/// assert!(policy.check_id(0x02_01_01_02).is_ok());
+/// ```
+///
+/// 对算法和参数的一组限制。
+///
+/// 默认策略允许一切。可以通过 `with_*` 方法添加限制。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CryptoPolicy {
+    min_classical_bits: u16,
+    min_quantum_category: Option<u8>,
+    deny_legacy: bool,
+    denied: Vec<u32>,
+    min_pbkdf2_iterations: u32,
+}
+
+static GLOBAL_POLICY: RwLock<Option<Arc<CryptoPolicy>>> = RwLock::new(None);
+
+impl CryptoPolicy {
+    /// Requires at least `bits` of classical security.
+    ///
+    /// 要求至少 `bits` 位的经典安全强度。
+    pub fn with_min_classical_bits(mut self, bits: u16) -> Self {
+        self.min_classical_bits = bits;
+        self
+    }
+
+    /// Requires at least the given NIST post-quantum security category, which also denies
+    /// every algorithm that is not quantum-resistant.
+    ///
+    /// 要求至少达到给定的 NIST 后量子安全类别，这同时会拒绝所有不抗量子的算法。
+    pub fn with_min_quantum_category(mut self, category: u8) -> Self {
+        self.min_quantum_category = Some(category);
+        self
+    }
+
+    /// Denies legacy and broken algorithms, such as SHA-1 and MD5.
+    ///
+    /// 拒绝旧算法和已被攻破的算法，例如 SHA-1 和 MD5。
+    pub fn with_legacy_denied(mut self) -> Self {
+        self.deny_legacy = true;
+        self
+    }
+
+    /// Denies the algorithm with the given ID regardless of its security level.
+    ///
+    /// 无论其安全级别如何，都拒绝具有给定 ID 的算法。
+    pub fn with_denied(mut self, id: u32) -> Self {
+        self.denied.push(id);
+        self
+    }
+
+    /// Requires PBKDF2 to use at least `iterations` iterations.
+    ///
+    /// 要求 PBKDF2 至少使用 `iterations` 次迭代。
+    pub fn with_min_pbkdf2_iterations(mut self, iterations: u32) -> Self {
+        self.min_pbkdf2_iterations = iterations;
+        self
+    }
+
+    /// Checks an algorithm with the given ID and security level against the policy.
+    ///
+    /// 根据策略检查具有给定 ID 和安全级别的算法。
+    pub fn check_level(&self, id: u32, level: SecurityLevel) -> Result<(), Error> {
+        let violation = if self.denied.contains(&id) {
+            PolicyError::AlgorithmDenied
+        } else if self.deny_legacy && (level.legacy || level.broken) {
+            PolicyError::LegacyAlgorithm
+        } else if level.classical_bits < self.min_classical_bits
+            || level.quantum_category < self.min_quantum_category
+        {
+            PolicyError::InsufficientSecurity
+        } else {
+            return Ok(());
+        };
+        Err(Error::PolicyViolation(violation))
+    }
+
+    /// Checks the algorithm `A` against the policy.
+    ///
+    /// 根据策略检查算法 `A`。
+    pub fn check<A: Algorithm>(&self) -> Result<(), Error> {
+        self.check_level(A::ID, A::SECURITY_LEVEL)
+    }
+
+    /// Checks the algorithm with the given ID against the policy. IDs without a recorded
+    /// security level are rejected with [`PolicyError::UnknownAlgorithm`].
+    ///
+    /// 根据策略检查具有给定 ID 的算法。没有记录安全级别的 ID 会以
+    /// [`PolicyError::UnknownAlgorithm`] 被拒绝。
+    pub fn check_id(&self, id: u32) -> Result<(), Error> {
+        let level = AlgorithmId::new(id)
+            .security_level()
+            .ok_or(Error::PolicyViolation(PolicyError::UnknownAlgorithm))?;
+        self.check_level(id, level)
+    }
+
+    /// Checks a PBKDF2 iteration count against the policy.
+    ///
+    /// 根据策略检查 PBKDF2 迭代次数。
+    pub fn check_pbkdf2_iterations(&self, iterations: u32) -> Result<(), Error> {
+        if iterations < self.min_pbkdf2_iterations {
+            return Err(Error::PolicyViolation(PolicyError::WeakParameter));
+        }
+        Ok(())
+    }
+
+    /// Installs the policy process-wide, replacing any previously installed policy.
+    ///
+    /// 在进程范围内安装该策略，替换之前安装的任何策略。
+    pub fn install(self) {
+        *GLOBAL_POLICY.write() = Some(Arc::new(self));
+    }
+
+    /// Removes the installed policy, if any.
+    ///
+    /// 移除已安装的策略（如果有）。
+    pub fn uninstall() {
+        *GLOBAL_POLICY.write() = None;
+    }
+
+    /// Returns the installed policy, if any.
+    ///
+    /// 返回已安装的策略（如果有）。
+    pub fn installed() -> Option<Arc<CryptoPolicy>> {
+        GLOBAL_POLICY.read().clone()
+    }
+}
+
+/// Checks an algorithm against the installed policy, if any.
+///
+/// 根据已安装的策略（如果有）检查算法。
+pub(crate) fn enforce_level(id: u32, level: SecurityLevel) -> Result<(), Error> {
+    match CryptoPolicy::installed() {
+        Some(policy) => policy.check_level(id, level),
+        None => Ok(()),
+    }
+}
+
+/// Checks the algorithm `A` against the installed policy, if any.
+///
+/// 根据已安装的策略（如果有）检查算法 `A`。
+pub(crate) fn enforce<A: Algorithm>() -> Result<(), Error> {
+    enforce_level(A::ID, A::SECURITY_LEVEL)
+}
+
+/// Checks a PBKDF2 iteration count against the installed policy, if any.
+///
+/// 根据已安装的策略（如果有）检查 PBKDF2 迭代次数。
+#[cfg(feature = "pbkdf2-default")]
+pub(crate) fn enforce_pbkdf2_iterations(iterations: u32) -> Result<(), Error> {
+    match CryptoPolicy::installed() {
+        Some(policy) => policy.check_pbkdf2_iterations(iterations),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_checks() {
+        let policy = CryptoPolicy::default()
+            .with_min_classical_bits(128)
+            .with_legacy_denied()
+            .with_denied(0x02_02_01_01)
+            .with_min_pbkdf2_iterations(600_000);
+
+        // RSA-2048, RSA-4096
+        assert_eq!(
+            policy.check_id(0x01_01_01_01),
+            Err(Error::PolicyViolation(PolicyError::InsufficientSecurity))
+        );
+        assert!(policy.check_id(0x01_01_01_11).is_ok());
+        // SHA-1, HMAC-SHA-1
+        assert_eq!(
+            policy.check_id(0x04_05_00_01),
+            Err(Error::PolicyViolation(PolicyError::LegacyAlgorithm))
+        );
+        assert_eq!(
+            policy.check_id(0x06_01_01_01),
+            Err(Error::PolicyViolation(PolicyError::LegacyAlgorithm))
+        );
+        // ChaCha20-Poly1305
+        assert_eq!(
+            policy.check_id(0x02_02_01_01),
+            Err(Error::PolicyViolation(PolicyError::AlgorithmDenied))
+        );
+        assert_eq!(
+            policy.check_id(0),
+            Err(Error::PolicyViolation(PolicyError::UnknownAlgorithm))
+        );
+        assert!(policy.check_pbkdf2_iterations(600_000).is_ok());
+        assert!(policy.check_pbkdf2_iterations(599_999).is_err());
+
+        // Kyber768 reaches category 3, ECDH-P256 is not quantum-resistant
+        let quantum = CryptoPolicy::default().with_min_quantum_category(3);
+        assert!(quantum.check_id(0x01_02_02_02).is_ok());
+        assert!(quantum.check_id(0x01_02_02_01).is_err());
+        assert!(quantum.check_id(0x01_01_03_01).is_err());
+        assert!(CryptoPolicy::default().check_id(0x04_05_00_02).is_ok());
+    }
+
+    #[test]
+    fn test_installed_policy() {
+        use crate::prelude::*;
+        use crate::registry::DynMac;
+
+        // A MAC with an ID of its own, so that denying it does not affect other tests.
+        #[derive(Clone, Debug, Default)]
+        struct TestMac;
+
+        impl Algorithm for TestMac {
+            fn name() -> String {
+                "Test-MAC".to_string()
+            }
+            const ID: u32 = 0x06_7f_00_01;
+            const SECURITY_LEVEL: SecurityLevel = SecurityLevel {
+                classical_bits: 256,
+                quantum_category: Some(5),
+                legacy: false,
+                broken: false,
+            };
+        }
+
+        impl SymmetricKeySet for TestMac {
+            type Key = SymmetricKey;
+        }
+
+        impl Mac for TestMac {
+            const TAG_SIZE: usize = 1;
+
+            fn compute(_key: &SymmetricKey, _message: &[u8]) -> Result<Vec<u8>, Error> {
+                Ok(vec![0])
+            }
+        }
+
+        let mac: Box<dyn DynMac> = Box::new(TestMac);
+        let key = SymmetricKey::new(vec![0; 32]);
+        assert!(mac.compute(&key, b"message").is_ok());
+
+        CryptoPolicy::default().with_denied(TestMac::ID).install();
+        assert!(CryptoPolicy::installed().is_some());
+        assert_eq!(
+            mac.compute(&key, b"message"),
+            Err(Error::PolicyViolation(PolicyError::AlgorithmDenied))
+        );
+        assert!(mac.verify(&key, b"message", &[0]).is_err());
+
+        CryptoPolicy::uninstall();
+        assert!(mac.compute(&key, b"message").is_ok());
+    }
+}
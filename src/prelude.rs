@@ -4,24 +4,27 @@
 //! `seal-crypto` crate 用户的 "prelude"。
 //! 这个 prelude 设计为通过 glob 导入，即 `use seal_crypto::prelude::*;`。
 pub use crate::errors::Error as CryptoError;
-#[cfg(all(feature = "secrecy", feature = "getrandom"))]
-pub use crate::traits::kdf::PasswordBasedDerivation;
+pub use crate::self_test::SelfTest;
 pub use crate::traits::{
     // core
     algorithm::*,
     // KDF
     kdf::*,
+    // hash
+    hash::*,
     // key
     key::*,
+    // MAC
+    mac::*,
     // asymmetric
     asymmetric::*,
     // symmetric
     symmetric::*,
     // params
     params::*,
-    // xof
-    xof::*,
 };
+#[cfg(feature = "digest")]
+pub use crate::traits::xof::*;
 
 #[cfg(feature = "digest")]
 pub use digest::XofReader as DigestXofReader;
@@ -0,0 +1,1097 @@
+//! Runtime selection of algorithms by their [`Algorithm::ID`].
+//!
+//! The scheme types of this crate are selected at compile time through generics. When the
+//! algorithm is only known at runtime, for example because a file header stores the ID of
+//! the hash it was written with, this module maps the ID to an object-safe, boxed
+//! implementation instead:
+//!
+//! - [`hasher`] returns a fresh [`DynHasher`] for a hash function ID.
+//! - [`mac`] returns a [`DynMac`] for a MAC ID.
+//! - [`aead`] returns a [`DynAead`] for an AEAD cipher ID.
+//! - [`signer`] returns a [`DynSigner`] for a signature scheme ID.
+//! - [`kem`] returns a [`DynKem`] for a KEM ID.
+//!
+//! Only the algorithms enabled by the crate features are registered. [`hasher_ids`],
+//! [`mac_ids`], [`aead_ids`], [`signer_ids`] and [`kem_ids`] list them per kind, and
+//! [`available_algorithms`] lists all of them. Every boxed algorithm reports its
+//! [`SecurityLevel`], so weak algorithms can be refused before they are used, and their
+//! operations fail if an installed [`CryptoPolicy`] disallows them. The `*_with_policy`
+//! lookups, such as [`aead_with_policy`], check the ID against a given policy instead, for
+//! callers that do not install one process-wide.
+//!
+//! `DynHasher` and `DynMac` are implemented for every [`HashFunction`] and [`Mac`] type,
+//! so a concrete scheme can also be boxed directly, e.g. `Box::new(Sha256::default())`.
+//! Their methods share names with those traits; avoid importing both into the same scope.
+//!
+//! Asymmetric keys of different schemes can be held together as [`AnyPublicKey`] and
+//! [`AnyPrivateKey`], which carry the ID of their algorithm in their encodings. [`DynSigner`]
+//! and [`DynKem`] take their keys in this form.
+//!
+//! 按 [`Algorithm::ID`] 在运行时选择算法。
+//!
+//! 本 crate 的方案类型通过泛型在编译时选择。当算法只在运行时才能确定时，例如文件头中
+//! 存储了写入时所用哈希的 ID，此模块会将 ID 映射到对象安全的装箱实现：
+//!
+//! - [`hasher`] 为哈希函数 ID 返回一个新的 [`DynHasher`]。
+//! - [`mac`] 为 MAC ID 返回一个 [`DynMac`]。
+//! - [`aead`] 为 AEAD 密码 ID 返回一个 [`DynAead`]。
+//! - [`signer`] 为签名方案 ID 返回一个 [`DynSigner`]。
+//! - [`kem`] 为 KEM ID 返回一个 [`DynKem`]。
+//!
+//! 只有 crate 特性启用的算法才会被注册。[`hasher_ids`]、[`mac_ids`]、[`aead_ids`]、
+//! [`signer_ids`] 和 [`kem_ids`] 按类别列出它们，[`available_algorithms`] 列出全部算法。
+//! 每个装箱的算法都会报告其 [`SecurityLevel`]，因此可以在使用前拒绝弱算法；
+//! 如果已安装的 [`CryptoPolicy`] 不允许它们，其操作会失败。对于不在进程范围内安装策略的
+//! 调用者，`*_with_policy` 查找（例如 [`aead_with_policy`]）会改为根据给定的策略检查 ID。
+//!
+//! `DynHasher` 和 `DynMac` 为每个 [`HashFunction`] 和 [`Mac`] 类型实现，因此也可以直接将具体方案
+//! 装箱，例如 `Box::new(Sha256::default())`。它们的方法与这些 trait 的方法同名；
+//! 请避免将两者导入同一作用域。
+//!
+//! 不同方案的非对称密钥可以统一以 [`AnyPublicKey`] 和 [`AnyPrivateKey`] 持有，
+//! 它们的编码中带有其算法的 ID。[`DynSigner`] 和 [`DynKem`] 以这种形式接收密钥。
+
+mod keys;
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+pub use keys::{AnyKeyScheme, AnyPrivateKey, AnyPublicKey};
+
+use crate::{
+    errors::Error,
+    policy::{self, CryptoPolicy},
+    traits::{
+        algorithm::{Algorithm, SecurityLevel},
+        asymmetric::{Kem, KeyGenerator, SharedSecret, Signer, Verifier},
+        hash::HashFunction,
+        key::{Key, KeyError, SymmetricKeySet},
+        mac::Mac,
+        symmetric::{AeadCipher, AeadScheme, SymmetricKey},
+    },
+};
+#[cfg(feature = "rsa-default")]
+use crate::{
+    systems::asymmetric::traditional::rsa::{Rsa2048, Rsa4096},
+    traits::params::{Sha256, Sha384, Sha512},
+};
+
+// ------------------- Object-Safe Traits -------------------
+// ------------------- 对象安全的 Trait -------------------
+
+/// An object-safe hash function state, usable as `Box<dyn DynHasher>`.
+///
+/// 对象安全的哈希函数状态，可用作 `Box<dyn DynHasher>`。
+pub trait DynHasher: Send + Sync {
+    /// Returns the [`Algorithm::ID`] of the hash function.
+    ///
+    /// 返回哈希函数的 [`Algorithm::ID`]。
+    fn id(&self) -> u32;
+
+    /// Returns the name of the hash function.
+    ///
+    /// 返回哈希函数的名称。
+    fn name(&self) -> String;
+
+    /// Returns the [`Algorithm::SECURITY_LEVEL`] of the hash function.
+    ///
+    /// 返回哈希函数的 [`Algorithm::SECURITY_LEVEL`]。
+    fn security_level(&self) -> SecurityLevel;
+
+    /// Returns the size of the digest in bytes.
+    ///
+    /// 返回摘要的大小（以字节为单位）。
+    fn output_size(&self) -> usize;
+
+    /// Absorbs more data into the hash state.
+    ///
+    /// 将更多数据吸收到哈希状态中。
+    fn update(&mut self, data: &[u8]);
+
+    /// Consumes the hash state and returns the digest.
+    ///
+    /// 消费哈希状态并返回摘要。
+    fn finalize(self: Box<Self>) -> Vec<u8>;
+
+    /// Clones the hash state into a new box.
+    ///
+    /// 将哈希状态克隆到一个新的 box 中。
+    fn box_clone(&self) -> Box<dyn DynHasher>;
+}
+
+impl<H: HashFunction> DynHasher for H {
+    fn id(&self) -> u32 {
+        <H as Algorithm>::ID
+    }
+
+    fn name(&self) -> String {
+        <H as Algorithm>::name()
+    }
+
+    fn security_level(&self) -> SecurityLevel {
+        <H as Algorithm>::SECURITY_LEVEL
+    }
+
+    fn output_size(&self) -> usize {
+        H::OUTPUT_SIZE
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        HashFunction::update(self, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        HashFunction::finalize(*self)
+    }
+
+    fn box_clone(&self) -> Box<dyn DynHasher> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn DynHasher> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+impl core::fmt::Debug for dyn DynHasher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynHasher")
+            .field("name", &self.name())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An object-safe MAC, usable as `Box<dyn DynMac>`.
+///
+/// 对象安全的 MAC，可用作 `Box<dyn DynMac>`。
+pub trait DynMac: Send + Sync {
+    /// Returns the [`Algorithm::ID`] of the MAC.
+    ///
+    /// 返回 MAC 的 [`Algorithm::ID`]。
+    fn id(&self) -> u32;
+
+    /// Returns the name of the MAC.
+    ///
+    /// 返回 MAC 的名称。
+    fn name(&self) -> String;
+
+    /// Returns the [`Algorithm::SECURITY_LEVEL`] of the MAC.
+    ///
+    /// 返回MAC的 [`Algorithm::SECURITY_LEVEL`]。
+    fn security_level(&self) -> SecurityLevel;
+
+    /// Returns the size of the tag in bytes.
+    ///
+    /// 返回标签的大小（以字节为单位）。
+    fn tag_size(&self) -> usize;
+
+    /// Computes the tag of `message` under `key`.
+    ///
+    /// 在 `key` 下计算 `message` 的标签。
+    fn compute(&self, key: &SymmetricKey, message: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// Verifies the tag of `message` under `key`, in constant time.
+    ///
+    /// 以常量时间验证 `message` 在 `key` 下的标签。
+    fn verify(&self, key: &SymmetricKey, message: &[u8], tag: &[u8]) -> Result<(), Error>;
+}
+
+impl<M: Mac + SymmetricKeySet<Key = SymmetricKey>> DynMac for M {
+    fn id(&self) -> u32 {
+        <M as Algorithm>::ID
+    }
+
+    fn name(&self) -> String {
+        <M as Algorithm>::name()
+    }
+
+    fn security_level(&self) -> SecurityLevel {
+        <M as Algorithm>::SECURITY_LEVEL
+    }
+
+    fn tag_size(&self) -> usize {
+        M::TAG_SIZE
+    }
+
+    fn compute(&self, key: &SymmetricKey, message: &[u8]) -> Result<Vec<u8>, Error> {
+        policy::enforce::<M>()?;
+        <M as Mac>::compute(key, message)
+    }
+
+    fn verify(&self, key: &SymmetricKey, message: &[u8], tag: &[u8]) -> Result<(), Error> {
+        policy::enforce::<M>()?;
+        <M as Mac>::verify(key, message, tag)
+    }
+}
+
+impl core::fmt::Debug for dyn DynMac {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynMac")
+            .field("name", &self.name())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An object-safe AEAD cipher, usable as `Box<dyn DynAead>`.
+///
+/// 对象安全的 AEAD 密码，可用作 `Box<dyn DynAead>`。
+pub trait DynAead: Send + Sync {
+    /// Returns the [`Algorithm::ID`] of the cipher.
+    ///
+    /// 返回密码的 [`Algorithm::ID`]。
+    fn id(&self) -> u32;
+
+    /// Returns the name of the cipher.
+    ///
+    /// 返回密码的名称。
+    fn name(&self) -> String;
+
+    /// Returns the [`Algorithm::SECURITY_LEVEL`] of the cipher.
+    ///
+    /// 返回密码的 [`Algorithm::SECURITY_LEVEL`]。
+    fn security_level(&self) -> SecurityLevel;
+
+    /// Returns the size of the key in bytes.
+    ///
+    /// 返回密钥的大小（以字节为单位）。
+    fn key_size(&self) -> usize;
+
+    /// Returns the size of the nonce in bytes.
+    ///
+    /// 返回 nonce 的大小（以字节为单位）。
+    fn nonce_size(&self) -> usize;
+
+    /// Returns the size of the authentication tag in bytes.
+    ///
+    /// 返回认证标签的大小（以字节为单位）。
+    fn tag_size(&self) -> usize;
+
+    /// Generates a new random key for the cipher.
+    ///
+    /// 为该密码生成一个新的随机密钥。
+    fn generate_key(&self) -> Result<SymmetricKey, Error>;
+
+    /// Encrypts `plaintext`, returning `[ciphertext || tag]`.
+    ///
+    /// 加密 `plaintext`，返回 `[ciphertext || tag]`。
+    fn encrypt(
+        &self,
+        key: &SymmetricKey,
+        nonce: &[u8],
+        plaintext: &[u8],
+        aad: Option<&[u8]>,
+    ) -> Result<Vec<u8>, Error>;
+
+    /// Decrypts and authenticates `[ciphertext || tag]`, returning the plaintext.
+    ///
+    /// 解密并认证 `[ciphertext || tag]`，返回明文。
+    fn decrypt(
+        &self,
+        key: &SymmetricKey,
+        nonce: &[u8],
+        ciphertext_with_tag: &[u8],
+        aad: Option<&[u8]>,
+    ) -> Result<Vec<u8>, Error>;
+}
+
+impl<A: AeadScheme + SymmetricKeySet<Key = SymmetricKey>> DynAead for A {
+    fn id(&self) -> u32 {
+        <A as Algorithm>::ID
+    }
+
+    fn name(&self) -> String {
+        <A as Algorithm>::name()
+    }
+
+    fn security_level(&self) -> SecurityLevel {
+        <A as Algorithm>::SECURITY_LEVEL
+    }
+
+    fn key_size(&self) -> usize {
+        <A as AeadCipher>::KEY_SIZE
+    }
+
+    fn nonce_size(&self) -> usize {
+        A::NONCE_SIZE
+    }
+
+    fn tag_size(&self) -> usize {
+        A::TAG_SIZE
+    }
+
+    fn generate_key(&self) -> Result<SymmetricKey, Error> {
+        policy::enforce::<A>()?;
+        A::generate_key()
+    }
+
+    fn encrypt(
+        &self,
+        key: &SymmetricKey,
+        nonce: &[u8],
+        plaintext: &[u8],
+        aad: Option<&[u8]>,
+    ) -> Result<Vec<u8>, Error> {
+        policy::enforce::<A>()?;
+        A::encrypt(key, nonce, plaintext, aad)
+    }
+
+    fn decrypt(
+        &self,
+        key: &SymmetricKey,
+        nonce: &[u8],
+        ciphertext_with_tag: &[u8],
+        aad: Option<&[u8]>,
+    ) -> Result<Vec<u8>, Error> {
+        policy::enforce::<A>()?;
+        A::decrypt(key, nonce, ciphertext_with_tag, aad)
+    }
+}
+
+impl core::fmt::Debug for dyn DynAead {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynAead")
+            .field("name", &self.name())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An object-safe signature scheme, usable as `Box<dyn DynSigner>`.
+///
+/// Keys are passed as [`AnyPublicKey`] and [`AnyPrivateKey`]; a key of another scheme is
+/// rejected with [`KeyError::UnsupportedAlgorithm`].
+///
+/// 对象安全的签名方案，可用作 `Box<dyn DynSigner>`。
+///
+/// 密钥以 [`AnyPublicKey`] 和 [`AnyPrivateKey`] 传递；其他方案的密钥会以
+/// [`KeyError::UnsupportedAlgorithm`] 被拒绝。
+pub trait DynSigner: Send + Sync {
+    /// Returns the [`Algorithm::ID`] of the scheme.
+    ///
+    /// 返回方案的 [`Algorithm::ID`]。
+    fn id(&self) -> u32;
+
+    /// Returns the name of the scheme.
+    ///
+    /// 返回方案的名称。
+    fn name(&self) -> String;
+
+    /// Returns the [`Algorithm::SECURITY_LEVEL`] of the scheme.
+    ///
+    /// 返回方案的 [`Algorithm::SECURITY_LEVEL`]。
+    fn security_level(&self) -> SecurityLevel;
+
+    /// Generates a new key pair for the scheme.
+    ///
+    /// 为该方案生成一个新的密钥对。
+    fn generate_keypair(&self) -> Result<(AnyPublicKey, AnyPrivateKey), Error>;
+
+    /// Signs `message` with `private_key`.
+    ///
+    /// 使用 `private_key` 对 `message` 签名。
+    fn sign(&self, private_key: &AnyPrivateKey, message: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// Verifies the signature of `message` under `public_key`.
+    ///
+    /// 验证 `message` 在 `public_key` 下的签名。
+    fn verify(
+        &self,
+        public_key: &AnyPublicKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Error>;
+}
+
+impl<S> DynSigner for S
+where
+    S: KeyGenerator + Signer + Verifier + AnyKeyScheme + Send + Sync,
+{
+    fn id(&self) -> u32 {
+        <S as Algorithm>::ID
+    }
+
+    fn name(&self) -> String {
+        <S as Algorithm>::name()
+    }
+
+    fn security_level(&self) -> SecurityLevel {
+        <S as Algorithm>::SECURITY_LEVEL
+    }
+
+    fn generate_keypair(&self) -> Result<(AnyPublicKey, AnyPrivateKey), Error> {
+        policy::enforce::<S>()?;
+        <S as KeyGenerator>::generate_keypair().map(wrap_keypair::<S>)
+    }
+
+    fn sign(&self, private_key: &AnyPrivateKey, message: &[u8]) -> Result<Vec<u8>, Error> {
+        policy::enforce::<S>()?;
+        let private_key =
+            S::private_key_ref(private_key).ok_or(Error::Key(KeyError::UnsupportedAlgorithm))?;
+        <S as Signer>::sign(private_key, message)
+    }
+
+    fn verify(
+        &self,
+        public_key: &AnyPublicKey,
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), Error> {
+        policy::enforce::<S>()?;
+        let public_key =
+            S::public_key_ref(public_key).ok_or(Error::Key(KeyError::UnsupportedAlgorithm))?;
+        <S as Verifier>::verify(public_key, message, &signature.to_vec())
+    }
+}
+
+/// Wraps a key pair of the scheme `S` into algorithm-tagged keys.
+///
+/// 将方案 `S` 的密钥对包装为带算法标签的密钥。
+// Both key enums are uninhabited when no asymmetric feature is enabled.
+// 未启用任何非对称特性时，两个密钥枚举都没有可能的值。
+#[allow(unreachable_code, unused_variables)]
+fn wrap_keypair<S: AnyKeyScheme>(
+    (public_key, private_key): (S::PublicKey, S::PrivateKey),
+) -> (AnyPublicKey, AnyPrivateKey) {
+    (
+        S::wrap_public_key(public_key),
+        S::wrap_private_key(private_key),
+    )
+}
+
+impl core::fmt::Debug for dyn DynSigner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynSigner")
+            .field("name", &self.name())
+            .finish_non_exhaustive()
+    }
+}
+
+/// An object-safe key encapsulation mechanism, usable as `Box<dyn DynKem>`.
+///
+/// Keys are passed as [`AnyPublicKey`] and [`AnyPrivateKey`]; a key of another scheme is
+/// rejected with [`KeyError::UnsupportedAlgorithm`].
+///
+/// 对象安全的密钥封装机制，可用作 `Box<dyn DynKem>`。
+///
+/// 密钥以 [`AnyPublicKey`] 和 [`AnyPrivateKey`] 传递；其他方案的密钥会以
+/// [`KeyError::UnsupportedAlgorithm`] 被拒绝。
+pub trait DynKem: Send + Sync {
+    /// Returns the [`Algorithm::ID`] of the scheme.
+    ///
+    /// 返回方案的 [`Algorithm::ID`]。
+    fn id(&self) -> u32;
+
+    /// Returns the name of the scheme.
+    ///
+    /// 返回方案的名称。
+    fn name(&self) -> String;
+
+    /// Returns the [`Algorithm::SECURITY_LEVEL`] of the scheme.
+    ///
+    /// 返回方案的 [`Algorithm::SECURITY_LEVEL`]。
+    fn security_level(&self) -> SecurityLevel;
+
+    /// Generates a new key pair for the scheme.
+    ///
+    /// 为该方案生成一个新的密钥对。
+    fn generate_keypair(&self) -> Result<(AnyPublicKey, AnyPrivateKey), Error>;
+
+    /// Encapsulates a fresh shared secret to `public_key`, returning the secret and the
+    /// encoded encapsulated key.
+    ///
+    /// 向 `public_key` 封装一个新的共享密钥，返回该密钥和编码后的封装密钥。
+    fn encapsulate(&self, public_key: &AnyPublicKey) -> Result<(SharedSecret, Vec<u8>), Error>;
+
+    /// Recovers the shared secret from an encoded encapsulated key.
+    ///
+    /// 从编码后的封装密钥中恢复共享密钥。
+    fn decapsulate(
+        &self,
+        private_key: &AnyPrivateKey,
+        encapsulated_key: &[u8],
+    ) -> Result<SharedSecret, Error>;
+}
+
+impl<K> DynKem for K
+where
+    K: KeyGenerator + Kem + AnyKeyScheme + Send + Sync,
+{
+    fn id(&self) -> u32 {
+        <K as Algorithm>::ID
+    }
+
+    fn name(&self) -> String {
+        <K as Algorithm>::name()
+    }
+
+    fn security_level(&self) -> SecurityLevel {
+        <K as Algorithm>::SECURITY_LEVEL
+    }
+
+    fn generate_keypair(&self) -> Result<(AnyPublicKey, AnyPrivateKey), Error> {
+        policy::enforce::<K>()?;
+        <K as KeyGenerator>::generate_keypair().map(wrap_keypair::<K>)
+    }
+
+    fn encapsulate(&self, public_key: &AnyPublicKey) -> Result<(SharedSecret, Vec<u8>), Error> {
+        policy::enforce::<K>()?;
+        let public_key =
+            K::public_key_ref(public_key).ok_or(Error::Key(KeyError::UnsupportedAlgorithm))?;
+        let (shared_secret, encapsulated_key) = K::encapsulate(public_key)?;
+        Ok((shared_secret, encapsulated_key.to_bytes()?))
+    }
+
+    fn decapsulate(
+        &self,
+        private_key: &AnyPrivateKey,
+        encapsulated_key: &[u8],
+    ) -> Result<SharedSecret, Error> {
+        policy::enforce::<K>()?;
+        let private_key =
+            K::private_key_ref(private_key).ok_or(Error::Key(KeyError::UnsupportedAlgorithm))?;
+        K::decapsulate(
+            private_key,
+            &K::EncapsulatedKey::from_bytes(encapsulated_key)?,
+        )
+    }
+}
+
+impl core::fmt::Debug for dyn DynKem {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DynKem")
+            .field("name", &self.name())
+            .finish_non_exhaustive()
+    }
+}
+
+// ------------------- Registry Tables -------------------
+// ------------------- 注册表 -------------------
+
+struct HasherEntry {
+    id: u32,
+    new: fn() -> Box<dyn DynHasher>,
+}
+
+struct MacEntry {
+    id: u32,
+    new: fn() -> Box<dyn DynMac>,
+}
+
+struct AeadEntry {
+    id: u32,
+    new: fn() -> Box<dyn DynAead>,
+}
+
+struct SignerEntry {
+    id: u32,
+    new: fn() -> Box<dyn DynSigner>,
+}
+
+struct KemEntry {
+    id: u32,
+    new: fn() -> Box<dyn DynKem>,
+}
+
+#[allow(unused_macros)]
+macro_rules! hasher_entry {
+    ($ty:ty) => {
+        HasherEntry {
+            id: <$ty as Algorithm>::ID,
+            new: || Box::new(<$ty>::default()),
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! mac_entry {
+    ($ty:ty) => {
+        MacEntry {
+            id: <$ty as Algorithm>::ID,
+            new: || Box::new(<$ty>::default()),
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! aead_entry {
+    ($ty:ty) => {
+        AeadEntry {
+            id: <$ty as Algorithm>::ID,
+            new: || Box::new(<$ty>::default()),
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! signer_entry {
+    ($ty:ty) => {
+        SignerEntry {
+            id: <$ty as Algorithm>::ID,
+            new: || Box::new(<$ty>::default()),
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! kem_entry {
+    ($ty:ty) => {
+        KemEntry {
+            id: <$ty as Algorithm>::ID,
+            new: || Box::new(<$ty>::default()),
+        }
+    };
+}
+
+static HASHERS: &[HasherEntry] = &[
+    #[cfg(feature = "sha2")]
+    hasher_entry!(crate::systems::hash::sha2::Sha256),
+    #[cfg(feature = "sha2")]
+    hasher_entry!(crate::systems::hash::sha2::Sha384),
+    #[cfg(feature = "sha2")]
+    hasher_entry!(crate::systems::hash::sha2::Sha512),
+    #[cfg(feature = "sha3")]
+    hasher_entry!(crate::systems::hash::sha3::Sha3_256),
+    #[cfg(feature = "sha3")]
+    hasher_entry!(crate::systems::hash::sha3::Sha3_384),
+    #[cfg(feature = "sha3")]
+    hasher_entry!(crate::systems::hash::sha3::Sha3_512),
+    #[cfg(feature = "blake2-default")]
+    hasher_entry!(crate::systems::hash::blake2::Blake2b512),
+    #[cfg(feature = "blake2-default")]
+    hasher_entry!(crate::systems::hash::blake2::Blake2b256),
+    #[cfg(feature = "blake2-default")]
+    hasher_entry!(crate::systems::hash::blake2::Blake2s256),
+    #[cfg(feature = "blake3-default")]
+    hasher_entry!(crate::systems::hash::blake3::Blake3),
+    #[cfg(feature = "insecure-legacy-hashes")]
+    hasher_entry!(crate::systems::hash::legacy::Sha1),
+    #[cfg(feature = "insecure-legacy-hashes")]
+    hasher_entry!(crate::systems::hash::legacy::Md5),
+    #[cfg(feature = "sm-default")]
+    hasher_entry!(crate::systems::hash::sm3::Sm3),
+];
+
+static MACS: &[MacEntry] = &[
+    #[cfg(feature = "hmac-default")]
+    mac_entry!(crate::systems::mac::hmac::HmacSha256),
+    #[cfg(feature = "hmac-default")]
+    mac_entry!(crate::systems::mac::hmac::HmacSha384),
+    #[cfg(feature = "hmac-default")]
+    mac_entry!(crate::systems::mac::hmac::HmacSha512),
+    #[cfg(all(feature = "hmac-default", feature = "insecure-legacy-hashes"))]
+    mac_entry!(crate::systems::mac::hmac::HmacSha1),
+    #[cfg(all(feature = "hmac-default", feature = "insecure-legacy-hashes"))]
+    mac_entry!(crate::systems::mac::hmac::HmacMd5),
+    #[cfg(feature = "cmac-default")]
+    mac_entry!(crate::systems::mac::cmac::Aes128Cmac),
+    #[cfg(feature = "cmac-default")]
+    mac_entry!(crate::systems::mac::cmac::Aes256Cmac),
+    #[cfg(feature = "shake-default")]
+    mac_entry!(crate::systems::mac::kmac::Kmac128),
+    #[cfg(feature = "shake-default")]
+    mac_entry!(crate::systems::mac::kmac::Kmac256),
+];
+
+static AEADS: &[AeadEntry] = &[
+    #[cfg(feature = "aes-gcm-default")]
+    aead_entry!(crate::systems::aead::aes_gcm::Aes128Gcm),
+    #[cfg(feature = "aes-gcm-default")]
+    aead_entry!(crate::systems::aead::aes_gcm::Aes256Gcm),
+    #[cfg(feature = "chacha20-poly1305-default")]
+    aead_entry!(crate::systems::aead::chacha20_poly1305::ChaCha20Poly1305),
+    #[cfg(feature = "chacha20-poly1305-default")]
+    aead_entry!(crate::systems::aead::chacha20_poly1305::XChaCha20Poly1305),
+    #[cfg(feature = "sm-default")]
+    aead_entry!(crate::systems::aead::sm4::Sm4Gcm),
+    #[cfg(feature = "sm-default")]
+    aead_entry!(crate::systems::aead::sm4::Sm4Ccm),
+];
+
+static SIGNERS: &[SignerEntry] = &[
+    #[cfg(feature = "rsa-default")]
+    signer_entry!(Rsa2048<Sha256>),
+    #[cfg(feature = "rsa-default")]
+    signer_entry!(Rsa2048<Sha384>),
+    #[cfg(feature = "rsa-default")]
+    signer_entry!(Rsa2048<Sha512>),
+    #[cfg(feature = "rsa-default")]
+    signer_entry!(Rsa4096<Sha256>),
+    #[cfg(feature = "rsa-default")]
+    signer_entry!(Rsa4096<Sha384>),
+    #[cfg(feature = "rsa-default")]
+    signer_entry!(Rsa4096<Sha512>),
+    #[cfg(feature = "ecc-default")]
+    signer_entry!(crate::systems::asymmetric::traditional::ecc::EcdsaP256),
+    #[cfg(all(feature = "ecc-default", not(feature = "fips")))]
+    signer_entry!(crate::systems::asymmetric::traditional::ecc::EcdsaSecp256k1),
+    #[cfg(feature = "ecc-default")]
+    signer_entry!(crate::systems::asymmetric::traditional::ecc::Ed25519),
+    #[cfg(feature = "sm-default")]
+    signer_entry!(crate::systems::asymmetric::traditional::sm2::Sm2),
+    #[cfg(feature = "dilithium-default")]
+    signer_entry!(crate::systems::asymmetric::post_quantum::dilithium::Dilithium2),
+    #[cfg(feature = "dilithium-default")]
+    signer_entry!(crate::systems::asymmetric::post_quantum::dilithium::Dilithium3),
+    #[cfg(feature = "dilithium-default")]
+    signer_entry!(crate::systems::asymmetric::post_quantum::dilithium::Dilithium5),
+];
+
+static KEMS: &[KemEntry] = &[
+    #[cfg(feature = "rsa-default")]
+    kem_entry!(Rsa2048<Sha256>),
+    #[cfg(feature = "rsa-default")]
+    kem_entry!(Rsa2048<Sha384>),
+    #[cfg(feature = "rsa-default")]
+    kem_entry!(Rsa2048<Sha512>),
+    #[cfg(feature = "rsa-default")]
+    kem_entry!(Rsa4096<Sha256>),
+    #[cfg(feature = "rsa-default")]
+    kem_entry!(Rsa4096<Sha384>),
+    #[cfg(feature = "rsa-default")]
+    kem_entry!(Rsa4096<Sha512>),
+    #[cfg(feature = "sm-default")]
+    kem_entry!(crate::systems::asymmetric::traditional::sm2::Sm2),
+    #[cfg(feature = "kyber-default")]
+    kem_entry!(crate::systems::asymmetric::post_quantum::kyber::Kyber512),
+    #[cfg(feature = "kyber-default")]
+    kem_entry!(crate::systems::asymmetric::post_quantum::kyber::Kyber768),
+    #[cfg(feature = "kyber-default")]
+    kem_entry!(crate::systems::asymmetric::post_quantum::kyber::Kyber1024),
+];
+
+// ------------------- Lookup -------------------
+// ------------------- 查找 -------------------
+
+/// Starts a new computation of the hash function with the given ID, or returns `None` if
+/// no enabled hash function has that ID.
+///
+/// 开始一次具有给定 ID 的哈希函数的新计算；如果没有已启用的哈希函数具有该 ID，则返回 `None`。
+pub fn hasher(id: u32) -> Option<Box<dyn DynHasher>> {
+    HASHERS
+        .iter()
+        .find(|entry| entry.id == id)
+        .map(|entry| (entry.new)())
+}
+
+/// Returns the MAC with the given ID, or `None` if no enabled MAC has that ID.
+///
+/// 返回具有给定 ID 的 MAC；如果没有已启用的 MAC 具有该 ID，则返回 `None`。
+pub fn mac(id: u32) -> Option<Box<dyn DynMac>> {
+    MACS.iter()
+        .find(|entry| entry.id == id)
+        .map(|entry| (entry.new)())
+}
+
+/// Returns the AEAD cipher with the given ID, or `None` if no enabled cipher has that ID.
+///
+/// 返回具有给定 ID 的 AEAD 密码；如果没有已启用的密码具有该 ID，则返回 `None`。
+pub fn aead(id: u32) -> Option<Box<dyn DynAead>> {
+    AEADS
+        .iter()
+        .find(|entry| entry.id == id)
+        .map(|entry| (entry.new)())
+}
+
+/// Returns the signature scheme with the given ID, or `None` if no enabled signature scheme
+/// has that ID.
+///
+/// 返回具有给定 ID 的签名方案；如果没有已启用的签名方案具有该 ID，则返回 `None`。
+pub fn signer(id: u32) -> Option<Box<dyn DynSigner>> {
+    SIGNERS
+        .iter()
+        .find(|entry| entry.id == id)
+        .map(|entry| (entry.new)())
+}
+
+/// Returns the KEM with the given ID, or `None` if no enabled KEM has that ID.
+///
+/// 返回具有给定 ID 的 KEM；如果没有已启用的 KEM 具有该 ID，则返回 `None`。
+pub fn kem(id: u32) -> Option<Box<dyn DynKem>> {
+    KEMS.iter()
+        .find(|entry| entry.id == id)
+        .map(|entry| (entry.new)())
+}
+
+/// Like [`hasher`], but fails with [`Error::PolicyViolation`] if `policy` disallows the hash
+/// function.
+///
+/// 与 [`hasher`] 相同，但如果 `policy` 不允许该哈希函数，则以 [`Error::PolicyViolation`] 失败。
+pub fn hasher_with_policy(
+    id: u32,
+    policy: &CryptoPolicy,
+) -> Result<Option<Box<dyn DynHasher>>, Error> {
+    policy.check_id(id)?;
+    Ok(hasher(id))
+}
+
+/// Like [`mac`], but fails with [`Error::PolicyViolation`] if `policy` disallows the MAC.
+///
+/// 与 [`mac`] 相同，但如果 `policy` 不允许该 MAC，则以 [`Error::PolicyViolation`] 失败。
+pub fn mac_with_policy(id: u32, policy: &CryptoPolicy) -> Result<Option<Box<dyn DynMac>>, Error> {
+    policy.check_id(id)?;
+    Ok(mac(id))
+}
+
+/// Like [`aead`], but fails with [`Error::PolicyViolation`] if `policy` disallows the cipher.
+///
+/// 与 [`aead`] 相同，但如果 `policy` 不允许该密码，则以 [`Error::PolicyViolation`] 失败。
+pub fn aead_with_policy(id: u32, policy: &CryptoPolicy) -> Result<Option<Box<dyn DynAead>>, Error> {
+    policy.check_id(id)?;
+    Ok(aead(id))
+}
+
+/// Like [`signer`], but fails with [`Error::PolicyViolation`] if `policy` disallows the
+/// signature scheme.
+///
+/// 与 [`signer`] 相同，但如果 `policy` 不允许该签名方案，则以 [`Error::PolicyViolation`] 失败。
+pub fn signer_with_policy(
+    id: u32,
+    policy: &CryptoPolicy,
+) -> Result<Option<Box<dyn DynSigner>>, Error> {
+    policy.check_id(id)?;
+    Ok(signer(id))
+}
+
+/// Like [`kem`], but fails with [`Error::PolicyViolation`] if `policy` disallows the KEM.
+///
+/// 与 [`kem`] 相同，但如果 `policy` 不允许该 KEM，则以 [`Error::PolicyViolation`] 失败。
+pub fn kem_with_policy(id: u32, policy: &CryptoPolicy) -> Result<Option<Box<dyn DynKem>>, Error> {
+    policy.check_id(id)?;
+    Ok(kem(id))
+}
+
+/// Returns the IDs of all enabled hash functions.
+///
+/// 返回所有已启用哈希函数的 ID。
+pub fn hasher_ids() -> impl Iterator<Item = u32> {
+    HASHERS.iter().map(|entry| entry.id)
+}
+
+/// Returns the IDs of all enabled MACs.
+///
+/// 返回所有已启用 MAC 的 ID。
+pub fn mac_ids() -> impl Iterator<Item = u32> {
+    MACS.iter().map(|entry| entry.id)
+}
+
+/// Returns the IDs of all enabled AEAD ciphers.
+///
+/// 返回所有已启用 AEAD 密码的 ID。
+pub fn aead_ids() -> impl Iterator<Item = u32> {
+    AEADS.iter().map(|entry| entry.id)
+}
+
+/// Returns the IDs of all enabled signature schemes.
+///
+/// 返回所有已启用签名方案的 ID。
+pub fn signer_ids() -> impl Iterator<Item = u32> {
+    SIGNERS.iter().map(|entry| entry.id)
+}
+
+/// Returns the IDs of all enabled KEMs.
+///
+/// 返回所有已启用 KEM 的 ID。
+pub fn kem_ids() -> impl Iterator<Item = u32> {
+    KEMS.iter().map(|entry| entry.id)
+}
+
+/// Returns the IDs of all registered algorithms in ascending order. A scheme that is both a
+/// signature scheme and a KEM, such as RSA, is listed once.
+///
+/// 按升序返回所有已注册算法的 ID。同时是签名方案和 KEM 的方案（例如 RSA）只列出一次。
+pub fn available_algorithms() -> impl Iterator<Item = u32> {
+    let mut ids: Vec<u32> = hasher_ids()
+        .chain(mac_ids())
+        .chain(aead_ids())
+        .chain(signer_ids())
+        .chain(kem_ids())
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_ids() {
+        assert!(hasher(0).is_none());
+        assert!(mac(0).is_none());
+
+        assert!(aead(0).is_none());
+        assert!(signer(0).is_none());
+        assert!(kem(0).is_none());
+
+        // IDs are unique within each table
+        let mut ids: Vec<u32> = hasher_ids()
+            .chain(mac_ids())
+            .chain(aead_ids())
+            .chain(signer_ids())
+            .collect();
+        let count = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), count);
+        let mut kems: Vec<u32> = kem_ids().collect();
+        kems.dedup();
+        assert_eq!(kems.len(), kem_ids().count());
+
+        let available: Vec<u32> = available_algorithms().collect();
+        assert!(available.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(kem_ids().all(|id| available.contains(&id)));
+    }
+
+    #[test]
+    fn test_lookup_with_policy() {
+        use crate::policy::PolicyError;
+
+        let policy = CryptoPolicy::default()
+            .with_min_classical_bits(128)
+            .with_legacy_denied();
+        // SHA-1, HMAC-SHA-1, RSA-PSS-2048-SHA-256
+        for id in [0x04_05_00_01, 0x06_01_01_01, 0x01_01_01_01] {
+            assert!(hasher_with_policy(id, &policy).is_err());
+            assert!(mac_with_policy(id, &policy).is_err());
+            assert!(signer_with_policy(id, &policy).is_err());
+        }
+        assert_eq!(
+            aead_with_policy(0, &policy).err(),
+            Some(Error::PolicyViolation(PolicyError::UnknownAlgorithm))
+        );
+        for id in aead_ids() {
+            assert_eq!(
+                aead_with_policy(id, &CryptoPolicy::default())
+                    .ok()
+                    .flatten()
+                    .map(|aead| aead.id()),
+                Some(id)
+            );
+        }
+        for id in kem_ids() {
+            assert!(kem_with_policy(id, &CryptoPolicy::default()).is_ok_and(|kem| kem.is_some()));
+        }
+    }
+
+    #[test]
+    fn test_algorithm_names() {
+        use crate::traits::algorithm::AlgorithmId;
+
+        macro_rules! describe {
+            ($table:ident) => {
+                $table.iter().map(|entry| {
+                    let algorithm = (entry.new)();
+                    (entry.id, algorithm.name(), algorithm.security_level())
+                })
+            };
+        }
+
+        let algorithms = describe!(HASHERS)
+            .chain(describe!(MACS))
+            .chain(describe!(AEADS))
+            .chain(describe!(SIGNERS))
+            .chain(describe!(KEMS));
+        for (id, name, level) in algorithms {
+            let id = AlgorithmId::new(id);
+            assert_eq!(id.name(), Some(name.as_str()));
+            assert_eq!(id.security_level(), Some(level));
+        }
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_hasher_lookup() {
+        use crate::systems::hash::sha2::Sha256;
+
+        let expected = <Sha256 as HashFunction>::hash(b"abc");
+        let mut hasher = hasher(<Sha256 as Algorithm>::ID).unwrap();
+        assert_eq!(hasher.name(), "SHA-256");
+        assert_eq!(hasher.output_size(), 32);
+        hasher.update(b"a");
+        let fork = hasher.clone();
+        hasher.update(b"bc");
+        assert_eq!(hasher.finalize(), expected);
+        assert_eq!(fork.finalize(), <Sha256 as HashFunction>::hash(b"a"));
+        assert!(hasher_ids().any(|id| id == <Sha256 as Algorithm>::ID));
+    }
+
+    #[cfg(feature = "hmac-default")]
+    #[test]
+    fn test_mac_lookup() {
+        use crate::systems::mac::hmac::HmacSha256;
+
+        let key = SymmetricKey::new(b"key".to_vec());
+        let mac = mac(<HmacSha256 as Algorithm>::ID).unwrap();
+        assert_eq!(mac.tag_size(), 32);
+        let tag = mac.compute(&key, b"message").unwrap();
+        assert_eq!(tag, <HmacSha256 as Mac>::compute(&key, b"message").unwrap());
+        assert!(mac.verify(&key, b"message", &tag).is_ok());
+        assert!(mac.verify(&key, b"other", &tag).is_err());
+    }
+
+    #[cfg(feature = "aes-gcm-default")]
+    #[test]
+    fn test_aead_lookup() {
+        use crate::systems::aead::aes_gcm::Aes256Gcm;
+
+        let aead = aead(<Aes256Gcm as Algorithm>::ID).unwrap();
+        assert_eq!(aead.key_size(), 32);
+        let key = aead.generate_key().unwrap();
+        let nonce = vec![0u8; aead.nonce_size()];
+        let ciphertext = aead
+            .encrypt(&key, &nonce, b"message", Some(b"aad"))
+            .unwrap();
+        assert_eq!(ciphertext.len(), 7 + aead.tag_size());
+        assert_eq!(
+            aead.decrypt(&key, &nonce, &ciphertext, Some(b"aad"))
+                .unwrap(),
+            b"message"
+        );
+        assert!(aead.decrypt(&key, &nonce, &ciphertext, None).is_err());
+    }
+
+    #[cfg(feature = "ecc-default")]
+    #[test]
+    fn test_signer_lookup() {
+        use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+
+        let signer = signer(<Ed25519 as Algorithm>::ID).unwrap();
+        let (public_key, private_key) = signer.generate_keypair().unwrap();
+        assert_eq!(public_key.algorithm_id(), signer.id());
+        let signature = signer.sign(&private_key, b"message").unwrap();
+        assert!(signer.verify(&public_key, b"message", &signature).is_ok());
+        assert!(signer.verify(&public_key, b"other", &signature).is_err());
+
+        // Keys of another scheme are rejected
+        let (other_public_key, other_private_key) = super::signer(<EcdsaP256 as Algorithm>::ID)
+            .unwrap()
+            .generate_keypair()
+            .unwrap();
+        assert_eq!(
+            signer.sign(&other_private_key, b"message").unwrap_err(),
+            Error::Key(KeyError::UnsupportedAlgorithm)
+        );
+        assert_eq!(
+            signer
+                .verify(&other_public_key, b"message", &signature)
+                .unwrap_err(),
+            Error::Key(KeyError::UnsupportedAlgorithm)
+        );
+    }
+
+    #[cfg(feature = "kyber-default")]
+    #[test]
+    fn test_kem_lookup() {
+        use crate::systems::asymmetric::post_quantum::kyber::Kyber768;
+
+        let kem = kem(<Kyber768 as Algorithm>::ID).unwrap();
+        assert_eq!(kem.name(), <Kyber768 as Algorithm>::name());
+        let (public_key, private_key) = kem.generate_keypair().unwrap();
+        let (shared_secret, encapsulated_key) = kem.encapsulate(&public_key).unwrap();
+        assert_eq!(
+            kem.decapsulate(&private_key, &encapsulated_key).unwrap(),
+            shared_secret
+        );
+        assert!(available_algorithms().any(|id| id == kem.id()));
+    }
+}
@@ -0,0 +1,514 @@
+//! Algorithm-tagged asymmetric keys.
+//!
+//! [`AnyPublicKey`] and [`AnyPrivateKey`] hold a key of any enabled asymmetric scheme together
+//! with the ID of its algorithm, so that keys of different schemes can be stored side by side.
+//! Their byte encoding is the 4-byte big-endian algorithm ID followed by the key's own
+//! [`Key::to_bytes`] encoding; with the `serde` feature they serialize as the same ID and key
+//! bytes.
+//!
+//! The ID is the [`Algorithm::ID`] of the scheme, except for RSA, whose keys are shared by the
+//! schemes of every hash and are tagged with the `ID_BASE` of their key size.
+//!
+//! 带算法标签的非对称密钥。
+//!
+//! [`AnyPublicKey`] 和 [`AnyPrivateKey`] 持有任一已启用非对称方案的密钥及其算法 ID，
+//! 因此不同方案的密钥可以一起存储。它们的字节编码为 4 字节大端序算法 ID，后跟密钥自身的
+//! [`Key::to_bytes`] 编码；启用 `serde` 特性时，它们序列化为相同的 ID 和密钥字节。
+//!
+//! 该 ID 是方案的 [`Algorithm::ID`]，但 RSA 除外：RSA 密钥由所有哈希的方案共享，
+//! 因此以其密钥大小的 `ID_BASE` 标记。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+use crate::prelude::*;
+#[cfg(feature = "dilithium-default")]
+use crate::systems::asymmetric::post_quantum::dilithium::{
+    Dilithium2, Dilithium2Params, Dilithium3, Dilithium3Params, Dilithium5, Dilithium5Params,
+    DilithiumPublicKey, DilithiumSecretKey,
+};
+#[cfg(feature = "kyber-default")]
+use crate::systems::asymmetric::post_quantum::kyber::{
+    Kyber512, Kyber512Params, Kyber768, Kyber768Params, Kyber1024, Kyber1024Params, KyberPublicKey,
+    KyberSecretKey,
+};
+#[cfg(feature = "ecc-default")]
+use crate::systems::asymmetric::traditional::ecc::{
+    EccPrivateKey, EccPublicKey, EcdsaP256, EcdsaP256Params, Ed25519, Ed25519Params,
+};
+#[cfg(all(feature = "ecc-default", not(feature = "fips")))]
+use crate::systems::asymmetric::traditional::ecc::{EcdsaSecp256k1, EcdsaSecp256k1Params};
+#[cfg(feature = "ecdh-default")]
+use crate::systems::asymmetric::traditional::ecdh::{
+    EcdhP256, EcdhP256Params, EcdhPrivateKey, EcdhPublicKey,
+};
+#[cfg(feature = "rsa-default")]
+use crate::systems::asymmetric::traditional::rsa::{
+    Rsa2048Params, Rsa4096Params, RsaKeyParams, RsaPrivateKey, RsaPublicKey, RsaScheme,
+};
+#[cfg(feature = "sm-default")]
+use crate::systems::asymmetric::traditional::sm2::{Sm2, Sm2PrivateKey, Sm2PublicKey};
+use zeroize::Zeroizing;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An asymmetric scheme whose keys can be held by [`AnyPublicKey`] and [`AnyPrivateKey`].
+/// This is a sealed trait, implemented for the asymmetric schemes of this crate.
+///
+/// 其密钥可以由 [`AnyPublicKey`] 和 [`AnyPrivateKey`] 持有的非对称方案。
+/// 这是一个密封的 trait，为本 crate 的非对称方案实现。
+pub trait AnyKeyScheme: AsymmetricKeySet + private::Sealed {
+    /// Wraps a public key of this scheme.
+    ///
+    /// 包装此方案的公钥。
+    fn wrap_public_key(key: Self::PublicKey) -> AnyPublicKey;
+
+    /// Wraps a private key of this scheme.
+    ///
+    /// 包装此方案的私钥。
+    fn wrap_private_key(key: Self::PrivateKey) -> AnyPrivateKey;
+
+    /// Returns the public key if it belongs to this scheme.
+    ///
+    /// 如果公钥属于此方案，则返回该公钥。
+    fn public_key_ref(key: &AnyPublicKey) -> Option<&Self::PublicKey>;
+
+    /// Returns the private key if it belongs to this scheme.
+    ///
+    /// 如果私钥属于此方案，则返回该私钥。
+    fn private_key_ref(key: &AnyPrivateKey) -> Option<&Self::PrivateKey>;
+}
+
+/// Splits the 4-byte big-endian algorithm ID off an encoded key.
+///
+/// 从编码后的密钥中分离出 4 字节大端序算法 ID。
+fn split_id(bytes: &[u8]) -> Result<(u32, &[u8]), Error> {
+    let (id, key) = bytes
+        .split_first_chunk::<4>()
+        .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+    Ok((u32::from_be_bytes(*id), key))
+}
+
+macro_rules! any_keys {
+    ($($(#[$cfg:meta])* $variant:ident($public:ty, $private:ty) = $id:expr;)*) => {
+        /// A public key of any enabled asymmetric scheme, tagged with its algorithm ID.
+        ///
+        /// 任一已启用非对称方案的公钥，带有其算法 ID 标签。
+        #[derive(Clone, Debug)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(Deserialize),
+            serde(try_from = "TaggedKey")
+        )]
+        #[non_exhaustive]
+        pub enum AnyPublicKey {
+            $($(#[$cfg])* #[doc = concat!("A `", stringify!($variant), "` public key.")]
+            $variant($public),)*
+        }
+
+        /// A private key of any enabled asymmetric scheme, tagged with its algorithm ID.
+        ///
+        /// 任一已启用非对称方案的私钥，带有其算法 ID 标签。
+        #[derive(Clone, Debug)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(Deserialize),
+            serde(try_from = "TaggedPrivateKey")
+        )]
+        #[non_exhaustive]
+        pub enum AnyPrivateKey {
+            $($(#[$cfg])* #[doc = concat!("A `", stringify!($variant), "` private key.")]
+            $variant($private),)*
+        }
+
+        impl AnyPublicKey {
+            /// Returns the ID of the key's algorithm.
+            ///
+            /// 返回密钥算法的 ID。
+            pub fn algorithm_id(&self) -> u32 {
+                match *self {
+                    $($(#[$cfg])* Self::$variant(_) => $id,)*
+                }
+            }
+
+            fn key_bytes(&self) -> Result<Vec<u8>, Error> {
+                match *self {
+                    $($(#[$cfg])* Self::$variant(ref key) => key.to_bytes(),)*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn from_key_bytes(id: u32, bytes: &[u8]) -> Result<Self, Error> {
+                $($(#[$cfg])* if id == $id {
+                    return Ok(Self::$variant(<$public>::from_bytes(bytes)?));
+                })*
+                Err(Error::Key(KeyError::UnsupportedAlgorithm))
+            }
+        }
+
+        impl AnyPrivateKey {
+            /// Returns the ID of the key's algorithm.
+            ///
+            /// 返回密钥算法的 ID。
+            pub fn algorithm_id(&self) -> u32 {
+                match *self {
+                    $($(#[$cfg])* Self::$variant(_) => $id,)*
+                }
+            }
+
+            fn key_bytes(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+                match *self {
+                    $($(#[$cfg])* Self::$variant(ref key) => key.to_bytes().map(Zeroizing::new),)*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn from_key_bytes(id: u32, bytes: &[u8]) -> Result<Self, Error> {
+                $($(#[$cfg])* if id == $id {
+                    return Ok(Self::$variant(<$private>::from_bytes(bytes)?));
+                })*
+                Err(Error::Key(KeyError::UnsupportedAlgorithm))
+            }
+        }
+    };
+}
+
+any_keys! {
+    #[cfg(feature = "rsa-default")]
+    Rsa2048(RsaPublicKey, RsaPrivateKey) = Rsa2048Params::ID_BASE;
+    #[cfg(feature = "rsa-default")]
+    Rsa4096(RsaPublicKey, RsaPrivateKey) = Rsa4096Params::ID_BASE;
+    #[cfg(feature = "ecc-default")]
+    EcdsaP256(EccPublicKey<EcdsaP256Params>, EccPrivateKey<EcdsaP256Params>) =
+        <EcdsaP256 as Algorithm>::ID;
+    #[cfg(all(feature = "ecc-default", not(feature = "fips")))]
+    EcdsaSecp256k1(EccPublicKey<EcdsaSecp256k1Params>, EccPrivateKey<EcdsaSecp256k1Params>) =
+        <EcdsaSecp256k1 as Algorithm>::ID;
+    #[cfg(feature = "ecc-default")]
+    Ed25519(EccPublicKey<Ed25519Params>, EccPrivateKey<Ed25519Params>) =
+        <Ed25519 as Algorithm>::ID;
+    #[cfg(feature = "ecdh-default")]
+    EcdhP256(EcdhPublicKey<EcdhP256Params>, EcdhPrivateKey<EcdhP256Params>) =
+        <EcdhP256 as Algorithm>::ID;
+    #[cfg(feature = "sm-default")]
+    Sm2(Sm2PublicKey, Sm2PrivateKey) = <Sm2 as Algorithm>::ID;
+    #[cfg(feature = "kyber-default")]
+    Kyber512(KyberPublicKey<Kyber512Params>, KyberSecretKey<Kyber512Params>) =
+        <Kyber512 as Algorithm>::ID;
+    #[cfg(feature = "kyber-default")]
+    Kyber768(KyberPublicKey<Kyber768Params>, KyberSecretKey<Kyber768Params>) =
+        <Kyber768 as Algorithm>::ID;
+    #[cfg(feature = "kyber-default")]
+    Kyber1024(KyberPublicKey<Kyber1024Params>, KyberSecretKey<Kyber1024Params>) =
+        <Kyber1024 as Algorithm>::ID;
+    #[cfg(feature = "dilithium-default")]
+    Dilithium2(DilithiumPublicKey<Dilithium2Params>, DilithiumSecretKey<Dilithium2Params>) =
+        <Dilithium2 as Algorithm>::ID;
+    #[cfg(feature = "dilithium-default")]
+    Dilithium3(DilithiumPublicKey<Dilithium3Params>, DilithiumSecretKey<Dilithium3Params>) =
+        <Dilithium3 as Algorithm>::ID;
+    #[cfg(feature = "dilithium-default")]
+    Dilithium5(DilithiumPublicKey<Dilithium5Params>, DilithiumSecretKey<Dilithium5Params>) =
+        <Dilithium5 as Algorithm>::ID;
+}
+
+#[allow(unused_macros)]
+macro_rules! any_key_scheme {
+    ([$($generics:tt)*] $scheme:ty => $variant:ident) => {
+        impl<$($generics)*> private::Sealed for $scheme {}
+
+        impl<$($generics)*> AnyKeyScheme for $scheme {
+            fn wrap_public_key(key: Self::PublicKey) -> AnyPublicKey {
+                AnyPublicKey::$variant(key)
+            }
+
+            fn wrap_private_key(key: Self::PrivateKey) -> AnyPrivateKey {
+                AnyPrivateKey::$variant(key)
+            }
+
+            #[allow(unreachable_patterns)]
+            fn public_key_ref(key: &AnyPublicKey) -> Option<&Self::PublicKey> {
+                match key {
+                    AnyPublicKey::$variant(key) => Some(key),
+                    _ => None,
+                }
+            }
+
+            #[allow(unreachable_patterns)]
+            fn private_key_ref(key: &AnyPrivateKey) -> Option<&Self::PrivateKey> {
+                match key {
+                    AnyPrivateKey::$variant(key) => Some(key),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rsa-default")]
+any_key_scheme!([H: Hasher + 'static] RsaScheme<Rsa2048Params, H> => Rsa2048);
+#[cfg(feature = "rsa-default")]
+any_key_scheme!([H: Hasher + 'static] RsaScheme<Rsa4096Params, H> => Rsa4096);
+#[cfg(feature = "ecc-default")]
+any_key_scheme!([] EcdsaP256 => EcdsaP256);
+#[cfg(all(feature = "ecc-default", not(feature = "fips")))]
+any_key_scheme!([] EcdsaSecp256k1 => EcdsaSecp256k1);
+#[cfg(feature = "ecc-default")]
+any_key_scheme!([] Ed25519 => Ed25519);
+#[cfg(feature = "ecdh-default")]
+any_key_scheme!([] EcdhP256 => EcdhP256);
+#[cfg(feature = "sm-default")]
+any_key_scheme!([] Sm2 => Sm2);
+#[cfg(feature = "kyber-default")]
+any_key_scheme!([] Kyber512 => Kyber512);
+#[cfg(feature = "kyber-default")]
+any_key_scheme!([] Kyber768 => Kyber768);
+#[cfg(feature = "kyber-default")]
+any_key_scheme!([] Kyber1024 => Kyber1024);
+#[cfg(feature = "dilithium-default")]
+any_key_scheme!([] Dilithium2 => Dilithium2);
+#[cfg(feature = "dilithium-default")]
+any_key_scheme!([] Dilithium3 => Dilithium3);
+#[cfg(feature = "dilithium-default")]
+any_key_scheme!([] Dilithium5 => Dilithium5);
+
+impl AnyPublicKey {
+    /// Wraps a public key of the scheme `S`.
+    ///
+    /// 包装方案 `S` 的公钥。
+    pub fn new<S: AnyKeyScheme>(key: S::PublicKey) -> Self {
+        S::wrap_public_key(key)
+    }
+
+    /// Returns the key if it belongs to the scheme `S`.
+    ///
+    /// 如果密钥属于方案 `S`，则返回该密钥。
+    pub fn downcast_ref<S: AnyKeyScheme>(&self) -> Option<&S::PublicKey> {
+        S::public_key_ref(self)
+    }
+
+    /// Returns the key if it belongs to the scheme `S`, or gives back `self` otherwise.
+    ///
+    /// 如果密钥属于方案 `S`，则返回该密钥；否则返还 `self`。
+    pub fn downcast<S: AnyKeyScheme>(self) -> Result<S::PublicKey, Self> {
+        S::public_key_ref(&self).cloned().ok_or(self)
+    }
+
+    /// Encodes the key as its 4-byte big-endian algorithm ID followed by the key bytes.
+    ///
+    /// 将密钥编码为 4 字节大端序算法 ID，后跟密钥字节。
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = self.algorithm_id().to_be_bytes().to_vec();
+        bytes.extend_from_slice(&self.key_bytes()?);
+        Ok(bytes)
+    }
+
+    /// Decodes a key produced by [`Self::to_bytes`].
+    ///
+    /// Fails with `KeyError::UnsupportedAlgorithm` if the algorithm ID is unknown or its
+    /// feature is disabled, and with the key type's own error if the key bytes are invalid.
+    ///
+    /// 解码由 [`Self::to_bytes`] 生成的密钥。
+    ///
+    /// 如果算法 ID 未知或其特性未启用，则以 `KeyError::UnsupportedAlgorithm` 失败；
+    /// 如果密钥字节无效，则以该密钥类型自身的错误失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let (id, key) = split_id(bytes)?;
+        Self::from_key_bytes(id, key)
+    }
+}
+
+impl AnyPrivateKey {
+    /// Wraps a private key of the scheme `S`.
+    ///
+    /// 包装方案 `S` 的私钥。
+    pub fn new<S: AnyKeyScheme>(key: S::PrivateKey) -> Self {
+        S::wrap_private_key(key)
+    }
+
+    /// Returns the key if it belongs to the scheme `S`.
+    ///
+    /// 如果密钥属于方案 `S`，则返回该密钥。
+    pub fn downcast_ref<S: AnyKeyScheme>(&self) -> Option<&S::PrivateKey> {
+        S::private_key_ref(self)
+    }
+
+    /// Returns the key if it belongs to the scheme `S`, or gives back `self` otherwise.
+    ///
+    /// 如果密钥属于方案 `S`，则返回该密钥；否则返还 `self`。
+    pub fn downcast<S: AnyKeyScheme>(self) -> Result<S::PrivateKey, Self> {
+        S::private_key_ref(&self).cloned().ok_or(self)
+    }
+
+    /// Encodes the key as its 4-byte big-endian algorithm ID followed by the key bytes.
+    ///
+    /// 将密钥编码为 4 字节大端序算法 ID，后跟密钥字节。
+    pub fn to_bytes(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let mut bytes = Zeroizing::new(self.algorithm_id().to_be_bytes().to_vec());
+        bytes.extend_from_slice(&self.key_bytes()?);
+        Ok(bytes)
+    }
+
+    /// Decodes a key produced by [`Self::to_bytes`].
+    ///
+    /// Fails with `KeyError::UnsupportedAlgorithm` if the algorithm ID is unknown or its
+    /// feature is disabled, and with the key type's own error if the key bytes are invalid.
+    ///
+    /// 解码由 [`Self::to_bytes`] 生成的密钥。
+    ///
+    /// 如果算法 ID 未知或其特性未启用，则以 `KeyError::UnsupportedAlgorithm` 失败；
+    /// 如果密钥字节无效，则以该密钥类型自身的错误失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let (id, key) = split_id(bytes)?;
+        Self::from_key_bytes(id, key)
+    }
+}
+
+// ------------------- Serde Representation -------------------
+// ------------------- Serde 表示 -------------------
+
+/// The serde form of [`AnyPublicKey`]: the algorithm ID and the key bytes.
+///
+/// [`AnyPublicKey`] 的 serde 形式：算法 ID 和密钥字节。
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct TaggedKey {
+    algorithm: u32,
+    #[serde(with = "crate::traits::key::serde_bytes")]
+    key: Vec<u8>,
+}
+
+/// The serde form of [`AnyPrivateKey`]: the algorithm ID and the key bytes.
+///
+/// [`AnyPrivateKey`] 的 serde 形式：算法 ID 和密钥字节。
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct TaggedPrivateKey {
+    algorithm: u32,
+    #[serde(with = "crate::traits::key::serde_bytes")]
+    key: Zeroizing<Vec<u8>>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TaggedKey> for AnyPublicKey {
+    type Error = Error;
+
+    fn try_from(tagged: TaggedKey) -> Result<Self, Error> {
+        Self::from_key_bytes(tagged.algorithm, &tagged.key)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AnyPublicKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TaggedKey {
+            algorithm: self.algorithm_id(),
+            key: self.key_bytes().map_err(serde::ser::Error::custom)?,
+        }
+        .serialize(serializer)
+    }
+}
+#[cfg(feature = "serde")]
+impl TryFrom<TaggedPrivateKey> for AnyPrivateKey {
+    type Error = Error;
+
+    fn try_from(tagged: TaggedPrivateKey) -> Result<Self, Error> {
+        Self::from_key_bytes(tagged.algorithm, &tagged.key)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for AnyPrivateKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TaggedPrivateKey {
+            algorithm: self.algorithm_id(),
+            key: self.key_bytes().map_err(serde::ser::Error::custom)?,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_algorithm() {
+        assert_eq!(
+            AnyPublicKey::from_bytes(&[0, 0, 0, 0, 1, 2, 3]).unwrap_err(),
+            Error::Key(KeyError::UnsupportedAlgorithm)
+        );
+        assert_eq!(
+            AnyPrivateKey::from_bytes(&[0, 0, 0]).unwrap_err(),
+            Error::Key(KeyError::InvalidEncoding)
+        );
+    }
+
+    #[cfg(feature = "ecc-default")]
+    #[test]
+    fn test_any_key_roundtrip() {
+        let (pk, sk) = Ed25519::generate_keypair().unwrap();
+        let any_pk = AnyPublicKey::new::<Ed25519>(pk.clone());
+        let any_sk = AnyPrivateKey::new::<Ed25519>(sk.clone());
+        assert_eq!(any_pk.algorithm_id(), <Ed25519 as Algorithm>::ID);
+        assert_eq!(any_sk.algorithm_id(), <Ed25519 as Algorithm>::ID);
+
+        // The encoding is the big-endian algorithm ID followed by the key bytes
+        let encoded = any_pk.to_bytes().unwrap();
+        assert_eq!(encoded[..4], <Ed25519 as Algorithm>::ID.to_be_bytes());
+        assert_eq!(encoded[4..], pk.to_bytes().unwrap());
+        let decoded = AnyPublicKey::from_bytes(&encoded).unwrap();
+        assert_eq!(decoded.downcast_ref::<Ed25519>(), Some(&pk));
+        assert!(decoded.downcast_ref::<EcdsaP256>().is_none());
+        let decoded = AnyPrivateKey::from_bytes(&any_sk.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded.downcast::<Ed25519>().unwrap(), sk);
+
+        // Down-casting to the wrong scheme gives the key back
+        let any_pk = any_pk.downcast::<EcdsaP256>().unwrap_err();
+        assert_eq!(any_pk.downcast::<Ed25519>().unwrap(), pk);
+
+        // Key bytes that do not parse for the tagged algorithm are rejected
+        let mut mismatched = <EcdsaP256 as Algorithm>::ID.to_be_bytes().to_vec();
+        mismatched.extend_from_slice(&[0u8; 8]);
+        assert!(AnyPublicKey::from_bytes(&mismatched).is_err());
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&any_sk).unwrap();
+            let restored: AnyPrivateKey = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored.downcast::<Ed25519>().unwrap(), sk);
+            let json = serde_json::to_value(AnyPublicKey::new::<Ed25519>(pk.clone())).unwrap();
+            assert_eq!(json["algorithm"], <Ed25519 as Algorithm>::ID);
+        }
+    }
+
+    #[cfg(all(feature = "kyber-default", feature = "dilithium-default"))]
+    #[test]
+    fn test_heterogeneous_keys() {
+        let (kyber_pk, _) = Kyber768::generate_keypair().unwrap();
+        let (dilithium_pk, dilithium_sk) = Dilithium2::generate_keypair().unwrap();
+        let stored: Vec<Vec<u8>> = [
+            AnyPublicKey::new::<Kyber768>(kyber_pk.clone()),
+            AnyPublicKey::new::<Dilithium2>(dilithium_pk.clone()),
+        ]
+        .iter()
+        .map(|key| key.to_bytes().unwrap())
+        .collect();
+
+        let loaded: Vec<AnyPublicKey> = stored
+            .iter()
+            .map(|bytes| AnyPublicKey::from_bytes(bytes).unwrap())
+            .collect();
+        assert_eq!(loaded[0].downcast_ref::<Kyber768>(), Some(&kyber_pk));
+        assert!(loaded[0].downcast_ref::<Kyber512>().is_none());
+        assert!(matches!(loaded[1], AnyPublicKey::Dilithium2(_)));
+
+        let signature = Dilithium2::sign(&dilithium_sk, b"message").unwrap();
+        let verifying_key = loaded[1].downcast_ref::<Dilithium2>().unwrap();
+        Dilithium2::verify(verifying_key, b"message", &signature).unwrap();
+    }
+}
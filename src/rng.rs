@@ -0,0 +1,791 @@
+//! Random number generation used by the crate's key and nonce generation.
+//!
+//! Every scheme that needs fresh randomness (key pairs, symmetric keys, nonces, salts and
+//! randomized padding) draws it from a single internal source. That source reads entropy from
+//! the [`EntropySource`] installed with [`install_entropy_source`], or, if none is installed,
+//! from the operating system through the `getrandom` feature. Without either, generation fails
+//! with the error of the calling operation. With the `fips` feature enabled the entropy seeds
+//! an [`HmacDrbg`] (NIST SP 800-90A HMAC_DRBG with SHA-256), so that all generated key material
+//! passes through an approved DRBG.
+//!
+//! Installing a source is how the crate is used on targets without an operating system RNG,
+//! for example a TRNG peripheral on an RTOS, and how tests make generation reproducible.
+//!
+//! [`HmacDrbg`] is also exposed for callers that need a deterministic, standards-based
+//! generator, for example to reproduce known-answer tests.
+//!
+//! crate 的密钥和 nonce 生成所使用的随机数生成。
+//!
+//! 每个需要新随机数的方案（密钥对、对称密钥、nonce、盐和随机填充）都从同一个内部来源获取随机数。
+//! 该来源从通过 [`install_entropy_source`] 安装的 [`EntropySource`] 读取熵；如果没有安装，
+//! 则通过 `getrandom` 特性从操作系统读取。两者都没有时，生成会以调用操作的错误失败。启用
+//! `fips` 特性后，这些熵用于为 [`HmacDrbg`]（使用 SHA-256 的 NIST SP 800-90A HMAC_DRBG）
+//! 播种，从而所有生成的密钥材料都经过经批准的 DRBG。
+//!
+//! 在没有操作系统 RNG 的目标上（例如 RTOS 上的 TRNG 外设），通过安装熵源来使用本 crate；
+//! 测试也可以借此使生成过程可复现。
+//!
+//! [`HmacDrbg`] 也对外公开，供需要确定性、基于标准的生成器的调用者使用，例如复现已知答案测试。
+
+// Which helpers are used depends on the enabled algorithm features.
+#![allow(dead_code)]
+
+use core::num::NonZeroU32;
+use rand_core_elliptic_curve::{CryptoRng, Error as RngError, RngCore, impls};
+
+use crate::errors::Error;
+use spin::RwLock;
+
+#[cfg(any(feature = "hmac-default", feature = "fast-rng-default"))]
+use crate::traits::key::KeyError;
+#[cfg(feature = "fast-rng-default")]
+use {
+    chacha20::{
+        ChaCha20,
+        cipher::{KeyIvInit, StreamCipher},
+    },
+    zeroize::Zeroizing,
+};
+#[cfg(feature = "hmac-default")]
+use {
+    hmac::{Hmac, Mac, digest::KeyInit},
+    sha2::Sha256,
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+
+/// The error code reported when the entropy source fails.
+///
+/// 熵源失败时报告的错误码。
+const ENTROPY_UNAVAILABLE: NonZeroU32 = NonZeroU32::MIN.saturating_add(RngError::CUSTOM_START - 1);
+
+// ------------------- Entropy Source -------------------
+// ------------------- 熵源 -------------------
+
+/// A source of full-entropy random bytes, such as a hardware TRNG, a DRBG seeded by one, or a
+/// deterministic generator in tests.
+///
+/// Implementations are shared by all threads, so a stateful generator needs interior
+/// mutability. Closures and functions of type `Fn(&mut [u8]) -> Result<(), Error>` implement
+/// this trait.
+///
+/// 全熵随机字节的来源，例如硬件 TRNG、由其播种的 DRBG，或测试中的确定性生成器。
+///
+/// 实现由所有线程共享，因此有状态的生成器需要内部可变性。类型为
+/// `Fn(&mut [u8]) -> Result<(), Error>` 的闭包和函数实现了此 trait。
+pub trait EntropySource: Send + Sync {
+    /// Fills `dest` entirely with random bytes, or fails without using any of them.
+    ///
+    /// 用随机字节完全填充 `dest`，或者失败且不使用其中任何字节。
+    fn fill_entropy(&self, dest: &mut [u8]) -> Result<(), Error>;
+}
+
+impl<F> EntropySource for F
+where
+    F: Fn(&mut [u8]) -> Result<(), Error> + Send + Sync,
+{
+    fn fill_entropy(&self, dest: &mut [u8]) -> Result<(), Error> {
+        self(dest)
+    }
+}
+
+static ENTROPY_SOURCE: RwLock<Option<&'static dyn EntropySource>> = RwLock::new(None);
+
+/// Installs `source` process-wide, replacing any previously installed source. All key, nonce
+/// and salt generation that does not take a caller-supplied RNG reads from it from now on.
+///
+/// 在进程范围内安装 `source`，替换之前安装的任何熵源。此后所有不接受调用者提供的 RNG 的密钥、
+/// nonce 和盐生成都从它读取。
+///
+/// # Examples
+///
+/// ```
+/// use seal_crypto::prelude::CryptoError;
+/// use seal_crypto::rng::{install_entropy_source, uninstall_entropy_source};
+///
+/// fn trng_fill(dest: &mut [u8]) -> Result<(), CryptoError> {
+///     // Read the hardware random number generator here.
+///     # dest.fill(0x5a);
+///     Ok(())
+/// }
+///
+/// install_entropy_source(&trng_fill);
+/// # uninstall_entropy_source();
+/// ```
+pub fn install_entropy_source(source: &'static dyn EntropySource) {
+    *ENTROPY_SOURCE.write() = Some(source);
+}
+
+/// Removes the installed source, if any, so that entropy comes from the operating system again.
+///
+/// 移除已安装的熵源（如果有），使熵重新来自操作系统。
+pub fn uninstall_entropy_source() {
+    *ENTROPY_SOURCE.write() = None;
+}
+
+/// Returns `true` if a source has been installed with [`install_entropy_source`].
+///
+/// 如果已通过 [`install_entropy_source`] 安装了熵源，则返回 `true`。
+pub fn has_entropy_source() -> bool {
+    ENTROPY_SOURCE.read().is_some()
+}
+
+// ------------------- HMAC_DRBG -------------------
+// ------------------- HMAC_DRBG -------------------
+
+/// A NIST SP 800-90A HMAC_DRBG instantiated with HMAC-SHA-256.
+///
+/// The generator provides 256 bits of security strength. It must be instantiated with at
+/// least that much entropy and has to be reseeded after [`HmacDrbg::RESEED_INTERVAL`]
+/// requests; [`HmacDrbg::generate`] fails once reseeding is required. Its internal state is
+/// zeroized on drop.
+///
+/// 使用 HMAC-SHA-256 实例化的 NIST SP 800-90A HMAC_DRBG。
+///
+/// 该生成器提供 256 位安全强度。它必须使用至少同等数量的熵实例化，并且在
+/// [`HmacDrbg::RESEED_INTERVAL`] 次请求后必须重新播种；需要重新播种时 [`HmacDrbg::generate`]
+/// 会失败。其内部状态在销毁时被清零。
+#[cfg(feature = "hmac-default")]
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct HmacDrbg {
+    key: [u8; 32],
+    value: [u8; 32],
+    reseed_counter: u64,
+}
+
+#[cfg(feature = "hmac-default")]
+impl HmacDrbg {
+    /// The number of requests allowed between reseeds (SP 800-90A, Table 2).
+    ///
+    /// 两次重新播种之间允许的请求数（SP 800-90A，表 2）。
+    pub const RESEED_INTERVAL: u64 = 1 << 48;
+
+    /// The largest number of bytes a single [`HmacDrbg::generate`] call may return.
+    ///
+    /// 单次 [`HmacDrbg::generate`] 调用可返回的最大字节数。
+    pub const MAX_REQUEST_SIZE: usize = 1 << 16;
+
+    /// Instantiates the generator.
+    ///
+    /// # Arguments
+    /// * `entropy_input` - Full-entropy input from an approved entropy source.
+    /// * `nonce` - A value that is not repeated across instantiations.
+    /// * `personalization` - Optional application-specific data; may be empty.
+    ///
+    /// 实例化生成器。
+    ///
+    /// # 参数
+    /// * `entropy_input` - 来自经批准熵源的全熵输入。
+    /// * `nonce` - 在各次实例化之间不重复的值。
+    /// * `personalization` - 可选的应用特定数据；可以为空。
+    pub fn new(entropy_input: &[u8; 32], nonce: &[u8; 16], personalization: &[u8]) -> Self {
+        let mut drbg = Self {
+            key: [0x00; 32],
+            value: [0x01; 32],
+            reseed_counter: 1,
+        };
+        drbg.update(&[entropy_input, nonce, personalization]);
+        drbg
+    }
+
+    /// Mixes fresh entropy into the state and resets the reseed counter.
+    ///
+    /// 将新的熵混入状态并重置重新播种计数器。
+    pub fn reseed(&mut self, entropy_input: &[u8; 32], additional_input: &[u8]) {
+        self.update(&[entropy_input, additional_input]);
+        self.reseed_counter = 1;
+    }
+
+    /// Returns `true` once the generator must be reseeded before producing more output.
+    ///
+    /// 当生成器在产生更多输出之前必须重新播种时返回 `true`。
+    pub fn needs_reseed(&self) -> bool {
+        self.reseed_counter > Self::RESEED_INTERVAL
+    }
+
+    /// Fills `output` with pseudorandom bytes.
+    ///
+    /// Fails with `KeyError::GenerationFailed` if the generator needs to be reseeded or if
+    /// `output` is longer than [`HmacDrbg::MAX_REQUEST_SIZE`].
+    ///
+    /// 用伪随机字节填充 `output`。
+    ///
+    /// 如果生成器需要重新播种，或者 `output` 长于 [`HmacDrbg::MAX_REQUEST_SIZE`]，则以
+    /// `KeyError::GenerationFailed` 失败。
+    pub fn generate(&mut self, output: &mut [u8], additional_input: &[u8]) -> Result<(), Error> {
+        if self.needs_reseed() || output.len() > Self::MAX_REQUEST_SIZE {
+            return Err(Error::Key(KeyError::GenerationFailed));
+        }
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+        for chunk in output.chunks_mut(32) {
+            self.value = self.hmac(&[&self.value]);
+            chunk.copy_from_slice(&self.value[..chunk.len()]);
+        }
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// The HMAC_DRBG_Update function (SP 800-90A, 10.1.2.2).
+    ///
+    /// HMAC_DRBG_Update 函数（SP 800-90A，10.1.2.2）。
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        let provided = provided_data.iter().any(|part| !part.is_empty());
+        for round in [0x00u8, 0x01] {
+            if round == 0x01 && !provided {
+                break;
+            }
+            let mut input: [&[u8]; 5] = [&[]; 5];
+            input[0] = &self.value;
+            input[1] = core::slice::from_ref(&round);
+            for (slot, part) in input[2..].iter_mut().zip(provided_data) {
+                *slot = part;
+            }
+            let mut key = self.hmac(&input);
+            self.key.copy_from_slice(&key);
+            key.zeroize();
+            self.value = self.hmac(&[&self.value]);
+        }
+    }
+
+    fn hmac(&self, parts: &[&[u8]]) -> [u8; 32] {
+        // HMAC pads keys shorter than the block size with zeros, so padding here is exact.
+        let mut block = hmac::digest::Key::<Hmac<Sha256>>::default();
+        block[..32].copy_from_slice(&self.key);
+        let mut mac = <Hmac<Sha256> as KeyInit>::new(&block);
+        block.zeroize();
+        for part in parts {
+            mac.update(part);
+        }
+        mac.finalize().into_bytes().into()
+    }
+}
+
+#[cfg(feature = "hmac-default")]
+impl RngCore for HmacDrbg {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(err) = self.try_fill_bytes(dest) {
+            panic!("HMAC_DRBG failed: {err}");
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        for chunk in dest.chunks_mut(Self::MAX_REQUEST_SIZE) {
+            self.generate(chunk, &[])
+                .map_err(|_| RngError::from(ENTROPY_UNAVAILABLE))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "hmac-default")]
+impl CryptoRng for HmacDrbg {}
+
+#[cfg(feature = "hmac-default")]
+impl core::fmt::Debug for HmacDrbg {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HmacDrbg").finish_non_exhaustive()
+    }
+}
+
+// ------------------- System RNG -------------------
+// ------------------- 系统 RNG -------------------
+
+/// The randomness source behind all key, nonce and salt generation in the crate.
+///
+/// Without the `fips` feature this reads the installed [`EntropySource`] or operating system
+/// entropy for every request. With it, the first request instantiates an [`HmacDrbg`] from that
+/// entropy and later requests are served by that DRBG, which is reseeded when required.
+///
+/// crate 中所有密钥、nonce 和盐生成背后的随机数来源。
+///
+/// 未启用 `fips` 特性时，每次请求都读取已安装的 [`EntropySource`] 或操作系统的熵。启用后，
+/// 第一次请求会用这些熵实例化一个 [`HmacDrbg`]，之后的请求由该 DRBG 提供，并在需要时重新播种。
+#[derive(Default)]
+pub(crate) struct SystemRng {
+    #[cfg(feature = "fips")]
+    drbg: Option<HmacDrbg>,
+}
+
+impl SystemRng {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    #[cfg(feature = "fips")]
+    fn drbg(&mut self) -> Result<&mut HmacDrbg, RngError> {
+        let mut entropy = zeroize::Zeroizing::new([0u8; 32]);
+        match &mut self.drbg {
+            Some(drbg) if !drbg.needs_reseed() => {}
+            Some(drbg) => {
+                read_entropy(entropy.as_mut())?;
+                drbg.reseed(&entropy, &[]);
+            }
+            None => {
+                let mut nonce = [0u8; 16];
+                read_entropy(entropy.as_mut())?;
+                read_entropy(&mut nonce)?;
+                self.drbg = Some(HmacDrbg::new(&entropy, &nonce, b"seal-crypto"));
+            }
+        }
+        self.drbg
+            .as_mut()
+            .ok_or(RngError::from(ENTROPY_UNAVAILABLE))
+    }
+}
+
+impl RngCore for SystemRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(err) = self.try_fill_bytes(dest) {
+            panic!("Error: {err}");
+        }
+    }
+
+    #[cfg(not(feature = "fips"))]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        read_entropy(dest)
+    }
+
+    #[cfg(feature = "fips")]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        for chunk in dest.chunks_mut(HmacDrbg::MAX_REQUEST_SIZE) {
+            self.drbg()?
+                .generate(chunk, &[])
+                .map_err(|_| RngError::from(ENTROPY_UNAVAILABLE))?;
+        }
+        Ok(())
+    }
+}
+
+impl CryptoRng for SystemRng {}
+
+/// Fills `dest` from the crate's randomness source.
+///
+/// 从 crate 的随机数来源填充 `dest`。
+pub(crate) fn fill(dest: &mut [u8]) -> Result<(), RngError> {
+    SystemRng::new().try_fill_bytes(dest)
+}
+
+/// Fills `dest` with randomness that is not used as key material, such as nonces, IVs and salts.
+/// With the `fast-rng` feature this reads the calling thread's [`FastRng`], otherwise it is
+/// the same as [`fill`].
+///
+/// 用不作为密钥材料使用的随机数（例如 nonce、IV 和盐）填充 `dest`。启用 `fast-rng` 特性时，
+/// 它读取调用线程的 [`FastRng`]，否则与 [`fill`] 相同。
+pub(crate) fn fill_nonce(dest: &mut [u8]) -> Result<(), RngError> {
+    #[cfg(all(feature = "fast-rng-default", feature = "std"))]
+    return fill_fast(dest).map_err(|_| RngError::from(ENTROPY_UNAVAILABLE));
+    #[cfg(not(all(feature = "fast-rng-default", feature = "std")))]
+    fill(dest)
+}
+
+/// An infallible RNG over [`fill_nonce`] for APIs that take a caller-supplied RNG. Like
+/// [`SystemRng`], it panics if no entropy is available.
+///
+/// 基于 [`fill_nonce`] 的不会失败的 RNG，用于接受调用者提供的 RNG 的 API。与 [`SystemRng`]
+/// 一样，如果没有可用的熵，它会 panic。
+pub(crate) struct NonceRng;
+
+impl RngCore for NonceRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(err) = self.try_fill_bytes(dest) {
+            panic!("Error: {err}");
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        fill_nonce(dest)
+    }
+}
+
+impl CryptoRng for NonceRng {}
+
+// ------------------- FastRng -------------------
+// ------------------- FastRng -------------------
+
+/// A fast userspace CSPRNG for nonces and other randomness that is not key material.
+///
+/// The output is a ChaCha20 keystream. After every request the generator replaces its key with
+/// the next 32 bytes of the keystream, so earlier output cannot be recovered from a later state.
+/// It mixes fresh entropy from the crate's entropy source (see [`install_entropy_source`]) into
+/// the key after every [`FastRng::RESEED_INTERVAL`] bytes and, on Unix, when it notices it is
+/// running in a forked child process, so that parent and child never share output. Keys should
+/// still be generated from the system RNG, which the crate does on its own.
+///
+/// 用于 nonce 和其他非密钥材料随机数的快速用户态 CSPRNG。
+///
+/// 输出是 ChaCha20 密钥流。每次请求之后，生成器都会用密钥流的下 32 个字节替换其密钥，因此无法从
+/// 之后的状态恢复先前的输出。每输出 [`FastRng::RESEED_INTERVAL`] 个字节之后，以及在 Unix 上
+/// 发现自己运行在 fork 出的子进程中时，它会将来自 crate 熵源（参见 [`install_entropy_source`]）
+/// 的新熵混入密钥，从而父进程和子进程永远不会共享输出。密钥仍应由系统 RNG 生成，本 crate
+/// 自身也是这样做的。
+#[cfg(feature = "fast-rng-default")]
+pub struct FastRng {
+    cipher: ChaCha20,
+    remaining: usize,
+    #[cfg(all(unix, feature = "std"))]
+    pid: u32,
+}
+
+#[cfg(feature = "fast-rng-default")]
+impl FastRng {
+    /// The number of bytes produced between reseeds from the entropy source.
+    ///
+    /// 两次从熵源重新播种之间产生的字节数。
+    pub const RESEED_INTERVAL: usize = 1 << 20;
+
+    /// Creates a generator seeded from the crate's entropy source.
+    ///
+    /// Fails with `KeyError::GenerationFailed` if no entropy is available.
+    ///
+    /// 创建一个从 crate 熵源播种的生成器。
+    ///
+    /// 如果没有可用的熵，则以 `KeyError::GenerationFailed` 失败。
+    pub fn new() -> Result<Self, Error> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        read_entropy(key.as_mut()).map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        Ok(Self::from_key(&key))
+    }
+
+    fn from_key(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: ChaCha20::new(key.into(), &[0u8; 12].into()),
+            remaining: Self::RESEED_INTERVAL,
+            #[cfg(all(unix, feature = "std"))]
+            pid: std::process::id(),
+        }
+    }
+
+    /// Mixes fresh entropy from the crate's entropy source into the key.
+    ///
+    /// 将来自 crate 熵源的新熵混入密钥。
+    pub fn reseed(&mut self) -> Result<(), Error> {
+        let mut fresh = Zeroizing::new([0u8; 32]);
+        read_entropy(fresh.as_mut()).map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        let mut key = self.next_key();
+        for (byte, fresh) in key.iter_mut().zip(fresh.iter()) {
+            *byte ^= fresh;
+        }
+        *self = Self::from_key(&key);
+        Ok(())
+    }
+
+    /// Fills `dest` with random bytes, reseeding first if required.
+    ///
+    /// Fails with `KeyError::GenerationFailed` if a required reseed finds no entropy.
+    ///
+    /// 用随机字节填充 `dest`，需要时会先重新播种。
+    ///
+    /// 如果需要重新播种时没有可用的熵，则以 `KeyError::GenerationFailed` 失败。
+    pub fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if self.forked() {
+            self.reseed()?;
+        }
+        for chunk in dest.chunks_mut(Self::RESEED_INTERVAL) {
+            if chunk.len() > self.remaining {
+                self.reseed()?;
+            }
+            chunk.fill(0);
+            self.cipher.apply_keystream(chunk);
+            self.remaining -= chunk.len();
+        }
+        let key = self.next_key();
+        let remaining = self.remaining;
+        *self = Self::from_key(&key);
+        self.remaining = remaining;
+        Ok(())
+    }
+
+    fn next_key(&mut self) -> Zeroizing<[u8; 32]> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        self.cipher.apply_keystream(key.as_mut());
+        key
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    fn forked(&self) -> bool {
+        std::process::id() != self.pid
+    }
+
+    #[cfg(not(all(unix, feature = "std")))]
+    fn forked(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(feature = "fast-rng-default")]
+impl RngCore for FastRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(err) = self.fill(dest) {
+            panic!("FastRng failed: {err}");
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        self.fill(dest)
+            .map_err(|_| RngError::from(ENTROPY_UNAVAILABLE))
+    }
+}
+
+#[cfg(feature = "fast-rng-default")]
+impl CryptoRng for FastRng {}
+
+#[cfg(feature = "fast-rng-default")]
+impl core::fmt::Debug for FastRng {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FastRng").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(feature = "fast-rng-default", feature = "std"))]
+std::thread_local! {
+    static THREAD_RNG: core::cell::RefCell<Option<FastRng>> = const { core::cell::RefCell::new(None) };
+}
+
+/// Fills `dest` from a [`FastRng`] owned by the calling thread, creating it on first use. The
+/// crate uses it for nonces, IVs and salts when the `fast-rng` feature is enabled.
+///
+/// Fails with `KeyError::GenerationFailed` if no entropy is available for seeding.
+///
+/// 从调用线程拥有的 [`FastRng`] 填充 `dest`，首次使用时创建它。启用 `fast-rng` 特性时，
+/// 本 crate 将其用于 nonce、IV 和盐。
+///
+/// 如果没有可用于播种的熵，则以 `KeyError::GenerationFailed` 失败。
+#[cfg(all(feature = "fast-rng-default", feature = "std"))]
+pub fn fill_fast(dest: &mut [u8]) -> Result<(), Error> {
+    THREAD_RNG.with(|cell| {
+        let mut slot = cell.borrow_mut();
+        match slot.as_mut() {
+            Some(rng) => rng.fill(dest),
+            None => slot.insert(FastRng::new()?).fill(dest),
+        }
+    })
+}
+
+/// Reads entropy from the installed source, or from the operating system if there is none.
+///
+/// 从已安装的熵源读取熵，如果没有则从操作系统读取。
+fn read_entropy(dest: &mut [u8]) -> Result<(), RngError> {
+    let installed = *ENTROPY_SOURCE.read();
+    match installed {
+        Some(source) => source
+            .fill_entropy(dest)
+            .map_err(|_| RngError::from(ENTROPY_UNAVAILABLE)),
+        None => system_entropy(dest),
+    }
+}
+
+#[cfg(feature = "getrandom")]
+fn system_entropy(dest: &mut [u8]) -> Result<(), RngError> {
+    getrandom::fill(dest).map_err(|_| RngError::from(ENTROPY_UNAVAILABLE))
+}
+
+#[cfg(not(feature = "getrandom"))]
+fn system_entropy(_dest: &mut [u8]) -> Result<(), RngError> {
+    Err(RngError::from(ENTROPY_UNAVAILABLE))
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_system_rng() {
+        let mut first = [0u8; 32];
+        let mut second = [0u8; 32];
+        assert!(fill(&mut first).is_ok());
+        assert!(fill(&mut second).is_ok());
+        assert_ne!(first, second);
+        assert_ne!(first, [0u8; 32]);
+    }
+
+    static COUNTER: AtomicU64 = AtomicU64::new(1);
+
+    fn counter_source(dest: &mut [u8]) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(8) {
+            let next = COUNTER
+                .fetch_add(1, Ordering::Relaxed)
+                .wrapping_mul(0x9e37_79b9_7f4a_7c15);
+            chunk.copy_from_slice(&next.to_le_bytes()[..chunk.len()]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_install_entropy_source() {
+        let mut first = [0u8; 32];
+        let mut second = [0u8; 32];
+        // Without `getrandom` nothing can be generated until a source is installed.
+        #[cfg(not(feature = "getrandom"))]
+        assert!(fill(&mut first).is_err());
+
+        install_entropy_source(&counter_source);
+        assert!(has_entropy_source());
+        let before = COUNTER.load(Ordering::Relaxed);
+        assert!(fill(&mut first).is_ok());
+        assert!(fill(&mut second).is_ok());
+        assert!(COUNTER.load(Ordering::Relaxed) > before);
+        assert_ne!(first, second);
+
+        uninstall_entropy_source();
+        assert!(!has_entropy_source());
+    }
+
+    #[test]
+    #[cfg(feature = "hmac-default")]
+    fn test_hmac_drbg_cavp() {
+        // NIST CAVP HMAC_DRBG, SHA-256, no prediction resistance, no reseed, COUNT = 0
+        let entropy: [u8; 32] =
+            hex::decode("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let nonce: [u8; 16] = hex::decode("659ba96c601dc69fc902940805ec0ca8")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let mut drbg = HmacDrbg::new(&entropy, &nonce, &[]);
+        let mut output = [0u8; 128];
+        drbg.generate(&mut output, &[]).unwrap();
+        drbg.generate(&mut output, &[]).unwrap();
+        assert_eq!(
+            hex::encode(output),
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89\
+             d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1\
+             07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668\
+             961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fast-rng-default")]
+    fn test_fast_rng_key_erasure() {
+        let key = [0x24u8; 32];
+        let mut rng = FastRng::from_key(&key);
+        let mut first = [0u8; 40];
+        let mut second = [0u8; 16];
+        rng.fill(&mut first).unwrap();
+        rng.fill(&mut second).unwrap();
+
+        // The output is the keystream, and the key is replaced by the 32 bytes that follow it.
+        let mut stream = [0u8; 72];
+        ChaCha20::new(&key.into(), &[0u8; 12].into()).apply_keystream(&mut stream);
+        assert_eq!(first, stream[..40]);
+        let next_key: [u8; 32] = stream[40..].try_into().unwrap();
+        let mut expected = [0u8; 16];
+        ChaCha20::new(&next_key.into(), &[0u8; 12].into()).apply_keystream(&mut expected);
+        assert_eq!(second, expected);
+        assert_eq!(rng.remaining, FastRng::RESEED_INTERVAL - 56);
+    }
+
+    #[test]
+    #[cfg(feature = "fast-rng-default")]
+    fn test_fast_rng_reseed() {
+        let key = [0x42u8; 32];
+        let mut plain = FastRng::from_key(&key);
+        let mut reseeded = FastRng::from_key(&key);
+        reseeded.reseed().unwrap();
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        plain.fill(&mut a).unwrap();
+        reseeded.fill(&mut b).unwrap();
+        assert_ne!(a, b);
+
+        // Requests larger than the reseed interval are split and reseeded in between.
+        let mut large = vec![0u8; FastRng::RESEED_INTERVAL + 100];
+        plain.fill(&mut large).unwrap();
+        assert_eq!(plain.remaining, FastRng::RESEED_INTERVAL - 100);
+        assert_ne!(large[..32], large[FastRng::RESEED_INTERVAL..][..32]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "fast-rng-default", feature = "std", unix))]
+    fn test_fast_rng_fork_detection() {
+        let key = [0x17u8; 32];
+        let mut parent = FastRng::from_key(&key);
+        let mut child = FastRng::from_key(&key);
+        // Pretend the generator was created in another process.
+        child.pid = child.pid.wrapping_add(1);
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        parent.fill(&mut a).unwrap();
+        child.fill(&mut b).unwrap();
+        assert_ne!(a, b);
+        assert_eq!(child.pid, std::process::id());
+    }
+
+    #[test]
+    #[cfg(all(feature = "fast-rng-default", feature = "getrandom", feature = "std"))]
+    fn test_fill_fast() {
+        let mut rng = FastRng::new().unwrap();
+        assert_ne!(rng.next_u64(), rng.next_u64());
+
+        let mut first = [0u8; 24];
+        let mut second = [0u8; 24];
+        fill_fast(&mut first).unwrap();
+        fill_fast(&mut second).unwrap();
+        assert_ne!(first, second);
+        assert!(fill_nonce(&mut first).is_ok());
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    #[cfg(feature = "hmac-default")]
+    fn test_hmac_drbg_limits() {
+        let mut drbg = HmacDrbg::new(&[7u8; 32], &[9u8; 16], b"test");
+        let mut large = vec![0u8; HmacDrbg::MAX_REQUEST_SIZE + 1];
+        assert_eq!(
+            drbg.generate(&mut large, &[]),
+            Err(Error::Key(KeyError::GenerationFailed))
+        );
+        // `RngCore` splits large requests into permitted chunks.
+        drbg.fill_bytes(&mut large);
+
+        let mut reseeded = drbg.clone();
+        reseeded.reseed(&[1u8; 32], &[]);
+        let mut a = [0u8; 16];
+        let mut b = [0u8; 16];
+        drbg.fill_bytes(&mut a);
+        reseeded.fill_bytes(&mut b);
+        assert_ne!(a, b);
+        assert!(!drbg.needs_reseed());
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! This module provides high-level, user-friendly interfaces to various cryptographic schemes.
 //! It organizes cryptographic functionality into logical categories such as asymmetric cryptography,
-//! symmetric cryptography, key derivation functions, hash functions, and extendable-output functions.
+//! symmetric cryptography, key derivation functions, hash functions, message authentication codes,
+//! one-time passwords, oblivious pseudorandom functions, password-authenticated key exchanges, secure messaging, NaCl compatibility, JOSE, hierarchical deterministic keys,
+//! commitments, secret sharing, threshold signatures, verifiable random functions, keystores,
+//! X.509 certificates and extendable-output functions.
 //!
 //! Each submodule contains concrete implementations that users can directly import and use
 //! without needing to understand the underlying implementation details.
@@ -10,13 +13,28 @@
 //! 面向用户的加密操作方案。
 //!
 //! 此模块为各种加密方案提供了高级的、用户友好的接口。
-//! 它将加密功能组织为逻辑类别，如非对称密码学、对称密码学、密钥派生函数、哈希函数和可扩展输出函数。
+//! 它将加密功能组织为逻辑类别，如非对称密码学、对称密码学、密钥派生函数、哈希函数、消息认证码、一次性密码、不经意伪随机函数、口令认证密钥交换、安全消息、NaCl 兼容、JOSE、分层确定性密钥、承诺、秘密共享、门限签名、可验证随机函数、密钥库、X.509 证书和可扩展输出函数。
 //!
 //! 每个子模块都包含用户可以直接导入和使用的具体实现，
 //! 而无需了解底层实现细节。
 
 pub mod asymmetric;
+pub mod commitment;
+pub mod formats;
 pub mod hash;
+pub mod hd;
+pub mod jose;
 pub mod kdf;
+pub mod keystore;
+pub mod mac;
+pub mod messaging;
+pub mod nacl;
+pub mod oprf;
+pub mod otp;
+pub mod pake;
+pub mod secret_sharing;
+pub mod threshold;
+pub mod vrf;
+pub mod x509;
 pub mod aead;
 pub mod xof;
@@ -7,6 +7,7 @@
 //! # Available Schemes
 //! - **AES-GCM**: Advanced Encryption Standard with Galois/Counter Mode
 //! - **ChaCha20-Poly1305**: ChaCha20 stream cipher with Poly1305 authenticator
+//! - **SM4-GCM / SM4-CCM**: SM4 block cipher in Galois/Counter or CCM mode
 //!
 //! # Security Considerations
 //! - Always use unique nonces for each encryption operation with the same key
@@ -21,6 +22,7 @@
 //! # 可用方案
 //! - **AES-GCM**: 高级加密标准与伽罗瓦/计数器模式
 //! - **ChaCha20-Poly1305**: ChaCha20 流密码与 Poly1305 认证器
+//! - **SM4-GCM / SM4-CCM**: 伽罗瓦/计数器模式或 CCM 模式下的 SM4 分组密码
 //!
 //! # 安全考虑
 //! - 对于同一密钥的每次加密操作，始终使用唯一的 nonce
@@ -58,3 +60,18 @@ pub mod chacha20_poly1305 {
     pub use crate::systems::aead::chacha20_poly1305::*;
     pub use chacha20poly1305::aead::Nonce;
 }
+
+/// SM4 authenticated encryption.
+///
+/// SM4-GCM and SM4-CCM (RFC 8998) run the SM4 block cipher of the Chinese commercial
+/// cryptography suite in Galois/Counter and CCM mode, for deployments that must use the
+/// SM algorithms.
+///
+/// SM4 认证加密。
+///
+/// SM4-GCM 和 SM4-CCM (RFC 8998) 以伽罗瓦/计数器模式和 CCM 模式运行中国商用密码套件中的
+/// SM4 分组密码，适用于必须使用 SM 算法的部署。
+#[cfg(feature = "sm-default")]
+pub mod sm4 {
+    pub use crate::systems::aead::sm4::*;
+}
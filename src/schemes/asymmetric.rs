@@ -29,6 +29,14 @@ pub mod traditional {
         #[cfg(feature = "ecdh-default")]
         pub use crate::systems::asymmetric::traditional::ecdh::*;
     }
+
+    /// SM2 signatures and public-key encryption.
+    ///
+    /// SM2 签名和公钥加密。
+    #[cfg(feature = "sm-default")]
+    pub mod sm2 {
+        pub use crate::systems::asymmetric::traditional::sm2::*;
+    }
 }
 
 /// Post-quantum cryptography schemes
@@ -0,0 +1,27 @@
+//! Commitment schemes.
+//!
+//! This module provides hash-based commitments for sealed-bid auctions, coin flips and
+//! other protocols where a value must be fixed before it is revealed. Committing returns a
+//! public [`Commitment`](hash::Commitment) and a secret [`Opening`](hash::Opening); the
+//! opening and the message are revealed later and checked against the commitment in
+//! constant time.
+//!
+//! # Available Schemes
+//! - **Hash commitments**: `H(r || m)` with a 32-byte random blinding factor, over SHA-256/512
+//!
+//! 承诺方案。
+//!
+//! 此模块为密封出价拍卖、抛硬币以及其他需要在公开前固定某个值的协议提供基于哈希的承诺。
+//! 作出承诺会返回公开的 [`Commitment`](hash::Commitment) 和秘密的 [`Opening`](hash::Opening)；
+//! 之后公开打开值和消息，并以常量时间根据承诺对它们进行检查。
+//!
+//! # 可用方案
+//! - **哈希承诺**: 使用 32 字节随机盲化因子的 `H(r || m)`，基于 SHA-256/512
+
+/// Hash-based commitments.
+///
+/// 基于哈希的承诺。
+#[cfg(feature = "sha2")]
+pub mod hash {
+    pub use crate::systems::commitment::hash::*;
+}
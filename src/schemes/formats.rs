@@ -0,0 +1,107 @@
+//! Interoperable signature and key formats.
+//!
+//! This module provides access to external formats that wrap the keys and signatures
+//! produced by this crate's schemes, for exchange with other tools.
+//!
+//! # Available Formats
+//! - **sshsig**: OpenSSH signature format, compatible with `ssh-keygen -Y sign/verify`
+//! - **openssh**: OpenSSH key files, compatible with `ssh-keygen` and `authorized_keys`
+//! - **encrypted_pkcs8**: Password-protected PKCS#8 private keys, readable by OpenSSL
+//! - **phc**: PHC strings for storing and verifying password hashes
+//! - **jwk**: JSON Web Keys and JWK Sets for exchanging keys, e.g. with OpenID Connect providers
+//! - **bip39**: Mnemonic phrases for writing down root seeds and restoring them
+//! - **bech32**: Checksummed key identifiers with an application-chosen prefix, e.g. `sealpk1...`
+//! - **base58check**: Checksummed key identifiers in the Bitcoin address alphabet
+//! - **cbor**: Compact algorithm-tagged binary form for constrained transports
+//!
+//! 可互操作的签名和密钥格式。
+//!
+//! 此模块提供对外部格式的访问，这些格式包装了本 crate 方案产生的密钥和签名，
+//! 以便与其他工具进行交换。
+//!
+//! # 可用格式
+//! - **sshsig**: OpenSSH 签名格式，与 `ssh-keygen -Y sign/verify` 兼容
+//! - **openssh**: OpenSSH 密钥文件，与 `ssh-keygen` 和 `authorized_keys` 兼容
+//! - **encrypted_pkcs8**: 受密码保护的 PKCS#8 私钥，可由 OpenSSL 读取
+//! - **phc**: 用于存储和验证密码哈希的 PHC 字符串
+//! - **jwk**: 用于交换密钥（例如与 OpenID Connect 提供方）的 JSON Web Key 和 JWK Set
+//! - **bip39**: 用于手写记录和恢复根种子的助记词
+//! - **bech32**: 带校验和、前缀由应用选择的密钥标识符，例如 `sealpk1...`
+//! - **base58check**: 使用比特币地址字母表、带校验和的密钥标识符
+//! - **cbor**: 适用于受限传输的紧凑、带算法标签的二进制形式
+
+/// OpenSSH `sshsig` signatures for Ed25519, ECDSA P-256 and RSA keys.
+///
+/// 适用于 Ed25519、ECDSA P-256 和 RSA 密钥的 OpenSSH `sshsig` 签名。
+#[cfg(feature = "sshsig-default")]
+pub mod sshsig {
+    pub use crate::systems::formats::sshsig::*;
+}
+
+/// OpenSSH public and private keys for Ed25519, ECDSA P-256 and RSA.
+///
+/// 适用于 Ed25519、ECDSA P-256 和 RSA 的 OpenSSH 公钥和私钥。
+#[cfg(feature = "openssh-default")]
+pub mod openssh {
+    pub use crate::systems::formats::openssh::*;
+}
+
+/// Encrypted PKCS#8 export and import for ECC, ECDH and RSA private keys.
+///
+/// 适用于 ECC、ECDH 和 RSA 私钥的加密 PKCS#8 导出和导入。
+#[cfg(feature = "encrypted-pkcs8-default")]
+pub mod encrypted_pkcs8 {
+    pub use crate::systems::formats::encrypted_pkcs8::*;
+}
+
+/// PHC strings for Argon2, PBKDF2 and scrypt password hashes.
+///
+/// 适用于 Argon2、PBKDF2 和 scrypt 密码哈希的 PHC 字符串。
+#[cfg(any(
+    feature = "pbkdf2-default",
+    feature = "argon2-default",
+    feature = "scrypt-default"
+))]
+pub mod phc {
+    pub use crate::systems::formats::phc::*;
+}
+
+/// JSON Web Keys for RSA, EC (P-256, secp256k1), Ed25519 and symmetric keys.
+///
+/// 适用于 RSA、EC (P-256、secp256k1)、Ed25519 和对称密钥的 JSON Web Key。
+#[cfg(feature = "serde")]
+pub mod jwk {
+    pub use crate::systems::formats::jwk::*;
+}
+
+/// BIP39 mnemonics for Ed25519 and Dilithium seeds and HD master seeds.
+///
+/// 适用于 Ed25519 和 Dilithium 种子以及 HD 主种子的 BIP39 助记词。
+#[cfg(feature = "bip39-default")]
+pub mod bip39 {
+    pub use crate::systems::formats::bip39::*;
+}
+
+/// Bech32 and Bech32m strings for any public key and its fingerprint.
+///
+/// 适用于任意公钥及其指纹的 Bech32 和 Bech32m 字符串。
+#[cfg(feature = "bech32-default")]
+pub mod bech32 {
+    pub use crate::systems::formats::bech32::*;
+}
+
+/// Base58Check strings for any public key and its fingerprint.
+///
+/// 适用于任意公钥及其指纹的 Base58Check 字符串。
+#[cfg(feature = "base58check-default")]
+pub mod base58check {
+    pub use crate::systems::formats::base58check::*;
+}
+
+/// Deterministic CBOR for keys, signatures, ciphertexts and encapsulated keys of any scheme.
+///
+/// 适用于任意方案的密钥、签名、密文和封装密钥的确定性 CBOR。
+#[cfg(feature = "serde")]
+pub mod cbor {
+    pub use crate::systems::formats::cbor::*;
+}
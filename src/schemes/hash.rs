@@ -1,6 +1,7 @@
 //! Hash function schemes.
 //!
-//! This module provides access to various cryptographic hash functions from the SHA-2 family.
+//! This module provides access to cryptographic hash functions from the SHA-2, SHA-3, BLAKE2 and
+//! BLAKE3 families.
 //! These hash functions are widely used for data integrity verification, digital signatures,
 //! and other cryptographic applications.
 //!
@@ -8,24 +9,52 @@
 //! - **SHA-256**: 256-bit output, suitable for most applications
 //! - **SHA-384**: 384-bit output, provides higher security margin
 //! - **SHA-512**: 512-bit output, highest security level in SHA-2 family
+//! - **SHA3-256/384/512**: SHA-3 (Keccak) digests, in the [`sha3`] submodule
+//! - **BLAKE2b/BLAKE2s**: keyed, salted and personalized hashing, in the [`blake2`] submodule
+//! - **BLAKE3**: hash, keyed_hash and derive_key modes with extendable output, in the [`blake3`]
+//!   submodule
+//! - **SM3**: the Chinese national hash standard, in the `sm3` submodule
+//!
+//! The marker types re-exported at this level select the hash used by other schemes
+//! (e.g. `RsaScheme<KP, Sha256>`). To hash data directly, use the types in the
+//! [`sha2`] submodule, which implement [`HashFunction`](crate::traits::hash::HashFunction).
+//! All of these hash types also implement
+//! [`MultihashCode`](crate::traits::hash::MultihashCode), which emits self-describing
+//! [`Multihash`](crate::traits::hash::Multihash) digests for content-addressed storage.
+//! The [`merkle`] submodule builds RFC 6962 Merkle trees and inclusion proofs from any of them.
 //!
 //! # Security Considerations
 //! All provided hash functions are cryptographically secure and resistant to collision attacks.
 //! Choose the appropriate hash function based on your security requirements and performance needs.
+//! The exception is the `legacy` submodule, enabled by the `insecure-legacy-hashes` feature: its
+//! SHA-1 and MD5 are broken and exist only to verify legacy data.
 //!
 //! 哈希函数方案。
 //!
-//! 此模块提供对 SHA-2 系列各种加密哈希函数的访问。
+//! 此模块提供对 SHA-2、SHA-3、BLAKE2 和 BLAKE3 系列加密哈希函数的访问。
 //! 这些哈希函数广泛用于数据完整性验证、数字签名和其他加密应用。
 //!
 //! # 可用的哈希函数
 //! - **SHA-256**: 256 位输出，适用于大多数应用
 //! - **SHA-384**: 384 位输出，提供更高的安全边际
 //! - **SHA-512**: 512 位输出，SHA-2 系列中的最高安全级别
+//! - **SHA3-256/384/512**: SHA-3 (Keccak) 摘要，位于 [`sha3`] 子模块中
+//! - **BLAKE2b/BLAKE2s**: 带密钥、加盐和个性化的哈希，位于 [`blake2`] 子模块中
+//! - **BLAKE3**: 支持可扩展输出的 hash、keyed_hash 和 derive_key 模式，位于 [`blake3`] 子模块中
+//! - **SM3**: 中国国家哈希标准，位于 `sm3` 子模块中
+//!
+//! 此层级重新导出的标记类型用于选择其他方案所使用的哈希（例如 `RsaScheme<KP, Sha256>`）。
+//! 要直接对数据进行哈希，请使用 [`sha2`] 子模块中的类型，它们实现了
+//! [`HashFunction`](crate::traits::hash::HashFunction)。
+//! 所有这些哈希类型还实现了 [`MultihashCode`](crate::traits::hash::MultihashCode)，
+//! 它为内容寻址存储生成自描述的 [`Multihash`](crate::traits::hash::Multihash) 摘要。
+//! [`merkle`] 子模块可基于其中任意一种构建 RFC 6962 Merkle 树和包含证明。
 //!
 //! # 安全考虑
 //! 所有提供的哈希函数都是加密安全的，能够抵抗碰撞攻击。
 //! 根据您的安全要求和性能需求选择合适的哈希函数。
+//! 例外是由 `insecure-legacy-hashes` 特性启用的 `legacy` 子模块：其中的 SHA-1 和 MD5 已被攻破，
+//! 仅用于验证旧数据。
 
 /// SHA-256 hash function.
 ///
@@ -44,3 +73,55 @@ pub use crate::traits::params::hash::Sha384;
 /// SHA-512 哈希函数。
 #[cfg(feature = "sha2")]
 pub use crate::traits::params::hash::Sha512;
+
+/// SHA-2 hash functions with one-shot and incremental hashing.
+///
+/// 支持一次性和增量哈希的 SHA-2 哈希函数。
+#[cfg(feature = "sha2")]
+pub mod sha2 {
+    pub use crate::systems::hash::sha2::*;
+}
+
+/// SHA-3 hash functions with one-shot and incremental hashing.
+///
+/// 支持一次性和增量哈希的 SHA-3 哈希函数。
+#[cfg(feature = "sha3")]
+pub mod sha3 {
+    pub use crate::systems::hash::sha3::*;
+}
+
+/// BLAKE2b and BLAKE2s hash functions, including keyed, salted and personalized modes.
+///
+/// BLAKE2b 和 BLAKE2s 哈希函数，包括带密钥、加盐和个性化模式。
+#[cfg(feature = "blake2-default")]
+pub mod blake2 {
+    pub use crate::systems::hash::blake2::*;
+}
+
+/// BLAKE3 in its hash, keyed_hash and derive_key modes, with extendable output.
+///
+/// BLAKE3 的 hash、keyed_hash 和 derive_key 模式，支持可扩展输出。
+#[cfg(feature = "blake3-default")]
+pub mod blake3 {
+    pub use crate::systems::hash::blake3::*;
+}
+
+/// The SM3 hash function.
+///
+/// SM3 哈希函数。
+#[cfg(feature = "sm-default")]
+pub mod sm3 {
+    pub use crate::systems::hash::sm3::*;
+}
+
+/// RFC 6962 Merkle trees with inclusion proofs, generic over the hash function.
+///
+/// 具有包含证明的 RFC 6962 Merkle 树，在哈希函数上是通用的。
+#[cfg(feature = "insecure-legacy-hashes")]
+pub mod legacy {
+    pub use crate::systems::hash::legacy::*;
+}
+
+pub mod merkle {
+    pub use crate::systems::hash::merkle::*;
+}
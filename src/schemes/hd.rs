@@ -0,0 +1,24 @@
+//! Hierarchical deterministic (HD) key derivation.
+//!
+//! This module derives trees of ECC key pairs from a single master seed along BIP32-style
+//! paths such as `m/44'/0'/0'`. Extended private keys derive any child; extended public keys
+//! derive the non-hardened children of secp256k1 and P-256 keys without the private key.
+//!
+//! # Available Schemes
+//! - **SLIP-0010**: BIP32 over secp256k1, plus the SLIP-0010 variants for P-256 and Ed25519
+//!
+//! 分层确定性 (HD) 密钥派生。
+//!
+//! 此模块沿 `m/44'/0'/0'` 等 BIP32 风格的路径，从单个主种子派生 ECC 密钥对树。
+//! 扩展私钥可以派生任何子密钥；扩展公钥无需私钥即可派生 secp256k1 和 P-256 密钥的非强化子密钥。
+//!
+//! # 可用方案
+//! - **SLIP-0010**: 基于 secp256k1 的 BIP32，以及用于 P-256 和 Ed25519 的 SLIP-0010 变体
+
+/// SLIP-0010 (and BIP32) key derivation.
+///
+/// SLIP-0010（及 BIP32）密钥派生。
+#[cfg(feature = "hd-default")]
+pub mod slip10 {
+    pub use crate::systems::hd::slip10::*;
+}
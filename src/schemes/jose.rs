@@ -0,0 +1,34 @@
+//! JSON Object Signing and Encryption (JOSE) schemes.
+//!
+//! This module provides JWS for issuing and verifying JSON Web Tokens and other signed JSON
+//! payloads, with keys from this crate's signature schemes or from JWK Sets, and JWE for
+//! exchanging encrypted payloads.
+//!
+//! # Available Schemes
+//! - **jws**: Compact JWS signing and verification with algorithm allow-lists
+//! - **jwe**: Compact JWE encryption and decryption with ECDH-ES and RSA-OAEP key management
+//!
+//! JSON 对象签名和加密 (JOSE) 方案。
+//!
+//! 此模块提供 JWS，用于签发和验证 JSON Web Token 及其他已签名的 JSON 载荷，密钥来自本 crate
+//! 的签名方案或 JWK Set；还提供 JWE，用于交换加密的载荷。
+//!
+//! # 可用方案
+//! - **jws**: 带算法允许列表的紧凑 JWS 签名和验证
+//! - **jwe**: 使用 ECDH-ES 和 RSA-OAEP 密钥管理的紧凑 JWE 加密和解密
+
+/// JSON Web Signatures.
+///
+/// JSON Web Signature。
+#[cfg(feature = "jws-default")]
+pub mod jws {
+    pub use crate::systems::jose::jws::*;
+}
+
+/// JSON Web Encryption.
+///
+/// JSON Web Encryption。
+#[cfg(feature = "jwe-default")]
+pub mod jwe {
+    pub use crate::systems::jose::jwe::*;
+}
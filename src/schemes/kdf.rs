@@ -8,6 +8,7 @@
 //! - **HKDF**: HMAC-based KDF, suitable for deriving keys from high-entropy sources
 //! - **PBKDF2**: Password-based KDF, designed for deriving keys from passwords
 //! - **Argon2**: Modern password hashing function, resistant to various attacks
+//! - **scrypt**: Memory-hard password-based KDF, for compatibility with existing systems
 //!
 //! # Security Considerations
 //! - Use HKDF when you have high-entropy input keying material
@@ -23,6 +24,7 @@
 //! - **HKDF**: 基于 HMAC 的 KDF，适用于从高熵源派生密钥
 //! - **PBKDF2**: 基于密码的 KDF，专为从密码派生密钥而设计
 //! - **Argon2**: 现代密码哈希函数，能够抵抗各种攻击
+//! - **scrypt**: 内存困难的基于密码的 KDF，用于与现有系统兼容
 //!
 //! # 安全考虑
 //! - 当您有高熵输入密钥材料时使用 HKDF
@@ -59,3 +61,95 @@ pub mod pbkdf2 {
 pub mod argon2 {
     pub use crate::systems::kdf::argon2::*;
 }
+
+/// scrypt password-based key derivation function.
+///
+/// scrypt is a memory-hard password-based KDF (RFC 7914). It is provided mainly to verify
+/// keys derived by existing systems; prefer Argon2 for new applications.
+///
+/// scrypt 基于密码的密钥派生函数。
+///
+/// scrypt 是一种内存困难的基于密码的 KDF (RFC 7914)。提供它主要是为了验证现有系统派生的密钥；
+/// 新应用程序请优先使用 Argon2。
+#[cfg(feature = "scrypt-default")]
+pub mod scrypt {
+    pub use crate::systems::kdf::scrypt::*;
+}
+
+/// NIST SP 800-108 key-based KDF (KBKDF).
+///
+/// KBKDF derives keys from a uniformly random key in counter or feedback mode, using any
+/// MAC in the crate, such as HMAC or AES-CMAC, as the PRF.
+///
+/// NIST SP 800-108 基于密钥的 KDF (KBKDF)。
+///
+/// KBKDF 以计数器或反馈模式从均匀随机的密钥派生密钥，并使用本 crate 中的任意 MAC
+/// （例如 HMAC 或 AES-CMAC）作为 PRF。
+pub mod kbkdf {
+    pub use crate::systems::kdf::kbkdf::*;
+}
+
+/// ANSI X9.63 key derivation function.
+///
+/// The hash-based KDF with a SharedInfo parameter required by ECIES and many smart-card
+/// protocols.
+///
+/// ANSI X9.63 密钥派生函数。
+///
+/// 带有 SharedInfo 参数的基于哈希的 KDF，为 ECIES 和许多智能卡协议所要求。
+#[cfg(feature = "sha2")]
+pub mod x963 {
+    pub use crate::systems::kdf::x963::*;
+}
+
+/// NIST SP 800-56C one-step key derivation function (ConcatKDF).
+///
+/// The hash-based KDF applied after ECDH, including the `FixedInfo` construction used by
+/// JOSE ECDH-ES.
+///
+/// NIST SP 800-56C 单步密钥派生函数 (ConcatKDF)。
+///
+/// 在 ECDH 之后应用的基于哈希的 KDF，包括 JOSE ECDH-ES 所用的 `FixedInfo` 构造。
+#[cfg(feature = "sha2")]
+pub mod concat {
+    pub use crate::systems::kdf::concat::*;
+}
+
+/// A key schedule deriving several labeled subkeys from one secret.
+///
+/// Each label is domain-separated in the `info` passed to the KDF, so related keys such as
+/// "enc", "mac" and "iv" never collide.
+///
+/// 从一个秘密派生多个带标签子密钥的密钥调度。
+///
+/// 每个标签都在传给 KDF 的 `info` 中进行域分离，因此 "enc"、"mac" 和 "iv" 等相关密钥永远不会冲突。
+pub mod schedule {
+    pub use crate::systems::kdf::schedule::*;
+}
+
+/// HChaCha20 and HSalsa20 subkey derivation.
+///
+/// These are the first step of XChaCha20 and XSalsa20. `hchacha20` requires the
+/// `chacha20-poly1305` feature and `hsalsa20` the `crypto-box` feature.
+///
+/// HChaCha20 和 HSalsa20 子密钥派生。
+///
+/// 它们是 XChaCha20 和 XSalsa20 的第一步。`hchacha20` 需要 `chacha20-poly1305` 特性，
+/// `hsalsa20` 需要 `crypto-box` 特性。
+pub mod subkey {
+    pub use crate::systems::kdf::subkey::*;
+}
+
+/// libsodium-compatible `crypto_kdf` subkey derivation.
+///
+/// Derives numbered subkeys from a master key and an 8-byte context with keyed BLAKE2b,
+/// matching `crypto_kdf_derive_from_key`.
+///
+/// 与 libsodium 兼容的 `crypto_kdf` 子密钥派生。
+///
+/// 使用带密钥的 BLAKE2b 从主密钥和 8 字节上下文派生带编号的子密钥，与
+/// `crypto_kdf_derive_from_key` 一致。
+#[cfg(feature = "blake2-default")]
+pub mod crypto_kdf {
+    pub use crate::systems::kdf::crypto_kdf::*;
+}
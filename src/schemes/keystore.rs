@@ -0,0 +1,23 @@
+//! Keystore files holding private keys and certificates.
+//!
+//! This module provides access to keystore containers, for loading credentials delivered by
+//! other tools into the crate's key types and for exporting them again.
+//!
+//! # Available Keystores
+//! - **pkcs12**: PKCS#12 `.p12` / `.pfx` files, compatible with OpenSSL, Windows and Java
+//!
+//! 保存私钥和证书的密钥库文件。
+//!
+//! 此模块提供对密钥库容器的访问，用于将其他工具交付的凭据加载到本 crate 的密钥类型中，
+//! 以及再次导出它们。
+//!
+//! # 可用密钥库
+//! - **pkcs12**: PKCS#12 `.p12` / `.pfx` 文件，与 OpenSSL、Windows 和 Java 兼容
+
+/// PKCS#12 keystores for ECC, ECDH and RSA private keys and X.509 certificates.
+///
+/// 适用于 ECC、ECDH 和 RSA 私钥以及 X.509 证书的 PKCS#12 密钥库。
+#[cfg(feature = "pkcs12-default")]
+pub mod pkcs12 {
+    pub use crate::systems::keystore::pkcs12::*;
+}
@@ -0,0 +1,56 @@
+//! Message authentication code (MAC) schemes.
+//!
+//! This module provides access to MACs, which let two parties sharing a secret key
+//! detect any modification of a message. Every scheme implements
+//! [`Mac`](crate::traits::mac::Mac); use its `verify` method, which compares tags in
+//! constant time, instead of comparing tags by hand.
+//!
+//! # Available MACs
+//! - **HMAC-SHA-256/384/512**: Hash-based MACs over the SHA-2 family
+//! - **AES-128/256-CMAC**: Block-cipher-based MACs (OMAC1)
+//! - **KMAC128/256**: Keccak-based MACs with customization strings
+//! - **AES-128/256-GMAC**: AES-GCM over additional data only; takes a nonce
+//!
+//! 消息认证码 (MAC) 方案。
+//!
+//! 此模块提供对 MAC 的访问，使共享秘密密钥的双方能够检测到对消息的任何修改。
+//! 每个方案都实现了 [`Mac`](crate::traits::mac::Mac)；请使用其以常量时间比较标签的 `verify` 方法，
+//! 而不是手动比较标签。
+//!
+//! # 可用的 MAC
+//! - **HMAC-SHA-256/384/512**: 基于 SHA-2 系列的哈希 MAC
+//! - **AES-128/256-CMAC**: 基于分组密码的 MAC (OMAC1)
+//! - **KMAC128/256**: 支持定制字符串的基于 Keccak 的 MAC
+//! - **AES-128/256-GMAC**: 仅对附加数据进行 AES-GCM 认证；需要 nonce
+
+/// HMAC (keyed-hash message authentication code).
+///
+/// HMAC（基于密钥的哈希消息认证码）。
+#[cfg(feature = "hmac-default")]
+pub mod hmac {
+    pub use crate::systems::mac::hmac::*;
+}
+
+/// AES-CMAC (cipher-based message authentication code).
+///
+/// AES-CMAC（基于分组密码的消息认证码）。
+#[cfg(feature = "cmac-default")]
+pub mod cmac {
+    pub use crate::systems::mac::cmac::*;
+}
+
+/// KMAC (Keccak message authentication code, NIST SP 800-185).
+///
+/// KMAC（Keccak 消息认证码，NIST SP 800-185）。
+#[cfg(feature = "shake-default")]
+pub mod kmac {
+    pub use crate::systems::mac::kmac::*;
+}
+
+/// AES-GMAC (Galois message authentication code, NIST SP 800-38D).
+///
+/// AES-GMAC（伽罗瓦消息认证码，NIST SP 800-38D）。
+#[cfg(feature = "gmac-default")]
+pub mod gmac {
+    pub use crate::systems::mac::gmac::*;
+}
@@ -0,0 +1,50 @@
+//! Secure messaging schemes.
+//!
+//! This module provides the key management of end-to-end encrypted conversations, such as
+//! starting a session with a user who is offline from the prekeys they published. Assembling
+//! these protocols from raw key agreements is error-prone: a forgotten signature check or a
+//! reused one-time prekey silently removes their authentication or forward secrecy.
+//!
+//! # Available Schemes
+//! - **X3DH**: the Extended Triple Diffie-Hellman key agreement, which verifies the signed
+//!   prekey and derives the root secret of a session
+//! - **Ratchet**: the symmetric-key and Diffie-Hellman ratchets that evolve the keys of a
+//!   Double Ratchet session, with bounded storage of skipped message keys
+//! - **Signcryption**: envelopes whose signature names their recipient, so that a signed
+//!   message cannot be forwarded to someone else as if it had been written to them
+//!
+//! 安全消息方案。
+//!
+//! 此模块提供端到端加密会话的密钥管理，例如根据离线用户发布的预密钥与其开始会话。从原始密钥
+//! 协商组装这些协议很容易出错：遗漏的签名检查或重复使用的一次性预密钥会悄无声息地破坏其认证或
+//! 前向保密性。
+//!
+//! # 可用方案
+//! - **X3DH**: 扩展三重迪菲-赫尔曼密钥协商，它验证已签名预密钥并派生会话的根秘密
+//! - **Ratchet**: 演进 Double Ratchet 会话密钥的对称密钥棘轮和迪菲-赫尔曼棘轮，对跳过的消息
+//!   密钥的存储有上限
+//! - **Signcryption**: 签名指明接收方的信封，使已签名的消息无法被转发给他人并冒充是写给他们的
+
+/// X3DH key agreement.
+///
+/// X3DH 密钥协商。
+#[cfg(feature = "x3dh-default")]
+pub mod x3dh {
+    pub use crate::systems::messaging::x3dh::*;
+}
+
+/// Symmetric-key and Diffie-Hellman ratchets.
+///
+/// 对称密钥棘轮和迪菲-赫尔曼棘轮。
+#[cfg(feature = "ratchet-default")]
+pub mod ratchet {
+    pub use crate::systems::messaging::ratchet::*;
+}
+
+/// Signcryption.
+///
+/// 签密。
+#[cfg(feature = "signcryption-default")]
+pub mod signcryption {
+    pub use crate::systems::messaging::signcryption::*;
+}
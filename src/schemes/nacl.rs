@@ -0,0 +1,36 @@
+//! NaCl and libsodium compatible schemes.
+//!
+//! This module provides the message formats of NaCl and libsodium, for talking to firmware,
+//! browsers or services that already use them and cannot switch to another format. New
+//! protocols without such a constraint should prefer the AEAD and key agreement schemes.
+//!
+//! # Available Schemes
+//! - **crypto_box**: `crypto_box_easy` and its precomputed `beforenm` form, byte-compatible
+//!   with NaCl, TweetNaCl and libsodium
+//! - **sealed_box**: libsodium's `crypto_box_seal`, anonymous encryption to a public key
+//!
+//! 与 NaCl 和 libsodium 兼容的方案。
+//!
+//! 此模块提供 NaCl 和 libsodium 的消息格式，用于与已经使用它们且无法改用其他格式的固件、
+//! 浏览器或服务通信。没有这种限制的新协议应优先使用 AEAD 和密钥协商方案。
+//!
+//! # 可用方案
+//! - **crypto_box**: `crypto_box_easy` 及其预计算的 `beforenm` 形式，与 NaCl、TweetNaCl 和
+//!   libsodium 字节兼容
+//! - **sealed_box**: libsodium 的 `crypto_box_seal`，对公钥的匿名加密
+
+/// NaCl crypto_box public-key authenticated encryption.
+///
+/// NaCl crypto_box 公钥认证加密。
+#[cfg(feature = "crypto-box-default")]
+pub mod crypto_box {
+    pub use crate::systems::nacl::crypto_box::*;
+}
+
+/// libsodium sealed boxes.
+///
+/// libsodium 密封盒。
+#[cfg(feature = "sealed-box-default")]
+pub mod sealed_box {
+    pub use crate::systems::nacl::sealed_box::*;
+}
@@ -0,0 +1,26 @@
+//! Oblivious pseudorandom function schemes.
+//!
+//! This module provides keyed pseudorandom functions that a client evaluates jointly with the
+//! server holding the key. The server never sees the client's input or the output, which makes
+//! OPRFs the building block for password hardening services, private set intersection and
+//! asymmetric password-authenticated key exchanges such as OPAQUE.
+//!
+//! # Available Schemes
+//! - **ristretto255-SHA512**: the OPRF mode of RFC 9497 over ristretto255 with SHA-512
+//!
+//! 不经意伪随机函数方案。
+//!
+//! 此模块提供由客户端与持有密钥的服务器共同计算的带密钥伪随机函数。服务器永远看不到客户端的
+//! 输入或输出，这使 OPRF 成为口令加固服务、隐私集合求交以及 OPAQUE 等非对称口令认证密钥交换的
+//! 构建模块。
+//!
+//! # 可用方案
+//! - **ristretto255-SHA512**: 基于 ristretto255 和 SHA-512 的 RFC 9497 OPRF 模式
+
+/// The ristretto255-SHA512 OPRF.
+///
+/// ristretto255-SHA512 OPRF。
+#[cfg(feature = "oprf-default")]
+pub mod ristretto255 {
+    pub use crate::systems::oprf::ristretto255::*;
+}
@@ -0,0 +1,36 @@
+//! One-time password (OTP) schemes.
+//!
+//! This module provides HOTP and TOTP for two-factor authentication codes, built on the
+//! crate's HMAC support. Codes are verified in constant time, and verification accepts a
+//! configurable window of counters or time steps to tolerate drift.
+//!
+//! # Available Schemes
+//! - **HOTP**: Counter-based codes (RFC 4226) over HMAC-SHA-256/512, and over HMAC-SHA-1 with
+//!   the `insecure-legacy-hashes` feature
+//! - **TOTP**: Time-based codes (RFC 6238) with configurable digits, period and skew
+//!
+//! 一次性密码 (OTP) 方案。
+//!
+//! 此模块提供基于本 crate HMAC 支持的 HOTP 和 TOTP，用于双因素认证码。码以常量时间验证，
+//! 并且验证时接受可配置的计数器或时间步窗口，以容忍漂移。
+//!
+//! # 可用方案
+//! - **HOTP**: 基于 HMAC-SHA-256/512 的计数器码 (RFC 4226)；启用 `insecure-legacy-hashes` 特性时
+//!   也可基于 HMAC-SHA-1
+//! - **TOTP**: 位数、周期和偏差可配置的时间码 (RFC 6238)
+
+/// HOTP (HMAC-based one-time password, RFC 4226).
+///
+/// HOTP（基于 HMAC 的一次性密码，RFC 4226）。
+#[cfg(feature = "otp-default")]
+pub mod hotp {
+    pub use crate::systems::otp::hotp::*;
+}
+
+/// TOTP (time-based one-time password, RFC 6238).
+///
+/// TOTP（基于时间的一次性密码，RFC 6238）。
+#[cfg(feature = "otp-default")]
+pub mod totp {
+    pub use crate::systems::otp::totp::*;
+}
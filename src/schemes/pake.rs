@@ -0,0 +1,52 @@
+//! Password-authenticated key exchange schemes.
+//!
+//! This module provides key exchanges authenticated by nothing but a password or pairing code,
+//! such as pairing a phone with a device that shows a six-digit code, or logging in to a server
+//! that must never see the password. A plain key agreement over a password-derived key would
+//! let anyone who records the exchange try passwords offline; these protocols allow one guess
+//! per run with a real party.
+//!
+//! # Available Schemes
+//! - **SPAKE2**: the balanced SPAKE2 (RFC 9382) and augmented SPAKE2+ (RFC 9383) over P-256,
+//!   with key confirmation before the shared secret is released
+//! - **SRP**: the augmented SRP-6a (RFC 5054) with the RFC 5054 groups, for compatibility with
+//!   existing deployments
+//! - **OPAQUE**: the augmented OPAQUE-3DH (RFC 9807) over ristretto255, where the server never
+//!   sees the password and its records resist precomputation
+//!
+//! 口令认证密钥交换方案。
+//!
+//! 此模块提供仅由口令或配对码认证的密钥交换，例如将手机与显示六位数字代码的设备配对，或登录
+//! 一个绝不能看到口令的服务器。基于口令派生密钥的普通密钥协商会让任何记录了交换过程的人离线
+//! 尝试口令；这些协议每次与真实的一方运行只允许一次猜测。
+//!
+//! # 可用方案
+//! - **SPAKE2**: 基于 P-256 的平衡 SPAKE2 (RFC 9382) 和增强 SPAKE2+ (RFC 9383)，在释放共享
+//!   秘密之前进行密钥确认
+//! - **SRP**: 使用 RFC 5054 群的增强 SRP-6a (RFC 5054)，用于兼容现有部署
+//! - **OPAQUE**: 基于 ristretto255 的增强 OPAQUE-3DH (RFC 9807)，服务器永远看不到口令，其记录
+//!   能够抵御预计算攻击
+
+/// SPAKE2 and SPAKE2+ password-authenticated key exchanges.
+///
+/// SPAKE2 和 SPAKE2+ 口令认证密钥交换。
+#[cfg(feature = "spake2-default")]
+pub mod spake2 {
+    pub use crate::systems::pake::spake2::*;
+}
+
+/// SRP-6a password-authenticated key exchange.
+///
+/// SRP-6a 口令认证密钥交换。
+#[cfg(feature = "srp-default")]
+pub mod srp {
+    pub use crate::systems::pake::srp::*;
+}
+
+/// OPAQUE augmented password-authenticated key exchange.
+///
+/// OPAQUE 增强口令认证密钥交换。
+#[cfg(feature = "opaque-default")]
+pub mod opaque {
+    pub use crate::systems::pake::opaque::*;
+}
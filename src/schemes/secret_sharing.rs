@@ -0,0 +1,38 @@
+//! Secret sharing schemes.
+//!
+//! This module provides threshold secret sharing for keys that no single person may hold,
+//! such as root keys split across officers. [`split`](shamir::split) turns a secret into
+//! shares, any `threshold` of which are recovered with [`combine`](shamir::combine); fewer
+//! shares reveal nothing about the secret.
+//!
+//! # Available Schemes
+//! - **Shamir**: Shamir secret sharing over GF(256), compatible with SLIP-39 byte shares
+//! - **Feldman VSS**: verifiable secret sharing of P-256 and secp256k1 scalars, whose public
+//!   commitments let each receiver check its share against a dishonest dealer
+//!
+//! 秘密共享方案。
+//!
+//! 此模块为任何单人都不应持有的密钥（例如拆分给多名管理员的根密钥）提供门限秘密共享。
+//! [`split`](shamir::split) 将秘密转换为多个份额，任意 `threshold` 个份额可以通过
+//! [`combine`](shamir::combine) 恢复秘密；更少的份额不会泄露关于秘密的任何信息。
+//!
+//! # 可用方案
+//! - **Shamir**: 基于 GF(256) 的 Shamir 秘密共享，与 SLIP-39 字节份额兼容
+//! - **Feldman VSS**: P-256 和 secp256k1 标量的可验证秘密共享，其公开承诺使每个接收者都能
+//!   检查其份额，以防范不诚实的分发者
+
+/// Feldman verifiable secret sharing.
+///
+/// Feldman 可验证秘密共享。
+#[cfg(all(feature = "secret-sharing-default", feature = "ecc-default"))]
+pub mod feldman {
+    pub use crate::systems::secret_sharing::feldman::*;
+}
+
+/// Shamir secret sharing.
+///
+/// Shamir 秘密共享。
+#[cfg(feature = "secret-sharing-default")]
+pub mod shamir {
+    pub use crate::systems::secret_sharing::shamir::*;
+}
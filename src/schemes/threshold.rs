@@ -0,0 +1,29 @@
+//! Threshold signature schemes.
+//!
+//! This module provides t-of-n signing for keys that must survive the compromise of any single
+//! machine, such as the key of a signing service run across several hosts. The hosts generate
+//! the key together with a distributed key generation, so the group private key never exists,
+//! and any `min_signers` of them produce a signature that verifies under the group public key
+//! like an ordinary Schnorr signature.
+//!
+//! # Available Schemes
+//! - **FROST**: two-round threshold Schnorr signatures (RFC 9591) over ristretto255 and
+//!   secp256k1, with distributed key generation and identification of invalid signature shares
+//!
+//! 门限签名方案。
+//!
+//! 此模块为必须能够承受任意单台机器被攻破的密钥（例如跨多台主机运行的签名服务的密钥）提供
+//! t-of-n 签名。这些主机通过分布式密钥生成共同生成密钥，因此群私钥从不存在，并且其中任意
+//! `min_signers` 台主机生成的签名都可以像普通 Schnorr 签名一样在群公钥下验证。
+//!
+//! # 可用方案
+//! - **FROST**: 基于 ristretto255 和 secp256k1 的两轮门限 Schnorr 签名 (RFC 9591)，带分布式
+//!   密钥生成和无效签名份额的识别
+
+/// FROST threshold Schnorr signatures.
+///
+/// FROST 门限 Schnorr 签名。
+#[cfg(feature = "threshold-default")]
+pub mod frost {
+    pub use crate::systems::threshold::frost::*;
+}
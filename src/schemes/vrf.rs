@@ -0,0 +1,28 @@
+//! Verifiable random function schemes.
+//!
+//! This module provides publicly verifiable randomness bound to a key: the key holder derives
+//! an output from an input, such as a lottery draw or a leader election for a round, and
+//! publishes a proof that the output is the only one its key allows. Nobody without the private
+//! key can predict the output, and nobody, the key holder included, can bias it.
+//!
+//! # Available Schemes
+//! - **ECVRF**: elliptic curve VRFs (RFC 9381) with the P-256-SHA256-TAI and
+//!   Edwards25519-SHA512-TAI suites, using the crate's P-256 and Ed25519 keys
+//!
+//! 可验证随机函数方案。
+//!
+//! 此模块提供绑定到密钥的可公开验证的随机性：密钥持有者从一个输入（例如一次抽签或某一轮的
+//! 领导者选举）派生出输出，并公开一个证明，表明该输出是其密钥所允许的唯一输出。没有私钥的人
+//! 无法预测输出，而任何人（包括密钥持有者）都无法使其产生偏差。
+//!
+//! # 可用方案
+//! - **ECVRF**: 使用 P-256-SHA256-TAI 和 Edwards25519-SHA512-TAI 套件的椭圆曲线 VRF
+//!   (RFC 9381)，使用 crate 的 P-256 和 Ed25519 密钥
+
+/// ECVRF verifiable random functions.
+///
+/// ECVRF 可验证随机函数。
+#[cfg(feature = "vrf-default")]
+pub mod ecvrf {
+    pub use crate::systems::vrf::ecvrf::*;
+}
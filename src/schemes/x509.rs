@@ -0,0 +1,24 @@
+//! X.509 certificate signing requests and certificates.
+//!
+//! This module provides certificate signing requests for provisioning services with keys
+//! generated by this crate, and self-signed certificates for development and private roots.
+//!
+//! # Available Schemes
+//! - **certificate**: PKCS#10 requests and self-signed certificates signed with ECDSA P-256,
+//!   Ed25519 or RSA-PSS
+//!
+//! X.509 证书签名请求和证书。
+//!
+//! 此模块提供证书签名请求，用于为服务配置由本 crate 生成的密钥，以及用于开发和私有根证书的
+//! 自签名证书。
+//!
+//! # 可用方案
+//! - **certificate**: 使用 ECDSA P-256、Ed25519 或 RSA-PSS 签名的 PKCS#10 请求和自签名证书
+
+/// X.509 certificate signing requests and self-signed certificates.
+///
+/// X.509 证书签名请求和自签名证书。
+#[cfg(feature = "x509-default")]
+pub mod certificate {
+    pub use crate::systems::x509::certificate::*;
+}
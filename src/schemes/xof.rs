@@ -7,6 +7,10 @@
 //! # Available XOFs
 //! - **SHAKE128**: Provides 128 bits of security strength
 //! - **SHAKE256**: Provides 256 bits of security strength
+//! - **cSHAKE128/256**: SHAKE with function-name (`N`) and customization (`S`) strings
+//! - **TupleHash128/256**: Unambiguous hashing of a sequence of byte strings
+//! - **ParallelHash128/256**: Hashing of long inputs in independently hashed blocks
+//! - **BLAKE3**: Extendable output in any of its three modes, also available as a hash function
 //!
 //! # Security Considerations
 //! - Choose the appropriate security level based on your requirements
@@ -21,6 +25,10 @@
 //! # 可用的 XOF
 //! - **SHAKE128**: 提供 128 位的安全强度
 //! - **SHAKE256**: 提供 256 位的安全强度
+//! - **cSHAKE128/256**: 带有函数名 (`N`) 和定制 (`S`) 字符串的 SHAKE
+//! - **TupleHash128/256**: 对字节串序列进行无歧义哈希
+//! - **ParallelHash128/256**: 以独立哈希的块对长输入进行哈希
+//! - **BLAKE3**: 三种模式均支持可扩展输出，同时也可作为哈希函数使用
 //!
 //! # 安全考虑
 //! - 根据您的要求选择适当的安全级别
@@ -41,3 +49,41 @@
 pub mod shake {
     pub use crate::systems::xof::shake::*;
 }
+
+/// cSHAKE, the customizable SHAKE function from NIST SP 800-185.
+///
+/// cSHAKE，NIST SP 800-185 中的可定制 SHAKE 函数。
+#[cfg(feature = "shake-default")]
+pub mod cshake {
+    pub use crate::systems::xof::cshake::*;
+}
+
+/// TupleHash from NIST SP 800-185, including its XOF variant.
+///
+/// NIST SP 800-185 中的 TupleHash，包括其 XOF 变体。
+#[cfg(feature = "shake-default")]
+pub mod tuplehash {
+    pub use crate::systems::xof::tuplehash::*;
+}
+
+/// ParallelHash from NIST SP 800-185, including its XOF variant.
+///
+/// NIST SP 800-185 中的 ParallelHash，包括其 XOF 变体。
+#[cfg(feature = "shake-default")]
+pub mod parallelhash {
+    pub use crate::systems::xof::parallelhash::*;
+}
+
+/// BLAKE3 used as an extendable-output function.
+///
+/// This is the same scheme as [`crate::schemes::hash::blake3`]; it implements
+/// [`XofDerivation`](crate::traits::xof::XofDerivation) alongside the hash traits.
+///
+/// 用作可扩展输出函数的 BLAKE3。
+///
+/// 它与 [`crate::schemes::hash::blake3`] 是同一方案；除哈希 trait 外还实现了
+/// [`XofDerivation`](crate::traits::xof::XofDerivation)。
+#[cfg(feature = "blake3-default")]
+pub mod blake3 {
+    pub use crate::systems::hash::blake3::*;
+}
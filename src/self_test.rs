@@ -0,0 +1,659 @@
+//! Power-on self-tests with built-in known-answer vectors.
+//!
+//! Every scheme implementing [`SelfTest`] carries a known-answer test (KAT): it runs its
+//! primitive on fixed inputs and compares the result with the expected output, and checks that
+//! the inverse operation (decryption, verification) accepts it. Schemes whose key generation
+//! or encapsulation is randomized additionally run a pairwise consistency test on a freshly
+//! generated key pair.
+//!
+//! [`run_self_tests`] runs the test of every scheme enabled by the crate's features and returns
+//! a [`SelfTestReport`], which deployments can check at startup:
+//!
+//! ```
+//! let report = seal_crypto::self_test::run_self_tests();
+//! assert!(report.passed());
+//! for result in &report.results {
+//!     println!("{}: {:?}", result.id, result.outcome);
+//! }
+//! ```
+//!
+//! Schemes are tested through their public operations, so a scheme denied by the installed
+//! [`crate::policy::CryptoPolicy`] reports [`Error::PolicyViolation`].
+//!
+//! 带有内置已知答案向量的上电自检。
+//!
+//! 每个实现了 [`SelfTest`] 的方案都带有已知答案测试 (KAT)：它在固定输入上运行其原语并将结果
+//! 与预期输出进行比较，并检查逆操作（解密、验证）能够接受该结果。密钥生成或封装是随机化的方案
+//! 还会在新生成的密钥对上运行成对一致性测试。
+//!
+//! [`run_self_tests`] 运行由 crate 特性启用的每个方案的测试，并返回一个 [`SelfTestReport`]，
+//! 部署可以在启动时检查它。
+//!
+//! 方案是通过其公开操作进行测试的，因此被已安装的 [`crate::policy::CryptoPolicy`] 拒绝的方案
+//! 会报告 [`Error::PolicyViolation`]。
+
+use alloc::{string::String, vec::Vec};
+use crate::errors::Error;
+use crate::prelude::*;
+
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+/// The reasons a self-test can fail.
+///
+/// 自检可能失败的原因。
+#[cfg_attr(feature = "std", derive(Error))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum SelfTestError {
+    /// The scheme produced an output that differs from the known answer, or rejected a
+    /// known-good input.
+    ///
+    /// 方案产生的输出与已知答案不同，或拒绝了已知有效的输入。
+    #[cfg_attr(feature = "std", error("Output does not match the known answer"))]
+    KnownAnswerMismatch,
+
+    /// A freshly generated key pair does not work together.
+    ///
+    /// 新生成的密钥对无法配合使用。
+    #[cfg_attr(
+        feature = "std",
+        error("Generated key pair failed the pairwise consistency test")
+    )]
+    PairwiseConsistency,
+}
+
+/// A scheme with a built-in known-answer self-test.
+///
+/// 带有内置已知答案自检的方案。
+pub trait SelfTest: Algorithm {
+    /// Runs the scheme's known-answer test, and its pairwise consistency test where one
+    /// applies.
+    ///
+    /// Fails with `SelfTestError::KnownAnswerMismatch` or
+    /// `SelfTestError::PairwiseConsistency`, or with the error of the operation under test.
+    ///
+    /// 运行方案的已知答案测试，以及适用时的成对一致性测试。
+    ///
+    /// 以 `SelfTestError::KnownAnswerMismatch` 或 `SelfTestError::PairwiseConsistency`
+    /// 失败，或以被测操作的错误失败。
+    fn self_test() -> Result<(), Error>;
+}
+
+/// The outcome of the self-test of one scheme.
+///
+/// 单个方案自检的结果。
+#[derive(Debug, PartialEq, Eq)]
+pub struct SelfTestResult {
+    /// The ID of the tested scheme.
+    ///
+    /// 被测方案的 ID。
+    pub id: AlgorithmId,
+    /// The name of the tested scheme.
+    ///
+    /// 被测方案的名称。
+    pub name: String,
+    /// `Ok(())` if the self-test passed, otherwise the reason it failed.
+    ///
+    /// 如果自检通过则为 `Ok(())`，否则为失败原因。
+    pub outcome: Result<(), Error>,
+}
+
+impl SelfTestResult {
+    /// Runs the self-test of `A`.
+    ///
+    /// 运行 `A` 的自检。
+    pub fn of<A: SelfTest>() -> Self {
+        Self {
+            id: AlgorithmId::of::<A>(),
+            name: A::name(),
+            outcome: A::self_test(),
+        }
+    }
+
+    /// Returns `true` if the self-test passed.
+    ///
+    /// 如果自检通过则返回 `true`。
+    pub fn passed(&self) -> bool {
+        self.outcome.is_ok()
+    }
+}
+
+/// The results of [`run_self_tests`], one per enabled scheme.
+///
+/// [`run_self_tests`] 的结果，每个已启用的方案一个。
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SelfTestReport {
+    /// The result of every scheme that was tested, in a fixed order.
+    ///
+    /// 每个被测方案的结果，顺序固定。
+    pub results: Vec<SelfTestResult>,
+}
+
+impl SelfTestReport {
+    /// Returns `true` if every self-test passed.
+    ///
+    /// 如果所有自检都通过则返回 `true`。
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(SelfTestResult::passed)
+    }
+
+    /// Returns the results of the self-tests that failed.
+    ///
+    /// 返回失败的自检结果。
+    pub fn failures(&self) -> impl Iterator<Item = &SelfTestResult> {
+        self.results.iter().filter(|result| !result.passed())
+    }
+}
+
+/// Runs the self-test of every scheme enabled by the crate's features.
+///
+/// 运行由 crate 特性启用的每个方案的自检。
+pub fn run_self_tests() -> SelfTestReport {
+    SelfTestReport {
+        results: SELF_TESTS.iter().map(|test| test()).collect(),
+    }
+}
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// Decodes a hex test vector. The vectors are constants, so invalid digits cannot occur.
+///
+/// 解码十六进制测试向量。向量都是常量，因此不会出现无效数字。
+#[allow(dead_code)]
+fn unhex(hex: &str) -> Vec<u8> {
+    fn nibble(digit: u8) -> u8 {
+        match digit {
+            b'0'..=b'9' => digit - b'0',
+            b'a'..=b'f' => digit - b'a' + 10,
+            _ => 0,
+        }
+    }
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            pair.iter()
+                .fold(0, |byte, &digit| (byte << 4) | nibble(digit))
+        })
+        .collect()
+}
+
+#[allow(dead_code)]
+fn known_answer(matches: bool) -> Result<(), Error> {
+    if matches {
+        Ok(())
+    } else {
+        Err(Error::SelfTest(SelfTestError::KnownAnswerMismatch))
+    }
+}
+
+#[allow(dead_code)]
+fn pairwise_consistent(consistent: bool) -> Result<(), Error> {
+    if consistent {
+        Ok(())
+    } else {
+        Err(Error::SelfTest(SelfTestError::PairwiseConsistency))
+    }
+}
+
+/// A message signed by the signature known-answer tests.
+///
+/// 签名已知答案测试所签名的消息。
+#[allow(dead_code)]
+const MESSAGE: &[u8] = b"seal-crypto self-test message";
+
+#[cfg(any(feature = "sha2", feature = "sha3", feature = "sm-default"))]
+fn hash_kat<H: HashFunction>(expected: &str) -> Result<(), Error> {
+    known_answer(H::hash(b"abc") == unhex(expected))
+}
+
+#[cfg(any(
+    feature = "hmac-default",
+    feature = "cmac-default",
+    feature = "shake-default"
+))]
+fn mac_kat<M: Mac<Key = SymmetricKey>>(
+    key: &str,
+    message: &[u8],
+    expected: &str,
+) -> Result<(), Error> {
+    let key = SymmetricKey::new(unhex(key));
+    let tag = M::compute(&key, message)?;
+    known_answer(tag == unhex(expected))?;
+    known_answer(M::verify(&key, message, &tag).is_ok())
+}
+
+#[cfg(any(
+    feature = "aes-gcm-default",
+    feature = "chacha20-poly1305-default",
+    feature = "sm-default"
+))]
+fn aead_kat<A: AeadScheme + SymmetricKeySet<Key = SymmetricKey>>(
+    nonce: &str,
+    expected: &str,
+) -> Result<(), Error> {
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const PLAINTEXT: &[u8] = b"seal-crypto self-test plaintext";
+    const AAD: &[u8] = b"seal-crypto self-test aad";
+
+    let mut key = unhex(KEY);
+    key.truncate(<A as AeadCipher>::KEY_SIZE);
+    let key = SymmetricKey::new(key);
+    let nonce = unhex(nonce);
+    let mut ciphertext = A::encrypt(&key, &nonce, PLAINTEXT, Some(AAD))?;
+    known_answer(ciphertext == unhex(expected))?;
+    let plaintext = A::decrypt(&key, &nonce, &ciphertext, Some(AAD));
+    known_answer(plaintext.as_deref() == Ok(PLAINTEXT))?;
+    if let Some(byte) = ciphertext.last_mut() {
+        *byte ^= 1;
+    }
+    known_answer(A::decrypt(&key, &nonce, &ciphertext, Some(AAD)).is_err())
+}
+
+#[cfg(feature = "hkdf-default")]
+fn hkdf_kat<K: KeyBasedDerivation + Default>(expected: &str) -> Result<(), Error> {
+    // RFC 5869, test case 1
+    let okm = K::default().derive(
+        &[0x0b; 22],
+        Some(&unhex("000102030405060708090a0b0c")),
+        Some(&unhex("f0f1f2f3f4f5f6f7f8f9")),
+        42,
+    )?;
+    known_answer(okm.as_bytes() == unhex(expected))
+}
+
+#[cfg(feature = "pbkdf2-default")]
+fn pbkdf2_kat<H: Hasher>(expected: &str) -> Result<(), Error> {
+    use crate::systems::kdf::pbkdf2::Pbkdf2Scheme;
+    use alloc::boxed::Box;
+    use secrecy::SecretBox;
+
+    let password = SecretBox::new(Box::from(&b"password"[..]));
+    let key = Pbkdf2Scheme::<H>::new(1000).derive(&password, b"salt", 32)?;
+    known_answer(key.as_bytes() == unhex(expected))
+}
+
+#[cfg(feature = "ecc-default")]
+fn ecdsa_kat<P: crate::systems::asymmetric::traditional::ecc::Sec1Params + Clone>(
+    secret: &str,
+    public: &str,
+    signature: &str,
+) -> Result<(), Error> {
+    use crate::systems::asymmetric::traditional::ecc::{EccPrivateKey, EccPublicKey, EccScheme};
+
+    let private_key = EccPrivateKey::<P>::from_raw_bytes(&unhex(secret))?;
+    let public_key = EccPublicKey::<P>::from_sec1_bytes(&unhex(public))?;
+    known_answer(EccScheme::<P>::derive_public_key(&private_key)? == public_key)?;
+    known_answer(EccScheme::<P>::verify(&public_key, MESSAGE, &unhex(signature)).is_ok())?;
+    known_answer(
+        EccScheme::<P>::verify(&public_key, b"other message", &unhex(signature)).is_err(),
+    )?;
+    let signature = EccScheme::<P>::sign(&private_key, MESSAGE)?;
+    pairwise_consistent(EccScheme::<P>::verify(&public_key, MESSAGE, &signature).is_ok())
+}
+
+#[cfg(any(feature = "dilithium-default", feature = "ecc-default"))]
+fn signature_pct<S: SignatureScheme>() -> Result<(), Error> {
+    let (public_key, private_key) = S::generate_keypair()?;
+    let signature = S::sign(&private_key, MESSAGE)?;
+    pairwise_consistent(S::verify(&public_key, MESSAGE, &signature).is_ok())
+}
+
+#[cfg(feature = "kyber-default")]
+fn kem_pct<K: KeyGenerator + Kem>() -> Result<(), Error> {
+    let (public_key, private_key) = K::generate_keypair()?;
+    let (shared_secret, encapsulated_key) = K::encapsulate(&public_key)?;
+    let decapsulated = K::decapsulate(&private_key, &encapsulated_key)?;
+    pairwise_consistent(decapsulated.expose_raw() == shared_secret.expose_raw())
+}
+
+#[cfg(feature = "ecc-default")]
+fn ed25519_kat() -> Result<(), Error> {
+    use crate::systems::asymmetric::traditional::ecc::{EccPrivateKey, Ed25519, Ed25519Params};
+
+    // RFC 8032, section 7.1, test 1
+    let private_key = EccPrivateKey::<Ed25519Params>::from_raw_bytes(&unhex(
+        "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+    ))?;
+    let signature = Ed25519::sign(&private_key, b"")?;
+    known_answer(
+        signature
+            == unhex(
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+                 5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+    )?;
+    let public_key = Ed25519::derive_public_key(&private_key)?;
+    known_answer(Ed25519::verify(&public_key, b"", &signature).is_ok())
+}
+
+#[cfg(feature = "ecdh-default")]
+fn ecdh_kat() -> Result<(), Error> {
+    use crate::systems::asymmetric::traditional::ecdh::{
+        EcdhP256, EcdhP256Params, EcdhPrivateKey, EcdhPublicKey,
+    };
+
+    let private_key = EcdhPrivateKey::<EcdhP256Params>::from_raw_bytes(&unhex(
+        "234ccc9865444311e3a2d0b0d7593730d6cc016962ffb24ebc219b4898845790",
+    ))?;
+    let peer = EcdhPublicKey::<EcdhP256Params>::from_sec1_bytes(&unhex(
+        "0246d49908cb42076a345a5934d4882cc750b8470396552c2392aa6945737182dc",
+    ))?;
+    let shared_secret = EcdhP256::agree(&private_key, &peer)?;
+    known_answer(
+        shared_secret.expose_raw()
+            == unhex("f8715c1e54d326bbf99310f103145bb879102146970611e2206f090807d194f6"),
+    )
+}
+
+#[cfg(feature = "sm-default")]
+fn sm2_kat() -> Result<(), Error> {
+    use crate::systems::asymmetric::traditional::sm2::{Sm2, Sm2PrivateKey, Sm2PublicKey};
+
+    let private_key = Sm2PrivateKey::from_bytes(&unhex(
+        "75cf576ad8999402cf41db8f7f8e18a8988fb067ad9a4ef5a7b8072ab8214fc8",
+    ))?;
+    let public_key = Sm2PublicKey::from_bytes(&unhex(
+        "043d262110c28ea4f26227e6ebf95585de3fe99e284620bc0548d69310df593d7c\
+         2f0eb212431a8ecd3ee57cd09a94f9174128d6589eaac48c534257da89c2738c",
+    ))?;
+    // Signed with the default distinguishing ID
+    let signature = unhex(
+        "ae0bccd8f5c5cdb5fe42bf0140e66224138110345f441034b6c15b11ba200cd0\
+         bdf3e94dd7fa85f8874f418d44f78fecbe2e2d9145d259ad7e5501284f9b8693",
+    );
+    known_answer(Sm2::derive_public_key(&private_key)? == public_key)?;
+    known_answer(Sm2::verify(&public_key, MESSAGE, &signature).is_ok())?;
+    known_answer(Sm2::verify(&public_key, b"other message", &signature).is_err())?;
+    let signature = Sm2::sign(&private_key, MESSAGE)?;
+    pairwise_consistent(Sm2::verify(&public_key, MESSAGE, &signature).is_ok())?;
+    let ciphertext = Sm2::encrypt(&public_key, MESSAGE)?;
+    pairwise_consistent(Sm2::decrypt(&private_key, &ciphertext)? == MESSAGE)
+}
+
+#[cfg(feature = "rsa-default")]
+fn rsa_pss_kat() -> Result<(), Error> {
+    use crate::systems::asymmetric::traditional::rsa::{Rsa2048, RsaPublicKey};
+
+    // RSA-PSS with SHA-256, MGF1-SHA-256 and a 32-byte salt
+    let public_key = RsaPublicKey::from_bytes(&unhex(
+        "30820122300d06092a864886f70d01010105000382010f003082010a028201010097107ab14ca81580d5d3a226cd0585\
+         59110d227618a7850e94fba0ddf638f6539707ec4b42b6b105549e98b176a9acdcd3c4964ea0dc6e4cb736a976fc879f\
+         b100162e077e9808cade34e4afa58d0216e68a696f0706e9dfbe813f9d8686119c7d90b104a64b6d7c2dfc1b917b82a1\
+         cb5b5a1c1a8b4991fc4f4445d9186ecd4c3e0e92c0a68bf5bbe8deac65f714f506bc827656544a8c2ead22a8505b9a8f\
+         940b842f5eb38c76974d7904b8c1f4b9c9dbcabede55da81e93986bd3973b1cbe29d3b09f2a509ecb27e1156b670c118\
+         dc049885aaaf98b528d84dd9f554c4bcb356ae64cae1b554ac3ed256c90fad8d325eb5641d47c3a677c7d4e21c58818c\
+         0f0203010001",
+    ))?;
+    let signature = unhex(
+        "3532f21b9568a26620d7fcc4da7aacf7cdfb6f96f5ba72fd200a596aceddd9c63874480eb10be3cebab3867ece66a051\
+         035b7cc43f081f3502dee70fc89d689b62b77870f23185a6ef6f66ba5a2cfc0ec23b5fc06f963bb3efe33854b4388531\
+         a14f953b8554732113bfcef4279a40c18ef07d535ad4d7cdf3dbca6d2cbc163623b19d2a8e582837555a32603caecf13\
+         6471f7a90582e06bfc7266cee9f127c7f472df97f5baa4e8451d637f5d55a952e483e7da2985e0bfffe6212d75bc6853\
+         782f104ea4296a8983daee27312621ce3f0047d965af9728ac87cb82deb23acfed999c3af92e2963d8a570099a5affa9\
+         e4aae09cc7915aeae749a59d492062c8",
+    );
+    known_answer(Rsa2048::<Sha256>::verify(&public_key, MESSAGE, &signature).is_ok())?;
+    known_answer(Rsa2048::<Sha256>::verify(&public_key, b"other message", &signature).is_err())
+}
+
+// ------------------- Self-Tests -------------------
+// ------------------- 自检 -------------------
+
+macro_rules! self_tests {
+    ($($(#[$meta:meta])* $scheme:ty => $test:expr,)*) => {
+        $(
+            $(#[$meta])*
+            impl SelfTest for $scheme {
+                fn self_test() -> Result<(), Error> {
+                    $test
+                }
+            }
+        )*
+
+        static SELF_TESTS: &[fn() -> SelfTestResult] = &[
+            $(
+                $(#[$meta])*
+                SelfTestResult::of::<$scheme>,
+            )*
+        ];
+    };
+}
+
+self_tests! {
+    #[cfg(feature = "sha2")]
+    crate::systems::hash::sha2::Sha256 => hash_kat::<Self>(
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+    ),
+    #[cfg(feature = "sha2")]
+    crate::systems::hash::sha2::Sha384 => hash_kat::<Self>(
+        "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+         8086072ba1e7cc2358baeca134c825a7",
+    ),
+    #[cfg(feature = "sha2")]
+    crate::systems::hash::sha2::Sha512 => hash_kat::<Self>(
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+         2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+    ),
+    #[cfg(feature = "sha3")]
+    crate::systems::hash::sha3::Sha3_256 => hash_kat::<Self>(
+        "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+    ),
+    #[cfg(feature = "sha3")]
+    crate::systems::hash::sha3::Sha3_384 => hash_kat::<Self>(
+        "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b2\
+         98d88cea927ac7f539f1edf228376d25",
+    ),
+    #[cfg(feature = "sha3")]
+    crate::systems::hash::sha3::Sha3_512 => hash_kat::<Self>(
+        "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+         10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+    ),
+    // GB/T 32905-2016, appendix A, example 1
+    #[cfg(feature = "sm-default")]
+    crate::systems::hash::sm3::Sm3 => hash_kat::<Self>(
+        "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0",
+    ),
+    // RFC 4231, test case 2
+    #[cfg(feature = "hmac-default")]
+    crate::systems::mac::hmac::HmacSha256 => mac_kat::<Self>(
+        "4a656665",
+        b"what do ya want for nothing?",
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+    ),
+    #[cfg(feature = "hmac-default")]
+    crate::systems::mac::hmac::HmacSha384 => mac_kat::<Self>(
+        "4a656665",
+        b"what do ya want for nothing?",
+        "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e\
+         8e2240ca5e69e2c78b3239ecfab21649",
+    ),
+    #[cfg(feature = "hmac-default")]
+    crate::systems::mac::hmac::HmacSha512 => mac_kat::<Self>(
+        "4a656665",
+        b"what do ya want for nothing?",
+        "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+         9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+    ),
+    // NIST SP 800-38B, examples 2 and 10
+    #[cfg(feature = "cmac-default")]
+    crate::systems::mac::cmac::Aes128Cmac => mac_kat::<Self>(
+        "2b7e151628aed2a6abf7158809cf4f3c",
+        &unhex("6bc1bee22e409f96e93d7e117393172a"),
+        "070a16b46b4d4144f79bdd9dd04a287c",
+    ),
+    #[cfg(feature = "cmac-default")]
+    crate::systems::mac::cmac::Aes256Cmac => mac_kat::<Self>(
+        "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+        &unhex("6bc1bee22e409f96e93d7e117393172a"),
+        "28a7023f452e8f82bd4bf28d8c37c35c",
+    ),
+    // NIST SP 800-185 KMAC samples 1 and 6
+    #[cfg(feature = "shake-default")]
+    crate::systems::mac::kmac::Kmac128 => mac_kat::<Self>(
+        "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f",
+        &[0x00, 0x01, 0x02, 0x03],
+        "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e",
+    ),
+    #[cfg(feature = "shake-default")]
+    crate::systems::mac::kmac::Kmac256 => {
+        let key = unhex("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f");
+        let tag = Self::compute_with_customization(
+            &key,
+            &[0x00, 0x01, 0x02, 0x03],
+            b"My Tagged Application",
+            64,
+        )?;
+        known_answer(
+            tag == unhex(
+                "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+                 f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd",
+            ),
+        )
+    },
+    #[cfg(feature = "aes-gcm-default")]
+    crate::systems::aead::aes_gcm::Aes128Gcm => aead_kat::<Self>(
+        "404142434445464748494a4b",
+        "4a4c08a640288bc9fccd2c3f8d42119e6109dc1869f2e5f2da29a65519a477\
+         97818a1f5dc193e7acd054cc4b212d43",
+    ),
+    #[cfg(feature = "aes-gcm-default")]
+    crate::systems::aead::aes_gcm::Aes256Gcm => aead_kat::<Self>(
+        "404142434445464748494a4b",
+        "91dccf4f0b5ff57abdb07816e8017f3d54b2372fc3157f4f162e2091f26b99\
+         06bbed024cd436914a2d01b2c25d17d5",
+    ),
+    #[cfg(feature = "chacha20-poly1305-default")]
+    crate::systems::aead::chacha20_poly1305::ChaCha20Poly1305 => aead_kat::<Self>(
+        "404142434445464748494a4b",
+        "8b311ded5f299c7828bb9920c88a01ab99b8066b30dc31039f65bc2f8f5f4e\
+         32f052f0837ee2908cb5c1fa860c0b80",
+    ),
+    #[cfg(feature = "chacha20-poly1305-default")]
+    crate::systems::aead::chacha20_poly1305::XChaCha20Poly1305 => aead_kat::<Self>(
+        "404142434445464748494a4b4c4d4e4f5051525354555657",
+        "a75c641cfd830b6fff80e89edcf909f4bfcec8b7677923f60b5893316c7c57\
+         80e25fd17b62e579dc382b3ed750b8e9",
+    ),
+    #[cfg(feature = "sm-default")]
+    crate::systems::aead::sm4::Sm4Gcm => aead_kat::<Self>(
+        "404142434445464748494a4b",
+        "9da4de64ae3a85e94cb2e401225e5feda9886e2562649c3edbc379c0148bb4\
+         d242d57b19e057928447269cb7fcfe58",
+    ),
+    #[cfg(feature = "sm-default")]
+    crate::systems::aead::sm4::Sm4Ccm => aead_kat::<Self>(
+        "404142434445464748494a4b",
+        "fc17710062dba4637f1b38a0a70410fc859460484e977ba446cde564a534ec\
+         6c040d18ea17ed425b514754f0830990",
+    ),
+    #[cfg(feature = "hkdf-default")]
+    crate::systems::kdf::hkdf::HkdfSha256 => hkdf_kat::<Self>(
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf\
+         34007208d5b887185865",
+    ),
+    #[cfg(feature = "hkdf-default")]
+    crate::systems::kdf::hkdf::HkdfSha384 => hkdf_kat::<Self>(
+        "9b5097a86038b805309076a44b3a9f38063e25b516dcbf369f394cfab43685f7\
+         48b6457763e4f0204fc5",
+    ),
+    #[cfg(feature = "hkdf-default")]
+    crate::systems::kdf::hkdf::HkdfSha512 => hkdf_kat::<Self>(
+        "832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c14815793\
+         38da362cb8d9f925d7cb",
+    ),
+    #[cfg(feature = "pbkdf2-default")]
+    crate::systems::kdf::pbkdf2::Pbkdf2Sha256 => pbkdf2_kat::<Sha256>(
+        "632c2812e46d4604102ba7618e9d6d7d2f8128f6266b4a03264d2a0460b7dcb3",
+    ),
+    #[cfg(feature = "pbkdf2-default")]
+    crate::systems::kdf::pbkdf2::Pbkdf2Sha384 => pbkdf2_kat::<Sha384>(
+        "3bd37e2236941d4a77b1b5b714c6f913fabb6b0841a6d7d8656b99d611e900fe",
+    ),
+    #[cfg(feature = "pbkdf2-default")]
+    crate::systems::kdf::pbkdf2::Pbkdf2Sha512 => pbkdf2_kat::<Sha512>(
+        "afe6c5530785b6cc6b1c6453384731bd5ee432ee549fd42fb6695779ad8a1c5b",
+    ),
+    #[cfg(feature = "rsa-default")]
+    crate::systems::asymmetric::traditional::rsa::Rsa2048<Sha256> => rsa_pss_kat(),
+    #[cfg(feature = "ecc-default")]
+    crate::systems::asymmetric::traditional::ecc::EcdsaP256 => ecdsa_kat::<
+        crate::systems::asymmetric::traditional::ecc::EcdsaP256Params,
+    >(
+        "078c3676bef0563820e02a04031e01c06d16489ff6a5be2bd4f9b12c50271a21",
+        "03217d40d5dc03226fea8833c8b0c1553bb467bd3b7344b729b439c63a65b721ea",
+        "f3cfd0c4e209d3dc03b5697021f99e2509877f025e309e869c0c01c9c71a4f62\
+         02d5f88865607396b46e041bf05df6baa262049e50c75935339e20e3b08f2a47",
+    ),
+    #[cfg(all(feature = "ecc-default", not(feature = "fips")))]
+    crate::systems::asymmetric::traditional::ecc::EcdsaSecp256k1 => ecdsa_kat::<
+        crate::systems::asymmetric::traditional::ecc::EcdsaSecp256k1Params,
+    >(
+        "84dc8b32d4e7952006cdeaf7165baa23ea30ae981a64812f049ad8456fcf384c",
+        "033238ccc05920a9822b68f45e0087b35da0d013d1abc57f73c2e2ba8eb0a1fc20",
+        "660c1877c3870f0a7ab0a0d89cf9ea9e57d5f45b8d4560fa1cbc8c96c6942c1c\
+         1b332bbf9f9ed53bdef1e82269da2058394f3f9b74454502ac059e6c55161e0b",
+    ),
+    #[cfg(feature = "ecc-default")]
+    crate::systems::asymmetric::traditional::ecc::Ed25519 => {
+        ed25519_kat()?;
+        signature_pct::<Self>()
+    },
+    #[cfg(feature = "ecdh-default")]
+    crate::systems::asymmetric::traditional::ecdh::EcdhP256 => ecdh_kat(),
+    #[cfg(feature = "sm-default")]
+    crate::systems::asymmetric::traditional::sm2::Sm2 => sm2_kat(),
+    #[cfg(feature = "kyber-default")]
+    crate::systems::asymmetric::post_quantum::kyber::Kyber512 => kem_pct::<Self>(),
+    #[cfg(feature = "kyber-default")]
+    crate::systems::asymmetric::post_quantum::kyber::Kyber768 => kem_pct::<Self>(),
+    #[cfg(feature = "kyber-default")]
+    crate::systems::asymmetric::post_quantum::kyber::Kyber1024 => kem_pct::<Self>(),
+    #[cfg(feature = "dilithium-default")]
+    crate::systems::asymmetric::post_quantum::dilithium::Dilithium2 => signature_pct::<Self>(),
+    #[cfg(feature = "dilithium-default")]
+    crate::systems::asymmetric::post_quantum::dilithium::Dilithium3 => signature_pct::<Self>(),
+    #[cfg(feature = "dilithium-default")]
+    crate::systems::asymmetric::post_quantum::dilithium::Dilithium5 => signature_pct::<Self>(),
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_self_tests() {
+        let report = run_self_tests();
+        assert!(
+            report.passed(),
+            "{:?}",
+            report.failures().collect::<Vec<_>>()
+        );
+        assert_eq!(report.failures().count(), 0);
+        assert_eq!(report.results.len(), SELF_TESTS.len());
+        for result in &report.results {
+            assert_eq!(result.id.name(), Some(result.name.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_unhex() {
+        assert_eq!(unhex("00ff7a"), [0x00, 0xff, 0x7a]);
+        assert_eq!(unhex(""), Vec::<u8>::new());
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_known_answer_mismatch() {
+        assert_eq!(
+            hash_kat::<crate::systems::hash::sha2::Sha256>("00"),
+            Err(Error::SelfTest(SelfTestError::KnownAnswerMismatch))
+        );
+        let result = SelfTestResult::of::<crate::systems::hash::sha2::Sha256>();
+        assert!(result.passed());
+        assert_eq!(result.name, "SHA-256");
+    }
+}
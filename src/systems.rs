@@ -11,7 +11,22 @@
 //! # Internal Organization
 //! - `asymmetric`: Implementations of public-key cryptographic schemes
 //! - `aead`: Implementations of symmetric authenticated encryption schemes
+//! - `commitment`: Implementations of commitment schemes
+//! - `hash`: Implementations of fixed-output hash functions
+//! - `hd`: Implementations of hierarchical deterministic key derivation
 //! - `kdf`: Implementations of key derivation functions
+//! - `jose`: Implementations of JSON Object Signing and Encryption
+//! - `keystore`: Implementations of keystore containers
+//! - `mac`: Implementations of message authentication codes
+//! - `messaging`: Implementations of secure messaging protocols
+//! - `nacl`: Implementations of NaCl and libsodium compatible constructions
+//! - `oprf`: Implementations of oblivious pseudorandom functions
+//! - `otp`: Implementations of one-time password algorithms
+//! - `pake`: Implementations of password-authenticated key exchanges
+//! - `secret_sharing`: Implementations of threshold secret sharing
+//! - `threshold`: Implementations of threshold signatures
+//! - `vrf`: Implementations of verifiable random functions
+//! - `x509`: Implementations of X.509 certificate structures
 //! - `xof`: Implementations of extendable-output functions
 //!
 //! `systems` 模块提供了加密 trait 的具体实现。
@@ -24,10 +39,61 @@
 //! # 内部组织
 //! - `asymmetric`: 公钥密码方案的实现
 //! - `aead`: 对称认证加密方案的实现
+//! - `commitment`: 承诺方案的实现
+//! - `hash`: 固定输出哈希函数的实现
+//! - `hd`: 分层确定性密钥派生的实现
 //! - `kdf`: 密钥派生函数的实现
+//! - `jose`: JSON 对象签名和加密的实现
+//! - `keystore`: 密钥库容器的实现
+//! - `mac`: 消息认证码的实现
+//! - `messaging`: 安全消息协议的实现
+//! - `nacl`: 与 NaCl 和 libsodium 兼容的构造的实现
+//! - `oprf`: 不经意伪随机函数的实现
+//! - `otp`: 一次性密码算法的实现
+//! - `pake`: 口令认证密钥交换的实现
+//! - `secret_sharing`: 门限秘密共享的实现
+//! - `threshold`: 门限签名的实现
+//! - `vrf`: 可验证随机函数的实现
+//! - `x509`: X.509 证书结构的实现
 //! - `xof`: 可扩展输出函数的实现
 
 pub mod asymmetric;
 pub mod aead;
+pub mod commitment;
+pub mod formats;
+pub mod hash;
+pub mod hd;
+pub mod jose;
 pub mod kdf;
+pub mod keystore;
+pub mod mac;
+pub mod messaging;
+pub mod nacl;
+pub mod oprf;
+pub mod otp;
+pub mod pake;
+pub mod secret_sharing;
+pub mod threshold;
+pub mod vrf;
+pub mod x509;
 pub mod xof;
+
+#[cfg(all(
+    test,
+    any(
+        feature = "aes-gcm-default",
+        feature = "crypto-box-default",
+        feature = "dilithium-default",
+        feature = "ecc-default",
+        feature = "ecdh-default",
+        feature = "kyber-default",
+        feature = "oprf-default",
+        feature = "rsa-default",
+        feature = "sealed-box-default",
+        feature = "secret-sharing-default",
+        feature = "sha2",
+        feature = "sm-default",
+        feature = "srp-default"
+    )
+))]
+pub(crate) mod test_rng;
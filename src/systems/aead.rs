@@ -7,6 +7,7 @@
 //! # Available Implementations
 //! - **AES-GCM**: Industry standard, hardware-accelerated on many platforms
 //! - **ChaCha20-Poly1305**: Software-optimized, constant-time implementation
+//! - **SM4-GCM / SM4-CCM**: Chinese commercial cryptography block cipher (RFC 8998)
 //!
 //! # Security Considerations
 //! All implementations provide authenticated encryption with associated data (AEAD),
@@ -20,6 +21,7 @@
 //! # 可用实现
 //! - **AES-GCM**: 行业标准，在许多平台上有硬件加速
 //! - **ChaCha20-Poly1305**: 软件优化，恒定时间实现
+//! - **SM4-GCM / SM4-CCM**: 中国商用密码分组密码 (RFC 8998)
 //!
 //! # 安全考虑
 //! 所有实现都提供带关联数据的认证加密 (AEAD)，确保加密数据的机密性和完整性。
@@ -35,3 +37,9 @@ pub mod aes_gcm;
 /// ChaCha20-Poly1305 认证加密实现。
 #[cfg(feature = "chacha20-poly1305-default")]
 pub mod chacha20_poly1305;
+
+/// SM4-GCM and SM4-CCM authenticated encryption implementation.
+///
+/// SM4-GCM 和 SM4-CCM 认证加密实现。
+#[cfg(feature = "sm-default")]
+pub mod sm4;
@@ -38,12 +38,19 @@
 //! - 恒定时间实现，抵抗时序攻击
 //! - 对小型和大型数据都高效
 
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
 use crate::errors::Error;
+use crate::policy;
 use crate::prelude::*;
+use crate::rng::SystemRng;
 use aes_gcm::aead::rand_core::RngCore;
-use aes_gcm::aead::{Aead, AeadInPlace, KeyInit, OsRng};
+use aes_gcm::aead::{Aead, AeadInPlace, KeyInit};
 use aes_gcm::{Aes128Gcm as Aes128GcmCore, Aes256Gcm as Aes256GcmCore, Nonce as NonceCore};
-use std::marker::PhantomData;
+use core::marker::PhantomData;
+use zeroize::Zeroize;
 
 // ------------------- Marker Structs and Trait for AES-GCM Parameters -------------------
 // ------------------- 用于 AES-GCM 参数的标记结构体和 Trait -------------------
@@ -178,8 +185,9 @@ impl<P: AesGcmParams> SymmetricKeyGenerator for AesGcmScheme<P> {
     const KEY_SIZE: usize = P::KEY_SIZE;
 
     fn generate_key() -> Result<SymmetricKey, Error> {
+        policy::enforce::<Self>()?;
         let mut key_bytes = vec![0u8; P::KEY_SIZE];
-        OsRng
+        SystemRng::new()
             .try_fill_bytes(&mut key_bytes)
             .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
         Ok(SymmetricKey::new(key_bytes))
@@ -194,6 +202,7 @@ impl<P: AesGcmParams> AeadEncryptor for AesGcmScheme<P> {
         output: &mut [u8],
         aad: Option<AssociatedData>,
     ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
         if key.len() != P::KEY_SIZE {
             return Err(Error::Symmetric(SymmetricError::InvalidKeySize));
         }
@@ -215,7 +224,10 @@ impl<P: AesGcmParams> AeadEncryptor for AesGcmScheme<P> {
 
         let tag = cipher
             .encrypt_in_place_detached(nonce, aad.unwrap_or_default(), ciphertext_buf)
-            .map_err(|_| Error::Symmetric(SymmetricError::Encryption))?;
+            .map_err(|_| {
+                ciphertext_buf.zeroize();
+                Error::Symmetric(SymmetricError::Encryption)
+            })?;
 
         tag_buf[..P::TAG_SIZE].copy_from_slice(&tag);
 
@@ -231,6 +243,7 @@ impl<P: AesGcmParams> AeadDecryptor for AesGcmScheme<P> {
         output: &mut [u8],
         aad: Option<AssociatedData>,
     ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
         if key.len() != P::KEY_SIZE {
             return Err(Error::Symmetric(SymmetricError::InvalidKeySize));
         }
@@ -258,7 +271,13 @@ impl<P: AesGcmParams> AeadDecryptor for AesGcmScheme<P> {
 
         cipher
             .decrypt_in_place_detached(nonce, aad.unwrap_or_default(), plaintext_buf, tag)
-            .map_err(|_| Error::Symmetric(SymmetricError::Decryption))?;
+            .map_err(|_| {
+                // GCM applies the keystream before the tag check fails, so `plaintext_buf` holds
+                // unauthenticated plaintext here.
+                // GCM 在标签检查失败之前就已应用密钥流，因此此时 `plaintext_buf` 中是未经认证的明文。
+                plaintext_buf.zeroize();
+                Error::Symmetric(SymmetricError::Decryption)
+            })?;
 
         Ok(plaintext_buf.len())
     }
@@ -293,20 +312,30 @@ pub type Tag<'a> = &'a [u8];
 #[cfg(test)]
 mod tests {
     use super::*;
-    use zeroize::Zeroizing;
+    use crate::systems::test_rng::SeededRng;
 
     fn test_roundtrip<S>()
     where
         S: AeadEncryptor<Key = SymmetricKey>
             + AeadDecryptor<Key = SymmetricKey>
-            + SymmetricKeyGenerator<Key = Zeroizing<Vec<u8>>>,
+            + SymmetricKeyGenerator<Key = SymmetricKey>,
     {
         let key = S::generate_key().unwrap();
+        let rng_key = S::generate_key_with_rng(&mut SeededRng(0)).unwrap();
+        assert_eq!(rng_key.len(), key.len());
+        assert_eq!(
+            rng_key,
+            S::generate_key_with_rng(&mut SeededRng(0)).unwrap()
+        );
+        assert_ne!(
+            rng_key,
+            S::generate_key_with_rng(&mut SeededRng(1)).unwrap()
+        );
         let plaintext = b"this is a secret message".to_vec();
         let aad = b"this is authenticated data".to_vec();
         let empty_vec = Vec::new();
         let mut nonce = vec![0u8; S::NONCE_SIZE];
-        OsRng.fill_bytes(&mut nonce);
+        SystemRng::new().fill_bytes(&mut nonce);
 
         // With AAD
         // 使用 AAD
@@ -339,6 +368,14 @@ mod tests {
         assert_eq!(bytes_written, plaintext.len());
         assert_eq!(plaintext, &decrypted_buffer_aad[..bytes_written]);
 
+        // A failed decryption leaves no plaintext in the output buffer
+        // 解密失败时输出缓冲区中不留下明文
+        let mut tampered = encrypted_buffer_aad.clone();
+        tampered[0] ^= 1;
+        let mut output = vec![0xffu8; plaintext.len()];
+        assert!(S::decrypt_to_buffer(&key, &nonce, &tampered, &mut output, Some(&aad)).is_err());
+        assert!(output.iter().all(|&b| b == 0));
+
         // Without AAD
         // 不使用 AAD
         let ciphertext_no_aad = S::encrypt(&key, &nonce, &plaintext, None).unwrap();
@@ -432,7 +469,7 @@ mod tests {
         wrong_key[0] ^= 1;
 
         let mut nonce = vec![0u8; <S as AeadCipher>::NONCE_SIZE];
-        OsRng.fill_bytes(&mut nonce);
+        SystemRng::new().fill_bytes(&mut nonce);
         let mut wrong_nonce = nonce.clone();
         wrong_nonce[0] ^= 1;
 
@@ -459,7 +496,7 @@ mod tests {
 
         // Wrong size key
         // 错误大小的密钥
-        let wrong_size_key = Zeroizing::new(vec![0; <S as AeadCipher>::KEY_SIZE - 1]);
+        let wrong_size_key = SymmetricKey::new(vec![0; <S as AeadCipher>::KEY_SIZE - 1]);
         let res = S::encrypt(&wrong_size_key, &nonce, plaintext, Some(aad));
         assert!(matches!(
             res.unwrap_err(),
@@ -485,4 +522,69 @@ mod tests {
     fn test_aes256gcm_invalid_inputs() {
         test_invalid_inputs::<AesGcmScheme<Aes256GcmParams>>();
     }
+
+    #[test]
+    fn test_typed_nonce() {
+        use crate::traits::symmetric::Nonce as TypedNonce;
+
+        let key = Aes256Gcm::generate_key().unwrap();
+        let mut nonce = TypedNonce::<Aes256Gcm>::from_counter(1);
+        assert_eq!(nonce.len(), 12);
+
+        let ciphertext =
+            Aes256Gcm::encrypt_with_nonce(&key, nonce.clone(), b"plaintext", Some(b"aad")).unwrap();
+        assert_eq!(
+            ciphertext,
+            Aes256Gcm::encrypt(&key, &nonce, b"plaintext", Some(b"aad")).unwrap()
+        );
+        assert_eq!(
+            Aes256Gcm::decrypt_with_nonce(&key, &nonce, &ciphertext, Some(b"aad")).unwrap(),
+            b"plaintext"
+        );
+
+        let mut buffer = [0u8; 9 + 16];
+        let written = Aes256Gcm::encrypt_to_buffer_with_nonce(
+            &key,
+            nonce.clone(),
+            b"plaintext",
+            &mut buffer,
+            Some(b"aad"),
+        )
+        .unwrap();
+        assert_eq!(&buffer[..written], ciphertext.as_slice());
+        let mut plaintext = [0u8; 9];
+        let written = Aes256Gcm::decrypt_to_buffer_with_nonce(
+            &key,
+            &nonce,
+            &ciphertext,
+            &mut plaintext,
+            Some(b"aad"),
+        )
+        .unwrap();
+        assert_eq!(&plaintext[..written], b"plaintext");
+
+        nonce.increment().unwrap();
+        assert!(Aes256Gcm::decrypt_with_nonce(&key, &nonce, &ciphertext, Some(b"aad")).is_err());
+        assert!(
+            Aes256Gcm::decrypt_to_buffer_with_nonce(
+                &key,
+                &nonce,
+                &ciphertext,
+                &mut plaintext,
+                Some(b"aad")
+            )
+            .is_err()
+        );
+        assert_eq!(plaintext, [0u8; 9]);
+
+        let key = Aes128Gcm::generate_key().unwrap();
+        let random = TypedNonce::<Aes128Gcm>::random().unwrap();
+        assert_eq!(random.len(), 12);
+        let ciphertext =
+            Aes128Gcm::encrypt_with_nonce(&key, random.clone(), b"plaintext", None).unwrap();
+        assert_eq!(
+            Aes128Gcm::decrypt_with_nonce(&key, &random, &ciphertext, None).unwrap(),
+            b"plaintext"
+        );
+    }
 }
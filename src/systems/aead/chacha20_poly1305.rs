@@ -62,14 +62,21 @@
 //! - 需要恒定时间加密操作的协议
 //! - 计算资源有限的嵌入式系统
 
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
 use crate::errors::Error;
+use crate::policy;
 use crate::prelude::*;
+use crate::rng::SystemRng;
 use chacha20poly1305::aead::rand_core::RngCore;
-use chacha20poly1305::aead::{Aead, AeadInPlace, Key, KeyInit, OsRng};
+use chacha20poly1305::aead::{Aead, AeadInPlace, Key, KeyInit};
 use chacha20poly1305::{
     ChaCha20Poly1305 as ChaCha20Poly1305Core, XChaCha20Poly1305 as XChaCha20Poly1305Core,
 };
-use std::marker::PhantomData;
+use core::marker::PhantomData;
+use zeroize::Zeroize;
 
 // ------------------- Marker Structs and Trait for ChaCha20-Poly1305 Parameters -------------------
 // ------------------- 用于 ChaCha20-Poly1305 参数的标记结构体和 Trait -------------------
@@ -167,8 +174,9 @@ impl<P: Chacha20Poly1305Params> SymmetricKeyGenerator for Chacha20Poly1305Scheme
     const KEY_SIZE: usize = P::KEY_SIZE;
 
     fn generate_key() -> Result<SymmetricKey, Error> {
+        policy::enforce::<Self>()?;
         let mut key_bytes = vec![0u8; P::KEY_SIZE];
-        OsRng
+        SystemRng::new()
             .try_fill_bytes(&mut key_bytes)
             .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
         Ok(SymmetricKey::new(key_bytes))
@@ -183,6 +191,7 @@ impl<P: Chacha20Poly1305Params> AeadEncryptor for Chacha20Poly1305Scheme<P> {
         output: &mut [u8],
         aad: Option<AssociatedData>,
     ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
         if key.len() != P::KEY_SIZE {
             return Err(Error::Symmetric(SymmetricError::InvalidKeySize));
         }
@@ -204,7 +213,10 @@ impl<P: Chacha20Poly1305Params> AeadEncryptor for Chacha20Poly1305Scheme<P> {
 
         let tag = cipher
             .encrypt_in_place_detached(nonce_core, aad.unwrap_or_default(), ciphertext_buf)
-            .map_err(|_| Error::Symmetric(SymmetricError::Encryption))?;
+            .map_err(|_| {
+                ciphertext_buf.zeroize();
+                Error::Symmetric(SymmetricError::Encryption)
+            })?;
 
         tag_buf[..P::TAG_SIZE].copy_from_slice(&tag);
 
@@ -220,6 +232,7 @@ impl<P: Chacha20Poly1305Params> AeadDecryptor for Chacha20Poly1305Scheme<P> {
         output: &mut [u8],
         aad: Option<AssociatedData>,
     ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
         if key.len() != P::KEY_SIZE {
             return Err(Error::Symmetric(SymmetricError::InvalidKeySize));
         }
@@ -247,7 +260,10 @@ impl<P: Chacha20Poly1305Params> AeadDecryptor for Chacha20Poly1305Scheme<P> {
 
         cipher
             .decrypt_in_place_detached(nonce_core, aad.unwrap_or_default(), plaintext_buf, tag)
-            .map_err(|_| Error::Symmetric(SymmetricError::Decryption))?;
+            .map_err(|_| {
+                plaintext_buf.zeroize();
+                Error::Symmetric(SymmetricError::Decryption)
+            })?;
 
         Ok(plaintext_buf.len())
     }
@@ -277,7 +293,6 @@ pub type Tag<'a> = &'a [u8];
 #[cfg(test)]
 mod tests {
     use super::*;
-    use zeroize::Zeroizing;
 
     fn test_roundtrip<S>()
     where
@@ -290,7 +305,7 @@ mod tests {
         let aad = b"this is authenticated data".to_vec();
         let empty_vec = Vec::new();
         let mut nonce = vec![0u8; S::NONCE_SIZE];
-        OsRng.fill_bytes(&mut nonce);
+        SystemRng::new().fill_bytes(&mut nonce);
 
         // With AAD
         // 使用 AAD
@@ -323,6 +338,14 @@ mod tests {
         assert_eq!(bytes_written, plaintext.len());
         assert_eq!(plaintext, &decrypted_buffer_aad[..bytes_written]);
 
+        // A failed decryption leaves no plaintext in the output buffer
+        // 解密失败时输出缓冲区中不留下明文
+        let mut tampered = encrypted_buffer_aad.clone();
+        tampered[0] ^= 1;
+        let mut output = vec![0xffu8; plaintext.len()];
+        assert!(S::decrypt_to_buffer(&key, &nonce, &tampered, &mut output, Some(&aad)).is_err());
+        assert!(output.iter().all(|&b| b == 0));
+
         // Without AAD
         // 不使用 AAD
         let ciphertext_no_aad = S::encrypt(&key, &nonce, &plaintext, None).unwrap();
@@ -389,10 +412,10 @@ mod tests {
         let key = S::generate_key().unwrap();
         let mut wrong_size_key = key.to_vec();
         wrong_size_key.push(0);
-        let wrong_size_key = Zeroizing::new(wrong_size_key);
+        let wrong_size_key = SymmetricKey::new(wrong_size_key);
 
         let mut nonce = vec![0u8; S::NONCE_SIZE];
-        OsRng.fill_bytes(&mut nonce);
+        SystemRng::new().fill_bytes(&mut nonce);
         let mut wrong_size_nonce = nonce.clone();
         wrong_size_nonce.push(0);
 
@@ -435,4 +458,28 @@ mod tests {
     fn test_xchacha20_poly1305_invalid_inputs() {
         test_invalid_inputs::<Chacha20Poly1305Scheme<XChaCha20Poly1305Params>>();
     }
+
+    #[test]
+    fn test_typed_nonce() {
+        let key = XChaCha20Poly1305::generate_key().unwrap();
+        let mut nonce = Nonce::<XChaCha20Poly1305>::from_counter(1);
+        assert_eq!(nonce.len(), 24);
+
+        let ciphertext =
+            XChaCha20Poly1305::encrypt_with_nonce(&key, nonce.clone(), b"plaintext", None).unwrap();
+        assert_eq!(
+            ciphertext,
+            XChaCha20Poly1305::encrypt(&key, &nonce, b"plaintext", None).unwrap()
+        );
+        assert_eq!(
+            XChaCha20Poly1305::decrypt_with_nonce(&key, &nonce, &ciphertext, None).unwrap(),
+            b"plaintext"
+        );
+
+        nonce.increment().unwrap();
+        assert!(XChaCha20Poly1305::decrypt_with_nonce(&key, &nonce, &ciphertext, None).is_err());
+
+        let random = Nonce::<ChaCha20Poly1305>::random().unwrap();
+        assert_eq!(random.len(), 12);
+    }
 }
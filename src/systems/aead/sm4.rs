@@ -0,0 +1,506 @@
+//! Provides an implementation of symmetric authenticated encryption (AEAD) using SM4.
+//!
+//! SM4 (GB/T 32907-2016) is the 128-bit block cipher of the Chinese commercial cryptography
+//! suite. This module runs it in the two AEAD modes specified for it by RFC 8998, which are
+//! required by deployments subject to the Chinese commercial cryptography regulations.
+//!
+//! # Supported Modes
+//! - **SM4-GCM**: Galois/Counter Mode, 12-byte nonce, 16-byte tag
+//! - **SM4-CCM**: Counter with CBC-MAC, 12-byte nonce, 16-byte tag
+//!
+//! # Security Considerations
+//! - SM4 uses 128-bit keys, giving a security level comparable to AES-128
+//! - Nonces must be unique for each encryption operation with the same key
+//!
+//! 提供了使用 SM4 的对称认证加密（AEAD）实现。
+//!
+//! SM4 (GB/T 32907-2016) 是中国商用密码套件中的 128 位分组密码。此模块以 RFC 8998 为其规定的
+//! 两种 AEAD 模式运行它，受中国商用密码法规约束的部署需要这些模式。
+//!
+//! # 支持的模式
+//! - **SM4-GCM**: 伽罗瓦/计数器模式，12 字节 nonce，16 字节标签
+//! - **SM4-CCM**: 计数器与 CBC-MAC 模式，12 字节 nonce，16 字节标签
+//!
+//! # 安全考虑
+//! - SM4 使用 128 位密钥，其安全级别与 AES-128 相当
+//! - 对于同一密钥的每次加密操作，nonce 必须唯一
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+use crate::ct::ConstantTimeEq;
+use crate::errors::Error;
+use crate::policy;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadInPlace, KeyInit};
+use cipher::consts::{U12, U16};
+use cipher::{BlockCipher, KeySizeUser};
+use core::marker::PhantomData;
+use subtle::ConditionallySelectable;
+use zeroize::Zeroize;
+
+// ------------------- SM4 Block Cipher -------------------
+// ------------------- SM4 分组密码 -------------------
+
+const SBOX: [u8; 256] = [
+    0xd6, 0x90, 0xe9, 0xfe, 0xcc, 0xe1, 0x3d, 0xb7, 0x16, 0xb6, 0x14, 0xc2, 0x28, 0xfb, 0x2c, 0x05,
+    0x2b, 0x67, 0x9a, 0x76, 0x2a, 0xbe, 0x04, 0xc3, 0xaa, 0x44, 0x13, 0x26, 0x49, 0x86, 0x06, 0x99,
+    0x9c, 0x42, 0x50, 0xf4, 0x91, 0xef, 0x98, 0x7a, 0x33, 0x54, 0x0b, 0x43, 0xed, 0xcf, 0xac, 0x62,
+    0xe4, 0xb3, 0x1c, 0xa9, 0xc9, 0x08, 0xe8, 0x95, 0x80, 0xdf, 0x94, 0xfa, 0x75, 0x8f, 0x3f, 0xa6,
+    0x47, 0x07, 0xa7, 0xfc, 0xf3, 0x73, 0x17, 0xba, 0x83, 0x59, 0x3c, 0x19, 0xe6, 0x85, 0x4f, 0xa8,
+    0x68, 0x6b, 0x81, 0xb2, 0x71, 0x64, 0xda, 0x8b, 0xf8, 0xeb, 0x0f, 0x4b, 0x70, 0x56, 0x9d, 0x35,
+    0x1e, 0x24, 0x0e, 0x5e, 0x63, 0x58, 0xd1, 0xa2, 0x25, 0x22, 0x7c, 0x3b, 0x01, 0x21, 0x78, 0x87,
+    0xd4, 0x00, 0x46, 0x57, 0x9f, 0xd3, 0x27, 0x52, 0x4c, 0x36, 0x02, 0xe7, 0xa0, 0xc4, 0xc8, 0x9e,
+    0xea, 0xbf, 0x8a, 0xd2, 0x40, 0xc7, 0x38, 0xb5, 0xa3, 0xf7, 0xf2, 0xce, 0xf9, 0x61, 0x15, 0xa1,
+    0xe0, 0xae, 0x5d, 0xa4, 0x9b, 0x34, 0x1a, 0x55, 0xad, 0x93, 0x32, 0x30, 0xf5, 0x8c, 0xb1, 0xe3,
+    0x1d, 0xf6, 0xe2, 0x2e, 0x82, 0x66, 0xca, 0x60, 0xc0, 0x29, 0x23, 0xab, 0x0d, 0x53, 0x4e, 0x6f,
+    0xd5, 0xdb, 0x37, 0x45, 0xde, 0xfd, 0x8e, 0x2f, 0x03, 0xff, 0x6a, 0x72, 0x6d, 0x6c, 0x5b, 0x51,
+    0x8d, 0x1b, 0xaf, 0x92, 0xbb, 0xdd, 0xbc, 0x7f, 0x11, 0xd9, 0x5c, 0x41, 0x1f, 0x10, 0x5a, 0xd8,
+    0x0a, 0xc1, 0x31, 0x88, 0xa5, 0xcd, 0x7b, 0xbd, 0x2d, 0x74, 0xd0, 0x12, 0xb8, 0xe5, 0xb4, 0xb0,
+    0x89, 0x69, 0x97, 0x4a, 0x0c, 0x96, 0x77, 0x7e, 0x65, 0xb9, 0xf1, 0x09, 0xc5, 0x6e, 0xc6, 0x84,
+    0x18, 0xf0, 0x7d, 0xec, 0x3a, 0xdc, 0x4d, 0x20, 0x79, 0xee, 0x5f, 0x3e, 0xd7, 0xcb, 0x39, 0x48,
+];
+
+const FK: [u32; 4] = [0xa3b1_bac6, 0x56aa_3350, 0x677d_9197, 0xb270_22dc];
+
+/// Looks up `input` in the S-box without a secret-dependent memory access, by scanning the
+/// whole table and selecting the matching entry in constant time.
+fn sbox(input: u8) -> u8 {
+    let mut output = 0u8;
+    for (index, value) in (0..=u8::MAX).zip(SBOX) {
+        output.conditional_assign(&value, input.ct_eq(&index));
+    }
+    output
+}
+
+/// The non-linear substitution `τ`, applying the S-box to each byte.
+fn tau(x: u32) -> u32 {
+    u32::from_be_bytes(x.to_be_bytes().map(sbox))
+}
+
+/// The round function's transform `T = L(τ(x))`.
+fn round_t(x: u32) -> u32 {
+    let b = tau(x);
+    b ^ b.rotate_left(2) ^ b.rotate_left(10) ^ b.rotate_left(18) ^ b.rotate_left(24)
+}
+
+/// The key schedule's transform `T' = L'(τ(x))`.
+fn key_t(x: u32) -> u32 {
+    let b = tau(x);
+    b ^ b.rotate_left(13) ^ b.rotate_left(23)
+}
+
+/// The SM4 block cipher (GB/T 32907-2016) with its expanded round keys.
+///
+/// It implements the RustCrypto `cipher` traits, which is how the generic GCM and CCM modes
+/// drive it. The round keys are zeroized when it is dropped.
+///
+/// SM4 分组密码 (GB/T 32907-2016) 及其扩展后的轮密钥。
+///
+/// 它实现了 RustCrypto 的 `cipher` trait，通用的 GCM 和 CCM 模式正是通过这些 trait 来驱动它。
+/// 轮密钥会在其被丢弃时清零。
+#[derive(Clone)]
+pub struct Sm4 {
+    round_keys: [u32; 32],
+}
+
+impl Sm4 {
+    fn crypt(&self, block: &mut [u8; 16], round_keys: impl Iterator<Item = u32>) {
+        let mut x = [0u32; 4];
+        for (word, chunk) in x.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        for rk in round_keys {
+            let next = x[0] ^ round_t(x[1] ^ x[2] ^ x[3] ^ rk);
+            x = [x[1], x[2], x[3], next];
+        }
+        for (chunk, word) in block.chunks_exact_mut(4).zip(x.iter().rev()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+    }
+}
+
+impl KeySizeUser for Sm4 {
+    type KeySize = U16;
+}
+
+impl KeyInit for Sm4 {
+    fn new(key: &cipher::Key<Self>) -> Self {
+        let mut k = [0u32; 4];
+        for ((word, chunk), fk) in k.iter_mut().zip(key.chunks_exact(4)).zip(FK) {
+            *word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) ^ fk;
+        }
+        let mut round_keys = [0u32; 32];
+        for (i, round_key) in round_keys.iter_mut().enumerate() {
+            // CK_i consists of the bytes (4i + j) * 7 mod 256 for j = 0..4.
+            // CK_i 由字节 (4i + j) * 7 mod 256 组成，其中 j = 0..4。
+            let ck = u32::from_be_bytes([0, 1, 2, 3].map(|j| ((4 * i as u32 + j) * 7 % 256) as u8));
+            let next = k[0] ^ key_t(k[1] ^ k[2] ^ k[3] ^ ck);
+            k = [k[1], k[2], k[3], next];
+            *round_key = next;
+        }
+        k.zeroize();
+        Self { round_keys }
+    }
+}
+
+impl BlockCipher for Sm4 {}
+
+cipher::impl_simple_block_encdec!(
+    Sm4, U16, cipher, block,
+    encrypt: {
+        let mut bytes: [u8; 16] = block.clone_in().into();
+        cipher.crypt(&mut bytes, cipher.round_keys.iter().copied());
+        block.get_out().copy_from_slice(&bytes);
+        bytes.zeroize();
+    }
+    decrypt: {
+        let mut bytes: [u8; 16] = block.clone_in().into();
+        cipher.crypt(&mut bytes, cipher.round_keys.iter().rev().copied());
+        block.get_out().copy_from_slice(&bytes);
+        bytes.zeroize();
+    }
+);
+
+impl Drop for Sm4 {
+    fn drop(&mut self) {
+        self.round_keys.zeroize();
+    }
+}
+
+// ------------------- Marker Structs and Trait for SM4 AEAD Parameters -------------------
+// ------------------- 用于 SM4 AEAD 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A sealed trait that defines the parameters for an SM4 AEAD scheme.
+///
+/// 一个密封的 trait，用于定义 SM4 AEAD 方案的参数。
+pub trait Sm4Params: private::Sealed + SchemeParams {
+    /// The underlying AEAD mode over [`Sm4`].
+    ///
+    /// 基于 [`Sm4`] 的底层 AEAD 模式。
+    type AeadCipher: Aead + AeadInPlace + KeyInit;
+    /// The size of the key in bytes.
+    ///
+    /// 密钥的大小（以字节为单位）。
+    const KEY_SIZE: usize;
+    /// The size of the nonce in bytes.
+    ///
+    /// Nonce 的大小（以字节为单位）。
+    const NONCE_SIZE: usize;
+    /// The size of the authentication tag in bytes.
+    ///
+    /// 认证标签的大小（以字节为单位）。
+    const TAG_SIZE: usize;
+}
+
+/// Marker struct for SM4-GCM parameters (RFC 8998).
+///
+/// SM4-GCM 参数的标记结构体 (RFC 8998)。
+#[derive(Clone, Debug, Default)]
+pub struct Sm4GcmParams;
+impl private::Sealed for Sm4GcmParams {}
+impl SchemeParams for Sm4GcmParams {
+    const NAME: &'static str = "SM4-GCM";
+    const ID: u32 = 0x02_03_01_01;
+}
+impl Sm4Params for Sm4GcmParams {
+    type AeadCipher = aes_gcm::AesGcm<Sm4, U12>;
+    const KEY_SIZE: usize = 16;
+    const NONCE_SIZE: usize = 12;
+    const TAG_SIZE: usize = 16;
+}
+
+/// Marker struct for SM4-CCM parameters (RFC 8998).
+///
+/// SM4-CCM 参数的标记结构体 (RFC 8998)。
+#[derive(Clone, Debug, Default)]
+pub struct Sm4CcmParams;
+impl private::Sealed for Sm4CcmParams {}
+impl SchemeParams for Sm4CcmParams {
+    const NAME: &'static str = "SM4-CCM";
+    const ID: u32 = 0x02_03_02_01;
+}
+impl Sm4Params for Sm4CcmParams {
+    type AeadCipher = ccm::Ccm<Sm4, U16, U12>;
+    const KEY_SIZE: usize = 16;
+    const NONCE_SIZE: usize = 12;
+    const TAG_SIZE: usize = 16;
+}
+
+// ------------------- Generic SM4 AEAD Implementation -------------------
+// ------------------- 通用 SM4 AEAD 实现 -------------------
+
+/// A generic struct representing an SM4 AEAD scheme for a given parameter set.
+///
+/// # Type Parameters
+/// * `P` - The parameter set selecting the mode, nonce size and tag size
+///
+/// 一个通用结构体，表示给定参数集的 SM4 AEAD 方案。
+///
+/// # 类型参数
+/// * `P` - 选择模式、nonce 大小和标签大小的参数集
+#[derive(Clone, Debug, Default)]
+pub struct Sm4Scheme<P: Sm4Params> {
+    _params: PhantomData<P>,
+}
+
+impl<P: Sm4Params> Algorithm for Sm4Scheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: Sm4Params> SymmetricKeySet for Sm4Scheme<P> {
+    type Key = SymmetricKey;
+}
+
+impl<P: Sm4Params> AeadCipher for Sm4Scheme<P> {
+    const KEY_SIZE: usize = P::KEY_SIZE;
+    const NONCE_SIZE: usize = P::NONCE_SIZE;
+    const TAG_SIZE: usize = P::TAG_SIZE;
+}
+
+impl<P: Sm4Params> SymmetricKeyGenerator for Sm4Scheme<P> {
+    const KEY_SIZE: usize = P::KEY_SIZE;
+
+    fn generate_key() -> Result<SymmetricKey, Error> {
+        policy::enforce::<Self>()?;
+        let mut key_bytes = vec![0u8; P::KEY_SIZE];
+        SystemRng::new()
+            .try_fill_bytes(&mut key_bytes)
+            .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        Ok(SymmetricKey::new(key_bytes))
+    }
+}
+
+impl<P: Sm4Params> AeadEncryptor for Sm4Scheme<P> {
+    fn encrypt_to_buffer(
+        key: &Self::Key,
+        nonce: &[u8],
+        plaintext: &[u8],
+        output: &mut [u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
+        if key.len() != P::KEY_SIZE {
+            return Err(Error::Symmetric(SymmetricError::InvalidKeySize));
+        }
+        if nonce.len() != P::NONCE_SIZE {
+            return Err(Error::Symmetric(SymmetricError::InvalidNonceSize));
+        }
+
+        let required_len = plaintext.len() + P::TAG_SIZE;
+        if output.len() < required_len {
+            return Err(Error::Symmetric(SymmetricError::OutputTooSmall));
+        }
+
+        let key = aes_gcm::aead::Key::<P::AeadCipher>::from_slice(key);
+        let cipher = P::AeadCipher::new(key);
+        let nonce = aes_gcm::aead::Nonce::<P::AeadCipher>::from_slice(nonce);
+
+        let (ciphertext_buf, tag_buf) = output.split_at_mut(plaintext.len());
+        ciphertext_buf.copy_from_slice(plaintext);
+
+        let tag = cipher
+            .encrypt_in_place_detached(nonce, aad.unwrap_or_default(), ciphertext_buf)
+            .map_err(|_| {
+                ciphertext_buf.zeroize();
+                Error::Symmetric(SymmetricError::Encryption)
+            })?;
+
+        tag_buf[..P::TAG_SIZE].copy_from_slice(&tag);
+
+        Ok(required_len)
+    }
+}
+
+impl<P: Sm4Params> AeadDecryptor for Sm4Scheme<P> {
+    fn decrypt_to_buffer(
+        key: &Self::Key,
+        nonce: &[u8],
+        ciphertext_with_tag: &[u8],
+        output: &mut [u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
+        if key.len() != P::KEY_SIZE {
+            return Err(Error::Symmetric(SymmetricError::InvalidKeySize));
+        }
+        if nonce.len() != P::NONCE_SIZE {
+            return Err(Error::Symmetric(SymmetricError::InvalidNonceSize));
+        }
+        if ciphertext_with_tag.len() < P::TAG_SIZE {
+            return Err(Error::Symmetric(SymmetricError::InvalidCiphertext));
+        }
+
+        let (ciphertext, tag) =
+            ciphertext_with_tag.split_at(ciphertext_with_tag.len() - P::TAG_SIZE);
+
+        if output.len() < ciphertext.len() {
+            return Err(Error::Symmetric(SymmetricError::OutputTooSmall));
+        }
+
+        let key = aes_gcm::aead::Key::<P::AeadCipher>::from_slice(key);
+        let cipher = P::AeadCipher::new(key);
+        let nonce = aes_gcm::aead::Nonce::<P::AeadCipher>::from_slice(nonce);
+        let tag = aes_gcm::aead::Tag::<P::AeadCipher>::from_slice(tag);
+
+        let plaintext_buf = &mut output[..ciphertext.len()];
+        plaintext_buf.copy_from_slice(ciphertext);
+
+        cipher
+            .decrypt_in_place_detached(nonce, aad.unwrap_or_default(), plaintext_buf, tag)
+            .map_err(|_| {
+                plaintext_buf.zeroize();
+                Error::Symmetric(SymmetricError::Decryption)
+            })?;
+
+        Ok(plaintext_buf.len())
+    }
+}
+
+// ------------------- Type Aliases -------------------
+// ------------------- 类型别名 -------------------
+
+/// A type alias for the SM4-GCM scheme.
+///
+/// SM4-GCM 方案的类型别名。
+pub type Sm4Gcm = Sm4Scheme<Sm4GcmParams>;
+
+/// A type alias for the SM4-CCM scheme.
+///
+/// SM4-CCM 方案的类型别名。
+pub type Sm4Ccm = Sm4Scheme<Sm4CcmParams>;
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cipher::{BlockDecrypt, BlockEncrypt};
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap_or_default())
+            .collect()
+    }
+
+    // RFC 8998, Appendix A.
+    const KEY: &str = "0123456789abcdeffedcba9876543210";
+    const NONCE: &str = "00001234567800000000abcd";
+    const AAD: &str = "feedfacedeadbeeffeedfacedeadbeefabaddad2";
+    const PLAINTEXT: &str = "aaaaaaaaaaaaaaaabbbbbbbbbbbbbbbbccccccccccccccccdddddddddddddddd\
+                             eeeeeeeeeeeeeeeeffffffffffffffffeeeeeeeeeeeeeeeeaaaaaaaaaaaaaaaa";
+
+    #[test]
+    fn test_block_cipher_vector() {
+        // GB/T 32907-2016, Appendix A.1.
+        let key = unhex(KEY);
+        let cipher = Sm4::new(cipher::Key::<Sm4>::from_slice(&key));
+        let mut block = cipher::Block::<Sm4>::clone_from_slice(&key);
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block.as_slice(), unhex("681edf34d206965e86b3e94f536e4246"));
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block.as_slice(), key.as_slice());
+    }
+
+    #[test]
+    fn test_key_schedule_vector() {
+        // GB/T 32907-2016, Appendix A.1: the first and last round keys.
+        let key = unhex(KEY);
+        let cipher = Sm4::new(cipher::Key::<Sm4>::from_slice(&key));
+        assert_eq!(cipher.round_keys[0], 0xf121_86f9);
+        assert_eq!(cipher.round_keys[31], 0x9124_a012);
+    }
+
+    #[test]
+    fn test_sbox_matches_table() {
+        for (input, expected) in (0..=u8::MAX).zip(SBOX) {
+            assert_eq!(sbox(input), expected);
+        }
+    }
+
+    fn check_vector<S>(ciphertext: &str, tag: &str) -> Result<(), Error>
+    where
+        S: AeadEncryptor<Key = SymmetricKey> + AeadDecryptor<Key = SymmetricKey>,
+    {
+        let key = SymmetricKey::new(unhex(KEY));
+        let (nonce, aad, plaintext) = (unhex(NONCE), unhex(AAD), unhex(PLAINTEXT));
+        let mut expected = unhex(ciphertext);
+        expected.extend(unhex(tag));
+
+        assert_eq!(S::encrypt(&key, &nonce, &plaintext, Some(&aad))?, expected);
+        assert_eq!(S::decrypt(&key, &nonce, &expected, Some(&aad))?, plaintext);
+
+        let mut tampered = expected.clone();
+        tampered[0] ^= 1;
+        assert_eq!(
+            S::decrypt(&key, &nonce, &tampered, Some(&aad)),
+            Err(Error::Symmetric(SymmetricError::Decryption))
+        );
+        let mut output = vec![0xffu8; plaintext.len()];
+        assert_eq!(
+            S::decrypt_to_buffer(&key, &nonce, &tampered, &mut output, Some(&aad)),
+            Err(Error::Symmetric(SymmetricError::Decryption))
+        );
+        assert!(output.iter().all(|&b| b == 0));
+        assert_eq!(
+            S::decrypt(&key, &nonce, &expected, None),
+            Err(Error::Symmetric(SymmetricError::Decryption))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sm4_gcm_vector() -> Result<(), Error> {
+        check_vector::<Sm4Gcm>(
+            "17f399f08c67d5ee19d0dc9969c4bb7d5fd46fd3756489069157b282bb200735\
+             d82710ca5c22f0ccfa7cbf93d496ac15a56834cbcf98c397b4024a2691233b8d",
+            "83de3541e4c2b58177e065a9bf7b62ec",
+        )
+    }
+
+    #[test]
+    fn test_sm4_ccm_vector() -> Result<(), Error> {
+        check_vector::<Sm4Ccm>(
+            "48af93501fa62adbcd414cce6034d895dda1bf8f132f042098661572e7483094\
+             fd12e518ce062c98acee28d95df4416bed31a2f04476c18bb40c84a74b97dc5b",
+            "16842d4fa186f56ab33256971fa110f4",
+        )
+    }
+
+    fn check_roundtrip<S: AeadScheme<Key = SymmetricKey>>() -> Result<(), Error> {
+        let key = S::generate_key()?;
+        assert_eq!(key.len(), <S as AeadCipher>::KEY_SIZE);
+        let nonce = vec![7u8; <S as AeadCipher>::NONCE_SIZE];
+        let ciphertext = S::encrypt(&key, &nonce, b"", None)?;
+        assert_eq!(ciphertext.len(), S::TAG_SIZE);
+        assert_eq!(S::decrypt(&key, &nonce, &ciphertext, None)?, b"");
+
+        assert_eq!(
+            S::encrypt(&key, &nonce[1..], b"data", None),
+            Err(Error::Symmetric(SymmetricError::InvalidNonceSize))
+        );
+        assert_eq!(
+            S::encrypt(&SymmetricKey::new(vec![0; 15]), &nonce, b"data", None),
+            Err(Error::Symmetric(SymmetricError::InvalidKeySize))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_roundtrip() -> Result<(), Error> {
+        check_roundtrip::<Sm4Gcm>()?;
+        check_roundtrip::<Sm4Ccm>()
+    }
+}
@@ -1,4 +1,10 @@
+#[cfg(any(feature = "kyber-default", feature = "dilithium-default"))]
+mod der;
 #[cfg(feature = "dilithium-default")]
 pub mod dilithium;
+#[cfg(feature = "dilithium-default")]
+mod dilithium_keygen;
 #[cfg(feature = "kyber-default")]
 pub mod kyber;
+#[cfg(feature = "kyber-default")]
+mod kyber_keygen;
@@ -0,0 +1,65 @@
+//! DER `SubjectPublicKeyInfo` and PKCS#8 encodings shared by the post-quantum schemes.
+//!
+//! Following the IETF drafts for lattice-based keys in X.509, the raw public key is the
+//! `subjectPublicKey` bit string and the raw secret key is the `privateKey` octet string; the
+//! algorithm identifier carries the parameter set's OID and no parameters.
+//!
+//! 由后量子方案共享的 DER `SubjectPublicKeyInfo` 和 PKCS#8 编码。
+//!
+//! 按照 IETF 关于 X.509 中格密钥的草案，原始公钥作为 `subjectPublicKey` 位串，原始私钥作为
+//! `privateKey` 八位字节串；算法标识符携带参数集的 OID，且不带参数。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+use crate::prelude::*;
+use pkcs8::der::asn1::BitStringRef;
+use pkcs8::der::{Decode, Encode};
+use pkcs8::spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef};
+use pkcs8::{ObjectIdentifier, PrivateKeyInfo};
+use zeroize::Zeroizing;
+
+fn algorithm(oid: ObjectIdentifier) -> AlgorithmIdentifierRef<'static> {
+    AlgorithmIdentifierRef {
+        oid,
+        parameters: None,
+    }
+}
+
+pub(super) fn encode_spki(oid: ObjectIdentifier, public_key: &[u8]) -> Result<Vec<u8>, Error> {
+    SubjectPublicKeyInfoRef {
+        algorithm: algorithm(oid),
+        subject_public_key: BitStringRef::from_bytes(public_key)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?,
+    }
+    .to_der()
+    .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
+pub(super) fn decode_spki(oid: ObjectIdentifier, der: &[u8]) -> Result<&[u8], Error> {
+    let spki = SubjectPublicKeyInfoRef::from_der(der)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    if spki.algorithm != algorithm(oid) {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    spki.subject_public_key
+        .as_bytes()
+        .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+pub(super) fn encode_pkcs8(
+    oid: ObjectIdentifier,
+    secret_key: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    PrivateKeyInfo::new(algorithm(oid), secret_key)
+        .to_der()
+        .map(Zeroizing::new)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
+pub(super) fn decode_pkcs8(oid: ObjectIdentifier, der: &[u8]) -> Result<&[u8], Error> {
+    let info = PrivateKeyInfo::try_from(der).map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    if info.algorithm != algorithm(oid) {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    Ok(info.private_key)
+}
@@ -53,6 +53,25 @@
 //! standardization process. It is recommended for new applications requiring
 //! quantum-resistant digital signatures.
 //!
+//! # Key Encodings
+//! Besides their raw bytes, keys can be encoded as a DER `SubjectPublicKeyInfo` or PKCS#8
+//! `PrivateKeyInfo`, identified by the round 3 OIDs used by the IETF drafts and Open Quantum
+//! Safe (`1.3.6.1.4.1.2.267.7.4.4`, `.6.5` and `.8.7`). The standardized ML-DSA algorithms are not
+//! interoperable with round 3 Dilithium and have their own OIDs.
+//!
+//! # Message Framing
+//! Plain signatures (`Signer`/`Verifier`) sign the raw message, as every round 3 Dilithium
+//! implementation does. Context and digest signatures (`ContextSigner`, `DigestSigner`) frame
+//! the message first, signing `0x00 || len(ctx) || ctx || message` and
+//! `0x01 || 0x00 || OID || digest` respectively. This framing is non-standard: it borrows the
+//! byte layout of FIPS 204, but the signatures are not ML-DSA signatures and no other
+//! implementation produces or verifies them. Context and digest signatures never verify as one
+//! another, but a plain signature over framed bytes verifies as a context or digest signature,
+//! so a key that signs attacker-chosen plain messages should not also be used for them.
+//!
+//! A secret key generated from a seed, by `generate_keypair_with_rng` or `from_seed`, can be
+//! stored as that 32-byte seed (`to_seed`) instead of its multi-kilobyte expanded form.
+//!
 //! 提供了 Dilithium 后量子签名方案的实现。
 //!
 //! Dilithium 是一种数字签名方案，设计为能够抵抗经典和量子计算机的攻击。
@@ -104,15 +123,37 @@
 //! # 标准化状态
 //! Dilithium 正在被 NIST 作为后量子密码学标准化过程的一部分进行标准化。
 //! 推荐用于需要抗量子数字签名的新应用程序。
+//!
+//! # 密钥编码
+//! 除原始字节外，密钥还可以编码为 DER `SubjectPublicKeyInfo` 或 PKCS#8 `PrivateKeyInfo`，
+//! 以 IETF 草案和 Open Quantum Safe 使用的第三轮 OID（`1.3.6.1.4.1.2.267.7.4.4`、`.6.5` 和 `.8.7`）标识。
+//! 标准化的 ML-DSA 算法与第三轮 Dilithium 不能互操作，并使用其自己的 OID。
+//!
+//! # 消息封装
+//! 普通签名（`Signer`/`Verifier`）与所有第三轮 Dilithium 实现一样，直接对原始消息签名。
+//! 上下文签名和摘要签名（`ContextSigner`、`DigestSigner`）会先封装消息，分别对
+//! `0x00 || len(ctx) || ctx || message` 和 `0x01 || 0x00 || OID || digest` 签名。该封装是非标准的：
+//! 它借用了 FIPS 204 的字节布局，但所得签名不是 ML-DSA 签名，其他实现既不会生成也无法验证它们。
+//! 上下文签名和摘要签名永远不会相互验证通过，但对封装后字节的普通签名可以作为上下文签名或摘要签名
+//! 验证通过，因此对攻击者选择的普通消息签名的密钥不应同时用于这两类签名。
+//!
+//! 由种子生成的私钥（通过 `generate_keypair_with_rng` 或 `from_seed`）可以存储为该 32 字节种子
+//! （`to_seed`），而不必存储数 KB 的扩展形式。
 
+use alloc::{format, string::String, vec, vec::Vec};
+use super::{der, dilithium_keygen};
+use crate::ct::{Choice, ConstantTimeEq};
 use crate::errors::Error;
+use crate::policy;
 use crate::prelude::*;
+use crate::traits::asymmetric::write_signature;
+use pkcs8::ObjectIdentifier;
 use pqcrypto_dilithium::{dilithium2, dilithium3, dilithium5};
 use pqcrypto_traits::sign::{
     DetachedSignature as PqDetachedSignature, PublicKey as PqPublicKey, SecretKey as PqSecretKey,
 };
-use std::convert::TryFrom;
-use std::marker::PhantomData;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
 use zeroize::{Zeroize, Zeroizing};
 
 // ------------------- Marker Structs and Trait for Dilithium Parameters -------------------
@@ -128,10 +169,28 @@ mod private {
 /// 一个定义特定 Dilithium 安全级别参数的 trait。
 /// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
 pub trait DilithiumParams: private::Sealed + SchemeParams {
-    type PqPublicKey: PqPublicKey + Clone;
+    type PqPublicKey: PqPublicKey + Clone + Send + Sync;
     type PqSecretKey: PqSecretKey + Clone;
     type PqDetachedSignature: PqDetachedSignature;
 
+    const SIGNATURE_BYTES: usize;
+    /// The OID identifying this parameter set in `SubjectPublicKeyInfo` and PKCS#8 encodings.
+    ///
+    /// 在 `SubjectPublicKeyInfo` 和 PKCS#8 编码中标识此参数集的 OID。
+    const OID: ObjectIdentifier;
+    /// The number of rows `k` of the public matrix `A`.
+    ///
+    /// 公共矩阵 `A` 的行数 `k`。
+    const K: usize;
+    /// The number of columns `l` of the public matrix `A`.
+    ///
+    /// 公共矩阵 `A` 的列数 `l`。
+    const L: usize;
+    /// The bound `eta` on the coefficients of the secret vectors `s1` and `s2`.
+    ///
+    /// 秘密向量 `s1` 和 `s2` 系数的界 `eta`。
+    const ETA: i64;
+
     fn public_key_bytes() -> usize;
     fn secret_key_bytes() -> usize;
 
@@ -166,6 +225,12 @@ impl DilithiumParams for Dilithium2Params {
     type PqSecretKey = dilithium2::SecretKey;
     type PqDetachedSignature = dilithium2::DetachedSignature;
 
+    const SIGNATURE_BYTES: usize = 2420;
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.2.267.7.4.4");
+    const K: usize = 4;
+    const L: usize = 4;
+    const ETA: i64 = 2;
+
     fn public_key_bytes() -> usize {
         dilithium2::public_key_bytes()
     }
@@ -211,6 +276,12 @@ impl DilithiumParams for Dilithium3Params {
     type PqSecretKey = dilithium3::SecretKey;
     type PqDetachedSignature = dilithium3::DetachedSignature;
 
+    const SIGNATURE_BYTES: usize = 3309;
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.2.267.7.6.5");
+    const K: usize = 6;
+    const L: usize = 5;
+    const ETA: i64 = 4;
+
     fn public_key_bytes() -> usize {
         dilithium3::public_key_bytes()
     }
@@ -257,6 +328,12 @@ impl DilithiumParams for Dilithium5Params {
     type PqSecretKey = dilithium5::SecretKey;
     type PqDetachedSignature = dilithium5::DetachedSignature;
 
+    const SIGNATURE_BYTES: usize = 4627;
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.2.267.7.8.7");
+    const K: usize = 8;
+    const L: usize = 7;
+    const ETA: i64 = 2;
+
     fn public_key_bytes() -> usize {
         dilithium5::public_key_bytes()
     }
@@ -289,6 +366,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DilithiumPublicKey<P: DilithiumParams> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
     bytes: Vec<u8>,
     _params: PhantomData<P>,
 }
@@ -321,14 +399,34 @@ impl<P: DilithiumParams> TryFrom<&[u8]> for DilithiumPublicKey<P> {
     }
 }
 
-#[derive(Debug, Zeroize, Clone, Eq, PartialEq)]
+#[derive(Debug, Zeroize, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[zeroize(drop)]
 pub struct DilithiumSecretKey<P: DilithiumParams + Clone> {
-    bytes: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    bytes: SecretBytes,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::traits::key::serde_bytes::option", default)
+    )]
+    seed: Option<Zeroizing<Vec<u8>>>,
     _params: PhantomData<P>,
 }
 
+impl<P: DilithiumParams + Clone> ConstantTimeEq for DilithiumSecretKey<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.as_slice().ct_eq(other.bytes.as_slice())
+    }
+}
+
+impl<P: DilithiumParams + Clone> PartialEq for DilithiumSecretKey<P> {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl<P: DilithiumParams + Clone> Eq for DilithiumSecretKey<P> {}
+
 impl<P: DilithiumParams> Key for DilithiumPublicKey<P> {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         if bytes.len() != P::public_key_bytes() {
@@ -345,13 +443,16 @@ impl<P: DilithiumParams> Key for DilithiumPublicKey<P> {
 }
 impl<P: DilithiumParams> PublicKey for DilithiumPublicKey<P> {}
 
+impl_hex_display!(DilithiumPublicKey<P: DilithiumParams>);
+
 impl<P: DilithiumParams + Clone> Key for DilithiumSecretKey<P> {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         if bytes.len() != P::secret_key_bytes() {
             return Err(Error::Key(KeyError::InvalidEncoding));
         }
         Ok(Self {
-            bytes: Zeroizing::new(bytes.to_vec()),
+            bytes: SecretBytes::from_slice(bytes),
+            seed: None,
             _params: PhantomData,
         })
     }
@@ -369,6 +470,104 @@ impl<P: DilithiumParams + Clone> TryFrom<&[u8]> for DilithiumSecretKey<P> {
 
 impl<P: DilithiumParams + Clone> PrivateKey<DilithiumPublicKey<P>> for DilithiumSecretKey<P> {}
 
+impl<P: DilithiumParams> DilithiumPublicKey<P> {
+    /// Encodes the public key as a DER `SubjectPublicKeyInfo`.
+    ///
+    /// 将公钥编码为 DER `SubjectPublicKeyInfo`。
+    pub fn to_spki_der(&self) -> Result<Vec<u8>, Error> {
+        der::encode_spki(P::OID, &self.bytes)
+    }
+
+    /// Decodes a public key from a DER `SubjectPublicKeyInfo`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the encoding is malformed or names another
+    /// algorithm or parameter set.
+    ///
+    /// 从 DER `SubjectPublicKeyInfo` 解码公钥。
+    ///
+    /// 如果编码格式错误或指定了其他算法或参数集，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_spki_der(der: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(der::decode_spki(P::OID, der)?)
+    }
+}
+
+impl<P: DilithiumParams + Clone> DilithiumSecretKey<P> {
+    /// The length in bytes of the seed a secret key is generated from.
+    ///
+    /// 生成私钥所用种子的字节长度。
+    pub const SEED_BYTES: usize = 32;
+
+    /// Expands a secret key from its 32-byte generation seed.
+    ///
+    /// The seed is kept with the key so that it can be stored in place of the expanded key.
+    /// Fails with `KeyError::InvalidLength` if the seed is not 32 bytes long.
+    ///
+    /// 从 32 字节的生成种子扩展出私钥。
+    ///
+    /// 种子会与密钥一起保存，以便代替扩展后的密钥进行存储。
+    /// 如果种子长度不是 32 字节，则以 `KeyError::InvalidLength` 失败。
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        Ok(keypair_from_seed::<P>(seed)?.1)
+    }
+
+    /// Returns the 32-byte seed the secret key was generated from.
+    ///
+    /// Only keys from [`KeyGenerator::generate_keypair_with_rng`] or [`Self::from_seed`] carry
+    /// their seed; other keys fail with `KeyError::SeedUnavailable`.
+    ///
+    /// 返回生成该私钥所用的 32 字节种子。
+    ///
+    /// 只有来自 [`KeyGenerator::generate_keypair_with_rng`] 或 [`Self::from_seed`] 的密钥才带有种子；
+    /// 其他密钥以 `KeyError::SeedUnavailable` 失败。
+    pub fn to_seed(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        self.seed
+            .clone()
+            .ok_or(Error::Key(KeyError::SeedUnavailable))
+    }
+
+    /// Encodes the secret key as a DER PKCS#8 `PrivateKeyInfo`.
+    ///
+    /// 将私钥编码为 DER PKCS#8 `PrivateKeyInfo`。
+    pub fn to_pkcs8_der(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        der::encode_pkcs8(P::OID, &self.bytes)
+    }
+
+    /// Decodes a secret key from a DER PKCS#8 `PrivateKeyInfo`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the encoding is malformed or names another
+    /// algorithm or parameter set.
+    ///
+    /// 从 DER PKCS#8 `PrivateKeyInfo` 解码私钥。
+    ///
+    /// 如果编码格式错误或指定了其他算法或参数集，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(der::decode_pkcs8(P::OID, der)?)
+    }
+}
+
+/// Builds the key pair generated from `seed`.
+///
+/// 构建由 `seed` 生成的密钥对。
+fn keypair_from_seed<P: DilithiumParams + Clone>(
+    seed: &[u8],
+) -> Result<(DilithiumPublicKey<P>, DilithiumSecretKey<P>), Error> {
+    if seed.len() != DilithiumSecretKey::<P>::SEED_BYTES {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    let (pk, sk) = dilithium_keygen::keypair_from_seed(seed, P::K, P::L, P::ETA);
+    Ok((
+        DilithiumPublicKey {
+            bytes: pk,
+            _params: PhantomData,
+        },
+        DilithiumSecretKey {
+            bytes: sk.into(),
+            seed: Some(Zeroizing::new(seed.to_vec())),
+            _params: PhantomData,
+        },
+    ))
+}
+
 // ------------------- Generic Dilithium Implementation -------------------
 // ------------------- 通用 Dilithium 实现 -------------------
 
@@ -421,6 +620,7 @@ impl<P: DilithiumParams + Clone + 'static> Algorithm for DilithiumScheme<P> {
 
 impl<P: DilithiumParams + Clone> KeyGenerator for DilithiumScheme<P> {
     fn generate_keypair() -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
+        policy::enforce::<Self>()?;
         let (pk, sk) = P::keypair();
         Ok((
             DilithiumPublicKey {
@@ -428,19 +628,44 @@ impl<P: DilithiumParams + Clone> KeyGenerator for DilithiumScheme<P> {
                 _params: PhantomData,
             },
             DilithiumSecretKey {
-                bytes: Zeroizing::new(sk.as_bytes().to_vec()),
+                bytes: SecretBytes::from_slice(sk.as_bytes()),
+                seed: None,
                 _params: PhantomData,
             },
         ))
     }
+
+    fn generate_keypair_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
+        policy::enforce::<Self>()?;
+        let mut seed = Zeroizing::new(vec![0u8; DilithiumSecretKey::<P>::SEED_BYTES]);
+        rng.try_fill_bytes(&mut seed)
+            .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        keypair_from_seed(&seed)
+    }
+}
+
+impl<P: DilithiumParams + Clone> PublicKeyDerivation for DilithiumScheme<P> {
+    fn derive_public_key(private_key: &Self::PrivateKey) -> Result<Self::PublicKey, Error> {
+        DilithiumPublicKey::from_bytes(&dilithium_keygen::public_key_from_secret_key(
+            &private_key.bytes,
+            P::K,
+            P::L,
+            P::ETA,
+        )?)
+    }
 }
 
 impl<P: DilithiumParams + Clone> Signer for DilithiumScheme<P> {
-    fn sign(private_key: &Self::PrivateKey, message: &[u8]) -> Result<Signature, Error> {
-        let sk = PqSecretKey::from_bytes(&private_key.bytes)
-            .map_err(|_| Error::Signature(SignatureError::Signing))?;
-        let sig = P::sign(&sk, message);
-        Ok(sig.as_bytes().to_vec())
+    const MAX_SIGNATURE_SIZE: usize = P::SIGNATURE_BYTES;
+
+    fn sign_to_buffer(
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error> {
+        sign_raw::<P>(private_key, message, output)
     }
 }
 
@@ -450,11 +675,144 @@ impl<P: DilithiumParams + Clone> Verifier for DilithiumScheme<P> {
         message: &[u8],
         signature: &Signature,
     ) -> Result<(), Error> {
-        let pk = PqPublicKey::from_bytes(&public_key.bytes)
-            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
-        let sig = PqDetachedSignature::from_bytes(signature.as_ref())
-            .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
-        P::verify(&sig, message, &pk)
+        Self::verify_prepared(&Self::prepare_public_key(public_key)?, message, signature)
+    }
+}
+
+impl<P: DilithiumParams + Clone> PreparableVerifier for DilithiumScheme<P> {
+    type PreparedKey = P::PqPublicKey;
+
+    fn prepare_public_key(public_key: &Self::PublicKey) -> Result<Self::PreparedKey, Error> {
+        PqPublicKey::from_bytes(&public_key.bytes)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn verify_prepared(
+        prepared_key: &Self::PreparedKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        verify_raw::<P>(prepared_key, message, signature)
+    }
+}
+
+/// Frames a message for a context signature as `0x00 || len(ctx) || ctx || message`. This is
+/// not a standard Dilithium encoding; see the module documentation.
+///
+/// 将上下文签名的消息封装为 `0x00 || len(ctx) || ctx || message`。这不是标准的 Dilithium 编码，
+/// 参见模块文档。
+fn frame_message(context: &[u8], message: &[u8]) -> Result<Vec<u8>, Error> {
+    if context.len() > MAX_CONTEXT_LEN {
+        return Err(Error::Signature(SignatureError::InvalidContext));
+    }
+    let mut framed = Vec::with_capacity(2 + context.len() + message.len());
+    framed.push(0x00);
+    framed.push(context.len() as u8);
+    framed.extend_from_slice(context);
+    framed.extend_from_slice(message);
+    Ok(framed)
+}
+
+fn sign_raw<P: DilithiumParams>(
+    private_key: &DilithiumSecretKey<P>,
+    message: &[u8],
+    output: &mut [u8],
+) -> Result<usize, Error> {
+    policy::enforce::<DilithiumScheme<P>>()?;
+    let sk = PqSecretKey::from_bytes(&private_key.bytes)
+        .map_err(|_| Error::Signature(SignatureError::Signing))?;
+    let sig = P::sign(&sk, message);
+    write_signature(sig.as_bytes(), output)
+}
+
+fn sign_raw_to_vec<P: DilithiumParams>(
+    private_key: &DilithiumSecretKey<P>,
+    message: &[u8],
+) -> Result<Signature, Error> {
+    let mut signature = vec![0u8; P::SIGNATURE_BYTES];
+    let bytes_written = sign_raw::<P>(private_key, message, &mut signature)?;
+    signature.truncate(bytes_written);
+    Ok(signature)
+}
+
+fn verify_raw<P: DilithiumParams>(
+    prepared_key: &P::PqPublicKey,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<(), Error> {
+    policy::enforce::<DilithiumScheme<P>>()?;
+    let sig = PqDetachedSignature::from_bytes(signature.as_ref())
+        .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
+    P::verify(&sig, message, prepared_key)
+}
+
+impl<P: DilithiumParams + Clone> ContextSigner for DilithiumScheme<P> {
+    fn sign_with_context(
+        private_key: &Self::PrivateKey,
+        context: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        sign_raw_to_vec::<P>(private_key, &frame_message(context, message)?)
+    }
+}
+
+impl<P: DilithiumParams + Clone> ContextVerifier for DilithiumScheme<P> {
+    fn verify_with_context(
+        public_key: &Self::PublicKey,
+        context: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        verify_raw::<P>(
+            &Self::prepare_public_key(public_key)?,
+            &frame_message(context, message)?,
+            signature,
+        )
+    }
+}
+
+/// Pre-hash mode: the signed message is `0x01 || 0x00 || OID || digest`, where `OID` is the
+/// DER-encoded OID of the hash algorithm. The leading `0x01` keeps digest signatures apart from
+/// the `0x00`-framed context signatures, and the OID separates digests of different hash
+/// algorithms. Like the context framing, this is not a standard Dilithium encoding.
+///
+/// 预哈希模式：被签名的消息是 `0x01 || 0x00 || OID || digest`，其中 `OID` 是哈希算法的 DER
+/// 编码 OID。开头的 `0x01` 使摘要签名与以 `0x00` 封装的上下文签名区分开，OID 则对不同哈希
+/// 算法的摘要实现域分离。与上下文封装一样，这不是标准的 Dilithium 编码。
+#[cfg(feature = "sha2")]
+fn prehash_message<H: Hasher>(digest: &[u8]) -> Result<Vec<u8>, Error> {
+    if digest.len() != H::OUTPUT_SIZE {
+        return Err(Error::Signature(SignatureError::InvalidDigestLength));
+    }
+    let mut message = Vec::with_capacity(2 + H::OID.len() + digest.len());
+    message.extend_from_slice(&[0x01, 0x00]);
+    message.extend_from_slice(H::OID);
+    message.extend_from_slice(digest);
+    Ok(message)
+}
+
+#[cfg(feature = "sha2")]
+impl<P: DilithiumParams + Clone> DigestSigner for DilithiumScheme<P> {
+    fn sign_digest<H: Hasher>(
+        private_key: &Self::PrivateKey,
+        digest: &[u8],
+    ) -> Result<Signature, Error> {
+        sign_raw_to_vec::<P>(private_key, &prehash_message::<H>(digest)?)
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl<P: DilithiumParams + Clone> DigestVerifier for DilithiumScheme<P> {
+    fn verify_digest<H: Hasher>(
+        public_key: &Self::PublicKey,
+        digest: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        verify_raw::<P>(
+            &Self::prepare_public_key(public_key)?,
+            &prehash_message::<H>(digest)?,
+            signature,
+        )
     }
 }
 
@@ -482,8 +840,9 @@ pub type Dilithium5 = DilithiumScheme<Dilithium5Params>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::systems::test_rng::SeededRng;
 
-    fn run_dilithium_tests<P: DilithiumParams + Default + Clone + std::fmt::Debug>() {
+    fn run_dilithium_tests<P: DilithiumParams + Default + Clone + core::fmt::Debug>() {
         // Test key generation
         // 测试密钥生成
         let (pk, sk) = DilithiumScheme::<P>::generate_keypair().unwrap();
@@ -499,22 +858,155 @@ mod tests {
         assert_eq!(pk, pk2);
         assert_eq!(sk.to_bytes(), sk2.to_bytes());
 
+        // Test public key derivation
+        // 测试公钥派生
+        assert_eq!(DilithiumScheme::<P>::derive_public_key(&sk).unwrap(), pk);
+        DilithiumScheme::<P>::validate_keypair(&pk, &sk).unwrap();
+        let (other_pk, _) = DilithiumScheme::<P>::generate_keypair().unwrap();
+        assert_eq!(
+            DilithiumScheme::<P>::validate_keypair(&other_pk, &sk).unwrap_err(),
+            Error::Key(KeyError::KeyPairMismatch)
+        );
+        let mut corrupted = sk_bytes.clone();
+        corrupted[0] ^= 1;
+        let corrupted = DilithiumSecretKey::<P>::from_bytes(&corrupted).unwrap();
+        assert!(DilithiumScheme::<P>::derive_public_key(&corrupted).is_err());
+
+        // Test key generation from a caller-supplied RNG is reproducible and interoperates
+        // with the backend
+        // 测试使用调用者提供的 RNG 生成密钥是可复现的，并且可以与后端互操作
+        let (rng_pk, rng_sk) =
+            DilithiumScheme::<P>::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        let (rng_pk2, rng_sk2) =
+            DilithiumScheme::<P>::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        assert_eq!(rng_pk, rng_pk2);
+        assert_eq!(rng_sk, rng_sk2);
+        assert_eq!(rng_pk.to_bytes().unwrap().len(), P::public_key_bytes());
+        assert_eq!(rng_sk.to_bytes().unwrap().len(), P::secret_key_bytes());
+        assert_eq!(
+            DilithiumScheme::<P>::derive_public_key(&rng_sk).unwrap(),
+            rng_pk
+        );
+        let rng_signature = DilithiumScheme::<P>::sign(&rng_sk, b"message").unwrap();
+        DilithiumScheme::<P>::verify(&rng_pk, b"message", &rng_signature).unwrap();
+        assert!(DilithiumScheme::<P>::verify(&pk, b"message", &rng_signature).is_err());
+
+        // Test seed-based storage of the secret key
+        // 测试基于种子的私钥存储
+        let seed = rng_sk.to_seed().unwrap();
+        assert_eq!(seed.len(), DilithiumSecretKey::<P>::SEED_BYTES);
+        let restored = DilithiumSecretKey::<P>::from_seed(&seed).unwrap();
+        assert_eq!(restored, rng_sk);
+        assert_eq!(restored.to_seed().unwrap(), seed);
+        assert_eq!(
+            DilithiumScheme::<P>::derive_public_key(&restored).unwrap(),
+            rng_pk
+        );
+        assert_eq!(
+            DilithiumSecretKey::<P>::from_seed(&seed[1..]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+        assert_eq!(
+            sk.to_seed().unwrap_err(),
+            Error::Key(KeyError::SeedUnavailable)
+        );
+
+        // Test SPKI and PKCS#8 encodings
+        // 测试 SPKI 和 PKCS#8 编码
+        let spki = pk.to_spki_der().unwrap();
+        let pkcs8 = sk.to_pkcs8_der().unwrap();
+        assert_eq!(DilithiumPublicKey::<P>::from_spki_der(&spki).unwrap(), pk);
+        assert_eq!(DilithiumSecretKey::<P>::from_pkcs8_der(&pkcs8).unwrap(), sk);
+        assert!(DilithiumPublicKey::<P>::from_spki_der(&pk_bytes).is_err());
+        assert!(DilithiumSecretKey::<P>::from_pkcs8_der(&spki).is_err());
+
         // Test sign/verify roundtrip
         // 测试签名/验证往返
         let message = b"this is the message to be signed";
         let signature = DilithiumScheme::<P>::sign(&sk, message).unwrap();
         assert!(DilithiumScheme::<P>::verify(&pk, message, &signature).is_ok());
+        assert_eq!(signature.len(), DilithiumScheme::<P>::MAX_SIGNATURE_SIZE);
+
+        // Test signing into a caller-provided buffer
+        // 测试签名到调用者提供的缓冲区
+        let mut buffer = vec![0u8; DilithiumScheme::<P>::MAX_SIGNATURE_SIZE];
+        let len = DilithiumScheme::<P>::sign_to_buffer(&sk, message, &mut buffer).unwrap();
+        assert!(DilithiumScheme::<P>::verify(&pk, message, &buffer[..len].to_vec()).is_ok());
+        assert!(
+            DilithiumScheme::<P>::sign_to_buffer(&sk, message, &mut buffer[..len - 1]).is_err()
+        );
 
         // Test tampered message verification fails
         // 测试篡改消息验证失败
         let tampered_message = b"this is a different message";
         assert!(DilithiumScheme::<P>::verify(&pk, tampered_message, &signature).is_err());
 
+        // Test verification with a prepared verifying key
+        // 测试使用预处理的验证密钥进行验证
+        let verifier = PreparedVerifier::<DilithiumScheme<P>>::new(&pk).unwrap();
+        assert!(verifier.verify(message, &signature).is_ok());
+        assert!(verifier.verify(tampered_message, &signature).is_err());
+
         // Test with empty message
         // 测试空消息
         let empty_message = b"";
         let signature_empty = DilithiumScheme::<P>::sign(&sk, empty_message).unwrap();
         assert!(DilithiumScheme::<P>::verify(&pk, empty_message, &signature_empty).is_ok());
+
+        // Plain signatures are over the raw message, as in other Dilithium implementations
+        // 普通签名直接针对原始消息，与其他 Dilithium 实现一致
+        let sig = PqDetachedSignature::from_bytes(&signature).unwrap();
+        let prepared = DilithiumScheme::<P>::prepare_public_key(&pk).unwrap();
+        assert!(P::verify(&sig, message, &prepared).is_ok());
+        let mut buffer = [0u8; 8];
+        assert!(DilithiumScheme::<P>::sign_to_buffer(&sk, message, &mut buffer).is_err());
+
+        // Test context-bound signatures are domain separated, also from plain signatures
+        // 测试绑定上下文的签名实现了域分离，且与普通签名分离
+        let context = b"protocol-a";
+        let signature_ctx = DilithiumScheme::<P>::sign_with_context(&sk, context, message).unwrap();
+        assert!(
+            DilithiumScheme::<P>::verify_with_context(&pk, context, message, &signature_ctx)
+                .is_ok()
+        );
+        assert!(
+            DilithiumScheme::<P>::verify_with_context(&pk, b"protocol-b", message, &signature_ctx)
+                .is_err()
+        );
+        assert!(DilithiumScheme::<P>::verify(&pk, message, &signature_ctx).is_err());
+        assert!(DilithiumScheme::<P>::sign_with_context(&sk, &[0u8; 256], message).is_err());
+
+        // Test prehash sign/verify roundtrip
+        // 测试预哈希签名/验证往返
+        #[cfg(feature = "sha2")]
+        {
+            let digest = Sha256::hash(message);
+            let signature = DilithiumScheme::<P>::sign_digest::<Sha256>(&sk, &digest).unwrap();
+            assert!(
+                DilithiumScheme::<P>::verify_digest::<Sha256>(&pk, &digest, &signature).is_ok()
+            );
+            assert!(DilithiumScheme::<P>::verify(&pk, message, &signature).is_err());
+            assert!(DilithiumScheme::<P>::sign_digest::<Sha512>(&sk, &digest).is_err());
+            assert!(
+                DilithiumScheme::<P>::verify_with_context(&pk, &[], &digest, &signature).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_dilithium_der_parameter_set() {
+        let (pk, sk) = DilithiumScheme::<Dilithium2Params>::generate_keypair().unwrap();
+        let spki = pk.to_spki_der().unwrap();
+        // AlgorithmIdentifier with the Dilithium2 round 3 OID and no parameters
+        assert!(
+            spki.windows(15)
+                .any(|w| w == hex::decode("300d060b2b0601040102820b070404").unwrap())
+        );
+        assert!(DilithiumPublicKey::<Dilithium3Params>::from_spki_der(&spki).is_err());
+        assert!(
+            DilithiumSecretKey::<Dilithium5Params>::from_pkcs8_der(&sk.to_pkcs8_der().unwrap())
+                .is_err()
+        );
     }
 
     #[test]
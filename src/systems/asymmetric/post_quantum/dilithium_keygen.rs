@@ -0,0 +1,333 @@
+//! Deterministic Dilithium key generation and public key recomputation.
+//!
+//! The PQClean backend draws its key generation randomness from the operating system, so key
+//! pairs built from a caller's RNG are computed here instead, following the round 3 reference
+//! implementation: `rho`, `rho'` and `key` are expanded from a 32-byte seed, `s1` and `s2` are
+//! sampled from `rho'`, and `t = A·s1 + s2` with `A` expanded from `rho`.
+//!
+//! The secret key holds `rho`, `s1`, `s2` and the low bits `t0`, but not the high bits `t1` that
+//! make up the public key. They are recomputed the same way, and the result is checked against
+//! the hash `tr` of the public key that the secret key also stores.
+//!
+//! 确定性的 Dilithium 密钥生成和公钥重新计算。
+//!
+//! PQClean 后端从操作系统获取密钥生成所需的随机性，因此由调用者 RNG 构建的密钥对在此处按照第三轮
+//! 参考实现计算：从 32 字节种子扩展出 `rho`、`rho'` 和 `key`，从 `rho'` 采样 `s1` 和 `s2`，
+//! 并计算 `t = A·s1 + s2`，其中 `A` 由 `rho` 扩展得到。
+//!
+//! 私钥包含 `rho`、`s1`、`s2` 和低位 `t0`，但不包含组成公钥的高位 `t1`。这些高位以相同方式重新
+//! 计算，并将结果与私钥中同样存储的公钥哈希 `tr` 进行比对。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+use crate::prelude::*;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Shake128, Shake256};
+use zeroize::Zeroizing;
+
+const N: usize = 256;
+const Q: i64 = 8_380_417;
+const D: u32 = 13;
+const ROOT_OF_UNITY: i64 = 1753;
+const SEED_BYTES: usize = 32;
+const CRH_BYTES: usize = 64;
+const TR_BYTES: usize = 64;
+const T0_PACKED_BYTES: usize = 416;
+
+type Poly = [i64; N];
+
+fn pow_mod(mut base: i64, mut exp: i64) -> i64 {
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % Q;
+        }
+        base = base * base % Q;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Powers of the 512th root of unity in bit-reversed order, as used by the reference NTT.
+///
+/// 按位反转顺序排列的 512 次单位根的幂，与参考 NTT 的用法一致。
+fn zetas() -> Poly {
+    let mut zetas = [0; N];
+    for (k, zeta) in (0u8..=u8::MAX).zip(zetas.iter_mut()) {
+        *zeta = pow_mod(ROOT_OF_UNITY, i64::from(k.reverse_bits()));
+    }
+    zetas
+}
+
+fn ntt(a: &mut Poly, zetas: &Poly) {
+    let mut k = 0;
+    let mut len = N / 2;
+    while len > 0 {
+        for start in (0..N).step_by(2 * len) {
+            k += 1;
+            for j in start..start + len {
+                let t = zetas[k] * a[j + len] % Q;
+                a[j + len] = (a[j] - t).rem_euclid(Q);
+                a[j] = (a[j] + t) % Q;
+            }
+        }
+        len /= 2;
+    }
+}
+
+fn inverse_ntt(a: &mut Poly, zetas: &Poly) {
+    let mut k = N;
+    let mut len = 1;
+    while len < N {
+        for start in (0..N).step_by(2 * len) {
+            k -= 1;
+            let zeta = Q - zetas[k];
+            for j in start..start + len {
+                let t = a[j];
+                a[j] = (t + a[j + len]) % Q;
+                a[j + len] = (t - a[j + len]).rem_euclid(Q) * zeta % Q;
+            }
+        }
+        len *= 2;
+    }
+    let n_inv = pow_mod(N as i64, Q - 2);
+    for coeff in a.iter_mut() {
+        *coeff = *coeff * n_inv % Q;
+    }
+}
+
+/// Samples the NTT-domain matrix entry `A[i][j]` from `rho` by rejection sampling.
+///
+/// 通过拒绝采样从 `rho` 中采样 NTT 域矩阵元素 `A[i][j]`。
+fn expand_a(rho: &[u8], i: usize, j: usize) -> Poly {
+    let mut xof = Shake128::default()
+        .chain(rho)
+        .chain([j as u8, i as u8])
+        .finalize_xof();
+    let mut a = [0; N];
+    let mut buf = [0u8; 3];
+    let mut ctr = 0;
+    while ctr < N {
+        xof.read(&mut buf);
+        let t = i64::from(u32::from_le_bytes([buf[0], buf[1], buf[2] & 0x7f, 0]));
+        if t < Q {
+            a[ctr] = t;
+            ctr += 1;
+        }
+    }
+    a
+}
+
+/// Reads `N` little-endian `bits`-wide values from `bytes`.
+///
+/// 从 `bytes` 中读取 `N` 个宽度为 `bits` 的小端值。
+fn unpack(bytes: &[u8], bits: u32) -> Zeroizing<Poly> {
+    let mut poly = Zeroizing::new([0; N]);
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    let mut bytes = bytes.iter();
+    for coeff in poly.iter_mut() {
+        while acc_bits < bits {
+            acc |= u32::from(bytes.next().copied().unwrap_or_default()) << acc_bits;
+            acc_bits += 8;
+        }
+        *coeff = i64::from(acc & ((1 << bits) - 1));
+        acc >>= bits;
+        acc_bits -= bits;
+    }
+    poly
+}
+
+/// Appends the `N` coefficients of `poly` as little-endian `bits`-wide values.
+///
+/// 将 `poly` 的 `N` 个系数以宽度为 `bits` 的小端值追加到输出中。
+fn pack(poly: &Poly, bits: u32, out: &mut Vec<u8>) {
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    for &coeff in poly {
+        acc |= (coeff as u32) << acc_bits;
+        acc_bits += bits;
+        while acc_bits >= 8 {
+            out.push(acc as u8);
+            acc >>= 8;
+            acc_bits -= 8;
+        }
+    }
+}
+
+/// Samples a polynomial with coefficients in `[-eta, eta]` from `rho_prime` by rejection
+/// sampling, returned reduced into `[0, Q)`.
+///
+/// 通过拒绝采样从 `rho_prime` 中采样系数位于 `[-eta, eta]` 的多项式，结果约简到 `[0, Q)`。
+fn sample_eta(rho_prime: &[u8], nonce: u16, eta: i64) -> Zeroizing<Poly> {
+    let mut xof = Shake256::default()
+        .chain(rho_prime)
+        .chain(nonce.to_le_bytes())
+        .finalize_xof();
+    let mut poly = Zeroizing::new([0; N]);
+    let mut buf = [0u8; 1];
+    let mut ctr = 0;
+    while ctr < N {
+        xof.read(&mut buf);
+        for t in [i64::from(buf[0] & 0x0f), i64::from(buf[0] >> 4)] {
+            let coeff = match eta {
+                2 if t < 15 => 2 - t % 5,
+                4 if t < 9 => 4 - t,
+                _ => continue,
+            };
+            if ctr < N {
+                poly[ctr] = coeff.rem_euclid(Q);
+                ctr += 1;
+            }
+        }
+    }
+    poly
+}
+
+/// Computes `t = A·s1 + s2` with `A` expanded from `rho`, coefficients in `[0, Q)`.
+///
+/// 计算 `t = A·s1 + s2`，其中 `A` 由 `rho` 扩展得到，系数位于 `[0, Q)`。
+fn compute_t(rho: &[u8], s1: &[Zeroizing<Poly>], s2: &[Zeroizing<Poly>]) -> Vec<Zeroizing<Poly>> {
+    let zetas = zetas();
+    let s1_hat: Vec<Zeroizing<Poly>> = s1
+        .iter()
+        .map(|s1| {
+            let mut poly = s1.clone();
+            ntt(&mut poly, &zetas);
+            poly
+        })
+        .collect();
+    s2.iter()
+        .enumerate()
+        .map(|(i, s2)| {
+            let mut t = Zeroizing::new([0; N]);
+            for (j, s1_hat) in s1_hat.iter().enumerate() {
+                let a = expand_a(rho, i, j);
+                for ((coeff, a), s) in t.iter_mut().zip(a).zip(s1_hat.iter()) {
+                    *coeff = (*coeff + a * s) % Q;
+                }
+            }
+            inverse_ntt(&mut t, &zetas);
+            for (coeff, s) in t.iter_mut().zip(s2.iter()) {
+                *coeff = (*coeff + s) % Q;
+            }
+            t
+        })
+        .collect()
+}
+
+/// Power2Round: the high bits `t1` of a coefficient in `[0, Q)`.
+///
+/// Power2Round：系数（位于 `[0, Q)`）的高位 `t1`。
+fn high_bits(coeff: i64) -> i64 {
+    (coeff + (1 << (D - 1)) - 1) >> D
+}
+
+fn hash_public_key(public_key: &[u8]) -> [u8; TR_BYTES] {
+    let mut tr = [0u8; TR_BYTES];
+    Shake256::default()
+        .chain(public_key)
+        .finalize_xof()
+        .read(&mut tr);
+    tr
+}
+
+/// Builds the `(public key, secret key)` of a `k`×`l` Dilithium key pair with coefficients in
+/// `[-eta, eta]` from the 32-byte key generation seed `zeta`.
+///
+/// 根据 32 字节密钥生成种子 `zeta` 构建系数位于 `[-eta, eta]` 的 `k`×`l` Dilithium 密钥对
+/// `(公钥, 私钥)`。
+pub(super) fn keypair_from_seed(
+    zeta: &[u8],
+    k: usize,
+    l: usize,
+    eta: i64,
+) -> (Vec<u8>, Zeroizing<Vec<u8>>) {
+    let mut seeds = Zeroizing::new([0u8; 2 * SEED_BYTES + CRH_BYTES]);
+    Shake256::default()
+        .chain(zeta)
+        .finalize_xof()
+        .read(seeds.as_mut());
+    let (rho, rest) = seeds.split_at(SEED_BYTES);
+    let (rho_prime, key) = rest.split_at(CRH_BYTES);
+
+    let s1: Vec<Zeroizing<Poly>> = (0..l)
+        .map(|nonce| sample_eta(rho_prime, nonce as u16, eta))
+        .collect();
+    let s2: Vec<Zeroizing<Poly>> = (l..l + k)
+        .map(|nonce| sample_eta(rho_prime, nonce as u16, eta))
+        .collect();
+    let t = compute_t(rho, &s1, &s2);
+
+    let mut public_key = rho.to_vec();
+    let mut t0 = Zeroizing::new(Vec::with_capacity(k * T0_PACKED_BYTES));
+    for t in &t {
+        let mut high = Zeroizing::new([0; N]);
+        let mut low = Zeroizing::new([0; N]);
+        for ((coeff, high), low) in t.iter().zip(high.iter_mut()).zip(low.iter_mut()) {
+            *high = high_bits(*coeff);
+            // The low bits `t0 = t - (t1 << D)` lie in `(-2^(D-1), 2^(D-1)]`
+            // 低位 `t0 = t - (t1 << D)` 位于 `(-2^(D-1), 2^(D-1)]`
+            *low = (1 << (D - 1)) - (*coeff - (*high << D));
+        }
+        pack(&high, 10, &mut public_key);
+        pack(&low, D, &mut t0);
+    }
+
+    let mut secret_key = Zeroizing::new(Vec::new());
+    secret_key.extend_from_slice(rho);
+    secret_key.extend_from_slice(key);
+    secret_key.extend_from_slice(&hash_public_key(&public_key));
+    for s in s1.iter().chain(&s2) {
+        let packed = Zeroizing::new(s.map(|coeff| 2 * eta - (coeff + eta) % Q));
+        pack(&packed, if eta == 2 { 3 } else { 4 }, &mut secret_key);
+    }
+    secret_key.extend_from_slice(&t0);
+    (public_key, secret_key)
+}
+
+/// Recomputes the public key `rho || t1` of a `k`×`l` Dilithium secret key with coefficients
+/// in `[-eta, eta]`.
+///
+/// Fails with `KeyError::InvalidEncoding` if the secret key has the wrong length or its
+/// stored `tr` does not match the recomputed public key.
+///
+/// 重新计算系数位于 `[-eta, eta]` 的 `k`×`l` Dilithium 私钥的公钥 `rho || t1`。
+///
+/// 如果私钥长度错误，或其存储的 `tr` 与重新计算的公钥不匹配，则以 `KeyError::InvalidEncoding` 失败。
+pub(super) fn public_key_from_secret_key(
+    secret_key: &[u8],
+    k: usize,
+    l: usize,
+    eta: i64,
+) -> Result<Vec<u8>, Error> {
+    let eta_bits = if eta == 2 { 3 } else { 4 };
+    let eta_bytes = eta_bits as usize * N / 8;
+    if secret_key.len() != 2 * SEED_BYTES + TR_BYTES + (k + l) * eta_bytes + k * T0_PACKED_BYTES {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    let (rho, rest) = secret_key.split_at(SEED_BYTES);
+    let (tr, rest) = rest[SEED_BYTES..].split_at(TR_BYTES);
+    let (s1_bytes, rest) = rest.split_at(l * eta_bytes);
+    let s2_bytes = &rest[..k * eta_bytes];
+
+    let unpack_eta = |bytes: &[u8]| {
+        let mut poly = unpack(bytes, eta_bits);
+        for coeff in poly.iter_mut() {
+            *coeff = (eta - *coeff).rem_euclid(Q);
+        }
+        poly
+    };
+    let s1: Vec<Zeroizing<Poly>> = s1_bytes.chunks_exact(eta_bytes).map(unpack_eta).collect();
+    let s2: Vec<Zeroizing<Poly>> = s2_bytes.chunks_exact(eta_bytes).map(unpack_eta).collect();
+
+    let mut public_key = rho.to_vec();
+    for t in compute_t(rho, &s1, &s2) {
+        pack(&t.map(high_bits), 10, &mut public_key);
+    }
+
+    if hash_public_key(&public_key) != tr {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    Ok(public_key)
+}
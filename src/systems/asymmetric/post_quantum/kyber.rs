@@ -26,6 +26,15 @@
 //! - Long-term security against future quantum threats
 //! - Applications requiring quantum-resistant key establishment
 //!
+//! # Key Encodings
+//! Besides their raw bytes, keys can be encoded as a DER `SubjectPublicKeyInfo` or PKCS#8
+//! `PrivateKeyInfo`, identified by the round 3 OIDs used by the IETF drafts and Open Quantum
+//! Safe (`1.3.6.1.4.1.22554.5.6.1`, `.2` and `.3`). The standardized ML-KEM algorithms are not
+//! interoperable with round 3 Kyber and have their own OIDs.
+//!
+//! A secret key generated from a seed, by `generate_keypair_with_rng` or `from_seed`, can be
+//! stored as that 64-byte seed (`to_seed`) instead of its expanded form.
+//!
 //! 提供了 Kyber 后量子 KEM 的实现。
 //!
 //! Kyber 是一种密钥封装机制 (KEM)，设计为能够抵抗经典和量子计算机的攻击。
@@ -52,16 +61,30 @@
 //! - 过渡期间的混合经典/后量子系统
 //! - 针对未来量子威胁的长期安全性
 //! - 需要抗量子密钥建立的应用程序
+//!
+//! # 密钥编码
+//! 除原始字节外，密钥还可以编码为 DER `SubjectPublicKeyInfo` 或 PKCS#8 `PrivateKeyInfo`，
+//! 以 IETF 草案和 Open Quantum Safe 使用的第三轮 OID（`1.3.6.1.4.1.22554.5.6.1`、`.2` 和 `.3`）标识。
+//! 标准化的 ML-KEM 算法与第三轮 Kyber 不能互操作，并使用其自己的 OID。
+//!
+//! 由种子生成的私钥（通过 `generate_keypair_with_rng` 或 `from_seed`）可以存储为该 64 字节种子
+//! （`to_seed`），而不必存储其扩展形式。
 
+use alloc::{format, string::String, vec, vec::Vec};
+use super::{der, kyber_keygen};
+use crate::ct::{Choice, ConstantTimeEq};
 use crate::errors::Error;
+use crate::policy;
 use crate::prelude::*;
+use pkcs8::ObjectIdentifier;
 use pqcrypto_kyber::{kyber512, kyber768, kyber1024};
 use pqcrypto_traits::kem::{
     Ciphertext as PqCiphertext, PublicKey as PqPublicKey, SecretKey as PqSecretKey,
     SharedSecret as PqSharedSecret,
 };
-use std::convert::TryFrom;
-use std::marker::PhantomData;
+use sha3::{Digest, Sha3_256};
+use core::convert::TryFrom;
+use core::marker::PhantomData;
 use zeroize::{Zeroize, Zeroizing};
 
 // ------------------- Marker Structs and Trait for Kyber Parameters -------------------
@@ -85,6 +108,18 @@ pub trait KyberParams: private::Sealed + SchemeParams {
     const PUBLIC_KEY_BYTES: usize;
     const SECRET_KEY_BYTES: usize;
     const CIPHERTEXT_BYTES: usize;
+    /// The OID identifying this parameter set in `SubjectPublicKeyInfo` and PKCS#8 encodings.
+    ///
+    /// 在 `SubjectPublicKeyInfo` 和 PKCS#8 编码中标识此参数集的 OID。
+    const OID: ObjectIdentifier;
+    /// The module rank `k`, the number of polynomials in the secret vector.
+    ///
+    /// 模块秩 `k`，即私钥向量中多项式的个数。
+    const K: usize;
+    /// The centered binomial distribution parameter `eta1` for the secret and error vectors.
+    ///
+    /// 私钥向量和误差向量所用的中心二项分布参数 `eta1`。
+    const ETA1: usize;
 
     fn keypair() -> (Self::PqPublicKey, Self::PqSecretKey);
     fn encapsulate(pk: &Self::PqPublicKey) -> (Self::PqSharedSecret, Self::PqCiphertext);
@@ -110,6 +145,9 @@ impl KyberParams for Kyber512Params {
     const PUBLIC_KEY_BYTES: usize = kyber512::public_key_bytes();
     const SECRET_KEY_BYTES: usize = kyber512::secret_key_bytes();
     const CIPHERTEXT_BYTES: usize = kyber512::ciphertext_bytes();
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.22554.5.6.1");
+    const K: usize = 2;
+    const ETA1: usize = 3;
 
     fn keypair() -> (Self::PqPublicKey, Self::PqSecretKey) {
         kyber512::keypair()
@@ -141,6 +179,9 @@ impl KyberParams for Kyber768Params {
     const PUBLIC_KEY_BYTES: usize = kyber768::public_key_bytes();
     const SECRET_KEY_BYTES: usize = kyber768::secret_key_bytes();
     const CIPHERTEXT_BYTES: usize = kyber768::ciphertext_bytes();
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.22554.5.6.2");
+    const K: usize = 3;
+    const ETA1: usize = 2;
 
     fn keypair() -> (Self::PqPublicKey, Self::PqSecretKey) {
         kyber768::keypair()
@@ -172,6 +213,9 @@ impl KyberParams for Kyber1024Params {
     const PUBLIC_KEY_BYTES: usize = kyber1024::public_key_bytes();
     const SECRET_KEY_BYTES: usize = kyber1024::secret_key_bytes();
     const CIPHERTEXT_BYTES: usize = kyber1024::ciphertext_bytes();
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.4.1.22554.5.6.3");
+    const K: usize = 4;
+    const ETA1: usize = 2;
 
     fn keypair() -> (Self::PqPublicKey, Self::PqSecretKey) {
         kyber1024::keypair()
@@ -193,6 +237,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KyberPublicKey<P: KyberParams> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
     bytes: Vec<u8>,
     _params: PhantomData<P>,
 }
@@ -204,6 +249,13 @@ impl<P: KyberParams> KyberPublicKey<P> {
     pub fn len(&self) -> usize {
         self.bytes.len()
     }
+
+    /// Returns `true` if the public key contains no bytes.
+    ///
+    /// 如果公钥不包含任何字节，则返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
 }
 
 impl<P: KyberParams> PartialEq for KyberPublicKey<P> {
@@ -254,14 +306,36 @@ impl<P: KyberParams> Key for KyberPublicKey<P> {
 
 impl<P: KyberParams> PublicKey for KyberPublicKey<P> {}
 
-#[derive(Debug, Zeroize, Clone, Eq, PartialEq)]
+impl_hex_display!(KyberPublicKey<P: KyberParams>);
+
+#[derive(Debug, Zeroize, Clone)]
 #[zeroize(drop)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct KyberSecretKey<P: KyberParams> {
-    bytes: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    bytes: SecretBytes,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::traits::key::serde_bytes::option", default)
+    )]
+    seed: Option<Zeroizing<Vec<u8>>>,
     _params: PhantomData<P>,
 }
 
+impl<P: KyberParams> ConstantTimeEq for KyberSecretKey<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.as_slice().ct_eq(other.bytes.as_slice())
+    }
+}
+
+impl<P: KyberParams> PartialEq for KyberSecretKey<P> {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl<P: KyberParams> Eq for KyberSecretKey<P> {}
+
 impl<P: KyberParams> KyberSecretKey<P> {
     /// Returns the length of the secret key in bytes.
     ///
@@ -269,6 +343,13 @@ impl<P: KyberParams> KyberSecretKey<P> {
     pub fn len(&self) -> usize {
         self.bytes.len()
     }
+
+    /// Returns `true` if the secret key contains no bytes.
+    ///
+    /// 如果私钥不包含任何字节，则返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
 }
 
 impl<P: KyberParams> Key for KyberSecretKey<P> {
@@ -277,7 +358,8 @@ impl<P: KyberParams> Key for KyberSecretKey<P> {
             return Err(Error::Key(KeyError::InvalidEncoding));
         }
         Ok(Self {
-            bytes: Zeroizing::new(bytes.to_vec()),
+            bytes: SecretBytes::from_slice(bytes),
+            seed: None,
             _params: PhantomData,
         })
     }
@@ -296,6 +378,106 @@ impl<P: KyberParams> TryFrom<&[u8]> for KyberSecretKey<P> {
 
 impl<P: KyberParams + Clone> PrivateKey<KyberPublicKey<P>> for KyberSecretKey<P> {}
 
+impl<P: KyberParams> KyberPublicKey<P> {
+    /// Encodes the public key as a DER `SubjectPublicKeyInfo`.
+    ///
+    /// 将公钥编码为 DER `SubjectPublicKeyInfo`。
+    pub fn to_spki_der(&self) -> Result<Vec<u8>, Error> {
+        der::encode_spki(P::OID, &self.bytes)
+    }
+
+    /// Decodes a public key from a DER `SubjectPublicKeyInfo`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the encoding is malformed or names another
+    /// algorithm or parameter set.
+    ///
+    /// 从 DER `SubjectPublicKeyInfo` 解码公钥。
+    ///
+    /// 如果编码格式错误或指定了其他算法或参数集，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_spki_der(der: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(der::decode_spki(P::OID, der)?)
+    }
+}
+
+impl<P: KyberParams> KyberSecretKey<P> {
+    /// The length in bytes of the seed `d || z` a secret key is generated from.
+    ///
+    /// 生成私钥所用种子 `d || z` 的字节长度。
+    pub const SEED_BYTES: usize = 64;
+
+    /// Expands a secret key from the 64-byte seed `d || z`, where `d` seeds key generation and
+    /// `z` is the implicit rejection value.
+    ///
+    /// The seed is kept with the key so that it can be stored in place of the expanded key.
+    /// Fails with `KeyError::InvalidLength` if the seed is not 64 bytes long.
+    ///
+    /// 从 64 字节种子 `d || z` 扩展出私钥，其中 `d` 是密钥生成的种子，`z` 是隐式拒绝值。
+    ///
+    /// 种子会与密钥一起保存，以便代替扩展后的密钥进行存储。
+    /// 如果种子长度不是 64 字节，则以 `KeyError::InvalidLength` 失败。
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        Ok(keypair_from_seed::<P>(seed)?.1)
+    }
+
+    /// Returns the 64-byte seed the secret key was generated from.
+    ///
+    /// Only keys from [`KeyGenerator::generate_keypair_with_rng`] or [`Self::from_seed`] carry
+    /// their seed; other keys fail with `KeyError::SeedUnavailable`.
+    ///
+    /// 返回生成该私钥所用的 64 字节种子。
+    ///
+    /// 只有来自 [`KeyGenerator::generate_keypair_with_rng`] 或 [`Self::from_seed`] 的密钥才带有种子；
+    /// 其他密钥以 `KeyError::SeedUnavailable` 失败。
+    pub fn to_seed(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        self.seed
+            .clone()
+            .ok_or(Error::Key(KeyError::SeedUnavailable))
+    }
+
+    /// Encodes the secret key as a DER PKCS#8 `PrivateKeyInfo`.
+    ///
+    /// 将私钥编码为 DER PKCS#8 `PrivateKeyInfo`。
+    pub fn to_pkcs8_der(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        der::encode_pkcs8(P::OID, &self.bytes)
+    }
+
+    /// Decodes a secret key from a DER PKCS#8 `PrivateKeyInfo`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the encoding is malformed or names another
+    /// algorithm or parameter set.
+    ///
+    /// 从 DER PKCS#8 `PrivateKeyInfo` 解码私钥。
+    ///
+    /// 如果编码格式错误或指定了其他算法或参数集，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(der::decode_pkcs8(P::OID, der)?)
+    }
+}
+
+/// Builds the key pair generated from the seed `d || z`.
+///
+/// 构建由种子 `d || z` 生成的密钥对。
+fn keypair_from_seed<P: KyberParams>(
+    seed: &[u8],
+) -> Result<(KyberPublicKey<P>, KyberSecretKey<P>), Error> {
+    if seed.len() != KyberSecretKey::<P>::SEED_BYTES {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    let (d, z) = seed.split_at(32);
+    let (pk, sk) = kyber_keygen::keypair_from_seed(d, z, P::K, P::ETA1);
+    Ok((
+        KyberPublicKey {
+            bytes: pk,
+            _params: PhantomData,
+        },
+        KyberSecretKey {
+            bytes: sk.into(),
+            seed: Some(Zeroizing::new(seed.to_vec())),
+            _params: PhantomData,
+        },
+    ))
+}
+
 // ------------------- Generic Kyber KEM Implementation -------------------
 // ------------------- 通用 Kyber KEM 实现 -------------------
 
@@ -321,6 +503,7 @@ impl<P: KyberParams + Clone> Algorithm for KyberScheme<P> {
 
 impl<P: KyberParams + Clone> KeyGenerator for KyberScheme<P> {
     fn generate_keypair() -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
+        policy::enforce::<Self>()?;
         let (pk, sk) = P::keypair();
         Ok((
             KyberPublicKey {
@@ -328,22 +511,54 @@ impl<P: KyberParams + Clone> KeyGenerator for KyberScheme<P> {
                 _params: PhantomData,
             },
             KyberSecretKey {
-                bytes: Zeroizing::new(sk.as_bytes().to_vec()),
+                bytes: SecretBytes::from_slice(sk.as_bytes()),
+                seed: None,
                 _params: PhantomData,
             },
         ))
     }
+
+    fn generate_keypair_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
+        policy::enforce::<Self>()?;
+        let mut seed = Zeroizing::new(vec![0u8; KyberSecretKey::<P>::SEED_BYTES]);
+        rng.try_fill_bytes(&mut seed)
+            .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        keypair_from_seed(&seed)
+    }
+}
+
+impl<P: KyberParams + Clone> PublicKeyDerivation for KyberScheme<P> {
+    fn derive_public_key(private_key: &Self::PrivateKey) -> Result<Self::PublicKey, Error> {
+        // The secret key is laid out as `s || pk || SHA3-256(pk) || z`.
+        // 私钥的布局为 `s || pk || SHA3-256(pk) || z`。
+        let hash_start = P::SECRET_KEY_BYTES - 64;
+        let public_key = private_key
+            .bytes
+            .get(hash_start - P::PUBLIC_KEY_BYTES..hash_start)
+            .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        let hash = private_key
+            .bytes
+            .get(hash_start..hash_start + 32)
+            .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        if Sha3_256::digest(public_key).as_slice() != hash {
+            return Err(Error::Key(KeyError::InvalidEncoding));
+        }
+        KyberPublicKey::from_bytes(public_key)
+    }
 }
 
 impl<P: KyberParams + Clone> Kem for KyberScheme<P> {
     type EncapsulatedKey = EncapsulatedKey;
 
     fn encapsulate(public_key: &Self::PublicKey) -> Result<(SharedSecret, EncapsulatedKey), Error> {
+        policy::enforce::<Self>()?;
         let pk = PqPublicKey::from_bytes(&public_key.bytes)
             .map_err(|_| Error::Kem(KemError::InvalidPublicKey))?;
         let (ss, ct) = P::encapsulate(&pk);
         Ok((
-            Zeroizing::new(ss.as_bytes().to_vec()),
+            SharedSecret::new(ss.as_bytes().to_vec()),
             ct.as_bytes().to_vec(),
         ))
     }
@@ -352,13 +567,14 @@ impl<P: KyberParams + Clone> Kem for KyberScheme<P> {
         private_key: &Self::PrivateKey,
         encapsulated_key: &EncapsulatedKey,
     ) -> Result<SharedSecret, Error> {
+        policy::enforce::<Self>()?;
         let sk = PqSecretKey::from_bytes(&private_key.bytes)
             .map_err(|_| Error::Kem(KemError::InvalidPrivateKey))?;
         let ct = PqCiphertext::from_bytes(encapsulated_key)
             .map_err(|_| Error::Kem(KemError::InvalidEncapsulatedKey))?;
 
         let ss = P::decapsulate(&sk, &ct);
-        Ok(Zeroizing::new(ss.as_bytes().to_vec()))
+        Ok(SharedSecret::new(ss.as_bytes().to_vec()))
     }
 }
 
@@ -386,6 +602,7 @@ pub type Kyber1024 = KyberScheme<Kyber1024Params>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::systems::test_rng::SeededRng;
 
     fn run_kyber_tests<P: KyberParams>()
     where
@@ -404,15 +621,75 @@ mod tests {
         assert_eq!(pk, pk2);
         assert_eq!(sk.to_bytes(), sk2.to_bytes());
 
+        // Test public key derivation
+        // 测试公钥派生
+        assert_eq!(KyberScheme::<P>::derive_public_key(&sk).unwrap(), pk);
+        KyberScheme::<P>::validate_keypair(&pk, &sk).unwrap();
+        let mut corrupted = sk_bytes.clone();
+        corrupted[P::SECRET_KEY_BYTES - 64 - 1] ^= 1;
+        let corrupted = KyberSecretKey::<P>::from_bytes(&corrupted).unwrap();
+        assert!(KyberScheme::<P>::derive_public_key(&corrupted).is_err());
+
+        // Test key generation from a caller-supplied RNG is reproducible and interoperates
+        // with the backend
+        // 测试使用调用者提供的 RNG 生成密钥是可复现的，并且可以与后端互操作
+        let (rng_pk, rng_sk) =
+            KyberScheme::<P>::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        let (rng_pk2, rng_sk2) =
+            KyberScheme::<P>::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        assert_eq!(rng_pk, rng_pk2);
+        assert_eq!(rng_sk, rng_sk2);
+        let (other_pk, _) = KyberScheme::<P>::generate_keypair_with_rng(&mut SeededRng(1)).unwrap();
+        assert_ne!(rng_pk, other_pk);
+        KyberScheme::<P>::validate_keypair(&rng_pk, &rng_sk).unwrap();
+        let (ss1, encapsulated_key) = KyberScheme::<P>::encapsulate(&rng_pk).unwrap();
+        let ss2 = KyberScheme::<P>::decapsulate(&rng_sk, &encapsulated_key).unwrap();
+        assert_eq!(ss1, ss2);
+
+        // Test seed-based storage of the secret key
+        // 测试基于种子的私钥存储
+        let seed = rng_sk.to_seed().unwrap();
+        assert_eq!(seed.len(), KyberSecretKey::<P>::SEED_BYTES);
+        let restored = KyberSecretKey::<P>::from_seed(&seed).unwrap();
+        assert_eq!(restored, rng_sk);
+        assert_eq!(restored.to_seed().unwrap(), seed);
+        assert_eq!(
+            KyberScheme::<P>::derive_public_key(&restored).unwrap(),
+            rng_pk
+        );
+        assert_eq!(
+            KyberSecretKey::<P>::from_seed(&seed[1..]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+        assert_eq!(
+            sk.to_seed().unwrap_err(),
+            Error::Key(KeyError::SeedUnavailable)
+        );
+
+        // Test SPKI and PKCS#8 encodings
+        // 测试 SPKI 和 PKCS#8 编码
+        let spki = pk.to_spki_der().unwrap();
+        let pkcs8 = sk.to_pkcs8_der().unwrap();
+        assert_eq!(KyberPublicKey::<P>::from_spki_der(&spki).unwrap(), pk);
+        assert_eq!(KyberSecretKey::<P>::from_pkcs8_der(&pkcs8).unwrap(), sk);
+        assert!(KyberPublicKey::<P>::from_spki_der(&pk_bytes).is_err());
+        assert!(KyberSecretKey::<P>::from_pkcs8_der(&spki).is_err());
+
         // Test KEM roundtrip
         // 测试 KEM 往返
         let (ss1, encapsulated_key) = KyberScheme::<P>::encapsulate(&pk).unwrap();
         let ss2 = KyberScheme::<P>::decapsulate(&sk, &encapsulated_key).unwrap();
         assert_eq!(ss1, ss2);
+        let keypair = KeyPair::<KyberScheme<P>>::new(pk.clone(), sk.clone());
+        assert_eq!(keypair.decapsulate(&encapsulated_key).unwrap(), ss1);
 
         // Test wrong key decapsulation
         // 测试使用错误密钥解封装
         let (pk2, _sk2) = KyberScheme::<P>::generate_keypair().unwrap();
+        assert_eq!(
+            KyberScheme::<P>::validate_keypair(&pk2, &sk).unwrap_err(),
+            Error::Key(KeyError::KeyPairMismatch)
+        );
         let (ss_for_pk2, encapsulated_key_for_pk2) = KyberScheme::<P>::encapsulate(&pk2).unwrap();
         let wrong_ss = KyberScheme::<P>::decapsulate(&sk, &encapsulated_key_for_pk2).unwrap();
         assert_ne!(ss_for_pk2, wrong_ss);
@@ -425,6 +702,21 @@ mod tests {
         assert_ne!(ss_orig, tampered_ss);
     }
 
+    #[test]
+    fn test_kyber_der_parameter_set() {
+        let (pk, sk) = Kyber768::generate_keypair().unwrap();
+        let spki = pk.to_spki_der().unwrap();
+        // AlgorithmIdentifier with the Kyber768 round 3 OID and no parameters
+        assert!(
+            spki.windows(15)
+                .any(|w| w == hex::decode("300d060b2b0601040181b01a050602").unwrap())
+        );
+        assert!(KyberPublicKey::<Kyber512Params>::from_spki_der(&spki).is_err());
+        assert!(
+            KyberSecretKey::<Kyber1024Params>::from_pkcs8_der(&sk.to_pkcs8_der().unwrap()).is_err()
+        );
+    }
+
     #[test]
     fn test_kyber_512() {
         run_kyber_tests::<Kyber512Params>();
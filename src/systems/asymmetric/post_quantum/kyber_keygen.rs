@@ -0,0 +1,180 @@
+//! Deterministic Kyber key generation from caller-supplied randomness.
+//!
+//! The PQClean backend draws its key generation randomness from the operating system, so key
+//! pairs built from a caller's RNG are computed here instead, following the round 3 reference
+//! implementation: `(rho, sigma) = SHA3-512(d)`, `A` expanded from `rho`, `s` and `e` sampled
+//! from `sigma`, and `t = A·s + e` in the NTT domain. The resulting keys use the same encoding
+//! as the backend and can be used with it directly.
+//!
+//! 根据调用者提供的随机性确定性地生成 Kyber 密钥。
+//!
+//! PQClean 后端从操作系统获取密钥生成所需的随机性，因此由调用者 RNG 构建的密钥对在此处按照第三轮
+//! 参考实现计算：`(rho, sigma) = SHA3-512(d)`，`A` 由 `rho` 扩展得到，`s` 和 `e` 从 `sigma`
+//! 采样，并在 NTT 域中计算 `t = A·s + e`。生成的密钥使用与后端相同的编码，可以直接与后端一起使用。
+
+use alloc::{vec, vec::Vec};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Digest, Sha3_256, Sha3_512, Shake128, Shake256};
+use zeroize::Zeroizing;
+
+const N: usize = 256;
+const Q: i32 = 3329;
+const ROOT_OF_UNITY: i32 = 17;
+const SYM_BYTES: usize = 32;
+const POLY_BYTES: usize = 384;
+
+type Poly = [i32; N];
+
+/// Powers of the 256th root of unity in bit-reversed order, as used by the reference NTT.
+///
+/// 按位反转顺序排列的 256 次单位根的幂，与参考 NTT 的用法一致。
+fn zetas() -> [i32; N / 2] {
+    let mut zetas = [0; N / 2];
+    for (k, zeta) in (0u8..).zip(zetas.iter_mut()) {
+        let mut result = 1;
+        for _ in 0..(k.reverse_bits() >> 1) {
+            result = result * ROOT_OF_UNITY % Q;
+        }
+        *zeta = result;
+    }
+    zetas
+}
+
+fn ntt(a: &mut Poly, zetas: &[i32; N / 2]) {
+    let mut k = 1;
+    let mut len = N / 2;
+    while len >= 2 {
+        for start in (0..N).step_by(2 * len) {
+            let zeta = zetas[k];
+            k += 1;
+            for j in start..start + len {
+                let t = zeta * a[j + len] % Q;
+                a[j + len] = (a[j] - t).rem_euclid(Q);
+                a[j] = (a[j] + t) % Q;
+            }
+        }
+        len /= 2;
+    }
+}
+
+/// Multiplies two NTT-domain polynomials and adds the product to `acc`.
+///
+/// 将两个 NTT 域多项式相乘，并将乘积累加到 `acc`。
+fn base_mul_add(acc: &mut Poly, a: &Poly, b: &Poly, zetas: &[i32; N / 2]) {
+    for i in 0..N / 2 {
+        let zeta = zetas[N / 4 + i / 2];
+        let zeta = if i % 2 == 0 { zeta } else { Q - zeta };
+        let (a0, a1) = (a[2 * i], a[2 * i + 1]);
+        let (b0, b1) = (b[2 * i], b[2 * i + 1]);
+        let r0 = (a1 * b1 % Q * zeta + a0 * b0) % Q;
+        let r1 = (a0 * b1 + a1 * b0) % Q;
+        acc[2 * i] = (acc[2 * i] + r0) % Q;
+        acc[2 * i + 1] = (acc[2 * i + 1] + r1) % Q;
+    }
+}
+
+/// Samples the NTT-domain matrix entry `A[i][j]` from `rho` by rejection sampling.
+///
+/// 通过拒绝采样从 `rho` 中采样 NTT 域矩阵元素 `A[i][j]`。
+fn expand_a(rho: &[u8], i: usize, j: usize) -> Poly {
+    let mut xof = Shake128::default()
+        .chain(rho)
+        .chain([j as u8, i as u8])
+        .finalize_xof();
+    let mut a = [0; N];
+    let mut buf = [0u8; 3];
+    let mut ctr = 0;
+    while ctr < N {
+        xof.read(&mut buf);
+        let (b0, b1, b2) = (i32::from(buf[0]), i32::from(buf[1]), i32::from(buf[2]));
+        for val in [(b0 | b1 << 8) & 0xfff, (b1 >> 4 | b2 << 4) & 0xfff] {
+            if val < Q && ctr < N {
+                a[ctr] = val;
+                ctr += 1;
+            }
+        }
+    }
+    a
+}
+
+/// Samples a noise polynomial from the centered binomial distribution with parameter `eta`.
+///
+/// 从参数为 `eta` 的中心二项分布中采样噪声多项式。
+fn sample_noise(sigma: &[u8], nonce: u8, eta: usize) -> Zeroizing<Poly> {
+    let mut buf = Zeroizing::new(vec![0u8; eta * N / 4]);
+    Shake256::default()
+        .chain(sigma)
+        .chain([nonce])
+        .finalize_xof()
+        .read(&mut buf);
+    let mut poly = Zeroizing::new([0; N]);
+    let mut bits = buf
+        .iter()
+        .flat_map(|byte| (0..8).map(move |bit| i32::from(byte >> bit & 1)));
+    for coeff in poly.iter_mut() {
+        let a: i32 = bits.by_ref().take(eta).sum();
+        let b: i32 = bits.by_ref().take(eta).sum();
+        *coeff = (a - b).rem_euclid(Q);
+    }
+    poly
+}
+
+/// Appends the coefficients of `poly` as little-endian 12-bit values.
+///
+/// 将 `poly` 的系数以 12 位小端值追加到输出中。
+fn pack(poly: &Poly, out: &mut Vec<u8>) {
+    for pair in poly.chunks_exact(2) {
+        let (t0, t1) = (pair[0] as u16, pair[1] as u16);
+        out.extend_from_slice(&[t0 as u8, (t0 >> 8 | t1 << 4) as u8, (t1 >> 4) as u8]);
+    }
+}
+
+/// Builds the `(public key, secret key)` of a rank-`k` Kyber key pair from the key generation
+/// seed `d` and the implicit rejection value `z`, with secret and error coefficients sampled
+/// with parameter `eta1`.
+///
+/// 根据密钥生成种子 `d` 和隐式拒绝值 `z` 构建秩为 `k` 的 Kyber 密钥对 `(公钥, 私钥)`，
+/// 私钥和误差系数使用参数 `eta1` 采样。
+pub(super) fn keypair_from_seed(
+    d: &[u8],
+    z: &[u8],
+    k: usize,
+    eta1: usize,
+) -> (Vec<u8>, Zeroizing<Vec<u8>>) {
+    let mut seeds = Zeroizing::new([0u8; 2 * SYM_BYTES]);
+    Sha3_512::new()
+        .chain_update(d)
+        .finalize_into(seeds.as_mut().into());
+    let (rho, sigma) = seeds.split_at(SYM_BYTES);
+    let zetas = zetas();
+
+    let noise = |nonce: usize| {
+        let mut poly = sample_noise(sigma, nonce as u8, eta1);
+        ntt(&mut poly, &zetas);
+        poly
+    };
+    let s_hat: Vec<Zeroizing<Poly>> = (0..k).map(noise).collect();
+
+    let mut public_key = Vec::with_capacity(k * POLY_BYTES + SYM_BYTES);
+    for i in 0..k {
+        let mut t_hat = noise(k + i);
+        for (j, s_hat) in s_hat.iter().enumerate() {
+            base_mul_add(&mut t_hat, &expand_a(rho, i, j), s_hat, &zetas);
+        }
+        pack(&t_hat, &mut public_key);
+    }
+    public_key.extend_from_slice(rho);
+
+    // The secret key is laid out as `s || pk || SHA3-256(pk) || z`.
+    // 私钥的布局为 `s || pk || SHA3-256(pk) || z`。
+    let mut secret_key = Zeroizing::new(Vec::with_capacity(
+        k * POLY_BYTES + public_key.len() + 2 * SYM_BYTES,
+    ));
+    for s_hat in &s_hat {
+        pack(s_hat, &mut secret_key);
+    }
+    secret_key.extend_from_slice(&public_key);
+    secret_key.extend_from_slice(&Sha3_256::digest(&public_key));
+    secret_key.extend_from_slice(z);
+    (public_key, secret_key)
+}
@@ -4,3 +4,5 @@ pub mod ecc;
 pub mod ecdh;
 #[cfg(feature = "rsa-default")]
 pub mod rsa;
+#[cfg(feature = "sm-default")]
+pub mod sm2;
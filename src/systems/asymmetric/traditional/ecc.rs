@@ -5,7 +5,7 @@
 //! cryptographic applications due to its efficiency and security properties.
 //!
 //! # Supported Algorithms
-//! - **ECDSA**: Elliptic Curve Digital Signature Algorithm using NIST P-256
+//! - **ECDSA**: Elliptic Curve Digital Signature Algorithm using NIST P-256 or secp256k1
 //! - **EdDSA**: Edwards-curve Digital Signature Algorithm using Ed25519
 //!
 //! # Algorithm Comparison
@@ -29,7 +29,10 @@
 //! - Suitable for resource-constrained environments
 //!
 //! # Key Formats
-//! Keys are expected to be in PKCS#8 DER format for interoperability.
+//! Keys are expected to be in PKCS#8 DER format for interoperability. ECDSA public keys can
+//! also be imported and exported as raw SEC1 points, compressed or uncompressed, with
+//! `from_sec1_bytes` and `to_sec1_bytes`, and private keys from their raw 32-byte scalar or
+//! Ed25519 seed with `from_raw_bytes` and `to_raw_bytes`.
 //!
 //! # Performance Characteristics
 //! - Significantly faster than RSA for equivalent security levels
@@ -58,7 +61,7 @@
 //! ECC 在现代密码应用中被广泛使用。
 //!
 //! # 支持的算法
-//! - **ECDSA**: 使用 NIST P-256 的椭圆曲线数字签名算法
+//! - **ECDSA**: 使用 NIST P-256 或 secp256k1 的椭圆曲线数字签名算法
 //! - **EdDSA**: 使用 Ed25519 的爱德华兹曲线数字签名算法
 //!
 //! # 算法比较
@@ -82,7 +85,9 @@
 //! - 适用于资源受限的环境
 //!
 //! # 密钥格式
-//! 密钥应为 PKCS#8 DER 格式以实现互操作性。
+//! 密钥应为 PKCS#8 DER 格式以实现互操作性。ECDSA 公钥也可以通过 `from_sec1_bytes` 和
+//! `to_sec1_bytes` 以压缩或未压缩的原始 SEC1 点形式导入和导出，私钥也可以通过
+//! `from_raw_bytes` 和 `to_raw_bytes` 以原始 32 字节标量或 Ed25519 种子的形式导入和导出。
 //!
 //! # 性能特征
 //! - 在相同安全级别下比 RSA 快得多
@@ -104,18 +109,36 @@
 //! - 区块链和加密货币应用
 //! - 需要高效密码学的物联网和嵌入式系统
 
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::ct::{Choice, ConstantTimeEq};
 use crate::errors::Error;
+use crate::policy;
 use crate::prelude::*;
-use ecdsa::{Signature as EcdsaSignature, SigningKey, VerifyingKey, signature::RandomizedSigner};
+use crate::rng::SystemRng;
+use crate::traits::asymmetric::write_signature;
+use ecdsa::{
+    Signature as EcdsaSignature, SigningKey, VerifyingKey, signature::RandomizedSigner as _,
+};
+use curve25519_dalek::edwards::EdwardsPoint;
+use curve25519_dalek::scalar::{Scalar as Ed25519Scalar, clamp_integer};
 use ed25519_dalek::{
-    Signature as Ed25519Signature, Signer as Ed25519DalekSigner, SigningKey as Ed25519SigningKey,
+    Digest as _, Sha512 as DalekSha512, Signature as Ed25519Signature,
+    Signer as Ed25519DalekSigner, SigningKey as Ed25519SigningKey,
     VerifyingKey as Ed25519VerifyingKey,
 };
-use elliptic_curve::pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey};
+use elliptic_curve::pkcs8::{
+    AssociatedOid, DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey,
+};
+use elliptic_curve::sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint};
+use elliptic_curve::{AffinePoint, CurveArithmetic, FieldBytesSize};
+#[cfg(not(feature = "fips"))]
+use k256::{Secp256k1, SecretKey as K256SecretKey, ecdsa::Signature as K256Signature};
 use p256::{NistP256, SecretKey, ecdsa::Signature as P256Signature};
-use rand_core_elliptic_curve::{OsRng, RngCore};
-use std::convert::TryFrom;
-use std::marker::PhantomData;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
 use zeroize::{Zeroize, Zeroizing};
 
 // ------------------- Marker Structs and Trait for ECC Parameters -------------------
@@ -131,11 +154,48 @@ mod private {
 /// 一个定义特定 ECC 方案参数的 trait。
 /// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
 pub trait EccParams: private::Sealed + SchemeParams {
-    fn generate_keypair() -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error>;
-    fn sign(private_key_der: &[u8], message: &[u8]) -> Result<Signature, Error>;
-    fn verify(public_key_der: &[u8], message: &[u8], signature: &Signature) -> Result<(), Error>;
-    fn validate_public_key(bytes: &[u8]) -> Result<(), Error>;
+    const SIGNATURE_SIZE: usize;
+
+    /// Generates a key pair using randomness drawn from `rng`.
+    ///
+    /// 使用从 `rng` 中获取的随机性生成密钥对。
+    fn generate_keypair<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error>;
+    /// Builds the key pair whose private scalar (or Ed25519 seed) is the 32-byte `secret`.
+    ///
+    /// 构建私有标量（或 Ed25519 种子）为 32 字节 `secret` 的密钥对。
+    fn keypair_from_secret(secret: &[u8]) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error>;
+    /// Extracts the 32-byte private scalar (or Ed25519 seed) from a PKCS#8 DER private key.
+    ///
+    /// 从 PKCS#8 DER 私钥中提取 32 字节私有标量（或 Ed25519 种子）。
+    fn secret_from_private_key(private_key_der: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error>;
+    fn sign_to_buffer(
+        private_key_der: &[u8],
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error>;
+    type PreparedKey: Clone + Send + Sync;
+
+    fn prepare_public_key(public_key_der: &[u8]) -> Result<Self::PreparedKey, Error>;
+    fn verify_prepared(
+        prepared_key: &Self::PreparedKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error>;
     fn validate_private_key(bytes: &[u8]) -> Result<(), Error>;
+
+    fn verify(public_key_der: &[u8], message: &[u8], signature: &Signature) -> Result<(), Error> {
+        Self::verify_prepared(
+            &Self::prepare_public_key(public_key_der)?,
+            message,
+            signature,
+        )
+    }
+
+    fn validate_public_key(bytes: &[u8]) -> Result<(), Error> {
+        Self::prepare_public_key(bytes).map(|_| ())
+    }
 }
 
 /// Marker struct for ECDSA P-256 parameters.
@@ -149,57 +209,254 @@ impl SchemeParams for EcdsaP256Params {
     const ID: u32 = 0x01_01_02_01;
 }
 impl EccParams for EcdsaP256Params {
-    fn generate_keypair() -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
-        let private_key = SecretKey::random(&mut OsRng);
-        let public_key = private_key.public_key();
+    const SIGNATURE_SIZE: usize = 64;
+    type PreparedKey = VerifyingKey<NistP256>;
 
-        let private_key_der = private_key
-            .to_pkcs8_der()
-            .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
-        let public_key_der = public_key
-            .to_public_key_der()
-            .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+    fn generate_keypair<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
+        weierstrass_keypair(&SecretKey::random(rng))
+    }
 
-        Ok((
-            public_key_der.as_bytes().to_vec(),
-            Zeroizing::new(private_key_der.as_bytes().to_vec()),
-        ))
+    fn keypair_from_secret(secret: &[u8]) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
+        weierstrass_keypair(&weierstrass_secret::<NistP256>(secret)?)
+    }
+
+    fn secret_from_private_key(private_key_der: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        weierstrass_secret_bytes::<NistP256>(private_key_der)
     }
 
-    fn sign(private_key_der: &[u8], message: &[u8]) -> Result<Signature, Error> {
+    fn sign_to_buffer(
+        private_key_der: &[u8],
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error> {
         let secret_key = SecretKey::from_pkcs8_der(private_key_der)
             .map_err(|_| Error::Signature(SignatureError::Signing))?;
         let signing_key: SigningKey<NistP256> = SigningKey::from(&secret_key);
-        let mut rng = OsRng;
+        let mut rng = SystemRng::new();
         let signature: P256Signature = signing_key.sign_with_rng(&mut rng, message);
-        Ok(signature.to_vec())
+        write_signature(&signature.to_bytes(), output)
     }
 
-    fn verify(public_key_der: &[u8], message: &[u8], signature: &Signature) -> Result<(), Error> {
-        let verifying_key = VerifyingKey::<NistP256>::from_public_key_der(public_key_der)
-            .map_err(|_| Error::Signature(SignatureError::Verification))?;
+    fn prepare_public_key(public_key_der: &[u8]) -> Result<Self::PreparedKey, Error> {
+        VerifyingKey::<NistP256>::from_public_key_der(public_key_der)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn verify_prepared(
+        prepared_key: &Self::PreparedKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
         let ecdsa_signature = EcdsaSignature::from_slice(signature.as_ref())
             .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
 
         use signature::Verifier as _;
-        verifying_key
+        prepared_key
             .verify(message, &ecdsa_signature)
             .map_err(|_| Error::Signature(SignatureError::Verification))
     }
 
-    fn validate_public_key(bytes: &[u8]) -> Result<(), Error> {
-        VerifyingKey::<NistP256>::from_public_key_der(bytes)
+    fn validate_private_key(bytes: &[u8]) -> Result<(), Error> {
+        SecretKey::from_pkcs8_der(bytes)
             .map(|_| ())
             .map_err(|_| Error::Key(KeyError::InvalidEncoding))
     }
+}
+
+/// Marker struct for ECDSA secp256k1 parameters.
+///
+/// secp256k1 is not a FIPS 186-5 curve, so it is unavailable with the `fips` feature.
+///
+/// ECDSA secp256k1 参数的标记结构体。
+///
+/// secp256k1 不是 FIPS 186-5 曲线，因此启用 `fips` 特性时不可用。
+#[cfg(not(feature = "fips"))]
+#[derive(Debug, Default, Clone)]
+pub struct EcdsaSecp256k1Params;
+#[cfg(not(feature = "fips"))]
+impl private::Sealed for EcdsaSecp256k1Params {}
+#[cfg(not(feature = "fips"))]
+impl SchemeParams for EcdsaSecp256k1Params {
+    const NAME: &'static str = "ECDSA-secp256k1-SHA256";
+    const ID: u32 = 0x01_01_02_03;
+}
+#[cfg(not(feature = "fips"))]
+impl EccParams for EcdsaSecp256k1Params {
+    const SIGNATURE_SIZE: usize = 64;
+    type PreparedKey = VerifyingKey<Secp256k1>;
+
+    fn generate_keypair<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
+        weierstrass_keypair(&K256SecretKey::random(rng))
+    }
+
+    fn keypair_from_secret(secret: &[u8]) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
+        weierstrass_keypair(&weierstrass_secret::<Secp256k1>(secret)?)
+    }
+
+    fn secret_from_private_key(private_key_der: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        weierstrass_secret_bytes::<Secp256k1>(private_key_der)
+    }
+
+    fn sign_to_buffer(
+        private_key_der: &[u8],
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error> {
+        let secret_key = K256SecretKey::from_pkcs8_der(private_key_der)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let signing_key: SigningKey<Secp256k1> = SigningKey::from(&secret_key);
+        let mut rng = SystemRng::new();
+        let signature: K256Signature = signing_key.sign_with_rng(&mut rng, message);
+        write_signature(&signature.to_bytes(), output)
+    }
+
+    fn prepare_public_key(public_key_der: &[u8]) -> Result<Self::PreparedKey, Error> {
+        VerifyingKey::<Secp256k1>::from_public_key_der(public_key_der)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn verify_prepared(
+        prepared_key: &Self::PreparedKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        let ecdsa_signature = EcdsaSignature::from_slice(signature.as_ref())
+            .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
+
+        use signature::Verifier as _;
+        prepared_key
+            .verify(message, &ecdsa_signature)
+            .map_err(|_| Error::Signature(SignatureError::Verification))
+    }
 
     fn validate_private_key(bytes: &[u8]) -> Result<(), Error> {
-        SecretKey::from_pkcs8_der(bytes)
+        K256SecretKey::from_pkcs8_der(bytes)
             .map(|_| ())
             .map_err(|_| Error::Key(KeyError::InvalidEncoding))
     }
 }
 
+/// An ECC parameter set on a short Weierstrass curve, whose public keys can also be
+/// exchanged as raw SEC1 points.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 短 Weierstrass 曲线上的 ECC 参数集，其公钥也可以作为原始 SEC1 点进行交换。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait Sec1Params: EccParams {
+    /// Converts a compressed or uncompressed SEC1 point into SPKI DER.
+    ///
+    /// 将压缩或未压缩的 SEC1 点转换为 SPKI DER。
+    fn sec1_to_spki(point: &[u8]) -> Result<Vec<u8>, Error>;
+    /// Converts SPKI DER into a SEC1 point, compressed if `compress` is set.
+    ///
+    /// 将 SPKI DER 转换为 SEC1 点，如果设置了 `compress` 则为压缩形式。
+    fn spki_to_sec1(public_key_der: &[u8], compress: bool) -> Result<Vec<u8>, Error>;
+}
+
+impl Sec1Params for EcdsaP256Params {
+    fn sec1_to_spki(point: &[u8]) -> Result<Vec<u8>, Error> {
+        weierstrass_sec1_to_spki::<NistP256>(point)
+    }
+
+    fn spki_to_sec1(public_key_der: &[u8], compress: bool) -> Result<Vec<u8>, Error> {
+        weierstrass_spki_to_sec1::<NistP256>(public_key_der, compress)
+    }
+}
+
+#[cfg(not(feature = "fips"))]
+impl Sec1Params for EcdsaSecp256k1Params {
+    fn sec1_to_spki(point: &[u8]) -> Result<Vec<u8>, Error> {
+        weierstrass_sec1_to_spki::<Secp256k1>(point)
+    }
+
+    fn spki_to_sec1(public_key_der: &[u8], compress: bool) -> Result<Vec<u8>, Error> {
+        weierstrass_spki_to_sec1::<Secp256k1>(public_key_der, compress)
+    }
+}
+
+/// An ECDSA parameter set, which can also sign and verify digests computed by the caller.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// ECDSA 参数集，它还可以对调用者计算的摘要进行签名和验证。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait EcdsaParams: Sec1Params {
+    /// Signs a prehashed message digest with a PKCS#8 DER private key.
+    ///
+    /// 使用 PKCS#8 DER 私钥对预哈希的消息摘要进行签名。
+    fn sign_prehash(private_key_der: &[u8], digest: &[u8]) -> Result<Signature, Error>;
+    /// Verifies a signature over a prehashed message digest with an SPKI DER public key.
+    ///
+    /// 使用 SPKI DER 公钥验证针对预哈希消息摘要的签名。
+    fn verify_prehash(
+        public_key_der: &[u8],
+        digest: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error>;
+}
+
+impl EcdsaParams for EcdsaP256Params {
+    fn sign_prehash(private_key_der: &[u8], digest: &[u8]) -> Result<Signature, Error> {
+        use signature::hazmat::RandomizedPrehashSigner;
+        let secret_key = SecretKey::from_pkcs8_der(private_key_der)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let signing_key: SigningKey<NistP256> = SigningKey::from(&secret_key);
+        let mut rng = SystemRng::new();
+        let signature: P256Signature = signing_key
+            .sign_prehash_with_rng(&mut rng, digest)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        Ok(signature.to_vec())
+    }
+
+    fn verify_prehash(
+        public_key_der: &[u8],
+        digest: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        use signature::hazmat::PrehashVerifier;
+        let verifying_key = VerifyingKey::<NistP256>::from_public_key_der(public_key_der)
+            .map_err(|_| Error::Signature(SignatureError::Verification))?;
+        let ecdsa_signature = EcdsaSignature::from_slice(signature.as_ref())
+            .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
+        verifying_key
+            .verify_prehash(digest, &ecdsa_signature)
+            .map_err(|_| Error::Signature(SignatureError::Verification))
+    }
+}
+
+#[cfg(not(feature = "fips"))]
+impl EcdsaParams for EcdsaSecp256k1Params {
+    fn sign_prehash(private_key_der: &[u8], digest: &[u8]) -> Result<Signature, Error> {
+        use signature::hazmat::RandomizedPrehashSigner;
+        let secret_key = K256SecretKey::from_pkcs8_der(private_key_der)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let signing_key: SigningKey<Secp256k1> = SigningKey::from(&secret_key);
+        let mut rng = SystemRng::new();
+        let signature: K256Signature = signing_key
+            .sign_prehash_with_rng(&mut rng, digest)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        Ok(signature.to_vec())
+    }
+
+    fn verify_prehash(
+        public_key_der: &[u8],
+        digest: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        use signature::hazmat::PrehashVerifier;
+        let verifying_key = VerifyingKey::<Secp256k1>::from_public_key_der(public_key_der)
+            .map_err(|_| Error::Signature(SignatureError::Verification))?;
+        let ecdsa_signature = EcdsaSignature::from_slice(signature.as_ref())
+            .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
+        verifying_key
+            .verify_prehash(digest, &ecdsa_signature)
+            .map_err(|_| Error::Signature(SignatureError::Verification))
+    }
+}
+
 /// Marker struct for Ed25519 parameters.
 ///
 /// Ed25519 参数的标记结构体。
@@ -211,12 +468,25 @@ impl SchemeParams for Ed25519Params {
     const ID: u32 = 0x01_01_02_02;
 }
 impl EccParams for Ed25519Params {
-    fn generate_keypair() -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
+    const SIGNATURE_SIZE: usize = 64;
+    type PreparedKey = Ed25519VerifyingKey;
+
+    fn generate_keypair<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
         let mut secret_bytes = [0u8; 32];
-        OsRng
-            .try_fill_bytes(&mut secret_bytes)
+        rng.try_fill_bytes(&mut secret_bytes)
             .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
-        let signing_key = Ed25519SigningKey::from_bytes(&secret_bytes);
+        let keypair = Self::keypair_from_secret(&secret_bytes);
+        secret_bytes.zeroize();
+        keypair
+    }
+
+    fn keypair_from_secret(secret: &[u8]) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error> {
+        let secret = Zeroizing::new(
+            <[u8; 32]>::try_from(secret).map_err(|_| Error::Key(KeyError::InvalidLength))?,
+        );
+        let signing_key = Ed25519SigningKey::from_bytes(&secret);
         let public_key = signing_key.verifying_key();
 
         let private_key_der = signing_key
@@ -232,31 +502,42 @@ impl EccParams for Ed25519Params {
         ))
     }
 
-    fn sign(private_key_der: &[u8], message: &[u8]) -> Result<Signature, Error> {
+    fn secret_from_private_key(private_key_der: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        Ed25519SigningKey::from_pkcs8_der(private_key_der)
+            .map(|signing_key| Zeroizing::new(signing_key.as_bytes().to_vec()))
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn sign_to_buffer(
+        private_key_der: &[u8],
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error> {
         let signing_key = Ed25519SigningKey::from_pkcs8_der(private_key_der)
             .map_err(|_| Error::Signature(SignatureError::Signing))?;
         let signature = signing_key.sign(message);
-        Ok(signature.to_bytes().to_vec())
+        write_signature(&signature.to_bytes(), output)
     }
 
-    fn verify(public_key_der: &[u8], message: &[u8], signature: &Signature) -> Result<(), Error> {
-        let verifying_key = Ed25519VerifyingKey::from_public_key_der(public_key_der)
-            .map_err(|_| Error::Signature(SignatureError::Verification))?;
+    fn prepare_public_key(public_key_der: &[u8]) -> Result<Self::PreparedKey, Error> {
+        Ed25519VerifyingKey::from_public_key_der(public_key_der)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn verify_prepared(
+        prepared_key: &Self::PreparedKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
         let ed_signature = Ed25519Signature::from_slice(signature.as_ref())
             .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
 
         use ed25519_dalek::Verifier as _;
-        verifying_key
+        prepared_key
             .verify(message, &ed_signature)
             .map_err(|_| Error::Signature(SignatureError::Verification))
     }
 
-    fn validate_public_key(bytes: &[u8]) -> Result<(), Error> {
-        Ed25519VerifyingKey::from_public_key_der(bytes)
-            .map(|_| ())
-            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
-    }
-
     fn validate_private_key(bytes: &[u8]) -> Result<(), Error> {
         Ed25519SigningKey::from_pkcs8_der(bytes)
             .map(|_| ())
@@ -264,6 +545,112 @@ impl EccParams for Ed25519Params {
     }
 }
 
+/// Hashes `dom2(0, context)` followed by `parts` with SHA-512 and reduces the result to a scalar,
+/// as Ed25519ctx in RFC 8032 section 5.1 does for both the nonce and the challenge.
+///
+/// 按 RFC 8032 第 5.1 节中 Ed25519ctx 对 nonce 与挑战值的做法，用 SHA-512 哈希 `dom2(0, context)`
+/// 及随后的 `parts`，并将结果约简为标量。
+fn ed25519ctx_scalar(context: &[u8], parts: &[&[u8]]) -> Ed25519Scalar {
+    let mut hasher = DalekSha512::new()
+        .chain_update(b"SigEd25519 no Ed25519 collisions")
+        .chain_update([0u8, context.len() as u8])
+        .chain_update(context);
+    for part in parts {
+        hasher.update(part);
+    }
+    let mut wide = Zeroizing::new([0u8; 64]);
+    wide.copy_from_slice(&hasher.finalize());
+    Ed25519Scalar::from_bytes_mod_order_wide(&wide)
+}
+
+/// Parses a 32-byte big-endian scalar in `[1, n)` as a secret key on curve `C`.
+///
+/// 将 `[1, n)` 范围内的 32 字节大端标量解析为曲线 `C` 上的私钥。
+fn weierstrass_secret<C: CurveArithmetic>(
+    secret: &[u8],
+) -> Result<elliptic_curve::SecretKey<C>, Error> {
+    if secret.len() != 32 {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    elliptic_curve::SecretKey::<C>::from_slice(secret)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
+/// Decodes a PKCS#8 DER secret key on curve `C` into its 32-byte big-endian scalar.
+///
+/// 将曲线 `C` 上的 PKCS#8 DER 私钥解码为其 32 字节大端标量。
+fn weierstrass_secret_bytes<C>(private_key_der: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let secret_key = elliptic_curve::SecretKey::<C>::from_pkcs8_der(private_key_der)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    let mut scalar = secret_key.to_bytes();
+    let bytes = Zeroizing::new(scalar.to_vec());
+    scalar.zeroize();
+    Ok(bytes)
+}
+
+/// Encodes a short Weierstrass secret key as (SPKI DER, PKCS#8 DER).
+///
+/// 将短 Weierstrass 曲线私钥编码为 (SPKI DER, PKCS#8 DER)。
+fn weierstrass_keypair<C>(
+    secret_key: &elliptic_curve::SecretKey<C>,
+) -> Result<(Vec<u8>, Zeroizing<Vec<u8>>), Error>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let private_key_der = secret_key
+        .to_pkcs8_der()
+        .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+    let public_key_der = secret_key
+        .public_key()
+        .to_public_key_der()
+        .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+
+    Ok((
+        public_key_der.as_bytes().to_vec(),
+        Zeroizing::new(private_key_der.as_bytes().to_vec()),
+    ))
+}
+
+/// Parses a SEC1 point on curve `C` and encodes it as SPKI DER.
+///
+/// 解析曲线 `C` 上的 SEC1 点并将其编码为 SPKI DER。
+fn weierstrass_sec1_to_spki<C>(point: &[u8]) -> Result<Vec<u8>, Error>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    elliptic_curve::PublicKey::<C>::from_sec1_bytes(point)
+        .and_then(|public_key| {
+            public_key
+                .to_public_key_der()
+                .map_err(|_| elliptic_curve::Error)
+        })
+        .map(|der| der.as_bytes().to_vec())
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
+/// Decodes SPKI DER on curve `C` into a SEC1 point.
+///
+/// 将曲线 `C` 上的 SPKI DER 解码为 SEC1 点。
+fn weierstrass_spki_to_sec1<C>(public_key_der: &[u8], compress: bool) -> Result<Vec<u8>, Error>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    elliptic_curve::PublicKey::<C>::from_public_key_der(public_key_der)
+        .map(|public_key| public_key.to_encoded_point(compress).as_bytes().to_vec())
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
 // ------------------- Newtype Wrappers for ECC Keys -------------------
 // ------------------- ECC 密钥的 Newtype 包装器 -------------------
 
@@ -273,6 +660,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EccPublicKey<P: EccParams> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
     bytes: Vec<u8>,
     _params: PhantomData<P>,
 }
@@ -323,19 +711,59 @@ impl<P: EccParams> Key for EccPublicKey<P> {
 
 impl<P: EccParams> PublicKey for EccPublicKey<P> {}
 
-#[derive(Debug, Zeroize, Clone, Eq, PartialEq)]
+impl_hex_display!(EccPublicKey<P: EccParams>);
+
+impl<P: Sec1Params> EccPublicKey<P> {
+    /// Imports a public key from a compressed (33-byte) or uncompressed (65-byte) SEC1 point.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the point is malformed or not on the curve.
+    ///
+    /// 从压缩（33 字节）或未压缩（65 字节）的 SEC1 点导入公钥。
+    ///
+    /// 如果点格式错误或不在曲线上，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_sec1_bytes(point: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            bytes: P::sec1_to_spki(point)?,
+            _params: PhantomData,
+        })
+    }
+
+    /// Exports the public key as a SEC1 point, compressed if `compress` is set.
+    ///
+    /// 将公钥导出为 SEC1 点，如果设置了 `compress` 则为压缩形式。
+    pub fn to_sec1_bytes(&self, compress: bool) -> Result<Vec<u8>, Error> {
+        P::spki_to_sec1(&self.bytes, compress)
+    }
+}
+
+#[derive(Debug, Zeroize, Clone)]
 #[zeroize(drop)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EccPrivateKey<P: EccParams> {
-    bytes: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    bytes: SecretBytes,
     _params: PhantomData<P>,
 }
 
+impl<P: EccParams> ConstantTimeEq for EccPrivateKey<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.as_slice().ct_eq(other.bytes.as_slice())
+    }
+}
+
+impl<P: EccParams> PartialEq for EccPrivateKey<P> {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl<P: EccParams> Eq for EccPrivateKey<P> {}
+
 impl<P: EccParams> Key for EccPrivateKey<P> {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         P::validate_private_key(bytes)?;
         Ok(Self {
-            bytes: Zeroizing::new(bytes.to_vec()),
+            bytes: SecretBytes::from_slice(bytes),
             _params: PhantomData,
         })
     }
@@ -354,6 +782,33 @@ impl<P: EccParams> TryFrom<&[u8]> for EccPrivateKey<P> {
 
 impl<P: EccParams + Clone> PrivateKey<EccPublicKey<P>> for EccPrivateKey<P> {}
 
+impl<P: EccParams> EccPrivateKey<P> {
+    /// Imports a private key from its raw 32-byte form: the big-endian scalar for ECDSA, or
+    /// the seed for Ed25519.
+    ///
+    /// Fails with `KeyError::InvalidLength` if `secret` is not 32 bytes, and with
+    /// `KeyError::InvalidEncoding` if an ECDSA scalar is zero or not below the curve order.
+    ///
+    /// 从原始 32 字节形式导入私钥：ECDSA 为大端标量，Ed25519 为种子。
+    ///
+    /// 如果 `secret` 不是 32 字节，则以 `KeyError::InvalidLength` 失败；如果 ECDSA 标量为零
+    /// 或不小于曲线阶，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_raw_bytes(secret: &[u8]) -> Result<Self, Error> {
+        let (_, private_key_der) = P::keypair_from_secret(secret)?;
+        Ok(Self {
+            bytes: private_key_der.into(),
+            _params: PhantomData,
+        })
+    }
+
+    /// Exports the raw 32-byte scalar (ECDSA) or seed (Ed25519) of this private key.
+    ///
+    /// 导出此私钥的原始 32 字节标量（ECDSA）或种子（Ed25519）。
+    pub fn to_raw_bytes(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        P::secret_from_private_key(&self.bytes)
+    }
+}
+
 // ------------------- Generic ECC Scheme Implementation -------------------
 // ------------------- 通用 ECC 方案实现 -------------------
 
@@ -379,23 +834,48 @@ impl<P: EccParams + Clone> Algorithm for EccScheme<P> {
 
 impl<P: EccParams + Clone> KeyGenerator for EccScheme<P> {
     fn generate_keypair() -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
-        let (pk_bytes, sk_bytes) = P::generate_keypair()?;
+        Self::generate_keypair_with_rng(&mut SystemRng::new())
+    }
+
+    fn generate_keypair_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
+        policy::enforce::<Self>()?;
+        let (pk_bytes, sk_bytes) = P::generate_keypair(rng)?;
         Ok((
             EccPublicKey {
                 bytes: pk_bytes,
                 _params: PhantomData,
             },
             EccPrivateKey {
-                bytes: sk_bytes,
+                bytes: sk_bytes.into(),
                 _params: PhantomData,
             },
         ))
     }
 }
 
+impl<P: EccParams + Clone> PublicKeyDerivation for EccScheme<P> {
+    fn derive_public_key(private_key: &Self::PrivateKey) -> Result<Self::PublicKey, Error> {
+        let secret = P::secret_from_private_key(&private_key.bytes)?;
+        let (public_key_der, _) = P::keypair_from_secret(&secret)?;
+        Ok(EccPublicKey {
+            bytes: public_key_der,
+            _params: PhantomData,
+        })
+    }
+}
+
 impl<P: EccParams + Clone> Signer for EccScheme<P> {
-    fn sign(private_key: &Self::PrivateKey, message: &[u8]) -> Result<Signature, Error> {
-        P::sign(&private_key.bytes, message)
+    const MAX_SIGNATURE_SIZE: usize = P::SIGNATURE_SIZE;
+
+    fn sign_to_buffer(
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
+        P::sign_to_buffer(&private_key.bytes, message, output)
     }
 }
 
@@ -405,10 +885,150 @@ impl<P: EccParams + Clone> Verifier for EccScheme<P> {
         message: &[u8],
         signature: &Signature,
     ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
         P::verify(&public_key.bytes, message, signature)
     }
 }
 
+impl RandomizedSigner for EccScheme<EcdsaP256Params> {
+    fn sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        policy::enforce::<Self>()?;
+        let secret_key = SecretKey::from_pkcs8_der(&private_key.bytes)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let signing_key: SigningKey<NistP256> = SigningKey::from(&secret_key);
+        let signature: P256Signature = signing_key
+            .try_sign_with_rng(rng, message)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        Ok(signature.to_vec())
+    }
+}
+
+impl<P: EccParams + Clone> PreparableVerifier for EccScheme<P> {
+    type PreparedKey = P::PreparedKey;
+
+    fn prepare_public_key(public_key: &Self::PublicKey) -> Result<Self::PreparedKey, Error> {
+        P::prepare_public_key(&public_key.bytes)
+    }
+
+    fn verify_prepared(
+        prepared_key: &Self::PreparedKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        P::verify_prepared(prepared_key, message, signature)
+    }
+}
+
+// Only Ed25519 implements context signing, natively as Ed25519ctx from RFC 8032. ECDSA has no
+// context input, and prefixing the message would overlap with plain signatures.
+// 只有 Ed25519 实现上下文签名，即 RFC 8032 原生的 Ed25519ctx。ECDSA 没有上下文输入，而给消息
+// 加前缀会与普通签名重叠。
+impl ContextSigner for EccScheme<Ed25519Params> {
+    fn sign_with_context(
+        private_key: &Self::PrivateKey,
+        context: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        policy::enforce::<Self>()?;
+        if context.len() > MAX_CONTEXT_LEN {
+            return Err(Error::Signature(SignatureError::InvalidContext));
+        }
+        let signing_key = Ed25519SigningKey::from_pkcs8_der(&private_key.bytes)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let mut expanded = Zeroizing::new([0u8; 64]);
+        expanded.copy_from_slice(&DalekSha512::digest(signing_key.as_bytes()));
+        let mut scalar_bytes = Zeroizing::new([0u8; 32]);
+        scalar_bytes.copy_from_slice(&expanded[..32]);
+        let scalar = Zeroizing::new(Ed25519Scalar::from_bytes_mod_order(clamp_integer(
+            *scalar_bytes,
+        )));
+
+        let r = Zeroizing::new(ed25519ctx_scalar(context, &[&expanded[32..], message]));
+        let big_r = EdwardsPoint::mul_base(&r).compress();
+        let k = ed25519ctx_scalar(
+            context,
+            &[
+                big_r.as_bytes(),
+                signing_key.verifying_key().as_bytes(),
+                message,
+            ],
+        );
+        let s = k * *scalar + *r;
+
+        let mut signature = Vec::with_capacity(64);
+        signature.extend_from_slice(big_r.as_bytes());
+        signature.extend_from_slice(s.as_bytes());
+        Ok(signature)
+    }
+}
+
+impl ContextVerifier for EccScheme<Ed25519Params> {
+    fn verify_with_context(
+        public_key: &Self::PublicKey,
+        context: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        if context.len() > MAX_CONTEXT_LEN {
+            return Err(Error::Signature(SignatureError::InvalidContext));
+        }
+        let verifying_key = Ed25519VerifyingKey::from_public_key_der(&public_key.bytes)
+            .map_err(|_| Error::Signature(SignatureError::Verification))?;
+        if signature.len() != 64 {
+            return Err(Error::Signature(SignatureError::InvalidSignature));
+        }
+        let (big_r, s) = signature.split_at(32);
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(s);
+        let s = Option::<Ed25519Scalar>::from(Ed25519Scalar::from_canonical_bytes(s_bytes))
+            .ok_or(Error::Signature(SignatureError::InvalidSignature))?;
+
+        let k = ed25519ctx_scalar(context, &[big_r, verifying_key.as_bytes(), message]);
+        let minus_a = -verifying_key.to_edwards();
+        let expected_r = EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &minus_a, &s);
+        if expected_r.compress().as_bytes() == big_r {
+            Ok(())
+        } else {
+            Err(Error::Signature(SignatureError::Verification))
+        }
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl<P: EcdsaParams + Clone> DigestSigner for EccScheme<P> {
+    fn sign_digest<H: Hasher>(
+        private_key: &Self::PrivateKey,
+        digest: &[u8],
+    ) -> Result<Signature, Error> {
+        policy::enforce::<Self>()?;
+        if digest.len() != H::OUTPUT_SIZE {
+            return Err(Error::Signature(SignatureError::InvalidDigestLength));
+        }
+        P::sign_prehash(&private_key.bytes, digest)
+    }
+}
+
+#[cfg(feature = "sha2")]
+impl<P: EcdsaParams + Clone> DigestVerifier for EccScheme<P> {
+    fn verify_digest<H: Hasher>(
+        public_key: &Self::PublicKey,
+        digest: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        if digest.len() != H::OUTPUT_SIZE {
+            return Err(Error::Signature(SignatureError::InvalidDigestLength));
+        }
+        P::verify_prehash(&public_key.bytes, digest, signature)
+    }
+}
+
 // ------------------- Type Aliases for Specific ECC Schemes -------------------
 // ------------------- 特定 ECC 方案的类型别名 -------------------
 
@@ -417,6 +1037,12 @@ impl<P: EccParams + Clone> Verifier for EccScheme<P> {
 /// 使用 SHA-256 的 ECDSA P-256 方案的类型别名。
 pub type EcdsaP256 = EccScheme<EcdsaP256Params>;
 
+/// A type alias for the ECDSA secp256k1 with SHA-256 scheme.
+///
+/// 使用 SHA-256 的 ECDSA secp256k1 方案的类型别名。
+#[cfg(not(feature = "fips"))]
+pub type EcdsaSecp256k1 = EccScheme<EcdsaSecp256k1Params>;
+
 /// A type alias for the Ed25519 scheme.
 ///
 /// Ed25519 方案的类型别名。
@@ -428,7 +1054,8 @@ pub type Ed25519 = EccScheme<Ed25519Params>;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fmt::Debug;
+    use crate::systems::test_rng::SeededRng;
+    use core::fmt::Debug;
 
     fn run_ecc_scheme_tests<P>()
     where
@@ -446,15 +1073,51 @@ mod tests {
         assert_eq!(pk, pk2);
         assert_eq!(sk.to_bytes(), sk2.to_bytes());
 
+        // Test public key derivation and keypair validation
+        assert_eq!(EccScheme::<P>::derive_public_key(&sk).unwrap(), pk);
+        EccScheme::<P>::validate_keypair(&pk, &sk).unwrap();
+        let (other_pk, _) = EccScheme::<P>::generate_keypair().unwrap();
+        assert_eq!(
+            EccScheme::<P>::validate_keypair(&other_pk, &sk).unwrap_err(),
+            Error::Key(KeyError::KeyPairMismatch)
+        );
+
+        // Test key generation from a caller-supplied RNG is reproducible
+        let (rng_pk, rng_sk) =
+            EccScheme::<P>::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        let (rng_pk2, rng_sk2) =
+            EccScheme::<P>::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        assert_eq!(rng_pk, rng_pk2);
+        assert_eq!(rng_sk, rng_sk2);
+        assert_ne!(
+            EccScheme::<P>::generate_keypair_with_rng(&mut SeededRng(1))
+                .unwrap()
+                .0,
+            rng_pk
+        );
+        EccScheme::<P>::validate_keypair(&rng_pk, &rng_sk).unwrap();
+
         // Test sign/verify roundtrip
         let message = b"this is the message to be signed";
         let signature = EccScheme::<P>::sign(&sk, message).unwrap();
         EccScheme::<P>::verify(&pk, message, &signature).unwrap();
+        assert_eq!(signature.len(), EccScheme::<P>::MAX_SIGNATURE_SIZE);
+
+        // Test signing into a caller-provided buffer
+        let mut buffer = [0u8; 64];
+        let len = EccScheme::<P>::sign_to_buffer(&sk, message, &mut buffer).unwrap();
+        EccScheme::<P>::verify(&pk, message, &buffer[..len].to_vec()).unwrap();
+        assert!(EccScheme::<P>::sign_to_buffer(&sk, message, &mut buffer[..len - 1]).is_err());
 
         // Test tampered message verification fails
         let tampered_message = b"this is a different message";
         assert!(EccScheme::<P>::verify(&pk, tampered_message, &signature).is_err());
 
+        // Test verification with a prepared verifying key
+        let verifier = PreparedVerifier::<EccScheme<P>>::new(&pk).unwrap();
+        verifier.verify(message, &signature).unwrap();
+        assert!(verifier.verify(tampered_message, &signature).is_err());
+
         // Test with empty message
         let empty_message = b"";
         let signature_empty = EccScheme::<P>::sign(&sk, empty_message).unwrap();
@@ -466,8 +1129,268 @@ mod tests {
         run_ecc_scheme_tests::<EcdsaP256Params>();
     }
 
+    #[test]
+    #[cfg(not(feature = "fips"))]
+    fn test_ecdsa_secp256k1() {
+        run_ecc_scheme_tests::<EcdsaSecp256k1Params>();
+    }
+
     #[test]
     fn test_ed25519() {
         run_ecc_scheme_tests::<Ed25519Params>();
     }
+
+    #[test]
+    fn test_keypair_from_secret() {
+        fn roundtrip<P: EccParams + Clone>(secret: &[u8]) {
+            let (pk_bytes, sk_bytes) = P::keypair_from_secret(secret).unwrap();
+            let pk = EccPublicKey::<P>::from_bytes(&pk_bytes).unwrap();
+            let sk = EccPrivateKey::<P>::from_bytes(&sk_bytes).unwrap();
+            let signature = EccScheme::<P>::sign(&sk, b"message").unwrap();
+            EccScheme::<P>::verify(&pk, b"message", &signature).unwrap();
+            assert_eq!(P::keypair_from_secret(secret).unwrap().0, pk_bytes);
+        }
+
+        roundtrip::<EcdsaP256Params>(&[7u8; 32]);
+        #[cfg(not(feature = "fips"))]
+        roundtrip::<EcdsaSecp256k1Params>(&[7u8; 32]);
+        roundtrip::<Ed25519Params>(&[7u8; 32]);
+
+        // Scalars must be 32 bytes and in [1, n)
+        assert_eq!(
+            EcdsaP256Params::keypair_from_secret(&[7u8; 31]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+        #[cfg(not(feature = "fips"))]
+        assert_eq!(
+            EcdsaSecp256k1Params::keypair_from_secret(&[0u8; 32]).unwrap_err(),
+            Error::Key(KeyError::InvalidEncoding)
+        );
+        assert_eq!(
+            EcdsaP256Params::keypair_from_secret(&[0xffu8; 32]).unwrap_err(),
+            Error::Key(KeyError::InvalidEncoding)
+        );
+        assert_eq!(
+            Ed25519Params::keypair_from_secret(&[7u8; 33]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_keypair() {
+        let keypair = KeyPair::<EcdsaP256>::generate().unwrap();
+        let signature = keypair.sign(b"message").unwrap();
+        keypair.verify(b"message", &signature).unwrap();
+        EcdsaP256::verify(keypair.public_key(), b"message", &signature).unwrap();
+        assert!(keypair.verify(b"other message", &signature).is_err());
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&keypair).unwrap();
+            let parsed: KeyPair<EcdsaP256> = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.public_key(), keypair.public_key());
+            assert_eq!(parsed.private_key(), keypair.private_key());
+            assert!(json.contains(&keypair.public_key().to_base64().unwrap()));
+
+            let encoded = bincode::serialize(&keypair).unwrap();
+            let parsed: KeyPair<EcdsaP256> = bincode::deserialize(&encoded).unwrap();
+            assert_eq!(parsed.public_key(), keypair.public_key());
+            assert_eq!(parsed.private_key(), keypair.private_key());
+        }
+
+        let (pk, sk) = keypair.into_parts();
+        let keypair = KeyPair::<EcdsaP256>::from((pk.clone(), sk));
+        assert_eq!(keypair.public_key(), &pk);
+        assert!(!format!("{keypair:?}").contains("private_key"));
+    }
+
+    #[test]
+    fn test_private_key_raw_bytes() {
+        fn roundtrip<P: EccParams + Clone>() {
+            let (pk, sk) = EccScheme::<P>::generate_keypair().unwrap();
+            let raw = sk.to_raw_bytes().unwrap();
+            assert_eq!(raw.len(), 32);
+            let imported = EccPrivateKey::<P>::from_raw_bytes(&raw).unwrap();
+            assert_eq!(imported, sk);
+            let signature = EccScheme::<P>::sign(&imported, b"message").unwrap();
+            EccScheme::<P>::verify(&pk, b"message", &signature).unwrap();
+        }
+
+        roundtrip::<EcdsaP256Params>();
+        #[cfg(not(feature = "fips"))]
+        roundtrip::<EcdsaSecp256k1Params>();
+        roundtrip::<Ed25519Params>();
+
+        // RFC 8032 test 1: the seed determines the public key
+        let seed = hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+            .unwrap();
+        let sk = EccPrivateKey::<Ed25519Params>::from_raw_bytes(&seed).unwrap();
+        let signature = EccScheme::<Ed25519Params>::sign(&sk, b"").unwrap();
+        assert_eq!(
+            hex::encode(&signature),
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        );
+
+        assert_eq!(
+            EccPrivateKey::<EcdsaP256Params>::from_raw_bytes(&[0u8; 32]).unwrap_err(),
+            Error::Key(KeyError::InvalidEncoding)
+        );
+        assert_eq!(
+            EccPrivateKey::<Ed25519Params>::from_raw_bytes(&seed[..31]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_ed25519ctx_vectors() {
+        // RFC 8032 section 7.2: Ed25519ctx with the contexts "foo" and "bar"
+        let seed = hex::decode("0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6")
+            .unwrap();
+        let message = hex::decode("f726936d19c800494e3fdaff20b276a8").unwrap();
+        let sk = EccPrivateKey::<Ed25519Params>::from_raw_bytes(&seed).unwrap();
+        let pk = Ed25519::derive_public_key(&sk).unwrap();
+        for (context, expected) in [
+            (
+                &b"foo"[..],
+                "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+            ),
+            (
+                &b"bar"[..],
+                "fc60d5872fc46b3aa69f8b5b4351d5808f92bcc044606db097abab6dbcb1aee3216c48e8b3b66431b5b186d1d28f8ee15a5ca2df6668346291c2043d4eb3e90d",
+            ),
+        ] {
+            let signature = Ed25519::sign_with_context(&sk, context, &message).unwrap();
+            assert_eq!(hex::encode(&signature), expected);
+            Ed25519::verify_with_context(&pk, context, &message, &signature).unwrap();
+        }
+
+        // Context-bound signatures are domain separated, also from plain signatures
+        let (pk, sk) = Ed25519::generate_keypair().unwrap();
+        let message = b"this is the message to be signed";
+        let context = b"protocol-a";
+        let signature_ctx = Ed25519::sign_with_context(&sk, context, message).unwrap();
+        Ed25519::verify_with_context(&pk, context, message, &signature_ctx).unwrap();
+        assert!(Ed25519::verify_with_context(&pk, b"protocol-b", message, &signature_ctx).is_err());
+        assert!(Ed25519::verify(&pk, message, &signature_ctx).is_err());
+        let signature = Ed25519::sign(&sk, message).unwrap();
+        assert!(Ed25519::verify_with_context(&pk, b"", message, &signature).is_err());
+        assert!(Ed25519::sign_with_context(&sk, &[0u8; 256], message).is_err());
+
+        // A non-canonical `S` is rejected
+        let mut signature = Ed25519::sign_with_context(&sk, b"foo", message).unwrap();
+        signature[63] |= 0xf0;
+        assert_eq!(
+            Ed25519::verify_with_context(&pk, b"foo", message, &signature).unwrap_err(),
+            Error::Signature(SignatureError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_sec1_points() {
+        // P-256 public key point generated with OpenSSL
+        let uncompressed = hex::decode("0438ed267a007a52a8b3e8b1fb784ff2f038f7f8b46a3410c0c589d0fec3824622d54a0d466f0c366b85a20c01d5a530172c457c52ba52826bb8efbdfc631e7783").unwrap();
+        let compressed =
+            hex::decode("0338ed267a007a52a8b3e8b1fb784ff2f038f7f8b46a3410c0c589d0fec3824622")
+                .unwrap();
+        let pk = EccPublicKey::<EcdsaP256Params>::from_sec1_bytes(&compressed).unwrap();
+        assert_eq!(
+            pk,
+            EccPublicKey::<EcdsaP256Params>::from_sec1_bytes(&uncompressed).unwrap()
+        );
+        assert_eq!(pk.to_sec1_bytes(true).unwrap(), compressed);
+        assert_eq!(pk.to_sec1_bytes(false).unwrap(), uncompressed);
+
+        // Points of the other curve, truncated points and the identity are rejected
+        #[cfg(not(feature = "fips"))]
+        assert!(EccPublicKey::<EcdsaSecp256k1Params>::from_sec1_bytes(&uncompressed).is_err());
+        assert!(EccPublicKey::<EcdsaP256Params>::from_sec1_bytes(&compressed[..32]).is_err());
+        assert!(EccPublicKey::<EcdsaP256Params>::from_sec1_bytes(&[0]).is_err());
+
+        #[cfg(not(feature = "fips"))]
+        {
+            let (pk, sk) = EcdsaSecp256k1::generate_keypair().unwrap();
+            let point = pk.to_sec1_bytes(true).unwrap();
+            assert_eq!(point.len(), 33);
+            let imported = EccPublicKey::<EcdsaSecp256k1Params>::from_sec1_bytes(&point).unwrap();
+            let signature = EcdsaSecp256k1::sign(&sk, b"message").unwrap();
+            EcdsaSecp256k1::verify(&imported, b"message", &signature).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async")]
+    fn test_async_sign_verify() {
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+
+        // The blanket impls resolve immediately, so a single poll is enough.
+        fn ready<F: Future>(future: F) -> F::Output {
+            match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+                Poll::Ready(output) => output,
+                Poll::Pending => panic!("future was not ready"),
+            }
+        }
+
+        let (pk, sk) = Ed25519::generate_keypair().unwrap();
+        let message = b"this is the message to be signed";
+        let signature = ready(Ed25519::sign_async(&sk, message)).unwrap();
+        ready(Ed25519::verify_async(&pk, message, &signature)).unwrap();
+        Ed25519::verify(&pk, message, &signature).unwrap();
+        assert!(ready(Ed25519::verify_async(&pk, b"tampered", &signature)).is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_p256_sign_with_rng() {
+        let (pk, sk) = EcdsaP256::generate_keypair().unwrap();
+        let message = b"this is the message to be signed";
+        let signature =
+            EcdsaP256::sign_with_rng(&mut rand_core_elliptic_curve::OsRng, &sk, message).unwrap();
+        EcdsaP256::verify(&pk, message, &signature).unwrap();
+        assert!(EcdsaP256::verify(&pk, b"this is a different message", &signature).is_err());
+
+        // The signature is determined by the caller's RNG, so it is actually used
+        let seeded = |seed| EcdsaP256::sign_with_rng(&mut SeededRng(seed), &sk, message).unwrap();
+        assert_eq!(seeded(1), seeded(1));
+        assert_ne!(seeded(1), seeded(2));
+        EcdsaP256::verify(&pk, message, &seeded(1)).unwrap();
+        EcdsaP256::verify(&pk, message, &seeded(2)).unwrap();
+    }
+
+    #[cfg(feature = "sha2")]
+    fn run_digest_signing_tests<P: EcdsaParams + Clone>() {
+        let (pk, sk) = EccScheme::<P>::generate_keypair().unwrap();
+        let message = b"this is the message to be signed";
+
+        // A SHA-256 prehash signature verifies with the message-based API and vice versa.
+        let digest = Sha256::hash(message);
+        let signature = EccScheme::<P>::sign_digest::<Sha256>(&sk, &digest).unwrap();
+        EccScheme::<P>::verify_digest::<Sha256>(&pk, &digest, &signature).unwrap();
+        EccScheme::<P>::verify(&pk, message, &signature).unwrap();
+        let signature = EccScheme::<P>::sign(&sk, message).unwrap();
+        EccScheme::<P>::verify_digest::<Sha256>(&pk, &digest, &signature).unwrap();
+
+        // Other hash algorithms are supported as long as the digest length matches.
+        let digest = Sha512::hash(message);
+        let signature = EccScheme::<P>::sign_digest::<Sha512>(&sk, &digest).unwrap();
+        EccScheme::<P>::verify_digest::<Sha512>(&pk, &digest, &signature).unwrap();
+        let other = Sha512::hash(b"x");
+        assert!(EccScheme::<P>::verify_digest::<Sha512>(&pk, &other, &signature).is_err());
+        assert_eq!(
+            EccScheme::<P>::sign_digest::<Sha384>(&sk, &digest).unwrap_err(),
+            Error::Signature(SignatureError::InvalidDigestLength)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_ecdsa_p256_digest_signing() {
+        run_digest_signing_tests::<EcdsaP256Params>();
+    }
+
+    #[test]
+    #[cfg(all(feature = "sha2", not(feature = "fips")))]
+    fn test_ecdsa_secp256k1_digest_signing() {
+        run_digest_signing_tests::<EcdsaSecp256k1Params>();
+    }
 }
@@ -22,6 +22,9 @@
 //!
 //! # Key Formats
 //! Keys are expected to be in PKCS#8 DER format for interoperability with other systems.
+//! Public keys can also be imported and exported as raw SEC1 points, compressed (33 bytes)
+//! or uncompressed (65 bytes), with `from_sec1_bytes` and `to_sec1_bytes`; private keys can
+//! be handled as raw 32-byte scalars with `from_raw_bytes` and `to_raw_bytes`.
 //!
 //! # Performance Characteristics
 //! - Much faster than RSA for equivalent security levels
@@ -32,7 +35,7 @@
 //! - Use ephemeral keys for forward secrecy
 //! - Authenticate the key exchange to prevent man-in-the-middle attacks
 //! - Validate public keys to prevent invalid curve attacks
-//! - Use the shared secret with a key derivation function (e.g., HKDF)
+//! - Derive keys from the shared secret with a KDF (e.g., HKDF) via `SharedSecret::expand`
 //! - Consider post-quantum alternatives for long-term security
 //!
 //! 提供了椭圆曲线迪菲-赫尔曼 (ECDH) 的实现。
@@ -58,6 +61,9 @@
 //!
 //! # 密钥格式
 //! 密钥应为 PKCS#8 DER 格式，以便与其他系统互操作。
+//! 公钥也可以通过 `from_sec1_bytes` 和 `to_sec1_bytes` 以原始 SEC1 点的形式导入和导出，
+//! 支持压缩 (33 字节) 和未压缩 (65 字节) 格式；私钥可以通过 `from_raw_bytes` 和
+//! `to_raw_bytes` 以原始 32 字节标量的形式处理。
 //!
 //! # 性能特征
 //! - 在相同安全级别下比 RSA 快得多
@@ -68,16 +74,23 @@
 //! - 使用临时密钥以获得前向保密性
 //! - 认证密钥交换以防止中间人攻击
 //! - 验证公钥以防止无效曲线攻击
-//! - 将共享密钥与密钥派生函数（例如 HKDF）一起使用
+//! - 通过 `SharedSecret::expand` 使用 KDF（例如 HKDF）从共享密钥派生密钥
 //! - 考虑后量子替代方案以获得长期安全性
 
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::ct::{Choice, ConstantTimeEq};
 use crate::errors::Error;
+use crate::policy;
 use crate::prelude::*;
+use crate::rng::SystemRng;
 use elliptic_curve::pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey};
+use elliptic_curve::sec1::ToEncodedPoint;
 use p256::{NistP256, PublicKey as P256PublicKey, SecretKey, ecdh};
-use rand_core_elliptic_curve::OsRng;
-use std::convert::TryFrom;
-use std::marker::PhantomData;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
 use zeroize::{Zeroize, Zeroizing};
 
 // ------------------- Marker Structs and Trait for ECDH Parameters -------------------
@@ -97,6 +110,22 @@ pub trait EcdhParams: private::Sealed + SchemeParams {
 
     fn validate_public_key(bytes: &[u8]) -> Result<(), Error>;
     fn validate_private_key(bytes: &[u8]) -> Result<(), Error>;
+    /// Converts a compressed or uncompressed SEC1 point into SPKI DER.
+    ///
+    /// 将压缩或未压缩的 SEC1 点转换为 SPKI DER。
+    fn sec1_to_spki(point: &[u8]) -> Result<Vec<u8>, Error>;
+    /// Converts SPKI DER into a SEC1 point, compressed if `compress` is set.
+    ///
+    /// 将 SPKI DER 转换为 SEC1 点，如果设置了 `compress` 则为压缩形式。
+    fn spki_to_sec1(public_key_der: &[u8], compress: bool) -> Result<Vec<u8>, Error>;
+    /// Encodes a raw big-endian private scalar as PKCS#8 DER, rejecting scalars outside `[1, n)`.
+    ///
+    /// 将原始大端私有标量编码为 PKCS#8 DER，拒绝不在 `[1, n)` 范围内的标量。
+    fn scalar_to_pkcs8(scalar: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error>;
+    /// Extracts the raw big-endian private scalar from a PKCS#8 DER private key.
+    ///
+    /// 从 PKCS#8 DER 私钥中提取原始大端私有标量。
+    fn pkcs8_to_scalar(private_key_der: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error>;
 }
 
 /// Marker struct for ECDH with NIST P-256 parameters.
@@ -123,6 +152,40 @@ impl EcdhParams for EcdhP256Params {
             .map(|_| ())
             .map_err(|_| Error::Key(KeyError::InvalidEncoding))
     }
+
+    fn sec1_to_spki(point: &[u8]) -> Result<Vec<u8>, Error> {
+        P256PublicKey::from_sec1_bytes(point)
+            .map_err(|_| Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))?
+            .to_public_key_der()
+            .map(|der| der.as_bytes().to_vec())
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn spki_to_sec1(public_key_der: &[u8], compress: bool) -> Result<Vec<u8>, Error> {
+        P256PublicKey::from_public_key_der(public_key_der)
+            .map(|public_key| public_key.to_encoded_point(compress).as_bytes().to_vec())
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn scalar_to_pkcs8(scalar: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        if scalar.len() != 32 {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        SecretKey::from_slice(scalar)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?
+            .to_pkcs8_der()
+            .map(|der| Zeroizing::new(der.as_bytes().to_vec()))
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn pkcs8_to_scalar(private_key_der: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let secret = SecretKey::from_pkcs8_der(private_key_der)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        let mut scalar = secret.to_bytes();
+        let bytes = Zeroizing::new(scalar.to_vec());
+        scalar.zeroize();
+        Ok(bytes)
+    }
 }
 
 // ------------------- Newtype Wrappers for ECDH Keys -------------------
@@ -134,6 +197,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EcdhPublicKey<P: EcdhParams> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
     bytes: Vec<u8>,
     _params: PhantomData<P>,
 }
@@ -184,19 +248,60 @@ impl<P: EcdhParams> Key for EcdhPublicKey<P> {
 
 impl<P: EcdhParams> PublicKey for EcdhPublicKey<P> {}
 
-#[derive(Debug, Zeroize, Clone, Eq, PartialEq)]
+impl_hex_display!(EcdhPublicKey<P: EcdhParams>);
+
+impl<P: EcdhParams> EcdhPublicKey<P> {
+    /// Imports a public key from a compressed (33-byte) or uncompressed (65-byte) SEC1 point.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if the point is malformed or not
+    /// on the curve.
+    ///
+    /// 从压缩（33 字节）或未压缩（65 字节）的 SEC1 点导入公钥。
+    ///
+    /// 如果点格式错误或不在曲线上，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn from_sec1_bytes(point: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            bytes: P::sec1_to_spki(point)?,
+            _params: PhantomData,
+        })
+    }
+
+    /// Exports the public key as a SEC1 point, compressed if `compress` is set.
+    ///
+    /// 将公钥导出为 SEC1 点，如果设置了 `compress` 则为压缩形式。
+    pub fn to_sec1_bytes(&self, compress: bool) -> Result<Vec<u8>, Error> {
+        P::spki_to_sec1(&self.bytes, compress)
+    }
+}
+
+#[derive(Debug, Zeroize, Clone)]
 #[zeroize(drop)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EcdhPrivateKey<P: EcdhParams> {
-    bytes: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    bytes: SecretBytes,
     _params: PhantomData<P>,
 }
 
+impl<P: EcdhParams> ConstantTimeEq for EcdhPrivateKey<P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.as_slice().ct_eq(other.bytes.as_slice())
+    }
+}
+
+impl<P: EcdhParams> PartialEq for EcdhPrivateKey<P> {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl<P: EcdhParams> Eq for EcdhPrivateKey<P> {}
+
 impl<P: EcdhParams> Key for EcdhPrivateKey<P> {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         P::validate_private_key(bytes)?;
         Ok(Self {
-            bytes: Zeroizing::new(bytes.to_vec()),
+            bytes: SecretBytes::from_slice(bytes),
             _params: PhantomData,
         })
     }
@@ -215,6 +320,31 @@ impl<P: EcdhParams> TryFrom<&[u8]> for EcdhPrivateKey<P> {
 
 impl<P: EcdhParams + Clone> PrivateKey<EcdhPublicKey<P>> for EcdhPrivateKey<P> {}
 
+impl<P: EcdhParams> EcdhPrivateKey<P> {
+    /// Imports a private key from its raw 32-byte big-endian scalar.
+    ///
+    /// Fails with `KeyError::InvalidLength` if `scalar` is not 32 bytes, and with
+    /// `KeyError::InvalidEncoding` if it is zero or not below the curve order.
+    ///
+    /// 从原始 32 字节大端标量导入私钥。
+    ///
+    /// 如果 `scalar` 不是 32 字节，则以 `KeyError::InvalidLength` 失败；如果它为零或不小于
+    /// 曲线阶，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_raw_bytes(scalar: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            bytes: P::scalar_to_pkcs8(scalar)?.into(),
+            _params: PhantomData,
+        })
+    }
+
+    /// Exports the raw 32-byte big-endian scalar of this private key.
+    ///
+    /// 导出此私钥的原始 32 字节大端标量。
+    pub fn to_raw_bytes(&self) -> Result<Zeroizing<Vec<u8>>, Error> {
+        P::pkcs8_to_scalar(&self.bytes)
+    }
+}
+
 // ------------------- Generic ECDH Scheme Implementation -------------------
 // ------------------- 通用 ECDH 方案实现 -------------------
 
@@ -240,7 +370,14 @@ impl<P: EcdhParams + Clone> Algorithm for EcdhScheme<P> {
 
 impl KeyGenerator for EcdhScheme<EcdhP256Params> {
     fn generate_keypair() -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
-        let secret = SecretKey::random(&mut OsRng);
+        Self::generate_keypair_with_rng(&mut SystemRng::new())
+    }
+
+    fn generate_keypair_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::PrivateKey), Error> {
+        policy::enforce::<Self>()?;
+        let secret = SecretKey::random(rng);
         let public_key = secret.public_key();
 
         let private_key_der = secret
@@ -257,18 +394,33 @@ impl KeyGenerator for EcdhScheme<EcdhP256Params> {
                 _params: PhantomData,
             },
             EcdhPrivateKey {
-                bytes: Zeroizing::new(private_key_der.as_bytes().to_vec()),
+                bytes: SecretBytes::from_slice(private_key_der.as_bytes()),
                 _params: PhantomData,
             },
         ))
     }
 }
 
+impl PublicKeyDerivation for EcdhScheme<EcdhP256Params> {
+    fn derive_public_key(private_key: &Self::PrivateKey) -> Result<Self::PublicKey, Error> {
+        let public_key_der = SecretKey::from_pkcs8_der(&private_key.bytes)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?
+            .public_key()
+            .to_public_key_der()
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        Ok(EcdhPublicKey {
+            bytes: public_key_der.as_bytes().to_vec(),
+            _params: PhantomData,
+        })
+    }
+}
+
 impl KeyAgreement for EcdhScheme<EcdhP256Params> {
     fn agree(
         private_key: &Self::PrivateKey,
         public_key: &Self::PublicKey,
     ) -> Result<SharedSecret, Error> {
+        policy::enforce::<Self>()?;
         let pk = P256PublicKey::from_public_key_der(&public_key.bytes)
             .map_err(|_| Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))?;
 
@@ -276,7 +428,7 @@ impl KeyAgreement for EcdhScheme<EcdhP256Params> {
             .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
         let shared_secret = ecdh::diffie_hellman(sk.to_nonzero_scalar(), pk.as_affine());
 
-        Ok(Zeroizing::new(shared_secret.raw_secret_bytes().to_vec()))
+        Ok(SharedSecret::new(shared_secret.raw_secret_bytes().to_vec()))
     }
 }
 
@@ -294,6 +446,7 @@ pub type EcdhP256 = EcdhScheme<EcdhP256Params>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::systems::test_rng::SeededRng;
 
     #[test]
     fn test_ecdh_p256_key_agreement() {
@@ -320,4 +473,104 @@ mod tests {
         let alice_shared2 = EcdhP256::agree(&alice_sk2, &bob_pk).unwrap();
         assert_eq!(alice_shared, alice_shared2);
     }
+
+    #[test]
+    fn test_ecdh_p256_keypair() {
+        let alice = KeyPair::<EcdhP256>::generate().unwrap();
+        let bob = KeyPair::<EcdhP256>::generate().unwrap();
+        let restored = KeyPair::<EcdhP256>::from_private_key(bob.private_key().clone()).unwrap();
+        assert_eq!(restored.public_key(), bob.public_key());
+        restored.validate().unwrap();
+        let mixed_up =
+            KeyPair::<EcdhP256>::new(alice.public_key().clone(), bob.private_key().clone());
+        assert_eq!(
+            mixed_up.validate().unwrap_err(),
+            Error::Key(KeyError::KeyPairMismatch)
+        );
+        assert_eq!(
+            alice.agree(bob.public_key()).unwrap(),
+            bob.agree(alice.public_key()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ecdh_p256_generate_keypair_with_rng() {
+        let (pk, sk) = EcdhP256::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        let (pk2, sk2) = EcdhP256::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        assert_eq!(pk, pk2);
+        assert_eq!(sk, sk2);
+        let (other_pk, _) = EcdhP256::generate_keypair_with_rng(&mut SeededRng(1)).unwrap();
+        assert_ne!(pk, other_pk);
+        EcdhP256::validate_keypair(&pk, &sk).unwrap();
+    }
+
+    #[test]
+    fn test_ecdh_p256_raw_private_key() {
+        let (alice_pk, alice_sk) = EcdhP256::generate_keypair().unwrap();
+        let (bob_pk, bob_sk) = EcdhP256::generate_keypair().unwrap();
+
+        let raw = alice_sk.to_raw_bytes().unwrap();
+        assert_eq!(raw.len(), 32);
+        let imported = EcdhPrivateKey::<EcdhP256Params>::from_raw_bytes(&raw).unwrap();
+        assert_eq!(imported, alice_sk);
+        assert_eq!(
+            EcdhP256::agree(&imported, &bob_pk).unwrap(),
+            EcdhP256::agree(&bob_sk, &alice_pk).unwrap()
+        );
+
+        assert_eq!(
+            EcdhPrivateKey::<EcdhP256Params>::from_raw_bytes(&raw[1..]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+        assert_eq!(
+            EcdhPrivateKey::<EcdhP256Params>::from_raw_bytes(&[0xffu8; 32]).unwrap_err(),
+            Error::Key(KeyError::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn test_ecdh_p256_sec1_points() {
+        let (alice_pk, alice_sk) = EcdhP256::generate_keypair().unwrap();
+        let (bob_pk, bob_sk) = EcdhP256::generate_keypair().unwrap();
+
+        // Bob receives Alice's key as a compressed point
+        let point = alice_pk.to_sec1_bytes(true).unwrap();
+        assert_eq!(point.len(), 33);
+        let imported = EcdhPublicKey::<EcdhP256Params>::from_sec1_bytes(&point).unwrap();
+        assert_eq!(imported, alice_pk);
+        assert_eq!(
+            EcdhP256::agree(&bob_sk, &imported).unwrap(),
+            EcdhP256::agree(&alice_sk, &bob_pk).unwrap()
+        );
+        assert_eq!(bob_pk.to_sec1_bytes(false).unwrap().len(), 65);
+
+        assert_eq!(
+            EcdhPublicKey::<EcdhP256Params>::from_sec1_bytes(&point[1..]).unwrap_err(),
+            Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey)
+        );
+    }
+
+    #[cfg(feature = "hkdf-default")]
+    #[test]
+    fn test_ecdh_shared_secret_expand() {
+        use crate::systems::kdf::hkdf::HkdfSha256;
+
+        let (alice_pk, alice_sk) = EcdhP256::generate_keypair().unwrap();
+        let (bob_pk, bob_sk) = EcdhP256::generate_keypair().unwrap();
+        let alice_shared = EcdhP256::agree(&alice_sk, &bob_pk).unwrap();
+        let bob_shared = EcdhP256::agree(&bob_sk, &alice_pk).unwrap();
+        assert_eq!(alice_shared.expose_raw().len(), 32);
+
+        let hkdf = HkdfSha256::default();
+        let alice_key = alice_shared.expand(&hkdf, b"session", 32).unwrap();
+        let bob_key = bob_shared.expand(&hkdf, b"session", 32).unwrap();
+        assert_eq!(alice_key, bob_key);
+        assert_eq!(
+            alice_key,
+            hkdf.derive(alice_shared.expose_raw(), None, Some(b"session"), 32)
+                .unwrap()
+        );
+        assert_ne!(alice_key.as_bytes(), alice_shared.expose_raw());
+        assert_eq!(format!("{alice_shared:?}"), "SharedSecret { .. }");
+    }
 }
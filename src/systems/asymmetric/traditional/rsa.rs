@@ -46,15 +46,25 @@
 //! - 使用适当的填充方案（OAEP、PSS）来防止攻击
 //! - 低于 2048 位的密钥大小被认为是不安全的
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::ct::{Choice, ConstantTimeEq};
 use crate::errors::Error;
+use crate::policy;
 use crate::prelude::*;
+use crate::rng::SystemRng;
+use crate::traits::asymmetric::write_signature;
 use rsa::{
     pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey},
-    rand_core::{OsRng, RngCore},
+    rand_core::RngCore,
 };
-use std::convert::TryFrom;
-use std::marker::PhantomData;
-use zeroize::{Zeroize, Zeroizing};
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use zeroize::Zeroize;
 // ------------------- Marker Structs and Trait for RSA Parameters -------------------
 // ------------------- 用于 RSA 参数的标记结构体和 Trait -------------------
 
@@ -68,7 +78,7 @@ mod private {
 /// 一个为 RSA 方案定义密钥大小的 trait。
 /// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
 pub trait RsaKeyParams:
-    private::Sealed + Send + Sync + 'static + Clone + Default + std::fmt::Debug
+    private::Sealed + Send + Sync + 'static + Clone + Default + core::fmt::Debug
 {
     /// The number of bits for the RSA key.
     ///
@@ -124,10 +134,13 @@ impl RsaPublicKey {
     }
 }
 
-#[derive(Debug, Zeroize, Clone, Eq, PartialEq)]
+#[derive(Debug, Zeroize, Clone)]
 #[zeroize(drop)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct RsaPrivateKey(Zeroizing<Vec<u8>>);
+pub struct RsaPrivateKey(
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    SecretBytes,
+);
 
 impl RsaPrivateKey {
     pub fn inner(&self) -> &[u8] {
@@ -135,6 +148,20 @@ impl RsaPrivateKey {
     }
 }
 
+impl ConstantTimeEq for RsaPrivateKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.as_slice().ct_eq(other.0.as_slice())
+    }
+}
+
+impl PartialEq for RsaPrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for RsaPrivateKey {}
+
 impl Key for RsaPublicKey {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         rsa::RsaPublicKey::from_public_key_der(bytes)
@@ -151,6 +178,8 @@ impl Key for RsaPublicKey {
     }
 }
 impl PublicKey for RsaPublicKey {}
+
+impl_hex_display!(RsaPublicKey);
 impl<'a> From<&'a RsaPublicKey> for RsaPublicKey {
     fn from(key: &'a RsaPublicKey) -> Self {
         key.clone()
@@ -169,7 +198,7 @@ impl Key for RsaPrivateKey {
         // Just validate that it's a valid key, then store the bytes
         rsa::RsaPrivateKey::from_pkcs8_der(bytes)
             .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
-        Ok(RsaPrivateKey(Zeroizing::new(bytes.to_vec())))
+        Ok(RsaPrivateKey(SecretBytes::from_slice(bytes)))
     }
 
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
@@ -228,8 +257,14 @@ impl<KP: RsaKeyParams, H: Hasher> Parameterized for RsaScheme<KP, H> {
 
 impl<KP: RsaKeyParams, H: Hasher> KeyGenerator for RsaScheme<KP, H> {
     fn generate_keypair() -> Result<(RsaPublicKey, RsaPrivateKey), Error> {
-        let mut rng = OsRng;
-        let private_key = rsa::RsaPrivateKey::new(&mut rng, KP::KEY_BITS)
+        Self::generate_keypair_with_rng(&mut SystemRng::new())
+    }
+
+    fn generate_keypair_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(RsaPublicKey, RsaPrivateKey), Error> {
+        policy::enforce::<Self>()?;
+        let private_key = rsa::RsaPrivateKey::new(rng, KP::KEY_BITS)
             .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
         let public_key = RsaPublicKey(private_key.to_public_key());
         let private_key_der = private_key
@@ -237,35 +272,63 @@ impl<KP: RsaKeyParams, H: Hasher> KeyGenerator for RsaScheme<KP, H> {
             .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
         Ok((
             public_key,
-            RsaPrivateKey(Zeroizing::new(private_key_der.as_bytes().to_vec())),
+            RsaPrivateKey(SecretBytes::from_slice(private_key_der.as_bytes())),
         ))
     }
 }
 
+impl<KP: RsaKeyParams, H: Hasher> PublicKeyDerivation for RsaScheme<KP, H> {
+    fn derive_public_key(private_key: &RsaPrivateKey) -> Result<RsaPublicKey, Error> {
+        rsa::RsaPrivateKey::from_pkcs8_der(&private_key.0)
+            .map(|private_key| RsaPublicKey(private_key.to_public_key()))
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+}
+
 impl<KP: RsaKeyParams, H: Hasher> Kem for RsaScheme<KP, H> {
     type EncapsulatedKey = EncapsulatedKey;
 
     fn encapsulate(public_key: &RsaPublicKey) -> Result<(SharedSecret, EncapsulatedKey), Error> {
-        let mut rng = OsRng;
+        policy::enforce::<Self>()?;
+        let mut rng = SystemRng::new();
         let mut shared_secret_bytes = vec![0u8; SHARED_SECRET_SIZE];
         rng.fill_bytes(&mut shared_secret_bytes);
         let encapsulated_key = H::rsa_oaep_encrypt(public_key, &shared_secret_bytes)?;
-        Ok((Zeroizing::new(shared_secret_bytes), encapsulated_key))
+        Ok((SharedSecret::new(shared_secret_bytes), encapsulated_key))
     }
 
     fn decapsulate(
         private_key: &RsaPrivateKey,
         encapsulated_key: &EncapsulatedKey,
     ) -> Result<SharedSecret, Error> {
+        policy::enforce::<Self>()?;
         let shared_secret_bytes = H::rsa_oaep_decrypt(private_key, encapsulated_key)?;
-        Ok(Zeroizing::new(shared_secret_bytes))
+        Ok(SharedSecret::new(shared_secret_bytes))
     }
 }
 
 impl<KP: RsaKeyParams, H: Hasher> Signer for RsaScheme<KP, H> {
+    const MAX_SIGNATURE_SIZE: usize = KP::KEY_BITS / 8;
+
     fn sign(private_key: &RsaPrivateKey, message: &[u8]) -> Result<Signature, Error> {
+        policy::enforce::<Self>()?;
         H::rsa_pss_sign(private_key, message)
     }
+
+    /// Unlike ECDSA, Ed25519 and Dilithium, this allocates: the `rsa` crate returns the
+    /// signature on the heap, and it is then copied into `output`.
+    ///
+    /// 与 ECDSA、Ed25519 和 Dilithium 不同，此方法会分配内存：`rsa` crate 在堆上返回签名，然后再将其复制到
+    /// `output` 中。
+    fn sign_to_buffer(
+        private_key: &RsaPrivateKey,
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
+        let signature = H::rsa_pss_sign(private_key, message)?;
+        write_signature(&signature, output)
+    }
 }
 
 impl<KP: RsaKeyParams, H: Hasher> Verifier for RsaScheme<KP, H> {
@@ -274,10 +337,68 @@ impl<KP: RsaKeyParams, H: Hasher> Verifier for RsaScheme<KP, H> {
         message: &[u8],
         signature: &Signature,
     ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
         H::rsa_pss_verify(public_key, message, signature)
     }
 }
 
+// RSA public keys are stored in decoded form, so preparing one only clones it.
+// RSA 公钥以解码后的形式存储，因此预处理只需克隆它。
+impl<KP: RsaKeyParams, H: Hasher> PreparableVerifier for RsaScheme<KP, H> {
+    type PreparedKey = RsaPublicKey;
+
+    fn prepare_public_key(public_key: &RsaPublicKey) -> Result<RsaPublicKey, Error> {
+        Ok(public_key.clone())
+    }
+
+    fn verify_prepared(
+        prepared_key: &RsaPublicKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        H::rsa_pss_verify(prepared_key, message, signature)
+    }
+}
+
+impl<KP: RsaKeyParams, H: Hasher> RandomizedSigner for RsaScheme<KP, H> {
+    fn sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        private_key: &RsaPrivateKey,
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        policy::enforce::<Self>()?;
+        H::rsa_pss_sign_with_rng(rng, private_key, message)
+    }
+}
+
+impl<KP: RsaKeyParams, H: Hasher> DigestSigner for RsaScheme<KP, H> {
+    fn sign_digest<D: Hasher>(
+        private_key: &RsaPrivateKey,
+        digest: &[u8],
+    ) -> Result<Signature, Error> {
+        policy::enforce::<Self>()?;
+        if digest.len() != D::OUTPUT_SIZE {
+            return Err(Error::Signature(SignatureError::InvalidDigestLength));
+        }
+        D::rsa_pss_sign_prehash(private_key, digest)
+    }
+}
+
+impl<KP: RsaKeyParams, H: Hasher> DigestVerifier for RsaScheme<KP, H> {
+    fn verify_digest<D: Hasher>(
+        public_key: &RsaPublicKey,
+        digest: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        if digest.len() != D::OUTPUT_SIZE {
+            return Err(Error::Signature(SignatureError::InvalidDigestLength));
+        }
+        D::rsa_pss_verify_prehash(public_key, digest, signature)
+    }
+}
+
 // ------------------- Type Aliases for Specific RSA Schemes -------------------
 // ------------------- 特定 RSA 方案的类型别名 -------------------
 
@@ -295,13 +416,18 @@ pub type Rsa4096<Sha = Sha256> = RsaScheme<Rsa4096Params, Sha>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::systems::test_rng::SeededRng;
 
     fn run_rsa_tests<KP: RsaKeyParams, H: Hasher>()
     where
         RsaScheme<KP, H>: KeyGenerator<PublicKey = RsaPublicKey, PrivateKey = RsaPrivateKey>
             + Kem<PublicKey = RsaPublicKey, PrivateKey = RsaPrivateKey>
             + Signer<PrivateKey = RsaPrivateKey>
-            + Verifier<PublicKey = RsaPublicKey>,
+            + Verifier<PublicKey = RsaPublicKey>
+            + DigestSigner<PrivateKey = RsaPrivateKey>
+            + DigestVerifier<PublicKey = RsaPublicKey>
+            + RandomizedSigner<PrivateKey = RsaPrivateKey>
+            + PreparableVerifier<PublicKey = RsaPublicKey>,
     {
         // Define the scheme to be tested based on the generic parameters.
         // 根据泛型参数定义要测试的方案。
@@ -320,6 +446,12 @@ mod tests {
         assert_eq!(pk.to_bytes(), pk2.to_bytes());
         assert_eq!(sk.to_bytes(), sk2.to_bytes());
 
+        // Test public key derivation
+        // 测试公钥派生
+        let derived = TestScheme::<H, KP>::derive_public_key(&sk).unwrap();
+        assert_eq!(derived.to_bytes(), pk.to_bytes());
+        TestScheme::<H, KP>::validate_keypair(&pk, &sk).unwrap();
+
         // Test KEM roundtrip
         // 测试 KEM 往返
         let (ss1, encapsulated_key) = TestScheme::encapsulate(&pk).unwrap();
@@ -331,11 +463,48 @@ mod tests {
         let message = b"this is the message to be signed";
         let signature = TestScheme::sign(&sk, message).unwrap();
         assert!(TestScheme::verify(&pk, message, &signature).is_ok());
+        assert_eq!(signature.len(), TestScheme::MAX_SIGNATURE_SIZE);
+
+        // Test signing into a caller-provided buffer
+        // 测试签名到调用者提供的缓冲区
+        let mut buffer = vec![0u8; TestScheme::MAX_SIGNATURE_SIZE];
+        let len = TestScheme::sign_to_buffer(&sk, message, &mut buffer).unwrap();
+        assert!(TestScheme::verify(&pk, message, &buffer[..len].to_vec()).is_ok());
+        assert!(TestScheme::sign_to_buffer(&sk, message, &mut buffer[..len - 1]).is_err());
 
         // Test tampered message verification fails
         // 测试篡改消息验证失败
         let tampered_message = b"this is a different message";
         assert!(TestScheme::verify(&pk, tampered_message, &signature).is_err());
+
+        // Test verification with a prepared verifying key
+        // 测试使用预处理的验证密钥进行验证
+        let verifier = PreparedVerifier::<TestScheme<H, KP>>::new(&pk).unwrap();
+        assert!(verifier.verify(message, &signature).is_ok());
+        assert!(verifier.verify(tampered_message, &signature).is_err());
+
+        // Test signing with a caller-supplied RNG
+        // 测试使用调用者提供的 RNG 进行签名
+        let signature_rng =
+            TestScheme::sign_with_rng(&mut rsa::rand_core::OsRng, &sk, message).unwrap();
+        assert!(TestScheme::verify(&pk, message, &signature_rng).is_ok());
+
+        // The signature is determined by the caller's RNG, so it is actually used
+        // 签名由调用者的 RNG 决定，因此该 RNG 确实被使用
+        let seeded = |seed| TestScheme::sign_with_rng(&mut SeededRng(seed), &sk, message).unwrap();
+        assert_eq!(seeded(1), seeded(1));
+        assert_ne!(seeded(1), seeded(2));
+        assert!(TestScheme::verify(&pk, message, &seeded(1)).is_ok());
+        assert!(TestScheme::verify(&pk, message, &seeded(2)).is_ok());
+
+        // Test prehash sign/verify roundtrip, interoperable with the message-based API
+        // 测试预哈希签名/验证往返，并与基于消息的 API 互通
+        let digest = H::hash(message);
+        let digest_signature = TestScheme::sign_digest::<H>(&sk, &digest).unwrap();
+        assert!(TestScheme::verify_digest::<H>(&pk, &digest, &digest_signature).is_ok());
+        assert!(TestScheme::verify(&pk, message, &digest_signature).is_ok());
+        assert!(TestScheme::verify_digest::<H>(&pk, &digest, &signature).is_ok());
+        assert!(TestScheme::sign_digest::<H>(&sk, &digest[1..]).is_err());
     }
 
     #[test]
@@ -349,4 +518,18 @@ mod tests {
     fn test_rsa_4096_sha512() {
         run_rsa_tests::<Rsa4096Params, Sha512>();
     }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_rsa_generate_keypair_with_rng() {
+        type TestScheme = RsaScheme<Rsa2048Params, Sha256>;
+        let (pk, sk) = TestScheme::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        let (pk2, sk2) = TestScheme::generate_keypair_with_rng(&mut SeededRng(0)).unwrap();
+        assert_eq!(pk.to_bytes(), pk2.to_bytes());
+        assert_eq!(sk.to_bytes(), sk2.to_bytes());
+        TestScheme::validate_keypair(&pk, &sk).unwrap();
+
+        let signature = TestScheme::sign(&sk, b"message").unwrap();
+        assert!(TestScheme::verify(&pk, b"message", &signature).is_ok());
+    }
 }
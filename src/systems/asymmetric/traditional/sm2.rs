@@ -0,0 +1,1204 @@
+//! Provides an implementation of the SM2 public-key algorithms.
+//!
+//! SM2 is the Chinese national elliptic-curve standard (GB/T 32918-2016, GM/T 0003-2012),
+//! defined over its own 256-bit prime curve. This module implements its digital signature and
+//! public-key encryption parts, both with SM3 as the hash function.
+//!
+//! # Signatures
+//! Before signing, the message is prefixed with `ZA`, an SM3 hash of the signer's identity
+//! (distinguishing ID) and public key, so a signature only verifies under the same ID.
+//! [`Signer`] and [`Verifier`] use the default ID `1234567812345678` from GM/T 0009-2012;
+//! [`Sm2::sign_with_id`] and [`Sm2::verify_with_id`] take any other ID. Signatures are the
+//! 64-byte `r || s`, and [`Sm2::signature_to_der`] converts them to the DER form used by
+//! GM/T 0009 and OpenSSL.
+//!
+//! # Encryption
+//! [`Sm2::encrypt`] produces the `C1 || C3 || C2` ciphertext of GB/T 32918.4-2016: an
+//! uncompressed ephemeral point, an SM3 check value and the masked message, 97 bytes longer
+//! than the plaintext. [`Sm2::ciphertext_to_der`] converts it to the GM/T 0009 DER form.
+//! The [`Kem`] implementation encrypts a fresh 32-byte shared secret.
+//!
+//! # Key Formats
+//! Public keys are 65-byte uncompressed SEC1 points; `from_sec1_bytes` also accepts the
+//! 33-byte compressed form. Private keys are raw 32-byte big-endian scalars.
+//!
+//! # Security Considerations
+//! - Curve arithmetic uses complete formulas and constant-time scalar multiplication.
+//! - Peer points are checked to lie on the curve before use.
+//! - SM2 is not a FIPS-approved algorithm, so it is unavailable with the `fips` feature.
+//!
+//! 提供了 SM2 公钥算法的实现。
+//!
+//! SM2 是中国国家椭圆曲线标准（GB/T 32918-2016，GM/T 0003-2012），定义在其自有的 256 位
+//! 素数曲线上。此模块实现了其中的数字签名和公钥加密部分，二者都以 SM3 作为哈希函数。
+//!
+//! # 签名
+//! 签名前，消息会被加上前缀 `ZA`，即签名者身份（可辨别标识）和公钥的 SM3 哈希，因此签名
+//! 只能在相同的标识下验证。[`Signer`] 和 [`Verifier`] 使用 GM/T 0009-2012 中的默认标识
+//! `1234567812345678`；[`Sm2::sign_with_id`] 和 [`Sm2::verify_with_id`] 接受其他任意标识。
+//! 签名为 64 字节的 `r || s`，[`Sm2::signature_to_der`] 将其转换为 GM/T 0009 和 OpenSSL
+//! 使用的 DER 形式。
+//!
+//! # 加密
+//! [`Sm2::encrypt`] 生成 GB/T 32918.4-2016 的 `C1 || C3 || C2` 密文：一个未压缩的临时点、
+//! 一个 SM3 校验值和被掩码的消息，比明文长 97 字节。[`Sm2::ciphertext_to_der`] 将其转换为
+//! GM/T 0009 的 DER 形式。[`Kem`] 实现会加密一个新的 32 字节共享密钥。
+//!
+//! # 密钥格式
+//! 公钥为 65 字节的未压缩 SEC1 点；`from_sec1_bytes` 也接受 33 字节的压缩形式。
+//! 私钥为原始 32 字节大端标量。
+//!
+//! # 安全考虑
+//! - 曲线运算使用完备公式和常数时间标量乘法。
+//! - 对端的点在使用前会被检查是否位于曲线上。
+//! - SM2 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::ct::{Choice, ConstantTimeEq};
+use crate::errors::Error;
+use crate::policy;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use crate::systems::hash::sm3::Sm3;
+use crate::traits::asymmetric::write_signature;
+use crypto_bigint::modular::constant_mod::Residue;
+use crypto_bigint::{Encoding, U256};
+use core::convert::TryFrom;
+use subtle::{ConditionallySelectable, ConstantTimeLess};
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ------------------- Curve Arithmetic -------------------
+// ------------------- 曲线运算 -------------------
+
+// The moduli are kept in a private module so the glob re-export in `schemes` does not expose them.
+// 模数放在私有模块中，以免 `schemes` 中的 glob 重新导出将其暴露。
+mod moduli {
+    use crypto_bigint::{U256, impl_modulus};
+
+    impl_modulus!(
+        FieldModulus,
+        U256,
+        "FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFF"
+    );
+    impl_modulus!(
+        OrderModulus,
+        U256,
+        "FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54123"
+    );
+}
+
+use moduli::{FieldModulus, OrderModulus};
+
+type FieldElement = Residue<FieldModulus, { U256::LIMBS }>;
+type Scalar = Residue<OrderModulus, { U256::LIMBS }>;
+
+const A: U256 =
+    U256::from_be_hex("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF00000000FFFFFFFFFFFFFFFC");
+const B: U256 =
+    U256::from_be_hex("28E9FA9E9D9F5E344D5A9E4BCF6509A7F39789F515AB8F92DDBCBD414D940E93");
+const GX: U256 =
+    U256::from_be_hex("32C4AE2C1F1981195F9904466A39C9948FE30BBFF2660BE1715A4589334C74C7");
+const GY: U256 =
+    U256::from_be_hex("BC3736A2F4F6779C59BDCEE36B692153D0A9877CC62A474002DF32E52139F0A0");
+const N: U256 =
+    U256::from_be_hex("FFFFFFFEFFFFFFFFFFFFFFFFFFFFFFFF7203DF6B21C6052B53BBF40939D54123");
+// (p + 1) / 4, the square-root exponent, as p ≡ 3 (mod 4).
+// (p + 1) / 4，即平方根指数，因为 p ≡ 3 (mod 4)。
+const SQRT_EXP: U256 =
+    U256::from_be_hex("3FFFFFFFBFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC00000004000000000000000");
+
+const FIELD_A: FieldElement = FieldElement::new(&A);
+const FIELD_B: FieldElement = FieldElement::new(&B);
+
+/// A curve point in projective coordinates; the identity is `(0 : 1 : 0)`.
+///
+/// 射影坐标下的曲线点；单位元为 `(0 : 1 : 0)`。
+#[derive(Clone, Copy)]
+struct Point {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl ConditionallySelectable for Point {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl Point {
+    const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ONE,
+        z: FieldElement::ZERO,
+    };
+
+    const GENERATOR: Self = Self {
+        x: FieldElement::new(&GX),
+        y: FieldElement::new(&GY),
+        z: FieldElement::ONE,
+    };
+
+    fn from_affine(x: FieldElement, y: FieldElement) -> Self {
+        Self {
+            x,
+            y,
+            z: FieldElement::ONE,
+        }
+    }
+
+    /// Returns the affine coordinates, or `None` for the identity.
+    ///
+    /// 返回仿射坐标，对于单位元返回 `None`。
+    fn to_affine(self) -> Option<(FieldElement, FieldElement)> {
+        let (z_inv, is_some) = self.z.invert();
+        bool::from(is_some).then(|| (self.x * z_inv, self.y * z_inv))
+    }
+
+    // Complete addition and doubling for a = -3, from Renes, Costello and Batina,
+    // "Complete addition formulas for prime order elliptic curves" (Algorithms 4 and 6).
+    // 适用于 a = -3 的完备加法和倍点公式，来自 Renes、Costello 和 Batina 的
+    // 《Complete addition formulas for prime order elliptic curves》（算法 4 和 6）。
+    fn add(&self, rhs: &Self) -> Self {
+        let xx = self.x * rhs.x;
+        let yy = self.y * rhs.y;
+        let zz = self.z * rhs.z;
+        let xy_pairs = ((self.x + self.y) * (rhs.x + rhs.y)) - (xx + yy);
+        let yz_pairs = ((self.y + self.z) * (rhs.y + rhs.z)) - (yy + zz);
+        let xz_pairs = ((self.x + self.z) * (rhs.x + rhs.z)) - (xx + zz);
+
+        let bzz_part = xz_pairs - (FIELD_B * zz);
+        let bzz3_part = bzz_part + bzz_part + bzz_part;
+        let yy_m_bzz3 = yy - bzz3_part;
+        let yy_p_bzz3 = yy + bzz3_part;
+
+        let zz3 = zz + zz + zz;
+        let bxz_part = (FIELD_B * xz_pairs) - (zz3 + xx);
+        let bxz3_part = bxz_part + bxz_part + bxz_part;
+        let xx3_m_zz3 = xx + xx + xx - zz3;
+
+        Self {
+            x: (yy_p_bzz3 * xy_pairs) - (yz_pairs * bxz3_part),
+            y: (yy_p_bzz3 * yy_m_bzz3) + (xx3_m_zz3 * bxz3_part),
+            z: (yy_m_bzz3 * yz_pairs) + (xy_pairs * xx3_m_zz3),
+        }
+    }
+
+    fn double(&self) -> Self {
+        let xx = self.x.square();
+        let yy = self.y.square();
+        let zz = self.z.square();
+        let xy2 = (self.x * self.y) + (self.x * self.y);
+        let xz2 = (self.x * self.z) + (self.x * self.z);
+
+        let bzz_part = (FIELD_B * zz) - xz2;
+        let bzz3_part = bzz_part + bzz_part + bzz_part;
+        let yy_m_bzz3 = yy - bzz3_part;
+        let yy_p_bzz3 = yy + bzz3_part;
+        let y_frag = yy_p_bzz3 * yy_m_bzz3;
+        let x_frag = yy_m_bzz3 * xy2;
+
+        let zz3 = zz + zz + zz;
+        let bxz2_part = (FIELD_B * xz2) - (zz3 + xx);
+        let bxz6_part = bxz2_part + bxz2_part + bxz2_part;
+        let xx3_m_zz3 = xx + xx + xx - zz3;
+
+        let yz2 = (self.y * self.z) + (self.y * self.z);
+        let yz2_yy = yz2 * yy;
+        Self {
+            x: x_frag - (bxz6_part * yz2),
+            y: y_frag + (xx3_m_zz3 * bxz6_part),
+            z: yz2_yy + yz2_yy + yz2_yy + yz2_yy,
+        }
+    }
+
+    /// Multiplies the point by `k` with a fixed sequence of operations.
+    ///
+    /// 以固定的运算序列将点乘以 `k`。
+    fn mul(&self, k: &U256) -> Self {
+        let mut result = Self::IDENTITY;
+        for i in (0..U256::BITS).rev() {
+            result = result.double();
+            let sum = result.add(self);
+            result = Self::conditional_select(&result, &sum, Choice::from(k.bit(i)));
+        }
+        result
+    }
+}
+
+fn field_to_bytes(element: &FieldElement) -> [u8; 32] {
+    element.retrieve().to_be_bytes()
+}
+
+fn field_from_bytes(bytes: &[u8]) -> Option<FieldElement> {
+    Option::from(FieldElement::new_checked(&U256::from_be_slice(bytes)))
+}
+
+fn is_on_curve(x: &FieldElement, y: &FieldElement) -> bool {
+    let rhs = x.square() * x + FIELD_A * x + FIELD_B;
+    bool::from(y.square().ct_eq(&rhs))
+}
+
+/// Encodes affine coordinates as an uncompressed SEC1 point.
+///
+/// 将仿射坐标编码为未压缩的 SEC1 点。
+fn encode_point(x: &FieldElement, y: &FieldElement) -> Vec<u8> {
+    let mut point = Vec::with_capacity(POINT_SIZE);
+    point.push(0x04);
+    point.extend_from_slice(&field_to_bytes(x));
+    point.extend_from_slice(&field_to_bytes(y));
+    point
+}
+
+/// Decodes a compressed or uncompressed SEC1 point, checking that it lies on the curve.
+///
+/// 解码压缩或未压缩的 SEC1 点，并检查它是否位于曲线上。
+fn decode_point(point: &[u8]) -> Option<(FieldElement, FieldElement)> {
+    let (x, y) = match (point.first(), point.len()) {
+        (Some(0x04), POINT_SIZE) => (
+            field_from_bytes(&point[1..33])?,
+            field_from_bytes(&point[33..])?,
+        ),
+        (Some(&tag @ (0x02 | 0x03)), 33) => {
+            let x = field_from_bytes(&point[1..])?;
+            let y = (x.square() * x + FIELD_A * x + FIELD_B).pow(&SQRT_EXP);
+            let is_odd = Choice::from(y.retrieve().bit(0));
+            let negate = is_odd ^ Choice::from(tag & 1);
+            (x, FieldElement::conditional_select(&y, &-y, negate))
+        }
+        _ => return None,
+    };
+    is_on_curve(&x, &y).then_some((x, y))
+}
+
+fn scalar_from_bytes(bytes: &[u8]) -> Zeroizing<U256> {
+    Zeroizing::new(U256::from_be_slice(bytes))
+}
+
+/// Accepts private scalars in `[1, n - 2]`, so that `1 + d` is invertible.
+///
+/// 接受位于 `[1, n - 2]` 的私有标量，以保证 `1 + d` 可逆。
+fn is_valid_private_scalar(d: &U256) -> bool {
+    let upper = N.wrapping_sub(&U256::ONE);
+    bool::from(!d.ct_eq(&U256::ZERO) & d.ct_lt(&upper))
+}
+
+/// Draws a scalar in `[1, bound)` by rejection sampling.
+///
+/// 通过拒绝采样抽取位于 `[1, bound)` 的标量。
+fn random_scalar<R: CryptoRngCore>(rng: &mut R, bound: &U256) -> Zeroizing<U256> {
+    let mut bytes = Zeroizing::new([0u8; 32]);
+    loop {
+        rng.fill_bytes(bytes.as_mut());
+        let k = scalar_from_bytes(bytes.as_ref());
+        if bool::from(!k.ct_eq(&U256::ZERO) & k.ct_lt(bound)) {
+            return k;
+        }
+    }
+}
+
+/// The SM3-based key derivation function of GB/T 32918.4-2016, section 5.4.3.
+///
+/// GB/T 32918.4-2016 第 5.4.3 节中基于 SM3 的密钥派生函数。
+fn kdf(z: &[u8], len: usize) -> Zeroizing<Vec<u8>> {
+    let mut output = Zeroizing::new(Vec::with_capacity(len + Sm3::OUTPUT_SIZE));
+    let mut counter = 1u32;
+    while output.len() < len {
+        let mut hasher = Sm3::default();
+        hasher.update(z);
+        hasher.update(&counter.to_be_bytes());
+        output.extend_from_slice(&Zeroizing::new(hasher.finalize()));
+        counter += 1;
+    }
+    output.truncate(len);
+    output
+}
+
+// ------------------- DER Encoding -------------------
+// ------------------- DER 编码 -------------------
+
+const DER_INTEGER: u8 = 0x02;
+const DER_OCTET_STRING: u8 = 0x04;
+const DER_SEQUENCE: u8 = 0x30;
+
+fn der_push(out: &mut Vec<u8>, tag: u8, content: &[u8]) {
+    out.push(tag);
+    let len = content.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes = (len as u32).to_be_bytes();
+        let skip = bytes.iter().take_while(|&&byte| byte == 0).count();
+        out.push(0x80 | (4 - skip) as u8);
+        out.extend_from_slice(&bytes[skip..]);
+    }
+    out.extend_from_slice(content);
+}
+
+fn der_push_integer(out: &mut Vec<u8>, value: &[u8]) {
+    let skip = value.iter().take_while(|&&byte| byte == 0).count();
+    let mut content = Vec::with_capacity(value.len() + 1);
+    match value.get(skip) {
+        None => content.push(0),
+        Some(&byte) if byte & 0x80 != 0 => content.push(0),
+        Some(_) => {}
+    }
+    content.extend_from_slice(&value[skip..]);
+    der_push(out, DER_INTEGER, &content);
+}
+
+/// Reads one element with the given tag, rejecting non-minimal lengths.
+///
+/// 读取一个具有给定标签的元素，拒绝非最短的长度编码。
+fn der_read<'a>(input: &mut &'a [u8], tag: u8) -> Option<&'a [u8]> {
+    let (&actual, rest) = input.split_first()?;
+    let (&first, mut rest) = rest.split_first()?;
+    if actual != tag {
+        return None;
+    }
+    let len = if first < 0x80 {
+        usize::from(first)
+    } else {
+        let count = usize::from(first & 0x7f);
+        if count == 0 || count > 4 || rest.len() < count || rest[0] == 0 {
+            return None;
+        }
+        let len = rest[..count]
+            .iter()
+            .fold(0usize, |len, &byte| (len << 8) | usize::from(byte));
+        rest = &rest[count..];
+        if len < 0x80 {
+            return None;
+        }
+        len
+    };
+    if rest.len() < len {
+        return None;
+    }
+    let (content, rest) = rest.split_at(len);
+    *input = rest;
+    Some(content)
+}
+
+/// Reads a minimally encoded non-negative INTEGER of at most 32 bytes, left-padded to 32.
+///
+/// 读取最多 32 字节、最短编码的非负 INTEGER，并左侧填充到 32 字节。
+fn der_read_integer(input: &mut &[u8]) -> Option<[u8; 32]> {
+    let mut content = der_read(input, DER_INTEGER)?;
+    match content {
+        [] => return None,
+        [first, ..] if first & 0x80 != 0 => return None,
+        [0, second, ..] if second & 0x80 == 0 => return None,
+        [0, _, ..] => content = &content[1..],
+        _ => {}
+    }
+    let mut value = [0u8; 32];
+    value
+        .len()
+        .checked_sub(content.len())
+        .map(|offset| value[offset..].copy_from_slice(content))?;
+    Some(value)
+}
+
+// ------------------- Newtype Wrappers for SM2 Keys -------------------
+// ------------------- SM2 密钥的 Newtype 包装器 -------------------
+
+const POINT_SIZE: usize = 65;
+const SCALAR_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+const SHARED_SECRET_SIZE: usize = 32;
+
+/// The default distinguishing ID of GM/T 0009-2012, used when no ID is given.
+///
+/// GM/T 0009-2012 的默认可辨别标识，在未给出标识时使用。
+pub const DEFAULT_ID: &[u8] = b"1234567812345678";
+
+/// The number of bytes a ciphertext adds to the plaintext: `C1` and `C3`.
+///
+/// 密文相对明文增加的字节数：`C1` 和 `C3`。
+pub const CIPHERTEXT_OVERHEAD: usize = POINT_SIZE + 32;
+
+/// An SM2 public key, held as a 65-byte uncompressed SEC1 point.
+///
+/// SM2 公钥，以 65 字节未压缩 SEC1 点的形式保存。
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sm2PublicKey {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    bytes: Vec<u8>,
+}
+
+impl Sm2PublicKey {
+    /// Imports a public key from a compressed (33-byte) or uncompressed (65-byte) SEC1 point.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the point is malformed or not on the curve.
+    ///
+    /// 从压缩（33 字节）或未压缩（65 字节）的 SEC1 点导入公钥。
+    ///
+    /// 如果点格式错误或不在曲线上，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_sec1_bytes(point: &[u8]) -> Result<Self, Error> {
+        let (x, y) = decode_point(point).ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        Ok(Self {
+            bytes: encode_point(&x, &y),
+        })
+    }
+
+    /// Exports the public key as a SEC1 point, compressed if `compress` is set.
+    ///
+    /// 将公钥导出为 SEC1 点，如果设置了 `compress` 则为压缩形式。
+    pub fn to_sec1_bytes(&self, compress: bool) -> Vec<u8> {
+        if !compress {
+            return self.bytes.clone();
+        }
+        let mut point = self.bytes[..33].to_vec();
+        point[0] = 0x02 | (self.bytes[POINT_SIZE - 1] & 1);
+        point
+    }
+
+    fn point(&self) -> Result<Point, Error> {
+        let (x, y) = decode_point(&self.bytes).ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        Ok(Point::from_affine(x, y))
+    }
+}
+
+impl<'a> From<&'a Sm2PublicKey> for Sm2PublicKey {
+    fn from(key: &'a Sm2PublicKey) -> Self {
+        key.clone()
+    }
+}
+
+impl TryFrom<&[u8]> for Sm2PublicKey {
+    type Error = Error;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Key::from_bytes(bytes)
+    }
+}
+
+impl Key for Sm2PublicKey {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != POINT_SIZE {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        Self::from_sec1_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.bytes.clone())
+    }
+}
+
+impl PublicKey for Sm2PublicKey {}
+
+impl_hex_display!(Sm2PublicKey);
+
+/// An SM2 private key, held as a raw 32-byte big-endian scalar.
+///
+/// SM2 私钥，以原始 32 字节大端标量的形式保存。
+#[derive(Debug, Zeroize, Clone)]
+#[zeroize(drop)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sm2PrivateKey {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    bytes: SecretBytes,
+}
+
+impl Sm2PrivateKey {
+    fn scalar(&self) -> Zeroizing<U256> {
+        scalar_from_bytes(&self.bytes)
+    }
+}
+
+impl ConstantTimeEq for Sm2PrivateKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.as_slice().ct_eq(other.bytes.as_slice())
+    }
+}
+
+impl PartialEq for Sm2PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for Sm2PrivateKey {}
+
+impl Key for Sm2PrivateKey {
+    /// Imports a private key from its raw 32-byte big-endian scalar.
+    ///
+    /// Fails with `KeyError::InvalidLength` if `bytes` is not 32 bytes, and with
+    /// `KeyError::InvalidEncoding` if the scalar is outside `[1, n - 2]`.
+    ///
+    /// 从原始 32 字节大端标量导入私钥。
+    ///
+    /// 如果 `bytes` 不是 32 字节，则以 `KeyError::InvalidLength` 失败；如果标量不在
+    /// `[1, n - 2]` 范围内，则以 `KeyError::InvalidEncoding` 失败。
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != SCALAR_SIZE {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        if !is_valid_private_scalar(&scalar_from_bytes(bytes)) {
+            return Err(Error::Key(KeyError::InvalidEncoding));
+        }
+        Ok(Self {
+            bytes: SecretBytes::from_slice(bytes),
+        })
+    }
+
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.bytes.to_vec())
+    }
+}
+
+impl TryFrom<&[u8]> for Sm2PrivateKey {
+    type Error = Error;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Key::from_bytes(bytes)
+    }
+}
+
+impl PrivateKey<Sm2PublicKey> for Sm2PrivateKey {}
+
+// ------------------- SM2 Scheme Implementation -------------------
+// ------------------- SM2 方案实现 -------------------
+
+/// The SM2 signature and public-key encryption scheme, with SM3 as its hash.
+///
+/// SM2 签名和公钥加密方案，以 SM3 作为其哈希。
+#[derive(Clone, Debug, Default)]
+pub struct Sm2;
+
+impl AsymmetricKeySet for Sm2 {
+    type PublicKey = Sm2PublicKey;
+    type PrivateKey = Sm2PrivateKey;
+}
+
+impl Algorithm for Sm2 {
+    fn name() -> String {
+        "SM2".to_string()
+    }
+    const ID: u32 = 0x01_01_04_01;
+}
+
+impl Sm2 {
+    /// Computes `ZA`, the SM3 hash of the distinguishing ID, the curve parameters and the
+    /// public key, which prefixes every signed message.
+    ///
+    /// Fails with `SignatureError::InvalidContext` if `id` is longer than 8191 bytes.
+    ///
+    /// 计算 `ZA`，即可辨别标识、曲线参数和公钥的 SM3 哈希，它是每条被签名消息的前缀。
+    ///
+    /// 如果 `id` 长于 8191 字节，则以 `SignatureError::InvalidContext` 失败。
+    pub fn identity_hash(public_key: &Sm2PublicKey, id: &[u8]) -> Result<Vec<u8>, Error> {
+        let entl = u16::try_from(id.len() * 8)
+            .map_err(|_| Error::Signature(SignatureError::InvalidContext))?;
+        let mut hasher = Sm3::default();
+        hasher.update(&entl.to_be_bytes());
+        hasher.update(id);
+        for parameter in [A, B, GX, GY] {
+            hasher.update(&parameter.to_be_bytes());
+        }
+        hasher.update(&public_key.bytes[1..]);
+        Ok(hasher.finalize())
+    }
+
+    fn message_digest(
+        public_key: &Sm2PublicKey,
+        id: &[u8],
+        message: &[u8],
+    ) -> Result<Scalar, Error> {
+        let mut hasher = Sm3::default();
+        hasher.update(&Self::identity_hash(public_key, id)?);
+        hasher.update(message);
+        Ok(Scalar::new(&U256::from_be_slice(&hasher.finalize())))
+    }
+
+    fn sign_with_id_and_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        private_key: &Sm2PrivateKey,
+        id: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        policy::enforce::<Self>()?;
+        let public_key = Self::derive_public_key(private_key)?;
+        let e = Self::message_digest(&public_key, id, message)?;
+        let d = Scalar::new(&private_key.scalar());
+        let (inverse, _) = (Scalar::ONE + d).invert();
+        loop {
+            let k = random_scalar(rng, &N);
+            let (x1, _) = Point::GENERATOR
+                .mul(&k)
+                .to_affine()
+                .ok_or(Error::Signature(SignatureError::Signing))?;
+            let k = Scalar::new(&k);
+            let r = e + Scalar::new(&x1.retrieve());
+            if bool::from(r.ct_eq(&Scalar::ZERO) | (r + k).ct_eq(&Scalar::ZERO)) {
+                continue;
+            }
+            let s = inverse * (k - r * d);
+            if bool::from(s.ct_eq(&Scalar::ZERO)) {
+                continue;
+            }
+            let mut signature = Vec::with_capacity(SIGNATURE_SIZE);
+            signature.extend_from_slice(&r.retrieve().to_be_bytes());
+            signature.extend_from_slice(&s.retrieve().to_be_bytes());
+            return Ok(signature);
+        }
+    }
+
+    /// Signs `message` as the holder of the distinguishing ID `id`.
+    ///
+    /// 以可辨别标识 `id` 持有者的身份对 `message` 签名。
+    pub fn sign_with_id(
+        private_key: &Sm2PrivateKey,
+        id: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        Self::sign_with_id_and_rng(&mut SystemRng::new(), private_key, id, message)
+    }
+
+    /// Verifies a signature made with the distinguishing ID `id`.
+    ///
+    /// 验证使用可辨别标识 `id` 生成的签名。
+    pub fn verify_with_id(
+        public_key: &Sm2PublicKey,
+        id: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        if signature.len() != SIGNATURE_SIZE {
+            return Err(Error::Signature(SignatureError::InvalidSignature));
+        }
+        let r = U256::from_be_slice(&signature[..32]);
+        let s = U256::from_be_slice(&signature[32..]);
+        for value in [&r, &s] {
+            if bool::from(value.ct_eq(&U256::ZERO) | !value.ct_lt(&N)) {
+                return Err(Error::Signature(SignatureError::Verification));
+            }
+        }
+        let t = Scalar::new(&r) + Scalar::new(&s);
+        if bool::from(t.ct_eq(&Scalar::ZERO)) {
+            return Err(Error::Signature(SignatureError::Verification));
+        }
+        let e = Self::message_digest(public_key, id, message)?;
+        let (x1, _) = Point::GENERATOR
+            .mul(&s)
+            .add(&public_key.point()?.mul(&t.retrieve()))
+            .to_affine()
+            .ok_or(Error::Signature(SignatureError::Verification))?;
+        if bool::from((e + Scalar::new(&x1.retrieve())).ct_eq(&Scalar::new(&r))) {
+            Ok(())
+        } else {
+            Err(Error::Signature(SignatureError::Verification))
+        }
+    }
+
+    /// Encrypts `plaintext` to `public_key`, producing `C1 || C3 || C2`.
+    ///
+    /// 将 `plaintext` 加密给 `public_key`，生成 `C1 || C3 || C2`。
+    pub fn encrypt(public_key: &Sm2PublicKey, plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+        Self::encrypt_with_rng(&mut SystemRng::new(), public_key, plaintext)
+    }
+
+    /// Encrypts `plaintext` to `public_key` using randomness drawn from `rng`.
+    ///
+    /// 使用从 `rng` 中获取的随机性将 `plaintext` 加密给 `public_key`。
+    pub fn encrypt_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        public_key: &Sm2PublicKey,
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        policy::enforce::<Self>()?;
+        let peer = public_key.point()?;
+        loop {
+            let k = random_scalar(rng, &N);
+            let (x1, y1) = Point::GENERATOR
+                .mul(&k)
+                .to_affine()
+                .ok_or(Error::Kem(KemError::Encapsulation))?;
+            let (x2, y2) = peer
+                .mul(&k)
+                .to_affine()
+                .ok_or(Error::Kem(KemError::InvalidPublicKey))?;
+            let (x2, y2) = (field_to_bytes(&x2), field_to_bytes(&y2));
+            let shared = Zeroizing::new([x2, y2].concat());
+            let mask = kdf(&shared, plaintext.len());
+            if !plaintext.is_empty() && mask.iter().all(|&byte| byte == 0) {
+                continue;
+            }
+
+            let mut hasher = Sm3::default();
+            hasher.update(&x2);
+            hasher.update(plaintext);
+            hasher.update(&y2);
+
+            let mut ciphertext = encode_point(&x1, &y1);
+            ciphertext.extend_from_slice(&hasher.finalize());
+            ciphertext.extend(plaintext.iter().zip(mask.iter()).map(|(m, t)| m ^ t));
+            return Ok(ciphertext);
+        }
+    }
+
+    /// Decrypts a `C1 || C3 || C2` ciphertext.
+    ///
+    /// Fails with `KemError::InvalidEncapsulatedKey` if the ciphertext is malformed, and with
+    /// `KemError::Decapsulation` if its check value does not match.
+    ///
+    /// 解密 `C1 || C3 || C2` 密文。
+    ///
+    /// 如果密文格式错误，则以 `KemError::InvalidEncapsulatedKey` 失败；如果其校验值不匹配，
+    /// 则以 `KemError::Decapsulation` 失败。
+    pub fn decrypt(private_key: &Sm2PrivateKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+        policy::enforce::<Self>()?;
+        if ciphertext.len() < CIPHERTEXT_OVERHEAD {
+            return Err(Error::Kem(KemError::InvalidEncapsulatedKey));
+        }
+        let (c1, rest) = ciphertext.split_at(POINT_SIZE);
+        let (c3, c2) = rest.split_at(Sm3::OUTPUT_SIZE);
+        let (x1, y1) = decode_point(c1)
+            .filter(|_| c1[0] == 0x04)
+            .ok_or(Error::Kem(KemError::InvalidEncapsulatedKey))?;
+        let (x2, y2) = Point::from_affine(x1, y1)
+            .mul(&private_key.scalar())
+            .to_affine()
+            .ok_or(Error::Kem(KemError::Decapsulation))?;
+        let (x2, y2) = (field_to_bytes(&x2), field_to_bytes(&y2));
+        let shared = Zeroizing::new([x2, y2].concat());
+        let mask = kdf(&shared, c2.len());
+        if !c2.is_empty() && mask.iter().all(|&byte| byte == 0) {
+            return Err(Error::Kem(KemError::Decapsulation));
+        }
+        let plaintext = Zeroizing::new(
+            c2.iter()
+                .zip(mask.iter())
+                .map(|(c, t)| c ^ t)
+                .collect::<Vec<u8>>(),
+        );
+
+        let mut hasher = Sm3::default();
+        hasher.update(&x2);
+        hasher.update(&plaintext);
+        hasher.update(&y2);
+        if bool::from(hasher.finalize().as_slice().ct_eq(c3)) {
+            Ok(plaintext.to_vec())
+        } else {
+            Err(Error::Kem(KemError::Decapsulation))
+        }
+    }
+
+    /// Converts a 64-byte `r || s` signature to the DER `SEQUENCE { r INTEGER, s INTEGER }`
+    /// of GM/T 0009-2012.
+    ///
+    /// 将 64 字节的 `r || s` 签名转换为 GM/T 0009-2012 的 DER
+    /// `SEQUENCE { r INTEGER, s INTEGER }`。
+    pub fn signature_to_der(signature: &[u8]) -> Result<Vec<u8>, Error> {
+        if signature.len() != SIGNATURE_SIZE {
+            return Err(Error::Signature(SignatureError::InvalidSignature));
+        }
+        let mut content = Vec::with_capacity(70);
+        der_push_integer(&mut content, &signature[..32]);
+        der_push_integer(&mut content, &signature[32..]);
+        let mut der = Vec::with_capacity(72);
+        der_push(&mut der, DER_SEQUENCE, &content);
+        Ok(der)
+    }
+
+    /// Converts a DER signature back to the 64-byte `r || s` form.
+    ///
+    /// 将 DER 签名转换回 64 字节的 `r || s` 形式。
+    pub fn signature_from_der(der: &[u8]) -> Result<Signature, Error> {
+        let parse = || {
+            let mut input = der;
+            let mut content = der_read(&mut input, DER_SEQUENCE)?;
+            let r = der_read_integer(&mut content)?;
+            let s = der_read_integer(&mut content)?;
+            (input.is_empty() && content.is_empty()).then(|| [r, s].concat())
+        };
+        parse().ok_or(Error::Signature(SignatureError::InvalidSignature))
+    }
+
+    /// Converts a `C1 || C3 || C2` ciphertext to the DER
+    /// `SEQUENCE { x INTEGER, y INTEGER, hash OCTET STRING, ciphertext OCTET STRING }`
+    /// of GM/T 0009-2012.
+    ///
+    /// 将 `C1 || C3 || C2` 密文转换为 GM/T 0009-2012 的 DER
+    /// `SEQUENCE { x INTEGER, y INTEGER, hash OCTET STRING, ciphertext OCTET STRING }`。
+    pub fn ciphertext_to_der(ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+        if ciphertext.len() < CIPHERTEXT_OVERHEAD || ciphertext[0] != 0x04 {
+            return Err(Error::Kem(KemError::InvalidEncapsulatedKey));
+        }
+        let mut content = Vec::with_capacity(ciphertext.len() + 16);
+        der_push_integer(&mut content, &ciphertext[1..33]);
+        der_push_integer(&mut content, &ciphertext[33..POINT_SIZE]);
+        der_push(
+            &mut content,
+            DER_OCTET_STRING,
+            &ciphertext[POINT_SIZE..CIPHERTEXT_OVERHEAD],
+        );
+        der_push(
+            &mut content,
+            DER_OCTET_STRING,
+            &ciphertext[CIPHERTEXT_OVERHEAD..],
+        );
+        let mut der = Vec::with_capacity(content.len() + 6);
+        der_push(&mut der, DER_SEQUENCE, &content);
+        Ok(der)
+    }
+
+    /// Converts a DER ciphertext back to the `C1 || C3 || C2` form.
+    ///
+    /// 将 DER 密文转换回 `C1 || C3 || C2` 形式。
+    pub fn ciphertext_from_der(der: &[u8]) -> Result<Vec<u8>, Error> {
+        let parse = || {
+            let mut input = der;
+            let mut content = der_read(&mut input, DER_SEQUENCE)?;
+            let x = der_read_integer(&mut content)?;
+            let y = der_read_integer(&mut content)?;
+            let hash = der_read(&mut content, DER_OCTET_STRING)?;
+            let c2 = der_read(&mut content, DER_OCTET_STRING)?;
+            if !input.is_empty() || !content.is_empty() || hash.len() != Sm3::OUTPUT_SIZE {
+                return None;
+            }
+            Some([&[0x04][..], &x, &y, hash, c2].concat())
+        };
+        parse().ok_or(Error::Kem(KemError::InvalidEncapsulatedKey))
+    }
+}
+
+impl KeyGenerator for Sm2 {
+    fn generate_keypair() -> Result<(Sm2PublicKey, Sm2PrivateKey), Error> {
+        Self::generate_keypair_with_rng(&mut SystemRng::new())
+    }
+
+    fn generate_keypair_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Sm2PublicKey, Sm2PrivateKey), Error> {
+        policy::enforce::<Self>()?;
+        let d = random_scalar(rng, &N.wrapping_sub(&U256::ONE));
+        let private_key = Sm2PrivateKey {
+            bytes: SecretBytes::from_slice(&d.to_be_bytes()),
+        };
+        let public_key = Self::derive_public_key(&private_key)?;
+        Ok((public_key, private_key))
+    }
+}
+
+impl PublicKeyDerivation for Sm2 {
+    fn derive_public_key(private_key: &Sm2PrivateKey) -> Result<Sm2PublicKey, Error> {
+        let (x, y) = Point::GENERATOR
+            .mul(&private_key.scalar())
+            .to_affine()
+            .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        Ok(Sm2PublicKey {
+            bytes: encode_point(&x, &y),
+        })
+    }
+}
+
+impl Signer for Sm2 {
+    const MAX_SIGNATURE_SIZE: usize = SIGNATURE_SIZE;
+
+    /// Unlike ECDSA, Ed25519 and Dilithium, this allocates: the public key and `ZA` are
+    /// computed on the heap, and the signature is then copied into `output`.
+    ///
+    /// 与 ECDSA、Ed25519 和 Dilithium 不同，此方法会分配内存：公钥和 `ZA` 在堆上计算，签名随后被复制到
+    /// `output` 中。
+    fn sign_to_buffer(
+        private_key: &Sm2PrivateKey,
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error> {
+        let signature = Self::sign_with_id(private_key, DEFAULT_ID, message)?;
+        write_signature(&signature, output)
+    }
+}
+
+impl Verifier for Sm2 {
+    fn verify(
+        public_key: &Sm2PublicKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        Self::verify_with_id(public_key, DEFAULT_ID, message, signature)
+    }
+}
+
+impl RandomizedSigner for Sm2 {
+    fn sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        private_key: &Sm2PrivateKey,
+        message: &[u8],
+    ) -> Result<Signature, Error> {
+        Self::sign_with_id_and_rng(rng, private_key, DEFAULT_ID, message)
+    }
+}
+
+impl Kem for Sm2 {
+    type EncapsulatedKey = EncapsulatedKey;
+
+    fn encapsulate(public_key: &Sm2PublicKey) -> Result<(SharedSecret, EncapsulatedKey), Error> {
+        let mut shared_secret_bytes = vec![0u8; SHARED_SECRET_SIZE];
+        crate::rng::fill(&mut shared_secret_bytes)
+            .map_err(|_| Error::Kem(KemError::Encapsulation))?;
+        let encapsulated_key = Self::encrypt(public_key, &shared_secret_bytes)?;
+        Ok((SharedSecret::new(shared_secret_bytes), encapsulated_key))
+    }
+
+    fn decapsulate(
+        private_key: &Sm2PrivateKey,
+        encapsulated_key: &EncapsulatedKey,
+    ) -> Result<SharedSecret, Error> {
+        if encapsulated_key.len() != CIPHERTEXT_OVERHEAD + SHARED_SECRET_SIZE {
+            return Err(Error::Kem(KemError::InvalidEncapsulatedKey));
+        }
+        Ok(SharedSecret::new(Self::decrypt(
+            private_key,
+            encapsulated_key,
+        )?))
+    }
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    // A key pair, signature and ciphertext produced by OpenSSL 3.5
+    // 由 OpenSSL 3.5 生成的密钥对、签名和密文
+    const PRIVATE_KEY: &str = "75cf576ad8999402cf41db8f7f8e18a8988fb067ad9a4ef5a7b8072ab8214fc8";
+    const PUBLIC_KEY: &str = "043d262110c28ea4f26227e6ebf95585de3fe99e284620bc0548d69310df593d7c\
+                              2f0eb212431a8ecd3ee57cd09a94f9174128d6589eaac48c534257da89c2738c";
+    const SIGNATURE_DER: &str = "3045022100f6c8f8abcba38efc339ded6720d23545e5d7fc016c6125e0c2d99c\
+                                 305679a89b02205e05184a5bae39a6aab3d752a397f3e990baf2ebc2269363\
+                                 9c3e2b73afde69e6";
+    // Signed by `openssl pkeyutl` without a `distid` option, which uses an empty ID
+    // 由未指定 `distid` 选项的 `openssl pkeyutl` 签名，它使用空标识
+    const EMPTY_ID_SIGNATURE_DER: &str = "3045022100d2a066d83d49b7c1b4beffab066812e0112a1bc56a8e\
+                                          b4c67db01c80bb31db400220317bcf01b88acedc96c3ba2929f2\
+                                          22429cd79447814bc538c98b7154cbe84c54";
+    const CIPHERTEXT_DER: &str = "307c0221008bb5ee8b62d0128d1ffc72e189e53ac464674268964bd7f2aab4\
+                                  d94a1611ccc2022052f5ca04f751df1d85fffbc7f6dfb17bd4b10a0f769293\
+                                  22842d8a1f0f57d87e0420bde3d7167c127b58f4f56a5f5660ebc68454cf25\
+                                  dc64f10552c2b850d5b3a3dc0413e1952d64cb481f2e39ac0daffa37d7b961\
+                                  e47e";
+
+    fn unhex(s: &str) -> Vec<u8> {
+        hex::decode(s).unwrap_or_default()
+    }
+
+    fn keypair() -> Result<(Sm2PublicKey, Sm2PrivateKey), Error> {
+        Ok((
+            Sm2PublicKey::from_bytes(&unhex(PUBLIC_KEY))?,
+            Sm2PrivateKey::from_bytes(&unhex(PRIVATE_KEY))?,
+        ))
+    }
+
+    #[test]
+    fn test_sm2_openssl_vectors() -> Result<(), Error> {
+        let (public_key, private_key) = keypair()?;
+        assert_eq!(Sm2::derive_public_key(&private_key)?, public_key);
+
+        let signature = Sm2::signature_from_der(&unhex(SIGNATURE_DER))?;
+        assert_eq!(signature.len(), 64);
+        assert_eq!(Sm2::signature_to_der(&signature)?, unhex(SIGNATURE_DER));
+        Sm2::verify(&public_key, b"message digest", &signature)?;
+        assert!(Sm2::verify(&public_key, b"message digesT", &signature).is_err());
+        assert!(Sm2::verify_with_id(&public_key, b"alice", b"message digest", &signature).is_err());
+        let empty_id = Sm2::signature_from_der(&unhex(EMPTY_ID_SIGNATURE_DER))?;
+        Sm2::verify_with_id(&public_key, b"", b"message digest", &empty_id)?;
+        assert!(Sm2::verify(&public_key, b"message digest", &empty_id).is_err());
+
+        let ciphertext = Sm2::ciphertext_from_der(&unhex(CIPHERTEXT_DER))?;
+        assert_eq!(ciphertext.len(), CIPHERTEXT_OVERHEAD + 19);
+        assert_eq!(Sm2::ciphertext_to_der(&ciphertext)?, unhex(CIPHERTEXT_DER));
+        assert_eq!(
+            Sm2::decrypt(&private_key, &ciphertext)?,
+            b"encryption standard"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sm2_sign_verify() -> Result<(), Error> {
+        let (public_key, private_key) = Sm2::generate_keypair()?;
+        let signature = Sm2::sign(&private_key, b"message")?;
+        assert_eq!(signature.len(), Sm2::MAX_SIGNATURE_SIZE);
+        Sm2::verify(&public_key, b"message", &signature)?;
+        assert_eq!(
+            Sm2::verify(&public_key, b"messagE", &signature),
+            Err(Error::Signature(SignatureError::Verification))
+        );
+        assert_eq!(
+            Sm2::verify(&public_key, b"message", &signature[1..].to_vec()),
+            Err(Error::Signature(SignatureError::InvalidSignature))
+        );
+
+        let mut tampered = signature.clone();
+        tampered[40] ^= 1;
+        assert!(Sm2::verify(&public_key, b"message", &tampered).is_err());
+        let (other_public_key, _) = Sm2::generate_keypair()?;
+        assert!(Sm2::verify(&other_public_key, b"message", &signature).is_err());
+
+        // A zero or out-of-range r is rejected
+        // 为零或超出范围的 r 会被拒绝
+        let mut zero_r = signature.clone();
+        zero_r[..32].fill(0);
+        assert!(Sm2::verify(&public_key, b"message", &zero_r).is_err());
+        let mut large_r = signature;
+        large_r[..32].copy_from_slice(&N.to_be_bytes());
+        assert!(Sm2::verify(&public_key, b"message", &large_r).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sm2_distinguishing_id() -> Result<(), Error> {
+        let (public_key, private_key) = keypair()?;
+        let signature = Sm2::sign_with_id(&private_key, b"alice@example.com", b"message")?;
+        Sm2::verify_with_id(&public_key, b"alice@example.com", b"message", &signature)?;
+        assert!(Sm2::verify(&public_key, b"message", &signature).is_err());
+
+        let default = Sm2::sign(&private_key, b"message")?;
+        Sm2::verify_with_id(&public_key, DEFAULT_ID, b"message", &default)?;
+        assert_ne!(
+            Sm2::identity_hash(&public_key, DEFAULT_ID)?,
+            Sm2::identity_hash(&public_key, b"alice@example.com")?
+        );
+        assert_eq!(
+            Sm2::identity_hash(&public_key, &[0u8; 8192]),
+            Err(Error::Signature(SignatureError::InvalidContext))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sm2_encrypt_decrypt() -> Result<(), Error> {
+        let (public_key, private_key) = Sm2::generate_keypair()?;
+        for plaintext in [&b""[..], b"x", &[0x5au8; 100]] {
+            let ciphertext = Sm2::encrypt(&public_key, plaintext)?;
+            assert_eq!(ciphertext.len(), plaintext.len() + CIPHERTEXT_OVERHEAD);
+            assert_eq!(Sm2::decrypt(&private_key, &ciphertext)?, plaintext);
+            let der = Sm2::ciphertext_to_der(&ciphertext)?;
+            assert_eq!(Sm2::ciphertext_from_der(&der)?, ciphertext);
+        }
+
+        let ciphertext = Sm2::encrypt(&public_key, b"secret")?;
+        let (_, other_private_key) = Sm2::generate_keypair()?;
+        assert_eq!(
+            Sm2::decrypt(&other_private_key, &ciphertext),
+            Err(Error::Kem(KemError::Decapsulation))
+        );
+        for index in [70, ciphertext.len() - 1] {
+            let mut tampered = ciphertext.clone();
+            tampered[index] ^= 1;
+            assert_eq!(
+                Sm2::decrypt(&private_key, &tampered),
+                Err(Error::Kem(KemError::Decapsulation))
+            );
+        }
+
+        // C1 must be an uncompressed point on the curve
+        // C1 必须是曲线上的未压缩点
+        let mut off_curve = ciphertext.clone();
+        off_curve[64] ^= 1;
+        assert_eq!(
+            Sm2::decrypt(&private_key, &off_curve),
+            Err(Error::Kem(KemError::InvalidEncapsulatedKey))
+        );
+        assert_eq!(
+            Sm2::decrypt(&private_key, &ciphertext[..CIPHERTEXT_OVERHEAD - 1]),
+            Err(Error::Kem(KemError::InvalidEncapsulatedKey))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sm2_kem() -> Result<(), Error> {
+        let (public_key, private_key) = Sm2::generate_keypair()?;
+        let (shared_secret, encapsulated_key) = Sm2::encapsulate(&public_key)?;
+        assert_eq!(shared_secret.expose_raw().len(), 32);
+        assert_eq!(
+            Sm2::decapsulate(&private_key, &encapsulated_key)?,
+            shared_secret
+        );
+        assert_eq!(
+            Sm2::decapsulate(&private_key, &encapsulated_key[1..].to_vec()),
+            Err(Error::Kem(KemError::InvalidEncapsulatedKey))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sm2_keys() -> Result<(), Error> {
+        let (public_key, private_key) = keypair()?;
+        let compressed = public_key.to_sec1_bytes(true);
+        assert_eq!(compressed.len(), 33);
+        assert_eq!(Sm2PublicKey::from_sec1_bytes(&compressed)?, public_key);
+        assert_eq!(public_key.to_sec1_bytes(false), unhex(PUBLIC_KEY));
+        assert_eq!(
+            Sm2PublicKey::from_bytes(&compressed),
+            Err(Error::Key(KeyError::InvalidLength))
+        );
+
+        let mut off_curve = unhex(PUBLIC_KEY);
+        off_curve[64] ^= 1;
+        assert_eq!(
+            Sm2PublicKey::from_bytes(&off_curve),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        );
+
+        assert_eq!(
+            Sm2PrivateKey::from_bytes(&private_key.to_bytes()?[1..]),
+            Err(Error::Key(KeyError::InvalidLength))
+        );
+        assert_eq!(
+            Sm2PrivateKey::from_bytes(&[0u8; 32]),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        );
+        // n - 1 is rejected, since 1 + d must be invertible
+        // n - 1 会被拒绝，因为 1 + d 必须可逆
+        let n_minus_one = N.wrapping_sub(&U256::ONE).to_be_bytes();
+        assert_eq!(
+            Sm2PrivateKey::from_bytes(&n_minus_one),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        );
+        let n_minus_two = N.wrapping_sub(&U256::from_u8(2)).to_be_bytes();
+        Sm2PrivateKey::from_bytes(&n_minus_two)?;
+
+        let (pk, sk) = Sm2::generate_keypair_with_rng(&mut SeededRng(0))?;
+        let (pk2, sk2) = Sm2::generate_keypair_with_rng(&mut SeededRng(0))?;
+        assert_eq!(pk, pk2);
+        assert_eq!(sk, sk2);
+        Sm2::validate_keypair(&pk, &sk)?;
+        assert_eq!(Sm2::ID, 0x01_01_04_01);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sm2_der_rejects_malformed_input() {
+        let signature = unhex(SIGNATURE_DER);
+        let mut trailing = signature.clone();
+        trailing.push(0);
+        assert!(Sm2::signature_from_der(&trailing).is_err());
+        // A non-minimal INTEGER with a redundant leading zero
+        // 带有多余前导零的非最短 INTEGER
+        let mut padded = signature.clone();
+        padded[5] = 0x00;
+        assert!(Sm2::signature_from_der(&padded).is_err());
+        assert!(Sm2::signature_from_der(&signature[..signature.len() - 1]).is_err());
+        assert!(Sm2::ciphertext_from_der(&signature).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+//! Commitment scheme implementations.
+//!
+//! This module provides commitment schemes, which let a party commit to a message now and
+//! reveal it later, without being able to change it in between.
+//!
+//! # Available Implementations
+//! - **Hash commitments**: `H(r || m)` with a random blinding factor, over SHA-256/512
+//!
+//! 承诺方案实现。
+//!
+//! 此模块提供承诺方案，使一方可以现在对消息作出承诺并在之后公开它，且在此期间无法更改。
+//!
+//! # 可用实现
+//! - **哈希承诺**: 使用随机盲化因子的 `H(r || m)`，基于 SHA-256/512
+
+/// Hash-based commitment implementation.
+///
+/// 基于哈希的承诺实现。
+#[cfg(feature = "sha2")]
+pub mod hash;
@@ -0,0 +1,257 @@
+//! Provides a hash-based commitment scheme.
+//!
+//! A commitment binds the committer to a message without revealing it. Here the commitment
+//! is `H(r || m)`, where `r` is a fresh 32-byte random blinding factor and `m` the message.
+//! The committer publishes the commitment and keeps `r`, the [`Opening`], secret; later they
+//! reveal the message and the opening, and anyone can check them against the commitment.
+//!
+//! # Security Considerations
+//! - Hiding relies on the blinding factor: it must come from a cryptographically secure RNG
+//!   and must never be reused. Without it, low-entropy messages such as bids could be
+//!   recovered by hashing every candidate.
+//! - Binding relies on the collision resistance of the hash function.
+//! - Verification compares the commitments in constant time.
+//!
+//! 提供了基于哈希的承诺方案。
+//!
+//! 承诺将承诺者绑定到某条消息上，而不泄露该消息。这里的承诺是 `H(r || m)`，其中 `r` 是新生成的
+//! 32 字节随机盲化因子，`m` 是消息。承诺者公布承诺并对 `r`（即 [`Opening`]）保密；之后公开消息
+//! 和打开值，任何人都可以根据承诺对它们进行检查。
+//!
+//! # 安全考虑
+//! - 隐藏性依赖于盲化因子：它必须来自加密安全的 RNG，并且绝不能重复使用。没有它，
+//!   诸如出价之类的低熵消息可以通过对每个候选值进行哈希而被恢复。
+//! - 绑定性依赖于哈希函数的抗碰撞性。
+//! - 验证以常量时间比较承诺。
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::ct::{Choice, ConstantTimeEq};
+use crate::{errors::Error, policy, prelude::*};
+use crate::traits::asymmetric::CryptoRngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use core::marker::PhantomData;
+use zeroize::Zeroizing;
+
+/// The size of the blinding factor in bytes.
+///
+/// 盲化因子的大小（以字节为单位）。
+pub const BLINDING_SIZE: usize = 32;
+
+/// A published commitment to a message.
+///
+/// 对消息的已公布承诺。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Commitment(
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))] Vec<u8>,
+);
+
+impl Commitment {
+    /// Wraps the bytes of a commitment received from the committer.
+    ///
+    /// 包装从承诺者处收到的承诺字节。
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// Returns the bytes of the commitment.
+    ///
+    /// 返回承诺的字节。
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Commitment {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// The secret blinding factor that opens a commitment.
+///
+/// 打开承诺的秘密盲化因子。
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Opening(
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    Zeroizing<Vec<u8>>,
+);
+
+impl Opening {
+    /// Wraps the bytes of an opening received from the committer.
+    /// Fails unless `bytes` is exactly [`BLINDING_SIZE`] bytes long.
+    ///
+    /// 包装从承诺者处收到的打开值字节。除非 `bytes` 恰好为 [`BLINDING_SIZE`] 字节，否则失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != BLINDING_SIZE {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        Ok(Self(Zeroizing::new(bytes.to_vec())))
+    }
+
+    /// Returns the bytes of the opening.
+    ///
+    /// 返回打开值的字节。
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ConstantTimeEq for Opening {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.as_slice().ct_eq(other.0.as_slice())
+    }
+}
+
+impl PartialEq for Opening {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for Opening {}
+
+/// A hash-based commitment scheme over a given hash function.
+///
+/// 基于给定哈希函数的哈希承诺方案。
+#[derive(Clone, Debug, Default)]
+pub struct HashCommitmentScheme<H: Hasher> {
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> HashCommitmentScheme<H> {
+    /// Commits to `message` with a blinding factor drawn from the crate's system RNG.
+    ///
+    /// 使用从 crate 的系统 RNG 获取的盲化因子对 `message` 作出承诺。
+    pub fn commit(message: &[u8]) -> Result<(Commitment, Opening), Error> {
+        policy::enforce::<Self>()?;
+        let mut blinding = Zeroizing::new(vec![0u8; BLINDING_SIZE]);
+        crate::rng::fill(&mut blinding).map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        let opening = Opening(blinding);
+        Ok((Self::commit_with_opening(message, &opening), opening))
+    }
+
+    /// Commits to `message` with a blinding factor drawn from `rng`.
+    ///
+    /// 使用从 `rng` 获取的盲化因子对 `message` 作出承诺。
+    pub fn commit_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        message: &[u8],
+    ) -> (Commitment, Opening) {
+        let mut blinding = Zeroizing::new(vec![0u8; BLINDING_SIZE]);
+        rng.fill_bytes(&mut blinding);
+        let opening = Opening(blinding);
+        (Self::commit_with_opening(message, &opening), opening)
+    }
+
+    /// Recomputes the commitment to `message` under an existing opening.
+    ///
+    /// 在已有的打开值下重新计算对 `message` 的承诺。
+    pub fn commit_with_opening(message: &[u8], opening: &Opening) -> Commitment {
+        let mut state = H::Digest::default();
+        digest::Digest::update(&mut state, opening.as_bytes());
+        digest::Digest::update(&mut state, message);
+        Commitment(digest::Digest::finalize(state).to_vec())
+    }
+
+    /// Checks, in constant time, that `message` and `opening` match `commitment`.
+    ///
+    /// 以常量时间检查 `message` 和 `opening` 是否与 `commitment` 匹配。
+    pub fn verify(commitment: &Commitment, message: &[u8], opening: &Opening) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        let expected = Self::commit_with_opening(message, opening);
+        if bool::from(expected.as_bytes().ct_eq(commitment.as_bytes())) {
+            Ok(())
+        } else {
+            Err(Error::Hash(HashError::CommitmentMismatch))
+        }
+    }
+}
+
+impl<H: Hasher> Algorithm for HashCommitmentScheme<H> {
+    fn name() -> String {
+        format!("Commitment-{}", H::NAME)
+    }
+    const ID: u32 = 0x08_01_00_00 + H::ID_OFFSET;
+}
+
+impl<H: Hasher> Parameterized for HashCommitmentScheme<H> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(H::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for hash commitments over SHA-256.
+///
+/// 基于 SHA-256 的哈希承诺的类型别名。
+pub type Sha256Commitment = HashCommitmentScheme<Sha256>;
+
+/// A type alias for hash commitments over SHA-512.
+///
+/// 基于 SHA-512 的哈希承诺的类型别名。
+pub type Sha512Commitment = HashCommitmentScheme<Sha512>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    #[test]
+    fn test_sha256_commitment() -> Result<(), Error> {
+        // SHA-256 over the blinding factor 00 01 .. 1f followed by the message
+        let opening = Opening::from_bytes(&(0u8..32).collect::<Vec<_>>())?;
+        let commitment = Sha256Commitment::commit_with_opening(b"bid: 100", &opening);
+        assert_eq!(
+            hex::encode(commitment.as_bytes()),
+            "0767899adb464189dd8253a8df78b242467aa8381f634d814d944a6f212eed68"
+        );
+        assert!(Sha256Commitment::verify(&commitment, b"bid: 100", &opening).is_ok());
+        assert_eq!(
+            Sha256Commitment::verify(&commitment, b"bid: 101", &opening).unwrap_err(),
+            Error::Hash(HashError::CommitmentMismatch)
+        );
+
+        // The opening survives a round trip through its byte encoding
+        let opening = Opening::from_bytes(opening.as_bytes())?;
+        let commitment = Commitment::from_bytes(commitment.as_bytes());
+        assert!(Sha256Commitment::verify(&commitment, b"bid: 100", &opening).is_ok());
+        assert_eq!(
+            Opening::from_bytes(&[0u8; 31]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_commitment_hides_equal_messages() {
+        let mut rng = SeededRng(0);
+        let (first, first_opening) = Sha512Commitment::commit_with_rng(&mut rng, b"message");
+        let (second, second_opening) = Sha512Commitment::commit_with_rng(&mut rng, b"message");
+        assert_eq!(first.as_bytes().len(), 64);
+        assert_ne!(first, second);
+        assert!(Sha512Commitment::verify(&first, b"message", &second_opening).is_err());
+        assert!(Sha512Commitment::verify(&second, b"message", &second_opening).is_ok());
+        assert!(Sha512Commitment::verify(&first, b"message", &first_opening).is_ok());
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_commit_with_os_rng() {
+        let (commitment, opening) = Sha256Commitment::commit(b"sealed bid").unwrap();
+        assert!(Sha256Commitment::verify(&commitment, b"sealed bid", &opening).is_ok());
+    }
+}
@@ -0,0 +1,92 @@
+//! Interoperable encodings and container formats built on the crate's algorithms.
+//!
+//! This module provides implementations of external formats that wrap keys and signatures
+//! produced by this crate, so they can be exchanged with other tools.
+//!
+//! # Available Implementations
+//! - **sshsig**: OpenSSH signature format, as produced by `ssh-keygen -Y sign`
+//! - **openssh**: OpenSSH public key lines and `openssh-key-v1` private keys
+//! - **encrypted_pkcs8**: Password-protected PKCS#8 private keys (PBES2 with scrypt or PBKDF2)
+//! - **phc**: PHC string format for password hashes
+//! - **jwk**: JSON Web Keys and JWK Sets (RFC 7517), with RFC 7638 thumbprints
+//! - **bip39**: BIP39 mnemonic phrases for backing up seeds
+//! - **bech32**: Bech32 and Bech32m strings for public keys and fingerprints
+//! - **base58check**: Base58Check strings for public keys and fingerprints
+//! - **cbor**: Deterministic CBOR for keys, signatures and ciphertexts tagged with their algorithm ID
+//!
+//! 基于本 crate 算法构建的可互操作编码和容器格式。
+//!
+//! 此模块提供外部格式的实现，用于包装本 crate 产生的密钥和签名，
+//! 以便与其他工具进行交换。
+//!
+//! # 可用实现
+//! - **sshsig**: OpenSSH 签名格式，与 `ssh-keygen -Y sign` 生成的格式相同
+//! - **openssh**: OpenSSH 公钥行和 `openssh-key-v1` 私钥
+//! - **encrypted_pkcs8**: 受密码保护的 PKCS#8 私钥（使用 scrypt 或 PBKDF2 的 PBES2）
+//! - **phc**: 用于密码哈希的 PHC 字符串格式
+//! - **jwk**: JSON Web Key 和 JWK Set (RFC 7517)，以及 RFC 7638 指纹
+//! - **bip39**: 用于备份种子的 BIP39 助记词
+//! - **bech32**: 用于公钥和指纹的 Bech32 和 Bech32m 字符串
+//! - **base58check**: 用于公钥和指纹的 Base58Check 字符串
+//! - **cbor**: 带算法 ID 标签的密钥、签名和密文的确定性 CBOR
+
+/// OpenSSH `sshsig` signature format implementation.
+///
+/// OpenSSH `sshsig` 签名格式实现。
+#[cfg(feature = "sshsig-default")]
+pub mod sshsig;
+
+/// OpenSSH public and private key encoding.
+///
+/// OpenSSH 公钥和私钥编码。
+#[cfg(feature = "openssh-default")]
+pub mod openssh;
+
+/// Password-protected PKCS#8 private keys.
+///
+/// 受密码保护的 PKCS#8 私钥。
+#[cfg(feature = "encrypted-pkcs8-default")]
+pub mod encrypted_pkcs8;
+
+#[cfg(any(feature = "sshsig-default", feature = "openssh-default"))]
+mod ssh_wire;
+
+/// PHC string format for password hashes.
+///
+/// 用于密码哈希的 PHC 字符串格式。
+#[cfg(any(
+    feature = "pbkdf2-default",
+    feature = "argon2-default",
+    feature = "scrypt-default"
+))]
+pub mod phc;
+
+/// JSON Web Key import and export.
+///
+/// JSON Web Key 的导入和导出。
+#[cfg(feature = "serde")]
+pub mod jwk;
+
+/// BIP39 mnemonic phrases.
+///
+/// BIP39 助记词。
+#[cfg(feature = "bip39-default")]
+pub mod bip39;
+
+/// Bech32 and Bech32m text encoding.
+///
+/// Bech32 和 Bech32m 文本编码。
+#[cfg(feature = "bech32-default")]
+pub mod bech32;
+
+/// Base58Check text encoding.
+///
+/// Base58Check 文本编码。
+#[cfg(feature = "base58check-default")]
+pub mod base58check;
+
+/// Algorithm-tagged CBOR encoding.
+///
+/// 带算法标签的 CBOR 编码。
+#[cfg(feature = "serde")]
+pub mod cbor;
@@ -0,0 +1,173 @@
+//! Provides Base58Check text encoding for public keys and key fingerprints.
+//!
+//! Base58Check, as used for Bitcoin addresses and WIF keys, prepends a version prefix to the
+//! payload, appends the first four bytes of its double SHA-256 and encodes the result in an
+//! alphabet without the easily confused characters `0`, `O`, `I` and `l`. The checksum
+//! rejects mistyped strings with a probability of about 1 - 2^-32.
+//!
+//! A [`Base58Check`] codec is configured with the version prefix expected by the application,
+//! and decoding is strict: the checksum and the complete prefix must match.
+//!
+//! 提供了用于公钥和密钥指纹的 Base58Check 文本编码。
+//!
+//! Base58Check（用于比特币地址和 WIF 密钥）在载荷前添加版本前缀，在末尾附加其双重 SHA-256
+//! 的前四个字节，并使用不含易混淆字符 `0`、`O`、`I` 和 `l` 的字母表对结果进行编码。校验和
+//! 以约 1 - 2^-32 的概率拒绝输错的字符串。
+//!
+//! [`Base58Check`] 编解码器使用应用所期望的版本前缀进行配置。解码是严格的：校验和及完整的
+//! 前缀都必须匹配。
+
+use alloc::{string::String, vec::Vec};
+use crate::errors::Error;
+use crate::prelude::*;
+
+fn invalid_encoding() -> Error {
+    Error::Key(KeyError::InvalidEncoding)
+}
+
+/// Encodes and strictly decodes Base58Check strings with a fixed version prefix.
+///
+/// 使用固定版本前缀编码和严格解码 Base58Check 字符串。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Base58Check {
+    prefix: Vec<u8>,
+}
+
+impl Base58Check {
+    /// Creates a codec that prepends `prefix` to every payload.
+    ///
+    /// The prefix may be empty or span several bytes; multi-byte prefixes can be chosen so that
+    /// all encoded strings of a given length start with the same characters.
+    ///
+    /// 创建在每个载荷前添加 `prefix` 的编解码器。
+    ///
+    /// 前缀可以为空，也可以跨越多个字节；可以选择多字节前缀，使给定长度的所有编码字符串都以
+    /// 相同的字符开头。
+    pub fn new(prefix: &[u8]) -> Self {
+        Self {
+            prefix: prefix.to_vec(),
+        }
+    }
+
+    /// Returns the version prefix.
+    ///
+    /// 返回版本前缀。
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// Encodes `data` as a Base58Check string.
+    ///
+    /// 将 `data` 编码为 Base58Check 字符串。
+    pub fn encode(&self, data: &[u8]) -> String {
+        let mut payload = Vec::with_capacity(self.prefix.len() + data.len());
+        payload.extend_from_slice(&self.prefix);
+        payload.extend_from_slice(data);
+        bs58::encode(payload).with_check().into_string()
+    }
+
+    /// Decodes a Base58Check string produced by [`Self::encode`].
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the string contains characters outside the
+    /// Base58 alphabet, its checksum is wrong, or it does not start with this codec's prefix.
+    ///
+    /// 解码由 [`Self::encode`] 生成的 Base58Check 字符串。
+    ///
+    /// 如果字符串包含 Base58 字母表以外的字符、校验和错误，或者不以此编解码器的前缀开头，
+    /// 则以 `KeyError::InvalidEncoding` 失败。
+    pub fn decode(&self, s: &str) -> Result<Vec<u8>, Error> {
+        let payload = bs58::decode(s)
+            .with_check(None)
+            .into_vec()
+            .map_err(|_| invalid_encoding())?;
+        payload
+            .strip_prefix(self.prefix.as_slice())
+            .map(<[u8]>::to_vec)
+            .ok_or_else(invalid_encoding)
+    }
+
+    /// Encodes a public key.
+    ///
+    /// 编码公钥。
+    pub fn encode_public_key<K: PublicKey>(&self, key: &K) -> Result<String, Error> {
+        Ok(self.encode(&key.to_bytes()?))
+    }
+
+    /// Decodes a public key encoded with [`Self::encode_public_key`].
+    ///
+    /// 解码使用 [`Self::encode_public_key`] 编码的公钥。
+    pub fn decode_public_key<K: PublicKey>(&self, s: &str) -> Result<K, Error> {
+        K::from_bytes(&self.decode(s)?)
+    }
+
+    /// Encodes the fingerprint of a public key: the hash `H` of its byte representation.
+    ///
+    /// 编码公钥的指纹：其字节表示的哈希 `H`。
+    #[cfg(feature = "sha2")]
+    pub fn encode_fingerprint<H: Hasher, K: PublicKey>(&self, key: &K) -> Result<String, Error> {
+        Ok(self.encode(&H::hash(&key.to_bytes()?)))
+    }
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_vector() -> Result<(), Error> {
+        // A Bitcoin P2PKH address: version 0x00 followed by a HASH160.
+        // 比特币 P2PKH 地址：版本 0x00 后跟 HASH160。
+        let hash160 = hex::decode("77bff20c60e522dfaa3350c39b030a5d004e839a")
+            .map_err(|_| invalid_encoding())?;
+        let codec = Base58Check::new(&[0x00]);
+        let address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        assert_eq!(codec.encode(&hash160), address);
+        assert_eq!(codec.decode(address)?, hash160);
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_decoding() -> Result<(), Error> {
+        let codec = Base58Check::new(&[0x1f, 0x40]);
+        let encoded = codec.encode(&[0xa5; 32]);
+        assert_eq!(codec.decode(&encoded)?, [0xa5; 32]);
+
+        let mut typo = encoded.clone().into_bytes();
+        let i = typo.len() / 2;
+        typo[i] = if typo[i] == b'2' { b'3' } else { b'2' };
+        let typo = String::from_utf8(typo).map_err(|_| invalid_encoding())?;
+        let other_prefix = Base58Check::new(&[0x1f, 0x41]).encode(&[0xa5; 32]);
+        let bad_alphabet = encoded.replacen(|c: char| c.is_ascii_alphanumeric(), "0", 1);
+        let truncated = encoded[..encoded.len() - 1].to_string();
+
+        for bad in [typo, other_prefix, bad_alphabet, truncated, String::new()] {
+            assert!(matches!(
+                codec.decode(&bad),
+                Err(Error::Key(KeyError::InvalidEncoding))
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(all(feature = "ecc-default", feature = "sha2"))]
+    #[test]
+    fn test_public_key_and_fingerprint() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::ecc::EcdsaP256;
+        use crate::traits::params::hash::Sha256;
+
+        let (pk, _) = EcdsaP256::generate_keypair()?;
+        let codec = Base58Check::new(b"pk");
+        let encoded = codec.encode_public_key(&pk)?;
+        let decoded: <EcdsaP256 as AsymmetricKeySet>::PublicKey =
+            codec.decode_public_key(&encoded)?;
+        assert_eq!(decoded.to_bytes()?, pk.to_bytes()?);
+
+        let fp = Base58Check::new(b"fp");
+        let fingerprint = fp.encode_fingerprint::<Sha256, _>(&pk)?;
+        assert_eq!(fp.decode(&fingerprint)?, Sha256::hash(&pk.to_bytes()?));
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+//! Provides Bech32 and Bech32m text encodings for public keys and key fingerprints.
+//!
+//! Bech32 (BIP173) and Bech32m (BIP350) strings consist of a human-readable prefix (HRP), the
+//! separator `1`, and the data in a case-insensitive 32-character alphabet followed by a
+//! six-character checksum. The checksum detects any error affecting up to four characters,
+//! which makes these strings well suited for identifiers that are printed or typed by hand.
+//!
+//! A [`Bech32Codec`] is configured with the HRP expected by the application, e.g. `"sealpk"`
+//! for public keys or `"sealfp"` for fingerprints, and decoding is strict: the HRP, the checksum
+//! variant and the padding bits must all match exactly, and mixed-case strings are rejected.
+//!
+//! 提供了用于公钥和密钥指纹的 Bech32 和 Bech32m 文本编码。
+//!
+//! Bech32 (BIP173) 和 Bech32m (BIP350) 字符串由人类可读前缀 (HRP)、分隔符 `1` 以及使用
+//! 不区分大小写的 32 字符字母表表示的数据组成，末尾为六个字符的校验和。校验和可以检测
+//! 影响最多四个字符的任何错误，因此这些字符串非常适合需要打印或手工输入的标识符。
+//!
+//! [`Bech32Codec`] 使用应用所期望的 HRP 进行配置，例如公钥使用 `"sealpk"`，指纹使用
+//! `"sealfp"`。解码是严格的：HRP、校验和变体和填充位都必须完全匹配，并且拒绝大小写混合的
+//! 字符串。
+
+use alloc::{string::String, vec::Vec};
+use crate::errors::Error;
+use crate::prelude::*;
+use ::bech32::primitives::decode::CheckedHrpstring;
+use ::bech32::{Bech32, Bech32m, Checksum, Hrp};
+
+fn invalid_encoding() -> Error {
+    Error::Key(KeyError::InvalidEncoding)
+}
+
+/// The checksum variant of a Bech32 string.
+///
+/// Bech32 字符串的校验和变体。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Bech32Variant {
+    /// The original BIP173 checksum.
+    ///
+    /// 原始的 BIP173 校验和。
+    Bech32,
+    /// The BIP350 checksum, which fixes a weakness of Bech32 against inserted or deleted `q`
+    /// characters. Recommended for new applications.
+    ///
+    /// BIP350 校验和，修复了 Bech32 对插入或删除 `q` 字符的弱点。推荐新应用使用。
+    #[default]
+    Bech32m,
+}
+
+/// Encodes and strictly decodes Bech32 strings with a fixed human-readable prefix.
+///
+/// 使用固定人类可读前缀编码和严格解码 Bech32 字符串。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bech32Codec {
+    hrp: Hrp,
+    variant: Bech32Variant,
+}
+
+impl Bech32Codec {
+    /// Creates a Bech32m codec for the human-readable prefix `hrp`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if `hrp` is empty, longer than 83 characters,
+    /// mixed-case or contains characters outside ASCII 33 to 126.
+    ///
+    /// 为人类可读前缀 `hrp` 创建 Bech32m 编解码器。
+    ///
+    /// 如果 `hrp` 为空、超过 83 个字符、大小写混合或包含 ASCII 33 到 126 范围以外的字符，
+    /// 则以 `KeyError::InvalidEncoding` 失败。
+    pub fn new(hrp: &str) -> Result<Self, Error> {
+        let hrp = Hrp::parse(hrp).map_err(|_| invalid_encoding())?;
+        Ok(Self {
+            hrp,
+            variant: Bech32Variant::default(),
+        })
+    }
+
+    /// Returns a codec that uses the checksum `variant` instead.
+    ///
+    /// 返回改用校验和变体 `variant` 的编解码器。
+    pub fn with_variant(mut self, variant: Bech32Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Returns the human-readable prefix, in lowercase.
+    ///
+    /// 返回小写形式的人类可读前缀。
+    pub fn hrp(&self) -> String {
+        self.hrp.to_lowercase()
+    }
+
+    /// Returns the checksum variant.
+    ///
+    /// 返回校验和变体。
+    pub fn variant(&self) -> Bech32Variant {
+        self.variant
+    }
+
+    /// Encodes `data` as a lowercase Bech32 string.
+    ///
+    /// Fails with `KeyError::InvalidLength` if the result would exceed 1023 characters.
+    ///
+    /// 将 `data` 编码为小写 Bech32 字符串。
+    ///
+    /// 如果结果将超过 1023 个字符，则以 `KeyError::InvalidLength` 失败。
+    pub fn encode(&self, data: &[u8]) -> Result<String, Error> {
+        match self.variant {
+            Bech32Variant::Bech32 => encode::<Bech32>(self.hrp, data),
+            Bech32Variant::Bech32m => encode::<Bech32m>(self.hrp, data),
+        }
+    }
+
+    /// Decodes a Bech32 string produced by [`Self::encode`].
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the string is malformed or mixed-case, its
+    /// checksum is wrong or of the other variant, its padding is non-canonical, or its HRP
+    /// differs from this codec's.
+    ///
+    /// 解码由 [`Self::encode`] 生成的 Bech32 字符串。
+    ///
+    /// 如果字符串格式错误或大小写混合、校验和错误或属于另一种变体、填充不规范，或者其 HRP
+    /// 与此编解码器的不同，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn decode(&self, s: &str) -> Result<Vec<u8>, Error> {
+        match self.variant {
+            Bech32Variant::Bech32 => decode::<Bech32>(self.hrp, s),
+            Bech32Variant::Bech32m => decode::<Bech32m>(self.hrp, s),
+        }
+    }
+
+    /// Encodes a public key.
+    ///
+    /// 编码公钥。
+    pub fn encode_public_key<K: PublicKey>(&self, key: &K) -> Result<String, Error> {
+        self.encode(&key.to_bytes()?)
+    }
+
+    /// Decodes a public key encoded with [`Self::encode_public_key`].
+    ///
+    /// 解码使用 [`Self::encode_public_key`] 编码的公钥。
+    pub fn decode_public_key<K: PublicKey>(&self, s: &str) -> Result<K, Error> {
+        K::from_bytes(&self.decode(s)?)
+    }
+
+    /// Encodes the fingerprint of a public key: the hash `H` of its byte representation.
+    ///
+    /// 编码公钥的指纹：其字节表示的哈希 `H`。
+    #[cfg(feature = "sha2")]
+    pub fn encode_fingerprint<H: Hasher, K: PublicKey>(&self, key: &K) -> Result<String, Error> {
+        self.encode(&H::hash(&key.to_bytes()?))
+    }
+}
+
+fn encode<Ck: Checksum>(hrp: Hrp, data: &[u8]) -> Result<String, Error> {
+    ::bech32::encode_lower::<Ck>(hrp, data).map_err(|_| Error::Key(KeyError::InvalidLength))
+}
+
+fn decode<Ck: Checksum>(hrp: Hrp, s: &str) -> Result<Vec<u8>, Error> {
+    let checked = CheckedHrpstring::new::<Ck>(s).map_err(|_| invalid_encoding())?;
+    checked
+        .validate_segwit_padding()
+        .map_err(|_| invalid_encoding())?;
+    if checked.hrp() != hrp {
+        return Err(invalid_encoding());
+    }
+    Ok(checked.byte_iter().collect())
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The 5-bit groups 0, 1, ..., 31 and 31, 30, ..., 0.
+    // 5 位分组 0, 1, ..., 31 和 31, 30, ..., 0。
+    const DATA: &str = "00443214c74254b635cf84653a56d7c675be77df";
+    const DATA_REVERSED: &str = "ffbbcdeb38bdab49ca307b9ac5a928398a418820";
+
+    #[test]
+    fn test_reference_vectors() -> Result<(), Error> {
+        // BIP173 and BIP350 valid test strings.
+        // BIP173 和 BIP350 的有效测试字符串。
+        let data = hex::decode(DATA).map_err(|_| invalid_encoding())?;
+        let data_reversed = hex::decode(DATA_REVERSED).map_err(|_| invalid_encoding())?;
+        let bech32 = Bech32Codec::new("abcdef")?.with_variant(Bech32Variant::Bech32);
+        let bech32m = Bech32Codec::new("abcdef")?;
+        let s = "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw";
+        let sm = "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx";
+
+        assert_eq!(bech32.encode(&data)?, s);
+        assert_eq!(bech32m.encode(&data_reversed)?, sm);
+        assert_eq!(bech32.decode(s)?, data);
+        assert_eq!(bech32m.decode(sm)?, data_reversed);
+        assert_eq!(bech32m.decode(&sm.to_uppercase())?, data_reversed);
+        assert_eq!(Bech32Codec::new("a")?.decode("a1lqfn3a")?, b"");
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_decoding() -> Result<(), Error> {
+        let codec = Bech32Codec::new("sealfp")?;
+        let encoded = codec.encode(&[0x5a; 32])?;
+        assert_eq!(codec.decode(&encoded)?, [0x5a; 32]);
+
+        // A single mistyped character.
+        // 单个输错的字符。
+        let mut typo = encoded.clone().into_bytes();
+        let i = typo.len() - 10;
+        typo[i] = if typo[i] == b'q' { b'p' } else { b'q' };
+        let typo = String::from_utf8(typo).map_err(|_| invalid_encoding())?;
+
+        // Mixed case, wrong variant and wrong prefix.
+        // 大小写混合、错误的变体和错误的前缀。
+        let mut mixed = encoded.clone();
+        mixed.replace_range(..1, "S");
+        let other_variant = codec
+            .clone()
+            .with_variant(Bech32Variant::Bech32)
+            .encode(&[0x5a; 32])?;
+        let other_hrp = Bech32Codec::new("sealpk")?.encode(&[0x5a; 32])?;
+
+        for bad in [typo, mixed, other_variant, other_hrp] {
+            assert!(matches!(
+                codec.decode(&bad),
+                Err(Error::Key(KeyError::InvalidEncoding))
+            ));
+        }
+        assert!(Bech32Codec::new("").is_err());
+        assert!(matches!(
+            codec.encode(&[0u8; 1024]),
+            Err(Error::Key(KeyError::InvalidLength))
+        ));
+        Ok(())
+    }
+
+    #[cfg(all(feature = "ecc-default", feature = "sha2"))]
+    #[test]
+    fn test_public_key_and_fingerprint() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::ecc::Ed25519;
+        use crate::traits::params::hash::Sha256;
+
+        let (pk, _) = Ed25519::generate_keypair()?;
+        let codec = Bech32Codec::new("sealpk")?;
+        let encoded = codec.encode_public_key(&pk)?;
+        assert!(encoded.starts_with("sealpk1"));
+        let decoded: <Ed25519 as AsymmetricKeySet>::PublicKey =
+            codec.decode_public_key(&encoded)?;
+        assert_eq!(decoded.to_bytes()?, pk.to_bytes()?);
+
+        let fp = Bech32Codec::new("sealfp")?;
+        let fingerprint = fp.encode_fingerprint::<Sha256, _>(&pk)?;
+        assert_eq!(fp.decode(&fingerprint)?, Sha256::hash(&pk.to_bytes()?));
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+//! Provides BIP39 mnemonic phrases for backing up secret seeds.
+//!
+//! BIP39 encodes 16 to 32 bytes of entropy as 12 to 24 words from a fixed English wordlist,
+//! with a checksum in the final word that catches most transcription errors. This makes
+//! root secrets practical to write down and type back in.
+//!
+//! A mnemonic can be used in two ways:
+//! - As a **seed container**: [`Mnemonic::from_entropy`] and [`Mnemonic::to_entropy`] convert
+//!   losslessly between the phrase and the original bytes, e.g. the 32-byte generation seeds
+//!   accepted by `EccPrivateKey::from_raw_bytes` (Ed25519) and Dilithium's `from_seed`.
+//! - As a **BIP39 wallet seed**: [`Mnemonic::to_seed`] stretches the phrase and an optional
+//!   passphrase into a 64-byte seed with PBKDF2-HMAC-SHA512, suitable for
+//!   `ExtendedPrivateKey::from_seed` in the `hd` module.
+//!
+//! Only the English wordlist is supported.
+//!
+//! 提供了用于备份秘密种子的 BIP39 助记词。
+//!
+//! BIP39 将 16 到 32 字节的熵编码为固定英文词表中的 12 到 24 个单词，最后一个单词中包含
+//! 校验和，可以发现大多数抄写错误。这使得根秘密便于手写记录并重新输入。
+//!
+//! 助记词有两种用法：
+//! - 作为**种子容器**：[`Mnemonic::from_entropy`] 和 [`Mnemonic::to_entropy`] 在短语与原始
+//!   字节之间无损转换，例如 `EccPrivateKey::from_raw_bytes` (Ed25519) 和 Dilithium 的
+//!   `from_seed` 接受的 32 字节生成种子。
+//! - 作为 **BIP39 钱包种子**：[`Mnemonic::to_seed`] 使用 PBKDF2-HMAC-SHA512 将短语和可选口令
+//!   拉伸为 64 字节种子，适用于 `hd` 模块中的 `ExtendedPrivateKey::from_seed`。
+//!
+//! 仅支持英文词表。
+
+use alloc::{string::String, vec::Vec};
+use crate::errors::Error;
+use crate::prelude::*;
+use ::bip39::Language;
+use core::fmt;
+use core::str::FromStr;
+use zeroize::Zeroizing;
+
+/// The word counts allowed by BIP39.
+///
+/// BIP39 允许的单词数。
+pub const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// The size of the seed produced by [`Mnemonic::to_seed`], in bytes.
+///
+/// [`Mnemonic::to_seed`] 生成的种子大小（字节）。
+pub const SEED_SIZE: usize = 64;
+
+fn map_error(err: ::bip39::Error) -> Error {
+    match err {
+        ::bip39::Error::BadWordCount(_) | ::bip39::Error::BadEntropyBitCount(_) => {
+            Error::Key(KeyError::InvalidLength)
+        }
+        _ => Error::Key(KeyError::InvalidEncoding),
+    }
+}
+
+/// A BIP39 mnemonic phrase.
+///
+/// The words are zeroized when the mnemonic is dropped.
+///
+/// BIP39 助记词短语。
+///
+/// 助记词被丢弃时会清零其中的单词。
+#[derive(Clone)]
+pub struct Mnemonic {
+    inner: ::bip39::Mnemonic,
+}
+
+impl Mnemonic {
+    /// Generates a new mnemonic of `word_count` words from fresh system randomness.
+    ///
+    /// Fails with `KeyError::InvalidLength` unless `word_count` is one of [`WORD_COUNTS`].
+    ///
+    /// 使用新的系统随机数生成包含 `word_count` 个单词的助记词。
+    ///
+    /// 除非 `word_count` 是 [`WORD_COUNTS`] 之一，否则以 `KeyError::InvalidLength` 失败。
+    pub fn generate(word_count: usize) -> Result<Self, Error> {
+        if !WORD_COUNTS.contains(&word_count) {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        let mut entropy = Zeroizing::new([0u8; 32]);
+        let entropy = &mut entropy[..word_count / 3 * 4];
+        crate::rng::fill(entropy).map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        Self::from_entropy(entropy)
+    }
+
+    /// Encodes `entropy` as a mnemonic.
+    ///
+    /// Fails with `KeyError::InvalidLength` unless `entropy` is 16, 20, 24, 28 or 32 bytes.
+    ///
+    /// 将 `entropy` 编码为助记词。
+    ///
+    /// 除非 `entropy` 为 16、20、24、28 或 32 字节，否则以 `KeyError::InvalidLength` 失败。
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, Error> {
+        ::bip39::Mnemonic::from_entropy_in(Language::English, entropy)
+            .map(|inner| Self { inner })
+            .map_err(map_error)
+    }
+
+    /// Parses a space-separated mnemonic phrase and verifies its checksum.
+    ///
+    /// Fails with `KeyError::InvalidLength` if the number of words is not one of
+    /// [`WORD_COUNTS`], and with `KeyError::InvalidEncoding` if a word is not in the wordlist
+    /// or the checksum does not match.
+    ///
+    /// 解析以空格分隔的助记词短语并验证其校验和。
+    ///
+    /// 如果单词数不是 [`WORD_COUNTS`] 之一，则以 `KeyError::InvalidLength` 失败；如果某个
+    /// 单词不在词表中或校验和不匹配，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn parse(phrase: &str) -> Result<Self, Error> {
+        ::bip39::Mnemonic::parse_in(Language::English, phrase)
+            .map(|inner| Self { inner })
+            .map_err(map_error)
+    }
+
+    /// Returns the number of words in the mnemonic.
+    ///
+    /// 返回助记词中的单词数。
+    pub fn word_count(&self) -> usize {
+        self.inner.word_count()
+    }
+
+    /// Returns the words of the mnemonic.
+    ///
+    /// 返回助记词的单词。
+    pub fn words(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.inner.words()
+    }
+
+    /// Returns the mnemonic as a single space-separated phrase.
+    ///
+    /// 以单个空格分隔的短语形式返回助记词。
+    pub fn phrase(&self) -> Zeroizing<String> {
+        let mut phrase = Zeroizing::new(String::new());
+        for (i, word) in self.words().enumerate() {
+            if i > 0 {
+                phrase.push(' ');
+            }
+            phrase.push_str(word);
+        }
+        phrase
+    }
+
+    /// Recovers the entropy encoded by the mnemonic.
+    ///
+    /// 恢复助记词所编码的熵。
+    pub fn to_entropy(&self) -> Zeroizing<Vec<u8>> {
+        let (entropy, len) = self.inner.to_entropy_array();
+        let entropy = Zeroizing::new(entropy);
+        Zeroizing::new(entropy[..len].to_vec())
+    }
+
+    /// Derives the 64-byte BIP39 seed from the mnemonic and `passphrase`.
+    ///
+    /// Pass an empty string if no passphrase is used. A different passphrase yields an
+    /// unrelated seed, so it must be backed up alongside the phrase.
+    ///
+    /// 从助记词和 `passphrase` 派生 64 字节的 BIP39 种子。
+    ///
+    /// 如果不使用口令，请传入空字符串。不同的口令会产生无关的种子，因此必须与短语一起备份。
+    pub fn to_seed(&self, passphrase: &str) -> Zeroizing<[u8; SEED_SIZE]> {
+        Zeroizing::new(self.inner.to_seed(passphrase))
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mnemonic")
+            .field("word_count", &self.word_count())
+            .finish_non_exhaustive()
+    }
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ZERO_PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                               abandon abandon abandon about";
+    const ZERO_SEED_TREZOR: &str = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553\
+                                    1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+    const LEGAL_PHRASE: &str =
+        "legal winner thank year wave sausage worth useful legal winner thank yellow";
+    const LEGAL_SEED_TREZOR: &str = "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6f\
+                                     a457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607";
+
+    #[test]
+    fn test_reference_vectors() -> Result<(), Error> {
+        for (entropy, phrase, seed) in [
+            ([0x00u8; 16], ZERO_PHRASE, ZERO_SEED_TREZOR),
+            ([0x7fu8; 16], LEGAL_PHRASE, LEGAL_SEED_TREZOR),
+        ] {
+            let mnemonic = Mnemonic::from_entropy(&entropy)?;
+            assert_eq!(mnemonic.phrase().as_str(), phrase);
+            assert_eq!(mnemonic.word_count(), 12);
+            assert_eq!(hex::encode(*mnemonic.to_seed("TREZOR")), seed);
+
+            let parsed: Mnemonic = phrase.parse()?;
+            assert_eq!(parsed.to_entropy().as_slice(), &entropy);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_roundtrip() -> Result<(), Error> {
+        for word_count in WORD_COUNTS {
+            let mnemonic = Mnemonic::generate(word_count)?;
+            assert_eq!(mnemonic.word_count(), word_count);
+            assert_eq!(mnemonic.to_entropy().len(), word_count / 3 * 4);
+
+            let parsed = Mnemonic::parse(&mnemonic.phrase())?;
+            assert_eq!(parsed.to_entropy(), mnemonic.to_entropy());
+            assert_eq!(parsed.to_seed("pass"), mnemonic.to_seed("pass"));
+            assert_ne!(parsed.to_seed("pass"), parsed.to_seed(""));
+        }
+        assert!(matches!(
+            Mnemonic::generate(13),
+            Err(Error::Key(KeyError::InvalidLength))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_invalid_phrases() {
+        // The last word carries the checksum.
+        // 最后一个单词携带校验和。
+        let bad_checksum = ZERO_PHRASE.replace("about", "abandon");
+        assert!(matches!(
+            Mnemonic::parse(&bad_checksum),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+        let unknown_word = ZERO_PHRASE.replace("about", "aboot");
+        assert!(matches!(
+            Mnemonic::parse(&unknown_word),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+        assert!(matches!(
+            Mnemonic::parse("abandon abandon about"),
+            Err(Error::Key(KeyError::InvalidLength))
+        ));
+        assert!(matches!(
+            Mnemonic::from_entropy(&[0u8; 15]),
+            Err(Error::Key(KeyError::InvalidLength))
+        ));
+    }
+
+    #[test]
+    fn test_debug_hides_words() -> Result<(), Error> {
+        let mnemonic = Mnemonic::parse(ZERO_PHRASE)?;
+        let debug = format!("{:?}", mnemonic);
+        assert!(!debug.contains("abandon"));
+        Ok(())
+    }
+
+    #[cfg(feature = "ecc-default")]
+    #[test]
+    fn test_ed25519_seed_backup() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::ecc::Ed25519;
+
+        let (pk, sk) = Ed25519::generate_keypair()?;
+        let mnemonic = Mnemonic::from_entropy(&sk.to_raw_bytes()?)?;
+        assert_eq!(mnemonic.word_count(), 24);
+
+        let restored = Mnemonic::parse(&mnemonic.phrase())?;
+        let restored_sk =
+            <Ed25519 as AsymmetricKeySet>::PrivateKey::from_raw_bytes(&restored.to_entropy())?;
+        let signature = Ed25519::sign(&restored_sk, b"backup")?;
+        Ed25519::verify(&pk, b"backup", &signature)
+    }
+
+    #[cfg(feature = "hd-default")]
+    #[test]
+    fn test_hd_master_from_seed() -> Result<(), Error> {
+        use crate::systems::hd::slip10::Bip32PrivateKey;
+
+        let seed = Mnemonic::parse(ZERO_PHRASE)?.to_seed("");
+        let master = Bip32PrivateKey::from_seed(seed.as_slice())?;
+        let xpub = master.extended_public_key()?;
+        assert_eq!(
+            hex::encode(xpub.as_bytes()),
+            "03d902f35f560e0470c63313c7369168d9d7df2d49bf295fd9fb7cb109ccee0494"
+        );
+        assert_eq!(
+            hex::encode(xpub.chain_code()),
+            "7923408dadd3c7b56eed15567707ae5e5dca089de972e07f3b860450e2a3b70e"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,446 @@
+//! Provides a compact, algorithm-tagged CBOR encoding for keys, signatures and ciphertexts.
+//!
+//! A [`TaggedObject`] binds raw key or message bytes to the [`Algorithm::ID`] they belong to
+//! and to their kind, so a decoder cannot mistake an Ed25519 public key for a P-256 one, or a
+//! signature for a ciphertext. It is encoded as a four-element CBOR array (RFC 8949):
+//!
+//! ```text
+//! [format_version: uint, kind: uint, algorithm_id: uint, bytes: bstr]
+//! ```
+//!
+//! Encoding follows the core deterministic encoding rules of RFC 8949 §4.2.1, so a given
+//! object always has exactly one encoding. Decoding is strict and rejects non-shortest
+//! integers, indefinite lengths, unknown format versions or kinds, and trailing data.
+//!
+//! Ciphertexts and envelopes, such as the output of an AEAD or of a `KeyRing`, are tagged with
+//! the ID of the scheme that produced them.
+//!
+//! 提供了一种紧凑的、带算法标签的 CBOR 编码，用于密钥、签名和密文。
+//!
+//! [`TaggedObject`] 将原始密钥或消息字节与其所属的 [`Algorithm::ID`] 及其种类绑定，使解码方
+//! 不会把 Ed25519 公钥误认为 P-256 公钥，或把签名误认为密文。它被编码为一个包含四个元素的
+//! CBOR 数组 (RFC 8949)：
+//!
+//! ```text
+//! [format_version: uint, kind: uint, algorithm_id: uint, bytes: bstr]
+//! ```
+//!
+//! 编码遵循 RFC 8949 §4.2.1 的核心确定性编码规则，因此给定对象始终只有一种编码。
+//! 解码是严格的，会拒绝非最短形式的整数、不定长度、未知的格式版本或种类以及尾随数据。
+//!
+//! 密文和信封（例如 AEAD 或 `KeyRing` 的输出）使用生成它们的方案的 ID 进行标记。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+use crate::prelude::*;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+/// The format version written by [`TaggedObject::to_cbor`].
+///
+/// [`TaggedObject::to_cbor`] 写入的格式版本。
+pub const FORMAT_VERSION: u64 = 1;
+
+const MAJOR_UINT: u8 = 0;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_ARRAY: u8 = 4;
+
+fn invalid_encoding() -> Error {
+    Error::Key(KeyError::InvalidEncoding)
+}
+
+/// The kind of data held by a [`TaggedObject`].
+///
+/// [`TaggedObject`] 所持有数据的种类。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum ObjectKind {
+    /// A public key.
+    ///
+    /// 公钥。
+    PublicKey = 1,
+    /// A private key.
+    ///
+    /// 私钥。
+    PrivateKey = 2,
+    /// A symmetric key.
+    ///
+    /// 对称密钥。
+    SymmetricKey = 3,
+    /// A signature or MAC tag.
+    ///
+    /// 签名或 MAC 标签。
+    Signature = 4,
+    /// A ciphertext or encrypted envelope.
+    ///
+    /// 密文或加密信封。
+    Ciphertext = 5,
+    /// A KEM encapsulated key.
+    ///
+    /// KEM 封装密钥。
+    EncapsulatedKey = 6,
+}
+
+impl ObjectKind {
+    fn from_code(code: u64) -> Result<Self, Error> {
+        Ok(match code {
+            1 => Self::PublicKey,
+            2 => Self::PrivateKey,
+            3 => Self::SymmetricKey,
+            4 => Self::Signature,
+            5 => Self::Ciphertext,
+            6 => Self::EncapsulatedKey,
+            _ => return Err(invalid_encoding()),
+        })
+    }
+}
+
+/// Key or message bytes tagged with their kind and algorithm ID.
+///
+/// 带有种类和算法 ID 标签的密钥或消息字节。
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaggedObject {
+    kind: ObjectKind,
+    algorithm: u32,
+    #[serde(with = "crate::traits::key::serde_bytes")]
+    bytes: Zeroizing<Vec<u8>>,
+}
+
+impl TaggedObject {
+    /// Tags `bytes` with `kind` and the algorithm ID `algorithm`.
+    ///
+    /// 使用 `kind` 和算法 ID `algorithm` 标记 `bytes`。
+    pub fn new(kind: ObjectKind, algorithm: u32, bytes: &[u8]) -> Self {
+        Self {
+            kind,
+            algorithm,
+            bytes: Zeroizing::new(bytes.to_vec()),
+        }
+    }
+
+    /// Tags a public key of the scheme `S`.
+    ///
+    /// 标记方案 `S` 的公钥。
+    pub fn public_key<S: AsymmetricKeySet>(key: &S::PublicKey) -> Result<Self, Error> {
+        let bytes = key.to_bytes()?;
+        Ok(Self::new(ObjectKind::PublicKey, S::ID, &bytes))
+    }
+
+    /// Tags a private key of the scheme `S`.
+    ///
+    /// 标记方案 `S` 的私钥。
+    pub fn private_key<S: AsymmetricKeySet>(key: &S::PrivateKey) -> Result<Self, Error> {
+        let bytes = Zeroizing::new(key.to_bytes()?);
+        Ok(Self::new(ObjectKind::PrivateKey, S::ID, &bytes))
+    }
+
+    /// Tags a symmetric key of the scheme `A`.
+    ///
+    /// 标记方案 `A` 的对称密钥。
+    pub fn symmetric_key<A: Algorithm>(key: &SymmetricKey) -> Self {
+        Self::new(ObjectKind::SymmetricKey, A::ID, key)
+    }
+
+    /// Tags a signature or MAC tag produced by the scheme `S`.
+    ///
+    /// 标记方案 `S` 生成的签名或 MAC 标签。
+    pub fn signature<S: Algorithm>(signature: &[u8]) -> Self {
+        Self::new(ObjectKind::Signature, S::ID, signature)
+    }
+
+    /// Tags a ciphertext or envelope produced by the scheme `A`.
+    ///
+    /// 标记方案 `A` 生成的密文或信封。
+    pub fn ciphertext<A: Algorithm>(ciphertext: &[u8]) -> Self {
+        Self::new(ObjectKind::Ciphertext, A::ID, ciphertext)
+    }
+
+    /// Tags an encapsulated key produced by the KEM `S`.
+    ///
+    /// 标记 KEM `S` 生成的封装密钥。
+    pub fn encapsulated_key<S: Algorithm>(encapsulated_key: &[u8]) -> Self {
+        Self::new(ObjectKind::EncapsulatedKey, S::ID, encapsulated_key)
+    }
+
+    /// Returns the kind of the tagged data.
+    ///
+    /// 返回被标记数据的种类。
+    pub fn kind(&self) -> ObjectKind {
+        self.kind
+    }
+
+    /// Returns the algorithm ID.
+    ///
+    /// 返回算法 ID。
+    pub fn algorithm_id(&self) -> u32 {
+        self.algorithm
+    }
+
+    /// Returns the tagged bytes.
+    ///
+    /// 返回被标记的字节。
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn expect(&self, kind: ObjectKind, algorithm: u32) -> Result<&[u8], Error> {
+        if self.kind != kind {
+            return Err(invalid_encoding());
+        }
+        if self.algorithm != algorithm {
+            return Err(Error::Key(KeyError::UnsupportedAlgorithm));
+        }
+        Ok(&self.bytes)
+    }
+
+    /// Returns the public key if this object holds one of the scheme `S`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the object holds another kind of data, and with
+    /// `KeyError::UnsupportedAlgorithm` if it belongs to a different algorithm.
+    ///
+    /// 如果此对象持有方案 `S` 的公钥，则返回该公钥。
+    ///
+    /// 如果对象持有其他种类的数据，则以 `KeyError::InvalidEncoding` 失败；如果它属于不同的
+    /// 算法，则以 `KeyError::UnsupportedAlgorithm` 失败。
+    pub fn to_public_key<S: AsymmetricKeySet>(&self) -> Result<S::PublicKey, Error> {
+        S::PublicKey::from_bytes(self.expect(ObjectKind::PublicKey, S::ID)?)
+    }
+
+    /// Returns the private key if this object holds one of the scheme `S`.
+    ///
+    /// 如果此对象持有方案 `S` 的私钥，则返回该私钥。
+    pub fn to_private_key<S: AsymmetricKeySet>(&self) -> Result<S::PrivateKey, Error> {
+        S::PrivateKey::from_bytes(self.expect(ObjectKind::PrivateKey, S::ID)?)
+    }
+
+    /// Returns the symmetric key if this object holds one of the scheme `A`.
+    ///
+    /// 如果此对象持有方案 `A` 的对称密钥，则返回该密钥。
+    pub fn to_symmetric_key<A: Algorithm>(&self) -> Result<SymmetricKey, Error> {
+        SymmetricKey::from_bytes(self.expect(ObjectKind::SymmetricKey, A::ID)?)
+    }
+
+    /// Returns the signature if this object holds one produced by the scheme `S`.
+    ///
+    /// 如果此对象持有方案 `S` 生成的签名，则返回该签名。
+    pub fn to_signature<S: Algorithm>(&self) -> Result<Signature, Error> {
+        Ok(self.expect(ObjectKind::Signature, S::ID)?.to_vec())
+    }
+
+    /// Returns the ciphertext if this object holds one produced by the scheme `A`.
+    ///
+    /// 如果此对象持有方案 `A` 生成的密文，则返回该密文。
+    pub fn to_ciphertext<A: Algorithm>(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.expect(ObjectKind::Ciphertext, A::ID)?.to_vec())
+    }
+
+    /// Returns the encapsulated key if this object holds one produced by the KEM `S`.
+    ///
+    /// 如果此对象持有 KEM `S` 生成的封装密钥，则返回该封装密钥。
+    pub fn to_encapsulated_key<S: Algorithm>(&self) -> Result<EncapsulatedKey, Error> {
+        Ok(self.expect(ObjectKind::EncapsulatedKey, S::ID)?.to_vec())
+    }
+
+    /// Encodes the object as deterministic CBOR.
+    ///
+    /// 将对象编码为确定性 CBOR。
+    pub fn to_cbor(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(Vec::with_capacity(self.bytes.len() + 16));
+        put_head(&mut out, MAJOR_ARRAY, 4);
+        put_head(&mut out, MAJOR_UINT, FORMAT_VERSION);
+        put_head(&mut out, MAJOR_UINT, self.kind as u64);
+        put_head(&mut out, MAJOR_UINT, u64::from(self.algorithm));
+        put_head(&mut out, MAJOR_BYTES, self.bytes.len() as u64);
+        out.extend_from_slice(&self.bytes);
+        out
+    }
+
+    /// Decodes an object produced by [`Self::to_cbor`].
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if `cbor` is not exactly one deterministically
+    /// encoded object of a supported format version.
+    ///
+    /// 解码由 [`Self::to_cbor`] 生成的对象。
+    ///
+    /// 如果 `cbor` 不是恰好一个以确定性方式编码、格式版本受支持的对象，则以
+    /// `KeyError::InvalidEncoding` 失败。
+    pub fn from_cbor(cbor: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader(cbor);
+        if reader.head(MAJOR_ARRAY)? != 4 || reader.head(MAJOR_UINT)? != FORMAT_VERSION {
+            return Err(invalid_encoding());
+        }
+        let kind = ObjectKind::from_code(reader.head(MAJOR_UINT)?)?;
+        let algorithm = u32::try_from(reader.head(MAJOR_UINT)?).map_err(|_| invalid_encoding())?;
+        let len = usize::try_from(reader.head(MAJOR_BYTES)?).map_err(|_| invalid_encoding())?;
+        let bytes = reader.take(len)?;
+        if !reader.0.is_empty() {
+            return Err(invalid_encoding());
+        }
+        Ok(Self::new(kind, algorithm, bytes))
+    }
+}
+
+impl fmt::Debug for TaggedObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedObject")
+            .field("kind", &self.kind)
+            .field("algorithm", &format_args!("{:#010x}", self.algorithm))
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
+// ------------------- CBOR Primitives -------------------
+// ------------------- CBOR 原语 -------------------
+
+/// Writes a CBOR data item head in its shortest form.
+///
+/// 以最短形式写入 CBOR 数据项头部。
+fn put_head(out: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    if value < 24 {
+        out.push(major | value as u8);
+    } else if let Ok(v) = u8::try_from(value) {
+        out.extend_from_slice(&[major | 24, v]);
+    } else if let Ok(v) = u16::try_from(value) {
+        out.push(major | 25);
+        out.extend_from_slice(&v.to_be_bytes());
+    } else if let Ok(v) = u32::try_from(value) {
+        out.push(major | 26);
+        out.extend_from_slice(&v.to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < len {
+            return Err(invalid_encoding());
+        }
+        let (head, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    /// Reads a data item head of the `major` type, rejecting non-shortest and indefinite forms.
+    ///
+    /// 读取 `major` 类型的数据项头部，拒绝非最短形式和不定长度形式。
+    fn head(&mut self, major: u8) -> Result<u64, Error> {
+        let initial = self.take(1)?[0];
+        if initial >> 5 != major {
+            return Err(invalid_encoding());
+        }
+        let (value, min) = match initial & 0x1f {
+            info @ 0..24 => return Ok(u64::from(info)),
+            24 => (u64::from(self.take(1)?[0]), 24),
+            25 => (be_uint(self.take(2)?), 1 << 8),
+            26 => (be_uint(self.take(4)?), 1 << 16),
+            27 => (be_uint(self.take(8)?), 1 << 32),
+            _ => return Err(invalid_encoding()),
+        };
+        if value < min {
+            return Err(invalid_encoding());
+        }
+        Ok(value)
+    }
+}
+
+fn be_uint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, &b| (acc << 8) | u64::from(b))
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_encoding() -> Result<(), Error> {
+        let object = TaggedObject::new(ObjectKind::Signature, 0x0102_0304, &[0xaa, 0xbb]);
+        let cbor = object.to_cbor();
+        assert_eq!(hex::encode(&*cbor), "8401041a0102030442aabb");
+        assert_eq!(TaggedObject::from_cbor(&cbor)?, object);
+
+        let long = TaggedObject::new(ObjectKind::Ciphertext, 23, &[7; 300]);
+        let cbor = long.to_cbor();
+        assert_eq!(hex::encode(&cbor[..7]), "8401051759012c");
+        assert_eq!(TaggedObject::from_cbor(&cbor)?, long);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_non_canonical_input() {
+        for bad in [
+            // Non-shortest version, indefinite-length array and byte string.
+            // 非最短形式的版本、不定长度数组和字节串。
+            "84180104010100",
+            "9f0104014040ff",
+            "840104015f40ff",
+            // Unknown version and kind, wrong array length, truncation, trailing data.
+            // 未知版本和种类、错误的数组长度、截断、尾随数据。
+            "8402040140",
+            "8401070140",
+            "83010401",
+            "8401040142aa",
+            "840104014000",
+            // Algorithm ID wider than 32 bits.
+            // 超过 32 位的算法 ID。
+            "8401041b000000010000000040",
+        ] {
+            let cbor = hex::decode(bad).unwrap_or_default();
+            assert!(
+                matches!(
+                    TaggedObject::from_cbor(&cbor),
+                    Err(Error::Key(KeyError::InvalidEncoding))
+                ),
+                "{bad}"
+            );
+        }
+    }
+
+    #[cfg(feature = "ecc-default")]
+    #[test]
+    fn test_key_and_signature_binding() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+
+        let (pk, sk) = Ed25519::generate_keypair()?;
+        let signature = Ed25519::sign(&sk, b"tagged")?;
+
+        let pk_cbor = TaggedObject::public_key::<Ed25519>(&pk)?.to_cbor();
+        let sk_cbor = TaggedObject::private_key::<Ed25519>(&sk)?.to_cbor();
+        let sig_cbor = TaggedObject::signature::<Ed25519>(&signature).to_cbor();
+
+        let pk2 = TaggedObject::from_cbor(&pk_cbor)?.to_public_key::<Ed25519>()?;
+        let sk2 = TaggedObject::from_cbor(&sk_cbor)?.to_private_key::<Ed25519>()?;
+        let sig2 = TaggedObject::from_cbor(&sig_cbor)?.to_signature::<Ed25519>()?;
+        Ed25519::verify(&pk2, b"tagged", &Ed25519::sign(&sk2, b"tagged")?)?;
+        Ed25519::verify(&pk, b"tagged", &sig2)?;
+
+        let tagged = TaggedObject::from_cbor(&pk_cbor)?;
+        assert!(matches!(
+            tagged.to_public_key::<EcdsaP256>(),
+            Err(Error::Key(KeyError::UnsupportedAlgorithm))
+        ));
+        assert!(matches!(
+            tagged.to_signature::<Ed25519>(),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_roundtrip() -> Result<(), Error> {
+        let object = TaggedObject::new(ObjectKind::SymmetricKey, 0x0201_0103, &[1; 32]);
+        let json = serde_json::to_string(&object).map_err(|_| invalid_encoding())?;
+        let decoded: TaggedObject = serde_json::from_str(&json).map_err(|_| invalid_encoding())?;
+        assert_eq!(decoded, object);
+        Ok(())
+    }
+}
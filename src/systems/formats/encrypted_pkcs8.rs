@@ -0,0 +1,329 @@
+//! Provides password-protected PKCS#8 private keys.
+//!
+//! An `EncryptedPrivateKeyInfo` (RFC 5208, RFC 5958) wraps a PKCS#8 private key encrypted
+//! with PBES2 (RFC 8018): a key is derived from the password with scrypt or PBKDF2 and
+//! encrypts the key with AES-256-CBC. This is the format OpenSSL writes for
+//! `openssl pkcs8 -topk8 -v2 aes-256-cbc`, so keys exported here can be read with e.g.
+//! `openssl pkey -inform DER -in key.der`.
+//!
+//! The key derivation is chosen by passing a scrypt or PBKDF2 scheme from
+//! [`crate::systems::kdf`], whose cost parameters are stored with the key. A random salt and
+//! IV are generated for every export.
+//!
+//! # Supported Keys
+//! Private keys stored as PKCS#8: ECDSA and EdDSA, ECDH and RSA keys.
+//!
+//! 提供了受密码保护的 PKCS#8 私钥。
+//!
+//! `EncryptedPrivateKeyInfo` (RFC 5208, RFC 5958) 包装了一个使用 PBES2 (RFC 8018) 加密的
+//! PKCS#8 私钥：使用 scrypt 或 PBKDF2 从密码派生密钥，并用其以 AES-256-CBC 加密私钥。
+//! 这是 OpenSSL 为 `openssl pkcs8 -topk8 -v2 aes-256-cbc` 写出的格式，因此这里导出的密钥可以用
+//! 例如 `openssl pkey -inform DER -in key.der` 读取。
+//!
+//! 通过传入 [`crate::systems::kdf`] 中的 scrypt 或 PBKDF2 方案来选择密钥派生方式，其成本参数
+//! 会与密钥一同存储。每次导出都会生成随机的盐和 IV。
+//!
+//! # 支持的密钥
+//! 以 PKCS#8 存储的私钥：ECDSA 和 EdDSA、ECDH 以及 RSA 密钥。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+use crate::prelude::*;
+use pkcs8::pkcs5::pbes2;
+use pkcs8::{EncryptedPrivateKeyInfo, PrivateKeyInfo};
+use secrecy::{ExposeSecret, SecretBox};
+use zeroize::Zeroizing;
+
+const SALT_SIZE: usize = 16;
+const IV_SIZE: usize = 16;
+
+// ------------------- Key Derivation -------------------
+// ------------------- 密钥派生 -------------------
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {
+        fn pbes2_kdf<'a>(&self, salt: &'a [u8]) -> Result<pbes2::Kdf<'a>, Error>;
+    }
+}
+
+/// A password-based KDF that can protect a PKCS#8 private key under PBES2.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 可在 PBES2 下保护 PKCS#8 私钥的基于密码的 KDF。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait Pbes2Kdf: private::Sealed {}
+
+#[cfg(feature = "scrypt-default")]
+impl private::Sealed for crate::systems::kdf::scrypt::ScryptScheme {
+    fn pbes2_kdf<'a>(&self, salt: &'a [u8]) -> Result<pbes2::Kdf<'a>, Error> {
+        pbes2::ScryptParams::from_params_and_salt(self.params()?, salt)
+            .map(Into::into)
+            .map_err(|_| Error::Kdf(KdfError::InvalidParameter))
+    }
+}
+
+#[cfg(feature = "scrypt-default")]
+impl Pbes2Kdf for crate::systems::kdf::scrypt::ScryptScheme {}
+
+#[cfg(feature = "pbkdf2-default")]
+impl<H: Hasher> private::Sealed for crate::systems::kdf::pbkdf2::Pbkdf2Scheme<H> {
+    fn pbes2_kdf<'a>(&self, salt: &'a [u8]) -> Result<pbes2::Kdf<'a>, Error> {
+        // PBES2 only names the HMAC-SHA-1 and HMAC-SHA-2 PRFs
+        let prf = match H::MULTIHASH_CODE {
+            0x12 => pbes2::Pbkdf2Prf::HmacWithSha256,
+            0x20 => pbes2::Pbkdf2Prf::HmacWithSha384,
+            0x13 => pbes2::Pbkdf2Prf::HmacWithSha512,
+            _ => return Err(Error::Kdf(KdfError::InvalidParameter)),
+        };
+        if self.iterations == 0 || self.iterations > pbes2::Pbkdf2Params::MAX_ITERATION_COUNT {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        Ok(pbes2::Pbkdf2Params {
+            salt,
+            iteration_count: self.iterations,
+            key_length: None,
+            prf,
+        }
+        .into())
+    }
+}
+
+#[cfg(feature = "pbkdf2-default")]
+impl<H: Hasher> Pbes2Kdf for crate::systems::kdf::pbkdf2::Pbkdf2Scheme<H> {}
+
+/// Calls `f` with PBES2 parameters for `kdf` and AES-256-CBC, using a fresh random salt and IV.
+///
+/// 使用 `kdf` 和 AES-256-CBC 的 PBES2 参数调用 `f`，其中使用新生成的随机盐和 IV。
+pub(crate) fn with_random_pbes2_params<K: Pbes2Kdf, T>(
+    kdf: &K,
+    f: impl FnOnce(pbes2::Parameters<'_>) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let mut salt = [0u8; SALT_SIZE];
+    let mut iv = [0u8; IV_SIZE];
+    crate::rng::fill_nonce(&mut salt).map_err(|_| Error::Kdf(KdfError::SaltGenerationFailed))?;
+    crate::rng::fill_nonce(&mut iv).map_err(|_| Error::Kdf(KdfError::SaltGenerationFailed))?;
+    f(pbes2::Parameters {
+        kdf: private::Sealed::pbes2_kdf(kdf, &salt)?,
+        encryption: pbes2::EncryptionScheme::Aes256Cbc { iv: &iv },
+    })
+}
+
+// ------------------- Key Support -------------------
+// ------------------- 密钥支持 -------------------
+
+/// A private key that can be exported to and imported from an encrypted PKCS#8 document.
+///
+/// 可以导出为加密 PKCS#8 文档以及从中导入的私钥。
+pub trait EncryptedPkcs8: Key {
+    /// Encrypts the key under `password` and returns the DER-encoded
+    /// `EncryptedPrivateKeyInfo`.
+    ///
+    /// # Arguments
+    /// * `password` - The password protecting the key.
+    /// * `kdf` - The scrypt or PBKDF2 scheme deriving the encryption key from the password.
+    ///
+    /// Fails with `KdfError::InvalidParameter` or `KdfError::MemoryLimitExceeded` if the KDF
+    /// parameters cannot be used, and with `KdfError::SaltGenerationFailed` if no randomness
+    /// is available.
+    ///
+    /// 使用 `password` 加密密钥，并返回 DER 编码的 `EncryptedPrivateKeyInfo`。
+    ///
+    /// # 参数
+    /// * `password` - 保护密钥的密码。
+    /// * `kdf` - 从密码派生加密密钥的 scrypt 或 PBKDF2 方案。
+    ///
+    /// 如果 KDF 参数无法使用，则以 `KdfError::InvalidParameter` 或
+    /// `KdfError::MemoryLimitExceeded` 失败；如果无法获得随机数，则以
+    /// `KdfError::SaltGenerationFailed` 失败。
+    fn to_encrypted_pkcs8<K: Pbes2Kdf>(
+        &self,
+        password: &SecretBox<[u8]>,
+        kdf: &K,
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let der = Zeroizing::new(self.to_bytes()?);
+        let info =
+            PrivateKeyInfo::try_from(der.as_slice()).map_err(|_| KeyError::InvalidEncoding)?;
+
+        let document = with_random_pbes2_params(kdf, |params| {
+            info.encrypt_with_params(params, password.expose_secret())
+                .map_err(|_| Error::Kdf(KdfError::DerivationFailed))
+        })?;
+        Ok(Zeroizing::new(document.as_bytes().to_vec()))
+    }
+
+    /// Decrypts a DER-encoded `EncryptedPrivateKeyInfo` with `password`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the document is malformed or does not hold a
+    /// key of this type, and with `KdfError::VerificationFailed` if it cannot be decrypted
+    /// with `password`.
+    ///
+    /// 使用 `password` 解密 DER 编码的 `EncryptedPrivateKeyInfo`。
+    ///
+    /// With the `fips` feature, documents protected by anything other than PBKDF2 and AES-CBC
+    /// fail with `PolicyError::AlgorithmDenied`.
+    ///
+    /// 如果文档格式错误或不包含此类型的密钥，则以 `KeyError::InvalidEncoding` 失败；
+    /// 如果无法使用 `password` 解密，则以 `KdfError::VerificationFailed` 失败。
+    /// 启用 `fips` 特性时，由 PBKDF2 和 AES-CBC 以外的算法保护的文档以
+    /// `PolicyError::AlgorithmDenied` 失败。
+    fn from_encrypted_pkcs8(der: &[u8], password: &SecretBox<[u8]>) -> Result<Self, Error> {
+        let info = EncryptedPrivateKeyInfo::try_from(der).map_err(|_| KeyError::InvalidEncoding)?;
+        #[cfg(feature = "fips")]
+        if !info.encryption_algorithm.pbes2().is_some_and(|params| {
+            params.kdf.is_pbkdf2()
+                && matches!(
+                    params.encryption,
+                    pbes2::EncryptionScheme::Aes128Cbc { .. }
+                        | pbes2::EncryptionScheme::Aes192Cbc { .. }
+                        | pbes2::EncryptionScheme::Aes256Cbc { .. }
+                )
+        }) {
+            return Err(crate::policy::PolicyError::AlgorithmDenied.into());
+        }
+        let document = info
+            .decrypt(password.expose_secret())
+            .map_err(|_| Error::Kdf(KdfError::VerificationFailed))?;
+        Self::from_bytes(document.as_bytes())
+    }
+}
+
+#[cfg(feature = "ecc-default")]
+impl<P: crate::systems::asymmetric::traditional::ecc::EccParams> EncryptedPkcs8
+    for crate::systems::asymmetric::traditional::ecc::EccPrivateKey<P>
+{
+}
+
+#[cfg(feature = "ecdh-default")]
+impl<P: crate::systems::asymmetric::traditional::ecdh::EcdhParams> EncryptedPkcs8
+    for crate::systems::asymmetric::traditional::ecdh::EcdhPrivateKey<P>
+{
+}
+
+#[cfg(feature = "rsa-default")]
+impl EncryptedPkcs8 for crate::systems::asymmetric::traditional::rsa::RsaPrivateKey {}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(all(test, feature = "ecc-default"))]
+mod tests {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+
+    fn password(value: &[u8]) -> SecretBox<[u8]> {
+        SecretBox::new(Box::from(value))
+    }
+
+    // A P-256 key encrypted by `openssl pkcs8 -topk8 -v2 aes-256-cbc` with the password
+    // "correct horse", and its plaintext PKCS#8 encoding.
+    const PLAINTEXT: &str = "308187020100301306072a8648ce3d020106082a8648ce3d030107046d306b0201010420a7da4f3b66997d519d21cdbca73adc12359fe37e58365f4e3bc2a5f01a1bfbe6a144034200045f25af744e9845a4e4249a6158444bcb9aeb0ac2380f65a8b9a036a73c2a5a08d702c8d5a5ba1b2ec463f74d145af5cb2c8cfa27876b3c553dc42713485ecc1b";
+
+    #[test]
+    #[cfg(feature = "scrypt-default")]
+    fn test_encrypted_pkcs8_scrypt() {
+        use crate::systems::kdf::scrypt::ScryptScheme;
+
+        let (_, sk) = EcdsaP256::generate_keypair().unwrap();
+        let kdf = ScryptScheme::new(1 << 10, 8, 1);
+        let der = sk
+            .to_encrypted_pkcs8(&password(b"correct horse"), &kdf)
+            .unwrap();
+        let decrypted = <EcdsaP256 as AsymmetricKeySet>::PrivateKey::from_encrypted_pkcs8(
+            &der,
+            &password(b"correct horse"),
+        )
+        .unwrap();
+        assert_eq!(decrypted.to_bytes().unwrap(), sk.to_bytes().unwrap());
+
+        // A fresh salt and IV are used for every export
+        let again = sk
+            .to_encrypted_pkcs8(&password(b"correct horse"), &kdf)
+            .unwrap();
+        assert_ne!(der, again);
+
+        assert_eq!(
+            <EcdsaP256 as AsymmetricKeySet>::PrivateKey::from_encrypted_pkcs8(
+                &der,
+                &password(b"battery staple"),
+            )
+            .unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        assert!(
+            <Ed25519 as AsymmetricKeySet>::PrivateKey::from_encrypted_pkcs8(
+                &der,
+                &password(b"correct horse"),
+            )
+            .is_err()
+        );
+        assert_eq!(
+            sk.to_encrypted_pkcs8(&password(b"pw"), &ScryptScheme::new(1000, 8, 1))
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+
+        // OpenSSL `-scrypt -scrypt_N 1024 -scrypt_r 8 -scrypt_p 1`
+        let openssl = hex::decode("3081ec305706092a864886f70d01050d304a302906092b06010401da47040b301c041079152b3e7ef7a2d6d563fbb64a69f60702020400020108020101301d060960864801650304012a0410dd93ff3bee9940cc960cb4aabd3628510481904fe3837ee1b54a4a60415412063dc478a2d85c05a9b8a4b2195bf695d235f5a794225cdca1993f276e184eae8d54144c6927793b5771515498442054992c85ae5d46f1dc20367e54eddf2db65222dcad630effa83cfb6c8923974778f578c256a4d3ec6e313711423bd3cb5e1ed34d6ee06d73b3aef7b7c011066029f89862af7fb66347faea693fe7e90687f0d71456").unwrap();
+        let sk = <EcdsaP256 as AsymmetricKeySet>::PrivateKey::from_encrypted_pkcs8(
+            &openssl,
+            &password(b"correct horse"),
+        )
+        .unwrap();
+        assert_eq!(hex::encode(sk.to_bytes().unwrap()), PLAINTEXT);
+    }
+
+    #[test]
+    #[cfg(feature = "pbkdf2-default")]
+    fn test_encrypted_pkcs8_pbkdf2() {
+        use crate::systems::kdf::pbkdf2::{Pbkdf2Sha256, Pbkdf2Sha512};
+
+        let (_, sk) = Ed25519::generate_keypair().unwrap();
+        for der in [
+            sk.to_encrypted_pkcs8(&password(b"pw"), &Pbkdf2Sha256::new(1000))
+                .unwrap(),
+            sk.to_encrypted_pkcs8(&password(b"pw"), &Pbkdf2Sha512::new(1000))
+                .unwrap(),
+        ] {
+            let decrypted = <Ed25519 as AsymmetricKeySet>::PrivateKey::from_encrypted_pkcs8(
+                &der,
+                &password(b"pw"),
+            )
+            .unwrap();
+            assert_eq!(decrypted.to_bytes().unwrap(), sk.to_bytes().unwrap());
+        }
+        assert_eq!(
+            sk.to_encrypted_pkcs8(&password(b"pw"), &Pbkdf2Sha256::new(0))
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+
+        // OpenSSL `-v2prf hmacWithSHA256 -iter 1000`
+        let openssl = hex::decode("3081f4305f06092a864886f70d01050d3052303106092a864886f70d01050c302404109b75ef1b28a7fd9d30b119f541a050bf020203e8300c06082a864886f70d02090500301d060960864801650304012a0410a3007b870fafbb4d534bf99c87d3fa9704819057fdbbea3df07b1cb0411eae54b1ee4d965493324cd410921e488f57e5765261cddbd0ec0373446ec59e38237ebdc96105e41199cd3d69facb3cf30bf757ee5d487c95ce3b0853acf63314311291fdbb4309bd4e09bc7aa28a7413d850776e7f202ae63a38fdda135272ccc539b34ecd8d45ac4bdd4d0de77608ef3ff17bbf9f0d5b11cd2aabaedc9d38579ef71d7e61").unwrap();
+        let sk = <EcdsaP256 as AsymmetricKeySet>::PrivateKey::from_encrypted_pkcs8(
+            &openssl,
+            &password(b"correct horse"),
+        )
+        .unwrap();
+        assert_eq!(hex::encode(sk.to_bytes().unwrap()), PLAINTEXT);
+    }
+
+    #[test]
+    #[cfg(all(feature = "rsa-default", feature = "pbkdf2-default"))]
+    fn test_encrypted_pkcs8_rsa() {
+        use crate::systems::asymmetric::traditional::rsa::Rsa2048;
+        use crate::systems::kdf::pbkdf2::Pbkdf2Sha256;
+
+        let (_, sk) = Rsa2048::<Sha256>::generate_keypair().unwrap();
+        let der = sk
+            .to_encrypted_pkcs8(&password(b"pw"), &Pbkdf2Sha256::new(1000))
+            .unwrap();
+        let decrypted = <Rsa2048<Sha256> as AsymmetricKeySet>::PrivateKey::from_encrypted_pkcs8(
+            &der,
+            &password(b"pw"),
+        )
+        .unwrap();
+        assert_eq!(decrypted.to_bytes().unwrap(), sk.to_bytes().unwrap());
+    }
+}
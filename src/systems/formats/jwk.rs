@@ -0,0 +1,902 @@
+//! Provides JSON Web Key (JWK) import and export.
+//!
+//! A JWK (RFC 7517) is a JSON object describing one key, and a JWK Set is an object with a
+//! `keys` array of them; OpenID Connect providers publish their signing keys this way.
+//! [`Jwk`] and [`JwkSet`] implement `serde`'s `Serialize` and `Deserialize`, so they can be
+//! read and written with `serde_json` or any other serde format. Key material is stored as
+//! unpadded base64url strings, as RFC 7518 requires.
+//!
+//! # Supported Keys
+//! - **RSA** (`"kty": "RSA"`): `n`, `e` and, for private keys, `d`, `p`, `q`, `dp`, `dq`, `qi`
+//! - **EC** (`"kty": "EC"`): ECDSA and ECDH keys on `P-256`, ECDSA keys on `secp256k1`
+//! - **OKP** (`"kty": "OKP"`, RFC 8037): Ed25519 keys
+//! - **oct** (`"kty": "oct"`): symmetric keys
+//!
+//! X25519 keys are not supported because the crate has no X25519 scheme.
+//!
+//! # Thumbprints
+//! [`Jwk::thumbprint`] computes the RFC 7638 thumbprint: the hash of the key's required
+//! members in canonical JSON form. It identifies a key independently of optional members
+//! such as `kid`, and its base64url encoding is commonly used as the `kid` itself.
+//!
+//! 提供了 JSON Web Key (JWK) 的导入和导出。
+//!
+//! JWK (RFC 7517) 是描述一个密钥的 JSON 对象，JWK Set 则是包含这些对象的 `keys` 数组的对象；
+//! OpenID Connect 提供方以这种方式发布其签名密钥。[`Jwk`] 和 [`JwkSet`] 实现了 `serde` 的
+//! `Serialize` 和 `Deserialize`，因此可以使用 `serde_json` 或任何其他 serde 格式读写它们。
+//! 按照 RFC 7518 的要求，密钥材料以无填充的 base64url 字符串存储。
+//!
+//! # 支持的密钥
+//! - **RSA** (`"kty": "RSA"`)：`n`、`e`，私钥还包括 `d`、`p`、`q`、`dp`、`dq`、`qi`
+//! - **EC** (`"kty": "EC"`)：`P-256` 上的 ECDSA 和 ECDH 密钥，`secp256k1` 上的 ECDSA 密钥
+//! - **OKP** (`"kty": "OKP"`，RFC 8037)：Ed25519 密钥
+//! - **oct** (`"kty": "oct"`)：对称密钥
+//!
+//! 由于本 crate 没有 X25519 方案，因此不支持 X25519 密钥。
+//!
+//! # 指纹
+//! [`Jwk::thumbprint`] 计算 RFC 7638 指纹：以规范 JSON 形式表示的密钥必需成员的哈希。
+//! 它独立于 `kid` 等可选成员来标识密钥，其 base64url 编码通常直接用作 `kid`。
+
+#[cfg(feature = "rsa-default")]
+use alloc::vec;
+#[cfg(feature = "sha2")]
+use alloc::format;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::errors::Error;
+use crate::prelude::*;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use core::fmt;
+use zeroize::Zeroizing;
+
+#[cfg(any(feature = "ecc-default", feature = "ecdh-default"))]
+use elliptic_curve::{
+    AffinePoint, CurveArithmetic, FieldBytesSize,
+    pkcs8::{AssociatedOid, DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey},
+    sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+};
+
+// ------------------- JWK Objects -------------------
+// ------------------- JWK 对象 -------------------
+
+/// A JSON Web Key.
+///
+/// Private members are zeroized on drop and are not shown by `Debug`.
+///
+/// JSON Web Key。
+///
+/// 私有成员在销毁时被清零，并且不会由 `Debug` 显示。
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Jwk {
+    kty: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    alg: Option<String>,
+    #[serde(rename = "use", default, skip_serializing_if = "Option::is_none")]
+    key_use: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    crv: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    x: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    y: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    n: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    e: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    d: Option<Zeroizing<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    p: Option<Zeroizing<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    q: Option<Zeroizing<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dp: Option<Zeroizing<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dq: Option<Zeroizing<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    qi: Option<Zeroizing<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    k: Option<Zeroizing<String>>,
+}
+
+impl Jwk {
+    /// Creates an `oct` JWK holding the symmetric key `key`.
+    ///
+    /// 创建持有对称密钥 `key` 的 `oct` JWK。
+    pub fn from_symmetric_key(key: &[u8]) -> Self {
+        Self {
+            kty: "oct".to_string(),
+            k: Some(encode_secret(key)),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the symmetric key of an `oct` JWK.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if this is not an `oct` JWK or `k` is malformed.
+    ///
+    /// 返回 `oct` JWK 的对称密钥。
+    ///
+    /// 如果这不是 `oct` JWK 或 `k` 格式错误，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn to_symmetric_key(&self) -> Result<SymmetricKey, Error> {
+        self.expect_kty("oct", None)?;
+        secret_member(&self.k).map(SymmetricKey::from)
+    }
+
+    /// Sets the key ID (`kid`).
+    ///
+    /// 设置密钥 ID (`kid`)。
+    pub fn with_kid(mut self, kid: &str) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+
+    /// Sets the intended algorithm (`alg`), e.g. `"ES256"`.
+    ///
+    /// 设置预期的算法 (`alg`)，例如 `"ES256"`。
+    pub fn with_alg(mut self, alg: &str) -> Self {
+        self.alg = Some(alg.to_string());
+        self
+    }
+
+    /// Sets the intended use (`use`), `"sig"` or `"enc"`.
+    ///
+    /// 设置预期的用途 (`use`)，即 `"sig"` 或 `"enc"`。
+    pub fn with_use(mut self, key_use: &str) -> Self {
+        self.key_use = Some(key_use.to_string());
+        self
+    }
+
+    /// Returns the key type (`kty`).
+    ///
+    /// 返回密钥类型 (`kty`)。
+    pub fn kty(&self) -> &str {
+        &self.kty
+    }
+
+    /// Returns the curve (`crv`) of an EC or OKP key.
+    ///
+    /// 返回 EC 或 OKP 密钥的曲线 (`crv`)。
+    pub fn crv(&self) -> Option<&str> {
+        self.crv.as_deref()
+    }
+
+    /// Returns the key ID (`kid`).
+    ///
+    /// 返回密钥 ID (`kid`)。
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Returns the intended algorithm (`alg`).
+    ///
+    /// 返回预期的算法 (`alg`)。
+    pub fn alg(&self) -> Option<&str> {
+        self.alg.as_deref()
+    }
+
+    /// Returns the intended use (`use`).
+    ///
+    /// 返回预期的用途 (`use`)。
+    pub fn key_use(&self) -> Option<&str> {
+        self.key_use.as_deref()
+    }
+
+    /// Returns whether the JWK contains private key material.
+    ///
+    /// 返回 JWK 是否包含私钥材料。
+    pub fn is_private(&self) -> bool {
+        self.d.is_some() || self.k.is_some()
+    }
+
+    /// Returns a copy of the JWK without its private members.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` for `oct` keys, which have no public part.
+    ///
+    /// 返回不含私有成员的 JWK 副本。
+    ///
+    /// 对于没有公开部分的 `oct` 密钥，以 `KeyError::InvalidEncoding` 失败。
+    pub fn to_public(&self) -> Result<Self, Error> {
+        if self.kty == "oct" {
+            return Err(Error::Key(KeyError::InvalidEncoding));
+        }
+        Ok(Self {
+            d: None,
+            p: None,
+            q: None,
+            dp: None,
+            dq: None,
+            qi: None,
+            k: None,
+            ..self.clone()
+        })
+    }
+
+    /// Computes the RFC 7638 thumbprint of the key with the hash function `H`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the key type is unknown or a required member
+    /// is missing.
+    ///
+    /// 使用哈希函数 `H` 计算密钥的 RFC 7638 指纹。
+    ///
+    /// 如果密钥类型未知或缺少必需成员，则以 `KeyError::InvalidEncoding` 失败。
+    #[cfg(feature = "sha2")]
+    pub fn thumbprint<H: Hasher>(&self) -> Result<Vec<u8>, Error> {
+        let k = self.k.as_ref().map(|k| k.as_str());
+        // Required members, in lexicographic order
+        let members: &[(&str, Option<&str>)] = match self.kty.as_str() {
+            "EC" => &[
+                ("crv", self.crv.as_deref()),
+                ("kty", Some("EC")),
+                ("x", self.x.as_deref()),
+                ("y", self.y.as_deref()),
+            ],
+            "OKP" => &[
+                ("crv", self.crv.as_deref()),
+                ("kty", Some("OKP")),
+                ("x", self.x.as_deref()),
+            ],
+            "RSA" => &[
+                ("e", self.e.as_deref()),
+                ("kty", Some("RSA")),
+                ("n", self.n.as_deref()),
+            ],
+            "oct" => &[("k", k), ("kty", Some("oct"))],
+            _ => return Err(Error::Key(KeyError::InvalidEncoding)),
+        };
+
+        let mut json = Zeroizing::new(String::from("{"));
+        for (i, (name, value)) in members.iter().enumerate() {
+            let value = value.ok_or(Error::Key(KeyError::InvalidEncoding))?;
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(&format!("\"{name}\":"));
+            push_json_string(&mut json, value);
+        }
+        json.push('}');
+        Ok(H::hash(json.as_bytes()))
+    }
+
+    fn expect_kty(&self, kty: &str, crv: Option<&str>) -> Result<(), Error> {
+        if self.kty != kty || self.crv.as_deref() != crv {
+            return Err(Error::Key(KeyError::InvalidEncoding));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Jwk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("kid", &self.kid)
+            .field("crv", &self.crv)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A JWK Set: a list of keys, serialized as `{"keys": [...]}`.
+///
+/// JWK Set：密钥列表，序列化为 `{"keys": [...]}`。
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    /// Creates a JWK Set from `keys`.
+    ///
+    /// 由 `keys` 创建 JWK Set。
+    pub fn new(keys: Vec<Jwk>) -> Self {
+        Self { keys }
+    }
+
+    /// Returns the keys of the set.
+    ///
+    /// 返回集合中的密钥。
+    pub fn keys(&self) -> &[Jwk] {
+        &self.keys
+    }
+
+    /// Adds `key` to the set.
+    ///
+    /// 将 `key` 添加到集合中。
+    pub fn push(&mut self, key: Jwk) {
+        self.keys.push(key);
+    }
+
+    /// Returns the first key whose `kid` is `kid`.
+    ///
+    /// 返回第一个 `kid` 为 `kid` 的密钥。
+    pub fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter().find(|key| key.kid() == Some(kid))
+    }
+}
+
+// ------------------- Encoding Helpers -------------------
+// ------------------- 编码辅助函数 -------------------
+
+#[cfg(any(
+    feature = "ecc-default",
+    feature = "ecdh-default",
+    feature = "rsa-default"
+))]
+fn encode(bytes: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn encode_secret(bytes: &[u8]) -> Zeroizing<String> {
+    Zeroizing::new(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+#[cfg(any(
+    feature = "ecc-default",
+    feature = "ecdh-default",
+    feature = "rsa-default"
+))]
+fn member(value: &Option<String>) -> Result<Vec<u8>, Error> {
+    value
+        .as_ref()
+        .and_then(|value| URL_SAFE_NO_PAD.decode(value).ok())
+        .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+fn secret_member(value: &Option<Zeroizing<String>>) -> Result<Zeroizing<Vec<u8>>, Error> {
+    value
+        .as_ref()
+        .and_then(|value| URL_SAFE_NO_PAD.decode(value.as_str()).ok())
+        .map(Zeroizing::new)
+        .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+/// Appends `value` to `json` as a JSON string literal.
+///
+/// 将 `value` 作为 JSON 字符串字面量追加到 `json`。
+#[cfg(feature = "sha2")]
+fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+// ------------------- Key Support -------------------
+// ------------------- 密钥支持 -------------------
+
+/// An asymmetric scheme whose keys can be converted to and from JWKs.
+///
+/// 其密钥可以与 JWK 相互转换的非对称方案。
+pub trait JwkScheme: AsymmetricKeySet {
+    /// Exports a public key as a JWK.
+    ///
+    /// 将公钥导出为 JWK。
+    fn public_key_to_jwk(public_key: &Self::PublicKey) -> Result<Jwk, Error>;
+
+    /// Exports a private key as a JWK, including the public members.
+    ///
+    /// 将私钥导出为 JWK，其中包括公开成员。
+    fn private_key_to_jwk(private_key: &Self::PrivateKey) -> Result<Jwk, Error>;
+
+    /// Imports a public key from a JWK, ignoring any private members.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the key type or curve does not match the
+    /// scheme or the key material is invalid.
+    ///
+    /// 从 JWK 导入公钥，忽略任何私有成员。
+    ///
+    /// 如果密钥类型或曲线与方案不符，或者密钥材料无效，则以 `KeyError::InvalidEncoding` 失败。
+    fn public_key_from_jwk(jwk: &Jwk) -> Result<Self::PublicKey, Error>;
+
+    /// Imports a private key from a JWK, checking that it matches the public members.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the key type or curve does not match the
+    /// scheme, the key material is invalid or the private and public members disagree.
+    ///
+    /// 从 JWK 导入私钥，并检查其与公开成员是否一致。
+    ///
+    /// 如果密钥类型或曲线与方案不符、密钥材料无效或私有成员与公开成员不一致，则以
+    /// `KeyError::InvalidEncoding` 失败。
+    fn private_key_from_jwk(jwk: &Jwk) -> Result<Self::PrivateKey, Error>;
+}
+
+#[cfg(any(feature = "ecc-default", feature = "ecdh-default"))]
+fn ec_public_jwk<C>(crv: &str, public_key: &elliptic_curve::PublicKey<C>) -> Jwk
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let point = public_key.to_encoded_point(false);
+    Jwk {
+        kty: "EC".to_string(),
+        crv: Some(crv.to_string()),
+        x: point.x().map(|x| encode(x)),
+        y: point.y().map(|y| encode(y)),
+        ..Jwk::default()
+    }
+}
+
+#[cfg(any(feature = "ecc-default", feature = "ecdh-default"))]
+fn ec_public_key<C>(crv: &str, jwk: &Jwk) -> Result<elliptic_curve::PublicKey<C>, Error>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    jwk.expect_kty("EC", Some(crv))?;
+    let (x, y) = (member(&jwk.x)?, member(&jwk.y)?);
+    if x.len() != y.len() {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    let sec1 = [&[0x04][..], &x, &y].concat();
+    elliptic_curve::PublicKey::<C>::from_sec1_bytes(&sec1)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
+#[cfg(any(feature = "ecc-default", feature = "ecdh-default"))]
+fn ec_to_jwk<C, K: Key>(crv: &str, key: &K, private: bool) -> Result<Jwk, Error>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let der = Zeroizing::new(key.to_bytes()?);
+    if !private {
+        let public_key = elliptic_curve::PublicKey::<C>::from_public_key_der(&der)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        return Ok(ec_public_jwk(crv, &public_key));
+    }
+    let secret_key = elliptic_curve::SecretKey::<C>::from_pkcs8_der(&der)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    Ok(Jwk {
+        d: Some(encode_secret(&secret_key.to_bytes())),
+        ..ec_public_jwk(crv, &secret_key.public_key())
+    })
+}
+
+#[cfg(any(feature = "ecc-default", feature = "ecdh-default"))]
+fn ec_from_jwk<C, K: Key>(crv: &str, jwk: &Jwk, private: bool) -> Result<K, Error>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let public_key = ec_public_key::<C>(crv, jwk)?;
+    if !private {
+        let der = public_key
+            .to_public_key_der()
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        return K::from_bytes(der.as_bytes());
+    }
+    let d = secret_member(&jwk.d)?;
+    if Some(d.len()) != member(&jwk.x).ok().map(|x| x.len()) {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    let secret_key = elliptic_curve::SecretKey::<C>::from_slice(&d)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    if secret_key.public_key() != public_key {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    let der = secret_key
+        .to_pkcs8_der()
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    K::from_bytes(der.as_bytes())
+}
+
+#[cfg(feature = "ecc-default")]
+mod ecc {
+    use super::*;
+    #[cfg(not(feature = "fips"))]
+    use crate::systems::asymmetric::traditional::ecc::EcdsaSecp256k1;
+    use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+    use ed25519_dalek::{SigningKey, VerifyingKey};
+
+    impl JwkScheme for EcdsaP256 {
+        fn public_key_to_jwk(public_key: &Self::PublicKey) -> Result<Jwk, Error> {
+            ec_to_jwk::<p256::NistP256, _>("P-256", public_key, false)
+        }
+
+        fn private_key_to_jwk(private_key: &Self::PrivateKey) -> Result<Jwk, Error> {
+            ec_to_jwk::<p256::NistP256, _>("P-256", private_key, true)
+        }
+
+        fn public_key_from_jwk(jwk: &Jwk) -> Result<Self::PublicKey, Error> {
+            ec_from_jwk::<p256::NistP256, _>("P-256", jwk, false)
+        }
+
+        fn private_key_from_jwk(jwk: &Jwk) -> Result<Self::PrivateKey, Error> {
+            ec_from_jwk::<p256::NistP256, _>("P-256", jwk, true)
+        }
+    }
+
+    #[cfg(not(feature = "fips"))]
+    impl JwkScheme for EcdsaSecp256k1 {
+        fn public_key_to_jwk(public_key: &Self::PublicKey) -> Result<Jwk, Error> {
+            ec_to_jwk::<k256::Secp256k1, _>("secp256k1", public_key, false)
+        }
+
+        fn private_key_to_jwk(private_key: &Self::PrivateKey) -> Result<Jwk, Error> {
+            ec_to_jwk::<k256::Secp256k1, _>("secp256k1", private_key, true)
+        }
+
+        fn public_key_from_jwk(jwk: &Jwk) -> Result<Self::PublicKey, Error> {
+            ec_from_jwk::<k256::Secp256k1, _>("secp256k1", jwk, false)
+        }
+
+        fn private_key_from_jwk(jwk: &Jwk) -> Result<Self::PrivateKey, Error> {
+            ec_from_jwk::<k256::Secp256k1, _>("secp256k1", jwk, true)
+        }
+    }
+
+    fn okp_public_jwk(public_key: &VerifyingKey) -> Jwk {
+        Jwk {
+            kty: "OKP".to_string(),
+            crv: Some("Ed25519".to_string()),
+            x: Some(encode(public_key.as_bytes())),
+            ..Jwk::default()
+        }
+    }
+
+    fn okp_public_key(jwk: &Jwk) -> Result<VerifyingKey, Error> {
+        jwk.expect_kty("OKP", Some("Ed25519"))?;
+        VerifyingKey::try_from(member(&jwk.x)?.as_slice())
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    impl JwkScheme for Ed25519 {
+        fn public_key_to_jwk(public_key: &Self::PublicKey) -> Result<Jwk, Error> {
+            VerifyingKey::from_public_key_der(&public_key.to_bytes()?)
+                .map(|key| okp_public_jwk(&key))
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+        }
+
+        fn private_key_to_jwk(private_key: &Self::PrivateKey) -> Result<Jwk, Error> {
+            let der = Zeroizing::new(private_key.to_bytes()?);
+            let signing_key = SigningKey::from_pkcs8_der(&der)
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            Ok(Jwk {
+                d: Some(encode_secret(signing_key.as_bytes())),
+                ..okp_public_jwk(&signing_key.verifying_key())
+            })
+        }
+
+        fn public_key_from_jwk(jwk: &Jwk) -> Result<Self::PublicKey, Error> {
+            let der = okp_public_key(jwk)?
+                .to_public_key_der()
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            Self::PublicKey::from_bytes(der.as_bytes())
+        }
+
+        fn private_key_from_jwk(jwk: &Jwk) -> Result<Self::PrivateKey, Error> {
+            let public_key = okp_public_key(jwk)?;
+            let d = secret_member(&jwk.d)?;
+            let seed = <&[u8; 32]>::try_from(d.as_slice())
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            let signing_key = SigningKey::from_bytes(seed);
+            if signing_key.verifying_key() != public_key {
+                return Err(Error::Key(KeyError::InvalidEncoding));
+            }
+            let der = signing_key
+                .to_pkcs8_der()
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            Self::PrivateKey::from_bytes(der.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "ecdh-default")]
+impl JwkScheme for crate::systems::asymmetric::traditional::ecdh::EcdhP256 {
+    fn public_key_to_jwk(public_key: &Self::PublicKey) -> Result<Jwk, Error> {
+        ec_to_jwk::<p256::NistP256, _>("P-256", public_key, false)
+    }
+
+    fn private_key_to_jwk(private_key: &Self::PrivateKey) -> Result<Jwk, Error> {
+        ec_to_jwk::<p256::NistP256, _>("P-256", private_key, true)
+    }
+
+    fn public_key_from_jwk(jwk: &Jwk) -> Result<Self::PublicKey, Error> {
+        ec_from_jwk::<p256::NistP256, _>("P-256", jwk, false)
+    }
+
+    fn private_key_from_jwk(jwk: &Jwk) -> Result<Self::PrivateKey, Error> {
+        ec_from_jwk::<p256::NistP256, _>("P-256", jwk, true)
+    }
+}
+
+#[cfg(feature = "rsa-default")]
+mod rsa_keys {
+    use super::*;
+    use crate::systems::asymmetric::traditional::rsa::{
+        RsaKeyParams, RsaPrivateKey, RsaPublicKey, RsaScheme,
+    };
+    use rsa::BigUint;
+    use rsa::pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey};
+    use rsa::traits::{PrivateKeyParts, PublicKeyParts};
+
+    fn rsa_public_jwk(key: &impl PublicKeyParts) -> Jwk {
+        Jwk {
+            kty: "RSA".to_string(),
+            n: Some(encode(&key.n().to_bytes_be())),
+            e: Some(encode(&key.e().to_bytes_be())),
+            ..Jwk::default()
+        }
+    }
+
+    fn uint(value: &Option<String>) -> Result<BigUint, Error> {
+        member(value).map(|bytes| BigUint::from_bytes_be(&bytes))
+    }
+
+    fn secret_uint(value: &Option<Zeroizing<String>>) -> Result<BigUint, Error> {
+        secret_member(value).map(|bytes| BigUint::from_bytes_be(&bytes))
+    }
+
+    impl<KP: RsaKeyParams, H: Hasher> JwkScheme for RsaScheme<KP, H> {
+        fn public_key_to_jwk(public_key: &Self::PublicKey) -> Result<Jwk, Error> {
+            Ok(rsa_public_jwk(public_key.inner()))
+        }
+
+        fn private_key_to_jwk(private_key: &Self::PrivateKey) -> Result<Jwk, Error> {
+            let mut key = rsa::RsaPrivateKey::from_pkcs8_der(private_key.inner())
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            key.precompute()
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            let (Some(dp), Some(dq), Some(qi), [p, q]) =
+                (key.dp(), key.dq(), key.crt_coefficient(), key.primes())
+            else {
+                return Err(Error::Key(KeyError::InvalidEncoding));
+            };
+            Ok(Jwk {
+                d: Some(encode_secret(&key.d().to_bytes_be())),
+                p: Some(encode_secret(&p.to_bytes_be())),
+                q: Some(encode_secret(&q.to_bytes_be())),
+                dp: Some(encode_secret(&dp.to_bytes_be())),
+                dq: Some(encode_secret(&dq.to_bytes_be())),
+                qi: Some(encode_secret(&qi.to_bytes_be())),
+                ..rsa_public_jwk(&key)
+            })
+        }
+
+        fn public_key_from_jwk(jwk: &Jwk) -> Result<Self::PublicKey, Error> {
+            jwk.expect_kty("RSA", None)?;
+            let key = rsa::RsaPublicKey::new(uint(&jwk.n)?, uint(&jwk.e)?)
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            let der = key
+                .to_public_key_der()
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            RsaPublicKey::from_bytes(der.as_bytes())
+        }
+
+        fn private_key_from_jwk(jwk: &Jwk) -> Result<Self::PrivateKey, Error> {
+            jwk.expect_kty("RSA", None)?;
+            // The primes are optional; without them they are recovered from `d`
+            let primes = match (&jwk.p, &jwk.q) {
+                (Some(_), Some(_)) => vec![secret_uint(&jwk.p)?, secret_uint(&jwk.q)?],
+                (None, None) => Vec::new(),
+                _ => return Err(Error::Key(KeyError::InvalidEncoding)),
+            };
+            let key = rsa::RsaPrivateKey::from_components(
+                uint(&jwk.n)?,
+                uint(&jwk.e)?,
+                secret_uint(&jwk.d)?,
+                primes,
+            )
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            let der = key
+                .to_pkcs8_der()
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            RsaPrivateKey::from_bytes(der.as_bytes())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric_jwk() {
+        let jwk = Jwk::from_symmetric_key(b"0123456789abcdef").with_kid("k1");
+        let json = serde_json::to_string(&jwk).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kty":"oct","kid":"k1","k":"MDEyMzQ1Njc4OWFiY2RlZg"}"#
+        );
+
+        let parsed: Jwk = serde_json::from_str(&json).unwrap();
+        assert!(parsed.is_private());
+        assert_eq!(
+            parsed.to_symmetric_key().unwrap().as_slice(),
+            b"0123456789abcdef"
+        );
+        assert_eq!(
+            parsed.to_public().unwrap_err(),
+            Error::Key(KeyError::InvalidEncoding)
+        );
+        assert!(!format!("{parsed:?}").contains("MDEy"));
+    }
+
+    #[test]
+    fn test_jwk_set() {
+        let json = r#"{"keys":[
+            {"kty":"oct","kid":"a","k":"AAEC"},
+            {"kty":"EC","kid":"b","use":"sig","alg":"ES256","crv":"P-256","x":"AA","y":"AA"}
+        ]}"#;
+        let mut set: JwkSet = serde_json::from_str(json).unwrap();
+        assert_eq!(set.keys().len(), 2);
+        let key = set.find("b").unwrap();
+        assert_eq!(key.kty(), "EC");
+        assert_eq!(key.crv(), Some("P-256"));
+        assert_eq!(key.alg(), Some("ES256"));
+        assert_eq!(key.key_use(), Some("sig"));
+        assert!(!key.is_private());
+        assert!(set.find("c").is_none());
+
+        set.push(Jwk::from_symmetric_key(&[0; 16]).with_kid("c"));
+        assert!(set.find("c").is_some());
+        assert!(JwkSet::new(Vec::new()).keys().is_empty());
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_thumbprint() {
+        // RFC 7638 section 3.1
+        let jwk: Jwk = serde_json::from_str(
+            r#"{"kty":"RSA","alg":"RS256","kid":"2011-04-29","e":"AQAB",
+            "n":"0vx7agoebGcQSuuPiLJXZptN9nndrQmbXEps2aiAFbWhM78LhWx4cbbfAAtVT86zwu1RK7aPFFxuhDR1L6tSoc_BJECPebWKRXjBZCiFV4n3oknjhMstn64tZ_2W-5JsGY4Hc5n9yBXArwl93lqt7_RN5w6Cf0h4QyQ5v-65YGjQR0_FDW2QvzqY368QQMicAtaSqzs8KJZgnYb9c7d0zgdAZHzu6qMQvRL5hajrn1n91CbOpbISD08qNLyrdkt-bFTWhAI4vMQFh6WeZu0fM4lFd2NcRwr3XPksINHaQ-G_xBniIqbw0Ls1jF44-csFCur-kEgU8awapJzKnqDKgw"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            encode(&jwk.thumbprint::<Sha256>().unwrap()),
+            "NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs"
+        );
+
+        // RFC 8037 appendix A.3
+        let jwk: Jwk = serde_json::from_str(
+            r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            encode(&jwk.thumbprint::<Sha256>().unwrap()),
+            "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k"
+        );
+
+        let jwk: Jwk = serde_json::from_str(r#"{"kty":"EC","crv":"P-256","x":"AA"}"#).unwrap();
+        assert_eq!(
+            jwk.thumbprint::<Sha256>().unwrap_err(),
+            Error::Key(KeyError::InvalidEncoding)
+        );
+    }
+
+    #[cfg(any(
+        feature = "ecc-default",
+        feature = "ecdh-default",
+        feature = "rsa-default"
+    ))]
+    fn round_trip<S: JwkScheme + KeyGenerator>() {
+        let (pk, sk) = S::generate_keypair().unwrap();
+        let public = serde_json::to_string(&S::public_key_to_jwk(&pk).unwrap()).unwrap();
+        let private = serde_json::to_string(&S::private_key_to_jwk(&sk).unwrap()).unwrap();
+
+        let public: Jwk = serde_json::from_str(&public).unwrap();
+        let private: Jwk = serde_json::from_str(&private).unwrap();
+        assert!(!public.is_private());
+        assert!(private.is_private());
+        assert_eq!(
+            serde_json::to_string(&private.to_public().unwrap()).unwrap(),
+            serde_json::to_string(&public).unwrap()
+        );
+        assert_eq!(
+            S::public_key_from_jwk(&public).unwrap().to_bytes().unwrap(),
+            pk.to_bytes().unwrap()
+        );
+        assert_eq!(
+            S::public_key_from_jwk(&private)
+                .unwrap()
+                .to_bytes()
+                .unwrap(),
+            pk.to_bytes().unwrap()
+        );
+        assert_eq!(
+            S::private_key_from_jwk(&private)
+                .unwrap()
+                .to_bytes()
+                .unwrap(),
+            sk.to_bytes().unwrap()
+        );
+        assert!(S::private_key_from_jwk(&public).is_err());
+        assert!(S::public_key_from_jwk(&Jwk::from_symmetric_key(&[0; 32])).is_err());
+
+        // A private key that does not match the public members is rejected
+        let (_, other) = S::generate_keypair().unwrap();
+        let other = S::private_key_to_jwk(&other).unwrap();
+        let mismatched = Jwk {
+            d: other.d.clone(),
+            ..private.clone()
+        };
+        assert!(S::private_key_from_jwk(&mismatched).is_err());
+    }
+
+    #[cfg(feature = "ecc-default")]
+    #[test]
+    fn test_ecc_jwk() {
+        #[cfg(not(feature = "fips"))]
+        use crate::systems::asymmetric::traditional::ecc::EcdsaSecp256k1;
+        use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+
+        round_trip::<EcdsaP256>();
+        #[cfg(not(feature = "fips"))]
+        round_trip::<EcdsaSecp256k1>();
+        round_trip::<Ed25519>();
+
+        // RFC 8037 appendix A.1
+        let jwk: Jwk = serde_json::from_str(
+            r#"{"kty":"OKP","crv":"Ed25519",
+            "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+            "x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+        )
+        .unwrap();
+        let sk = Ed25519::private_key_from_jwk(&jwk).unwrap();
+        let exported = Ed25519::private_key_to_jwk(&sk).unwrap();
+        assert_eq!(
+            serde_json::to_string(&exported).unwrap(),
+            serde_json::to_string(&jwk).unwrap()
+        );
+
+        let p256 = EcdsaP256::public_key_to_jwk(&EcdsaP256::generate_keypair().unwrap().0).unwrap();
+        #[cfg(not(feature = "fips"))]
+        assert!(EcdsaSecp256k1::public_key_from_jwk(&p256).is_err());
+        assert!(Ed25519::public_key_from_jwk(&p256).is_err());
+    }
+
+    #[cfg(feature = "ecdh-default")]
+    #[test]
+    fn test_ecdh_jwk() {
+        use crate::systems::asymmetric::traditional::ecdh::EcdhP256;
+
+        round_trip::<EcdhP256>();
+    }
+
+    #[cfg(feature = "rsa-default")]
+    #[test]
+    fn test_rsa_jwk() {
+        use crate::systems::asymmetric::traditional::rsa::Rsa2048;
+
+        round_trip::<Rsa2048<Sha256>>();
+
+        // The primes are optional and recovered from the private exponent
+        let (_, sk) = Rsa2048::<Sha256>::generate_keypair().unwrap();
+        let jwk = Rsa2048::<Sha256>::private_key_to_jwk(&sk).unwrap();
+        let minimal = Jwk {
+            p: None,
+            q: None,
+            dp: None,
+            dq: None,
+            qi: None,
+            ..jwk.clone()
+        };
+        // The recovered primes may come out in either order, so compare the exported members
+        let recovered = Rsa2048::<Sha256>::private_key_from_jwk(&minimal).unwrap();
+        let recovered = Rsa2048::<Sha256>::private_key_to_jwk(&recovered).unwrap();
+        assert_eq!(
+            serde_json::to_string(&recovered.to_public().unwrap()).unwrap(),
+            serde_json::to_string(&jwk.to_public().unwrap()).unwrap()
+        );
+        assert_eq!(recovered.d, jwk.d);
+        let partial = Jwk { q: None, ..jwk };
+        assert!(Rsa2048::<Sha256>::private_key_from_jwk(&partial).is_err());
+    }
+}
@@ -0,0 +1,295 @@
+//! Provides the PHC string format for storing password hashes.
+//!
+//! A PHC string records everything needed to verify a password: the algorithm, its version and
+//! parameters, the salt and the hash, e.g.
+//! `$argon2id$v=19$m=19456,t=2,p=1$c29tZXNhbHQ$...`. Salt and hash are encoded in base64
+//! without padding.
+//!
+//! The password-based KDFs implement [`PasswordHasher`] to produce and check these strings.
+//! [`verify_password`] reads the algorithm from the string and dispatches to the matching
+//! scheme, so stored hashes keep verifying after the default parameters change.
+//!
+//! # Supported Algorithms
+//! - **argon2id**, **argon2i**, **argon2d**: `v=19`, parameters `m`, `t`, `p`
+//! - **pbkdf2-sha256**, **pbkdf2-sha384**, **pbkdf2-sha512**: parameters `i` and optionally `l`
+//! - **scrypt**: parameters `ln`, `r`, `p`
+//!
+//! 提供了用于存储密码哈希的 PHC 字符串格式。
+//!
+//! PHC 字符串记录了验证密码所需的一切：算法、其版本和参数、盐以及哈希，例如
+//! `$argon2id$v=19$m=19456,t=2,p=1$c29tZXNhbHQ$...`。盐和哈希使用不带填充的 base64 编码。
+//!
+//! 基于密码的 KDF 实现了 [`PasswordHasher`] 以生成和检查这些字符串。
+//! [`verify_password`] 从字符串中读取算法并分派到相应的方案，因此在默认参数变化后，
+//! 已存储的哈希仍然可以验证。
+//!
+//! # 支持的算法
+//! - **argon2id**、**argon2i**、**argon2d**: `v=19`，参数 `m`、`t`、`p`
+//! - **pbkdf2-sha256**、**pbkdf2-sha384**、**pbkdf2-sha512**: 参数 `i`，以及可选的 `l`
+//! - **scrypt**: 参数 `ln`、`r`、`p`
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::errors::Error;
+use crate::prelude::*;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD_NO_PAD;
+use secrecy::SecretBox;
+use core::fmt;
+use core::str::FromStr;
+
+/// A parsed PHC string.
+///
+/// 已解析的 PHC 字符串。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhcString {
+    algorithm: String,
+    version: Option<u32>,
+    params: Vec<(String, String)>,
+    salt: Vec<u8>,
+    hash: Vec<u8>,
+}
+
+fn invalid() -> Error {
+    Error::Kdf(KdfError::InvalidPhcString)
+}
+
+fn is_valid_symbol(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+impl PhcString {
+    /// Creates a PHC string from its parts.
+    ///
+    /// 从各组成部分创建 PHC 字符串。
+    pub fn new(
+        algorithm: &str,
+        version: Option<u32>,
+        params: &[(&str, String)],
+        salt: &[u8],
+        hash: &[u8],
+    ) -> Self {
+        Self {
+            algorithm: algorithm.to_string(),
+            version,
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+            salt: salt.to_vec(),
+            hash: hash.to_vec(),
+        }
+    }
+
+    /// Returns the algorithm identifier, e.g. `argon2id`.
+    ///
+    /// 返回算法标识符，例如 `argon2id`。
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// Returns the algorithm version, if present.
+    ///
+    /// 返回算法版本（如果存在）。
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    /// Returns the value of the named parameter, if present.
+    ///
+    /// 返回指定参数的值（如果存在）。
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the named parameter as a `u32`, failing if it is missing or not a number.
+    ///
+    /// 以 `u32` 形式返回指定参数；如果缺失或不是数字则失败。
+    pub fn param_u32(&self, name: &str) -> Result<u32, Error> {
+        self.param(name)
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(invalid)
+    }
+
+    /// Returns the salt.
+    ///
+    /// 返回盐。
+    pub fn salt(&self) -> &[u8] {
+        &self.salt
+    }
+
+    /// Returns the hash.
+    ///
+    /// 返回哈希。
+    pub fn hash(&self) -> &[u8] {
+        &self.hash
+    }
+}
+
+impl FromStr for PhcString {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s
+            .strip_prefix('$')
+            .ok_or_else(invalid)?
+            .split('$')
+            .peekable();
+
+        let algorithm = fields
+            .next()
+            .filter(|id| is_valid_symbol(id))
+            .ok_or_else(invalid)?;
+
+        let version = match fields.peek().and_then(|field| field.strip_prefix("v=")) {
+            Some(v) => {
+                fields.next();
+                Some(v.parse().map_err(|_| invalid())?)
+            }
+            None => None,
+        };
+
+        let mut params = Vec::new();
+        if let Some(field) = fields.next_if(|field| field.contains('=')) {
+            for pair in field.split(',') {
+                let (name, value) = pair.split_once('=').ok_or_else(invalid)?;
+                if !is_valid_symbol(name) || value.is_empty() {
+                    return Err(invalid());
+                }
+                params.push((name.to_string(), value.to_string()));
+            }
+        }
+
+        let decode = |field: Option<&str>| {
+            field
+                .and_then(|f| STANDARD_NO_PAD.decode(f).ok())
+                .filter(|bytes| !bytes.is_empty())
+                .ok_or_else(invalid)
+        };
+        let salt = decode(fields.next())?;
+        let hash = decode(fields.next())?;
+        if fields.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            algorithm: algorithm.to_string(),
+            version,
+            params,
+            salt,
+            hash,
+        })
+    }
+}
+
+impl fmt::Display for PhcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "${}", self.algorithm)?;
+        if let Some(version) = self.version {
+            write!(f, "$v={}", version)?;
+        }
+        for (i, (name, value)) in self.params.iter().enumerate() {
+            let separator = if i == 0 { '$' } else { ',' };
+            write!(f, "{}{}={}", separator, name, value)?;
+        }
+        write!(
+            f,
+            "${}${}",
+            STANDARD_NO_PAD.encode(&self.salt),
+            STANDARD_NO_PAD.encode(&self.hash)
+        )
+    }
+}
+
+/// Verifies a password against a PHC string produced by any supported algorithm.
+///
+/// Fails with `KdfError::InvalidPhcString` if the string is malformed or its algorithm is not
+/// enabled, and with `KdfError::VerificationFailed` if the password does not match.
+///
+/// 根据任意受支持算法生成的 PHC 字符串验证密码。
+///
+/// 如果字符串格式错误或其算法未启用，则以 `KdfError::InvalidPhcString` 失败；
+/// 如果密码不匹配，则以 `KdfError::VerificationFailed` 失败。
+pub fn verify_password(phc: &str, password: &SecretBox<[u8]>) -> Result<(), Error> {
+    let phc: PhcString = phc.parse()?;
+    match phc.algorithm() {
+        #[cfg(feature = "argon2-default")]
+        "argon2id" => crate::systems::kdf::argon2::Argon2id::verify_password(&phc, password),
+        #[cfg(feature = "argon2-default")]
+        "argon2i" => crate::systems::kdf::argon2::Argon2i::verify_password(&phc, password),
+        #[cfg(feature = "argon2-default")]
+        "argon2d" => crate::systems::kdf::argon2::Argon2d::verify_password(&phc, password),
+        #[cfg(feature = "pbkdf2-default")]
+        "pbkdf2-sha256" => {
+            crate::systems::kdf::pbkdf2::Pbkdf2Sha256::verify_password(&phc, password)
+        }
+        #[cfg(feature = "pbkdf2-default")]
+        "pbkdf2-sha384" => {
+            crate::systems::kdf::pbkdf2::Pbkdf2Sha384::verify_password(&phc, password)
+        }
+        #[cfg(feature = "pbkdf2-default")]
+        "pbkdf2-sha512" => {
+            crate::systems::kdf::pbkdf2::Pbkdf2Sha512::verify_password(&phc, password)
+        }
+        #[cfg(feature = "scrypt-default")]
+        "scrypt" => crate::systems::kdf::scrypt::Scrypt::verify_password(&phc, password),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phc_string_round_trip() {
+        let s = "$argon2id$v=19$m=65536,t=2,p=1$gZiV/M1gPc22ElAH/Jh1Hw$CWOrkoo7oJBQ/iyh7uJ0LO2aLEfrHwTWllSAxT0zRno";
+        let phc: PhcString = s.parse().unwrap();
+        assert_eq!(phc.algorithm(), "argon2id");
+        assert_eq!(phc.version(), Some(19));
+        assert_eq!(phc.param_u32("m").unwrap(), 65536);
+        assert_eq!(phc.param("p"), Some("1"));
+        assert_eq!(phc.salt().len(), 16);
+        assert_eq!(phc.hash().len(), 32);
+        assert_eq!(phc.to_string(), s);
+
+        let phc: PhcString = "$scrypt$c2FsdHNhbHQ$aGFzaA".parse().unwrap();
+        assert_eq!(phc.algorithm(), "scrypt");
+        assert_eq!(phc.version(), None);
+        assert_eq!(phc.to_string(), "$scrypt$c2FsdHNhbHQ$aGFzaA");
+    }
+
+    #[test]
+    fn test_phc_string_rejects_malformed_input() {
+        for s in [
+            "",
+            "argon2id$v=19",
+            "$$c2FsdA$aGFzaA",
+            "$Argon2id$c2FsdA$aGFzaA",
+            "$argon2id$v=x$c2FsdA$aGFzaA",
+            "$argon2id$m=$c2FsdA$aGFzaA",
+            "$argon2id$c2FsdA",
+            "$argon2id$c2FsdA$aGFzaA$extra",
+            "$argon2id$c2FsdA$not base64",
+        ] {
+            assert_eq!(
+                s.parse::<PhcString>().unwrap_err(),
+                Error::Kdf(KdfError::InvalidPhcString),
+                "{s}"
+            );
+        }
+
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        assert_eq!(
+            verify_password("$bcrypt$c2FsdA$aGFzaA", &password).unwrap_err(),
+            Error::Kdf(KdfError::InvalidPhcString)
+        );
+    }
+}
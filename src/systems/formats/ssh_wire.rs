@@ -0,0 +1,94 @@
+//! SSH wire encoding (RFC 4251) shared by the OpenSSH-based formats.
+//!
+//! 由基于 OpenSSH 的格式共享的 SSH 线路编码 (RFC 4251)。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+
+pub(super) fn put_u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_be_bytes());
+}
+
+pub(super) fn put_string(buf: &mut Vec<u8>, value: &[u8]) {
+    put_u32(buf, value.len() as u32);
+    buf.extend_from_slice(value);
+}
+
+/// Encodes an unsigned big-endian integer as an SSH `mpint`.
+///
+/// 将无符号大端整数编码为 SSH `mpint`。
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+pub(super) fn put_mpint(buf: &mut Vec<u8>, value: &[u8]) {
+    let start = value.iter().position(|&b| b != 0).unwrap_or(value.len());
+    let value = &value[start..];
+    if value.first().is_some_and(|&b| b & 0x80 != 0) {
+        put_u32(buf, value.len() as u32 + 1);
+        buf.push(0);
+        buf.extend_from_slice(value);
+    } else {
+        put_string(buf, value);
+    }
+}
+
+/// Decodes an SSH `mpint` into a fixed-width unsigned big-endian integer.
+///
+/// Returns `None` if the value does not fit into `out`.
+///
+/// 将 SSH `mpint` 解码为固定宽度的无符号大端整数。
+///
+/// 如果该值无法放入 `out`，则返回 `None`。
+#[cfg(feature = "ecc-default")]
+pub(super) fn mpint_to_fixed(value: &[u8], out: &mut [u8]) -> Option<()> {
+    let start = value.iter().position(|&b| b != 0).unwrap_or(value.len());
+    let value = &value[start..];
+    let offset = out.len().checked_sub(value.len())?;
+    out[..offset].fill(0);
+    out[offset..].copy_from_slice(value);
+    Some(())
+}
+
+/// A cursor over SSH-encoded data that fails with the format's own error.
+///
+/// 一个遍历 SSH 编码数据的游标，失败时返回所属格式自身的错误。
+pub(super) struct Reader<'a> {
+    data: &'a [u8],
+    error: fn() -> Error,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(data: &'a [u8], error: fn() -> Error) -> Self {
+        Self { data, error }
+    }
+
+    pub(super) fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.data.len() < len {
+            return Err((self.error)());
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    pub(super) fn read_u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub(super) fn read_string(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.read_u32()? as usize;
+        self.take(len)
+    }
+
+    #[cfg(feature = "openssh-default")]
+    pub(super) fn rest(&mut self) -> &'a [u8] {
+        core::mem::take(&mut self.data)
+    }
+
+    pub(super) fn finish(&self) -> Result<(), Error> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err((self.error)())
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Fixed-output hash function implementations.
+//!
+//! This module provides implementations of cryptographic hash functions that produce
+//! a digest of fixed size. Each implementation supports both one-shot hashing and
+//! incremental `update`/`finalize` processing for data that arrives in pieces.
+//!
+//! # Available Implementations
+//! - **SHA-2**: SHA-256, SHA-384 and SHA-512
+//! - **SHA-3**: SHA3-256, SHA3-384 and SHA3-512
+//! - **BLAKE2**: BLAKE2b and BLAKE2s, with keyed, salted and personalized modes
+//! - **BLAKE3**: hash, keyed_hash and derive_key modes with extendable output
+//! - **SM3**: the Chinese national hash standard, used by SM2
+//!
+//! SHA-1 and MD5 are available behind the `insecure-legacy-hashes` feature, only for
+//! verifying legacy data (see `legacy`).
+//!
+//! The [`merkle`] module builds RFC 6962 Merkle trees and inclusion proofs on top of them.
+//!
+//! With the `interop` feature, all of them also implement the RustCrypto `digest::Digest`
+//! trait (see [`interop`]).
+//!
+//! 固定输出哈希函数实现。
+//!
+//! 此模块提供产生固定大小摘要的加密哈希函数的实现。
+//! 每个实现都支持一次性哈希，以及用于分段到达数据的增量式 `update`/`finalize` 处理。
+//!
+//! # 可用实现
+//! - **SHA-2**: SHA-256、SHA-384 和 SHA-512
+//! - **SHA-3**: SHA3-256、SHA3-384 和 SHA3-512
+//! - **BLAKE2**: BLAKE2b 和 BLAKE2s，支持带密钥、加盐和个性化模式
+//! - **BLAKE3**: hash、keyed_hash 和 derive_key 模式，支持可扩展输出
+//! - **SM3**: 中国国家哈希标准，由 SM2 使用
+//!
+//! SHA-1 和 MD5 位于 `insecure-legacy-hashes` 特性之后，仅用于验证旧数据（参见 `legacy`）。
+//!
+//! [`merkle`] 模块在它们之上构建 RFC 6962 Merkle 树和包含证明。
+//!
+//! 启用 `interop` 特性时，它们还都实现了 RustCrypto 的 `digest::Digest` trait（参见 [`interop`]）。
+
+/// SHA-2 family hash function implementation.
+///
+/// SHA-2 系列哈希函数实现。
+#[cfg(feature = "sha2")]
+pub mod sha2;
+
+/// SHA-3 family hash function implementation.
+///
+/// SHA-3 系列哈希函数实现。
+#[cfg(feature = "sha3")]
+pub mod sha3;
+
+/// BLAKE2 family hash function implementation.
+///
+/// BLAKE2 系列哈希函数实现。
+#[cfg(feature = "blake2-default")]
+pub mod blake2;
+
+/// BLAKE3 hash function implementation.
+///
+/// BLAKE3 哈希函数实现。
+#[cfg(feature = "blake3-default")]
+pub mod blake3;
+
+/// SM3 hash function implementation.
+///
+/// SM3 哈希函数实现。
+#[cfg(feature = "sm-default")]
+pub mod sm3;
+
+/// Merkle trees with RFC 6962 inclusion proofs, over any of the hash types above.
+///
+/// 基于上述任意哈希类型、具有 RFC 6962 包含证明的 Merkle 树。
+#[cfg(feature = "insecure-legacy-hashes")]
+pub mod legacy;
+
+pub mod merkle;
+
+/// Implementations of the RustCrypto `digest` traits for the hash types above.
+///
+/// 为上述哈希类型实现的 RustCrypto `digest` trait。
+#[cfg(all(
+    feature = "interop",
+    any(
+        feature = "sha2",
+        feature = "sha3",
+        feature = "blake2-default",
+        feature = "blake3-default"
+    )
+))]
+pub mod interop;
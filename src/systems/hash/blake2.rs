@@ -0,0 +1,412 @@
+//! Provides an implementation of the BLAKE2 family of hash functions.
+//!
+//! BLAKE2 (RFC 7693) is a fast hash function with built-in support for keyed hashing,
+//! which makes it usable directly as a MAC without an HMAC wrapper. It also accepts a salt
+//! and a personalization string for domain separation between applications.
+//!
+//! # Algorithm Variants
+//! - **BLAKE2b-512**: 64-byte digest, optimised for 64-bit platforms
+//! - **BLAKE2b-256**: 32-byte digest, BLAKE2b truncated through its parameter block
+//! - **BLAKE2s-256**: 32-byte digest, optimised for 8- to 32-bit platforms
+//!
+//! # Parameter Limits
+//! | Variant  | Key          | Salt     | Personalization |
+//! |----------|--------------|----------|-----------------|
+//! | BLAKE2b  | 0..=64 bytes | 16 bytes | 16 bytes        |
+//! | BLAKE2s  | 0..=32 bytes | 8 bytes  | 8 bytes         |
+//!
+//! Shorter salts and personalization strings are zero-padded, as specified by RFC 7693.
+//!
+//! 提供了 BLAKE2 系列哈希函数的实现。
+//!
+//! BLAKE2 (RFC 7693) 是一种快速哈希函数，内置对带密钥哈希的支持，
+//! 因此无需 HMAC 包装即可直接用作 MAC。它还接受盐和个性化字符串，用于在应用程序之间进行域分离。
+//!
+//! # 算法变体
+//! - **BLAKE2b-512**: 64 字节摘要，针对 64 位平台优化
+//! - **BLAKE2b-256**: 32 字节摘要，通过参数块截断的 BLAKE2b
+//! - **BLAKE2s-256**: 32 字节摘要，针对 8 至 32 位平台优化
+//!
+//! # 参数限制
+//! | 变体     | 密钥          | 盐      | 个性化字符串 |
+//! |----------|--------------|---------|-------------|
+//! | BLAKE2b  | 0..=64 字节   | 16 字节 | 16 字节      |
+//! | BLAKE2s  | 0..=32 字节   | 8 字节  | 8 字节       |
+//!
+//! 较短的盐和个性化字符串会按照 RFC 7693 的规定用零填充。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::traits::hash::write_digest;
+use crate::{errors::Error, prelude::*};
+
+// ------------------- Marker Structs and Trait for BLAKE2 Parameters -------------------
+// ------------------- 用于 BLAKE2 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines the parameters for a specific BLAKE2 variant.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义特定 BLAKE2 变体参数的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait Blake2Params: private::Sealed + SchemeParams {
+    const OUTPUT_SIZE: usize;
+    const MULTIHASH_CODE: u64;
+    const MAX_KEY_SIZE: usize;
+    const SALT_SIZE: usize;
+    const PERSONAL_SIZE: usize;
+    type State: Clone + Send + Sync + core::fmt::Debug;
+
+    /// Creates a new hash state. The lengths must already have been checked
+    /// against the limits above.
+    ///
+    /// 创建新的哈希状态。长度必须已根据上述限制进行检查。
+    fn new_state(key: &[u8], salt: &[u8], personal: &[u8]) -> Self::State;
+    fn update(state: &mut Self::State, data: &[u8]);
+    fn finalize(state: &Self::State) -> Vec<u8>;
+    fn finalize_into(state: &Self::State, output: &mut [u8]) -> Result<usize, Error>;
+}
+
+fn blake2b_state(
+    output_size: usize,
+    key: &[u8],
+    salt: &[u8],
+    personal: &[u8],
+) -> blake2b_simd::State {
+    blake2b_simd::Params::new()
+        .hash_length(output_size)
+        .key(key)
+        .salt(salt)
+        .personal(personal)
+        .to_state()
+}
+
+/// Marker struct for BLAKE2b-512 parameters.
+///
+/// BLAKE2b-512 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Blake2b512Params;
+impl private::Sealed for Blake2b512Params {}
+impl SchemeParams for Blake2b512Params {
+    const NAME: &'static str = "BLAKE2b-512";
+    const ID: u32 = 0x04_03_00_01;
+}
+impl Blake2Params for Blake2b512Params {
+    const OUTPUT_SIZE: usize = 64;
+    const MULTIHASH_CODE: u64 = 0xb240;
+    const MAX_KEY_SIZE: usize = blake2b_simd::KEYBYTES;
+    const SALT_SIZE: usize = blake2b_simd::SALTBYTES;
+    const PERSONAL_SIZE: usize = blake2b_simd::PERSONALBYTES;
+    type State = blake2b_simd::State;
+
+    fn new_state(key: &[u8], salt: &[u8], personal: &[u8]) -> Self::State {
+        blake2b_state(Self::OUTPUT_SIZE, key, salt, personal)
+    }
+
+    fn update(state: &mut Self::State, data: &[u8]) {
+        state.update(data);
+    }
+
+    fn finalize(state: &Self::State) -> Vec<u8> {
+        state.finalize().as_bytes().to_vec()
+    }
+
+    fn finalize_into(state: &Self::State, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(state.finalize().as_bytes(), output)
+    }
+}
+
+/// Marker struct for BLAKE2b-256 parameters.
+///
+/// BLAKE2b-256 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Blake2b256Params;
+impl private::Sealed for Blake2b256Params {}
+impl SchemeParams for Blake2b256Params {
+    const NAME: &'static str = "BLAKE2b-256";
+    const ID: u32 = 0x04_03_00_02;
+}
+impl Blake2Params for Blake2b256Params {
+    const OUTPUT_SIZE: usize = 32;
+    const MULTIHASH_CODE: u64 = 0xb220;
+    const MAX_KEY_SIZE: usize = blake2b_simd::KEYBYTES;
+    const SALT_SIZE: usize = blake2b_simd::SALTBYTES;
+    const PERSONAL_SIZE: usize = blake2b_simd::PERSONALBYTES;
+    type State = blake2b_simd::State;
+
+    fn new_state(key: &[u8], salt: &[u8], personal: &[u8]) -> Self::State {
+        blake2b_state(Self::OUTPUT_SIZE, key, salt, personal)
+    }
+
+    fn update(state: &mut Self::State, data: &[u8]) {
+        state.update(data);
+    }
+
+    fn finalize(state: &Self::State) -> Vec<u8> {
+        state.finalize().as_bytes().to_vec()
+    }
+
+    fn finalize_into(state: &Self::State, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(state.finalize().as_bytes(), output)
+    }
+}
+
+/// Marker struct for BLAKE2s-256 parameters.
+///
+/// BLAKE2s-256 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Blake2s256Params;
+impl private::Sealed for Blake2s256Params {}
+impl SchemeParams for Blake2s256Params {
+    const NAME: &'static str = "BLAKE2s-256";
+    const ID: u32 = 0x04_03_00_03;
+}
+impl Blake2Params for Blake2s256Params {
+    const OUTPUT_SIZE: usize = 32;
+    const MULTIHASH_CODE: u64 = 0xb260;
+    const MAX_KEY_SIZE: usize = blake2s_simd::KEYBYTES;
+    const SALT_SIZE: usize = blake2s_simd::SALTBYTES;
+    const PERSONAL_SIZE: usize = blake2s_simd::PERSONALBYTES;
+    type State = blake2s_simd::State;
+
+    fn new_state(key: &[u8], salt: &[u8], personal: &[u8]) -> Self::State {
+        blake2s_simd::Params::new()
+            .hash_length(Self::OUTPUT_SIZE)
+            .key(key)
+            .salt(salt)
+            .personal(personal)
+            .to_state()
+    }
+
+    fn update(state: &mut Self::State, data: &[u8]) {
+        state.update(data);
+    }
+
+    fn finalize(state: &Self::State) -> Vec<u8> {
+        state.finalize().as_bytes().to_vec()
+    }
+
+    fn finalize_into(state: &Self::State, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(state.finalize().as_bytes(), output)
+    }
+}
+
+// ------------------- Generic BLAKE2 Implementation -------------------
+// ------------------- 通用 BLAKE2 实现 -------------------
+
+/// A generic struct representing a BLAKE2 hash computation for a given parameter set.
+///
+/// `Default` starts a plain, unkeyed computation. Use [`new_keyed`](Self::new_keyed) for
+/// the keyed MAC mode, or [`with_params`](Self::with_params) to also set a salt and a
+/// personalization string.
+///
+/// 一个通用的 BLAKE2 哈希计算结构体，它在参数集上是通用的。
+///
+/// `Default` 开始一次普通的无密钥计算。使用 [`new_keyed`](Self::new_keyed) 进入带密钥的 MAC 模式，
+/// 或使用 [`with_params`](Self::with_params) 同时设置盐和个性化字符串。
+#[derive(Clone, Debug)]
+pub struct Blake2Scheme<P: Blake2Params> {
+    state: P::State,
+}
+
+impl<P: Blake2Params> Blake2Scheme<P> {
+    /// Starts a keyed BLAKE2 computation (the BLAKE2 MAC mode).
+    ///
+    /// 开始一次带密钥的 BLAKE2 计算（BLAKE2 MAC 模式）。
+    pub fn new_keyed(key: &[u8]) -> Result<Self, Error> {
+        Self::with_params(key, &[], &[])
+    }
+
+    /// Starts a BLAKE2 computation with an optional key (may be empty), salt and
+    /// personalization string.
+    ///
+    /// 使用可选的密钥（可以为空）、盐和个性化字符串开始一次 BLAKE2 计算。
+    pub fn with_params(key: &[u8], salt: &[u8], personalization: &[u8]) -> Result<Self, Error> {
+        if key.len() > P::MAX_KEY_SIZE {
+            return Err(HashError::InvalidKeyLength.into());
+        }
+        if salt.len() > P::SALT_SIZE {
+            return Err(HashError::InvalidSaltLength.into());
+        }
+        if personalization.len() > P::PERSONAL_SIZE {
+            return Err(HashError::InvalidPersonalizationLength.into());
+        }
+        Ok(Self {
+            state: P::new_state(key, salt, personalization),
+        })
+    }
+}
+
+impl<P: Blake2Params> Default for Blake2Scheme<P> {
+    fn default() -> Self {
+        Self {
+            state: P::new_state(&[], &[], &[]),
+        }
+    }
+}
+
+impl<P: Blake2Params> Algorithm for Blake2Scheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: Blake2Params> Parameterized for Blake2Scheme<P> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(P::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<P: Blake2Params> HashFunction for Blake2Scheme<P> {
+    const OUTPUT_SIZE: usize = P::OUTPUT_SIZE;
+
+    fn update(&mut self, data: &[u8]) {
+        P::update(&mut self.state, data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        P::finalize(&self.state)
+    }
+
+    fn finalize_into(self, output: &mut [u8]) -> Result<usize, Error> {
+        P::finalize_into(&self.state, output)
+    }
+}
+
+impl<P: Blake2Params> MultihashCode for Blake2Scheme<P> {
+    const MULTIHASH_CODE: u64 = P::MULTIHASH_CODE;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the BLAKE2b-512 hash function.
+///
+/// BLAKE2b-512 哈希函数的类型别名。
+pub type Blake2b512 = Blake2Scheme<Blake2b512Params>;
+
+/// A type alias for the BLAKE2b-256 hash function.
+///
+/// BLAKE2b-256 哈希函数的类型别名。
+pub type Blake2b256 = Blake2Scheme<Blake2b256Params>;
+
+/// A type alias for the BLAKE2s-256 hash function.
+///
+/// BLAKE2s-256 哈希函数的类型别名。
+pub type Blake2s256 = Blake2Scheme<Blake2s256Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_blake2_test<P: Blake2Params>(expected_abc: &str) {
+        let one_shot = Blake2Scheme::<P>::hash(b"abc");
+        assert_eq!(one_shot.len(), Blake2Scheme::<P>::OUTPUT_SIZE);
+        assert_eq!(hex::encode(&one_shot), expected_abc);
+
+        let mut hasher = Blake2Scheme::<P>::default();
+        hasher.update(b"a");
+        hasher.update(b"bc");
+        assert_eq!(hasher.finalize(), one_shot);
+
+        let mut buffer = [0u8; 64];
+        let written = Blake2Scheme::<P>::hash_into(b"abc", &mut buffer).unwrap();
+        assert_eq!(buffer[..written], one_shot[..]);
+
+        // A keyed hash differs from the plain one, and so does each extra parameter
+        let keyed = Blake2Scheme::<P>::new_keyed(b"key").unwrap();
+        let salted = Blake2Scheme::<P>::with_params(b"key", b"salt", &[]).unwrap();
+        let personal = Blake2Scheme::<P>::with_params(b"key", b"salt", b"app").unwrap();
+        let mut digests = [keyed, salted, personal].map(|mut h| {
+            h.update(b"abc");
+            h.finalize()
+        });
+        digests.sort();
+        assert!(digests.windows(2).all(|w| w[0] != w[1]));
+        assert!(!digests.contains(&one_shot));
+
+        // Over-long parameters are rejected
+        let too_long = vec![0u8; P::MAX_KEY_SIZE + 1];
+        assert_eq!(
+            Blake2Scheme::<P>::new_keyed(&too_long).unwrap_err(),
+            Error::Hash(HashError::InvalidKeyLength)
+        );
+        assert_eq!(
+            Blake2Scheme::<P>::with_params(&[], &too_long[..P::SALT_SIZE + 1], &[]).unwrap_err(),
+            Error::Hash(HashError::InvalidSaltLength)
+        );
+        assert_eq!(
+            Blake2Scheme::<P>::with_params(&[], &[], &too_long[..P::PERSONAL_SIZE + 1])
+                .unwrap_err(),
+            Error::Hash(HashError::InvalidPersonalizationLength)
+        );
+    }
+
+    #[test]
+    fn test_blake2b512() {
+        run_blake2_test::<Blake2b512Params>(
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+        );
+    }
+
+    #[test]
+    fn test_blake2b256() {
+        run_blake2_test::<Blake2b256Params>(
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+        );
+    }
+
+    #[test]
+    fn test_blake2s256() {
+        run_blake2_test::<Blake2s256Params>(
+            "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982",
+        );
+    }
+
+    #[test]
+    fn test_blake2_keyed_vectors() {
+        // Keyed vectors from the reference BLAKE2 KAT files (empty input)
+        let key: Vec<u8> = (0u8..64).collect();
+        let hasher = Blake2b512::new_keyed(&key).unwrap();
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786\
+             b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568"
+        );
+        let hasher = Blake2s256::new_keyed(&key[..32]).unwrap();
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49"
+        );
+    }
+
+    #[test]
+    fn test_blake2_salt_and_personalization_vectors() {
+        // Cross-checked against Python's hashlib.blake2b / hashlib.blake2s
+        let mut hasher =
+            Blake2b256::with_params(b"on-disk-key", b"salt", b"seal-crypto-v1").unwrap();
+        hasher.update(b"hello world");
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "479096d778cbf542845c98f4d2dc4aca31e9df1411c7e45c4e03b153d8d273a5"
+        );
+        let mut hasher = Blake2s256::with_params(&[], b"salt", b"seal").unwrap();
+        hasher.update(b"hello world");
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "2d7d95851ff55e93a934fe28481778ad0e02b6a5ba56785b45fd3c90b230e77e"
+        );
+    }
+}
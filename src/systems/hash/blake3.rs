@@ -0,0 +1,328 @@
+//! Provides an implementation of the BLAKE3 hash function.
+//!
+//! BLAKE3 is a tree-based hash built on the BLAKE2s compression function. It is
+//! considerably faster than SHA-2 and SHA-3 on large inputs and natively supports
+//! three modes, selected when the hash state is created:
+//!
+//! - **hash**: plain hashing (`Default`)
+//! - **keyed_hash**: a MAC with a 32-byte key ([`Blake3Scheme::new_keyed`])
+//! - **derive_key**: key derivation under a context string ([`Blake3Scheme::new_derive_key`])
+//!
+//! Every mode has extendable output: the default digest is 32 bytes, but
+//! [`Blake3Scheme::finalize_xof`] returns a reader for any amount of output. The scheme
+//! also implements [`XofDerivation`], the trait used by the SHAKE schemes.
+//!
+//! Large inputs can be hashed on multiple threads through [`ParallelUpdate`] when the
+//! `rayon` feature is enabled, and files are memory-mapped when the `mmap` feature is.
+//!
+//! # Security Considerations
+//! - The derive_key context string should be hardcoded, globally unique and
+//!   application-specific, e.g. `"example.com 2025-01-01 session tokens v1"`.
+//!
+//! 提供了 BLAKE3 哈希函数的实现。
+//!
+//! BLAKE3 是基于 BLAKE2s 压缩函数构建的树形哈希。它在大输入上比 SHA-2 和 SHA-3 快得多，
+//! 并原生支持三种模式，在创建哈希状态时选择：
+//!
+//! - **hash**: 普通哈希（`Default`）
+//! - **keyed_hash**: 使用 32 字节密钥的 MAC（[`Blake3Scheme::new_keyed`]）
+//! - **derive_key**: 在上下文字符串下进行密钥派生（[`Blake3Scheme::new_derive_key`]）
+//!
+//! 每种模式都支持可扩展输出：默认摘要为 32 字节，而 [`Blake3Scheme::finalize_xof`]
+//! 返回一个可读取任意长度输出的读取器。该方案还实现了 SHAKE 方案所使用的 [`XofDerivation`] trait。
+//!
+//! 启用 `rayon` 特性时，可以通过 [`ParallelUpdate`] 在多个线程上对大型输入进行哈希；
+//! 启用 `mmap` 特性时，文件会通过内存映射读取。
+//!
+//! # 安全考虑
+//! - derive_key 的上下文字符串应当是硬编码的、全局唯一的并且特定于应用程序，
+//!   例如 `"example.com 2025-01-01 session tokens v1"`。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::traits::hash::write_digest;
+use crate::traits::kdf::check_output_len;
+use crate::{errors::Error, policy, prelude::*};
+use digest::XofReader as DigestXofReader;
+
+/// The size of a BLAKE3 key in bytes.
+///
+/// BLAKE3 密钥的字节大小。
+pub const KEY_SIZE: usize = blake3::KEY_LEN;
+
+/// Adapts `blake3::OutputReader` to the `digest::XofReader` interface.
+///
+/// 将 `blake3::OutputReader` 适配到 `digest::XofReader` 接口。
+struct Blake3Reader(blake3::OutputReader);
+
+impl DigestXofReader for Blake3Reader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        self.0.fill(buffer);
+    }
+}
+
+/// A struct representing a BLAKE3 computation in one of its three modes.
+///
+/// The derivation methods ([`KeyBasedDerivation::derive`] and [`XofDerivation::reader`])
+/// absorb `salt`, `ikm` and `info`, in that order, into a copy of the current state, so
+/// they inherit the mode the scheme was created with.
+///
+/// 表示三种模式之一的 BLAKE3 计算的结构体。
+///
+/// 派生方法（[`KeyBasedDerivation::derive`] 和 [`XofDerivation::reader`]）按顺序将 `salt`、
+/// `ikm` 和 `info` 吸收到当前状态的副本中，因此它们继承创建方案时所选择的模式。
+#[derive(Clone, Debug, Default)]
+pub struct Blake3Scheme {
+    hasher: blake3::Hasher,
+}
+
+impl Blake3Scheme {
+    /// Starts a computation in keyed_hash mode. The key must be exactly 32 bytes.
+    ///
+    /// 以 keyed_hash 模式开始计算。密钥必须恰好为 32 字节。
+    pub fn new_keyed(key: &[u8]) -> Result<Self, Error> {
+        let key: &[u8; KEY_SIZE] = key
+            .try_into()
+            .map_err(|_| Error::Hash(HashError::InvalidKeyLength))?;
+        Ok(Self {
+            hasher: blake3::Hasher::new_keyed(key),
+        })
+    }
+
+    /// Starts a computation in derive_key mode under the given context string.
+    ///
+    /// 以 derive_key 模式在给定的上下文字符串下开始计算。
+    pub fn new_derive_key(context: &str) -> Self {
+        Self {
+            hasher: blake3::Hasher::new_derive_key(context),
+        }
+    }
+
+    /// Consumes the hash state and returns a reader for an arbitrary amount of output.
+    /// The first 32 bytes equal the output of [`HashFunction::finalize`].
+    ///
+    /// 消费哈希状态并返回一个可读取任意长度输出的读取器。
+    /// 前 32 字节与 [`HashFunction::finalize`] 的输出相同。
+    pub fn finalize_xof(self) -> XofReader<'static> {
+        XofReader::new(Blake3Reader(self.hasher.finalize_xof()))
+    }
+
+    fn absorb(&self, ikm: &[u8], salt: Option<&[u8]>, info: Option<&[u8]>) -> Self {
+        let mut scheme = self.clone();
+        if let Some(s) = salt {
+            scheme.hasher.update(s);
+        }
+        scheme.hasher.update(ikm);
+        if let Some(i) = info {
+            scheme.hasher.update(i);
+        }
+        scheme
+    }
+}
+
+impl Algorithm for Blake3Scheme {
+    fn name() -> String {
+        "BLAKE3".to_string()
+    }
+    const ID: u32 = 0x04_04_00_01;
+}
+
+impl Parameterized for Blake3Scheme {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(Self::name()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl HashFunction for Blake3Scheme {
+    const OUTPUT_SIZE: usize = blake3::OUT_LEN;
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.hasher.finalize().as_bytes().to_vec()
+    }
+
+    fn finalize_into(self, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(self.hasher.finalize().as_bytes(), output)
+    }
+}
+
+impl ParallelUpdate for Blake3Scheme {
+    fn update_parallel(&mut self, data: &[u8]) {
+        #[cfg(feature = "rayon")]
+        self.hasher.update_rayon(data);
+        #[cfg(not(feature = "rayon"))]
+        self.hasher.update(data);
+    }
+
+    #[cfg(feature = "mmap")]
+    fn update_file<P: AsRef<std::path::Path>>(&mut self, path: P) -> std::io::Result<u64> {
+        // blake3 falls back to buffered reads for small files and special files
+        let before = self.hasher.count();
+        #[cfg(feature = "rayon")]
+        self.hasher.update_mmap_rayon(path)?;
+        #[cfg(not(feature = "rayon"))]
+        self.hasher.update_mmap(path)?;
+        Ok(self.hasher.count() - before)
+    }
+}
+
+impl MultihashCode for Blake3Scheme {
+    const MULTIHASH_CODE: u64 = 0x1e;
+}
+
+impl Derivation for Blake3Scheme {}
+
+impl KeyBasedDerivation for Blake3Scheme {
+    fn derive(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        check_output_len(output_len, self.max_output_len())?;
+        let mut reader = self.absorb(ikm, salt, info).finalize_xof();
+        let mut okm = vec![0u8; output_len];
+        reader.read(&mut okm);
+
+        Ok(DerivedKey::new(okm))
+    }
+}
+
+impl XofDerivation for Blake3Scheme {
+    fn reader<'a>(
+        &self,
+        ikm: &'a [u8],
+        salt: Option<&'a [u8]>,
+        info: Option<&'a [u8]>,
+    ) -> Result<XofReader<'a>, Error> {
+        policy::enforce::<Self>()?;
+        Ok(self.absorb(ikm, salt, info).finalize_xof())
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the BLAKE3 scheme.
+///
+/// BLAKE3 方案的类型别名。
+pub type Blake3 = Blake3Scheme;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blake3_hash() {
+        assert_eq!(
+            hex::encode(Blake3::hash(b"abc")),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+
+        let mut hasher = Blake3::default();
+        hasher.update(b"a");
+        hasher.update(b"bc");
+        assert_eq!(hasher.finalize(), Blake3::hash(b"abc"));
+        assert_eq!(Blake3::hash_array::<32>(b"abc").unwrap()[..], Blake3::hash(b"abc")[..]);
+
+        assert_eq!(
+            hex::encode(Blake3::hash_multihash(b"abc").to_bytes()),
+            "1e206437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_blake3_parallel_update() {
+        let data: Vec<u8> = (0..3 * 1024 * 1024 + 17).map(|i| (i % 251) as u8).collect();
+        let expected = Blake3::hash(&data);
+
+        let mut hasher = Blake3::default();
+        hasher.update_parallel(&data);
+        assert_eq!(hasher.finalize(), expected);
+
+        assert_eq!(
+            hash_reader::<Blake3, _>(std::io::Cursor::new(&data)).unwrap(),
+            expected
+        );
+
+        let path = std::env::temp_dir().join(format!("seal-blake3-{}", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+        let hashed = hash_file::<Blake3, _>(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(hashed.unwrap(), expected);
+    }
+
+    #[test]
+    fn test_blake3_xof() {
+        // Extended output of the empty input, from the official test vectors
+        let mut reader = Blake3::default().finalize_xof();
+        let mut output = [0u8; 64];
+        reader.read(&mut output[..10]);
+        reader.read(&mut output[10..]);
+        assert_eq!(
+            hex::encode(output),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262\
+             e00f03e7b69af26b7faaf09fcd333050338ddfe085b8cc869ca98b206c08243a"
+        );
+        assert_eq!(&output[..32], Blake3::hash(b"").as_slice());
+    }
+
+    #[test]
+    fn test_blake3_keyed_hash() {
+        let hasher = Blake3::new_keyed(b"whats the Elvish word for friend").unwrap();
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26"
+        );
+        assert_eq!(
+            Blake3::new_keyed(&[0u8; 31]).unwrap_err(),
+            Error::Hash(HashError::InvalidKeyLength)
+        );
+    }
+
+    #[test]
+    fn test_blake3_derive_key() {
+        let mut hasher = Blake3::new_derive_key("seal-crypto 2025 test context");
+        hasher.update(b"input key material");
+        let expected = "3d0408e0b3b21041a43dc77ceeb62229e2f82be437214f03d815392eba598114";
+        assert_eq!(hex::encode(hasher.finalize()), expected);
+
+        // The derivation traits inherit the mode chosen at construction
+        let scheme = Blake3::new_derive_key("seal-crypto 2025 test context");
+        let key = scheme
+            .derive(b"input key material", None, None, 32)
+            .unwrap();
+        assert_eq!(hex::encode(key.as_bytes()), expected);
+    }
+
+    #[test]
+    fn test_blake3_xof_derivation() {
+        let scheme = Blake3::default();
+        let key = scheme
+            .derive(b"ikm", Some(b"salt"), Some(b"info"), 48)
+            .unwrap();
+        assert_eq!(
+            hex::encode(key.as_bytes()),
+            "4f287e7bfb6084856359f262a9f44dfd7a053eb61dae223d\
+             09039aa11411183cfa671ac213d79bb5b2947cb681be06f5"
+        );
+
+        let mut reader = scheme.reader(b"ikm", Some(b"salt"), Some(b"info")).unwrap();
+        let mut streamed = [0u8; 48];
+        reader.read(&mut streamed);
+        assert_eq!(&streamed[..], key.as_bytes());
+    }
+}
@@ -0,0 +1,203 @@
+//! Implements the RustCrypto `digest` traits for the crate's hash types.
+//!
+//! With the `interop` feature, every fixed-output hash scheme implements `digest::Update`,
+//! `digest::FixedOutput` and `digest::HashMarker`, and therefore `digest::Digest` through
+//! the `digest` crate's blanket implementation. This lets the schemes be passed to crates
+//! that are generic over `Digest`, such as signature, X.509 or JWT libraries. SHA-2 and
+//! SHA-3 also implement `Reset`, and thus `digest::DynDigest`; BLAKE2 and BLAKE3 do not,
+//! because resetting a keyed state to `Default` would silently drop the key.
+//!
+//! The `digest` traits have methods named like those of [`HashFunction`]. Code that has
+//! both traits in scope must call them with fully qualified syntax, e.g.
+//! `HashFunction::update(&mut hasher, data)`.
+//!
+//! 为本 crate 的哈希类型实现 RustCrypto `digest` trait。
+//!
+//! 启用 `interop` 特性时，每个固定输出的哈希方案都实现 `digest::Update`、`digest::FixedOutput`
+//! 和 `digest::HashMarker`，从而通过 `digest` crate 的通用实现获得 `digest::Digest`。
+//! 这使得这些方案可以传给在 `Digest` 上通用的 crate，例如签名、X.509 或 JWT 库。
+//! SHA-2 和 SHA-3 还实现了 `Reset`，因此也实现了 `digest::DynDigest`；BLAKE2 和 BLAKE3 没有实现，
+//! 因为将带密钥的状态重置为 `Default` 会悄悄丢弃密钥。
+//!
+//! `digest` trait 的方法与 [`HashFunction`] 的方法同名。同时引入两个 trait 的代码
+//! 必须使用完全限定语法调用它们，例如 `HashFunction::update(&mut hasher, data)`。
+
+use crate::prelude::*;
+use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+/// Implements `Update`, `FixedOutput` and `HashMarker` in terms of [`HashFunction`].
+/// With `reset`, also implements `Reset` and `FixedOutputReset` by restarting from
+/// `Default`, which is only correct for schemes without a key.
+///
+/// 基于 [`HashFunction`] 实现 `Update`、`FixedOutput` 和 `HashMarker`。
+/// 使用 `reset` 时，还通过从 `Default` 重新开始来实现 `Reset` 和 `FixedOutputReset`，
+/// 这只对没有密钥的方案是正确的。
+macro_rules! impl_digest {
+    ([$($generics:tt)*] $scheme:ty) => {
+        impl<$($generics)*> Update for $scheme {
+            fn update(&mut self, data: &[u8]) {
+                HashFunction::update(self, data);
+            }
+        }
+
+        impl<$($generics)*> FixedOutput for $scheme {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&HashFunction::finalize(self));
+            }
+        }
+
+        impl<$($generics)*> HashMarker for $scheme {}
+    };
+    (reset [$($generics:tt)*] $scheme:ty) => {
+        impl_digest!([$($generics)*] $scheme);
+
+        impl<$($generics)*> Reset for $scheme {
+            fn reset(&mut self) {
+                *self = Self::default();
+            }
+        }
+
+        impl<$($generics)*> FixedOutputReset for $scheme {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                FixedOutput::finalize_into(core::mem::take(self), out);
+            }
+        }
+    };
+}
+
+// ------------------- SHA-2 -------------------
+
+#[cfg(feature = "sha2")]
+mod sha2_impls {
+    use super::*;
+    use crate::systems::hash::sha2::Sha2Scheme;
+
+    impl<H: Hasher> OutputSizeUser for Sha2Scheme<H> {
+        type OutputSize = <H::Digest as OutputSizeUser>::OutputSize;
+    }
+
+    impl_digest!(reset [H: Hasher] Sha2Scheme<H>);
+}
+
+// ------------------- SHA-3 -------------------
+
+#[cfg(feature = "sha3")]
+mod sha3_impls {
+    use super::*;
+    use crate::systems::hash::sha3::{Sha3Params, Sha3Scheme};
+
+    impl<P: Sha3Params> OutputSizeUser for Sha3Scheme<P> {
+        type OutputSize = <P::Digest as OutputSizeUser>::OutputSize;
+    }
+
+    impl_digest!(reset [P: Sha3Params] Sha3Scheme<P>);
+}
+
+// ------------------- BLAKE2 -------------------
+
+#[cfg(feature = "blake2-default")]
+mod blake2_impls {
+    use super::*;
+    use crate::systems::hash::blake2::{Blake2b256, Blake2b512, Blake2s256};
+    use digest::consts::{U32, U64};
+
+    impl OutputSizeUser for Blake2b512 {
+        type OutputSize = U64;
+    }
+
+    impl OutputSizeUser for Blake2b256 {
+        type OutputSize = U32;
+    }
+
+    impl OutputSizeUser for Blake2s256 {
+        type OutputSize = U32;
+    }
+
+    impl_digest!([] Blake2b512);
+    impl_digest!([] Blake2b256);
+    impl_digest!([] Blake2s256);
+}
+
+// ------------------- BLAKE3 -------------------
+
+#[cfg(feature = "blake3-default")]
+mod blake3_impls {
+    use super::*;
+    use crate::systems::hash::blake3::Blake3Scheme;
+    use digest::consts::U32;
+
+    impl OutputSizeUser for Blake3Scheme {
+        type OutputSize = U32;
+    }
+
+    impl_digest!([] Blake3Scheme);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digest::Digest;
+
+    /// Hashes through the `digest` API only, as a third-party crate would.
+    fn digest_via_trait<D: Digest>(pieces: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = D::new();
+        for piece in pieces {
+            hasher.update(piece);
+        }
+        hasher.finalize().to_vec()
+    }
+
+    fn run_interop_test<S: HashFunction + Digest>() {
+        let expected = <S as HashFunction>::hash(b"abc");
+        assert_eq!(digest_via_trait::<S>(&[b"a", b"bc"]), expected);
+        assert_eq!(<S as Digest>::output_size(), S::OUTPUT_SIZE);
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_sha2_interop() {
+        use crate::systems::hash::sha2::{Sha256, Sha384, Sha512};
+
+        run_interop_test::<Sha256>();
+        run_interop_test::<Sha384>();
+        run_interop_test::<Sha512>();
+        assert_eq!(
+            digest_via_trait::<Sha256>(&[b"abc"]),
+            sha2::Sha256::digest(b"abc").to_vec()
+        );
+
+        // Reset makes the scheme usable as a `DynDigest`
+        let mut boxed: Box<dyn digest::DynDigest> = Box::new(Sha256::default());
+        boxed.update(b"abc");
+        assert_eq!(boxed.finalize_reset().to_vec(), Sha256::hash(b"abc"));
+        assert_eq!(boxed.finalize().to_vec(), Sha256::hash(b""));
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn test_sha3_interop() {
+        use crate::systems::hash::sha3::{Sha3_256, Sha3_384, Sha3_512};
+
+        run_interop_test::<Sha3_256>();
+        run_interop_test::<Sha3_384>();
+        run_interop_test::<Sha3_512>();
+    }
+
+    #[cfg(feature = "blake2-default")]
+    #[test]
+    fn test_blake2_interop() {
+        use crate::systems::hash::blake2::{Blake2b256, Blake2b512, Blake2s256};
+
+        run_interop_test::<Blake2b512>();
+        run_interop_test::<Blake2b256>();
+        run_interop_test::<Blake2s256>();
+    }
+
+    #[cfg(feature = "blake3-default")]
+    #[test]
+    fn test_blake3_interop() {
+        use crate::systems::hash::blake3::Blake3;
+
+        run_interop_test::<Blake3>();
+    }
+}
@@ -0,0 +1,207 @@
+//! Provides SHA-1 and MD5 for verifying legacy data.
+//!
+//! Both hash functions are broken: practical collisions are known for SHA-1 and trivial for
+//! MD5. They are only available behind the explicitly named `insecure-legacy-hashes` feature
+//! so that historical artifacts and protocols, such as old file checksums or HMAC-SHA1
+//! tokens, can be checked within the crate's type and error system.
+//!
+//! # Algorithm Variants
+//! - **SHA-1**: 20-byte digest
+//! - **MD5**: 16-byte digest
+//!
+//! # Security Considerations
+//! - Never use these functions in new designs, and never rely on them where collision
+//!   resistance matters, such as signatures, certificates or content addressing.
+//! - HMAC does not depend on collision resistance, so HMAC-SHA1 remains acceptable for
+//!   verifying existing tokens; new protocols should still use HMAC-SHA-256.
+//!
+//! 提供用于验证旧数据的 SHA-1 和 MD5。
+//!
+//! 这两种哈希函数都已被攻破：SHA-1 存在实际可行的碰撞，而 MD5 的碰撞则轻而易举。
+//! 它们只在名称明确的 `insecure-legacy-hashes` 特性下可用，以便在本 crate 的类型和错误体系内
+//! 检查历史数据和协议，例如旧的文件校验和或 HMAC-SHA1 令牌。
+//!
+//! # 算法变体
+//! - **SHA-1**: 20 字节摘要
+//! - **MD5**: 16 字节摘要
+//!
+//! # 安全考虑
+//! - 切勿在新设计中使用这些函数，也不要在依赖抗碰撞性的场合（例如签名、证书或内容寻址）依赖它们。
+//! - HMAC 不依赖抗碰撞性，因此 HMAC-SHA1 仍可用于验证现有令牌；新协议仍应使用 HMAC-SHA-256。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::{errors::Error, prelude::*};
+use crate::traits::hash::write_digest;
+
+// ------------------- Marker structs and traits for legacy hash parameters -------------------
+// ------------------- 用于旧哈希参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines the parameters for a legacy hash function.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义旧哈希函数参数的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait LegacyHashParams: private::Sealed + SchemeParams {
+    const OUTPUT_SIZE: usize;
+    const MULTIHASH_CODE: u64;
+    type Digest: digest::Digest
+        + digest::core_api::BlockSizeUser
+        + Clone
+        + Default
+        + Send
+        + Sync
+        + 'static
+        + core::fmt::Debug;
+}
+
+/// Marker struct for SHA-1 parameters.
+///
+/// SHA-1 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Sha1Params;
+impl private::Sealed for Sha1Params {}
+impl SchemeParams for Sha1Params {
+    const NAME: &'static str = "SHA-1";
+    const ID: u32 = 0x04_05_00_01;
+}
+impl LegacyHashParams for Sha1Params {
+    const OUTPUT_SIZE: usize = 20;
+    const MULTIHASH_CODE: u64 = 0x11;
+    type Digest = sha1::Sha1;
+}
+
+/// Marker struct for MD5 parameters.
+///
+/// MD5 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Md5Params;
+impl private::Sealed for Md5Params {}
+impl SchemeParams for Md5Params {
+    const NAME: &'static str = "MD5";
+    const ID: u32 = 0x04_05_00_02;
+}
+impl LegacyHashParams for Md5Params {
+    const OUTPUT_SIZE: usize = 16;
+    const MULTIHASH_CODE: u64 = 0xd5;
+    type Digest = md5::Md5;
+}
+
+// ------------------- Generic Legacy Hash Implementation -------------------
+// ------------------- 通用旧哈希实现 -------------------
+
+/// A generic struct representing a legacy hash computation for a given parameter set.
+///
+/// A fresh value (via `Default`) starts a new computation. Feed data with
+/// [`HashFunction::update`] and obtain the digest with [`HashFunction::finalize`].
+///
+/// Hashing cannot fail, so an installed [`CryptoPolicy`](crate::policy::CryptoPolicy) is
+/// not enforced here, even one that disallows legacy algorithms. Callers subject to a policy
+/// must check the algorithm ID with [`CryptoPolicy::check_id`](crate::policy::CryptoPolicy::check_id)
+/// before hashing.
+///
+/// 一个通用的旧哈希计算结构体，它在参数集上是通用的。
+///
+/// 新值（通过 `Default`）开始一次新的计算。使用 [`HashFunction::update`] 输入数据，
+/// 并使用 [`HashFunction::finalize`] 获取摘要。
+///
+/// 哈希运算不会失败，因此这里不会强制执行已安装的 [`CryptoPolicy`](crate::policy::CryptoPolicy)，
+/// 即使它不允许旧算法。受策略约束的调用者必须在哈希之前使用
+/// [`CryptoPolicy::check_id`](crate::policy::CryptoPolicy::check_id) 检查算法 ID。
+#[derive(Clone, Debug, Default)]
+pub struct LegacyHashScheme<P: LegacyHashParams> {
+    state: P::Digest,
+}
+
+impl<P: LegacyHashParams> Algorithm for LegacyHashScheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: LegacyHashParams> Parameterized for LegacyHashScheme<P> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(P::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<P: LegacyHashParams> HashFunction for LegacyHashScheme<P> {
+    const OUTPUT_SIZE: usize = P::OUTPUT_SIZE;
+
+    fn update(&mut self, data: &[u8]) {
+        digest::Digest::update(&mut self.state, data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        digest::Digest::finalize(self.state).to_vec()
+    }
+
+    fn finalize_into(self, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(&digest::Digest::finalize(self.state), output)
+    }
+}
+
+impl<P: LegacyHashParams> MultihashCode for LegacyHashScheme<P> {
+    const MULTIHASH_CODE: u64 = P::MULTIHASH_CODE;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the SHA-1 hash function. It is not checked against the installed
+/// policy; see [`LegacyHashScheme`].
+///
+/// SHA-1 哈希函数的类型别名。它不会根据已安装的策略进行检查；参见 [`LegacyHashScheme`]。
+pub type Sha1 = LegacyHashScheme<Sha1Params>;
+
+/// A type alias for the MD5 hash function. It is not checked against the installed
+/// policy; see [`LegacyHashScheme`].
+///
+/// MD5 哈希函数的类型别名。它不会根据已安装的策略进行检查；参见 [`LegacyHashScheme`]。
+pub type Md5 = LegacyHashScheme<Md5Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_legacy_test<P: LegacyHashParams>(expected_abc: &str) {
+        let one_shot = LegacyHashScheme::<P>::hash(b"abc");
+        assert_eq!(one_shot.len(), LegacyHashScheme::<P>::OUTPUT_SIZE);
+        assert_eq!(hex::encode(&one_shot), expected_abc);
+
+        // Streaming in several pieces must match the one-shot digest
+        let mut hasher = LegacyHashScheme::<P>::default();
+        hasher.update(b"ab");
+        hasher.update(b"c");
+        assert_eq!(hasher.finalize(), one_shot);
+        assert_eq!(LegacyHashScheme::<P>::ID, P::ID);
+    }
+
+    #[test]
+    fn test_sha1() {
+        // FIPS 180-4 example
+        run_legacy_test::<Sha1Params>("a9993e364706816aba3e25717850c26c9cd0d89d");
+    }
+
+    #[test]
+    fn test_md5() {
+        // RFC 1321 test suite
+        run_legacy_test::<Md5Params>("900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex::encode(Md5::hash(b"")),
+            "d41d8cd98f00b204e9800998ecf8427e"
+        );
+    }
+}
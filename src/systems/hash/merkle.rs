@@ -0,0 +1,358 @@
+//! Provides Merkle trees with inclusion proofs, as specified by RFC 6962.
+//!
+//! A Merkle tree hashes a list of leaves into a single root. An inclusion proof (audit
+//! path) shows that a leaf is part of the tree using only `log2(n)` hashes. The tree shape
+//! and hashing follow RFC 6962 (Certificate Transparency), so roots and proofs interoperate
+//! with transparency logs:
+//!
+//! - leaf hash: `H(0x00 || leaf)`
+//! - node hash: `H(0x01 || left || right)`
+//! - the root of the empty tree is `H("")`
+//!
+//! The different prefixes for leaves and nodes prevent second-preimage attacks in which an
+//! inner node is passed off as a leaf. The hash is any [`HashFunction`] of this crate.
+//!
+//! 提供了 RFC 6962 规定的 Merkle 树及其包含证明。
+//!
+//! Merkle 树将叶子列表哈希为单个根。包含证明（审计路径）只需 `log2(n)` 个哈希即可证明某个叶子
+//! 属于该树。树的形状和哈希方式遵循 RFC 6962（证书透明度），因此根和证明可以与透明日志互操作：
+//!
+//! - 叶子哈希：`H(0x00 || leaf)`
+//! - 节点哈希：`H(0x01 || left || right)`
+//! - 空树的根为 `H("")`
+//!
+//! 叶子和节点使用不同的前缀，防止将内部节点冒充为叶子的第二原像攻击。
+//! 哈希可以是本 crate 的任何 [`HashFunction`]。
+
+use alloc::{vec, vec::Vec};
+use crate::{errors::Error, prelude::*};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use core::marker::PhantomData;
+use subtle::ConstantTimeEq;
+
+/// The prefix of leaf hashes.
+///
+/// 叶子哈希的前缀。
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// The prefix of interior node hashes.
+///
+/// 内部节点哈希的前缀。
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// An inclusion proof for one leaf of a Merkle tree.
+///
+/// Merkle 树中某个叶子的包含证明。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InclusionProof {
+    leaf_index: u64,
+    tree_size: u64,
+    path: Vec<Vec<u8>>,
+}
+
+impl InclusionProof {
+    /// Creates a proof from its parts, e.g. as received from a transparency log.
+    ///
+    /// 从各组成部分创建证明，例如从透明日志收到的证明。
+    pub fn new(leaf_index: u64, tree_size: u64, path: Vec<Vec<u8>>) -> Self {
+        Self {
+            leaf_index,
+            tree_size,
+            path,
+        }
+    }
+
+    /// Returns the index of the proven leaf.
+    ///
+    /// 返回被证明叶子的索引。
+    pub fn leaf_index(&self) -> u64 {
+        self.leaf_index
+    }
+
+    /// Returns the number of leaves in the tree the proof was made for.
+    ///
+    /// 返回生成证明时树中的叶子数。
+    pub fn tree_size(&self) -> u64 {
+        self.tree_size
+    }
+
+    /// Returns the audit path, from the leaf's sibling up to the root's child.
+    ///
+    /// 返回审计路径，从叶子的兄弟节点一直到根的子节点。
+    pub fn path(&self) -> &[Vec<u8>] {
+        &self.path
+    }
+}
+
+/// A Merkle tree over a given hash function.
+///
+/// All levels of the tree are kept in memory, so the root is available immediately and
+/// proofs are built without rehashing.
+///
+/// 基于给定哈希函数的 Merkle 树。
+///
+/// 树的所有层级都保存在内存中，因此根可以立即获得，构建证明时也无需重新哈希。
+#[derive(Clone, Debug)]
+pub struct MerkleTree<H: HashFunction> {
+    // levels[0] holds the leaf hashes and the last level holds the root
+    levels: Vec<Vec<Vec<u8>>>,
+    _hash: PhantomData<H>,
+}
+
+impl<H: HashFunction> MerkleTree<H> {
+    /// Builds the tree over `leaves`, in order.
+    ///
+    /// 按顺序在 `leaves` 上构建树。
+    pub fn new<I>(leaves: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let leaf_hashes = leaves
+            .into_iter()
+            .map(|leaf| Self::hash_leaf(leaf.as_ref()))
+            .collect();
+        Self::from_leaf_hashes(leaf_hashes)
+    }
+
+    /// Builds the tree over leaves that have already been hashed with
+    /// [`hash_leaf`](Self::hash_leaf).
+    ///
+    /// 在已使用 [`hash_leaf`](Self::hash_leaf) 哈希过的叶子上构建树。
+    pub fn from_leaf_hashes(leaf_hashes: Vec<Vec<u8>>) -> Self {
+        let mut levels = vec![leaf_hashes];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            // An odd node at the end of a level is promoted unchanged, which gives the
+            // RFC 6962 shape where the left subtree is the largest power of two
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => Self::hash_node(left, right),
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self {
+            levels,
+            _hash: PhantomData,
+        }
+    }
+
+    /// Hashes a leaf as `H(0x00 || leaf)`.
+    ///
+    /// 将叶子哈希为 `H(0x00 || leaf)`。
+    pub fn hash_leaf(leaf: &[u8]) -> Vec<u8> {
+        let mut hasher = H::default();
+        hasher.update(&[LEAF_PREFIX]);
+        hasher.update(leaf);
+        hasher.finalize()
+    }
+
+    /// Hashes two children as `H(0x01 || left || right)`.
+    ///
+    /// 将两个子节点哈希为 `H(0x01 || left || right)`。
+    pub fn hash_node(left: &[u8], right: &[u8]) -> Vec<u8> {
+        let mut hasher = H::default();
+        hasher.update(&[NODE_PREFIX]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize()
+    }
+
+    /// Returns the number of leaves.
+    ///
+    /// 返回叶子的数量。
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns whether the tree has no leaves.
+    ///
+    /// 返回树是否没有叶子。
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Returns the root hash.
+    ///
+    /// 返回根哈希。
+    pub fn root(&self) -> Vec<u8> {
+        match self.levels.last().and_then(|level| level.first()) {
+            Some(root) => root.clone(),
+            None => H::hash(&[]),
+        }
+    }
+
+    /// Builds the inclusion proof for the leaf at `index`.
+    ///
+    /// 为位于 `index` 的叶子构建包含证明。
+    pub fn prove(&self, index: usize) -> Result<InclusionProof, Error> {
+        if index >= self.len() {
+            return Err(Error::Hash(HashError::InvalidLeafIndex));
+        }
+        let mut path = Vec::new();
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            // A node without a sibling was promoted and contributes nothing to the path
+            if let Some(sibling) = level.get(position ^ 1) {
+                path.push(sibling.clone());
+            }
+            position /= 2;
+        }
+        Ok(InclusionProof::new(index as u64, self.len() as u64, path))
+    }
+
+    /// Verifies that `leaf` is included in the tree with the given root, in constant time.
+    ///
+    /// 以常量时间验证 `leaf` 包含在具有给定根的树中。
+    pub fn verify(root: &[u8], leaf: &[u8], proof: &InclusionProof) -> Result<(), Error> {
+        Self::verify_leaf_hash(root, &Self::hash_leaf(leaf), proof)
+    }
+
+    /// Verifies that a leaf hashed with [`hash_leaf`](Self::hash_leaf) is included in the
+    /// tree with the given root, following the algorithm of RFC 9162, section 2.1.3.2.
+    ///
+    /// 验证使用 [`hash_leaf`](Self::hash_leaf) 哈希过的叶子包含在具有给定根的树中，
+    /// 遵循 RFC 9162 第 2.1.3.2 节的算法。
+    pub fn verify_leaf_hash(
+        root: &[u8],
+        leaf_hash: &[u8],
+        proof: &InclusionProof,
+    ) -> Result<(), Error> {
+        let invalid = Error::Hash(HashError::InvalidInclusionProof);
+        if proof.leaf_index >= proof.tree_size {
+            return Err(invalid);
+        }
+        let mut index = proof.leaf_index;
+        let mut last = proof.tree_size - 1;
+        let mut hash = leaf_hash.to_vec();
+        for sibling in &proof.path {
+            if last == 0 {
+                return Err(invalid);
+            }
+            if index & 1 == 1 || index == last {
+                hash = Self::hash_node(sibling, &hash);
+                while index & 1 == 0 && index != 0 {
+                    index >>= 1;
+                    last >>= 1;
+                }
+            } else {
+                hash = Self::hash_node(&hash, sibling);
+            }
+            index >>= 1;
+            last >>= 1;
+        }
+        if last == 0 && bool::from(hash.ct_eq(root)) {
+            Ok(())
+        } else {
+            Err(invalid)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sha2"))]
+mod tests {
+    use super::*;
+    use crate::systems::hash::sha2::Sha256;
+
+    // The test leaves of the Certificate Transparency reference implementation
+    const LEAVES: [&str; 8] = [
+        "",
+        "00",
+        "10",
+        "2021",
+        "3031",
+        "40414243",
+        "5051525354555657",
+        "606162636465666768696a6b6c6d6e6f",
+    ];
+
+    fn leaves(n: usize) -> Vec<Vec<u8>> {
+        LEAVES[..n]
+            .iter()
+            .map(|l| hex::decode(l).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_merkle_roots() {
+        for (n, expected) in [
+            (
+                1,
+                "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+            ),
+            (
+                3,
+                "aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77",
+            ),
+            (
+                5,
+                "4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4",
+            ),
+            (
+                7,
+                "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c",
+            ),
+            (
+                8,
+                "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328",
+            ),
+        ] {
+            let tree = MerkleTree::<Sha256>::new(leaves(n));
+            assert_eq!(tree.len(), n);
+            assert_eq!(hex::encode(tree.root()), expected);
+        }
+
+        let empty = MerkleTree::<Sha256>::new(Vec::<Vec<u8>>::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.root(), Sha256::hash(b""));
+    }
+
+    #[test]
+    fn test_merkle_inclusion_proofs() {
+        let proof = MerkleTree::<Sha256>::new(leaves(7)).prove(6).unwrap();
+        let path: Vec<String> = proof.path().iter().map(hex::encode).collect();
+        assert_eq!(
+            path,
+            [
+                "0ebc5d3437fbe2db158b9f126a1d118e308181031d0a949f8dededebc558ef6a",
+                "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+            ]
+        );
+
+        // Every leaf of every tree size proves against its own root and nothing else
+        for n in 1..=LEAVES.len() {
+            let data = leaves(n);
+            let tree = MerkleTree::<Sha256>::new(&data);
+            let root = tree.root();
+            for (i, leaf) in data.iter().enumerate() {
+                let proof = tree.prove(i).unwrap();
+                assert!(MerkleTree::<Sha256>::verify(&root, leaf, &proof).is_ok());
+                assert!(MerkleTree::<Sha256>::verify(&root, b"other", &proof).is_err());
+
+                let moved = InclusionProof::new(
+                    (proof.leaf_index() + 1) % n as u64,
+                    proof.tree_size(),
+                    proof.path().to_vec(),
+                );
+                if n > 1 {
+                    assert!(MerkleTree::<Sha256>::verify(&root, leaf, &moved).is_err());
+                }
+            }
+        }
+
+        let tree = MerkleTree::<Sha256>::new(leaves(5));
+        assert_eq!(
+            tree.prove(5).unwrap_err(),
+            Error::Hash(HashError::InvalidLeafIndex)
+        );
+        let truncated = InclusionProof::new(2, 5, tree.prove(2).unwrap().path()[..2].to_vec());
+        assert_eq!(
+            MerkleTree::<Sha256>::verify(&tree.root(), b"\x10", &truncated).unwrap_err(),
+            Error::Hash(HashError::InvalidInclusionProof)
+        );
+    }
+}
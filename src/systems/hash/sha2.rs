@@ -0,0 +1,212 @@
+//! Provides an implementation of the SHA-2 family of hash functions.
+//!
+//! SHA-2 is the hash family standardised in FIPS 180-4. It is built on the
+//! Merkle–Damgård construction and produces a fixed-size digest.
+//!
+//! # Algorithm Variants
+//! - **SHA-256**: 32-byte digest
+//! - **SHA-384**: 48-byte digest
+//! - **SHA-512**: 64-byte digest
+//!
+//! # Security Considerations
+//! - SHA-2 digests are subject to length-extension attacks; use HMAC rather than
+//!   `H(key || message)` when authenticating data.
+//!
+//! 提供了 SHA-2 系列哈希函数的实现。
+//!
+//! SHA-2 是 FIPS 180-4 中标准化的哈希系列。它基于 Merkle–Damgård 结构，产生固定大小的摘要。
+//!
+//! # 算法变体
+//! - **SHA-256**: 32 字节摘要
+//! - **SHA-384**: 48 字节摘要
+//! - **SHA-512**: 64 字节摘要
+//!
+//! # 安全考虑
+//! - SHA-2 摘要容易受到长度扩展攻击；认证数据时请使用 HMAC，而不是 `H(key || message)`。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::{errors::Error, prelude::*};
+use crate::traits::hash::write_digest;
+
+/// A generic struct representing a SHA-2 hash computation for a given hasher.
+///
+/// A fresh value (via `Default`) starts a new computation. Feed data with
+/// [`HashFunction::update`] and obtain the digest with [`HashFunction::finalize`].
+///
+/// # Type Parameters
+/// * `H` - The specific SHA-2 variant (SHA-256, SHA-384 or SHA-512)
+///
+/// 一个通用的 SHA-2 哈希计算结构体，它在哈希器上是通用的。
+///
+/// 新值（通过 `Default`）开始一次新的计算。使用 [`HashFunction::update`] 输入数据，
+/// 并使用 [`HashFunction::finalize`] 获取摘要。
+///
+/// # 类型参数
+/// * `H` - 特定的 SHA-2 变体（SHA-256、SHA-384 或 SHA-512）
+#[derive(Clone, Debug, Default)]
+pub struct Sha2Scheme<H: Hasher> {
+    state: H::Digest,
+}
+
+impl<H: Hasher> Algorithm for Sha2Scheme<H> {
+    fn name() -> String {
+        H::NAME.to_string()
+    }
+    const ID: u32 = 0x04_01_00_00 + H::ID_OFFSET;
+}
+
+impl<H: Hasher> Parameterized for Sha2Scheme<H> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(H::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<H: Hasher> HashFunction for Sha2Scheme<H> {
+    const OUTPUT_SIZE: usize = H::OUTPUT_SIZE;
+
+    fn update(&mut self, data: &[u8]) {
+        digest::Digest::update(&mut self.state, data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        digest::Digest::finalize(self.state).to_vec()
+    }
+
+    fn finalize_into(self, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(&digest::Digest::finalize(self.state), output)
+    }
+}
+
+impl<H: Hasher> MultihashCode for Sha2Scheme<H> {
+    const MULTIHASH_CODE: u64 = H::MULTIHASH_CODE;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the SHA-256 hash function.
+///
+/// SHA-256 哈希函数的类型别名。
+pub type Sha256 = Sha2Scheme<crate::traits::params::hash::Sha256>;
+
+/// A type alias for the SHA-384 hash function.
+///
+/// SHA-384 哈希函数的类型别名。
+pub type Sha384 = Sha2Scheme<crate::traits::params::hash::Sha384>;
+
+/// A type alias for the SHA-512 hash function.
+///
+/// SHA-512 哈希函数的类型别名。
+pub type Sha512 = Sha2Scheme<crate::traits::params::hash::Sha512>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_sha2_test<H: Hasher>(expected_abc: &str) {
+        let one_shot = Sha2Scheme::<H>::hash(b"abc");
+        assert_eq!(one_shot.len(), Sha2Scheme::<H>::OUTPUT_SIZE);
+        assert_eq!(hex::encode(&one_shot), expected_abc);
+
+        // Streaming in several pieces must match the one-shot digest
+        let mut hasher = Sha2Scheme::<H>::default();
+        hasher.update(b"a");
+        hasher.update(b"");
+        hasher.update(b"bc");
+        assert_eq!(hasher.finalize(), one_shot);
+
+        // The one-shot digest agrees with the parameter type's own hash
+        assert_eq!(one_shot, H::hash(b"abc"));
+        assert_ne!(Sha2Scheme::<H>::hash(b"abd"), one_shot);
+
+        // Writing into a buffer yields the same digest, and a short buffer is rejected
+        let mut buffer = [0u8; 80];
+        let written = Sha2Scheme::<H>::hash_into(b"abc", &mut buffer).unwrap();
+        assert_eq!(buffer[..written], one_shot[..]);
+        assert_eq!(
+            Sha2Scheme::<H>::hash_into(b"abc", &mut buffer[..H::OUTPUT_SIZE - 1]).unwrap_err(),
+            Error::Hash(HashError::InvalidOutputLength)
+        );
+    }
+
+    #[test]
+    fn test_sha256_multihash() {
+        // The multihash of "hello world" from the multiformats documentation
+        let multihash = Sha256::hash_multihash(b"hello world");
+        assert_eq!(
+            hex::encode(multihash.to_bytes()),
+            "1220b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+        assert!(Sha256::verify_multihash(b"hello world", &multihash));
+        assert!(!Sha256::verify_multihash(b"hello there", &multihash));
+        assert!(!Sha512::verify_multihash(b"hello world", &multihash));
+
+        let parsed = Multihash::from_bytes(&multihash.to_bytes()).unwrap();
+        assert_eq!(parsed.code(), Sha256::MULTIHASH_CODE);
+        assert_eq!(parsed, multihash);
+    }
+
+    #[test]
+    fn test_sha256() {
+        run_sha2_test::<crate::traits::params::hash::Sha256>(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        );
+    }
+
+    #[test]
+    fn test_sha384() {
+        run_sha2_test::<crate::traits::params::hash::Sha384>(
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded163\
+             1a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7",
+        );
+    }
+
+    #[test]
+    fn test_sha512() {
+        run_sha2_test::<crate::traits::params::hash::Sha512>(
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        );
+    }
+
+    #[test]
+    fn test_sha2_clone_forks_state() {
+        let mut hasher = Sha256::default();
+        hasher.update(b"prefix-");
+        let mut fork = hasher.clone();
+        hasher.update(b"a");
+        fork.update(b"b");
+        assert_eq!(hasher.finalize(), Sha256::hash(b"prefix-a"));
+        assert_eq!(fork.finalize(), Sha256::hash(b"prefix-b"));
+        assert_eq!(Sha256::ID, 0x04_01_00_01);
+        assert_eq!(Sha512::name(), "SHA-512");
+    }
+
+    #[test]
+    fn test_sha2_array_output() {
+        let digest: [u8; 32] = Sha256::hash_array(b"abc").unwrap();
+        assert_eq!(digest[..], Sha256::hash(b"abc")[..]);
+
+        let mut hasher = Sha512::default();
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize_array::<64>().unwrap()[..], Sha512::hash(b"abc")[..]);
+
+        // The array length must equal the digest size
+        assert_eq!(
+            Sha256::hash_array::<64>(b"abc").unwrap_err(),
+            Error::Hash(HashError::InvalidOutputLength)
+        );
+        assert_eq!(
+            Sha384::hash_array::<32>(b"abc").unwrap_err(),
+            Error::Hash(HashError::InvalidOutputLength)
+        );
+    }
+}
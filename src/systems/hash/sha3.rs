@@ -0,0 +1,215 @@
+//! Provides an implementation of the SHA-3 family of fixed-output hash functions.
+//!
+//! SHA-3 is the hash family standardised in FIPS 202. It is based on the Keccak sponge
+//! construction, the same permutation that underlies the SHAKE XOFs, and is not
+//! vulnerable to length-extension attacks.
+//!
+//! # Algorithm Variants
+//! - **SHA3-256**: 32-byte digest
+//! - **SHA3-384**: 48-byte digest
+//! - **SHA3-512**: 64-byte digest
+//!
+//! 提供了 SHA-3 系列固定输出哈希函数的实现。
+//!
+//! SHA-3 是 FIPS 202 中标准化的哈希系列。它基于 Keccak 海绵结构（与 SHAKE XOF 使用相同的置换），
+//! 不易受到长度扩展攻击。
+//!
+//! # 算法变体
+//! - **SHA3-256**: 32 字节摘要
+//! - **SHA3-384**: 48 字节摘要
+//! - **SHA3-512**: 64 字节摘要
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::{errors::Error, prelude::*};
+use crate::traits::hash::write_digest;
+
+// ------------------- Marker Structs and Trait for SHA-3 Parameters -------------------
+// ------------------- 用于 SHA-3 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines the parameters for a specific SHA-3 variant.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义特定 SHA-3 变体参数的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait Sha3Params: private::Sealed + SchemeParams {
+    const OUTPUT_SIZE: usize;
+    const MULTIHASH_CODE: u64;
+    type Digest: digest::Digest + Clone + Default + Send + Sync + 'static + core::fmt::Debug;
+}
+
+/// Marker struct for SHA3-256 parameters.
+///
+/// SHA3-256 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Sha3_256Params;
+impl private::Sealed for Sha3_256Params {}
+impl SchemeParams for Sha3_256Params {
+    const NAME: &'static str = "SHA3-256";
+    const ID: u32 = 0x04_02_00_01;
+}
+impl Sha3Params for Sha3_256Params {
+    const OUTPUT_SIZE: usize = 32;
+    const MULTIHASH_CODE: u64 = 0x16;
+    type Digest = sha3::Sha3_256;
+}
+
+/// Marker struct for SHA3-384 parameters.
+///
+/// SHA3-384 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Sha3_384Params;
+impl private::Sealed for Sha3_384Params {}
+impl SchemeParams for Sha3_384Params {
+    const NAME: &'static str = "SHA3-384";
+    const ID: u32 = 0x04_02_00_02;
+}
+impl Sha3Params for Sha3_384Params {
+    const OUTPUT_SIZE: usize = 48;
+    const MULTIHASH_CODE: u64 = 0x15;
+    type Digest = sha3::Sha3_384;
+}
+
+/// Marker struct for SHA3-512 parameters.
+///
+/// SHA3-512 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Sha3_512Params;
+impl private::Sealed for Sha3_512Params {}
+impl SchemeParams for Sha3_512Params {
+    const NAME: &'static str = "SHA3-512";
+    const ID: u32 = 0x04_02_00_03;
+}
+impl Sha3Params for Sha3_512Params {
+    const OUTPUT_SIZE: usize = 64;
+    const MULTIHASH_CODE: u64 = 0x14;
+    type Digest = sha3::Sha3_512;
+}
+
+// ------------------- Generic SHA-3 Implementation -------------------
+// ------------------- 通用 SHA-3 实现 -------------------
+
+/// A generic struct representing a SHA-3 hash computation for a given parameter set.
+///
+/// A fresh value (via `Default`) starts a new computation. Feed data with
+/// [`HashFunction::update`] and obtain the digest with [`HashFunction::finalize`].
+///
+/// 一个通用的 SHA-3 哈希计算结构体，它在参数集上是通用的。
+///
+/// 新值（通过 `Default`）开始一次新的计算。使用 [`HashFunction::update`] 输入数据，
+/// 并使用 [`HashFunction::finalize`] 获取摘要。
+#[derive(Clone, Debug, Default)]
+pub struct Sha3Scheme<P: Sha3Params> {
+    state: P::Digest,
+}
+
+impl<P: Sha3Params> Algorithm for Sha3Scheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: Sha3Params> Parameterized for Sha3Scheme<P> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(P::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<P: Sha3Params> HashFunction for Sha3Scheme<P> {
+    const OUTPUT_SIZE: usize = P::OUTPUT_SIZE;
+
+    fn update(&mut self, data: &[u8]) {
+        digest::Digest::update(&mut self.state, data);
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        digest::Digest::finalize(self.state).to_vec()
+    }
+
+    fn finalize_into(self, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(&digest::Digest::finalize(self.state), output)
+    }
+}
+
+impl<P: Sha3Params> MultihashCode for Sha3Scheme<P> {
+    const MULTIHASH_CODE: u64 = P::MULTIHASH_CODE;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the SHA3-256 hash function.
+///
+/// SHA3-256 哈希函数的类型别名。
+pub type Sha3_256 = Sha3Scheme<Sha3_256Params>;
+
+/// A type alias for the SHA3-384 hash function.
+///
+/// SHA3-384 哈希函数的类型别名。
+pub type Sha3_384 = Sha3Scheme<Sha3_384Params>;
+
+/// A type alias for the SHA3-512 hash function.
+///
+/// SHA3-512 哈希函数的类型别名。
+pub type Sha3_512 = Sha3Scheme<Sha3_512Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_sha3_test<P: Sha3Params>(expected_abc: &str) {
+        let one_shot = Sha3Scheme::<P>::hash(b"abc");
+        assert_eq!(one_shot.len(), Sha3Scheme::<P>::OUTPUT_SIZE);
+        assert_eq!(hex::encode(&one_shot), expected_abc);
+
+        // Streaming in several pieces must match the one-shot digest
+        let mut hasher = Sha3Scheme::<P>::default();
+        hasher.update(b"ab");
+        hasher.update(b"c");
+        assert_eq!(hasher.finalize(), one_shot);
+        assert_eq!(Sha3Scheme::<P>::ID, P::ID);
+    }
+
+    #[test]
+    fn test_sha3_256() {
+        run_sha3_test::<Sha3_256Params>(
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+        );
+    }
+
+    #[test]
+    fn test_sha3_384() {
+        run_sha3_test::<Sha3_384Params>(
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c25\
+             96da7cf0e49be4b298d88cea927ac7f539f1edf228376d25",
+        );
+    }
+
+    #[test]
+    fn test_sha3_512() {
+        run_sha3_test::<Sha3_512Params>(
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e\
+             10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0",
+        );
+    }
+
+    #[test]
+    fn test_sha3_empty_input() {
+        assert_eq!(
+            hex::encode(Sha3_256::hash(b"")),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+//! Provides an implementation of the SM3 hash function.
+//!
+//! SM3 is the Chinese national hash standard (GB/T 32905-2016, ISO/IEC 10118-3). Like SHA-256 it
+//! is a Merkle–Damgård construction over 64-byte blocks with a 32-byte digest, and it is the hash
+//! used by SM2 signatures and encryption.
+//!
+//! # Security Considerations
+//! - SM3 digests are subject to length-extension attacks; use HMAC rather than
+//!   `H(key || message)` when authenticating data.
+//!
+//! 提供了 SM3 哈希函数的实现。
+//!
+//! SM3 是中国国家哈希标准（GB/T 32905-2016，ISO/IEC 10118-3）。与 SHA-256 一样，它是基于
+//! 64 字节分组的 Merkle–Damgård 结构，产生 32 字节摘要，也是 SM2 签名和加密所使用的哈希。
+//!
+//! # 安全考虑
+//! - SM3 摘要容易受到长度扩展攻击；认证数据时请使用 HMAC，而不是 `H(key || message)`。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::{errors::Error, prelude::*};
+use crate::traits::hash::write_digest;
+
+const BLOCK_SIZE: usize = 64;
+
+const IV: [u32; 8] = [
+    0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d, 0xb0fb0e4e,
+];
+
+/// The SM3 hash function.
+///
+/// A fresh value (via `Default`) starts a new computation. Feed data with
+/// [`HashFunction::update`] and obtain the digest with [`HashFunction::finalize`].
+///
+/// SM3 哈希函数。
+///
+/// 新值（通过 `Default`）开始一次新的计算。使用 [`HashFunction::update`] 输入数据，
+/// 并使用 [`HashFunction::finalize`] 获取摘要。
+#[derive(Clone, Debug)]
+pub struct Sm3 {
+    state: [u32; 8],
+    buffer: [u8; BLOCK_SIZE],
+    buffer_len: usize,
+    length: u64,
+}
+
+impl Default for Sm3 {
+    fn default() -> Self {
+        Self {
+            state: IV,
+            buffer: [0; BLOCK_SIZE],
+            buffer_len: 0,
+            length: 0,
+        }
+    }
+}
+
+impl Sm3 {
+    fn compress(state: &mut [u32; 8], block: &[u8; BLOCK_SIZE]) {
+        let mut w = [0u32; 68];
+        for (word, chunk) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        for j in 16..68 {
+            w[j] = p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+                ^ w[j - 13].rotate_left(7)
+                ^ w[j - 6];
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for j in 0..64 {
+            let (t, ff, gg) = if j < 16 {
+                (0x79cc4519u32, a ^ b ^ c, e ^ f ^ g)
+            } else {
+                (0x7a879d8a, (a & b) | (a & c) | (b & c), (e & f) | (!e & g))
+            };
+            let a12 = a.rotate_left(12);
+            let ss1 = a12
+                .wrapping_add(e)
+                .wrapping_add(t.rotate_left(j as u32 % 32))
+                .rotate_left(7);
+            let ss2 = ss1 ^ a12;
+            let tt1 = ff
+                .wrapping_add(d)
+                .wrapping_add(ss2)
+                .wrapping_add(w[j] ^ w[j + 4]);
+            let tt2 = gg.wrapping_add(h).wrapping_add(ss1).wrapping_add(w[j]);
+            d = c;
+            c = b.rotate_left(9);
+            b = a;
+            a = tt1;
+            h = g;
+            g = f.rotate_left(19);
+            f = e;
+            e = p0(tt2);
+        }
+
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word ^= value;
+        }
+    }
+
+    /// Pads the buffered input, compresses the last block(s) and returns the digest.
+    ///
+    /// 填充缓冲的输入，压缩最后的块并返回摘要。
+    fn finish(mut self) -> [u8; 32] {
+        let bit_length = self.length.wrapping_mul(8);
+        self.buffer[self.buffer_len] = 0x80;
+        self.buffer[self.buffer_len + 1..].fill(0);
+        if self.buffer_len >= BLOCK_SIZE - 8 {
+            Self::compress(&mut self.state, &self.buffer);
+            self.buffer.fill(0);
+        }
+        self.buffer[BLOCK_SIZE - 8..].copy_from_slice(&bit_length.to_be_bytes());
+        Self::compress(&mut self.state, &self.buffer);
+        let mut digest = [0u8; 32];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+}
+
+fn p0(x: u32) -> u32 {
+    x ^ x.rotate_left(9) ^ x.rotate_left(17)
+}
+
+fn p1(x: u32) -> u32 {
+    x ^ x.rotate_left(15) ^ x.rotate_left(23)
+}
+
+impl Algorithm for Sm3 {
+    fn name() -> String {
+        "SM3".to_string()
+    }
+    const ID: u32 = 0x04_06_00_01;
+}
+
+impl Parameterized for Sm3 {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String("SM3".to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl HashFunction for Sm3 {
+    const OUTPUT_SIZE: usize = 32;
+
+    fn update(&mut self, mut data: &[u8]) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        if self.buffer_len > 0 {
+            let take = (BLOCK_SIZE - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+            if self.buffer_len < BLOCK_SIZE {
+                return;
+            }
+            Self::compress(&mut self.state, &self.buffer);
+            self.buffer_len = 0;
+        }
+        let mut blocks = data.chunks_exact(BLOCK_SIZE);
+        for block in &mut blocks {
+            let mut buffer = [0u8; BLOCK_SIZE];
+            buffer.copy_from_slice(block);
+            Self::compress(&mut self.state, &buffer);
+        }
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        self.finish().to_vec()
+    }
+
+    fn finalize_into(self, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(&self.finish(), output)
+    }
+}
+
+impl MultihashCode for Sm3 {
+    const MULTIHASH_CODE: u64 = 0x534d;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sm3() {
+        // GB/T 32905-2016, appendix A, examples 1 and 2
+        assert_eq!(
+            hex::encode(Sm3::hash(b"abc")),
+            "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0"
+        );
+        assert_eq!(
+            hex::encode(Sm3::hash(&b"abcd".repeat(16))),
+            "debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732"
+        );
+        assert_eq!(
+            hex::encode(Sm3::hash(b"")),
+            "1ab21d8355cfa17f8e61194831e81a8f22bec8c728fefb747ed035eb5082aa2b"
+        );
+        assert_eq!(Sm3::ID, 0x04_06_00_01);
+        assert_eq!(Sm3::name(), "SM3");
+    }
+
+    #[test]
+    fn test_sm3_streaming() {
+        // Every split point around the block and padding boundaries must match the one-shot digest
+        let data: Vec<u8> = (0..200u8).collect();
+        for len in [0, 1, 55, 56, 63, 64, 65, 119, 120, 128, 200] {
+            let one_shot = Sm3::hash(&data[..len]);
+            assert_eq!(one_shot.len(), Sm3::OUTPUT_SIZE);
+            for split in [0, 1.min(len), len / 2, len.saturating_sub(1), len] {
+                let mut hasher = Sm3::default();
+                hasher.update(&data[..split]);
+                hasher.update(&data[split..len]);
+                assert_eq!(hasher.finalize(), one_shot);
+            }
+            assert_eq!(Sm3::hash_array::<32>(&data[..len]).unwrap()[..], one_shot[..]);
+        }
+    }
+
+    #[test]
+    fn test_sm3_multihash() {
+        let multihash = Sm3::hash_multihash(b"abc");
+        assert_eq!(multihash.code(), 0x534d);
+        assert!(Sm3::verify_multihash(b"abc", &multihash));
+        assert!(!Sm3::verify_multihash(b"abd", &multihash));
+    }
+}
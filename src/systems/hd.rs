@@ -0,0 +1,22 @@
+//! Hierarchical deterministic (HD) key derivation implementations.
+//!
+//! This module derives trees of signing keys from a single master seed, so that a fleet of
+//! keys can be backed up and recreated from one secret. Every derived key is an ordinary key
+//! of the crate's ECC schemes.
+//!
+//! # Available Implementations
+//! - **SLIP-0010**: BIP32 derivation paths over secp256k1, NIST P-256 and Ed25519
+//!
+//! 分层确定性 (HD) 密钥派生实现。
+//!
+//! 此模块从单个主种子派生签名密钥树，从而可以用一个秘密备份和重建一组密钥。
+//! 每个派生出的密钥都是本 crate ECC 方案的普通密钥。
+//!
+//! # 可用实现
+//! - **SLIP-0010**: 基于 secp256k1、NIST P-256 和 Ed25519 的 BIP32 派生路径
+
+/// SLIP-0010 (and BIP32) key derivation implementation.
+///
+/// SLIP-0010（及 BIP32）密钥派生实现。
+#[cfg(feature = "hd-default")]
+pub mod slip10;
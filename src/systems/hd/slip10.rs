@@ -0,0 +1,939 @@
+//! Provides hierarchical deterministic key derivation (BIP32 / SLIP-0010).
+//!
+//! BIP32 derives a tree of secp256k1 keys from one seed: the master key is
+//! `HMAC-SHA512("Bitcoin seed", seed)`, and every child is derived from its parent's key and
+//! 32-byte chain code along a path such as `m/44'/0'/0'/0/7`. SLIP-0010 generalizes the same
+//! scheme to other curves by changing the HMAC key; this module implements it for
+//! secp256k1 (which is exactly BIP32), NIST P-256 and Ed25519, and the derived keys are the
+//! crate's [`EcdsaSecp256k1`], [`EcdsaP256`] and [`Ed25519`] key pairs.
+//!
+//! Children with the hardened bit set (`'` or `h` in a path) are derived from the private
+//! key; the others are derived from the public key, so an [`ExtendedPublicKey`] can compute
+//! them without any secret. Ed25519 only supports hardened derivation.
+//!
+//! # Security Considerations
+//! - The seed must have at least 128 bits of entropy; 16 to 64 bytes are accepted.
+//! - A leaked non-hardened child private key together with its parent extended public key
+//!   reveals the parent private key. Use hardened derivation for account-level keys.
+//! - When an intermediate value is not a valid scalar, which happens with negligible
+//!   probability, derivation is retried as SLIP-0010 specifies instead of skipping the index.
+//!
+//! 提供了分层确定性密钥派生 (BIP32 / SLIP-0010)。
+//!
+//! BIP32 从一个种子派生 secp256k1 密钥树：主密钥为 `HMAC-SHA512("Bitcoin seed", seed)`，
+//! 每个子密钥都沿 `m/44'/0'/0'/0/7` 等路径由其父密钥和 32 字节链码派生。SLIP-0010 通过更换
+//! HMAC 密钥将同一方案推广到其他曲线；此模块为 secp256k1（即 BIP32）、NIST P-256 和 Ed25519
+//! 实现了它，派生出的密钥是本 crate 的 [`EcdsaSecp256k1`]、[`EcdsaP256`] 和 [`Ed25519`] 密钥对。
+//!
+//! 设置了强化位（路径中的 `'` 或 `h`）的子密钥由私钥派生；其余子密钥由公钥派生，因此
+//! [`ExtendedPublicKey`] 无需任何秘密即可计算它们。Ed25519 仅支持强化派生。
+//!
+//! # 安全考虑
+//! - 种子必须至少具有 128 位熵；接受 16 到 64 字节。
+//! - 泄露的非强化子私钥与其父扩展公钥一起会暴露父私钥。账户级密钥请使用强化派生。
+//! - 当某个中间值不是有效标量时（其概率可忽略），将按照 SLIP-0010 的规定重试派生，而不是跳过该索引。
+//!
+//! [`EcdsaSecp256k1`]: crate::systems::asymmetric::traditional::ecc::EcdsaSecp256k1
+//! [`EcdsaP256`]: crate::systems::asymmetric::traditional::ecc::EcdsaP256
+//! [`Ed25519`]: crate::systems::asymmetric::traditional::ecc::Ed25519
+
+use alloc::vec::Vec;
+use crate::systems::asymmetric::traditional::ecc::{
+    EccParams, EccPrivateKey, EccPublicKey, EcdsaP256Params, EcdsaSecp256k1Params, Ed25519Params,
+};
+use crate::{errors::Error, prelude::*};
+use ed25519_dalek::{SigningKey as Ed25519SigningKey, VerifyingKey as Ed25519VerifyingKey};
+use elliptic_curve::group::{Curve as _, Group as _};
+use elliptic_curve::pkcs8::{AssociatedOid, EncodePublicKey};
+use elliptic_curve::sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint};
+use elliptic_curve::{
+    AffinePoint, CurveArithmetic, FieldBytesSize, NonZeroScalar, ProjectivePoint, PublicKey,
+    Scalar, ScalarPrimitive, SecretKey,
+};
+use k256::Secp256k1;
+use p256::NistP256;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use zeroize::Zeroizing;
+
+/// The smallest accepted seed length in bytes.
+///
+/// 可接受的最小种子长度（以字节为单位）。
+pub const MIN_SEED_SIZE: usize = 16;
+
+/// The largest accepted seed length in bytes.
+///
+/// 可接受的最大种子长度（以字节为单位）。
+pub const MAX_SEED_SIZE: usize = 64;
+
+// ------------------- Derivation Paths -------------------
+// ------------------- 派生路径 -------------------
+
+/// The index of a child key, with the hardened bit `2^31` set for hardened children.
+///
+/// 子密钥的索引，强化子密钥设置了强化位 `2^31`。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChildNumber(u32);
+
+impl ChildNumber {
+    /// The bit that marks a hardened child.
+    ///
+    /// 标记强化子密钥的位。
+    pub const HARDENED_BIT: u32 = 1 << 31;
+
+    /// Creates the non-hardened child number `index`.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if `index` is `2^31` or larger.
+    ///
+    /// 创建非强化子密钥编号 `index`。
+    ///
+    /// 如果 `index` 大于或等于 `2^31`，则以 `KdfError::InvalidParameter` 失败。
+    pub fn normal(index: u32) -> Result<Self, Error> {
+        if index & Self::HARDENED_BIT != 0 {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        Ok(Self(index))
+    }
+
+    /// Creates the hardened child number `index'`.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if `index` is `2^31` or larger.
+    ///
+    /// 创建强化子密钥编号 `index'`。
+    ///
+    /// 如果 `index` 大于或等于 `2^31`，则以 `KdfError::InvalidParameter` 失败。
+    pub fn hardened(index: u32) -> Result<Self, Error> {
+        Self::normal(index).map(|child| Self(child.0 | Self::HARDENED_BIT))
+    }
+
+    /// Returns whether this is a hardened child.
+    ///
+    /// 返回这是否是强化子密钥。
+    pub fn is_hardened(&self) -> bool {
+        self.0 & Self::HARDENED_BIT != 0
+    }
+
+    /// Returns the index without the hardened bit.
+    ///
+    /// 返回不含强化位的索引。
+    pub fn index(&self) -> u32 {
+        self.0 & !Self::HARDENED_BIT
+    }
+}
+
+impl From<u32> for ChildNumber {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ChildNumber> for u32 {
+    fn from(child: ChildNumber) -> Self {
+        child.0
+    }
+}
+
+impl fmt::Display for ChildNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.index())?;
+        if self.is_hardened() {
+            f.write_str("'")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ChildNumber {
+    type Err = Error;
+
+    /// Parses `7` as a normal child and `7'`, `7h` or `7H` as a hardened child.
+    ///
+    /// 将 `7` 解析为普通子密钥，将 `7'`、`7h` 或 `7H` 解析为强化子密钥。
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, hardened) = match s.strip_suffix(['\'', 'h', 'H']) {
+            Some(digits) => (digits, true),
+            None => (s, false),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        let index = digits
+            .parse::<u32>()
+            .map_err(|_| Error::Kdf(KdfError::InvalidParameter))?;
+        if hardened {
+            Self::hardened(index)
+        } else {
+            Self::normal(index)
+        }
+    }
+}
+
+/// A derivation path such as `m/44'/0'/0'/0/7`, listing the children to derive in order.
+///
+/// 派生路径，例如 `m/44'/0'/0'/0/7`，按顺序列出要派生的子密钥。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    children: Vec<ChildNumber>,
+}
+
+impl DerivationPath {
+    /// Appends `child` to the path.
+    ///
+    /// 将 `child` 追加到路径末尾。
+    pub fn child(mut self, child: ChildNumber) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Returns the children of the path, from the master key down.
+    ///
+    /// 返回路径中的子密钥，从主密钥开始向下排列。
+    pub fn children(&self) -> &[ChildNumber] {
+        &self.children
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+        for child in &self.children {
+            write!(f, "/{child}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Parses a path of the form `m/<child>/<child>/...`; `m` alone is the master key.
+    ///
+    /// 解析 `m/<child>/<child>/...` 形式的路径；单独的 `m` 表示主密钥。
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split('/');
+        if components.next() != Some("m") {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        let children = components
+            .map(ChildNumber::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { children })
+    }
+}
+
+// ------------------- Curve Parameters -------------------
+// ------------------- 曲线参数 -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines how keys of an ECC scheme are derived by SLIP-0010.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义如何通过 SLIP-0010 派生 ECC 方案密钥的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait HdParams: EccParams + Clone + private::Sealed {
+    /// The HMAC key that turns a seed into the master key.
+    ///
+    /// 将种子转换为主密钥的 HMAC 密钥。
+    const SEED_KEY: &'static [u8];
+
+    /// Whether only hardened children can be derived.
+    ///
+    /// 是否只能派生强化子密钥。
+    const HARDENED_ONLY: bool;
+
+    /// Returns the secret `tweak + parent (mod n)`, or `tweak` itself for the master key,
+    /// or `None` if the result is not a valid private key.
+    ///
+    /// 返回秘密 `tweak + parent (mod n)`（对于主密钥则为 `tweak` 本身）；如果结果不是有效私钥，
+    /// 则返回 `None`。
+    fn child_secret(parent: Option<&[u8; 32]>, tweak: &[u8]) -> Option<Zeroizing<[u8; 32]>>;
+
+    /// Returns the 33-byte serialized public key of `secret`.
+    ///
+    /// 返回 `secret` 的 33 字节序列化公钥。
+    fn public_key_bytes(secret: &[u8; 32]) -> Result<[u8; 33], Error>;
+
+    /// Returns the public key `parent + tweak * G`, or `None` if it is not a valid key.
+    ///
+    /// 返回公钥 `parent + tweak * G`；如果它不是有效密钥，则返回 `None`。
+    fn child_public_key(parent: &[u8; 33], tweak: &[u8]) -> Result<Option<[u8; 33]>, Error>;
+
+    /// Encodes a 33-byte serialized public key as SPKI DER.
+    ///
+    /// 将 33 字节序列化公钥编码为 SPKI DER。
+    fn public_key_der(public_key: &[u8; 33]) -> Result<Vec<u8>, Error>;
+}
+
+impl private::Sealed for EcdsaSecp256k1Params {}
+impl HdParams for EcdsaSecp256k1Params {
+    const SEED_KEY: &'static [u8] = b"Bitcoin seed";
+    const HARDENED_ONLY: bool = false;
+
+    fn child_secret(parent: Option<&[u8; 32]>, tweak: &[u8]) -> Option<Zeroizing<[u8; 32]>> {
+        weierstrass_child_secret::<Secp256k1>(parent, tweak)
+    }
+
+    fn public_key_bytes(secret: &[u8; 32]) -> Result<[u8; 33], Error> {
+        weierstrass_public_key_bytes::<Secp256k1>(secret)
+    }
+
+    fn child_public_key(parent: &[u8; 33], tweak: &[u8]) -> Result<Option<[u8; 33]>, Error> {
+        weierstrass_child_public_key::<Secp256k1>(parent, tweak)
+    }
+
+    fn public_key_der(public_key: &[u8; 33]) -> Result<Vec<u8>, Error> {
+        weierstrass_public_key_der::<Secp256k1>(public_key)
+    }
+}
+
+impl private::Sealed for EcdsaP256Params {}
+impl HdParams for EcdsaP256Params {
+    const SEED_KEY: &'static [u8] = b"Nist256p1 seed";
+    const HARDENED_ONLY: bool = false;
+
+    fn child_secret(parent: Option<&[u8; 32]>, tweak: &[u8]) -> Option<Zeroizing<[u8; 32]>> {
+        weierstrass_child_secret::<NistP256>(parent, tweak)
+    }
+
+    fn public_key_bytes(secret: &[u8; 32]) -> Result<[u8; 33], Error> {
+        weierstrass_public_key_bytes::<NistP256>(secret)
+    }
+
+    fn child_public_key(parent: &[u8; 33], tweak: &[u8]) -> Result<Option<[u8; 33]>, Error> {
+        weierstrass_child_public_key::<NistP256>(parent, tweak)
+    }
+
+    fn public_key_der(public_key: &[u8; 33]) -> Result<Vec<u8>, Error> {
+        weierstrass_public_key_der::<NistP256>(public_key)
+    }
+}
+
+impl private::Sealed for Ed25519Params {}
+impl HdParams for Ed25519Params {
+    const SEED_KEY: &'static [u8] = b"ed25519 seed";
+    const HARDENED_ONLY: bool = true;
+
+    // Every 32-byte string is a valid Ed25519 seed, so the tweak is the child key itself.
+    // 任何 32 字节串都是有效的 Ed25519 种子，因此 tweak 本身就是子密钥。
+    fn child_secret(_parent: Option<&[u8; 32]>, tweak: &[u8]) -> Option<Zeroizing<[u8; 32]>> {
+        <[u8; 32]>::try_from(tweak).ok().map(Zeroizing::new)
+    }
+
+    fn public_key_bytes(secret: &[u8; 32]) -> Result<[u8; 33], Error> {
+        let mut public_key = [0u8; 33];
+        public_key[1..].copy_from_slice(
+            Ed25519SigningKey::from_bytes(secret)
+                .verifying_key()
+                .as_bytes(),
+        );
+        Ok(public_key)
+    }
+
+    fn child_public_key(_parent: &[u8; 33], _tweak: &[u8]) -> Result<Option<[u8; 33]>, Error> {
+        Err(Error::Kdf(KdfError::InvalidParameter))
+    }
+
+    fn public_key_der(public_key: &[u8; 33]) -> Result<Vec<u8>, Error> {
+        let (prefix, key) = public_key.split_at(1);
+        if prefix != [0] {
+            return Err(Error::Key(KeyError::InvalidEncoding));
+        }
+        Ed25519VerifyingKey::try_from(key)
+            .ok()
+            .and_then(|key| key.to_public_key_der().ok())
+            .map(|der| der.as_bytes().to_vec())
+            .ok_or(Error::Key(KeyError::InvalidEncoding))
+    }
+}
+
+fn scalar<C: CurveArithmetic>(bytes: &[u8]) -> Option<Scalar<C>> {
+    ScalarPrimitive::<C>::from_slice(bytes)
+        .ok()
+        .map(Scalar::<C>::from)
+}
+
+fn weierstrass_child_secret<C: CurveArithmetic>(
+    parent: Option<&[u8; 32]>,
+    tweak: &[u8],
+) -> Option<Zeroizing<[u8; 32]>> {
+    let mut secret = scalar::<C>(tweak)?;
+    if let Some(parent) = parent {
+        secret += scalar::<C>(parent)?;
+    }
+    let secret = SecretKey::<C>::from(Option::<NonZeroScalar<C>>::from(NonZeroScalar::new(
+        secret,
+    ))?);
+    <[u8; 32]>::try_from(secret.to_bytes().as_slice())
+        .ok()
+        .map(Zeroizing::new)
+}
+
+fn compressed<C>(public_key: &PublicKey<C>) -> Result<[u8; 33], Error>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    <[u8; 33]>::try_from(public_key.to_encoded_point(true).as_bytes())
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
+fn weierstrass_public_key_bytes<C>(secret: &[u8; 32]) -> Result<[u8; 33], Error>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let secret =
+        SecretKey::<C>::from_slice(secret).map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    compressed(&secret.public_key())
+}
+
+fn weierstrass_child_public_key<C>(
+    parent: &[u8; 33],
+    tweak: &[u8],
+) -> Result<Option<[u8; 33]>, Error>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let parent = PublicKey::<C>::from_sec1_bytes(parent)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    let Some(tweak) = scalar::<C>(tweak) else {
+        return Ok(None);
+    };
+    let point = ProjectivePoint::<C>::generator() * tweak + parent.to_projective();
+    match PublicKey::<C>::from_affine(point.to_affine()) {
+        Ok(child) => compressed(&child).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+fn weierstrass_public_key_der<C>(public_key: &[u8; 33]) -> Result<Vec<u8>, Error>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    PublicKey::<C>::from_sec1_bytes(public_key)
+        .ok()
+        .and_then(|key| key.to_public_key_der().ok())
+        .map(|der| der.as_bytes().to_vec())
+        .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+/// Computes `I = HMAC-SHA512(key, data)` and passes `IL` to `accept` until it yields a key,
+/// retrying with `I` for the master key or `0x01 || IR || ser32(index)` for a child.
+///
+/// 计算 `I = HMAC-SHA512(key, data)` 并将 `IL` 传给 `accept`，直到其产生密钥为止；
+/// 对于主密钥使用 `I` 重试，对于子密钥使用 `0x01 || IR || ser32(index)` 重试。
+fn derive_with_retry<T>(
+    key: &[u8],
+    mut data: Zeroizing<Vec<u8>>,
+    index: Option<u32>,
+    mut accept: impl FnMut(&[u8]) -> Result<Option<T>, Error>,
+) -> Result<(T, [u8; 32]), Error> {
+    loop {
+        let i = Zeroizing::new(Sha512::hmac(key, &data)?);
+        let (il, ir) = i.split_at(32);
+        let chain_code =
+            <[u8; 32]>::try_from(ir).map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+        if let Some(derived) = accept(il)? {
+            return Ok((derived, chain_code));
+        }
+        data = match index {
+            Some(index) => Zeroizing::new([&[1u8][..], ir, &index.to_be_bytes()].concat()),
+            None => i,
+        };
+    }
+}
+
+// ------------------- Extended Keys -------------------
+// ------------------- 扩展密钥 -------------------
+
+/// An extended private key: a private key together with its chain code and position in the
+/// derivation tree.
+///
+/// 扩展私钥：私钥及其链码和在派生树中的位置。
+#[derive(Clone)]
+pub struct ExtendedPrivateKey<P: HdParams> {
+    secret: Zeroizing<[u8; 32]>,
+    chain_code: [u8; 32],
+    depth: u8,
+    child_number: ChildNumber,
+    _params: PhantomData<P>,
+}
+
+impl<P: HdParams> ExtendedPrivateKey<P> {
+    /// Derives the master key from `seed`.
+    ///
+    /// Fails with `KeyError::InvalidLength` unless the seed is between [`MIN_SEED_SIZE`] and
+    /// [`MAX_SEED_SIZE`] bytes long.
+    ///
+    /// 从 `seed` 派生主密钥。
+    ///
+    /// 除非种子长度介于 [`MIN_SEED_SIZE`] 和 [`MAX_SEED_SIZE`] 字节之间，否则以
+    /// `KeyError::InvalidLength` 失败。
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        if !(MIN_SEED_SIZE..=MAX_SEED_SIZE).contains(&seed.len()) {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        let (secret, chain_code) =
+            derive_with_retry(P::SEED_KEY, Zeroizing::new(seed.to_vec()), None, |tweak| {
+                Ok(P::child_secret(None, tweak))
+            })?;
+        Ok(Self {
+            secret,
+            chain_code,
+            depth: 0,
+            child_number: ChildNumber(0),
+            _params: PhantomData,
+        })
+    }
+
+    /// Derives the child key `child`.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if the scheme only supports hardened children
+    /// and `child` is not hardened, or if the tree is already 255 levels deep.
+    ///
+    /// 派生子密钥 `child`。
+    ///
+    /// 如果方案仅支持强化子密钥而 `child` 不是强化的，或者树已有 255 层深，则以
+    /// `KdfError::InvalidParameter` 失败。
+    pub fn derive_child(&self, child: ChildNumber) -> Result<Self, Error> {
+        if P::HARDENED_ONLY && !child.is_hardened() {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(Error::Kdf(KdfError::InvalidParameter))?;
+
+        let mut data = Zeroizing::new(Vec::with_capacity(37));
+        if child.is_hardened() {
+            data.push(0);
+            data.extend_from_slice(self.secret.as_slice());
+        } else {
+            data.extend_from_slice(&P::public_key_bytes(&self.secret)?);
+        }
+        data.extend_from_slice(&child.0.to_be_bytes());
+
+        let (secret, chain_code) =
+            derive_with_retry(&self.chain_code, data, Some(child.0), |tweak| {
+                Ok(P::child_secret(Some(&self.secret), tweak))
+            })?;
+        Ok(Self {
+            secret,
+            chain_code,
+            depth,
+            child_number: child,
+            _params: PhantomData,
+        })
+    }
+
+    /// Derives the key at `path` below this key.
+    ///
+    /// 派生此密钥之下位于 `path` 的密钥。
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.children()
+            .iter()
+            .try_fold(self.clone(), |key, &child| key.derive_child(child))
+    }
+
+    /// Returns the key pair of this node.
+    ///
+    /// 返回此节点的密钥对。
+    pub fn keypair(&self) -> Result<(EccPublicKey<P>, EccPrivateKey<P>), Error> {
+        let (public_key, private_key) = P::keypair_from_secret(self.secret.as_slice())?;
+        Ok((
+            EccPublicKey::from_bytes(&public_key)?,
+            EccPrivateKey::from_bytes(&private_key)?,
+        ))
+    }
+
+    /// Returns the extended public key of this node.
+    ///
+    /// 返回此节点的扩展公钥。
+    pub fn extended_public_key(&self) -> Result<ExtendedPublicKey<P>, Error> {
+        Ok(ExtendedPublicKey {
+            public_key: P::public_key_bytes(&self.secret)?,
+            chain_code: self.chain_code,
+            depth: self.depth,
+            child_number: self.child_number,
+            _params: PhantomData,
+        })
+    }
+
+    /// Returns the raw 32-byte private scalar (or Ed25519 seed).
+    ///
+    /// 返回原始的 32 字节私有标量（或 Ed25519 种子）。
+    pub fn expose_raw(&self) -> &[u8] {
+        self.secret.as_slice()
+    }
+
+    /// Returns the chain code.
+    ///
+    /// 返回链码。
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Returns the depth of this node in the tree; the master key has depth 0.
+    ///
+    /// 返回此节点在树中的深度；主密钥的深度为 0。
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the child number this node was derived with (0 for the master key).
+    ///
+    /// 返回派生此节点时使用的子密钥编号（主密钥为 0）。
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+}
+
+impl<P: HdParams> fmt::Debug for ExtendedPrivateKey<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("depth", &self.depth)
+            .field("child_number", &self.child_number)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An extended public key: a serialized public key together with its chain code and
+/// position in the derivation tree.
+///
+/// 扩展公钥：序列化公钥及其链码和在派生树中的位置。
+#[derive(Clone, Debug)]
+pub struct ExtendedPublicKey<P: HdParams> {
+    public_key: [u8; 33],
+    chain_code: [u8; 32],
+    depth: u8,
+    child_number: ChildNumber,
+    _params: PhantomData<P>,
+}
+
+impl<P: HdParams> ExtendedPublicKey<P> {
+    /// Derives the non-hardened child public key `child`.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if `child` is hardened, if the scheme only
+    /// supports hardened children, or if the tree is already 255 levels deep.
+    ///
+    /// 派生非强化子公钥 `child`。
+    ///
+    /// 如果 `child` 是强化的、方案仅支持强化子密钥，或者树已有 255 层深，则以
+    /// `KdfError::InvalidParameter` 失败。
+    pub fn derive_child(&self, child: ChildNumber) -> Result<Self, Error> {
+        if P::HARDENED_ONLY || child.is_hardened() {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or(Error::Kdf(KdfError::InvalidParameter))?;
+
+        let data = Zeroizing::new([&self.public_key[..], &child.0.to_be_bytes()].concat());
+        let (public_key, chain_code) =
+            derive_with_retry(&self.chain_code, data, Some(child.0), |tweak| {
+                P::child_public_key(&self.public_key, tweak)
+            })?;
+        Ok(Self {
+            public_key,
+            chain_code,
+            depth,
+            child_number: child,
+            _params: PhantomData,
+        })
+    }
+
+    /// Derives the public key at `path` below this key; every child must be non-hardened.
+    ///
+    /// 派生此密钥之下位于 `path` 的公钥；每个子密钥都必须是非强化的。
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self, Error> {
+        path.children()
+            .iter()
+            .try_fold(self.clone(), |key, &child| key.derive_child(child))
+    }
+
+    /// Returns the public key of this node.
+    ///
+    /// 返回此节点的公钥。
+    pub fn public_key(&self) -> Result<EccPublicKey<P>, Error> {
+        EccPublicKey::from_bytes(&P::public_key_der(&self.public_key)?)
+    }
+
+    /// Returns the 33-byte serialized public key: a compressed SEC1 point, or `0x00`
+    /// followed by the Ed25519 public key.
+    ///
+    /// 返回 33 字节的序列化公钥：压缩的 SEC1 点，或 `0x00` 后接 Ed25519 公钥。
+    pub fn as_bytes(&self) -> &[u8; 33] {
+        &self.public_key
+    }
+
+    /// Returns the chain code.
+    ///
+    /// 返回链码。
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Returns the depth of this node in the tree; the master key has depth 0.
+    ///
+    /// 返回此节点在树中的深度；主密钥的深度为 0。
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the child number this node was derived with (0 for the master key).
+    ///
+    /// 返回派生此节点时使用的子密钥编号（主密钥为 0）。
+    pub fn child_number(&self) -> ChildNumber {
+        self.child_number
+    }
+}
+
+impl<P: HdParams> PartialEq for ExtendedPublicKey<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.public_key == other.public_key
+            && self.chain_code == other.chain_code
+            && self.depth == other.depth
+            && self.child_number == other.child_number
+    }
+}
+
+impl<P: HdParams> Eq for ExtendedPublicKey<P> {}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for BIP32 extended private keys over secp256k1.
+///
+/// 基于 secp256k1 的 BIP32 扩展私钥的类型别名。
+pub type Bip32PrivateKey = ExtendedPrivateKey<EcdsaSecp256k1Params>;
+
+/// A type alias for BIP32 extended public keys over secp256k1.
+///
+/// 基于 secp256k1 的 BIP32 扩展公钥的类型别名。
+pub type Bip32PublicKey = ExtendedPublicKey<EcdsaSecp256k1Params>;
+
+/// A type alias for SLIP-0010 extended private keys over NIST P-256.
+///
+/// 基于 NIST P-256 的 SLIP-0010 扩展私钥的类型别名。
+pub type P256ExtendedPrivateKey = ExtendedPrivateKey<EcdsaP256Params>;
+
+/// A type alias for SLIP-0010 extended public keys over NIST P-256.
+///
+/// 基于 NIST P-256 的 SLIP-0010 扩展公钥的类型别名。
+pub type P256ExtendedPublicKey = ExtendedPublicKey<EcdsaP256Params>;
+
+/// A type alias for SLIP-0010 extended private keys over Ed25519.
+///
+/// 基于 Ed25519 的 SLIP-0010 扩展私钥的类型别名。
+pub type Ed25519ExtendedPrivateKey = ExtendedPrivateKey<Ed25519Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecc::EccScheme;
+
+    /// Derives `path` from `seed` and checks the chain code, private key and public key.
+    fn check<P: HdParams>(seed: &str, path: &str, chain_code: &str, secret: &str, public: &str) {
+        let seed = hex::decode(seed).unwrap();
+        let key = ExtendedPrivateKey::<P>::from_seed(&seed)
+            .unwrap()
+            .derive_path(&path.parse().unwrap())
+            .unwrap();
+        assert_eq!(hex::encode(key.chain_code()), chain_code, "{path}");
+        assert_eq!(hex::encode(key.expose_raw()), secret, "{path}");
+        let xpub = key.extended_public_key().unwrap();
+        assert_eq!(hex::encode(xpub.as_bytes()), public, "{path}");
+        assert_eq!(xpub.chain_code(), key.chain_code());
+    }
+
+    const SEED_1: &str = "000102030405060708090a0b0c0d0e0f";
+
+    #[test]
+    fn test_bip32_secp256k1() {
+        // BIP32 test vector 1
+        check::<EcdsaSecp256k1Params>(
+            SEED_1,
+            "m",
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508",
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+            "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2",
+        );
+        check::<EcdsaSecp256k1Params>(
+            SEED_1,
+            "m/0'/1",
+            "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c",
+        );
+        check::<EcdsaSecp256k1Params>(
+            SEED_1,
+            "m/0'/1/2'/2/1000000000",
+            "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+            "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+            "022a471424da5e657499d1ff51cb43c47481a03b1e77f951fe64cec9f5a48f7011",
+        );
+    }
+
+    #[test]
+    fn test_slip10_p256() {
+        // SLIP-0010 test vector 1 for nist256p1
+        check::<EcdsaP256Params>(
+            SEED_1,
+            "m",
+            "beeb672fe4621673f722f38529c07392fecaa61015c80c34f29ce8b41b3cb6ea",
+            "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2",
+            "0266874dc6ade47b3ecd096745ca09bcd29638dd52c2c12117b11ed3e458cfa9e8",
+        );
+        check::<EcdsaP256Params>(
+            SEED_1,
+            "m/0'/1/2'/2/1000000000",
+            "b9b7b82d326bb9cb5b5b121066feea4eb93d5241103c9e7a18aad40f1dde8059",
+            "21c4f269ef0a5fd1badf47eeacebeeaa3de22eb8e5b0adcd0f27dd99d34d0119",
+            "02216cd26d31147f72427a453c443ed2cde8a1e53c9cc44e5ddf739725413fe3f4",
+        );
+
+        // SLIP-0010 derivation retry and seed retry vectors
+        check::<EcdsaP256Params>(
+            SEED_1,
+            "m/28578'/33941",
+            "9e87fe95031f14736774cd82f25fd885065cb7c358c1edf813c72af535e83071",
+            "092154eed4af83e078ff9b84322015aefe5769e31270f62c3f66c33888335f3a",
+            "0235bfee614c0d5b2cae260000bb1d0d84b270099ad790022c1ae0b2e782efe120",
+        );
+        check::<EcdsaP256Params>(
+            "a7305bc8df8d0951f0cb224c0e95d7707cbdf2c6ce7e8d481fec69c7ff5e9446",
+            "m",
+            "7762f9729fed06121fd13f326884c82f59aa95c57ac492ce8c9654e60efd130c",
+            "3b8c18469a4634517d6d0b65448f8e6c62091b45540a1743c5846be55d47d88f",
+            "0383619fadcde31063d8c5cb00dbfe1713f3e6fa169d8541a798752a1c1ca0cb20",
+        );
+    }
+
+    #[test]
+    fn test_slip10_ed25519() {
+        // SLIP-0010 test vector 1 for ed25519
+        check::<Ed25519Params>(
+            SEED_1,
+            "m",
+            "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+            "00a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed",
+        );
+        check::<Ed25519Params>(
+            SEED_1,
+            "m/0H/1H/2H/2H/1000000000H",
+            "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230",
+            "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793",
+            "003c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a",
+        );
+
+        let master = Ed25519ExtendedPrivateKey::from_seed(&[1u8; 32]).unwrap();
+        assert_eq!(
+            master
+                .derive_child(ChildNumber::normal(0).unwrap())
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+        let xpub = master.extended_public_key().unwrap();
+        assert_eq!(
+            xpub.derive_child(ChildNumber::normal(0).unwrap())
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn test_public_derivation() {
+        let seed = hex::decode(SEED_1).unwrap();
+        let account = Bip32PrivateKey::from_seed(&seed)
+            .unwrap()
+            .derive_path(&"m/0'/1/2'".parse().unwrap())
+            .unwrap();
+        let path: DerivationPath = "m/2/1000000000".parse().unwrap();
+        let from_private = account.derive_path(&path).unwrap();
+        let from_public = account
+            .extended_public_key()
+            .unwrap()
+            .derive_path(&path)
+            .unwrap();
+        assert_eq!(from_public, from_private.extended_public_key().unwrap());
+        assert_eq!(from_public.depth(), 5);
+        assert_eq!(
+            from_public.child_number(),
+            ChildNumber::normal(1_000_000_000).unwrap()
+        );
+        assert_eq!(
+            from_public
+                .derive_child(ChildNumber::hardened(0).unwrap())
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+
+        // Derived nodes are ordinary key pairs of the ECC schemes
+        let (pk, sk) = from_private.keypair().unwrap();
+        assert_eq!(pk, from_public.public_key().unwrap());
+        let signature = EccScheme::<EcdsaSecp256k1Params>::sign(&sk, b"message").unwrap();
+        EccScheme::<EcdsaSecp256k1Params>::verify(&pk, b"message", &signature).unwrap();
+
+        let p256 = P256ExtendedPrivateKey::from_seed(&seed).unwrap();
+        let child = ChildNumber::normal(7).unwrap();
+        assert_eq!(
+            p256.extended_public_key()
+                .unwrap()
+                .derive_child(child)
+                .unwrap(),
+            p256.derive_child(child)
+                .unwrap()
+                .extended_public_key()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_derivation_path() {
+        let path: DerivationPath = "m/44'/0h/0H/0/7".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44'/0'/0'/0/7");
+        assert_eq!(
+            path,
+            DerivationPath::default()
+                .child(ChildNumber::hardened(44).unwrap())
+                .child(ChildNumber::hardened(0).unwrap())
+                .child(ChildNumber::hardened(0).unwrap())
+                .child(ChildNumber::normal(0).unwrap())
+                .child(ChildNumber::normal(7).unwrap())
+        );
+        assert_eq!(u32::from(path.children()[0]), 0x8000_002c);
+        assert_eq!(
+            "m".parse::<DerivationPath>().unwrap(),
+            DerivationPath::default()
+        );
+
+        for invalid in ["", "m/", "44'/0'", "m/x", "m/+1", "m/1''", "m/2147483648"] {
+            assert_eq!(
+                invalid.parse::<DerivationPath>().unwrap_err(),
+                Error::Kdf(KdfError::InvalidParameter),
+                "{invalid}"
+            );
+        }
+        assert!(ChildNumber::hardened(1 << 31).is_err());
+        assert_eq!(
+            Bip32PrivateKey::from_seed(&[0u8; 15]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+        assert_eq!(
+            Bip32PrivateKey::from_seed(&[0u8; 65]).unwrap_err(),
+            Error::Key(KeyError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! JSON Object Signing and Encryption (JOSE) implementations.
+//!
+//! This module provides the JOSE message formats, which carry signatures and ciphertexts made
+//! with this crate's schemes as URL-safe strings, e.g. in JSON Web Tokens. Keys are exchanged as
+//! JWKs with [`crate::systems::formats::jwk`].
+//!
+//! # Available Implementations
+//! - **JWS**: JSON Web Signatures (RFC 7515) in the compact serialization
+//! - **JWE**: JSON Web Encryption (RFC 7516) in the compact serialization
+//!
+//! JSON 对象签名和加密 (JOSE) 实现。
+//!
+//! 此模块提供 JOSE 消息格式，它们以 URL 安全字符串的形式承载使用本 crate 方案生成的签名和密文，
+//! 例如在 JSON Web Token 中。密钥通过 [`crate::systems::formats::jwk`] 以 JWK 形式交换。
+//!
+//! # 可用实现
+//! - **JWS**: 紧凑序列化的 JSON Web Signature (RFC 7515)
+//! - **JWE**: 紧凑序列化的 JSON Web Encryption (RFC 7516)
+
+/// JSON Web Signature implementation.
+///
+/// JSON Web Signature 实现。
+#[cfg(feature = "jws-default")]
+pub mod jws;
+
+/// JSON Web Encryption implementation.
+///
+/// JSON Web Encryption 实现。
+#[cfg(feature = "jwe-default")]
+pub mod jwe;
@@ -0,0 +1,1000 @@
+//! Provides JSON Web Encryption (JWE) in the compact serialization.
+//!
+//! A compact JWE (RFC 7516) is `BASE64URL(header) || '.' || BASE64URL(encrypted_key) || '.' ||
+//! BASE64URL(iv) || '.' || BASE64URL(ciphertext) || '.' || BASE64URL(tag)`. A random content
+//! encryption key (CEK) encrypts the plaintext with the `enc` algorithm, authenticating the
+//! encoded header, and the `alg` algorithm delivers the CEK to the recipient.
+//!
+//! # Key Management Algorithms (`alg`)
+//! - **ECDH-ES**: Direct key agreement with an ephemeral ECDH P-256 key
+//!   ([`EcdhP256`](crate::systems::asymmetric::traditional::ecdh::EcdhP256)), whose public key is
+//!   sent in the `epk` header member; the CEK is derived with ConcatKDF and `encrypted_key` is
+//!   empty
+//! - **RSA-OAEP-256**, **RSA-OAEP-384**, **RSA-OAEP-512**: The CEK encrypted with RSA-OAEP by
+//!   the RSA schemes over SHA-256, SHA-384 and SHA-512
+//!
+//! `RSA-OAEP`, which uses SHA-1, is not supported.
+//!
+//! # Content Encryption Algorithms (`enc`)
+//! - **A128GCM**, **A256GCM**: AES-GCM with a 96-bit IV and a 128-bit tag
+//!
+//! # Security Considerations
+//! - A [`JweDecryptor`] only accepts the algorithms it was created with, and the key
+//!   management algorithm must be that of the key it decrypts with.
+//! - Headers with a `crit` or `zip` member are rejected, as neither header extensions nor
+//!   compression are supported.
+//! - All failures after the header has been accepted are reported as
+//!   `SymmetricError::Decryption`; an RSA-OAEP failure is not distinguished from an invalid tag,
+//!   as RFC 7516 recommends.
+//! - A JWE does not authenticate its sender: anyone with the recipient's public key can create
+//!   one. Sign the payload as a JWS first if the sender matters.
+//!
+//! 提供了紧凑序列化的 JSON Web Encryption (JWE)。
+//!
+//! 紧凑 JWE (RFC 7516) 为 `BASE64URL(header) || '.' || BASE64URL(encrypted_key) || '.' ||
+//! BASE64URL(iv) || '.' || BASE64URL(ciphertext) || '.' || BASE64URL(tag)`。随机的内容加密密钥
+//! (CEK) 使用 `enc` 算法加密明文并认证编码后的头部，`alg` 算法则将 CEK 传递给接收方。
+//!
+//! # 密钥管理算法 (`alg`)
+//! - **ECDH-ES**: 使用临时 ECDH P-256 密钥
+//!   ([`EcdhP256`](crate::systems::asymmetric::traditional::ecdh::EcdhP256)) 的直接密钥协商，其
+//!   公钥在 `epk` 头部成员中发送；CEK 由 ConcatKDF 派生，`encrypted_key` 为空
+//! - **RSA-OAEP-256**、**RSA-OAEP-384**、**RSA-OAEP-512**: 由基于 SHA-256、SHA-384 和 SHA-512
+//!   的 RSA 方案使用 RSA-OAEP 加密的 CEK
+//!
+//! 不支持使用 SHA-1 的 `RSA-OAEP`。
+//!
+//! # 内容加密算法 (`enc`)
+//! - **A128GCM**、**A256GCM**: 使用 96 位 IV 和 128 位标签的 AES-GCM
+//!
+//! # 安全考虑
+//! - [`JweDecryptor`] 只接受创建它时给定的算法，并且密钥管理算法必须是用于解密的密钥的算法。
+//! - 带有 `crit` 或 `zip` 成员的头部会被拒绝，因为既不支持头部扩展也不支持压缩。
+//! - 头部被接受之后的所有失败都报告为 `SymmetricError::Decryption`；按照 RFC 7516 的建议，
+//!   RSA-OAEP 失败与无效标签不作区分。
+//! - JWE 不认证其发送方：任何拥有接收方公钥的人都可以创建它。如果发送方很重要，请先将载荷签名
+//!   为 JWS。
+
+#[cfg(feature = "rsa-default")]
+use alloc::vec;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::{NonceRng, SystemRng};
+use crate::systems::aead::aes_gcm::{Aes128Gcm, Aes256Gcm};
+use crate::systems::formats::jwk::Jwk;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use rand_core_elliptic_curve::RngCore;
+use serde::{Deserialize, Serialize};
+
+/// The length in bytes of the AES-GCM IV.
+const IV_SIZE: usize = 12;
+
+/// The length in bytes of the AES-GCM tag.
+const TAG_SIZE: usize = 16;
+
+// ------------------- Algorithms -------------------
+// ------------------- 算法 -------------------
+
+/// A JWE key management algorithm, the `alg` header member.
+///
+/// JWE 密钥管理算法，即 `alg` 头部成员。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JweAlgorithm {
+    /// ECDH-ES direct key agreement.
+    ///
+    /// ECDH-ES 直接密钥协商。
+    EcdhEs,
+    /// RSA-OAEP using SHA-256 and MGF1 with SHA-256.
+    ///
+    /// 使用 SHA-256 和基于 SHA-256 的 MGF1 的 RSA-OAEP。
+    RsaOaep256,
+    /// RSA-OAEP using SHA-384 and MGF1 with SHA-384.
+    ///
+    /// 使用 SHA-384 和基于 SHA-384 的 MGF1 的 RSA-OAEP。
+    RsaOaep384,
+    /// RSA-OAEP using SHA-512 and MGF1 with SHA-512.
+    ///
+    /// 使用 SHA-512 和基于 SHA-512 的 MGF1 的 RSA-OAEP。
+    RsaOaep512,
+}
+
+impl JweAlgorithm {
+    /// Returns the registered name of the algorithm, e.g. `"ECDH-ES"`.
+    ///
+    /// 返回算法的注册名称，例如 `"ECDH-ES"`。
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::EcdhEs => "ECDH-ES",
+            Self::RsaOaep256 => "RSA-OAEP-256",
+            Self::RsaOaep384 => "RSA-OAEP-384",
+            Self::RsaOaep512 => "RSA-OAEP-512",
+        }
+    }
+
+    /// Looks up an algorithm by its registered name.
+    ///
+    /// 按注册名称查找算法。
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::EcdhEs,
+            Self::RsaOaep256,
+            Self::RsaOaep384,
+            Self::RsaOaep512,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.name() == name)
+    }
+}
+
+/// A JWE content encryption algorithm, the `enc` header member.
+///
+/// JWE 内容加密算法，即 `enc` 头部成员。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JweEncryption {
+    /// AES-GCM with a 128-bit key.
+    ///
+    /// 使用 128 位密钥的 AES-GCM。
+    A128Gcm,
+    /// AES-GCM with a 256-bit key.
+    ///
+    /// 使用 256 位密钥的 AES-GCM。
+    A256Gcm,
+}
+
+impl JweEncryption {
+    /// Returns the registered name of the algorithm, e.g. `"A256GCM"`.
+    ///
+    /// 返回算法的注册名称，例如 `"A256GCM"`。
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::A128Gcm => "A128GCM",
+            Self::A256Gcm => "A256GCM",
+        }
+    }
+
+    /// Looks up an algorithm by its registered name.
+    ///
+    /// 按注册名称查找算法。
+    pub fn from_name(name: &str) -> Option<Self> {
+        [Self::A128Gcm, Self::A256Gcm]
+            .into_iter()
+            .find(|encryption| encryption.name() == name)
+    }
+
+    /// Returns the length in bytes of the content encryption key.
+    ///
+    /// 返回内容加密密钥的字节长度。
+    pub fn key_size(self) -> usize {
+        match self {
+            Self::A128Gcm => 16,
+            Self::A256Gcm => 32,
+        }
+    }
+
+    #[cfg(feature = "rsa-default")]
+    fn generate_key(self) -> SymmetricKey {
+        let mut key = SymmetricKey::new(vec![0u8; self.key_size()]);
+        SystemRng::new().fill_bytes(&mut key);
+        key
+    }
+
+    fn encrypt(
+        self,
+        key: &SymmetricKey,
+        iv: &[u8],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::A128Gcm => Aes128Gcm::encrypt(key, iv, plaintext, Some(aad)),
+            Self::A256Gcm => Aes256Gcm::encrypt(key, iv, plaintext, Some(aad)),
+        }
+    }
+
+    fn decrypt(
+        self,
+        key: &SymmetricKey,
+        iv: &[u8],
+        sealed: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::A128Gcm => Aes128Gcm::decrypt(key, iv, sealed, Some(aad)),
+            Self::A256Gcm => Aes256Gcm::decrypt(key, iv, sealed, Some(aad)),
+        }
+    }
+}
+
+/// A trait for asymmetric schemes that have a JWE key management algorithm.
+///
+/// 用于具有 JWE 密钥管理算法的非对称方案的 trait。
+pub trait JweScheme: private::KeyManagement {
+    /// The JWE key management algorithm of the scheme.
+    ///
+    /// 该方案的 JWE 密钥管理算法。
+    const ALGORITHM: JweAlgorithm;
+}
+
+mod private {
+    use super::*;
+
+    /// Delivers the content encryption key to the recipient.
+    pub trait KeyManagement: AsymmetricKeySet {
+        /// Returns the CEK and the encrypted key, adding any members the recipient needs to
+        /// `header`.
+        fn encrypt_key(
+            public_key: &Self::PublicKey,
+            header: &mut JweHeader,
+            encryption: JweEncryption,
+        ) -> Result<(SymmetricKey, Vec<u8>), Error>;
+
+        /// Recovers the CEK from the encrypted key and `header`.
+        fn decrypt_key(
+            private_key: &Self::PrivateKey,
+            header: &JweHeader,
+            encrypted_key: &[u8],
+            encryption: JweEncryption,
+        ) -> Result<SymmetricKey, Error>;
+    }
+}
+
+#[cfg(feature = "ecdh-default")]
+mod ecdh_es {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecdh::EcdhP256;
+    use crate::systems::formats::jwk::JwkScheme;
+    use crate::systems::kdf::concat::{ConcatKdfSha256, FixedInfo};
+
+    /// Derives the CEK from the shared secret `z` (RFC 7518, Section 4.6.2).
+    fn derive_cek(
+        z: &SharedSecret,
+        header: &JweHeader,
+        encryption: JweEncryption,
+    ) -> Result<SymmetricKey, Error> {
+        let key_bits = u32::try_from(encryption.key_size() * 8)
+            .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+        let fixed_info = FixedInfo::jose(
+            encryption.name(),
+            &decode_member(&header.apu)?,
+            &decode_member(&header.apv)?,
+            key_bits,
+        );
+        let cek = ConcatKdfSha256::default().derive_with_fixed_info(
+            z.expose_raw(),
+            &fixed_info,
+            encryption.key_size(),
+        )?;
+        Ok(SymmetricKey::new(cek.as_bytes().to_vec()))
+    }
+
+    impl private::KeyManagement for EcdhP256 {
+        fn encrypt_key(
+            public_key: &Self::PublicKey,
+            header: &mut JweHeader,
+            encryption: JweEncryption,
+        ) -> Result<(SymmetricKey, Vec<u8>), Error> {
+            let (ephemeral_public_key, ephemeral_private_key) = Self::generate_keypair()?;
+            header.epk = Some(Self::public_key_to_jwk(&ephemeral_public_key)?);
+            let z = Self::agree(&ephemeral_private_key, public_key)?;
+            Ok((derive_cek(&z, header, encryption)?, Vec::new()))
+        }
+
+        fn decrypt_key(
+            private_key: &Self::PrivateKey,
+            header: &JweHeader,
+            encrypted_key: &[u8],
+            encryption: JweEncryption,
+        ) -> Result<SymmetricKey, Error> {
+            let decryption = || Error::Symmetric(SymmetricError::Decryption);
+            if !encrypted_key.is_empty() {
+                return Err(decryption());
+            }
+            let epk = header.epk.as_ref().ok_or_else(decryption)?;
+            let ephemeral_public_key = Self::public_key_from_jwk(epk).map_err(|_| decryption())?;
+            let z = Self::agree(private_key, &ephemeral_public_key)?;
+            derive_cek(&z, header, encryption)
+        }
+    }
+
+    impl JweScheme for EcdhP256 {
+        const ALGORITHM: JweAlgorithm = JweAlgorithm::EcdhEs;
+    }
+}
+
+#[cfg(feature = "rsa-default")]
+mod rsa_oaep {
+    use super::*;
+    use crate::systems::asymmetric::traditional::rsa::{RsaKeyParams, RsaScheme};
+
+    impl<KP: RsaKeyParams, H: Hasher> private::KeyManagement for RsaScheme<KP, H> {
+        fn encrypt_key(
+            public_key: &Self::PublicKey,
+            _header: &mut JweHeader,
+            encryption: JweEncryption,
+        ) -> Result<(SymmetricKey, Vec<u8>), Error> {
+            let cek = encryption.generate_key();
+            let encrypted_key = H::rsa_oaep_encrypt(public_key, &cek)?;
+            Ok((cek, encrypted_key))
+        }
+
+        fn decrypt_key(
+            private_key: &Self::PrivateKey,
+            _header: &JweHeader,
+            encrypted_key: &[u8],
+            encryption: JweEncryption,
+        ) -> Result<SymmetricKey, Error> {
+            // A failed decryption continues with a random CEK, so that it fails like an invalid
+            // tag instead of revealing that the OAEP padding was wrong (RFC 7516, Section 11.5)
+            Ok(H::rsa_oaep_decrypt(private_key, encrypted_key)
+                .ok()
+                .filter(|cek| cek.len() == encryption.key_size())
+                .map(SymmetricKey::new)
+                .unwrap_or_else(|| encryption.generate_key()))
+        }
+    }
+
+    impl<KP: RsaKeyParams> JweScheme for RsaScheme<KP, Sha256> {
+        const ALGORITHM: JweAlgorithm = JweAlgorithm::RsaOaep256;
+    }
+
+    impl<KP: RsaKeyParams> JweScheme for RsaScheme<KP, Sha384> {
+        const ALGORITHM: JweAlgorithm = JweAlgorithm::RsaOaep384;
+    }
+
+    impl<KP: RsaKeyParams> JweScheme for RsaScheme<KP, Sha512> {
+        const ALGORITHM: JweAlgorithm = JweAlgorithm::RsaOaep512;
+    }
+}
+
+// ------------------- Header -------------------
+// ------------------- 头部 -------------------
+
+/// A JWE protected header.
+///
+/// JWE 受保护头部。
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JweHeader {
+    alg: String,
+    enc: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    typ: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cty: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    epk: Option<Jwk>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    apu: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    apv: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    zip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    crit: Option<Vec<String>>,
+}
+
+impl JweHeader {
+    /// Creates an empty header; [`Jwe::encrypt`] fills in `alg`, `enc` and, for ECDH-ES, `epk`.
+    ///
+    /// 创建一个空头部；[`Jwe::encrypt`] 会填入 `alg`、`enc`，对于 ECDH-ES 还会填入 `epk`。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the ID of the recipient's key (`kid`).
+    ///
+    /// 设置接收方密钥的 ID (`kid`)。
+    pub fn with_kid(mut self, kid: &str) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+
+    /// Sets the media type of the token (`typ`).
+    ///
+    /// 设置令牌的媒体类型 (`typ`)。
+    pub fn with_typ(mut self, typ: &str) -> Self {
+        self.typ = Some(typ.to_string());
+        self
+    }
+
+    /// Sets the media type of the plaintext (`cty`), e.g. `"JWT"` for a nested JWT.
+    ///
+    /// 设置明文的媒体类型 (`cty`)，例如嵌套 JWT 时为 `"JWT"`。
+    pub fn with_cty(mut self, cty: &str) -> Self {
+        self.cty = Some(cty.to_string());
+        self
+    }
+
+    /// Sets the ECDH-ES agreement PartyUInfo (`apu`), information about the producer.
+    ///
+    /// 设置 ECDH-ES 协商的 PartyUInfo (`apu`)，即关于生成方的信息。
+    pub fn with_apu(mut self, apu: &[u8]) -> Self {
+        self.apu = Some(URL_SAFE_NO_PAD.encode(apu));
+        self
+    }
+
+    /// Sets the ECDH-ES agreement PartyVInfo (`apv`), information about the recipient.
+    ///
+    /// 设置 ECDH-ES 协商的 PartyVInfo (`apv`)，即关于接收方的信息。
+    pub fn with_apv(mut self, apv: &[u8]) -> Self {
+        self.apv = Some(URL_SAFE_NO_PAD.encode(apv));
+        self
+    }
+
+    /// Returns the key management algorithm name (`alg`).
+    ///
+    /// 返回密钥管理算法名称 (`alg`)。
+    pub fn alg(&self) -> &str {
+        &self.alg
+    }
+
+    /// Returns the content encryption algorithm name (`enc`).
+    ///
+    /// 返回内容加密算法名称 (`enc`)。
+    pub fn enc(&self) -> &str {
+        &self.enc
+    }
+
+    /// Returns the key management algorithm, or `None` if `alg` is not supported.
+    ///
+    /// 返回密钥管理算法；如果 `alg` 不受支持，则返回 `None`。
+    pub fn algorithm(&self) -> Option<JweAlgorithm> {
+        JweAlgorithm::from_name(&self.alg)
+    }
+
+    /// Returns the content encryption algorithm, or `None` if `enc` is not supported.
+    ///
+    /// 返回内容加密算法；如果 `enc` 不受支持，则返回 `None`。
+    pub fn encryption(&self) -> Option<JweEncryption> {
+        JweEncryption::from_name(&self.enc)
+    }
+
+    /// Returns the ID of the recipient's key (`kid`).
+    ///
+    /// 返回接收方密钥的 ID (`kid`)。
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Returns the media type of the token (`typ`).
+    ///
+    /// 返回令牌的媒体类型 (`typ`)。
+    pub fn typ(&self) -> Option<&str> {
+        self.typ.as_deref()
+    }
+
+    /// Returns the media type of the plaintext (`cty`).
+    ///
+    /// 返回明文的媒体类型 (`cty`)。
+    pub fn cty(&self) -> Option<&str> {
+        self.cty.as_deref()
+    }
+
+    /// Returns the ephemeral public key of ECDH-ES (`epk`).
+    ///
+    /// 返回 ECDH-ES 的临时公钥 (`epk`)。
+    pub fn epk(&self) -> Option<&Jwk> {
+        self.epk.as_ref()
+    }
+}
+
+/// Decodes an optional base64url header member, absent members being empty.
+#[cfg(feature = "ecdh-default")]
+fn decode_member(value: &Option<String>) -> Result<Vec<u8>, Error> {
+    value.as_deref().map_or(Ok(Vec::new()), |value| {
+        URL_SAFE_NO_PAD
+            .decode(value)
+            .map_err(|_| Error::Symmetric(SymmetricError::InvalidCiphertext))
+    })
+}
+
+// ------------------- Encryption -------------------
+// ------------------- 加密 -------------------
+
+/// Compact JWE encryption.
+///
+/// 紧凑 JWE 加密。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Jwe;
+
+impl Jwe {
+    /// Encrypts `plaintext` to a public key of `S` with the content encryption algorithm
+    /// `encryption`, returning the compact JWE.
+    ///
+    /// 使用内容加密算法 `encryption` 将 `plaintext` 加密给 `S` 的公钥，返回紧凑 JWE。
+    pub fn encrypt<S: JweScheme>(
+        public_key: &S::PublicKey,
+        encryption: JweEncryption,
+        header: &JweHeader,
+        plaintext: &[u8],
+    ) -> Result<String, Error> {
+        let mut header = JweHeader {
+            alg: S::ALGORITHM.name().to_string(),
+            enc: encryption.name().to_string(),
+            epk: None,
+            zip: None,
+            crit: None,
+            ..header.clone()
+        };
+        let (cek, encrypted_key) = S::encrypt_key(public_key, &mut header, encryption)?;
+        let header = serde_json::to_vec(&header)
+            .map_err(|_| Error::Symmetric(SymmetricError::Encryption))?;
+        let mut token = URL_SAFE_NO_PAD.encode(header);
+
+        let mut iv = [0u8; IV_SIZE];
+        NonceRng.fill_bytes(&mut iv);
+        let sealed = encryption.encrypt(&cek, &iv, plaintext, token.as_bytes())?;
+        let (ciphertext, tag) = sealed.split_at(sealed.len() - TAG_SIZE);
+        for part in [&encrypted_key[..], &iv, ciphertext, tag] {
+            token.push('.');
+            token.push_str(&URL_SAFE_NO_PAD.encode(part));
+        }
+        Ok(token)
+    }
+
+    /// Returns the header of `token` without decrypting it.
+    ///
+    /// The header is authenticated only by decryption; use it only to select the key to
+    /// decrypt with.
+    ///
+    /// 返回 `token` 的头部，而不解密它。
+    ///
+    /// 头部只有在解密时才被认证；仅用它来选择用于解密的密钥。
+    pub fn unverified_header(token: &str) -> Result<JweHeader, Error> {
+        Ok(CompactJwe::parse(token)?.header)
+    }
+}
+
+// ------------------- Decryption -------------------
+// ------------------- 解密 -------------------
+
+/// A decrypted JWE.
+///
+/// 已解密的 JWE。
+#[derive(Clone, Debug)]
+pub struct DecryptedJwe {
+    header: JweHeader,
+    plaintext: Vec<u8>,
+}
+
+impl DecryptedJwe {
+    /// Returns the protected header.
+    ///
+    /// 返回受保护头部。
+    pub fn header(&self) -> &JweHeader {
+        &self.header
+    }
+
+    /// Returns the plaintext.
+    ///
+    /// 返回明文。
+    pub fn plaintext(&self) -> &[u8] {
+        &self.plaintext
+    }
+
+    /// Consumes the JWE, returning its plaintext.
+    ///
+    /// 消耗该 JWE，返回其明文。
+    pub fn into_plaintext(self) -> Vec<u8> {
+        self.plaintext
+    }
+}
+
+/// Decrypts compact JWEs that use one of a fixed set of algorithms.
+///
+/// 解密使用一组固定算法之一的紧凑 JWE。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JweDecryptor {
+    algorithms: Vec<JweAlgorithm>,
+    encryptions: Vec<JweEncryption>,
+}
+
+impl JweDecryptor {
+    /// Creates a decryptor that accepts the key management `algorithms` and the content
+    /// `encryptions`.
+    ///
+    /// 创建一个接受密钥管理算法 `algorithms` 和内容加密算法 `encryptions` 的解密者。
+    pub fn new(algorithms: &[JweAlgorithm], encryptions: &[JweEncryption]) -> Self {
+        Self {
+            algorithms: algorithms.to_vec(),
+            encryptions: encryptions.to_vec(),
+        }
+    }
+
+    /// Returns the accepted key management algorithms.
+    ///
+    /// 返回可接受的密钥管理算法。
+    pub fn algorithms(&self) -> &[JweAlgorithm] {
+        &self.algorithms
+    }
+
+    /// Returns the accepted content encryption algorithms.
+    ///
+    /// 返回可接受的内容加密算法。
+    pub fn encryptions(&self) -> &[JweEncryption] {
+        &self.encryptions
+    }
+
+    /// Decrypts `token` with a private key of `S`.
+    ///
+    /// Fails with `KeyError::UnsupportedAlgorithm` if the algorithms of the token are not
+    /// allowed or the key management algorithm is not that of `S`, with
+    /// `SymmetricError::InvalidCiphertext` if the token is malformed, and with
+    /// `SymmetricError::Decryption` if it cannot be decrypted.
+    ///
+    /// 使用 `S` 的私钥解密 `token`。
+    ///
+    /// 如果令牌的算法不被允许或密钥管理算法不是 `S` 的算法，则以
+    /// `KeyError::UnsupportedAlgorithm` 失败；如果令牌格式错误，则以
+    /// `SymmetricError::InvalidCiphertext` 失败；如果无法解密，则以 `SymmetricError::Decryption`
+    /// 失败。
+    pub fn decrypt<S: JweScheme>(
+        &self,
+        token: &str,
+        private_key: &S::PrivateKey,
+    ) -> Result<DecryptedJwe, Error> {
+        let jwe = CompactJwe::parse(token)?;
+        if jwe.algorithm != S::ALGORITHM
+            || !self.algorithms.contains(&jwe.algorithm)
+            || !self.encryptions.contains(&jwe.encryption)
+        {
+            return Err(Error::Key(KeyError::UnsupportedAlgorithm));
+        }
+        let decryption = |_| Error::Symmetric(SymmetricError::Decryption);
+        let cek = S::decrypt_key(private_key, &jwe.header, &jwe.encrypted_key, jwe.encryption)
+            .map_err(decryption)?;
+        let sealed = [&jwe.ciphertext[..], &jwe.tag].concat();
+        let plaintext = jwe
+            .encryption
+            .decrypt(&cek, &jwe.iv, &sealed, jwe.encoded_header.as_bytes())
+            .map_err(decryption)?;
+        Ok(DecryptedJwe {
+            header: jwe.header,
+            plaintext,
+        })
+    }
+}
+
+/// A parsed compact JWE that has not been decrypted.
+struct CompactJwe<'a> {
+    encoded_header: &'a str,
+    header: JweHeader,
+    algorithm: JweAlgorithm,
+    encryption: JweEncryption,
+    encrypted_key: Vec<u8>,
+    iv: Vec<u8>,
+    ciphertext: Vec<u8>,
+    tag: Vec<u8>,
+}
+
+impl<'a> CompactJwe<'a> {
+    fn parse(token: &'a str) -> Result<Self, Error> {
+        let malformed = || Error::Symmetric(SymmetricError::InvalidCiphertext);
+        let parts: Vec<&str> = token.split('.').collect();
+        let [encoded_header, encrypted_key, iv, ciphertext, tag] = parts[..] else {
+            return Err(malformed());
+        };
+        let decode = |part: &str| URL_SAFE_NO_PAD.decode(part).map_err(|_| malformed());
+
+        let header: JweHeader =
+            serde_json::from_slice(&decode(encoded_header)?).map_err(|_| malformed())?;
+        // No header extensions or compression are understood, so both must be rejected
+        if header.crit.is_some() || header.zip.is_some() {
+            return Err(malformed());
+        }
+        let (Some(algorithm), Some(encryption)) = (header.algorithm(), header.encryption()) else {
+            return Err(Error::Key(KeyError::UnsupportedAlgorithm));
+        };
+        let (iv, tag) = (decode(iv)?, decode(tag)?);
+        if iv.len() != IV_SIZE || tag.len() != TAG_SIZE {
+            return Err(malformed());
+        }
+        Ok(Self {
+            encoded_header,
+            header,
+            algorithm,
+            encryption,
+            encrypted_key: decode(encrypted_key)?,
+            iv,
+            ciphertext: decode(ciphertext)?,
+            tag,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a token from a JSON header and the raw remaining parts.
+    fn token(header: &str, parts: [&[u8]; 4]) -> String {
+        let mut token = URL_SAFE_NO_PAD.encode(header);
+        for part in parts {
+            token.push('.');
+            token.push_str(&URL_SAFE_NO_PAD.encode(part));
+        }
+        token
+    }
+
+    /// Replaces part `index` of `token` after applying `f` to its decoded bytes.
+    fn tamper(token: &str, index: usize, f: impl FnOnce(&mut Vec<u8>)) -> String {
+        let mut parts: Vec<String> = token.split('.').map(str::to_string).collect();
+        let mut part = URL_SAFE_NO_PAD.decode(&parts[index]).unwrap_or_default();
+        f(&mut part);
+        parts[index] = URL_SAFE_NO_PAD.encode(part);
+        parts.join(".")
+    }
+
+    #[test]
+    fn test_algorithm_names() {
+        for algorithm in [
+            JweAlgorithm::EcdhEs,
+            JweAlgorithm::RsaOaep256,
+            JweAlgorithm::RsaOaep384,
+            JweAlgorithm::RsaOaep512,
+        ] {
+            assert_eq!(JweAlgorithm::from_name(algorithm.name()), Some(algorithm));
+        }
+        for encryption in [JweEncryption::A128Gcm, JweEncryption::A256Gcm] {
+            assert_eq!(
+                JweEncryption::from_name(encryption.name()),
+                Some(encryption)
+            );
+        }
+        assert_eq!(JweAlgorithm::from_name("RSA-OAEP"), None);
+        assert_eq!(JweAlgorithm::from_name("dir"), None);
+        assert_eq!(JweEncryption::from_name("A128CBC-HS256"), None);
+        assert_eq!(JweEncryption::A128Gcm.key_size(), 16);
+        assert_eq!(JweEncryption::A256Gcm.key_size(), 32);
+    }
+
+    #[test]
+    fn test_rejects_malformed_tokens() {
+        let malformed = Some(Error::Symmetric(SymmetricError::InvalidCiphertext));
+        let header = r#"{"alg":"ECDH-ES","enc":"A128GCM"}"#;
+        let valid_header = URL_SAFE_NO_PAD.encode(header);
+
+        for bad in [
+            String::new(),
+            "a.b.c.d".to_string(),
+            format!("{valid_header}..AAAAAAAAAAAAAAAA.AA.AAAAAAAAAAAAAAAAAAAAAA.AA"),
+            format!("{valid_header}..*.AA.AAAAAAAAAAAAAAAAAAAAAA"),
+            token("not json", [&[], &[0; 12], &[0; 4], &[0; 16]]),
+            token(r#"{"alg":"ECDH-ES"}"#, [&[], &[0; 12], &[0; 4], &[0; 16]]),
+            token(header, [&[], &[0; 11], &[0; 4], &[0; 16]]),
+            token(header, [&[], &[0; 12], &[0; 4], &[0; 15]]),
+        ] {
+            assert_eq!(Jwe::unverified_header(&bad).err(), malformed);
+        }
+
+        // Neither header extensions nor compression are understood.
+        // 既不理解头部扩展，也不理解压缩。
+        for header in [
+            r#"{"alg":"ECDH-ES","enc":"A128GCM","crit":["exp"],"exp":0}"#,
+            r#"{"alg":"ECDH-ES","enc":"A128GCM","zip":"DEF"}"#,
+        ] {
+            let unsupported = token(header, [&[], &[0; 12], &[0; 4], &[0; 16]]);
+            assert_eq!(Jwe::unverified_header(&unsupported).err(), malformed);
+        }
+
+        // Unknown algorithms are never accepted.
+        // 未知算法永远不会被接受。
+        for header in [
+            r#"{"alg":"dir","enc":"A128GCM"}"#,
+            r#"{"alg":"RSA-OAEP","enc":"A128GCM"}"#,
+            r#"{"alg":"ECDH-ES","enc":"A128CBC-HS256"}"#,
+        ] {
+            let unsupported = token(header, [&[], &[0; 12], &[0; 4], &[0; 16]]);
+            assert_eq!(
+                Jwe::unverified_header(&unsupported).err(),
+                Some(Error::Key(KeyError::UnsupportedAlgorithm))
+            );
+        }
+    }
+
+    #[cfg(feature = "ecdh-default")]
+    mod ecdh_es {
+        use super::*;
+        use crate::systems::asymmetric::traditional::ecdh::EcdhP256;
+        use crate::systems::formats::jwk::JwkScheme;
+
+        // Bob's key of RFC 7518, Appendix C. The token was created independently with Alice's
+        // ephemeral key of the same appendix and an IV of 0x00..0x0b.
+        // RFC 7518 附录 C 中 Bob 的密钥。该令牌是使用同一附录中 Alice 的临时密钥和
+        // 0x00..0x0b 的 IV 独立生成的。
+        const BOB_JWK: &str = r#"{"kty":"EC","crv":"P-256",
+            "x":"weNJy2HscCSM6AEDTDg04biOvhFhyyWvOHQfeF_PxMQ",
+            "y":"e8lnCO-AlStT-NJVX-crhB7QRYhiix03illJOVAOyck",
+            "d":"VEmDZpDXXK8p8N0Cndsxs924q6nS1RXFASRl6BfUqdw"}"#;
+        const TOKEN: &str = "eyJhbGciOiJFQ0RILUVTIiwiZW5jIjoiQTEyOEdDTSIsImFwdSI6IlFXeHBZMlUiLCJh\
+            cHYiOiJRbTlpIiwiZXBrIjp7Imt0eSI6IkVDIiwiY3J2IjoiUC0yNTYiLCJ4IjoiZ0kwR0FJTEJkdTdUNTNha3\
+            JGbU15R2NzRjNuNWRPN01td05CSEtXNVNWMCIsInkiOiJTTFdfeFNmZnpsUFdySEVWSTMwREhNXzRlZ1Z3dDNO\
+            UXFlVUQ3bk1GcHBzIn19..AAECAwQFBgcICQoL.OOJ0gW29xd7dIlx_S61IxQjnT9Q6HA.YyP2SxphBXbAq-G2O\
+            DC2rw";
+
+        #[test]
+        fn test_rfc7518_key_agreement() -> Result<(), Error> {
+            let jwk: Jwk = serde_json::from_str(BOB_JWK).unwrap_or_default();
+            let private_key = EcdhP256::private_key_from_jwk(&jwk)?;
+            let decryptor = JweDecryptor::new(&[JweAlgorithm::EcdhEs], &[JweEncryption::A128Gcm]);
+            let decrypted = decryptor.decrypt::<EcdhP256>(TOKEN, &private_key)?;
+            assert_eq!(decrypted.header().algorithm(), Some(JweAlgorithm::EcdhEs));
+            assert_eq!(
+                decrypted.header().encryption(),
+                Some(JweEncryption::A128Gcm)
+            );
+            assert_eq!(decrypted.plaintext(), b"Live long and prosper.");
+            Ok(())
+        }
+
+        #[test]
+        fn test_roundtrip() -> Result<(), Error> {
+            let (public_key, private_key) = EcdhP256::generate_keypair()?;
+            let decryptor = JweDecryptor::new(
+                &[JweAlgorithm::EcdhEs],
+                &[JweEncryption::A128Gcm, JweEncryption::A256Gcm],
+            );
+            let header = JweHeader::new()
+                .with_kid("recipient")
+                .with_cty("JWT")
+                .with_apu(b"Alice")
+                .with_apv(b"Bob");
+
+            for encryption in [JweEncryption::A128Gcm, JweEncryption::A256Gcm] {
+                for plaintext in [&b""[..], b"{\"sub\":\"alice\"}"] {
+                    let token =
+                        Jwe::encrypt::<EcdhP256>(&public_key, encryption, &header, plaintext)?;
+                    let header = Jwe::unverified_header(&token)?;
+                    assert_eq!(header.alg(), "ECDH-ES");
+                    assert_eq!(header.enc(), encryption.name());
+                    assert_eq!(header.kid(), Some("recipient"));
+                    assert_eq!(header.cty(), Some("JWT"));
+                    assert_eq!(header.typ(), None);
+                    assert!(header.epk().is_some());
+                    assert_eq!(token.split('.').nth(1), Some(""));
+
+                    let decrypted = decryptor.decrypt::<EcdhP256>(&token, &private_key)?;
+                    assert_eq!(decrypted.into_plaintext(), plaintext);
+                }
+            }
+
+            // Each token uses a fresh ephemeral key and IV.
+            // 每个令牌都使用新的临时密钥和 IV。
+            let first =
+                Jwe::encrypt::<EcdhP256>(&public_key, JweEncryption::A256Gcm, &header, b"")?;
+            let second =
+                Jwe::encrypt::<EcdhP256>(&public_key, JweEncryption::A256Gcm, &header, b"")?;
+            assert_ne!(first, second);
+            Ok(())
+        }
+
+        #[test]
+        fn test_rejects_disallowed_algorithms() -> Result<(), Error> {
+            let (public_key, private_key) = EcdhP256::generate_keypair()?;
+            let token = Jwe::encrypt::<EcdhP256>(
+                &public_key,
+                JweEncryption::A128Gcm,
+                &JweHeader::new(),
+                b"secret",
+            )?;
+            let unsupported = Some(Error::Key(KeyError::UnsupportedAlgorithm));
+
+            for decryptor in [
+                JweDecryptor::new(&[JweAlgorithm::EcdhEs], &[JweEncryption::A256Gcm]),
+                JweDecryptor::new(&[JweAlgorithm::RsaOaep256], &[JweEncryption::A128Gcm]),
+                JweDecryptor::new(&[], &[]),
+            ] {
+                assert_eq!(
+                    decryptor.decrypt::<EcdhP256>(&token, &private_key).err(),
+                    unsupported
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_rejects_tampering() -> Result<(), Error> {
+            let (public_key, private_key) = EcdhP256::generate_keypair()?;
+            let (_, other_private_key) = EcdhP256::generate_keypair()?;
+            let header = JweHeader::new().with_apu(b"Alice");
+            let token =
+                Jwe::encrypt::<EcdhP256>(&public_key, JweEncryption::A256Gcm, &header, b"secret")?;
+            let decryptor = JweDecryptor::new(&[JweAlgorithm::EcdhEs], &[JweEncryption::A256Gcm]);
+            let failed = Some(Error::Symmetric(SymmetricError::Decryption));
+
+            for index in 2..5 {
+                let tampered = tamper(&token, index, |part| part[0] ^= 1);
+                assert_eq!(
+                    decryptor.decrypt::<EcdhP256>(&tampered, &private_key).err(),
+                    failed
+                );
+            }
+            let with_encrypted_key = tamper(&token, 1, |part| part.push(0));
+            assert_eq!(
+                decryptor
+                    .decrypt::<EcdhP256>(&with_encrypted_key, &private_key)
+                    .err(),
+                failed
+            );
+            assert_eq!(
+                decryptor
+                    .decrypt::<EcdhP256>(&token, &other_private_key)
+                    .err(),
+                failed
+            );
+
+            // A re-encoded header changes both the derived key and the authenticated data.
+            // 重新编码的头部会同时改变派生密钥和被认证的数据。
+            for header in [
+                Jwe::unverified_header(&token)?.with_apu(b"Mallory"),
+                JweHeader {
+                    epk: None,
+                    ..Jwe::unverified_header(&token)?
+                },
+            ] {
+                let header = serde_json::to_vec(&header).unwrap_or_default();
+                let tampered = tamper(&token, 0, |part| *part = header);
+                assert_eq!(
+                    decryptor.decrypt::<EcdhP256>(&tampered, &private_key).err(),
+                    failed
+                );
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rsa-default")]
+    #[test]
+    fn test_rsa_oaep() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::rsa::Rsa2048;
+
+        let (public_key, private_key) = Rsa2048::<Sha256>::generate_keypair()?;
+        let token = Jwe::encrypt::<Rsa2048<Sha256>>(
+            &public_key,
+            JweEncryption::A256Gcm,
+            &JweHeader::new().with_typ("JWT"),
+            b"secret",
+        )?;
+        assert_eq!(Jwe::unverified_header(&token)?.alg(), "RSA-OAEP-256");
+        assert!(Jwe::unverified_header(&token)?.epk().is_none());
+
+        let decryptor = JweDecryptor::new(
+            &[JweAlgorithm::RsaOaep256, JweAlgorithm::RsaOaep512],
+            &[JweEncryption::A256Gcm],
+        );
+        let decrypted = decryptor.decrypt::<Rsa2048<Sha256>>(&token, &private_key)?;
+        assert_eq!(decrypted.header().typ(), Some("JWT"));
+        assert_eq!(decrypted.plaintext(), b"secret");
+        assert_eq!(
+            decryptor
+                .decrypt::<Rsa2048<Sha512>>(&token, &private_key)
+                .err(),
+            Some(Error::Key(KeyError::UnsupportedAlgorithm))
+        );
+
+        // An invalid encrypted key fails like an invalid tag.
+        // 无效的加密密钥与无效标签的失败方式相同。
+        let failed = Some(Error::Symmetric(SymmetricError::Decryption));
+        for tampered in [
+            tamper(&token, 1, |part| part[0] ^= 1),
+            tamper(&token, 1, |part| part.truncate(16)),
+            tamper(&token, 4, |part| part[0] ^= 1),
+        ] {
+            assert_eq!(
+                decryptor
+                    .decrypt::<Rsa2048<Sha256>>(&tampered, &private_key)
+                    .err(),
+                failed
+            );
+        }
+        Ok(())
+    }
+}
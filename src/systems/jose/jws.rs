@@ -0,0 +1,825 @@
+//! Provides JSON Web Signatures (JWS) in the compact serialization.
+//!
+//! A compact JWS (RFC 7515) is `BASE64URL(header) || '.' || BASE64URL(payload) || '.' ||
+//! BASE64URL(signature)`, where the signature covers the first two parts and the JSON header
+//! names the algorithm in its `alg` member. JSON Web Tokens are compact JWSs whose payload is
+//! a JSON claims set.
+//!
+//! # Algorithms
+//! - **ES256**: ECDSA P-256 with SHA-256 ([`EcdsaP256`](crate::systems::asymmetric::traditional::ecc::EcdsaP256))
+//! - **ES256K**: ECDSA secp256k1 with SHA-256 (RFC 8812), not available with the `fips` feature
+//! - **EdDSA**: Ed25519 (RFC 8037)
+//! - **PS256**, **PS384**, **PS512**: RSA-PSS with the RSA schemes over SHA-256, SHA-384 and
+//!   SHA-512
+//!
+//! The Dilithium scheme is the round 3 submission, which is not interoperable with ML-DSA, so it
+//! has no JWS algorithm.
+//!
+//! # Algorithm Allow-Lists
+//! The `alg` member of a received token is chosen by whoever produced it. A [`JwsVerifier`]
+//! only accepts the algorithms it was created with, and checks that the algorithm belongs to
+//! the key it verifies with, so a token cannot make the verifier use another algorithm, e.g.
+//! `none`, or use a key for an algorithm it was not meant for.
+//!
+//! # Security Considerations
+//! - Create one verifier per use with the algorithms its keys actually use.
+//! - Headers with a `crit` member are rejected, as no header extensions are supported.
+//! - [`Jws::unverified_header`] returns the header before the signature is checked; use it only
+//!   to select a key.
+//! - RSA keys read from JWKs must have at least 2048 bits, as RFC 7518 requires.
+//!
+//! 提供了紧凑序列化的 JSON Web Signature (JWS)。
+//!
+//! 紧凑 JWS (RFC 7515) 为 `BASE64URL(header) || '.' || BASE64URL(payload) || '.' ||
+//! BASE64URL(signature)`，其中签名覆盖前两部分，JSON 头部在其 `alg` 成员中指明算法。JSON Web
+//! Token 就是载荷为 JSON 声明集的紧凑 JWS。
+//!
+//! # 算法
+//! - **ES256**: 使用 SHA-256 的 ECDSA P-256 ([`EcdsaP256`](crate::systems::asymmetric::traditional::ecc::EcdsaP256))
+//! - **ES256K**: 使用 SHA-256 的 ECDSA secp256k1 (RFC 8812)，启用 `fips` 特性时不可用
+//! - **EdDSA**: Ed25519 (RFC 8037)
+//! - **PS256**、**PS384**、**PS512**: 基于 SHA-256、SHA-384 和 SHA-512 的 RSA 方案的 RSA-PSS
+//!
+//! Dilithium 方案是第三轮提交版本，与 ML-DSA 不能互操作，因此没有 JWS 算法。
+//!
+//! # 算法允许列表
+//! 收到的令牌的 `alg` 成员由其生成者选择。[`JwsVerifier`] 只接受创建它时给定的算法，并检查该
+//! 算法是否属于用于验证的密钥，因此令牌无法让验证者使用其他算法（例如 `none`），也无法让密钥
+//! 被用于非预期的算法。
+//!
+//! # 安全考虑
+//! - 为每种用途创建一个验证者，只包含其密钥实际使用的算法。
+//! - 带有 `crit` 成员的头部会被拒绝，因为不支持任何头部扩展。
+//! - [`Jws::unverified_header`] 在检查签名之前返回头部；仅用它来选择密钥。
+//! - 按照 RFC 7518 的要求，从 JWK 读取的 RSA 密钥必须至少有 2048 位。
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::systems::formats::jwk::{Jwk, JwkSet};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+
+#[cfg(any(feature = "ecc-default", feature = "rsa-default"))]
+use crate::systems::formats::jwk::JwkScheme;
+
+// ------------------- Algorithms -------------------
+// ------------------- 算法 -------------------
+
+/// A JWS algorithm, the `alg` header member.
+///
+/// JWS 算法，即 `alg` 头部成员。
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum JwsAlgorithm {
+    /// ECDSA using P-256 and SHA-256.
+    ///
+    /// 使用 P-256 和 SHA-256 的 ECDSA。
+    Es256,
+    /// ECDSA using secp256k1 and SHA-256.
+    ///
+    /// 使用 secp256k1 和 SHA-256 的 ECDSA。
+    Es256K,
+    /// EdDSA using Ed25519.
+    ///
+    /// 使用 Ed25519 的 EdDSA。
+    EdDsa,
+    /// RSA-PSS using SHA-256.
+    ///
+    /// 使用 SHA-256 的 RSA-PSS。
+    Ps256,
+    /// RSA-PSS using SHA-384.
+    ///
+    /// 使用 SHA-384 的 RSA-PSS。
+    Ps384,
+    /// RSA-PSS using SHA-512.
+    ///
+    /// 使用 SHA-512 的 RSA-PSS。
+    Ps512,
+}
+
+impl JwsAlgorithm {
+    /// Returns the registered name of the algorithm, e.g. `"ES256"`.
+    ///
+    /// 返回算法的注册名称，例如 `"ES256"`。
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Es256 => "ES256",
+            Self::Es256K => "ES256K",
+            Self::EdDsa => "EdDSA",
+            Self::Ps256 => "PS256",
+            Self::Ps384 => "PS384",
+            Self::Ps512 => "PS512",
+        }
+    }
+
+    /// Looks up an algorithm by its registered name.
+    ///
+    /// 按注册名称查找算法。
+    pub fn from_name(name: &str) -> Option<Self> {
+        [
+            Self::Es256,
+            Self::Es256K,
+            Self::EdDsa,
+            Self::Ps256,
+            Self::Ps384,
+            Self::Ps512,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.name() == name)
+    }
+}
+
+/// A trait for signature schemes that have a JWS algorithm.
+///
+/// 用于具有 JWS 算法的签名方案的 trait。
+pub trait JwsScheme: Signer + Verifier {
+    /// The JWS algorithm of the scheme.
+    ///
+    /// 该方案的 JWS 算法。
+    const ALGORITHM: JwsAlgorithm;
+}
+
+#[cfg(feature = "ecc-default")]
+mod ecc {
+    use super::*;
+    #[cfg(not(feature = "fips"))]
+    use crate::systems::asymmetric::traditional::ecc::EcdsaSecp256k1;
+    use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+
+    impl JwsScheme for EcdsaP256 {
+        const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Es256;
+    }
+
+    #[cfg(not(feature = "fips"))]
+    impl JwsScheme for EcdsaSecp256k1 {
+        const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Es256K;
+    }
+
+    impl JwsScheme for Ed25519 {
+        const ALGORITHM: JwsAlgorithm = JwsAlgorithm::EdDsa;
+    }
+}
+
+#[cfg(feature = "rsa-default")]
+mod rsa_pss {
+    use super::*;
+    use crate::systems::asymmetric::traditional::rsa::{RsaKeyParams, RsaScheme};
+
+    impl<KP: RsaKeyParams> JwsScheme for RsaScheme<KP, Sha256> {
+        const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Ps256;
+    }
+
+    impl<KP: RsaKeyParams> JwsScheme for RsaScheme<KP, Sha384> {
+        const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Ps384;
+    }
+
+    impl<KP: RsaKeyParams> JwsScheme for RsaScheme<KP, Sha512> {
+        const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Ps512;
+    }
+}
+
+// ------------------- Header -------------------
+// ------------------- 头部 -------------------
+
+/// A JWS protected header.
+///
+/// JWS 受保护头部。
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwsHeader {
+    alg: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    typ: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cty: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    crit: Option<Vec<String>>,
+}
+
+impl JwsHeader {
+    /// Creates an empty header; [`Jws::sign`] fills in `alg`.
+    ///
+    /// 创建一个空头部；[`Jws::sign`] 会填入 `alg`。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the key ID (`kid`).
+    ///
+    /// 设置密钥 ID (`kid`)。
+    pub fn with_kid(mut self, kid: &str) -> Self {
+        self.kid = Some(kid.to_string());
+        self
+    }
+
+    /// Sets the media type of the token (`typ`), e.g. `"JWT"`.
+    ///
+    /// 设置令牌的媒体类型 (`typ`)，例如 `"JWT"`。
+    pub fn with_typ(mut self, typ: &str) -> Self {
+        self.typ = Some(typ.to_string());
+        self
+    }
+
+    /// Sets the media type of the payload (`cty`).
+    ///
+    /// 设置载荷的媒体类型 (`cty`)。
+    pub fn with_cty(mut self, cty: &str) -> Self {
+        self.cty = Some(cty.to_string());
+        self
+    }
+
+    /// Returns the algorithm name (`alg`).
+    ///
+    /// 返回算法名称 (`alg`)。
+    pub fn alg(&self) -> &str {
+        &self.alg
+    }
+
+    /// Returns the algorithm, or `None` if `alg` is not a supported algorithm.
+    ///
+    /// 返回算法；如果 `alg` 不是受支持的算法，则返回 `None`。
+    pub fn algorithm(&self) -> Option<JwsAlgorithm> {
+        JwsAlgorithm::from_name(&self.alg)
+    }
+
+    /// Returns the key ID (`kid`).
+    ///
+    /// 返回密钥 ID (`kid`)。
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Returns the media type of the token (`typ`).
+    ///
+    /// 返回令牌的媒体类型 (`typ`)。
+    pub fn typ(&self) -> Option<&str> {
+        self.typ.as_deref()
+    }
+
+    /// Returns the media type of the payload (`cty`).
+    ///
+    /// 返回载荷的媒体类型 (`cty`)。
+    pub fn cty(&self) -> Option<&str> {
+        self.cty.as_deref()
+    }
+}
+
+// ------------------- Signing -------------------
+// ------------------- 签名 -------------------
+
+/// Compact JWS signing.
+///
+/// 紧凑 JWS 签名。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Jws;
+
+impl Jws {
+    /// Signs `payload` with a private key of `S`, returning the compact JWS.
+    ///
+    /// The `alg` member of `header` is set to the algorithm of `S`.
+    ///
+    /// 使用 `S` 的私钥对 `payload` 签名，返回紧凑 JWS。
+    ///
+    /// `header` 的 `alg` 成员会被设置为 `S` 的算法。
+    pub fn sign<S: JwsScheme>(
+        private_key: &S::PrivateKey,
+        header: &JwsHeader,
+        payload: &[u8],
+    ) -> Result<String, Error> {
+        let header = JwsHeader {
+            alg: S::ALGORITHM.name().to_string(),
+            crit: None,
+            ..header.clone()
+        };
+        let header =
+            serde_json::to_vec(&header).map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let mut token = URL_SAFE_NO_PAD.encode(header);
+        token.push('.');
+        token.push_str(&URL_SAFE_NO_PAD.encode(payload));
+        let signature = S::sign(private_key, token.as_bytes())?;
+        token.push('.');
+        token.push_str(&URL_SAFE_NO_PAD.encode(signature));
+        Ok(token)
+    }
+
+    /// Returns the header of `token` without verifying its signature.
+    ///
+    /// The header is not authenticated; use it only to select the key to verify with.
+    ///
+    /// 返回 `token` 的头部，而不验证其签名。
+    ///
+    /// 该头部未经认证；仅用它来选择用于验证的密钥。
+    pub fn unverified_header(token: &str) -> Result<JwsHeader, Error> {
+        Ok(CompactJws::parse(token)?.header)
+    }
+}
+
+// ------------------- Verification -------------------
+// ------------------- 验证 -------------------
+
+/// A verified JWS.
+///
+/// 已验证的 JWS。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifiedJws {
+    header: JwsHeader,
+    payload: Vec<u8>,
+}
+
+impl VerifiedJws {
+    /// Returns the protected header.
+    ///
+    /// 返回受保护头部。
+    pub fn header(&self) -> &JwsHeader {
+        &self.header
+    }
+
+    /// Returns the payload.
+    ///
+    /// 返回载荷。
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Consumes the JWS, returning its payload.
+    ///
+    /// 消耗该 JWS，返回其载荷。
+    pub fn into_payload(self) -> Vec<u8> {
+        self.payload
+    }
+}
+
+/// Verifies compact JWSs signed with one of a fixed set of algorithms.
+///
+/// 验证使用一组固定算法之一签名的紧凑 JWS。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JwsVerifier {
+    allowed: Vec<JwsAlgorithm>,
+}
+
+impl JwsVerifier {
+    /// Creates a verifier that accepts the `allowed` algorithms.
+    ///
+    /// 创建一个接受 `allowed` 算法的验证者。
+    pub fn new(allowed: &[JwsAlgorithm]) -> Self {
+        Self {
+            allowed: allowed.to_vec(),
+        }
+    }
+
+    /// Returns the accepted algorithms.
+    ///
+    /// 返回可接受的算法。
+    pub fn allowed(&self) -> &[JwsAlgorithm] {
+        &self.allowed
+    }
+
+    /// Verifies `token` with a public key of `S`.
+    ///
+    /// Fails with `KeyError::UnsupportedAlgorithm` if the algorithm of the token is not allowed
+    /// or is not the algorithm of `S`, and with `SignatureError::InvalidSignature` if the token
+    /// is malformed.
+    ///
+    /// 使用 `S` 的公钥验证 `token`。
+    ///
+    /// 如果令牌的算法不被允许或不是 `S` 的算法，则以 `KeyError::UnsupportedAlgorithm` 失败；
+    /// 如果令牌格式错误，则以 `SignatureError::InvalidSignature` 失败。
+    pub fn verify<S: JwsScheme>(
+        &self,
+        token: &str,
+        public_key: &S::PublicKey,
+    ) -> Result<VerifiedJws, Error> {
+        let jws = self.parse(token)?;
+        if jws.algorithm != S::ALGORITHM {
+            return Err(Error::Key(KeyError::UnsupportedAlgorithm));
+        }
+        S::verify(public_key, jws.signing_input.as_bytes(), &jws.signature)?;
+        Ok(jws.into_verified())
+    }
+
+    /// Verifies `token` with the public key of `jwk`.
+    ///
+    /// Besides the failures of [`JwsVerifier::verify`], fails with `KeyError::UsageNotAllowed`
+    /// if the `use` or `alg` members of `jwk` exclude the algorithm of the token, and with
+    /// `KeyError::InvalidEncoding` if `jwk` is not a key of that algorithm.
+    ///
+    /// 使用 `jwk` 的公钥验证 `token`。
+    ///
+    /// 除 [`JwsVerifier::verify`] 的失败情况外，如果 `jwk` 的 `use` 或 `alg` 成员排除了令牌的
+    /// 算法，则以 `KeyError::UsageNotAllowed` 失败；如果 `jwk` 不是该算法的密钥，则以
+    /// `KeyError::InvalidEncoding` 失败。
+    pub fn verify_with_jwk(&self, token: &str, jwk: &Jwk) -> Result<VerifiedJws, Error> {
+        self.verify_parsed_with_jwk(self.parse(token)?, jwk)
+    }
+
+    /// Verifies `token` with the key of `keys` named by the `kid` header member.
+    ///
+    /// Fails with `SignatureError::Verification` if the token has no `kid` or `keys` has no
+    /// key with that ID.
+    ///
+    /// 使用 `keys` 中由 `kid` 头部成员指定的密钥验证 `token`。
+    ///
+    /// 如果令牌没有 `kid`，或 `keys` 中没有该 ID 的密钥，则以 `SignatureError::Verification`
+    /// 失败。
+    pub fn verify_with_jwk_set(&self, token: &str, keys: &JwkSet) -> Result<VerifiedJws, Error> {
+        let jws = self.parse(token)?;
+        let jwk = jws
+            .header
+            .kid()
+            .and_then(|kid| keys.find(kid))
+            .ok_or(Error::Signature(SignatureError::Verification))?;
+        self.verify_parsed_with_jwk(jws, jwk)
+    }
+
+    fn parse<'a>(&self, token: &'a str) -> Result<CompactJws<'a>, Error> {
+        let jws = CompactJws::parse(token)?;
+        if !self.allowed.contains(&jws.algorithm) {
+            return Err(Error::Key(KeyError::UnsupportedAlgorithm));
+        }
+        Ok(jws)
+    }
+
+    fn verify_parsed_with_jwk(&self, jws: CompactJws<'_>, jwk: &Jwk) -> Result<VerifiedJws, Error> {
+        let name = jws.algorithm.name();
+        if jwk.key_use().is_some_and(|key_use| key_use != "sig")
+            || jwk.alg().is_some_and(|alg| alg != name)
+        {
+            return Err(Error::Key(KeyError::UsageNotAllowed));
+        }
+        let verify =
+            jwk_verifier(jws.algorithm).ok_or(Error::Key(KeyError::UnsupportedAlgorithm))?;
+        verify(jwk, jws.signing_input.as_bytes(), &jws.signature)?;
+        Ok(jws.into_verified())
+    }
+}
+
+/// A parsed compact JWS whose signature has not been verified.
+struct CompactJws<'a> {
+    header: JwsHeader,
+    algorithm: JwsAlgorithm,
+    signing_input: &'a str,
+    payload: Vec<u8>,
+    signature: Signature,
+}
+
+impl<'a> CompactJws<'a> {
+    fn parse(token: &'a str) -> Result<Self, Error> {
+        let malformed = || Error::Signature(SignatureError::InvalidSignature);
+        let (signing_input, signature) = token.rsplit_once('.').ok_or_else(malformed)?;
+        let (header, payload) = signing_input.split_once('.').ok_or_else(malformed)?;
+        let decode = |part: &str| URL_SAFE_NO_PAD.decode(part).map_err(|_| malformed());
+
+        let header: JwsHeader =
+            serde_json::from_slice(&decode(header)?).map_err(|_| malformed())?;
+        // No header extensions are understood, so any critical one must be rejected
+        if header.crit.is_some() {
+            return Err(malformed());
+        }
+        let algorithm = header
+            .algorithm()
+            .ok_or(Error::Key(KeyError::UnsupportedAlgorithm))?;
+        Ok(Self {
+            header,
+            algorithm,
+            signing_input,
+            payload: decode(payload)?,
+            signature: decode(signature)?,
+        })
+    }
+
+    fn into_verified(self) -> VerifiedJws {
+        VerifiedJws {
+            header: self.header,
+            payload: self.payload,
+        }
+    }
+}
+
+/// Verifies a signature with the public key of a JWK.
+type JwkVerify = fn(&Jwk, &[u8], &Signature) -> Result<(), Error>;
+
+#[cfg(feature = "ecc-default")]
+fn verify_with<S: JwsScheme + JwkScheme>(
+    jwk: &Jwk,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<(), Error> {
+    S::verify(&S::public_key_from_jwk(jwk)?, message, signature)
+}
+
+/// The RSA scheme used to verify with RSA JWKs; its key size parameter only matters for key
+/// generation.
+#[cfg(feature = "rsa-default")]
+type JwkRsa<H> = crate::systems::asymmetric::traditional::rsa::Rsa2048<H>;
+
+#[cfg(feature = "rsa-default")]
+fn verify_with_rsa<H: Hasher>(
+    jwk: &Jwk,
+    message: &[u8],
+    signature: &Signature,
+) -> Result<(), Error> {
+    use rsa::traits::PublicKeyParts;
+
+    let public_key = JwkRsa::<H>::public_key_from_jwk(jwk)?;
+    if public_key.inner().size() < 2048 / 8 {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    JwkRsa::<H>::verify(&public_key, message, signature)
+}
+
+fn jwk_verifier(algorithm: JwsAlgorithm) -> Option<JwkVerify> {
+    #[cfg(feature = "ecc-default")]
+    use crate::systems::asymmetric::traditional::ecc as schemes;
+
+    match algorithm {
+        #[cfg(feature = "ecc-default")]
+        JwsAlgorithm::Es256 => Some(verify_with::<schemes::EcdsaP256>),
+        #[cfg(all(feature = "ecc-default", not(feature = "fips")))]
+        JwsAlgorithm::Es256K => Some(verify_with::<schemes::EcdsaSecp256k1>),
+        #[cfg(feature = "ecc-default")]
+        JwsAlgorithm::EdDsa => Some(verify_with::<schemes::Ed25519>),
+        #[cfg(feature = "rsa-default")]
+        JwsAlgorithm::Ps256 => Some(verify_with_rsa::<Sha256>),
+        #[cfg(feature = "rsa-default")]
+        JwsAlgorithm::Ps384 => Some(verify_with_rsa::<Sha384>),
+        #[cfg(feature = "rsa-default")]
+        JwsAlgorithm::Ps512 => Some(verify_with_rsa::<Sha512>),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a token from a JSON header, a payload and a signature.
+    fn token(header: &str, payload: &[u8], signature: &[u8]) -> String {
+        format!(
+            "{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(header),
+            URL_SAFE_NO_PAD.encode(payload),
+            URL_SAFE_NO_PAD.encode(signature)
+        )
+    }
+
+    #[test]
+    fn test_algorithm_names() {
+        for algorithm in [
+            JwsAlgorithm::Es256,
+            JwsAlgorithm::Es256K,
+            JwsAlgorithm::EdDsa,
+            JwsAlgorithm::Ps256,
+            JwsAlgorithm::Ps384,
+            JwsAlgorithm::Ps512,
+        ] {
+            assert_eq!(JwsAlgorithm::from_name(algorithm.name()), Some(algorithm));
+        }
+        assert_eq!(JwsAlgorithm::from_name("none"), None);
+        assert_eq!(JwsAlgorithm::from_name("es256"), None);
+    }
+
+    #[test]
+    fn test_rejects_malformed_tokens() {
+        let verifier = JwsVerifier::new(&[JwsAlgorithm::Es256]);
+        let malformed = Err(Error::Signature(SignatureError::InvalidSignature));
+        let valid_header = URL_SAFE_NO_PAD.encode(r#"{"alg":"ES256"}"#);
+
+        for bad in [
+            String::new(),
+            "a.b".to_string(),
+            format!("{valid_header}.e30.AA.AA"),
+            format!("{valid_header}.e30=.AA"),
+            format!("{valid_header}.e30.*"),
+            token("not json", b"{}", &[0; 64]),
+            token("[]", b"{}", &[0; 64]),
+            token(r#"{"typ":"JWT"}"#, b"{}", &[0; 64]),
+            token(r#"{"alg":"ES256","alg":"ES256"}"#, b"{}", &[0; 64]),
+        ] {
+            assert!(Jws::unverified_header(&bad).is_err());
+            assert_eq!(verifier.verify_with_jwk(&bad, &Jwk::default()), malformed);
+        }
+
+        // Critical header extensions are not understood.
+        // 不理解关键头部扩展。
+        let critical = token(r#"{"alg":"ES256","crit":["exp"],"exp":0}"#, b"{}", &[0; 64]);
+        assert_eq!(
+            verifier.verify_with_jwk(&critical, &Jwk::default()),
+            malformed
+        );
+
+        // `none` and unknown algorithms are never accepted.
+        // `none` 和未知算法永远不会被接受。
+        for alg in ["none", "HS256", "RS256"] {
+            let unsigned = token(&format!(r#"{{"alg":"{alg}"}}"#), b"{}", &[]);
+            assert_eq!(
+                verifier.verify_with_jwk(&unsigned, &Jwk::default()),
+                Err(Error::Key(KeyError::UnsupportedAlgorithm))
+            );
+        }
+    }
+
+    #[cfg(feature = "ecc-default")]
+    mod ecc {
+        use super::*;
+        use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+
+        fn jwk(json: &str) -> Jwk {
+            serde_json::from_str(json).unwrap_or_default()
+        }
+
+        // RFC 7515, Appendix A.3.
+        // RFC 7515 附录 A.3。
+        const ES256_JWK: &str = r#"{"kty":"EC","crv":"P-256",
+            "x":"f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+            "y":"x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"}"#;
+        const ES256_TOKEN: &str = "eyJhbGciOiJFUzI1NiJ9.\
+            eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ.\
+            DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q";
+        const ES256_PAYLOAD: &[u8] =
+            b"{\"iss\":\"joe\",\r\n \"exp\":1300819380,\r\n \"http://example.com/is_root\":true}";
+
+        // RFC 8037, Appendix A.4.
+        // RFC 8037 附录 A.4。
+        const ED25519_JWK: &str = r#"{"kty":"OKP","crv":"Ed25519",
+            "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+            "x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+        const ED25519_TOKEN: &str = "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc.\
+            hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg";
+
+        #[test]
+        fn test_rfc7515_es256() -> Result<(), Error> {
+            let verifier = JwsVerifier::new(&[JwsAlgorithm::Es256]);
+            let jwk = jwk(ES256_JWK);
+            let verified = verifier.verify_with_jwk(ES256_TOKEN, &jwk)?;
+            assert_eq!(verified.header().algorithm(), Some(JwsAlgorithm::Es256));
+            assert_eq!(verified.payload(), ES256_PAYLOAD);
+
+            let public_key = EcdsaP256::public_key_from_jwk(&jwk)?;
+            let verified = verifier.verify::<EcdsaP256>(ES256_TOKEN, &public_key)?;
+            assert_eq!(verified.into_payload(), ES256_PAYLOAD);
+            Ok(())
+        }
+
+        #[test]
+        fn test_rfc8037_ed25519() -> Result<(), Error> {
+            let jwk = jwk(ED25519_JWK);
+            let private_key = Ed25519::private_key_from_jwk(&jwk)?;
+            let token = Jws::sign::<Ed25519>(
+                &private_key,
+                &JwsHeader::new(),
+                b"Example of Ed25519 signing",
+            )?;
+            assert_eq!(token, ED25519_TOKEN);
+
+            let verifier = JwsVerifier::new(&[JwsAlgorithm::EdDsa]);
+            let verified = verifier.verify_with_jwk(&token, &jwk.to_public()?)?;
+            assert_eq!(verified.payload(), b"Example of Ed25519 signing");
+            Ok(())
+        }
+
+        #[test]
+        fn test_sign_and_verify_with_jwk_set() -> Result<(), Error> {
+            let (public_key, private_key) = EcdsaP256::generate_keypair()?;
+            let (other_public_key, _) = Ed25519::generate_keypair()?;
+            let keys = JwkSet::new(vec![
+                Ed25519::public_key_to_jwk(&other_public_key)?.with_kid("other"),
+                EcdsaP256::public_key_to_jwk(&public_key)?
+                    .with_kid("signer")
+                    .with_use("sig")
+                    .with_alg("ES256"),
+            ]);
+
+            let header = JwsHeader::new().with_kid("signer").with_typ("JWT");
+            let token = Jws::sign::<EcdsaP256>(&private_key, &header, br#"{"sub":"alice"}"#)?;
+            let header = Jws::unverified_header(&token)?;
+            assert_eq!(header.alg(), "ES256");
+            assert_eq!(header.kid(), Some("signer"));
+            assert_eq!(header.typ(), Some("JWT"));
+            assert_eq!(header.cty(), None);
+
+            let verifier = JwsVerifier::new(&[JwsAlgorithm::Es256, JwsAlgorithm::EdDsa]);
+            let verified = verifier.verify_with_jwk_set(&token, &keys)?;
+            assert_eq!(verified.header(), &header);
+            assert_eq!(verified.payload(), br#"{"sub":"alice"}"#);
+            assert_eq!(verifier.verify::<EcdsaP256>(&token, &public_key)?, verified);
+
+            // A token without a known `kid` has no key in the set.
+            // 没有已知 `kid` 的令牌在集合中没有对应的密钥。
+            let unnamed = Jws::sign::<EcdsaP256>(&private_key, &JwsHeader::new(), b"{}")?;
+            assert_eq!(
+                verifier.verify_with_jwk_set(&unnamed, &keys),
+                Err(Error::Signature(SignatureError::Verification))
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_allow_list() -> Result<(), Error> {
+            let (public_key, private_key) = EcdsaP256::generate_keypair()?;
+            let (ed_public_key, _) = Ed25519::generate_keypair()?;
+            let token = Jws::sign::<EcdsaP256>(&private_key, &JwsHeader::new(), b"{}")?;
+            let jwk = EcdsaP256::public_key_to_jwk(&public_key)?;
+            let unsupported = Err(Error::Key(KeyError::UnsupportedAlgorithm));
+
+            // The algorithm of the token must be allowed.
+            // 令牌的算法必须被允许。
+            let eddsa_only = JwsVerifier::new(&[JwsAlgorithm::EdDsa]);
+            assert_eq!(eddsa_only.allowed(), &[JwsAlgorithm::EdDsa]);
+            assert_eq!(eddsa_only.verify_with_jwk(&token, &jwk), unsupported);
+            assert_eq!(
+                eddsa_only.verify::<EcdsaP256>(&token, &public_key),
+                unsupported
+            );
+            assert_eq!(
+                JwsVerifier::new(&[]).verify_with_jwk(&token, &jwk),
+                unsupported
+            );
+
+            // It must also be the algorithm of the key.
+            // 它还必须是密钥的算法。
+            let both = JwsVerifier::new(&[JwsAlgorithm::Es256, JwsAlgorithm::EdDsa]);
+            assert_eq!(both.verify::<Ed25519>(&token, &ed_public_key), unsupported);
+            assert_eq!(
+                both.verify_with_jwk(&token, &Ed25519::public_key_to_jwk(&ed_public_key)?),
+                Err(Error::Key(KeyError::InvalidEncoding))
+            );
+
+            // JWKs restricted to another use or algorithm are refused.
+            // 限定为其他用途或算法的 JWK 会被拒绝。
+            let not_allowed = Err(Error::Key(KeyError::UsageNotAllowed));
+            assert_eq!(
+                both.verify_with_jwk(&token, &jwk.clone().with_use("enc")),
+                not_allowed
+            );
+            assert_eq!(
+                both.verify_with_jwk(&token, &jwk.clone().with_alg("ES384")),
+                not_allowed
+            );
+
+            // A token re-labelled with another algorithm does not verify.
+            // 被重新标记为其他算法的令牌无法通过验证。
+            let (_, rest) = token.split_once('.').unwrap_or_default();
+            let relabelled = format!("{}.{rest}", URL_SAFE_NO_PAD.encode(r#"{"alg":"EdDSA"}"#));
+            assert!(both.verify_with_jwk(&relabelled, &jwk).is_err());
+            Ok(())
+        }
+
+        #[test]
+        fn test_rejects_tampering() -> Result<(), Error> {
+            let (public_key, private_key) = EcdsaP256::generate_keypair()?;
+            let token = Jws::sign::<EcdsaP256>(&private_key, &JwsHeader::new(), b"{\"n\":1}")?;
+            let verifier = JwsVerifier::new(&[JwsAlgorithm::Es256]);
+            let (header, rest) = token.split_once('.').unwrap_or_default();
+            let (_, signature) = rest.split_once('.').unwrap_or_default();
+
+            let tampered = format!(
+                "{header}.{}.{signature}",
+                URL_SAFE_NO_PAD.encode(b"{\"n\":2}")
+            );
+            assert_eq!(
+                verifier.verify::<EcdsaP256>(&tampered, &public_key),
+                Err(Error::Signature(SignatureError::Verification))
+            );
+            let truncated = &token[..token.len() - 2];
+            assert!(
+                verifier
+                    .verify::<EcdsaP256>(truncated, &public_key)
+                    .is_err()
+            );
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rsa-default")]
+    #[test]
+    fn test_rsa_pss() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::rsa::Rsa2048;
+        use crate::systems::formats::jwk::JwkScheme;
+
+        let (public_key, private_key) = Rsa2048::<Sha384>::generate_keypair()?;
+        let token = Jws::sign::<Rsa2048<Sha384>>(&private_key, &JwsHeader::new(), b"{}")?;
+        assert_eq!(Jws::unverified_header(&token)?.alg(), "PS384");
+
+        let verifier = JwsVerifier::new(&[JwsAlgorithm::Ps256, JwsAlgorithm::Ps384]);
+        let jwk = Rsa2048::<Sha384>::public_key_to_jwk(&public_key)?;
+        assert_eq!(verifier.verify_with_jwk(&token, &jwk)?.payload(), b"{}");
+        assert_eq!(
+            verifier
+                .verify::<Rsa2048<Sha384>>(&token, &public_key)?
+                .payload(),
+            b"{}"
+        );
+        assert_eq!(
+            verifier.verify::<Rsa2048<Sha256>>(&token, &public_key),
+            Err(Error::Key(KeyError::UnsupportedAlgorithm))
+        );
+        Ok(())
+    }
+}
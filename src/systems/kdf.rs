@@ -7,11 +7,19 @@
 //! - **HKDF**: HMAC-based key derivation for high-entropy inputs
 //! - **PBKDF2**: Password-based key derivation with configurable iterations
 //! - **Argon2**: Modern memory-hard password hashing function
+//! - **scrypt**: Memory-hard password-based KDF, mainly for compatibility
+//! - **KBKDF**: NIST SP 800-108 counter and feedback modes over any MAC
+//! - **X9.63 KDF**: Hash-based KDF with SharedInfo, as used by ECIES
+//! - **ConcatKDF**: NIST SP 800-56C one-step KDF, as used by JOSE ECDH-ES
+//! - **KeySchedule**: Several labeled subkeys from one secret over any KDF
+//! - **HChaCha20 / HSalsa20**: Subkey derivation from a uniformly random 256-bit key
+//! - **crypto_kdf**: libsodium-compatible BLAKE2b subkey derivation by ID and context
 //!
 //! # Usage Guidelines
 //! - Use HKDF when deriving keys from high-entropy sources like shared secrets
 //! - Use PBKDF2 or Argon2 when deriving keys from user passwords
 //! - Always use appropriate iteration counts and unique salts
+//! - Use `StreamingDerivation::reader` (HKDF, X9.63, ConcatKDF) to read long outputs in pieces
 //!
 //! 密钥派生函数 (KDF) 实现。
 //!
@@ -21,11 +29,19 @@
 //! - **HKDF**: 基于 HMAC 的密钥派生，用于高熵输入
 //! - **PBKDF2**: 基于密码的密钥派生，具有可配置的迭代次数
 //! - **Argon2**: 现代内存困难密码哈希函数
+//! - **scrypt**: 内存困难的基于密码的 KDF，主要用于兼容
+//! - **KBKDF**: 基于任意 MAC 的 NIST SP 800-108 计数器和反馈模式
+//! - **X9.63 KDF**: 带 SharedInfo 的基于哈希的 KDF，用于 ECIES
+//! - **ConcatKDF**: NIST SP 800-56C 单步 KDF，用于 JOSE ECDH-ES
+//! - **KeySchedule**: 基于任意 KDF 从一个秘密派生多个带标签的子密钥
+//! - **HChaCha20 / HSalsa20**: 从均匀随机的 256 位密钥派生子密钥
+//! - **crypto_kdf**: 与 libsodium 兼容的按 ID 和上下文进行的 BLAKE2b 子密钥派生
 //!
 //! # 使用指南
 //! - 从高熵源（如共享密钥）派生密钥时使用 HKDF
 //! - 从用户密码派生密钥时使用 PBKDF2 或 Argon2
 //! - 始终使用适当的迭代次数和唯一的盐
+//! - 使用 `StreamingDerivation::reader`（HKDF、X9.63、ConcatKDF）分段读取较长的输出
 
 /// HMAC-based Key Derivation Function (HKDF) implementation.
 ///
@@ -44,3 +60,42 @@ pub mod pbkdf2;
 /// Argon2 密码哈希函数实现。
 #[cfg(feature = "argon2-default")]
 pub mod argon2;
+
+/// scrypt password-based key derivation function implementation.
+///
+/// scrypt 基于密码的密钥派生函数实现。
+#[cfg(feature = "scrypt-default")]
+pub mod scrypt;
+
+/// NIST SP 800-108 key-based KDF implementation.
+///
+/// NIST SP 800-108 基于密钥的 KDF 实现。
+pub mod kbkdf;
+
+/// ANSI X9.63 key derivation function implementation.
+///
+/// ANSI X9.63 密钥派生函数实现。
+#[cfg(feature = "sha2")]
+pub mod x963;
+
+/// NIST SP 800-56C one-step key derivation function (ConcatKDF) implementation.
+///
+/// NIST SP 800-56C 单步密钥派生函数 (ConcatKDF) 实现。
+#[cfg(feature = "sha2")]
+pub mod concat;
+
+/// Labeled multi-output key schedule.
+///
+/// 带标签的多输出密钥调度。
+pub mod schedule;
+
+/// HChaCha20 and HSalsa20 subkey derivation functions.
+///
+/// HChaCha20 和 HSalsa20 子密钥派生函数。
+pub mod subkey;
+
+/// libsodium-compatible `crypto_kdf` subkey derivation.
+///
+/// 与 libsodium 兼容的 `crypto_kdf` 子密钥派生。
+#[cfg(feature = "blake2-default")]
+pub mod crypto_kdf;
@@ -7,11 +7,12 @@
 //! key derivation.
 //!
 //! # Algorithm Variants
-//! This implementation uses **Argon2id**, which combines the benefits of both
-//! Argon2i (data-independent) and Argon2d (data-dependent) variants:
+//! All three variants are available; **Argon2id**, which combines the benefits of both
+//! Argon2i (data-independent) and Argon2d (data-dependent), is the default:
 //! - **Argon2i**: Resistant to side-channel attacks
-//! - **Argon2d**: Resistant to time-memory trade-off attacks
-//! - **Argon2id**: Combines both protections (recommended)
+//! - **Argon2d**: Resistant to time-memory trade-off attacks, but its memory access pattern
+//!   depends on the password; only use it where side channels are not a concern
+//! - **Argon2id**: Combines both protections (recommended, RFC 9106)
 //!
 //! # Security Properties
 //! - **Memory-hard**: Requires significant memory to compute, making parallel attacks expensive
@@ -64,10 +65,10 @@
 //! 安全专家推荐 Argon2 用于需要基于密码的密钥派生的新应用程序。
 //!
 //! # 算法变体
-//! 此实现使用 **Argon2id**，它结合了 Argon2i（数据无关）和 Argon2d（数据相关）变体的优点：
+//! 三种变体均可用；默认使用 **Argon2id**，它结合了 Argon2i（数据无关）和 Argon2d（数据相关）变体的优点：
 //! - **Argon2i**: 抵抗侧信道攻击
-//! - **Argon2d**: 抵抗时间-内存权衡攻击
-//! - **Argon2id**: 结合两种保护（推荐）
+//! - **Argon2d**: 抵抗时间-内存权衡攻击，但其内存访问模式依赖于密码；仅在无需考虑侧信道的场合使用
+//! - **Argon2id**: 结合两种保护（推荐，RFC 9106）
 //!
 //! # 安全属性
 //! - **内存困难**: 需要大量内存来计算，使并行攻击变得昂贵
@@ -113,17 +114,24 @@
 //! - **vs scrypt**: Argon2 对侧信道攻击有更好的抵抗力
 //! - **vs bcrypt**: Argon2 更可配置且更面向未来
 
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use crate::{
     errors::Error,
     traits::{
         algorithm::Algorithm,
-        kdf::{Derivation, DerivedKey, KdfError, PasswordBasedDerivation},
+        kdf::{Derivation, DerivedKey, KdfError, PasswordBasedDerivation, PasswordHasher},
+        key::ExposeSecretBytes,
     },
 };
-use crate::traits::params::{Parameterized, ParamValue};
-#[cfg(feature = "std")]
+use crate::policy;
+use crate::systems::formats::phc::PhcString;
+use crate::traits::params::{ParamValue, Parameterized, SchemeParams};
 use argon2::Argon2 as Argon2_p;
-use secrecy::SecretBox;
+use core::marker::PhantomData;
 
 /// Argon2id default memory cost (in kibibytes). OWASP recommendation: 19 MiB = 19456 KiB.
 /// We use a slightly more conservative value that is a power of 2.
@@ -142,9 +150,84 @@ pub const ARGON2_DEFAULT_T_COST: u32 = 2;
 /// Argon2id 默认并行成本。OWASP 建议值为 1。
 pub const ARGON2_DEFAULT_P_COST: u32 = 1;
 
-/// A struct representing the Argon2id cryptographic system.
+/// The smallest memory cost (in kibibytes) accepted by [`Argon2Builder`]: 7 MiB, the lowest
+/// setting in the OWASP recommendations.
+///
+/// [`Argon2Builder`] 接受的最小内存成本（单位：KiB）：7 MiB，即 OWASP 建议中的最低设置。
+pub const ARGON2_MIN_M_COST: u32 = 7168;
+
+/// The smallest product of memory cost (KiB) and time cost accepted by [`Argon2Builder`].
+/// Every OWASP-recommended setting, from 46 MiB with one pass to 7 MiB with five passes,
+/// meets it.
+///
+/// [`Argon2Builder`] 接受的内存成本（KiB）与时间成本的最小乘积。
+/// 所有 OWASP 建议的设置（从 46 MiB 一次迭代到 7 MiB 五次迭代）都满足该值。
+pub const ARGON2_MIN_COST_PRODUCT: u64 = 35840;
+
+// ------------------- Marker structs and traits for Argon2 parameters -------------------
+// ------------------- 用于 Argon2 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that selects an Argon2 variant.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个用于选择 Argon2 变体的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait Argon2Params: private::Sealed + SchemeParams {
+    const ALGORITHM: argon2::Algorithm;
+}
+
+/// Marker struct for Argon2id parameters.
+///
+/// Argon2id 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Argon2idParams;
+impl private::Sealed for Argon2idParams {}
+impl SchemeParams for Argon2idParams {
+    const NAME: &'static str = "Argon2id";
+    const ID: u32 = 0x03_01_01_01;
+}
+impl Argon2Params for Argon2idParams {
+    const ALGORITHM: argon2::Algorithm = argon2::Algorithm::Argon2id;
+}
+
+/// Marker struct for Argon2i parameters.
+///
+/// Argon2i 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Argon2iParams;
+impl private::Sealed for Argon2iParams {}
+impl SchemeParams for Argon2iParams {
+    const NAME: &'static str = "Argon2i";
+    const ID: u32 = 0x03_01_01_02;
+}
+impl Argon2Params for Argon2iParams {
+    const ALGORITHM: argon2::Algorithm = argon2::Algorithm::Argon2i;
+}
+
+/// Marker struct for Argon2d parameters.
 ///
-/// This struct encapsulates the Argon2id password hashing algorithm with configurable
+/// Argon2d 参数的标记结构体。
+#[derive(Debug, Default, Clone)]
+pub struct Argon2dParams;
+impl private::Sealed for Argon2dParams {}
+impl SchemeParams for Argon2dParams {
+    const NAME: &'static str = "Argon2d";
+    const ID: u32 = 0x03_01_01_03;
+}
+impl Argon2Params for Argon2dParams {
+    const ALGORITHM: argon2::Algorithm = argon2::Algorithm::Argon2d;
+}
+
+// ------------------- Generic Argon2 Implementation -------------------
+// ------------------- 通用 Argon2 实现 -------------------
+
+/// A struct representing the Argon2 cryptographic system for a given variant.
+///
+/// This struct encapsulates the Argon2 password hashing algorithm with configurable
 /// parameters for memory cost, time cost, and parallelism. It provides a secure way
 /// to derive keys from passwords while being resistant to various attack vectors.
 ///
@@ -159,9 +242,9 @@ pub const ARGON2_DEFAULT_P_COST: u32 = 1;
 /// - **t_cost**: Minimum 2 iterations, increase if memory is limited
 /// - **p_cost**: Usually 1, can be increased for multi-core systems
 ///
-/// 代表 Argon2id 加密系统的结构体。
+/// 代表给定变体的 Argon2 加密系统的结构体。
 ///
-/// 此结构体封装了 Argon2id 密码哈希算法，具有可配置的内存成本、时间成本和并行度参数。
+/// 此结构体封装了 Argon2 密码哈希算法，具有可配置的内存成本、时间成本和并行度参数。
 /// 它提供了一种从密码派生密钥的安全方法，同时抵抗各种攻击向量。
 ///
 /// # 安全特性
@@ -175,7 +258,7 @@ pub const ARGON2_DEFAULT_P_COST: u32 = 1;
 /// - **t_cost**: 最少 2 次迭代，如果内存有限可增加
 /// - **p_cost**: 通常为 1，可为多核系统增加
 #[derive(Clone, Debug)]
-pub struct Argon2Scheme {
+pub struct Argon2Scheme<P: Argon2Params = Argon2idParams> {
     /// Memory cost in kibibytes.
     ///
     /// 内存成本（单位：KiB）。
@@ -188,9 +271,10 @@ pub struct Argon2Scheme {
     ///
     /// 并行成本（线程数）。
     pub p_cost: u32,
+    _params: PhantomData<P>,
 }
 
-impl Argon2Scheme {
+impl<P: Argon2Params> Argon2Scheme<P> {
     /// Creates a new Argon2 scheme with specific parameters.
     ///
     /// # Arguments
@@ -219,11 +303,12 @@ impl Argon2Scheme {
             m_cost,
             t_cost,
             p_cost,
+            _params: PhantomData,
         }
     }
 }
 
-impl Default for Argon2Scheme {
+impl<P: Argon2Params> Default for Argon2Scheme<P> {
     /// Creates a new Argon2 scheme with default security parameters based on OWASP recommendations.
     ///
     /// The default parameters provide a good balance between security and performance:
@@ -253,18 +338,175 @@ impl Default for Argon2Scheme {
     }
 }
 
-impl Derivation for Argon2Scheme {}
+// ------------------- Parameter Builder -------------------
+// ------------------- 参数构建器 -------------------
+
+/// A builder that validates Argon2 parameters and can tune them to a time target.
+///
+/// [`build`](Self::build) rejects combinations that Argon2 cannot run as well as ones below
+/// [`ARGON2_MIN_M_COST`] and [`ARGON2_MIN_COST_PRODUCT`], so a weak configuration is caught
+/// when it is written rather than after it has been deployed. An optional memory cap bounds
+/// both `build` and [`calibrate`](Self::calibrate).
+///
+/// 一个验证 Argon2 参数并可按时间目标调整参数的构建器。
+///
+/// [`build`](Self::build) 会拒绝 Argon2 无法运行的组合，以及低于 [`ARGON2_MIN_M_COST`] 和
+/// [`ARGON2_MIN_COST_PRODUCT`] 的组合，从而在编写配置时而不是部署之后发现弱配置。
+/// 可选的内存上限同时约束 `build` 和 [`calibrate`](Self::calibrate)。
+#[derive(Clone, Debug)]
+pub struct Argon2Builder<P: Argon2Params> {
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    max_m_cost: Option<u32>,
+    _params: PhantomData<P>,
+}
+
+impl<P: Argon2Params> Default for Argon2Builder<P> {
+    /// Starts from the default parameters with no memory cap.
+    ///
+    /// 从默认参数开始，且不设内存上限。
+    fn default() -> Self {
+        Self {
+            m_cost: ARGON2_DEFAULT_M_COST,
+            t_cost: ARGON2_DEFAULT_T_COST,
+            p_cost: ARGON2_DEFAULT_P_COST,
+            max_m_cost: None,
+            _params: PhantomData,
+        }
+    }
+}
+
+impl<P: Argon2Params> Argon2Builder<P> {
+    /// Sets the memory cost in kibibytes.
+    ///
+    /// 设置内存成本（单位：KiB）。
+    pub fn m_cost(mut self, m_cost: u32) -> Self {
+        self.m_cost = m_cost;
+        self
+    }
+
+    /// Sets the time cost (iterations).
+    ///
+    /// 设置时间成本（迭代次数）。
+    pub fn t_cost(mut self, t_cost: u32) -> Self {
+        self.t_cost = t_cost;
+        self
+    }
+
+    /// Sets the parallelism cost (threads).
+    ///
+    /// 设置并行成本（线程数）。
+    pub fn p_cost(mut self, p_cost: u32) -> Self {
+        self.p_cost = p_cost;
+        self
+    }
+
+    /// Caps the memory cost, in kibibytes, that the built scheme may use.
+    ///
+    /// 限制所构建方案可使用的内存成本（单位：KiB）。
+    pub fn max_m_cost(mut self, max_m_cost: u32) -> Self {
+        self.max_m_cost = Some(max_m_cost);
+        self
+    }
+
+    /// Validates the parameters and builds the scheme.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if a cost is zero, the parallelism exceeds
+    /// 2^24 - 1, the memory cost is below 8 KiB per lane or [`ARGON2_MIN_M_COST`], or
+    /// `m_cost * t_cost` is below [`ARGON2_MIN_COST_PRODUCT`]. Fails with
+    /// `KdfError::MemoryLimitExceeded` if the memory cost is above the cap.
+    ///
+    /// 验证参数并构建方案。
+    ///
+    /// 如果某个成本为零、并行度超过 2^24 - 1、内存成本低于每通道 8 KiB 或 [`ARGON2_MIN_M_COST`]，
+    /// 或者 `m_cost * t_cost` 低于 [`ARGON2_MIN_COST_PRODUCT`]，则以 `KdfError::InvalidParameter`
+    /// 失败。如果内存成本超过上限，则以 `KdfError::MemoryLimitExceeded` 失败。
+    pub fn build(&self) -> Result<Argon2Scheme<P>, Error> {
+        if self.t_cost == 0
+            || self.p_cost == 0
+            || self.p_cost > 0xFF_FFFF
+            || self.m_cost < 8 * self.p_cost
+            || self.m_cost < ARGON2_MIN_M_COST
+            || u64::from(self.m_cost) * u64::from(self.t_cost) < ARGON2_MIN_COST_PRODUCT
+        {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        if self.max_m_cost.is_some_and(|max| self.m_cost > max) {
+            return Err(Error::Kdf(KdfError::MemoryLimitExceeded));
+        }
+        Ok(Argon2Scheme::new(self.m_cost, self.t_cost, self.p_cost))
+    }
+
+    /// Benchmarks this host and builds a scheme whose derivation takes about `target`.
+    ///
+    /// Memory is the main defence against parallel attacks, so the memory cost starts at the
+    /// cap (or the configured `m_cost` without one) and is only halved while a single pass
+    /// takes longer than `target`. The time cost is then chosen to fill `target`, but never
+    /// below what the minimums require, so on slow hosts the result may take longer than
+    /// `target`. The configured `t_cost` is ignored; `p_cost` is kept.
+    ///
+    /// 对本机进行基准测试，并构建一个派生耗时约为 `target` 的方案。
+    ///
+    /// 内存是抵御并行攻击的主要手段，因此内存成本从上限（若未设置上限则为已配置的 `m_cost`）
+    /// 开始，只有当单次迭代耗时超过 `target` 时才减半。随后选择时间成本以填满 `target`，
+    /// 但绝不低于最小值的要求，因此在较慢的主机上结果可能耗时超过 `target`。
+    /// 已配置的 `t_cost` 会被忽略；`p_cost` 保持不变。
+    #[cfg(all(feature = "std", not(all(target_arch = "wasm32", target_os = "unknown"))))]
+    pub fn calibrate(&self, target: core::time::Duration) -> Result<Argon2Scheme<P>, Error> {
+        use std::time::Instant;
+
+        let floor = ARGON2_MIN_M_COST.max(8 * self.p_cost);
+        let mut tuned = self.clone();
+        tuned.m_cost = self.max_m_cost.unwrap_or(self.m_cost);
+        tuned.t_cost = u32::MAX;
+        tuned.build()?;
+
+        let mut output = [0u8; 32];
+        loop {
+            let params = argon2::Params::new(tuned.m_cost, 1, tuned.p_cost, Some(output.len()))
+                .map_err(|_| Error::Kdf(KdfError::InvalidParameter))?;
+            let argon2 = Argon2_p::new(P::ALGORITHM, argon2::Version::V0x13, params);
+
+            let start = Instant::now();
+            argon2
+                .hash_password_into(b"calibration password", b"calibration salt", &mut output)
+                .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+            let elapsed = start.elapsed();
+
+            if elapsed <= target || tuned.m_cost / 2 < floor {
+                let passes = target.as_secs_f64() / elapsed.as_secs_f64().max(1e-9);
+                let required = ARGON2_MIN_COST_PRODUCT.div_ceil(u64::from(tuned.m_cost));
+                tuned.t_cost = (passes.clamp(1.0, u32::MAX as f64) as u32)
+                    .max(u32::try_from(required).unwrap_or(u32::MAX));
+                return tuned.build();
+            }
+            tuned.m_cost /= 2;
+        }
+    }
+}
+
+impl<P: Argon2Params> Argon2Scheme<P> {
+    /// Returns a builder that validates parameters before creating the scheme.
+    ///
+    /// 返回一个在创建方案前验证参数的构建器。
+    pub fn builder() -> Argon2Builder<P> {
+        Argon2Builder::default()
+    }
+}
+
+impl<P: Argon2Params> Derivation for Argon2Scheme<P> {}
 
-impl Algorithm for Argon2Scheme {
+impl<P: Argon2Params> Algorithm for Argon2Scheme<P> {
     fn name() -> String {
-        "Argon2id".to_string()
+        P::NAME.to_string()
     }
-    const ID: u32 = 0x03_01_01_01;
+    const ID: u32 = P::ID;
 }
 
-impl Parameterized for Argon2Scheme {
+impl<P: Argon2Params> Parameterized for Argon2Scheme<P> {
     fn get_type_params() -> Vec<(&'static str, ParamValue)> {
-        vec![]
+        vec![("variant", ParamValue::String(P::NAME.to_string()))]
     }
 
     fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
@@ -276,19 +518,28 @@ impl Parameterized for Argon2Scheme {
     }
 }
 
-impl PasswordBasedDerivation for Argon2Scheme {
+impl<P: Argon2Params> PasswordBasedDerivation for Argon2Scheme<P> {
     fn derive(
         &self,
-        password: &SecretBox<[u8]>,
+        password: &impl ExposeSecretBytes,
         salt: &[u8],
         output_len: usize,
     ) -> Result<DerivedKey, Error> {
-        use secrecy::ExposeSecret;
+        policy::enforce::<Self>()?;
+        // Argon2 requires at least four bytes of output.
+        //
+        // Argon2 要求输出至少为四个字节。
+        if !(argon2::Params::MIN_OUTPUT_LEN..=self.max_output_len()).contains(&output_len) {
+            return Err(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: output_len,
+                max: self.max_output_len(),
+            }));
+        }
 
         let params = argon2::Params::new(self.m_cost, self.t_cost, self.p_cost, Some(output_len))
             .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
 
-        let argon2 = Argon2_p::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+        let argon2 = Argon2_p::new(P::ALGORITHM, argon2::Version::V0x13, params);
 
         // Directly hash the password with the raw salt into an output buffer.
         // This is the most direct way to use Argon2 for key derivation.
@@ -297,21 +548,71 @@ impl PasswordBasedDerivation for Argon2Scheme {
         // 这是将 Argon2 用于密钥派生的最直接方法。
         let mut output = vec![0u8; output_len];
         argon2
-            .hash_password_into(password.expose_secret(), salt, &mut output)
+            .hash_password_into(password.expose_secret_bytes(), salt, &mut output)
             .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
 
         Ok(DerivedKey::new(output))
     }
+
+    fn max_output_len(&self) -> usize {
+        argon2::Params::MAX_OUTPUT_LEN
+    }
+}
+
+impl<P: Argon2Params> PasswordHasher for Argon2Scheme<P> {
+    fn to_phc(&self, salt: &[u8], hash: &[u8]) -> PhcString {
+        PhcString::new(
+            &P::NAME.to_lowercase(),
+            Some(0x13),
+            &[
+                ("m", self.m_cost.to_string()),
+                ("t", self.t_cost.to_string()),
+                ("p", self.p_cost.to_string()),
+            ],
+            salt,
+            hash,
+        )
+    }
+
+    fn from_phc(phc: &PhcString) -> Result<Self, Error> {
+        if phc.algorithm() != P::NAME.to_lowercase() || phc.version() != Some(0x13) {
+            return Err(Error::Kdf(KdfError::InvalidPhcString));
+        }
+        Ok(Self::new(
+            phc.param_u32("m")?,
+            phc.param_u32("t")?,
+            phc.param_u32("p")?,
+        ))
+    }
 }
 
+// --- Type Aliases ---
+// --- 类型别名 ---
+
 /// A type alias for the Argon2id scheme.
 ///
 /// Argon2id 方案的类型别名。
-pub type Argon2 = Argon2Scheme;
+pub type Argon2id = Argon2Scheme<Argon2idParams>;
+
+/// A type alias for the Argon2i scheme.
+///
+/// Argon2i 方案的类型别名。
+pub type Argon2i = Argon2Scheme<Argon2iParams>;
+
+/// A type alias for the Argon2d scheme.
+///
+/// Argon2d 方案的类型别名。
+pub type Argon2d = Argon2Scheme<Argon2dParams>;
+
+/// A type alias for the recommended Argon2 variant, Argon2id.
+///
+/// 推荐的 Argon2 变体 Argon2id 的类型别名。
+pub type Argon2 = Argon2id;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use secrecy::SecretBox;
 
     #[test]
     fn test_argon2_derivation_std() {
@@ -320,7 +621,7 @@ mod tests {
         let output_len = 32;
 
         // Use low-cost parameters for fast testing
-        let scheme = Argon2Scheme::new(16, 1, 1);
+        let scheme = Argon2::new(16, 1, 1);
 
         let derived_key_result = scheme.derive(&password, salt, output_len);
         assert!(derived_key_result.is_ok());
@@ -329,7 +630,7 @@ mod tests {
         assert_eq!(derived_key.as_bytes().len(), output_len);
 
         // Test with default parameters
-        let default_scheme = Argon2Scheme::default();
+        let default_scheme = Argon2::default();
         let derived_key_default_result = default_scheme.derive(&password, salt, output_len);
         assert!(derived_key_default_result.is_ok());
     }
@@ -340,7 +641,7 @@ mod tests {
         let password = SecretBox::new(Box::from(b"a-secure-password".as_slice()));
         let salt = b"a-unique-salt-for-this-user";
         let output_len = 64;
-        let scheme = Argon2Scheme::new(16, 1, 1);
+        let scheme = Argon2::new(16, 1, 1);
 
         let key1 = scheme.derive(&password, salt, output_len).unwrap();
         let key2 = scheme.derive(&password, salt, output_len).unwrap();
@@ -355,7 +656,7 @@ mod tests {
         let salt1 = b"salt-number-one";
         let salt2 = b"salt-number-two";
         let output_len = 32;
-        let scheme = Argon2Scheme::new(16, 1, 1);
+        let scheme = Argon2::new(16, 1, 1);
 
         let key1 = scheme.derive(&password, salt1, output_len).unwrap();
         let key2 = scheme.derive(&password, salt2, output_len).unwrap();
@@ -363,16 +664,54 @@ mod tests {
         assert_ne!(key1.as_bytes(), key2.as_bytes());
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_argon2_verify() {
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let wrong = SecretBox::new(Box::from(b"passw0rd".as_slice()));
+        let scheme = Argon2::new(16, 1, 1);
+        let key = scheme.derive(&password, b"saltsalt", 32).unwrap();
+
+        assert!(scheme.verify(&password, b"saltsalt", key.as_bytes()).is_ok());
+        assert_eq!(
+            scheme.verify(&wrong, b"saltsalt", key.as_bytes()).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        assert_eq!(
+            Argon2::new(16, 2, 1)
+                .verify(&password, b"saltsalt", key.as_bytes())
+                .unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_argon2_output_length() {
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let scheme = Argon2::new(16, 1, 1);
+        assert!(scheme.derive(&password, b"saltsalt", 4).is_ok());
+        for requested in [0, 3] {
+            assert_eq!(
+                scheme.derive(&password, b"saltsalt", requested).unwrap_err(),
+                Error::Kdf(KdfError::InvalidOutputLength {
+                    requested,
+                    max: 0xFFFF_FFFF
+                })
+            );
+        }
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_argon2_generate_salt() {
-        let scheme = Argon2Scheme::default();
+        let scheme = Argon2::default();
         let salt_result = scheme.generate_salt();
         assert!(salt_result.is_ok());
         let salt = salt_result.unwrap();
         assert_eq!(
             salt.len(),
-            <Argon2Scheme as PasswordBasedDerivation>::RECOMMENDED_SALT_LENGTH
+            <Argon2 as PasswordBasedDerivation>::RECOMMENDED_SALT_LENGTH
         );
 
         // Generate another salt to ensure they are not identical
@@ -382,4 +721,99 @@ mod tests {
             "Generated salts should be random and not identical"
         );
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_argon2_variants() {
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let salt = b"some-random-salt";
+
+        let id = Argon2id::new(16, 1, 1).derive(&password, salt, 32).unwrap();
+        let i = Argon2i::new(16, 1, 1).derive(&password, salt, 32).unwrap();
+        let d = Argon2d::new(16, 1, 1).derive(&password, salt, 32).unwrap();
+        assert_ne!(id, i);
+        assert_ne!(id, d);
+        assert_ne!(i, d);
+
+        assert_eq!(Argon2::name(), "Argon2id");
+        assert_eq!(<Argon2Scheme>::name(), "Argon2id");
+        let default: Argon2Scheme = Argon2Scheme::default();
+        assert_eq!(default.derive(&password, salt, 32).unwrap().len(), 32);
+        assert_eq!(Argon2i::name(), "Argon2i");
+        assert_eq!(Argon2d::ID, 0x03_01_01_03);
+        assert_eq!(
+            Argon2i::get_type_params(),
+            vec![("variant", ParamValue::String("Argon2i".to_string()))]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_argon2_phc() {
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let wrong = SecretBox::new(Box::from(b"passw0rd".as_slice()));
+
+        let phc = Argon2i::new(16, 1, 1).hash_password(&password).unwrap();
+        assert!(phc.to_string().starts_with("$argon2i$v=19$m=16,t=1,p=1$"));
+        assert!(Argon2i::verify_password(&phc, &password).is_ok());
+        assert_eq!(
+            Argon2i::verify_password(&phc, &wrong).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        assert_eq!(
+            Argon2id::verify_password(&phc, &password).unwrap_err(),
+            Error::Kdf(KdfError::InvalidPhcString)
+        );
+        assert!(
+            crate::systems::formats::phc::verify_password(&phc.to_string(), &password).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_argon2_builder_validation() {
+        let scheme = Argon2::builder().m_cost(19456).t_cost(2).build();
+        assert!(matches!(scheme, Ok(ref s) if s.m_cost == 19456 && s.t_cost == 2));
+        assert!(Argon2::builder().m_cost(47104).t_cost(1).build().is_ok());
+
+        for builder in [
+            Argon2::builder().m_cost(16).t_cost(1),
+            Argon2::builder().m_cost(19456).t_cost(1),
+            Argon2::builder().t_cost(0),
+            Argon2::builder().p_cost(0),
+            Argon2::builder().m_cost(8192).t_cost(5).p_cost(2048),
+        ] {
+            assert_eq!(
+                builder.build().err(),
+                Some(Error::Kdf(KdfError::InvalidParameter))
+            );
+        }
+        assert_eq!(
+            Argon2::builder().max_m_cost(16384).build().err(),
+            Some(Error::Kdf(KdfError::MemoryLimitExceeded))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_argon2_builder_calibrate() {
+        use core::time::Duration;
+
+        // A single pass over 8 MiB cannot be halved without dropping below the minimum,
+        // so the memory stays at the cap and the time cost is raised to meet the minimums.
+        let scheme = Argon2id::builder()
+            .max_m_cost(8192)
+            .calibrate(Duration::from_millis(1))
+            .unwrap();
+        assert_eq!(scheme.m_cost, 8192);
+        assert!(scheme.t_cost >= 5);
+        assert_eq!(scheme.p_cost, ARGON2_DEFAULT_P_COST);
+
+        assert_eq!(
+            Argon2id::builder()
+                .max_m_cost(4096)
+                .calibrate(Duration::from_millis(1))
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+    }
 }
@@ -0,0 +1,293 @@
+//! Provides the NIST SP 800-56C one-step KDF with a hash function (ConcatKDF).
+//!
+//! ConcatKDF derives keying material from a shared secret `Z`, usually the output of ECDH,
+//! by concatenating `H(counter || Z || FixedInfo)` for a 32-bit big-endian counter starting
+//! at 1. It is the KDF of JOSE ECDH-ES (RFC 7518, Section 4.6).
+//!
+//! The scheme implements [`KeyBasedDerivation`]: the IKM is `Z` and the info is the encoded
+//! `FixedInfo`. [`FixedInfo`] builds the usual
+//! `AlgorithmID || PartyUInfo || PartyVInfo {|| SuppPubInfo} {|| SuppPrivInfo}` encoding,
+//! where the first three fields are prefixed with their 32-bit big-endian length.
+//! ConcatKDF has no salt, so passing one is an error.
+//!
+//! 提供了使用哈希函数的 NIST SP 800-56C 单步 KDF (ConcatKDF)。
+//!
+//! ConcatKDF 从共享密钥 `Z`（通常是 ECDH 的输出）派生密钥材料，方法是将
+//! `H(counter || Z || FixedInfo)` 依次拼接，其中 32 位大端计数器从 1 开始。
+//! 它是 JOSE ECDH-ES（RFC 7518 第 4.6 节）所用的 KDF。
+//!
+//! 该方案实现了 [`KeyBasedDerivation`]：IKM 为 `Z`，info 为编码后的 `FixedInfo`。
+//! [`FixedInfo`] 构造常用的 `AlgorithmID || PartyUInfo || PartyVInfo {|| SuppPubInfo} {|| SuppPrivInfo}`
+//! 编码，其中前三个字段以其 32 位大端长度作为前缀。ConcatKDF 没有盐，因此传入盐会出错。
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::policy;
+use crate::traits::kdf::check_output_len;
+use crate::traits::params::{ParamValue, Parameterized};
+use crate::{errors::Error, prelude::*};
+use core::marker::PhantomData;
+use zeroize::Zeroizing;
+
+/// The `FixedInfo` input of ConcatKDF.
+///
+/// ConcatKDF 的 `FixedInfo` 输入。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FixedInfo {
+    algorithm_id: Vec<u8>,
+    party_u_info: Vec<u8>,
+    party_v_info: Vec<u8>,
+    supp_pub_info: Vec<u8>,
+    supp_priv_info: Vec<u8>,
+}
+
+impl FixedInfo {
+    /// Creates a `FixedInfo` from the algorithm identifier and the information about both
+    /// parties, without supplementary information.
+    ///
+    /// 由算法标识符和双方的信息创建 `FixedInfo`，不含补充信息。
+    pub fn new(algorithm_id: &[u8], party_u_info: &[u8], party_v_info: &[u8]) -> Self {
+        Self {
+            algorithm_id: algorithm_id.to_vec(),
+            party_u_info: party_u_info.to_vec(),
+            party_v_info: party_v_info.to_vec(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates the `FixedInfo` used by JOSE ECDH-ES: `algorithm` is the `enc` value (or the
+    /// key-wrapping `alg`), `apu` and `apv` are the decoded header parameters, and the
+    /// `SuppPubInfo` is the key length in bits.
+    ///
+    /// 创建 JOSE ECDH-ES 所用的 `FixedInfo`：`algorithm` 为 `enc` 值（或密钥包装的 `alg`），
+    /// `apu` 和 `apv` 为解码后的头部参数，`SuppPubInfo` 为以位为单位的密钥长度。
+    pub fn jose(algorithm: &str, apu: &[u8], apv: &[u8], key_bits: u32) -> Self {
+        Self::new(algorithm.as_bytes(), apu, apv).with_supp_pub_info(&key_bits.to_be_bytes())
+    }
+
+    /// Sets the `SuppPubInfo` field, which is appended as-is.
+    ///
+    /// 设置 `SuppPubInfo` 字段，该字段按原样追加。
+    pub fn with_supp_pub_info(mut self, supp_pub_info: &[u8]) -> Self {
+        self.supp_pub_info = supp_pub_info.to_vec();
+        self
+    }
+
+    /// Sets the `SuppPrivInfo` field, which is appended as-is.
+    ///
+    /// 设置 `SuppPrivInfo` 字段，该字段按原样追加。
+    pub fn with_supp_priv_info(mut self, supp_priv_info: &[u8]) -> Self {
+        self.supp_priv_info = supp_priv_info.to_vec();
+        self
+    }
+
+    /// Encodes the fields into the `FixedInfo` byte string.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if a length-prefixed field is longer than
+    /// `u32::MAX` bytes.
+    ///
+    /// 将各字段编码为 `FixedInfo` 字节串。
+    ///
+    /// 如果某个带长度前缀的字段长于 `u32::MAX` 字节，则以 `KdfError::InvalidParameter` 失败。
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut out = Vec::new();
+        for field in [&self.algorithm_id, &self.party_u_info, &self.party_v_info] {
+            let len =
+                u32::try_from(field.len()).map_err(|_| Error::Kdf(KdfError::InvalidParameter))?;
+            out.extend_from_slice(&len.to_be_bytes());
+            out.extend_from_slice(field);
+        }
+        out.extend_from_slice(&self.supp_pub_info);
+        out.extend_from_slice(&self.supp_priv_info);
+        Ok(out)
+    }
+}
+
+/// A generic struct representing ConcatKDF for a given hash function.
+///
+/// 一个通用的 ConcatKDF 结构体，它在哈希函数上是通用的。
+#[derive(Clone, Debug, Default)]
+pub struct ConcatKdfScheme<H: Hasher> {
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> ConcatKdfScheme<H> {
+    /// Derives `output_len` bytes from the shared secret `z` and a structured `FixedInfo`.
+    ///
+    /// 从共享密钥 `z` 和结构化的 `FixedInfo` 派生 `output_len` 字节。
+    pub fn derive_with_fixed_info(
+        &self,
+        z: &[u8],
+        fixed_info: &FixedInfo,
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        self.derive(z, None, Some(&fixed_info.to_bytes()?), output_len)
+    }
+
+    fn block(ikm: &[u8], counter: u32, info: Option<&[u8]>) -> Zeroizing<Vec<u8>> {
+        let mut state = H::Digest::default();
+        digest::Digest::update(&mut state, counter.to_be_bytes());
+        digest::Digest::update(&mut state, ikm);
+        digest::Digest::update(&mut state, info.unwrap_or_default());
+        Zeroizing::new(digest::Digest::finalize(state).to_vec())
+    }
+}
+
+impl<H: Hasher> Derivation for ConcatKdfScheme<H> {}
+
+impl<H: Hasher> Algorithm for ConcatKdfScheme<H> {
+    fn name() -> String {
+        format!("ConcatKDF-{}", H::NAME)
+    }
+    const ID: u32 = 0x03_07_00_00 + H::ID_OFFSET;
+}
+
+impl<H: Hasher> Parameterized for ConcatKdfScheme<H> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(H::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<H: Hasher> KeyBasedDerivation for ConcatKdfScheme<H> {
+    fn derive(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        if salt.is_some() {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        check_output_len(output_len, self.max_output_len())?;
+
+        let blocks = output_len.div_ceil(H::OUTPUT_SIZE);
+        let mut okm = Vec::with_capacity(blocks * H::OUTPUT_SIZE);
+        for counter in (1..=u32::MAX).take(blocks) {
+            okm.extend_from_slice(&Self::block(ikm, counter, info));
+        }
+        okm.truncate(output_len);
+        Ok(DerivedKey::new(okm))
+    }
+
+    fn max_output_len(&self) -> usize {
+        (u32::MAX as usize).saturating_mul(H::OUTPUT_SIZE)
+    }
+}
+
+impl<H: Hasher> StreamingDerivation for ConcatKdfScheme<H> {
+    fn reader<'a>(
+        &self,
+        ikm: &'a [u8],
+        salt: Option<&'a [u8]>,
+        info: Option<&'a [u8]>,
+    ) -> Result<KdfReader<'a>, Error> {
+        policy::enforce::<Self>()?;
+        if salt.is_some() {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        let mut counter = 0u32;
+        Ok(KdfReader::new(self.max_output_len(), move || {
+            counter = counter
+                .checked_add(1)
+                .ok_or(Error::Kdf(KdfError::DerivationFailed))?;
+            Ok(Self::block(ikm, counter, info))
+        }))
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for ConcatKDF over SHA-256.
+///
+/// 基于 SHA-256 的 ConcatKDF 的类型别名。
+pub type ConcatKdfSha256 = ConcatKdfScheme<Sha256>;
+
+/// A type alias for ConcatKDF over SHA-384.
+///
+/// 基于 SHA-384 的 ConcatKDF 的类型别名。
+pub type ConcatKdfSha384 = ConcatKdfScheme<Sha384>;
+
+/// A type alias for ConcatKDF over SHA-512.
+///
+/// 基于 SHA-512 的 ConcatKDF 的类型别名。
+pub type ConcatKdfSha512 = ConcatKdfScheme<Sha512>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concat_kdf_jose() {
+        // RFC 7518, Appendix C: ECDH-ES key agreement for A128GCM
+        let z = hex::decode("9e56d91d817135d372834283bf84269cfb316ea3da806a48f6daa7798cfe90c4")
+            .unwrap_or_default();
+        let fixed_info = FixedInfo::jose("A128GCM", b"Alice", b"Bob", 128);
+        assert_eq!(
+            fixed_info.to_bytes().map(hex::encode).unwrap_or_default(),
+            "000000074131323847434d00000005416c69636500000003426f6200000080"
+        );
+
+        let key = ConcatKdfSha256::default()
+            .derive_with_fixed_info(&z, &fixed_info, 16)
+            .map(|key| hex::encode(key.as_bytes()));
+        assert_eq!(
+            key.ok().as_deref(),
+            Some("56aa8deaf8236d205c2228cd71a7101a")
+        );
+    }
+
+    #[test]
+    fn test_concat_kdf_multiple_blocks() {
+        let z: Vec<u8> = (0..48).collect();
+        let okm = ConcatKdfSha384::default()
+            .derive(&z, None, Some(b"fixed"), 72)
+            .map(|okm| hex::encode(okm.as_bytes()));
+        assert_eq!(
+            okm.ok().as_deref(),
+            Some(
+                "7ed5c5279b60e54b49d7635c7d6a775309c73b720012c7dd2f5ee0b6e1cd6d22\
+                 634f188749faba6a8254db6faab858f78b956a19062676cf17d417d2e369e1c6\
+                 d740f7fdf50b1362"
+            )
+        );
+        assert_eq!(
+            ConcatKdfSha256::default()
+                .derive(&z, Some(b"salt"), None, 32)
+                .err(),
+            Some(Error::Kdf(KdfError::InvalidParameter))
+        );
+    }
+
+    #[test]
+    fn test_concat_kdf_reader() {
+        let scheme = ConcatKdfSha256::default();
+        let full = scheme
+            .derive(b"shared secret", None, Some(b"info"), 100)
+            .map(|okm| okm.as_bytes().to_vec())
+            .unwrap_or_default();
+        let Ok(mut reader) = scheme.reader(b"shared secret", None, Some(b"info")) else {
+            panic!("reader should be created");
+        };
+        let mut streamed = [0u8; 100];
+        let (head, tail) = streamed.split_at_mut(45);
+        assert!(reader.read(head).is_ok());
+        assert!(reader.read(tail).is_ok());
+        assert_eq!(streamed.as_slice(), full.as_slice());
+
+        assert_eq!(
+            scheme.reader(b"shared secret", Some(b"salt"), None).err(),
+            Some(Error::Kdf(KdfError::InvalidParameter))
+        );
+    }
+}
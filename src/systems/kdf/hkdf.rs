@@ -8,6 +8,10 @@
 //! 1. **Extract Phase**: Extracts a pseudorandom key (PRK) from the input keying material
 //! 2. **Expand Phase**: Expands the PRK into the desired output keying material (OKM)
 //!
+//! [`KeyBasedDerivation::derive`] runs both phases at once. Protocols such as TLS 1.3 and
+//! Noise that need the PRK itself can call [`HkdfScheme::extract`] and then [`Prk::expand`]
+//! as often as needed.
+//!
 //! # Security Properties
 //! - Provides strong security guarantees when used with high-entropy input
 //! - Resistant to known cryptanalytic attacks
@@ -41,6 +45,9 @@
 //! 1. **提取阶段**: 从输入密钥材料中提取伪随机密钥 (PRK)
 //! 2. **扩展阶段**: 将 PRK 扩展为所需的输出密钥材料 (OKM)
 //!
+//! [`KeyBasedDerivation::derive`] 一次执行两个阶段。TLS 1.3 和 Noise 等需要 PRK 本身的协议
+//! 可以调用 [`HkdfScheme::extract`]，然后根据需要多次调用 [`Prk::expand`]。
+//!
 //! # 安全属性
 //! - 在与高熵输入一起使用时提供强安全保证
 //! - 抵抗已知的密码分析攻击
@@ -65,12 +72,126 @@
 //! - 上下文信息有助于域分离
 //! - 输出长度不应超过 255 * hash_length
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use crate::{
     errors::Error,
     prelude::*
 };
+use crate::ct::{Choice, ConstantTimeEq};
+use crate::policy;
+use crate::traits::kdf::check_output_len;
 use crate::traits::params::{ParamValue, Parameterized};
-use std::marker::PhantomData;
+use core::marker::PhantomData;
+use zeroize::Zeroizing;
+
+// --- Pseudorandom Key ---
+// --- 伪随机密钥 ---
+
+/// The pseudorandom key (PRK) produced by the HKDF-Extract step.
+///
+/// 由 HKDF-Extract 步骤生成的伪随机密钥 (PRK)。
+#[derive(Clone, Debug)]
+pub struct Prk<H: Hasher> {
+    bytes: Zeroizing<Vec<u8>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> Prk<H> {
+    /// Wraps an existing PRK, such as one from a protocol's key schedule.
+    /// Fails unless `bytes` is at least as long as the hash output.
+    ///
+    /// 包装一个已有的 PRK，例如来自协议密钥调度的 PRK。除非 `bytes` 至少与哈希输出一样长，否则失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < H::OUTPUT_SIZE {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        Ok(Self {
+            bytes: Zeroizing::new(bytes.to_vec()),
+            _hasher: PhantomData,
+        })
+    }
+
+    /// Returns the bytes of the PRK.
+    ///
+    /// 返回 PRK 的字节。
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The largest output of HKDF-Expand: 255 times the hash output size.
+    ///
+    /// HKDF-Expand 的最大输出：哈希输出大小的 255 倍。
+    pub const MAX_OUTPUT_LEN: usize = 255 * H::OUTPUT_SIZE;
+
+    /// Runs the HKDF-Expand step, deriving `output_len` bytes bound to `info`.
+    /// Fails with `KdfError::InvalidOutputLength` if `output_len` is zero or exceeds
+    /// [`Prk::MAX_OUTPUT_LEN`].
+    ///
+    /// 执行 HKDF-Expand 步骤，派生与 `info` 绑定的 `output_len` 字节。
+    /// 如果 `output_len` 为零或超过 [`Prk::MAX_OUTPUT_LEN`]，则以
+    /// `KdfError::InvalidOutputLength` 失败。
+    pub fn expand(&self, info: &[u8], output_len: usize) -> Result<DerivedKey, Error> {
+        policy::enforce::<HkdfScheme<H>>()?;
+        check_output_len(output_len, Self::MAX_OUTPUT_LEN)?;
+        let mut okm = DerivedKey::new(vec![0u8; output_len]);
+        self.expand_into(info, okm.as_mut())?;
+        Ok(okm)
+    }
+
+    /// Runs the HKDF-Expand step into `output`, without heap allocation. The bytes are those
+    /// returned by [`Prk::expand`] for `output.len()`, with the same length limits.
+    ///
+    /// 在不进行堆分配的情况下将 HKDF-Expand 步骤的结果写入 `output`。这些字节与
+    /// [`Prk::expand`] 对 `output.len()` 返回的字节相同，长度限制也相同。
+    pub fn expand_into(&self, info: &[u8], output: &mut [u8]) -> Result<(), Error> {
+        policy::enforce::<HkdfScheme<H>>()?;
+        check_output_len(output.len(), Self::MAX_OUTPUT_LEN)?;
+        H::hkdf_expand_prk(&self.bytes, info, output).map_err(Error::Kdf)
+    }
+
+    /// Runs HKDF-Expand incrementally, returning a reader over the same bytes as
+    /// [`Prk::expand`]. Reading past [`Prk::MAX_OUTPUT_LEN`] bytes fails with
+    /// `KdfError::InvalidOutputLength`.
+    ///
+    /// 增量执行 HKDF-Expand，返回一个读取器，其字节与 [`Prk::expand`] 相同。
+    /// 读取超过 [`Prk::MAX_OUTPUT_LEN`] 字节时以 `KdfError::InvalidOutputLength` 失败。
+    pub fn reader<'a>(&self, info: &'a [u8]) -> KdfReader<'a> {
+        let prk = self.bytes.clone();
+        let mut previous = Zeroizing::new(Vec::new());
+        let mut counter = 0u8;
+        KdfReader::new(Self::MAX_OUTPUT_LEN, move || {
+            counter = counter
+                .checked_add(1)
+                .ok_or(Error::Kdf(KdfError::DerivationFailed))?;
+            let mut input = Zeroizing::new(Vec::with_capacity(previous.len() + info.len() + 1));
+            input.extend_from_slice(&previous);
+            input.extend_from_slice(info);
+            input.push(counter);
+            previous = Zeroizing::new(H::hmac(&prk, &input)?);
+            Ok(previous.clone())
+        })
+    }
+}
+
+impl<H: Hasher> ConstantTimeEq for Prk<H> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.as_slice().ct_eq(other.bytes.as_slice())
+    }
+}
+
+impl<H: Hasher> PartialEq for Prk<H> {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl<H: Hasher> Eq for Prk<H> {}
+
 // --- Generic HKDF Implementation ---
 // --- 通用 HKDF 实现 ---
 
@@ -90,6 +211,70 @@ impl<H: Hasher> Default for HkdfScheme<H> {
     }
 }
 
+impl<H: Hasher> HkdfScheme<H> {
+    /// Runs the HKDF-Extract step, turning `ikm` into a uniformly random [`Prk`].
+    /// Without a salt, a string of zeros as long as the hash output is used.
+    ///
+    /// 执行 HKDF-Extract 步骤，将 `ikm` 转换为均匀随机的 [`Prk`]。
+    /// 如果没有盐，则使用与哈希输出等长的全零字符串。
+    pub fn extract(salt: Option<&[u8]>, ikm: &[u8]) -> Prk<H> {
+        Prk {
+            bytes: Zeroizing::new(H::hkdf_extract(salt, ikm)),
+            _hasher: PhantomData,
+        }
+    }
+}
+
+// --- TLS 1.3 Key Schedule ---
+// --- TLS 1.3 密钥调度 ---
+
+/// Implements HKDF-Expand-Label from TLS 1.3 (RFC 8446, Section 7.1), which QUIC also uses.
+///
+/// The `HkdfLabel` structure, that is the output length, `"tls13 "` followed by `label`, and
+/// `context`, is encoded and passed to [`Prk::expand`] as the info. To continue the key
+/// schedule, wrap the result with [`Prk::from_bytes`].
+///
+/// Fails with `KdfError::InvalidOutputLength` if `output_len` is zero, exceeds
+/// [`Prk::MAX_OUTPUT_LEN`] or does not fit in 16 bits, and with
+/// `KdfError::InvalidParameter` if the prefixed label or the context is longer than 255 bytes.
+///
+/// 实现 TLS 1.3（RFC 8446 第 7.1 节）中的 HKDF-Expand-Label，QUIC 也使用该函数。
+///
+/// `HkdfLabel` 结构（即输出长度、`"tls13 "` 加上 `label`，以及 `context`）被编码后作为 info
+/// 传给 [`Prk::expand`]。若要继续密钥调度，请使用 [`Prk::from_bytes`] 包装结果。
+///
+/// 如果 `output_len` 为零、超过 [`Prk::MAX_OUTPUT_LEN`] 或无法用 16 位表示，则以
+/// `KdfError::InvalidOutputLength` 失败；
+/// 如果带前缀的标签或上下文长于 255 字节，则以 `KdfError::InvalidParameter` 失败。
+pub fn hkdf_expand_label<H: Hasher>(
+    prk: &Prk<H>,
+    label: &[u8],
+    context: &[u8],
+    output_len: usize,
+) -> Result<DerivedKey, Error> {
+    const LABEL_PREFIX: &[u8] = b"tls13 ";
+
+    let length = u16::try_from(output_len).map_err(|_| {
+        Error::Kdf(KdfError::InvalidOutputLength {
+            requested: output_len,
+            max: u16::MAX as usize,
+        })
+    })?;
+    let label_len = u8::try_from(LABEL_PREFIX.len() + label.len())
+        .map_err(|_| Error::Kdf(KdfError::InvalidParameter))?;
+    let context_len =
+        u8::try_from(context.len()).map_err(|_| Error::Kdf(KdfError::InvalidParameter))?;
+
+    let mut info = Vec::with_capacity(4 + label_len as usize + context.len());
+    info.extend_from_slice(&length.to_be_bytes());
+    info.push(label_len);
+    info.extend_from_slice(LABEL_PREFIX);
+    info.extend_from_slice(label);
+    info.push(context_len);
+    info.extend_from_slice(context);
+    prk.expand(&info, output_len)
+}
+
 impl<H: Hasher> Derivation for HkdfScheme<H> {}
 
 impl<H: Hasher> Algorithm for HkdfScheme<H> {
@@ -117,9 +302,39 @@ impl<H: Hasher> KeyBasedDerivation for HkdfScheme<H> {
         info: Option<&[u8]>,
         output_len: usize,
     ) -> Result<DerivedKey, Error> {
-        let mut okm = vec![0u8; output_len];
-        H::hkdf_expand(salt, ikm, info, &mut okm).map_err(Error::Kdf)?;
-        Ok(DerivedKey::new(okm))
+        policy::enforce::<Self>()?;
+        check_output_len(output_len, self.max_output_len())?;
+        let mut okm = DerivedKey::new(vec![0u8; output_len]);
+        self.derive_into(ikm, salt, info, okm.as_mut())?;
+        Ok(okm)
+    }
+
+    fn derive_into(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+        output: &mut [u8],
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        check_output_len(output.len(), self.max_output_len())?;
+        H::hkdf_expand(salt, ikm, info, output).map_err(Error::Kdf)
+    }
+
+    fn max_output_len(&self) -> usize {
+        Prk::<H>::MAX_OUTPUT_LEN
+    }
+}
+
+impl<H: Hasher> StreamingDerivation for HkdfScheme<H> {
+    fn reader<'a>(
+        &self,
+        ikm: &'a [u8],
+        salt: Option<&'a [u8]>,
+        info: Option<&'a [u8]>,
+    ) -> Result<KdfReader<'a>, Error> {
+        policy::enforce::<Self>()?;
+        Ok(Self::extract(salt, ikm).reader(info.unwrap_or_default()))
     }
 }
 
@@ -172,6 +387,13 @@ mod tests {
 
         let derived_key_no_info_result = scheme.derive(ikm, Some(salt), None, output_len);
         assert!(derived_key_no_info_result.is_ok());
+
+        // IKM in a secret wrapper derives the same key
+        let secret_ikm = SecretBytes::from_slice(ikm);
+        let from_secret =
+            scheme.derive_from_secret(&secret_ikm, Some(salt), Some(info), output_len);
+        assert!(from_secret.is_ok());
+        assert_eq!(from_secret.unwrap(), derived_key);
     }
 
     #[test]
@@ -191,4 +413,160 @@ mod tests {
     fn test_hkdf_sha512() {
         run_hkdf_test::<Sha512>();
     }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_hkdf_extract_expand() {
+        // RFC 5869, Appendix A.1
+        let ikm = [0x0bu8; 22];
+        let salt = hex::decode("000102030405060708090a0b0c").unwrap_or_default();
+        let info = hex::decode("f0f1f2f3f4f5f6f7f8f9").unwrap_or_default();
+
+        let prk = HkdfSha256::extract(Some(&salt), &ikm);
+        assert_eq!(
+            hex::encode(prk.as_bytes()),
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+        );
+        let okm = prk.expand(&info, 42);
+        assert!(matches!(okm, Ok(ref okm) if hex::encode(okm.as_bytes())
+            == "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"));
+
+        // The buffer and array variants derive the same bytes
+        let mut buffer = [0u8; 42];
+        assert!(prk.expand_into(&info, &mut buffer).is_ok());
+        assert!(matches!(okm, Ok(ref okm) if okm.as_bytes() == buffer));
+        let array = HkdfSha256::default().derive_array::<42>(&ikm, Some(&salt), Some(&info));
+        assert!(matches!(array, Ok(ref array) if **array == buffer));
+
+        // The split API matches the combined one
+        let combined = HkdfSha256::default().derive(&ikm, Some(&salt), Some(&info), 42);
+        assert_eq!(combined.ok(), okm.ok());
+
+        // A PRK round-trips through its bytes, and expanding past 255 blocks fails
+        assert!(matches!(Prk::<Sha256>::from_bytes(prk.as_bytes()), Ok(ref p) if *p == prk));
+        assert_eq!(
+            Prk::<Sha256>::from_bytes(&[0u8; 16]).err(),
+            Some(Error::Kdf(KdfError::InvalidParameter))
+        );
+        assert_eq!(
+            prk.expand(&info, 255 * 32 + 1).err(),
+            Some(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 255 * 32 + 1,
+                max: 255 * 32
+            }))
+        );
+        assert_eq!(
+            HkdfSha256::default().derive(&ikm, None, None, 0).err(),
+            Some(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 0,
+                max: 255 * 32
+            }))
+        );
+        assert_eq!(
+            HkdfSha256::default().derive_array::<0>(&ikm, None, None).err(),
+            Some(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 0,
+                max: 255 * 32
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_hkdf_reader() {
+        let scheme = HkdfSha256::default();
+        let full = scheme
+            .derive(b"ikm", Some(b"salt"), Some(b"info"), 255 * 32)
+            .unwrap_or_else(|_| DerivedKey::new(Vec::new()));
+        let Ok(mut reader) = scheme.reader(b"ikm", Some(b"salt"), Some(b"info")) else {
+            panic!("HKDF reader should be created");
+        };
+
+        // Reads of any size concatenate to the all-at-once output
+        let mut streamed = Vec::new();
+        for len in [1, 31, 32, 33, 100] {
+            let mut chunk = vec![0u8; len];
+            assert!(reader.read(&mut chunk).is_ok());
+            streamed.extend_from_slice(&chunk);
+        }
+        let rest = reader.read_key(full.len() - streamed.len());
+        streamed.extend(rest.map(|key| key.as_bytes().to_vec()).unwrap_or_default());
+        assert_eq!(streamed.as_slice(), full.as_bytes());
+
+        assert_eq!(
+            reader.read(&mut [0u8; 1]).err(),
+            Some(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 255 * 32 + 1,
+                max: 255 * 32
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_hkdf_expand_label() {
+        // RFC 9001, Appendix A.1: QUIC client Initial keys
+        let salt = hex::decode("38762cf7f55934b34d179ae6a4c80cadccbb7f0a").unwrap_or_default();
+        let dcid = hex::decode("8394c8f03e515708").unwrap_or_default();
+        let initial = HkdfSha256::extract(Some(&salt), &dcid);
+
+        let expand_hex = |prk: &Prk<Sha256>, label: &[u8], len| {
+            hkdf_expand_label(prk, label, b"", len)
+                .map(|okm| hex::encode(okm.as_bytes()))
+                .unwrap_or_default()
+        };
+        assert_eq!(
+            expand_hex(&initial, b"client in", 32),
+            "c00cf151ca5be075ed0ebfb5c80323c42d6b7db67881289af4008f1f6c357aea"
+        );
+
+        let client = hkdf_expand_label(&initial, b"client in", b"", 32)
+            .and_then(|secret| Prk::<Sha256>::from_bytes(secret.as_bytes()));
+        let Ok(client) = client else {
+            panic!("deriving the client Initial secret failed")
+        };
+        assert_eq!(
+            expand_hex(&client, b"quic key", 16),
+            "1f369613dd76d5467730efcbe3b1a22d"
+        );
+        assert_eq!(
+            expand_hex(&client, b"quic iv", 12),
+            "fa044b2f42a3fd3b46fb255c"
+        );
+
+        assert_eq!(
+            hkdf_expand_label(&client, &[b'a'; 250], b"", 16).err(),
+            Some(Error::Kdf(KdfError::InvalidParameter))
+        );
+        assert_eq!(
+            hkdf_expand_label(&client, b"key", b"", 1 << 16).err(),
+            Some(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 1 << 16,
+                max: u16::MAX as usize
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "sha2", feature = "aes-gcm-default"))]
+    fn test_hkdf_derive_key_for() {
+        use crate::systems::aead::aes_gcm::{Aes128Gcm, Aes256Gcm};
+
+        let scheme = HkdfSha256::default();
+        let key = scheme
+            .derive_key_for::<Aes256Gcm>(b"ikm", Some(b"salt"), Some(b"enc"))
+            .unwrap();
+        assert_eq!(key.len(), 32);
+        let okm = scheme.derive(b"ikm", Some(b"salt"), Some(b"enc"), 32).unwrap();
+        assert_eq!(key.as_slice(), okm.as_bytes());
+
+        let nonce = [0u8; 12];
+        let ciphertext = Aes256Gcm::encrypt(&key, &nonce, b"message", None).unwrap();
+        assert!(Aes256Gcm::decrypt(&key, &nonce, &ciphertext, None).is_ok());
+
+        let key = scheme
+            .derive_key_for::<Aes128Gcm>(b"ikm", Some(b"salt"), Some(b"enc"))
+            .unwrap();
+        assert_eq!(key.len(), 16);
+    }
 }
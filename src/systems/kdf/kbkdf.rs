@@ -0,0 +1,414 @@
+//! Provides the key-based KDF from NIST SP 800-108 (KBKDF).
+//!
+//! KBKDF derives keying material from a secret key by repeatedly applying a pseudorandom
+//! function (PRF) to a counter and fixed input data. Any [`Mac`] in the crate can serve as the
+//! PRF, so both HMAC-based and CMAC-based variants are available, e.g.
+//! `KbkdfScheme<Aes128Cmac>` for the AES-CMAC counter-mode KDF used by payment HSMs.
+//!
+//! # Modes
+//! - **Counter**: `K(i) = PRF(K_I, [i]_r || FixedInput)`
+//! - **Feedback**: `K(i) = PRF(K_I, K(i-1) {|| [i]_r} || FixedInput)`, with `K(0) = IV`
+//!
+//! # Fixed Input Encoding
+//! [`KbkdfScheme::derive`] builds the fixed input as
+//! `Label || 0x00 || Context || [L]_2`, where `L` is the output length in bits. The counter
+//! width, the width of `[L]_2` and the `0x00` separator are configurable. Peers that use any
+//! other layout can pass their fixed input as-is to
+//! [`KbkdfScheme::derive_with_fixed_input`].
+//!
+//! # Security Considerations
+//! - The key must be uniformly random; use a password-based KDF for passwords.
+//! - Use distinct labels for keys with different purposes.
+//!
+//! 提供了 NIST SP 800-108 中基于密钥的 KDF (KBKDF)。
+//!
+//! KBKDF 通过对计数器和固定输入数据重复应用伪随机函数 (PRF)，从秘密密钥派生密钥材料。
+//! 本 crate 中的任何 [`Mac`] 都可以用作 PRF，因此基于 HMAC 和基于 CMAC 的变体都可用，例如
+//! 用于支付 HSM 的 AES-CMAC 计数器模式 KDF `KbkdfScheme<Aes128Cmac>`。
+//!
+//! # 模式
+//! - **计数器**: `K(i) = PRF(K_I, [i]_r || FixedInput)`
+//! - **反馈**: `K(i) = PRF(K_I, K(i-1) {|| [i]_r} || FixedInput)`，其中 `K(0) = IV`
+//!
+//! # 固定输入编码
+//! [`KbkdfScheme::derive`] 将固定输入构造为 `Label || 0x00 || Context || [L]_2`，其中 `L`
+//! 是以位为单位的输出长度。计数器宽度、`[L]_2` 的宽度以及 `0x00` 分隔符均可配置。
+//! 使用其他布局的对端可以将其固定输入原样传给 [`KbkdfScheme::derive_with_fixed_input`]。
+//!
+//! # 安全考虑
+//! - 密钥必须是均匀随机的；对于密码请使用基于密码的 KDF。
+//! - 对不同用途的密钥使用不同的标签。
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::policy;
+use crate::traits::kdf::check_output_len;
+use crate::{errors::Error, prelude::*};
+use core::marker::PhantomData;
+use zeroize::Zeroizing;
+
+/// The KBKDF mode of iteration.
+///
+/// KBKDF 的迭代模式。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KbkdfMode {
+    /// Counter mode.
+    ///
+    /// 计数器模式。
+    Counter,
+    /// Feedback mode, starting from `iv` and optionally including the counter in each block.
+    ///
+    /// 反馈模式，从 `iv` 开始，并可选择在每个块中包含计数器。
+    Feedback {
+        /// The initial value `K(0)`; may be empty.
+        ///
+        /// 初始值 `K(0)`；可以为空。
+        iv: Vec<u8>,
+        /// Whether `[i]_r` is included in each PRF input.
+        ///
+        /// 每个 PRF 输入中是否包含 `[i]_r`。
+        counter: bool,
+    },
+}
+
+/// A generic struct representing SP 800-108 KBKDF over a given PRF.
+///
+/// 一个通用的 SP 800-108 KBKDF 结构体，它在 PRF 上是通用的。
+#[derive(Clone, Debug)]
+pub struct KbkdfScheme<M: Mac> {
+    /// The mode of iteration.
+    ///
+    /// 迭代模式。
+    pub mode: KbkdfMode,
+    /// The width of the counter `[i]_r` in bits: 8, 16, 24 or 32.
+    ///
+    /// 计数器 `[i]_r` 的宽度（以位为单位）：8、16、24 或 32。
+    pub counter_bits: u32,
+    /// The width of the output length `[L]_2` in bits: 8, 16, 24 or 32.
+    ///
+    /// 输出长度 `[L]_2` 的宽度（以位为单位）：8、16、24 或 32。
+    pub length_bits: u32,
+    /// Whether a `0x00` byte separates the label from the context.
+    ///
+    /// 标签与上下文之间是否以 `0x00` 字节分隔。
+    pub separator: bool,
+    _prf: PhantomData<M>,
+}
+
+impl<M: Mac> Default for KbkdfScheme<M> {
+    /// Creates a counter-mode KBKDF, see [`counter`](Self::counter).
+    ///
+    /// 创建一个计数器模式的 KBKDF，参见 [`counter`](Self::counter)。
+    fn default() -> Self {
+        Self::counter()
+    }
+}
+
+impl<M: Mac> KbkdfScheme<M> {
+    /// Creates a counter-mode KBKDF with a 32-bit counter, a 32-bit length and the separator.
+    ///
+    /// 创建一个计数器模式的 KBKDF，使用 32 位计数器、32 位长度并带分隔符。
+    pub fn counter() -> Self {
+        Self {
+            mode: KbkdfMode::Counter,
+            counter_bits: 32,
+            length_bits: 32,
+            separator: true,
+            _prf: PhantomData,
+        }
+    }
+
+    /// Creates a feedback-mode KBKDF with the given IV and otherwise the same defaults as
+    /// [`counter`](Self::counter), including the counter in each block.
+    ///
+    /// 使用给定 IV 创建一个反馈模式的 KBKDF，其余默认值与 [`counter`](Self::counter) 相同，
+    /// 并在每个块中包含计数器。
+    pub fn feedback(iv: &[u8]) -> Self {
+        Self {
+            mode: KbkdfMode::Feedback {
+                iv: iv.to_vec(),
+                counter: true,
+            },
+            ..Self::counter()
+        }
+    }
+
+    /// Derives `output_len` bytes from `key`, building the fixed input from `label` and
+    /// `context` as described in the module documentation.
+    ///
+    /// 从 `key` 派生 `output_len` 字节，并按照模块文档所述由 `label` 和 `context` 构造固定输入。
+    pub fn derive(
+        &self,
+        key: &M::Key,
+        label: &[u8],
+        context: &[u8],
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        self.check_widths()?;
+        check_output_len(output_len, self.max_output_len())?;
+        let length = encode_int(output_len * 8, self.length_bits);
+
+        let mut fixed_input = Vec::with_capacity(label.len() + context.len() + 1 + length.len());
+        fixed_input.extend_from_slice(label);
+        if self.separator {
+            fixed_input.push(0x00);
+        }
+        fixed_input.extend_from_slice(context);
+        fixed_input.extend_from_slice(&length);
+        self.derive_with_fixed_input(key, &fixed_input, output_len)
+    }
+
+    /// Derives `output_len` bytes from `key` using `fixed_input` exactly as given.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if the counter width is not supported, and with
+    /// `KdfError::InvalidOutputLength` if `output_len` is zero or the number of blocks does not
+    /// fit in the counter.
+    ///
+    /// 使用原样给出的 `fixed_input` 从 `key` 派生 `output_len` 字节。
+    ///
+    /// 如果计数器宽度不受支持，则以 `KdfError::InvalidParameter` 失败；如果 `output_len` 为零
+    /// 或块数无法用计数器表示，则以 `KdfError::InvalidOutputLength` 失败。
+    pub fn derive_with_fixed_input(
+        &self,
+        key: &M::Key,
+        fixed_input: &[u8],
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        self.check_widths()?;
+        check_output_len(output_len, self.counter_max_output_len())?;
+
+        let blocks = output_len.div_ceil(M::TAG_SIZE);
+        let mut okm = Zeroizing::new(Vec::with_capacity(blocks * M::TAG_SIZE));
+        // In feedback mode each output block is fed into the next, so the blocks and the
+        // inputs holding them are zeroized like the output itself.
+        // 在反馈模式下每个输出块都会输入到下一个块中，因此这些块以及保存它们的输入与输出本身一样会被清零。
+        let mut previous = Zeroizing::new(match &self.mode {
+            KbkdfMode::Counter => Vec::new(),
+            KbkdfMode::Feedback { iv, .. } => iv.clone(),
+        });
+        for i in 1..=blocks {
+            let mut input =
+                Zeroizing::new(Vec::with_capacity(previous.len() + 4 + fixed_input.len()));
+            let with_counter = match &self.mode {
+                KbkdfMode::Counter => true,
+                KbkdfMode::Feedback { counter, .. } => {
+                    input.extend_from_slice(&previous);
+                    *counter
+                }
+            };
+            if with_counter {
+                input.extend_from_slice(&encode_int(i, self.counter_bits));
+            }
+            input.extend_from_slice(fixed_input);
+
+            let block = Zeroizing::new(M::compute(key, &input)?);
+            okm.extend_from_slice(&block);
+            previous = block;
+        }
+        okm.truncate(output_len);
+        Ok(DerivedKey(okm))
+    }
+
+    /// The largest output that [`KbkdfScheme::derive`] accepts with this configuration,
+    /// limited by the counter width and by the width of the encoded length in bits.
+    ///
+    /// 在此配置下 [`KbkdfScheme::derive`] 接受的最大输出，受计数器宽度和以位为单位的
+    /// 编码长度宽度限制。
+    pub fn max_output_len(&self) -> usize {
+        self.counter_max_output_len()
+            .min(max_int(self.length_bits) / 8)
+    }
+
+    fn counter_max_output_len(&self) -> usize {
+        match self.mode {
+            KbkdfMode::Feedback { counter: false, .. } => usize::MAX,
+            _ => max_int(self.counter_bits).saturating_mul(M::TAG_SIZE),
+        }
+    }
+
+    fn check_widths(&self) -> Result<(), Error> {
+        for bits in [self.counter_bits, self.length_bits] {
+            if !matches!(bits, 8 | 16 | 24 | 32) {
+                return Err(Error::Kdf(KdfError::InvalidParameter));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The largest value that fits in `bits` bits.
+///
+/// 能用 `bits` 位表示的最大值。
+fn max_int(bits: u32) -> usize {
+    let max = 1u64.checked_shl(bits).map_or(u64::MAX, |bound| bound - 1);
+    usize::try_from(max).unwrap_or(usize::MAX)
+}
+
+/// Encodes `value` big-endian in `bits` bits. The value must fit.
+///
+/// 以 `bits` 位大端编码 `value`。该值必须能够容纳。
+fn encode_int(value: usize, bits: u32) -> Vec<u8> {
+    (value as u64).to_be_bytes()[8 - bits as usize / 8..].to_vec()
+}
+
+impl<M: Mac> Derivation for KbkdfScheme<M> {}
+
+impl<M: Mac> Algorithm for KbkdfScheme<M> {
+    fn name() -> String {
+        format!("KBKDF-{}", M::name())
+    }
+    const ID: u32 = 0x03_05_00_00 + (M::ID & 0xFFFF);
+    // The ID does not identify the MAC family, so take the level from the MAC itself.
+    // ID 不能区分 MAC 族，因此直接采用 MAC 自身的级别。
+    const SECURITY_LEVEL: SecurityLevel = M::SECURITY_LEVEL;
+}
+
+impl<M: Mac> Parameterized for KbkdfScheme<M> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("prf", ParamValue::String(M::name()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        let mode = match self.mode {
+            KbkdfMode::Counter => "counter",
+            KbkdfMode::Feedback { .. } => "feedback",
+        };
+        vec![
+            ("mode", ParamValue::String(mode.to_string())),
+            ("counter_bits", ParamValue::U32(self.counter_bits)),
+            ("length_bits", ParamValue::U32(self.length_bits)),
+        ]
+    }
+}
+
+#[cfg(all(test, any(feature = "hmac-default", feature = "cmac-default")))]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "cmac-default")]
+    #[test]
+    fn test_kbkdf_counter_cmac() {
+        use crate::systems::mac::cmac::Aes128Cmac;
+
+        // NIST CAVP KBKDF, counter mode, CMAC-AES128, 8-bit counter before the fixed input
+        let scheme = KbkdfScheme::<Aes128Cmac> {
+            counter_bits: 8,
+            ..KbkdfScheme::counter()
+        };
+        let key = SymmetricKey::new(hex::decode("dff1e50ac0b69dc40f1051d46c2b069c").unwrap());
+        let fixed_input = hex::decode(
+            "c16e6e02c5a3dcc8d78b9ac1306877761310455b4e41469951d9e6c2245a064b\
+             33fd8c3b01203a7824485bf0a64060c4648b707d2607935699316ea5",
+        )
+        .unwrap();
+        let okm = scheme
+            .derive_with_fixed_input(&key, &fixed_input, 16)
+            .unwrap();
+        assert_eq!(
+            hex::encode(okm.as_bytes()),
+            "8be8f0869b3c0ba97b71863d1b9f7813"
+        );
+
+        let key = SymmetricKey::new((0..16).collect::<Vec<u8>>());
+        let okm = KbkdfScheme::<Aes128Cmac>::counter()
+            .derive(&key, b"enc", b"hsm", 32)
+            .unwrap();
+        assert_eq!(
+            hex::encode(okm.as_bytes()),
+            "b233aaf5fa2cd6f5283a016c882d7059ab784b379b6290e11f71ae5c3bd59e84"
+        );
+    }
+
+    #[cfg(feature = "hmac-default")]
+    #[test]
+    fn test_kbkdf_hmac_modes() {
+        use crate::systems::mac::hmac::HmacSha256;
+
+        let key = SymmetricKey::new((0..32).collect::<Vec<u8>>());
+        let counter = KbkdfScheme::<HmacSha256>::counter()
+            .derive(&key, b"label", b"context", 42)
+            .unwrap();
+        assert_eq!(
+            hex::encode(counter.as_bytes()),
+            "b9cd5f6323f01f4680650855f1ebea9b4c54c08131b506fc28c856364a38a2f4fb680c12ea51696887d9"
+        );
+
+        let feedback = KbkdfScheme::<HmacSha256>::feedback(&[0x11; 32])
+            .derive(&key, b"label", b"context", 42)
+            .unwrap();
+        assert_eq!(
+            hex::encode(feedback.as_bytes()),
+            "e71e22cf94f55815a03b321a3f8139fcae72111869f62d73f7b69a04238430d40cb486293612bb1d07c9"
+        );
+
+        let no_counter = KbkdfScheme::<HmacSha256> {
+            mode: KbkdfMode::Feedback {
+                iv: vec![0x11; 32],
+                counter: false,
+            },
+            ..KbkdfScheme::counter()
+        };
+        assert_eq!(
+            hex::encode(
+                no_counter
+                    .derive(&key, b"label", b"context", 42)
+                    .unwrap()
+                    .as_bytes()
+            ),
+            "bed243e55b59ce321f557710780d8ea9a2aa2da05670b6f2c4f5e2d00d06149eba8e5013933d402ce2e7"
+        );
+
+        // An 8-bit counter covers at most 255 blocks
+        let short = KbkdfScheme::<HmacSha256> {
+            counter_bits: 8,
+            length_bits: 16,
+            ..KbkdfScheme::counter()
+        };
+        assert!(short.derive(&key, b"", b"", 255 * 32).is_ok());
+        assert_eq!(
+            short.derive(&key, b"", b"", 255 * 32 + 1).unwrap_err(),
+            Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 255 * 32 + 1,
+                max: 255 * 32
+            })
+        );
+        assert_eq!(
+            short.derive(&key, b"", b"", 0).unwrap_err(),
+            Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 0,
+                max: 255 * 32
+            })
+        );
+        let odd = KbkdfScheme::<HmacSha256> {
+            counter_bits: 12,
+            ..KbkdfScheme::counter()
+        };
+        assert_eq!(
+            odd.derive(&key, b"", b"", 32).unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+        assert_eq!(KbkdfScheme::<HmacSha256>::name(), "KBKDF-HMAC-SHA-256");
+    }
+
+    #[cfg(all(feature = "hmac-default", feature = "shake-default"))]
+    #[test]
+    fn test_kbkdf_security_level() {
+        use crate::systems::mac::{hmac::HmacSha256, kmac::Kmac128};
+
+        // KBKDF over KMAC128 shares its ID with KBKDF over HMAC-SHA-256
+        assert_eq!(KbkdfScheme::<Kmac128>::ID, KbkdfScheme::<HmacSha256>::ID);
+        assert_eq!(
+            KbkdfScheme::<Kmac128>::SECURITY_LEVEL,
+            Kmac128::SECURITY_LEVEL
+        );
+        assert_eq!(
+            KbkdfScheme::<HmacSha256>::SECURITY_LEVEL,
+            HmacSha256::SECURITY_LEVEL
+        );
+    }
+}
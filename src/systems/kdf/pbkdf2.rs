@@ -77,13 +77,21 @@
 //! 更高的迭代次数提供更好的安全性，但会增加计算时间。
 //! 默认迭代次数设置为在为大多数应用程序保持可接受性能的同时提供合理的安全性。
 
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use crate::{
     errors::Error,
     prelude::*
 };
+use crate::systems::formats::phc::PhcString;
+use crate::policy;
+use crate::traits::kdf::check_output_len;
 use crate::traits::params::{ParamValue, Parameterized};
-use secrecy::{ExposeSecret, SecretBox};
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
 // A reasonable default for iterations, based on OWASP recommendations.
 // For high-security applications, this value should be tuned.
@@ -110,6 +118,44 @@ impl<H: Hasher> Pbkdf2Scheme<H> {
             _hasher: PhantomData,
         }
     }
+
+    /// Benchmarks this host and returns the iteration count at which deriving one block of
+    /// output takes about `target`.
+    ///
+    /// The result is an estimate scaled from a short measurement, so it varies between runs
+    /// and with system load. Calibrate on hardware comparable to production, and store the
+    /// chosen count with each hash (e.g. in a PHC string) rather than recalibrating on every
+    /// start.
+    ///
+    /// 对本机进行基准测试，并返回派生一个输出块约需 `target` 时间的迭代次数。
+    ///
+    /// 结果是根据一次短暂测量缩放得到的估计值，因此会随运行和系统负载而变化。请在与生产环境相当的
+    /// 硬件上进行校准，并将选定的迭代次数随每个哈希一起存储（例如存储在 PHC 字符串中），
+    /// 而不是在每次启动时重新校准。
+    #[cfg(all(feature = "std", not(all(target_arch = "wasm32", target_os = "unknown"))))]
+    pub fn calibrate(target: core::time::Duration) -> u32 {
+        use std::time::{Duration, Instant};
+
+        let sample = (target / 10).max(Duration::from_millis(10)).min(target);
+        let mut okm = vec![0u8; H::OUTPUT_SIZE];
+        let mut probe: u32 = 1_000;
+        loop {
+            let start = Instant::now();
+            H::pbkdf2_hmac(
+                b"calibration password",
+                b"calibration salt",
+                probe,
+                &mut okm,
+            );
+            let elapsed = start.elapsed();
+            if elapsed >= sample || probe > u32::MAX / 2 {
+                let scaled =
+                    probe as f64 * target.as_secs_f64() / elapsed.as_secs_f64().max(1e-9);
+                return scaled.clamp(1.0, u32::MAX as f64) as u32;
+            }
+            probe *= 2;
+        }
+    }
 }
 
 impl<H: Hasher> Default for Pbkdf2Scheme<H> {
@@ -143,16 +189,60 @@ impl<H: Hasher> Parameterized for Pbkdf2Scheme<H> {
 impl<H: Hasher> PasswordBasedDerivation for Pbkdf2Scheme<H> {
     fn derive(
         &self,
-        password: &SecretBox<[u8]>,
+        password: &impl ExposeSecretBytes,
         salt: &[u8],
         output_len: usize,
     ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        policy::enforce_pbkdf2_iterations(self.iterations)?;
+        check_output_len(output_len, self.max_output_len())?;
         let mut okm = vec![0u8; output_len];
 
-        H::pbkdf2_hmac(password.expose_secret(), salt, self.iterations, &mut okm);
+        H::pbkdf2_hmac(password.expose_secret_bytes(), salt, self.iterations, &mut okm);
 
         Ok(DerivedKey::new(okm))
     }
+
+    fn max_output_len(&self) -> usize {
+        (u32::MAX as usize).saturating_mul(H::OUTPUT_SIZE)
+    }
+}
+
+impl<H: Hasher> Pbkdf2Scheme<H> {
+    /// The PHC identifier of this scheme, e.g. `pbkdf2-sha256`.
+    ///
+    /// 此方案的 PHC 标识符，例如 `pbkdf2-sha256`。
+    fn phc_id() -> String {
+        format!("pbkdf2-{}", H::NAME.to_lowercase().replace('-', ""))
+    }
+}
+
+impl<H: Hasher> PasswordHasher for Pbkdf2Scheme<H> {
+    fn to_phc(&self, salt: &[u8], hash: &[u8]) -> PhcString {
+        PhcString::new(
+            &Self::phc_id(),
+            None,
+            &[
+                ("i", self.iterations.to_string()),
+                ("l", hash.len().to_string()),
+            ],
+            salt,
+            hash,
+        )
+    }
+
+    fn from_phc(phc: &PhcString) -> Result<Self, Error> {
+        if phc.algorithm() != Self::phc_id() || phc.version().is_some() {
+            return Err(Error::Kdf(KdfError::InvalidPhcString));
+        }
+        if phc.param("l").is_some() && phc.param_u32("l")? as usize != phc.hash().len() {
+            return Err(Error::Kdf(KdfError::InvalidPhcString));
+        }
+        match phc.param_u32("i")? {
+            0 => Err(Error::Kdf(KdfError::InvalidPhcString)),
+            iterations => Ok(Self::new(iterations)),
+        }
+    }
 }
 
 // --- Type Aliases ---
@@ -181,6 +271,7 @@ pub type Pbkdf2Sha512 = Pbkdf2Scheme<Sha512>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use secrecy::SecretBox;
 
     fn run_pbkdf2_test<H: Hasher>()
     where
@@ -203,6 +294,11 @@ mod tests {
         let derived_key_result_default = scheme_default.derive(&password, salt, output_len);
         assert!(derived_key_result_default.is_ok());
 
+        // Any secret wrapper can hold the password
+        let secret_password = SecretBytes::from_secret(&password);
+        let from_secret = scheme_custom.derive(&secret_password, salt, output_len);
+        assert_eq!(from_secret.unwrap(), derived_key_custom);
+
         // Test without salt is no longer needed, as the function signature enforces it.
     }
 
@@ -262,4 +358,98 @@ mod tests {
     fn test_pbkdf2_sha512_generate_salt() {
         run_pbkdf2_generate_salt_test::<Sha512>();
     }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_pbkdf2_phc() {
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let wrong = SecretBox::new(Box::from(b"passw0rd".as_slice()));
+
+        // Produced with Python's hashlib.pbkdf2_hmac
+        let stored = "$pbkdf2-sha256$i=1000,l=32$c2FsdHNhbHRzYWx0c2FsdA\
+                      $8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA";
+        let phc: PhcString = stored.parse().unwrap();
+        assert!(Pbkdf2Sha256::verify_password(&phc, &password).is_ok());
+        assert_eq!(
+            Pbkdf2Sha256::verify_password(&phc, &wrong).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        assert_eq!(
+            Pbkdf2Sha512::verify_password(&phc, &password).unwrap_err(),
+            Error::Kdf(KdfError::InvalidPhcString)
+        );
+        assert!(crate::systems::formats::phc::verify_password(stored, &password).is_ok());
+
+        let phc = Pbkdf2Sha512::new(1000).hash_password(&password).unwrap();
+        assert!(phc.to_string().starts_with("$pbkdf2-sha512$i=1000,l=32$"));
+        assert!(Pbkdf2Sha512::verify_password(&phc, &password).is_ok());
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_pbkdf2_verify() {
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let wrong = SecretBox::new(Box::from(b"passw0rd".as_slice()));
+        let scheme = Pbkdf2Sha256::new(1000);
+        let mut key = scheme.derive(&password, b"salt", 32).unwrap();
+
+        assert!(scheme.verify(&password, b"salt", key.as_bytes()).is_ok());
+        assert_eq!(
+            scheme.verify(&wrong, b"salt", key.as_bytes()).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        assert_eq!(
+            Pbkdf2Sha256::new(1001)
+                .verify(&password, b"salt", key.as_bytes())
+                .unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        key.as_mut()[31] ^= 1;
+        assert_eq!(
+            scheme.verify(&password, b"salt", key.as_bytes()).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn test_pbkdf2_output_length() {
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let scheme = Pbkdf2Sha256::new(1);
+        assert_eq!(scheme.max_output_len(), (u32::MAX as usize).saturating_mul(32));
+        assert_eq!(
+            scheme.derive(&password, b"salt", 0).unwrap_err(),
+            Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 0,
+                max: scheme.max_output_len()
+            })
+        );
+    }
+
+    #[cfg(all(feature = "sha2", feature = "std"))]
+    #[test]
+    fn test_pbkdf2_calibrate() {
+        use core::time::Duration;
+
+        let iterations = Pbkdf2Sha256::calibrate(Duration::from_millis(20));
+        assert!(iterations >= 1);
+        assert_eq!(Pbkdf2Sha256::calibrate(Duration::ZERO), 1);
+    }
+
+    #[cfg(all(feature = "sha2", feature = "chacha20-poly1305-default"))]
+    #[test]
+    fn test_pbkdf2_derive_key_for() {
+        use crate::systems::aead::chacha20_poly1305::ChaCha20Poly1305;
+
+        let password = SecretBox::new(Box::from(b"password".as_slice()));
+        let scheme = Pbkdf2Sha256::new(1000);
+        let key = scheme
+            .derive_key_for::<ChaCha20Poly1305>(&password, b"salt")
+            .unwrap();
+        assert_eq!(key.len(), 32);
+        assert_eq!(
+            key.as_slice(),
+            scheme.derive(&password, b"salt", 32).unwrap().as_bytes()
+        );
+    }
 }
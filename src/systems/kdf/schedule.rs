@@ -0,0 +1,215 @@
+//! Provides a key schedule that derives several labeled subkeys from one secret.
+//!
+//! Protocols usually need a few related keys, such as an encryption key, a MAC key and an
+//! IV, derived from the same master secret. Choosing the `info` strings by hand invites
+//! collisions. A [`KeySchedule`] instead lists the outputs by label and derives each one
+//! with an unambiguous `info` of
+//! `len(context) || context || len(label) || label || output_len`, all lengths being 32-bit
+//! big-endian. Each subkey therefore depends only on the context, its own label and its
+//! length, and duplicate labels are rejected.
+//!
+//! Any [`KeyBasedDerivation`] can drive the schedule, e.g. HKDF.
+//!
+//! 提供了从一个秘密派生多个带标签子密钥的密钥调度。
+//!
+//! 协议通常需要从同一主密钥派生几个相关的密钥，例如加密密钥、MAC 密钥和 IV。手工选择 `info`
+//! 字符串容易导致冲突。[`KeySchedule`] 则按标签列出各个输出，并使用无歧义的 `info`
+//! `len(context) || context || len(label) || label || output_len`（所有长度均为 32 位大端）
+//! 派生每个输出。因此每个子密钥只依赖于上下文、其自身的标签和长度，重复的标签会被拒绝。
+//!
+//! 任何 [`KeyBasedDerivation`]（例如 HKDF）都可以驱动该调度。
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::traits::kdf::key_for;
+use crate::{errors::Error, prelude::*};
+
+/// A builder listing the labeled outputs to derive from one secret.
+///
+/// 列出要从一个秘密派生的带标签输出的构建器。
+#[derive(Clone, Debug)]
+pub struct KeySchedule<'a, K: KeyBasedDerivation> {
+    kdf: &'a K,
+    context: Vec<u8>,
+    outputs: Vec<(String, usize)>,
+}
+
+impl<'a, K: KeyBasedDerivation> KeySchedule<'a, K> {
+    /// Starts a key schedule over `kdf`. The `context`, e.g. a protocol name and version,
+    /// is bound into every output.
+    ///
+    /// 基于 `kdf` 开始一个密钥调度。`context`（例如协议名称和版本）会绑定到每个输出中。
+    pub fn new(kdf: &'a K, context: &[u8]) -> Self {
+        Self {
+            kdf,
+            context: context.to_vec(),
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Adds an output of `len` bytes under `label`.
+    ///
+    /// 在 `label` 下添加一个 `len` 字节的输出。
+    pub fn output(mut self, label: &str, len: usize) -> Self {
+        self.outputs.push((label.to_string(), len));
+        self
+    }
+
+    /// Adds a key for the symmetric scheme `S` under `label`, sized to `S::KEY_SIZE`.
+    ///
+    /// 在 `label` 下添加一个用于对称方案 `S` 的密钥，其大小为 `S::KEY_SIZE`。
+    pub fn key_for<S: SymmetricKeyGenerator>(self, label: &str) -> Self {
+        self.output(label, S::KEY_SIZE)
+    }
+
+    /// Derives every listed output from `ikm` and `salt`.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if a label is used twice or a field is too
+    /// long to encode, and otherwise with any error of the underlying KDF.
+    ///
+    /// 从 `ikm` 和 `salt` 派生所有列出的输出。
+    ///
+    /// 如果某个标签被使用两次或某个字段过长而无法编码，则以 `KdfError::InvalidParameter` 失败；
+    /// 否则返回底层 KDF 的任何错误。
+    pub fn derive(&self, ikm: &[u8], salt: Option<&[u8]>) -> Result<DerivedKeys, Error> {
+        let mut keys: Vec<(String, DerivedKey)> = Vec::with_capacity(self.outputs.len());
+        for (label, len) in &self.outputs {
+            if keys.iter().any(|(existing, _)| existing == label) {
+                return Err(Error::Kdf(KdfError::InvalidParameter));
+            }
+            let mut info = Vec::with_capacity(12 + self.context.len() + label.len());
+            for field in [self.context.as_slice(), label.as_bytes()] {
+                info.extend_from_slice(&encode_len(field.len())?);
+                info.extend_from_slice(field);
+            }
+            info.extend_from_slice(&encode_len(*len)?);
+
+            let key = self.kdf.derive(ikm, salt, Some(&info), *len)?;
+            keys.push((label.clone(), key));
+        }
+        Ok(DerivedKeys { keys })
+    }
+}
+
+fn encode_len(len: usize) -> Result<[u8; 4], Error> {
+    u32::try_from(len)
+        .map(u32::to_be_bytes)
+        .map_err(|_| Error::Kdf(KdfError::InvalidParameter))
+}
+
+/// The outputs of a [`KeySchedule`], looked up by label.
+///
+/// [`KeySchedule`] 的输出，按标签查找。
+#[derive(Clone, Debug)]
+pub struct DerivedKeys {
+    keys: Vec<(String, DerivedKey)>,
+}
+
+impl DerivedKeys {
+    /// Returns the output derived under `label`, if it was listed.
+    ///
+    /// 返回在 `label` 下派生的输出（如果已列出）。
+    pub fn get(&self, label: &str) -> Option<&DerivedKey> {
+        self.keys
+            .iter()
+            .find(|(existing, _)| existing == label)
+            .map(|(_, key)| key)
+    }
+
+    /// Returns the output derived under `label` as a key for the symmetric scheme `S`.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if the label was not listed, and with
+    /// `KdfError::InvalidOutputLength` if its length is not `S::KEY_SIZE`.
+    ///
+    /// 以对称方案 `S` 的密钥形式返回在 `label` 下派生的输出。
+    ///
+    /// 如果该标签未列出，则以 `KdfError::InvalidParameter` 失败；如果其长度不是 `S::KEY_SIZE`，
+    /// 则以 `KdfError::InvalidOutputLength` 失败。
+    pub fn key<S: SymmetricKeyGenerator>(&self, label: &str) -> Result<S::Key, Error> {
+        let key = self
+            .get(label)
+            .ok_or(Error::Kdf(KdfError::InvalidParameter))?;
+        key_for::<S>(key.clone())
+    }
+}
+
+#[cfg(all(test, feature = "hkdf-default"))]
+mod tests {
+    use super::*;
+    use crate::systems::kdf::hkdf::HkdfSha256;
+
+    #[test]
+    fn test_key_schedule() {
+        let hkdf = HkdfSha256::default();
+        let keys = KeySchedule::new(&hkdf, b"example protocol v1")
+            .output("enc", 32)
+            .output("mac", 32)
+            .output("iv", 12)
+            .derive(b"master secret", Some(b"salt"))
+            .unwrap();
+
+        let enc = keys.get("enc").unwrap();
+        let mac = keys.get("mac").unwrap();
+        assert_eq!(keys.get("iv").unwrap().as_bytes().len(), 12);
+        assert_ne!(enc, mac);
+        assert!(keys.get("other").is_none());
+
+        // Each output matches a direct derivation with the encoded info
+        let info = [
+            &19u32.to_be_bytes()[..],
+            b"example protocol v1",
+            &3u32.to_be_bytes(),
+            b"enc",
+            &32u32.to_be_bytes(),
+        ]
+        .concat();
+        let direct = hkdf
+            .derive(b"master secret", Some(b"salt"), Some(&info), 32)
+            .unwrap();
+        assert_eq!(enc, &direct);
+
+        // Outputs depend only on their own label and length, not on the other outputs
+        let alone = KeySchedule::new(&hkdf, b"example protocol v1")
+            .output("enc", 32)
+            .derive(b"master secret", Some(b"salt"))
+            .unwrap();
+        assert_eq!(alone.get("enc"), Some(enc));
+
+        assert_eq!(
+            KeySchedule::new(&hkdf, b"")
+                .output("enc", 32)
+                .output("enc", 16)
+                .derive(b"master secret", None)
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+    }
+
+    #[cfg(feature = "aes-gcm-default")]
+    #[test]
+    fn test_key_schedule_typed_keys() {
+        use crate::systems::aead::aes_gcm::{Aes128Gcm, Aes256Gcm};
+
+        let hkdf = HkdfSha256::default();
+        let keys = KeySchedule::new(&hkdf, b"example protocol v1")
+            .key_for::<Aes256Gcm>("client")
+            .key_for::<Aes128Gcm>("server")
+            .derive(b"master secret", None)
+            .unwrap();
+        assert_eq!(keys.key::<Aes256Gcm>("client").unwrap().len(), 32);
+        assert_eq!(keys.key::<Aes128Gcm>("server").unwrap().len(), 16);
+        assert_eq!(
+            keys.key::<Aes128Gcm>("client").unwrap_err(),
+            Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 32,
+                max: 16
+            })
+        );
+        assert_eq!(
+            keys.key::<Aes128Gcm>("missing").unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+    }
+}
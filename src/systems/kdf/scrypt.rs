@@ -0,0 +1,326 @@
+//! Provides an implementation of the scrypt password-based key derivation function.
+//!
+//! scrypt (RFC 7914) is a memory-hard password-based KDF. It fills a large table with
+//! Salsa20/8-based mixing of the password and salt and then reads it back in a
+//! data-dependent order, so attackers must spend memory as well as time on every guess.
+//!
+//! # Parameters
+//! - **N**: CPU/memory cost, a power of two greater than 1
+//! - **r**: Block size; memory use is about `128 * r * N` bytes
+//! - **p**: Parallelization, the number of independent mixing lanes
+//!
+//! # Default Parameters
+//! Based on OWASP recommendations: `N = 2^17`, `r = 8`, `p = 1`, which needs 128 MiB.
+//!
+//! # Security Considerations
+//! - Parameters read from stored hashes or other untrusted input can demand arbitrary
+//!   amounts of memory. Derivation is refused with `KdfError::MemoryLimitExceeded` when the
+//!   table would exceed `max_memory`, which defaults to [`SCRYPT_DEFAULT_MAX_MEMORY`].
+//! - Use unique random salts for each password.
+//! - Prefer Argon2id for new applications; scrypt is mainly provided for compatibility.
+//!
+//! 提供了 scrypt 基于密码的密钥派生函数的实现。
+//!
+//! scrypt (RFC 7914) 是一种内存困难的基于密码的 KDF。它使用基于 Salsa20/8 的混合将密码和盐填入
+//! 一个大表，然后以依赖数据的顺序读回，因此攻击者每次猜测都必须同时花费内存和时间。
+//!
+//! # 参数
+//! - **N**: CPU/内存成本，大于 1 的 2 的幂
+//! - **r**: 块大小；内存使用量约为 `128 * r * N` 字节
+//! - **p**: 并行化参数，即独立混合通道的数量
+//!
+//! # 默认参数
+//! 基于 OWASP 建议：`N = 2^17`、`r = 8`、`p = 1`，需要 128 MiB 内存。
+//!
+//! # 安全考虑
+//! - 从存储的哈希或其他不受信任的输入中读取的参数可能要求任意大小的内存。当表的大小超过
+//!   `max_memory`（默认为 [`SCRYPT_DEFAULT_MAX_MEMORY`]）时，派生会以 `KdfError::MemoryLimitExceeded` 被拒绝。
+//! - 为每个密码使用唯一的随机盐。
+//! - 新应用程序优先使用 Argon2id；提供 scrypt 主要是为了兼容性。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::policy;
+use crate::systems::formats::phc::PhcString;
+use crate::traits::kdf::{PasswordHasher, check_output_len};
+use crate::traits::params::{ParamValue, Parameterized};
+use crate::{
+    errors::Error,
+    traits::{
+        algorithm::Algorithm,
+        kdf::{Derivation, DerivedKey, KdfError, PasswordBasedDerivation},
+        key::ExposeSecretBytes,
+    },
+};
+
+/// scrypt default CPU/memory cost `N`. OWASP recommendation: 2^17.
+///
+/// scrypt 默认 CPU/内存成本 `N`。OWASP 建议值为 2^17。
+pub const SCRYPT_DEFAULT_N: u32 = 1 << 17;
+
+/// scrypt default block size `r`. OWASP recommendation: 8.
+///
+/// scrypt 默认块大小 `r`。OWASP 建议值为 8。
+pub const SCRYPT_DEFAULT_R: u32 = 8;
+
+/// scrypt default parallelization `p`. OWASP recommendation: 1.
+///
+/// scrypt 默认并行化参数 `p`。OWASP 建议值为 1。
+pub const SCRYPT_DEFAULT_P: u32 = 1;
+
+/// The default upper bound on the memory a derivation may use, in bytes (256 MiB).
+///
+/// 派生可使用内存的默认上限（以字节为单位，256 MiB）。
+pub const SCRYPT_DEFAULT_MAX_MEMORY: usize = 256 * 1024 * 1024;
+
+/// A struct representing the scrypt cryptographic system.
+///
+/// 代表 scrypt 加密系统的结构体。
+#[derive(Clone, Debug)]
+pub struct ScryptScheme {
+    /// CPU/memory cost `N`, a power of two greater than 1.
+    ///
+    /// CPU/内存成本 `N`，大于 1 的 2 的幂。
+    pub n: u32,
+    /// Block size `r`.
+    ///
+    /// 块大小 `r`。
+    pub r: u32,
+    /// Parallelization `p`.
+    ///
+    /// 并行化参数 `p`。
+    pub p: u32,
+    /// The largest amount of memory, in bytes, a derivation is allowed to allocate.
+    ///
+    /// 派生允许分配的最大内存量（以字节为单位）。
+    pub max_memory: usize,
+}
+
+impl ScryptScheme {
+    /// Creates a new scrypt scheme with the given `N`, `r` and `p` and the default memory limit.
+    ///
+    /// 使用给定的 `N`、`r` 和 `p` 以及默认内存上限创建一个新的 scrypt 方案。
+    pub fn new(n: u32, r: u32, p: u32) -> Self {
+        Self {
+            n,
+            r,
+            p,
+            max_memory: SCRYPT_DEFAULT_MAX_MEMORY,
+        }
+    }
+
+    /// Sets the largest amount of memory, in bytes, a derivation is allowed to allocate.
+    ///
+    /// 设置派生允许分配的最大内存量（以字节为单位）。
+    pub fn with_max_memory(mut self, max_memory: usize) -> Self {
+        self.max_memory = max_memory;
+        self
+    }
+
+    /// Returns the memory, in bytes, a derivation with these parameters allocates.
+    ///
+    /// 返回使用这些参数进行派生时分配的内存（以字节为单位）。
+    pub fn memory_required(&self) -> u128 {
+        128 * self.r as u128 * (self.n as u128 + self.p as u128)
+    }
+
+    pub(crate) fn params(&self) -> Result<scrypt::Params, Error> {
+        if self.n < 2 || !self.n.is_power_of_two() {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        if self.memory_required() > self.max_memory as u128 {
+            return Err(Error::Kdf(KdfError::MemoryLimitExceeded));
+        }
+        // The length given here only matters for PHC strings; `scrypt::scrypt` uses the
+        // length of the output buffer.
+        //
+        // 此处给出的长度仅对 PHC 字符串有意义；`scrypt::scrypt` 使用输出缓冲区的长度。
+        let log_n = self.n.trailing_zeros() as u8;
+        scrypt::Params::new(log_n, self.r, self.p, scrypt::Params::RECOMMENDED_LEN)
+            .map_err(|_| Error::Kdf(KdfError::InvalidParameter))
+    }
+}
+
+impl Default for ScryptScheme {
+    /// Creates a new scrypt scheme with the OWASP recommended parameters.
+    ///
+    /// 使用 OWASP 推荐的参数创建一个新的 scrypt 方案。
+    fn default() -> Self {
+        Self::new(SCRYPT_DEFAULT_N, SCRYPT_DEFAULT_R, SCRYPT_DEFAULT_P)
+    }
+}
+
+impl Derivation for ScryptScheme {}
+
+impl Algorithm for ScryptScheme {
+    fn name() -> String {
+        "scrypt".to_string()
+    }
+    const ID: u32 = 0x03_04_00_01;
+}
+
+impl Parameterized for ScryptScheme {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![
+            ("n", ParamValue::U32(self.n)),
+            ("r", ParamValue::U32(self.r)),
+            ("p", ParamValue::U32(self.p)),
+        ]
+    }
+}
+
+impl PasswordBasedDerivation for ScryptScheme {
+    fn derive(
+        &self,
+        password: &impl ExposeSecretBytes,
+        salt: &[u8],
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        check_output_len(output_len, self.max_output_len())?;
+        let params = self.params()?;
+        let mut output = vec![0u8; output_len];
+        scrypt::scrypt(password.expose_secret_bytes(), salt, &params, &mut output)
+            .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+        Ok(DerivedKey::new(output))
+    }
+
+    fn max_output_len(&self) -> usize {
+        // scrypt ends with PBKDF2-HMAC-SHA256, which yields at most (2^32 - 1) blocks.
+        //
+        // scrypt 最后一步为 PBKDF2-HMAC-SHA256，最多产生 (2^32 - 1) 个块。
+        (u32::MAX as usize).saturating_mul(32)
+    }
+}
+
+impl PasswordHasher for ScryptScheme {
+    fn to_phc(&self, salt: &[u8], hash: &[u8]) -> PhcString {
+        PhcString::new(
+            "scrypt",
+            None,
+            &[
+                ("ln", self.n.trailing_zeros().to_string()),
+                ("r", self.r.to_string()),
+                ("p", self.p.to_string()),
+            ],
+            salt,
+            hash,
+        )
+    }
+
+    fn from_phc(phc: &PhcString) -> Result<Self, Error> {
+        if phc.algorithm() != "scrypt" || phc.version().is_some() {
+            return Err(Error::Kdf(KdfError::InvalidPhcString));
+        }
+        let n = 1u32
+            .checked_shl(phc.param_u32("ln")?)
+            .ok_or(Error::Kdf(KdfError::InvalidPhcString))?;
+        Ok(Self::new(n, phc.param_u32("r")?, phc.param_u32("p")?))
+    }
+}
+
+/// A type alias for the scrypt scheme.
+///
+/// scrypt 方案的类型别名。
+pub type Scrypt = ScryptScheme;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::SecretBox;
+
+    fn password(bytes: &[u8]) -> SecretBox<[u8]> {
+        SecretBox::new(Box::from(bytes))
+    }
+
+    #[test]
+    fn test_scrypt_rfc7914_vectors() {
+        let key = Scrypt::new(16, 1, 1)
+            .derive(&password(b""), b"", 64)
+            .unwrap();
+        assert_eq!(
+            hex::encode(key.as_bytes()),
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+             fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        );
+
+        let key = Scrypt::new(1024, 8, 16)
+            .derive(&password(b"password"), b"NaCl", 64)
+            .unwrap();
+        assert_eq!(
+            hex::encode(key.as_bytes()),
+            "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162\
+             2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+        );
+    }
+
+    #[test]
+    fn test_scrypt_parameter_checks() {
+        let pw = password(b"password");
+        assert_eq!(
+            Scrypt::new(1000, 8, 1)
+                .derive(&pw, b"salt", 32)
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+        assert_eq!(
+            Scrypt::new(16, 0, 1).derive(&pw, b"salt", 32).unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+        assert_eq!(
+            Scrypt::new(16, 1, 1).derive(&pw, b"salt", 0).unwrap_err(),
+            Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 0,
+                max: (u32::MAX as usize).saturating_mul(32)
+            })
+        );
+
+        // N = 2^20 with r = 8 needs 1 GiB, above the default limit
+        let greedy = Scrypt::new(1 << 20, 8, 1);
+        assert_eq!(
+            greedy.derive(&pw, b"salt", 32).unwrap_err(),
+            Error::Kdf(KdfError::MemoryLimitExceeded)
+        );
+        assert_eq!(
+            Scrypt::new(1024, 8, 1)
+                .with_max_memory(1024 * 1024)
+                .derive(&pw, b"salt", 32)
+                .unwrap_err(),
+            Error::Kdf(KdfError::MemoryLimitExceeded)
+        );
+        assert!(Scrypt::default().memory_required() <= SCRYPT_DEFAULT_MAX_MEMORY as u128);
+    }
+
+    #[test]
+    fn test_scrypt_phc() {
+        // Produced with Python's hashlib.scrypt
+        let stored = "$scrypt$ln=4,r=1,p=1$c2FsdHNhbHRzYWx0c2FsdA\
+                      $OW+YCz5hkohNQCW7WleBqzj2qIyQr3XOr8uAq3oite0";
+        let phc: PhcString = stored.parse().unwrap();
+        assert!(Scrypt::verify_password(&phc, &password(b"password")).is_ok());
+        assert_eq!(
+            Scrypt::verify_password(&phc, &password(b"passw0rd")).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        assert_eq!(
+            Scrypt::from_phc(&phc)
+                .unwrap()
+                .to_phc(phc.salt(), phc.hash()),
+            phc
+        );
+
+        // Parameters from a stored hash are still subject to the memory limit
+        let greedy: PhcString = "$scrypt$ln=30,r=8,p=1$c2FsdA$aGFzaA".parse().unwrap();
+        assert_eq!(
+            Scrypt::verify_password(&greedy, &password(b"password")).unwrap_err(),
+            Error::Kdf(KdfError::MemoryLimitExceeded)
+        );
+    }
+}
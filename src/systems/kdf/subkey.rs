@@ -0,0 +1,104 @@
+//! Provides the HChaCha20 and HSalsa20 subkey derivation functions.
+//!
+//! HChaCha20 and HSalsa20 map a 256-bit key and a 128-bit input to a 256-bit subkey. They
+//! are the first step of XChaCha20 and XSalsa20 respectively, which is how those ciphers
+//! extend the nonce to 192 bits. Protocols built on X25519 also use them directly to turn
+//! the raw shared secret into a symmetric key, e.g. NaCl's `crypto_box_beforenm`, which is
+//! HSalsa20 applied to the shared secret with an all-zero input.
+//!
+//! # Security Considerations
+//! - The key must be uniformly random or a Diffie-Hellman shared secret. These functions
+//!   are not general-purpose KDFs and must not be used on passwords.
+//! - The same key and input always produce the same subkey.
+//!
+//! 提供了 HChaCha20 和 HSalsa20 子密钥派生函数。
+//!
+//! HChaCha20 和 HSalsa20 将 256 位密钥和 128 位输入映射为 256 位子密钥。它们分别是
+//! XChaCha20 和 XSalsa20 的第一步，这两种密码正是借此将 nonce 扩展到 192 位。基于 X25519
+//! 的协议也直接使用它们将原始共享密钥转换为对称密钥，例如 NaCl 的 `crypto_box_beforenm`，
+//! 即对共享密钥以全零输入应用 HSalsa20。
+//!
+//! # 安全考虑
+//! - 密钥必须是均匀随机的，或者是 Diffie-Hellman 共享密钥。这些函数不是通用 KDF，
+//!   不得用于密码。
+//! - 相同的密钥和输入总是产生相同的子密钥。
+
+#[cfg(any(feature = "chacha20-poly1305-default", feature = "crypto-box-default"))]
+use crate::prelude::*;
+
+/// The size of the key and of the derived subkey in bytes.
+///
+/// 密钥和派生子密钥的大小（以字节为单位）。
+pub const KEY_SIZE: usize = 32;
+
+/// The size of the input in bytes.
+///
+/// 输入的大小（以字节为单位）。
+pub const INPUT_SIZE: usize = 16;
+
+/// Derives a subkey with HChaCha20, as used by XChaCha20.
+///
+/// 使用 HChaCha20 派生子密钥，与 XChaCha20 中的用法相同。
+#[cfg(feature = "chacha20-poly1305-default")]
+pub fn hchacha20(key: &[u8; KEY_SIZE], input: &[u8; INPUT_SIZE]) -> SymmetricKey {
+    use chacha20::cipher::consts::U10;
+
+    let subkey = chacha20::hchacha::<U10>(key.into(), input.into());
+    SymmetricKey::new(subkey.to_vec())
+}
+
+/// Derives a subkey with HSalsa20, as used by XSalsa20 and NaCl's `crypto_box`.
+///
+/// 使用 HSalsa20 派生子密钥，与 XSalsa20 和 NaCl 的 `crypto_box` 中的用法相同。
+#[cfg(feature = "crypto-box-default")]
+pub fn hsalsa20(key: &[u8; KEY_SIZE], input: &[u8; INPUT_SIZE]) -> SymmetricKey {
+    use salsa20::cipher::consts::U10;
+
+    let subkey = salsa20::hsalsa::<U10>(key.into(), input.into());
+    SymmetricKey::new(subkey.to_vec())
+}
+
+#[cfg(all(
+    test,
+    any(feature = "chacha20-poly1305-default", feature = "crypto-box-default")
+))]
+mod tests {
+    use super::*;
+
+    fn array<const N: usize>(s: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        assert!(hex::decode_to_slice(s, &mut out).is_ok());
+        out
+    }
+
+    #[cfg(feature = "chacha20-poly1305-default")]
+    #[test]
+    fn test_hchacha20() {
+        // draft-irtf-cfrg-xchacha, section 2.2.1
+        let key = array("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let input = array("000000090000004a0000000031415927");
+        assert_eq!(
+            hex::encode(hchacha20(&key, &input)),
+            "82413b4227b27bfed30e42508a877d73a0f9e4d58a74a853c12ec41326d3ecdc"
+        );
+    }
+
+    #[cfg(feature = "crypto-box-default")]
+    #[test]
+    fn test_hsalsa20() {
+        // The `crypto_core_hsalsa20` tests from NaCl
+        let shared = array("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        let first = hsalsa20(&shared, &[0u8; INPUT_SIZE]);
+        assert_eq!(
+            hex::encode(&first),
+            "1b27556473e985d462cd51197a9a46c76009549eac6474f206c4ee0844f68389"
+        );
+        let mut first_key = [0u8; KEY_SIZE];
+        first_key.copy_from_slice(&first);
+        let second = hsalsa20(&first_key, &array("69696ee955b62b73cd62bda875fc73d6"));
+        assert_eq!(
+            hex::encode(&second),
+            "dc908dda0b9344a953629b733820778880f3ceb421bb61b91cbd4c3e66256ce4"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Provides the ANSI X9.63 key derivation function.
+//!
+//! The X9.63 KDF derives keying material from a shared secret `Z`, typically the output of
+//! an ECDH exchange, and optional `SharedInfo` by concatenating
+//! `H(Z || counter || SharedInfo)` for a 32-bit big-endian counter starting at 1. It is
+//! specified by SEC 1 for ECIES and is common in smart-card protocols.
+//!
+//! The scheme implements [`KeyBasedDerivation`]: the IKM is `Z` and the info is
+//! `SharedInfo`. X9.63 has no salt, so passing one is an error.
+//!
+//! # Security Considerations
+//! - Prefer HKDF for new designs; use X9.63 where a peer or standard requires it.
+//! - Output is limited to `(2^32 - 1)` hash blocks.
+//!
+//! 提供了 ANSI X9.63 密钥派生函数。
+//!
+//! X9.63 KDF 从共享密钥 `Z`（通常是 ECDH 交换的输出）和可选的 `SharedInfo` 派生密钥材料，
+//! 方法是将 `H(Z || counter || SharedInfo)` 依次拼接，其中 32 位大端计数器从 1 开始。
+//! 它由 SEC 1 为 ECIES 规定，并常见于智能卡协议中。
+//!
+//! 该方案实现了 [`KeyBasedDerivation`]：IKM 为 `Z`，info 为 `SharedInfo`。
+//! X9.63 没有盐，因此传入盐会出错。
+//!
+//! # 安全考虑
+//! - 新设计请优先使用 HKDF；在对端或标准要求时使用 X9.63。
+//! - 输出最多为 `(2^32 - 1)` 个哈希块。
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::policy;
+use crate::traits::kdf::check_output_len;
+use crate::traits::params::{ParamValue, Parameterized};
+use crate::{errors::Error, prelude::*};
+use core::marker::PhantomData;
+use zeroize::Zeroizing;
+
+/// A generic struct representing the X9.63 KDF for a given hash function.
+///
+/// 一个通用的 X9.63 KDF 结构体，它在哈希函数上是通用的。
+#[derive(Clone, Debug, Default)]
+pub struct X963KdfScheme<H: Hasher> {
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> X963KdfScheme<H> {
+    fn block(ikm: &[u8], counter: u32, info: Option<&[u8]>) -> Zeroizing<Vec<u8>> {
+        let mut state = H::Digest::default();
+        digest::Digest::update(&mut state, ikm);
+        digest::Digest::update(&mut state, counter.to_be_bytes());
+        digest::Digest::update(&mut state, info.unwrap_or_default());
+        Zeroizing::new(digest::Digest::finalize(state).to_vec())
+    }
+}
+
+impl<H: Hasher> Derivation for X963KdfScheme<H> {}
+
+impl<H: Hasher> Algorithm for X963KdfScheme<H> {
+    fn name() -> String {
+        format!("X9.63-KDF-{}", H::NAME)
+    }
+    const ID: u32 = 0x03_06_00_00 + H::ID_OFFSET;
+}
+
+impl<H: Hasher> Parameterized for X963KdfScheme<H> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(H::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<H: Hasher> KeyBasedDerivation for X963KdfScheme<H> {
+    fn derive(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        if salt.is_some() {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        check_output_len(output_len, self.max_output_len())?;
+
+        let blocks = output_len.div_ceil(H::OUTPUT_SIZE);
+        let mut okm = Vec::with_capacity(blocks * H::OUTPUT_SIZE);
+        for counter in (1..=u32::MAX).take(blocks) {
+            okm.extend_from_slice(&Self::block(ikm, counter, info));
+        }
+        okm.truncate(output_len);
+        Ok(DerivedKey::new(okm))
+    }
+
+    fn max_output_len(&self) -> usize {
+        (u32::MAX as usize).saturating_mul(H::OUTPUT_SIZE)
+    }
+}
+
+impl<H: Hasher> StreamingDerivation for X963KdfScheme<H> {
+    fn reader<'a>(
+        &self,
+        ikm: &'a [u8],
+        salt: Option<&'a [u8]>,
+        info: Option<&'a [u8]>,
+    ) -> Result<KdfReader<'a>, Error> {
+        policy::enforce::<Self>()?;
+        if salt.is_some() {
+            return Err(Error::Kdf(KdfError::InvalidParameter));
+        }
+        let mut counter = 0u32;
+        Ok(KdfReader::new(self.max_output_len(), move || {
+            counter = counter
+                .checked_add(1)
+                .ok_or(Error::Kdf(KdfError::DerivationFailed))?;
+            Ok(Self::block(ikm, counter, info))
+        }))
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the X9.63 KDF over SHA-256.
+///
+/// 基于 SHA-256 的 X9.63 KDF 的类型别名。
+pub type X963KdfSha256 = X963KdfScheme<Sha256>;
+
+/// A type alias for the X9.63 KDF over SHA-384.
+///
+/// 基于 SHA-384 的 X9.63 KDF 的类型别名。
+pub type X963KdfSha384 = X963KdfScheme<Sha384>;
+
+/// A type alias for the X9.63 KDF over SHA-512.
+///
+/// 基于 SHA-512 的 X9.63 KDF 的类型别名。
+pub type X963KdfSha512 = X963KdfScheme<Sha512>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn derive_hex<H: Hasher>(z: &str, shared_info: &str, output_len: usize) -> String {
+        let z = hex::decode(z).unwrap_or_default();
+        let shared_info = hex::decode(shared_info).unwrap_or_default();
+        X963KdfScheme::<H>::default()
+            .derive(&z, None, Some(&shared_info), output_len)
+            .map(|okm| hex::encode(okm.as_bytes()))
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_x963_kdf_sha256() {
+        // NIST CAVP ANSI X9.63 KDF vectors for SHA-256
+        assert_eq!(
+            derive_hex::<Sha256>("96c05619d56c328ab95fe84b18264b08725b85e33fd34f08", "", 16),
+            "443024c3dae66b95e6f5670601558f71"
+        );
+        assert_eq!(
+            derive_hex::<Sha256>(
+                "22518b10e70f2a3f243810ae3254139efbee04aa57c7af7d",
+                "75eef81aa3041e33b80971203d2c0c52",
+                32
+            ),
+            "c498af77161cc59f2962b9a713e2b215152d139766ce34a776df11866a69bf2e"
+        );
+    }
+
+    #[test]
+    fn test_x963_kdf_multiple_blocks() {
+        let z = (0..32).map(|b| format!("{b:02x}")).collect::<String>();
+        assert_eq!(
+            derive_hex::<Sha512>(&z, &hex::encode(b"shared info"), 80),
+            "690226c0e6ac85fe577d4e7f1751e44be3028e400b2a2f4c824cbe97e147b133\
+             e204741bb20286e114d6907b66113c6f38396388e0000477725636aacd40db67\
+             b5fe27c2eb0f2c73ea5c5cd2d3037c60"
+        );
+        assert_eq!(
+            X963KdfSha256::default()
+                .derive(b"z", Some(b"salt"), None, 32)
+                .err(),
+            Some(Error::Kdf(KdfError::InvalidParameter))
+        );
+        assert_eq!(
+            X963KdfSha256::default().derive(b"z", None, None, 0).err(),
+            Some(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 0,
+                max: (u32::MAX as usize).saturating_mul(32)
+            }))
+        );
+    }
+
+    #[test]
+    fn test_x963_kdf_reader() {
+        let scheme = X963KdfSha256::default();
+        let full = scheme
+            .derive(b"shared secret", None, Some(b"info"), 100)
+            .map(|okm| okm.as_bytes().to_vec())
+            .unwrap_or_default();
+        let Ok(mut reader) = scheme.reader(b"shared secret", None, Some(b"info")) else {
+            panic!("reader should be created");
+        };
+        let mut streamed = [0u8; 100];
+        let (head, tail) = streamed.split_at_mut(45);
+        assert!(reader.read(head).is_ok());
+        assert!(reader.read(tail).is_ok());
+        assert_eq!(streamed.as_slice(), full.as_slice());
+
+        assert_eq!(
+            scheme.reader(b"shared secret", Some(b"salt"), None).err(),
+            Some(Error::Kdf(KdfError::InvalidParameter))
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Keystore containers that bundle private keys with their certificates.
+//!
+//! This module provides implementations of keystore files, as exported by browsers, operating
+//! systems and Java tooling, so credentials can be loaded into the crate's key types without
+//! converting them with external tools first.
+//!
+//! # Available Implementations
+//! - **pkcs12**: PKCS#12 (`.p12` / `.pfx`) files (RFC 7292)
+//!
+//! 将私钥与其证书打包在一起的密钥库容器。
+//!
+//! 此模块提供密钥库文件的实现，这些文件由浏览器、操作系统和 Java 工具导出，
+//! 从而无需先用外部工具转换，即可将凭据加载到本 crate 的密钥类型中。
+//!
+//! # 可用实现
+//! - **pkcs12**: PKCS#12 (`.p12` / `.pfx`) 文件 (RFC 7292)
+
+/// PKCS#12 keystore implementation.
+///
+/// PKCS#12 密钥库实现。
+#[cfg(feature = "pkcs12-default")]
+pub mod pkcs12;
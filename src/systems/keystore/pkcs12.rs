@@ -0,0 +1,772 @@
+//! Provides PKCS#12 (`.p12` / `.pfx`) keystores.
+//!
+//! A PKCS#12 file (RFC 7292) bundles private keys with their X.509 certificates under a
+//! password. [`Pkcs12Store::from_der`] opens such a file and [`Pkcs12Store::to_der`] creates
+//! one; private keys are converted into the crate's key types with
+//! [`Pkcs12PrivateKey::to_key`], and certificates are kept as DER, which `Certificate::from_der`
+//! of the `x509` feature parses. Keys and certificates are paired through their `localKeyId`
+//! attribute.
+//!
+//! Files are written the way OpenSSL 3 writes them by default: keys and certificates are
+//! encrypted with PBES2 and AES-256-CBC, using the scrypt or PBKDF2 scheme passed to
+//! [`Pkcs12Store::to_der`], and the file is authenticated with HMAC-SHA-256. Such files can be
+//! imported with `openssl pkcs12`, on Windows and by Java's `KeyStore`.
+//!
+//! # Limitations
+//! Only PBES2-encrypted files with an HMAC-SHA-2 MAC are read. Files using the legacy
+//! RC2, RC4 or 3DES encryption or a SHA-1 MAC, as written by OpenSSL 1.x by default, are
+//! rejected; they can be re-exported with `openssl pkcs12 -export` first.
+//!
+//! 提供了 PKCS#12 (`.p12` / `.pfx`) 密钥库。
+//!
+//! PKCS#12 文件 (RFC 7292) 在密码保护下将私钥与其 X.509 证书打包在一起。
+//! [`Pkcs12Store::from_der`] 打开此类文件，[`Pkcs12Store::to_der`] 创建此类文件；
+//! 私钥通过 [`Pkcs12PrivateKey::to_key`] 转换为本 crate 的密钥类型，证书则以 DER 形式保存，
+//! 可由 `x509` 特性的 `Certificate::from_der` 解析。密钥和证书通过其 `localKeyId` 属性配对。
+//!
+//! 文件按 OpenSSL 3 的默认方式写出：密钥和证书使用传给 [`Pkcs12Store::to_der`] 的 scrypt
+//! 或 PBKDF2 方案，以 PBES2 和 AES-256-CBC 加密，整个文件以 HMAC-SHA-256 认证。
+//! 此类文件可以通过 `openssl pkcs12`、在 Windows 上以及由 Java 的 `KeyStore` 导入。
+//!
+//! # 限制
+//! 只读取使用 PBES2 加密并带有 HMAC-SHA-2 MAC 的文件。使用旧式 RC2、RC4 或 3DES 加密
+//! 或 SHA-1 MAC 的文件（OpenSSL 1.x 默认写出的格式）会被拒绝；可以先使用
+//! `openssl pkcs12 -export` 重新导出它们。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::systems::formats::encrypted_pkcs8::{
+    EncryptedPkcs8, Pbes2Kdf, with_random_pbes2_params,
+};
+use ::pkcs12::cert_type::CertBag;
+use ::pkcs12::digest_info::DigestInfo;
+use ::pkcs12::kdf::{Pkcs12KeyType, derive_key_utf8};
+use ::pkcs12::mac_data::MacData;
+use ::pkcs12::pfx::{Pfx, Version};
+use ::pkcs12::safe_bag::{SafeBag, SafeContents};
+use cms::content_info::{CmsVersion, ContentInfo};
+use cms::encrypted_data::EncryptedData;
+use cms::enveloped_data::EncryptedContentInfo;
+use hmac::SimpleHmac;
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::digest::{Digest, FixedOutputReset, KeyInit, Mac};
+use pkcs8::der::asn1::{Any, AnyRef, BmpString, ObjectIdentifier, OctetString, SetOfVec};
+use pkcs8::der::{Decode, Encode, Tag, TagNumber, Tagged};
+use pkcs8::pkcs5::{EncryptionScheme, pbes2};
+use pkcs8::spki::AlgorithmIdentifierOwned;
+use pkcs8::{EncryptedPrivateKeyInfo, PrivateKeyInfo};
+use secrecy::{ExposeSecret, SecretBox};
+use core::fmt;
+use x509_cert::Certificate;
+use x509_cert::attr::{Attribute, Attributes};
+use zeroize::Zeroizing;
+
+const ID_DATA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.1");
+const ID_ENCRYPTED_DATA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.6");
+const FRIENDLY_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.20");
+const LOCAL_KEY_ID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.21");
+const SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+const SHA384: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.2");
+const SHA512: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.3");
+
+const MAC_SALT_SIZE: usize = 16;
+/// The MAC iteration count OpenSSL uses by default.
+///
+/// OpenSSL 默认使用的 MAC 迭代次数。
+const MAC_ITERATIONS: i32 = 2048;
+
+fn invalid_encoding() -> Error {
+    Error::Key(KeyError::InvalidEncoding)
+}
+
+// ------------------- Bag Attributes -------------------
+// ------------------- 包属性 -------------------
+
+/// The `friendlyName` and `localKeyId` attributes of a key or certificate.
+///
+/// 密钥或证书的 `friendlyName` 和 `localKeyId` 属性。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct BagAttributes {
+    friendly_name: Option<String>,
+    local_key_id: Option<Vec<u8>>,
+}
+
+impl BagAttributes {
+    fn from_attributes(attributes: Option<&Attributes>) -> Result<Self, Error> {
+        let mut result = Self::default();
+        for attribute in attributes.into_iter().flat_map(|a| a.iter()) {
+            let Some(value) = attribute.values.iter().next() else {
+                continue;
+            };
+            if attribute.oid == FRIENDLY_NAME {
+                let name = value
+                    .decode_as::<BmpString>()
+                    .map_err(|_| invalid_encoding())?;
+                result.friendly_name = Some(name.to_string());
+            } else if attribute.oid == LOCAL_KEY_ID {
+                let id = value
+                    .decode_as::<OctetString>()
+                    .map_err(|_| invalid_encoding())?;
+                result.local_key_id = Some(id.into_bytes());
+            }
+        }
+        Ok(result)
+    }
+
+    fn to_attributes(&self) -> Result<Option<Attributes>, Error> {
+        let mut attributes = Vec::new();
+        if let Some(name) = &self.friendly_name {
+            let value = BmpString::from_utf8(name).and_then(|name| Any::encode_from(&name));
+            attributes.push((FRIENDLY_NAME, value));
+        }
+        if let Some(id) = &self.local_key_id {
+            let value = OctetString::new(id.as_slice()).and_then(|id| Any::encode_from(&id));
+            attributes.push((LOCAL_KEY_ID, value));
+        }
+        if attributes.is_empty() {
+            return Ok(None);
+        }
+        let attributes = attributes
+            .into_iter()
+            .map(|(oid, value)| {
+                Ok(Attribute {
+                    oid,
+                    values: SetOfVec::try_from(vec![value?])?,
+                })
+            })
+            .collect::<Result<Vec<_>, pkcs8::der::Error>>()
+            .and_then(SetOfVec::try_from)
+            .map_err(|_| invalid_encoding())?;
+        Ok(Some(attributes))
+    }
+}
+
+// ------------------- Entries -------------------
+// ------------------- 条目 -------------------
+
+/// A private key stored in a PKCS#12 file, held as its PKCS#8 encoding.
+///
+/// 存储在 PKCS#12 文件中的私钥，以其 PKCS#8 编码保存。
+#[derive(Clone)]
+pub struct Pkcs12PrivateKey {
+    der: Zeroizing<Vec<u8>>,
+    attributes: BagAttributes,
+}
+
+impl Pkcs12PrivateKey {
+    /// Creates an entry for `key`.
+    ///
+    /// 为 `key` 创建一个条目。
+    pub fn new<K: EncryptedPkcs8>(key: &K) -> Result<Self, Error> {
+        Self::from_pkcs8(Zeroizing::new(key.to_bytes()?))
+    }
+
+    fn from_pkcs8(der: Zeroizing<Vec<u8>>) -> Result<Self, Error> {
+        PrivateKeyInfo::try_from(der.as_slice()).map_err(|_| invalid_encoding())?;
+        Ok(Self {
+            der,
+            attributes: BagAttributes::default(),
+        })
+    }
+
+    /// Sets the `friendlyName` attribute, shown as the entry's alias by most tools.
+    ///
+    /// 设置 `friendlyName` 属性，大多数工具将其显示为条目的别名。
+    pub fn with_friendly_name(mut self, name: &str) -> Self {
+        self.attributes.friendly_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the `localKeyId` attribute, which pairs the key with its certificate.
+    ///
+    /// 设置 `localKeyId` 属性，用于将密钥与其证书配对。
+    pub fn with_local_key_id(mut self, id: &[u8]) -> Self {
+        self.attributes.local_key_id = Some(id.to_vec());
+        self
+    }
+
+    /// Returns the `friendlyName` attribute, if present.
+    ///
+    /// 返回 `friendlyName` 属性（如果存在）。
+    pub fn friendly_name(&self) -> Option<&str> {
+        self.attributes.friendly_name.as_deref()
+    }
+
+    /// Returns the `localKeyId` attribute, if present.
+    ///
+    /// 返回 `localKeyId` 属性（如果存在）。
+    pub fn local_key_id(&self) -> Option<&[u8]> {
+        self.attributes.local_key_id.as_deref()
+    }
+
+    /// Returns the PKCS#8 encoding of the key.
+    ///
+    /// 返回密钥的 PKCS#8 编码。
+    pub fn pkcs8_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// Converts the entry into a key of type `K`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the entry does not hold a key of this type.
+    ///
+    /// 将条目转换为 `K` 类型的密钥。
+    ///
+    /// 如果条目不包含此类型的密钥，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn to_key<K: EncryptedPkcs8>(&self) -> Result<K, Error> {
+        K::from_bytes(&self.der)
+    }
+}
+
+impl fmt::Debug for Pkcs12PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pkcs12PrivateKey")
+            .field("friendly_name", &self.attributes.friendly_name)
+            .field("local_key_id", &self.attributes.local_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An X.509 certificate stored in a PKCS#12 file, held as DER.
+///
+/// 存储在 PKCS#12 文件中的 X.509 证书，以 DER 形式保存。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pkcs12Certificate {
+    der: Vec<u8>,
+    attributes: BagAttributes,
+}
+
+impl Pkcs12Certificate {
+    /// Creates an entry for a DER-encoded certificate.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if `der` is not an X.509 certificate.
+    ///
+    /// 为 DER 编码的证书创建一个条目。
+    ///
+    /// 如果 `der` 不是 X.509 证书，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn new(der: &[u8]) -> Result<Self, Error> {
+        Certificate::from_der(der).map_err(|_| invalid_encoding())?;
+        Ok(Self {
+            der: der.to_vec(),
+            attributes: BagAttributes::default(),
+        })
+    }
+
+    /// Sets the `friendlyName` attribute, shown as the entry's alias by most tools.
+    ///
+    /// 设置 `friendlyName` 属性，大多数工具将其显示为条目的别名。
+    pub fn with_friendly_name(mut self, name: &str) -> Self {
+        self.attributes.friendly_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the `localKeyId` attribute, which pairs the certificate with its private key.
+    ///
+    /// 设置 `localKeyId` 属性，用于将证书与其私钥配对。
+    pub fn with_local_key_id(mut self, id: &[u8]) -> Self {
+        self.attributes.local_key_id = Some(id.to_vec());
+        self
+    }
+
+    /// Returns the `friendlyName` attribute, if present.
+    ///
+    /// 返回 `friendlyName` 属性（如果存在）。
+    pub fn friendly_name(&self) -> Option<&str> {
+        self.attributes.friendly_name.as_deref()
+    }
+
+    /// Returns the `localKeyId` attribute, if present.
+    ///
+    /// 返回 `localKeyId` 属性（如果存在）。
+    pub fn local_key_id(&self) -> Option<&[u8]> {
+        self.attributes.local_key_id.as_deref()
+    }
+
+    /// Returns the DER encoding of the certificate.
+    ///
+    /// 返回证书的 DER 编码。
+    pub fn der(&self) -> &[u8] {
+        &self.der
+    }
+}
+
+// ------------------- Keystore -------------------
+// ------------------- 密钥库 -------------------
+
+/// The private keys and certificates of a PKCS#12 file.
+///
+/// PKCS#12 文件中的私钥和证书。
+#[derive(Clone, Debug, Default)]
+pub struct Pkcs12Store {
+    private_keys: Vec<Pkcs12PrivateKey>,
+    certificates: Vec<Pkcs12Certificate>,
+}
+
+impl Pkcs12Store {
+    /// Creates an empty keystore.
+    ///
+    /// 创建一个空的密钥库。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the private keys in the keystore.
+    ///
+    /// 返回密钥库中的私钥。
+    pub fn private_keys(&self) -> &[Pkcs12PrivateKey] {
+        &self.private_keys
+    }
+
+    /// Returns the certificates in the keystore.
+    ///
+    /// 返回密钥库中的证书。
+    pub fn certificates(&self) -> &[Pkcs12Certificate] {
+        &self.certificates
+    }
+
+    /// Adds a private key to the keystore.
+    ///
+    /// 向密钥库添加一个私钥。
+    pub fn push_private_key(&mut self, key: Pkcs12PrivateKey) {
+        self.private_keys.push(key);
+    }
+
+    /// Adds a certificate to the keystore.
+    ///
+    /// 向密钥库添加一个证书。
+    pub fn push_certificate(&mut self, certificate: Pkcs12Certificate) {
+        self.certificates.push(certificate);
+    }
+
+    /// Finds the certificate sharing `key`'s `localKeyId`.
+    ///
+    /// 查找与 `key` 具有相同 `localKeyId` 的证书。
+    pub fn certificate_for(&self, key: &Pkcs12PrivateKey) -> Option<&Pkcs12Certificate> {
+        let id = key.local_key_id()?;
+        self.certificates
+            .iter()
+            .find(|certificate| certificate.local_key_id() == Some(id))
+    }
+
+    /// Encrypts the keystore under `password` and returns the DER-encoded PKCS#12 file.
+    ///
+    /// # Arguments
+    /// * `password` - The password protecting the file. It must be valid UTF-8.
+    /// * `kdf` - The scrypt or PBKDF2 scheme deriving the encryption keys from the password.
+    ///
+    /// Fails with `KdfError::InvalidParameter` if the password is not UTF-8 or the KDF
+    /// parameters cannot be used, and with `KdfError::SaltGenerationFailed` if no randomness
+    /// is available.
+    ///
+    /// 使用 `password` 加密密钥库，并返回 DER 编码的 PKCS#12 文件。
+    ///
+    /// # 参数
+    /// * `password` - 保护文件的密码，必须是有效的 UTF-8。
+    /// * `kdf` - 从密码派生加密密钥的 scrypt 或 PBKDF2 方案。
+    ///
+    /// 如果密码不是 UTF-8 或 KDF 参数无法使用，则以 `KdfError::InvalidParameter` 失败；
+    /// 如果无法获得随机数，则以 `KdfError::SaltGenerationFailed` 失败。
+    pub fn to_der<K: Pbes2Kdf>(
+        &self,
+        password: &SecretBox<[u8]>,
+        kdf: &K,
+    ) -> Result<Vec<u8>, Error> {
+        let password = password_str(password)?;
+        let mut auth_safe = Vec::new();
+
+        if !self.private_keys.is_empty() {
+            let mut bags = SafeContents::new();
+            for key in &self.private_keys {
+                let info =
+                    PrivateKeyInfo::try_from(key.der.as_slice()).map_err(|_| invalid_encoding())?;
+                let shrouded = with_random_pbes2_params(kdf, |params| {
+                    info.encrypt_with_params(params, password)
+                        .map_err(|_| Error::Kdf(KdfError::DerivationFailed))
+                })?;
+                bags.push(SafeBag {
+                    bag_id: ::pkcs12::PKCS_12_PKCS8_KEY_BAG_OID,
+                    bag_value: shrouded.as_bytes().to_vec(),
+                    bag_attributes: key.attributes.to_attributes()?,
+                });
+            }
+            // Shrouded keys are already encrypted, so they go into a plain data content
+            let contents = bags.to_der().map_err(|_| invalid_encoding())?;
+            auth_safe.push(data_content(contents)?);
+        }
+
+        if !self.certificates.is_empty() {
+            let mut bags = SafeContents::new();
+            for certificate in &self.certificates {
+                let cert_bag = CertBag {
+                    cert_id: ::pkcs12::PKCS_12_X509_CERT_OID,
+                    cert_value: OctetString::new(certificate.der.as_slice())
+                        .map_err(|_| invalid_encoding())?,
+                };
+                bags.push(SafeBag {
+                    bag_id: ::pkcs12::PKCS_12_CERT_BAG_OID,
+                    bag_value: cert_bag.to_der().map_err(|_| invalid_encoding())?,
+                    bag_attributes: certificate.attributes.to_attributes()?,
+                });
+            }
+            let contents = bags.to_der().map_err(|_| invalid_encoding())?;
+            auth_safe.push(encrypted_data_content(&contents, password, kdf)?);
+        }
+
+        let auth_safe = auth_safe.to_der().map_err(|_| invalid_encoding())?;
+        let mut salt = [0u8; MAC_SALT_SIZE];
+        crate::rng::fill_nonce(&mut salt).map_err(|_| Error::Kdf(KdfError::SaltGenerationFailed))?;
+        let mac = mac::<sha2::Sha256>(password, &salt, MAC_ITERATIONS, &auth_safe)?;
+        let mac_data = MacData {
+            mac: DigestInfo {
+                algorithm: AlgorithmIdentifierOwned {
+                    oid: SHA256,
+                    parameters: Some(Any::null()),
+                },
+                digest: OctetString::new(mac.finalize().into_bytes().to_vec())
+                    .map_err(|_| invalid_encoding())?,
+            },
+            mac_salt: OctetString::new(salt.as_slice()).map_err(|_| invalid_encoding())?,
+            iterations: MAC_ITERATIONS,
+        };
+
+        Pfx {
+            version: Version::V3,
+            auth_safe: data_content(auth_safe)?,
+            mac_data: Some(mac_data),
+        }
+        .to_der()
+        .map_err(|_| invalid_encoding())
+    }
+
+    /// Opens a DER-encoded PKCS#12 file with `password`.
+    ///
+    /// Certificate revocation lists, secret bags and unknown certificate types are skipped.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the file is malformed or uses an unsupported
+    /// algorithm, and with `KdfError::VerificationFailed` if its MAC does not verify under
+    /// `password`.
+    ///
+    /// 使用 `password` 打开 DER 编码的 PKCS#12 文件。
+    ///
+    /// 证书吊销列表、秘密包和未知的证书类型会被跳过。
+    ///
+    /// 如果文件格式错误或使用了不支持的算法，则以 `KeyError::InvalidEncoding` 失败；
+    /// 如果其 MAC 无法在 `password` 下验证，则以 `KdfError::VerificationFailed` 失败。
+    pub fn from_der(der: &[u8], password: &SecretBox<[u8]>) -> Result<Self, Error> {
+        let password = password_str(password)?;
+        let pfx = Pfx::from_der(der).map_err(|_| invalid_encoding())?;
+        let auth_safe = data_content_bytes(&pfx.auth_safe)?;
+        // Public-key integrity mode (signed data) and files without a MAC are not supported
+        let mac_data = pfx.mac_data.ok_or_else(invalid_encoding)?;
+        verify_mac(&mac_data, password, &auth_safe)?;
+
+        let mut store = Self::new();
+        let contents = Vec::<ContentInfo>::from_der(&auth_safe).map_err(|_| invalid_encoding())?;
+        for content in &contents {
+            let bags = if content.content_type == ID_DATA {
+                Zeroizing::new(data_content_bytes(content)?)
+            } else if content.content_type == ID_ENCRYPTED_DATA {
+                decrypt_data_content(content, password)?
+            } else {
+                return Err(invalid_encoding());
+            };
+            let bags = SafeContents::from_der(&bags).map_err(|_| invalid_encoding())?;
+            for bag in &bags {
+                store.read_bag(bag, password)?;
+            }
+        }
+        Ok(store)
+    }
+
+    fn read_bag(&mut self, bag: &SafeBag, password: &str) -> Result<(), Error> {
+        let attributes = BagAttributes::from_attributes(bag.bag_attributes.as_ref())?;
+        let value = bag_value(bag)?;
+        match bag.bag_id {
+            ::pkcs12::PKCS_12_PKCS8_KEY_BAG_OID => {
+                let info =
+                    EncryptedPrivateKeyInfo::try_from(value).map_err(|_| invalid_encoding())?;
+                let document = info
+                    .decrypt(password)
+                    .map_err(|_| Error::Kdf(KdfError::VerificationFailed))?;
+                let mut key =
+                    Pkcs12PrivateKey::from_pkcs8(Zeroizing::new(document.as_bytes().to_vec()))?;
+                key.attributes = attributes;
+                self.private_keys.push(key);
+            }
+            ::pkcs12::PKCS_12_KEY_BAG_OID => {
+                let mut key = Pkcs12PrivateKey::from_pkcs8(Zeroizing::new(value.to_vec()))?;
+                key.attributes = attributes;
+                self.private_keys.push(key);
+            }
+            ::pkcs12::PKCS_12_CERT_BAG_OID => {
+                let cert_bag = CertBag::from_der(value).map_err(|_| invalid_encoding())?;
+                if cert_bag.cert_id == ::pkcs12::PKCS_12_X509_CERT_OID {
+                    let mut certificate = Pkcs12Certificate::new(cert_bag.cert_value.as_bytes())?;
+                    certificate.attributes = attributes;
+                    self.certificates.push(certificate);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// PBES2 uses the password's UTF-8 bytes, while the MAC key is derived from its BMPString
+/// encoding, so the password must be valid UTF-8.
+///
+/// PBES2 使用密码的 UTF-8 字节，而 MAC 密钥从其 BMPString 编码派生，因此密码必须是有效的 UTF-8。
+fn password_str(password: &SecretBox<[u8]>) -> Result<&str, Error> {
+    core::str::from_utf8(password.expose_secret())
+        .map_err(|_| Error::Kdf(KdfError::InvalidParameter))
+}
+
+fn data_content(bytes: Vec<u8>) -> Result<ContentInfo, Error> {
+    let content = OctetString::new(bytes)
+        .and_then(|octets| Any::encode_from(&octets))
+        .map_err(|_| invalid_encoding())?;
+    Ok(ContentInfo {
+        content_type: ID_DATA,
+        content,
+    })
+}
+
+fn data_content_bytes(content: &ContentInfo) -> Result<Vec<u8>, Error> {
+    if content.content_type != ID_DATA {
+        return Err(invalid_encoding());
+    }
+    content
+        .content
+        .decode_as::<OctetString>()
+        .map(OctetString::into_bytes)
+        .map_err(|_| invalid_encoding())
+}
+
+/// Returns the `bagValue` of a decoded bag.
+///
+/// `pkcs12` keeps the `[0]` tag around the value when decoding but not when encoding, so it is
+/// removed here.
+///
+/// 返回已解码包的 `bagValue`。
+///
+/// `pkcs12` 在解码时会保留值外层的 `[0]` 标签，而编码时不会，因此在这里将其移除。
+fn bag_value(bag: &SafeBag) -> Result<&[u8], Error> {
+    let wrapper = AnyRef::from_der(&bag.bag_value).map_err(|_| invalid_encoding())?;
+    if wrapper.tag()
+        != (Tag::ContextSpecific {
+            constructed: true,
+            number: TagNumber::N0,
+        })
+    {
+        return Err(invalid_encoding());
+    }
+    Ok(wrapper.value())
+}
+
+fn encrypted_data_content<K: Pbes2Kdf>(
+    plaintext: &[u8],
+    password: &str,
+    kdf: &K,
+) -> Result<ContentInfo, Error> {
+    let (algorithm, ciphertext) = with_random_pbes2_params(kdf, |params| {
+        let ciphertext = params
+            .encrypt(password, plaintext)
+            .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+        let algorithm = EncryptionScheme::from(params)
+            .to_der()
+            .map_err(|_| invalid_encoding())?;
+        Ok((algorithm, ciphertext))
+    })?;
+    let encrypted = EncryptedData {
+        version: CmsVersion::V0,
+        enc_content_info: EncryptedContentInfo {
+            content_type: ID_DATA,
+            content_enc_alg: AlgorithmIdentifierOwned::from_der(&algorithm)
+                .map_err(|_| invalid_encoding())?,
+            encrypted_content: Some(OctetString::new(ciphertext).map_err(|_| invalid_encoding())?),
+        },
+        unprotected_attrs: None,
+    };
+    Ok(ContentInfo {
+        content_type: ID_ENCRYPTED_DATA,
+        content: Any::encode_from(&encrypted).map_err(|_| invalid_encoding())?,
+    })
+}
+
+fn decrypt_data_content(
+    content: &ContentInfo,
+    password: &str,
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let encrypted = content
+        .content
+        .decode_as::<EncryptedData>()
+        .map_err(|_| invalid_encoding())?;
+    let info = encrypted.enc_content_info;
+    let ciphertext = info.encrypted_content.ok_or_else(invalid_encoding)?;
+    let algorithm = info
+        .content_enc_alg
+        .to_der()
+        .map_err(|_| invalid_encoding())?;
+    // Legacy PKCS#12 PBE algorithms fail to parse here
+    let scheme =
+        EncryptionScheme::try_from(algorithm.as_slice()).map_err(|_| invalid_encoding())?;
+    if scheme.pbes2().is_none() {
+        return Err(invalid_encoding());
+    }
+    scheme
+        .decrypt(password, ciphertext.as_bytes())
+        .map(Zeroizing::new)
+        .map_err(|_| Error::Kdf(KdfError::VerificationFailed))
+}
+
+/// Computes the PKCS#12 MAC (RFC 7292 Appendix B) over `data`.
+///
+/// 计算 `data` 上的 PKCS#12 MAC (RFC 7292 附录 B)。
+fn mac<D: Digest + FixedOutputReset + BlockSizeUser>(
+    password: &str,
+    salt: &[u8],
+    iterations: i32,
+    data: &[u8],
+) -> Result<SimpleHmac<D>, Error> {
+    let key = derive_key_utf8::<D>(
+        password,
+        salt,
+        Pkcs12KeyType::Mac,
+        iterations,
+        <D as Digest>::output_size(),
+    )
+    .map(Zeroizing::new)
+    .map_err(|_| Error::Kdf(KdfError::InvalidParameter))?;
+    let mut mac = <SimpleHmac<D> as KeyInit>::new_from_slice(&key)
+        .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+    Mac::update(&mut mac, data);
+    Ok(mac)
+}
+
+fn verify_mac(mac_data: &MacData, password: &str, data: &[u8]) -> Result<(), Error> {
+    let salt = mac_data.mac_salt.as_bytes();
+    let iterations = mac_data.iterations;
+    // Bounds the work an untrusted file can request
+    if iterations < 1 || iterations as u32 > pbes2::Pbkdf2Params::MAX_ITERATION_COUNT {
+        return Err(invalid_encoding());
+    }
+    let expected = mac_data.mac.digest.as_bytes();
+    let verified = match mac_data.mac.algorithm.oid {
+        SHA256 => mac::<sha2::Sha256>(password, salt, iterations, data)?.verify_slice(expected),
+        SHA384 => mac::<sha2::Sha384>(password, salt, iterations, data)?.verify_slice(expected),
+        SHA512 => mac::<sha2::Sha512>(password, salt, iterations, data)?.verify_slice(expected),
+        _ => return Err(invalid_encoding()),
+    };
+    verified.map_err(|_| Error::Kdf(KdfError::VerificationFailed))
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(all(test, feature = "ecc-default", feature = "pbkdf2-default"))]
+mod tests {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+    use crate::systems::kdf::pbkdf2::Pbkdf2Sha256;
+
+    fn password(value: &[u8]) -> SecretBox<[u8]> {
+        SecretBox::new(Box::from(value))
+    }
+
+    // A P-256 key and a self-signed certificate for "CN=alice", exported by
+    // `openssl pkcs12 -export -name alice -iter 1000 -maciter` with the password
+    // "correct horse", and the key's plaintext PKCS#8 encoding.
+    const OPENSSL_P12: &str = "30820447020103308203fd06092a864886f70d010701a08203ee048203ea308203e63082027a06092a864886f70d010706a082026b308202670201003082026006092a864886f70d010701305f06092a864886f70d01050d3052303106092a864886f70d01050c30240410db8b668600a81438ca51545ab9a903aa020203e8300c06082a864886f70d02090500301d060960864801650304012a041079d1f11a02b6c53370ce367444b057ea808201f024b42e112e45780a3a152d6407472ff344b152a2cffa2da9064c96c2740883ffde93c1beac777053d4794d89c0a90602961a9d7f884ef67dca0004b32502136f71c43512b7b7b3e3db05a72b9a4a50e9e2143dc6bfc1a8149ab5721ab3a437987fe03d254d4cdab86d4a652b1750a6697ef96b3e10fa43f7e37d4f61c9637fba9ffb10c86a130142e12c07f626ff4b423cf290914ce5ba6317ad4298a29b75bc8c3f300de8d0f9bab61fcd099cd277b6ef04e0e33aba7148e1a985eb68a45c20e7ca1b4111069c948675fedcf6e3d92d74c29a4cdf632ec7cc104ab333b6db703833ce36dc0bdcdda3a2208ffc5fda5638a616463292a1f59347fddaed1d4056a371540ee833323ff6d691b2685a989ee520692501fc982c4ec2d69dda62ee54f16ba4a5671dd6d526599df84b717e27285ae0a2e06a4cd425b7f38f2e3576f057b173d2d20214722112d3f0c09a0d998095351dbcdb4f38e0ca7e911ee9d590d9687ba5b2544148d806ba6dc7c22934d3d00f53d6d55a80d08dcd7c3879104d37b128a325bdf85349c19247f7b645cddcbd2319fe71bd64e795728dc3b188bdaf289ca9503cffde1f228c2ee2cab302e96f4e998b3b0b42d88ef81342f35e09255228de677bccd2516955c4c27c39e0fa892d18893a6cfa3031214a4169f25d1c8f3b5cccb797e0215a5a6f5992e2e03082016406092a864886f70d010701a0820155048201513082014d30820149060b2a864886f70d010c0a0102a081f73081f4305f06092a864886f70d01050d3052303106092a864886f70d01050c3024041003f12367c92383232811453980c02a76020203e8300c06082a864886f70d02090500301d060960864801650304012a0410c54107ac7f7f8a222e7f7c1f0c7df346048190dd199e4b4f322cbb1899b3dc0da97d1f9cd1bd6b3e93298727e9fb2fa5b7ae0c7aee9091270b71d8aca82e0feb08cad3c156ce134b3d27e9d7eb9d74c709723b5f7f72f0f12cfd329503491b6c530e91dc86736a42b595e55e6c76e0f9d4c824301acd4db79c060cd1a3fe4bb2be2b5be93869d88fb15481906b8e43ae9a8af02a86658f1a113155c4fcfb6f8d244e403140301906092a864886f70d010914310c1e0a0061006c006900630065302306092a864886f70d01091531160414d2db5a2749bd75b1292d33bf028016899149f47430413031300d060960864801650304020105000420fd47dd9df2e0c7f2f399da831e53df9c2a8a9c16383530ff3c9c0dec2021d8f904088a6d208b5f2da51a020203e8";
+    const PLAINTEXT: &str = "308187020100301306072a8648ce3d020106082a8648ce3d030107046d306b02010104202d1858097bb770e28d58e81b8a2f4e75761dc77f6cee9f3f07beb20e3700d39ea1440342000438ed267a007a52a8b3e8b1fb784ff2f038f7f8b46a3410c0c589d0fec3824622d54a0d466f0c366b85a20c01d5a530172c457c52ba52826bb8efbdfc631e7783";
+
+    #[test]
+    fn test_pkcs12_openssl() {
+        let der = hex::decode(OPENSSL_P12).unwrap();
+        let store = Pkcs12Store::from_der(&der, &password(b"correct horse")).unwrap();
+        assert_eq!(store.private_keys().len(), 1);
+        assert_eq!(store.certificates().len(), 1);
+
+        let key = &store.private_keys()[0];
+        assert_eq!(key.friendly_name(), Some("alice"));
+        // OpenSSL uses the SHA-1 fingerprint of the certificate as the local key id
+        assert_eq!(
+            hex::encode(key.local_key_id().unwrap()),
+            "d2db5a2749bd75b1292d33bf028016899149f474"
+        );
+        let sk = key
+            .to_key::<<EcdsaP256 as AsymmetricKeySet>::PrivateKey>()
+            .unwrap();
+        assert_eq!(hex::encode(sk.to_bytes().unwrap()), PLAINTEXT);
+        assert!(
+            key.to_key::<<Ed25519 as AsymmetricKeySet>::PrivateKey>()
+                .is_err()
+        );
+
+        let certificate = store.certificate_for(key).unwrap();
+        assert_eq!(certificate.friendly_name(), Some("alice"));
+        assert_eq!(certificate.der().len(), 376);
+
+        assert_eq!(
+            Pkcs12Store::from_der(&der, &password(b"battery staple")).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+    }
+
+    #[test]
+    fn test_pkcs12_roundtrip() {
+        let der = hex::decode(OPENSSL_P12).unwrap();
+        let openssl = Pkcs12Store::from_der(&der, &password(b"correct horse")).unwrap();
+        let certificate = openssl.certificates()[0].der();
+
+        let (_, sk) = Ed25519::generate_keypair().unwrap();
+        let mut store = Pkcs12Store::new();
+        store.push_private_key(
+            Pkcs12PrivateKey::new(&sk)
+                .unwrap()
+                .with_friendly_name("bob")
+                .with_local_key_id(b"1"),
+        );
+        store.push_certificate(
+            Pkcs12Certificate::new(certificate)
+                .unwrap()
+                .with_local_key_id(b"1"),
+        );
+        let der = store
+            .to_der(&password(b"pw"), &Pbkdf2Sha256::new(1000))
+            .unwrap();
+
+        let opened = Pkcs12Store::from_der(&der, &password(b"pw")).unwrap();
+        let key = &opened.private_keys()[0];
+        assert_eq!(key.friendly_name(), Some("bob"));
+        assert_eq!(
+            key.to_key::<<Ed25519 as AsymmetricKeySet>::PrivateKey>()
+                .unwrap()
+                .to_bytes()
+                .unwrap(),
+            sk.to_bytes().unwrap()
+        );
+        let paired = opened.certificate_for(key).unwrap();
+        assert_eq!(paired.der(), certificate);
+        assert_eq!(paired.friendly_name(), None);
+
+        assert_eq!(
+            Pkcs12Store::from_der(&der, &password(b"wrong")).unwrap_err(),
+            Error::Kdf(KdfError::VerificationFailed)
+        );
+        assert!(Pkcs12Certificate::new(b"not a certificate").is_err());
+        assert_eq!(
+            store
+                .to_der(&password(b"\xff"), &Pbkdf2Sha256::new(1000))
+                .unwrap_err(),
+            Error::Kdf(KdfError::InvalidParameter)
+        );
+    }
+}
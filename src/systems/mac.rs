@@ -0,0 +1,46 @@
+//! Message authentication code (MAC) implementations.
+//!
+//! This module provides implementations of MACs that authenticate a message under a
+//! secret symmetric key. All of them implement the [`Mac`](crate::traits::mac::Mac) trait,
+//! whose `verify` method compares tags in constant time.
+//!
+//! # Available Implementations
+//! - **HMAC**: HMAC-SHA-256, HMAC-SHA-384 and HMAC-SHA-512 (RFC 2104)
+//! - **CMAC**: AES-128-CMAC and AES-256-CMAC (NIST SP 800-38B)
+//! - **KMAC**: KMAC128 and KMAC256 with customization strings (NIST SP 800-185)
+//! - **GMAC**: AES-128-GMAC and AES-256-GMAC, which take a nonce and so do not implement `Mac` (NIST SP 800-38D)
+//!
+//! 消息认证码 (MAC) 实现。
+//!
+//! 此模块提供在秘密对称密钥下认证消息的 MAC 实现。它们都实现了
+//! [`Mac`](crate::traits::mac::Mac) trait，其 `verify` 方法以常量时间比较标签。
+//!
+//! # 可用实现
+//! - **HMAC**: HMAC-SHA-256、HMAC-SHA-384 和 HMAC-SHA-512 (RFC 2104)
+//! - **CMAC**: AES-128-CMAC 和 AES-256-CMAC (NIST SP 800-38B)
+//! - **KMAC**: 支持定制字符串的 KMAC128 和 KMAC256 (NIST SP 800-185)
+//! - **GMAC**: AES-128-GMAC 和 AES-256-GMAC，它们需要 nonce，因此不实现 `Mac` (NIST SP 800-38D)
+
+/// HMAC implementation over the SHA-2 family.
+///
+/// 基于 SHA-2 系列的 HMAC 实现。
+#[cfg(feature = "hmac-default")]
+pub mod hmac;
+
+/// AES-CMAC (OMAC1) implementation.
+///
+/// AES-CMAC (OMAC1) 实现。
+#[cfg(feature = "cmac-default")]
+pub mod cmac;
+
+/// KMAC implementation built on cSHAKE.
+///
+/// 基于 cSHAKE 的 KMAC 实现。
+#[cfg(feature = "shake-default")]
+pub mod kmac;
+
+/// AES-GMAC, the authentication-only mode of AES-GCM.
+///
+/// AES-GMAC，AES-GCM 的仅认证模式。
+#[cfg(feature = "gmac-default")]
+pub mod gmac;
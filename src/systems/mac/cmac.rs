@@ -0,0 +1,197 @@
+//! Provides an implementation of the cipher-based message authentication code (CMAC).
+//!
+//! CMAC (NIST SP 800-38B, RFC 4493), also known as OMAC1, builds a MAC from a block
+//! cipher instead of a hash function. It is required by a number of industry
+//! specifications, such as GlobalPlatform SCP03 and automotive UDS security access.
+//!
+//! # Algorithm Variants
+//! - **AES-128-CMAC**: 16-byte key, 16-byte tag
+//! - **AES-256-CMAC**: 32-byte key, 16-byte tag
+//!
+//! # Security Considerations
+//! - Keys must be exactly the AES key size of the chosen variant.
+//! - Always verify tags with [`Mac::verify`], which compares them in constant time.
+//!
+//! 提供了基于分组密码的消息认证码 (CMAC) 的实现。
+//!
+//! CMAC（NIST SP 800-38B，RFC 4493），也称为 OMAC1，使用分组密码而不是哈希函数构建 MAC。
+//! 许多行业规范都要求使用它，例如 GlobalPlatform SCP03 和汽车 UDS 安全访问。
+//!
+//! # 算法变体
+//! - **AES-128-CMAC**: 16 字节密钥，16 字节标签
+//! - **AES-256-CMAC**: 32 字节密钥，16 字节标签
+//!
+//! # 安全考虑
+//! - 密钥必须恰好等于所选变体的 AES 密钥大小。
+//! - 始终使用 [`Mac::verify`] 验证标签，它以常量时间进行比较。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::traits::mac::write_tag;
+use crate::policy;
+use crate::{errors::Error, prelude::*};
+use cmac::Cmac;
+use digest::{KeyInit, Mac as _};
+use core::marker::PhantomData;
+
+// ------------------- Marker Structs and Trait for CMAC Parameters -------------------
+// ------------------- 用于 CMAC 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines the parameters for a specific CMAC variant.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义特定 CMAC 变体参数的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait CmacParams: private::Sealed + SchemeParams {
+    /// The underlying `cmac` MAC type.
+    ///
+    /// 底层的 `cmac` MAC 类型。
+    type CmacCore: digest::Mac + KeyInit;
+    /// The size of the key in bytes.
+    ///
+    /// 密钥的大小（以字节为单位）。
+    const KEY_SIZE: usize;
+}
+
+/// Marker struct for AES-128-CMAC parameters.
+///
+/// AES-128-CMAC 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct Aes128CmacParams;
+impl private::Sealed for Aes128CmacParams {}
+impl SchemeParams for Aes128CmacParams {
+    const NAME: &'static str = "AES-128-CMAC";
+    const ID: u32 = 0x06_02_01_01;
+}
+impl CmacParams for Aes128CmacParams {
+    type CmacCore = Cmac<aes::Aes128>;
+    const KEY_SIZE: usize = 16;
+}
+
+/// Marker struct for AES-256-CMAC parameters.
+///
+/// AES-256-CMAC 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct Aes256CmacParams;
+impl private::Sealed for Aes256CmacParams {}
+impl SchemeParams for Aes256CmacParams {
+    const NAME: &'static str = "AES-256-CMAC";
+    const ID: u32 = 0x06_02_01_02;
+}
+impl CmacParams for Aes256CmacParams {
+    type CmacCore = Cmac<aes::Aes256>;
+    const KEY_SIZE: usize = 32;
+}
+
+// ------------------- Generic CMAC Implementation -------------------
+// ------------------- 通用 CMAC 实现 -------------------
+
+/// A generic struct representing the CMAC construction for a given parameter set.
+///
+/// 一个通用的 CMAC 结构体，它在参数集上是通用的。
+#[derive(Clone, Debug, Default)]
+pub struct CmacScheme<P: CmacParams> {
+    _params: PhantomData<P>,
+}
+
+impl<P: CmacParams> Algorithm for CmacScheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: CmacParams> SymmetricKeySet for CmacScheme<P> {
+    type Key = SymmetricKey;
+}
+
+impl<P: CmacParams> Mac for CmacScheme<P> {
+    const TAG_SIZE: usize = 16;
+
+    fn compute(key: &Self::Key, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut tag = vec![0u8; Self::TAG_SIZE];
+        Self::compute_to_buffer(key, message, &mut tag)?;
+        Ok(tag)
+    }
+
+    fn compute_to_buffer(key: &Self::Key, message: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
+        if key.len() != P::KEY_SIZE {
+            return Err(Error::Mac(MacError::InvalidKeySize));
+        }
+        let mut mac = <P::CmacCore as KeyInit>::new_from_slice(key)
+            .map_err(|_| Error::Mac(MacError::InvalidKeySize))?;
+        mac.update(message);
+        write_tag(&mac.finalize().into_bytes(), output)
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the AES-128-CMAC scheme.
+///
+/// AES-128-CMAC 方案的类型别名。
+pub type Aes128Cmac = CmacScheme<Aes128CmacParams>;
+
+/// A type alias for the AES-256-CMAC scheme.
+///
+/// AES-256-CMAC 方案的类型别名。
+pub type Aes256Cmac = CmacScheme<Aes256CmacParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_cmac_test<P: CmacParams>(key_hex: &str, empty_tag: &str, block_tag: &str) {
+        // Vectors from NIST SP 800-38B, appendix D
+        let key = SymmetricKey::new(hex::decode(key_hex).unwrap());
+        let block = hex::decode("6bc1bee22e409f96e93d7e117393172a").unwrap();
+
+        let tag = CmacScheme::<P>::compute(&key, &[]).unwrap();
+        assert_eq!(hex::encode(&tag), empty_tag);
+        let tag = CmacScheme::<P>::compute(&key, &block).unwrap();
+        assert_eq!(tag.len(), CmacScheme::<P>::TAG_SIZE);
+        assert_eq!(hex::encode(&tag), block_tag);
+
+        assert!(CmacScheme::<P>::verify(&key, &block, &tag).is_ok());
+        assert_eq!(CmacScheme::<P>::compute_array::<16>(&key, &block).unwrap()[..], tag[..]);
+        let mut tampered = block.clone();
+        tampered[15] ^= 0x80;
+        assert_eq!(
+            CmacScheme::<P>::verify(&key, &tampered, &tag).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+
+        let short_key = SymmetricKey::new(vec![0u8; P::KEY_SIZE - 1]);
+        assert_eq!(
+            CmacScheme::<P>::compute(&short_key, &block).unwrap_err(),
+            Error::Mac(MacError::InvalidKeySize)
+        );
+    }
+
+    #[test]
+    fn test_aes128_cmac() {
+        run_cmac_test::<Aes128CmacParams>(
+            "2b7e151628aed2a6abf7158809cf4f3c",
+            "bb1d6929e95937287fa37d129b756746",
+            "070a16b46b4d4144f79bdd9dd04a287c",
+        );
+    }
+
+    #[test]
+    fn test_aes256_cmac() {
+        run_cmac_test::<Aes256CmacParams>(
+            "603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4",
+            "028962f61b7bf89efc6b551f4667d983",
+            "28a7023f452e8f82bd4bf28d8c37c35c",
+        );
+    }
+}
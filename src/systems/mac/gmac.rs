@@ -0,0 +1,263 @@
+//! Provides an implementation of GMAC, the authentication-only mode of AES-GCM.
+//!
+//! GMAC (NIST SP 800-38D) is AES-GCM applied to an empty plaintext: the whole message is
+//! passed as additional authenticated data and only the 16-byte tag is produced. Unlike
+//! the other MACs in this crate it requires a nonce, so it does not implement the
+//! [`Mac`](crate::traits::mac::Mac) trait and exposes `compute`/`verify` methods that
+//! take the nonce explicitly.
+//!
+//! # Algorithm Variants
+//! - **AES-128-GMAC**: 16-byte key, 12-byte nonce, 16-byte tag
+//! - **AES-256-GMAC**: 32-byte key, 12-byte nonce, 16-byte tag
+//!
+//! # Security Considerations
+//! - A nonce must never be reused with the same key. Reuse reveals the GHASH key and
+//!   allows forgeries for every message authenticated under that key.
+//! - Verification compares tags in constant time. `verify_truncated` checks the leftmost
+//!   bytes of the tag; SP 800-38D recommends keeping at least 12 of them.
+//!
+//! 提供了 GMAC（AES-GCM 的仅认证模式）的实现。
+//!
+//! GMAC (NIST SP 800-38D) 是应用于空明文的 AES-GCM：整个消息作为附加认证数据传入，
+//! 只生成 16 字节的标签。与本 crate 中的其他 MAC 不同，它需要 nonce，因此它没有实现
+//! [`Mac`](crate::traits::mac::Mac) trait，而是提供显式接收 nonce 的 `compute`/`verify` 方法。
+//!
+//! # 算法变体
+//! - **AES-128-GMAC**: 16 字节密钥，12 字节 nonce，16 字节标签
+//! - **AES-256-GMAC**: 32 字节密钥，12 字节 nonce，16 字节标签
+//!
+//! # 安全考虑
+//! - 同一密钥下绝不能重复使用 nonce。重复使用会泄露 GHASH 密钥，
+//!   并允许伪造在该密钥下认证的任何消息。
+//! - 验证以常量时间比较标签。`verify_truncated` 检查标签最左侧的字节；SP 800-38D 建议至少保留 12 个字节。
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use crate::policy;
+use crate::{errors::Error, prelude::*};
+use aes_gcm::aead::{AeadInPlace, KeyInit};
+use aes_gcm::{Aes128Gcm as Aes128GcmCore, Aes256Gcm as Aes256GcmCore, Nonce as NonceCore};
+use core::marker::PhantomData;
+
+// ------------------- Marker Structs and Trait for GMAC Parameters -------------------
+// ------------------- 用于 GMAC 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines the parameters for a specific GMAC variant.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义特定 GMAC 变体参数的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait GmacParams: private::Sealed + SchemeParams {
+    /// The underlying `aes_gcm` AEAD cipher type.
+    ///
+    /// 底层的 `aes_gcm` AEAD 密码类型。
+    type AeadCipher: AeadInPlace + KeyInit;
+    /// The size of the key in bytes.
+    ///
+    /// 密钥的大小（以字节为单位）。
+    const KEY_SIZE: usize;
+}
+
+/// Marker struct for AES-128-GMAC parameters.
+///
+/// AES-128-GMAC 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct Aes128GmacParams;
+impl private::Sealed for Aes128GmacParams {}
+impl SchemeParams for Aes128GmacParams {
+    const NAME: &'static str = "AES-128-GMAC";
+    const ID: u32 = 0x06_04_01_01;
+}
+impl GmacParams for Aes128GmacParams {
+    type AeadCipher = Aes128GcmCore;
+    const KEY_SIZE: usize = 16;
+}
+
+/// Marker struct for AES-256-GMAC parameters.
+///
+/// AES-256-GMAC 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct Aes256GmacParams;
+impl private::Sealed for Aes256GmacParams {}
+impl SchemeParams for Aes256GmacParams {
+    const NAME: &'static str = "AES-256-GMAC";
+    const ID: u32 = 0x06_04_01_02;
+}
+impl GmacParams for Aes256GmacParams {
+    type AeadCipher = Aes256GcmCore;
+    const KEY_SIZE: usize = 32;
+}
+
+// ------------------- Generic GMAC Implementation -------------------
+// ------------------- 通用 GMAC 实现 -------------------
+
+/// A generic struct representing GMAC for a given parameter set.
+///
+/// 一个通用的 GMAC 结构体，它在参数集上是通用的。
+#[derive(Clone, Debug, Default)]
+pub struct GmacScheme<P: GmacParams> {
+    _params: PhantomData<P>,
+}
+
+impl<P: GmacParams> GmacScheme<P> {
+    /// The size of the nonce in bytes.
+    ///
+    /// Nonce 的大小（以字节为单位）。
+    pub const NONCE_SIZE: usize = 12;
+
+    /// The size of the authentication tag in bytes.
+    ///
+    /// 认证标签的大小（以字节为单位）。
+    pub const TAG_SIZE: usize = 16;
+
+    fn cipher(key: &SymmetricKey, nonce: &[u8]) -> Result<P::AeadCipher, Error> {
+        policy::enforce::<Self>()?;
+        if key.len() != P::KEY_SIZE {
+            return Err(Error::Mac(MacError::InvalidKeySize));
+        }
+        if nonce.len() != Self::NONCE_SIZE {
+            return Err(Error::Mac(MacError::InvalidNonceSize));
+        }
+        P::AeadCipher::new_from_slice(key).map_err(|_| Error::Mac(MacError::InvalidKeySize))
+    }
+
+    /// Computes the GMAC tag of `message` under `key` and `nonce`.
+    ///
+    /// 在 `key` 和 `nonce` 下计算 `message` 的 GMAC 标签。
+    pub fn compute(key: &SymmetricKey, nonce: &[u8], message: &[u8]) -> Result<Vec<u8>, Error> {
+        let cipher = Self::cipher(key, nonce)?;
+        let tag = cipher
+            .encrypt_in_place_detached(NonceCore::from_slice(nonce), message, &mut [])
+            .map_err(|_| Error::Symmetric(SymmetricError::Encryption))?;
+        Ok(tag.to_vec())
+    }
+
+    /// Verifies the GMAC tag of `message` under `key` and `nonce`, in constant time.
+    ///
+    /// 以常量时间验证 `message` 在 `key` 和 `nonce` 下的 GMAC 标签。
+    pub fn verify(
+        key: &SymmetricKey,
+        nonce: &[u8],
+        message: &[u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        let cipher = Self::cipher(key, nonce)?;
+        if tag.len() != Self::TAG_SIZE {
+            return Err(Error::Mac(MacError::VerificationFailed));
+        }
+        cipher
+            .decrypt_in_place_detached(
+                NonceCore::from_slice(nonce),
+                message,
+                &mut [],
+                aes_gcm::Tag::from_slice(tag),
+            )
+            .map_err(|_| Error::Mac(MacError::VerificationFailed))
+    }
+
+    /// Verifies a GMAC tag truncated to `len` bytes, in constant time. As in GCM, a
+    /// truncated tag is the leftmost `len` bytes of the full tag.
+    ///
+    /// 以常量时间验证截断为 `len` 字节的 GMAC 标签。与 GCM 一样，截断标签是完整标签最左侧的
+    /// `len` 个字节。
+    pub fn verify_truncated(
+        key: &SymmetricKey,
+        nonce: &[u8],
+        message: &[u8],
+        tag: &[u8],
+        len: usize,
+    ) -> Result<(), Error> {
+        let expected = Self::compute(key, nonce, message)?;
+        verify_truncated_tag(&expected, tag, len)
+    }
+}
+
+impl<P: GmacParams> Algorithm for GmacScheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: GmacParams> SymmetricKeySet for GmacScheme<P> {
+    type Key = SymmetricKey;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the AES-128-GMAC scheme.
+///
+/// AES-128-GMAC 方案的类型别名。
+pub type Aes128Gmac = GmacScheme<Aes128GmacParams>;
+
+/// A type alias for the AES-256-GMAC scheme.
+///
+/// AES-256-GMAC 方案的类型别名。
+pub type Aes256Gmac = GmacScheme<Aes256GmacParams>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Key, nonce and AAD from the GCM specification test cases, with an empty plaintext
+    const KEY: &str = "feffe9928665731c6d6a8f9467308308";
+    const NONCE: &str = "cafebabefacedbaddecaf888";
+    const AAD: &str = "feedfacedeadbeeffeedfacedeadbeefabaddad2";
+
+    fn run_gmac_test<P: GmacParams>(key_hex: &str, expected: &str) {
+        let key = SymmetricKey::new(hex::decode(key_hex).unwrap());
+        let nonce = hex::decode(NONCE).unwrap();
+        let aad = hex::decode(AAD).unwrap();
+
+        let tag = GmacScheme::<P>::compute(&key, &nonce, &aad).unwrap();
+        assert_eq!(hex::encode(&tag), expected);
+        assert!(GmacScheme::<P>::verify(&key, &nonce, &aad, &tag).is_ok());
+
+        let mut tampered = aad.clone();
+        tampered[0] ^= 1;
+        assert_eq!(
+            GmacScheme::<P>::verify(&key, &nonce, &tampered, &tag).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+        assert_eq!(
+            GmacScheme::<P>::verify(&key, &nonce, &aad, &tag[..12]).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+        assert!(GmacScheme::<P>::verify_truncated(&key, &nonce, &aad, &tag[..12], 12).is_ok());
+        assert_eq!(
+            GmacScheme::<P>::verify_truncated(&key, &nonce, &tampered, &tag[..12], 12).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+        assert_eq!(
+            GmacScheme::<P>::compute(&key, &nonce[..8], &aad).unwrap_err(),
+            Error::Mac(MacError::InvalidNonceSize)
+        );
+        let short_key = SymmetricKey::new(vec![0u8; P::KEY_SIZE - 1]);
+        assert_eq!(
+            GmacScheme::<P>::compute(&short_key, &nonce, &aad).unwrap_err(),
+            Error::Mac(MacError::InvalidKeySize)
+        );
+    }
+
+    #[test]
+    fn test_aes128_gmac() {
+        // GCM test case 1: all-zero key and nonce, empty input
+        let zero_key = SymmetricKey::new(vec![0u8; 16]);
+        let tag = Aes128Gmac::compute(&zero_key, &[0u8; 12], &[]).unwrap();
+        assert_eq!(hex::encode(tag), "58e2fccefa7e3061367f1d57a4e7455a");
+
+        run_gmac_test::<Aes128GmacParams>(KEY, "346434fd51d5cd0c5887ec63e39b907a");
+    }
+
+    #[test]
+    fn test_aes256_gmac() {
+        run_gmac_test::<Aes256GmacParams>(&KEY.repeat(2), "9f6be07603c0b0bd1272854063e9c9ba");
+    }
+}
@@ -0,0 +1,287 @@
+//! Provides an implementation of the keyed-hash message authentication code (HMAC).
+//!
+//! HMAC (RFC 2104, FIPS 198-1) authenticates a message by hashing it twice with the key
+//! mixed into inner and outer padding blocks. It is secure as long as the underlying
+//! hash is a PRF, and unlike `H(key || message)` it is not affected by length extension.
+//!
+//! # Algorithm Variants
+//! - **HMAC-SHA-256**: 32-byte tag
+//! - **HMAC-SHA-384**: 48-byte tag
+//! - **HMAC-SHA-512**: 64-byte tag
+//! - **HMAC-SHA-1** and **HMAC-MD5**: 20- and 16-byte tags, behind the `insecure-legacy-hashes`
+//!   feature, for verifying existing tags only
+//!
+//! # Security Considerations
+//! - Keys of any length are accepted; keys longer than the hash block size are hashed first.
+//!   Use keys at least as long as the tag for full security.
+//! - Always verify tags with [`Mac::verify`], which compares them in constant time.
+//!
+//! 提供了基于密钥的哈希消息认证码 (HMAC) 的实现。
+//!
+//! HMAC (RFC 2104, FIPS 198-1) 通过将密钥混入内部和外部填充块并对消息进行两次哈希来认证消息。
+//! 只要底层哈希是 PRF，它就是安全的，并且与 `H(key || message)` 不同，它不受长度扩展攻击的影响。
+//!
+//! # 算法变体
+//! - **HMAC-SHA-256**: 32 字节标签
+//! - **HMAC-SHA-384**: 48 字节标签
+//! - **HMAC-SHA-512**: 64 字节标签
+//! - **HMAC-SHA-1** 和 **HMAC-MD5**: 20 和 16 字节标签，位于 `insecure-legacy-hashes` 特性之后，
+//!   仅用于验证现有标签
+//!
+//! # 安全考虑
+//! - 接受任意长度的密钥；长于哈希块大小的密钥会先被哈希。为获得完整的安全性，请使用至少与标签等长的密钥。
+//! - 始终使用 [`Mac::verify`] 验证标签，它以常量时间进行比较。
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "insecure-legacy-hashes")]
+use crate::systems::hash::legacy::{LegacyHashParams, Md5Params, Sha1Params};
+#[cfg(feature = "insecure-legacy-hashes")]
+use crate::traits::mac::write_tag;
+use crate::policy;
+use crate::{errors::Error, prelude::*};
+use core::marker::PhantomData;
+
+/// A generic struct representing the HMAC construction for a given hash function.
+///
+/// 一个通用的 HMAC 结构体，它在哈希函数上是通用的。
+#[derive(Clone, Debug, Default)]
+pub struct HmacScheme<H: Hasher> {
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Hasher> Algorithm for HmacScheme<H> {
+    fn name() -> String {
+        format!("HMAC-{}", H::NAME)
+    }
+    const ID: u32 = 0x06_01_00_00 + H::ID_OFFSET;
+}
+
+impl<H: Hasher> Parameterized for HmacScheme<H> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(H::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<H: Hasher> SymmetricKeySet for HmacScheme<H> {
+    type Key = SymmetricKey;
+}
+
+impl<H: Hasher> Mac for HmacScheme<H> {
+    const TAG_SIZE: usize = H::OUTPUT_SIZE;
+
+    fn compute(key: &Self::Key, message: &[u8]) -> Result<Vec<u8>, Error> {
+        policy::enforce::<Self>()?;
+        H::hmac(key, message)
+    }
+
+    fn compute_to_buffer(key: &Self::Key, message: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
+        H::hmac_into(key, message, output)
+    }
+}
+
+/// The HMAC construction over a legacy hash function, for verifying existing tags such as
+/// HMAC-SHA1 tokens. New protocols should use [`HmacScheme`] instead.
+///
+/// 基于旧哈希函数的 HMAC 结构，用于验证现有标签，例如 HMAC-SHA1 令牌。新协议应改用 [`HmacScheme`]。
+#[cfg(feature = "insecure-legacy-hashes")]
+#[derive(Clone, Debug, Default)]
+pub struct LegacyHmacScheme<P: LegacyHashParams> {
+    _params: PhantomData<P>,
+}
+
+#[cfg(feature = "insecure-legacy-hashes")]
+impl<P: LegacyHashParams> Algorithm for LegacyHmacScheme<P> {
+    fn name() -> String {
+        format!("HMAC-{}", P::NAME)
+    }
+    const ID: u32 = 0x06_01_01_00 + (P::ID & 0xFF);
+}
+
+#[cfg(feature = "insecure-legacy-hashes")]
+impl<P: LegacyHashParams> Parameterized for LegacyHmacScheme<P> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(P::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+#[cfg(feature = "insecure-legacy-hashes")]
+impl<P: LegacyHashParams> SymmetricKeySet for LegacyHmacScheme<P> {
+    type Key = SymmetricKey;
+}
+
+#[cfg(feature = "insecure-legacy-hashes")]
+impl<P: LegacyHashParams> Mac for LegacyHmacScheme<P> {
+    const TAG_SIZE: usize = P::OUTPUT_SIZE;
+
+    fn compute(key: &Self::Key, message: &[u8]) -> Result<Vec<u8>, Error> {
+        let mut tag = vec![0u8; P::OUTPUT_SIZE];
+        Self::compute_to_buffer(key, message, &mut tag)?;
+        Ok(tag)
+    }
+
+    fn compute_to_buffer(key: &Self::Key, message: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        policy::enforce::<Self>()?;
+        let mut mac = <hmac::SimpleHmac<P::Digest> as hmac::digest::KeyInit>::new_from_slice(key)
+            .map_err(|_| KeyError::InvalidLength)?;
+        hmac::Mac::update(&mut mac, message);
+        write_tag(&hmac::Mac::finalize(mac).into_bytes(), output)
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the HMAC-SHA-256 scheme.
+///
+/// HMAC-SHA-256 方案的类型别名。
+pub type HmacSha256 = HmacScheme<Sha256>;
+
+/// A type alias for the HMAC-SHA-384 scheme.
+///
+/// HMAC-SHA-384 方案的类型别名。
+pub type HmacSha384 = HmacScheme<Sha384>;
+
+/// A type alias for the HMAC-SHA-512 scheme.
+///
+/// HMAC-SHA-512 方案的类型别名。
+pub type HmacSha512 = HmacScheme<Sha512>;
+
+/// A type alias for the HMAC-SHA-1 scheme.
+///
+/// HMAC-SHA-1 方案的类型别名。
+#[cfg(feature = "insecure-legacy-hashes")]
+pub type HmacSha1 = LegacyHmacScheme<Sha1Params>;
+
+/// A type alias for the HMAC-MD5 scheme.
+///
+/// HMAC-MD5 方案的类型别名。
+#[cfg(feature = "insecure-legacy-hashes")]
+pub type HmacMd5 = LegacyHmacScheme<Md5Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_hmac_test<H: Hasher>(expected: &str) {
+        // RFC 4231, test case 2
+        let key = SymmetricKey::new(b"Jefe".to_vec());
+        let message = b"what do ya want for nothing?";
+
+        let tag = HmacScheme::<H>::compute(&key, message).unwrap();
+        assert_eq!(tag.len(), HmacScheme::<H>::TAG_SIZE);
+        assert_eq!(hex::encode(&tag), expected);
+
+        assert!(HmacScheme::<H>::verify(&key, message, &tag).is_ok());
+
+        let mut tampered_tag = tag.clone();
+        tampered_tag[0] ^= 1;
+        assert_eq!(
+            HmacScheme::<H>::verify(&key, message, &tampered_tag).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+        assert!(HmacScheme::<H>::verify(&key, b"another message", &tag).is_err());
+        assert!(HmacScheme::<H>::verify(&key, message, &tag[..tag.len() - 1]).is_err());
+
+        // Truncated tags are the leading bytes of the full tag
+        for len in [8, 16] {
+            let truncated = HmacScheme::<H>::compute_truncated(&key, message, len).unwrap();
+            assert_eq!(truncated, tag[..len]);
+            assert!(HmacScheme::<H>::verify_truncated(&key, message, &truncated, len).is_ok());
+            assert_eq!(
+                HmacScheme::<H>::verify_truncated(&key, b"another message", &truncated, len)
+                    .unwrap_err(),
+                Error::Mac(MacError::VerificationFailed)
+            );
+        }
+        assert_eq!(
+            HmacScheme::<H>::verify_truncated(&key, message, &tag[..8], 16).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+        assert_eq!(
+            HmacScheme::<H>::verify_truncated(&key, message, &tag[..2], 2).unwrap_err(),
+            Error::Mac(MacError::InvalidTagLength)
+        );
+        assert_eq!(
+            HmacScheme::<H>::compute_truncated(&key, message, tag.len() + 1).unwrap_err(),
+            Error::Mac(MacError::InvalidTagLength)
+        );
+
+        // Writing into a buffer yields the same tag, and a short buffer is rejected
+        let mut buffer = [0u8; 80];
+        let written = HmacScheme::<H>::compute_to_buffer(&key, message, &mut buffer).unwrap();
+        assert_eq!(buffer[..written], tag[..]);
+        assert_eq!(
+            HmacScheme::<H>::compute_to_buffer(&key, message, &mut buffer[..tag.len() - 1])
+                .unwrap_err(),
+            Error::Mac(MacError::OutputTooSmall)
+        );
+
+        let wrong_key = SymmetricKey::new(b"Jeff".to_vec());
+        assert!(HmacScheme::<H>::verify(&wrong_key, message, &tag).is_err());
+    }
+
+    #[test]
+    fn test_hmac_sha256() {
+        run_hmac_test::<Sha256>("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+
+        let key = SymmetricKey::new(b"Jefe".to_vec());
+        let message = b"what do ya want for nothing?";
+        let tag: [u8; 32] = HmacSha256::compute_array(&key, message).unwrap();
+        assert_eq!(tag[..], HmacSha256::compute(&key, message).unwrap()[..]);
+        assert_eq!(
+            HmacSha256::compute_array::<16>(&key, message).unwrap_err(),
+            Error::Mac(MacError::InvalidTagLength)
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha384() {
+        run_hmac_test::<Sha384>(
+            "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e\
+             8e2240ca5e69e2c78b3239ecfab21649",
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha512() {
+        run_hmac_test::<Sha512>(
+            "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+             9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+        );
+    }
+
+    #[cfg(feature = "insecure-legacy-hashes")]
+    #[test]
+    fn test_legacy_hmac() {
+        // RFC 2202, test case 2
+        let key = SymmetricKey::new(b"Jefe".to_vec());
+        let message = b"what do ya want for nothing?";
+
+        let tag = HmacSha1::compute(&key, message).unwrap();
+        assert_eq!(
+            hex::encode(&tag),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        );
+        assert!(HmacSha1::verify(&key, message, &tag).is_ok());
+        assert!(HmacSha1::verify_truncated(&key, message, &tag[..10], 10).is_ok());
+        assert!(HmacSha1::verify(&key, b"another message", &tag).is_err());
+
+        let tag = HmacMd5::compute(&key, message).unwrap();
+        assert_eq!(hex::encode(&tag), "750c783e6ab0b503eaa86e310a5db738");
+        assert_eq!(HmacMd5::name(), "HMAC-MD5");
+    }
+}
@@ -0,0 +1,267 @@
+//! Provides an implementation of KMAC, the Keccak message authentication code.
+//!
+//! KMAC (NIST SP 800-185) is a MAC built directly on cSHAKE, the customizable variant of
+//! the SHAKE XOFs already used by this crate. Because Keccak is not vulnerable to length
+//! extension, KMAC needs no nested construction like HMAC. It also takes an optional
+//! customization string `S` for domain separation, and its output length is a parameter
+//! that is bound into the tag.
+//!
+//! # Algorithm Variants
+//! - **KMAC128**: 128-bit security, 32-byte default tag
+//! - **KMAC256**: 256-bit security, 64-byte default tag
+//!
+//! # Security Considerations
+//! - The key should be at least as long as the targeted security strength.
+//! - Tags computed with different output lengths or customization strings are unrelated;
+//!   verification must use the same values as computation.
+//!
+//! 提供了 KMAC（Keccak 消息认证码）的实现。
+//!
+//! KMAC (NIST SP 800-185) 是直接构建在 cSHAKE 之上的 MAC，cSHAKE 是本 crate 已使用的 SHAKE XOF
+//! 的可定制变体。由于 Keccak 不受长度扩展攻击的影响，KMAC 不需要像 HMAC 那样的嵌套结构。
+//! 它还接受用于域分离的可选定制字符串 `S`，并且其输出长度是一个会被绑定到标签中的参数。
+//!
+//! # 算法变体
+//! - **KMAC128**: 128 位安全性，默认 32 字节标签
+//! - **KMAC256**: 256 位安全性，默认 64 字节标签
+//!
+//! # 安全考虑
+//! - 密钥长度应至少与目标安全强度相当。
+//! - 使用不同输出长度或定制字符串计算的标签互不相关；验证时必须使用与计算时相同的值。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::systems::xof::sp800_185::{RATE_128, RATE_256, absorb_bytepad, right_encode};
+use crate::policy;
+use crate::{errors::Error, prelude::*};
+use digest::{ExtendableOutput, Update};
+use sha3::{CShake128, CShake128Core, CShake256, CShake256Core};
+use core::marker::PhantomData;
+use subtle::ConstantTimeEq;
+
+// ------------------- Marker Structs and Trait for KMAC Parameters -------------------
+// ------------------- 用于 KMAC 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines the parameters for a specific KMAC variant.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义特定 KMAC 变体参数的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait KmacParams: private::Sealed + SchemeParams {
+    /// The underlying cSHAKE type.
+    ///
+    /// 底层的 cSHAKE 类型。
+    type CShake: Update + ExtendableOutput;
+    /// The rate of the underlying cSHAKE in bytes.
+    ///
+    /// 底层 cSHAKE 的速率（以字节为单位）。
+    const RATE: usize;
+    /// The default tag size in bytes, used by the [`Mac`] implementation.
+    ///
+    /// 默认的标签大小（以字节为单位），由 [`Mac`] 实现使用。
+    const TAG_SIZE: usize;
+
+    /// Creates a cSHAKE instance with the given function name and customization string.
+    ///
+    /// 使用给定的函数名和定制字符串创建 cSHAKE 实例。
+    fn new_cshake(function_name: &[u8], customization: &[u8]) -> Self::CShake;
+}
+
+/// Marker struct for KMAC128 parameters.
+///
+/// KMAC128 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct Kmac128Params;
+impl private::Sealed for Kmac128Params {}
+impl SchemeParams for Kmac128Params {
+    const NAME: &'static str = "KMAC128";
+    const ID: u32 = 0x06_03_00_01;
+}
+impl KmacParams for Kmac128Params {
+    type CShake = CShake128;
+    const RATE: usize = RATE_128;
+    const TAG_SIZE: usize = 32;
+
+    fn new_cshake(function_name: &[u8], customization: &[u8]) -> Self::CShake {
+        CShake128::from_core(CShake128Core::new_with_function_name(
+            function_name,
+            customization,
+        ))
+    }
+}
+
+/// Marker struct for KMAC256 parameters.
+///
+/// KMAC256 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct Kmac256Params;
+impl private::Sealed for Kmac256Params {}
+impl SchemeParams for Kmac256Params {
+    const NAME: &'static str = "KMAC256";
+    const ID: u32 = 0x06_03_00_02;
+}
+impl KmacParams for Kmac256Params {
+    type CShake = CShake256;
+    const RATE: usize = RATE_256;
+    const TAG_SIZE: usize = 64;
+
+    fn new_cshake(function_name: &[u8], customization: &[u8]) -> Self::CShake {
+        CShake256::from_core(CShake256Core::new_with_function_name(
+            function_name,
+            customization,
+        ))
+    }
+}
+
+// ------------------- Generic KMAC Implementation -------------------
+// ------------------- 通用 KMAC 实现 -------------------
+
+/// A generic struct representing KMAC for a given parameter set.
+///
+/// The [`Mac`] implementation uses an empty customization string and the default tag size.
+/// Use [`compute_with_customization`](Self::compute_with_customization) and
+/// [`verify_with_customization`](Self::verify_with_customization) to choose both.
+///
+/// 一个通用的 KMAC 结构体，它在参数集上是通用的。
+///
+/// [`Mac`] 实现使用空的定制字符串和默认标签大小。使用
+/// [`compute_with_customization`](Self::compute_with_customization) 和
+/// [`verify_with_customization`](Self::verify_with_customization) 来选择这两者。
+#[derive(Clone, Debug, Default)]
+pub struct KmacScheme<P: KmacParams> {
+    _params: PhantomData<P>,
+}
+
+impl<P: KmacParams> KmacScheme<P> {
+    /// Computes a KMAC tag of `output_len` bytes under the customization string `S`.
+    ///
+    /// 在定制字符串 `S` 下计算长度为 `output_len` 字节的 KMAC 标签。
+    pub fn compute_with_customization(
+        key: &[u8],
+        message: &[u8],
+        customization: &[u8],
+        output_len: usize,
+    ) -> Result<Vec<u8>, Error> {
+        policy::enforce::<Self>()?;
+        if output_len == 0 {
+            return Err(Error::Mac(MacError::InvalidTagLength));
+        }
+        let mut cshake = P::new_cshake(b"KMAC", customization);
+        absorb_bytepad(&mut cshake, &[key], P::RATE);
+        cshake.update(message);
+        cshake.update(&right_encode((output_len as u64) * 8));
+        let mut tag = vec![0u8; output_len];
+        cshake.finalize_xof_into(&mut tag);
+        Ok(tag)
+    }
+
+    /// Verifies a KMAC tag computed with the given customization string, in constant time.
+    /// The output length is taken from the tag.
+    ///
+    /// 以常量时间验证使用给定定制字符串计算的 KMAC 标签。输出长度取自标签。
+    pub fn verify_with_customization(
+        key: &[u8],
+        message: &[u8],
+        customization: &[u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        policy::enforce::<Self>()?;
+        let expected = Self::compute_with_customization(key, message, customization, tag.len())
+            .map_err(|_| Error::Mac(MacError::VerificationFailed))?;
+        if bool::from(expected.as_slice().ct_eq(tag)) {
+            Ok(())
+        } else {
+            Err(Error::Mac(MacError::VerificationFailed))
+        }
+    }
+}
+
+impl<P: KmacParams> Algorithm for KmacScheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: KmacParams> SymmetricKeySet for KmacScheme<P> {
+    type Key = SymmetricKey;
+}
+
+impl<P: KmacParams> Mac for KmacScheme<P> {
+    const TAG_SIZE: usize = P::TAG_SIZE;
+
+    fn compute(key: &Self::Key, message: &[u8]) -> Result<Vec<u8>, Error> {
+        Self::compute_with_customization(key, message, &[], P::TAG_SIZE)
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the KMAC128 scheme.
+///
+/// KMAC128 方案的类型别名。
+pub type Kmac128 = KmacScheme<Kmac128Params>;
+
+/// A type alias for the KMAC256 scheme.
+///
+/// KMAC256 方案的类型别名。
+pub type Kmac256 = KmacScheme<Kmac256Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Samples from the NIST SP 800-185 example values
+    const KEY: &str = "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f";
+    const DATA: [u8; 4] = [0x00, 0x01, 0x02, 0x03];
+    const CUSTOMIZATION: &[u8] = b"My Tagged Application";
+
+    #[test]
+    fn test_kmac128() {
+        let key = SymmetricKey::new(hex::decode(KEY).unwrap());
+        let tag = Kmac128::compute(&key, &DATA).unwrap();
+        assert_eq!(
+            hex::encode(&tag),
+            "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e"
+        );
+        assert!(Kmac128::verify(&key, &DATA, &tag).is_ok());
+
+        let tag = Kmac128::compute_with_customization(&key, &DATA, CUSTOMIZATION, 32).unwrap();
+        assert_eq!(
+            hex::encode(&tag),
+            "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5"
+        );
+        assert!(Kmac128::verify_with_customization(&key, &DATA, CUSTOMIZATION, &tag).is_ok());
+        assert_eq!(
+            Kmac128::verify(&key, &DATA, &tag).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+    }
+
+    #[test]
+    fn test_kmac256() {
+        let key = SymmetricKey::new(hex::decode(KEY).unwrap());
+        let tag = Kmac256::compute_with_customization(&key, &DATA, CUSTOMIZATION, 64).unwrap();
+        assert_eq!(
+            hex::encode(&tag),
+            "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+             f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+        );
+        assert_eq!(tag.len(), Kmac256::TAG_SIZE);
+
+        // The output length is bound into the tag, so a truncated tag does not verify
+        assert!(
+            Kmac256::verify_with_customization(&key, &DATA, CUSTOMIZATION, &tag[..32]).is_err()
+        );
+        assert!(Kmac256::verify_with_customization(&key, &DATA, b"other", &tag).is_err());
+        assert!(Kmac256::verify_with_customization(&key, &DATA, CUSTOMIZATION, &[]).is_err());
+    }
+}
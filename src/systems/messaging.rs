@@ -0,0 +1,66 @@
+//! Secure messaging protocol implementations.
+//!
+//! This module provides the building blocks of end-to-end encrypted messaging, which establish
+//! and evolve the keys of a conversation between two parties.
+//!
+//! # Available Implementations
+//! - **X3DH**: The Extended Triple Diffie-Hellman key agreement, generic over the key agreement
+//!   and signature schemes
+//! - **Ratchet**: The symmetric-key and Diffie-Hellman ratchets of the Double Ratchet algorithm
+//! - **Signcryption**: Envelopes signed by their sender and encrypted to their recipient, generic
+//!   over the KEM, signature and AEAD schemes
+//!
+//! 安全消息协议实现。
+//!
+//! 此模块提供端到端加密消息的构建块，用于建立和演进双方会话的密钥。
+//!
+//! # 可用实现
+//! - **X3DH**: 扩展三重迪菲-赫尔曼密钥协商，对密钥协商方案和签名方案是泛型的
+//! - **Ratchet**: Double Ratchet 算法的对称密钥棘轮和迪菲-赫尔曼棘轮
+//! - **Signcryption**: 由发送方签名并加密给接收方的信封，对 KEM、签名和 AEAD 方案是泛型的
+
+/// X3DH key agreement implementation.
+///
+/// X3DH 密钥协商实现。
+#[cfg(feature = "x3dh-default")]
+pub mod x3dh;
+
+/// Symmetric-key and Diffie-Hellman ratchet implementation.
+///
+/// 对称密钥棘轮和迪菲-赫尔曼棘轮实现。
+#[cfg(feature = "ratchet-default")]
+pub mod ratchet;
+
+/// Signcryption implementation.
+///
+/// 签密实现。
+#[cfg(feature = "signcryption-default")]
+pub mod signcryption;
+
+#[cfg(any(
+    feature = "x3dh-default",
+    feature = "ratchet-default",
+    feature = "signcryption-default"
+))]
+use crate::{errors::Error, prelude::*};
+#[cfg(any(
+    feature = "x3dh-default",
+    feature = "ratchet-default",
+    feature = "signcryption-default"
+))]
+use alloc::vec::Vec;
+
+/// Appends `Encode(key)`, the 4-byte big-endian length of the bytes of `key` followed by the
+/// bytes, to `out`.
+#[cfg(any(
+    feature = "x3dh-default",
+    feature = "ratchet-default",
+    feature = "signcryption-default"
+))]
+fn encode_key<P: Key>(out: &mut Vec<u8>, key: &P) -> Result<(), Error> {
+    let bytes = key.to_bytes()?;
+    let len = u32::try_from(bytes.len()).map_err(|_| Error::Key(KeyError::InvalidLength))?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(&bytes);
+    Ok(())
+}
@@ -0,0 +1,919 @@
+//! Provides the symmetric-key and Diffie-Hellman ratchets of the Double Ratchet algorithm.
+//!
+//! A [`SymmetricRatchet`] is a KDF chain: every step derives a message key and replaces the chain
+//! key, so that a compromised chain key reveals no earlier message key. A [`DhRatchet`] combines a
+//! root chain with a sending and a receiving [`SymmetricRatchet`], and starts new chains from a
+//! fresh Diffie-Hellman output whenever the peer's ratchet key changes, so that the session heals
+//! after a compromise. Together they are the key schedule of a Double Ratchet session; encrypting
+//! the messages with the derived keys is left to the caller.
+//!
+//! A session typically starts from X3DH:
+//! 1. The initiator calls [`DhRatchet::initiator`] with the X3DH shared secret and the signed
+//!    prekey of the responder.
+//! 2. The responder calls [`DhRatchet::responder`] with the same secret and the key pair of its
+//!    signed prekey.
+//! 3. Each message is sent with the [`RatchetHeader`] and message key returned by
+//!    [`DhRatchet::send`], and opened with [`DhRatchet::receive`].
+//!
+//! # Compatibility
+//! The chains follow the Double Ratchet specification with the recommended functions:
+//! - `KDF_RK(rk, dh_out)` is HKDF-SHA256 with `rk` as salt, `dh_out` as input key material and
+//!   the application `info`, expanded to a new root key and chain key of 32 bytes each;
+//! - `KDF_CK(ck)` gives the message key `HMAC-SHA256(ck, 0x01)` and the next chain key
+//!   `HMAC-SHA256(ck, 0x02)`.
+//!
+//! # Security Considerations
+//! - Authenticate [`RatchetHeader::to_bytes`] together with the associated data of the session
+//!   with every message, e.g. as AEAD associated data.
+//! - Keys of skipped messages are kept so that late messages can still be opened; at most
+//!   [`MAX_SKIP`] keys are skipped per message and [`MAX_SKIPPED_KEYS`] are stored, the oldest
+//!   being dropped first.
+//! - [`DhRatchet::receive`] only updates the state if the message opens, so that forged messages
+//!   cannot advance or corrupt the session.
+//! - Chain keys, root keys and message keys are wiped from memory when they are dropped or
+//!   replaced.
+//!
+//! 提供了 Double Ratchet 算法的对称密钥棘轮和迪菲-赫尔曼棘轮。
+//!
+//! [`SymmetricRatchet`] 是一条 KDF 链：每一步派生一个消息密钥并替换链密钥，使得泄露的链密钥不会
+//! 暴露任何更早的消息密钥。[`DhRatchet`] 将一条根链与一个发送和一个接收 [`SymmetricRatchet`]
+//! 组合起来，并在对方的棘轮密钥改变时从新的迪菲-赫尔曼输出开始新的链，使会话在泄露后得以恢复。
+//! 二者共同构成 Double Ratchet 会话的密钥编排；使用派生出的密钥加密消息由调用者负责。
+//!
+//! 会话通常从 X3DH 开始：
+//! 1. 发起方使用 X3DH 共享秘密和响应方的已签名预密钥调用 [`DhRatchet::initiator`]。
+//! 2. 响应方使用同一秘密和其已签名预密钥的密钥对调用 [`DhRatchet::responder`]。
+//! 3. 每条消息都使用 [`DhRatchet::send`] 返回的 [`RatchetHeader`] 和消息密钥发送，并使用
+//!    [`DhRatchet::receive`] 打开。
+//!
+//! # 兼容性
+//! 各条链遵循 Double Ratchet 规范，并使用推荐的函数：
+//! - `KDF_RK(rk, dh_out)` 是以 `rk` 为盐、`dh_out` 为输入密钥材料、并使用应用 `info` 的
+//!   HKDF-SHA256，扩展为各 32 字节的新根密钥和链密钥；
+//! - `KDF_CK(ck)` 给出消息密钥 `HMAC-SHA256(ck, 0x01)` 和下一个链密钥 `HMAC-SHA256(ck, 0x02)`。
+//!
+//! # 安全考虑
+//! - 请在每条消息中将 [`RatchetHeader::to_bytes`] 与会话的关联数据一起认证，例如作为 AEAD
+//!   关联数据。
+//! - 跳过的消息的密钥会被保留，以便仍能打开迟到的消息；每条消息最多跳过 [`MAX_SKIP`] 个密钥，
+//!   最多存储 [`MAX_SKIPPED_KEYS`] 个密钥，最旧的密钥最先被丢弃。
+//! - [`DhRatchet::receive`] 仅在消息成功打开时才更新状态，使伪造的消息无法推进或破坏会话。
+//! - 链密钥、根密钥和消息密钥在被丢弃或替换时会从内存中清除。
+
+use alloc::vec::Vec;
+use super::encode_key;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use crate::systems::asymmetric::traditional::ecdh::EcdhP256;
+use core::fmt;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use alloc::collections::{BTreeMap, VecDeque};
+use zeroize::Zeroizing;
+
+/// The length in bytes of root keys, chain keys and message keys.
+///
+/// 根密钥、链密钥和消息密钥的字节长度。
+pub const CHAIN_KEY_SIZE: usize = 32;
+
+/// The largest number of message keys a single message may skip.
+///
+/// 单条消息最多可以跳过的消息密钥数量。
+pub const MAX_SKIP: u32 = 1000;
+
+/// The largest number of skipped message keys a ratchet stores.
+///
+/// 一个棘轮最多存储的跳过消息密钥数量。
+pub const MAX_SKIPPED_KEYS: usize = 2000;
+
+/// The HMAC input that derives the message key from a chain key.
+const MESSAGE_KEY_INPUT: u8 = 0x01;
+
+/// The HMAC input that derives the next chain key from a chain key.
+const CHAIN_KEY_INPUT: u8 = 0x02;
+
+type ChainKey = Zeroizing<[u8; CHAIN_KEY_SIZE]>;
+
+// ------------------- Symmetric-key ratchet -------------------
+// ------------------- 对称密钥棘轮 -------------------
+
+/// A KDF chain that derives one message key per step and stores the keys of skipped messages.
+///
+/// 一条 KDF 链，每一步派生一个消息密钥，并存储跳过的消息的密钥。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SymmetricRatchet {
+    chain_key: ChainKey,
+    index: u32,
+    skipped: BTreeMap<u32, DerivedKey>,
+}
+
+impl SymmetricRatchet {
+    /// Starts a chain from a 32-byte chain key.
+    ///
+    /// 从一个 32 字节的链密钥开始一条链。
+    pub fn new(chain_key: &[u8]) -> Result<Self, Error> {
+        Ok(Self::from_chain_key(Zeroizing::new(
+            chain_key
+                .try_into()
+                .map_err(|_| Error::Key(KeyError::InvalidLength))?,
+        )))
+    }
+
+    fn from_chain_key(chain_key: ChainKey) -> Self {
+        Self {
+            chain_key,
+            index: 0,
+            skipped: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the index of the next message key, i.e. the number of steps taken so far.
+    ///
+    /// 返回下一个消息密钥的索引，即目前已执行的步数。
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the number of stored keys of skipped messages.
+    ///
+    /// 返回已存储的跳过消息密钥的数量。
+    pub fn skipped_keys(&self) -> usize {
+        self.skipped.len()
+    }
+
+    /// Steps the chain and returns the message key of index [`SymmetricRatchet::index`].
+    ///
+    /// 推进该链并返回索引为 [`SymmetricRatchet::index`] 的消息密钥。
+    pub fn next_key(&mut self) -> Result<DerivedKey, Error> {
+        let index = self.index.checked_add(1).ok_or(Error::KeyAgreement(
+            KeyAgreementError::MessageKeyUnavailable,
+        ))?;
+        let message_key = hmac_sha256(&self.chain_key, MESSAGE_KEY_INPUT)?;
+        self.chain_key = hmac_sha256(&self.chain_key, CHAIN_KEY_INPUT)?;
+        self.index = index;
+        Ok(DerivedKey::new(message_key.to_vec()))
+    }
+
+    /// Returns the message key of `index`, for messages that may arrive out of order.
+    ///
+    /// The keys of the messages skipped to reach `index` are stored, and the key of an earlier
+    /// message is taken from them. Fails with [`KeyAgreementError::MessageKeyUnavailable`] if the
+    /// key was already returned or dropped, and with
+    /// [`KeyAgreementError::TooManySkippedMessages`] if more than [`MAX_SKIP`] keys would be
+    /// skipped.
+    ///
+    /// 返回 `index` 的消息密钥，用于可能乱序到达的消息。
+    ///
+    /// 为到达 `index` 而跳过的消息的密钥会被存储，较早消息的密钥从中取出。如果该密钥已被返回或
+    /// 丢弃，则以 [`KeyAgreementError::MessageKeyUnavailable`] 失败；如果将跳过超过
+    /// [`MAX_SKIP`] 个密钥，则以 [`KeyAgreementError::TooManySkippedMessages`] 失败。
+    pub fn key_for(&mut self, index: u32) -> Result<DerivedKey, Error> {
+        if index < self.index {
+            return self.skipped.remove(&index).ok_or(Error::KeyAgreement(
+                KeyAgreementError::MessageKeyUnavailable,
+            ));
+        }
+        self.skip_to(index)?;
+        self.next_key()
+    }
+
+    /// Stores the keys of the messages from the current index up to, but excluding, `until`.
+    fn skip_to(&mut self, until: u32) -> Result<(), Error> {
+        if until.saturating_sub(self.index) > MAX_SKIP {
+            return Err(Error::KeyAgreement(
+                KeyAgreementError::TooManySkippedMessages,
+            ));
+        }
+        while self.index < until {
+            let index = self.index;
+            let message_key = self.next_key()?;
+            self.skipped.insert(index, message_key);
+        }
+        while self.skipped.len() > MAX_SKIPPED_KEYS {
+            self.skipped.pop_first();
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for SymmetricRatchet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SymmetricRatchet")
+            .field("index", &self.index)
+            .field("skipped_keys", &self.skipped.len())
+            .finish_non_exhaustive()
+    }
+}
+
+// ------------------- Diffie-Hellman ratchet -------------------
+// ------------------- 迪菲-赫尔曼棘轮 -------------------
+
+/// The header sent with each message, naming the sender's ratchet key and the message's position.
+///
+/// 随每条消息发送的头部，指明发送方的棘轮密钥和该消息的位置。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct RatchetHeader<K: AsymmetricKeySet> {
+    /// The current ratchet public key of the sender.
+    ///
+    /// 发送方当前的棘轮公钥。
+    pub ratchet_key: K::PublicKey,
+    /// The number of messages in the sender's previous sending chain.
+    ///
+    /// 发送方上一条发送链中的消息数量。
+    pub previous_chain_length: u32,
+    /// The index of the message in the current sending chain.
+    ///
+    /// 该消息在当前发送链中的索引。
+    pub index: u32,
+}
+
+impl<K: AsymmetricKeySet> RatchetHeader<K> {
+    /// Serializes the header as `Encode(ratchet_key) | previous_chain_length | index`, with both
+    /// numbers as 4-byte big-endian integers.
+    ///
+    /// 将头部序列化为 `Encode(ratchet_key) | previous_chain_length | index`，两个数字均为 4 字节
+    /// 大端整数。
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut out = Vec::new();
+        encode_key(&mut out, &self.ratchet_key)?;
+        out.extend_from_slice(&self.previous_chain_length.to_be_bytes());
+        out.extend_from_slice(&self.index.to_be_bytes());
+        Ok(out)
+    }
+
+    /// Deserializes a header produced by [`RatchetHeader::to_bytes`].
+    ///
+    /// 反序列化由 [`RatchetHeader::to_bytes`] 生成的头部。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let invalid = || Error::Key(KeyError::InvalidEncoding);
+        let (len, rest) = bytes.split_first_chunk::<4>().ok_or_else(invalid)?;
+        let len = usize::try_from(u32::from_be_bytes(*len)).map_err(|_| invalid())?;
+        if rest.len() != len.checked_add(8).ok_or_else(invalid)? {
+            return Err(invalid());
+        }
+        let (key, numbers) = rest.split_at(len);
+        let (previous_chain_length, index) = numbers.split_at(4);
+        Ok(Self {
+            ratchet_key: K::PublicKey::from_bytes(key)?,
+            previous_chain_length: u32::from_be_bytes(
+                previous_chain_length.try_into().map_err(|_| invalid())?,
+            ),
+            index: u32::from_be_bytes(index.try_into().map_err(|_| invalid())?),
+        })
+    }
+}
+
+impl<K: AsymmetricKeySet> Clone for RatchetHeader<K> {
+    fn clone(&self) -> Self {
+        Self {
+            ratchet_key: self.ratchet_key.clone(),
+            previous_chain_length: self.previous_chain_length,
+            index: self.index,
+        }
+    }
+}
+
+impl<K: AsymmetricKeySet> fmt::Debug for RatchetHeader<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RatchetHeader")
+            .field("previous_chain_length", &self.previous_chain_length)
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The stored keys of skipped messages of a receiving chain that has been replaced.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct SkippedChain {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    ratchet_key: Vec<u8>,
+    keys: BTreeMap<u32, DerivedKey>,
+}
+
+/// The key schedule of a Double Ratchet session over the key agreement scheme `K`.
+///
+/// With the `serde` feature, the whole state can be stored between messages; it contains the
+/// session's secrets and must be protected accordingly.
+///
+/// 基于密钥协商方案 `K` 的 Double Ratchet 会话的密钥编排。
+///
+/// 启用 `serde` 特性时，整个状态可以在消息之间存储；它包含会话的秘密，必须相应地加以保护。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct DhRatchet<K: AsymmetricKeySet> {
+    root_key: ChainKey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    info: Vec<u8>,
+    ratchet_key: KeyPair<K>,
+    remote_ratchet_key: Option<K::PublicKey>,
+    sending: Option<SymmetricRatchet>,
+    receiving: Option<SymmetricRatchet>,
+    previous_chain_length: u32,
+    skipped: VecDeque<SkippedChain>,
+}
+
+impl<K: KeyAgreement + KeyGenerator> DhRatchet<K> {
+    /// Starts the session of the party that sends first, from the shared secret of the key
+    /// agreement that set up the session and the peer's initial ratchet key.
+    ///
+    /// With X3DH, `shared_secret` is the session's shared secret and `remote_ratchet_key` is the
+    /// responder's signed prekey. `info` identifies the application and must match on both sides.
+    ///
+    /// 从建立会话的密钥协商的共享秘密和对方的初始棘轮密钥，开始先发送一方的会话。
+    ///
+    /// 使用 X3DH 时，`shared_secret` 是会话的共享秘密，`remote_ratchet_key` 是响应方的已签名
+    /// 预密钥。`info` 标识应用，双方必须一致。
+    pub fn initiator(
+        shared_secret: &SharedSecret,
+        remote_ratchet_key: K::PublicKey,
+        info: &[u8],
+    ) -> Result<Self, Error> {
+        Self::initiator_with_rng(
+            shared_secret,
+            remote_ratchet_key,
+            info,
+            &mut SystemRng::new(),
+        )
+    }
+
+    /// Starts the session of the party that sends first like [`DhRatchet::initiator`], with the
+    /// given RNG.
+    ///
+    /// 与 [`DhRatchet::initiator`] 一样开始先发送一方的会话，并使用给定的 RNG。
+    pub fn initiator_with_rng<R: CryptoRngCore>(
+        shared_secret: &SharedSecret,
+        remote_ratchet_key: K::PublicKey,
+        info: &[u8],
+        rng: &mut R,
+    ) -> Result<Self, Error> {
+        let root_key = root_key(shared_secret)?;
+        let ratchet_key = KeyPair::from(K::generate_keypair_with_rng(rng)?);
+        let (root_key, sending) =
+            kdf_rk(&root_key, &ratchet_key.agree(&remote_ratchet_key)?, info)?;
+        Ok(Self {
+            root_key,
+            info: info.to_vec(),
+            ratchet_key,
+            remote_ratchet_key: Some(remote_ratchet_key),
+            sending: Some(SymmetricRatchet::from_chain_key(sending)),
+            receiving: None,
+            previous_chain_length: 0,
+            skipped: VecDeque::new(),
+        })
+    }
+
+    /// Starts the session of the party that receives first, from the shared secret and the
+    /// key pair of its initial ratchet key, e.g. the key pair of its X3DH signed prekey.
+    ///
+    /// It can only send after receiving the first message.
+    ///
+    /// 从共享秘密和其初始棘轮密钥的密钥对（例如其 X3DH 已签名预密钥的密钥对），开始先接收一方的
+    /// 会话。
+    ///
+    /// 它只有在收到第一条消息之后才能发送。
+    pub fn responder(
+        shared_secret: &SharedSecret,
+        ratchet_key: KeyPair<K>,
+        info: &[u8],
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            root_key: root_key(shared_secret)?,
+            info: info.to_vec(),
+            ratchet_key,
+            remote_ratchet_key: None,
+            sending: None,
+            receiving: None,
+            previous_chain_length: 0,
+            skipped: VecDeque::new(),
+        })
+    }
+
+    /// Returns the current ratchet public key.
+    ///
+    /// 返回当前的棘轮公钥。
+    pub fn ratchet_public_key(&self) -> &K::PublicKey {
+        self.ratchet_key.public_key()
+    }
+
+    /// Returns the number of stored keys of skipped messages, across all receiving chains.
+    ///
+    /// 返回所有接收链中已存储的跳过消息密钥的数量。
+    pub fn skipped_keys(&self) -> usize {
+        self.receiving
+            .as_ref()
+            .map_or(0, SymmetricRatchet::skipped_keys)
+            + self
+                .skipped
+                .iter()
+                .map(|chain| chain.keys.len())
+                .sum::<usize>()
+    }
+
+    /// Returns the header and the message key of the next message to send.
+    ///
+    /// Fails with [`KeyAgreementError::AgreementFailed`] for a responder that has not received
+    /// a message yet.
+    ///
+    /// 返回下一条要发送的消息的头部和消息密钥。
+    ///
+    /// 对于尚未收到消息的响应方，以 [`KeyAgreementError::AgreementFailed`] 失败。
+    pub fn send(&mut self) -> Result<(RatchetHeader<K>, DerivedKey), Error> {
+        let sending = self
+            .sending
+            .as_mut()
+            .ok_or(Error::KeyAgreement(KeyAgreementError::AgreementFailed))?;
+        let header = RatchetHeader {
+            ratchet_key: self.ratchet_key.public_key().clone(),
+            previous_chain_length: self.previous_chain_length,
+            index: sending.index(),
+        };
+        Ok((header, sending.next_key()?))
+    }
+
+    /// Derives the message key of `header` and opens the message with `open`.
+    ///
+    /// The state is only updated if `open` succeeds, so `open` should authenticate the message,
+    /// e.g. by decrypting it with an AEAD. Fails with
+    /// [`KeyAgreementError::MessageKeyUnavailable`] for a replayed message, with
+    /// [`KeyAgreementError::TooManySkippedMessages`] if more than [`MAX_SKIP`] keys would be
+    /// skipped, and with the error of `open`.
+    ///
+    /// 派生 `header` 的消息密钥，并使用 `open` 打开消息。
+    ///
+    /// 仅当 `open` 成功时才更新状态，因此 `open` 应当认证该消息，例如使用 AEAD 对其解密。对于
+    /// 重放的消息，以 [`KeyAgreementError::MessageKeyUnavailable`] 失败；如果将跳过超过
+    /// [`MAX_SKIP`] 个密钥，则以 [`KeyAgreementError::TooManySkippedMessages`] 失败；此外还会以
+    /// `open` 的错误失败。
+    pub fn receive<T, F>(&mut self, header: &RatchetHeader<K>, open: F) -> Result<T, Error>
+    where
+        F: FnOnce(&DerivedKey) -> Result<T, Error>,
+    {
+        self.receive_with_rng(header, &mut SystemRng::new(), open)
+    }
+
+    /// Derives the message key of `header` and opens the message like [`DhRatchet::receive`],
+    /// with the given RNG for a new ratchet key.
+    ///
+    /// 与 [`DhRatchet::receive`] 一样派生 `header` 的消息密钥并打开消息，并使用给定的 RNG
+    /// 生成新的棘轮密钥。
+    pub fn receive_with_rng<T, F, R>(
+        &mut self,
+        header: &RatchetHeader<K>,
+        rng: &mut R,
+        open: F,
+    ) -> Result<T, Error>
+    where
+        F: FnOnce(&DerivedKey) -> Result<T, Error>,
+        R: CryptoRngCore,
+    {
+        let mut next = self.clone();
+        let message_key = next.message_key(header, rng)?;
+        let value = open(&message_key)?;
+        *self = next;
+        Ok(value)
+    }
+
+    /// Derives the message key of `header`, stepping the Diffie-Hellman ratchet if its ratchet
+    /// key is new.
+    fn message_key<R: CryptoRngCore>(
+        &mut self,
+        header: &RatchetHeader<K>,
+        rng: &mut R,
+    ) -> Result<DerivedKey, Error> {
+        let header_key = header.ratchet_key.to_bytes()?;
+        if let Some(position) = self
+            .skipped
+            .iter()
+            .position(|chain| chain.ratchet_key == header_key)
+        {
+            let chain = &mut self.skipped[position];
+            let message_key = chain.keys.remove(&header.index).ok_or(Error::KeyAgreement(
+                KeyAgreementError::MessageKeyUnavailable,
+            ))?;
+            if chain.keys.is_empty() {
+                self.skipped.remove(position);
+            }
+            return Ok(message_key);
+        }
+
+        let remote_key = self
+            .remote_ratchet_key
+            .as_ref()
+            .map(Key::to_bytes)
+            .transpose()?;
+        if remote_key.as_ref() != Some(&header_key) {
+            if let (Some(mut receiving), Some(remote_key)) = (self.receiving.take(), remote_key) {
+                receiving.skip_to(header.previous_chain_length)?;
+                if !receiving.skipped.is_empty() {
+                    self.skipped.push_back(SkippedChain {
+                        ratchet_key: remote_key,
+                        keys: core::mem::take(&mut receiving.skipped),
+                    });
+                }
+            }
+            self.step(header.ratchet_key.clone(), rng)?;
+        }
+
+        let message_key = self
+            .receiving
+            .as_mut()
+            .ok_or(Error::KeyAgreement(
+                KeyAgreementError::MessageKeyUnavailable,
+            ))?
+            .key_for(header.index)?;
+        self.drop_excess_skipped_keys();
+        Ok(message_key)
+    }
+
+    /// Steps the Diffie-Hellman ratchet to the peer's new ratchet key.
+    fn step<R: CryptoRngCore>(
+        &mut self,
+        remote_ratchet_key: K::PublicKey,
+        rng: &mut R,
+    ) -> Result<(), Error> {
+        self.previous_chain_length = self.sending.as_ref().map_or(0, SymmetricRatchet::index);
+        let (root_key, receiving) = kdf_rk(
+            &self.root_key,
+            &self.ratchet_key.agree(&remote_ratchet_key)?,
+            &self.info,
+        )?;
+        self.ratchet_key = KeyPair::from(K::generate_keypair_with_rng(rng)?);
+        let (root_key, sending) = kdf_rk(
+            &root_key,
+            &self.ratchet_key.agree(&remote_ratchet_key)?,
+            &self.info,
+        )?;
+        self.root_key = root_key;
+        self.receiving = Some(SymmetricRatchet::from_chain_key(receiving));
+        self.sending = Some(SymmetricRatchet::from_chain_key(sending));
+        self.remote_ratchet_key = Some(remote_ratchet_key);
+        Ok(())
+    }
+
+    /// Drops the oldest keys of replaced receiving chains beyond [`MAX_SKIPPED_KEYS`].
+    fn drop_excess_skipped_keys(&mut self) {
+        let mut excess = self.skipped_keys().saturating_sub(MAX_SKIPPED_KEYS);
+        while excess > 0 {
+            let Some(oldest) = self.skipped.front_mut() else {
+                break;
+            };
+            if oldest.keys.pop_first().is_some() {
+                excess -= 1;
+            }
+            if oldest.keys.is_empty() {
+                self.skipped.pop_front();
+            }
+        }
+    }
+}
+
+impl<K: AsymmetricKeySet> Clone for DhRatchet<K> {
+    fn clone(&self) -> Self {
+        Self {
+            root_key: self.root_key.clone(),
+            info: self.info.clone(),
+            ratchet_key: self.ratchet_key.clone(),
+            remote_ratchet_key: self.remote_ratchet_key.clone(),
+            sending: self.sending.clone(),
+            receiving: self.receiving.clone(),
+            previous_chain_length: self.previous_chain_length,
+            skipped: self.skipped.clone(),
+        }
+    }
+}
+
+impl<K: AsymmetricKeySet> fmt::Debug for DhRatchet<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DhRatchet")
+            .field("sending", &self.sending)
+            .field("receiving", &self.receiving)
+            .field("previous_chain_length", &self.previous_chain_length)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The Diffie-Hellman ratchet over ECDH P-256.
+///
+/// 基于 ECDH P-256 的迪菲-赫尔曼棘轮。
+pub type DhRatchetP256 = DhRatchet<EcdhP256>;
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// Takes the 32-byte shared secret of the session as the first root key.
+fn root_key(shared_secret: &SharedSecret) -> Result<ChainKey, Error> {
+    Ok(Zeroizing::new(
+        shared_secret
+            .expose_raw()
+            .try_into()
+            .map_err(|_| Error::Key(KeyError::InvalidLength))?,
+    ))
+}
+
+/// `KDF_RK(rk, dh_out)`, returning the new root key and chain key.
+fn kdf_rk(
+    root_key: &[u8; CHAIN_KEY_SIZE],
+    dh_output: &SharedSecret,
+    info: &[u8],
+) -> Result<(ChainKey, ChainKey), Error> {
+    let mut okm = Zeroizing::new([0u8; 2 * CHAIN_KEY_SIZE]);
+    Hkdf::<Sha256>::new(Some(root_key), dh_output.expose_raw())
+        .expand(info, okm.as_mut())
+        .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+    let mut new_root_key = Zeroizing::new([0u8; CHAIN_KEY_SIZE]);
+    let mut chain_key = Zeroizing::new([0u8; CHAIN_KEY_SIZE]);
+    new_root_key.copy_from_slice(&okm[..CHAIN_KEY_SIZE]);
+    chain_key.copy_from_slice(&okm[CHAIN_KEY_SIZE..]);
+    Ok((new_root_key, chain_key))
+}
+
+/// `HMAC-SHA256(chain_key, input)`.
+fn hmac_sha256(chain_key: &[u8; CHAIN_KEY_SIZE], input: u8) -> Result<ChainKey, Error> {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(chain_key)
+        .map_err(|_| Error::Kdf(KdfError::DerivationFailed))?;
+    mac.update(&[input]);
+    let mut output = Zeroizing::new([0u8; CHAIN_KEY_SIZE]);
+    output.copy_from_slice(&mac.finalize().into_bytes());
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    const INFO: &[u8] = b"seal-crypto ratchet test";
+
+    /// Opens a "message" by checking that it carries the expected message key.
+    /// 通过检查“消息”是否携带预期的消息密钥来打开它。
+    fn open(expected: &DerivedKey) -> impl FnOnce(&DerivedKey) -> Result<(), Error> + '_ {
+        move |key| {
+            if key == expected {
+                Ok(())
+            } else {
+                Err(Error::Symmetric(SymmetricError::Decryption))
+            }
+        }
+    }
+
+    fn session(seed: u64) -> Result<(DhRatchetP256, DhRatchetP256, SeededRng), Error> {
+        let mut rng = SeededRng(seed);
+        let shared_secret = SharedSecret::new(vec![7u8; CHAIN_KEY_SIZE]);
+        let bob_key = KeyPair::<EcdhP256>::from(EcdhP256::generate_keypair_with_rng(&mut rng)?);
+        let alice = DhRatchetP256::initiator_with_rng(
+            &shared_secret,
+            bob_key.public_key().clone(),
+            INFO,
+            &mut rng,
+        )?;
+        let bob = DhRatchetP256::responder(&shared_secret, bob_key, INFO)?;
+        Ok((alice, bob, rng))
+    }
+
+    #[test]
+    fn test_symmetric_ratchet_vector() -> Result<(), Error> {
+        let chain_key: Vec<u8> = (0..32).collect();
+        let mut ratchet = SymmetricRatchet::new(&chain_key)?;
+        assert_eq!(
+            ratchet.next_key()?.as_bytes(),
+            hex::decode("9b4c8120a4823a95f47cde17a244f4507244ee6e3957d1fab9fa29b44d3829b7")
+                .unwrap_or_default()
+        );
+        assert_eq!(
+            ratchet.next_key()?.as_bytes(),
+            hex::decode("f7703c39dea9feb30cb6369304ad7b847b9aca58c1152af317aa78a91beddda1")
+                .unwrap_or_default()
+        );
+        assert_eq!(ratchet.index(), 2);
+        assert!(SymmetricRatchet::new(&chain_key[..31]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_symmetric_ratchet_out_of_order() -> Result<(), Error> {
+        let mut sender = SymmetricRatchet::new(&[1u8; 32])?;
+        let mut receiver = SymmetricRatchet::new(&[1u8; 32])?;
+        let keys = (0..5)
+            .map(|_| sender.next_key())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(receiver.key_for(3)?, keys[3]);
+        assert_eq!(receiver.skipped_keys(), 3);
+        assert_eq!(receiver.key_for(0)?, keys[0]);
+        assert_eq!(receiver.key_for(4)?, keys[4]);
+        assert_eq!(receiver.key_for(2)?, keys[2]);
+        assert_eq!(receiver.key_for(1)?, keys[1]);
+        assert_eq!(receiver.skipped_keys(), 0);
+
+        // Each key is returned once.
+        // 每个密钥只返回一次。
+        assert_eq!(
+            receiver.key_for(3),
+            Err(Error::KeyAgreement(
+                KeyAgreementError::MessageKeyUnavailable
+            ))
+        );
+
+        // Skipping too far fails without changing the chain.
+        // 跳得太远会失败，且不会改变该链。
+        assert_eq!(
+            receiver.key_for(5 + MAX_SKIP + 1),
+            Err(Error::KeyAgreement(
+                KeyAgreementError::TooManySkippedMessages
+            ))
+        );
+        assert_eq!(receiver.index(), 5);
+        assert_eq!(receiver.key_for(5 + MAX_SKIP)?, {
+            for _ in 0..MAX_SKIP {
+                sender.next_key()?;
+            }
+            sender.next_key()?
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_symmetric_ratchet_bounded_storage() -> Result<(), Error> {
+        let mut receiver = SymmetricRatchet::new(&[2u8; 32])?;
+        receiver.key_for(MAX_SKIP)?;
+        receiver.key_for(2 * MAX_SKIP)?;
+        receiver.key_for(3 * MAX_SKIP)?;
+        assert_eq!(receiver.skipped_keys(), MAX_SKIPPED_KEYS);
+
+        // The oldest keys are dropped first.
+        // 最旧的密钥最先被丢弃。
+        assert!(receiver.key_for(0).is_err());
+        assert!(receiver.key_for(3 * MAX_SKIP - 1).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dh_ratchet_conversation() -> Result<(), Error> {
+        let (mut alice, mut bob, mut rng) = session(1)?;
+        assert!(bob.send().is_err());
+
+        for round in 0..3 {
+            // Alice sends a few messages, then Bob answers, which steps both DH ratchets.
+            // Alice 发送几条消息，然后 Bob 回复，这会推进双方的迪菲-赫尔曼棘轮。
+            for index in 0..=round {
+                let (header, key) = alice.send()?;
+                assert_eq!(header.index, index);
+                bob.receive_with_rng(&header, &mut rng, open(&key))?;
+            }
+            let alice_key = alice.ratchet_public_key().to_bytes()?;
+            let (header, key) = bob.send()?;
+            alice.receive_with_rng(&header, &mut rng, open(&key))?;
+            assert_ne!(alice.ratchet_public_key().to_bytes()?, alice_key);
+        }
+        assert_eq!(alice.skipped_keys(), 0);
+        assert_eq!(bob.skipped_keys(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dh_ratchet_out_of_order() -> Result<(), Error> {
+        let (mut alice, mut bob, mut rng) = session(2)?;
+        let first = alice.send()?;
+        let delayed = alice.send()?;
+        let third = alice.send()?;
+
+        bob.receive_with_rng(&first.0, &mut rng, open(&first.1))?;
+        bob.receive_with_rng(&third.0, &mut rng, open(&third.1))?;
+        assert_eq!(bob.skipped_keys(), 1);
+
+        // Both sides step their DH ratchets before the delayed message arrives.
+        // 在延迟消息到达之前，双方都推进了各自的迪菲-赫尔曼棘轮。
+        let (header, key) = bob.send()?;
+        alice.receive_with_rng(&header, &mut rng, open(&key))?;
+        let lost = alice.send()?;
+        let (header, key) = alice.send()?;
+        assert_eq!(header.previous_chain_length, 3);
+        bob.receive_with_rng(&header, &mut rng, open(&key))?;
+        assert_eq!(bob.skipped_keys(), 2);
+
+        bob.receive_with_rng(&delayed.0, &mut rng, open(&delayed.1))?;
+        bob.receive_with_rng(&lost.0, &mut rng, open(&lost.1))?;
+        assert_eq!(bob.skipped_keys(), 0);
+
+        // Replays are rejected: a message of a finished chain no longer opens, and one of the
+        // current chain has no key left.
+        // 重放被拒绝：已结束链的消息不再能打开，当前链的消息已没有剩余的密钥。
+        assert!(
+            bob.receive_with_rng(&delayed.0, &mut rng, open(&delayed.1))
+                .is_err()
+        );
+        assert_eq!(bob.skipped_keys(), 0);
+        assert_eq!(
+            bob.receive_with_rng(&header, &mut rng, open(&key)),
+            Err(Error::KeyAgreement(
+                KeyAgreementError::MessageKeyUnavailable
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dh_ratchet_rejected_messages_keep_state() -> Result<(), Error> {
+        let (mut alice, mut bob, mut rng) = session(3)?;
+        let (header, key) = alice.send()?;
+
+        // A message that fails to open leaves the state untouched.
+        // 无法打开的消息不会改变状态。
+        let wrong = DerivedKey::new(vec![0u8; CHAIN_KEY_SIZE]);
+        assert!(
+            bob.receive_with_rng(&header, &mut rng, open(&wrong))
+                .is_err()
+        );
+        assert!(bob.send().is_err());
+
+        // A forged header with a new ratchet key does not step the ratchet either.
+        // 带有新棘轮密钥的伪造头部也不会推进棘轮。
+        let mut forged = header.clone();
+        forged.ratchet_key = EcdhP256::generate_keypair_with_rng(&mut rng)?.0;
+        assert!(bob.receive_with_rng(&forged, &mut rng, open(&key)).is_err());
+
+        let mut far = header.clone();
+        far.index = MAX_SKIP + 1;
+        assert_eq!(
+            bob.receive_with_rng(&far, &mut rng, open(&key)),
+            Err(Error::KeyAgreement(
+                KeyAgreementError::TooManySkippedMessages
+            ))
+        );
+
+        bob.receive_with_rng(&header, &mut rng, open(&key))?;
+        let (header, key) = bob.send()?;
+        alice.receive_with_rng(&header, &mut rng, open(&key))
+    }
+
+    #[test]
+    fn test_header_encoding() -> Result<(), Error> {
+        let (mut alice, _, _) = session(4)?;
+        let (header, _) = alice.send()?;
+        let bytes = header.to_bytes()?;
+        let decoded = RatchetHeader::<EcdhP256>::from_bytes(&bytes)?;
+        assert_eq!(decoded.to_bytes()?, bytes);
+
+        assert!(RatchetHeader::<EcdhP256>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(RatchetHeader::<EcdhP256>::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+        assert!(RatchetHeader::<EcdhP256>::from_bytes(&[0xff; 12]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "x3dh-default")]
+    #[test]
+    fn test_with_x3dh() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::ecc::EcdsaP256;
+        use crate::systems::messaging::x3dh::{X3dhIdentity, X3dhP256};
+
+        // The X3DH secret and Bob's signed prekey start the ratchets.
+        // X3DH 秘密和 Bob 的已签名预密钥启动棘轮。
+        let mut rng = SeededRng(5);
+        let bob_identity = X3dhIdentity::<EcdhP256, EcdsaP256>::generate_with_rng(&mut rng)?;
+        let signed_prekey = bob_identity.generate_signed_prekey_with_rng(1, &mut rng)?;
+        let alice_identity = X3dhIdentity::generate_with_rng(&mut rng)?;
+        let bundle = bob_identity.prekey_bundle(&signed_prekey, None);
+        let (message, alice_session) =
+            X3dhP256::initiate_with_rng(&alice_identity, &bundle, INFO, &mut rng)?;
+        let bob_session = X3dhP256::respond(&bob_identity, &signed_prekey, None, &message, INFO)?;
+
+        let mut alice = DhRatchetP256::initiator_with_rng(
+            &alice_session.shared_secret,
+            bundle.signed_prekey.clone(),
+            INFO,
+            &mut rng,
+        )?;
+        let mut bob = DhRatchetP256::responder(
+            &bob_session.shared_secret,
+            signed_prekey.key_pair().clone(),
+            INFO,
+        )?;
+        let (header, key) = alice.send()?;
+        bob.receive_with_rng(&header, &mut rng, open(&key))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), Error> {
+        let (mut alice, mut bob, mut rng) = session(6)?;
+        let first = alice.send()?;
+        let (header, key) = alice.send()?;
+        bob.receive_with_rng(&header, &mut rng, open(&key))?;
+
+        // A stored session keeps its chains and skipped keys.
+        // 存储的会话保留其链和跳过的密钥。
+        let json =
+            serde_json::to_string(&bob).map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        let mut restored: DhRatchetP256 =
+            serde_json::from_str(&json).map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        assert_eq!(restored.skipped_keys(), 1);
+        restored.receive_with_rng(&first.0, &mut rng, open(&first.1))?;
+        let (header, key) = restored.send()?;
+        alice.receive_with_rng(&header, &mut rng, open(&key))
+    }
+}
@@ -0,0 +1,440 @@
+//! Provides signcryption, an envelope that is both signed by its sender and encrypted to its
+//! recipient.
+//!
+//! Composing a signature and public-key encryption by hand is easy to get subtly wrong. If the
+//! signature only covers the plaintext, a recipient can re-encrypt the signed message to a third
+//! party, who then believes the sender wrote to them (surreptitious forwarding); if it is made
+//! over the ciphertext instead, anyone can strip it and sign the ciphertext as their own.
+//! [`Signcryption`] fixes one order that avoids both:
+//!
+//! 1. The sender signs `LABEL | Encode(sender_pk) | Encode(recipient_pk) | Encode(aad) |
+//!    plaintext`, so the signature names the recipient it was meant for.
+//! 2. A fresh KEM encapsulation to the recipient yields `enc` and a shared secret, from which
+//!    HKDF-SHA256 derives the AEAD key and nonce with the header
+//!    `LABEL | Encode(enc) | Encode(sender_pk) | Encode(recipient_pk) | Encode(aad)` as info.
+//! 3. The AEAD encrypts `Encode(signature) | plaintext` with the same header as associated
+//!    data, which binds both identities and `enc` to the ciphertext.
+//!
+//! `Encode(x)` is the 4-byte big-endian length of `x` followed by `x`, and the envelope is
+//! `Encode(enc) | ciphertext | tag`. The KEM, signature and AEAD schemes are type parameters.
+//!
+//! # Security Considerations
+//! - The signature is only visible to the recipient, who can show it to others to prove what
+//!   the sender wrote to them: signcryption is not deniable.
+//! - The envelope does not identify the sender; the recipient must know which sender public key
+//!   to open it with, e.g. from the surrounding protocol.
+//! - Envelopes can be replayed; include a message ID or timestamp in `aad` or the plaintext if
+//!   the recipient must detect that.
+//!
+//! 提供了签密，即一种既由发送方签名、又加密给接收方的信封。
+//!
+//! 手工组合签名和公钥加密很容易出现细微的错误。如果签名只覆盖明文，接收方可以将签名后的消息
+//! 重新加密给第三方，使第三方相信发送方是写给他们的（隐蔽转发）；如果改为对密文签名，任何人
+//! 都可以剥离该签名，并以自己的名义对密文签名。[`Signcryption`] 固定了一种可同时避免两者的
+//! 顺序：
+//!
+//! 1. 发送方对 `LABEL | Encode(sender_pk) | Encode(recipient_pk) | Encode(aad) | plaintext`
+//!    签名，因此签名指明了其预期的接收方。
+//! 2. 对接收方进行一次新的 KEM 封装，得到 `enc` 和共享秘密，HKDF-SHA256 以头部
+//!    `LABEL | Encode(enc) | Encode(sender_pk) | Encode(recipient_pk) | Encode(aad)` 作为 info，
+//!    从中派生 AEAD 密钥和 nonce。
+//! 3. AEAD 以同一头部作为关联数据加密 `Encode(signature) | plaintext`，从而将双方身份和 `enc`
+//!    绑定到密文。
+//!
+//! `Encode(x)` 是 4 字节大端的 `x` 的长度，后跟 `x`，信封为 `Encode(enc) | ciphertext | tag`。
+//! KEM、签名和 AEAD 方案均为类型参数。
+//!
+//! # 安全考虑
+//! - 签名只对接收方可见，接收方可以向他人出示签名以证明发送方写给他们的内容：签密不具备
+//!   可否认性。
+//! - 信封不标识发送方；接收方必须知道使用哪个发送方公钥打开它，例如通过外围协议得知。
+//! - 信封可能被重放；如果接收方必须检测重放，请在 `aad` 或明文中包含消息 ID 或时间戳。
+
+use alloc::vec::Vec;
+use super::encode_key;
+use crate::errors::Error;
+use crate::policy::CryptoPolicy;
+use crate::prelude::*;
+use crate::systems::kdf::hkdf::HkdfSha256;
+use core::marker::PhantomData;
+use zeroize::Zeroizing;
+
+/// The label that separates signcryption signatures and keys from other uses of the keys.
+const LABEL: &[u8] = b"seal-crypto signcryption v1";
+
+/// Appends `Encode(bytes)`, the 4-byte big-endian length of `bytes` followed by `bytes`.
+fn encode(out: &mut Vec<u8>, bytes: &[u8]) -> Result<(), Error> {
+    let len =
+        u32::try_from(bytes.len()).map_err(|_| Error::Symmetric(SymmetricError::Encryption))?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(bytes);
+    Ok(())
+}
+
+/// Splits `Encode(x) | rest` into `x` and `rest`.
+fn decode(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (len, rest) = bytes.split_first_chunk::<4>()?;
+    let len = usize::try_from(u32::from_be_bytes(*len)).ok()?;
+    (rest.len() >= len).then(|| rest.split_at(len))
+}
+
+/// Signcryption with the KEM `K`, the signature scheme `S` and the AEAD `A`.
+///
+/// 使用 KEM `K`、签名方案 `S` 和 AEAD `A` 的签密。
+pub struct Signcryption<K, S, A> {
+    _schemes: PhantomData<(K, S, A)>,
+}
+
+impl<K, S, A> Signcryption<K, S, A>
+where
+    K: Kem,
+    S: Signer + Verifier,
+    A: AeadScheme,
+{
+    /// Signs `plaintext` with the sender's key pair and encrypts it to `recipient_public_key`,
+    /// authenticating `aad` along with it.
+    ///
+    /// 使用发送方的密钥对签名 `plaintext` 并将其加密给 `recipient_public_key`，同时认证 `aad`。
+    pub fn signcrypt(
+        sender_private_key: &S::PrivateKey,
+        sender_public_key: &S::PublicKey,
+        recipient_public_key: &K::PublicKey,
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let signature = S::sign(
+            sender_private_key,
+            &signed_message::<K, S>(sender_public_key, recipient_public_key, aad, plaintext)?,
+        )?;
+        Self::seal(
+            sender_public_key,
+            recipient_public_key,
+            &signature,
+            plaintext,
+            aad,
+        )
+    }
+
+    /// Decrypts an envelope with the recipient's key pair and verifies that the sender with
+    /// `sender_public_key` signed it for this recipient and `aad`, returning the plaintext.
+    ///
+    /// Fails with `SymmetricError::Decryption` if the envelope cannot be decrypted, e.g. because
+    /// it was modified or `sender_public_key` or `aad` is not the one it was made with, and with
+    /// `SignatureError::Verification` if it decrypts but the signature is not the sender's for
+    /// this recipient.
+    ///
+    /// 使用接收方的密钥对解密信封，并验证持有 `sender_public_key` 的发送方为此接收方和 `aad`
+    /// 签名了它，返回明文。
+    ///
+    /// 如果信封无法解密（例如由于被修改，或 `sender_public_key` 或 `aad` 不是创建它时使用的），
+    /// 则以 `SymmetricError::Decryption` 失败；如果信封可以解密但签名不是发送方为此接收方所作
+    /// 的，则以 `SignatureError::Verification` 失败。
+    pub fn unsigncrypt(
+        recipient_private_key: &K::PrivateKey,
+        recipient_public_key: &K::PublicKey,
+        sender_public_key: &S::PublicKey,
+        envelope: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let decryption = || Error::Symmetric(SymmetricError::Decryption);
+        let (encapsulated_key, sealed) = decode(envelope).ok_or_else(decryption)?;
+        let shared_secret = K::EncapsulatedKey::from_bytes(encapsulated_key)
+            .and_then(|encapsulated_key| K::decapsulate(recipient_private_key, &encapsulated_key))
+            .map_err(|_| decryption())?;
+        let header = header::<K, S>(
+            encapsulated_key,
+            sender_public_key,
+            recipient_public_key,
+            aad,
+        )?;
+        let (key, nonce) = derive_key::<A>(&shared_secret, &header)?;
+        let opened = Zeroizing::new(
+            A::decrypt(&key, &nonce, sealed, Some(&header)).map_err(|_| decryption())?,
+        );
+
+        let (signature, plaintext) = decode(&opened).ok_or_else(decryption)?;
+        S::verify(
+            sender_public_key,
+            &signed_message::<K, S>(sender_public_key, recipient_public_key, aad, plaintext)?,
+            &signature.to_vec(),
+        )
+        .map_err(|_| Error::Signature(SignatureError::Verification))?;
+        Ok(plaintext.to_vec())
+    }
+
+    /// Like [`Signcryption::signcrypt`], but fails with [`Error::PolicyViolation`] if `policy`
+    /// disallows `K`, `S` or `A`.
+    ///
+    /// 与 [`Signcryption::signcrypt`] 相同，但如果 `policy` 不允许 `K`、`S` 或 `A`，则以
+    /// [`Error::PolicyViolation`] 失败。
+    pub fn signcrypt_with_policy(
+        policy: &CryptoPolicy,
+        sender_private_key: &S::PrivateKey,
+        sender_public_key: &S::PublicKey,
+        recipient_public_key: &K::PublicKey,
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        Self::check_policy(policy)?;
+        Self::signcrypt(
+            sender_private_key,
+            sender_public_key,
+            recipient_public_key,
+            plaintext,
+            aad,
+        )
+    }
+
+    /// Like [`Signcryption::unsigncrypt`], but fails with [`Error::PolicyViolation`] if
+    /// `policy` disallows `K`, `S` or `A`.
+    ///
+    /// 与 [`Signcryption::unsigncrypt`] 相同，但如果 `policy` 不允许 `K`、`S` 或 `A`，则以
+    /// [`Error::PolicyViolation`] 失败。
+    pub fn unsigncrypt_with_policy(
+        policy: &CryptoPolicy,
+        recipient_private_key: &K::PrivateKey,
+        recipient_public_key: &K::PublicKey,
+        sender_public_key: &S::PublicKey,
+        envelope: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        Self::check_policy(policy)?;
+        Self::unsigncrypt(
+            recipient_private_key,
+            recipient_public_key,
+            sender_public_key,
+            envelope,
+            aad,
+        )
+    }
+
+    fn check_policy(policy: &CryptoPolicy) -> Result<(), Error> {
+        policy.check::<K>()?;
+        policy.check::<S>()?;
+        policy.check::<A>()?;
+        policy.check::<HkdfSha256>()
+    }
+
+    /// Encrypts `Encode(signature) | plaintext` to `recipient_public_key`.
+    fn seal(
+        sender_public_key: &S::PublicKey,
+        recipient_public_key: &K::PublicKey,
+        signature: &[u8],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let (shared_secret, encapsulated_key) = K::encapsulate(recipient_public_key)?;
+        let encapsulated_key = encapsulated_key.to_bytes()?;
+        let header = header::<K, S>(
+            &encapsulated_key,
+            sender_public_key,
+            recipient_public_key,
+            aad,
+        )?;
+        let (key, nonce) = derive_key::<A>(&shared_secret, &header)?;
+
+        let mut signed = Zeroizing::new(Vec::with_capacity(4 + signature.len() + plaintext.len()));
+        encode(&mut signed, signature)?;
+        signed.extend_from_slice(plaintext);
+        let sealed = A::encrypt(&key, &nonce, &signed, Some(&header))?;
+
+        let mut envelope = Vec::with_capacity(4 + encapsulated_key.len() + sealed.len());
+        encode(&mut envelope, &encapsulated_key)?;
+        envelope.extend_from_slice(&sealed);
+        Ok(envelope)
+    }
+}
+
+/// Returns the message the sender signs,
+/// `LABEL | Encode(sender_pk) | Encode(recipient_pk) | Encode(aad) | plaintext`.
+fn signed_message<K: AsymmetricKeySet, S: AsymmetricKeySet>(
+    sender_public_key: &S::PublicKey,
+    recipient_public_key: &K::PublicKey,
+    aad: &[u8],
+    plaintext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let mut message = LABEL.to_vec();
+    encode_key(&mut message, sender_public_key)?;
+    encode_key(&mut message, recipient_public_key)?;
+    encode(&mut message, aad)?;
+    message.extend_from_slice(plaintext);
+    Ok(message)
+}
+
+/// Returns the KDF info and AEAD associated data,
+/// `LABEL | Encode(enc) | Encode(sender_pk) | Encode(recipient_pk) | Encode(aad)`.
+fn header<K: AsymmetricKeySet, S: AsymmetricKeySet>(
+    encapsulated_key: &[u8],
+    sender_public_key: &S::PublicKey,
+    recipient_public_key: &K::PublicKey,
+    aad: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let mut header = LABEL.to_vec();
+    encode(&mut header, encapsulated_key)?;
+    encode_key(&mut header, sender_public_key)?;
+    encode_key(&mut header, recipient_public_key)?;
+    encode(&mut header, aad)?;
+    Ok(header)
+}
+
+/// Derives the AEAD key and nonce of an envelope from the shared secret.
+fn derive_key<A: AeadScheme>(
+    shared_secret: &SharedSecret,
+    header: &[u8],
+) -> Result<(A::Key, Vec<u8>), Error> {
+    let key_size = <A as AeadCipher>::KEY_SIZE;
+    let okm = shared_secret.expand(&HkdfSha256::default(), header, key_size + A::NONCE_SIZE)?;
+    let (key, nonce) = okm.as_bytes().split_at(key_size);
+    Ok((A::Key::from_bytes(key)?, nonce.to_vec()))
+}
+
+#[cfg(all(
+    test,
+    feature = "kyber-default",
+    feature = "ecc-default",
+    feature = "aes-gcm-default"
+))]
+mod tests {
+    use super::*;
+    use crate::systems::aead::aes_gcm::Aes256Gcm;
+    use crate::systems::asymmetric::post_quantum::kyber::Kyber768;
+    use crate::systems::asymmetric::traditional::ecc::Ed25519;
+
+    type Envelope = Signcryption<Kyber768, Ed25519, Aes256Gcm>;
+
+    const PLAINTEXT: &[u8] = b"meet at noon";
+    const AAD: &[u8] = b"message 1";
+
+    #[test]
+    fn test_roundtrip() -> Result<(), Error> {
+        let (alice_pk, alice_sk) = Ed25519::generate_keypair()?;
+        let (bob_pk, bob_sk) = Kyber768::generate_keypair()?;
+
+        let envelope = Envelope::signcrypt(&alice_sk, &alice_pk, &bob_pk, PLAINTEXT, AAD)?;
+        let opened = Envelope::unsigncrypt(&bob_sk, &bob_pk, &alice_pk, &envelope, AAD)?;
+        assert_eq!(opened, PLAINTEXT);
+
+        // Encapsulation is randomized, so the same message gives another envelope.
+        // 封装是随机的，因此同一消息会得到另一个信封。
+        let other = Envelope::signcrypt(&alice_sk, &alice_pk, &bob_pk, PLAINTEXT, AAD)?;
+        assert_ne!(other, envelope);
+
+        let empty = Envelope::signcrypt(&alice_sk, &alice_pk, &bob_pk, b"", b"")?;
+        assert!(Envelope::unsigncrypt(&bob_sk, &bob_pk, &alice_pk, &empty, b"")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_forwarded_signature_rejected() -> Result<(), Error> {
+        let (alice_pk, alice_sk) = Ed25519::generate_keypair()?;
+        let (bob_pk, _) = Kyber768::generate_keypair()?;
+        let (carol_pk, carol_sk) = Kyber768::generate_keypair()?;
+
+        // Bob re-seals the signature Alice made for him to Carol, naming Alice as the sender.
+        // Bob 将 Alice 为他所作的签名重新封装给 Carol，并声称 Alice 是发送方。
+        let signature = Ed25519::sign(
+            &alice_sk,
+            &signed_message::<Kyber768, Ed25519>(&alice_pk, &bob_pk, AAD, PLAINTEXT)?,
+        )?;
+        let forwarded = Envelope::seal(&alice_pk, &carol_pk, &signature, PLAINTEXT, AAD)?;
+        assert_eq!(
+            Envelope::unsigncrypt(&carol_sk, &carol_pk, &alice_pk, &forwarded, AAD).err(),
+            Some(Error::Signature(SignatureError::Verification))
+        );
+
+        // Alice's own envelope to Carol opens.
+        // Alice 自己发给 Carol 的信封可以打开。
+        let direct = Envelope::signcrypt(&alice_sk, &alice_pk, &carol_pk, PLAINTEXT, AAD)?;
+        assert_eq!(
+            Envelope::unsigncrypt(&carol_sk, &carol_pk, &alice_pk, &direct, AAD)?,
+            PLAINTEXT
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrong_parties_rejected() -> Result<(), Error> {
+        let (alice_pk, alice_sk) = Ed25519::generate_keypair()?;
+        let (mallory_pk, mallory_sk) = Ed25519::generate_keypair()?;
+        let (bob_pk, bob_sk) = Kyber768::generate_keypair()?;
+        let (carol_pk, carol_sk) = Kyber768::generate_keypair()?;
+        let decryption = Some(Error::Symmetric(SymmetricError::Decryption));
+
+        let envelope = Envelope::signcrypt(&alice_sk, &alice_pk, &bob_pk, PLAINTEXT, AAD)?;
+        assert_eq!(
+            Envelope::unsigncrypt(&bob_sk, &bob_pk, &mallory_pk, &envelope, AAD).err(),
+            decryption
+        );
+        assert_eq!(
+            Envelope::unsigncrypt(&carol_sk, &carol_pk, &alice_pk, &envelope, AAD).err(),
+            decryption
+        );
+
+        // Mallory's signature under Alice's name does not verify.
+        // Mallory 以 Alice 的名义所作的签名无法通过验证。
+        let signature = Ed25519::sign(
+            &mallory_sk,
+            &signed_message::<Kyber768, Ed25519>(&alice_pk, &bob_pk, AAD, PLAINTEXT)?,
+        )?;
+        let forged = Envelope::seal(&alice_pk, &bob_pk, &signature, PLAINTEXT, AAD)?;
+        assert_eq!(
+            Envelope::unsigncrypt(&bob_sk, &bob_pk, &alice_pk, &forged, AAD).err(),
+            Some(Error::Signature(SignatureError::Verification))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampering_rejected() -> Result<(), Error> {
+        let (alice_pk, alice_sk) = Ed25519::generate_keypair()?;
+        let (bob_pk, bob_sk) = Kyber768::generate_keypair()?;
+        let decryption = Some(Error::Symmetric(SymmetricError::Decryption));
+
+        let envelope = Envelope::signcrypt(&alice_sk, &alice_pk, &bob_pk, PLAINTEXT, AAD)?;
+        assert_eq!(
+            Envelope::unsigncrypt(&bob_sk, &bob_pk, &alice_pk, &envelope, b"message 2").err(),
+            decryption
+        );
+        for index in [0, 4, envelope.len() / 2, envelope.len() - 1] {
+            let mut tampered = envelope.clone();
+            tampered[index] ^= 1;
+            assert_eq!(
+                Envelope::unsigncrypt(&bob_sk, &bob_pk, &alice_pk, &tampered, AAD).err(),
+                decryption
+            );
+        }
+        for len in [0, 3, 4, envelope.len() - 1] {
+            assert_eq!(
+                Envelope::unsigncrypt(&bob_sk, &bob_pk, &alice_pk, &envelope[..len], AAD).err(),
+                decryption
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_policy() -> Result<(), Error> {
+        use crate::policy::PolicyError;
+
+        let (alice_pk, alice_sk) = Ed25519::generate_keypair()?;
+        let (bob_pk, bob_sk) = Kyber768::generate_keypair()?;
+
+        let policy = CryptoPolicy::default().with_min_classical_bits(128);
+        let seal = |policy| {
+            Envelope::signcrypt_with_policy(policy, &alice_sk, &alice_pk, &bob_pk, PLAINTEXT, AAD)
+        };
+        let open = |policy, envelope| {
+            Envelope::unsigncrypt_with_policy(policy, &bob_sk, &bob_pk, &alice_pk, envelope, AAD)
+        };
+        let envelope = seal(&policy)?;
+        assert_eq!(open(&policy, &envelope)?, PLAINTEXT);
+
+        // Ed25519 is not quantum-resistant.
+        // Ed25519 不抗量子。
+        let quantum = CryptoPolicy::default().with_min_quantum_category(3);
+        let violation = Some(Error::PolicyViolation(PolicyError::InsufficientSecurity));
+        assert_eq!(seal(&quantum).err(), violation);
+        assert_eq!(open(&quantum, &envelope).err(), violation);
+        Ok(())
+    }
+}
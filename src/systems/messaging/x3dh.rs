@@ -0,0 +1,872 @@
+//! Provides the Extended Triple Diffie-Hellman (X3DH) key agreement of the Signal protocol.
+//!
+//! X3DH lets an initiator establish a shared secret with a responder who is offline, from a
+//! prekey bundle the responder published beforehand. The result is the root secret of a
+//! messaging session, e.g. the initial root key of a Double Ratchet.
+//!
+//! The exchange runs as follows:
+//! 1. The responder creates an [`X3dhIdentity`], an [`X3dhSignedPrekey`] and a batch of
+//!    [`X3dhOneTimePrekey`]s, and publishes a [`X3dhPrekeyBundle`] for each one-time prekey.
+//! 2. The initiator fetches a bundle and calls [`X3dh::initiate`], which checks the prekey
+//!    signature before any key agreement and returns the [`X3dhInitialMessage`] to send along
+//!    with the first ciphertext.
+//! 3. The responder looks up the prekeys named by the message and calls [`X3dh::respond`].
+//!
+//! Both sides end with the same [`X3dhSession`], made of the shared secret and the associated
+//! data that binds both identities to the messages of the session.
+//!
+//! # Compatibility
+//! The key agreement and signature schemes are type parameters, and an identity holds one key
+//! pair of each: unlike Signal's XEdDSA, the identity key is not used for both. Hence the
+//! computation follows the X3DH specification with these encodings:
+//! - the signed prekey signature covers `Encode(IK_dh) | Encode(SPK)`, binding the prekey to the
+//!   identity key it is used with;
+//! - `Encode(PK)` is the 4-byte big-endian length of the public key bytes followed by the bytes,
+//!   and the associated data is `Encode(IK_A) | Encode(IK_B)`, where an identity key encodes as
+//!   `Encode(IK_dh) | Encode(IK_sig)`;
+//! - `SK = HKDF-SHA256(salt = 0^32, ikm = 0xFF^32 | DH1 | DH2 | DH3 [| DH4], info)`, 32 bytes.
+//!
+//! # Security Considerations
+//! - Check the peer's identity key out of band (e.g. by comparing safety numbers); X3DH only
+//!   proves possession of the keys in the bundle.
+//! - Delete each one-time prekey as soon as a message has used it, and replace the signed
+//!   prekey periodically; both bound the damage of a later key compromise.
+//! - The initial message can be replayed; the responder should not rely on it being fresh.
+//! - X3DH is not a FIPS approved protocol, so it is not available with the `fips` feature.
+//!
+//! 提供了 Signal 协议的扩展三重迪菲-赫尔曼 (X3DH) 密钥协商。
+//!
+//! X3DH 让发起方根据响应方事先发布的预密钥包，与处于离线状态的响应方建立共享秘密。其结果是
+//! 消息会话的根秘密，例如 Double Ratchet 的初始根密钥。
+//!
+//! 该交换过程如下：
+//! 1. 响应方创建一个 [`X3dhIdentity`]、一个 [`X3dhSignedPrekey`] 和一批
+//!    [`X3dhOneTimePrekey`]，并为每个一次性预密钥发布一个 [`X3dhPrekeyBundle`]。
+//! 2. 发起方获取一个预密钥包并调用 [`X3dh::initiate`]，它在任何密钥协商之前检查预密钥签名，
+//!    并返回与第一个密文一起发送的 [`X3dhInitialMessage`]。
+//! 3. 响应方查找该消息指定的预密钥并调用 [`X3dh::respond`]。
+//!
+//! 双方最终得到相同的 [`X3dhSession`]，它由共享秘密和将双方身份绑定到会话消息的关联数据组成。
+//!
+//! # 兼容性
+//! 密钥协商方案和签名方案是类型参数，一个身份分别持有二者的一个密钥对：与 Signal 的 XEdDSA
+//! 不同，身份密钥不会同时用于两者。因此计算过程遵循 X3DH 规范，并使用以下编码：
+//! - 已签名预密钥的签名覆盖 `Encode(IK_dh) | Encode(SPK)`，将预密钥绑定到与其一起使用的
+//!   身份密钥；
+//! - `Encode(PK)` 是 4 字节大端的公钥字节长度，后跟这些字节，关联数据为
+//!   `Encode(IK_A) | Encode(IK_B)`，其中身份密钥编码为 `Encode(IK_dh) | Encode(IK_sig)`；
+//! - `SK = HKDF-SHA256(salt = 0^32, ikm = 0xFF^32 | DH1 | DH2 | DH3 [| DH4], info)`，32 字节。
+//!
+//! # 安全考虑
+//! - 请通过带外方式检查对方的身份密钥（例如比较安全码）；X3DH 只能证明对预密钥包中密钥的持有。
+//! - 一次性预密钥被某条消息使用后请立即删除，并定期更换已签名预密钥；二者都能限制日后密钥泄露
+//!   造成的损害。
+//! - 初始消息可能被重放；响应方不应依赖其新鲜性。
+//! - X3DH 不是 FIPS 批准的协议，因此启用 `fips` 特性时不可用。
+
+use alloc::{vec, vec::Vec};
+use super::encode_key;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use crate::systems::asymmetric::traditional::ecc::EcdsaP256;
+use crate::systems::asymmetric::traditional::ecdh::EcdhP256;
+use core::fmt;
+use core::marker::PhantomData;
+use hkdf::Hkdf;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+/// The length in bytes of the shared secret.
+///
+/// 共享秘密的字节长度。
+pub const SHARED_SECRET_SIZE: usize = 32;
+
+/// The prefix `F` of the KDF input, which separates X3DH secrets from other uses of the keys.
+const KDF_PREFIX: [u8; 32] = [0xFF; 32];
+
+// ------------------- Keys -------------------
+// ------------------- 密钥 -------------------
+
+/// The public identity key of a party: its key agreement key and its signing key.
+///
+/// 一方的公开身份密钥：其密钥协商密钥和签名密钥。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct X3dhIdentityKey<K: AsymmetricKeySet, S: AsymmetricKeySet> {
+    /// The key agreement key `IK_dh`.
+    ///
+    /// 密钥协商密钥 `IK_dh`。
+    pub dh_key: K::PublicKey,
+    /// The key `IK_sig` that signs the prekeys.
+    ///
+    /// 对预密钥签名的密钥 `IK_sig`。
+    pub signing_key: S::PublicKey,
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> X3dhIdentityKey<K, S> {
+    /// Returns `Encode(IK_dh) | Encode(IK_sig)`, the encoding used in the associated data.
+    ///
+    /// 返回 `Encode(IK_dh) | Encode(IK_sig)`，即关联数据中使用的编码。
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut out = Vec::new();
+        encode_key(&mut out, &self.dh_key)?;
+        encode_key(&mut out, &self.signing_key)?;
+        Ok(out)
+    }
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> Clone for X3dhIdentityKey<K, S> {
+    fn clone(&self) -> Self {
+        Self {
+            dh_key: self.dh_key.clone(),
+            signing_key: self.signing_key.clone(),
+        }
+    }
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> fmt::Debug for X3dhIdentityKey<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X3dhIdentityKey").finish_non_exhaustive()
+    }
+}
+
+/// The long-term identity of a party, holding the private halves of its identity key.
+///
+/// 一方的长期身份，持有其身份密钥的私有部分。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct X3dhIdentity<K: AsymmetricKeySet, S: AsymmetricKeySet> {
+    dh: KeyPair<K>,
+    signing: KeyPair<S>,
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> X3dhIdentity<K, S> {
+    /// Bundles an existing key agreement key pair and signing key pair into an identity.
+    ///
+    /// 将已有的密钥协商密钥对和签名密钥对捆绑为一个身份。
+    pub fn from_parts(dh: KeyPair<K>, signing: KeyPair<S>) -> Self {
+        Self { dh, signing }
+    }
+
+    /// Returns the public identity key to publish.
+    ///
+    /// 返回要发布的公开身份密钥。
+    pub fn public_key(&self) -> X3dhIdentityKey<K, S> {
+        X3dhIdentityKey {
+            dh_key: self.dh.public_key().clone(),
+            signing_key: self.signing.public_key().clone(),
+        }
+    }
+
+    /// Returns the prekey bundle that publishes `signed_prekey` and, if given, `one_time_prekey`.
+    ///
+    /// 返回发布 `signed_prekey` 以及（如果给出）`one_time_prekey` 的预密钥包。
+    pub fn prekey_bundle(
+        &self,
+        signed_prekey: &X3dhSignedPrekey<K>,
+        one_time_prekey: Option<&X3dhOneTimePrekey<K>>,
+    ) -> X3dhPrekeyBundle<K, S> {
+        X3dhPrekeyBundle {
+            identity_key: self.public_key(),
+            signed_prekey_id: signed_prekey.id,
+            signed_prekey: signed_prekey.key_pair.public_key().clone(),
+            signed_prekey_signature: signed_prekey.signature.clone(),
+            one_time_prekey: one_time_prekey
+                .map(|prekey| (prekey.id, prekey.key_pair.public_key().clone())),
+        }
+    }
+}
+
+impl<K: AsymmetricKeySet, S: Signer> X3dhIdentity<K, S> {
+    /// Signs `key_pair` with the identity key, turning it into the signed prekey `id`.
+    ///
+    /// 使用身份密钥对 `key_pair` 签名，使其成为已签名预密钥 `id`。
+    pub fn sign_prekey(&self, id: u32, key_pair: KeyPair<K>) -> Result<X3dhSignedPrekey<K>, Error> {
+        let message = prekey_signature_message::<K>(self.dh.public_key(), key_pair.public_key())?;
+        let signature = self.signing.sign(&message)?;
+        Ok(X3dhSignedPrekey {
+            id,
+            key_pair,
+            signature,
+        })
+    }
+}
+
+impl<K: KeyGenerator, S: KeyGenerator + Signer> X3dhIdentity<K, S> {
+    /// Generates a new identity.
+    ///
+    /// 生成一个新的身份。
+    pub fn generate() -> Result<Self, Error> {
+        Self::generate_with_rng(&mut SystemRng::new())
+    }
+
+    /// Generates a new identity with the given RNG.
+    ///
+    /// 使用给定的 RNG 生成一个新的身份。
+    pub fn generate_with_rng<R: CryptoRngCore>(rng: &mut R) -> Result<Self, Error> {
+        let dh = KeyPair::from(K::generate_keypair_with_rng(rng)?);
+        let signing = KeyPair::from(S::generate_keypair_with_rng(rng)?);
+        Ok(Self { dh, signing })
+    }
+
+    /// Generates a new signed prekey with the identifier `id`.
+    ///
+    /// 生成一个标识符为 `id` 的新已签名预密钥。
+    pub fn generate_signed_prekey(&self, id: u32) -> Result<X3dhSignedPrekey<K>, Error> {
+        self.generate_signed_prekey_with_rng(id, &mut SystemRng::new())
+    }
+
+    /// Generates a new signed prekey with the identifier `id` and the given RNG.
+    ///
+    /// 使用给定的 RNG 生成一个标识符为 `id` 的新已签名预密钥。
+    pub fn generate_signed_prekey_with_rng<R: CryptoRngCore>(
+        &self,
+        id: u32,
+        rng: &mut R,
+    ) -> Result<X3dhSignedPrekey<K>, Error> {
+        let key_pair = KeyPair::from(K::generate_keypair_with_rng(rng)?);
+        self.sign_prekey(id, key_pair)
+    }
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> fmt::Debug for X3dhIdentity<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X3dhIdentity").finish_non_exhaustive()
+    }
+}
+
+/// A medium-term prekey signed by the identity key of its owner.
+///
+/// 由其所有者的身份密钥签名的中期预密钥。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct X3dhSignedPrekey<K: AsymmetricKeySet> {
+    id: u32,
+    key_pair: KeyPair<K>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    signature: Signature,
+}
+
+impl<K: AsymmetricKeySet> X3dhSignedPrekey<K> {
+    /// Returns the identifier of the prekey.
+    ///
+    /// 返回预密钥的标识符。
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the key pair of the prekey.
+    ///
+    /// 返回预密钥的密钥对。
+    pub fn key_pair(&self) -> &KeyPair<K> {
+        &self.key_pair
+    }
+
+    /// Returns the signature of the identity key over the prekey.
+    ///
+    /// 返回身份密钥对预密钥的签名。
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+}
+
+impl<K: AsymmetricKeySet> fmt::Debug for X3dhSignedPrekey<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X3dhSignedPrekey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A prekey that is used by at most one initial message and then deleted.
+///
+/// 最多被一条初始消息使用、随后即被删除的预密钥。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct X3dhOneTimePrekey<K: AsymmetricKeySet> {
+    id: u32,
+    key_pair: KeyPair<K>,
+}
+
+impl<K: AsymmetricKeySet> X3dhOneTimePrekey<K> {
+    /// Wraps an existing key pair as the one-time prekey `id`.
+    ///
+    /// 将已有的密钥对包装为一次性预密钥 `id`。
+    pub fn new(id: u32, key_pair: KeyPair<K>) -> Self {
+        Self { id, key_pair }
+    }
+
+    /// Returns the identifier of the prekey.
+    ///
+    /// 返回预密钥的标识符。
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the key pair of the prekey.
+    ///
+    /// 返回预密钥的密钥对。
+    pub fn key_pair(&self) -> &KeyPair<K> {
+        &self.key_pair
+    }
+}
+
+impl<K: KeyGenerator> X3dhOneTimePrekey<K> {
+    /// Generates a new one-time prekey with the identifier `id`.
+    ///
+    /// 生成一个标识符为 `id` 的新一次性预密钥。
+    pub fn generate(id: u32) -> Result<Self, Error> {
+        Self::generate_with_rng(id, &mut SystemRng::new())
+    }
+
+    /// Generates a new one-time prekey with the identifier `id` and the given RNG.
+    ///
+    /// 使用给定的 RNG 生成一个标识符为 `id` 的新一次性预密钥。
+    pub fn generate_with_rng<R: CryptoRngCore>(id: u32, rng: &mut R) -> Result<Self, Error> {
+        let key_pair = KeyPair::from(K::generate_keypair_with_rng(rng)?);
+        Ok(Self { id, key_pair })
+    }
+}
+
+impl<K: AsymmetricKeySet> fmt::Debug for X3dhOneTimePrekey<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X3dhOneTimePrekey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+// ------------------- Messages -------------------
+// ------------------- 消息 -------------------
+
+/// The prekeys a responder publishes so that initiators can reach it while it is offline.
+///
+/// 响应方发布的预密钥，使发起方能够在其离线时与其联系。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct X3dhPrekeyBundle<K: AsymmetricKeySet, S: AsymmetricKeySet> {
+    /// The identity key `IK_B` of the responder.
+    ///
+    /// 响应方的身份密钥 `IK_B`。
+    pub identity_key: X3dhIdentityKey<K, S>,
+    /// The identifier of the signed prekey.
+    ///
+    /// 已签名预密钥的标识符。
+    pub signed_prekey_id: u32,
+    /// The signed prekey `SPK_B`.
+    ///
+    /// 已签名预密钥 `SPK_B`。
+    pub signed_prekey: K::PublicKey,
+    /// The signature of `IK_B` over the signed prekey.
+    ///
+    /// `IK_B` 对已签名预密钥的签名。
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    pub signed_prekey_signature: Signature,
+    /// The identifier and public key of the one-time prekey `OPK_B`, if one is left.
+    ///
+    /// 一次性预密钥 `OPK_B` 的标识符和公钥（如果还有剩余）。
+    pub one_time_prekey: Option<(u32, K::PublicKey)>,
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> Clone for X3dhPrekeyBundle<K, S> {
+    fn clone(&self) -> Self {
+        Self {
+            identity_key: self.identity_key.clone(),
+            signed_prekey_id: self.signed_prekey_id,
+            signed_prekey: self.signed_prekey.clone(),
+            signed_prekey_signature: self.signed_prekey_signature.clone(),
+            one_time_prekey: self.one_time_prekey.clone(),
+        }
+    }
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> fmt::Debug for X3dhPrekeyBundle<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X3dhPrekeyBundle")
+            .field("signed_prekey_id", &self.signed_prekey_id)
+            .field(
+                "one_time_prekey_id",
+                &self.one_time_prekey.as_ref().map(|(id, _)| id),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+/// The message an initiator sends with its first ciphertext, naming the prekeys it used.
+///
+/// 发起方随第一个密文发送的消息，指明其使用的预密钥。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct X3dhInitialMessage<K: AsymmetricKeySet, S: AsymmetricKeySet> {
+    /// The identity key `IK_A` of the initiator.
+    ///
+    /// 发起方的身份密钥 `IK_A`。
+    pub identity_key: X3dhIdentityKey<K, S>,
+    /// The ephemeral key `EK_A` of the initiator.
+    ///
+    /// 发起方的临时密钥 `EK_A`。
+    pub ephemeral_key: K::PublicKey,
+    /// The identifier of the signed prekey the initiator used.
+    ///
+    /// 发起方使用的已签名预密钥的标识符。
+    pub signed_prekey_id: u32,
+    /// The identifier of the one-time prekey the initiator used, if any.
+    ///
+    /// 发起方使用的一次性预密钥的标识符（如果有）。
+    pub one_time_prekey_id: Option<u32>,
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> Clone for X3dhInitialMessage<K, S> {
+    fn clone(&self) -> Self {
+        Self {
+            identity_key: self.identity_key.clone(),
+            ephemeral_key: self.ephemeral_key.clone(),
+            signed_prekey_id: self.signed_prekey_id,
+            one_time_prekey_id: self.one_time_prekey_id,
+        }
+    }
+}
+
+impl<K: AsymmetricKeySet, S: AsymmetricKeySet> fmt::Debug for X3dhInitialMessage<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X3dhInitialMessage")
+            .field("signed_prekey_id", &self.signed_prekey_id)
+            .field("one_time_prekey_id", &self.one_time_prekey_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The result of X3DH, shared by both parties.
+///
+/// X3DH 的结果，由双方共享。
+#[derive(Debug)]
+pub struct X3dhSession {
+    /// The shared secret `SK`, the root secret of the session.
+    ///
+    /// 共享秘密 `SK`，即会话的根秘密。
+    pub shared_secret: SharedSecret,
+    /// The associated data `Encode(IK_A) | Encode(IK_B)`, to authenticate with every message
+    /// of the session.
+    ///
+    /// 关联数据 `Encode(IK_A) | Encode(IK_B)`，会话的每条消息都应对其进行认证。
+    pub associated_data: Vec<u8>,
+}
+
+// ------------------- Protocol -------------------
+// ------------------- 协议 -------------------
+
+/// X3DH over the key agreement scheme `K`, with identity keys signing prekeys with `S`.
+///
+/// 基于密钥协商方案 `K` 的 X3DH，身份密钥使用 `S` 对预密钥签名。
+pub struct X3dh<K, S> {
+    _marker: PhantomData<(K, S)>,
+}
+
+impl<K: KeyAgreement + KeyGenerator, S: Verifier> X3dh<K, S> {
+    /// Checks the signature of the responder's identity key over the signed prekey of `bundle`.
+    ///
+    /// [`X3dh::initiate`] calls this itself; it is exposed to reject bad bundles early, e.g.
+    /// when a server receives them.
+    ///
+    /// 检查响应方身份密钥对 `bundle` 中已签名预密钥的签名。
+    ///
+    /// [`X3dh::initiate`] 会自行调用它；将其公开是为了尽早拒绝错误的预密钥包，例如在服务器
+    /// 收到它们时。
+    pub fn verify_bundle(bundle: &X3dhPrekeyBundle<K, S>) -> Result<(), Error> {
+        let message =
+            prekey_signature_message::<K>(&bundle.identity_key.dh_key, &bundle.signed_prekey)?;
+        S::verify(
+            &bundle.identity_key.signing_key,
+            &message,
+            &bundle.signed_prekey_signature,
+        )
+    }
+
+    /// Starts a session with the owner of `bundle`, bound to the application context `info`.
+    ///
+    /// The prekey signature is checked before any key agreement, and a bad signature fails with
+    /// the error of `S`.
+    ///
+    /// # Returns
+    /// The initial message to send to the responder, and the session.
+    ///
+    /// 与 `bundle` 的所有者开始一个会话，并绑定到应用上下文 `info`。
+    ///
+    /// 预密钥签名在任何密钥协商之前被检查，错误的签名会以 `S` 的错误失败。
+    ///
+    /// # 返回
+    /// 要发送给响应方的初始消息，以及会话。
+    pub fn initiate(
+        identity: &X3dhIdentity<K, S>,
+        bundle: &X3dhPrekeyBundle<K, S>,
+        info: &[u8],
+    ) -> Result<(X3dhInitialMessage<K, S>, X3dhSession), Error> {
+        Self::initiate_with_rng(identity, bundle, info, &mut SystemRng::new())
+    }
+
+    /// Starts a session with the owner of `bundle` like [`X3dh::initiate`], with the given RNG
+    /// for the ephemeral key.
+    ///
+    /// 与 [`X3dh::initiate`] 一样与 `bundle` 的所有者开始一个会话，并使用给定的 RNG 生成临时密钥。
+    pub fn initiate_with_rng<R: CryptoRngCore>(
+        identity: &X3dhIdentity<K, S>,
+        bundle: &X3dhPrekeyBundle<K, S>,
+        info: &[u8],
+        rng: &mut R,
+    ) -> Result<(X3dhInitialMessage<K, S>, X3dhSession), Error> {
+        Self::verify_bundle(bundle)?;
+
+        let (ephemeral_key, ephemeral_private) = K::generate_keypair_with_rng(rng)?;
+        let mut secrets = vec![
+            identity.dh.agree(&bundle.signed_prekey)?,
+            K::agree(&ephemeral_private, &bundle.identity_key.dh_key)?,
+            K::agree(&ephemeral_private, &bundle.signed_prekey)?,
+        ];
+        if let Some((_, one_time_prekey)) = &bundle.one_time_prekey {
+            secrets.push(K::agree(&ephemeral_private, one_time_prekey)?);
+        }
+
+        let identity_key = identity.public_key();
+        let session = X3dhSession {
+            shared_secret: derive_shared_secret(&secrets, info)?,
+            associated_data: associated_data(&identity_key, &bundle.identity_key)?,
+        };
+        let message = X3dhInitialMessage {
+            identity_key,
+            ephemeral_key,
+            signed_prekey_id: bundle.signed_prekey_id,
+            one_time_prekey_id: bundle.one_time_prekey.as_ref().map(|(id, _)| *id),
+        };
+        Ok((message, session))
+    }
+
+    /// Completes the session started by `message`, bound to the application context `info`.
+    ///
+    /// `signed_prekey` and `one_time_prekey` must be the prekeys named by `message`, otherwise
+    /// this fails with [`KeyAgreementError::AgreementFailed`]. Delete the one-time prekey
+    /// afterwards, whether or not the first ciphertext decrypts.
+    ///
+    /// 完成由 `message` 开始的会话，并绑定到应用上下文 `info`。
+    ///
+    /// `signed_prekey` 和 `one_time_prekey` 必须是 `message` 指定的预密钥，否则会以
+    /// [`KeyAgreementError::AgreementFailed`] 失败。之后请删除该一次性预密钥，无论第一个密文
+    /// 能否解密。
+    pub fn respond(
+        identity: &X3dhIdentity<K, S>,
+        signed_prekey: &X3dhSignedPrekey<K>,
+        one_time_prekey: Option<&X3dhOneTimePrekey<K>>,
+        message: &X3dhInitialMessage<K, S>,
+        info: &[u8],
+    ) -> Result<X3dhSession, Error> {
+        let one_time_prekey = match (message.one_time_prekey_id, one_time_prekey) {
+            (None, None) => None,
+            (Some(id), Some(prekey)) if prekey.id == id => Some(prekey),
+            _ => return Err(Error::KeyAgreement(KeyAgreementError::AgreementFailed)),
+        };
+        if message.signed_prekey_id != signed_prekey.id {
+            return Err(Error::KeyAgreement(KeyAgreementError::AgreementFailed));
+        }
+
+        let mut secrets = vec![
+            signed_prekey.key_pair.agree(&message.identity_key.dh_key)?,
+            identity.dh.agree(&message.ephemeral_key)?,
+            signed_prekey.key_pair.agree(&message.ephemeral_key)?,
+        ];
+        if let Some(prekey) = one_time_prekey {
+            secrets.push(prekey.key_pair.agree(&message.ephemeral_key)?);
+        }
+
+        Ok(X3dhSession {
+            shared_secret: derive_shared_secret(&secrets, info)?,
+            associated_data: associated_data(&message.identity_key, &identity.public_key())?,
+        })
+    }
+}
+
+/// X3DH over ECDH P-256, with identity keys signing prekeys with ECDSA P-256.
+///
+/// 基于 ECDH P-256 的 X3DH，身份密钥使用 ECDSA P-256 对预密钥签名。
+pub type X3dhP256 = X3dh<EcdhP256, EcdsaP256>;
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// The message signed by the identity key: `Encode(IK_dh) | Encode(SPK)`.
+fn prekey_signature_message<K: AsymmetricKeySet>(
+    identity_key: &K::PublicKey,
+    prekey: &K::PublicKey,
+) -> Result<Vec<u8>, Error> {
+    let mut message = Vec::new();
+    encode_key(&mut message, identity_key)?;
+    encode_key(&mut message, prekey)?;
+    Ok(message)
+}
+
+/// The associated data `Encode(IK_A) | Encode(IK_B)`.
+fn associated_data<K: AsymmetricKeySet, S: AsymmetricKeySet>(
+    initiator: &X3dhIdentityKey<K, S>,
+    responder: &X3dhIdentityKey<K, S>,
+) -> Result<Vec<u8>, Error> {
+    let mut ad = initiator.to_bytes()?;
+    ad.extend_from_slice(&responder.to_bytes()?);
+    Ok(ad)
+}
+
+/// `SK = HKDF-SHA256(salt = 0^32, ikm = F | DH1 | DH2 | DH3 [| DH4], info)`.
+fn derive_shared_secret(secrets: &[SharedSecret], info: &[u8]) -> Result<SharedSecret, Error> {
+    let mut ikm = Zeroizing::new(KDF_PREFIX.to_vec());
+    for secret in secrets {
+        ikm.extend_from_slice(secret.expose_raw());
+    }
+    let mut okm = Zeroizing::new(vec![0u8; SHARED_SECRET_SIZE]);
+    Hkdf::<Sha256>::new(Some(&[0u8; 32]), &ikm)
+        .expand(info, &mut okm)
+        .map_err(|_| Error::KeyAgreement(KeyAgreementError::AgreementFailed))?;
+    Ok(SharedSecret::new(okm.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecc::Ed25519;
+    use crate::systems::test_rng::SeededRng;
+
+    const INFO: &[u8] = b"seal-crypto X3DH test";
+
+    /// The responder's keys: its identity, a signed prekey and one one-time prekey.
+    /// 响应方的密钥：其身份、一个已签名预密钥和一个一次性预密钥。
+    struct Responder<K: AsymmetricKeySet, S: AsymmetricKeySet> {
+        identity: X3dhIdentity<K, S>,
+        signed_prekey: X3dhSignedPrekey<K>,
+        one_time_prekey: X3dhOneTimePrekey<K>,
+    }
+
+    fn responder<K: KeyGenerator, S: KeyGenerator + Signer>(
+        rng: &mut SeededRng,
+    ) -> Result<Responder<K, S>, Error> {
+        let identity = X3dhIdentity::<K, S>::generate_with_rng(rng)?;
+        let signed_prekey = identity.generate_signed_prekey_with_rng(7, rng)?;
+        let one_time_prekey = X3dhOneTimePrekey::generate_with_rng(42, rng)?;
+        Ok(Responder {
+            identity,
+            signed_prekey,
+            one_time_prekey,
+        })
+    }
+
+    fn run_x3dh<K: KeyAgreement + KeyGenerator, S: SignatureScheme>(
+        use_one_time_prekey: bool,
+    ) -> Result<(), Error> {
+        let mut rng = SeededRng(1);
+        let bob = responder::<K, S>(&mut rng)?;
+        let alice = X3dhIdentity::<K, S>::generate_with_rng(&mut rng)?;
+        let one_time_prekey = use_one_time_prekey.then_some(&bob.one_time_prekey);
+
+        let bundle = bob
+            .identity
+            .prekey_bundle(&bob.signed_prekey, one_time_prekey);
+        let (message, alice_session) = X3dh::initiate_with_rng(&alice, &bundle, INFO, &mut rng)?;
+        assert_eq!(message.signed_prekey_id, 7);
+        assert_eq!(
+            message.one_time_prekey_id,
+            use_one_time_prekey.then_some(42)
+        );
+
+        let bob_session = X3dh::respond(
+            &bob.identity,
+            &bob.signed_prekey,
+            one_time_prekey,
+            &message,
+            INFO,
+        )?;
+        assert_eq!(alice_session.shared_secret, bob_session.shared_secret);
+        assert_eq!(
+            alice_session.shared_secret.expose_raw().len(),
+            SHARED_SECRET_SIZE
+        );
+        assert_eq!(alice_session.associated_data, bob_session.associated_data);
+
+        let mut expected_ad = alice.public_key().to_bytes()?;
+        expected_ad.extend_from_slice(&bob.identity.public_key().to_bytes()?);
+        assert_eq!(alice_session.associated_data, expected_ad);
+
+        // Another application context gives another secret.
+        // 另一个应用上下文会得到另一个秘密。
+        let other = X3dh::respond(
+            &bob.identity,
+            &bob.signed_prekey,
+            one_time_prekey,
+            &message,
+            b"other",
+        )?;
+        assert_ne!(other.shared_secret, alice_session.shared_secret);
+        Ok(())
+    }
+
+    #[test]
+    fn test_x3dh_p256() -> Result<(), Error> {
+        run_x3dh::<EcdhP256, EcdsaP256>(true)?;
+        run_x3dh::<EcdhP256, EcdsaP256>(false)
+    }
+
+    #[test]
+    fn test_x3dh_ed25519_signatures() -> Result<(), Error> {
+        run_x3dh::<EcdhP256, Ed25519>(true)?;
+        run_x3dh::<EcdhP256, Ed25519>(false)
+    }
+
+    #[test]
+    fn test_one_time_prekey_changes_secret() -> Result<(), Error> {
+        let mut rng = SeededRng(2);
+        let bob = responder::<EcdhP256, EcdsaP256>(&mut rng)?;
+        let alice = X3dhIdentity::generate_with_rng(&mut rng)?;
+
+        let with = bob
+            .identity
+            .prekey_bundle(&bob.signed_prekey, Some(&bob.one_time_prekey));
+        let (message, session) = X3dhP256::initiate_with_rng(&alice, &with, INFO, &mut rng)?;
+
+        // Dropping the one-time prekey from the message changes the secret of the responder.
+        // 从消息中去掉一次性预密钥会改变响应方的秘密。
+        let mut stripped = message.clone();
+        stripped.one_time_prekey_id = None;
+        let bob_session =
+            X3dhP256::respond(&bob.identity, &bob.signed_prekey, None, &stripped, INFO)?;
+        assert_ne!(bob_session.shared_secret, session.shared_secret);
+        Ok(())
+    }
+
+    #[test]
+    fn test_forged_prekey_rejected() -> Result<(), Error> {
+        let mut rng = SeededRng(3);
+        let bob = responder::<EcdhP256, EcdsaP256>(&mut rng)?;
+        let mallory = responder::<EcdhP256, EcdsaP256>(&mut rng)?;
+        let alice = X3dhIdentity::generate_with_rng(&mut rng)?;
+        let bundle = bob
+            .identity
+            .prekey_bundle(&bob.signed_prekey, Some(&bob.one_time_prekey));
+        assert!(X3dhP256::verify_bundle(&bundle).is_ok());
+
+        // A prekey substituted by an attacker.
+        // 被攻击者替换的预密钥。
+        let mut forged = bundle.clone();
+        forged.signed_prekey = mallory.signed_prekey.key_pair().public_key().clone();
+        assert!(matches!(
+            X3dhP256::initiate_with_rng(&alice, &forged, INFO, &mut rng),
+            Err(Error::Signature(_))
+        ));
+
+        // A prekey signed by another identity.
+        // 由另一个身份签名的预密钥。
+        let mut forged = bundle.clone();
+        forged.signed_prekey = mallory.signed_prekey.key_pair().public_key().clone();
+        forged.signed_prekey_signature = mallory.signed_prekey.signature().clone();
+        assert!(matches!(
+            X3dhP256::initiate_with_rng(&alice, &forged, INFO, &mut rng),
+            Err(Error::Signature(_))
+        ));
+
+        // A valid signed prekey moved under another identity key.
+        // 被移动到另一个身份密钥下的有效已签名预密钥。
+        let mut forged = bundle.clone();
+        forged.identity_key.dh_key = mallory.identity.public_key().dh_key;
+        assert!(matches!(
+            X3dhP256::initiate_with_rng(&alice, &forged, INFO, &mut rng),
+            Err(Error::Signature(_))
+        ));
+
+        let mut forged = bundle;
+        forged.signed_prekey_signature.clear();
+        assert!(X3dhP256::initiate_with_rng(&alice, &forged, INFO, &mut rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrong_prekeys_rejected() -> Result<(), Error> {
+        let mut rng = SeededRng(4);
+        let bob = responder::<EcdhP256, EcdsaP256>(&mut rng)?;
+        let alice = X3dhIdentity::generate_with_rng(&mut rng)?;
+        let bundle = bob
+            .identity
+            .prekey_bundle(&bob.signed_prekey, Some(&bob.one_time_prekey));
+        let (message, _) = X3dhP256::initiate_with_rng(&alice, &bundle, INFO, &mut rng)?;
+
+        let agreement_failed = |result: Result<X3dhSession, Error>| {
+            matches!(
+                result,
+                Err(Error::KeyAgreement(KeyAgreementError::AgreementFailed))
+            )
+        };
+
+        // Missing one-time prekey.
+        // 缺少一次性预密钥。
+        assert!(agreement_failed(X3dhP256::respond(
+            &bob.identity,
+            &bob.signed_prekey,
+            None,
+            &message,
+            INFO
+        )));
+
+        // Another one-time prekey.
+        // 另一个一次性预密钥。
+        let other = X3dhOneTimePrekey::<EcdhP256>::generate_with_rng(43, &mut rng)?;
+        assert!(agreement_failed(X3dhP256::respond(
+            &bob.identity,
+            &bob.signed_prekey,
+            Some(&other),
+            &message,
+            INFO
+        )));
+
+        // Another signed prekey.
+        // 另一个已签名预密钥。
+        let rotated = bob.identity.generate_signed_prekey_with_rng(8, &mut rng)?;
+        assert!(agreement_failed(X3dhP256::respond(
+            &bob.identity,
+            &rotated,
+            Some(&bob.one_time_prekey),
+            &message,
+            INFO
+        )));
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<(), Error> {
+        let mut rng = SeededRng(5);
+        let bob = responder::<EcdhP256, EcdsaP256>(&mut rng)?;
+        let alice = X3dhIdentity::generate_with_rng(&mut rng)?;
+
+        // The bundle, the initial message and the responder's keys survive serialization.
+        // 预密钥包、初始消息和响应方的密钥在序列化后保持不变。
+        let bundle = bob
+            .identity
+            .prekey_bundle(&bob.signed_prekey, Some(&bob.one_time_prekey));
+        let bundle: X3dhPrekeyBundle<EcdhP256, EcdsaP256> =
+            serde_json::from_str(&serde_json::to_string(&bundle).unwrap_or_default())
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        let (message, session) = X3dhP256::initiate_with_rng(&alice, &bundle, INFO, &mut rng)?;
+        let message: X3dhInitialMessage<EcdhP256, EcdsaP256> =
+            serde_json::from_str(&serde_json::to_string(&message).unwrap_or_default())
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+
+        let identity: X3dhIdentity<EcdhP256, EcdsaP256> =
+            serde_json::from_str(&serde_json::to_string(&bob.identity).unwrap_or_default())
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        let signed_prekey: X3dhSignedPrekey<EcdhP256> =
+            serde_json::from_str(&serde_json::to_string(&bob.signed_prekey).unwrap_or_default())
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        let one_time_prekey: X3dhOneTimePrekey<EcdhP256> =
+            serde_json::from_str(&serde_json::to_string(&bob.one_time_prekey).unwrap_or_default())
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+
+        let bob_session = X3dhP256::respond(
+            &identity,
+            &signed_prekey,
+            Some(&one_time_prekey),
+            &message,
+            INFO,
+        )?;
+        assert_eq!(bob_session.shared_secret, session.shared_secret);
+        Ok(())
+    }
+}
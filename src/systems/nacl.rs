@@ -0,0 +1,29 @@
+//! NaCl and libsodium compatible constructions.
+//!
+//! This module provides constructions whose messages are byte-compatible with NaCl, TweetNaCl
+//! and libsodium, for exchanging data with peers that use those libraries.
+//!
+//! # Available Implementations
+//! - **crypto_box**: X25519-XSalsa20-Poly1305 public-key authenticated encryption
+//! - **sealed_box**: Anonymous public-key encryption built on crypto_box
+//!
+//! 与 NaCl 和 libsodium 兼容的构造。
+//!
+//! 此模块提供其消息与 NaCl、TweetNaCl 和 libsodium 字节兼容的构造，用于与使用这些库的对端交换
+//! 数据。
+//!
+//! # 可用实现
+//! - **crypto_box**: X25519-XSalsa20-Poly1305 公钥认证加密
+//! - **sealed_box**: 基于 crypto_box 的匿名公钥加密
+
+/// NaCl crypto_box implementation.
+///
+/// NaCl crypto_box 实现。
+#[cfg(feature = "crypto-box-default")]
+pub mod crypto_box;
+
+/// libsodium sealed box implementation.
+///
+/// libsodium 密封盒实现。
+#[cfg(feature = "sealed-box-default")]
+pub mod sealed_box;
@@ -0,0 +1,443 @@
+//! Provides NaCl's crypto_box, X25519-XSalsa20-Poly1305 public-key authenticated encryption.
+//!
+//! A box is encrypted by one key pair to another: both parties derive the same key from their
+//! own secret key and the peer's public key, so that a box proves it was sealed by the holder of
+//! the sender's secret key, to anyone holding the recipient's secret key.
+//!
+//! # Compatibility
+//! The boxes are byte-compatible with libsodium's `crypto_box_easy`, i.e. the 16-byte Poly1305
+//! tag followed by the ciphertext, which is also the output of NaCl's and TweetNaCl's
+//! `crypto_box` without its 16 leading zero bytes. [`CryptoBox::new`] is `crypto_box_beforenm`,
+//! and [`CryptoBox::precomputed_key`] and [`CryptoBox::from_precomputed_key`] exchange its
+//! result with the `_afternm` functions of those libraries.
+//!
+//! # Security Considerations
+//! - Never reuse a nonce with the same pair of keys; use [`CryptoBox::generate_nonce`] or a
+//!   counter that is never reset, and send the nonce along with the box.
+//! - Either party can seal boxes for the other, so a box does not prove which of the two sealed
+//!   it; include the sender in the message if both directions are used.
+//! - As in libsodium, key pairs whose shared point is the identity are rejected.
+//! - XSalsa20-Poly1305 is not a FIPS approved algorithm, so it is not available with the `fips`
+//!   feature.
+//!
+//! 提供了 NaCl 的 crypto_box，即 X25519-XSalsa20-Poly1305 公钥认证加密。
+//!
+//! 一个 box 由一个密钥对加密给另一个密钥对：双方从自己的私钥和对方的公钥派生出相同的密钥，因此
+//! 一个 box 能向任何持有接收方私钥的人证明它是由发送方私钥的持有者封装的。
+//!
+//! # 兼容性
+//! box 与 libsodium 的 `crypto_box_easy` 字节兼容，即 16 字节的 Poly1305 标签后跟密文，这也是
+//! NaCl 和 TweetNaCl 的 `crypto_box` 去掉开头 16 个零字节后的输出。[`CryptoBox::new`] 即
+//! `crypto_box_beforenm`，[`CryptoBox::precomputed_key`] 和 [`CryptoBox::from_precomputed_key`]
+//! 用于与这些库的 `_afternm` 函数交换其结果。
+//!
+//! # 安全考虑
+//! - 切勿对同一对密钥重复使用 nonce；请使用 [`CryptoBox::generate_nonce`] 或永不重置的计数器，
+//!   并将 nonce 与 box 一起发送。
+//! - 任何一方都可以为另一方封装 box，因此 box 无法证明是双方中的哪一方封装的；如果双向都会使用，
+//!   请在消息中包含发送方。
+//! - 与 libsodium 一样，共享点为单位元的密钥对会被拒绝。
+//! - XSalsa20-Poly1305 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::{vec, vec::Vec};
+use crate::ct::ConstantTimeEq;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::{NonceRng, SystemRng};
+use crate::systems::kdf::subkey::{INPUT_SIZE, hsalsa20};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use poly1305::Poly1305;
+use poly1305::universal_hash::KeyInit;
+use salsa20::XSalsa20;
+use salsa20::cipher::{KeyIvInit, StreamCipher};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+/// The length in bytes of a public key.
+///
+/// 公钥的字节长度。
+pub const PUBLIC_KEY_SIZE: usize = 32;
+
+/// The length in bytes of a secret key.
+///
+/// 私钥的字节长度。
+pub const SECRET_KEY_SIZE: usize = 32;
+
+/// The length in bytes of a precomputed key.
+///
+/// 预计算密钥的字节长度。
+pub const PRECOMPUTED_KEY_SIZE: usize = 32;
+
+/// The length in bytes of a nonce.
+///
+/// nonce 的字节长度。
+pub const NONCE_SIZE: usize = 24;
+
+/// The length in bytes of the Poly1305 tag that precedes the ciphertext.
+///
+/// 位于密文之前的 Poly1305 标签的字节长度。
+pub const MAC_SIZE: usize = 16;
+
+/// A crypto_box nonce.
+///
+/// crypto_box 的 nonce。
+pub type CryptoBoxNonce = [u8; NONCE_SIZE];
+
+// ------------------- Keys -------------------
+// ------------------- 密钥 -------------------
+
+/// An X25519 public key.
+///
+/// X25519 公钥。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CryptoBoxPublicKey([u8; PUBLIC_KEY_SIZE]);
+
+impl CryptoBoxPublicKey {
+    /// Parses a 32-byte public key.
+    ///
+    /// 解析一个 32 字节的公钥。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self(
+            bytes
+                .try_into()
+                .map_err(|_| Error::Key(KeyError::InvalidLength))?,
+        ))
+    }
+
+    /// Returns the 32 bytes of the public key.
+    ///
+    /// 返回公钥的 32 个字节。
+    pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_SIZE] {
+        &self.0
+    }
+}
+
+/// An X25519 secret key.
+///
+/// X25519 私钥。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CryptoBoxSecretKey(Zeroizing<[u8; SECRET_KEY_SIZE]>);
+
+impl CryptoBoxSecretKey {
+    /// Generates a new secret key.
+    ///
+    /// 生成一个新的私钥。
+    pub fn generate() -> Self {
+        Self::generate_with_rng(&mut SystemRng::new())
+    }
+
+    /// Generates a new secret key with the given RNG.
+    ///
+    /// 使用给定的 RNG 生成一个新的私钥。
+    pub fn generate_with_rng<R: CryptoRngCore>(rng: &mut R) -> Self {
+        let mut secret = Zeroizing::new([0u8; SECRET_KEY_SIZE]);
+        rng.fill_bytes(secret.as_mut());
+        Self(secret)
+    }
+
+    /// Parses a 32-byte secret key, e.g. one generated by `crypto_box_keypair`.
+    ///
+    /// 解析一个 32 字节的私钥，例如由 `crypto_box_keypair` 生成的私钥。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self(Zeroizing::new(
+            bytes
+                .try_into()
+                .map_err(|_| Error::Key(KeyError::InvalidLength))?,
+        )))
+    }
+
+    /// Returns the 32 bytes of the secret key.
+    ///
+    /// 返回私钥的 32 个字节。
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.0.to_vec())
+    }
+
+    /// Returns the public key, `crypto_scalarmult_base` of the secret key.
+    ///
+    /// 返回公钥，即私钥的 `crypto_scalarmult_base`。
+    pub fn public_key(&self) -> CryptoBoxPublicKey {
+        CryptoBoxPublicKey(MontgomeryPoint::mul_base_clamped(*self.0).to_bytes())
+    }
+}
+
+impl core::fmt::Debug for CryptoBoxSecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CryptoBoxSecretKey").finish_non_exhaustive()
+    }
+}
+
+// ------------------- Box -------------------
+// ------------------- Box -------------------
+
+/// A crypto_box between one party's secret key and the other's public key, ready to seal and
+/// open boxes.
+///
+/// 由一方的私钥和另一方的公钥建立的 crypto_box，可用于封装和打开 box。
+#[derive(Clone)]
+pub struct CryptoBox {
+    key: Zeroizing<[u8; PRECOMPUTED_KEY_SIZE]>,
+}
+
+impl CryptoBox {
+    /// Derives the key shared by `secret_key` and `public_key` (`crypto_box_beforenm`).
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if the shared point is the
+    /// identity, e.g. because `public_key` has a small order.
+    ///
+    /// 派生 `secret_key` 和 `public_key` 共享的密钥（`crypto_box_beforenm`）。
+    ///
+    /// 如果共享点为单位元（例如因为 `public_key` 的阶很小），则以
+    /// `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn new(
+        public_key: &CryptoBoxPublicKey,
+        secret_key: &CryptoBoxSecretKey,
+    ) -> Result<Self, Error> {
+        let shared = Zeroizing::new(
+            MontgomeryPoint(public_key.0)
+                .mul_clamped(*secret_key.0)
+                .to_bytes(),
+        );
+        if bool::from(shared.as_slice().ct_eq(&[0u8; 32])) {
+            return Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey));
+        }
+        let mut key = Zeroizing::new([0u8; PRECOMPUTED_KEY_SIZE]);
+        key.copy_from_slice(&hsalsa20(&shared, &[0u8; INPUT_SIZE]));
+        Ok(Self { key })
+    }
+
+    /// Restores a box from a key returned by [`CryptoBox::precomputed_key`] or
+    /// `crypto_box_beforenm`.
+    ///
+    /// 从 [`CryptoBox::precomputed_key`] 或 `crypto_box_beforenm` 返回的密钥恢复一个 box。
+    pub fn from_precomputed_key(key: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            key: Zeroizing::new(
+                key.try_into()
+                    .map_err(|_| Error::Key(KeyError::InvalidLength))?,
+            ),
+        })
+    }
+
+    /// Returns the precomputed key, which must be kept as secret as the secret key.
+    ///
+    /// 返回预计算密钥，它必须像私钥一样保密。
+    pub fn precomputed_key(&self) -> &[u8; PRECOMPUTED_KEY_SIZE] {
+        &self.key
+    }
+
+    /// Generates a random nonce.
+    ///
+    /// 生成一个随机 nonce。
+    pub fn generate_nonce() -> CryptoBoxNonce {
+        Self::generate_nonce_with_rng(&mut NonceRng)
+    }
+
+    /// Generates a random nonce with the given RNG.
+    ///
+    /// 使用给定的 RNG 生成一个随机 nonce。
+    pub fn generate_nonce_with_rng<R: CryptoRngCore>(rng: &mut R) -> CryptoBoxNonce {
+        let mut nonce = [0u8; NONCE_SIZE];
+        rng.fill_bytes(&mut nonce);
+        nonce
+    }
+
+    /// Seals `plaintext` under `nonce` (`crypto_box_easy_afternm`), returning the tag followed
+    /// by the ciphertext.
+    ///
+    /// 使用 `nonce` 封装 `plaintext`（`crypto_box_easy_afternm`），返回标签及其后的密文。
+    pub fn seal(&self, nonce: &CryptoBoxNonce, plaintext: &[u8]) -> Vec<u8> {
+        secretbox_seal(&self.key, nonce, plaintext)
+    }
+
+    /// Opens a box sealed under `nonce` (`crypto_box_open_easy_afternm`).
+    ///
+    /// Fails with `SymmetricError::Decryption` if the box was not sealed with this key and
+    /// nonce, or was modified.
+    ///
+    /// 打开一个使用 `nonce` 封装的 box（`crypto_box_open_easy_afternm`）。
+    ///
+    /// 如果该 box 不是使用此密钥和 nonce 封装的，或已被修改，则以 `SymmetricError::Decryption`
+    /// 失败。
+    pub fn open(&self, nonce: &CryptoBoxNonce, sealed: &[u8]) -> Result<Vec<u8>, Error> {
+        secretbox_open(&self.key, nonce, sealed)
+    }
+}
+
+impl core::fmt::Debug for CryptoBox {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CryptoBox").finish_non_exhaustive()
+    }
+}
+
+// ------------------- XSalsa20-Poly1305 -------------------
+// ------------------- XSalsa20-Poly1305 -------------------
+
+/// Starts the XSalsa20 keystream of `key` and `nonce`, returning the cipher positioned after
+/// the first 32 bytes and the Poly1305 key made of those bytes.
+fn secretbox_cipher(
+    key: &[u8; PRECOMPUTED_KEY_SIZE],
+    nonce: &CryptoBoxNonce,
+) -> (XSalsa20, Poly1305) {
+    let mut cipher = XSalsa20::new(key.into(), nonce.into());
+    let mut mac_key = Zeroizing::new([0u8; 32]);
+    cipher.apply_keystream(mac_key.as_mut());
+    let mac = Poly1305::new(mac_key.as_ref().into());
+    (cipher, mac)
+}
+
+/// `crypto_secretbox_easy`: the Poly1305 tag followed by the XSalsa20 ciphertext.
+pub(crate) fn secretbox_seal(
+    key: &[u8; PRECOMPUTED_KEY_SIZE],
+    nonce: &CryptoBoxNonce,
+    plaintext: &[u8],
+) -> Vec<u8> {
+    let (mut cipher, mac) = secretbox_cipher(key, nonce);
+    let mut sealed = vec![0u8; MAC_SIZE + plaintext.len()];
+    let (tag, ciphertext) = sealed.split_at_mut(MAC_SIZE);
+    ciphertext.copy_from_slice(plaintext);
+    cipher.apply_keystream(ciphertext);
+    tag.copy_from_slice(&mac.compute_unpadded(ciphertext));
+    sealed
+}
+
+/// `crypto_secretbox_open_easy`, checking the tag before decrypting.
+pub(crate) fn secretbox_open(
+    key: &[u8; PRECOMPUTED_KEY_SIZE],
+    nonce: &CryptoBoxNonce,
+    sealed: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let (tag, ciphertext) = sealed
+        .split_at_checked(MAC_SIZE)
+        .ok_or(Error::Symmetric(SymmetricError::Decryption))?;
+    let (mut cipher, mac) = secretbox_cipher(key, nonce);
+    let expected = mac.compute_unpadded(ciphertext);
+    if !bool::from(expected.as_slice().ct_eq(tag)) {
+        return Err(Error::Symmetric(SymmetricError::Decryption));
+    }
+    let mut plaintext = ciphertext.to_vec();
+    cipher.apply_keystream(&mut plaintext);
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    fn decode(hex: &str) -> Vec<u8> {
+        hex::decode(hex).unwrap_or_default()
+    }
+
+    // The crypto_box test of NaCl (tests/box.c, box2.c, scalarmult5.c and core1.c).
+    // NaCl 的 crypto_box 测试（tests/box.c、box2.c、scalarmult5.c 和 core1.c）。
+    const ALICE_SK: &str = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+    const ALICE_PK: &str = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+    const BOB_SK: &str = "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb";
+    const BOB_PK: &str = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+    const PRECOMPUTED: &str = "1b27556473e985d462cd51197a9a46c76009549eac6474f206c4ee0844f68389";
+    const NONCE: &str = "69696ee955b62b73cd62bda875fc73d68219e0036b7a0b37";
+    const MESSAGE: &str = "be075fc53c81f2d5cf141316ebeb0c7b5228c52a4c62cbd44b66849b64244ffc\
+        e5ecbaaf33bd751a1ac728d45e6c61296cdc3c01233561f41db66cce314adb31\
+        0e3be8250c46f06dceea3a7fa1348057e2f6556ad6b1318a024a838f21af1fde\
+        048977eb48f59ffd4924ca1c60902e52f0a089bc76897040e082f93776384864\
+        5e0705";
+    const SEALED: &str = "f3ffc7703f9400e52a7dfb4b3d3305d98e993b9f48681273c29650ba32fc76ce\
+        48332ea7164d96a4476fb8c531a1186ac0dfc17c98dce87b4da7f011ec48c972\
+        71d2c20f9b928fe2270d6fb863d51738b48eeee314a7cc8ab932164548e526ae\
+        90224368517acfeabd6bb3732bc0e9da99832b61ca01b6de56244a9e88d5f9b3\
+        7973f622a43d14a6599b1f654cb45a74e355a5";
+
+    #[test]
+    fn test_nacl_vector() -> Result<(), Error> {
+        let alice = CryptoBoxSecretKey::from_bytes(&decode(ALICE_SK))?;
+        let bob = CryptoBoxSecretKey::from_bytes(&decode(BOB_SK))?;
+        assert_eq!(alice.public_key().as_bytes().to_vec(), decode(ALICE_PK));
+        assert_eq!(bob.public_key().as_bytes().to_vec(), decode(BOB_PK));
+
+        let alice_box = CryptoBox::new(&bob.public_key(), &alice)?;
+        let bob_box = CryptoBox::new(&alice.public_key(), &bob)?;
+        assert_eq!(alice_box.precomputed_key().to_vec(), decode(PRECOMPUTED));
+        assert_eq!(bob_box.precomputed_key().to_vec(), decode(PRECOMPUTED));
+
+        let nonce: CryptoBoxNonce = decode(NONCE)
+            .try_into()
+            .map_err(|_| Error::Key(KeyError::InvalidLength))?;
+        assert_eq!(alice_box.seal(&nonce, &decode(MESSAGE)), decode(SEALED));
+        assert_eq!(bob_box.open(&nonce, &decode(SEALED))?, decode(MESSAGE));
+        Ok(())
+    }
+
+    #[test]
+    fn test_roundtrip() -> Result<(), Error> {
+        let mut rng = SeededRng(1);
+        let alice = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+        let bob = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+        let alice_box = CryptoBox::new(&bob.public_key(), &alice)?;
+        let bob_box = CryptoBox::from_precomputed_key(
+            CryptoBox::new(&alice.public_key(), &bob)?.precomputed_key(),
+        )?;
+
+        for len in [0, 1, 63, 64, 65, 1000] {
+            let nonce = CryptoBox::generate_nonce_with_rng(&mut rng);
+            let message = vec![0x5a; len];
+            let sealed = alice_box.seal(&nonce, &message);
+            assert_eq!(sealed.len(), MAC_SIZE + len);
+            assert_eq!(bob_box.open(&nonce, &sealed)?, message);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_tampering() -> Result<(), Error> {
+        let mut rng = SeededRng(2);
+        let alice = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+        let bob = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+        let eve = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+        let alice_box = CryptoBox::new(&bob.public_key(), &alice)?;
+        let bob_box = CryptoBox::new(&alice.public_key(), &bob)?;
+        let nonce = CryptoBox::generate_nonce_with_rng(&mut rng);
+        let sealed = alice_box.seal(&nonce, b"attack at dawn");
+        let failed = Err(Error::Symmetric(SymmetricError::Decryption));
+
+        for index in [0, MAC_SIZE, sealed.len() - 1] {
+            let mut tampered = sealed.clone();
+            tampered[index] ^= 1;
+            assert_eq!(bob_box.open(&nonce, &tampered), failed);
+        }
+        let mut other_nonce = nonce;
+        other_nonce[23] ^= 1;
+        assert_eq!(bob_box.open(&other_nonce, &sealed), failed);
+        assert_eq!(bob_box.open(&nonce, &sealed[..MAC_SIZE - 1]), failed);
+
+        // A box between other keys does not open.
+        // 由其他密钥建立的 box 无法打开。
+        let eve_box = CryptoBox::new(&alice.public_key(), &eve)?;
+        assert_eq!(eve_box.open(&nonce, &sealed), failed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        let secret = CryptoBoxSecretKey::generate_with_rng(&mut SeededRng(3));
+
+        // Points of small order give an all-zero shared point and are rejected.
+        // 小阶点会得到全零的共享点，因此被拒绝。
+        for point in [[0u8; 32], {
+            let mut one = [0u8; 32];
+            one[0] = 1;
+            one
+        }] {
+            assert_eq!(
+                CryptoBox::new(&CryptoBoxPublicKey(point), &secret).err(),
+                Some(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+            );
+        }
+        assert!(CryptoBoxPublicKey::from_bytes(&[0u8; 31]).is_err());
+        assert!(CryptoBoxSecretKey::from_bytes(&[0u8; 33]).is_err());
+        assert!(CryptoBox::from_precomputed_key(&[0u8; 16]).is_err());
+    }
+}
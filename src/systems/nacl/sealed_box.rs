@@ -0,0 +1,214 @@
+//! Provides libsodium's sealed boxes, anonymous public-key encryption built on crypto_box.
+//!
+//! A sealed box is encrypted to a public key with a fresh ephemeral key pair whose secret key is
+//! discarded right after sealing, so only the holder of the recipient's secret key can open it
+//! and nothing in the box identifies the sender.
+//!
+//! # Compatibility
+//! The boxes are byte-compatible with libsodium's `crypto_box_seal`, and therefore with the
+//! bindings built on it such as sodium.js and libsodium.js: the ephemeral public key followed by
+//! the crypto_box of the message, under the nonce `BLAKE2b-192(ephemeral_pk || recipient_pk)`.
+//!
+//! # Security Considerations
+//! - A sealed box does not authenticate its sender; anyone who knows the recipient's public key
+//!   can produce one. Use [`crate::systems::nacl::crypto_box`] or sign the message if the
+//!   recipient must know who sent it.
+//! - The sender cannot decrypt a box after sealing it.
+//! - XSalsa20-Poly1305 is not a FIPS approved algorithm, so it is not available with the `fips`
+//!   feature.
+//!
+//! 提供了 libsodium 的密封盒，即基于 crypto_box 的匿名公钥加密。
+//!
+//! 密封盒使用一个新的临时密钥对加密给某个公钥，该临时私钥在封装后立即丢弃，因此只有接收方私钥
+//! 的持有者能够打开它，而且盒中没有任何内容能识别发送方。
+//!
+//! # 兼容性
+//! 密封盒与 libsodium 的 `crypto_box_seal` 字节兼容，因此也与基于它的绑定（如 sodium.js 和
+//! libsodium.js）兼容：临时公钥后跟消息的 crypto_box，其 nonce 为
+//! `BLAKE2b-192(ephemeral_pk || recipient_pk)`。
+//!
+//! # 安全考虑
+//! - 密封盒不认证发送方；任何知道接收方公钥的人都可以生成一个。如果接收方必须知道发送者，请使用
+//!   [`crate::systems::nacl::crypto_box`] 或对消息签名。
+//! - 发送方在封装后无法解密该盒。
+//! - XSalsa20-Poly1305 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use crate::systems::nacl::crypto_box::{
+    CryptoBox, CryptoBoxNonce, CryptoBoxPublicKey, CryptoBoxSecretKey, MAC_SIZE, NONCE_SIZE,
+    PUBLIC_KEY_SIZE,
+};
+
+/// The number of bytes a sealed box adds to its message (`crypto_box_SEALBYTES`).
+///
+/// 密封盒在其消息之外增加的字节数（`crypto_box_SEALBYTES`）。
+pub const SEAL_OVERHEAD: usize = PUBLIC_KEY_SIZE + MAC_SIZE;
+
+/// libsodium's anonymous sealed boxes.
+///
+/// libsodium 的匿名密封盒。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SealedBox;
+
+impl SealedBox {
+    /// Seals `plaintext` to `recipient` (`crypto_box_seal`).
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if `recipient` is a point of small
+    /// order.
+    ///
+    /// 将 `plaintext` 封装给 `recipient`（`crypto_box_seal`）。
+    ///
+    /// 如果 `recipient` 是小阶点，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn seal(plaintext: &[u8], recipient: &CryptoBoxPublicKey) -> Result<Vec<u8>, Error> {
+        Self::seal_with_rng(plaintext, recipient, &mut SystemRng::new())
+    }
+
+    /// Seals `plaintext` to `recipient` with the given RNG.
+    ///
+    /// 使用给定的 RNG 将 `plaintext` 封装给 `recipient`。
+    pub fn seal_with_rng<R: CryptoRngCore>(
+        plaintext: &[u8],
+        recipient: &CryptoBoxPublicKey,
+        rng: &mut R,
+    ) -> Result<Vec<u8>, Error> {
+        let ephemeral = CryptoBoxSecretKey::generate_with_rng(rng);
+        seal_with_ephemeral(plaintext, recipient, &ephemeral)
+    }
+
+    /// Opens a sealed box with the recipient's secret key (`crypto_box_seal_open`).
+    ///
+    /// Fails with `SymmetricError::Decryption` if the box was not sealed to this key, was
+    /// modified or is too short.
+    ///
+    /// 使用接收方私钥打开一个密封盒（`crypto_box_seal_open`）。
+    ///
+    /// 如果该盒不是封装给此密钥的、已被修改或过短，则以 `SymmetricError::Decryption` 失败。
+    pub fn open(sealed: &[u8], secret_key: &CryptoBoxSecretKey) -> Result<Vec<u8>, Error> {
+        if sealed.len() < SEAL_OVERHEAD {
+            return Err(Error::Symmetric(SymmetricError::Decryption));
+        }
+        let (ephemeral, boxed) = sealed.split_at(PUBLIC_KEY_SIZE);
+        let ephemeral = CryptoBoxPublicKey::from_bytes(ephemeral)?;
+        let nonce = seal_nonce(&ephemeral, &secret_key.public_key());
+        CryptoBox::new(&ephemeral, secret_key)
+            .map_err(|_| Error::Symmetric(SymmetricError::Decryption))?
+            .open(&nonce, boxed)
+    }
+}
+
+/// Seals `plaintext` to `recipient` from the ephemeral secret key `ephemeral`.
+fn seal_with_ephemeral(
+    plaintext: &[u8],
+    recipient: &CryptoBoxPublicKey,
+    ephemeral: &CryptoBoxSecretKey,
+) -> Result<Vec<u8>, Error> {
+    let ephemeral_pk = ephemeral.public_key();
+    let nonce = seal_nonce(&ephemeral_pk, recipient);
+    let boxed = CryptoBox::new(recipient, ephemeral)?.seal(&nonce, plaintext);
+
+    let mut sealed = Vec::with_capacity(PUBLIC_KEY_SIZE + boxed.len());
+    sealed.extend_from_slice(ephemeral_pk.as_bytes());
+    sealed.extend_from_slice(&boxed);
+    Ok(sealed)
+}
+
+/// The nonce of a sealed box, `BLAKE2b-192(ephemeral_pk || recipient_pk)`.
+fn seal_nonce(ephemeral: &CryptoBoxPublicKey, recipient: &CryptoBoxPublicKey) -> CryptoBoxNonce {
+    let hash = blake2b_simd::Params::new()
+        .hash_length(NONCE_SIZE)
+        .to_state()
+        .update(ephemeral.as_bytes())
+        .update(recipient.as_bytes())
+        .finalize();
+    let mut nonce = [0u8; NONCE_SIZE];
+    nonce.copy_from_slice(hash.as_bytes());
+    nonce
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    fn decode(hex: &str) -> Vec<u8> {
+        hex::decode(hex).unwrap_or_default()
+    }
+
+    // The keys of NaCl's crypto_box test, with Alice's key pair as the ephemeral key pair. The
+    // expected box was computed independently from the libsodium construction.
+    // NaCl crypto_box 测试中的密钥，以 Alice 的密钥对作为临时密钥对。期望的盒是根据 libsodium
+    // 的构造独立计算得到的。
+    const EPHEMERAL_SK: &str = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+    const RECIPIENT_SK: &str = "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb";
+    const NONCE: &str = "bde68c3007dd6c6038618666c8830b023d3c0a8e988a8d38";
+    const MESSAGE: &[u8] = b"Sealed boxes are anonymous";
+    const SEALED: &str = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a\
+        e2329a18de1229614ec7d6d0c998c0fdf462cdaa8d9c2333bf1e2e09cd1a5b2e\
+        4f393019d23f74ab8c4d";
+
+    #[test]
+    fn test_vector() -> Result<(), Error> {
+        let ephemeral = CryptoBoxSecretKey::from_bytes(&decode(EPHEMERAL_SK))?;
+        let recipient = CryptoBoxSecretKey::from_bytes(&decode(RECIPIENT_SK))?;
+        assert_eq!(
+            seal_nonce(&ephemeral.public_key(), &recipient.public_key()).to_vec(),
+            decode(NONCE)
+        );
+
+        let sealed = seal_with_ephemeral(MESSAGE, &recipient.public_key(), &ephemeral)?;
+        assert_eq!(sealed, decode(SEALED));
+        assert_eq!(SealedBox::open(&sealed, &recipient)?, MESSAGE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_roundtrip() -> Result<(), Error> {
+        let mut rng = SeededRng(1);
+        let recipient = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+
+        for len in [0, 1, 64, 1000] {
+            let message = vec![0xa5; len];
+            let sealed = SealedBox::seal_with_rng(&message, &recipient.public_key(), &mut rng)?;
+            assert_eq!(sealed.len(), SEAL_OVERHEAD + len);
+            assert_eq!(SealedBox::open(&sealed, &recipient)?, message);
+        }
+
+        // Each seal uses a fresh ephemeral key.
+        // 每次封装都使用新的临时密钥。
+        let first = SealedBox::seal_with_rng(b"hello", &recipient.public_key(), &mut rng)?;
+        let second = SealedBox::seal_with_rng(b"hello", &recipient.public_key(), &mut rng)?;
+        assert_ne!(first, second);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_tampering() -> Result<(), Error> {
+        let mut rng = SeededRng(2);
+        let recipient = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+        let other = CryptoBoxSecretKey::generate_with_rng(&mut rng);
+        let sealed =
+            SealedBox::seal_with_rng(b"attack at dawn", &recipient.public_key(), &mut rng)?;
+        let failed = Err(Error::Symmetric(SymmetricError::Decryption));
+
+        for index in [0, PUBLIC_KEY_SIZE, SEAL_OVERHEAD, sealed.len() - 1] {
+            let mut tampered = sealed.clone();
+            tampered[index] ^= 1;
+            assert_eq!(SealedBox::open(&tampered, &recipient), failed);
+        }
+        assert_eq!(
+            SealedBox::open(&sealed[..SEAL_OVERHEAD - 1], &recipient),
+            failed
+        );
+        assert_eq!(SealedBox::open(&sealed, &other), failed);
+
+        // An ephemeral key of small order is rejected as a decryption failure.
+        // 小阶的临时密钥会作为解密失败被拒绝。
+        let mut small_order = sealed;
+        small_order[..PUBLIC_KEY_SIZE].fill(0);
+        assert_eq!(SealedBox::open(&small_order, &recipient), failed);
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+//! Oblivious pseudorandom function implementations.
+//!
+//! This module provides oblivious pseudorandom functions, which let a client evaluate a keyed
+//! pseudorandom function on its input together with the server holding the key, without the
+//! server learning the input or the output.
+//!
+//! # Available Implementations
+//! - **ristretto255-SHA512**: The OPRF mode of RFC 9497 over ristretto255
+//!
+//! 不经意伪随机函数实现。
+//!
+//! 此模块提供不经意伪随机函数，它们让客户端与持有密钥的服务器一起在其输入上计算带密钥的伪随机
+//! 函数，而服务器不会得知输入或输出。
+//!
+//! # 可用实现
+//! - **ristretto255-SHA512**: 基于 ristretto255 的 RFC 9497 OPRF 模式
+
+/// ristretto255-SHA512 OPRF implementation.
+///
+/// ristretto255-SHA512 OPRF 实现。
+#[cfg(feature = "oprf-default")]
+pub mod ristretto255;
@@ -0,0 +1,453 @@
+//! Provides the oblivious pseudorandom function of RFC 9497 over ristretto255 with SHA-512, in
+//! OPRF mode.
+//!
+//! An OPRF lets a client compute `F(k, input)` with a server that holds the key `k`, such that
+//! the server learns nothing about the input or the output and the client learns nothing about
+//! the key beyond the output:
+//! 1. The client blinds its input with [`Oprf::blind`], keeps the [`OprfBlind`] and sends the
+//!    blinded element to the server.
+//! 2. The server evaluates it with [`OprfKey::blind_evaluate`] and returns the evaluated
+//!    element.
+//! 3. The client unblinds the result with [`Oprf::finalize`] and obtains the
+//!    [`OUTPUT_SIZE`]-byte output.
+//!
+//! The server can compute the same output directly with [`OprfKey::evaluate`] when it knows the
+//! input. In OPRF mode the client cannot check which key the server used; the verifiable modes
+//! of RFC 9497 are not provided.
+//!
+//! # Security Considerations
+//! - A blind must be used for one evaluation only.
+//! - The OPRF is not a FIPS approved algorithm, so it is not available with the `fips` feature.
+//!
+//! 提供了基于 ristretto255 和 SHA-512 的 RFC 9497 不经意伪随机函数（OPRF 模式）。
+//!
+//! OPRF 让客户端与持有密钥 `k` 的服务器一起计算 `F(k, input)`，使得服务器对输入和输出一无所知，
+//! 而客户端除了输出之外对密钥一无所知：
+//! 1. 客户端使用 [`Oprf::blind`] 盲化其输入，保留 [`OprfBlind`] 并将盲化元素发送给服务器。
+//! 2. 服务器使用 [`OprfKey::blind_evaluate`] 对其求值并返回求值元素。
+//! 3. 客户端使用 [`Oprf::finalize`] 对结果去盲，并获得 [`OUTPUT_SIZE`] 字节的输出。
+//!
+//! 当服务器知道输入时，它可以使用 [`OprfKey::evaluate`] 直接计算相同的输出。在 OPRF 模式下，
+//! 客户端无法检查服务器使用了哪个密钥；不提供 RFC 9497 的可验证模式。
+//!
+//! # 安全考虑
+//! - 一个盲化因子只能用于一次求值。
+//! - OPRF 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::vec::Vec;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXmd, Expander};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroizing;
+
+/// The length in bytes of a serialized group element.
+///
+/// 序列化群元素的字节长度。
+pub const ELEMENT_SIZE: usize = 32;
+
+/// The length in bytes of a serialized scalar.
+///
+/// 序列化标量的字节长度。
+pub const SCALAR_SIZE: usize = 32;
+
+/// The length in bytes of an OPRF output.
+///
+/// OPRF 输出的字节长度。
+pub const OUTPUT_SIZE: usize = 64;
+
+/// The context string of the `ristretto255-SHA512` suite in OPRF mode.
+const CONTEXT_STRING: &[u8] = b"OPRFV1-\x00-ristretto255-SHA512";
+
+/// The longest input the OPRF accepts, as its length is encoded in two bytes.
+const MAX_INPUT_SIZE: usize = u16::MAX as usize;
+
+// ------------------- Keys -------------------
+// ------------------- 密钥 -------------------
+
+/// The server's OPRF private key.
+///
+/// 服务器的 OPRF 私钥。
+#[derive(Clone)]
+pub struct OprfKey {
+    secret: Zeroizing<Scalar>,
+}
+
+impl OprfKey {
+    /// Generates a key from the crate's system RNG.
+    ///
+    /// 从 crate 的系统 RNG 生成一个密钥。
+    pub fn generate() -> Self {
+        Self::generate_with_rng(&mut SystemRng::new())
+    }
+
+    /// Generates a key with randomness drawn from `rng`.
+    ///
+    /// 使用从 `rng` 获取的随机性生成一个密钥。
+    pub fn generate_with_rng<R: CryptoRngCore>(rng: &mut R) -> Self {
+        Self {
+            secret: Zeroizing::new(random_nonzero_scalar(rng)),
+        }
+    }
+
+    /// Derives a key deterministically from a seed and a public `info` string
+    /// (`DeriveKeyPair` of RFC 9497).
+    ///
+    /// Fails with `KeyError::InvalidLength` if `seed` is not 32 bytes, and with
+    /// `KeyError::InvalidEncoding` if `info` is longer than 65535 bytes.
+    ///
+    /// 从种子和公开的 `info` 字符串确定性地派生一个密钥（RFC 9497 的 `DeriveKeyPair`）。
+    ///
+    /// 如果 `seed` 不是 32 字节，则以 `KeyError::InvalidLength` 失败；如果 `info` 长于 65535
+    /// 字节，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn derive(seed: &[u8], info: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            secret: Zeroizing::new(derive_scalar(seed, info)?),
+        })
+    }
+
+    /// Imports a key from its 32-byte little-endian scalar.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the scalar is zero or not canonical.
+    ///
+    /// 从其 32 字节小端标量导入密钥。
+    ///
+    /// 如果标量为零或不是规范编码，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            secret: Zeroizing::new(parse_nonzero_scalar(bytes)?),
+        })
+    }
+
+    /// Exports the key as its 32-byte little-endian scalar.
+    ///
+    /// 将密钥导出为其 32 字节小端标量。
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.secret.to_bytes().to_vec())
+    }
+
+    /// Returns the public key `k * G`, which verifiable modes publish.
+    ///
+    /// 返回公钥 `k * G`，可验证模式会公开它。
+    pub fn public_key(&self) -> Vec<u8> {
+        (RISTRETTO_BASEPOINT_POINT * *self.secret)
+            .compress()
+            .to_bytes()
+            .to_vec()
+    }
+
+    /// Evaluates a client's blinded element (`BlindEvaluate` of RFC 9497).
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the element is malformed or the identity.
+    ///
+    /// 对客户端的盲化元素求值（RFC 9497 的 `BlindEvaluate`）。
+    ///
+    /// 如果元素格式错误或为单位元，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn blind_evaluate(&self, blinded_element: &[u8]) -> Result<Vec<u8>, Error> {
+        let blinded = parse_element(blinded_element)?;
+        Ok((blinded * *self.secret).compress().to_bytes().to_vec())
+    }
+
+    /// Computes the OPRF output for `input` directly (`Evaluate` of RFC 9497).
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if `input` is longer than 65535 bytes.
+    ///
+    /// 直接计算 `input` 的 OPRF 输出（RFC 9497 的 `Evaluate`）。
+    ///
+    /// 如果 `input` 长于 65535 字节，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn evaluate(&self, input: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let element = hash_to_group(input)?;
+        finalize_hash(input, &(element * *self.secret))
+    }
+}
+
+impl core::fmt::Debug for OprfKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OprfKey").finish_non_exhaustive()
+    }
+}
+
+/// The blinding scalar a client keeps between blinding its input and finalizing the output.
+///
+/// 客户端在盲化其输入与最终计算输出之间保留的盲化标量。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OprfBlind {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    blind: Zeroizing<Vec<u8>>,
+}
+
+impl OprfBlind {
+    /// Imports a blind from its 32-byte little-endian scalar.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the scalar is zero or not canonical.
+    ///
+    /// 从其 32 字节小端标量导入盲化因子。
+    ///
+    /// 如果标量为零或不是规范编码，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        parse_nonzero_scalar(bytes)?;
+        Ok(Self {
+            blind: Zeroizing::new(bytes.to_vec()),
+        })
+    }
+
+    /// Exports the blind as its 32-byte little-endian scalar.
+    ///
+    /// 将盲化因子导出为其 32 字节小端标量。
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.blind
+    }
+}
+
+impl core::fmt::Debug for OprfBlind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OprfBlind").finish_non_exhaustive()
+    }
+}
+
+// ------------------- OPRF -------------------
+// ------------------- OPRF -------------------
+
+/// The client side of the OPRF.
+///
+/// OPRF 的客户端。
+#[derive(Clone, Debug, Default)]
+pub struct Oprf;
+
+impl Oprf {
+    /// Blinds `input`, drawing the blind from the crate's system RNG.
+    ///
+    /// See [`Oprf::blind_with_rng`].
+    ///
+    /// 盲化 `input`，盲化因子从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Oprf::blind_with_rng`]。
+    pub fn blind(input: &[u8]) -> Result<(OprfBlind, Vec<u8>), Error> {
+        Self::blind_with_rng(&mut SystemRng::new(), input)
+    }
+
+    /// Blinds `input` with randomness drawn from `rng`, and returns the blind together with the
+    /// blinded element to send to the server (`Blind` of RFC 9497).
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if `input` is longer than 65535 bytes.
+    ///
+    /// 使用从 `rng` 获取的随机性盲化 `input`，并将盲化因子与需要发送给服务器的盲化元素一起返回
+    /// （RFC 9497 的 `Blind`）。
+    ///
+    /// 如果 `input` 长于 65535 字节，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn blind_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        input: &[u8],
+    ) -> Result<(OprfBlind, Vec<u8>), Error> {
+        let blind = Zeroizing::new(random_nonzero_scalar(rng));
+        let blinded = blind_with_scalar(input, &blind)?;
+        Ok((
+            OprfBlind {
+                blind: Zeroizing::new(blind.to_bytes().to_vec()),
+            },
+            blinded,
+        ))
+    }
+
+    /// Unblinds the server's evaluated element and returns the OPRF output for `input`
+    /// (`Finalize` of RFC 9497).
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the element is malformed or the identity.
+    ///
+    /// 对服务器的求值元素去盲，并返回 `input` 的 OPRF 输出（RFC 9497 的 `Finalize`）。
+    ///
+    /// 如果元素格式错误或为单位元，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn finalize(
+        input: &[u8],
+        blind: &OprfBlind,
+        evaluated_element: &[u8],
+    ) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let blind = Zeroizing::new(parse_nonzero_scalar(&blind.blind)?);
+        let evaluated = parse_element(evaluated_element)?;
+        finalize_hash(input, &(evaluated * blind.invert()))
+    }
+}
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// Blinds `input` with a given scalar.
+pub(crate) fn blind_with_scalar(input: &[u8], blind: &Scalar) -> Result<Vec<u8>, Error> {
+    let element = hash_to_group(input)?;
+    Ok((element * blind).compress().to_bytes().to_vec())
+}
+
+/// `DeriveKeyPair` of RFC 9497, returning the private scalar.
+pub(crate) fn derive_scalar(seed: &[u8], info: &[u8]) -> Result<Scalar, Error> {
+    if seed.len() != 32 {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    let info_length = length_prefix(info)?;
+    let mut dst = b"DeriveKeyPair".to_vec();
+    dst.extend_from_slice(CONTEXT_STRING);
+    for counter in 0..=u8::MAX {
+        let scalar = hash_to_scalar(&[seed, &info_length, info, &[counter]], &dst)?;
+        if scalar != Scalar::ZERO {
+            return Ok(scalar);
+        }
+    }
+    Err(Error::Key(KeyError::InvalidEncoding))
+}
+
+fn hash_to_group(input: &[u8]) -> Result<RistrettoPoint, Error> {
+    length_prefix(input)?;
+    let mut dst = b"HashToGroup-".to_vec();
+    dst.extend_from_slice(CONTEXT_STRING);
+    let mut uniform = Zeroizing::new([0u8; 64]);
+    expand_message(&[input], &dst, uniform.as_mut())?;
+    let element = RistrettoPoint::from_uniform_bytes(&uniform);
+    if element == RistrettoPoint::identity() {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    Ok(element)
+}
+
+fn hash_to_scalar(parts: &[&[u8]], dst: &[u8]) -> Result<Scalar, Error> {
+    let mut uniform = Zeroizing::new([0u8; 64]);
+    expand_message(parts, dst, uniform.as_mut())?;
+    Ok(Scalar::from_bytes_mod_order_wide(&uniform))
+}
+
+/// expand_message_xmd of RFC 9380 with SHA-512.
+fn expand_message(parts: &[&[u8]], dst: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    let dsts = [dst];
+    let mut expander = ExpandMsgXmd::<Sha512>::expand_message(parts, &dsts, output.len())
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    expander.fill_bytes(output);
+    Ok(())
+}
+
+fn finalize_hash(input: &[u8], unblinded: &RistrettoPoint) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let element = unblinded.compress();
+    let mut hasher = Sha512::new();
+    hasher.update(length_prefix(input)?);
+    hasher.update(input);
+    hasher.update((ELEMENT_SIZE as u16).to_be_bytes());
+    hasher.update(element.as_bytes());
+    hasher.update(b"Finalize");
+    Ok(Zeroizing::new(hasher.finalize().to_vec()))
+}
+
+fn length_prefix(bytes: &[u8]) -> Result<[u8; 2], Error> {
+    if bytes.len() > MAX_INPUT_SIZE {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    Ok((bytes.len() as u16).to_be_bytes())
+}
+
+fn random_nonzero_scalar<R: CryptoRngCore>(rng: &mut R) -> Scalar {
+    loop {
+        let mut wide = Zeroizing::new([0u8; 64]);
+        rng.fill_bytes(wide.as_mut());
+        let scalar = Scalar::from_bytes_mod_order_wide(&wide);
+        if scalar != Scalar::ZERO {
+            return scalar;
+        }
+    }
+}
+
+fn parse_nonzero_scalar(bytes: &[u8]) -> Result<Scalar, Error> {
+    let bytes: [u8; SCALAR_SIZE] = bytes
+        .try_into()
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    Option::<Scalar>::from(Scalar::from_canonical_bytes(bytes))
+        .filter(|scalar| *scalar != Scalar::ZERO)
+        .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+/// Deserializes an element, rejecting the identity as RFC 9497 requires.
+pub(crate) fn parse_element(bytes: &[u8]) -> Result<RistrettoPoint, Error> {
+    CompressedRistretto::from_slice(bytes)
+        .ok()
+        .and_then(|compressed| compressed.decompress())
+        .filter(|element| *element != RistrettoPoint::identity())
+        .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    #[test]
+    fn test_rfc9497_vector() -> Result<(), Error> {
+        // RFC 9497 Appendix A.1.1, ristretto255-SHA512 in OPRF mode, test vector 1.
+        // RFC 9497 附录 A.1.1，OPRF 模式下的 ristretto255-SHA512，测试向量 1。
+        let key = OprfKey::derive(&[0xa3; 32], b"test key")?;
+        assert_eq!(
+            hex::encode(key.to_bytes()),
+            "5ebcea5ee37023ccb9fc2d2019f9d7737be85591ae8652ffa9ef0f4d37063b0e"
+        );
+
+        let blinded =
+            hex::decode("609a0ae68c15a3cf6903766461307e5c8bb2f95e7e6550e1ffa2dc99e412803c")
+                .unwrap_or_default();
+        assert_eq!(
+            hex::encode(key.blind_evaluate(&blinded)?),
+            "7ec6578ae5120958eb2db1745758ff379e77cb64fe77b0b2d8cc917ea0869c7e"
+        );
+        assert_eq!(
+            hex::encode(key.evaluate(&[0x00])?),
+            "527759c3d9366f277d8c6020418d96bb393ba2afb20ff90df23fb7708264e2f3\
+             ab9135e3bd69955851de4b1f9fe8a0973396719b7912ba9ee8aa7d0b5e24bcf6"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_oprf_roundtrip() -> Result<(), Error> {
+        let mut rng = SeededRng(7);
+        let key = OprfKey::generate_with_rng(&mut rng);
+        let input = b"correct horse battery staple";
+
+        let (blind, blinded) = Oprf::blind_with_rng(&mut rng, input)?;
+        let evaluated = key.blind_evaluate(&blinded)?;
+        let output = Oprf::finalize(input, &blind, &evaluated)?;
+        assert_eq!(output.len(), OUTPUT_SIZE);
+        assert_eq!(output, key.evaluate(input)?);
+
+        // A fresh blind gives a different blinded element but the same output.
+        // 新的盲化因子给出不同的盲化元素，但输出相同。
+        let (blind2, blinded2) = Oprf::blind_with_rng(&mut rng, input)?;
+        assert_ne!(blinded, blinded2);
+        let output2 = Oprf::finalize(input, &blind2, &key.blind_evaluate(&blinded2)?)?;
+        assert_eq!(output, output2);
+
+        // The blind survives serialization, and another key gives another output.
+        // 盲化因子在序列化后仍然可用，另一个密钥给出另一个输出。
+        let restored = OprfBlind::from_bytes(blind.as_bytes())?;
+        assert_eq!(Oprf::finalize(input, &restored, &evaluated)?, output);
+        let other = OprfKey::from_bytes(&OprfKey::generate_with_rng(&mut rng).to_bytes())?;
+        assert_ne!(
+            Oprf::finalize(input, &blind, &other.blind_evaluate(&blinded)?)?,
+            output
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let key = OprfKey::generate_with_rng(&mut SeededRng(3));
+        let identity = [0u8; ELEMENT_SIZE];
+        assert!(key.blind_evaluate(&identity).is_err());
+        assert!(key.blind_evaluate(&[0xff; ELEMENT_SIZE]).is_err());
+        assert!(key.blind_evaluate(&[1u8; 31]).is_err());
+        assert!(OprfKey::from_bytes(&[0u8; SCALAR_SIZE]).is_err());
+        assert!(OprfKey::derive(&[0u8; 31], b"").is_err());
+        assert!(OprfBlind::from_bytes(&[0xff; SCALAR_SIZE]).is_err());
+        assert!(key.evaluate(&vec![0u8; MAX_INPUT_SIZE + 1]).is_err());
+    }
+}
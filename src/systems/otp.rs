@@ -0,0 +1,30 @@
+//! One-time password (OTP) implementations.
+//!
+//! This module provides the HMAC-based one-time password algorithms used for
+//! two-factor authentication. Both generate short decimal codes from a shared
+//! secret and verify them in constant time.
+//!
+//! # Available Implementations
+//! - **HOTP**: Counter-based one-time passwords (RFC 4226)
+//! - **TOTP**: Time-based one-time passwords (RFC 6238)
+//!
+//! 一次性密码 (OTP) 实现。
+//!
+//! 此模块提供用于双因素认证的基于 HMAC 的一次性密码算法。
+//! 两者都从共享密钥生成简短的十进制码，并以常量时间验证它们。
+//!
+//! # 可用实现
+//! - **HOTP**: 基于计数器的一次性密码 (RFC 4226)
+//! - **TOTP**: 基于时间的一次性密码 (RFC 6238)
+
+/// HOTP (HMAC-based one-time password) implementation.
+///
+/// HOTP（基于 HMAC 的一次性密码）实现。
+#[cfg(feature = "otp-default")]
+pub mod hotp;
+
+/// TOTP (time-based one-time password) implementation.
+///
+/// TOTP（基于时间的一次性密码）实现。
+#[cfg(feature = "otp-default")]
+pub mod totp;
@@ -0,0 +1,275 @@
+//! Provides an implementation of HOTP, the HMAC-based one-time password (RFC 4226).
+//!
+//! A HOTP code is derived from a shared secret and a moving counter: the counter is
+//! authenticated with HMAC, and the tag is dynamically truncated to a short decimal code.
+//! Both sides must keep their counters in step; [`HotpScheme::verify`] accepts codes a
+//! few counter values ahead of the expected one to recover from drift.
+//!
+//! 提供了 HOTP（基于 HMAC 的一次性密码，RFC 4226）的实现。
+//!
+//! HOTP 码由共享密钥和一个递增的计数器派生：使用 HMAC 认证计数器，然后将标签动态截断为
+//! 简短的十进制码。双方必须保持计数器同步；[`HotpScheme::verify`] 接受比预期计数器
+//! 超前若干值的码，以便从漂移中恢复。
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "insecure-legacy-hashes")]
+use crate::systems::hash::legacy::Sha1Params;
+#[cfg(feature = "insecure-legacy-hashes")]
+use crate::systems::mac::hmac::{HmacSha1, LegacyHmacScheme};
+use crate::systems::mac::hmac::{HmacScheme, HmacSha256, HmacSha512};
+use crate::{errors::Error, policy, prelude::*};
+use core::marker::PhantomData;
+use subtle::ConstantTimeEq;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// An HMAC scheme that HOTP and TOTP can be built on. This is a sealed trait.
+///
+/// It is implemented for [`HmacScheme`] over every SHA-2 hash and, with the
+/// `insecure-legacy-hashes` feature, for `HmacSha1`, the algorithm of RFC 4226 and the
+/// default of most authenticator apps.
+///
+/// 可用于构建 HOTP 和 TOTP 的 HMAC 方案。这是一个密封的 trait。
+///
+/// 它为基于各 SHA-2 哈希的 [`HmacScheme`] 实现；启用 `insecure-legacy-hashes` 特性时，
+/// 也为 `HmacSha1` 实现，即 RFC 4226 的算法和大多数验证器应用的默认算法。
+pub trait OtpHmac: private::Sealed + Mac<Key = SymmetricKey> {
+    /// The name of the underlying hash function.
+    ///
+    /// 底层哈希函数的名称。
+    const HASH_NAME: &'static str;
+
+    /// The offset of this hash function in the algorithm IDs of the OTP schemes.
+    ///
+    /// 此哈希函数在 OTP 方案算法 ID 中的偏移量。
+    const ID_OFFSET: u32;
+}
+
+impl<H: Hasher> private::Sealed for HmacScheme<H> {}
+
+impl<H: Hasher> OtpHmac for HmacScheme<H> {
+    const HASH_NAME: &'static str = H::NAME;
+    const ID_OFFSET: u32 = H::ID_OFFSET;
+}
+
+#[cfg(feature = "insecure-legacy-hashes")]
+impl private::Sealed for LegacyHmacScheme<Sha1Params> {}
+
+#[cfg(feature = "insecure-legacy-hashes")]
+impl OtpHmac for HmacSha1 {
+    const HASH_NAME: &'static str = "SHA-1";
+    const ID_OFFSET: u32 = 0x01_00 + (Sha1Params::ID & 0xFF);
+}
+
+/// The smallest number of digits accepted, as required by RFC 4226.
+///
+/// 接受的最小位数，符合 RFC 4226 的要求。
+pub const MIN_DIGITS: u32 = 6;
+
+/// The largest number of digits accepted.
+///
+/// 接受的最大位数。
+pub const MAX_DIGITS: u32 = 9;
+
+/// The default number of digits.
+///
+/// 默认位数。
+pub const DEFAULT_DIGITS: u32 = 6;
+
+/// The largest look-ahead window accepted by [`HotpScheme::verify`]. Every counter in the
+/// window is tried, so a wider window costs more and gives a guesser more chances.
+///
+/// [`HotpScheme::verify`] 接受的最大前向窗口。窗口中的每个计数器都会被尝试，
+/// 因此更宽的窗口开销更大，也给猜测者更多机会。
+pub const MAX_LOOK_AHEAD: u64 = 100;
+
+/// HOTP over a given HMAC scheme.
+///
+/// `Default` produces 6-digit codes.
+///
+/// 基于给定 HMAC 方案的 HOTP。
+///
+/// `Default` 生成 6 位的码。
+#[derive(Clone, Debug)]
+pub struct HotpScheme<M: OtpHmac> {
+    digits: u32,
+    _mac: PhantomData<M>,
+}
+
+impl<M: OtpHmac> HotpScheme<M> {
+    /// Creates a HOTP scheme producing codes of `digits` decimal digits.
+    /// Fails unless `digits` is between [`MIN_DIGITS`] and [`MAX_DIGITS`].
+    ///
+    /// 创建一个生成 `digits` 位十进制码的 HOTP 方案。
+    /// 除非 `digits` 介于 [`MIN_DIGITS`] 和 [`MAX_DIGITS`] 之间，否则失败。
+    pub fn new(digits: u32) -> Result<Self, Error> {
+        if !(MIN_DIGITS..=MAX_DIGITS).contains(&digits) {
+            return Err(Error::Mac(MacError::InvalidTagLength));
+        }
+        Ok(Self {
+            digits,
+            _mac: PhantomData,
+        })
+    }
+
+    /// Returns the number of digits of the generated codes.
+    ///
+    /// 返回生成的码的位数。
+    pub fn digits(&self) -> u32 {
+        self.digits
+    }
+
+    /// Generates the code for `counter`.
+    ///
+    /// 为 `counter` 生成码。
+    pub fn generate(&self, key: &SymmetricKey, counter: u64) -> Result<String, Error> {
+        policy::enforce::<Self>()?;
+        let tag = M::compute(key, &counter.to_be_bytes())?;
+        let offset = (tag[tag.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            tag[offset] & 0x7f,
+            tag[offset + 1],
+            tag[offset + 2],
+            tag[offset + 3],
+        ]);
+        let code = binary % 10u32.pow(self.digits);
+        Ok(format!("{:0width$}", code, width = self.digits as usize))
+    }
+
+    /// Verifies `code` against the counters `counter..=counter + look_ahead`, in constant
+    /// time, and returns the counter that matched. The caller must store the returned
+    /// counter plus one so that the code cannot be replayed. Fails with
+    /// `MacError::InvalidParameter` if `look_ahead` exceeds [`MAX_LOOK_AHEAD`].
+    ///
+    /// 以常量时间针对计数器 `counter..=counter + look_ahead` 验证 `code`，并返回匹配的计数器。
+    /// 调用者必须保存返回的计数器加一，以防止码被重放。如果 `look_ahead` 超过
+    /// [`MAX_LOOK_AHEAD`]，则以 `MacError::InvalidParameter` 失败。
+    pub fn verify(
+        &self,
+        key: &SymmetricKey,
+        code: &str,
+        counter: u64,
+        look_ahead: u64,
+    ) -> Result<u64, Error> {
+        policy::enforce::<Self>()?;
+        if look_ahead > MAX_LOOK_AHEAD {
+            return Err(Error::Mac(MacError::InvalidParameter));
+        }
+        let mut matched = None;
+        for candidate in counter..=counter.saturating_add(look_ahead) {
+            let expected = self.generate(key, candidate)?;
+            if bool::from(expected.as_bytes().ct_eq(code.as_bytes())) && matched.is_none() {
+                matched = Some(candidate);
+            }
+        }
+        matched.ok_or(Error::Mac(MacError::VerificationFailed))
+    }
+}
+
+impl<M: OtpHmac> Default for HotpScheme<M> {
+    fn default() -> Self {
+        Self {
+            digits: DEFAULT_DIGITS,
+            _mac: PhantomData,
+        }
+    }
+}
+
+impl<M: OtpHmac> Algorithm for HotpScheme<M> {
+    fn name() -> String {
+        format!("HOTP-{}", M::HASH_NAME)
+    }
+    const ID: u32 = 0x07_01_00_00 + M::ID_OFFSET;
+}
+
+impl<M: OtpHmac> Parameterized for HotpScheme<M> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(M::HASH_NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![("digits", ParamValue::U32(self.digits))]
+    }
+}
+
+impl<M: OtpHmac> SymmetricKeySet for HotpScheme<M> {
+    type Key = SymmetricKey;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for HOTP over HMAC-SHA-1, as specified by RFC 4226.
+///
+/// 基于 HMAC-SHA-1 的 HOTP 的类型别名，如 RFC 4226 所规定。
+#[cfg(feature = "insecure-legacy-hashes")]
+pub type HotpSha1 = HotpScheme<HmacSha1>;
+
+/// A type alias for HOTP over HMAC-SHA-256.
+///
+/// 基于 HMAC-SHA-256 的 HOTP 的类型别名。
+pub type HotpSha256 = HotpScheme<HmacSha256>;
+
+/// A type alias for HOTP over HMAC-SHA-512.
+///
+/// 基于 HMAC-SHA-512 的 HOTP 的类型别名。
+pub type HotpSha512 = HotpScheme<HmacSha512>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "insecure-legacy-hashes")]
+    fn test_hotp_sha1() {
+        // Test vectors from RFC 4226, appendix D
+        let key = SymmetricKey::new(b"12345678901234567890".to_vec());
+        let hotp = HotpSha1::default();
+        let codes: Vec<String> = (0..10).map(|c| hotp.generate(&key, c).unwrap()).collect();
+        assert_eq!(
+            codes,
+            [
+                "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583",
+                "399871", "520489",
+            ]
+        );
+        assert_eq!(hotp.verify(&key, "969429", 1, 2).unwrap(), 3);
+        assert_eq!(HotpSha1::name(), "HOTP-SHA-1");
+    }
+
+    #[test]
+    fn test_hotp_sha256() {
+        // The RFC 4226 secret with HMAC-SHA-256, for which the RFC publishes no vectors
+        let key = SymmetricKey::new(b"12345678901234567890".to_vec());
+        let hotp = HotpSha256::default();
+        let codes: Vec<String> = (0..4).map(|c| hotp.generate(&key, c).unwrap()).collect();
+        assert_eq!(codes, ["875740", "247374", "254785", "496144"]);
+
+        assert_eq!(hotp.verify(&key, "254785", 0, 3).unwrap(), 2);
+        assert_eq!(
+            hotp.verify(&key, "254785", 0, 1).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+        assert!(hotp.verify(&key, "25478", 2, 0).is_err());
+        assert_eq!(
+            hotp.verify(&key, "254785", 0, MAX_LOOK_AHEAD + 1).unwrap_err(),
+            Error::Mac(MacError::InvalidParameter)
+        );
+
+        assert_eq!(
+            HotpSha256::new(5).unwrap_err(),
+            Error::Mac(MacError::InvalidTagLength)
+        );
+        assert_eq!(
+            HotpSha256::new(8).unwrap().generate(&key, 0).unwrap().len(),
+            8
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Provides an implementation of TOTP, the time-based one-time password (RFC 6238).
+//!
+//! TOTP is HOTP whose counter is the number of `period`-second steps since the Unix epoch.
+//! To tolerate clock drift between the two sides, verification accepts codes from a
+//! configurable number of steps before and after the current one.
+//!
+//! 提供了 TOTP（基于时间的一次性密码，RFC 6238）的实现。
+//!
+//! TOTP 是计数器为自 Unix 纪元以来 `period` 秒步数的 HOTP。为了容忍双方之间的时钟漂移，
+//! 验证时接受当前步之前和之后可配置步数内的码。
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use super::hotp::{HotpScheme, OtpHmac};
+#[cfg(feature = "insecure-legacy-hashes")]
+use crate::systems::mac::hmac::HmacSha1;
+use crate::systems::mac::hmac::{HmacSha256, HmacSha512};
+use crate::{errors::Error, policy, prelude::*};
+
+/// The default time step in seconds.
+///
+/// 默认的时间步长（以秒为单位）。
+pub const DEFAULT_PERIOD: u32 = 30;
+
+/// The default number of steps accepted on either side of the current one.
+///
+/// 默认在当前步两侧接受的步数。
+pub const DEFAULT_SKEW: u32 = 1;
+
+/// TOTP over a given HMAC scheme.
+///
+/// `Default` produces 6-digit codes with a 30-second period and accepts one step of skew.
+///
+/// 基于给定 HMAC 方案的 TOTP。
+///
+/// `Default` 生成 6 位的码，周期为 30 秒，并接受一步的偏差。
+#[derive(Clone, Debug)]
+pub struct TotpScheme<M: OtpHmac> {
+    hotp: HotpScheme<M>,
+    period: u32,
+    skew: u32,
+}
+
+impl<M: OtpHmac> TotpScheme<M> {
+    /// Creates a TOTP scheme producing codes of `digits` decimal digits that change every
+    /// `period` seconds. Fails if `digits` is out of range or `period` is zero.
+    ///
+    /// 创建一个生成 `digits` 位十进制码、每 `period` 秒变化一次的 TOTP 方案。
+    /// 如果 `digits` 超出范围或 `period` 为零则失败。
+    pub fn new(digits: u32, period: u32) -> Result<Self, Error> {
+        if period == 0 {
+            return Err(Error::Mac(MacError::InvalidParameter));
+        }
+        Ok(Self {
+            hotp: HotpScheme::new(digits)?,
+            period,
+            skew: DEFAULT_SKEW,
+        })
+    }
+
+    /// Sets the number of steps accepted on either side of the current one. Verification
+    /// fails with `MacError::InvalidParameter` if the resulting window of `2 * skew` steps
+    /// exceeds [`MAX_LOOK_AHEAD`](super::hotp::MAX_LOOK_AHEAD).
+    ///
+    /// 设置在当前步两侧接受的步数。如果由此得到的 `2 * skew` 步窗口超过
+    /// [`MAX_LOOK_AHEAD`](super::hotp::MAX_LOOK_AHEAD)，验证会以 `MacError::InvalidParameter` 失败。
+    pub fn with_skew(mut self, skew: u32) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// Returns the number of digits of the generated codes.
+    ///
+    /// 返回生成的码的位数。
+    pub fn digits(&self) -> u32 {
+        self.hotp.digits()
+    }
+
+    /// Returns the time step in seconds.
+    ///
+    /// 返回时间步长（以秒为单位）。
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+
+    /// Returns the number of steps accepted on either side of the current one.
+    ///
+    /// 返回在当前步两侧接受的步数。
+    pub fn skew(&self) -> u32 {
+        self.skew
+    }
+
+    /// Generates the code for the Unix time `unix_time`, in seconds.
+    ///
+    /// 为 Unix 时间 `unix_time`（以秒为单位）生成码。
+    pub fn generate_at(&self, key: &SymmetricKey, unix_time: u64) -> Result<String, Error> {
+        policy::enforce::<Self>()?;
+        self.hotp.generate(key, unix_time / self.period as u64)
+    }
+
+    /// Verifies `code` at the Unix time `unix_time` within the skew window, in constant
+    /// time, and returns the time step that matched. To prevent replay, the caller should
+    /// reject later codes whose step is not greater than the last accepted one.
+    ///
+    /// 在偏差窗口内以常量时间验证 Unix 时间 `unix_time` 时的 `code`，并返回匹配的时间步。
+    /// 为防止重放，调用者应拒绝之后步数不大于上次接受步数的码。
+    pub fn verify_at(&self, key: &SymmetricKey, code: &str, unix_time: u64) -> Result<u64, Error> {
+        policy::enforce::<Self>()?;
+        let step = unix_time / self.period as u64;
+        let first = step.saturating_sub(self.skew as u64);
+        let window = step - first + self.skew as u64;
+        self.hotp.verify(key, code, first, window)
+    }
+
+    /// Generates the code for the current system time. Fails with `MacError::InvalidTime` if
+    /// the clock reads a time before the Unix epoch.
+    ///
+    /// 为当前系统时间生成码。如果时钟读到的时间早于 Unix 纪元，则以 `MacError::InvalidTime` 失败。
+    #[cfg(all(
+        feature = "std",
+        any(not(all(target_arch = "wasm32", target_os = "unknown")), feature = "wasm-js")
+    ))]
+    pub fn generate_now(&self, key: &SymmetricKey) -> Result<String, Error> {
+        self.generate_at(key, unix_now()?)
+    }
+
+    /// Verifies `code` at the current system time within the skew window. Fails with
+    /// `MacError::InvalidTime` if the clock reads a time before the Unix epoch.
+    ///
+    /// 在偏差窗口内验证当前系统时间的 `code`。如果时钟读到的时间早于 Unix 纪元，则以
+    /// `MacError::InvalidTime` 失败。
+    #[cfg(all(
+        feature = "std",
+        any(not(all(target_arch = "wasm32", target_os = "unknown")), feature = "wasm-js")
+    ))]
+    pub fn verify_now(&self, key: &SymmetricKey, code: &str) -> Result<u64, Error> {
+        self.verify_at(key, code, unix_now()?)
+    }
+}
+
+#[cfg(all(feature = "std", not(all(target_arch = "wasm32", target_os = "unknown"))))]
+fn unix_now() -> Result<u64, Error> {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .map_err(|_| Error::Mac(MacError::InvalidTime))
+}
+
+// `std::time::SystemTime::now` panics on `wasm32-unknown-unknown`, so the clock comes from JavaScript.
+// `std::time::SystemTime::now` 在 `wasm32-unknown-unknown` 上会 panic，因此时钟来自 JavaScript。
+#[cfg(all(feature = "std", target_arch = "wasm32", target_os = "unknown", feature = "wasm-js"))]
+fn unix_now() -> Result<u64, Error> {
+    let now = js_sys::Date::now();
+    if now.is_nan() || now < 0.0 {
+        return Err(Error::Mac(MacError::InvalidTime));
+    }
+    Ok((now / 1000.0) as u64)
+}
+
+impl<M: OtpHmac> Default for TotpScheme<M> {
+    fn default() -> Self {
+        Self {
+            hotp: HotpScheme::default(),
+            period: DEFAULT_PERIOD,
+            skew: DEFAULT_SKEW,
+        }
+    }
+}
+
+impl<M: OtpHmac> Algorithm for TotpScheme<M> {
+    fn name() -> String {
+        format!("TOTP-{}", M::HASH_NAME)
+    }
+    const ID: u32 = 0x07_02_00_00 + M::ID_OFFSET;
+}
+
+impl<M: OtpHmac> Parameterized for TotpScheme<M> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("hash", ParamValue::String(M::HASH_NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![
+            ("digits", ParamValue::U32(self.hotp.digits())),
+            ("period", ParamValue::U32(self.period)),
+            ("skew", ParamValue::U32(self.skew)),
+        ]
+    }
+}
+
+impl<M: OtpHmac> SymmetricKeySet for TotpScheme<M> {
+    type Key = SymmetricKey;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for TOTP over HMAC-SHA-1, the default of RFC 6238.
+///
+/// 基于 HMAC-SHA-1 的 TOTP 的类型别名，即 RFC 6238 的默认算法。
+#[cfg(feature = "insecure-legacy-hashes")]
+pub type TotpSha1 = TotpScheme<HmacSha1>;
+
+/// A type alias for TOTP over HMAC-SHA-256.
+///
+/// 基于 HMAC-SHA-256 的 TOTP 的类型别名。
+pub type TotpSha256 = TotpScheme<HmacSha256>;
+
+/// A type alias for TOTP over HMAC-SHA-512.
+///
+/// 基于 HMAC-SHA-512 的 TOTP 的类型别名。
+pub type TotpSha512 = TotpScheme<HmacSha512>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from RFC 6238, appendix B
+    const TIMES: [u64; 6] = [
+        59,
+        1111111109,
+        1111111111,
+        1234567890,
+        2000000000,
+        20000000000,
+    ];
+
+    fn run_totp_test<M: OtpHmac + Clone>(seed: &[u8], expected: [&str; 6]) {
+        let key = SymmetricKey::new(seed.to_vec());
+        let totp = TotpScheme::<M>::new(8, 30).unwrap();
+        for (time, code) in TIMES.iter().zip(expected) {
+            assert_eq!(totp.generate_at(&key, *time).unwrap(), code);
+            assert_eq!(totp.verify_at(&key, code, *time).unwrap(), time / 30);
+        }
+
+        // One step of skew is accepted by default, two are not
+        let code = totp.generate_at(&key, 1111111109).unwrap();
+        assert!(totp.verify_at(&key, &code, 1111111109 + 30).is_ok());
+        assert!(totp.verify_at(&key, &code, 1111111109 - 30).is_ok());
+        assert_eq!(
+            totp.verify_at(&key, &code, 1111111109 + 60).unwrap_err(),
+            Error::Mac(MacError::VerificationFailed)
+        );
+        let wide = totp.clone().with_skew(2);
+        assert!(wide.verify_at(&key, &code, 1111111109 + 60).is_ok());
+        let strict = totp.with_skew(0);
+        assert!(strict.verify_at(&key, &code, 1111111109 + 30).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "insecure-legacy-hashes")]
+    fn test_totp_sha1() {
+        run_totp_test::<HmacSha1>(
+            b"12345678901234567890",
+            [
+                "94287082", "07081804", "14050471", "89005924", "69279037", "65353130",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_totp_sha256() {
+        run_totp_test::<HmacSha256>(
+            b"12345678901234567890123456789012",
+            [
+                "46119246", "68084774", "67062674", "91819424", "90698825", "77737706",
+            ],
+        );
+        assert_eq!(
+            TotpSha256::new(6, 0).unwrap_err(),
+            Error::Mac(MacError::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn test_totp_sha512() {
+        run_totp_test::<HmacSha512>(
+            b"1234567890123456789012345678901234567890123456789012345678901234",
+            [
+                "90693936", "25091201", "99943326", "93441116", "38618901", "47863826",
+            ],
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! Password-authenticated key exchange implementations.
+//!
+//! This module provides password-authenticated key exchanges (PAKEs), which let two parties
+//! that share only a password establish a strong shared secret without exposing the password to
+//! offline guessing.
+//!
+//! # Available Implementations
+//! - **SPAKE2**: The balanced SPAKE2 (RFC 9382) and augmented SPAKE2+ (RFC 9383) over P-256
+//! - **SRP**: The augmented SRP-6a (RFC 5054) over the RFC 5054 groups
+//! - **OPAQUE**: The augmented OPAQUE-3DH (RFC 9807) over ristretto255
+//!
+//! 口令认证密钥交换实现。
+//!
+//! 此模块提供口令认证密钥交换 (PAKE)，它让仅共享一个口令的双方建立一个强共享秘密，而不会使
+//! 口令暴露于离线猜测。
+//!
+//! # 可用实现
+//! - **SPAKE2**: 基于 P-256 的平衡 SPAKE2 (RFC 9382) 和增强 SPAKE2+ (RFC 9383)
+//! - **SRP**: 基于 RFC 5054 群的增强 SRP-6a (RFC 5054)
+//! - **OPAQUE**: 基于 ristretto255 的增强 OPAQUE-3DH (RFC 9807)
+
+/// SPAKE2 and SPAKE2+ implementation.
+///
+/// SPAKE2 和 SPAKE2+ 实现。
+#[cfg(feature = "spake2-default")]
+pub mod spake2;
+
+/// SRP-6a password-authenticated key exchange implementation.
+///
+/// SRP-6a 口令认证密钥交换实现。
+#[cfg(feature = "srp-default")]
+pub mod srp;
+
+/// OPAQUE augmented password-authenticated key exchange implementation.
+///
+/// OPAQUE 增强口令认证密钥交换实现。
+#[cfg(feature = "opaque-default")]
+pub mod opaque;
@@ -0,0 +1,1419 @@
+//! Provides the OPAQUE augmented password-authenticated key exchange of RFC 9807, using the
+//! OPAQUE-3DH key exchange over ristretto255 with SHA-512.
+//!
+//! The server never sees the password, not even during registration, and what it stores does
+//! not allow an offline dictionary attack without first running the protocol against it, in
+//! contrast with SPAKE2+ where the verifier record can be attacked directly once stolen.
+//!
+//! Registration takes one round trip:
+//! 1. The client calls [`Opaque::start_registration`] and sends the request to the server.
+//! 2. The server answers with [`OpaqueServerSetup::registration_response`].
+//! 3. The client calls [`OpaqueClientRegistration::finish`] and uploads the resulting
+//!    [`OpaqueRecord`], which the server stores under the credential identifier.
+//!
+//! Login takes three messages:
+//! 1. The client calls [`Opaque::start_login`] and sends `KE1`.
+//! 2. The server calls [`OpaqueServerSetup::start_login`] with the stored record, keeps the
+//!    [`OpaqueServerLogin`] state and sends `KE2`.
+//! 3. The client calls [`OpaqueClientLogin::finish`], which authenticates the server, and sends
+//!    `KE3`. The server checks it with [`OpaqueServerLogin::finish`].
+//!
+//! Both sides end up with the same session key, and the client also obtains an export key that
+//! only it can compute, for example to encrypt data it stores on the server.
+//!
+//! # Key Stretching
+//! The client hardens the OPRF output with a key stretching function, given as a [`Ksf`]. Any
+//! [`PasswordBasedDerivation`] such as Argon2id can be used, with a fixed all-zero salt since the
+//! OPRF output is already unique per user; [`IdentityKsf`] skips stretching and is meant for
+//! tests. Registration and every login must use the same function and parameters.
+//!
+//! # States
+//! The client and server states are serializable, so that a stateless server can keep them
+//! between requests. They contain secrets and must be stored encrypted.
+//!
+//! # Security Considerations
+//! - A login for an unknown user must still be answered, with `None` as the record, so that
+//!   an attacker cannot tell which users are registered.
+//! - The server must keep its [`OpaqueServerSetup`] secret and stable: its OPRF seed and key
+//!   pair are needed for every registered user.
+//! - Rate-limit failed logins, since each one tests a single password guess.
+//! - OPAQUE is not a FIPS approved algorithm, so it is not available with the `fips` feature.
+//!
+//! 提供了 RFC 9807 的 OPAQUE 增强口令认证密钥交换，使用基于 ristretto255 和 SHA-512 的
+//! OPAQUE-3DH 密钥交换。
+//!
+//! 服务器永远看不到口令，即使在注册期间也是如此，并且它存储的内容在不先与其运行协议的情况下无法
+//! 进行离线字典攻击；与之相比，SPAKE2+ 的验证方记录一旦被盗就可以直接被攻击。
+//!
+//! 注册需要一次往返：
+//! 1. 客户端调用 [`Opaque::start_registration`] 并将请求发送给服务器。
+//! 2. 服务器以 [`OpaqueServerSetup::registration_response`] 作答。
+//! 3. 客户端调用 [`OpaqueClientRegistration::finish`] 并上传生成的 [`OpaqueRecord`]，服务器将其
+//!    存储在凭据标识符之下。
+//!
+//! 登录需要三条消息：
+//! 1. 客户端调用 [`Opaque::start_login`] 并发送 `KE1`。
+//! 2. 服务器使用存储的记录调用 [`OpaqueServerSetup::start_login`]，保留 [`OpaqueServerLogin`]
+//!    状态并发送 `KE2`。
+//! 3. 客户端调用 [`OpaqueClientLogin::finish`]，它会认证服务器，然后发送 `KE3`。服务器使用
+//!    [`OpaqueServerLogin::finish`] 检查它。
+//!
+//! 双方最终得到相同的会话密钥，客户端还会获得一个只有它能计算的导出密钥，例如用于加密它存储在
+//! 服务器上的数据。
+//!
+//! # 密钥拉伸
+//! 客户端使用以 [`Ksf`] 给出的密钥拉伸函数加固 OPRF 输出。可以使用任何
+//! [`PasswordBasedDerivation`]（例如 Argon2id），并使用固定的全零盐，因为 OPRF 输出对每个用户
+//! 已经是唯一的；[`IdentityKsf`] 跳过拉伸，仅用于测试。注册和每次登录必须使用相同的函数和参数。
+//!
+//! # 状态
+//! 客户端和服务器状态是可序列化的，以便无状态服务器可以在请求之间保留它们。它们包含秘密，
+//! 必须加密存储。
+//!
+//! # 安全考虑
+//! - 对未知用户的登录仍然必须以 `None` 作为记录进行应答，使攻击者无法分辨哪些用户已注册。
+//! - 服务器必须保持其 [`OpaqueServerSetup`] 的机密性和稳定性：每个已注册用户都需要其 OPRF
+//!   种子和密钥对。
+//! - 请对失败的登录进行速率限制，因为每次登录都会测试一次口令猜测。
+//! - OPAQUE 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use crate::ct::ConstantTimeEq;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use crate::systems::oprf::ristretto255::{
+    ELEMENT_SIZE, Oprf, OprfBlind, OprfKey, derive_scalar, parse_element,
+};
+use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+use curve25519_dalek::scalar::Scalar;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use secrecy::{ExposeSecret, SecretBox};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+use zeroize::Zeroizing;
+
+/// The length in bytes of nonces and seeds.
+const NONCE_SIZE: usize = 32;
+
+/// The output length in bytes of SHA-512, and so of MACs and derived keys.
+const HASH_SIZE: usize = 64;
+
+/// The length in bytes of an envelope: a nonce followed by an authentication tag.
+const ENVELOPE_SIZE: usize = NONCE_SIZE + HASH_SIZE;
+
+/// The length in bytes of the masked server public key and envelope.
+const MASKED_RESPONSE_SIZE: usize = ELEMENT_SIZE + ENVELOPE_SIZE;
+
+/// The length in bytes of a credential response: evaluated element, masking nonce and masked
+/// response.
+const CREDENTIAL_RESPONSE_SIZE: usize = ELEMENT_SIZE + NONCE_SIZE + MASKED_RESPONSE_SIZE;
+
+/// The salt passed to a [`PasswordBasedDerivation`] used as key stretching function.
+const KSF_SALT: [u8; 16] = [0u8; 16];
+
+/// The length in bytes of a registration request.
+///
+/// 注册请求的字节长度。
+pub const REGISTRATION_REQUEST_SIZE: usize = ELEMENT_SIZE;
+
+/// The length in bytes of a registration response.
+///
+/// 注册响应的字节长度。
+pub const REGISTRATION_RESPONSE_SIZE: usize = 2 * ELEMENT_SIZE;
+
+/// The length in bytes of the client's first login message `KE1`.
+///
+/// 客户端第一条登录消息 `KE1` 的字节长度。
+pub const KE1_SIZE: usize = ELEMENT_SIZE + NONCE_SIZE + ELEMENT_SIZE;
+
+/// The length in bytes of the server's login message `KE2`.
+///
+/// 服务器登录消息 `KE2` 的字节长度。
+pub const KE2_SIZE: usize = CREDENTIAL_RESPONSE_SIZE + NONCE_SIZE + ELEMENT_SIZE + HASH_SIZE;
+
+/// The length in bytes of the client's final login message `KE3`.
+///
+/// 客户端最后一条登录消息 `KE3` 的字节长度。
+pub const KE3_SIZE: usize = HASH_SIZE;
+
+// ------------------- Key Stretching -------------------
+// ------------------- 密钥拉伸 -------------------
+
+/// A key stretching function applied by the client to the OPRF output.
+///
+/// 客户端应用于 OPRF 输出的密钥拉伸函数。
+pub trait Ksf {
+    /// Stretches the 64-byte OPRF output into a 64-byte value.
+    ///
+    /// 将 64 字节的 OPRF 输出拉伸为 64 字节的值。
+    fn stretch(&self, input: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error>;
+}
+
+/// The identity key stretching function, which returns its input unchanged.
+///
+/// It offers no protection against offline attacks on a stolen record and should only be used
+/// for tests and interoperability checks.
+///
+/// 恒等密钥拉伸函数，原样返回其输入。
+///
+/// 它不能防御对被盗记录的离线攻击，仅应用于测试和互操作性检查。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IdentityKsf;
+
+impl Ksf for IdentityKsf {
+    fn stretch(&self, input: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        Ok(Zeroizing::new(input.to_vec()))
+    }
+}
+
+impl<T: PasswordBasedDerivation> Ksf for T {
+    fn stretch(&self, input: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        let password = SecretBox::new(Box::from(input));
+        let stretched = self.derive(&password, &KSF_SALT, HASH_SIZE)?;
+        Ok(Zeroizing::new(stretched.as_bytes().to_vec()))
+    }
+}
+
+// ------------------- Identities and Records -------------------
+// ------------------- 身份与记录 -------------------
+
+/// The optional identities of the client and the server.
+///
+/// An absent identity defaults to the party's public key. Both sides must use the same
+/// identities during registration and every login.
+///
+/// 客户端和服务器的可选身份。
+///
+/// 缺省的身份默认为该方的公钥。双方在注册和每次登录期间必须使用相同的身份。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpaqueIdentities<'a> {
+    /// The client's identity, such as a user name.
+    ///
+    /// 客户端的身份，例如用户名。
+    pub client: Option<&'a [u8]>,
+    /// The server's identity, such as a domain name.
+    ///
+    /// 服务器的身份，例如域名。
+    pub server: Option<&'a [u8]>,
+}
+
+/// The record a server stores for a registered client.
+///
+/// It holds the client's public key, the masking key and the envelope. It must be kept secret:
+/// it does not allow logging in as the client, but it allows impersonating the server to the
+/// client and, together with the server's OPRF seed, an offline dictionary attack.
+///
+/// 服务器为已注册的客户端存储的记录。
+///
+/// 它包含客户端的公钥、掩码密钥和信封。它必须保密：它不允许以客户端身份登录，但允许向客户端冒充
+/// 服务器，并且与服务器的 OPRF 种子一起允许进行离线字典攻击。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpaqueRecord {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    client_public_key: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    masking_key: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    envelope: Vec<u8>,
+}
+
+impl OpaqueRecord {
+    /// The length in bytes of a serialized record.
+    ///
+    /// 序列化记录的字节长度。
+    pub const SIZE: usize = ELEMENT_SIZE + HASH_SIZE + ENVELOPE_SIZE;
+
+    /// Serializes the record.
+    ///
+    /// 序列化该记录。
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(Self::SIZE));
+        bytes.extend_from_slice(&self.client_public_key);
+        bytes.extend_from_slice(&self.masking_key);
+        bytes.extend_from_slice(&self.envelope);
+        bytes
+    }
+
+    /// Deserializes a record.
+    ///
+    /// Fails with `KeyError::InvalidLength` if the record does not have [`Self::SIZE`] bytes,
+    /// and with `KeyError::InvalidEncoding` if the client public key is malformed.
+    ///
+    /// 反序列化一个记录。
+    ///
+    /// 如果记录不是 [`Self::SIZE`] 字节，则以 `KeyError::InvalidLength` 失败；如果客户端公钥
+    /// 格式错误，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::SIZE {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        let (client_public_key, rest) = bytes.split_at(ELEMENT_SIZE);
+        let (masking_key, envelope) = rest.split_at(HASH_SIZE);
+        parse_element(client_public_key)?;
+        Ok(Self {
+            client_public_key: client_public_key.to_vec(),
+            masking_key: Zeroizing::new(masking_key.to_vec()),
+            envelope: envelope.to_vec(),
+        })
+    }
+
+    /// Creates a record for an unknown user, so that its login fails like a wrong password.
+    fn fake<R: CryptoRngCore>(rng: &mut R) -> Self {
+        let mut masking_key = Zeroizing::new(vec![0u8; HASH_SIZE]);
+        rng.fill_bytes(&mut masking_key);
+        let mut envelope = vec![0u8; ENVELOPE_SIZE];
+        rng.fill_bytes(&mut envelope);
+        let client_key = OprfKey::generate_with_rng(rng);
+        Self {
+            client_public_key: client_key.public_key(),
+            masking_key,
+            envelope,
+        }
+    }
+}
+
+impl core::fmt::Debug for OpaqueRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpaqueRecord").finish_non_exhaustive()
+    }
+}
+
+// ------------------- Server -------------------
+// ------------------- 服务器 -------------------
+
+/// The server's long-term OPAQUE configuration: its OPRF seed and its key pair.
+///
+/// 服务器的长期 OPAQUE 配置：其 OPRF 种子和密钥对。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpaqueServerSetup {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    oprf_seed: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    private_key: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    public_key: Vec<u8>,
+}
+
+impl OpaqueServerSetup {
+    /// Generates a configuration from the crate's system RNG.
+    ///
+    /// 从 crate 的系统 RNG 生成一个配置。
+    pub fn generate() -> Result<Self, Error> {
+        Self::generate_with_rng(&mut SystemRng::new())
+    }
+
+    /// Generates a configuration with randomness drawn from `rng`.
+    ///
+    /// 使用从 `rng` 获取的随机性生成一个配置。
+    pub fn generate_with_rng<R: CryptoRngCore>(rng: &mut R) -> Result<Self, Error> {
+        let mut oprf_seed = Zeroizing::new([0u8; HASH_SIZE]);
+        rng.fill_bytes(oprf_seed.as_mut());
+        let mut key_seed = Zeroizing::new([0u8; NONCE_SIZE]);
+        rng.fill_bytes(key_seed.as_mut());
+        let private_key = Zeroizing::new(derive_dh_scalar(key_seed.as_ref())?);
+        Self::from_parts(oprf_seed.as_ref(), &private_key.to_bytes())
+    }
+
+    /// Builds a configuration from a 64-byte OPRF seed and a 32-byte little-endian private
+    /// key.
+    ///
+    /// Fails with `KeyError::InvalidLength` if the seed does not have 64 bytes, and with
+    /// `KeyError::InvalidEncoding` if the private key is malformed.
+    ///
+    /// 从 64 字节的 OPRF 种子和 32 字节小端私钥构建一个配置。
+    ///
+    /// 如果种子不是 64 字节，则以 `KeyError::InvalidLength` 失败；如果私钥格式错误，则以
+    /// `KeyError::InvalidEncoding` 失败。
+    pub fn from_parts(oprf_seed: &[u8], private_key: &[u8]) -> Result<Self, Error> {
+        if oprf_seed.len() != HASH_SIZE {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        let key = OprfKey::from_bytes(private_key)?;
+        Ok(Self {
+            oprf_seed: Zeroizing::new(oprf_seed.to_vec()),
+            private_key: key.to_bytes(),
+            public_key: key.public_key(),
+        })
+    }
+
+    /// Returns the server's 32-byte public key.
+    ///
+    /// 返回服务器的 32 字节公钥。
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// Answers a client's registration request for `credential_identifier`, a stable and
+    /// unique identifier of the account such as its user ID.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if the request is malformed.
+    ///
+    /// 为 `credential_identifier`（账户的稳定且唯一的标识符，例如其用户 ID）应答客户端的注册
+    /// 请求。
+    ///
+    /// 如果请求格式错误，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn registration_response(
+        &self,
+        request: &[u8],
+        credential_identifier: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let oprf_key = self.oprf_key(credential_identifier)?;
+        let mut response = oprf_key
+            .blind_evaluate(request)
+            .map_err(|_| invalid_peer())?;
+        response.extend_from_slice(&self.public_key);
+        Ok(response)
+    }
+
+    /// Answers a client's `KE1`, drawing randomness from the crate's system RNG.
+    ///
+    /// See [`OpaqueServerSetup::start_login_with_rng`].
+    ///
+    /// 应答客户端的 `KE1`，随机性从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`OpaqueServerSetup::start_login_with_rng`]。
+    pub fn start_login(
+        &self,
+        record: Option<&OpaqueRecord>,
+        credential_identifier: &[u8],
+        ke1: &[u8],
+        identities: OpaqueIdentities<'_>,
+        context: &[u8],
+    ) -> Result<(OpaqueServerLogin, Vec<u8>), Error> {
+        self.start_login_with_rng(
+            &mut SystemRng::new(),
+            record,
+            credential_identifier,
+            ke1,
+            identities,
+            context,
+        )
+    }
+
+    /// Answers a client's `KE1` with randomness drawn from `rng`, and returns the server state
+    /// together with `KE2`.
+    ///
+    /// `record` is the record stored for `credential_identifier`, or `None` if there is none,
+    /// in which case the login proceeds with a fake record and fails at the last step.
+    /// `context` is shared application context, such as a protocol name and version, that both
+    /// sides must agree on.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if `KE1` is malformed.
+    ///
+    /// 使用从 `rng` 获取的随机性应答客户端的 `KE1`，并将服务器状态与 `KE2` 一起返回。
+    ///
+    /// `record` 是为 `credential_identifier` 存储的记录，如果没有则为 `None`，此时登录使用伪造的
+    /// 记录继续进行并在最后一步失败。`context` 是双方必须达成一致的共享应用上下文，例如协议名称和
+    /// 版本。
+    ///
+    /// 如果 `KE1` 格式错误，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn start_login_with_rng<R: CryptoRngCore>(
+        &self,
+        rng: &mut R,
+        record: Option<&OpaqueRecord>,
+        credential_identifier: &[u8],
+        ke1: &[u8],
+        identities: OpaqueIdentities<'_>,
+        context: &[u8],
+    ) -> Result<(OpaqueServerLogin, Vec<u8>), Error> {
+        let fake;
+        let record = match record {
+            Some(record) => record,
+            None => {
+                fake = OpaqueRecord::fake(rng);
+                &fake
+            }
+        };
+        let mut randomness = Zeroizing::new([0u8; 3 * NONCE_SIZE]);
+        rng.fill_bytes(randomness.as_mut());
+        let (masking_nonce, rest) = randomness.split_at(NONCE_SIZE);
+        let (server_nonce, keyshare_seed) = rest.split_at(NONCE_SIZE);
+        self.respond(
+            record,
+            credential_identifier,
+            ke1,
+            identities,
+            context,
+            &ServerRandomness {
+                masking_nonce,
+                server_nonce,
+                keyshare_seed,
+            },
+        )
+    }
+
+    fn respond(
+        &self,
+        record: &OpaqueRecord,
+        credential_identifier: &[u8],
+        ke1: &[u8],
+        identities: OpaqueIdentities<'_>,
+        context: &[u8],
+        randomness: &ServerRandomness<'_>,
+    ) -> Result<(OpaqueServerLogin, Vec<u8>), Error> {
+        if ke1.len() != KE1_SIZE {
+            return Err(invalid_peer());
+        }
+        let (blinded, rest) = ke1.split_at(ELEMENT_SIZE);
+        let client_keyshare = &rest[NONCE_SIZE..];
+
+        // Credential response: the evaluated element and the masked server public key and
+        // envelope.
+        // 凭据响应：求值元素以及被掩码的服务器公钥和信封。
+        let mut ke2 = self
+            .oprf_key(credential_identifier)?
+            .blind_evaluate(blinded)
+            .map_err(|_| invalid_peer())?;
+        ke2.extend_from_slice(randomness.masking_nonce);
+        let pad = credential_response_pad(&record.masking_key, randomness.masking_nonce)?;
+        let plaintext = self.public_key.iter().chain(record.envelope.iter());
+        ke2.extend(plaintext.zip(pad.iter()).map(|(byte, pad)| byte ^ pad));
+
+        // 3DH key exchange.
+        // 3DH 密钥交换。
+        let keyshare = Zeroizing::new(derive_dh_scalar(randomness.keyshare_seed)?);
+        ke2.extend_from_slice(randomness.server_nonce);
+        ke2.extend_from_slice(&public_key(&keyshare));
+        let private_key = Zeroizing::new(parse_scalar(&self.private_key)?);
+        let mut ikm = Zeroizing::new(Vec::with_capacity(3 * ELEMENT_SIZE));
+        ikm.extend_from_slice(&dh(&keyshare, client_keyshare)?);
+        ikm.extend_from_slice(&dh(&private_key, client_keyshare)?);
+        ikm.extend_from_slice(&dh(&keyshare, &record.client_public_key)?);
+
+        let client_identity = identities.client.unwrap_or(&record.client_public_key);
+        let server_identity = identities.server.unwrap_or(&self.public_key);
+        let preamble = preamble(context, client_identity, ke1, server_identity, &ke2)?;
+        let keys = derive_keys(&ikm, &preamble)?;
+        let server_mac = hmac_sha512(&keys.server_mac_key, &[&Sha512::digest(&preamble)])?;
+        let expected_client_mac = hmac_sha512(
+            &keys.client_mac_key,
+            &[&Sha512::new()
+                .chain_update(&preamble)
+                .chain_update(&server_mac)
+                .finalize()],
+        )?;
+        ke2.extend_from_slice(&server_mac);
+
+        let state = OpaqueServerLogin {
+            expected_client_mac,
+            session_key: keys.session_key,
+        };
+        Ok((state, ke2))
+    }
+
+    fn oprf_key(&self, credential_identifier: &[u8]) -> Result<OprfKey, Error> {
+        let mut seed = Zeroizing::new([0u8; NONCE_SIZE]);
+        expand(
+            &self.oprf_seed,
+            &[credential_identifier, b"OprfKey"],
+            seed.as_mut(),
+        )?;
+        OprfKey::derive(seed.as_ref(), b"OPAQUE-DeriveKeyPair")
+    }
+}
+
+impl core::fmt::Debug for OpaqueServerSetup {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpaqueServerSetup")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The randomness the server draws for a login.
+struct ServerRandomness<'a> {
+    masking_nonce: &'a [u8],
+    server_nonce: &'a [u8],
+    keyshare_seed: &'a [u8],
+}
+
+/// A server login waiting for the client's `KE3`.
+///
+/// 一个等待客户端 `KE3` 的服务器登录。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpaqueServerLogin {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    expected_client_mac: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    session_key: Zeroizing<Vec<u8>>,
+}
+
+impl OpaqueServerLogin {
+    /// Checks the client's `KE3` and returns the 64-byte session key.
+    ///
+    /// Fails with `KeyAgreementError::ConfirmationFailed` if the client used a wrong password
+    /// or other identities or context, if the user is unknown, or if the exchange was tampered
+    /// with.
+    ///
+    /// 检查客户端的 `KE3` 并返回 64 字节的会话密钥。
+    ///
+    /// 如果客户端使用了错误的口令或其他身份或上下文、用户未知或者交换被篡改，则以
+    /// `KeyAgreementError::ConfirmationFailed` 失败。
+    pub fn finish(self, ke3: &[u8]) -> Result<SharedSecret, Error> {
+        check_mac(&self.expected_client_mac, ke3)?;
+        Ok(SharedSecret::new(self.session_key.to_vec()))
+    }
+}
+
+impl core::fmt::Debug for OpaqueServerLogin {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpaqueServerLogin").finish_non_exhaustive()
+    }
+}
+
+// ------------------- Client -------------------
+// ------------------- 客户端 -------------------
+
+/// The client side of OPAQUE.
+///
+/// OPAQUE 的客户端。
+#[derive(Clone, Debug, Default)]
+pub struct Opaque;
+
+impl Opaque {
+    /// Starts a registration, drawing randomness from the crate's system RNG.
+    ///
+    /// See [`Opaque::start_registration_with_rng`].
+    ///
+    /// 开始一次注册，随机性从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Opaque::start_registration_with_rng`]。
+    pub fn start_registration(
+        password: &SecretBox<[u8]>,
+    ) -> Result<(OpaqueClientRegistration, Vec<u8>), Error> {
+        Self::start_registration_with_rng(&mut SystemRng::new(), password)
+    }
+
+    /// Starts a registration with randomness drawn from `rng`, and returns the client state
+    /// together with the request to send to the server.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the password is longer than 65535 bytes.
+    ///
+    /// 使用从 `rng` 获取的随机性开始一次注册，并将客户端状态与需要发送给服务器的请求一起返回。
+    ///
+    /// 如果口令长于 65535 字节，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn start_registration_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        password: &SecretBox<[u8]>,
+    ) -> Result<(OpaqueClientRegistration, Vec<u8>), Error> {
+        let (blind, request) = Oprf::blind_with_rng(rng, password.expose_secret())?;
+        let state = OpaqueClientRegistration {
+            password: Zeroizing::new(password.expose_secret().to_vec()),
+            blind,
+        };
+        Ok((state, request))
+    }
+
+    /// Starts a login, drawing randomness from the crate's system RNG.
+    ///
+    /// See [`Opaque::start_login_with_rng`].
+    ///
+    /// 开始一次登录，随机性从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Opaque::start_login_with_rng`]。
+    pub fn start_login(password: &SecretBox<[u8]>) -> Result<(OpaqueClientLogin, Vec<u8>), Error> {
+        Self::start_login_with_rng(&mut SystemRng::new(), password)
+    }
+
+    /// Starts a login with randomness drawn from `rng`, and returns the client state together
+    /// with `KE1`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the password is longer than 65535 bytes.
+    ///
+    /// 使用从 `rng` 获取的随机性开始一次登录，并将客户端状态与 `KE1` 一起返回。
+    ///
+    /// 如果口令长于 65535 字节，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn start_login_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        password: &SecretBox<[u8]>,
+    ) -> Result<(OpaqueClientLogin, Vec<u8>), Error> {
+        let (blind, blinded) = Oprf::blind_with_rng(rng, password.expose_secret())?;
+        let mut randomness = Zeroizing::new([0u8; 2 * NONCE_SIZE]);
+        rng.fill_bytes(randomness.as_mut());
+        let (client_nonce, keyshare_seed) = randomness.split_at(NONCE_SIZE);
+        OpaqueClientLogin::new(
+            password.expose_secret(),
+            blind,
+            blinded,
+            client_nonce,
+            keyshare_seed,
+        )
+    }
+}
+
+/// A client registration waiting for the server's response.
+///
+/// 一个等待服务器响应的客户端注册。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpaqueClientRegistration {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    password: Zeroizing<Vec<u8>>,
+    blind: OprfBlind,
+}
+
+impl OpaqueClientRegistration {
+    /// Processes the server's registration response, drawing randomness from the crate's
+    /// system RNG.
+    ///
+    /// See [`OpaqueClientRegistration::finish_with_rng`].
+    ///
+    /// 处理服务器的注册响应，随机性从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`OpaqueClientRegistration::finish_with_rng`]。
+    pub fn finish<K: Ksf>(
+        self,
+        ksf: &K,
+        response: &[u8],
+        identities: OpaqueIdentities<'_>,
+    ) -> Result<OpaqueRegistration, Error> {
+        self.finish_with_rng(&mut SystemRng::new(), ksf, response, identities)
+    }
+
+    /// Processes the server's registration response with randomness drawn from `rng`, and
+    /// returns the record to upload together with the export key.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if the response is malformed.
+    ///
+    /// 使用从 `rng` 获取的随机性处理服务器的注册响应，并返回需要上传的记录以及导出密钥。
+    ///
+    /// 如果响应格式错误，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn finish_with_rng<R: CryptoRngCore, K: Ksf>(
+        self,
+        rng: &mut R,
+        ksf: &K,
+        response: &[u8],
+        identities: OpaqueIdentities<'_>,
+    ) -> Result<OpaqueRegistration, Error> {
+        let mut envelope_nonce = [0u8; NONCE_SIZE];
+        rng.fill_bytes(&mut envelope_nonce);
+        self.store(ksf, response, identities, &envelope_nonce)
+    }
+
+    fn store<K: Ksf>(
+        self,
+        ksf: &K,
+        response: &[u8],
+        identities: OpaqueIdentities<'_>,
+        envelope_nonce: &[u8],
+    ) -> Result<OpaqueRegistration, Error> {
+        if response.len() != REGISTRATION_RESPONSE_SIZE {
+            return Err(invalid_peer());
+        }
+        let (evaluated, server_public_key) = response.split_at(ELEMENT_SIZE);
+        parse_element(server_public_key).map_err(|_| invalid_peer())?;
+        let randomized_password = randomized_password(ksf, &self.password, &self.blind, evaluated)?;
+
+        let mut masking_key = Zeroizing::new(vec![0u8; HASH_SIZE]);
+        expand(&randomized_password, &[b"MaskingKey"], &mut masking_key)?;
+        let keys = EnvelopeKeys::derive(&randomized_password, envelope_nonce)?;
+        let client_public_key = public_key(&keys.private_key);
+        let cleartext = cleartext_credentials(server_public_key, &client_public_key, identities)?;
+        let mut envelope = envelope_nonce.to_vec();
+        envelope.extend_from_slice(&hmac_sha512(&keys.auth_key, &[envelope_nonce, &cleartext])?);
+
+        Ok(OpaqueRegistration {
+            record: OpaqueRecord {
+                client_public_key,
+                masking_key,
+                envelope,
+            },
+            export_key: keys.export_key,
+        })
+    }
+}
+
+impl core::fmt::Debug for OpaqueClientRegistration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpaqueClientRegistration")
+            .finish_non_exhaustive()
+    }
+}
+
+/// The result of a client registration.
+///
+/// 客户端注册的结果。
+#[derive(Debug)]
+pub struct OpaqueRegistration {
+    /// The record to upload to the server.
+    ///
+    /// 需要上传到服务器的记录。
+    pub record: OpaqueRecord,
+    /// The 64-byte export key, which every successful login reproduces.
+    ///
+    /// 64 字节的导出密钥，每次成功登录都会重现它。
+    pub export_key: Zeroizing<Vec<u8>>,
+}
+
+/// A client login waiting for the server's `KE2`.
+///
+/// 一个等待服务器 `KE2` 的客户端登录。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpaqueClientLogin {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    password: Zeroizing<Vec<u8>>,
+    blind: OprfBlind,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    keyshare: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    ke1: Vec<u8>,
+}
+
+impl OpaqueClientLogin {
+    fn new(
+        password: &[u8],
+        blind: OprfBlind,
+        blinded: Vec<u8>,
+        client_nonce: &[u8],
+        keyshare_seed: &[u8],
+    ) -> Result<(Self, Vec<u8>), Error> {
+        let keyshare = Zeroizing::new(derive_dh_scalar(keyshare_seed)?);
+        let mut ke1 = blinded;
+        ke1.extend_from_slice(client_nonce);
+        ke1.extend_from_slice(&public_key(&keyshare));
+        let state = Self {
+            password: Zeroizing::new(password.to_vec()),
+            blind,
+            keyshare: Zeroizing::new(keyshare.to_bytes().to_vec()),
+            ke1: ke1.clone(),
+        };
+        Ok((state, ke1))
+    }
+
+    /// Processes the server's `KE2`, authenticating the server, and returns `KE3` together
+    /// with the session key and the export key.
+    ///
+    /// Fails with `KeyAgreementError::ConfirmationFailed` if the password is wrong, if the
+    /// server used other identities or context or does not know the user, or if the exchange
+    /// was tampered with, and with `KeyAgreementError::InvalidPeerPublicKey` if `KE2` is
+    /// malformed.
+    ///
+    /// 处理服务器的 `KE2` 并认证服务器，然后返回 `KE3` 以及会话密钥和导出密钥。
+    ///
+    /// 如果口令错误、服务器使用了其他身份或上下文或者不认识该用户，或者交换被篡改，则以
+    /// `KeyAgreementError::ConfirmationFailed` 失败；如果 `KE2` 格式错误，则以
+    /// `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn finish<K: Ksf>(
+        self,
+        ksf: &K,
+        ke2: &[u8],
+        identities: OpaqueIdentities<'_>,
+        context: &[u8],
+    ) -> Result<OpaqueClientSession, Error> {
+        if ke2.len() != KE2_SIZE {
+            return Err(invalid_peer());
+        }
+        let (evaluated, rest) = ke2.split_at(ELEMENT_SIZE);
+        let (masking_nonce, rest) = rest.split_at(NONCE_SIZE);
+        let (masked_response, rest) = rest.split_at(MASKED_RESPONSE_SIZE);
+        let server_keyshare = &rest[NONCE_SIZE..NONCE_SIZE + ELEMENT_SIZE];
+        let (transcript, server_mac) = ke2.split_at(KE2_SIZE - HASH_SIZE);
+
+        // Recover the server public key and the envelope, then the client's private key.
+        // 恢复服务器公钥和信封，然后恢复客户端的私钥。
+        let randomized_password = randomized_password(ksf, &self.password, &self.blind, evaluated)?;
+        let mut masking_key = Zeroizing::new(vec![0u8; HASH_SIZE]);
+        expand(&randomized_password, &[b"MaskingKey"], &mut masking_key)?;
+        let pad = credential_response_pad(&masking_key, masking_nonce)?;
+        let response: Zeroizing<Vec<u8>> = Zeroizing::new(
+            masked_response
+                .iter()
+                .zip(pad.iter())
+                .map(|(byte, pad)| byte ^ pad)
+                .collect(),
+        );
+        let (server_public_key, envelope) = response.split_at(ELEMENT_SIZE);
+        let (envelope_nonce, auth_tag) = envelope.split_at(NONCE_SIZE);
+        let keys = EnvelopeKeys::derive(&randomized_password, envelope_nonce)?;
+        let client_public_key = public_key(&keys.private_key);
+        let cleartext = cleartext_credentials(server_public_key, &client_public_key, identities)?;
+        check_mac(
+            &hmac_sha512(&keys.auth_key, &[envelope_nonce, &cleartext])?,
+            auth_tag,
+        )?;
+
+        // 3DH key exchange and server authentication.
+        // 3DH 密钥交换与服务器认证。
+        let keyshare = Zeroizing::new(parse_scalar(&self.keyshare)?);
+        let mut ikm = Zeroizing::new(Vec::with_capacity(3 * ELEMENT_SIZE));
+        ikm.extend_from_slice(&dh(&keyshare, server_keyshare)?);
+        ikm.extend_from_slice(&dh(&keyshare, server_public_key)?);
+        ikm.extend_from_slice(&dh(&keys.private_key, server_keyshare)?);
+
+        let client_identity = identities.client.unwrap_or(&client_public_key);
+        let server_identity = identities.server.unwrap_or(server_public_key);
+        let preamble = preamble(
+            context,
+            client_identity,
+            &self.ke1,
+            server_identity,
+            transcript,
+        )?;
+        let session_keys = derive_keys(&ikm, &preamble)?;
+        check_mac(
+            &hmac_sha512(&session_keys.server_mac_key, &[&Sha512::digest(&preamble)])?,
+            server_mac,
+        )?;
+        let client_mac = hmac_sha512(
+            &session_keys.client_mac_key,
+            &[&Sha512::new()
+                .chain_update(&preamble)
+                .chain_update(server_mac)
+                .finalize()],
+        )?;
+
+        Ok(OpaqueClientSession {
+            ke3: client_mac.to_vec(),
+            session_key: SharedSecret::new(session_keys.session_key.to_vec()),
+            export_key: keys.export_key,
+        })
+    }
+}
+
+impl core::fmt::Debug for OpaqueClientLogin {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpaqueClientLogin").finish_non_exhaustive()
+    }
+}
+
+/// The result of a successful client login.
+///
+/// 成功的客户端登录的结果。
+#[derive(Debug)]
+pub struct OpaqueClientSession {
+    /// The message `KE3` to send to the server.
+    ///
+    /// 需要发送给服务器的消息 `KE3`。
+    pub ke3: Vec<u8>,
+    /// The 64-byte session key shared with the server.
+    ///
+    /// 与服务器共享的 64 字节会话密钥。
+    pub session_key: SharedSecret,
+    /// The 64-byte export key, the same as at registration.
+    ///
+    /// 64 字节的导出密钥，与注册时相同。
+    pub export_key: Zeroizing<Vec<u8>>,
+}
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// The keys derived from the randomized password and an envelope nonce.
+struct EnvelopeKeys {
+    auth_key: Zeroizing<Vec<u8>>,
+    export_key: Zeroizing<Vec<u8>>,
+    private_key: Zeroizing<Scalar>,
+}
+
+impl EnvelopeKeys {
+    fn derive(randomized_password: &[u8], envelope_nonce: &[u8]) -> Result<Self, Error> {
+        let mut auth_key = Zeroizing::new(vec![0u8; HASH_SIZE]);
+        expand(
+            randomized_password,
+            &[envelope_nonce, b"AuthKey"],
+            &mut auth_key,
+        )?;
+        let mut export_key = Zeroizing::new(vec![0u8; HASH_SIZE]);
+        expand(
+            randomized_password,
+            &[envelope_nonce, b"ExportKey"],
+            &mut export_key,
+        )?;
+        let mut seed = Zeroizing::new([0u8; NONCE_SIZE]);
+        expand(
+            randomized_password,
+            &[envelope_nonce, b"PrivateKey"],
+            seed.as_mut(),
+        )?;
+        Ok(Self {
+            auth_key,
+            export_key,
+            private_key: Zeroizing::new(derive_dh_scalar(seed.as_ref())?),
+        })
+    }
+}
+
+/// The keys of the 3DH key schedule.
+struct SessionKeys {
+    server_mac_key: Zeroizing<Vec<u8>>,
+    client_mac_key: Zeroizing<Vec<u8>>,
+    session_key: Zeroizing<Vec<u8>>,
+}
+
+fn derive_keys(ikm: &[u8], preamble: &[u8]) -> Result<SessionKeys, Error> {
+    let preamble_hash = Sha512::digest(preamble);
+    let (prk, _) = Hkdf::<Sha512>::extract(None, ikm);
+    let prk = Zeroizing::new(prk.to_vec());
+    let handshake_secret = expand_label(&prk, b"HandshakeSecret", &preamble_hash)?;
+    Ok(SessionKeys {
+        server_mac_key: expand_label(&handshake_secret, b"ServerMAC", &[])?,
+        client_mac_key: expand_label(&handshake_secret, b"ClientMAC", &[])?,
+        session_key: expand_label(&prk, b"SessionKey", &preamble_hash)?,
+    })
+}
+
+/// `Expand-Label` of RFC 9807, always producing a hash-sized output.
+fn expand_label(secret: &[u8], label: &[u8], context: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let length = (HASH_SIZE as u16).to_be_bytes();
+    let label_length = [(b"OPAQUE-".len() + label.len()) as u8];
+    let context_length = [context.len() as u8];
+    let mut output = Zeroizing::new(vec![0u8; HASH_SIZE]);
+    expand(
+        secret,
+        &[
+            &length,
+            &label_length,
+            b"OPAQUE-",
+            label,
+            &context_length,
+            context,
+        ],
+        &mut output,
+    )?;
+    Ok(output)
+}
+
+fn preamble(
+    context: &[u8],
+    client_identity: &[u8],
+    ke1: &[u8],
+    server_identity: &[u8],
+    ke2_transcript: &[u8],
+) -> Result<Vec<u8>, Error> {
+    let mut preamble = b"OPAQUEv1-".to_vec();
+    for (field, followed_by) in [
+        (context, &[][..]),
+        (client_identity, ke1),
+        (server_identity, ke2_transcript),
+    ] {
+        preamble.extend_from_slice(&length_prefix(field)?);
+        preamble.extend_from_slice(field);
+        preamble.extend_from_slice(followed_by);
+    }
+    Ok(preamble)
+}
+
+fn cleartext_credentials(
+    server_public_key: &[u8],
+    client_public_key: &[u8],
+    identities: OpaqueIdentities<'_>,
+) -> Result<Vec<u8>, Error> {
+    let server_identity = identities.server.unwrap_or(server_public_key);
+    let client_identity = identities.client.unwrap_or(client_public_key);
+    let mut cleartext = server_public_key.to_vec();
+    for identity in [server_identity, client_identity] {
+        cleartext.extend_from_slice(&length_prefix(identity)?);
+        cleartext.extend_from_slice(identity);
+    }
+    Ok(cleartext)
+}
+
+fn randomized_password<K: Ksf>(
+    ksf: &K,
+    password: &[u8],
+    blind: &OprfBlind,
+    evaluated: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let oprf_output = Oprf::finalize(password, blind, evaluated).map_err(|_| invalid_peer())?;
+    let stretched = ksf.stretch(&oprf_output)?;
+    let mut ikm = Zeroizing::new(oprf_output.to_vec());
+    ikm.extend_from_slice(&stretched);
+    let (prk, _) = Hkdf::<Sha512>::extract(None, &ikm);
+    Ok(Zeroizing::new(prk.to_vec()))
+}
+
+fn credential_response_pad(
+    masking_key: &[u8],
+    masking_nonce: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let mut pad = Zeroizing::new(vec![0u8; MASKED_RESPONSE_SIZE]);
+    expand(
+        masking_key,
+        &[masking_nonce, b"CredentialResponsePad"],
+        &mut pad,
+    )?;
+    Ok(pad)
+}
+
+fn expand(prk: &[u8], info: &[&[u8]], output: &mut [u8]) -> Result<(), Error> {
+    Hkdf::<Sha512>::from_prk(prk)
+        .and_then(|hkdf| {
+            hkdf.expand_multi_info(info, output)
+                .map_err(|_| hkdf::InvalidPrkLength)
+        })
+        .map_err(|_| Error::KeyAgreement(KeyAgreementError::AgreementFailed))
+}
+
+fn hmac_sha512(key: &[u8], message: &[&[u8]]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key)
+        .map_err(|_| Error::KeyAgreement(KeyAgreementError::AgreementFailed))?;
+    for part in message {
+        mac.update(part);
+    }
+    Ok(Zeroizing::new(mac.finalize().into_bytes().to_vec()))
+}
+
+fn check_mac(expected: &[u8], received: &[u8]) -> Result<(), Error> {
+    if bool::from(expected.ct_eq(received)) {
+        Ok(())
+    } else {
+        Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+    }
+}
+
+/// `DeriveDiffieHellmanKeyPair` of RFC 9807, returning the private scalar.
+fn derive_dh_scalar(seed: &[u8]) -> Result<Scalar, Error> {
+    derive_scalar(seed, b"OPAQUE-DeriveDiffieHellmanKeyPair")
+}
+
+fn public_key(private_key: &Scalar) -> Vec<u8> {
+    (RISTRETTO_BASEPOINT_POINT * private_key)
+        .compress()
+        .to_bytes()
+        .to_vec()
+}
+
+fn dh(private_key: &Scalar, public_key: &[u8]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let point = parse_element(public_key).map_err(|_| invalid_peer())?;
+    Ok(Zeroizing::new(
+        (point * private_key).compress().to_bytes().to_vec(),
+    ))
+}
+
+fn parse_scalar(bytes: &[u8]) -> Result<Scalar, Error> {
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+    Option::from(Scalar::from_canonical_bytes(bytes)).ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+fn length_prefix(bytes: &[u8]) -> Result<[u8; 2], Error> {
+    u16::try_from(bytes.len())
+        .map(u16::to_be_bytes)
+        .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+}
+
+fn invalid_peer() -> Error {
+    Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::oprf::ristretto255::blind_with_scalar;
+    use crate::systems::test_rng::SeededRng;
+
+    const CONTEXT: &[u8] = b"OPAQUE-POC";
+
+    fn bytes(hex: &str) -> Vec<u8> {
+        hex::decode(hex).unwrap_or_default()
+    }
+
+    fn password(password: &[u8]) -> SecretBox<[u8]> {
+        SecretBox::new(Box::from(password))
+    }
+
+    fn register(
+        rng: &mut SeededRng,
+        server: &OpaqueServerSetup,
+        secret: &[u8],
+        identities: OpaqueIdentities<'_>,
+    ) -> Result<OpaqueRegistration, Error> {
+        let (client, request) = Opaque::start_registration_with_rng(rng, &password(secret))?;
+        assert_eq!(request.len(), REGISTRATION_REQUEST_SIZE);
+        let response = server.registration_response(&request, b"alice")?;
+        assert_eq!(response.len(), REGISTRATION_RESPONSE_SIZE);
+        client.finish_with_rng(rng, &IdentityKsf, &response, identities)
+    }
+
+    #[test]
+    fn test_rfc9807_vector() -> Result<(), Error> {
+        // OPAQUE-3DH real test vector 1 of RFC 9807 (ristretto255, SHA-512, identity KSF).
+        // RFC 9807 的 OPAQUE-3DH 真实测试向量 1（ristretto255、SHA-512、恒等 KSF）。
+        let secret = b"CorrectHorseBatteryStaple";
+        let server = OpaqueServerSetup::from_parts(
+            &bytes(
+                "f433d0227b0b9dd54f7c4422b600e764e47fb503f1f9a0f0a47c6606b054a7fd\
+                 c65347f1a08f277e22358bbabe26f823fca82c7848e9a75661f4ec5d5c1989ef",
+            ),
+            &bytes("47451a85372f8b3537e249d7b54188091fb18edde78094b43e2ba42b5eb89f0d"),
+        )?;
+        assert_eq!(
+            hex::encode(server.public_key()),
+            "b2fe7af9f48cc502d016729d2fe25cdd433f2c4bc904660b2a382c9b79df1a78"
+        );
+
+        // Registration.
+        // 注册。
+        let blind = parse_scalar(&bytes(
+            "76cfbfe758db884bebb33582331ba9f159720ca8784a2a070a265d9c2d6abe01",
+        ))?;
+        let request = blind_with_scalar(secret, &blind)?;
+        assert_eq!(
+            hex::encode(&request),
+            "5059ff249eb1551b7ce4991f3336205bde44a105a032e747d21bf382e75f7a71"
+        );
+        let response = server.registration_response(&request, b"1234")?;
+        assert_eq!(
+            hex::encode(&response),
+            "7408a268083e03abc7097fc05b587834539065e86fb0c7b6342fcf5e01e5b019\
+             b2fe7af9f48cc502d016729d2fe25cdd433f2c4bc904660b2a382c9b79df1a78"
+        );
+        let client = OpaqueClientRegistration {
+            password: Zeroizing::new(secret.to_vec()),
+            blind: OprfBlind::from_bytes(&blind.to_bytes())?,
+        };
+        let registration = client.store(
+            &IdentityKsf,
+            &response,
+            OpaqueIdentities::default(),
+            &bytes("ac13171b2f17bc2c74997f0fce1e1f35bec6b91fe2e12dbd323d23ba7a38dfec"),
+        )?;
+        assert_eq!(
+            hex::encode(&registration.record.client_public_key),
+            "76a845464c68a5d2f7e442436bb1424953b17d3e2e289ccbaccafb57ac5c3675"
+        );
+        let export_key = "1ef15b4fa99e8a852412450ab78713aad30d21fa6966c9b8c9fb3262a970dc62\
+                          950d4dd4ed62598229b1b72794fc0335199d9f7fcc6eaedde92cc04870e63f16";
+        assert_eq!(hex::encode(&registration.export_key), export_key);
+
+        // Login.
+        // 登录。
+        let blind = parse_scalar(&bytes(
+            "6ecc102d2e7a7cf49617aad7bbe188556792d4acd60a1a8a8d2b65d4b0790308",
+        ))?;
+        let (client, ke1) = OpaqueClientLogin::new(
+            secret,
+            OprfBlind::from_bytes(&blind.to_bytes())?,
+            blind_with_scalar(secret, &blind)?,
+            &bytes("da7e07376d6d6f034cfa9bb537d11b8c6b4238c334333d1f0aebb380cae6a6cc"),
+            &bytes("82850a697b42a505f5b68fcdafce8c31f0af2b581f063cf1091933541936304b"),
+        )?;
+        let (server_login, ke2) = server.respond(
+            &registration.record,
+            b"1234",
+            &ke1,
+            OpaqueIdentities::default(),
+            CONTEXT,
+            &ServerRandomness {
+                masking_nonce: &bytes(
+                    "38fe59af0df2c79f57b8780278f5ae47355fe1f817119041951c80f612fdfc6d",
+                ),
+                server_nonce: &bytes(
+                    "71cd9960ecef2fe0d0f7494986fa3d8b2bb01963537e60efb13981e138e3d4a1",
+                ),
+                keyshare_seed: &bytes(
+                    "05a4f54206eef1ba2f615bc0aa285cb22f26d1153b5b40a1e85ff80da12f982f",
+                ),
+            },
+        )?;
+        let session = client.finish(&IdentityKsf, &ke2, OpaqueIdentities::default(), CONTEXT)?;
+        let session_key = "42afde6f5aca0cfa5c163763fbad55e73a41db6b41bc87b8e7b62214a8eedc67\
+                           31fa3cb857d657ab9b3764b89a84e91ebcb4785166fbb02cedfcbdfda215b96f";
+        assert_eq!(hex::encode(session.session_key.expose_raw()), session_key);
+        assert_eq!(hex::encode(&session.export_key), export_key);
+        let server_key = server_login.finish(&session.ke3)?;
+        assert_eq!(hex::encode(server_key.expose_raw()), session_key);
+        Ok(())
+    }
+
+    #[test]
+    fn test_opaque() -> Result<(), Error> {
+        let mut rng = SeededRng(11);
+        let server = OpaqueServerSetup::generate_with_rng(&mut rng)?;
+        let identities = OpaqueIdentities {
+            client: Some(b"alice"),
+            server: Some(b"example.com"),
+        };
+        let registration = register(&mut rng, &server, b"hunter2", identities)?;
+        let record = OpaqueRecord::from_bytes(&registration.record.to_bytes())?;
+
+        let (client, ke1) = Opaque::start_login_with_rng(&mut rng, &password(b"hunter2"))?;
+        assert_eq!(ke1.len(), KE1_SIZE);
+        let (server_login, ke2) = server.start_login_with_rng(
+            &mut rng,
+            Some(&record),
+            b"alice",
+            &ke1,
+            identities,
+            b"v1",
+        )?;
+        assert_eq!(ke2.len(), KE2_SIZE);
+        let session = client.finish(&IdentityKsf, &ke2, identities, b"v1")?;
+        assert_eq!(session.ke3.len(), KE3_SIZE);
+        assert_eq!(session.export_key, registration.export_key);
+        let server_key = server_login.finish(&session.ke3)?;
+        assert_eq!(server_key.expose_raw(), session.session_key.expose_raw());
+        assert_eq!(server_key.expose_raw().len(), HASH_SIZE);
+
+        // A wrong password, other identities or another context make the client reject the
+        // server, so no KE3 is ever produced.
+        // 错误的口令、其他身份或另一个上下文会使客户端拒绝服务器，因此永远不会产生 KE3。
+        let attempts: [(&[u8], OpaqueIdentities<'_>, &[u8]); 3] = [
+            (b"hunter3", identities, b"v1"),
+            (b"hunter2", OpaqueIdentities::default(), b"v1"),
+            (b"hunter2", identities, b"v2"),
+        ];
+        for (secret, client_identities, context) in attempts {
+            let (client, ke1) = Opaque::start_login_with_rng(&mut rng, &password(secret))?;
+            let (_, ke2) = server.start_login_with_rng(
+                &mut rng,
+                Some(&record),
+                b"alice",
+                &ke1,
+                identities,
+                b"v1",
+            )?;
+            assert!(matches!(
+                client.finish(&IdentityKsf, &ke2, client_identities, context),
+                Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+            ));
+        }
+
+        // A tampered KE3 is rejected by the server.
+        // 被篡改的 KE3 会被服务器拒绝。
+        let (client, ke1) = Opaque::start_login_with_rng(&mut rng, &password(b"hunter2"))?;
+        let (server_login, ke2) = server.start_login_with_rng(
+            &mut rng,
+            Some(&record),
+            b"alice",
+            &ke1,
+            identities,
+            b"v1",
+        )?;
+        let mut ke3 = client.finish(&IdentityKsf, &ke2, identities, b"v1")?.ke3;
+        ke3[0] ^= 1;
+        assert!(matches!(
+            server_login.finish(&ke3),
+            Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_user() -> Result<(), Error> {
+        let mut rng = SeededRng(12);
+        let server = OpaqueServerSetup::generate_with_rng(&mut rng)?;
+        let (client, ke1) = Opaque::start_login_with_rng(&mut rng, &password(b"hunter2"))?;
+        let (_, ke2) = server.start_login_with_rng(
+            &mut rng,
+            None,
+            b"mallory",
+            &ke1,
+            OpaqueIdentities::default(),
+            b"",
+        )?;
+        assert_eq!(ke2.len(), KE2_SIZE);
+        assert!(matches!(
+            client.finish(&IdentityKsf, &ke2, OpaqueIdentities::default(), b""),
+            Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_messages() -> Result<(), Error> {
+        let mut rng = SeededRng(13);
+        let server = OpaqueServerSetup::generate_with_rng(&mut rng)?;
+        let identities = OpaqueIdentities::default();
+        assert!(matches!(
+            server.registration_response(&[0u8; REGISTRATION_REQUEST_SIZE], b"alice"),
+            Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+        ));
+        assert!(matches!(
+            server.start_login_with_rng(
+                &mut rng,
+                None,
+                b"alice",
+                &[0u8; KE1_SIZE],
+                identities,
+                b""
+            ),
+            Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+        ));
+
+        let (client, _) = Opaque::start_registration_with_rng(&mut rng, &password(b"pw"))?;
+        assert!(matches!(
+            client.finish_with_rng(&mut rng, &IdentityKsf, &[0u8; 10], identities),
+            Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+        ));
+        let (client, _) = Opaque::start_login_with_rng(&mut rng, &password(b"pw"))?;
+        assert!(matches!(
+            client.finish(&IdentityKsf, &[0u8; KE2_SIZE], identities, b""),
+            Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+        ));
+        assert!(OpaqueRecord::from_bytes(&[0u8; OpaqueRecord::SIZE]).is_err());
+        assert!(OpaqueServerSetup::from_parts(&[0u8; 32], &[1u8; 32]).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "argon2-default")]
+    #[test]
+    fn test_argon2_ksf() -> Result<(), Error> {
+        use crate::systems::kdf::argon2::Argon2id;
+
+        let mut rng = SeededRng(14);
+        let ksf = Argon2id::new(64, 1, 1);
+        let server = OpaqueServerSetup::generate_with_rng(&mut rng)?;
+        let identities = OpaqueIdentities::default();
+        let (client, request) = Opaque::start_registration_with_rng(&mut rng, &password(b"pw"))?;
+        let response = server.registration_response(&request, b"alice")?;
+        let registration = client.finish_with_rng(&mut rng, &ksf, &response, identities)?;
+
+        let (client, ke1) = Opaque::start_login_with_rng(&mut rng, &password(b"pw"))?;
+        let (_, ke2) = server.start_login_with_rng(
+            &mut rng,
+            Some(&registration.record),
+            b"alice",
+            &ke1,
+            identities,
+            b"",
+        )?;
+        assert!(matches!(
+            client.clone().finish(&IdentityKsf, &ke2, identities, b""),
+            Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+        ));
+        let session = client.finish(&ksf, &ke2, identities, b"")?;
+        assert_eq!(session.export_key, registration.export_key);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_states() -> Result<(), Error> {
+        let invalid = || Error::Key(KeyError::InvalidEncoding);
+        let mut rng = SeededRng(15);
+        let server = OpaqueServerSetup::generate_with_rng(&mut rng)?;
+        let server: OpaqueServerSetup =
+            serde_json::from_str(&serde_json::to_string(&server).map_err(|_| invalid())?)
+                .map_err(|_| invalid())?;
+        let identities = OpaqueIdentities::default();
+        let registration = register(&mut rng, &server, b"pw", identities)?;
+
+        let (client, ke1) = Opaque::start_login_with_rng(&mut rng, &password(b"pw"))?;
+        let client: OpaqueClientLogin =
+            serde_json::from_str(&serde_json::to_string(&client).map_err(|_| invalid())?)
+                .map_err(|_| invalid())?;
+        let (server_login, ke2) = server.start_login_with_rng(
+            &mut rng,
+            Some(&registration.record),
+            b"alice",
+            &ke1,
+            identities,
+            b"",
+        )?;
+        let server_login: OpaqueServerLogin =
+            serde_json::from_str(&serde_json::to_string(&server_login).map_err(|_| invalid())?)
+                .map_err(|_| invalid())?;
+        let session = client.finish(&IdentityKsf, &ke2, identities, b"")?;
+        let server_key = server_login.finish(&session.ke3)?;
+        assert_eq!(server_key.expose_raw(), session.session_key.expose_raw());
+        Ok(())
+    }
+}
@@ -0,0 +1,1047 @@
+//! Provides the SPAKE2 (RFC 9382) and SPAKE2+ (RFC 9383) password-authenticated key exchanges
+//! over P-256.
+//!
+//! Both protocols turn a password shared by two parties into a strong shared secret, such that
+//! an eavesdropper or an active attacker learns nothing that allows an offline dictionary
+//! attack: every guess of the password needs one run of the protocol with a real party.
+//!
+//! - [`Spake2`] is the balanced protocol: both parties hold the same password-derived value.
+//!   It suits pairing two devices over a code shown on one of them.
+//! - [`Spake2Plus`] is the augmented protocol: the verifier (e.g. a server or an accessory)
+//!   stores only a [`Spake2PlusRecord`] derived from the password, from which the password
+//!   cannot be used to log in directly.
+//!
+//! Both sessions are state machines that release the [`SharedSecret`] only after the peer's key
+//! confirmation has been checked, so a session with the wrong password never yields a key.
+//!
+//! # Ciphersuite
+//! The ciphersuites are SPAKE2-P256-SHA256-HKDF-HMAC and
+//! SPAKE2+-P256-SHA256-HKDF-SHA256-HMAC-SHA256. Shares are uncompressed SEC1 points of
+//! [`SHARE_SIZE`] bytes and confirmations are HMAC-SHA256 tags of [`CONFIRMATION_SIZE`] bytes.
+//!
+//! # Passwords
+//! The password inputs are not passwords but the output of a memory-hard function such as
+//! Argon2 or scrypt over the password (and, for SPAKE2+, over the two identities as RFC 9383
+//! recommends), so that a stolen SPAKE2+ record is expensive to brute force. They must be at
+//! least [`MIN_PASSWORD_HASH_SIZE`] bytes long for each derived scalar.
+//!
+//! # Security Considerations
+//! - Sessions use fresh randomness and must not be reused; `finish` and `verify` consume them.
+//! - Both parties must agree on the identities and the additional data or context; otherwise
+//!   the key confirmation fails as with a wrong password.
+//! - Rate-limit failed sessions, since each one tests one password guess.
+//! - SPAKE2 and SPAKE2+ are not FIPS approved algorithms, so they are not available with the
+//!   `fips` feature.
+//!
+//! 提供了基于 P-256 的 SPAKE2 (RFC 9382) 和 SPAKE2+ (RFC 9383) 口令认证密钥交换。
+//!
+//! 两个协议都将双方共享的口令变为一个强共享秘密，使得窃听者或主动攻击者无法获得任何可以进行
+//! 离线字典攻击的信息：每次猜测口令都需要与真实的一方运行一次协议。
+//!
+//! - [`Spake2`] 是平衡协议：双方持有相同的由口令派生的值。它适用于通过其中一台设备上显示的
+//!   代码配对两台设备。
+//! - [`Spake2Plus`] 是增强协议：验证方（例如服务器或配件）只存储一个由口令派生的
+//!   [`Spake2PlusRecord`]，无法用它直接代替口令登录。
+//!
+//! 两种会话都是状态机，只有在检查了对方的密钥确认之后才会释放 [`SharedSecret`]，因此使用错误
+//! 口令的会话永远不会产生密钥。
+//!
+//! # 密码套件
+//! 密码套件为 SPAKE2-P256-SHA256-HKDF-HMAC 和 SPAKE2+-P256-SHA256-HKDF-SHA256-HMAC-SHA256。
+//! 份额是 [`SHARE_SIZE`] 字节的未压缩 SEC1 点，确认是 [`CONFIRMATION_SIZE`] 字节的
+//! HMAC-SHA256 标签。
+//!
+//! # 口令
+//! 口令输入不是口令本身，而是对口令（对于 SPAKE2+，按照 RFC 9383 的建议还包括两个身份）
+//! 运行 Argon2 或 scrypt 等内存困难函数的输出，使得被盗的 SPAKE2+ 记录难以暴力破解。对于每个
+//! 派生的标量，它们至少需要 [`MIN_PASSWORD_HASH_SIZE`] 字节长。
+//!
+//! # 安全考虑
+//! - 会话使用新的随机性并且不能重复使用；`finish` 和 `verify` 会消耗它们。
+//! - 双方必须就身份以及附加数据或上下文达成一致；否则密钥确认会像口令错误时一样失败。
+//! - 请对失败的会话进行速率限制，因为每次会话都会测试一次口令猜测。
+//! - SPAKE2 和 SPAKE2+ 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::{vec, vec::Vec};
+use crate::ct::ConstantTimeEq;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use elliptic_curve::ff::{Field, PrimeField};
+use elliptic_curve::group::{Group, GroupEncoding};
+use elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use p256::{AffinePoint, EncodedPoint, ProjectivePoint, Scalar};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+/// The length in bytes of a share, an uncompressed P-256 point.
+///
+/// 份额的字节长度，即一个未压缩的 P-256 点。
+pub const SHARE_SIZE: usize = 65;
+
+/// The length in bytes of a key confirmation.
+///
+/// 密钥确认的字节长度。
+pub const CONFIRMATION_SIZE: usize = 32;
+
+/// The minimum length in bytes of the password hash each scalar is derived from.
+///
+/// 派生每个标量所用的口令哈希的最小字节长度。
+pub const MIN_PASSWORD_HASH_SIZE: usize = 40;
+
+/// The point `M` of RFC 9382 for P-256, compressed.
+const M: [u8; 33] = [
+    0x02, 0x88, 0x6e, 0x2f, 0x97, 0xac, 0xe4, 0x6e, 0x55, 0xba, 0x9d, 0xd7, 0x24, 0x25, 0x79, 0xf2,
+    0x99, 0x3b, 0x64, 0xe1, 0x6e, 0xf3, 0xdc, 0xab, 0x95, 0xaf, 0xd4, 0x97, 0x33, 0x3d, 0x8f, 0xa1,
+    0x2f,
+];
+
+/// The point `N` of RFC 9382 for P-256, compressed.
+const N: [u8; 33] = [
+    0x03, 0xd8, 0xbb, 0xd6, 0xc6, 0x39, 0xc6, 0x29, 0x37, 0xb0, 0x4d, 0x99, 0x7f, 0x38, 0xc3, 0x77,
+    0x07, 0x19, 0xc6, 0x29, 0xd7, 0x01, 0x4d, 0x49, 0xa2, 0x4b, 0x4f, 0x98, 0xba, 0xa1, 0x29, 0x2b,
+    0x49,
+];
+
+// ------------------- SPAKE2 -------------------
+// ------------------- SPAKE2 -------------------
+
+/// The role of a party in SPAKE2, which fixes whether it blinds its share with `M` or `N`.
+///
+/// SPAKE2 中一方的角色，它决定该方使用 `M` 还是 `N` 对其份额进行盲化。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spake2Role {
+    /// Party A, usually the one that initiates the exchange.
+    ///
+    /// A 方，通常是发起交换的一方。
+    A,
+    /// Party B, usually the one that responds.
+    ///
+    /// B 方，通常是响应的一方。
+    B,
+}
+
+/// The SPAKE2 balanced password-authenticated key exchange.
+///
+/// SPAKE2 平衡口令认证密钥交换。
+#[derive(Clone, Debug, Default)]
+pub struct Spake2;
+
+impl Spake2 {
+    /// Starts a session as `role`, drawing the ephemeral scalar from the crate's system RNG.
+    ///
+    /// See [`Spake2::start_with_rng`].
+    ///
+    /// 以 `role` 的身份开始一个会话，临时标量从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Spake2::start_with_rng`]。
+    pub fn start(
+        role: Spake2Role,
+        password_hash: &[u8],
+        identity_a: &[u8],
+        identity_b: &[u8],
+        aad: &[u8],
+    ) -> Result<(Spake2Session, Vec<u8>), Error> {
+        Self::start_with_rng(
+            &mut SystemRng::new(),
+            role,
+            password_hash,
+            identity_a,
+            identity_b,
+            aad,
+        )
+    }
+
+    /// Starts a session as `role` with randomness drawn from `rng`, and returns it together
+    /// with the share to send to the peer.
+    ///
+    /// `identity_a` and `identity_b` name the two parties and may be empty; `aad` is bound
+    /// into the key confirmation. Fails with `KeyError::InvalidLength` if the password hash is
+    /// shorter than [`MIN_PASSWORD_HASH_SIZE`].
+    ///
+    /// 以 `role` 的身份使用从 `rng` 获取的随机性开始一个会话，并将其与需要发送给对方的份额一起
+    /// 返回。
+    ///
+    /// `identity_a` 和 `identity_b` 指定双方的名称，可以为空；`aad` 被绑定到密钥确认中。如果口令
+    /// 哈希短于 [`MIN_PASSWORD_HASH_SIZE`]，则以 `KeyError::InvalidLength` 失败。
+    pub fn start_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        role: Spake2Role,
+        password_hash: &[u8],
+        identity_a: &[u8],
+        identity_b: &[u8],
+        aad: &[u8],
+    ) -> Result<(Spake2Session, Vec<u8>), Error> {
+        let w = password_scalar(password_hash)?;
+        let x = Zeroizing::new(Scalar::random(&mut *rng));
+        Spake2Session::new(role, w, x, identity_a, identity_b, aad)
+    }
+}
+
+/// A SPAKE2 session waiting for the peer's share.
+///
+/// 一个等待对方份额的 SPAKE2 会话。
+pub struct Spake2Session {
+    role: Spake2Role,
+    w: Zeroizing<Scalar>,
+    x: Zeroizing<Scalar>,
+    share: Vec<u8>,
+    identity_a: Vec<u8>,
+    identity_b: Vec<u8>,
+    aad: Vec<u8>,
+}
+
+impl Spake2Session {
+    fn new(
+        role: Spake2Role,
+        w: Zeroizing<Scalar>,
+        x: Zeroizing<Scalar>,
+        identity_a: &[u8],
+        identity_b: &[u8],
+        aad: &[u8],
+    ) -> Result<(Self, Vec<u8>), Error> {
+        let blinding = match role {
+            Spake2Role::A => decode_constant(&M)?,
+            Spake2Role::B => decode_constant(&N)?,
+        };
+        let share = encode_point(&(ProjectivePoint::GENERATOR * *x + blinding * *w));
+        let session = Self {
+            role,
+            w,
+            x,
+            share: share.clone(),
+            identity_a: identity_a.to_vec(),
+            identity_b: identity_b.to_vec(),
+            aad: aad.to_vec(),
+        };
+        Ok((session, share))
+    }
+
+    /// Processes the peer's share and returns the key confirmation step.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if the share is not a valid point.
+    ///
+    /// 处理对方的份额并返回密钥确认步骤。
+    ///
+    /// 如果份额不是有效的点，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn finish(self, peer_share: &[u8]) -> Result<Spake2Confirmation, Error> {
+        let peer_point = decode_share(peer_share)?;
+        let (share_a, share_b, peer_blinding) = match self.role {
+            Spake2Role::A => (self.share.as_slice(), peer_share, decode_constant(&N)?),
+            Spake2Role::B => (peer_share, self.share.as_slice(), decode_constant(&M)?),
+        };
+        let k = Zeroizing::new(encode_point(
+            &((peer_point - peer_blinding * *self.w) * *self.x),
+        ));
+        let w = Zeroizing::new(self.w.to_repr());
+
+        let transcript = Zeroizing::new(transcript(&[
+            &self.identity_a,
+            &self.identity_b,
+            share_a,
+            share_b,
+            &k,
+            &w,
+        ]));
+        let hash = Zeroizing::new(Sha256::digest(transcript.as_slice()));
+        let (ke, ka) = hash.split_at(hash.len() / 2);
+
+        let mut info = b"ConfirmationKeys".to_vec();
+        info.extend_from_slice(&self.aad);
+        let mut confirmation_keys = Zeroizing::new([0u8; 32]);
+        Hkdf::<Sha256>::new(None, ka)
+            .expand(&info, confirmation_keys.as_mut())
+            .map_err(|_| Error::KeyAgreement(KeyAgreementError::AgreementFailed))?;
+        let (kc_a, kc_b) = confirmation_keys.split_at(16);
+        let (own_key, peer_key) = match self.role {
+            Spake2Role::A => (kc_a, kc_b),
+            Spake2Role::B => (kc_b, kc_a),
+        };
+
+        Ok(Spake2Confirmation {
+            confirmation: hmac_sha256(own_key, &transcript)?,
+            expected: hmac_sha256(peer_key, &transcript)?,
+            shared_secret: SharedSecret::new(ke.to_vec()),
+        })
+    }
+}
+
+impl core::fmt::Debug for Spake2Session {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Spake2Session")
+            .field("role", &self.role)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The key confirmation step of a SPAKE2 session.
+///
+/// SPAKE2 会话的密钥确认步骤。
+pub struct Spake2Confirmation {
+    confirmation: Vec<u8>,
+    expected: Vec<u8>,
+    shared_secret: SharedSecret,
+}
+
+impl Spake2Confirmation {
+    /// Returns the key confirmation to send to the peer.
+    ///
+    /// 返回需要发送给对方的密钥确认。
+    pub fn confirmation(&self) -> &[u8] {
+        &self.confirmation
+    }
+
+    /// Checks the peer's key confirmation and returns the shared secret `Ke`.
+    ///
+    /// Fails with `KeyAgreementError::ConfirmationFailed` if the peer used another password,
+    /// other identities or other additional data, or if the exchange was tampered with.
+    ///
+    /// 检查对方的密钥确认并返回共享秘密 `Ke`。
+    ///
+    /// 如果对方使用了另一个口令、其他身份或其他附加数据，或者交换被篡改，则以
+    /// `KeyAgreementError::ConfirmationFailed` 失败。
+    pub fn verify(self, peer_confirmation: &[u8]) -> Result<SharedSecret, Error> {
+        check_confirmation(&self.expected, peer_confirmation)?;
+        Ok(self.shared_secret)
+    }
+}
+
+impl core::fmt::Debug for Spake2Confirmation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Spake2Confirmation").finish_non_exhaustive()
+    }
+}
+
+// ------------------- SPAKE2+ -------------------
+// ------------------- SPAKE2+ -------------------
+
+/// The prover's password-derived scalars `w0` and `w1` in SPAKE2+.
+///
+/// SPAKE2+ 中证明方由口令派生的标量 `w0` 和 `w1`。
+pub struct Spake2PlusSecret {
+    w0: Zeroizing<Scalar>,
+    w1: Zeroizing<Scalar>,
+}
+
+impl Spake2PlusSecret {
+    /// Derives `w0` and `w1` from the two halves of a password hash (`w0s || w1s` in
+    /// RFC 9383).
+    ///
+    /// Fails with `KeyError::InvalidLength` if the hash has an odd length or its halves are
+    /// shorter than [`MIN_PASSWORD_HASH_SIZE`].
+    ///
+    /// 从口令哈希的两半（RFC 9383 中的 `w0s || w1s`）派生 `w0` 和 `w1`。
+    ///
+    /// 如果哈希长度为奇数或其两半短于 [`MIN_PASSWORD_HASH_SIZE`]，则以
+    /// `KeyError::InvalidLength` 失败。
+    pub fn from_password_hash(password_hash: &[u8]) -> Result<Self, Error> {
+        if !password_hash.len().is_multiple_of(2) {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        let (w0s, w1s) = password_hash.split_at(password_hash.len() / 2);
+        Ok(Self {
+            w0: password_scalar(w0s)?,
+            w1: password_scalar(w1s)?,
+        })
+    }
+
+    /// Returns the record the verifier stores for this password.
+    ///
+    /// 返回验证方为此口令存储的记录。
+    pub fn record(&self) -> Spake2PlusRecord {
+        Spake2PlusRecord {
+            w0: self.w0.clone(),
+            l: ProjectivePoint::GENERATOR * *self.w1,
+        }
+    }
+}
+
+impl core::fmt::Debug for Spake2PlusSecret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Spake2PlusSecret").finish_non_exhaustive()
+    }
+}
+
+/// The verifier's registration record `(w0, L)` in SPAKE2+.
+///
+/// The record is serialized as the 32-byte big-endian `w0` followed by the uncompressed point
+/// `L`. It must be kept confidential: it does not allow logging in as the prover, but it does
+/// allow an offline dictionary attack and impersonating the verifier.
+///
+/// SPAKE2+ 中验证方的注册记录 `(w0, L)`。
+///
+/// 该记录被序列化为 32 字节大端 `w0` 后接未压缩点 `L`。它必须保密：它不允许以证明方身份登录，
+/// 但允许进行离线字典攻击和冒充验证方。
+#[derive(Clone)]
+pub struct Spake2PlusRecord {
+    w0: Zeroizing<Scalar>,
+    l: ProjectivePoint,
+}
+
+impl Spake2PlusRecord {
+    /// The length in bytes of a serialized record.
+    ///
+    /// 序列化记录的字节长度。
+    pub const SIZE: usize = 32 + SHARE_SIZE;
+
+    /// Serializes the record.
+    ///
+    /// 序列化该记录。
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(Self::SIZE));
+        bytes.extend_from_slice(&self.w0.to_repr());
+        bytes.extend_from_slice(&encode_point(&self.l));
+        bytes
+    }
+
+    /// Deserializes a record.
+    ///
+    /// Fails with `KeyError::InvalidLength` if the record does not have [`Self::SIZE`] bytes,
+    /// and with `KeyError::InvalidEncoding` if `w0` or `L` is malformed.
+    ///
+    /// 反序列化一个记录。
+    ///
+    /// 如果记录不是 [`Self::SIZE`] 字节，则以 `KeyError::InvalidLength` 失败；如果 `w0` 或 `L`
+    /// 格式错误，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::SIZE {
+            return Err(Error::Key(KeyError::InvalidLength));
+        }
+        let (w0, l) = bytes.split_at(32);
+        let w0 = p256::FieldBytes::clone_from_slice(w0);
+        let w0 = Option::<Scalar>::from(Scalar::from_repr(w0))
+            .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        let l = decode_share(l).map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        Ok(Self {
+            w0: Zeroizing::new(w0),
+            l,
+        })
+    }
+}
+
+impl core::fmt::Debug for Spake2PlusRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Spake2PlusRecord").finish_non_exhaustive()
+    }
+}
+
+/// The SPAKE2+ augmented password-authenticated key exchange.
+///
+/// The exchange takes three messages: the prover sends its share, the verifier answers with
+/// its share and confirmation, and the prover sends its confirmation.
+///
+/// SPAKE2+ 增强口令认证密钥交换。
+///
+/// 该交换需要三条消息：证明方发送其份额，验证方以其份额和确认作答，然后证明方发送其确认。
+#[derive(Clone, Debug, Default)]
+pub struct Spake2Plus;
+
+impl Spake2Plus {
+    /// Starts a session as the prover, drawing the ephemeral scalar from the crate's system RNG.
+    ///
+    /// See [`Spake2Plus::start_prover_with_rng`].
+    ///
+    /// 以证明方的身份开始一个会话，临时标量从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Spake2Plus::start_prover_with_rng`]。
+    pub fn start_prover(
+        secret: &Spake2PlusSecret,
+        context: &[u8],
+        identity_prover: &[u8],
+        identity_verifier: &[u8],
+    ) -> Result<(Spake2PlusProver, Vec<u8>), Error> {
+        Self::start_prover_with_rng(
+            &mut SystemRng::new(),
+            secret,
+            context,
+            identity_prover,
+            identity_verifier,
+        )
+    }
+
+    /// Starts a session as the prover with randomness drawn from `rng`, and returns it
+    /// together with the share `shareP` to send to the verifier.
+    ///
+    /// `context` binds the session to the application protocol; the identities may be empty.
+    ///
+    /// 以证明方的身份使用从 `rng` 获取的随机性开始一个会话，并将其与需要发送给验证方的份额
+    /// `shareP` 一起返回。
+    ///
+    /// `context` 将会话绑定到应用协议；身份可以为空。
+    pub fn start_prover_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        secret: &Spake2PlusSecret,
+        context: &[u8],
+        identity_prover: &[u8],
+        identity_verifier: &[u8],
+    ) -> Result<(Spake2PlusProver, Vec<u8>), Error> {
+        let x = Zeroizing::new(Scalar::random(&mut *rng));
+        Spake2PlusProver::new(secret, x, context, identity_prover, identity_verifier)
+    }
+
+    /// Answers the prover's share as the verifier, drawing the ephemeral scalar from the
+    /// crate's system RNG.
+    ///
+    /// See [`Spake2Plus::respond_with_rng`].
+    ///
+    /// 以验证方的身份应答证明方的份额，临时标量从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Spake2Plus::respond_with_rng`]。
+    pub fn respond(
+        record: &Spake2PlusRecord,
+        context: &[u8],
+        identity_prover: &[u8],
+        identity_verifier: &[u8],
+        share_prover: &[u8],
+    ) -> Result<Spake2PlusVerifier, Error> {
+        Self::respond_with_rng(
+            &mut SystemRng::new(),
+            record,
+            context,
+            identity_prover,
+            identity_verifier,
+            share_prover,
+        )
+    }
+
+    /// Answers the prover's share as the verifier with randomness drawn from `rng`.
+    ///
+    /// The returned session holds the share `shareV` and the confirmation `confirmV` to send
+    /// back. Fails with `KeyAgreementError::InvalidPeerPublicKey` if the prover's share is not
+    /// a valid point.
+    ///
+    /// 以验证方的身份使用从 `rng` 获取的随机性应答证明方的份额。
+    ///
+    /// 返回的会话持有需要发回的份额 `shareV` 和确认 `confirmV`。如果证明方的份额不是有效的点，
+    /// 则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn respond_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        record: &Spake2PlusRecord,
+        context: &[u8],
+        identity_prover: &[u8],
+        identity_verifier: &[u8],
+        share_prover: &[u8],
+    ) -> Result<Spake2PlusVerifier, Error> {
+        let y = Zeroizing::new(Scalar::random(&mut *rng));
+        Spake2PlusVerifier::new(
+            record,
+            y,
+            context,
+            identity_prover,
+            identity_verifier,
+            share_prover,
+        )
+    }
+}
+
+/// A SPAKE2+ prover session waiting for the verifier's answer.
+///
+/// 一个等待验证方应答的 SPAKE2+ 证明方会话。
+pub struct Spake2PlusProver {
+    w0: Zeroizing<Scalar>,
+    w1: Zeroizing<Scalar>,
+    x: Zeroizing<Scalar>,
+    share: Vec<u8>,
+    transcript_prefix: Vec<u8>,
+}
+
+impl Spake2PlusProver {
+    fn new(
+        secret: &Spake2PlusSecret,
+        x: Zeroizing<Scalar>,
+        context: &[u8],
+        identity_prover: &[u8],
+        identity_verifier: &[u8],
+    ) -> Result<(Self, Vec<u8>), Error> {
+        let share =
+            encode_point(&(ProjectivePoint::GENERATOR * *x + decode_constant(&M)? * *secret.w0));
+        let session = Self {
+            w0: secret.w0.clone(),
+            w1: secret.w1.clone(),
+            x,
+            share: share.clone(),
+            transcript_prefix: plus_transcript_prefix(context, identity_prover, identity_verifier)?,
+        };
+        Ok((session, share))
+    }
+
+    /// Processes the verifier's share and confirmation, and returns the confirmation to send
+    /// back together with the shared secret `K_shared`.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if the share is not a valid point,
+    /// and with `KeyAgreementError::ConfirmationFailed` if the verifier's confirmation does not
+    /// match.
+    ///
+    /// 处理验证方的份额和确认，并返回需要发回的确认以及共享秘密 `K_shared`。
+    ///
+    /// 如果份额不是有效的点，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败；如果验证方的
+    /// 确认不匹配，则以 `KeyAgreementError::ConfirmationFailed` 失败。
+    pub fn finish(
+        self,
+        share_verifier: &[u8],
+        confirmation_verifier: &[u8],
+    ) -> Result<(Vec<u8>, SharedSecret), Error> {
+        let unblinded = decode_share(share_verifier)? - decode_constant(&N)? * *self.w0;
+        let z = unblinded * *self.x;
+        let v = unblinded * *self.w1;
+        let keys = PlusKeys::derive(
+            &self.transcript_prefix,
+            &self.share,
+            share_verifier,
+            &z,
+            &v,
+            &self.w0,
+        )?;
+        check_confirmation(
+            &hmac_sha256(&keys.confirm_verifier, &self.share)?,
+            confirmation_verifier,
+        )?;
+        let confirmation = hmac_sha256(&keys.confirm_prover, share_verifier)?;
+        Ok((confirmation, keys.shared_secret))
+    }
+}
+
+impl core::fmt::Debug for Spake2PlusProver {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Spake2PlusProver").finish_non_exhaustive()
+    }
+}
+
+/// A SPAKE2+ verifier session waiting for the prover's confirmation.
+///
+/// 一个等待证明方确认的 SPAKE2+ 验证方会话。
+pub struct Spake2PlusVerifier {
+    share: Vec<u8>,
+    confirmation: Vec<u8>,
+    expected: Vec<u8>,
+    shared_secret: SharedSecret,
+}
+
+impl Spake2PlusVerifier {
+    fn new(
+        record: &Spake2PlusRecord,
+        y: Zeroizing<Scalar>,
+        context: &[u8],
+        identity_prover: &[u8],
+        identity_verifier: &[u8],
+        share_prover: &[u8],
+    ) -> Result<Self, Error> {
+        let unblinded = decode_share(share_prover)? - decode_constant(&M)? * *record.w0;
+        let share =
+            encode_point(&(ProjectivePoint::GENERATOR * *y + decode_constant(&N)? * *record.w0));
+        let z = unblinded * *y;
+        let v = record.l * *y;
+        let keys = PlusKeys::derive(
+            &plus_transcript_prefix(context, identity_prover, identity_verifier)?,
+            share_prover,
+            &share,
+            &z,
+            &v,
+            &record.w0,
+        )?;
+        Ok(Self {
+            confirmation: hmac_sha256(&keys.confirm_verifier, share_prover)?,
+            expected: hmac_sha256(&keys.confirm_prover, &share)?,
+            share,
+            shared_secret: keys.shared_secret,
+        })
+    }
+
+    /// Returns the share `shareV` to send to the prover.
+    ///
+    /// 返回需要发送给证明方的份额 `shareV`。
+    pub fn share(&self) -> &[u8] {
+        &self.share
+    }
+
+    /// Returns the confirmation `confirmV` to send to the prover.
+    ///
+    /// 返回需要发送给证明方的确认 `confirmV`。
+    pub fn confirmation(&self) -> &[u8] {
+        &self.confirmation
+    }
+
+    /// Checks the prover's confirmation and returns the shared secret `K_shared`.
+    ///
+    /// Fails with `KeyAgreementError::ConfirmationFailed` if the prover's confirmation does not
+    /// match, e.g. because it used another password.
+    ///
+    /// 检查证明方的确认并返回共享秘密 `K_shared`。
+    ///
+    /// 如果证明方的确认不匹配（例如因为它使用了另一个口令），则以
+    /// `KeyAgreementError::ConfirmationFailed` 失败。
+    pub fn finish(self, confirmation_prover: &[u8]) -> Result<SharedSecret, Error> {
+        check_confirmation(&self.expected, confirmation_prover)?;
+        Ok(self.shared_secret)
+    }
+}
+
+impl core::fmt::Debug for Spake2PlusVerifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Spake2PlusVerifier").finish_non_exhaustive()
+    }
+}
+
+/// The keys of RFC 9383, section 3.4, derived from the transcript.
+struct PlusKeys {
+    confirm_prover: Zeroizing<Vec<u8>>,
+    confirm_verifier: Zeroizing<Vec<u8>>,
+    shared_secret: SharedSecret,
+}
+
+impl PlusKeys {
+    fn derive(
+        transcript_prefix: &[u8],
+        share_prover: &[u8],
+        share_verifier: &[u8],
+        z: &ProjectivePoint,
+        v: &ProjectivePoint,
+        w0: &Scalar,
+    ) -> Result<Self, Error> {
+        let z = Zeroizing::new(encode_point(z));
+        let v = Zeroizing::new(encode_point(v));
+        let w0 = Zeroizing::new(w0.to_repr());
+        let mut tt = Zeroizing::new(transcript_prefix.to_vec());
+        tt.extend_from_slice(&transcript(&[share_prover, share_verifier, &z, &v, &w0]));
+        let k_main = Zeroizing::new(Sha256::digest(tt.as_slice()));
+
+        let hkdf = Hkdf::<Sha256>::new(None, &k_main);
+        let mut confirmation_keys = Zeroizing::new([0u8; 64]);
+        let mut shared_key = Zeroizing::new(vec![0u8; 32]);
+        hkdf.expand(b"ConfirmationKeys", confirmation_keys.as_mut())
+            .and_then(|_| hkdf.expand(b"SharedKey", &mut shared_key))
+            .map_err(|_| Error::KeyAgreement(KeyAgreementError::AgreementFailed))?;
+        Ok(Self {
+            confirm_prover: Zeroizing::new(confirmation_keys[..32].to_vec()),
+            confirm_verifier: Zeroizing::new(confirmation_keys[32..].to_vec()),
+            shared_secret: SharedSecret::new(shared_key.to_vec()),
+        })
+    }
+}
+
+/// The part of the SPAKE2+ transcript that precedes the shares.
+fn plus_transcript_prefix(
+    context: &[u8],
+    identity_prover: &[u8],
+    identity_verifier: &[u8],
+) -> Result<Vec<u8>, Error> {
+    Ok(transcript(&[
+        context,
+        identity_prover,
+        identity_verifier,
+        &encode_point(&decode_constant(&M)?),
+        &encode_point(&decode_constant(&N)?),
+    ]))
+}
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// Reduces a password hash to a scalar, interpreting it as a big-endian integer.
+fn password_scalar(password_hash: &[u8]) -> Result<Zeroizing<Scalar>, Error> {
+    if password_hash.len() < MIN_PASSWORD_HASH_SIZE {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    let radix = Scalar::from(256u64);
+    let mut w = Zeroizing::new(Scalar::ZERO);
+    for byte in password_hash {
+        *w = *w * radix + Scalar::from(u64::from(*byte));
+    }
+    Ok(w)
+}
+
+/// Concatenates the parts, each prefixed with its length as an 8-byte little-endian integer.
+fn transcript(parts: &[&[u8]]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(parts.iter().map(|part| 8 + part.len()).sum());
+    for part in parts {
+        bytes.extend_from_slice(&(part.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(part);
+    }
+    bytes
+}
+
+fn encode_point(point: &ProjectivePoint) -> Vec<u8> {
+    point
+        .to_affine()
+        .to_encoded_point(false)
+        .as_bytes()
+        .to_vec()
+}
+
+fn decode_constant(bytes: &[u8; 33]) -> Result<ProjectivePoint, Error> {
+    let repr = p256::CompressedPoint::clone_from_slice(bytes);
+    Option::from(ProjectivePoint::from_bytes(&repr))
+        .ok_or(Error::KeyAgreement(KeyAgreementError::AgreementFailed))
+}
+
+/// Decodes a peer's share, which must be an uncompressed point other than the identity.
+fn decode_share(bytes: &[u8]) -> Result<ProjectivePoint, Error> {
+    let invalid = || Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey);
+    if bytes.len() != SHARE_SIZE {
+        return Err(invalid());
+    }
+    let point = EncodedPoint::from_bytes(bytes).map_err(|_| invalid())?;
+    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&point))
+        .map(ProjectivePoint::from)
+        .filter(|point| !bool::from(point.is_identity()))
+        .ok_or_else(invalid)
+}
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key)
+        .map_err(|_| Error::KeyAgreement(KeyAgreementError::AgreementFailed))?;
+    mac.update(message);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn check_confirmation(expected: &[u8], received: &[u8]) -> Result<(), Error> {
+    if bool::from(expected.ct_eq(received)) {
+        Ok(())
+    } else {
+        Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    type Outcomes = (Result<SharedSecret, Error>, Result<SharedSecret, Error>);
+
+    fn scalar(hex: &str) -> Result<Zeroizing<Scalar>, Error> {
+        let bytes = p256::FieldBytes::clone_from_slice(&hex::decode(hex).unwrap_or_default());
+        Option::from(Scalar::from_repr(bytes))
+            .map(Zeroizing::new)
+            .ok_or(Error::Key(KeyError::InvalidEncoding))
+    }
+
+    #[test]
+    fn test_rfc9382_vector() -> Result<(), Error> {
+        // SPAKE2-P256-SHA256-HKDF-HMAC, appendix B of RFC 9382.
+        // SPAKE2-P256-SHA256-HKDF-HMAC，RFC 9382 附录 B。
+        let w = scalar("2ee57912099d31560b3a44b1184b9b4866e904c49d12ac5042c97dca461b1a5f")?;
+        let x = scalar("43dd0fd7215bdcb482879fca3220c6a968e66d70b1356cac18bb26c84a78d729")?;
+        let y = scalar("dcb60106f276b02606d8ef0a328c02e4b629f84f89786af5befb0bc75b6e66be")?;
+        let (session_a, share_a) =
+            Spake2Session::new(Spake2Role::A, w.clone(), x, b"server", b"client", b"")?;
+        let (session_b, share_b) =
+            Spake2Session::new(Spake2Role::B, w, y, b"server", b"client", b"")?;
+        assert_eq!(
+            hex::encode(&share_a),
+            "04a56fa807caaa53a4d28dbb9853b9815c61a411118a6fe516a8798434751470f9010153ac33d0d5f2047ffdb1a3e42c9b4e6be662766e1eeb4116988ede5f912c"
+        );
+        assert_eq!(
+            hex::encode(&share_b),
+            "0406557e482bd03097ad0cbaa5df82115460d951e3451962f1eaf4367a420676d09857ccbc522686c83d1852abfa8ed6e4a1155cf8f1543ceca528afb591a1e0b7"
+        );
+
+        let confirmation_a = session_a.finish(&share_b)?;
+        let confirmation_b = session_b.finish(&share_a)?;
+        assert_eq!(
+            hex::encode(confirmation_a.confirmation()),
+            "58ad4aa88e0b60d5061eb6b5dd93e80d9c4f00d127c65b3b35b1b5281fee38f0"
+        );
+        assert_eq!(
+            hex::encode(confirmation_b.confirmation()),
+            "d3e2e547f1ae04f2dbdbf0fc4b79f8ecff2dff314b5d32fe9fcef2fb26dc459b"
+        );
+        let confirmation_from_a = confirmation_a.confirmation().to_vec();
+        let secret_a = confirmation_a.verify(confirmation_b.confirmation())?;
+        let secret_b = confirmation_b.verify(&confirmation_from_a)?;
+        assert_eq!(
+            hex::encode(secret_a.expose_raw()),
+            "0e0672dc86f8e45565d338b0540abe69"
+        );
+        assert_eq!(secret_a, secret_b);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rfc9383_vector() -> Result<(), Error> {
+        // SPAKE2+-P256-SHA256-HKDF-SHA256-HMAC-SHA256, appendix C of RFC 9383.
+        // SPAKE2+-P256-SHA256-HKDF-SHA256-HMAC-SHA256，RFC 9383 附录 C。
+        let secret = Spake2PlusSecret {
+            w0: scalar("bb8e1bbcf3c48f62c08db243652ae55d3e5586053fca77102994f23ad95491b3")?,
+            w1: scalar("7e945f34d78785b8a3ef44d0df5a1a97d6b3b460409a345ca7830387a74b1dba")?,
+        };
+        let record = secret.record();
+        assert_eq!(
+            hex::encode(encode_point(&record.l)),
+            "04eb7c9db3d9a9eb1f8adab81b5794c1f13ae3e225efbe91ea487425854c7fc00f00bfedcbd09b2400142d40a14f2064ef31dfaa903b91d1faea7093d835966efd"
+        );
+
+        let context = b"SPAKE2+-P256-SHA256-HKDF-SHA256-HMAC-SHA256 Test Vectors";
+        let x = scalar("d1232c8e8693d02368976c174e2088851b8365d0d79a9eee709c6a05a2fad539")?;
+        let y = scalar("717a72348a182085109c8d3917d6c43d59b224dc6a7fc4f0483232fa6516d8b3")?;
+        let (prover, share_prover) =
+            Spake2PlusProver::new(&secret, x, context, b"client", b"server")?;
+        assert_eq!(
+            hex::encode(&share_prover),
+            "04ef3bd051bf78a2234ec0df197f7828060fe9856503579bb1733009042c15c0c1de127727f418b5966afadfdd95a6e4591d171056b333dab97a79c7193e341727"
+        );
+        let verifier =
+            Spake2PlusVerifier::new(&record, y, context, b"client", b"server", &share_prover)?;
+        assert_eq!(
+            hex::encode(verifier.share()),
+            "04c0f65da0d11927bdf5d560c69e1d7d939a05b0e88291887d679fcadea75810fb5cc1ca7494db39e82ff2f50665255d76173e09986ab46742c798a9a68437b048"
+        );
+        assert_eq!(
+            hex::encode(verifier.confirmation()),
+            "9747bcc4f8fe9f63defee53ac9b07876d907d55047e6ff2def2e7529089d3e68"
+        );
+
+        let (confirmation_prover, secret_prover) =
+            prover.finish(verifier.share(), verifier.confirmation())?;
+        assert_eq!(
+            hex::encode(&confirmation_prover),
+            "926cc713504b9b4d76c9162ded04b5493e89109f6d89462cd33adc46fda27527"
+        );
+        let secret_verifier = verifier.finish(&confirmation_prover)?;
+        assert_eq!(
+            hex::encode(secret_prover.expose_raw()),
+            "0c5f8ccd1413423a54f6c1fb26ff01534a87f893779c6e68666d772bfd91f3e7"
+        );
+        assert_eq!(secret_prover, secret_verifier);
+        Ok(())
+    }
+
+    /// Runs SPAKE2 between A and B and returns their outcomes.
+    fn run_spake2(
+        rng: &mut SeededRng,
+        password_a: &[u8],
+        password_b: &[u8],
+        aad_b: &[u8],
+    ) -> Result<Outcomes, Error> {
+        let (session_a, share_a) =
+            Spake2::start_with_rng(rng, Spake2Role::A, password_a, b"alice", b"bob", b"aad")?;
+        let (session_b, share_b) =
+            Spake2::start_with_rng(rng, Spake2Role::B, password_b, b"alice", b"bob", aad_b)?;
+        let confirmation_a = session_a.finish(&share_b)?;
+        let confirmation_b = session_b.finish(&share_a)?;
+        let confirmation_from_a = confirmation_a.confirmation().to_vec();
+        Ok((
+            confirmation_a.verify(confirmation_b.confirmation()),
+            confirmation_b.verify(&confirmation_from_a),
+        ))
+    }
+
+    #[test]
+    fn test_spake2() -> Result<(), Error> {
+        let mut rng = SeededRng(1);
+        let password = [7u8; MIN_PASSWORD_HASH_SIZE];
+        let (secret_a, secret_b) = run_spake2(&mut rng, &password, &password, b"aad")?;
+        let secret_a = secret_a?;
+        assert_eq!(secret_a.expose_raw().len(), 16);
+        assert_eq!(secret_a, secret_b?);
+
+        // A wrong password or other additional data fails the confirmation on both sides.
+        // 错误的口令或不同的附加数据会使双方的确认都失败。
+        let mut wrong_password = password;
+        wrong_password[0] ^= 1;
+        for (password_b, aad_b) in [(&wrong_password, b"aad".as_slice()), (&password, b"")] {
+            let (secret_a, secret_b) = run_spake2(&mut rng, &password, password_b, aad_b)?;
+            assert!(matches!(
+                secret_a,
+                Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+            ));
+            assert!(matches!(
+                secret_b,
+                Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+            ));
+        }
+
+        // Malformed shares and short password hashes are rejected.
+        // 格式错误的份额和过短的口令哈希会被拒绝。
+        let (session, share) =
+            Spake2::start_with_rng(&mut rng, Spake2Role::A, &password, b"", b"", b"")?;
+        assert!(matches!(
+            session.finish(&share[1..]),
+            Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+        ));
+        let (session, _) =
+            Spake2::start_with_rng(&mut rng, Spake2Role::A, &password, b"", b"", b"")?;
+        let mut not_on_curve = share;
+        not_on_curve[64] ^= 1;
+        assert!(matches!(
+            session.finish(&not_on_curve),
+            Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+        ));
+        assert!(matches!(
+            Spake2::start(Spake2Role::A, &password[1..], b"", b"", b""),
+            Err(Error::Key(KeyError::InvalidLength))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_spake2_plus() -> Result<(), Error> {
+        let mut rng = SeededRng(2);
+        let password_hash = [9u8; 2 * MIN_PASSWORD_HASH_SIZE];
+        let secret = Spake2PlusSecret::from_password_hash(&password_hash)?;
+        let record_bytes = secret.record().to_bytes();
+        assert_eq!(record_bytes.len(), Spake2PlusRecord::SIZE);
+        let record = Spake2PlusRecord::from_bytes(&record_bytes)?;
+        assert_eq!(*record.to_bytes(), *record_bytes);
+
+        let (prover, share_prover) =
+            Spake2Plus::start_prover_with_rng(&mut rng, &secret, b"app", b"client", b"server")?;
+        assert_eq!(share_prover.len(), SHARE_SIZE);
+        let verifier = Spake2Plus::respond_with_rng(
+            &mut rng,
+            &record,
+            b"app",
+            b"client",
+            b"server",
+            &share_prover,
+        )?;
+        assert_eq!(verifier.confirmation().len(), CONFIRMATION_SIZE);
+        let (confirmation, secret_prover) =
+            prover.finish(verifier.share(), verifier.confirmation())?;
+        assert_eq!(secret_prover, verifier.finish(&confirmation)?);
+
+        // A prover with the wrong password rejects the verifier's confirmation, and a verifier
+        // rejects an altered confirmation.
+        // 使用错误口令的证明方会拒绝验证方的确认，验证方会拒绝被篡改的确认。
+        let mut wrong_hash = password_hash;
+        wrong_hash[0] ^= 1;
+        let wrong_secret = Spake2PlusSecret::from_password_hash(&wrong_hash)?;
+        let (prover, share_prover) = Spake2Plus::start_prover_with_rng(
+            &mut rng,
+            &wrong_secret,
+            b"app",
+            b"client",
+            b"server",
+        )?;
+        let verifier = Spake2Plus::respond_with_rng(
+            &mut rng,
+            &record,
+            b"app",
+            b"client",
+            b"server",
+            &share_prover,
+        )?;
+        assert!(matches!(
+            prover.finish(verifier.share(), verifier.confirmation()),
+            Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+        ));
+        let mut altered = confirmation;
+        altered[0] ^= 1;
+        assert!(matches!(
+            verifier.finish(&altered),
+            Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+        ));
+
+        assert!(matches!(
+            Spake2PlusSecret::from_password_hash(&password_hash[1..]),
+            Err(Error::Key(KeyError::InvalidLength))
+        ));
+        assert!(matches!(
+            Spake2PlusRecord::from_bytes(&record_bytes[1..]),
+            Err(Error::Key(KeyError::InvalidLength))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,1154 @@
+//! Provides the SRP-6a password-authenticated key exchange of RFC 5054 (originally RFC 2945),
+//! with the client and server sides and the RFC 5054 groups.
+//!
+//! SRP is an augmented protocol: the server stores a salt and a verifier derived from the
+//! password, never the password itself. It is provided for compatibility with existing SRP
+//! deployments; new designs should prefer OPAQUE or SPAKE2+.
+//!
+//! The exchange takes four messages:
+//! 1. The client calls [`Srp::start_client`] and sends its username and share `A`.
+//! 2. The server looks up the [`SrpRecord`] of the username, calls [`Srp::start_server`] and
+//!    sends the salt and its share `B`.
+//! 3. The client calls [`SrpClient::finish`] and sends its proof `M1`.
+//! 4. The server checks `M1` with [`SrpServer::finish`], which releases the session key, and
+//!    sends its proof `M2`, which the client checks with [`SrpConfirmation::verify`].
+//!
+//! # Compatibility
+//! The values follow RFC 5054 and RFC 2945, in the form shared by most SRP-6a libraries:
+//! - `k = H(N | PAD(g))`, `u = H(PAD(A) | PAD(B))` and `x = H(s | H(I | ":" | P))`;
+//! - `K = H(S)`, `M1 = H(H(N) XOR H(g) | H(I) | s | A | B | K)` and `M2 = H(A | M1 | K)`, where
+//!   `A`, `B` and `S` are minimal big-endian integers without padding.
+//!
+//! The hash is a type parameter: SHA-1, which RFC 5054 specifies, is only available with the
+//! `insecure-legacy-hashes` feature, and SHA-256 or SHA-512 can be used by deployments that
+//! chose them.
+//!
+//! # Security Considerations
+//! - The verifier allows an offline dictionary attack and must be stored as a secret.
+//! - Answer unknown usernames with a fake record derived from a server secret, so that an
+//!   attacker cannot tell which users are registered.
+//! - Use at least the 2048-bit group; the 1024-bit and 1536-bit groups only exist for old
+//!   deployments.
+//! - SRP is not a FIPS approved algorithm, so it is not available with the `fips` feature.
+//!
+//! 提供了 RFC 5054（最初为 RFC 2945）的 SRP-6a 口令认证密钥交换，包括客户端、服务器以及
+//! RFC 5054 的群。
+//!
+//! SRP 是增强协议：服务器存储从口令派生的盐和验证值，而绝不存储口令本身。提供它是为了与现有的
+//! SRP 部署兼容；新设计应优先使用 OPAQUE 或 SPAKE2+。
+//!
+//! 该交换需要四条消息：
+//! 1. 客户端调用 [`Srp::start_client`] 并发送其用户名和份额 `A`。
+//! 2. 服务器查找该用户名的 [`SrpRecord`]，调用 [`Srp::start_server`] 并发送盐和其份额 `B`。
+//! 3. 客户端调用 [`SrpClient::finish`] 并发送其证明 `M1`。
+//! 4. 服务器使用 [`SrpServer::finish`] 检查 `M1`，它会释放会话密钥，然后服务器发送其证明 `M2`，
+//!    客户端使用 [`SrpConfirmation::verify`] 检查它。
+//!
+//! # 兼容性
+//! 各个值遵循 RFC 5054 和 RFC 2945，采用大多数 SRP-6a 库共有的形式：
+//! - `k = H(N | PAD(g))`、`u = H(PAD(A) | PAD(B))` 和 `x = H(s | H(I | ":" | P))`；
+//! - `K = H(S)`、`M1 = H(H(N) XOR H(g) | H(I) | s | A | B | K)` 和 `M2 = H(A | M1 | K)`，其中
+//!   `A`、`B` 和 `S` 是不带填充的最短大端整数。
+//!
+//! 哈希是一个类型参数：RFC 5054 规定的 SHA-1 仅在启用 `insecure-legacy-hashes` 特性时可用，
+//! 选择了 SHA-256 或 SHA-512 的部署也可以使用它们。
+//!
+//! # 安全考虑
+//! - 验证值允许进行离线字典攻击，必须作为秘密存储。
+//! - 请使用从服务器秘密派生的伪造记录应答未知的用户名，使攻击者无法分辨哪些用户已注册。
+//! - 请至少使用 2048 位的群；1024 位和 1536 位的群仅为旧部署而存在。
+//! - SRP 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::{vec, vec::Vec};
+use crate::ct::ConstantTimeEq;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+#[cfg(feature = "insecure-legacy-hashes")]
+use crate::systems::hash::legacy::Sha1Params;
+use core::marker::PhantomData;
+use crypto_bigint::modular::runtime_mod::{DynResidue, DynResidueParams};
+use crypto_bigint::{
+    Encoding, Limb, U512, U1024, U1536, U2048, U3072, U4096, U6144, U8192, Uint, Zero,
+};
+use digest::Digest;
+use secrecy::{ExposeSecret, SecretBox};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+/// The length in bytes of the private exponents `a` and `b`.
+const PRIVATE_EXPONENT_SIZE: usize = 32;
+
+/// The length in bytes of the salts generated by [`Srp::register`].
+const SALT_SIZE: usize = 32;
+
+// ------------------- Groups -------------------
+// ------------------- 群 -------------------
+
+/// The groups of RFC 5054, appendix A.
+///
+/// RFC 5054 附录 A 中的群。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SrpGroup {
+    /// The 1024-bit group with generator 2, for legacy deployments only.
+    ///
+    /// 生成元为 2 的 1024 位群，仅用于旧部署。
+    G1024,
+    /// The 1536-bit group with generator 2, for legacy deployments only.
+    ///
+    /// 生成元为 2 的 1536 位群，仅用于旧部署。
+    G1536,
+    /// The 2048-bit group with generator 2.
+    ///
+    /// 生成元为 2 的 2048 位群。
+    G2048,
+    /// The 3072-bit group with generator 5.
+    ///
+    /// 生成元为 5 的 3072 位群。
+    G3072,
+    /// The 4096-bit group with generator 5.
+    ///
+    /// 生成元为 5 的 4096 位群。
+    G4096,
+    /// The 6144-bit group with generator 5.
+    ///
+    /// 生成元为 5 的 6144 位群。
+    G6144,
+    /// The 8192-bit group with generator 19.
+    ///
+    /// 生成元为 19 的 8192 位群。
+    G8192,
+}
+
+impl SrpGroup {
+    /// Returns the big-endian prime modulus `N`.
+    ///
+    /// 返回大端素数模数 `N`。
+    pub fn modulus(&self) -> &'static [u8] {
+        match self {
+            Self::G1024 => &N_1024,
+            Self::G1536 => &N_1536,
+            Self::G2048 => &N_2048,
+            Self::G3072 => &N_3072,
+            Self::G4096 => &N_4096,
+            Self::G6144 => &N_6144,
+            Self::G8192 => &N_8192,
+        }
+    }
+
+    /// Returns the generator `g`.
+    ///
+    /// 返回生成元 `g`。
+    pub fn generator(&self) -> u8 {
+        match self {
+            Self::G1024 | Self::G1536 | Self::G2048 => 2,
+            Self::G3072 | Self::G4096 | Self::G6144 => 5,
+            Self::G8192 => 19,
+        }
+    }
+
+    /// Returns the length in bytes of `N`, which is also the length of shares and verifiers.
+    ///
+    /// 返回 `N` 的字节长度，这也是份额和验证值的长度。
+    pub fn size(&self) -> usize {
+        self.modulus().len()
+    }
+}
+
+/// The 1024-bit group of RFC 5054, appendix A.
+const N_1024: [u8; 128] = decode_hex(concat!(
+    "EEAF0AB9 ADB38DD6 9C33F80A FA8FC5E8 60726187 75FF3C0B 9EA2314C 9C256576 ",
+    "D674DF74 96EA81D3 383B4813 D692C6E0 E0D5D8E2 50B98BE4 8E495C1D 6089DAD1 ",
+    "5DC7D7B4 6154D6B6 CE8EF4AD 69B15D49 82559B29 7BCF1885 C529F566 660E57EC ",
+    "68EDBC3C 05726CC0 2FD4CBF4 976EAA9A FD5138FE 8376435B 9FC61D2F C0EB06E3"
+));
+
+/// The 1536-bit group of RFC 5054, appendix A.
+const N_1536: [u8; 192] = decode_hex(concat!(
+    "9DEF3CAF B939277A B1F12A86 17A47BBB DBA51DF4 99AC4C80 BEEEA961 4B19CC4D ",
+    "5F4F5F55 6E27CBDE 51C6A94B E4607A29 1558903B A0D0F843 80B655BB 9A22E8DC ",
+    "DF028A7C EC67F0D0 8134B1C8 B9798914 9B609E0B E3BAB63D 47548381 DBC5B1FC ",
+    "764E3F4B 53DD9DA1 158BFD3E 2B9C8CF5 6EDF0195 39349627 DB2FD53D 24B7C486 ",
+    "65772E43 7D6C7F8C E442734A F7CCB7AE 837C264A E3A9BEB8 7F8A2FE9 B8B5292E ",
+    "5A021FFF 5E91479E 8CE7A28C 2442C6F3 15180F93 499A234D CF76E3FE D135F9BB"
+));
+
+/// The 2048-bit group of RFC 5054, appendix A.
+const N_2048: [u8; 256] = decode_hex(concat!(
+    "AC6BDB41 324A9A9B F166DE5E 1389582F AF72B665 1987EE07 FC319294 3DB56050 ",
+    "A37329CB B4A099ED 8193E075 7767A13D D52312AB 4B03310D CD7F48A9 DA04FD50 ",
+    "E8083969 EDB767B0 CF609517 9A163AB3 661A05FB D5FAAAE8 2918A996 2F0B93B8 ",
+    "55F97993 EC975EEA A80D740A DBF4FF74 7359D041 D5C33EA7 1D281E44 6B14773B ",
+    "CA97B43A 23FB8016 76BD207A 436C6481 F1D2B907 8717461A 5B9D32E6 88F87748 ",
+    "544523B5 24B0D57D 5EA77A27 75D2ECFA 032CFBDB F52FB378 61602790 04E57AE6 ",
+    "AF874E73 03CE5329 9CCC041C 7BC308D8 2A5698F3 A8D0C382 71AE35F8 E9DBFBB6 ",
+    "94B5C803 D89F7AE4 35DE236D 525F5475 9B65E372 FCD68EF2 0FA7111F 9E4AFF73"
+));
+
+/// The 3072-bit group of RFC 5054, appendix A.
+const N_3072: [u8; 384] = decode_hex(concat!(
+    "FFFFFFFF FFFFFFFF C90FDAA2 2168C234 C4C6628B 80DC1CD1 29024E08 8A67CC74 ",
+    "020BBEA6 3B139B22 514A0879 8E3404DD EF9519B3 CD3A431B 302B0A6D F25F1437 ",
+    "4FE1356D 6D51C245 E485B576 625E7EC6 F44C42E9 A637ED6B 0BFF5CB6 F406B7ED ",
+    "EE386BFB 5A899FA5 AE9F2411 7C4B1FE6 49286651 ECE45B3D C2007CB8 A163BF05 ",
+    "98DA4836 1C55D39A 69163FA8 FD24CF5F 83655D23 DCA3AD96 1C62F356 208552BB ",
+    "9ED52907 7096966D 670C354E 4ABC9804 F1746C08 CA18217C 32905E46 2E36CE3B ",
+    "E39E772C 180E8603 9B2783A2 EC07A28F B5C55DF0 6F4C52C9 DE2BCBF6 95581718 ",
+    "3995497C EA956AE5 15D22618 98FA0510 15728E5A 8AAAC42D AD33170D 04507A33 ",
+    "A85521AB DF1CBA64 ECFB8504 58DBEF0A 8AEA7157 5D060C7D B3970F85 A6E1E4C7 ",
+    "ABF5AE8C DB0933D7 1E8C94E0 4A25619D CEE3D226 1AD2EE6B F12FFA06 D98A0864 ",
+    "D8760273 3EC86A64 521F2B18 177B200C BBE11757 7A615D6C 770988C0 BAD946E2 ",
+    "08E24FA0 74E5AB31 43DB5BFC E0FD108E 4B82D120 A93AD2CA FFFFFFFF FFFFFFFF"
+));
+
+/// The 4096-bit group of RFC 5054, appendix A.
+const N_4096: [u8; 512] = decode_hex(concat!(
+    "FFFFFFFF FFFFFFFF C90FDAA2 2168C234 C4C6628B 80DC1CD1 29024E08 8A67CC74 ",
+    "020BBEA6 3B139B22 514A0879 8E3404DD EF9519B3 CD3A431B 302B0A6D F25F1437 ",
+    "4FE1356D 6D51C245 E485B576 625E7EC6 F44C42E9 A637ED6B 0BFF5CB6 F406B7ED ",
+    "EE386BFB 5A899FA5 AE9F2411 7C4B1FE6 49286651 ECE45B3D C2007CB8 A163BF05 ",
+    "98DA4836 1C55D39A 69163FA8 FD24CF5F 83655D23 DCA3AD96 1C62F356 208552BB ",
+    "9ED52907 7096966D 670C354E 4ABC9804 F1746C08 CA18217C 32905E46 2E36CE3B ",
+    "E39E772C 180E8603 9B2783A2 EC07A28F B5C55DF0 6F4C52C9 DE2BCBF6 95581718 ",
+    "3995497C EA956AE5 15D22618 98FA0510 15728E5A 8AAAC42D AD33170D 04507A33 ",
+    "A85521AB DF1CBA64 ECFB8504 58DBEF0A 8AEA7157 5D060C7D B3970F85 A6E1E4C7 ",
+    "ABF5AE8C DB0933D7 1E8C94E0 4A25619D CEE3D226 1AD2EE6B F12FFA06 D98A0864 ",
+    "D8760273 3EC86A64 521F2B18 177B200C BBE11757 7A615D6C 770988C0 BAD946E2 ",
+    "08E24FA0 74E5AB31 43DB5BFC E0FD108E 4B82D120 A9210801 1A723C12 A787E6D7 ",
+    "88719A10 BDBA5B26 99C32718 6AF4E23C 1A946834 B6150BDA 2583E9CA 2AD44CE8 ",
+    "DBBBC2DB 04DE8EF9 2E8EFC14 1FBECAA6 287C5947 4E6BC05D 99B2964F A090C3A2 ",
+    "233BA186 515BE7ED 1F612970 CEE2D7AF B81BDD76 2170481C D0069127 D5B05AA9 ",
+    "93B4EA98 8D8FDDC1 86FFB7DC 90A6C08F 4DF435C9 34063199 FFFFFFFF FFFFFFFF"
+));
+
+/// The 6144-bit group of RFC 5054, appendix A.
+const N_6144: [u8; 768] = decode_hex(concat!(
+    "FFFFFFFF FFFFFFFF C90FDAA2 2168C234 C4C6628B 80DC1CD1 29024E08 8A67CC74 ",
+    "020BBEA6 3B139B22 514A0879 8E3404DD EF9519B3 CD3A431B 302B0A6D F25F1437 ",
+    "4FE1356D 6D51C245 E485B576 625E7EC6 F44C42E9 A637ED6B 0BFF5CB6 F406B7ED ",
+    "EE386BFB 5A899FA5 AE9F2411 7C4B1FE6 49286651 ECE45B3D C2007CB8 A163BF05 ",
+    "98DA4836 1C55D39A 69163FA8 FD24CF5F 83655D23 DCA3AD96 1C62F356 208552BB ",
+    "9ED52907 7096966D 670C354E 4ABC9804 F1746C08 CA18217C 32905E46 2E36CE3B ",
+    "E39E772C 180E8603 9B2783A2 EC07A28F B5C55DF0 6F4C52C9 DE2BCBF6 95581718 ",
+    "3995497C EA956AE5 15D22618 98FA0510 15728E5A 8AAAC42D AD33170D 04507A33 ",
+    "A85521AB DF1CBA64 ECFB8504 58DBEF0A 8AEA7157 5D060C7D B3970F85 A6E1E4C7 ",
+    "ABF5AE8C DB0933D7 1E8C94E0 4A25619D CEE3D226 1AD2EE6B F12FFA06 D98A0864 ",
+    "D8760273 3EC86A64 521F2B18 177B200C BBE11757 7A615D6C 770988C0 BAD946E2 ",
+    "08E24FA0 74E5AB31 43DB5BFC E0FD108E 4B82D120 A9210801 1A723C12 A787E6D7 ",
+    "88719A10 BDBA5B26 99C32718 6AF4E23C 1A946834 B6150BDA 2583E9CA 2AD44CE8 ",
+    "DBBBC2DB 04DE8EF9 2E8EFC14 1FBECAA6 287C5947 4E6BC05D 99B2964F A090C3A2 ",
+    "233BA186 515BE7ED 1F612970 CEE2D7AF B81BDD76 2170481C D0069127 D5B05AA9 ",
+    "93B4EA98 8D8FDDC1 86FFB7DC 90A6C08F 4DF435C9 34028492 36C3FAB4 D27C7026 ",
+    "C1D4DCB2 602646DE C9751E76 3DBA37BD F8FF9406 AD9E530E E5DB382F 413001AE ",
+    "B06A53ED 9027D831 179727B0 865A8918 DA3EDBEB CF9B14ED 44CE6CBA CED4BB1B ",
+    "DB7F1447 E6CC254B 33205151 2BD7AF42 6FB8F401 378CD2BF 5983CA01 C64B92EC ",
+    "F032EA15 D1721D03 F482D7CE 6E74FEF6 D55E702F 46980C82 B5A84031 900B1C9E ",
+    "59E7C97F BEC7E8F3 23A97A7E 36CC88BE 0F1D45B7 FF585AC5 4BD407B2 2B4154AA ",
+    "CC8F6D7E BF48E1D8 14CC5ED2 0F8037E0 A79715EE F29BE328 06A1D58B B7C5DA76 ",
+    "F550AA3D 8A1FBFF0 EB19CCB1 A313D55C DA56C9EC 2EF29632 387FE8D7 6E3C0468 ",
+    "043E8F66 3F4860EE 12BF2D5B 0B7474D6 E694F91E 6DCC4024 FFFFFFFF FFFFFFFF"
+));
+
+/// The 8192-bit group of RFC 5054, appendix A.
+const N_8192: [u8; 1024] = decode_hex(concat!(
+    "FFFFFFFF FFFFFFFF C90FDAA2 2168C234 C4C6628B 80DC1CD1 29024E08 8A67CC74 ",
+    "020BBEA6 3B139B22 514A0879 8E3404DD EF9519B3 CD3A431B 302B0A6D F25F1437 ",
+    "4FE1356D 6D51C245 E485B576 625E7EC6 F44C42E9 A637ED6B 0BFF5CB6 F406B7ED ",
+    "EE386BFB 5A899FA5 AE9F2411 7C4B1FE6 49286651 ECE45B3D C2007CB8 A163BF05 ",
+    "98DA4836 1C55D39A 69163FA8 FD24CF5F 83655D23 DCA3AD96 1C62F356 208552BB ",
+    "9ED52907 7096966D 670C354E 4ABC9804 F1746C08 CA18217C 32905E46 2E36CE3B ",
+    "E39E772C 180E8603 9B2783A2 EC07A28F B5C55DF0 6F4C52C9 DE2BCBF6 95581718 ",
+    "3995497C EA956AE5 15D22618 98FA0510 15728E5A 8AAAC42D AD33170D 04507A33 ",
+    "A85521AB DF1CBA64 ECFB8504 58DBEF0A 8AEA7157 5D060C7D B3970F85 A6E1E4C7 ",
+    "ABF5AE8C DB0933D7 1E8C94E0 4A25619D CEE3D226 1AD2EE6B F12FFA06 D98A0864 ",
+    "D8760273 3EC86A64 521F2B18 177B200C BBE11757 7A615D6C 770988C0 BAD946E2 ",
+    "08E24FA0 74E5AB31 43DB5BFC E0FD108E 4B82D120 A9210801 1A723C12 A787E6D7 ",
+    "88719A10 BDBA5B26 99C32718 6AF4E23C 1A946834 B6150BDA 2583E9CA 2AD44CE8 ",
+    "DBBBC2DB 04DE8EF9 2E8EFC14 1FBECAA6 287C5947 4E6BC05D 99B2964F A090C3A2 ",
+    "233BA186 515BE7ED 1F612970 CEE2D7AF B81BDD76 2170481C D0069127 D5B05AA9 ",
+    "93B4EA98 8D8FDDC1 86FFB7DC 90A6C08F 4DF435C9 34028492 36C3FAB4 D27C7026 ",
+    "C1D4DCB2 602646DE C9751E76 3DBA37BD F8FF9406 AD9E530E E5DB382F 413001AE ",
+    "B06A53ED 9027D831 179727B0 865A8918 DA3EDBEB CF9B14ED 44CE6CBA CED4BB1B ",
+    "DB7F1447 E6CC254B 33205151 2BD7AF42 6FB8F401 378CD2BF 5983CA01 C64B92EC ",
+    "F032EA15 D1721D03 F482D7CE 6E74FEF6 D55E702F 46980C82 B5A84031 900B1C9E ",
+    "59E7C97F BEC7E8F3 23A97A7E 36CC88BE 0F1D45B7 FF585AC5 4BD407B2 2B4154AA ",
+    "CC8F6D7E BF48E1D8 14CC5ED2 0F8037E0 A79715EE F29BE328 06A1D58B B7C5DA76 ",
+    "F550AA3D 8A1FBFF0 EB19CCB1 A313D55C DA56C9EC 2EF29632 387FE8D7 6E3C0468 ",
+    "043E8F66 3F4860EE 12BF2D5B 0B7474D6 E694F91E 6DBE1159 74A3926F 12FEE5E4 ",
+    "38777CB6 A932DF8C D8BEC4D0 73B931BA 3BC832B6 8D9DD300 741FA7BF 8AFC47ED ",
+    "2576F693 6BA42466 3AAB639C 5AE4F568 3423B474 2BF1C978 238F16CB E39D652D ",
+    "E3FDB8BE FC848AD9 22222E04 A4037C07 13EB57A8 1A23F0C7 3473FC64 6CEA306B ",
+    "4BCBC886 2F8385DD FA9D4B7F A2C087E8 79683303 ED5BDD3A 062B3CF5 B3A278A6 ",
+    "6D2A13F8 3F44F82D DF310EE0 74AB6A36 4597E899 A0255DC1 64F31CC5 0846851D ",
+    "F9AB4819 5DED7EA1 B1D510BD 7EE74D73 FAF36BC3 1ECFA268 359046F4 EB879F92 ",
+    "4009438B 481C6CD7 889A002E D5EE382B C9190DA6 FC026E47 9558E447 5677E9AA ",
+    "9E3050E2 765694DF C81F56E8 80B96E71 60C980DD 98EDD3DF FFFFFFFF FFFFFFFF"
+));
+
+/// Runs a generic arithmetic function with the limb count of the group.
+macro_rules! dispatch {
+    ($group:expr, $function:ident($($argument:expr),* $(,)?)) => {{
+        let group = $group;
+        match group {
+            SrpGroup::G1024 => $function::<{ U1024::LIMBS }>(group, $($argument),*),
+            SrpGroup::G1536 => $function::<{ U1536::LIMBS }>(group, $($argument),*),
+            SrpGroup::G2048 => $function::<{ U2048::LIMBS }>(group, $($argument),*),
+            SrpGroup::G3072 => $function::<{ U3072::LIMBS }>(group, $($argument),*),
+            SrpGroup::G4096 => $function::<{ U4096::LIMBS }>(group, $($argument),*),
+            SrpGroup::G6144 => $function::<{ U6144::LIMBS }>(group, $($argument),*),
+            SrpGroup::G8192 => $function::<{ U8192::LIMBS }>(group, $($argument),*),
+        }
+    }};
+}
+
+// ------------------- Hashes -------------------
+// ------------------- 哈希 -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A hash function that SRP can be instantiated with.
+///
+/// This is a sealed trait implemented by the SHA-2 hashers and, with the
+/// `insecure-legacy-hashes` feature, by SHA-1.
+///
+/// 可以用来实例化 SRP 的哈希函数。
+///
+/// 这是一个密封的 trait，由 SHA-2 哈希器实现，并且在启用 `insecure-legacy-hashes` 特性时由
+/// SHA-1 实现。
+pub trait SrpHash: private::Sealed {
+    /// The incremental `digest` implementation.
+    ///
+    /// 增量式 `digest` 实现。
+    type Digest: Digest;
+}
+
+impl<H: Hasher> private::Sealed for H {}
+impl<H: Hasher> SrpHash for H {
+    type Digest = H::Digest;
+}
+
+#[cfg(feature = "insecure-legacy-hashes")]
+impl private::Sealed for Sha1Params {}
+#[cfg(feature = "insecure-legacy-hashes")]
+impl SrpHash for Sha1Params {
+    type Digest = sha1::Sha1;
+}
+
+// ------------------- Records -------------------
+// ------------------- 记录 -------------------
+
+/// The salt and verifier a server stores for a registered user.
+///
+/// 服务器为已注册用户存储的盐和验证值。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SrpRecord {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    salt: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    verifier: Vec<u8>,
+}
+
+impl SrpRecord {
+    /// Wraps a salt and a big-endian verifier, for example one imported from an existing
+    /// deployment.
+    ///
+    /// The verifier is checked against the group when a login starts.
+    ///
+    /// 包装一个盐和一个大端验证值，例如从现有部署导入的验证值。
+    ///
+    /// 验证值会在登录开始时根据群进行检查。
+    pub fn new(salt: &[u8], verifier: &[u8]) -> Self {
+        Self {
+            salt: salt.to_vec(),
+            verifier: verifier.to_vec(),
+        }
+    }
+
+    /// Returns the salt, which the server sends to the client.
+    ///
+    /// 返回盐，服务器会将其发送给客户端。
+    pub fn salt(&self) -> &[u8] {
+        &self.salt
+    }
+
+    /// Returns the verifier `v = g^x mod N`.
+    ///
+    /// 返回验证值 `v = g^x mod N`。
+    pub fn verifier(&self) -> &[u8] {
+        &self.verifier
+    }
+}
+
+impl core::fmt::Debug for SrpRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SrpRecord").finish_non_exhaustive()
+    }
+}
+
+// ------------------- SRP -------------------
+// ------------------- SRP -------------------
+
+/// The SRP-6a protocol over a group with the hash `H`.
+///
+/// 基于一个群和哈希 `H` 的 SRP-6a 协议。
+pub struct Srp<H: SrpHash> {
+    group: SrpGroup,
+    _hash: PhantomData<H>,
+}
+
+impl<H: SrpHash> Srp<H> {
+    /// Creates the protocol over `group`.
+    ///
+    /// 在 `group` 上创建该协议。
+    pub fn new(group: SrpGroup) -> Self {
+        Self {
+            group,
+            _hash: PhantomData,
+        }
+    }
+
+    /// Returns the group.
+    ///
+    /// 返回群。
+    pub fn group(&self) -> SrpGroup {
+        self.group
+    }
+
+    /// Registers a user, drawing the salt from the crate's system RNG.
+    ///
+    /// See [`Srp::register_with_rng`].
+    ///
+    /// 注册一个用户，盐从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Srp::register_with_rng`]。
+    pub fn register(
+        &self,
+        username: &[u8],
+        password: &SecretBox<[u8]>,
+    ) -> Result<SrpRecord, Error> {
+        self.register_with_rng(&mut SystemRng::new(), username, password)
+    }
+
+    /// Registers a user with a 32-byte salt drawn from `rng`, and returns the record the server
+    /// stores.
+    ///
+    /// 使用从 `rng` 获取的 32 字节盐注册一个用户，并返回服务器存储的记录。
+    pub fn register_with_rng<R: CryptoRngCore>(
+        &self,
+        rng: &mut R,
+        username: &[u8],
+        password: &SecretBox<[u8]>,
+    ) -> Result<SrpRecord, Error> {
+        let mut salt = [0u8; SALT_SIZE];
+        rng.fill_bytes(&mut salt);
+        self.register_with_salt(username, password, &salt)
+    }
+
+    /// Computes the record of a user for a given salt.
+    ///
+    /// 为给定的盐计算用户的记录。
+    pub fn register_with_salt(
+        &self,
+        username: &[u8],
+        password: &SecretBox<[u8]>,
+        salt: &[u8],
+    ) -> Result<SrpRecord, Error> {
+        let identity_hash = identity_hash::<H>(username, password);
+        let x = Zeroizing::new(hash::<H>(&[salt, &identity_hash]));
+        let verifier = dispatch!(self.group, generator_pow(&x));
+        Ok(SrpRecord::new(salt, &verifier))
+    }
+
+    /// Starts the client side, drawing randomness from the crate's system RNG.
+    ///
+    /// See [`Srp::start_client_with_rng`].
+    ///
+    /// 开始客户端流程，随机性从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Srp::start_client_with_rng`]。
+    pub fn start_client(
+        &self,
+        username: &[u8],
+        password: &SecretBox<[u8]>,
+    ) -> Result<(SrpClient<H>, Vec<u8>), Error> {
+        self.start_client_with_rng(&mut SystemRng::new(), username, password)
+    }
+
+    /// Starts the client side with randomness drawn from `rng`, and returns the client state
+    /// together with the share `A` to send with the username.
+    ///
+    /// 使用从 `rng` 获取的随机性开始客户端流程，并将客户端状态与需要随用户名一起发送的份额 `A`
+    /// 一起返回。
+    pub fn start_client_with_rng<R: CryptoRngCore>(
+        &self,
+        rng: &mut R,
+        username: &[u8],
+        password: &SecretBox<[u8]>,
+    ) -> Result<(SrpClient<H>, Vec<u8>), Error> {
+        let mut a = Zeroizing::new(vec![0u8; PRIVATE_EXPONENT_SIZE]);
+        rng.fill_bytes(&mut a);
+        self.client_with_exponent(username, password, a)
+    }
+
+    fn client_with_exponent(
+        &self,
+        username: &[u8],
+        password: &SecretBox<[u8]>,
+        a: Zeroizing<Vec<u8>>,
+    ) -> Result<(SrpClient<H>, Vec<u8>), Error> {
+        let share = dispatch!(self.group, generator_pow(&a)).to_vec();
+        let client = SrpClient {
+            group: self.group,
+            username: username.to_vec(),
+            identity_hash: identity_hash::<H>(username, password),
+            a,
+            share: share.clone(),
+            _hash: PhantomData,
+        };
+        Ok((client, share))
+    }
+
+    /// Starts the server side for a user's record, drawing randomness from the crate's system
+    /// RNG.
+    ///
+    /// See [`Srp::start_server_with_rng`].
+    ///
+    /// 为用户的记录开始服务器流程，随机性从 crate 的系统 RNG 获取。
+    ///
+    /// 参见 [`Srp::start_server_with_rng`]。
+    pub fn start_server(
+        &self,
+        username: &[u8],
+        record: &SrpRecord,
+    ) -> Result<(SrpServer<H>, Vec<u8>), Error> {
+        self.start_server_with_rng(&mut SystemRng::new(), username, record)
+    }
+
+    /// Starts the server side for a user's record with randomness drawn from `rng`, and
+    /// returns the server state together with the share `B` to send with the record's salt.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the verifier is not a valid element of the
+    /// group.
+    ///
+    /// 使用从 `rng` 获取的随机性为用户的记录开始服务器流程，并将服务器状态与需要随记录的盐一起
+    /// 发送的份额 `B` 一起返回。
+    ///
+    /// 如果验证值不是群的有效元素，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn start_server_with_rng<R: CryptoRngCore>(
+        &self,
+        rng: &mut R,
+        username: &[u8],
+        record: &SrpRecord,
+    ) -> Result<(SrpServer<H>, Vec<u8>), Error> {
+        let mut b = Zeroizing::new(vec![0u8; PRIVATE_EXPONENT_SIZE]);
+        rng.fill_bytes(&mut b);
+        self.server_with_exponent(username, record, b)
+    }
+
+    fn server_with_exponent(
+        &self,
+        username: &[u8],
+        record: &SrpRecord,
+        b: Zeroizing<Vec<u8>>,
+    ) -> Result<(SrpServer<H>, Vec<u8>), Error> {
+        let k = multiplier::<H>(self.group);
+        let share = dispatch!(self.group, server_share(&k, &record.verifier, &b))
+            .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        let server = SrpServer {
+            group: self.group,
+            username: username.to_vec(),
+            record: record.clone(),
+            b,
+            share: share.clone(),
+            _hash: PhantomData,
+        };
+        Ok((server, share))
+    }
+}
+
+impl<H: SrpHash> Clone for Srp<H> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H: SrpHash> Copy for Srp<H> {}
+
+impl<H: SrpHash> core::fmt::Debug for Srp<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Srp").field("group", &self.group).finish()
+    }
+}
+
+/// A client waiting for the server's salt and share.
+///
+/// 一个等待服务器的盐和份额的客户端。
+pub struct SrpClient<H: SrpHash> {
+    group: SrpGroup,
+    username: Vec<u8>,
+    identity_hash: Zeroizing<Vec<u8>>,
+    a: Zeroizing<Vec<u8>>,
+    share: Vec<u8>,
+    _hash: PhantomData<H>,
+}
+
+impl<H: SrpHash> SrpClient<H> {
+    /// Processes the server's salt and share `B`, and returns the confirmation step holding
+    /// the proof `M1` to send.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if `B` is not a valid share.
+    ///
+    /// 处理服务器的盐和份额 `B`，并返回持有需要发送的证明 `M1` 的确认步骤。
+    ///
+    /// 如果 `B` 不是有效的份额，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败。
+    pub fn finish(self, salt: &[u8], server_share: &[u8]) -> Result<SrpConfirmation, Error> {
+        let group = self.group;
+        let server_share = padded(group, server_share)?;
+        let u = scrambler::<H>(&self.share, &server_share);
+        let k = multiplier::<H>(group);
+        let x = Zeroizing::new(hash::<H>(&[salt, &self.identity_hash]));
+        let premaster = dispatch!(group, client_premaster(&k, &x, &self.a, &u, &server_share))
+            .ok_or(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))?;
+
+        let key = Zeroizing::new(hash::<H>(&[minimal(&premaster)]));
+        let proof = compute_client_proof::<H>(
+            group,
+            &self.username,
+            salt,
+            &self.share,
+            &server_share,
+            &key,
+        );
+        let expected = hash::<H>(&[minimal(&self.share), &proof, &key]);
+        Ok(SrpConfirmation {
+            proof,
+            expected,
+            key,
+        })
+    }
+}
+
+impl<H: SrpHash> core::fmt::Debug for SrpClient<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SrpClient")
+            .field("group", &self.group)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The confirmation step of a client.
+///
+/// 客户端的确认步骤。
+pub struct SrpConfirmation {
+    proof: Vec<u8>,
+    expected: Vec<u8>,
+    key: Zeroizing<Vec<u8>>,
+}
+
+impl SrpConfirmation {
+    /// Returns the client's proof `M1` to send to the server.
+    ///
+    /// 返回需要发送给服务器的客户端证明 `M1`。
+    pub fn proof(&self) -> &[u8] {
+        &self.proof
+    }
+
+    /// Checks the server's proof `M2` and returns the session key `K`.
+    ///
+    /// Fails with `KeyAgreementError::ConfirmationFailed` if the server does not hold the
+    /// user's verifier or the exchange was tampered with.
+    ///
+    /// 检查服务器的证明 `M2` 并返回会话密钥 `K`。
+    ///
+    /// 如果服务器不持有该用户的验证值或者交换被篡改，则以
+    /// `KeyAgreementError::ConfirmationFailed` 失败。
+    pub fn verify(self, server_proof: &[u8]) -> Result<SharedSecret, Error> {
+        check_proof(&self.expected, server_proof)?;
+        Ok(SharedSecret::new(self.key.to_vec()))
+    }
+}
+
+impl core::fmt::Debug for SrpConfirmation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SrpConfirmation").finish_non_exhaustive()
+    }
+}
+
+/// A server waiting for the client's share and proof.
+///
+/// 一个等待客户端份额和证明的服务器。
+pub struct SrpServer<H: SrpHash> {
+    group: SrpGroup,
+    username: Vec<u8>,
+    record: SrpRecord,
+    b: Zeroizing<Vec<u8>>,
+    share: Vec<u8>,
+    _hash: PhantomData<H>,
+}
+
+impl<H: SrpHash> SrpServer<H> {
+    /// Checks the client's share `A` and proof `M1`, and returns the server's proof `M2`
+    /// together with the session key `K`.
+    ///
+    /// Fails with `KeyAgreementError::InvalidPeerPublicKey` if `A` is not a valid share, and
+    /// with `KeyAgreementError::ConfirmationFailed` if the password is wrong or the exchange
+    /// was tampered with.
+    ///
+    /// 检查客户端的份额 `A` 和证明 `M1`，并返回服务器的证明 `M2` 以及会话密钥 `K`。
+    ///
+    /// 如果 `A` 不是有效的份额，则以 `KeyAgreementError::InvalidPeerPublicKey` 失败；如果口令
+    /// 错误或交换被篡改，则以 `KeyAgreementError::ConfirmationFailed` 失败。
+    pub fn finish(
+        self,
+        client_share: &[u8],
+        client_proof: &[u8],
+    ) -> Result<(Vec<u8>, SharedSecret), Error> {
+        let group = self.group;
+        let client_share = padded(group, client_share)?;
+        let u = scrambler::<H>(&client_share, &self.share);
+        let premaster = dispatch!(
+            group,
+            server_premaster(&client_share, &self.record.verifier, &u, &self.b)
+        )
+        .ok_or(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))?;
+
+        let key = Zeroizing::new(hash::<H>(&[minimal(&premaster)]));
+        let expected = compute_client_proof::<H>(
+            group,
+            &self.username,
+            &self.record.salt,
+            &client_share,
+            &self.share,
+            &key,
+        );
+        check_proof(&expected, client_proof)?;
+        let proof = hash::<H>(&[minimal(&client_share), &expected, &key]);
+        Ok((proof, SharedSecret::new(key.to_vec())))
+    }
+}
+
+impl<H: SrpHash> core::fmt::Debug for SrpServer<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SrpServer")
+            .field("group", &self.group)
+            .finish_non_exhaustive()
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for SRP-6a with SHA-256.
+///
+/// 使用 SHA-256 的 SRP-6a 的类型别名。
+pub type SrpSha256 = Srp<Sha256>;
+
+/// A type alias for SRP-6a with SHA-512.
+///
+/// 使用 SHA-512 的 SRP-6a 的类型别名。
+pub type SrpSha512 = Srp<Sha512>;
+
+/// A type alias for SRP-6a with SHA-1, as specified by RFC 5054.
+///
+/// 使用 SHA-1 的 SRP-6a 的类型别名，如 RFC 5054 所规定。
+#[cfg(feature = "insecure-legacy-hashes")]
+pub type SrpSha1 = Srp<Sha1Params>;
+
+// ------------------- Protocol Values -------------------
+// ------------------- 协议值 -------------------
+
+fn hash<H: SrpHash>(parts: &[&[u8]]) -> Vec<u8> {
+    let mut digest = H::Digest::new();
+    for part in parts {
+        digest.update(part);
+    }
+    digest.finalize().to_vec()
+}
+
+/// `H(I | ":" | P)`, the part of `x` that does not depend on the salt.
+fn identity_hash<H: SrpHash>(username: &[u8], password: &SecretBox<[u8]>) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(hash::<H>(&[username, b":", password.expose_secret()]))
+}
+
+/// `k = H(N | PAD(g))`.
+fn multiplier<H: SrpHash>(group: SrpGroup) -> Vec<u8> {
+    let mut generator = vec![0u8; group.size()];
+    generator[group.size() - 1] = group.generator();
+    hash::<H>(&[group.modulus(), &generator])
+}
+
+/// `u = H(PAD(A) | PAD(B))`.
+fn scrambler<H: SrpHash>(client_share: &[u8], server_share: &[u8]) -> Vec<u8> {
+    hash::<H>(&[client_share, server_share])
+}
+
+/// `M1 = H(H(N) XOR H(g) | H(I) | s | A | B | K)`.
+fn compute_client_proof<H: SrpHash>(
+    group: SrpGroup,
+    username: &[u8],
+    salt: &[u8],
+    client_share: &[u8],
+    server_share: &[u8],
+    key: &[u8],
+) -> Vec<u8> {
+    let group_hash: Vec<u8> = hash::<H>(&[group.modulus()])
+        .iter()
+        .zip(hash::<H>(&[&[group.generator()]]))
+        .map(|(n, g)| n ^ g)
+        .collect();
+    hash::<H>(&[
+        &group_hash,
+        &hash::<H>(&[username]),
+        salt,
+        minimal(client_share),
+        minimal(server_share),
+        key,
+    ])
+}
+
+fn check_proof(expected: &[u8], received: &[u8]) -> Result<(), Error> {
+    if bool::from(expected.ct_eq(received)) {
+        Ok(())
+    } else {
+        Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+    }
+}
+
+/// Left-pads a peer's share to the length of `N`.
+fn padded(group: SrpGroup, share: &[u8]) -> Result<Vec<u8>, Error> {
+    let share = minimal(share);
+    if share.len() > group.size() {
+        return Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey));
+    }
+    let mut padded = vec![0u8; group.size() - share.len()];
+    padded.extend_from_slice(share);
+    Ok(padded)
+}
+
+/// Strips the leading zero bytes of a big-endian integer.
+fn minimal(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|&byte| byte != 0)
+        .unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+// ------------------- Modular Arithmetic -------------------
+// ------------------- 模运算 -------------------
+
+/// The residues modulo the group's `N`.
+struct Residues<const LIMBS: usize> {
+    params: DynResidueParams<LIMBS>,
+    generator: DynResidue<LIMBS>,
+}
+
+impl<const LIMBS: usize> Residues<LIMBS> {
+    fn new(group: SrpGroup) -> Self {
+        let params = DynResidueParams::new(&Uint::from_be_slice(group.modulus()));
+        let generator = DynResidue::new(&Uint::from_u64(u64::from(group.generator())), params);
+        Self { params, generator }
+    }
+
+    /// Reduces a big-endian integer of at most the length of `N`.
+    fn reduce(&self, bytes: &[u8]) -> Option<DynResidue<LIMBS>> {
+        let size = LIMBS * Limb::BYTES;
+        if bytes.len() > size {
+            return None;
+        }
+        let mut padded = Zeroizing::new(vec![0u8; size]);
+        padded[size - bytes.len()..].copy_from_slice(bytes);
+        let mut integer = Uint::<LIMBS>::from_be_slice(&padded);
+        let residue = DynResidue::new(&integer, self.params);
+        zeroize::Zeroize::zeroize(&mut integer);
+        Some(residue)
+    }
+
+    /// Reduces a peer's value, which must not be zero modulo `N`.
+    fn reduce_nonzero(&self, bytes: &[u8]) -> Option<DynResidue<LIMBS>> {
+        self.reduce(bytes)
+            .filter(|residue| !bool::from(residue.retrieve().is_zero()))
+    }
+
+    /// Encodes a residue as a big-endian integer of the length of `N`.
+    fn encode(&self, residue: &DynResidue<LIMBS>) -> Zeroizing<Vec<u8>> {
+        let mut integer = residue.retrieve();
+        let encoded = integer
+            .as_words()
+            .iter()
+            .rev()
+            .flat_map(|word| word.to_be_bytes())
+            .collect();
+        zeroize::Zeroize::zeroize(&mut integer);
+        Zeroizing::new(encoded)
+    }
+}
+
+/// Raises a residue to an exponent of at most 512 bits, in time independent of its value.
+fn pow<const LIMBS: usize>(base: &DynResidue<LIMBS>, exponent: &[u8]) -> DynResidue<LIMBS> {
+    let mut padded = Zeroizing::new([0u8; 64]);
+    padded[64 - exponent.len()..].copy_from_slice(exponent);
+    let mut exponent = U512::from_be_bytes(*padded);
+    let result = base.pow_bounded_exp(&exponent, U512::BITS);
+    zeroize::Zeroize::zeroize(&mut exponent);
+    result
+}
+
+/// `g^e mod N`.
+fn generator_pow<const LIMBS: usize>(group: SrpGroup, exponent: &[u8]) -> Zeroizing<Vec<u8>> {
+    let residues = Residues::<LIMBS>::new(group);
+    residues.encode(&pow(&residues.generator, exponent))
+}
+
+/// `B = k * v + g^b mod N`.
+fn server_share<const LIMBS: usize>(
+    group: SrpGroup,
+    k: &[u8],
+    verifier: &[u8],
+    b: &[u8],
+) -> Option<Vec<u8>> {
+    let residues = Residues::<LIMBS>::new(group);
+    let k = residues.reduce(k)?;
+    let verifier = residues.reduce_nonzero(verifier)?;
+    let share = k * verifier + pow(&residues.generator, b);
+    Some(residues.encode(&share).to_vec())
+}
+
+/// `S = (B - k * g^x)^(a + u * x) mod N`, computed as `base^a * (base^u)^x`.
+fn client_premaster<const LIMBS: usize>(
+    group: SrpGroup,
+    k: &[u8],
+    x: &[u8],
+    a: &[u8],
+    u: &[u8],
+    server_share: &[u8],
+) -> Option<Zeroizing<Vec<u8>>> {
+    let residues = Residues::<LIMBS>::new(group);
+    let server_share = residues.reduce_nonzero(server_share)?;
+    if minimal(u).is_empty() {
+        return None;
+    }
+    let k = residues.reduce(k)?;
+    let base = server_share - k * pow(&residues.generator, x);
+    let premaster = pow(&base, a) * pow(&pow(&base, u), x);
+    Some(residues.encode(&premaster))
+}
+
+/// `S = (A * v^u)^b mod N`.
+fn server_premaster<const LIMBS: usize>(
+    group: SrpGroup,
+    client_share: &[u8],
+    verifier: &[u8],
+    u: &[u8],
+    b: &[u8],
+) -> Option<Zeroizing<Vec<u8>>> {
+    let residues = Residues::<LIMBS>::new(group);
+    let client_share = residues.reduce_nonzero(client_share)?;
+    if minimal(u).is_empty() {
+        return None;
+    }
+    let verifier = residues.reduce_nonzero(verifier)?;
+    let premaster = pow(&(client_share * pow(&verifier, u)), b);
+    Some(residues.encode(&premaster))
+}
+
+/// Decodes the hexadecimal digits of a group constant, skipping spaces.
+const fn decode_hex<const N: usize>(hex: &str) -> [u8; N] {
+    let hex = hex.as_bytes();
+    let mut output = [0u8; N];
+    let mut digits = 0;
+    let mut index = 0;
+    while index < hex.len() {
+        let value = match hex[index] {
+            b' ' => {
+                index += 1;
+                continue;
+            }
+            digit @ b'0'..=b'9' => digit - b'0',
+            digit @ b'A'..=b'F' => digit - b'A' + 10,
+            _ => panic!("invalid hexadecimal digit"),
+        };
+        output[digits / 2] |= value << (4 * (1 - digits % 2));
+        digits += 1;
+        index += 1;
+    }
+    assert!(digits == 2 * N, "wrong number of hexadecimal digits");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    fn password(password: &[u8]) -> SecretBox<[u8]> {
+        SecretBox::new(Box::from(password))
+    }
+
+    #[cfg(feature = "insecure-legacy-hashes")]
+    #[test]
+    fn test_rfc5054_vector() -> Result<(), Error> {
+        // Appendix B of RFC 5054: the 1024-bit group with SHA-1.
+        // RFC 5054 附录 B：使用 SHA-1 的 1024 位群。
+        let bytes = |hex: &str| hex::decode(hex.replace(' ', "")).unwrap_or_default();
+        let srp = SrpSha1::new(SrpGroup::G1024);
+        let username = b"alice";
+        let secret = password(b"password123");
+        let salt = bytes("BEB25379 D1A8581E B5A72767 3A2441EE");
+
+        assert_eq!(
+            multiplier::<Sha1Params>(srp.group()),
+            bytes("7556AA04 5AEF2CDD 07ABAF0F 665C3E81 8913186F")
+        );
+        let identity_hash = identity_hash::<Sha1Params>(username, &secret);
+        assert_eq!(
+            hash::<Sha1Params>(&[&salt, &identity_hash]),
+            bytes("94B7555A ABE9127C C58CCF49 93DB6CF8 4D16C124")
+        );
+        let record = srp.register_with_salt(username, &secret, &salt)?;
+        assert_eq!(
+            record.verifier(),
+            bytes(
+                "7E273DE8 696FFC4F 4E337D05 B4B375BE B0DDE156 9E8FA00A 9886D812 9BADA1F1 \
+                 822223CA 1A605B53 0E379BA4 729FDC59 F105B478 7E5186F5 C671085A 1447B52A \
+                 48CF1970 B4FB6F84 00BBF4CE BFBB1681 52E08AB5 EA53D15C 1AFF87B2 B9DA6E04 \
+                 E058AD51 CC72BFC9 033B564E 26480D78 E955A5E2 9E7AB245 DB2BE315 E2099AFB"
+            )
+        );
+
+        let a = bytes("60975527 035CF2AD 1989806F 0407210B C81EDC04 E2762A56 AFD529DD DA2D4393");
+        let (client, client_share) =
+            srp.client_with_exponent(username, &secret, Zeroizing::new(a))?;
+        assert_eq!(
+            client_share,
+            bytes(
+                "61D5E490 F6F1B795 47B0704C 436F523D D0E560F0 C64115BB 72557EC4 4352E890 \
+                 3211C046 92272D8B 2D1A5358 A2CF1B6E 0BFCF99F 921530EC 8E393561 79EAE45E \
+                 42BA92AE ACED8251 71E1E8B9 AF6D9C03 E1327F44 BE087EF0 6530E69F 66615261 \
+                 EEF54073 CA11CF58 58F0EDFD FE15EFEA B349EF5D 76988A36 72FAC47B 0769447B"
+            )
+        );
+        let b = bytes("E487CB59 D31AC550 471E81F0 0F6928E0 1DDA08E9 74A004F4 9E61F5D1 05284D20");
+        let (server, server_share) =
+            srp.server_with_exponent(username, &record, Zeroizing::new(b))?;
+        assert_eq!(
+            server_share,
+            bytes(
+                "BD0C6151 2C692C0C B6D041FA 01BB152D 4916A1E7 7AF46AE1 05393011 BAF38964 \
+                 DC46A067 0DD125B9 5A981652 236F99D9 B681CBF8 7837EC99 6C6DA044 53728610 \
+                 D0C6DDB5 8B318885 D7D82C7F 8DEB75CE 7BD4FBAA 37089E6F 9C6059F3 88838E7A \
+                 00030B33 1EB76840 910440B1 B27AAEAE EB4012B7 D7665238 A8E3FB00 4B117B58"
+            )
+        );
+        assert_eq!(
+            scrambler::<Sha1Params>(&client_share, &server_share),
+            bytes("CE38B959 3487DA98 554ED47D 70A7AE5F 462EF019")
+        );
+
+        // K = H(S) for the premaster secret S of the RFC.
+        // K = H(S)，其中 S 是 RFC 中的预主密钥。
+        let premaster = bytes(
+            "B0DC82BA BCF30674 AE450C02 87745E79 90A3381F 63B387AA F271A10D 233861E3 \
+             59B48220 F7C4693C 9AE12B0A 6F67809F 0876E2D0 13800D6C 41BB59B6 D5979B5C \
+             00A172B4 A2A5903A 0BDCAF8A 709585EB 2AFAFA8F 3499B200 210DCC1F 10EB3394 \
+             3CD67FC8 8A2F39A4 BE5BEC4E C0A3212D C346D7E4 74B29EDE 8A469FFE CA686E5A",
+        );
+        let confirmation = client.finish(&salt, &server_share)?;
+        let (server_proof, server_key) = server.finish(&client_share, confirmation.proof())?;
+        let client_key = confirmation.verify(&server_proof)?;
+        assert_eq!(client_key.expose_raw(), hash::<Sha1Params>(&[&premaster]));
+        assert_eq!(server_key.expose_raw(), client_key.expose_raw());
+        Ok(())
+    }
+
+    #[test]
+    fn test_srp() -> Result<(), Error> {
+        let mut rng = SeededRng(21);
+        for group in [SrpGroup::G1024, SrpGroup::G2048, SrpGroup::G3072] {
+            let srp = SrpSha256::new(group);
+            let record = srp.register_with_rng(&mut rng, b"alice", &password(b"hunter2"))?;
+            assert_eq!(record.verifier().len(), group.size());
+
+            let (client, client_share) =
+                srp.start_client_with_rng(&mut rng, b"alice", &password(b"hunter2"))?;
+            let (server, server_share) = srp.start_server_with_rng(&mut rng, b"alice", &record)?;
+            assert_eq!(client_share.len(), group.size());
+            assert_eq!(server_share.len(), group.size());
+
+            let confirmation = client.finish(record.salt(), &server_share)?;
+            let (server_proof, server_key) = server.finish(&client_share, confirmation.proof())?;
+            let client_key = confirmation.verify(&server_proof)?;
+            assert_eq!(client_key.expose_raw(), server_key.expose_raw());
+            assert_eq!(client_key.expose_raw().len(), 32);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrong_password() -> Result<(), Error> {
+        let mut rng = SeededRng(22);
+        let srp = SrpSha512::new(SrpGroup::G2048);
+        let record = srp.register_with_rng(&mut rng, b"alice", &password(b"hunter2"))?;
+
+        let (client, client_share) =
+            srp.start_client_with_rng(&mut rng, b"alice", &password(b"hunter3"))?;
+        let (server, server_share) = srp.start_server_with_rng(&mut rng, b"alice", &record)?;
+        let confirmation = client.finish(record.salt(), &server_share)?;
+        assert!(matches!(
+            server.finish(&client_share, confirmation.proof()),
+            Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+        ));
+
+        // A server without the verifier cannot produce a proof the client accepts.
+        // 没有验证值的服务器无法产生客户端接受的证明。
+        let (client, _) = srp.start_client_with_rng(&mut rng, b"alice", &password(b"hunter2"))?;
+        let (_, server_share) = srp.start_server_with_rng(&mut rng, b"alice", &record)?;
+        let confirmation = client.finish(record.salt(), &server_share)?;
+        let forged = confirmation.proof().to_vec();
+        assert!(matches!(
+            confirmation.verify(&forged),
+            Err(Error::KeyAgreement(KeyAgreementError::ConfirmationFailed))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_shares() -> Result<(), Error> {
+        let mut rng = SeededRng(23);
+        let group = SrpGroup::G2048;
+        let srp = SrpSha256::new(group);
+        let record = srp.register_with_rng(&mut rng, b"alice", &password(b"hunter2"))?;
+        let mut multiple = group.modulus().to_vec();
+        multiple.insert(0, 0);
+        let invalid_shares = [
+            vec![0u8; group.size()],
+            group.modulus().to_vec(),
+            multiple,
+            vec![1u8; group.size() + 1],
+        ];
+        for share in &invalid_shares {
+            let (client, _) =
+                srp.start_client_with_rng(&mut rng, b"alice", &password(b"hunter2"))?;
+            assert!(matches!(
+                client.finish(record.salt(), share),
+                Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+            ));
+            let (server, _) = srp.start_server_with_rng(&mut rng, b"alice", &record)?;
+            assert!(matches!(
+                server.finish(share, &[0u8; 32]),
+                Err(Error::KeyAgreement(KeyAgreementError::InvalidPeerPublicKey))
+            ));
+        }
+
+        let broken = SrpRecord::new(record.salt(), &[0u8; 4]);
+        assert!(
+            srp.start_server_with_rng(&mut rng, b"alice", &broken)
+                .is_err()
+        );
+        Ok(())
+    }
+}
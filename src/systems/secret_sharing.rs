@@ -0,0 +1,29 @@
+//! Secret sharing implementations.
+//!
+//! This module provides threshold secret sharing, which splits a secret into shares so that
+//! only a quorum of share holders can recover it.
+//!
+//! # Available Implementations
+//! - **Shamir**: Shamir secret sharing over GF(256) with SLIP-39 compatible byte shares
+//! - **Feldman VSS**: Verifiable secret sharing of P-256 and secp256k1 scalars
+//!
+//! 秘密共享实现。
+//!
+//! 此模块提供门限秘密共享，它将秘密拆分为多个份额，使得只有达到法定人数的份额持有者才能
+//! 恢复它。
+//!
+//! # 可用实现
+//! - **Shamir**: 基于 GF(256) 的 Shamir 秘密共享，份额与 SLIP-39 字节份额兼容
+//! - **Feldman VSS**: P-256 和 secp256k1 标量的可验证秘密共享
+
+/// Feldman verifiable secret sharing implementation.
+///
+/// Feldman 可验证秘密共享实现。
+#[cfg(all(feature = "secret-sharing-default", feature = "ecc-default"))]
+pub mod feldman;
+
+/// Shamir secret sharing implementation.
+///
+/// Shamir 秘密共享实现。
+#[cfg(feature = "secret-sharing-default")]
+pub mod shamir;
@@ -0,0 +1,525 @@
+//! Provides Feldman verifiable secret sharing over the NIST P-256 and secp256k1 curves.
+//!
+//! Like Shamir secret sharing, the dealer splits a secret scalar `s` into shares `f(i)` of a
+//! random polynomial `f(x) = s + a_1 x + ... + a_{t-1} x^{t-1}` over the curve's scalar field,
+//! so that any `t` shares recover `s`. In addition the dealer publishes the commitments
+//! `C_j = a_j * G` to every coefficient, and each receiver checks its share against them:
+//! `f(i) * G = C_0 + i C_1 + ... + i^{t-1} C_{t-1}`. A dealer who hands out shares that do not
+//! lie on one polynomial of degree `t - 1` is therefore caught before the secret is needed.
+//!
+//! The first commitment `C_0 = s * G` is the public key of the secret, so an EC private key,
+//! such as one exported with `EccPrivateKey::to_raw_bytes`, can be shared and everyone can
+//! check that the shares belong to the expected public key.
+//!
+//! # Security Considerations
+//! - The commitments must reach every receiver unchanged, e.g. over a broadcast channel or
+//!   signed by the dealer; otherwise a dealer can show different commitments to each receiver.
+//! - The commitments reveal `s * G`. Feldman VSS hides the secret only as well as the discrete
+//!   logarithm problem does, and is not suited to low-entropy secrets.
+//! - Coefficients and intermediate scalars are zeroized on drop.
+//!
+//! 提供了基于 NIST P-256 和 secp256k1 曲线的 Feldman 可验证秘密共享。
+//!
+//! 与 Shamir 秘密共享一样，分发者将秘密标量 `s` 拆分为曲线标量域上随机多项式
+//! `f(x) = s + a_1 x + ... + a_{t-1} x^{t-1}` 的份额 `f(i)`，使得任意 `t` 个份额可以恢复 `s`。
+//! 此外，分发者公布对每个系数的承诺 `C_j = a_j * G`，每个接收者根据这些承诺检查其份额：
+//! `f(i) * G = C_0 + i C_1 + ... + i^{t-1} C_{t-1}`。因此，分发不位于同一个 `t - 1` 次多项式上
+//! 的份额的分发者会在需要秘密之前被发现。
+//!
+//! 第一个承诺 `C_0 = s * G` 是秘密的公钥，因此可以共享 EC 私钥（例如使用
+//! `EccPrivateKey::to_raw_bytes` 导出的私钥），并且每个人都可以检查份额是否属于预期的公钥。
+//!
+//! # 安全考虑
+//! - 承诺必须原样到达每个接收者，例如通过广播信道或由分发者签名；否则分发者可以向每个
+//!   接收者展示不同的承诺。
+//! - 承诺会泄露 `s * G`。Feldman VSS 对秘密的隐藏程度仅与离散对数问题的难度相当，不适用于
+//!   低熵秘密。
+//! - 系数和中间标量在丢弃时会被清零。
+
+use alloc::{vec, vec::Vec};
+use crate::ct::ConstantTimeEq;
+use crate::{errors::Error, prelude::*};
+use elliptic_curve::ff::{Field, PrimeField};
+use elliptic_curve::group::{Curve as _, Group as _};
+use elliptic_curve::sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint};
+use elliptic_curve::{AffinePoint, CurveArithmetic, FieldBytes, FieldBytesSize, ProjectivePoint};
+use elliptic_curve::{PublicKey, Scalar};
+use k256::Secp256k1;
+use p256::NistP256;
+use crate::traits::asymmetric::CryptoRngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use core::marker::PhantomData;
+use zeroize::Zeroizing;
+
+/// One share of a secret scalar, for the receiver with index `index`.
+///
+/// 秘密标量的一个份额，属于索引为 `index` 的接收者。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VssShare {
+    index: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl VssShare {
+    /// Wraps a share received from the dealer.
+    ///
+    /// Fails with `KeyError::InvalidShare` if `index` is zero, which would be the secret itself.
+    ///
+    /// 包装从分发者接收的份额。
+    ///
+    /// 如果 `index` 为零（即秘密本身），则以 `KeyError::InvalidShare` 失败。
+    pub fn new(index: u8, value: &[u8]) -> Result<Self, Error> {
+        if index == 0 {
+            return Err(Error::Key(KeyError::InvalidShare));
+        }
+        Ok(Self {
+            index,
+            value: Zeroizing::new(value.to_vec()),
+        })
+    }
+
+    /// Returns the receiver's index, starting from 1.
+    ///
+    /// 返回接收者的索引，从 1 开始。
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the share's big-endian scalar.
+    ///
+    /// 返回份额的大端序标量。
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl core::fmt::Debug for VssShare {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VssShare")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The dealer's public commitments to the polynomial coefficients, as concatenated
+/// compressed SEC1 points.
+///
+/// 分发者对多项式系数的公开承诺，为串联的压缩 SEC1 点。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VssCommitments(
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))] Vec<u8>,
+);
+
+impl VssCommitments {
+    /// Wraps commitments received from the dealer. They are validated when a share is
+    /// verified against them.
+    ///
+    /// 包装从分发者接收的承诺。它们会在根据其验证份额时被校验。
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// Returns the concatenated compressed points.
+    ///
+    /// 返回串联的压缩点。
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for VssCommitments {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Feldman verifiable secret sharing over the curve `C`.
+///
+/// 基于曲线 `C` 的 Feldman 可验证秘密共享。
+#[derive(Clone, Debug, Default)]
+pub struct FeldmanVss<C> {
+    _curve: PhantomData<C>,
+}
+
+impl<C> FeldmanVss<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Splits the big-endian scalar `secret` into `shares` shares, any `threshold` of which
+    /// recover it, drawing the coefficients from the crate's system RNG.
+    ///
+    /// 将大端序标量 `secret` 拆分为 `shares` 个份额，其中任意 `threshold` 个份额都可以恢复它，
+    /// 系数从 crate 的系统 RNG 获取。
+    pub fn split(
+        secret: &[u8],
+        threshold: u8,
+        shares: u8,
+    ) -> Result<(Vec<VssShare>, VssCommitments), Error> {
+        Self::split_with(secret, threshold, shares, |bytes| {
+            crate::rng::fill(bytes).map_err(|_| Error::Key(KeyError::GenerationFailed))
+        })
+    }
+
+    /// Splits the big-endian scalar `secret` into `shares` shares, any `threshold` of which
+    /// recover it, and returns them with the commitments to hand to every receiver.
+    ///
+    /// Fails with `KeyError::InvalidThreshold` unless `1 <= threshold <= shares`, or with
+    /// `KeyError::InvalidEncoding` if `secret` is not a non-zero scalar of the curve.
+    ///
+    /// 将大端序标量 `secret` 拆分为 `shares` 个份额，其中任意 `threshold` 个份额都可以恢复它，
+    /// 并将它们与需要交给每个接收者的承诺一起返回。
+    ///
+    /// 除非 `1 <= threshold <= shares`，否则以 `KeyError::InvalidThreshold` 失败；如果 `secret`
+    /// 不是曲线的非零标量，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn split_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        secret: &[u8],
+        threshold: u8,
+        shares: u8,
+    ) -> Result<(Vec<VssShare>, VssCommitments), Error> {
+        Self::split_with(secret, threshold, shares, |bytes| {
+            rng.fill_bytes(bytes);
+            Ok(())
+        })
+    }
+
+    fn split_with(
+        secret: &[u8],
+        threshold: u8,
+        shares: u8,
+        mut fill: impl FnMut(&mut [u8]) -> Result<(), Error>,
+    ) -> Result<(Vec<VssShare>, VssCommitments), Error> {
+        if threshold == 0 || threshold > shares {
+            return Err(Error::Key(KeyError::InvalidThreshold));
+        }
+        let secret = Zeroizing::new(parse_scalar::<C>(secret)?);
+        if bool::from(secret.is_zero()) {
+            return Err(Error::Key(KeyError::InvalidEncoding));
+        }
+
+        let mut coefficients = Zeroizing::new(vec![*secret]);
+        while coefficients.len() < usize::from(threshold) {
+            coefficients.push(random_scalar::<C>(&mut fill)?);
+        }
+
+        let mut commitments = Vec::with_capacity(usize::from(threshold) * point_size::<C>());
+        for coefficient in coefficients.iter() {
+            commitments.extend_from_slice(&compress::<C>(
+                ProjectivePoint::<C>::generator() * coefficient,
+            )?);
+        }
+
+        let shares = (1..=shares)
+            .map(|index| {
+                let mut value = Zeroizing::new(Scalar::<C>::ZERO);
+                for coefficient in coefficients.iter().rev() {
+                    *value = *value * Scalar::<C>::from(u64::from(index)) + coefficient;
+                }
+                VssShare {
+                    index,
+                    value: Zeroizing::new(value.to_repr().to_vec()),
+                }
+            })
+            .collect();
+        Ok((shares, VssCommitments(commitments)))
+    }
+
+    /// Checks a share against the dealer's commitments without reconstructing the secret.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the commitments or the share are malformed,
+    /// and with `KeyError::ShareCommitmentMismatch` if the share is not consistent with the
+    /// commitments, i.e. the dealer or the channel is dishonest.
+    ///
+    /// 在不重建秘密的情况下，根据分发者的承诺检查份额。
+    ///
+    /// 如果承诺或份额格式错误，则以 `KeyError::InvalidEncoding` 失败；如果份额与承诺不一致，
+    /// 即分发者或信道不诚实，则以 `KeyError::ShareCommitmentMismatch` 失败。
+    pub fn verify_share(share: &VssShare, commitments: &VssCommitments) -> Result<(), Error> {
+        let points = Self::commitment_points(commitments)?;
+        let value = Zeroizing::new(parse_scalar::<C>(&share.value)?);
+        let x = Scalar::<C>::from(u64::from(share.index));
+
+        let mut expected = ProjectivePoint::<C>::identity();
+        for point in points.iter().rev() {
+            expected = expected * x + point;
+        }
+        let actual = ProjectivePoint::<C>::generator() * *value;
+        if bool::from(actual.ct_eq(&expected)) {
+            Ok(())
+        } else {
+            Err(Error::Key(KeyError::ShareCommitmentMismatch))
+        }
+    }
+
+    /// Returns the compressed SEC1 public key `s * G` of the shared secret, the first
+    /// commitment.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the commitments are malformed.
+    ///
+    /// 返回共享秘密的压缩 SEC1 公钥 `s * G`，即第一个承诺。
+    ///
+    /// 如果承诺格式错误，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn public_key(commitments: &VssCommitments) -> Result<Vec<u8>, Error> {
+        Self::commitment_points(commitments)?;
+        Ok(commitments.0[..point_size::<C>()].to_vec())
+    }
+
+    /// Returns the number of shares needed to recover the secret.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the commitments are malformed.
+    ///
+    /// 返回恢复秘密所需的份额数。
+    ///
+    /// 如果承诺格式错误，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn threshold(commitments: &VssCommitments) -> Result<u8, Error> {
+        u8::try_from(Self::commitment_points(commitments)?.len())
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+    }
+
+    /// Recovers the big-endian secret scalar from shares.
+    ///
+    /// Verify every share with [`Self::verify_share`] first: this function cannot tell whether
+    /// enough shares were given, and interpolates whatever polynomial they describe.
+    ///
+    /// Fails with `KeyError::InsufficientShares` if no shares are given, and with
+    /// `KeyError::InvalidShare` if an index repeats or a value is not a scalar of the curve.
+    ///
+    /// 从份额中恢复大端序秘密标量。
+    ///
+    /// 请先使用 [`Self::verify_share`] 验证每个份额：此函数无法判断给定的份额是否足够，
+    /// 并且会对它们所描述的任意多项式进行插值。
+    ///
+    /// 如果未给定任何份额，则以 `KeyError::InsufficientShares` 失败；如果索引重复或某个值不是
+    /// 曲线的标量，则以 `KeyError::InvalidShare` 失败。
+    pub fn combine(shares: &[VssShare]) -> Result<Zeroizing<Vec<u8>>, Error> {
+        if shares.is_empty() {
+            return Err(Error::Key(KeyError::InsufficientShares));
+        }
+        let mut secret = Zeroizing::new(Scalar::<C>::ZERO);
+        for (i, share) in shares.iter().enumerate() {
+            if shares[..i].iter().any(|other| other.index == share.index) {
+                return Err(Error::Key(KeyError::InvalidShare));
+            }
+            let xi = Scalar::<C>::from(u64::from(share.index));
+            let (mut numerator, mut denominator) = (Scalar::<C>::ONE, Scalar::<C>::ONE);
+            for other in shares.iter().filter(|other| other.index != share.index) {
+                let xj = Scalar::<C>::from(u64::from(other.index));
+                numerator *= xj;
+                denominator *= xj - xi;
+            }
+            let basis = numerator
+                * Option::<Scalar<C>>::from(denominator.invert())
+                    .ok_or(Error::Key(KeyError::InvalidShare))?;
+            let value = Zeroizing::new(
+                parse_scalar::<C>(&share.value).map_err(|_| Error::Key(KeyError::InvalidShare))?,
+            );
+            *secret += *value * basis;
+        }
+        Ok(Zeroizing::new(secret.to_repr().to_vec()))
+    }
+
+    fn commitment_points(commitments: &VssCommitments) -> Result<Vec<ProjectivePoint<C>>, Error> {
+        let bytes = commitments.as_bytes();
+        if bytes.is_empty() || !bytes.len().is_multiple_of(point_size::<C>()) {
+            return Err(Error::Key(KeyError::InvalidEncoding));
+        }
+        bytes
+            .chunks_exact(point_size::<C>())
+            .map(|point| {
+                PublicKey::<C>::from_sec1_bytes(point)
+                    .map(|point| point.to_projective())
+                    .map_err(|_| Error::Key(KeyError::InvalidEncoding))
+            })
+            .collect()
+    }
+}
+
+/// The size of a compressed SEC1 point: a tag byte and the x-coordinate.
+fn point_size<C: CurveArithmetic>() -> usize {
+    FieldBytes::<C>::default().len() + 1
+}
+
+fn parse_scalar<C: CurveArithmetic>(bytes: &[u8]) -> Result<Scalar<C>, Error> {
+    if bytes.len() != FieldBytes::<C>::default().len() {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    Option::from(Scalar::<C>::from_repr(FieldBytes::<C>::clone_from_slice(
+        bytes,
+    )))
+    .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+/// Draws a uniformly random non-zero scalar by rejection sampling.
+fn random_scalar<C: CurveArithmetic>(
+    fill: &mut impl FnMut(&mut [u8]) -> Result<(), Error>,
+) -> Result<Scalar<C>, Error> {
+    let mut bytes = Zeroizing::new(FieldBytes::<C>::default());
+    loop {
+        fill(&mut bytes)?;
+        if let Some(scalar) = Option::<Scalar<C>>::from(Scalar::<C>::from_repr((*bytes).clone()))
+            && !bool::from(scalar.is_zero())
+        {
+            return Ok(scalar);
+        }
+    }
+}
+
+fn compress<C>(point: ProjectivePoint<C>) -> Result<Vec<u8>, Error>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    PublicKey::<C>::from_affine(point.to_affine())
+        .map(|point| point.to_encoded_point(true).as_bytes().to_vec())
+        .map_err(|_| Error::Key(KeyError::GenerationFailed))
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for Feldman VSS over NIST P-256.
+///
+/// 基于 NIST P-256 的 Feldman VSS 的类型别名。
+pub type FeldmanVssP256 = FeldmanVss<NistP256>;
+
+/// A type alias for Feldman VSS over secp256k1.
+///
+/// 基于 secp256k1 的 Feldman VSS 的类型别名。
+pub type FeldmanVssSecp256k1 = FeldmanVss<Secp256k1>;
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecc::{
+        EccPrivateKey, EccPublicKey, EcdsaP256, EcdsaP256Params,
+    };
+    use crate::systems::test_rng::SeededRng;
+
+    fn run_vss_tests<C>() -> Result<(), Error>
+    where
+        C: CurveArithmetic,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldBytesSize<C>: ModulusSize,
+    {
+        let secret = [0x11u8; 32];
+        let (shares, commitments) =
+            FeldmanVss::<C>::split_with_rng(&mut SeededRng(0), &secret, 3, 5)?;
+        assert_eq!(shares.len(), 5);
+        assert_eq!(commitments.as_bytes().len(), 3 * 33);
+        assert_eq!(FeldmanVss::<C>::threshold(&commitments)?, 3);
+        for share in &shares {
+            FeldmanVss::<C>::verify_share(share, &commitments)?;
+        }
+
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let subset = [shares[c].clone(), shares[a].clone(), shares[b].clone()];
+                    assert_eq!(FeldmanVss::<C>::combine(&subset)?.as_slice(), secret);
+                }
+                let pair = [shares[a].clone(), shares[b].clone()];
+                assert_ne!(FeldmanVss::<C>::combine(&pair)?.as_slice(), secret);
+            }
+        }
+        assert_eq!(FeldmanVss::<C>::combine(&shares)?.as_slice(), secret);
+
+        // A share that is not on the committed polynomial is rejected.
+        // 不在已承诺多项式上的份额会被拒绝。
+        let mut value = shares[0].value().to_vec();
+        value[31] ^= 1;
+        let forged = VssShare::new(1, &value)?;
+        assert!(matches!(
+            FeldmanVss::<C>::verify_share(&forged, &commitments),
+            Err(Error::Key(KeyError::ShareCommitmentMismatch))
+        ));
+        let moved = VssShare::new(2, shares[0].value())?;
+        assert!(matches!(
+            FeldmanVss::<C>::verify_share(&moved, &commitments),
+            Err(Error::Key(KeyError::ShareCommitmentMismatch))
+        ));
+
+        // Commitments of another dealing do not vouch for these shares.
+        // 另一次分发的承诺不能为这些份额作证。
+        let (_, other) = FeldmanVss::<C>::split_with_rng(&mut SeededRng(1), &secret, 3, 5)?;
+        assert_eq!(
+            FeldmanVss::<C>::public_key(&other)?,
+            FeldmanVss::<C>::public_key(&commitments)?
+        );
+        assert!(matches!(
+            FeldmanVss::<C>::verify_share(&shares[0], &other),
+            Err(Error::Key(KeyError::ShareCommitmentMismatch))
+        ));
+
+        let truncated = VssCommitments::from_bytes(&commitments.as_bytes()[1..]);
+        assert!(matches!(
+            FeldmanVss::<C>::verify_share(&shares[0], &truncated),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_feldman_p256() -> Result<(), Error> {
+        run_vss_tests::<NistP256>()
+    }
+
+    #[test]
+    fn test_feldman_secp256k1() -> Result<(), Error> {
+        run_vss_tests::<Secp256k1>()
+    }
+
+    #[test]
+    fn test_share_ecc_private_key() -> Result<(), Error> {
+        let private_key = EccPrivateKey::<EcdsaP256Params>::from_raw_bytes(&[0x42; 32])?;
+        let secret = private_key.to_raw_bytes()?;
+        let (shares, commitments) =
+            FeldmanVssP256::split_with_rng(&mut SeededRng(9), &secret, 2, 3)?;
+
+        // The first commitment is the public key of the shared private key.
+        // 第一个承诺是被共享私钥的公钥。
+        let expected = EcdsaP256::derive_public_key(&private_key)?;
+        let public_key = EccPublicKey::<EcdsaP256Params>::from_sec1_bytes(
+            &FeldmanVssP256::public_key(&commitments)?,
+        )?;
+        assert_eq!(public_key, expected);
+
+        let recovered = FeldmanVssP256::combine(&shares[1..])?;
+        let recovered = EccPrivateKey::<EcdsaP256Params>::from_raw_bytes(&recovered)?;
+        assert_eq!(recovered.to_raw_bytes()?, secret);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_bad_parameters() {
+        let mut rng = SeededRng(0);
+        for (threshold, count) in [(0, 3), (4, 3)] {
+            let result = FeldmanVssP256::split_with_rng(&mut rng, &[1; 32], threshold, count);
+            assert!(matches!(
+                result,
+                Err(Error::Key(KeyError::InvalidThreshold))
+            ));
+        }
+        for secret in [&[0u8; 32][..], &[0xff; 32], &[1; 31]] {
+            let result = FeldmanVssP256::split_with_rng(&mut rng, secret, 2, 3);
+            assert!(matches!(result, Err(Error::Key(KeyError::InvalidEncoding))));
+        }
+        assert!(matches!(
+            VssShare::new(0, &[1; 32]),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+        assert!(matches!(
+            FeldmanVssP256::combine(&[]),
+            Err(Error::Key(KeyError::InsufficientShares))
+        ));
+    }
+}
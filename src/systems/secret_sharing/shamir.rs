@@ -0,0 +1,532 @@
+//! Provides Shamir secret sharing over GF(256), compatible with the byte shares of SLIP-39.
+//!
+//! A secret is split into up to 16 shares so that any `threshold` of them recover it, while
+//! fewer reveal nothing about it. Each byte of the secret is the value at `x = 255` of a random
+//! polynomial of degree `threshold - 1` over GF(256) with the Rijndael reduction polynomial
+//! `x^8 + x^4 + x^3 + x + 1`, and share `i` holds the values at `x = i`.
+//!
+//! Shares follow the SLIP-39 layout: the value at `x = 254` is a 4-byte HMAC-SHA256 digest of
+//! the secret followed by random padding, which lets [`combine`] detect corrupted shares or
+//! shares of different secrets. A 1-of-n split copies the secret into every share, as in
+//! SLIP-39. The group and mnemonic layers of SLIP-39 are not implemented; shares produced here
+//! can be encoded as SLIP-39 member shares and vice versa.
+//!
+//! # Security Considerations
+//! - Field arithmetic uses no secret-dependent table lookups or branches.
+//! - Random coefficients, the digest share and interpolation buffers are zeroized on drop.
+//! - Threshold secret sharing offers no protection against a dealer or share holder who lies
+//!   about their share beyond the digest check; see verifiable secret sharing for that.
+//!
+//! 提供了基于 GF(256) 的 Shamir 秘密共享，与 SLIP-39 的字节份额兼容。
+//!
+//! 一个秘密被拆分为最多 16 个份额，其中任意 `threshold` 个份额都可以恢复它，而更少的份额
+//! 不会泄露关于它的任何信息。秘密的每个字节是 GF(256)（使用 Rijndael 约化多项式
+//! `x^8 + x^4 + x^3 + x + 1`）上一个 `threshold - 1` 次随机多项式在 `x = 255` 处的值，
+//! 份额 `i` 保存 `x = i` 处的值。
+//!
+//! 份额遵循 SLIP-39 布局：`x = 254` 处的值是秘密的 4 字节 HMAC-SHA256 摘要后跟随机填充，
+//! 这使 [`combine`] 能够检测损坏的份额或属于不同秘密的份额。与 SLIP-39 一样，1-of-n 拆分会将
+//! 秘密复制到每个份额中。未实现 SLIP-39 的分组层和助记词层；此处生成的份额可以编码为
+//! SLIP-39 成员份额，反之亦然。
+//!
+//! # 安全考虑
+//! - 有限域运算不使用依赖秘密的查表或分支。
+//! - 随机系数、摘要份额和插值缓冲区在丢弃时会被清零。
+//! - 除摘要检查外，门限秘密共享无法防范对其份额撒谎的分发者或份额持有者；如有需要，
+//!   请参阅可验证秘密共享。
+
+use alloc::{vec, vec::Vec};
+use crate::ct::ConstantTimeEq;
+use crate::{errors::Error, prelude::*};
+use crate::traits::asymmetric::CryptoRngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+/// The maximum number of shares a secret can be split into.
+///
+/// 一个秘密最多可以拆分成的份额数。
+pub const MAX_SHARES: u8 = 16;
+
+/// The minimum length of a secret in bytes.
+///
+/// 秘密的最小长度（以字节为单位）。
+pub const MIN_SECRET_SIZE: usize = 16;
+
+const DIGEST_SIZE: usize = 4;
+const DIGEST_INDEX: u8 = 254;
+const SECRET_INDEX: u8 = 255;
+
+/// One share of a split secret.
+///
+/// 拆分后秘密的一个份额。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Share {
+    index: u8,
+    threshold: u8,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// Wraps a share received from another implementation, such as a decoded SLIP-39 member
+    /// share with its member index and member threshold.
+    ///
+    /// Fails with `KeyError::InvalidThreshold` if `threshold` is not between 1 and
+    /// [`MAX_SHARES`], or with `KeyError::InvalidShare` if `index` is not below [`MAX_SHARES`]
+    /// or `value` is shorter than [`MIN_SECRET_SIZE`] bytes or has an odd length.
+    ///
+    /// 包装从其他实现接收的份额，例如带有成员索引和成员门限的已解码 SLIP-39 成员份额。
+    ///
+    /// 如果 `threshold` 不在 1 到 [`MAX_SHARES`] 之间，则以 `KeyError::InvalidThreshold` 失败；
+    /// 如果 `index` 不小于 [`MAX_SHARES`]，或 `value` 短于 [`MIN_SECRET_SIZE`] 字节或长度为奇数，
+    /// 则以 `KeyError::InvalidShare` 失败。
+    pub fn new(index: u8, threshold: u8, value: &[u8]) -> Result<Self, Error> {
+        if threshold == 0 || threshold > MAX_SHARES {
+            return Err(Error::Key(KeyError::InvalidThreshold));
+        }
+        if index >= MAX_SHARES || !is_valid_size(value.len()) {
+            return Err(Error::Key(KeyError::InvalidShare));
+        }
+        Ok(Self {
+            index,
+            threshold,
+            value: Zeroizing::new(value.to_vec()),
+        })
+    }
+
+    /// Returns the share's index, between 0 and [`MAX_SHARES`] - 1.
+    ///
+    /// 返回份额的索引，介于 0 和 [`MAX_SHARES`] - 1 之间。
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the number of shares needed to recover the secret.
+    ///
+    /// 返回恢复秘密所需的份额数。
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns the share's value, as long as the secret.
+    ///
+    /// 返回份额的值，其长度与秘密相同。
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+}
+
+impl core::fmt::Debug for Share {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Share")
+            .field("index", &self.index)
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Splits `secret` into `shares` shares, any `threshold` of which recover it, drawing the
+/// random coefficients from the crate's system RNG.
+///
+/// 将 `secret` 拆分为 `shares` 个份额，其中任意 `threshold` 个份额都可以恢复它，随机系数从
+/// crate 的系统 RNG 获取。
+pub fn split(secret: &[u8], threshold: u8, shares: u8) -> Result<Vec<Share>, Error> {
+    check_split(secret, threshold, shares)?;
+    let mut random = Zeroizing::new(vec![0u8; random_size(secret, threshold)]);
+    crate::rng::fill(&mut random).map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+    split_with_random(secret, threshold, shares, &random)
+}
+
+/// Splits `secret` into `shares` shares, any `threshold` of which recover it, drawing the
+/// random coefficients from `rng`.
+///
+/// Fails with `KeyError::InvalidThreshold` unless `1 <= threshold <= shares <= 16`, or with
+/// `KeyError::InvalidLength` if the secret is shorter than [`MIN_SECRET_SIZE`] bytes or has
+/// an odd length.
+///
+/// 将 `secret` 拆分为 `shares` 个份额，其中任意 `threshold` 个份额都可以恢复它，随机系数从
+/// `rng` 获取。
+///
+/// 除非 `1 <= threshold <= shares <= 16`，否则以 `KeyError::InvalidThreshold` 失败；如果秘密
+/// 短于 [`MIN_SECRET_SIZE`] 字节或长度为奇数，则以 `KeyError::InvalidLength` 失败。
+pub fn split_with_rng<R: CryptoRngCore>(
+    rng: &mut R,
+    secret: &[u8],
+    threshold: u8,
+    shares: u8,
+) -> Result<Vec<Share>, Error> {
+    check_split(secret, threshold, shares)?;
+    let mut random = Zeroizing::new(vec![0u8; random_size(secret, threshold)]);
+    rng.fill_bytes(&mut random);
+    split_with_random(secret, threshold, shares, &random)
+}
+
+/// Recovers the secret from at least `threshold` shares of the same split.
+///
+/// All given shares are used, so a corrupted share is detected even when enough intact shares
+/// are present.
+///
+/// Fails with `KeyError::InsufficientShares` if fewer shares than their threshold are given,
+/// with `KeyError::InvalidShare` if the shares disagree on their threshold or length or repeat
+/// an index or have a value shorter than [`MIN_SECRET_SIZE`] bytes or of odd length, and with
+/// `KeyError::ShareDigestMismatch` if the recovered secret does not match
+/// its digest, i.e. a share is corrupted or belongs to another secret.
+///
+/// 从同一次拆分的至少 `threshold` 个份额中恢复秘密。
+///
+/// 所有给定的份额都会被使用，因此即使存在足够多的完好份额，也能检测到损坏的份额。
+///
+/// 如果给定的份额少于其门限，则以 `KeyError::InsufficientShares` 失败；如果份额的门限或长度
+/// 不一致、索引重复，或值短于 [`MIN_SECRET_SIZE`] 字节或长度为奇数，则以 `KeyError::InvalidShare` 失败；如果恢复出的秘密与其摘要不匹配，
+/// 即某个份额已损坏或属于另一个秘密，则以 `KeyError::ShareDigestMismatch` 失败。
+pub fn combine(shares: &[Share]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let first = shares
+        .first()
+        .ok_or(Error::Key(KeyError::InsufficientShares))?;
+    if !is_valid_size(first.value.len()) {
+        return Err(Error::Key(KeyError::InvalidShare));
+    }
+    for (i, share) in shares.iter().enumerate() {
+        if share.threshold != first.threshold
+            || share.value.len() != first.value.len()
+            || shares[..i].iter().any(|other| other.index == share.index)
+        {
+            return Err(Error::Key(KeyError::InvalidShare));
+        }
+    }
+    if shares.len() < usize::from(first.threshold) {
+        return Err(Error::Key(KeyError::InsufficientShares));
+    }
+
+    if first.threshold == 1 {
+        if shares
+            .iter()
+            .all(|share| bool::from(share.value.as_slice().ct_eq(first.value.as_slice())))
+        {
+            return Ok(first.value.clone());
+        }
+        return Err(Error::Key(KeyError::ShareDigestMismatch));
+    }
+
+    let points: Vec<(u8, &[u8])> = shares
+        .iter()
+        .map(|share| (share.index, share.value.as_slice()))
+        .collect();
+    let secret = interpolate(&points, SECRET_INDEX);
+    let digest_share = interpolate(&points, DIGEST_INDEX);
+    let (digest, random_part) = digest_share.split_at(DIGEST_SIZE);
+    let expected = Zeroizing::new(create_digest(random_part, &secret)?);
+    if bool::from(digest.ct_eq(expected.as_slice())) {
+        Ok(secret)
+    } else {
+        Err(Error::Key(KeyError::ShareDigestMismatch))
+    }
+}
+
+fn check_split(secret: &[u8], threshold: u8, shares: u8) -> Result<(), Error> {
+    if threshold == 0 || threshold > shares || shares > MAX_SHARES {
+        return Err(Error::Key(KeyError::InvalidThreshold));
+    }
+    if !is_valid_size(secret.len()) {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    Ok(())
+}
+
+/// Whether `len` is a valid length for a secret and its shares, as in SLIP-39.
+fn is_valid_size(len: usize) -> bool {
+    len >= MIN_SECRET_SIZE && len.is_multiple_of(2)
+}
+
+/// The number of random bytes a split consumes: `threshold - 2` random shares and the
+/// random part of the digest share.
+fn random_size(secret: &[u8], threshold: u8) -> usize {
+    match threshold {
+        1 => 0,
+        t => usize::from(t - 2) * secret.len() + secret.len() - DIGEST_SIZE,
+    }
+}
+
+/// Follows the SLIP-39 `split_secret` procedure with the given random bytes.
+fn split_with_random(
+    secret: &[u8],
+    threshold: u8,
+    shares: u8,
+    random: &[u8],
+) -> Result<Vec<Share>, Error> {
+    let share = |index, value: &[u8]| Share {
+        index,
+        threshold,
+        value: Zeroizing::new(value.to_vec()),
+    };
+    if threshold == 1 {
+        return Ok((0..shares).map(|index| share(index, secret)).collect());
+    }
+
+    let random_shares = threshold - 2;
+    let (coefficients, random_part) = random.split_at(usize::from(random_shares) * secret.len());
+    let mut digest_share = Zeroizing::new(create_digest(random_part, secret)?);
+    digest_share.extend_from_slice(random_part);
+
+    let mut base: Vec<(u8, &[u8])> = (0..random_shares)
+        .zip(coefficients.chunks_exact(secret.len()))
+        .collect();
+    base.push((DIGEST_INDEX, &digest_share));
+    base.push((SECRET_INDEX, secret));
+
+    let mut result: Vec<Share> = base[..usize::from(random_shares)]
+        .iter()
+        .map(|(index, value)| share(*index, value))
+        .collect();
+    for index in random_shares..shares {
+        result.push(Share {
+            index,
+            threshold,
+            value: interpolate(&base, index),
+        });
+    }
+    Ok(result)
+}
+
+/// The first four bytes of `HMAC-SHA256(random_part, secret)`.
+fn create_digest(random_part: &[u8], secret: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut digest = Sha256::hmac(random_part, secret)?;
+    digest.truncate(DIGEST_SIZE);
+    Ok(digest)
+}
+
+/// Evaluates at `x` the polynomial through `points`, which have distinct x-coordinates and
+/// values of equal length. Only the values are secret.
+fn interpolate(points: &[(u8, &[u8])], x: u8) -> Zeroizing<Vec<u8>> {
+    if let Some((_, value)) = points.iter().find(|(xi, _)| *xi == x) {
+        return Zeroizing::new(value.to_vec());
+    }
+    let len = points.first().map_or(0, |(_, value)| value.len());
+    let mut result = Zeroizing::new(vec![0u8; len]);
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let (mut numerator, mut denominator) = (1u8, 1u8);
+        for (j, (xj, _)) in points.iter().enumerate() {
+            if i != j {
+                numerator = gf_mul(numerator, x ^ xj);
+                denominator = gf_mul(denominator, xi ^ xj);
+            }
+        }
+        let basis = gf_mul(numerator, gf_inv(denominator));
+        for (out, y) in result.iter_mut().zip(yi.iter()) {
+            *out ^= gf_mul(basis, *y);
+        }
+    }
+    result
+}
+
+/// Multiplies in GF(256) modulo `x^8 + x^4 + x^3 + x + 1` without branches.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        a = (a << 1) ^ (0x1b & 0u8.wrapping_sub(a >> 7));
+        b >>= 1;
+    }
+    product
+}
+
+/// Inverts a non-zero element as `a^254`.
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut power = a;
+    for bit in 0..8 {
+        if (254u8 >> bit) & 1 == 1 {
+            result = gf_mul(result, power);
+        }
+        power = gf_mul(power, power);
+    }
+    result
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    fn secret() -> Vec<u8> {
+        (0u8..32).collect()
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        // {53} * {ca} = {01} and {57} * {83} = {c1}, from FIPS 197.
+        // 来自 FIPS 197 的 {53} * {ca} = {01} 和 {57} * {83} = {c1}。
+        assert_eq!(gf_mul(0x53, 0xca), 0x01);
+        assert_eq!(gf_mul(0x57, 0x83), 0xc1);
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+
+    #[test]
+    fn test_slip39_reference_shares() -> Result<(), Error> {
+        // A 3-of-5 split computed with the SLIP-39 reference implementation, with the random
+        // share a5 a5 .. a5 and the digest share's random part 40 41 .. 5b.
+        // 使用 SLIP-39 参考实现计算的 3-of-5 拆分，随机份额为 a5 a5 .. a5，摘要份额的随机部分
+        // 为 40 41 .. 5b。
+        let values = [
+            "42e99f56e1e1e1e1e9e9e9e9e1e1e1e1f9f9f9f9e1e1e1e1e9e9e9e9e1e1e1e1",
+            "8c920091aa7f1bceb56004d1588de93c8b5e3aef5580e4314a9ffb2ea77216c3",
+            "6bde3a62ee3b5f8af92c489d1cc9ad78d70266b311c4a07506d3b762e3365287",
+            "2a419dc491591ad2935b18d08b4300c8975f1cd4a56d2ee6a76f2ce4bf7734fc",
+        ];
+        let mut shares = vec![Share::new(0, 3, &[0xa5; 32])?];
+        for (index, value) in (1..).zip(values) {
+            let value = hex::decode(value).map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+            shares.push(Share::new(index, 3, &value)?);
+        }
+        assert_eq!(combine(&shares[1..4])?.as_slice(), secret());
+        assert_eq!(combine(&shares)?.as_slice(), secret());
+
+        let mut random = vec![0xa5; 32];
+        random.extend(0x40..0x5c);
+        let split = split_with_random(&secret(), 3, 5, &random)?;
+        assert_eq!(split.len(), shares.len());
+        for (share, expected) in split.iter().zip(&shares) {
+            assert_eq!(share.index(), expected.index());
+            assert_eq!(share.value(), expected.value());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_any_threshold_subset_recovers() -> Result<(), Error> {
+        let shares = split_with_rng(&mut SeededRng(7), &secret(), 3, 5)?;
+        assert_eq!(shares.len(), 5);
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let subset = [shares[c].clone(), shares[a].clone(), shares[b].clone()];
+                    assert_eq!(combine(&subset)?.as_slice(), secret());
+                }
+                let pair = [shares[a].clone(), shares[b].clone()];
+                assert!(matches!(
+                    combine(&pair),
+                    Err(Error::Key(KeyError::InsufficientShares))
+                ));
+            }
+        }
+
+        // 1-of-n and n-of-n splits.
+        // 1-of-n 和 n-of-n 拆分。
+        let copies = split_with_rng(&mut SeededRng(0), &secret(), 1, 3)?;
+        assert!(copies.iter().all(|share| share.value() == secret()));
+        assert_eq!(combine(&copies[2..])?.as_slice(), secret());
+        let all = split_with_rng(&mut SeededRng(0), &secret(), 16, 16)?;
+        assert_eq!(combine(&all)?.as_slice(), secret());
+        assert!(matches!(
+            combine(&all[1..]),
+            Err(Error::Key(KeyError::InsufficientShares))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_bad_shares() -> Result<(), Error> {
+        let shares = split_with_rng(&mut SeededRng(0), &secret(), 2, 3)?;
+
+        let mut corrupted = shares[1].clone();
+        corrupted.value[0] ^= 1;
+        let pair = [shares[0].clone(), corrupted.clone()];
+        assert!(matches!(
+            combine(&pair),
+            Err(Error::Key(KeyError::ShareDigestMismatch))
+        ));
+        // A corrupted extra share is detected as well.
+        // 多余的损坏份额也会被检测到。
+        let triple = [shares[0].clone(), shares[2].clone(), corrupted];
+        assert!(matches!(
+            combine(&triple),
+            Err(Error::Key(KeyError::ShareDigestMismatch))
+        ));
+
+        let other = split_with_rng(&mut SeededRng(1), &secret(), 2, 3)?;
+        let mixed = [shares[0].clone(), other[1].clone()];
+        assert!(matches!(
+            combine(&mixed),
+            Err(Error::Key(KeyError::ShareDigestMismatch))
+        ));
+        let repeated = [shares[0].clone(), shares[0].clone()];
+        assert!(matches!(
+            combine(&repeated),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+        let other_threshold = [shares[0].clone(), Share::new(1, 3, shares[1].value())?];
+        assert!(matches!(
+            combine(&other_threshold),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+        assert!(matches!(
+            combine(&[]),
+            Err(Error::Key(KeyError::InsufficientShares))
+        ));
+
+        // Short shares, e.g. decoded by another implementation, are rejected rather than
+        // reaching the digest split.
+        // 短份额（例如由其他实现解码的份额）会被拒绝，而不会到达摘要拆分。
+        let short = |index| Share {
+            index,
+            threshold: 2,
+            value: Zeroizing::new(vec![0xaa; 2]),
+        };
+        assert!(matches!(
+            combine(&[short(0), short(1)]),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+        assert!(matches!(
+            Share::new(0, 2, &[0xaa; 2]),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+        assert!(matches!(
+            Share::new(0, 2, &[0xaa; 17]),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_bad_parameters() -> Result<(), Error> {
+        let mut rng = SeededRng(0);
+        for (threshold, count) in [(0, 3), (4, 3), (2, 17)] {
+            let result = split_with_rng(&mut rng, &secret(), threshold, count);
+            assert!(matches!(
+                result,
+                Err(Error::Key(KeyError::InvalidThreshold))
+            ));
+        }
+        for len in [0, 14, 17] {
+            let result = split_with_rng(&mut rng, &vec![0u8; len], 2, 3);
+            assert!(matches!(result, Err(Error::Key(KeyError::InvalidLength))));
+        }
+        assert!(matches!(
+            Share::new(16, 2, &[0; 16]),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+        assert!(matches!(
+            Share::new(0, 0, &[0; 16]),
+            Err(Error::Key(KeyError::InvalidThreshold))
+        ));
+        assert!(!format!("{:?}", Share::new(0, 1, &[0xaa; 16])?).contains("170"));
+        Ok(())
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_split_with_system_rng() -> Result<(), Error> {
+        let key = [0x42u8; 32];
+        let shares = split(&key, 2, 3)?;
+        assert_ne!(shares[0].value(), shares[1].value());
+        assert_eq!(combine(&shares[1..])?.as_slice(), key);
+        Ok(())
+    }
+}
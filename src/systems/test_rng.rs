@@ -0,0 +1,35 @@
+//! A deterministic RNG for reproducible tests.
+//!
+//! 用于可复现测试的确定性 RNG。
+
+use rand_core_elliptic_curve::{CryptoRng, Error, RngCore, impls};
+
+/// A SplitMix64 generator whose output is fully determined by its seed.
+///
+/// 输出完全由种子决定的 SplitMix64 生成器。
+pub(crate) struct SeededRng(pub(crate) u64);
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for SeededRng {}
@@ -0,0 +1,24 @@
+//! Threshold signature implementations.
+//!
+//! This module provides threshold signatures, which let any quorum of the holders of key
+//! shares sign under one group public key without the group private key ever existing in one
+//! place.
+//!
+//! # Available Implementations
+//! - **FROST**: Two-round threshold Schnorr signatures (RFC 9591) over ristretto255 and
+//!   secp256k1, with distributed key generation
+//!
+//! 门限签名实现。
+//!
+//! 此模块提供门限签名，它让密钥份额持有者中的任意法定人数在一个群公钥下签名，而群私钥从不
+//! 出现在任何一处。
+//!
+//! # 可用实现
+//! - **FROST**: 基于 ristretto255 和 secp256k1 的两轮门限 Schnorr 签名 (RFC 9591)，带分布式
+//!   密钥生成
+
+/// FROST threshold Schnorr signature implementation.
+///
+/// FROST 门限 Schnorr 签名实现。
+#[cfg(feature = "threshold-default")]
+pub mod frost;
@@ -0,0 +1,1628 @@
+//! Provides FROST threshold Schnorr signatures (RFC 9591) over ristretto255 and secp256k1.
+//!
+//! FROST lets any `min_signers` of `max_signers` participants produce one Schnorr signature under
+//! a shared group public key, while no participant ever holds the group's private key. A
+//! signature produced by any quorum verifies with [`Frost::verify`] like a single-signer one.
+//!
+//! # Key Generation
+//! The participants run a three-part distributed key generation (Pedersen DKG with proofs of
+//! knowledge, as in the FROST paper), so no dealer ever sees the private key:
+//! 1. [`Frost::dkg_part1`] samples a secret polynomial and returns a [`Round1Package`] to
+//!    broadcast to every other participant.
+//! 2. [`Frost::dkg_part2`] checks the other participants' proofs of knowledge and returns one
+//!    [`Round2Package`] per participant, to send over a confidential, authenticated channel.
+//! 3. [`Frost::dkg_part3`] checks the received shares against their commitments and returns the
+//!    participant's [`KeyPackage`] and the [`PublicKeyPackage`] shared by everyone.
+//!
+//! # Signing
+//! Signing takes two rounds, coordinated by any party (which may be a participant):
+//! 1. Every chosen signer calls [`Frost::commit`], keeps the [`SigningNonces`] and sends the
+//!    [`SigningCommitments`] to the coordinator, who bundles them with the message into a
+//!    [`SigningPackage`].
+//! 2. Every signer calls [`Frost::sign`] on the package and returns its [`SignatureShare`]. The
+//!    coordinator combines the shares with [`Frost::aggregate`], which names the participant of
+//!    any invalid share.
+//!
+//! Signatures are `R || z`, the serialized group commitment followed by the response scalar:
+//! 64 bytes over ristretto255 and 65 bytes over secp256k1.
+//!
+//! # Security Considerations
+//! - [`SigningNonces`] must be used for one signature only; [`Frost::sign`] consumes them, and
+//!   they cannot be cloned or serialized.
+//! - Round 1 packages of the key generation must reach every participant unchanged, e.g. over a
+//!   broadcast channel; round 2 packages carry secret shares and must be encrypted.
+//! - The secp256k1 ciphersuite is the generic RFC 9591 one; its signatures are not BIP-340
+//!   (Taproot) signatures.
+//! - FROST is not a FIPS approved algorithm, so it is not available with the `fips` feature.
+//!
+//! 提供了基于 ristretto255 和 secp256k1 的 FROST 门限 Schnorr 签名 (RFC 9591)。
+//!
+//! FROST 允许 `max_signers` 个参与者中的任意 `min_signers` 个在共享的群公钥下生成一个 Schnorr
+//! 签名，而任何参与者都不会持有群的私钥。任意法定人数生成的签名都可以像单签名者签名一样使用
+//! [`Frost::verify`] 验证。
+//!
+//! # 密钥生成
+//! 参与者运行一个分三部分的分布式密钥生成（带知识证明的 Pedersen DKG，如 FROST 论文所述），
+//! 因此没有任何分发者会看到私钥：
+//! 1. [`Frost::dkg_part1`] 采样一个秘密多项式，并返回一个需要广播给所有其他参与者的
+//!    [`Round1Package`]。
+//! 2. [`Frost::dkg_part2`] 检查其他参与者的知识证明，并为每个参与者返回一个 [`Round2Package`]，
+//!    需要通过机密且经过认证的信道发送。
+//! 3. [`Frost::dkg_part3`] 根据承诺检查收到的份额，并返回参与者的 [`KeyPackage`] 以及所有人共享的
+//!    [`PublicKeyPackage`]。
+//!
+//! # 签名
+//! 签名需要两轮，由任意一方（可以是参与者）协调：
+//! 1. 每个被选中的签名者调用 [`Frost::commit`]，保留 [`SigningNonces`] 并将
+//!    [`SigningCommitments`] 发送给协调者，协调者将它们与消息一起打包为 [`SigningPackage`]。
+//! 2. 每个签名者对该包调用 [`Frost::sign`] 并返回其 [`SignatureShare`]。协调者使用
+//!    [`Frost::aggregate`] 合并份额，它会指出任何无效份额所属的参与者。
+//!
+//! 签名为 `R || z`，即序列化的群承诺后接响应标量：在 ristretto255 上为 64 字节，在 secp256k1 上
+//! 为 65 字节。
+//!
+//! # 安全考虑
+//! - [`SigningNonces`] 只能用于一个签名；[`Frost::sign`] 会消耗它们，并且它们无法被克隆或序列化。
+//! - 密钥生成的第一轮包必须原样到达每个参与者，例如通过广播信道；第二轮包携带秘密份额，必须被
+//!   加密。
+//! - secp256k1 密码套件是通用的 RFC 9591 套件；其签名不是 BIP-340 (Taproot) 签名。
+//! - FROST 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::vec::Vec;
+use crate::ct::ConstantTimeEq;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use curve25519_dalek::ristretto::RistrettoPoint;
+use curve25519_dalek::scalar::Scalar as RistrettoScalar;
+use elliptic_curve::ff::{Field, PrimeField};
+use elliptic_curve::group::{Group, GroupEncoding};
+use elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+use k256::Secp256k1;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use alloc::collections::BTreeMap;
+use core::marker::PhantomData;
+use zeroize::{Zeroize, Zeroizing};
+
+type Scalar<C> = <<C as FrostCiphersuite>::Group as Group>::Scalar;
+
+// ------------------- Ciphersuites -------------------
+// ------------------- 密码套件 -------------------
+
+/// A FROST ciphersuite: a prime-order group and the hash functions `H1` to `H5` of RFC 9591.
+///
+/// 一个 FROST 密码套件：一个素数阶群以及 RFC 9591 的哈希函数 `H1` 到 `H5`。
+pub trait FrostCiphersuite: Clone + core::fmt::Debug + Default + Send + Sync + 'static {
+    /// The prime-order group the signatures are computed in.
+    ///
+    /// 计算签名所在的素数阶群。
+    type Group: Group<Scalar: Zeroize> + GroupEncoding + ConstantTimeEq;
+
+    /// The ciphersuite's context string, which prefixes every hash input.
+    ///
+    /// 密码套件的上下文字符串，作为每个哈希输入的前缀。
+    const CONTEXT_STRING: &'static [u8];
+
+    /// Hashes the concatenated `parts` to a scalar, domain-separated by `tag` (`"rho"`,
+    /// `"chal"`, `"nonce"` or `"dkg"`).
+    ///
+    /// 将串联的 `parts` 哈希为标量，并以 `tag`（`"rho"`、`"chal"`、`"nonce"` 或 `"dkg"`）进行
+    /// 域分离。
+    fn hash_to_scalar(tag: &[u8], parts: &[&[u8]]) -> Result<Scalar<Self>, Error>;
+
+    /// Hashes the concatenated `parts` to bytes, domain-separated by `tag` (`"msg"` or `"com"`).
+    ///
+    /// 将串联的 `parts` 哈希为字节，并以 `tag`（`"msg"` 或 `"com"`）进行域分离。
+    fn hash(tag: &[u8], parts: &[&[u8]]) -> Vec<u8>;
+}
+
+/// The `FROST(ristretto255, SHA-512)` ciphersuite.
+///
+/// `FROST(ristretto255, SHA-512)` 密码套件。
+#[derive(Clone, Debug, Default)]
+pub struct Ristretto255Sha512;
+
+impl FrostCiphersuite for Ristretto255Sha512 {
+    type Group = RistrettoPoint;
+
+    const CONTEXT_STRING: &'static [u8] = b"FROST-RISTRETTO255-SHA512-v1";
+
+    fn hash_to_scalar(tag: &[u8], parts: &[&[u8]]) -> Result<RistrettoScalar, Error> {
+        let mut wide = [0u8; 64];
+        wide.copy_from_slice(&Self::hash(tag, parts));
+        Ok(RistrettoScalar::from_bytes_mod_order_wide(&wide))
+    }
+
+    fn hash(tag: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = Sha512::new();
+        hasher.update(Self::CONTEXT_STRING);
+        hasher.update(tag);
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize().to_vec()
+    }
+}
+
+/// The `FROST(secp256k1, SHA-256)` ciphersuite.
+///
+/// `FROST(secp256k1, SHA-256)` 密码套件。
+#[derive(Clone, Debug, Default)]
+pub struct Secp256k1Sha256;
+
+impl FrostCiphersuite for Secp256k1Sha256 {
+    type Group = k256::ProjectivePoint;
+
+    const CONTEXT_STRING: &'static [u8] = b"FROST-secp256k1-SHA256-v1";
+
+    fn hash_to_scalar(tag: &[u8], parts: &[&[u8]]) -> Result<k256::Scalar, Error> {
+        // hash_to_field of RFC 9380 with expand_message_xmd, keyed by `contextString || tag`.
+        // 使用 expand_message_xmd 的 RFC 9380 hash_to_field，以 `contextString || tag` 为域分离标签。
+        Secp256k1::hash_to_scalar::<ExpandMsgXmd<Sha256>>(parts, &[Self::CONTEXT_STRING, tag])
+            .map_err(|_| Error::Signature(SignatureError::Signing))
+    }
+
+    fn hash(tag: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(Self::CONTEXT_STRING);
+        hasher.update(tag);
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize().to_vec()
+    }
+}
+
+// ------------------- Key Packages -------------------
+// ------------------- 密钥包 -------------------
+
+/// A participant's long-lived signing key: its secret share of the group private key together
+/// with the public values needed to sign.
+///
+/// 参与者的长期签名密钥：它对群私钥的秘密份额，以及签名所需的公开值。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyPackage {
+    identifier: u16,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    signing_share: Zeroizing<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    verifying_share: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    group_public_key: Vec<u8>,
+    min_signers: u16,
+}
+
+impl KeyPackage {
+    /// Returns the participant's identifier, starting from 1.
+    ///
+    /// 返回参与者的标识符，从 1 开始。
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the serialized secret share of the group private key.
+    ///
+    /// 返回群私钥的序列化秘密份额。
+    pub fn signing_share(&self) -> &[u8] {
+        &self.signing_share
+    }
+
+    /// Returns the serialized public key of the participant's share.
+    ///
+    /// 返回参与者份额的序列化公钥。
+    pub fn verifying_share(&self) -> &[u8] {
+        &self.verifying_share
+    }
+
+    /// Returns the serialized group public key that signatures verify under.
+    ///
+    /// 返回用于验证签名的序列化群公钥。
+    pub fn group_public_key(&self) -> &[u8] {
+        &self.group_public_key
+    }
+
+    /// Returns the number of signers needed to produce a signature.
+    ///
+    /// 返回生成签名所需的签名者数量。
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+}
+
+impl core::fmt::Debug for KeyPackage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyPackage")
+            .field("identifier", &self.identifier)
+            .field("min_signers", &self.min_signers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The public result of a key generation, the same for every participant: the group public
+/// key and the public key of every participant's share.
+///
+/// 密钥生成的公开结果，对每个参与者都相同：群公钥以及每个参与者份额的公钥。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PublicKeyPackage {
+    verifying_shares: BTreeMap<u16, Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    group_public_key: Vec<u8>,
+}
+
+impl PublicKeyPackage {
+    /// Returns the serialized public key of the share of `identifier`, if it took part.
+    ///
+    /// 返回 `identifier` 份额的序列化公钥（如果它参与了密钥生成）。
+    pub fn verifying_share(&self, identifier: u16) -> Option<&[u8]> {
+        self.verifying_shares.get(&identifier).map(Vec::as_slice)
+    }
+
+    /// Returns the serialized group public key that signatures verify under.
+    ///
+    /// 返回用于验证签名的序列化群公钥。
+    pub fn group_public_key(&self) -> &[u8] {
+        &self.group_public_key
+    }
+}
+
+// ------------------- Key Generation Packages -------------------
+// ------------------- 密钥生成包 -------------------
+
+/// The state a participant keeps between parts 1 and 2 of the key generation.
+///
+/// 参与者在密钥生成第 1 部分和第 2 部分之间保留的状态。
+pub struct Round1SecretPackage {
+    identifier: u16,
+    coefficients: Zeroizing<Vec<u8>>,
+    commitment: Vec<u8>,
+    min_signers: u16,
+    max_signers: u16,
+}
+
+impl core::fmt::Debug for Round1SecretPackage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Round1SecretPackage")
+            .field("identifier", &self.identifier)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The package a participant broadcasts in part 1 of the key generation: the commitments to
+/// its polynomial and a proof of knowledge of its secret.
+///
+/// 参与者在密钥生成第 1 部分中广播的包：对其多项式的承诺以及对其秘密的知识证明。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Round1Package {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    commitment: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    proof_of_knowledge: Vec<u8>,
+}
+
+impl Round1Package {
+    /// Wraps a package received from another participant. It is validated in part 2.
+    ///
+    /// 包装从另一个参与者接收的包。它会在第 2 部分中被校验。
+    pub fn new(commitment: &[u8], proof_of_knowledge: &[u8]) -> Self {
+        Self {
+            commitment: commitment.to_vec(),
+            proof_of_knowledge: proof_of_knowledge.to_vec(),
+        }
+    }
+
+    /// Returns the concatenated serialized commitments to the polynomial coefficients.
+    ///
+    /// 返回对多项式系数的串联序列化承诺。
+    pub fn commitment(&self) -> &[u8] {
+        &self.commitment
+    }
+
+    /// Returns the proof of knowledge `R || mu` of the secret coefficient.
+    ///
+    /// 返回对秘密系数的知识证明 `R || mu`。
+    pub fn proof_of_knowledge(&self) -> &[u8] {
+        &self.proof_of_knowledge
+    }
+}
+
+/// The state a participant keeps between parts 2 and 3 of the key generation.
+///
+/// 参与者在密钥生成第 2 部分和第 3 部分之间保留的状态。
+pub struct Round2SecretPackage {
+    identifier: u16,
+    commitment: Vec<u8>,
+    own_share: Zeroizing<Vec<u8>>,
+    min_signers: u16,
+    max_signers: u16,
+}
+
+impl core::fmt::Debug for Round2SecretPackage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Round2SecretPackage")
+            .field("identifier", &self.identifier)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The secret share a participant sends to one other participant in part 2 of the key
+/// generation.
+///
+/// 参与者在密钥生成第 2 部分中发送给另一个参与者的秘密份额。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Round2Package {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    signing_share: Zeroizing<Vec<u8>>,
+}
+
+impl Round2Package {
+    /// Wraps a package received from another participant. It is validated in part 3.
+    ///
+    /// 包装从另一个参与者接收的包。它会在第 3 部分中被校验。
+    pub fn new(signing_share: &[u8]) -> Self {
+        Self {
+            signing_share: Zeroizing::new(signing_share.to_vec()),
+        }
+    }
+
+    /// Returns the serialized secret share.
+    ///
+    /// 返回序列化的秘密份额。
+    pub fn signing_share(&self) -> &[u8] {
+        &self.signing_share
+    }
+}
+
+impl core::fmt::Debug for Round2Package {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Round2Package").finish_non_exhaustive()
+    }
+}
+
+// ------------------- Signing Packages -------------------
+// ------------------- 签名包 -------------------
+
+/// A signer's secret nonces for one signature. They are zeroized on drop.
+///
+/// 签名者用于一个签名的秘密 nonce。它们在丢弃时会被清零。
+pub struct SigningNonces {
+    hiding: Zeroizing<Vec<u8>>,
+    binding: Zeroizing<Vec<u8>>,
+    commitments: SigningCommitments,
+}
+
+impl SigningNonces {
+    /// Returns the public commitments to the nonces.
+    ///
+    /// 返回对 nonce 的公开承诺。
+    pub fn commitments(&self) -> &SigningCommitments {
+        &self.commitments
+    }
+}
+
+impl core::fmt::Debug for SigningNonces {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningNonces")
+            .field("commitments", &self.commitments)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A signer's public commitments to its hiding and binding nonces.
+///
+/// 签名者对其隐藏 nonce 和绑定 nonce 的公开承诺。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SigningCommitments {
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    hiding: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    binding: Vec<u8>,
+}
+
+impl SigningCommitments {
+    /// Wraps commitments received from a signer. They are validated when signing.
+    ///
+    /// 包装从签名者接收的承诺。它们会在签名时被校验。
+    pub fn new(hiding: &[u8], binding: &[u8]) -> Self {
+        Self {
+            hiding: hiding.to_vec(),
+            binding: binding.to_vec(),
+        }
+    }
+
+    /// Returns the serialized commitment to the hiding nonce.
+    ///
+    /// 返回对隐藏 nonce 的序列化承诺。
+    pub fn hiding(&self) -> &[u8] {
+        &self.hiding
+    }
+
+    /// Returns the serialized commitment to the binding nonce.
+    ///
+    /// 返回对绑定 nonce 的序列化承诺。
+    pub fn binding(&self) -> &[u8] {
+        &self.binding
+    }
+}
+
+/// The message to sign and the commitments of the chosen signers, which the coordinator sends
+/// to every signer.
+///
+/// 待签名的消息以及被选中签名者的承诺，由协调者发送给每个签名者。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SigningPackage {
+    commitments: BTreeMap<u16, SigningCommitments>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    message: Vec<u8>,
+}
+
+impl SigningPackage {
+    /// Bundles the signers' commitments, keyed by identifier, with the message.
+    ///
+    /// 将按标识符索引的签名者承诺与消息打包在一起。
+    pub fn new(commitments: BTreeMap<u16, SigningCommitments>, message: &[u8]) -> Self {
+        Self {
+            commitments,
+            message: message.to_vec(),
+        }
+    }
+
+    /// Returns the signers' commitments, keyed by identifier.
+    ///
+    /// 返回按标识符索引的签名者承诺。
+    pub fn commitments(&self) -> &BTreeMap<u16, SigningCommitments> {
+        &self.commitments
+    }
+
+    /// Returns the message to sign.
+    ///
+    /// 返回待签名的消息。
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+}
+
+/// A signer's share of a signature, a serialized scalar.
+///
+/// 签名者的签名份额，为一个序列化标量。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SignatureShare(
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))] Vec<u8>,
+);
+
+impl SignatureShare {
+    /// Wraps a share received from a signer. It is validated when aggregating.
+    ///
+    /// 包装从签名者接收的份额。它会在聚合时被校验。
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// Returns the serialized scalar.
+    ///
+    /// 返回序列化的标量。
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for SignatureShare {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// ------------------- FROST -------------------
+// ------------------- FROST -------------------
+
+/// FROST threshold Schnorr signatures over the ciphersuite `C`.
+///
+/// 基于密码套件 `C` 的 FROST 门限 Schnorr 签名。
+#[derive(Clone, Debug, Default)]
+pub struct Frost<C> {
+    _ciphersuite: PhantomData<C>,
+}
+
+impl<C: FrostCiphersuite> Frost<C> {
+    /// Starts the key generation as participant `identifier`, drawing the secret polynomial
+    /// from the crate's system RNG.
+    ///
+    /// 以参与者 `identifier` 的身份开始密钥生成，秘密多项式从 crate 的系统 RNG 获取。
+    pub fn dkg_part1(
+        identifier: u16,
+        max_signers: u16,
+        min_signers: u16,
+    ) -> Result<(Round1SecretPackage, Round1Package), Error> {
+        Self::dkg_part1_with_rng(&mut SystemRng::new(), identifier, max_signers, min_signers)
+    }
+
+    /// Starts the key generation as participant `identifier` of `max_signers`, any
+    /// `min_signers` of whom will be able to sign.
+    ///
+    /// Returns the secret state to keep for part 2 and the package to broadcast to every other
+    /// participant. Fails with `KeyError::InvalidThreshold` unless
+    /// `2 <= min_signers <= max_signers`, or with `KeyError::InvalidShare` if `identifier` is
+    /// zero.
+    ///
+    /// 以 `max_signers` 个参与者中参与者 `identifier` 的身份开始密钥生成，其中任意
+    /// `min_signers` 个参与者将能够签名。
+    ///
+    /// 返回需要为第 2 部分保留的秘密状态，以及需要广播给所有其他参与者的包。除非
+    /// `2 <= min_signers <= max_signers`，否则以 `KeyError::InvalidThreshold` 失败；如果
+    /// `identifier` 为零，则以 `KeyError::InvalidShare` 失败。
+    pub fn dkg_part1_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        identifier: u16,
+        max_signers: u16,
+        min_signers: u16,
+    ) -> Result<(Round1SecretPackage, Round1Package), Error> {
+        if min_signers < 2 || min_signers > max_signers {
+            return Err(Error::Key(KeyError::InvalidThreshold));
+        }
+        if identifier == 0 {
+            return Err(Error::Key(KeyError::InvalidShare));
+        }
+
+        let coefficients: Zeroizing<Vec<Scalar<C>>> = Zeroizing::new(
+            (0..min_signers)
+                .map(|_| Scalar::<C>::random(&mut *rng))
+                .collect(),
+        );
+        let commitment: Vec<C::Group> = coefficients
+            .iter()
+            .map(|coefficient| C::Group::generator() * coefficient)
+            .collect();
+
+        // Schnorr proof of knowledge of the constant term, bound to the participant.
+        // 对常数项的 Schnorr 知识证明，与参与者绑定。
+        let k = Zeroizing::new(Scalar::<C>::random(&mut *rng));
+        let r = C::Group::generator() * *k;
+        let c = dkg_challenge::<C>(identifier, &commitment[0], &r)?;
+        let mu = *k + coefficients[0] * c;
+
+        let mut secret = Zeroizing::new(Vec::new());
+        for coefficient in coefficients.iter() {
+            secret.extend_from_slice(coefficient.to_repr().as_ref());
+        }
+        let commitment = encode_elements::<C>(&commitment)?;
+        let package = Round1Package {
+            commitment: commitment.clone(),
+            proof_of_knowledge: [element_to_bytes::<C>(&r)?, scalar_to_bytes::<C>(&mu)].concat(),
+        };
+        Ok((
+            Round1SecretPackage {
+                identifier,
+                coefficients: secret,
+                commitment,
+                min_signers,
+                max_signers,
+            },
+            package,
+        ))
+    }
+
+    /// Continues the key generation with the round 1 packages of every other participant,
+    /// keyed by identifier.
+    ///
+    /// Returns the secret state to keep for part 3 and one package per other participant, to
+    /// send to it confidentially. Fails with `KeyError::InsufficientShares` unless there is
+    /// exactly one package per other participant, with `KeyError::InvalidShare` if an
+    /// identifier is zero or the participant's own, with `KeyError::InvalidEncoding` if a
+    /// package is malformed, and with `KeyError::InvalidProofOfKnowledge` naming a participant
+    /// whose proof of knowledge is invalid.
+    ///
+    /// 使用所有其他参与者按标识符索引的第 1 轮包继续密钥生成。
+    ///
+    /// 返回需要为第 3 部分保留的秘密状态，以及为每个其他参与者准备的一个包，需要以机密方式发送
+    /// 给该参与者。除非每个其他参与者恰好有一个包，否则以 `KeyError::InsufficientShares` 失败；
+    /// 如果某个标识符为零或为参与者自身的标识符，则以 `KeyError::InvalidShare` 失败；如果某个包
+    /// 格式错误，则以 `KeyError::InvalidEncoding` 失败；如果某个参与者的知识证明无效，则以指出该
+    /// 参与者的 `KeyError::InvalidProofOfKnowledge` 失败。
+    pub fn dkg_part2(
+        secret: Round1SecretPackage,
+        round1_packages: &BTreeMap<u16, Round1Package>,
+    ) -> Result<(Round2SecretPackage, BTreeMap<u16, Round2Package>), Error> {
+        check_participants(
+            secret.identifier,
+            secret.max_signers,
+            round1_packages.keys(),
+        )?;
+        for (&identifier, package) in round1_packages {
+            let commitment = decode_commitment::<C>(&package.commitment, secret.min_signers)?;
+            let (r, mu) = decode_proof::<C>(&package.proof_of_knowledge)?;
+            let c = dkg_challenge::<C>(identifier, &commitment[0], &r)?;
+            let expected = C::Group::generator() * mu - commitment[0] * c;
+            if !bool::from(r.ct_eq(&expected)) {
+                return Err(Error::Key(KeyError::InvalidProofOfKnowledge(identifier)));
+            }
+        }
+
+        let coefficients = decode_scalars::<C>(&secret.coefficients)?;
+        let packages = round1_packages
+            .keys()
+            .map(|&identifier| {
+                let share = Zeroizing::new(evaluate::<C>(&coefficients, identifier));
+                (
+                    identifier,
+                    Round2Package {
+                        signing_share: Zeroizing::new(scalar_to_bytes::<C>(&share)),
+                    },
+                )
+            })
+            .collect();
+        let own_share = Zeroizing::new(evaluate::<C>(&coefficients, secret.identifier));
+        Ok((
+            Round2SecretPackage {
+                identifier: secret.identifier,
+                commitment: secret.commitment.clone(),
+                own_share: Zeroizing::new(scalar_to_bytes::<C>(&own_share)),
+                min_signers: secret.min_signers,
+                max_signers: secret.max_signers,
+            },
+            packages,
+        ))
+    }
+
+    /// Finishes the key generation with the round 1 packages of part 2 and the round 2
+    /// packages received from every other participant, both keyed by identifier.
+    ///
+    /// Fails with `KeyError::InsufficientShares` unless both maps hold exactly the other
+    /// participants, with `KeyError::InvalidEncoding` if a package is malformed, and with
+    /// `KeyError::InvalidDkgShare` naming a participant whose share does not match its
+    /// commitments.
+    ///
+    /// 使用第 2 部分的第 1 轮包以及从所有其他参与者接收的第 2 轮包（均按标识符索引）完成密钥
+    /// 生成。
+    ///
+    /// 除非两个映射恰好包含其他参与者，否则以 `KeyError::InsufficientShares` 失败；如果某个包格式
+    /// 错误，则以 `KeyError::InvalidEncoding` 失败；如果某个参与者的份额与其承诺不匹配，则以指出
+    /// 该参与者的 `KeyError::InvalidDkgShare` 失败。
+    pub fn dkg_part3(
+        secret: &Round2SecretPackage,
+        round1_packages: &BTreeMap<u16, Round1Package>,
+        round2_packages: &BTreeMap<u16, Round2Package>,
+    ) -> Result<(KeyPackage, PublicKeyPackage), Error> {
+        check_participants(
+            secret.identifier,
+            secret.max_signers,
+            round1_packages.keys(),
+        )?;
+        if !round1_packages.keys().eq(round2_packages.keys()) {
+            return Err(Error::Key(KeyError::InsufficientShares));
+        }
+
+        // Sum the commitments of all participants coefficient by coefficient; evaluating the
+        // sum at `j` gives the public key of participant `j`'s final share.
+        // 逐系数地对所有参与者的承诺求和；在 `j` 处对该和求值即得到参与者 `j` 最终份额的公钥。
+        let mut group_commitment = decode_commitment::<C>(&secret.commitment, secret.min_signers)?;
+        let mut signing_share = Zeroizing::new(parse_scalar::<C>(&secret.own_share)?);
+        for (&identifier, package) in round1_packages {
+            let commitment = decode_commitment::<C>(&package.commitment, secret.min_signers)?;
+            let share = round2_packages
+                .get(&identifier)
+                .ok_or(Error::Key(KeyError::InsufficientShares))?;
+            let share = Zeroizing::new(parse_scalar::<C>(&share.signing_share)?);
+            let expected = evaluate_commitment::<C>(&commitment, secret.identifier);
+            if !bool::from((C::Group::generator() * *share).ct_eq(&expected)) {
+                return Err(Error::Key(KeyError::InvalidDkgShare(identifier)));
+            }
+            *signing_share += *share;
+            for (sum, point) in group_commitment.iter_mut().zip(&commitment) {
+                *sum += point;
+            }
+        }
+
+        let verifying_shares = core::iter::once(secret.identifier)
+            .chain(round1_packages.keys().copied())
+            .map(|identifier| {
+                let share = evaluate_commitment::<C>(&group_commitment, identifier);
+                element_to_bytes::<C>(&share).map(|share| (identifier, share))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+        let group_public_key = element_to_bytes::<C>(&group_commitment[0])?;
+        let verifying_share = element_to_bytes::<C>(&(C::Group::generator() * *signing_share))?;
+        if verifying_shares.get(&secret.identifier) != Some(&verifying_share) {
+            return Err(Error::Key(KeyError::GenerationFailed));
+        }
+
+        Ok((
+            KeyPackage {
+                identifier: secret.identifier,
+                signing_share: Zeroizing::new(scalar_to_bytes::<C>(&signing_share)),
+                verifying_share,
+                group_public_key: group_public_key.clone(),
+                min_signers: secret.min_signers,
+            },
+            PublicKeyPackage {
+                verifying_shares,
+                group_public_key,
+            },
+        ))
+    }
+
+    /// Generates fresh nonces for one signature, drawing them from the crate's system RNG.
+    ///
+    /// 为一个签名生成新的 nonce，从 crate 的系统 RNG 获取。
+    pub fn commit(key_package: &KeyPackage) -> Result<(SigningNonces, SigningCommitments), Error> {
+        Self::commit_with_rng(&mut SystemRng::new(), key_package)
+    }
+
+    /// Generates fresh nonces for one signature (round 1 of signing).
+    ///
+    /// Keep the nonces for [`Self::sign`] and send the commitments to the coordinator. As in
+    /// RFC 9591, the nonces are derived from both fresh randomness and the signing share, so a
+    /// weak RNG alone does not reveal the share. Fails with `KeyError::InvalidEncoding` if the
+    /// key package is malformed.
+    ///
+    /// 为一个签名生成新的 nonce（签名的第 1 轮）。
+    ///
+    /// 保留 nonce 用于 [`Self::sign`]，并将承诺发送给协调者。与 RFC 9591 一样，nonce 由新的
+    /// 随机性和签名份额共同派生，因此仅凭一个弱 RNG 不会泄露份额。如果密钥包格式错误，则以
+    /// `KeyError::InvalidEncoding` 失败。
+    pub fn commit_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        key_package: &KeyPackage,
+    ) -> Result<(SigningNonces, SigningCommitments), Error> {
+        let signing_share = Zeroizing::new(parse_scalar::<C>(&key_package.signing_share)?);
+        let hiding = Zeroizing::new(nonce_generate::<C, R>(rng, &signing_share)?);
+        let binding = Zeroizing::new(nonce_generate::<C, R>(rng, &signing_share)?);
+        let commitments = SigningCommitments {
+            hiding: element_to_bytes::<C>(&(C::Group::generator() * *hiding))?,
+            binding: element_to_bytes::<C>(&(C::Group::generator() * *binding))?,
+        };
+        Ok((
+            SigningNonces {
+                hiding: Zeroizing::new(scalar_to_bytes::<C>(&hiding)),
+                binding: Zeroizing::new(scalar_to_bytes::<C>(&binding)),
+                commitments: commitments.clone(),
+            },
+            commitments,
+        ))
+    }
+
+    /// Computes the participant's signature share over the signing package (round 2 of
+    /// signing), consuming the nonces of round 1.
+    ///
+    /// Fails with `SignatureError::Signing` if the package has fewer than `min_signers`
+    /// commitments or does not hold the participant's commitments from `nonces`, and with
+    /// `KeyError::InvalidEncoding` if the package or the key package is malformed.
+    ///
+    /// 对签名包计算参与者的签名份额（签名的第 2 轮），并消耗第 1 轮的 nonce。
+    ///
+    /// 如果包中的承诺少于 `min_signers` 个，或不包含参与者来自 `nonces` 的承诺，则以
+    /// `SignatureError::Signing` 失败；如果该包或密钥包格式错误，则以 `KeyError::InvalidEncoding`
+    /// 失败。
+    pub fn sign(
+        signing_package: &SigningPackage,
+        nonces: SigningNonces,
+        key_package: &KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        if signing_package.commitments.len() < usize::from(key_package.min_signers)
+            || signing_package.commitments.get(&key_package.identifier) != Some(&nonces.commitments)
+        {
+            return Err(Error::Signature(SignatureError::Signing));
+        }
+        let context = SigningContext::<C>::new(signing_package, &key_package.group_public_key)?;
+        let signing_share = Zeroizing::new(parse_scalar::<C>(&key_package.signing_share)?);
+        let hiding = Zeroizing::new(parse_scalar::<C>(&nonces.hiding)?);
+        let binding = Zeroizing::new(parse_scalar::<C>(&nonces.binding)?);
+
+        let binding_factor = context.binding_factor(key_package.identifier)?;
+        let lambda = context.lagrange_coefficient(key_package.identifier)?;
+        let share = Zeroizing::new(
+            *hiding + *binding * binding_factor + lambda * *signing_share * context.challenge,
+        );
+        Ok(SignatureShare(scalar_to_bytes::<C>(&share)))
+    }
+
+    /// Checks the signature share of `identifier` against its verifying share, so the
+    /// coordinator can tell which signer misbehaved.
+    ///
+    /// Fails with `SignatureError::InvalidSignatureShare` if the share is invalid or
+    /// `identifier` is not a signer of the package or a participant of the key generation.
+    ///
+    /// 根据 `identifier` 的验证份额检查其签名份额，使协调者能够判断哪个签名者行为不当。
+    ///
+    /// 如果份额无效，或者 `identifier` 不是该包的签名者或密钥生成的参与者，则以
+    /// `SignatureError::InvalidSignatureShare` 失败。
+    pub fn verify_signature_share(
+        signing_package: &SigningPackage,
+        identifier: u16,
+        signature_share: &SignatureShare,
+        public_key_package: &PublicKeyPackage,
+    ) -> Result<(), Error> {
+        let context =
+            SigningContext::<C>::new(signing_package, &public_key_package.group_public_key)?;
+        context.verify_share(identifier, signature_share, public_key_package)
+    }
+
+    /// Combines the signature shares of every signer of the package, keyed by identifier, into
+    /// a signature under the group public key.
+    ///
+    /// Fails with `SignatureError::Signing` unless there is exactly one share per signer of the
+    /// package, and with `SignatureError::InvalidSignatureShare` naming the first signer whose
+    /// share is invalid.
+    ///
+    /// 将包中每个签名者按标识符索引的签名份额合并为群公钥下的签名。
+    ///
+    /// 除非包中每个签名者恰好有一个份额，否则以 `SignatureError::Signing` 失败；如果某个签名者的
+    /// 份额无效，则以指出第一个此类签名者的 `SignatureError::InvalidSignatureShare` 失败。
+    pub fn aggregate(
+        signing_package: &SigningPackage,
+        signature_shares: &BTreeMap<u16, SignatureShare>,
+        public_key_package: &PublicKeyPackage,
+    ) -> Result<Signature, Error> {
+        if !signing_package
+            .commitments
+            .keys()
+            .eq(signature_shares.keys())
+        {
+            return Err(Error::Signature(SignatureError::Signing));
+        }
+        let context =
+            SigningContext::<C>::new(signing_package, &public_key_package.group_public_key)?;
+
+        let mut z = Scalar::<C>::ZERO;
+        for (&identifier, share) in signature_shares {
+            z += parse_scalar::<C>(&share.0)
+                .map_err(|_| Error::Signature(SignatureError::InvalidSignatureShare(identifier)))?;
+        }
+        let signature = [
+            element_to_bytes::<C>(&context.group_commitment)?,
+            scalar_to_bytes::<C>(&z),
+        ]
+        .concat();
+
+        if Self::verify(
+            &public_key_package.group_public_key,
+            &signing_package.message,
+            &signature,
+        )
+        .is_err()
+        {
+            // Find the cheater, as an invalid signature is useless anyway.
+            // 找出作弊者，因为无效的签名本来就没有用处。
+            for (&identifier, share) in signature_shares {
+                context.verify_share(identifier, share, public_key_package)?;
+            }
+            return Err(Error::Signature(SignatureError::Verification));
+        }
+        Ok(signature)
+    }
+
+    /// Verifies a signature `R || z` over `message` under the serialized group public key.
+    ///
+    /// Fails with `SignatureError::InvalidSignature` if the signature is malformed, with
+    /// `KeyError::InvalidEncoding` if the public key is malformed, and with
+    /// `SignatureError::Verification` if the signature is invalid.
+    ///
+    /// 在序列化的群公钥下验证对 `message` 的签名 `R || z`。
+    ///
+    /// 如果签名格式错误，则以 `SignatureError::InvalidSignature` 失败；如果公钥格式错误，则以
+    /// `KeyError::InvalidEncoding` 失败；如果签名无效，则以 `SignatureError::Verification` 失败。
+    pub fn verify(group_public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        let element_size = element_size::<C>();
+        if signature.len() != element_size + scalar_size::<C>() {
+            return Err(Error::Signature(SignatureError::InvalidSignature));
+        }
+        let (r, z) = signature.split_at(element_size);
+        let r = parse_element::<C>(r)
+            .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
+        let z =
+            parse_scalar::<C>(z).map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
+        let public_key = parse_element::<C>(group_public_key)?;
+
+        let c = challenge::<C>(&r, &public_key, message)?;
+        if bool::from((C::Group::generator() * z).ct_eq(&(r + public_key * c))) {
+            Ok(())
+        } else {
+            Err(Error::Signature(SignatureError::Verification))
+        }
+    }
+}
+
+/// A signer's commitments and binding factor within a signing package.
+struct SignerCommitment<C: FrostCiphersuite> {
+    identifier: u16,
+    hiding: C::Group,
+    binding: C::Group,
+    binding_factor: Scalar<C>,
+}
+
+/// The values every signer and the coordinator derive from a signing package.
+struct SigningContext<C: FrostCiphersuite> {
+    signers: Vec<SignerCommitment<C>>,
+    group_commitment: C::Group,
+    challenge: Scalar<C>,
+}
+
+impl<C: FrostCiphersuite> SigningContext<C> {
+    fn new(signing_package: &SigningPackage, group_public_key: &[u8]) -> Result<Self, Error> {
+        let public_key = parse_element::<C>(group_public_key)?;
+        let mut encoded = Vec::new();
+        let mut commitments = Vec::with_capacity(signing_package.commitments.len());
+        for (&identifier, commitment) in &signing_package.commitments {
+            if identifier == 0 {
+                return Err(Error::Key(KeyError::InvalidShare));
+            }
+            let hiding = parse_element::<C>(&commitment.hiding)?;
+            let binding = parse_element::<C>(&commitment.binding)?;
+            encoded.extend_from_slice(&scalar_to_bytes::<C>(&identifier_scalar::<C>(identifier)));
+            encoded.extend_from_slice(&element_to_bytes::<C>(&hiding)?);
+            encoded.extend_from_slice(&element_to_bytes::<C>(&binding)?);
+            commitments.push((identifier, hiding, binding));
+        }
+
+        // compute_binding_factors of RFC 9591, section 4.4.
+        // RFC 9591 第 4.4 节的 compute_binding_factors。
+        let public_key_bytes = element_to_bytes::<C>(&public_key)?;
+        let message_hash = C::hash(b"msg", &[&signing_package.message]);
+        let commitments_hash = C::hash(b"com", &[&encoded]);
+        let mut signers = Vec::with_capacity(commitments.len());
+        let mut group_commitment = C::Group::identity();
+        for (identifier, hiding, binding) in commitments {
+            let binding_factor = C::hash_to_scalar(
+                b"rho",
+                &[
+                    &public_key_bytes,
+                    &message_hash,
+                    &commitments_hash,
+                    scalar_to_bytes::<C>(&identifier_scalar::<C>(identifier)).as_slice(),
+                ],
+            )?;
+            group_commitment += hiding + binding * binding_factor;
+            signers.push(SignerCommitment {
+                identifier,
+                hiding,
+                binding,
+                binding_factor,
+            });
+        }
+        let challenge = challenge::<C>(&group_commitment, &public_key, &signing_package.message)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        Ok(Self {
+            signers,
+            group_commitment,
+            challenge,
+        })
+    }
+
+    fn binding_factor(&self, identifier: u16) -> Result<Scalar<C>, Error> {
+        self.signers
+            .iter()
+            .find(|signer| signer.identifier == identifier)
+            .map(|signer| signer.binding_factor)
+            .ok_or(Error::Signature(SignatureError::Signing))
+    }
+
+    /// derive_interpolating_value of RFC 9591, section 4.2: the Lagrange coefficient of
+    /// `identifier` at zero over the signers.
+    fn lagrange_coefficient(&self, identifier: u16) -> Result<Scalar<C>, Error> {
+        let x = identifier_scalar::<C>(identifier);
+        let (mut numerator, mut denominator) = (Scalar::<C>::ONE, Scalar::<C>::ONE);
+        for signer in self
+            .signers
+            .iter()
+            .filter(|signer| signer.identifier != identifier)
+        {
+            let other = identifier_scalar::<C>(signer.identifier);
+            numerator *= other;
+            denominator *= other - x;
+        }
+        Option::<Scalar<C>>::from(denominator.invert())
+            .map(|inverse| numerator * inverse)
+            .ok_or(Error::Signature(SignatureError::Signing))
+    }
+
+    fn verify_share(
+        &self,
+        identifier: u16,
+        signature_share: &SignatureShare,
+        public_key_package: &PublicKeyPackage,
+    ) -> Result<(), Error> {
+        let invalid = || Error::Signature(SignatureError::InvalidSignatureShare(identifier));
+        let signer = self
+            .signers
+            .iter()
+            .find(|signer| signer.identifier == identifier)
+            .ok_or_else(invalid)?;
+        let verifying_share = public_key_package
+            .verifying_share(identifier)
+            .and_then(|share| parse_element::<C>(share).ok())
+            .ok_or_else(invalid)?;
+        let z = parse_scalar::<C>(&signature_share.0).map_err(|_| invalid())?;
+
+        let lambda = self.lagrange_coefficient(identifier)?;
+        let expected = signer.hiding
+            + signer.binding * signer.binding_factor
+            + verifying_share * (self.challenge * lambda);
+        if bool::from((C::Group::generator() * z).ct_eq(&expected)) {
+            Ok(())
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+/// Checks that `others` are exactly the other `max_signers - 1` participants.
+fn check_participants<'a>(
+    identifier: u16,
+    max_signers: u16,
+    others: impl ExactSizeIterator<Item = &'a u16>,
+) -> Result<(), Error> {
+    if others.len() != usize::from(max_signers) - 1 {
+        return Err(Error::Key(KeyError::InsufficientShares));
+    }
+    for &other in others {
+        if other == 0 || other == identifier {
+            return Err(Error::Key(KeyError::InvalidShare));
+        }
+    }
+    Ok(())
+}
+
+fn identifier_scalar<C: FrostCiphersuite>(identifier: u16) -> Scalar<C> {
+    Scalar::<C>::from(u64::from(identifier))
+}
+
+/// nonce_generate of RFC 9591, section 4.1.
+fn nonce_generate<C: FrostCiphersuite, R: CryptoRngCore>(
+    rng: &mut R,
+    secret: &Scalar<C>,
+) -> Result<Scalar<C>, Error> {
+    let mut random_bytes = Zeroizing::new([0u8; 32]);
+    rng.fill_bytes(random_bytes.as_mut());
+    let secret = Zeroizing::new(scalar_to_bytes::<C>(secret));
+    C::hash_to_scalar(b"nonce", &[random_bytes.as_ref(), &secret])
+}
+
+/// The challenge of the proof of knowledge in the key generation.
+fn dkg_challenge<C: FrostCiphersuite>(
+    identifier: u16,
+    verifying_key: &C::Group,
+    r: &C::Group,
+) -> Result<Scalar<C>, Error> {
+    C::hash_to_scalar(
+        b"dkg",
+        &[
+            &scalar_to_bytes::<C>(&identifier_scalar::<C>(identifier)),
+            &element_to_bytes::<C>(verifying_key)?,
+            &element_to_bytes::<C>(r)?,
+        ],
+    )
+}
+
+/// compute_challenge of RFC 9591, section 4.6.
+fn challenge<C: FrostCiphersuite>(
+    r: &C::Group,
+    public_key: &C::Group,
+    message: &[u8],
+) -> Result<Scalar<C>, Error> {
+    C::hash_to_scalar(
+        b"chal",
+        &[
+            &element_to_bytes::<C>(r)?,
+            &element_to_bytes::<C>(public_key)?,
+            message,
+        ],
+    )
+}
+
+/// Evaluates the polynomial with the given coefficients at `x` with Horner's method.
+fn evaluate<C: FrostCiphersuite>(coefficients: &[Scalar<C>], x: u16) -> Scalar<C> {
+    let x = identifier_scalar::<C>(x);
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::<C>::ZERO, |value, coefficient| {
+            value * x + coefficient
+        })
+}
+
+/// Evaluates the polynomial committed to by `commitment` at `x` in the exponent.
+fn evaluate_commitment<C: FrostCiphersuite>(commitment: &[C::Group], x: u16) -> C::Group {
+    let x = identifier_scalar::<C>(x);
+    commitment
+        .iter()
+        .rev()
+        .fold(C::Group::identity(), |value, point| value * x + point)
+}
+
+fn scalar_size<C: FrostCiphersuite>() -> usize {
+    <Scalar<C> as PrimeField>::Repr::default().as_ref().len()
+}
+
+fn element_size<C: FrostCiphersuite>() -> usize {
+    <C::Group as GroupEncoding>::Repr::default().as_ref().len()
+}
+
+fn scalar_to_bytes<C: FrostCiphersuite>(scalar: &Scalar<C>) -> Vec<u8> {
+    scalar.to_repr().as_ref().to_vec()
+}
+
+fn parse_scalar<C: FrostCiphersuite>(bytes: &[u8]) -> Result<Scalar<C>, Error> {
+    let mut repr = <Scalar<C> as PrimeField>::Repr::default();
+    if bytes.len() != repr.as_ref().len() {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    repr.as_mut().copy_from_slice(bytes);
+    Option::from(Scalar::<C>::from_repr(repr)).ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+/// Serializes a group element, rejecting the identity as RFC 9591 requires.
+fn element_to_bytes<C: FrostCiphersuite>(element: &C::Group) -> Result<Vec<u8>, Error> {
+    if bool::from(element.is_identity()) {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    Ok(element.to_bytes().as_ref().to_vec())
+}
+
+/// Deserializes a group element, rejecting the identity as RFC 9591 requires.
+fn parse_element<C: FrostCiphersuite>(bytes: &[u8]) -> Result<C::Group, Error> {
+    let mut repr = <C::Group as GroupEncoding>::Repr::default();
+    if bytes.len() != repr.as_ref().len() {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    repr.as_mut().copy_from_slice(bytes);
+    Option::<C::Group>::from(C::Group::from_bytes(&repr))
+        .filter(|element| !bool::from(element.is_identity()))
+        .ok_or(Error::Key(KeyError::InvalidEncoding))
+}
+
+fn encode_elements<C: FrostCiphersuite>(elements: &[C::Group]) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::with_capacity(elements.len() * element_size::<C>());
+    for element in elements {
+        bytes.extend_from_slice(&element_to_bytes::<C>(element)?);
+    }
+    Ok(bytes)
+}
+
+/// Decodes the commitments of a polynomial with `min_signers` coefficients.
+fn decode_commitment<C: FrostCiphersuite>(
+    bytes: &[u8],
+    min_signers: u16,
+) -> Result<Vec<C::Group>, Error> {
+    if bytes.len() != usize::from(min_signers) * element_size::<C>() {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    bytes
+        .chunks_exact(element_size::<C>())
+        .map(parse_element::<C>)
+        .collect()
+}
+
+fn decode_proof<C: FrostCiphersuite>(bytes: &[u8]) -> Result<(C::Group, Scalar<C>), Error> {
+    if bytes.len() != element_size::<C>() + scalar_size::<C>() {
+        return Err(Error::Key(KeyError::InvalidEncoding));
+    }
+    let (r, mu) = bytes.split_at(element_size::<C>());
+    Ok((parse_element::<C>(r)?, parse_scalar::<C>(mu)?))
+}
+
+fn decode_scalars<C: FrostCiphersuite>(bytes: &[u8]) -> Result<Zeroizing<Vec<Scalar<C>>>, Error> {
+    let mut scalars = Zeroizing::new(Vec::with_capacity(bytes.len() / scalar_size::<C>()));
+    for chunk in bytes.chunks_exact(scalar_size::<C>()) {
+        scalars.push(parse_scalar::<C>(chunk)?);
+    }
+    Ok(scalars)
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for `FROST(ristretto255, SHA-512)`.
+///
+/// `FROST(ristretto255, SHA-512)` 的类型别名。
+pub type FrostRistretto255 = Frost<Ristretto255Sha512>;
+
+/// A type alias for `FROST(secp256k1, SHA-256)`.
+///
+/// `FROST(secp256k1, SHA-256)` 的类型别名。
+pub type FrostSecp256k1 = Frost<Secp256k1Sha256>;
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_rng::SeededRng;
+
+    type Keys = BTreeMap<u16, (KeyPackage, PublicKeyPackage)>;
+
+    /// Runs the key generation for participants `1..=max_signers`.
+    fn run_dkg<C: FrostCiphersuite>(
+        rng: &mut SeededRng,
+        max_signers: u16,
+        min_signers: u16,
+    ) -> Result<Keys, Error> {
+        let mut round1_secrets = BTreeMap::new();
+        let mut round1_packages = BTreeMap::new();
+        for identifier in 1..=max_signers {
+            let (secret, package) =
+                Frost::<C>::dkg_part1_with_rng(rng, identifier, max_signers, min_signers)?;
+            round1_secrets.insert(identifier, secret);
+            round1_packages.insert(identifier, package);
+        }
+
+        let mut round2_secrets = BTreeMap::new();
+        let mut received: BTreeMap<u16, BTreeMap<u16, Round2Package>> = BTreeMap::new();
+        for (identifier, secret) in round1_secrets {
+            let mut others = round1_packages.clone();
+            others.remove(&identifier);
+            let (secret, packages) = Frost::<C>::dkg_part2(secret, &others)?;
+            for (receiver, package) in packages {
+                received
+                    .entry(receiver)
+                    .or_default()
+                    .insert(identifier, package);
+            }
+            round2_secrets.insert(identifier, (secret, others));
+        }
+
+        let mut keys = BTreeMap::new();
+        for (identifier, (secret, others)) in round2_secrets {
+            let packages = received.remove(&identifier).unwrap_or_default();
+            keys.insert(
+                identifier,
+                Frost::<C>::dkg_part3(&secret, &others, &packages)?,
+            );
+        }
+        Ok(keys)
+    }
+
+    /// Runs both signing rounds with the given signers.
+    fn sign<C: FrostCiphersuite>(
+        rng: &mut SeededRng,
+        keys: &Keys,
+        signers: &[u16],
+        message: &[u8],
+    ) -> Result<(SigningPackage, BTreeMap<u16, SignatureShare>), Error> {
+        let mut nonces = BTreeMap::new();
+        let mut commitments = BTreeMap::new();
+        for identifier in signers {
+            let (signer_nonces, signer_commitments) =
+                Frost::<C>::commit_with_rng(rng, &keys[identifier].0)?;
+            nonces.insert(*identifier, signer_nonces);
+            commitments.insert(*identifier, signer_commitments);
+        }
+        let package = SigningPackage::new(commitments, message);
+        let mut shares = BTreeMap::new();
+        for (identifier, signer_nonces) in nonces {
+            let share = Frost::<C>::sign(&package, signer_nonces, &keys[&identifier].0)?;
+            shares.insert(identifier, share);
+        }
+        Ok((package, shares))
+    }
+
+    fn run_frost_tests<C: FrostCiphersuite>() -> Result<(), Error> {
+        let mut rng = SeededRng(1);
+        let keys = run_dkg::<C>(&mut rng, 5, 3)?;
+        let public = keys[&1].1.clone();
+        for (identifier, (key_package, public_key_package)) in &keys {
+            assert_eq!(public_key_package, &public);
+            assert_eq!(key_package.identifier(), *identifier);
+            assert_eq!(key_package.min_signers(), 3);
+            assert_eq!(key_package.group_public_key(), public.group_public_key());
+            assert_eq!(
+                Some(key_package.verifying_share()),
+                public.verifying_share(*identifier)
+            );
+        }
+
+        // Any three shares interpolate to the private key of the group public key.
+        // 任意三个份额插值得到群公钥对应的私钥。
+        let mut group_secret = Scalar::<C>::ZERO;
+        for identifier in [1u16, 2, 4] {
+            let mut lambda = Scalar::<C>::ONE;
+            for other in [1u16, 2, 4]
+                .into_iter()
+                .filter(|&other| other != identifier)
+            {
+                let denominator =
+                    identifier_scalar::<C>(other) - identifier_scalar::<C>(identifier);
+                lambda *= identifier_scalar::<C>(other)
+                    * Option::<Scalar<C>>::from(denominator.invert()).unwrap_or(Scalar::<C>::ZERO);
+            }
+            group_secret += lambda * parse_scalar::<C>(keys[&identifier].0.signing_share())?;
+        }
+        assert_eq!(
+            element_to_bytes::<C>(&(C::Group::generator() * group_secret))?,
+            public.group_public_key()
+        );
+
+        let message = b"release v1.2.3";
+        for signers in [&[1u16, 3, 5][..], &[2, 3, 4], &[1, 2, 3, 4, 5]] {
+            let (package, shares) = sign::<C>(&mut rng, &keys, signers, message)?;
+            for (identifier, share) in &shares {
+                Frost::<C>::verify_signature_share(&package, *identifier, share, &public)?;
+            }
+            let signature = Frost::<C>::aggregate(&package, &shares, &public)?;
+            assert_eq!(signature.len(), element_size::<C>() + scalar_size::<C>());
+            Frost::<C>::verify(public.group_public_key(), message, &signature)?;
+            assert!(matches!(
+                Frost::<C>::verify(public.group_public_key(), b"release v1.2.4", &signature),
+                Err(Error::Signature(SignatureError::Verification))
+            ));
+            assert!(matches!(
+                Frost::<C>::verify(public.group_public_key(), message, &signature[1..]),
+                Err(Error::Signature(SignatureError::InvalidSignature))
+            ));
+        }
+
+        // A forged share is caught and its signer named.
+        // 伪造的份额会被发现，并指出其签名者。
+        let (package, mut shares) = sign::<C>(&mut rng, &keys, &[1, 3, 5], message)?;
+        shares.insert(3, shares[&1].clone());
+        assert!(matches!(
+            Frost::<C>::aggregate(&package, &shares, &public),
+            Err(Error::Signature(SignatureError::InvalidSignatureShare(3)))
+        ));
+        shares.remove(&3);
+        assert!(matches!(
+            Frost::<C>::aggregate(&package, &shares, &public),
+            Err(Error::Signature(SignatureError::Signing))
+        ));
+
+        // Signing needs `min_signers` commitments, including the signer's own.
+        // 签名需要 `min_signers` 个承诺，其中包括签名者自己的承诺。
+        let (nonces, commitments) = Frost::<C>::commit_with_rng(&mut rng, &keys[&1].0)?;
+        let (_, other) = Frost::<C>::commit_with_rng(&mut rng, &keys[&2].0)?;
+        let too_few = SigningPackage::new(
+            BTreeMap::from([(1, commitments.clone()), (2, other.clone())]),
+            message,
+        );
+        assert!(matches!(
+            Frost::<C>::sign(&too_few, nonces, &keys[&1].0),
+            Err(Error::Signature(SignatureError::Signing))
+        ));
+        let (nonces, _) = Frost::<C>::commit_with_rng(&mut rng, &keys[&1].0)?;
+        let (_, third) = Frost::<C>::commit_with_rng(&mut rng, &keys[&3].0)?;
+        let stale = SigningPackage::new(
+            BTreeMap::from([(1, commitments), (2, other), (3, third)]),
+            message,
+        );
+        assert!(matches!(
+            Frost::<C>::sign(&stale, nonces, &keys[&1].0),
+            Err(Error::Signature(SignatureError::Signing))
+        ));
+        Ok(())
+    }
+
+    /// One signer of an RFC 9591 test vector: identifier, share, hiding nonce randomness (if
+    /// checked), hiding nonce, binding nonce and signature share.
+    type VectorSigner = (
+        u16,
+        &'static str,
+        Option<&'static str>,
+        &'static str,
+        &'static str,
+        &'static str,
+    );
+
+    fn run_vector<C: FrostCiphersuite>(
+        group_secret: &str,
+        coefficient: &str,
+        signers: &[VectorSigner],
+        signature: &str,
+    ) -> Result<(), Error> {
+        let scalar = |hex: &str| parse_scalar::<C>(&hex::decode(hex).unwrap_or_default());
+        let coefficients = [scalar(group_secret)?, scalar(coefficient)?];
+        let group_public_key = element_to_bytes::<C>(&(C::Group::generator() * coefficients[0]))?;
+
+        let mut nonces = Vec::new();
+        let mut commitments = BTreeMap::new();
+        for &(identifier, share, randomness, hiding, binding, _) in signers {
+            let signing_share = evaluate::<C>(&coefficients, identifier);
+            assert_eq!(hex::encode(scalar_to_bytes::<C>(&signing_share)), share);
+            if let Some(randomness) = randomness {
+                let derived = C::hash_to_scalar(
+                    b"nonce",
+                    &[
+                        &hex::decode(randomness).unwrap_or_default(),
+                        &scalar_to_bytes::<C>(&signing_share),
+                    ],
+                )?;
+                assert_eq!(hex::encode(scalar_to_bytes::<C>(&derived)), hiding);
+            }
+
+            let (hiding, binding) = (scalar(hiding)?, scalar(binding)?);
+            let signer_commitments = SigningCommitments {
+                hiding: element_to_bytes::<C>(&(C::Group::generator() * hiding))?,
+                binding: element_to_bytes::<C>(&(C::Group::generator() * binding))?,
+            };
+            commitments.insert(identifier, signer_commitments.clone());
+            let key_package = KeyPackage {
+                identifier,
+                signing_share: Zeroizing::new(scalar_to_bytes::<C>(&signing_share)),
+                verifying_share: element_to_bytes::<C>(&(C::Group::generator() * signing_share))?,
+                group_public_key: group_public_key.clone(),
+                min_signers: 2,
+            };
+            let signer_nonces = SigningNonces {
+                hiding: Zeroizing::new(scalar_to_bytes::<C>(&hiding)),
+                binding: Zeroizing::new(scalar_to_bytes::<C>(&binding)),
+                commitments: signer_commitments,
+            };
+            nonces.push((key_package, signer_nonces));
+        }
+
+        let package = SigningPackage::new(commitments, b"test");
+        let mut shares = BTreeMap::new();
+        let mut verifying_shares = BTreeMap::new();
+        for ((key_package, signer_nonces), signer) in nonces.into_iter().zip(signers) {
+            let share = Frost::<C>::sign(&package, signer_nonces, &key_package)?;
+            assert_eq!(hex::encode(share.as_bytes()), signer.5);
+            verifying_shares.insert(key_package.identifier, key_package.verifying_share.clone());
+            shares.insert(key_package.identifier, share);
+        }
+        let public_key_package = PublicKeyPackage {
+            verifying_shares,
+            group_public_key: group_public_key.clone(),
+        };
+        let aggregated = Frost::<C>::aggregate(&package, &shares, &public_key_package)?;
+        assert_eq!(hex::encode(&aggregated), signature);
+        Frost::<C>::verify(&group_public_key, b"test", &aggregated)
+    }
+
+    #[test]
+    fn test_rfc9591_vectors() -> Result<(), Error> {
+        // RFC 9591, appendix E.4: FROST(ristretto255, SHA-512), signers 1 and 3 of 3
+        // RFC 9591 附录 E.4：FROST(ristretto255, SHA-512)，3 个参与者中的签名者 1 和 3
+        run_vector::<Ristretto255Sha512>(
+            "1b25a55e463cfd15cf14a5d3acc3d15053f08da49c8afcf3ab265f2ebc4f970b",
+            "410f8b744b19325891d73736923525a4f596c805d060dfb9c98009d34e3fec02",
+            &[
+                (
+                    1,
+                    "5c3430d391552f6e60ecdc093ff9f6f4488756aa6cebdbad75a768010b8f830e",
+                    Some("f595a133b4d95c6e1f79887220c8b275ce6277e7f68a6640e1e7140f9be2fb5c"),
+                    "214f2cabb86ed71427ea7ad4283b0fae26b6746c801ce824b83ceb2b99278c03",
+                    "c9b8f5e16770d15603f744f8694c44e335e8faef00dad182b8d7a34a62552f0c",
+                    "9285f875923ce7e0c491a592e9ea1865ec1b823ead4854b48c8a46287749ee09",
+                ),
+                (
+                    3,
+                    "f17e505f0e2581c6acfe54d3846a622834b5e7b50cad9a2109a97ba7a80d5c04",
+                    None,
+                    "3f7927872b0f9051dd98dd73eb2b91494173bbe0feb65a3e7e58d3e2318fa40f",
+                    "ffd79445fb8030f0a3ddd3861aa4b42b618759282bfe24f1f9304c7009728305",
+                    "7cb211fe0e3d59d25db6e36b3fb32344794139602a7b24f1ae0dc4e26ad7b908",
+                ),
+            ],
+            "fc45655fbc66bbffad654ea4ce5fdae253a49a64ace25d9adb62010dd9fb25552164141787162e5b4cab915b4aa45d94655dbb9ed7c378a53b980a0be220a802",
+        )?;
+
+        // RFC 9591, appendix E.5: FROST(secp256k1, SHA-256), signers 1 and 3 of 3
+        // RFC 9591 附录 E.5：FROST(secp256k1, SHA-256)，3 个参与者中的签名者 1 和 3
+        run_vector::<Secp256k1Sha256>(
+            "0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114",
+            "fbf85eadae3058ea14f19148bb72b45e4399c0b16028acaf0395c9b03c823579",
+            &[
+                (
+                    1,
+                    "08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c",
+                    Some("7ea5ed09af19f6ff21040c07ec2d2adbd35b759da5a401d4c99dd26b82391cb2"),
+                    "841d3a6450d7580b4da83c8e618414d0f024391f2aeb511d7579224420aa81f0",
+                    "8d2624f532af631377f33cf44b5ac5f849067cae2eacb88680a31e77c79b5a80",
+                    "c4fce1775a1e141fb579944166eab0d65eefe7b98d480a569bbbfcb14f91c197",
+                ),
+                (
+                    3,
+                    "00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc",
+                    None,
+                    "2b19b13f193f4ce83a399362a90cdc1e0ddcd83e57089a7af0bdca71d47869b2",
+                    "7a443bde83dc63ef52dda354005225ba0e553243402a4705ce28ffaafe0f5b98",
+                    "0160fd0d388932f4826d2ebcd6b9eaba734f7c71cf25b4279a4ca2581e47b18d",
+                ),
+            ],
+            "0205b6d04d3774c8929413e3c76024d54149c372d57aae62574ed74319b5ea14d0c65dde8492a7471437e6c2fe3da49b90d23f642b5c6dbe7e36089f096dd97324",
+        )
+    }
+
+    #[test]
+    fn test_frost_ristretto255() -> Result<(), Error> {
+        run_frost_tests::<Ristretto255Sha512>()
+    }
+
+    #[test]
+    fn test_frost_secp256k1() -> Result<(), Error> {
+        run_frost_tests::<Secp256k1Sha256>()
+    }
+
+    #[test]
+    fn test_dkg_identifies_cheaters() -> Result<(), Error> {
+        let mut rng = SeededRng(2);
+        let (secret1, package1) = FrostRistretto255::dkg_part1_with_rng(&mut rng, 1, 3, 2)?;
+        let (_, package2) = FrostRistretto255::dkg_part1_with_rng(&mut rng, 2, 3, 2)?;
+        let (secret3, package3) = FrostRistretto255::dkg_part1_with_rng(&mut rng, 3, 3, 2)?;
+
+        // A proof of knowledge is bound to its participant.
+        // 知识证明与其参与者绑定。
+        let (forged_secret, _) = FrostRistretto255::dkg_part1_with_rng(&mut rng, 1, 3, 2)?;
+        let swapped = BTreeMap::from([(2, package3.clone()), (3, package2.clone())]);
+        assert!(matches!(
+            FrostRistretto255::dkg_part2(forged_secret, &swapped),
+            Err(Error::Key(KeyError::InvalidProofOfKnowledge(2)))
+        ));
+
+        let others = BTreeMap::from([(2, package2.clone()), (3, package3.clone())]);
+        let (secret1, _) = FrostRistretto255::dkg_part2(secret1, &others)?;
+        let (_, packages3) =
+            FrostRistretto255::dkg_part2(secret3, &BTreeMap::from([(1, package1), (2, package2)]))?;
+
+        // Participant 3 sends participant 1 the share meant for participant 2.
+        // 参与者 3 将本应发给参与者 2 的份额发送给参与者 1。
+        let received = BTreeMap::from([(2, packages3[&1].clone()), (3, packages3[&2].clone())]);
+        assert!(matches!(
+            FrostRistretto255::dkg_part3(&secret1, &others, &received),
+            Err(Error::Key(KeyError::InvalidDkgShare(2)))
+        ));
+        let missing = BTreeMap::from([(3, packages3[&1].clone())]);
+        assert!(matches!(
+            FrostRistretto255::dkg_part3(&secret1, &others, &missing),
+            Err(Error::Key(KeyError::InsufficientShares))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_bad_parameters() -> Result<(), Error> {
+        let mut rng = SeededRng(3);
+        for (max_signers, min_signers) in [(3, 1), (3, 4), (0, 0)] {
+            assert!(matches!(
+                FrostSecp256k1::dkg_part1_with_rng(&mut rng, 1, max_signers, min_signers),
+                Err(Error::Key(KeyError::InvalidThreshold))
+            ));
+        }
+        assert!(matches!(
+            FrostSecp256k1::dkg_part1_with_rng(&mut rng, 0, 3, 2),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+
+        let (secret, package) = FrostSecp256k1::dkg_part1(1, 3, 2)?;
+        let own = BTreeMap::from([(1, package.clone()), (2, package)]);
+        assert!(matches!(
+            FrostSecp256k1::dkg_part2(secret, &own),
+            Err(Error::Key(KeyError::InvalidShare))
+        ));
+
+        let (secret, package) = FrostSecp256k1::dkg_part1(1, 3, 2)?;
+        let truncated =
+            Round1Package::new(&package.commitment()[1..], package.proof_of_knowledge());
+        let others = BTreeMap::from([(2, truncated), (3, package.clone())]);
+        assert!(matches!(
+            FrostSecp256k1::dkg_part2(secret, &others),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+
+        // Packages of one ciphersuite are not valid in the other.
+        // 一个密码套件的包在另一个密码套件中无效。
+        let keys = run_dkg::<Secp256k1Sha256>(&mut rng, 2, 2)?;
+        assert!(matches!(
+            FrostRistretto255::commit(&keys[&1].0),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+        let signature = [
+            element_to_bytes::<Ristretto255Sha512>(&RistrettoPoint::generator())?,
+            vec![0; 32],
+        ]
+        .concat();
+        assert!(matches!(
+            FrostRistretto255::verify(keys[&1].0.group_public_key(), b"", &signature),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+//! Verifiable random function implementations.
+//!
+//! This module provides verifiable random functions, which map an input to a pseudorandom
+//! output under a private key, together with a proof that anyone holding the public key can
+//! check.
+//!
+//! # Available Implementations
+//! - **ECVRF**: Elliptic curve VRFs (RFC 9381) over P-256 and Edwards25519
+//!
+//! 可验证随机函数实现。
+//!
+//! 此模块提供可验证随机函数，它们在私钥下将输入映射为伪随机输出，并附带一个任何持有公钥的人
+//! 都可以检查的证明。
+//!
+//! # 可用实现
+//! - **ECVRF**: 基于 P-256 和 Edwards25519 的椭圆曲线 VRF (RFC 9381)
+
+/// ECVRF verifiable random function implementation.
+///
+/// ECVRF 可验证随机函数实现。
+#[cfg(feature = "vrf-default")]
+pub mod ecvrf;
@@ -0,0 +1,670 @@
+//! Provides the ECVRF verifiable random function (RFC 9381) over P-256 and Edwards25519.
+//!
+//! A VRF is the public-key version of a keyed hash: the holder of a private key maps an input
+//! `alpha` to a pseudorandom output `beta`, together with a proof `pi` that lets anyone holding
+//! the public key check that `beta` is the one and only output for `alpha` under that key.
+//! Without the private key, `beta` is indistinguishable from random.
+//!
+//! The functions take the ECC keys of this crate: [`EcvrfP256`] uses the keys of
+//! [`EcdsaP256`](crate::systems::asymmetric::traditional::ecc::EcdsaP256) and
+//! [`EcvrfEdwards25519`] the keys of
+//! [`Ed25519`](crate::systems::asymmetric::traditional::ecc::Ed25519).
+//!
+//! # Usage
+//! 1. The prover calls [`Ecvrf::prove`] and publishes the proof, or sends it along with `alpha`.
+//! 2. A verifier calls [`Ecvrf::verify`], which returns `beta` only if the proof is valid.
+//! 3. The prover obtains `beta` from its own proof with [`Ecvrf::proof_to_hash`].
+//!
+//! # Security Considerations
+//! - Proofs are deterministic; proving the same input twice yields the same proof.
+//! - [`Ecvrf::proof_to_hash`] does not check the proof. Verifiers must use the output of
+//!   [`Ecvrf::verify`] instead.
+//! - Verification over Edwards25519 rejects public keys of small order, so that the output is
+//!   unique even for keys chosen by an adversary (full uniqueness in RFC 9381 terms).
+//! - Using one key both for ECVRF and for ECDSA or Ed25519 signatures is allowed by the
+//!   specification, but separate keys are preferable.
+//! - ECVRF is not a FIPS approved algorithm, so it is not available with the `fips` feature.
+//!
+//! 提供了基于 P-256 和 Edwards25519 的 ECVRF 可验证随机函数 (RFC 9381)。
+//!
+//! VRF 是带密钥哈希的公钥版本：私钥持有者将输入 `alpha` 映射为伪随机输出 `beta`，并附带一个
+//! 证明 `pi`，任何持有公钥的人都可以用它检查 `beta` 是该密钥下 `alpha` 唯一的输出。没有私钥时，
+//! `beta` 与随机值不可区分。
+//!
+//! 这些函数使用此 crate 的 ECC 密钥：[`EcvrfP256`] 使用
+//! [`EcdsaP256`](crate::systems::asymmetric::traditional::ecc::EcdsaP256) 的密钥，
+//! [`EcvrfEdwards25519`] 使用 [`Ed25519`](crate::systems::asymmetric::traditional::ecc::Ed25519)
+//! 的密钥。
+//!
+//! # 用法
+//! 1. 证明者调用 [`Ecvrf::prove`] 并公开证明，或将其与 `alpha` 一起发送。
+//! 2. 验证者调用 [`Ecvrf::verify`]，它仅在证明有效时返回 `beta`。
+//! 3. 证明者使用 [`Ecvrf::proof_to_hash`] 从自己的证明中获得 `beta`。
+//!
+//! # 安全考虑
+//! - 证明是确定性的；对同一输入证明两次会得到相同的证明。
+//! - [`Ecvrf::proof_to_hash`] 不检查证明。验证者必须改用 [`Ecvrf::verify`] 的输出。
+//! - 在 Edwards25519 上的验证会拒绝小阶公钥，因此即使对于攻击者选择的密钥，输出也是唯一的
+//!   （RFC 9381 中的完全唯一性）。
+//! - 规范允许将同一密钥同时用于 ECVRF 和 ECDSA 或 Ed25519 签名，但最好使用不同的密钥。
+//! - ECVRF 不是 FIPS 批准的算法，因此启用 `fips` 特性时不可用。
+
+use alloc::vec::Vec;
+use crate::ct::ConstantTimeEq;
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::systems::asymmetric::traditional::ecc::{
+    EccParams, EccPrivateKey, EccPublicKey, EcdsaP256Params, Ed25519Params,
+};
+use curve25519_dalek::edwards::EdwardsPoint;
+use curve25519_dalek::scalar::Scalar as Ed25519Scalar;
+use ed25519_dalek::pkcs8::DecodePublicKey;
+use elliptic_curve::Curve;
+use elliptic_curve::bigint::ArrayEncoding;
+use elliptic_curve::ff::{Field, PrimeField};
+use elliptic_curve::group::{Group, GroupEncoding};
+use elliptic_curve::ops::Reduce;
+use p256::{NistP256, ProjectivePoint};
+use sha2::{Digest, Sha256, Sha512};
+use core::marker::PhantomData;
+use zeroize::{Zeroize, Zeroizing};
+
+type Scalar<S> = <<S as EcvrfSuite>::Group as Group>::Scalar;
+
+/// The length in bytes of the challenge `c` in a proof (`cLen` in RFC 9381).
+const CHALLENGE_SIZE: usize = 16;
+
+// ------------------- Suites -------------------
+// ------------------- 套件 -------------------
+
+/// An ECVRF suite: a group, a hash function and the key conversions of RFC 9381.
+///
+/// 一个 ECVRF 套件：一个群、一个哈希函数以及 RFC 9381 的密钥转换。
+pub trait EcvrfSuite: Clone + core::fmt::Debug + Default + Send + Sync + 'static {
+    /// The ECC parameters of the keys the suite uses.
+    ///
+    /// 套件所用密钥的 ECC 参数。
+    type Params: EccParams + Clone;
+
+    /// The group the proofs are computed in.
+    ///
+    /// 计算证明所在的群。
+    type Group: Group<Scalar: Zeroize> + GroupEncoding + ConstantTimeEq;
+
+    /// The single-byte suite string that prefixes every hash input.
+    ///
+    /// 作为每个哈希输入前缀的单字节套件字符串。
+    const SUITE_STRING: u8;
+
+    /// The cofactor of the curve.
+    ///
+    /// 曲线的余因子。
+    const COFACTOR: u64;
+
+    /// Whether scalars are encoded little-endian (Edwards25519) rather than big-endian (P-256).
+    ///
+    /// 标量是否以小端（Edwards25519）而非大端（P-256）编码。
+    const LITTLE_ENDIAN: bool;
+
+    /// The length in bytes of a proof `pi`.
+    ///
+    /// 证明 `pi` 的字节长度。
+    const PROOF_SIZE: usize;
+
+    /// The length in bytes of an output `beta`.
+    ///
+    /// 输出 `beta` 的字节长度。
+    const OUTPUT_SIZE: usize;
+
+    /// Hashes the concatenated `parts` with the suite's hash function.
+    ///
+    /// 使用套件的哈希函数对串联的 `parts` 进行哈希。
+    fn hash(parts: &[&[u8]]) -> Vec<u8>;
+
+    /// Returns the secret scalar `x` of a private key.
+    ///
+    /// 返回私钥的秘密标量 `x`。
+    fn secret_scalar(
+        private_key: &EccPrivateKey<Self::Params>,
+    ) -> Result<Zeroizing<Scalar<Self>>, Error>;
+
+    /// Returns the encoding `PK_string` of a public key.
+    ///
+    /// 返回公钥的编码 `PK_string`。
+    fn public_key_string(public_key: &EccPublicKey<Self::Params>) -> Result<Vec<u8>, Error>;
+
+    /// Interprets a hash as a point, or returns `None` if it is not the encoding of one
+    /// (`interpret_hash_value_as_a_point` in RFC 9381).
+    ///
+    /// 将哈希解释为一个点，如果它不是某个点的编码则返回 `None`（RFC 9381 中的
+    /// `interpret_hash_value_as_a_point`）。
+    fn hash_to_point(hash: &[u8]) -> Option<Self::Group>;
+
+    /// Derives the nonce of a proof from the private key and the encoded point `h_string`.
+    ///
+    /// 从私钥和编码点 `h_string` 派生证明的 nonce。
+    fn nonce(
+        private_key: &EccPrivateKey<Self::Params>,
+        h_string: &[u8],
+    ) -> Result<Zeroizing<Scalar<Self>>, Error>;
+}
+
+/// The `ECVRF-P256-SHA256-TAI` suite.
+///
+/// `ECVRF-P256-SHA256-TAI` 套件。
+#[derive(Clone, Debug, Default)]
+pub struct P256Sha256Tai;
+
+impl EcvrfSuite for P256Sha256Tai {
+    type Params = EcdsaP256Params;
+    type Group = ProjectivePoint;
+
+    const SUITE_STRING: u8 = 0x01;
+    const COFACTOR: u64 = 1;
+    const LITTLE_ENDIAN: bool = false;
+    const PROOF_SIZE: usize = 81;
+    const OUTPUT_SIZE: usize = 32;
+
+    fn hash(parts: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize().to_vec()
+    }
+
+    fn secret_scalar(
+        private_key: &EccPrivateKey<EcdsaP256Params>,
+    ) -> Result<Zeroizing<p256::Scalar>, Error> {
+        let secret = private_key.to_raw_bytes()?;
+        let repr = p256::FieldBytes::from_exact_iter(secret.iter().copied())
+            .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        Option::<p256::Scalar>::from(p256::Scalar::from_repr(repr))
+            .map(Zeroizing::new)
+            .ok_or(Error::Key(KeyError::InvalidEncoding))
+    }
+
+    fn public_key_string(public_key: &EccPublicKey<EcdsaP256Params>) -> Result<Vec<u8>, Error> {
+        public_key.to_sec1_bytes(true)
+    }
+
+    fn hash_to_point(hash: &[u8]) -> Option<ProjectivePoint> {
+        let mut repr = <ProjectivePoint as GroupEncoding>::Repr::default();
+        repr[0] = 0x02;
+        repr[1..].copy_from_slice(hash.get(..32)?);
+        ProjectivePoint::from_bytes(&repr).into()
+    }
+
+    /// RFC 6979 with SHA-256, as section 5.4.2.1 of RFC 9381 specifies.
+    fn nonce(
+        private_key: &EccPrivateKey<EcdsaP256Params>,
+        h_string: &[u8],
+    ) -> Result<Zeroizing<p256::Scalar>, Error> {
+        let x = Zeroizing::new(Self::secret_scalar(private_key)?.to_repr());
+        let h = <p256::Scalar as Reduce<p256::U256>>::reduce_bytes(&Sha256::digest(h_string));
+        let k = Zeroizing::new(rfc6979::generate_k::<Sha256, _>(
+            &x,
+            &NistP256::ORDER.to_be_byte_array(),
+            &h.to_repr(),
+            &[],
+        ));
+        Option::<p256::Scalar>::from(p256::Scalar::from_repr(*k))
+            .map(Zeroizing::new)
+            .ok_or(Error::Signature(SignatureError::Signing))
+    }
+}
+
+/// The `ECVRF-EDWARDS25519-SHA512-TAI` suite.
+///
+/// `ECVRF-EDWARDS25519-SHA512-TAI` 套件。
+#[derive(Clone, Debug, Default)]
+pub struct Edwards25519Sha512Tai;
+
+impl EcvrfSuite for Edwards25519Sha512Tai {
+    type Params = Ed25519Params;
+    type Group = EdwardsPoint;
+
+    const SUITE_STRING: u8 = 0x03;
+    const COFACTOR: u64 = 8;
+    const LITTLE_ENDIAN: bool = true;
+    const PROOF_SIZE: usize = 80;
+    const OUTPUT_SIZE: usize = 64;
+
+    fn hash(parts: &[&[u8]]) -> Vec<u8> {
+        let mut hasher = Sha512::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize().to_vec()
+    }
+
+    /// The clamped first half of the SHA-512 hash of the seed, as in Ed25519 (RFC 8032,
+    /// section 5.1.5).
+    fn secret_scalar(
+        private_key: &EccPrivateKey<Ed25519Params>,
+    ) -> Result<Zeroizing<Ed25519Scalar>, Error> {
+        let hash = expand_seed(private_key)?;
+        let mut scalar_bytes = Zeroizing::new([0u8; 32]);
+        scalar_bytes.copy_from_slice(&hash[..32]);
+        scalar_bytes[0] &= 248;
+        scalar_bytes[31] &= 127;
+        scalar_bytes[31] |= 64;
+        Ok(Zeroizing::new(Ed25519Scalar::from_bytes_mod_order(
+            *scalar_bytes,
+        )))
+    }
+
+    fn public_key_string(public_key: &EccPublicKey<Ed25519Params>) -> Result<Vec<u8>, Error> {
+        let verifying_key =
+            ed25519_dalek::VerifyingKey::from_public_key_der(&public_key.to_bytes()?)
+                .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        Ok(verifying_key.as_bytes().to_vec())
+    }
+
+    fn hash_to_point(hash: &[u8]) -> Option<EdwardsPoint> {
+        let mut repr = <EdwardsPoint as GroupEncoding>::Repr::default();
+        repr.copy_from_slice(hash.get(..32)?);
+        EdwardsPoint::from_bytes(&repr).into()
+    }
+
+    /// The hash of the second half of the expanded seed and `h_string`, as section 5.4.2.2 of
+    /// RFC 9381 specifies.
+    fn nonce(
+        private_key: &EccPrivateKey<Ed25519Params>,
+        h_string: &[u8],
+    ) -> Result<Zeroizing<Ed25519Scalar>, Error> {
+        let hash = expand_seed(private_key)?;
+        let mut wide = Zeroizing::new([0u8; 64]);
+        wide.copy_from_slice(&Self::hash(&[&hash[32..], h_string]));
+        Ok(Zeroizing::new(Ed25519Scalar::from_bytes_mod_order_wide(
+            &wide,
+        )))
+    }
+}
+
+/// Hashes an Ed25519 seed with SHA-512.
+fn expand_seed(private_key: &EccPrivateKey<Ed25519Params>) -> Result<Zeroizing<[u8; 64]>, Error> {
+    let seed = private_key.to_raw_bytes()?;
+    let mut hash = Zeroizing::new([0u8; 64]);
+    hash.copy_from_slice(&Sha512::digest(seed.as_slice()));
+    Ok(hash)
+}
+
+// ------------------- ECVRF -------------------
+// ------------------- ECVRF -------------------
+
+/// The ECVRF verifiable random function over the suite `S`.
+///
+/// 基于套件 `S` 的 ECVRF 可验证随机函数。
+#[derive(Clone, Debug, Default)]
+pub struct Ecvrf<S> {
+    _suite: PhantomData<S>,
+}
+
+impl<S: EcvrfSuite> Ecvrf<S> {
+    /// The length in bytes of a proof: 81 over P-256 and 80 over Edwards25519.
+    ///
+    /// 证明的字节长度：P-256 上为 81，Edwards25519 上为 80。
+    pub const PROOF_SIZE: usize = S::PROOF_SIZE;
+
+    /// The length in bytes of an output: 32 over P-256 and 64 over Edwards25519.
+    ///
+    /// 输出的字节长度：P-256 上为 32，Edwards25519 上为 64。
+    pub const OUTPUT_SIZE: usize = S::OUTPUT_SIZE;
+
+    /// Computes the proof `pi` for the input `alpha` under `private_key`.
+    ///
+    /// Fails with `SignatureError::Signing` in the negligibly unlikely case that `alpha` cannot
+    /// be hashed to the curve.
+    ///
+    /// 在 `private_key` 下为输入 `alpha` 计算证明 `pi`。
+    ///
+    /// 在 `alpha` 无法被哈希到曲线上这一可能性可忽略的情况下，以 `SignatureError::Signing`
+    /// 失败。
+    pub fn prove(private_key: &EccPrivateKey<S::Params>, alpha: &[u8]) -> Result<Vec<u8>, Error> {
+        let x = S::secret_scalar(private_key)?;
+        let public_key = S::Group::generator() * *x;
+        let public_key_string = public_key.to_bytes().as_ref().to_vec();
+
+        let h = encode_to_curve::<S>(&public_key_string, alpha)
+            .ok_or(Error::Signature(SignatureError::Signing))?;
+        let h_string = h.to_bytes().as_ref().to_vec();
+        let gamma = h * *x;
+        let k = S::nonce(private_key, &h_string)?;
+        let (c_bytes, c) = challenge::<S>(&[
+            &public_key,
+            &h,
+            &gamma,
+            &(S::Group::generator() * *k),
+            &(h * *k),
+        ]);
+        let s = *k + c * *x;
+
+        let mut proof = Vec::with_capacity(S::PROOF_SIZE);
+        proof.extend_from_slice(gamma.to_bytes().as_ref());
+        proof.extend_from_slice(&c_bytes);
+        proof.extend_from_slice(s.to_repr().as_ref());
+        Ok(proof)
+    }
+
+    /// Verifies the proof `pi` for the input `alpha` under `public_key` and returns the output
+    /// `beta`.
+    ///
+    /// Fails with `SignatureError::InvalidSignature` if the proof is malformed, with
+    /// `KeyError::InvalidEncoding` if the public key is malformed or of small order, and with
+    /// `SignatureError::Verification` if the proof is invalid.
+    ///
+    /// 在 `public_key` 下验证输入 `alpha` 的证明 `pi` 并返回输出 `beta`。
+    ///
+    /// 如果证明格式错误，则以 `SignatureError::InvalidSignature` 失败；如果公钥格式错误或为
+    /// 小阶，则以 `KeyError::InvalidEncoding` 失败；如果证明无效，则以
+    /// `SignatureError::Verification` 失败。
+    pub fn verify(
+        public_key: &EccPublicKey<S::Params>,
+        alpha: &[u8],
+        proof: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let public_key_string = S::public_key_string(public_key)?;
+        let y = parse_point::<S>(&public_key_string)
+            .filter(|y| !bool::from((*y * cofactor::<S>()).is_identity()))
+            .ok_or(Error::Key(KeyError::InvalidEncoding))?;
+        let DecodedProof {
+            gamma,
+            c_bytes,
+            c,
+            s,
+        } = decode_proof::<S>(proof)?;
+
+        let h = encode_to_curve::<S>(&public_key_string, alpha)
+            .ok_or(Error::Signature(SignatureError::Verification))?;
+        let u = S::Group::generator() * s - y * c;
+        let v = h * s - gamma * c;
+        let (expected, _) = challenge::<S>(&[&y, &h, &gamma, &u, &v]);
+        if bool::from(expected.as_slice().ct_eq(c_bytes.as_slice())) {
+            Ok(gamma_to_hash::<S>(&gamma))
+        } else {
+            Err(Error::Signature(SignatureError::Verification))
+        }
+    }
+
+    /// Returns the output `beta` of a proof without verifying it.
+    ///
+    /// Only the prover should use this, on proofs it computed itself; verifiers must use the
+    /// output of [`Ecvrf::verify`]. Fails with `SignatureError::InvalidSignature` if the proof
+    /// is malformed.
+    ///
+    /// 返回证明的输出 `beta` 而不验证它。
+    ///
+    /// 只有证明者应当在其自己计算的证明上使用此函数；验证者必须使用 [`Ecvrf::verify`] 的输出。
+    /// 如果证明格式错误，则以 `SignatureError::InvalidSignature` 失败。
+    pub fn proof_to_hash(proof: &[u8]) -> Result<Vec<u8>, Error> {
+        Ok(gamma_to_hash::<S>(&decode_proof::<S>(proof)?.gamma))
+    }
+}
+
+/// ECVRF over P-256 with SHA-256 (`ECVRF-P256-SHA256-TAI`), with the keys of `EcdsaP256`.
+///
+/// 使用 SHA-256 的基于 P-256 的 ECVRF（`ECVRF-P256-SHA256-TAI`），使用 `EcdsaP256` 的密钥。
+pub type EcvrfP256 = Ecvrf<P256Sha256Tai>;
+
+/// ECVRF over Edwards25519 with SHA-512 (`ECVRF-EDWARDS25519-SHA512-TAI`), with the keys of
+/// `Ed25519`.
+///
+/// 使用 SHA-512 的基于 Edwards25519 的 ECVRF（`ECVRF-EDWARDS25519-SHA512-TAI`），使用
+/// `Ed25519` 的密钥。
+pub type EcvrfEdwards25519 = Ecvrf<Edwards25519Sha512Tai>;
+
+// ------------------- Helpers -------------------
+// ------------------- 辅助函数 -------------------
+
+fn cofactor<S: EcvrfSuite>() -> Scalar<S> {
+    Scalar::<S>::from(S::COFACTOR)
+}
+
+fn parse_point<S: EcvrfSuite>(bytes: &[u8]) -> Option<S::Group> {
+    let mut repr = <S::Group as GroupEncoding>::Repr::default();
+    if bytes.len() != repr.as_ref().len() {
+        return None;
+    }
+    repr.as_mut().copy_from_slice(bytes);
+    S::Group::from_bytes(&repr).into()
+}
+
+/// encode_to_curve_try_and_increment of RFC 9381, section 5.4.1.1.
+fn encode_to_curve<S: EcvrfSuite>(public_key_string: &[u8], alpha: &[u8]) -> Option<S::Group> {
+    (0..=u8::MAX).find_map(|counter| {
+        let hash = S::hash(&[
+            &[S::SUITE_STRING, 0x01],
+            public_key_string,
+            alpha,
+            &[counter, 0x00],
+        ]);
+        S::hash_to_point(&hash).map(|point| point * cofactor::<S>())
+    })
+}
+
+/// challenge_generation of RFC 9381, section 5.4.3, returning the truncated hash and its value
+/// as a scalar.
+fn challenge<S: EcvrfSuite>(points: &[&S::Group]) -> ([u8; CHALLENGE_SIZE], Scalar<S>) {
+    let encoded: Vec<_> = points.iter().map(|point| point.to_bytes()).collect();
+    let mut parts: Vec<&[u8]> = Vec::with_capacity(points.len() + 2);
+    parts.push(&[S::SUITE_STRING, 0x02]);
+    parts.extend(encoded.iter().map(|point| point.as_ref()));
+    parts.push(&[0x00]);
+
+    let mut c_bytes = [0u8; CHALLENGE_SIZE];
+    c_bytes.copy_from_slice(&S::hash(&parts)[..CHALLENGE_SIZE]);
+    (c_bytes, challenge_scalar::<S>(&c_bytes))
+}
+
+/// Converts a challenge to a scalar; it is always below the group order.
+fn challenge_scalar<S: EcvrfSuite>(c_bytes: &[u8; CHALLENGE_SIZE]) -> Scalar<S> {
+    let mut repr = <Scalar<S> as PrimeField>::Repr::default();
+    let repr_bytes = repr.as_mut();
+    if S::LITTLE_ENDIAN {
+        repr_bytes[..CHALLENGE_SIZE].copy_from_slice(c_bytes);
+    } else {
+        let offset = repr_bytes.len() - CHALLENGE_SIZE;
+        repr_bytes[offset..].copy_from_slice(c_bytes);
+    }
+    Option::from(Scalar::<S>::from_repr(repr)).unwrap_or(Scalar::<S>::ZERO)
+}
+
+/// The parts of a proof `pi = Gamma || c || s`.
+struct DecodedProof<S: EcvrfSuite> {
+    gamma: S::Group,
+    c_bytes: [u8; CHALLENGE_SIZE],
+    c: Scalar<S>,
+    s: Scalar<S>,
+}
+
+/// decode_proof of RFC 9381, section 5.4.4.
+fn decode_proof<S: EcvrfSuite>(proof: &[u8]) -> Result<DecodedProof<S>, Error> {
+    let invalid = || Error::Signature(SignatureError::InvalidSignature);
+    if proof.len() != S::PROOF_SIZE {
+        return Err(invalid());
+    }
+    let point_size = <S::Group as GroupEncoding>::Repr::default().as_ref().len();
+    let (gamma, rest) = proof.split_at(point_size);
+    let (c, s) = rest.split_at(CHALLENGE_SIZE);
+
+    let gamma = parse_point::<S>(gamma).ok_or_else(invalid)?;
+    let mut c_bytes = [0u8; CHALLENGE_SIZE];
+    c_bytes.copy_from_slice(c);
+    let mut s_repr = <Scalar<S> as PrimeField>::Repr::default();
+    s_repr.as_mut().copy_from_slice(s);
+    let s = Option::from(Scalar::<S>::from_repr(s_repr)).ok_or_else(invalid)?;
+    Ok(DecodedProof {
+        gamma,
+        c_bytes,
+        c: challenge_scalar::<S>(&c_bytes),
+        s,
+    })
+}
+
+/// The hash of `cofactor * Gamma` that forms the output (RFC 9381, section 5.2).
+fn gamma_to_hash<S: EcvrfSuite>(gamma: &S::Group) -> Vec<u8> {
+    let point = (*gamma * cofactor::<S>()).to_bytes();
+    S::hash(&[&[S::SUITE_STRING, 0x03], point.as_ref(), &[0x00]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+    use crate::systems::test_rng::SeededRng;
+
+    /// Checks one RFC 9381 test vector: the proof, the output and the verification.
+    fn check_vector<S: EcvrfSuite>(
+        secret: &str,
+        alpha: &str,
+        proof: &str,
+        output: &str,
+    ) -> Result<(), Error> {
+        let private_key =
+            EccPrivateKey::<S::Params>::from_raw_bytes(&hex::decode(secret).unwrap_or_default())?;
+        let public_key = EccPublicKey::<S::Params>::from_bytes(&public_key_of(&private_key)?)?;
+        let alpha = hex::decode(alpha).unwrap_or_default();
+
+        let pi = Ecvrf::<S>::prove(&private_key, &alpha)?;
+        assert_eq!(hex::encode(&pi), proof);
+        assert_eq!(hex::encode(Ecvrf::<S>::proof_to_hash(&pi)?), output);
+        assert_eq!(
+            hex::encode(Ecvrf::<S>::verify(&public_key, &alpha, &pi)?),
+            output
+        );
+        Ok(())
+    }
+
+    /// Derives the DER public key of a private key through a key pair with the same secret.
+    fn public_key_of<P: EccParams>(private_key: &EccPrivateKey<P>) -> Result<Vec<u8>, Error> {
+        let (public_key, _) = P::keypair_from_secret(&private_key.to_raw_bytes()?)?;
+        Ok(public_key)
+    }
+
+    #[test]
+    fn test_rfc9381_vectors() -> Result<(), Error> {
+        // ECVRF-P256-SHA256-TAI, appendix B.1, examples 10 and 11.
+        // ECVRF-P256-SHA256-TAI，附录 B.1，示例 10 和 11。
+        let p256_secret = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+        check_vector::<P256Sha256Tai>(
+            p256_secret,
+            "73616d706c65",
+            "035b5c726e8c0e2c488a107c600578ee75cb702343c153cb1eb8dec77f4b5071b4a53f0a46f018bc2c56e58d383f2305e0975972c26feea0eb122fe7893c15af376b33edf7de17c6ea056d4d82de6bc02f",
+            "a3ad7b0ef73d8fc6655053ea22f9bede8c743f08bbed3d38821f0e16474b505e",
+        )?;
+        check_vector::<P256Sha256Tai>(
+            p256_secret,
+            "74657374",
+            "034dac60aba508ba0c01aa9be80377ebd7562c4a52d74722e0abae7dc3080ddb56c19e067b15a8a8174905b13617804534214f935b94c2287f797e393eb0816969d864f37625b443f30f1a5a33f2b3c854",
+            "a284f94ceec2ff4b3794629da7cbafa49121972671b466cab4ce170aa365f26d",
+        )?;
+
+        // ECVRF-EDWARDS25519-SHA512-TAI, appendix B.3, examples 16 and 17.
+        // ECVRF-EDWARDS25519-SHA512-TAI，附录 B.3，示例 16 和 17。
+        check_vector::<Edwards25519Sha512Tai>(
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "",
+            "8657106690b5526245a92b003bb079ccd1a92130477671f6fc01ad16f26f723f26f8a57ccaed74ee1b190bed1f479d9727d2d0f9b005a6e456a35d4fb0daab1268a1b0db10836d9826a528ca76567805",
+            "90cf1df3b703cce59e2a35b925d411164068269d7b2d29f3301c03dd757876ff66b71dda49d2de59d03450451af026798e8f81cd2e333de5cdf4f3e140fdd8ae",
+        )?;
+        check_vector::<Edwards25519Sha512Tai>(
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "72",
+            "f3141cd382dc42909d19ec5110469e4feae18300e94f304590abdced48aed5933bf0864a62558b3ed7f2fea45c92a465301b3bbf5e3e54ddf2d935be3b67926da3ef39226bbc355bdc9850112c8f4b02",
+            "eb4440665d3891d668e7e0fcaf587f1b4bd7fbfe99d0eb2211ccec90496310eb5e33821bc613efb94db5e5b54c70a848a0bef4553a41befc57663b56373a5031",
+        )
+    }
+
+    fn run_roundtrip<S: EcvrfSuite>(
+        public_key: &EccPublicKey<S::Params>,
+        private_key: &EccPrivateKey<S::Params>,
+        other_public_key: &EccPublicKey<S::Params>,
+    ) -> Result<(), Error> {
+        let alpha = b"block 1024";
+        let proof = Ecvrf::<S>::prove(private_key, alpha)?;
+        assert_eq!(proof.len(), Ecvrf::<S>::PROOF_SIZE);
+        assert_eq!(proof, Ecvrf::<S>::prove(private_key, alpha)?);
+
+        let output = Ecvrf::<S>::verify(public_key, alpha, &proof)?;
+        assert_eq!(output.len(), Ecvrf::<S>::OUTPUT_SIZE);
+        assert_eq!(output, Ecvrf::<S>::proof_to_hash(&proof)?);
+        assert_ne!(
+            output,
+            Ecvrf::<S>::proof_to_hash(&Ecvrf::<S>::prove(private_key, b"block 1025")?)?
+        );
+
+        // Wrong input, wrong key and altered proofs are rejected.
+        // 错误的输入、错误的密钥和被篡改的证明会被拒绝。
+        assert!(matches!(
+            Ecvrf::<S>::verify(public_key, b"block 1025", &proof),
+            Err(Error::Signature(SignatureError::Verification))
+        ));
+        assert!(matches!(
+            Ecvrf::<S>::verify(other_public_key, alpha, &proof),
+            Err(Error::Signature(SignatureError::Verification))
+        ));
+        let gamma_size = proof.len() - CHALLENGE_SIZE - 32;
+        let mut altered = proof.clone();
+        altered[gamma_size] ^= 1;
+        assert!(matches!(
+            Ecvrf::<S>::verify(public_key, alpha, &altered),
+            Err(Error::Signature(SignatureError::Verification))
+        ));
+        assert!(matches!(
+            Ecvrf::<S>::verify(public_key, alpha, &proof[1..]),
+            Err(Error::Signature(SignatureError::InvalidSignature))
+        ));
+        // An s that is not below the group order is malformed.
+        // 不小于群阶的 s 是格式错误的。
+        let mut altered = proof.clone();
+        altered[gamma_size + CHALLENGE_SIZE..].fill(0xff);
+        assert!(matches!(
+            Ecvrf::<S>::verify(public_key, alpha, &altered),
+            Err(Error::Signature(SignatureError::InvalidSignature))
+        ));
+        assert!(matches!(
+            Ecvrf::<S>::proof_to_hash(&altered),
+            Err(Error::Signature(SignatureError::InvalidSignature))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ecvrf_p256() -> Result<(), Error> {
+        let mut rng = SeededRng(1);
+        let (public_key, private_key) = EcdsaP256::generate_keypair_with_rng(&mut rng)?;
+        let (other_public_key, _) = EcdsaP256::generate_keypair_with_rng(&mut rng)?;
+        run_roundtrip::<P256Sha256Tai>(&public_key, &private_key, &other_public_key)
+    }
+
+    #[test]
+    fn test_ecvrf_edwards25519() -> Result<(), Error> {
+        let mut rng = SeededRng(2);
+        let (public_key, private_key) = Ed25519::generate_keypair_with_rng(&mut rng)?;
+        let (other_public_key, _) = Ed25519::generate_keypair_with_rng(&mut rng)?;
+        run_roundtrip::<Edwards25519Sha512Tai>(&public_key, &private_key, &other_public_key)
+    }
+
+    #[test]
+    fn test_rejects_small_order_key() -> Result<(), Error> {
+        use ed25519_dalek::pkcs8::EncodePublicKey;
+
+        // The identity point has small order; any proof under it is rejected.
+        // 单位点为小阶；其下的任何证明都会被拒绝。
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&identity)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        let der = verifying_key
+            .to_public_key_der()
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        let public_key = EccPublicKey::<Ed25519Params>::from_bytes(der.as_bytes())?;
+        let proof = [0u8; 80];
+        assert!(matches!(
+            EcvrfEdwards25519::verify(&public_key, b"alpha", &proof),
+            Err(Error::Key(KeyError::InvalidEncoding))
+        ));
+        Ok(())
+    }
+}
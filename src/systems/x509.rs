@@ -0,0 +1,21 @@
+//! X.509 public key infrastructure implementations.
+//!
+//! This module provides the X.509 structures needed to get keys of this crate's signature
+//! schemes certified, without exporting them to another tool first.
+//!
+//! # Available Implementations
+//! - **Certificate**: PKCS#10 certificate signing requests (RFC 2986) and self-signed X.509 v3
+//!   certificates (RFC 5280)
+//!
+//! X.509 公钥基础设施实现。
+//!
+//! 此模块提供为本 crate 签名方案的密钥获取认证所需的 X.509 结构，而无需先将密钥导出到其他工具。
+//!
+//! # 可用实现
+//! - **Certificate**: PKCS#10 证书签名请求 (RFC 2986) 和自签名 X.509 v3 证书 (RFC 5280)
+
+/// X.509 certificate signing request and certificate implementation.
+///
+/// X.509 证书签名请求和证书实现。
+#[cfg(feature = "x509-default")]
+pub mod certificate;
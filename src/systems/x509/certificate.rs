@@ -0,0 +1,1042 @@
+//! Provides X.509 certificate signing requests and self-signed certificates.
+//!
+//! [`CertificateRequestBuilder`] creates PKCS#10 certificate signing requests (RFC 2986) for a
+//! key pair of one of the crate's signature schemes, so a key never has to be exported to
+//! another tool to be certified, and [`CertificateRequest`] parses and verifies requests made
+//! elsewhere. [`CertificateBuilder`] issues minimal self-signed X.509 v3 certificates
+//! (RFC 5280), e.g. for development, pinned service identities or private roots.
+//!
+//! # Signature Algorithms
+//! - **ECDSA P-256**: `ecdsa-with-SHA256` ([`EcdsaP256`](crate::systems::asymmetric::traditional::ecc::EcdsaP256))
+//! - **Ed25519**: `id-Ed25519` (RFC 8410)
+//! - **RSA-PSS**: `id-RSASSA-PSS` with SHA-256, SHA-384 or SHA-512, MGF1 with the same hash
+//!   and a salt as long as the hash (RFC 4055), for the RSA schemes of those hashes
+//!
+//! Further schemes, such as ML-DSA once its certificate encoding is available, are added by
+//! implementing [`X509Scheme`] for them; the round 3 Dilithium schemes have no registered
+//! object identifiers and are not supported.
+//!
+//! # Names
+//! Subjects are given as RFC 4514 strings, most specific attribute first, e.g.
+//! `"CN=api.example.com,O=Example"`. Subject alternative names can hold DNS names and IP
+//! addresses.
+//!
+//! 提供了 X.509 证书签名请求和自签名证书。
+//!
+//! [`CertificateRequestBuilder`] 为本 crate 某个签名方案的密钥对创建 PKCS#10 证书签名请求
+//! (RFC 2986)，因此无需为了认证密钥而将其导出到其他工具；[`CertificateRequest`] 解析并验证
+//! 在其他地方生成的请求。[`CertificateBuilder`] 签发最小化的自签名 X.509 v3 证书 (RFC 5280)，
+//! 例如用于开发、固定的服务身份或私有根证书。
+//!
+//! # 签名算法
+//! - **ECDSA P-256**: `ecdsa-with-SHA256` ([`EcdsaP256`](crate::systems::asymmetric::traditional::ecc::EcdsaP256))
+//! - **Ed25519**: `id-Ed25519` (RFC 8410)
+//! - **RSA-PSS**: 使用 SHA-256、SHA-384 或 SHA-512、基于同一哈希的 MGF1 以及与哈希等长的盐的
+//!   `id-RSASSA-PSS` (RFC 4055)，适用于这些哈希的 RSA 方案
+//!
+//! 更多方案（例如在其证书编码可用后的 ML-DSA）通过为其实现 [`X509Scheme`] 来添加；第 3 轮
+//! Dilithium 方案没有注册的对象标识符，因此不受支持。
+//!
+//! # 名称
+//! 主体以 RFC 4514 字符串给出，最具体的属性在前，例如 `"CN=api.example.com,O=Example"`。
+//! 主体备用名称可以包含 DNS 名称和 IP 地址。
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::errors::Error;
+use crate::prelude::*;
+use crate::rng::SystemRng;
+use rand_core_elliptic_curve::RngCore;
+use core::net::IpAddr;
+use core::str::FromStr;
+use core::time::Duration;
+use x509_cert::Certificate as X509Certificate;
+use x509_cert::certificate::{TbsCertificate, Version};
+use x509_cert::der::asn1::{BitString, GeneralizedTime, Ia5String, OctetString, UtcTime};
+use x509_cert::der::oid::AssociatedOid;
+use x509_cert::der::pem::LineEnding;
+use x509_cert::der::{Decode, DecodePem, Encode, EncodePem};
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::pkix::{
+    BasicConstraints, KeyUsage, KeyUsages, SubjectAltName, SubjectKeyIdentifier,
+};
+use x509_cert::ext::{AsExtension, Extension};
+use x509_cert::name::Name;
+use x509_cert::request::{CertReq, CertReqInfo, ExtensionReq};
+use x509_cert::serial_number::SerialNumber;
+use x509_cert::spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+use x509_cert::time::{Time, Validity};
+
+/// The length in bytes of generated serial numbers.
+const SERIAL_NUMBER_SIZE: usize = 16;
+
+/// The length in bytes of subject key identifiers (RFC 7093, Section 2, method 1).
+const KEY_IDENTIFIER_SIZE: usize = 20;
+
+fn invalid_encoding() -> Error {
+    Error::Key(KeyError::InvalidEncoding)
+}
+
+// ------------------- Signature Algorithms -------------------
+// ------------------- 签名算法 -------------------
+
+/// A trait for signature schemes that can sign X.509 certificates and requests.
+///
+/// 用于可以签名 X.509 证书和请求的签名方案的 trait。
+pub trait X509Scheme: private::X509Signature {}
+
+mod private {
+    use super::*;
+
+    /// The X.509 encodings of a signature scheme.
+    pub trait X509Signature: Signer + Verifier {
+        /// Returns the `AlgorithmIdentifier` of signatures made by the scheme.
+        fn signature_algorithm() -> Result<AlgorithmIdentifierOwned, Error>;
+
+        /// Encodes a signature as the contents of the `signature` BIT STRING.
+        fn encode_signature(signature: Signature) -> Result<Vec<u8>, Error> {
+            Ok(signature)
+        }
+
+        /// Decodes a signature from the contents of the `signature` BIT STRING.
+        fn decode_signature(bytes: &[u8]) -> Result<Signature, Error> {
+            Ok(bytes.to_vec())
+        }
+
+        /// Converts a public key into a `SubjectPublicKeyInfo`.
+        fn to_spki(public_key: &Self::PublicKey) -> Result<SubjectPublicKeyInfoOwned, Error> {
+            SubjectPublicKeyInfoOwned::from_der(&public_key.to_bytes()?)
+                .map_err(|_| invalid_encoding())
+        }
+
+        /// Converts a `SubjectPublicKeyInfo` into a public key.
+        fn from_spki(spki: &SubjectPublicKeyInfoOwned) -> Result<Self::PublicKey, Error> {
+            Self::PublicKey::from_bytes(&spki.to_der().map_err(|_| invalid_encoding())?)
+        }
+    }
+}
+
+#[cfg(feature = "ecc-default")]
+mod ecc {
+    use super::*;
+    use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+    use p256::ecdsa::Signature as P256Signature;
+    use x509_cert::der::oid::ObjectIdentifier;
+
+    const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+    const ID_ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+    // X.509 carries ECDSA signatures as a DER `Ecdsa-Sig-Value`, whereas the scheme uses the
+    // fixed-size `r || s` encoding.
+    // X.509 以 DER `Ecdsa-Sig-Value` 承载 ECDSA 签名，而该方案使用固定长度的 `r || s` 编码。
+    impl private::X509Signature for EcdsaP256 {
+        fn signature_algorithm() -> Result<AlgorithmIdentifierOwned, Error> {
+            Ok(AlgorithmIdentifierOwned {
+                oid: ECDSA_WITH_SHA256,
+                parameters: None,
+            })
+        }
+
+        fn encode_signature(signature: Signature) -> Result<Vec<u8>, Error> {
+            let signature = P256Signature::from_slice(&signature)
+                .map_err(|_| Error::Signature(SignatureError::Signing))?;
+            Ok(signature.to_der().as_bytes().to_vec())
+        }
+
+        fn decode_signature(bytes: &[u8]) -> Result<Signature, Error> {
+            let signature = P256Signature::from_der(bytes)
+                .map_err(|_| Error::Signature(SignatureError::InvalidSignature))?;
+            Ok(signature.to_bytes().to_vec())
+        }
+    }
+
+    impl X509Scheme for EcdsaP256 {}
+
+    impl private::X509Signature for Ed25519 {
+        fn signature_algorithm() -> Result<AlgorithmIdentifierOwned, Error> {
+            Ok(AlgorithmIdentifierOwned {
+                oid: ID_ED25519,
+                parameters: None,
+            })
+        }
+    }
+
+    impl X509Scheme for Ed25519 {}
+}
+
+#[cfg(feature = "rsa-default")]
+mod rsa_pss {
+    use super::*;
+    use crate::systems::asymmetric::traditional::rsa::{RsaKeyParams, RsaScheme};
+
+    macro_rules! impl_rsa_pss {
+        ($hasher:ty, $digest:ty) => {
+            impl<KP: RsaKeyParams> private::X509Signature for RsaScheme<KP, $hasher> {
+                fn signature_algorithm() -> Result<AlgorithmIdentifierOwned, Error> {
+                    ::rsa::pss::get_default_pss_signature_algo_id::<$digest>()
+                        .map_err(|_| invalid_encoding())
+                }
+            }
+
+            impl<KP: RsaKeyParams> X509Scheme for RsaScheme<KP, $hasher> {}
+        };
+    }
+
+    impl_rsa_pss!(Sha256, ::sha2::Sha256);
+    impl_rsa_pss!(Sha384, ::sha2::Sha384);
+    impl_rsa_pss!(Sha512, ::sha2::Sha512);
+}
+
+/// Signs the DER encoding of `tbs` with `S`, returning the signature BIT STRING.
+fn sign<S: X509Scheme, T: Encode>(
+    private_key: &S::PrivateKey,
+    tbs: &T,
+) -> Result<BitString, Error> {
+    let signature = S::sign(private_key, &tbs.to_der().map_err(|_| invalid_encoding())?)?;
+    BitString::from_bytes(&S::encode_signature(signature)?).map_err(|_| invalid_encoding())
+}
+
+/// Verifies the signature of `tbs` made with `S` by `public_key`.
+fn verify<S: X509Scheme, T: Encode>(
+    public_key: &S::PublicKey,
+    tbs: &T,
+    algorithm: &AlgorithmIdentifierOwned,
+    signature: &BitString,
+) -> Result<(), Error> {
+    if *algorithm != S::signature_algorithm()? {
+        return Err(Error::Key(KeyError::UnsupportedAlgorithm));
+    }
+    let signature = signature
+        .as_bytes()
+        .ok_or(Error::Signature(SignatureError::InvalidSignature))?;
+    S::verify(
+        public_key,
+        &tbs.to_der().map_err(|_| invalid_encoding())?,
+        &S::decode_signature(signature)?,
+    )
+}
+
+// ------------------- Names -------------------
+// ------------------- 名称 -------------------
+
+fn parse_name(name: &str) -> Result<Name, Error> {
+    Name::from_str(name).map_err(|_| invalid_encoding())
+}
+
+/// The DNS names and IP addresses of a subject alternative name extension.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct AltNames {
+    dns_names: Vec<String>,
+    ip_addresses: Vec<IpAddr>,
+}
+
+impl AltNames {
+    fn is_empty(&self) -> bool {
+        self.dns_names.is_empty() && self.ip_addresses.is_empty()
+    }
+
+    fn to_extension(&self, subject: &Name) -> Result<Extension, Error> {
+        let dns_names = self.dns_names.iter().map(|name| {
+            Ia5String::new(name)
+                .map(GeneralName::DnsName)
+                .map_err(|_| invalid_encoding())
+        });
+        let ip_addresses = self.ip_addresses.iter().map(|address| {
+            let octets = match address {
+                IpAddr::V4(address) => address.octets().to_vec(),
+                IpAddr::V6(address) => address.octets().to_vec(),
+            };
+            OctetString::new(octets)
+                .map(GeneralName::IpAddress)
+                .map_err(|_| invalid_encoding())
+        });
+        let names = dns_names
+            .chain(ip_addresses)
+            .collect::<Result<Vec<_>, Error>>()?;
+        SubjectAltName(names)
+            .to_extension(subject, &[])
+            .map_err(|_| invalid_encoding())
+    }
+
+    /// Collects the DNS names and IP addresses of the extension in `extensions`, if any.
+    fn from_extensions(extensions: &[Extension]) -> Result<Self, Error> {
+        let mut alt_names = Self::default();
+        for extension in extensions {
+            if extension.extn_id != SubjectAltName::OID {
+                continue;
+            }
+            let names = SubjectAltName::from_der(extension.extn_value.as_bytes())
+                .map_err(|_| invalid_encoding())?;
+            for name in names.0 {
+                match name {
+                    GeneralName::DnsName(name) => alt_names.dns_names.push(name.to_string()),
+                    GeneralName::IpAddress(octets) => {
+                        let address = match octets.as_bytes() {
+                            &[a, b, c, d] => IpAddr::from([a, b, c, d]),
+                            octets => IpAddr::from(
+                                <[u8; 16]>::try_from(octets).map_err(|_| invalid_encoding())?,
+                            ),
+                        };
+                        alt_names.ip_addresses.push(address);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(alt_names)
+    }
+}
+
+// ------------------- Certificate Requests -------------------
+// ------------------- 证书请求 -------------------
+
+/// A builder of PKCS#10 certificate signing requests.
+///
+/// PKCS#10 证书签名请求的构建器。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateRequestBuilder {
+    subject: String,
+    alt_names: AltNames,
+}
+
+impl CertificateRequestBuilder {
+    /// Creates a builder for a request for the RFC 4514 `subject`, e.g. `"CN=api.example.com"`.
+    ///
+    /// 为 RFC 4514 格式的 `subject`（例如 `"CN=api.example.com"`）创建请求构建器。
+    pub fn new(subject: &str) -> Self {
+        Self {
+            subject: subject.to_string(),
+            alt_names: AltNames::default(),
+        }
+    }
+
+    /// Requests a DNS name as a subject alternative name.
+    ///
+    /// 请求将一个 DNS 名称作为主体备用名称。
+    pub fn with_dns_name(mut self, name: &str) -> Self {
+        self.alt_names.dns_names.push(name.to_string());
+        self
+    }
+
+    /// Requests an IP address as a subject alternative name.
+    ///
+    /// 请求将一个 IP 地址作为主体备用名称。
+    pub fn with_ip_address(mut self, address: IpAddr) -> Self {
+        self.alt_names.ip_addresses.push(address);
+        self
+    }
+
+    /// Creates the request for `public_key`, signed with its `private_key`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the subject or a DNS name is malformed.
+    ///
+    /// 为 `public_key` 创建请求，并使用其 `private_key` 签名。
+    ///
+    /// 如果主体或某个 DNS 名称格式错误，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn sign<S: X509Scheme>(
+        &self,
+        public_key: &S::PublicKey,
+        private_key: &S::PrivateKey,
+    ) -> Result<CertificateRequest, Error> {
+        let subject = parse_name(&self.subject)?;
+        let mut attributes = Default::default();
+        if !self.alt_names.is_empty() {
+            let extensions = ExtensionReq(vec![self.alt_names.to_extension(&subject)?]);
+            let attribute = extensions.try_into().map_err(|_| invalid_encoding())?;
+            attributes = vec![attribute].try_into().map_err(|_| invalid_encoding())?;
+        }
+        let info = CertReqInfo {
+            version: x509_cert::request::Version::V1,
+            subject,
+            public_key: S::to_spki(public_key)?,
+            attributes,
+        };
+        let signature = sign::<S, _>(private_key, &info)?;
+        Ok(CertificateRequest {
+            inner: CertReq {
+                info,
+                algorithm: S::signature_algorithm()?,
+                signature,
+            },
+            alt_names: self.alt_names.clone(),
+        })
+    }
+}
+
+/// A PKCS#10 certificate signing request.
+///
+/// PKCS#10 证书签名请求。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateRequest {
+    inner: CertReq,
+    alt_names: AltNames,
+}
+
+impl CertificateRequest {
+    /// Parses a DER-encoded request.
+    ///
+    /// 解析 DER 编码的请求。
+    pub fn from_der(der: &[u8]) -> Result<Self, Error> {
+        Self::new(CertReq::from_der(der).map_err(|_| invalid_encoding())?)
+    }
+
+    /// Parses a PEM-encoded `CERTIFICATE REQUEST`.
+    ///
+    /// 解析 PEM 编码的 `CERTIFICATE REQUEST`。
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        Self::new(CertReq::from_pem(pem).map_err(|_| invalid_encoding())?)
+    }
+
+    fn new(inner: CertReq) -> Result<Self, Error> {
+        let mut extensions = Vec::new();
+        for attribute in inner.info.attributes.iter() {
+            if attribute.oid != ExtensionReq::OID {
+                continue;
+            }
+            for value in attribute.values.iter() {
+                let requested = value
+                    .decode_as::<ExtensionReq>()
+                    .map_err(|_| invalid_encoding())?;
+                extensions.extend(requested.0);
+            }
+        }
+        let alt_names = AltNames::from_extensions(&extensions)?;
+        Ok(Self { inner, alt_names })
+    }
+
+    /// Encodes the request as DER.
+    ///
+    /// 将请求编码为 DER。
+    pub fn to_der(&self) -> Result<Vec<u8>, Error> {
+        self.inner.to_der().map_err(|_| invalid_encoding())
+    }
+
+    /// Encodes the request as a PEM `CERTIFICATE REQUEST`.
+    ///
+    /// 将请求编码为 PEM `CERTIFICATE REQUEST`。
+    pub fn to_pem(&self) -> Result<String, Error> {
+        self.inner
+            .to_pem(LineEnding::LF)
+            .map_err(|_| invalid_encoding())
+    }
+
+    /// Returns the subject as an RFC 4514 string.
+    ///
+    /// 以 RFC 4514 字符串形式返回主体。
+    pub fn subject(&self) -> String {
+        self.inner.info.subject.to_string()
+    }
+
+    /// Returns the requested DNS names.
+    ///
+    /// 返回请求的 DNS 名称。
+    pub fn dns_names(&self) -> &[String] {
+        &self.alt_names.dns_names
+    }
+
+    /// Returns the requested IP addresses.
+    ///
+    /// 返回请求的 IP 地址。
+    pub fn ip_addresses(&self) -> &[IpAddr] {
+        &self.alt_names.ip_addresses
+    }
+
+    /// Verifies that the request was signed with the private key of its public key of `S`,
+    /// returning the public key.
+    ///
+    /// Fails with `KeyError::UnsupportedAlgorithm` if the request was not signed with `S`.
+    ///
+    /// 验证请求是否由其 `S` 公钥对应的私钥签名，并返回该公钥。
+    ///
+    /// 如果请求不是使用 `S` 签名的，则以 `KeyError::UnsupportedAlgorithm` 失败。
+    pub fn verify<S: X509Scheme>(&self) -> Result<S::PublicKey, Error> {
+        let public_key = S::from_spki(&self.inner.info.public_key)?;
+        verify::<S, _>(
+            &public_key,
+            &self.inner.info,
+            &self.inner.algorithm,
+            &self.inner.signature,
+        )?;
+        Ok(public_key)
+    }
+}
+
+// ------------------- Certificates -------------------
+// ------------------- 证书 -------------------
+
+/// A builder of self-signed X.509 v3 certificates.
+///
+/// 自签名 X.509 v3 证书的构建器。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateBuilder {
+    subject: String,
+    not_before: Duration,
+    not_after: Duration,
+    serial_number: Option<Vec<u8>>,
+    alt_names: AltNames,
+    ca: Option<Option<u8>>,
+}
+
+impl CertificateBuilder {
+    /// Creates a builder for a certificate for the RFC 4514 `subject` that is valid from
+    /// `not_before` to `not_after`, both given as durations since the Unix epoch.
+    ///
+    /// 为 RFC 4514 格式的 `subject` 创建证书构建器，证书的有效期从 `not_before` 到
+    /// `not_after`，两者均以自 Unix 纪元起的时长给出。
+    pub fn new(subject: &str, not_before: Duration, not_after: Duration) -> Self {
+        Self {
+            subject: subject.to_string(),
+            not_before,
+            not_after,
+            serial_number: None,
+            alt_names: AltNames::default(),
+            ca: None,
+        }
+    }
+
+    /// Sets the serial number, a big-endian positive integer; a random 128-bit serial number is
+    /// used otherwise.
+    ///
+    /// 设置序列号（大端序正整数）；否则使用随机的 128 位序列号。
+    pub fn with_serial_number(mut self, serial_number: &[u8]) -> Self {
+        self.serial_number = Some(serial_number.to_vec());
+        self
+    }
+
+    /// Adds a DNS name as a subject alternative name.
+    ///
+    /// 添加一个 DNS 名称作为主体备用名称。
+    pub fn with_dns_name(mut self, name: &str) -> Self {
+        self.alt_names.dns_names.push(name.to_string());
+        self
+    }
+
+    /// Adds an IP address as a subject alternative name.
+    ///
+    /// 添加一个 IP 地址作为主体备用名称。
+    pub fn with_ip_address(mut self, address: IpAddr) -> Self {
+        self.alt_names.ip_addresses.push(address);
+        self
+    }
+
+    /// Makes the certificate a CA certificate, allowing at most `path_len` intermediate CAs
+    /// below it if given.
+    ///
+    /// 使证书成为 CA 证书；如果给定 `path_len`，则其下最多允许 `path_len` 个中间 CA。
+    pub fn with_ca(mut self, path_len: Option<u8>) -> Self {
+        self.ca = Some(path_len);
+        self
+    }
+
+    /// Issues the certificate for `public_key`, signed with its own `private_key`.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if the subject, a DNS name, the serial number or
+    /// the validity period is malformed.
+    ///
+    /// 为 `public_key` 签发证书，并使用其自身的 `private_key` 签名。
+    ///
+    /// 如果主体、某个 DNS 名称、序列号或有效期格式错误，则以 `KeyError::InvalidEncoding` 失败。
+    pub fn self_sign<S: X509Scheme>(
+        &self,
+        public_key: &S::PublicKey,
+        private_key: &S::PrivateKey,
+    ) -> Result<Certificate, Error> {
+        if self.not_after < self.not_before {
+            return Err(invalid_encoding());
+        }
+        let subject = parse_name(&self.subject)?;
+        let spki = S::to_spki(public_key)?;
+        let serial_number = match &self.serial_number {
+            // Serial numbers must be positive (RFC 5280, Section 4.1.2.2)
+            Some(serial_number) if serial_number.iter().all(|&byte| byte == 0) => {
+                return Err(invalid_encoding());
+            }
+            Some(serial_number) => SerialNumber::new(serial_number),
+            None => {
+                let mut serial_number = [0u8; SERIAL_NUMBER_SIZE];
+                SystemRng::new().fill_bytes(&mut serial_number);
+                // Clearing the top bit keeps the number positive in 16 bytes, and setting the
+                // next one keeps it from shrinking
+                serial_number[0] = (serial_number[0] & 0x3f) | 0x40;
+                SerialNumber::new(&serial_number)
+            }
+        }
+        .map_err(|_| invalid_encoding())?;
+
+        let key_usage = match self.ca {
+            Some(_) => KeyUsages::DigitalSignature | KeyUsages::KeyCertSign | KeyUsages::CRLSign,
+            None => KeyUsages::DigitalSignature.into(),
+        };
+        let key_identifier =
+            &Sha256::hash(spki.subject_public_key.raw_bytes())[..KEY_IDENTIFIER_SIZE];
+        let mut extensions = vec![
+            BasicConstraints {
+                ca: self.ca.is_some(),
+                path_len_constraint: self.ca.flatten(),
+            }
+            .to_extension(&subject, &[]),
+            KeyUsage(key_usage).to_extension(&subject, &[]),
+            OctetString::new(key_identifier)
+                .and_then(|id| SubjectKeyIdentifier(id).to_extension(&subject, &[])),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| invalid_encoding())?;
+        if !self.alt_names.is_empty() {
+            extensions.push(self.alt_names.to_extension(&subject)?);
+        }
+
+        let signature_algorithm = S::signature_algorithm()?;
+        let tbs_certificate = TbsCertificate {
+            version: Version::V3,
+            serial_number,
+            signature: signature_algorithm.clone(),
+            issuer: subject.clone(),
+            validity: Validity {
+                not_before: to_time(self.not_before)?,
+                not_after: to_time(self.not_after)?,
+            },
+            subject,
+            subject_public_key_info: spki,
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions: Some(extensions),
+        };
+        let signature = sign::<S, _>(private_key, &tbs_certificate)?;
+        Ok(Certificate {
+            inner: X509Certificate {
+                tbs_certificate,
+                signature_algorithm,
+                signature,
+            },
+            alt_names: self.alt_names.clone(),
+        })
+    }
+}
+
+/// Converts a duration since the Unix epoch into a `Time`, as a `UTCTime` through 2049 and a
+/// `GeneralizedTime` from 2050 (RFC 5280, Section 4.1.2.5).
+fn to_time(time: Duration) -> Result<Time, Error> {
+    UtcTime::from_unix_duration(time)
+        .map(Time::UtcTime)
+        .or_else(|_| GeneralizedTime::from_unix_duration(time).map(Time::GeneralTime))
+        .map_err(|_| invalid_encoding())
+}
+
+/// An X.509 certificate.
+///
+/// X.509 证书。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Certificate {
+    inner: X509Certificate,
+    alt_names: AltNames,
+}
+
+impl Certificate {
+    /// Parses a DER-encoded certificate.
+    ///
+    /// 解析 DER 编码的证书。
+    pub fn from_der(der: &[u8]) -> Result<Self, Error> {
+        Self::new(X509Certificate::from_der(der).map_err(|_| invalid_encoding())?)
+    }
+
+    /// Parses a PEM-encoded `CERTIFICATE`.
+    ///
+    /// 解析 PEM 编码的 `CERTIFICATE`。
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        Self::new(X509Certificate::from_pem(pem).map_err(|_| invalid_encoding())?)
+    }
+
+    fn new(inner: X509Certificate) -> Result<Self, Error> {
+        let extensions = inner
+            .tbs_certificate
+            .extensions
+            .as_deref()
+            .unwrap_or_default();
+        let alt_names = AltNames::from_extensions(extensions)?;
+        Ok(Self { inner, alt_names })
+    }
+
+    /// Encodes the certificate as DER.
+    ///
+    /// 将证书编码为 DER。
+    pub fn to_der(&self) -> Result<Vec<u8>, Error> {
+        self.inner.to_der().map_err(|_| invalid_encoding())
+    }
+
+    /// Encodes the certificate as a PEM `CERTIFICATE`.
+    ///
+    /// 将证书编码为 PEM `CERTIFICATE`。
+    pub fn to_pem(&self) -> Result<String, Error> {
+        self.inner
+            .to_pem(LineEnding::LF)
+            .map_err(|_| invalid_encoding())
+    }
+
+    /// Returns the subject as an RFC 4514 string.
+    ///
+    /// 以 RFC 4514 字符串形式返回主体。
+    pub fn subject(&self) -> String {
+        self.inner.tbs_certificate.subject.to_string()
+    }
+
+    /// Returns the issuer as an RFC 4514 string.
+    ///
+    /// 以 RFC 4514 字符串形式返回签发者。
+    pub fn issuer(&self) -> String {
+        self.inner.tbs_certificate.issuer.to_string()
+    }
+
+    /// Returns the serial number as a big-endian integer.
+    ///
+    /// 以大端序整数形式返回序列号。
+    pub fn serial_number(&self) -> &[u8] {
+        self.inner.tbs_certificate.serial_number.as_bytes()
+    }
+
+    /// Returns the start of the validity period as a duration since the Unix epoch.
+    ///
+    /// 以自 Unix 纪元起的时长返回有效期的开始。
+    pub fn not_before(&self) -> Duration {
+        self.inner
+            .tbs_certificate
+            .validity
+            .not_before
+            .to_unix_duration()
+    }
+
+    /// Returns the end of the validity period as a duration since the Unix epoch.
+    ///
+    /// 以自 Unix 纪元起的时长返回有效期的结束。
+    pub fn not_after(&self) -> Duration {
+        self.inner
+            .tbs_certificate
+            .validity
+            .not_after
+            .to_unix_duration()
+    }
+
+    /// Returns whether the certificate is a CA certificate.
+    ///
+    /// 返回证书是否为 CA 证书。
+    pub fn is_ca(&self) -> Result<bool, Error> {
+        let constraints = self
+            .inner
+            .tbs_certificate
+            .get::<BasicConstraints>()
+            .map_err(|_| invalid_encoding())?;
+        Ok(constraints.is_some_and(|(_, constraints)| constraints.ca))
+    }
+
+    /// Returns the DNS names of the subject alternative names.
+    ///
+    /// 返回主体备用名称中的 DNS 名称。
+    pub fn dns_names(&self) -> &[String] {
+        &self.alt_names.dns_names
+    }
+
+    /// Returns the IP addresses of the subject alternative names.
+    ///
+    /// 返回主体备用名称中的 IP 地址。
+    pub fn ip_addresses(&self) -> &[IpAddr] {
+        &self.alt_names.ip_addresses
+    }
+
+    /// Returns the subject public key of `S`, without verifying the certificate.
+    ///
+    /// 返回 `S` 的主体公钥，而不验证证书。
+    pub fn public_key<S: X509Scheme>(&self) -> Result<S::PublicKey, Error> {
+        S::from_spki(&self.inner.tbs_certificate.subject_public_key_info)
+    }
+
+    /// Verifies that a self-signed certificate was signed with the private key of its own
+    /// public key of `S`, returning the public key.
+    ///
+    /// Fails with `KeyError::UnsupportedAlgorithm` if the certificate was not signed with `S`,
+    /// and with `SignatureError::Verification` if it was not signed by its own key or its
+    /// issuer is not its subject. The validity period is not checked.
+    ///
+    /// 验证自签名证书是否由其自身 `S` 公钥对应的私钥签名，并返回该公钥。
+    ///
+    /// 如果证书不是使用 `S` 签名的，则以 `KeyError::UnsupportedAlgorithm` 失败；如果证书不是由
+    /// 其自身密钥签名的或其签发者不是其主体，则以 `SignatureError::Verification` 失败。
+    /// 不检查有效期。
+    pub fn verify_self_signed<S: X509Scheme>(&self) -> Result<S::PublicKey, Error> {
+        let tbs_certificate = &self.inner.tbs_certificate;
+        if tbs_certificate.issuer != tbs_certificate.subject
+            || tbs_certificate.signature != self.inner.signature_algorithm
+        {
+            return Err(Error::Signature(SignatureError::Verification));
+        }
+        let public_key = self.public_key::<S>()?;
+        verify::<S, _>(
+            &public_key,
+            tbs_certificate,
+            &self.inner.signature_algorithm,
+            &self.inner.signature,
+        )?;
+        Ok(public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2049-12-31T00:00:00Z, the last day encoded as `UTCTime`.
+    const NOT_BEFORE: Duration = Duration::from_secs(2_524_521_600);
+    /// 2050-12-31T00:00:00Z, encoded as `GeneralizedTime`.
+    const NOT_AFTER: Duration = Duration::from_secs(2_556_057_600);
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        let malformed = Some(Error::Key(KeyError::InvalidEncoding));
+        assert_eq!(CertificateRequest::from_der(&[0x30, 0x00]).err(), malformed);
+        assert_eq!(CertificateRequest::from_pem("").err(), malformed);
+        assert_eq!(Certificate::from_der(&[]).err(), malformed);
+        assert_eq!(
+            Certificate::from_pem("-----BEGIN CERTIFICATE-----\n-----END CERTIFICATE-----\n").err(),
+            malformed
+        );
+        assert!(matches!(to_time(NOT_BEFORE), Ok(Time::UtcTime(_))));
+        assert!(matches!(to_time(NOT_AFTER), Ok(Time::GeneralTime(_))));
+        assert_eq!(to_time(Duration::from_secs(u64::MAX)).err(), malformed);
+        assert!(parse_name("CN=example.com,O=Example").is_ok());
+        assert_eq!(parse_name("example.com").err(), malformed);
+    }
+
+    #[cfg(feature = "ecc-default")]
+    mod ecc {
+        use super::super::private::X509Signature;
+        use super::*;
+        use crate::systems::asymmetric::traditional::ecc::{EcdsaP256, Ed25519};
+
+        // Requests created with `openssl req -new`.
+        // 使用 `openssl req -new` 创建的请求。
+        const OPENSSL_ED25519_CSR: &str = "-----BEGIN CERTIFICATE REQUEST-----
+MIHfMIGSAgEAMCwxEDAOBgNVBAoMB0V4YW1wbGUxGDAWBgNVBAMMD2FwaS5leGFt
+cGxlLmNvbTAqMAUGAytlcAMhAAqEO6OvfbQfOUbYAsDBtuktE0+iCMugjvyIUr6w
+/m/poDMwMQYJKoZIhvcNAQkOMSQwIjAgBgNVHREEGTAXgg9hcGkuZXhhbXBsZS5j
+b22HBAoAAAEwBQYDK2VwA0EA8lGPhqaq4gpfSZdNb3a1dA6t/1dYwEU6IcPG7oW8
+bF3kzHWnLa/7yL0m0FK30p72u34gi5wS3nVI68D3HvFXCg==
+-----END CERTIFICATE REQUEST-----
+";
+        const OPENSSL_P256_CSR: &str = "-----BEGIN CERTIFICATE REQUEST-----
+MIHOMHYCAQAwFDESMBAGA1UEAwwJZGV2aWNlLTQyMFkwEwYHKoZIzj0CAQYIKoZI
+zj0DAQcDQgAEjFban1irYRsz/3LC6PQj7grHCNAzV+O1eA5feDz9urZ3jkLZiqHK
+dDcotMq7D8HgdljXqbk5cAws96tiiMj1wKAAMAoGCCqGSM49BAMCA0gAMEUCIEKJ
+JTl5WVBSZUOPqFnaPjd+l7rJ1DiIZHQn+6bP3eKfAiEAxBOaxsrNmOloV0WwAwEn
+O/fGiHEngQXHFjcp4qTR+a0=
+-----END CERTIFICATE REQUEST-----
+";
+
+        #[test]
+        fn test_openssl_requests() -> Result<(), Error> {
+            let request = CertificateRequest::from_pem(OPENSSL_ED25519_CSR)?;
+            assert_eq!(request.subject(), "CN=api.example.com,O=Example");
+            assert_eq!(request.dns_names(), ["api.example.com"]);
+            assert_eq!(request.ip_addresses(), [IpAddr::from([10, 0, 0, 1])]);
+            request.verify::<Ed25519>()?;
+            assert_eq!(request.to_pem()?, OPENSSL_ED25519_CSR);
+            assert_eq!(
+                request.verify::<EcdsaP256>().err(),
+                Some(Error::Key(KeyError::InvalidEncoding))
+            );
+
+            let request = CertificateRequest::from_pem(OPENSSL_P256_CSR)?;
+            assert_eq!(request.subject(), "CN=device-42");
+            assert!(request.dns_names().is_empty());
+            let public_key = request.verify::<EcdsaP256>()?;
+            assert_eq!(
+                CertificateRequest::from_der(&request.to_der()?)?.verify::<EcdsaP256>()?,
+                public_key
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_request_roundtrip() -> Result<(), Error> {
+            let (public_key, private_key) = EcdsaP256::generate_keypair()?;
+            let request = CertificateRequestBuilder::new("CN=api.example.com,O=Example,C=US")
+                .with_dns_name("api.example.com")
+                .with_dns_name("*.api.example.com")
+                .with_ip_address("2001:db8::1".parse().unwrap_or(IpAddr::from([0; 16])))
+                .sign::<EcdsaP256>(&public_key, &private_key)?;
+
+            let parsed = CertificateRequest::from_pem(&request.to_pem()?)?;
+            assert_eq!(parsed, request);
+            assert_eq!(parsed.subject(), "CN=api.example.com,O=Example,C=US");
+            assert_eq!(parsed.dns_names(), ["api.example.com", "*.api.example.com"]);
+            assert_eq!(
+                parsed.ip_addresses(),
+                [IpAddr::from([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1])]
+            );
+            assert_eq!(parsed.verify::<EcdsaP256>()?, public_key);
+
+            // A request without alternative names has no attributes.
+            // 没有备用名称的请求不含属性。
+            let request = CertificateRequestBuilder::new("CN=device-42")
+                .sign::<EcdsaP256>(&public_key, &private_key)?;
+            assert!(request.inner.info.attributes.is_empty());
+            assert_eq!(request.verify::<EcdsaP256>()?, public_key);
+            Ok(())
+        }
+
+        #[test]
+        fn test_request_rejects_tampering() -> Result<(), Error> {
+            let (public_key, private_key) = Ed25519::generate_keypair()?;
+            let request = CertificateRequestBuilder::new("CN=device-42")
+                .sign::<Ed25519>(&public_key, &private_key)?;
+
+            let mut forged = request.clone();
+            forged.inner.info.subject = parse_name("CN=device-43")?;
+            assert_eq!(
+                forged.verify::<Ed25519>().err(),
+                Some(Error::Signature(SignatureError::Verification))
+            );
+
+            // A key substituted into the request does not match the signature.
+            // 替换到请求中的密钥与签名不匹配。
+            let (other_public_key, _) = Ed25519::generate_keypair()?;
+            let mut forged = request;
+            forged.inner.info.public_key = Ed25519::to_spki(&other_public_key)?;
+            assert_eq!(
+                forged.verify::<Ed25519>().err(),
+                Some(Error::Signature(SignatureError::Verification))
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_self_signed() -> Result<(), Error> {
+            let (public_key, private_key) = Ed25519::generate_keypair()?;
+            let certificate =
+                CertificateBuilder::new("CN=Example Root,O=Example", NOT_BEFORE, NOT_AFTER)
+                    .with_serial_number(&[0x01, 0x02])
+                    .with_ca(Some(0))
+                    .self_sign::<Ed25519>(&public_key, &private_key)?;
+
+            let parsed = Certificate::from_pem(&certificate.to_pem()?)?;
+            assert_eq!(parsed, certificate);
+            assert_eq!(parsed.subject(), "CN=Example Root,O=Example");
+            assert_eq!(parsed.issuer(), parsed.subject());
+            assert_eq!(parsed.serial_number(), [0x01, 0x02]);
+            assert_eq!(parsed.not_before(), NOT_BEFORE);
+            assert_eq!(parsed.not_after(), NOT_AFTER);
+            assert!(parsed.is_ca()?);
+            assert!(parsed.dns_names().is_empty());
+            assert_eq!(parsed.public_key::<Ed25519>()?, public_key);
+            assert_eq!(parsed.verify_self_signed::<Ed25519>()?, public_key);
+            assert_eq!(
+                parsed.verify_self_signed::<EcdsaP256>().err(),
+                Some(Error::Key(KeyError::InvalidEncoding))
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn test_self_signed_leaf() -> Result<(), Error> {
+            let (public_key, private_key) = EcdsaP256::generate_keypair()?;
+            let builder = CertificateBuilder::new("CN=localhost", NOT_BEFORE, NOT_AFTER)
+                .with_dns_name("localhost")
+                .with_ip_address(IpAddr::from([127, 0, 0, 1]));
+            let certificate = builder.self_sign::<EcdsaP256>(&public_key, &private_key)?;
+
+            let parsed = Certificate::from_der(&certificate.to_der()?)?;
+            assert!(!parsed.is_ca()?);
+            assert_eq!(parsed.dns_names(), ["localhost"]);
+            assert_eq!(parsed.ip_addresses(), [IpAddr::from([127, 0, 0, 1])]);
+            assert_eq!(parsed.verify_self_signed::<EcdsaP256>()?, public_key);
+
+            // Random serial numbers are positive 16-byte integers.
+            // 随机序列号是 16 字节的正整数。
+            let serial_number = parsed.serial_number();
+            assert_eq!(serial_number.len(), SERIAL_NUMBER_SIZE);
+            assert_eq!(serial_number[0] & 0xc0, 0x40);
+            let other = builder.self_sign::<EcdsaP256>(&public_key, &private_key)?;
+            assert_ne!(other.serial_number(), serial_number);
+
+            // A certificate signed by another key or naming another issuer is rejected.
+            // 由其他密钥签名或指明其他签发者的证书会被拒绝。
+            let (other_public_key, _) = EcdsaP256::generate_keypair()?;
+            let mut forged = certificate.clone();
+            forged.inner.tbs_certificate.subject_public_key_info =
+                EcdsaP256::to_spki(&other_public_key)?;
+            let mut renamed = certificate;
+            renamed.inner.tbs_certificate.issuer = parse_name("CN=Example Root")?;
+            for certificate in [forged, renamed] {
+                assert_eq!(
+                    certificate.verify_self_signed::<EcdsaP256>().err(),
+                    Some(Error::Signature(SignatureError::Verification))
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn test_builders_reject_invalid_fields() -> Result<(), Error> {
+            let (public_key, private_key) = EcdsaP256::generate_keypair()?;
+            let malformed = Some(Error::Key(KeyError::InvalidEncoding));
+
+            for builder in [
+                CertificateRequestBuilder::new("localhost"),
+                CertificateRequestBuilder::new("CN=example").with_dns_name("bücher.example"),
+            ] {
+                assert_eq!(
+                    builder.sign::<EcdsaP256>(&public_key, &private_key).err(),
+                    malformed
+                );
+            }
+            for builder in [
+                CertificateBuilder::new("CN=example", NOT_AFTER, NOT_BEFORE),
+                CertificateBuilder::new("CN=example", NOT_BEFORE, NOT_AFTER)
+                    .with_serial_number(&[0xff; 21]),
+                CertificateBuilder::new("CN=example", NOT_BEFORE, NOT_AFTER)
+                    .with_serial_number(&[0]),
+                CertificateBuilder::new("CN=example", NOT_BEFORE, NOT_AFTER)
+                    .with_dns_name("bücher.example"),
+            ] {
+                assert_eq!(
+                    builder
+                        .self_sign::<EcdsaP256>(&public_key, &private_key)
+                        .err(),
+                    malformed
+                );
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rsa-default")]
+    #[test]
+    fn test_rsa_pss() -> Result<(), Error> {
+        use crate::systems::asymmetric::traditional::rsa::Rsa2048;
+
+        let (public_key, private_key) = Rsa2048::<Sha384>::generate_keypair()?;
+        let request = CertificateRequestBuilder::new("CN=rsa.example.com")
+            .with_dns_name("rsa.example.com")
+            .sign::<Rsa2048<Sha384>>(&public_key, &private_key)?;
+        let request = CertificateRequest::from_der(&request.to_der()?)?;
+        assert_eq!(
+            request.verify::<Rsa2048<Sha384>>()?.to_bytes()?,
+            public_key.to_bytes()?
+        );
+        assert_eq!(
+            request.verify::<Rsa2048<Sha256>>().err(),
+            Some(Error::Key(KeyError::UnsupportedAlgorithm))
+        );
+
+        let certificate = CertificateBuilder::new("CN=rsa.example.com", NOT_BEFORE, NOT_AFTER)
+            .self_sign::<Rsa2048<Sha384>>(&public_key, &private_key)?;
+        assert_eq!(
+            certificate
+                .verify_self_signed::<Rsa2048<Sha384>>()?
+                .to_bytes()?,
+            public_key.to_bytes()?
+        );
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@
 //!
 //! # Available Implementations
 //! - **SHAKE**: SHA-3 based XOFs with different security levels
+//! - **cSHAKE**: SHAKE with function-name and customization strings (NIST SP 800-185)
+//! - **TupleHash / ParallelHash**: cSHAKE-based hashing of tuples and of long inputs in blocks
 //!
 //! # Security Considerations
 //! XOFs maintain their security properties regardless of output length,
@@ -18,6 +20,8 @@
 //!
 //! # 可用实现
 //! - **SHAKE**: 基于 SHA-3 的 XOF，具有不同的安全级别
+//! - **cSHAKE**: 带有函数名和定制字符串的 SHAKE (NIST SP 800-185)
+//! - **TupleHash / ParallelHash**: 基于 cSHAKE 的元组哈希和长输入分块哈希
 //!
 //! # 安全考虑
 //! XOF 无论输出长度如何都保持其安全属性，使其适用于生成密钥、nonce 和其他加密材料。
@@ -27,3 +31,24 @@
 /// SHAKE 系列可扩展输出函数实现。
 #[cfg(feature = "shake-default")]
 pub mod shake;
+
+/// cSHAKE customizable extendable-output functions implementation.
+///
+/// cSHAKE 可定制可扩展输出函数实现。
+#[cfg(feature = "shake-default")]
+pub mod cshake;
+
+/// TupleHash, unambiguous hashing of a sequence of byte strings.
+///
+/// TupleHash，对字节串序列进行无歧义哈希。
+#[cfg(feature = "shake-default")]
+pub mod tuplehash;
+
+/// ParallelHash, cSHAKE-based hashing of long inputs in independent blocks.
+///
+/// ParallelHash，基于 cSHAKE 以独立块对长输入进行哈希。
+#[cfg(feature = "shake-default")]
+pub mod parallelhash;
+
+#[cfg(feature = "shake-default")]
+pub(crate) mod sp800_185;
@@ -0,0 +1,322 @@
+//! Provides an implementation of cSHAKE, the customizable SHAKE function.
+//!
+//! cSHAKE (NIST SP 800-185) extends SHAKE with two domain-separation inputs:
+//! - **N**, the function-name string, reserved for functions defined by NIST on top of
+//!   cSHAKE (e.g. `"KMAC"`, `"TupleHash"`); applications normally leave it empty.
+//! - **S**, the customization string, chosen by the application.
+//!
+//! When both are empty, cSHAKE is identical to SHAKE.
+//!
+//! Unlike [`ShakeScheme`](crate::systems::xof::shake::ShakeScheme), whose KDF-shaped API
+//! concatenates salt and info into the input, this scheme produces spec-compliant cSHAKE
+//! output. It still implements [`XofDerivation`], absorbing `salt`, `ikm` and `info` in that
+//! order after the configured `N` and `S`.
+//!
+//! # Algorithm Variants
+//! - **cSHAKE128**: 128 bits of security strength
+//! - **cSHAKE256**: 256 bits of security strength
+//!
+//! 提供了 cSHAKE（可定制 SHAKE 函数）的实现。
+//!
+//! cSHAKE (NIST SP 800-185) 为 SHAKE 增加了两个域分离输入：
+//! - **N**，函数名字符串，保留给 NIST 在 cSHAKE 之上定义的函数（例如 `"KMAC"`、`"TupleHash"`）；
+//!   应用程序通常将其留空。
+//! - **S**，定制字符串，由应用程序选择。
+//!
+//! 当两者都为空时，cSHAKE 与 SHAKE 完全相同。
+//!
+//! 与 [`ShakeScheme`](crate::systems::xof::shake::ShakeScheme) 将盐和 info 拼接到输入中的 KDF 式 API 不同，
+//! 此方案产生符合规范的 cSHAKE 输出。它仍然实现了 [`XofDerivation`]，在配置的 `N` 和 `S` 之后
+//! 按顺序吸收 `salt`、`ikm` 和 `info`。
+//!
+//! # 算法变体
+//! - **cSHAKE128**: 128 位安全强度
+//! - **cSHAKE256**: 256 位安全强度
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use crate::traits::kdf::check_output_len;
+use crate::{errors::Error, policy, prelude::*};
+use digest::{ExtendableOutput, Update};
+use sha3::{CShake128Core, CShake256Core};
+
+// ------------------- Marker Structs and Trait for cSHAKE Parameters -------------------
+// ------------------- 用于 cSHAKE 参数的标记结构体和 Trait -------------------
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A trait that defines the parameters for a specific cSHAKE variant.
+/// This is a sealed trait, meaning only types within this crate can implement it.
+///
+/// 一个定义特定 cSHAKE 变体参数的 trait。
+/// 这是一个密封的 trait，意味着只有此 crate 中的类型才能实现它。
+pub trait CShakeParams: private::Sealed + SchemeParams {
+    /// The underlying `sha3` cSHAKE type.
+    ///
+    /// 底层的 `sha3` cSHAKE 类型。
+    type CShake: Update + ExtendableOutput + Clone + Send + Sync + core::fmt::Debug + 'static;
+    /// The rate of the sponge in bytes.
+    ///
+    /// 海绵结构的速率（以字节为单位）。
+    const RATE: usize;
+    /// The security strength in bits.
+    ///
+    /// 安全强度（以位为单位）。
+    const SECURITY_STRENGTH: usize;
+    /// The offset added to the base ID of schemes built on this cSHAKE variant.
+    ///
+    /// 添加到基于此 cSHAKE 变体构建的方案的基础 ID 上的偏移量。
+    const ID_OFFSET: u32;
+
+    /// Creates a cSHAKE instance with the given function name and customization string.
+    ///
+    /// 使用给定的函数名和定制字符串创建 cSHAKE 实例。
+    fn new_cshake(function_name: &[u8], customization: &[u8]) -> Self::CShake;
+}
+
+/// Marker struct for cSHAKE128 parameters.
+///
+/// cSHAKE128 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct CShake128Params;
+impl private::Sealed for CShake128Params {}
+impl SchemeParams for CShake128Params {
+    const NAME: &'static str = "cSHAKE128";
+    const ID: u32 = 0x05_02_00_01;
+}
+impl CShakeParams for CShake128Params {
+    type CShake = sha3::CShake128;
+    const RATE: usize = super::sp800_185::RATE_128;
+    const SECURITY_STRENGTH: usize = 128;
+    const ID_OFFSET: u32 = 1;
+
+    fn new_cshake(function_name: &[u8], customization: &[u8]) -> Self::CShake {
+        sha3::CShake128::from_core(CShake128Core::new_with_function_name(
+            function_name,
+            customization,
+        ))
+    }
+}
+
+/// Marker struct for cSHAKE256 parameters.
+///
+/// cSHAKE256 参数的标记结构体。
+#[derive(Clone, Debug, Default)]
+pub struct CShake256Params;
+impl private::Sealed for CShake256Params {}
+impl SchemeParams for CShake256Params {
+    const NAME: &'static str = "cSHAKE256";
+    const ID: u32 = 0x05_02_00_02;
+}
+impl CShakeParams for CShake256Params {
+    type CShake = sha3::CShake256;
+    const RATE: usize = super::sp800_185::RATE_256;
+    const SECURITY_STRENGTH: usize = 256;
+    const ID_OFFSET: u32 = 2;
+
+    fn new_cshake(function_name: &[u8], customization: &[u8]) -> Self::CShake {
+        sha3::CShake256::from_core(CShake256Core::new_with_function_name(
+            function_name,
+            customization,
+        ))
+    }
+}
+
+// ------------------- Generic cSHAKE Implementation -------------------
+// ------------------- 通用 cSHAKE 实现 -------------------
+
+/// A generic struct representing a cSHAKE computation for a given parameter set.
+///
+/// `Default` uses empty `N` and `S`, which makes it equivalent to plain SHAKE.
+///
+/// 一个通用的 cSHAKE 计算结构体，它在参数集上是通用的。
+///
+/// `Default` 使用空的 `N` 和 `S`，这使其等同于普通的 SHAKE。
+#[derive(Clone, Debug)]
+pub struct CShakeScheme<P: CShakeParams> {
+    state: P::CShake,
+}
+
+impl<P: CShakeParams> CShakeScheme<P> {
+    /// Starts a cSHAKE computation with the function-name string `N` and the
+    /// customization string `S`.
+    ///
+    /// 使用函数名字符串 `N` 和定制字符串 `S` 开始一次 cSHAKE 计算。
+    pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+        Self {
+            state: P::new_cshake(function_name, customization),
+        }
+    }
+
+    /// Starts a cSHAKE computation with an empty `N` and the customization string `S`.
+    ///
+    /// 使用空的 `N` 和定制字符串 `S` 开始一次 cSHAKE 计算。
+    pub fn with_customization(customization: &[u8]) -> Self {
+        Self::new(&[], customization)
+    }
+
+    /// Absorbs more data.
+    ///
+    /// 吸收更多数据。
+    pub fn update(&mut self, data: &[u8]) {
+        self.state.update(data);
+    }
+
+    /// Consumes the state and returns a reader for an arbitrary amount of output.
+    ///
+    /// 消费状态并返回一个可读取任意长度输出的读取器。
+    pub fn finalize_xof(self) -> XofReader<'static> {
+        XofReader::new(self.state.finalize_xof())
+    }
+
+    /// Computes `output_len` bytes of `cSHAKE(data, output_len, N, S)` in one shot.
+    ///
+    /// 一次性计算 `cSHAKE(data, output_len, N, S)` 的 `output_len` 字节。
+    pub fn digest(
+        function_name: &[u8],
+        customization: &[u8],
+        data: &[u8],
+        output_len: usize,
+    ) -> Vec<u8> {
+        let mut scheme = Self::new(function_name, customization);
+        scheme.update(data);
+        let mut output = vec![0u8; output_len];
+        scheme.state.finalize_xof_into(&mut output);
+        output
+    }
+
+    fn absorb(&self, ikm: &[u8], salt: Option<&[u8]>, info: Option<&[u8]>) -> Self {
+        let mut scheme = self.clone();
+        if let Some(s) = salt {
+            scheme.update(s);
+        }
+        scheme.update(ikm);
+        if let Some(i) = info {
+            scheme.update(i);
+        }
+        scheme
+    }
+}
+
+impl<P: CShakeParams> Default for CShakeScheme<P> {
+    fn default() -> Self {
+        Self::new(&[], &[])
+    }
+}
+
+impl<P: CShakeParams> Algorithm for CShakeScheme<P> {
+    fn name() -> String {
+        P::NAME.to_string()
+    }
+    const ID: u32 = P::ID;
+}
+
+impl<P: CShakeParams> Parameterized for CShakeScheme<P> {
+    fn get_type_params() -> Vec<(&'static str, ParamValue)> {
+        vec![("xof", ParamValue::String(P::NAME.to_string()))]
+    }
+
+    fn get_instance_params(&self) -> Vec<(&'static str, ParamValue)> {
+        vec![]
+    }
+}
+
+impl<P: CShakeParams> Derivation for CShakeScheme<P> {}
+
+impl<P: CShakeParams> KeyBasedDerivation for CShakeScheme<P> {
+    fn derive(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        check_output_len(output_len, self.max_output_len())?;
+        let mut reader = self.absorb(ikm, salt, info).finalize_xof();
+        let mut okm = vec![0u8; output_len];
+        reader.read(&mut okm);
+
+        Ok(DerivedKey::new(okm))
+    }
+}
+
+impl<P: CShakeParams> XofDerivation for CShakeScheme<P> {
+    fn reader<'a>(
+        &self,
+        ikm: &'a [u8],
+        salt: Option<&'a [u8]>,
+        info: Option<&'a [u8]>,
+    ) -> Result<XofReader<'a>, Error> {
+        policy::enforce::<Self>()?;
+        Ok(self.absorb(ikm, salt, info).finalize_xof())
+    }
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for the cSHAKE128 scheme.
+///
+/// cSHAKE128 方案的类型别名。
+pub type CShake128 = CShakeScheme<CShake128Params>;
+
+/// A type alias for the cSHAKE256 scheme.
+///
+/// cSHAKE256 方案的类型别名。
+pub type CShake256 = CShakeScheme<CShake256Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::xof::shake::ShakeScheme;
+
+    // Samples from the NIST SP 800-185 example values
+    const DATA: [u8; 4] = [0x00, 0x01, 0x02, 0x03];
+    const CUSTOMIZATION: &[u8] = b"Email Signature";
+
+    #[test]
+    fn test_cshake128() {
+        let output = CShake128::digest(&[], CUSTOMIZATION, &DATA, 32);
+        assert_eq!(
+            hex::encode(output),
+            "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5"
+        );
+    }
+
+    #[test]
+    fn test_cshake256() {
+        let mut scheme = CShake256::with_customization(CUSTOMIZATION);
+        scheme.update(&DATA[..1]);
+        scheme.update(&DATA[1..]);
+        let mut reader = scheme.finalize_xof();
+        let mut output = [0u8; 64];
+        reader.read(&mut output);
+        assert_eq!(
+            hex::encode(output),
+            "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+             64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+        );
+    }
+
+    #[test]
+    fn test_cshake_empty_strings_equal_shake() {
+        let cshake = CShake128::default()
+            .derive(b"ikm", Some(b"salt"), Some(b"info"), 48)
+            .unwrap();
+        let shake = ShakeScheme::<crate::traits::params::xof::Shake128>::default()
+            .derive(b"ikm", Some(b"salt"), Some(b"info"), 48)
+            .unwrap();
+        assert_eq!(cshake, shake);
+
+        let customized = CShake128::new(b"N", b"S")
+            .derive(b"ikm", Some(b"salt"), Some(b"info"), 48)
+            .unwrap();
+        assert_ne!(customized, shake);
+    }
+}
@@ -0,0 +1,385 @@
+//! Provides an implementation of ParallelHash from NIST SP 800-185.
+//!
+//! ParallelHash splits its input into blocks of `B` bytes, hashes each block independently
+//! with cSHAKE, and then hashes the concatenated block digests. Because the block digests
+//! do not depend on each other, very long inputs can be processed on several cores: with
+//! the `rayon` feature, [`ParallelUpdate::update_parallel`] hashes the blocks of each input
+//! on the rayon thread pool, while `update` processes them sequentially.
+//!
+//! # Algorithm Variants
+//! - **ParallelHash128 / ParallelHash256**: the output length is chosen by the caller and
+//!   bound into the result.
+//! - **ParallelHashXOF128 / ParallelHashXOF256**: arbitrary-length output read from an
+//!   [`XofReader`]; the output length is not bound into the result.
+//!
+//! The block size is part of the result, so all parties must agree on it.
+//!
+//! 提供了 NIST SP 800-185 中 ParallelHash 的实现。
+//!
+//! ParallelHash 将输入拆分为 `B` 字节的块，使用 cSHAKE 独立地对每个块进行哈希，
+//! 然后对拼接的块摘要进行哈希。由于块摘要之间互不依赖，很长的输入可以在多个核心上处理：
+//! 启用 `rayon` 特性时，[`ParallelUpdate::update_parallel`] 在 rayon 线程池上对每次输入的块
+//! 进行哈希，而 `update` 则按顺序处理它们。
+//!
+//! # 算法变体
+//! - **ParallelHash128 / ParallelHash256**: 输出长度由调用者选择，并被绑定到结果中。
+//! - **ParallelHashXOF128 / ParallelHashXOF256**: 从 [`XofReader`] 读取任意长度的输出；
+//!   输出长度不会被绑定到结果中。
+//!
+//! 块大小是结果的一部分，因此各方必须就其达成一致。
+
+use alloc::{format, string::String, vec, vec::Vec};
+use super::cshake::{CShake128Params, CShake256Params, CShakeParams};
+use super::sp800_185::{left_encode, right_encode};
+use crate::{errors::Error, prelude::*};
+use digest::{ExtendableOutput, Update};
+
+/// The block size used by `Default`, in bytes.
+///
+/// `Default` 使用的块大小（以字节为单位）。
+pub const DEFAULT_BLOCK_SIZE: usize = 8192;
+
+// ------------------- Shared Block Processing -------------------
+// ------------------- 共享的块处理 -------------------
+
+/// The streaming state shared by the fixed-length and XOF variants.
+///
+/// 定长变体和 XOF 变体共享的流式状态。
+#[derive(Clone, Debug)]
+struct BlockState<P: CShakeParams> {
+    state: P::CShake,
+    block_size: usize,
+    buffer: Vec<u8>,
+    blocks: u64,
+}
+
+impl<P: CShakeParams> BlockState<P> {
+    fn new(function_name: &[u8], block_size: usize, customization: &[u8]) -> Result<Self, Error> {
+        if block_size == 0 {
+            return Err(Error::Hash(HashError::InvalidBlockSize));
+        }
+        Ok(Self::new_unchecked(
+            function_name,
+            block_size,
+            customization,
+        ))
+    }
+
+    fn new_unchecked(function_name: &[u8], block_size: usize, customization: &[u8]) -> Self {
+        let mut state = P::new_cshake(function_name, customization);
+        state.update(&left_encode(block_size as u64));
+        Self {
+            state,
+            block_size,
+            buffer: Vec::with_capacity(block_size),
+            blocks: 0,
+        }
+    }
+
+    fn block_digest(block: &[u8]) -> Vec<u8> {
+        let mut chunk = P::new_cshake(&[], &[]);
+        chunk.update(block);
+        let mut digest = vec![0u8; P::SECURITY_STRENGTH / 4];
+        chunk.finalize_xof_into(&mut digest);
+        digest
+    }
+
+    fn absorb_digest(&mut self, digest: &[u8]) {
+        self.state.update(digest);
+        self.blocks += 1;
+    }
+
+    /// Completes a buffered partial block from the front of `data` and returns the rest.
+    ///
+    /// 使用 `data` 开头的数据补全缓冲的不完整块，并返回剩余部分。
+    fn fill_buffer<'a>(&mut self, data: &'a [u8]) -> &'a [u8] {
+        if self.buffer.is_empty() {
+            return data;
+        }
+        let take = (self.block_size - self.buffer.len()).min(data.len());
+        self.buffer.extend_from_slice(&data[..take]);
+        if self.buffer.len() == self.block_size {
+            let digest = Self::block_digest(&self.buffer);
+            self.absorb_digest(&digest);
+            self.buffer.clear();
+        }
+        &data[take..]
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let data = self.fill_buffer(data);
+        let mut blocks = data.chunks_exact(self.block_size);
+        for block in &mut blocks {
+            let digest = Self::block_digest(block);
+            self.absorb_digest(&digest);
+        }
+        self.buffer.extend_from_slice(blocks.remainder());
+    }
+
+    #[cfg(feature = "rayon")]
+    fn update_parallel(&mut self, data: &[u8]) {
+        use rayon::prelude::*;
+
+        let data = self.fill_buffer(data);
+        let full = data.len() - data.len() % self.block_size;
+        let digests: Vec<Vec<u8>> = data[..full]
+            .par_chunks_exact(self.block_size)
+            .map(Self::block_digest)
+            .collect();
+        for digest in &digests {
+            self.absorb_digest(digest);
+        }
+        self.buffer.extend_from_slice(&data[full..]);
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    fn update_parallel(&mut self, data: &[u8]) {
+        self.update(data);
+    }
+
+    fn finish(mut self, output_bits: u64) -> P::CShake {
+        if !self.buffer.is_empty() {
+            let digest = Self::block_digest(&self.buffer);
+            self.absorb_digest(&digest);
+        }
+        self.state.update(&right_encode(self.blocks));
+        self.state.update(&right_encode(output_bits));
+        self.state
+    }
+}
+
+// ------------------- Generic ParallelHash Implementation -------------------
+// ------------------- 通用 ParallelHash 实现 -------------------
+
+/// ParallelHash with a caller-chosen output length, generic over the cSHAKE variant.
+///
+/// ParallelHash 输出长度由调用者选择，在 cSHAKE 变体上是通用的。
+#[derive(Clone, Debug)]
+pub struct ParallelHashScheme<P: CShakeParams> {
+    inner: BlockState<P>,
+}
+
+impl<P: CShakeParams> ParallelHashScheme<P> {
+    /// Starts a ParallelHash computation with block size `B` (in bytes) and the
+    /// customization string `S`. Fails if `block_size` is zero.
+    ///
+    /// 使用块大小 `B`（以字节为单位）和定制字符串 `S` 开始一次 ParallelHash 计算。
+    /// 如果 `block_size` 为零则失败。
+    pub fn new(block_size: usize, customization: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            inner: BlockState::new(b"ParallelHash", block_size, customization)?,
+        })
+    }
+
+    /// Absorbs more data.
+    ///
+    /// 吸收更多数据。
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Consumes the state and returns `output_len` bytes of output.
+    ///
+    /// 消费状态并返回 `output_len` 字节的输出。
+    pub fn finalize(self, output_len: usize) -> Vec<u8> {
+        let state = self.inner.finish((output_len as u64) * 8);
+        let mut output = vec![0u8; output_len];
+        state.finalize_xof_into(&mut output);
+        output
+    }
+
+    /// Hashes `data` in one shot.
+    ///
+    /// 一次性对 `data` 进行哈希。
+    pub fn digest(
+        data: &[u8],
+        block_size: usize,
+        customization: &[u8],
+        output_len: usize,
+    ) -> Result<Vec<u8>, Error> {
+        let mut scheme = Self::new(block_size, customization)?;
+        scheme.update(data);
+        Ok(scheme.finalize(output_len))
+    }
+}
+
+impl<P: CShakeParams> Default for ParallelHashScheme<P> {
+    fn default() -> Self {
+        Self {
+            inner: BlockState::new_unchecked(b"ParallelHash", DEFAULT_BLOCK_SIZE, &[]),
+        }
+    }
+}
+
+impl<P: CShakeParams> ParallelUpdate for ParallelHashScheme<P> {
+    fn update_parallel(&mut self, data: &[u8]) {
+        self.inner.update_parallel(data);
+    }
+}
+
+impl<P: CShakeParams> Algorithm for ParallelHashScheme<P> {
+    fn name() -> String {
+        format!("ParallelHash{}", P::SECURITY_STRENGTH)
+    }
+    const ID: u32 = 0x05_04_00_00 + P::ID_OFFSET;
+}
+
+/// ParallelHashXOF with arbitrary-length output, generic over the cSHAKE variant.
+///
+/// 具有任意长度输出的 ParallelHashXOF，在 cSHAKE 变体上是通用的。
+#[derive(Clone, Debug)]
+pub struct ParallelHashXofScheme<P: CShakeParams> {
+    inner: BlockState<P>,
+}
+
+impl<P: CShakeParams> ParallelHashXofScheme<P> {
+    /// Starts a ParallelHashXOF computation with block size `B` (in bytes) and the
+    /// customization string `S`. Fails if `block_size` is zero.
+    ///
+    /// 使用块大小 `B`（以字节为单位）和定制字符串 `S` 开始一次 ParallelHashXOF 计算。
+    /// 如果 `block_size` 为零则失败。
+    pub fn new(block_size: usize, customization: &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            inner: BlockState::new(b"ParallelHashXOF", block_size, customization)?,
+        })
+    }
+
+    /// Absorbs more data.
+    ///
+    /// 吸收更多数据。
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Consumes the state and returns a reader for an arbitrary amount of output.
+    ///
+    /// 消费状态并返回一个可读取任意长度输出的读取器。
+    pub fn finalize_xof(self) -> XofReader<'static> {
+        XofReader::new(self.inner.finish(0).finalize_xof())
+    }
+}
+
+impl<P: CShakeParams> Default for ParallelHashXofScheme<P> {
+    fn default() -> Self {
+        Self {
+            inner: BlockState::new_unchecked(b"ParallelHashXOF", DEFAULT_BLOCK_SIZE, &[]),
+        }
+    }
+}
+
+impl<P: CShakeParams> ParallelUpdate for ParallelHashXofScheme<P> {
+    fn update_parallel(&mut self, data: &[u8]) {
+        self.inner.update_parallel(data);
+    }
+}
+
+impl<P: CShakeParams> Algorithm for ParallelHashXofScheme<P> {
+    fn name() -> String {
+        format!("ParallelHashXOF{}", P::SECURITY_STRENGTH)
+    }
+    const ID: u32 = 0x05_04_01_00 + P::ID_OFFSET;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for ParallelHash128.
+///
+/// ParallelHash128 的类型别名。
+pub type ParallelHash128 = ParallelHashScheme<CShake128Params>;
+
+/// A type alias for ParallelHash256.
+///
+/// ParallelHash256 的类型别名。
+pub type ParallelHash256 = ParallelHashScheme<CShake256Params>;
+
+/// A type alias for ParallelHashXOF128.
+///
+/// ParallelHashXOF128 的类型别名。
+pub type ParallelHashXof128 = ParallelHashXofScheme<CShake128Params>;
+
+/// A type alias for ParallelHashXOF256.
+///
+/// ParallelHashXOF256 的类型别名。
+pub type ParallelHashXof256 = ParallelHashXofScheme<CShake256Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Samples from the NIST SP 800-185 example values
+    const DATA: [u8; 24] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+        0x17, 0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27,
+    ];
+    const CUSTOMIZATION: &[u8] = b"Parallel Data";
+
+    #[test]
+    fn test_parallelhash128() {
+        assert_eq!(
+            hex::encode(ParallelHash128::digest(&DATA, 8, &[], 32).unwrap()),
+            "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5"
+        );
+        assert_eq!(
+            hex::encode(ParallelHash128::digest(&DATA, 8, CUSTOMIZATION, 32).unwrap()),
+            "fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206"
+        );
+
+        // Streaming across block boundaries, with a trailing partial block
+        let mut scheme = ParallelHash128::new(8, &[]).unwrap();
+        for piece in DATA[..20].chunks(3) {
+            scheme.update(piece);
+        }
+        assert_eq!(
+            hex::encode(scheme.finalize(32)),
+            "a725ed02aa4acb2041624b6222cd487942e03e63836b0face5dec556b9121297"
+        );
+
+        assert_eq!(
+            ParallelHash128::new(0, &[]).unwrap_err(),
+            Error::Hash(HashError::InvalidBlockSize)
+        );
+    }
+
+    #[test]
+    fn test_parallelhash_parallel_update() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let expected = ParallelHash256::digest(&data, 1024, CUSTOMIZATION, 64).unwrap();
+
+        // A partial block is buffered before and after the parallel part
+        let mut scheme = ParallelHash256::new(1024, CUSTOMIZATION).unwrap();
+        scheme.update(&data[..100]);
+        scheme.update_parallel(&data[100..90_000]);
+        scheme.update(&data[90_000..]);
+        assert_eq!(scheme.finalize(64), expected);
+
+        let mut scheme = ParallelHash256::new(1024, CUSTOMIZATION).unwrap();
+        assert_eq!(
+            scheme.update_reader(std::io::Cursor::new(&data)).unwrap(),
+            data.len() as u64
+        );
+        assert_eq!(scheme.finalize(64), expected);
+    }
+
+    #[test]
+    fn test_parallelhash256() {
+        assert_eq!(
+            hex::encode(ParallelHash256::digest(&DATA, 8, CUSTOMIZATION, 64).unwrap()),
+            "cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb\
+             33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110"
+        );
+    }
+
+    #[test]
+    fn test_parallelhash_xof128() {
+        let mut scheme = ParallelHashXof128::new(8, CUSTOMIZATION).unwrap();
+        scheme.update(&DATA);
+        let mut reader = scheme.finalize_xof();
+        let mut output = [0u8; 32];
+        reader.read(&mut output);
+        assert_eq!(
+            hex::encode(output),
+            "9c9461abd5f9c9f7ab8d14f53209898bb9c9cc5e906c9092a5b9565d9977e69a"
+        );
+    }
+}
@@ -79,11 +79,18 @@
 //! - 输出长度可以是任意的，但应匹配安全要求
 //! - 在密钥派生中考虑使用盐以获得额外的安全性
 
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use crate::{
     errors::Error,
+    policy,
     prelude::*,
 };
-use std::marker::PhantomData;
+use crate::traits::kdf::check_output_len;
+use core::marker::PhantomData;
 
 /// A generic struct representing the SHAKE cryptographic system for a given XOF.
 ///
@@ -145,6 +152,8 @@ impl<X: Xof> KeyBasedDerivation for ShakeScheme<X> {
         info: Option<&[u8]>,
         output_len: usize,
     ) -> Result<DerivedKey, Error> {
+        policy::enforce::<Self>()?;
+        check_output_len(output_len, self.max_output_len())?;
         let mut reader = X::new_xof_reader(ikm, salt, info);
         let mut okm = vec![0u8; output_len];
         reader.read(&mut okm);
@@ -160,6 +169,7 @@ impl<X: Xof> XofDerivation for ShakeScheme<X> {
         salt: Option<&'a [u8]>,
         info: Option<&'a [u8]>,
     ) -> Result<XofReader<'a>, Error> {
+        policy::enforce::<Self>()?;
         let reader = X::new_xof_reader(ikm, salt, info);
         Ok(XofReader::from_boxed(reader))
     }
@@ -0,0 +1,87 @@
+//! Encoding primitives from NIST SP 800-185, shared by the cSHAKE-derived functions.
+//!
+//! NIST SP 800-185 中的编码原语，由基于 cSHAKE 的函数共享。
+
+use alloc::{vec, vec::Vec};
+use digest::Update;
+
+/// The cSHAKE128 / KMAC128 rate in bytes.
+///
+/// cSHAKE128 / KMAC128 的速率（以字节为单位）。
+pub(crate) const RATE_128: usize = 168;
+
+/// The cSHAKE256 / KMAC256 rate in bytes.
+///
+/// cSHAKE256 / KMAC256 的速率（以字节为单位）。
+pub(crate) const RATE_256: usize = 136;
+
+/// Returns the big-endian bytes of `x` with leading zeros removed (at least one byte).
+///
+/// 返回去除前导零的 `x` 的大端字节（至少一个字节）。
+fn minimal_be_bytes(x: u64) -> ([u8; 8], usize) {
+    let bytes = x.to_be_bytes();
+    let skip = (x.leading_zeros() as usize / 8).min(7);
+    (bytes, skip)
+}
+
+/// `left_encode(x)`: the byte length of `x` followed by `x` itself.
+///
+/// `left_encode(x)`：`x` 的字节长度，后接 `x` 本身。
+pub(crate) fn left_encode(x: u64) -> Vec<u8> {
+    let (bytes, skip) = minimal_be_bytes(x);
+    let mut out = Vec::with_capacity(9);
+    out.push((8 - skip) as u8);
+    out.extend_from_slice(&bytes[skip..]);
+    out
+}
+
+/// `right_encode(x)`: `x` followed by its byte length.
+///
+/// `right_encode(x)`：`x`，后接其字节长度。
+pub(crate) fn right_encode(x: u64) -> Vec<u8> {
+    let (bytes, skip) = minimal_be_bytes(x);
+    let mut out = Vec::with_capacity(9);
+    out.extend_from_slice(&bytes[skip..]);
+    out.push((8 - skip) as u8);
+    out
+}
+
+/// Absorbs `encode_string(s)`, i.e. `left_encode(bit_len(s)) || s`, and returns the
+/// number of bytes absorbed.
+///
+/// 吸收 `encode_string(s)`，即 `left_encode(bit_len(s)) || s`，并返回吸收的字节数。
+pub(crate) fn absorb_encoded_string<U: Update>(state: &mut U, s: &[u8]) -> usize {
+    let prefix = left_encode((s.len() as u64) * 8);
+    state.update(&prefix);
+    state.update(s);
+    prefix.len() + s.len()
+}
+
+/// Absorbs `bytepad(encode_string(s_1) || ... || encode_string(s_n), rate)`.
+///
+/// 吸收 `bytepad(encode_string(s_1) || ... || encode_string(s_n), rate)`。
+pub(crate) fn absorb_bytepad<U: Update>(state: &mut U, strings: &[&[u8]], rate: usize) {
+    let prefix = left_encode(rate as u64);
+    state.update(&prefix);
+    let mut absorbed = prefix.len();
+    for s in strings {
+        absorbed += absorb_encoded_string(state, s);
+    }
+    let padding = (rate - absorbed % rate) % rate;
+    state.update(&vec![0u8; padding]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encodings() {
+        assert_eq!(left_encode(0), [1, 0]);
+        assert_eq!(left_encode(168), [1, 168]);
+        assert_eq!(left_encode(256), [2, 1, 0]);
+        assert_eq!(right_encode(0), [0, 1]);
+        assert_eq!(right_encode(256), [1, 0, 2]);
+        assert_eq!(left_encode(u64::MAX).len(), 9);
+    }
+}
@@ -0,0 +1,223 @@
+//! Provides an implementation of TupleHash from NIST SP 800-185.
+//!
+//! TupleHash hashes a sequence of byte strings so that the boundaries between them are
+//! part of the result: `("ab", "c")` and `("a", "bc")` hash to different values. Each item
+//! is absorbed as `encode_string(item)`, which makes the construction suitable for
+//! structured multi-field records without any ad-hoc length prefixing.
+//!
+//! # Algorithm Variants
+//! - **TupleHash128 / TupleHash256**: the output length is chosen by the caller and bound
+//!   into the result.
+//! - **TupleHashXOF128 / TupleHashXOF256**: arbitrary-length output read from an
+//!   [`XofReader`]; the output length is not bound into the result.
+//!
+//! 提供了 NIST SP 800-185 中 TupleHash 的实现。
+//!
+//! TupleHash 对字节串序列进行哈希，使得它们之间的边界成为结果的一部分：`("ab", "c")` 与
+//! `("a", "bc")` 的哈希值不同。每一项都以 `encode_string(item)` 的形式被吸收，
+//! 这使得该构造适用于结构化的多字段记录，而无需任何临时的长度前缀。
+//!
+//! # 算法变体
+//! - **TupleHash128 / TupleHash256**: 输出长度由调用者选择，并被绑定到结果中。
+//! - **TupleHashXOF128 / TupleHashXOF256**: 从 [`XofReader`] 读取任意长度的输出；
+//!   输出长度不会被绑定到结果中。
+
+use alloc::{format, string::String, vec, vec::Vec};
+use super::cshake::{CShake128Params, CShake256Params, CShakeParams};
+use super::sp800_185::{absorb_encoded_string, right_encode};
+use crate::prelude::*;
+use digest::{ExtendableOutput, Update};
+
+// ------------------- Generic TupleHash Implementation -------------------
+// ------------------- 通用 TupleHash 实现 -------------------
+
+/// TupleHash with a caller-chosen output length, generic over the cSHAKE variant.
+///
+/// Items are added one at a time with [`push`](Self::push); `Default` uses an empty
+/// customization string.
+///
+/// 输出长度由调用者选择的 TupleHash，在 cSHAKE 变体上是通用的。
+///
+/// 使用 [`push`](Self::push) 逐项添加；`Default` 使用空的定制字符串。
+#[derive(Clone, Debug)]
+pub struct TupleHashScheme<P: CShakeParams> {
+    state: P::CShake,
+}
+
+impl<P: CShakeParams> TupleHashScheme<P> {
+    /// Starts a TupleHash computation with the customization string `S`.
+    ///
+    /// 使用定制字符串 `S` 开始一次 TupleHash 计算。
+    pub fn new(customization: &[u8]) -> Self {
+        Self {
+            state: P::new_cshake(b"TupleHash", customization),
+        }
+    }
+
+    /// Appends one item to the tuple.
+    ///
+    /// 向元组追加一项。
+    pub fn push(&mut self, item: &[u8]) {
+        absorb_encoded_string(&mut self.state, item);
+    }
+
+    /// Consumes the state and returns `output_len` bytes of output.
+    ///
+    /// 消费状态并返回 `output_len` 字节的输出。
+    pub fn finalize(mut self, output_len: usize) -> Vec<u8> {
+        self.state.update(&right_encode((output_len as u64) * 8));
+        let mut output = vec![0u8; output_len];
+        self.state.finalize_xof_into(&mut output);
+        output
+    }
+
+    /// Hashes a complete tuple in one shot.
+    ///
+    /// 一次性对完整的元组进行哈希。
+    pub fn digest(items: &[&[u8]], customization: &[u8], output_len: usize) -> Vec<u8> {
+        let mut scheme = Self::new(customization);
+        for item in items {
+            scheme.push(item);
+        }
+        scheme.finalize(output_len)
+    }
+}
+
+impl<P: CShakeParams> Default for TupleHashScheme<P> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<P: CShakeParams> Algorithm for TupleHashScheme<P> {
+    fn name() -> String {
+        format!("TupleHash{}", P::SECURITY_STRENGTH)
+    }
+    const ID: u32 = 0x05_03_00_00 + P::ID_OFFSET;
+}
+
+/// TupleHashXOF with arbitrary-length output, generic over the cSHAKE variant.
+///
+/// 具有任意长度输出的 TupleHashXOF，在 cSHAKE 变体上是通用的。
+#[derive(Clone, Debug)]
+pub struct TupleHashXofScheme<P: CShakeParams> {
+    state: P::CShake,
+}
+
+impl<P: CShakeParams> TupleHashXofScheme<P> {
+    /// Starts a TupleHashXOF computation with the customization string `S`.
+    ///
+    /// 使用定制字符串 `S` 开始一次 TupleHashXOF 计算。
+    pub fn new(customization: &[u8]) -> Self {
+        Self {
+            state: P::new_cshake(b"TupleHashXOF", customization),
+        }
+    }
+
+    /// Appends one item to the tuple.
+    ///
+    /// 向元组追加一项。
+    pub fn push(&mut self, item: &[u8]) {
+        absorb_encoded_string(&mut self.state, item);
+    }
+
+    /// Consumes the state and returns a reader for an arbitrary amount of output.
+    ///
+    /// 消费状态并返回一个可读取任意长度输出的读取器。
+    pub fn finalize_xof(mut self) -> XofReader<'static> {
+        self.state.update(&right_encode(0));
+        XofReader::new(self.state.finalize_xof())
+    }
+}
+
+impl<P: CShakeParams> Default for TupleHashXofScheme<P> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<P: CShakeParams> Algorithm for TupleHashXofScheme<P> {
+    fn name() -> String {
+        format!("TupleHashXOF{}", P::SECURITY_STRENGTH)
+    }
+    const ID: u32 = 0x05_03_01_00 + P::ID_OFFSET;
+}
+
+// --- Type Aliases ---
+// --- 类型别名 ---
+
+/// A type alias for TupleHash128.
+///
+/// TupleHash128 的类型别名。
+pub type TupleHash128 = TupleHashScheme<CShake128Params>;
+
+/// A type alias for TupleHash256.
+///
+/// TupleHash256 的类型别名。
+pub type TupleHash256 = TupleHashScheme<CShake256Params>;
+
+/// A type alias for TupleHashXOF128.
+///
+/// TupleHashXOF128 的类型别名。
+pub type TupleHashXof128 = TupleHashXofScheme<CShake128Params>;
+
+/// A type alias for TupleHashXOF256.
+///
+/// TupleHashXOF256 的类型别名。
+pub type TupleHashXof256 = TupleHashXofScheme<CShake256Params>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Samples from the NIST SP 800-185 example values
+    const ITEMS: [&[u8]; 2] = [&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15]];
+    const CUSTOMIZATION: &[u8] = b"My Tuple App";
+
+    #[test]
+    fn test_tuplehash128() {
+        assert_eq!(
+            hex::encode(TupleHash128::digest(&ITEMS, &[], 32)),
+            "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1"
+        );
+        assert_eq!(
+            hex::encode(TupleHash128::digest(&ITEMS, CUSTOMIZATION, 32)),
+            "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb"
+        );
+
+        // Moving a byte across an item boundary changes the result
+        let shifted: [&[u8]; 2] = [&[0x00, 0x01], &[0x02, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15]];
+        assert_ne!(
+            TupleHash128::digest(&ITEMS, &[], 32),
+            TupleHash128::digest(&shifted, &[], 32)
+        );
+    }
+
+    #[test]
+    fn test_tuplehash256() {
+        let mut scheme = TupleHash256::new(CUSTOMIZATION);
+        for item in ITEMS {
+            scheme.push(item);
+        }
+        assert_eq!(
+            hex::encode(scheme.finalize(64)),
+            "147c2191d5ed7efd98dbd96d7ab5a11692576f5fe2a5065f3e33de6bba9f3aa1\
+             c4e9a068a289c61c95aab30aee1e410b0b607de3620e24a4e3bf9852a1d4367e"
+        );
+    }
+
+    #[test]
+    fn test_tuplehash_xof128() {
+        let mut scheme = TupleHashXof128::new(CUSTOMIZATION);
+        for item in ITEMS {
+            scheme.push(item);
+        }
+        let mut reader = scheme.finalize_xof();
+        let mut output = [0u8; 32];
+        reader.read(&mut output);
+        assert_eq!(
+            hex::encode(output),
+            "2284d2b4d5c8dfd1adbbf7c0f80a81f5bdfd8fc4ca192ec061729286a3b0b22e"
+        );
+    }
+}
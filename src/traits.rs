@@ -4,8 +4,10 @@
 
 pub mod algorithm;
 pub mod asymmetric;
+pub mod hash;
 pub mod kdf;
 pub mod key;
+pub mod mac;
 pub mod params;
 pub mod symmetric;
 pub mod xof;
@@ -2,10 +2,13 @@
 //!
 //! 定义了加密算法的顶层 trait。
 
+use alloc::string::String;
+use core::fmt;
+
 /// A trait that provides a unique name for a cryptographic algorithm.
 ///
 /// 为加密算法提供唯一名称的 trait。
-pub trait Algorithm: 'static + Sized + Send + Sync + Clone + Default + std::fmt::Debug {
+pub trait Algorithm: 'static + Sized + Send + Sync + Clone + Default + core::fmt::Debug {
     /// The unique name of the signature algorithm (e.g., "RSA-PSS-SHA256").
     ///
     /// 签名算法的唯一名称（例如，"RSA-PSS-SHA256"）。
@@ -15,4 +18,441 @@ pub trait Algorithm: 'static + Sized + Send + Sync + Clone + Default + std::fmt:
     ///
     /// 一个唯一的、稳定的、机器可读的算法标识符。
     const ID: u32;
+
+    /// The security level of the algorithm. Defaults to the level recorded for [`Self::ID`];
+    /// using it for an ID that has no recorded level fails to compile.
+    ///
+    /// 算法的安全级别。默认为 [`Self::ID`] 所记录的级别；对没有记录级别的 ID 使用它会导致
+    /// 编译失败。
+    const SECURITY_LEVEL: SecurityLevel = match find(Self::ID) {
+        Some(index) => KNOWN_ALGORITHMS[index].2,
+        None => panic!("no security level is recorded for this algorithm ID"),
+    };
+}
+
+/// The estimated strength of an algorithm.
+///
+/// `classical_bits` is the cost of the best known classical attack, in bits: collision
+/// resistance for unkeyed hash functions and commitments, and key recovery or forgery with a
+/// full-size key for keyed primitives, following NIST SP 800-57. `quantum_category` is the
+/// NIST post-quantum security category (1 to 5) the algorithm reaches, or `None` if a
+/// quantum computer breaks it outright.
+///
+/// 算法的估计强度。
+///
+/// `classical_bits` 是已知最佳经典攻击的代价（以位为单位）：对无密钥哈希函数和承诺而言为
+/// 抗碰撞性，对有密钥原语而言为使用完整长度密钥时的密钥恢复或伪造，遵循 NIST SP 800-57。
+/// `quantum_category` 是算法达到的 NIST 后量子安全类别（1 到 5），如果量子计算机可以直接
+/// 攻破该算法，则为 `None`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SecurityLevel {
+    /// Estimated classical security in bits.
+    ///
+    /// 估计的经典安全强度（以位为单位）。
+    pub classical_bits: u16,
+    /// NIST post-quantum security category, or `None` if not quantum-resistant.
+    ///
+    /// NIST 后量子安全类别；如果不抗量子，则为 `None`。
+    pub quantum_category: Option<u8>,
+    /// Whether the algorithm is only provided for interoperability with existing data.
+    ///
+    /// 该算法是否仅为与现有数据互操作而提供。
+    pub legacy: bool,
+    /// Whether practical attacks against the algorithm are known.
+    ///
+    /// 是否已知针对该算法的实际攻击。
+    pub broken: bool,
+}
+
+/// A level without known weaknesses.
+///
+/// 没有已知弱点的级别。
+const fn level(classical_bits: u16, quantum_category: Option<u8>) -> SecurityLevel {
+    SecurityLevel {
+        classical_bits,
+        quantum_category,
+        legacy: false,
+        broken: false,
+    }
+}
+
+/// A level for algorithms kept only for interoperability.
+///
+/// 仅为互操作而保留的算法的级别。
+const fn legacy(classical_bits: u16, quantum_category: Option<u8>, broken: bool) -> SecurityLevel {
+    SecurityLevel {
+        classical_bits,
+        quantum_category,
+        legacy: true,
+        broken,
+    }
+}
+
+/// The category of an algorithm, encoded in the highest byte of its ID.
+///
+/// 算法的类别，编码在其 ID 的最高字节中。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AlgorithmCategory {
+    /// Public-key signature, KEM and key agreement schemes (`0x01`).
+    ///
+    /// 公钥签名、KEM 和密钥协商方案 (`0x01`)。
+    Asymmetric,
+    /// Authenticated encryption with associated data (`0x02`).
+    ///
+    /// 带关联数据的认证加密 (`0x02`)。
+    Aead,
+    /// Key and password derivation functions (`0x03`).
+    ///
+    /// 密钥和密码派生函数 (`0x03`)。
+    Kdf,
+    /// Fixed-output hash functions (`0x04`).
+    ///
+    /// 固定输出哈希函数 (`0x04`)。
+    Hash,
+    /// Extendable-output functions (`0x05`).
+    ///
+    /// 可扩展输出函数 (`0x05`)。
+    Xof,
+    /// Message authentication codes (`0x06`).
+    ///
+    /// 消息认证码 (`0x06`)。
+    Mac,
+    /// One-time password algorithms (`0x07`).
+    ///
+    /// 一次性密码算法 (`0x07`)。
+    Otp,
+    /// Commitment schemes (`0x08`).
+    ///
+    /// 承诺方案 (`0x08`)。
+    Commitment,
+}
+
+impl AlgorithmCategory {
+    /// Decodes the category byte of an ID, or returns `None` for an unassigned byte.
+    ///
+    /// 解码 ID 的类别字节；对于未分配的字节返回 `None`。
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x01 => Some(Self::Asymmetric),
+            0x02 => Some(Self::Aead),
+            0x03 => Some(Self::Kdf),
+            0x04 => Some(Self::Hash),
+            0x05 => Some(Self::Xof),
+            0x06 => Some(Self::Mac),
+            0x07 => Some(Self::Otp),
+            0x08 => Some(Self::Commitment),
+            _ => None,
+        }
+    }
+
+    /// Returns the category byte used in IDs.
+    ///
+    /// 返回 ID 中使用的类别字节。
+    pub const fn to_byte(self) -> u8 {
+        match self {
+            Self::Asymmetric => 0x01,
+            Self::Aead => 0x02,
+            Self::Kdf => 0x03,
+            Self::Hash => 0x04,
+            Self::Xof => 0x05,
+            Self::Mac => 0x06,
+            Self::Otp => 0x07,
+            Self::Commitment => 0x08,
+        }
+    }
+}
+
+impl fmt::Display for AlgorithmCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Asymmetric => "asymmetric",
+            Self::Aead => "AEAD",
+            Self::Kdf => "KDF",
+            Self::Hash => "hash",
+            Self::Xof => "XOF",
+            Self::Mac => "MAC",
+            Self::Otp => "OTP",
+            Self::Commitment => "commitment",
+        })
+    }
+}
+
+/// A structured view of an [`Algorithm::ID`].
+///
+/// IDs are laid out as `0xCC_FF_VV_RR`: the category byte, the algorithm family within the
+/// category, the variant within the family, and the revision or parameter set of the
+/// variant. `Display` prints the algorithm name along with the hex ID, e.g.
+/// `SHA-256 (0x04010001)`, so logged and persisted IDs can be read without the source.
+///
+/// [`Algorithm::ID`] 的结构化视图。
+///
+/// ID 的布局为 `0xCC_FF_VV_RR`：类别字节、类别内的算法族、族内的变体，以及变体的修订版或
+/// 参数集。`Display` 会输出算法名称及十六进制 ID，例如 `SHA-256 (0x04010001)`，
+/// 因此无需查看源码即可读懂日志和持久化数据中的 ID。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlgorithmId(u32);
+
+impl AlgorithmId {
+    /// Wraps a raw ID.
+    ///
+    /// 包装原始 ID。
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Returns the ID of the algorithm `A`.
+    ///
+    /// 返回算法 `A` 的 ID。
+    pub const fn of<A: Algorithm>() -> Self {
+        Self(A::ID)
+    }
+
+    /// Returns the raw ID.
+    ///
+    /// 返回原始 ID。
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the category of the ID, or `None` if its category byte is unassigned.
+    ///
+    /// 返回 ID 的类别；如果其类别字节未分配，则返回 `None`。
+    pub const fn category(self) -> Option<AlgorithmCategory> {
+        AlgorithmCategory::from_byte(self.0.to_be_bytes()[0])
+    }
+
+    /// Returns the family byte.
+    ///
+    /// 返回算法族字节。
+    pub const fn family(self) -> u8 {
+        self.0.to_be_bytes()[1]
+    }
+
+    /// Returns the variant byte.
+    ///
+    /// 返回变体字节。
+    pub const fn variant(self) -> u8 {
+        self.0.to_be_bytes()[2]
+    }
+
+    /// Returns the revision byte.
+    ///
+    /// 返回修订字节。
+    pub const fn revision(self) -> u8 {
+        self.0.to_be_bytes()[3]
+    }
+
+    /// Returns the name of the algorithm with this ID, as given by [`Algorithm::name`], or
+    /// `None` if the ID is not assigned. The lookup covers every algorithm of this crate,
+    /// whether or not its feature is enabled.
+    ///
+    /// 返回具有此 ID 的算法名称（与 [`Algorithm::name`] 一致）；如果该 ID 未分配，则返回
+    /// `None`。查找范围涵盖本 crate 的所有算法，无论其特性是否启用。
+    pub fn name(self) -> Option<&'static str> {
+        find(self.0).map(|index| KNOWN_ALGORITHMS[index].1)
+    }
+
+    /// Returns the recorded security level of the algorithm with this ID, or `None` if the
+    /// ID is not assigned.
+    ///
+    /// 返回具有此 ID 的算法所记录的安全级别；如果该 ID 未分配，则返回 `None`。
+    pub fn security_level(self) -> Option<SecurityLevel> {
+        find(self.0).map(|index| KNOWN_ALGORITHMS[index].2)
+    }
+}
+
+impl From<u32> for AlgorithmId {
+    fn from(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<AlgorithmId> for u32 {
+    fn from(id: AlgorithmId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for AlgorithmId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.name(), self.category()) {
+            (Some(name), _) => write!(f, "{name} ({:#010x})", self.0),
+            (None, Some(category)) => write!(f, "unknown {category} ({:#010x})", self.0),
+            (None, None) => write!(f, "unknown ({:#010x})", self.0),
+        }
+    }
+}
+
+/// Returns the index of `id` in [`KNOWN_ALGORITHMS`].
+///
+/// 返回 `id` 在 [`KNOWN_ALGORITHMS`] 中的索引。
+const fn find(id: u32) -> Option<usize> {
+    let (mut low, mut high) = (0, KNOWN_ALGORITHMS.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        let entry = KNOWN_ALGORITHMS[mid].0;
+        if entry == id {
+            return Some(mid);
+        } else if entry < id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    None
+}
+
+/// The names and security levels of all assigned IDs, sorted by ID.
+///
+/// KBKDF IDs are derived from the low 16 bits of the MAC ID, so KBKDF over KMAC shares its
+/// IDs with KBKDF over HMAC; those IDs are listed under their HMAC names.
+///
+/// 所有已分配 ID 的名称和安全级别，按 ID 排序。
+///
+/// KBKDF 的 ID 由 MAC ID 的低 16 位派生，因此基于 KMAC 的 KBKDF 与基于 HMAC 的 KBKDF
+/// 共用 ID；这些 ID 以其 HMAC 名称列出。
+const KNOWN_ALGORITHMS: &[(u32, &str, SecurityLevel)] = &[
+    (0x01_01_01_01, "RSA-PSS-2048-SHA-256", level(112, None)),
+    (0x01_01_01_02, "RSA-PSS-2048-SHA-384", level(112, None)),
+    (0x01_01_01_03, "RSA-PSS-2048-SHA-512", level(112, None)),
+    (0x01_01_01_11, "RSA-PSS-4096-SHA-256", level(140, None)),
+    (0x01_01_01_12, "RSA-PSS-4096-SHA-384", level(140, None)),
+    (0x01_01_01_13, "RSA-PSS-4096-SHA-512", level(140, None)),
+    (0x01_01_02_01, "ECDSA-P256-SHA256", level(128, None)),
+    (0x01_01_02_02, "Ed25519", level(128, None)),
+    (0x01_01_02_03, "ECDSA-secp256k1-SHA256", level(128, None)),
+    (0x01_01_03_01, "ECDH-P256", level(128, None)),
+    (0x01_01_04_01, "SM2", level(128, None)),
+    (0x01_02_01_02, "Dilithium-Dilithium2", level(128, Some(2))),
+    (0x01_02_01_03, "Dilithium-Dilithium3", level(192, Some(3))),
+    (0x01_02_01_05, "Dilithium-Dilithium5", level(256, Some(5))),
+    (0x01_02_02_01, "KYBER-KEM-Kyber512", level(128, Some(1))),
+    (0x01_02_02_02, "KYBER-KEM-Kyber768", level(192, Some(3))),
+    (0x01_02_02_03, "KYBER-KEM-Kyber1024", level(256, Some(5))),
+    (0x02_01_01_01, "AES-128-GCM", level(128, Some(1))),
+    (0x02_01_01_02, "AES-256-GCM", level(256, Some(5))),
+    (0x02_02_01_01, "ChaCha20-Poly1305", level(256, Some(5))),
+    (0x02_02_02_01, "XChaCha20-Poly1305", level(256, Some(5))),
+    (0x02_03_01_01, "SM4-GCM", level(128, Some(1))),
+    (0x02_03_02_01, "SM4-CCM", level(128, Some(1))),
+    (0x03_01_01_01, "Argon2id", level(256, Some(5))),
+    (0x03_01_01_02, "Argon2i", level(256, Some(5))),
+    (0x03_01_01_03, "Argon2d", level(256, Some(5))),
+    (0x03_02_00_01, "HKDF-SHA-256", level(256, Some(5))),
+    (0x03_02_00_02, "HKDF-SHA-384", level(256, Some(5))),
+    (0x03_02_00_03, "HKDF-SHA-512", level(256, Some(5))),
+    (0x03_03_00_01, "PBKDF2-HMAC-SHA-256", level(256, Some(5))),
+    (0x03_03_00_02, "PBKDF2-HMAC-SHA-384", level(256, Some(5))),
+    (0x03_03_00_03, "PBKDF2-HMAC-SHA-512", level(256, Some(5))),
+    (0x03_04_00_01, "scrypt", level(256, Some(5))),
+    (0x03_05_00_01, "KBKDF-HMAC-SHA-256", level(256, Some(5))),
+    (0x03_05_00_02, "KBKDF-HMAC-SHA-384", level(256, Some(5))),
+    (0x03_05_00_03, "KBKDF-HMAC-SHA-512", level(256, Some(5))),
+    (0x03_05_01_01, "KBKDF-AES-128-CMAC", level(128, Some(1))),
+    (0x03_05_01_02, "KBKDF-AES-256-CMAC", level(256, Some(5))),
+    (0x03_06_00_01, "X9.63-KDF-SHA-256", level(256, Some(5))),
+    (0x03_06_00_02, "X9.63-KDF-SHA-384", level(256, Some(5))),
+    (0x03_06_00_03, "X9.63-KDF-SHA-512", level(256, Some(5))),
+    (0x03_07_00_01, "ConcatKDF-SHA-256", level(256, Some(5))),
+    (0x03_07_00_02, "ConcatKDF-SHA-384", level(256, Some(5))),
+    (0x03_07_00_03, "ConcatKDF-SHA-512", level(256, Some(5))),
+    (0x04_01_00_01, "SHA-256", level(128, Some(2))),
+    (0x04_01_00_02, "SHA-384", level(192, Some(4))),
+    (0x04_01_00_03, "SHA-512", level(256, Some(5))),
+    (0x04_02_00_01, "SHA3-256", level(128, Some(2))),
+    (0x04_02_00_02, "SHA3-384", level(192, Some(4))),
+    (0x04_02_00_03, "SHA3-512", level(256, Some(5))),
+    (0x04_03_00_01, "BLAKE2b-512", level(256, Some(5))),
+    (0x04_03_00_02, "BLAKE2b-256", level(128, Some(2))),
+    (0x04_03_00_03, "BLAKE2s-256", level(128, Some(2))),
+    (0x04_04_00_01, "BLAKE3", level(128, Some(2))),
+    (0x04_05_00_01, "SHA-1", legacy(63, None, true)),
+    (0x04_05_00_02, "MD5", legacy(18, None, true)),
+    (0x04_06_00_01, "SM3", level(128, None)),
+    (0x05_01_00_01, "SHAKE128", level(128, Some(2))),
+    (0x05_01_00_02, "SHAKE256", level(256, Some(5))),
+    (0x05_02_00_01, "cSHAKE128", level(128, Some(2))),
+    (0x05_02_00_02, "cSHAKE256", level(256, Some(5))),
+    (0x05_03_00_01, "TupleHash128", level(128, Some(2))),
+    (0x05_03_00_02, "TupleHash256", level(256, Some(5))),
+    (0x05_03_01_01, "TupleHashXOF128", level(128, Some(2))),
+    (0x05_03_01_02, "TupleHashXOF256", level(256, Some(5))),
+    (0x05_04_00_01, "ParallelHash128", level(128, Some(2))),
+    (0x05_04_00_02, "ParallelHash256", level(256, Some(5))),
+    (0x05_04_01_01, "ParallelHashXOF128", level(128, Some(2))),
+    (0x05_04_01_02, "ParallelHashXOF256", level(256, Some(5))),
+    (0x06_01_00_01, "HMAC-SHA-256", level(256, Some(5))),
+    (0x06_01_00_02, "HMAC-SHA-384", level(256, Some(5))),
+    (0x06_01_00_03, "HMAC-SHA-512", level(256, Some(5))),
+    (0x06_01_01_01, "HMAC-SHA-1", legacy(128, Some(1), false)),
+    (0x06_01_01_02, "HMAC-MD5", legacy(128, Some(1), false)),
+    (0x06_02_01_01, "AES-128-CMAC", level(128, Some(1))),
+    (0x06_02_01_02, "AES-256-CMAC", level(256, Some(5))),
+    (0x06_03_00_01, "KMAC128", level(128, Some(1))),
+    (0x06_03_00_02, "KMAC256", level(256, Some(5))),
+    (0x06_04_01_01, "AES-128-GMAC", level(128, Some(1))),
+    (0x06_04_01_02, "AES-256-GMAC", level(256, Some(5))),
+    (0x07_01_00_01, "HOTP-SHA-256", level(256, Some(5))),
+    (0x07_01_00_02, "HOTP-SHA-384", level(256, Some(5))),
+    (0x07_01_00_03, "HOTP-SHA-512", level(256, Some(5))),
+    (0x07_02_00_01, "TOTP-SHA-256", level(256, Some(5))),
+    (0x07_02_00_02, "TOTP-SHA-384", level(256, Some(5))),
+    (0x07_02_00_03, "TOTP-SHA-512", level(256, Some(5))),
+    (0x08_01_00_01, "Commitment-SHA-256", level(128, Some(2))),
+    (0x08_01_00_02, "Commitment-SHA-384", level(192, Some(4))),
+    (0x08_01_00_03, "Commitment-SHA-512", level(256, Some(5))),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_id_fields() {
+        let id = AlgorithmId::new(0x01_02_02_03);
+        assert_eq!(id.category(), Some(AlgorithmCategory::Asymmetric));
+        assert_eq!(id.family(), 0x02);
+        assert_eq!(id.variant(), 0x02);
+        assert_eq!(id.revision(), 0x03);
+        assert_eq!(id.name(), Some("KYBER-KEM-Kyber1024"));
+        assert_eq!(id.to_string(), "KYBER-KEM-Kyber1024 (0x01020203)");
+        assert_eq!(u32::from(id), 0x01_02_02_03);
+
+        assert_eq!(
+            AlgorithmId::new(0x04_7f_00_01).to_string(),
+            "unknown hash (0x047f0001)"
+        );
+        assert_eq!(AlgorithmId::new(0).to_string(), "unknown (0x00000000)");
+        assert_eq!(AlgorithmId::new(0).category(), None);
+    }
+
+    #[test]
+    fn test_security_levels() {
+        let sha1 = AlgorithmId::new(0x04_05_00_01).security_level();
+        assert!(sha1.is_some_and(|level| level.legacy && level.broken));
+
+        let kyber768 = AlgorithmId::new(0x01_02_02_02).security_level();
+        assert_eq!(kyber768.and_then(|level| level.quantum_category), Some(3));
+
+        let rsa2048 = AlgorithmId::new(0x01_01_01_01).security_level();
+        assert!(rsa2048.is_some_and(|level| level.classical_bits < 128));
+        assert_eq!(AlgorithmId::new(0).security_level(), None);
+    }
+
+    #[test]
+    fn test_known_algorithms_sorted() {
+        assert!(
+            KNOWN_ALGORITHMS
+                .windows(2)
+                .all(|pair| pair[0].0 < pair[1].0)
+        );
+        for &(id, _, _) in KNOWN_ALGORITHMS {
+            let category = AlgorithmId::new(id).category();
+            assert_eq!(
+                category.map(AlgorithmCategory::to_byte),
+                Some(id.to_be_bytes()[0])
+            );
+        }
+    }
 }
@@ -2,9 +2,24 @@
 //!
 //! 定义了非对称加密操作的 trait。
 
+use alloc::{vec, vec::Vec};
+use crate::ct::{Choice, ConstantTimeEq};
 use crate::errors::Error;
+use crate::traits::kdf::{DerivedKey, KeyBasedDerivation};
 use crate::traits::key::Key;
-use crate::traits::key::AsymmetricKeySet;
+use crate::traits::key::{AsymmetricKeySet, ExposeSecretBytes, KeyError};
+#[cfg(feature = "sha2")]
+use crate::traits::params::Hasher;
+use crate::traits::symmetric::SymmetricKeyGenerator;
+use core::fmt;
+/// Re-export of the `rand_core` 0.6 trait for cryptographically secure RNGs, as accepted by
+/// [`KeyGenerator::generate_keypair_with_rng`] and `RandomizedSigner::sign_with_rng`.
+///
+/// 重新导出 `rand_core` 0.6 中用于密码学安全 RNG 的 trait，供
+/// [`KeyGenerator::generate_keypair_with_rng`] 和 `RandomizedSigner::sign_with_rng` 使用。
+pub use rand_core_elliptic_curve::CryptoRngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 #[cfg(feature = "std")]
 use thiserror::Error;
 use zeroize::Zeroizing;
@@ -24,6 +39,74 @@ pub trait KeyGenerator: AsymmetricKeySet {
     /// # 返回
     /// 一个包含密钥对的 `Result`，如果生成失败则返回错误。
     fn generate_keypair() -> Result<(Self::PublicKey, Self::PrivateKey), Error>;
+
+    /// Generates a new key pair using randomness drawn from `rng`.
+    ///
+    /// This is for environments where the operating system RNG used by
+    /// [`generate_keypair`](Self::generate_keypair) is prohibited or unavailable, such as a
+    /// certified DRBG or deterministic simulation tests.
+    ///
+    /// # Returns
+    /// A result containing the key pair, or an error if generation fails.
+    ///
+    /// 使用从 `rng` 中获取的随机性生成一个新的密钥对。
+    ///
+    /// 适用于禁止使用或无法使用 [`generate_keypair`](Self::generate_keypair) 所用的操作系统 RNG
+    /// 的环境，例如经过认证的 DRBG 或确定性模拟测试。
+    ///
+    /// # 返回
+    /// 一个包含密钥对的 `Result`，如果生成失败则返回错误。
+    fn generate_keypair_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::PrivateKey), Error>;
+}
+
+// --- Public Key Derivation ---
+/// A trait for schemes whose public key can be recomputed from the private key alone.
+///
+/// This allows storing only the private key and restoring the full key pair on load.
+///
+/// 用于可仅从私钥重新计算公钥的方案的 trait。
+///
+/// 这样只需存储私钥，即可在加载时恢复完整的密钥对。
+pub trait PublicKeyDerivation: AsymmetricKeySet {
+    /// Derives the public key that belongs to `private_key`.
+    ///
+    /// # Returns
+    /// The matching public key, or an error if the private key is malformed.
+    ///
+    /// 派生属于 `private_key` 的公钥。
+    ///
+    /// # 返回
+    /// 匹配的公钥，如果私钥格式错误则返回错误。
+    fn derive_public_key(private_key: &Self::PrivateKey) -> Result<Self::PublicKey, Error>;
+
+    /// Checks that `public_key` belongs to `private_key` by comparing it with the public key
+    /// derived from `private_key`.
+    ///
+    /// This is a cheap guard for keys loaded from external storage, which may have been mixed
+    /// up or corrupted.
+    ///
+    /// # Returns
+    /// `Ok(())` if the keys belong together, `KeyError::KeyPairMismatch` if they do not, or
+    /// another error if the private key is malformed.
+    ///
+    /// 通过与从 `private_key` 派生的公钥进行比较，检查 `public_key` 是否属于 `private_key`。
+    ///
+    /// 这是针对从外部存储加载的密钥的低成本防护，这些密钥可能已被混淆或损坏。
+    ///
+    /// # 返回
+    /// 如果密钥相互匹配，则返回 `Ok(())`；如果不匹配，则返回 `KeyError::KeyPairMismatch`；
+    /// 如果私钥格式错误，则返回其他错误。
+    fn validate_keypair(
+        public_key: &Self::PublicKey,
+        private_key: &Self::PrivateKey,
+    ) -> Result<(), Error> {
+        if Self::derive_public_key(private_key)?.to_bytes()? != public_key.to_bytes()? {
+            return Err(Error::Key(KeyError::KeyPairMismatch));
+        }
+        Ok(())
+    }
 }
 
 // --- Signer / Verifier ---
@@ -57,16 +140,95 @@ pub enum SignatureError {
     /// 提供的签名格式错误或长度无效。
     #[cfg_attr(feature = "std", error("Invalid signature format"))]
     InvalidSignature,
+
+    /// The provided digest does not match the output size of the selected hash algorithm.
+    ///
+    /// 提供的摘要与所选哈希算法的输出大小不匹配。
+    #[cfg_attr(feature = "std", error("Invalid digest length"))]
+    InvalidDigestLength,
+
+    /// The provided context string is longer than 255 bytes.
+    ///
+    /// 提供的上下文字符串超过 255 字节。
+    #[cfg_attr(feature = "std", error("Context string too long"))]
+    InvalidContext,
+
+    /// The provided output buffer is too small to hold the signature.
+    ///
+    /// 提供的输出缓冲区太小，无法容纳签名。
+    #[cfg_attr(feature = "std", error("Output buffer too small"))]
+    OutputTooSmall,
+
+    /// A participant's share of a threshold signature is invalid.
+    ///
+    /// 某个参与者的门限签名份额无效。
+    #[cfg_attr(feature = "std", error("Invalid signature share from participant {0}"))]
+    InvalidSignatureShare(u16),
+}
+
+/// Copies a signature into `output`, returning the number of bytes written.
+///
+/// 将签名复制到 `output` 中，并返回写入的字节数。
+#[cfg(any(
+    feature = "rsa-default",
+    feature = "ecc-default",
+    feature = "dilithium-default",
+    feature = "sm-default"
+))]
+pub(crate) fn write_signature(signature: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+    let out = output
+        .get_mut(..signature.len())
+        .ok_or(Error::Signature(SignatureError::OutputTooSmall))?;
+    out.copy_from_slice(signature);
+    Ok(signature.len())
 }
 
 /// A trait for cryptographic schemes that can create digital signatures.
 ///
 /// 用于能够创建数字签名的加密方案的 trait。
 pub trait Signer: AsymmetricKeySet {
+    /// The maximum size of a signature produced by this scheme, in bytes.
+    ///
+    /// 此方案产生的签名的最大大小（以字节为单位）。
+    const MAX_SIGNATURE_SIZE: usize;
+
     /// Creates a digital signature for a given message digest.
     ///
     /// 为给定的消息摘要创建一个数字签名。
-    fn sign(private_key: &Self::PrivateKey, message: &[u8]) -> Result<Signature, Error>;
+    fn sign(private_key: &Self::PrivateKey, message: &[u8]) -> Result<Signature, Error> {
+        let mut buffer = vec![0u8; Self::MAX_SIGNATURE_SIZE];
+        let bytes_written = Self::sign_to_buffer(private_key, message, &mut buffer)?;
+        buffer.truncate(bytes_written);
+        Ok(buffer)
+    }
+
+    /// Creates a digital signature for a given message and writes it into the provided buffer.
+    ///
+    /// # Arguments
+    /// * `output` - The buffer to write the signature to. A buffer of
+    ///   `MAX_SIGNATURE_SIZE` bytes is always large enough.
+    ///
+    /// # Returns
+    /// The number of bytes written to `output`.
+    ///
+    /// The ECDSA, Ed25519 and Dilithium schemes of this crate sign without heap allocation.
+    /// RSA and SM2 still allocate while signing and only copy the result into `output`.
+    ///
+    /// 为给定消息创建数字签名，并将其写入提供的缓冲区。
+    ///
+    /// # 参数
+    /// * `output` - 用于写入签名的缓冲区。`MAX_SIGNATURE_SIZE` 字节的缓冲区总是足够大。
+    ///
+    /// # 返回
+    /// 写入 `output` 的字节数。
+    ///
+    /// 本 crate 的 ECDSA、Ed25519 和 Dilithium 方案签名时不进行堆分配。RSA 和 SM2 签名时仍会
+    /// 分配内存，只是将结果复制到 `output` 中。
+    fn sign_to_buffer(
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize, Error>;
 }
 
 /// A trait for cryptographic schemes that can verify digital signatures.
@@ -89,6 +251,80 @@ pub trait Verifier: AsymmetricKeySet {
     ) -> Result<(), Error>;
 }
 
+/// A trait for signature schemes whose public keys can be decoded and validated once,
+/// then reused to verify many signatures.
+///
+/// 用于公钥可以一次性解码和验证、然后重复用于验证多个签名的签名方案的 trait。
+pub trait PreparableVerifier: Verifier {
+    /// The decoded, validated form of a public key.
+    ///
+    /// 公钥经过解码和验证后的形式。
+    type PreparedKey: Clone + Send + Sync;
+
+    /// Decodes and validates a public key for repeated verification.
+    ///
+    /// 解码并验证公钥，以供重复验证使用。
+    fn prepare_public_key(public_key: &Self::PublicKey) -> Result<Self::PreparedKey, Error>;
+
+    /// Verifies a digital signature using a prepared public key.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid, otherwise an `Err`.
+    ///
+    /// 使用预处理的公钥验证数字签名。
+    ///
+    /// # 返回
+    /// 如果签名有效，则返回 `Ok(())`，否则返回 `Err`。
+    fn verify_prepared(
+        prepared_key: &Self::PreparedKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error>;
+}
+
+/// A verifying key that has been decoded and validated once and can then verify many messages.
+///
+/// This avoids re-decoding the public key bytes on every call to [`Verifier::verify`].
+///
+/// 一个已经过一次性解码和验证、随后可以验证多条消息的验证密钥。
+///
+/// 这避免了每次调用 [`Verifier::verify`] 时都重新解码公钥字节。
+pub struct PreparedVerifier<S: PreparableVerifier> {
+    key: S::PreparedKey,
+}
+
+impl<S: PreparableVerifier> PreparedVerifier<S> {
+    /// Decodes and validates the given public key.
+    ///
+    /// 解码并验证给定的公钥。
+    pub fn new(public_key: &S::PublicKey) -> Result<Self, Error> {
+        Ok(Self {
+            key: S::prepare_public_key(public_key)?,
+        })
+    }
+
+    /// Verifies a digital signature for a given message.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid, otherwise an `Err`.
+    ///
+    /// 验证给定消息的数字签名。
+    ///
+    /// # 返回
+    /// 如果签名有效，则返回 `Ok(())`，否则返回 `Err`。
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        S::verify_prepared(&self.key, message, signature)
+    }
+}
+
+impl<S: PreparableVerifier> Clone for PreparedVerifier<S> {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+        }
+    }
+}
+
 /// A unified trait for a complete signature scheme.
 ///
 /// It combines key generation, signing, and verification capabilities.
@@ -102,14 +338,287 @@ pub trait SignatureScheme: KeyGenerator + Signer + Verifier {}
 
 impl<T: KeyGenerator + Signer + Verifier> SignatureScheme for T {}
 
+// --- Async Signer / Verifier ---
+/// An asynchronous counterpart of [`Signer`], for schemes whose private keys live in a
+/// remote backend such as a cloud KMS or an HSM.
+///
+/// Every synchronous [`Signer`] implements this trait through a blanket impl that resolves
+/// immediately. Remote backends implement it directly, using a key handle (e.g. a KMS key
+/// identifier) as `Self::PrivateKey`.
+///
+/// [`Signer`] 的异步版本，用于私钥存放在远程后端（如云 KMS 或 HSM）中的方案。
+///
+/// 每个同步的 [`Signer`] 都通过一个立即完成的 blanket impl 实现此 trait。
+/// 远程后端直接实现此 trait，使用密钥句柄（例如 KMS 密钥标识符）作为 `Self::PrivateKey`。
+#[cfg(feature = "async")]
+pub trait AsyncSigner: AsymmetricKeySet {
+    /// Asynchronously creates a digital signature for a given message.
+    ///
+    /// 异步地为给定消息创建数字签名。
+    fn sign_async(
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+    ) -> impl Future<Output = Result<Signature, Error>> + Send;
+}
+
+/// An asynchronous counterpart of [`Verifier`].
+///
+/// Every synchronous [`Verifier`] implements this trait through a blanket impl that resolves
+/// immediately.
+///
+/// [`Verifier`] 的异步版本。
+///
+/// 每个同步的 [`Verifier`] 都通过一个立即完成的 blanket impl 实现此 trait。
+#[cfg(feature = "async")]
+pub trait AsyncVerifier: AsymmetricKeySet {
+    /// Asynchronously verifies a digital signature for a given message.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid, otherwise an `Err`.
+    ///
+    /// 异步地验证给定消息的数字签名。
+    ///
+    /// # 返回
+    /// 如果签名有效，则返回 `Ok(())`，否则返回 `Err`。
+    fn verify_async(
+        public_key: &Self::PublicKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+#[cfg(feature = "async")]
+impl<T: Signer> AsyncSigner for T {
+    fn sign_async(
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+    ) -> impl Future<Output = Result<Signature, Error>> + Send {
+        core::future::ready(T::sign(private_key, message))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Verifier> AsyncVerifier for T {
+    fn verify_async(
+        public_key: &Self::PublicKey,
+        message: &[u8],
+        signature: &Signature,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        core::future::ready(T::verify(public_key, message, signature))
+    }
+}
+
+/// The maximum length of a signature context string, in bytes.
+///
+/// 签名上下文字符串的最大长度（以字节为单位）。
+pub const MAX_CONTEXT_LEN: usize = 255;
+
+/// A trait for signature schemes whose signing operation consumes randomness.
+///
+/// `Signer::sign` draws randomness from the operating system RNG; this trait lets callers
+/// supply their own cryptographically secure RNG instead, e.g. a certified DRBG. It is
+/// implemented for RSA-PSS, ECDSA and SM2. Deterministic schemes such as Ed25519 and the
+/// Dilithium implementation in this crate do not consume randomness and do not implement it.
+///
+/// 用于签名操作需要消耗随机性的签名方案的 trait。
+///
+/// `Signer::sign` 从操作系统 RNG 中获取随机性；此 trait 允许调用者提供自己的密码学安全 RNG，
+/// 例如经过认证的 DRBG。它为 RSA-PSS、ECDSA 和 SM2 实现。确定性方案（如 Ed25519
+/// 和此 crate 中的 Dilithium 实现）不消耗随机性，因此不实现此 trait。
+#[cfg(any(
+    feature = "rsa-default",
+    feature = "ecc-default",
+    feature = "sm-default"
+))]
+pub trait RandomizedSigner: Signer {
+    /// Creates a digital signature for a message using randomness drawn from `rng`.
+    ///
+    /// 使用从 `rng` 中获取的随机性为消息创建数字签名。
+    fn sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        private_key: &Self::PrivateKey,
+        message: &[u8],
+    ) -> Result<Signature, Error>;
+}
+
+/// A trait for signature schemes that bind an application context string into the signature.
+///
+/// A signature created under one context does not verify under another context, nor as a
+/// plain signature from [`Signer::sign`]. It is only implemented by schemes that keep the two
+/// message spaces apart: Ed25519 binds the context natively as Ed25519ctx from RFC 8032, and
+/// Dilithium frames every message it signs, plain ones as a context signature with an empty
+/// context. RSA-PSS and ECDSA have no context input and do not implement it, because prefixing
+/// the message would produce signatures that plain signing can forge. The context must be at
+/// most 255 bytes.
+///
+/// 用于将应用上下文字符串绑定到签名中的签名方案的 trait。
+///
+/// 在一个上下文下创建的签名不会在另一个上下文下验证通过，也不会作为 [`Signer::sign`]
+/// 的普通签名验证通过。它只由能使两种消息空间互不相交的方案实现：Ed25519 通过 RFC 8032 的
+/// Ed25519ctx 原生绑定上下文，Dilithium 则对其签名的每条消息进行封装，普通消息被视为上下文为空的
+/// 上下文签名。RSA-PSS 和 ECDSA 没有上下文输入，不实现此 trait，因为给消息加前缀所得的签名
+/// 可以由普通签名伪造。上下文最多为 255 字节。
+pub trait ContextSigner: Signer {
+    /// Creates a digital signature for a message under the given context.
+    ///
+    /// 在给定上下文下为消息创建数字签名。
+    fn sign_with_context(
+        private_key: &Self::PrivateKey,
+        context: &[u8],
+        message: &[u8],
+    ) -> Result<Signature, Error>;
+}
+
+/// A trait for signature schemes that verify signatures bound to an application context string.
+///
+/// 用于验证绑定到应用上下文字符串的签名的签名方案的 trait。
+pub trait ContextVerifier: Verifier {
+    /// Verifies a digital signature for a message under the given context.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid for this context, otherwise an `Err`.
+    ///
+    /// 在给定上下文下验证消息的数字签名。
+    ///
+    /// # 返回
+    /// 如果签名在此上下文下有效，则返回 `Ok(())`，否则返回 `Err`。
+    fn verify_with_context(
+        public_key: &Self::PublicKey,
+        context: &[u8],
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error>;
+}
+
+/// A trait for signature schemes that can sign a pre-computed message digest.
+///
+/// This is useful when the digest is computed elsewhere (e.g. by a remote front-end)
+/// and only the hash is shipped to the signing host. The hash algorithm used to
+/// compute the digest is identified by the type parameter `H`.
+///
+/// 用于能够对预先计算的消息摘要进行签名的签名方案的 trait。
+///
+/// 当摘要在其他地方计算（例如由远程前端计算），并且只有哈希值被发送到签名主机时，
+/// 这非常有用。用于计算摘要的哈希算法由类型参数 `H` 标识。
+#[cfg(feature = "sha2")]
+pub trait DigestSigner: AsymmetricKeySet {
+    /// Creates a digital signature for a digest computed with the hash algorithm `H`.
+    ///
+    /// # Errors
+    /// Returns `SignatureError::InvalidDigestLength` if `digest` is not `H::OUTPUT_SIZE` bytes.
+    ///
+    /// 为使用哈希算法 `H` 计算的摘要创建数字签名。
+    ///
+    /// # 错误
+    /// 如果 `digest` 的长度不是 `H::OUTPUT_SIZE` 字节，则返回 `SignatureError::InvalidDigestLength`。
+    fn sign_digest<H: Hasher>(
+        private_key: &Self::PrivateKey,
+        digest: &[u8],
+    ) -> Result<Signature, Error>;
+}
+
+/// A trait for signature schemes that can verify a signature over a pre-computed message digest.
+///
+/// 用于能够验证对预先计算的消息摘要的签名的签名方案的 trait。
+#[cfg(feature = "sha2")]
+pub trait DigestVerifier: AsymmetricKeySet {
+    /// Verifies a digital signature for a digest computed with the hash algorithm `H`.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid, otherwise an `Err`.
+    ///
+    /// 验证使用哈希算法 `H` 计算的摘要的数字签名。
+    ///
+    /// # 返回
+    /// 如果签名有效，则返回 `Ok(())`，否则返回 `Err`。
+    fn verify_digest<H: Hasher>(
+        public_key: &Self::PublicKey,
+        digest: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error>;
+}
+
 // --- KEM ---
-/// A secret value, derived from a KEM, suitable for use as a symmetric key.
-/// It is wrapped in `Zeroizing` to ensure it's wiped from memory when dropped.
+/// A secret established by a KEM or a key agreement, wiped from memory when dropped.
 ///
-/// 一个从 KEM 派生的秘密值，适合用作对称密钥。
-/// 它被包装在 `Zeroizing` 中，以确保在被丢弃时从内存中清除。
-#[allow(dead_code)]
-pub type SharedSecret = Zeroizing<Vec<u8>>;
+/// The raw secret is not necessarily uniformly random, e.g. an ECDH shared secret is a curve
+/// coordinate, so it should not be used directly as a key. Derive keys from it with
+/// [`SharedSecret::expand`]; [`SharedSecret::expose_raw`] is for protocols that define their
+/// own processing of the raw bytes.
+///
+/// 由 KEM 或密钥协商建立的秘密，在被丢弃时从内存中清除。
+///
+/// 原始秘密不一定是均匀随机的，例如 ECDH 共享密钥是一个曲线坐标，因此不应直接用作密钥。
+/// 请使用 [`SharedSecret::expand`] 从中派生密钥；[`SharedSecret::expose_raw`]
+/// 用于自行定义原始字节处理方式的协议。
+#[derive(Clone)]
+pub struct SharedSecret(Zeroizing<Vec<u8>>);
+
+impl SharedSecret {
+    /// Wraps the raw output of a KEM or key agreement.
+    ///
+    /// 包装 KEM 或密钥协商的原始输出。
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(Zeroizing::new(bytes))
+    }
+
+    /// Returns the raw secret bytes.
+    ///
+    /// 返回原始秘密字节。
+    pub fn expose_raw(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Derives `output_len` bytes from the secret with `kdf`, bound to the context `info`.
+    ///
+    /// 使用 `kdf` 从秘密派生 `output_len` 字节，并绑定到上下文 `info`。
+    pub fn expand<K: KeyBasedDerivation>(
+        &self,
+        kdf: &K,
+        info: &[u8],
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        kdf.derive(&self.0, None, Some(info), output_len)
+    }
+
+    /// Derives a key for the symmetric scheme `S` from the secret with `kdf`, bound to the
+    /// context `info`.
+    ///
+    /// 使用 `kdf` 从秘密派生一个用于对称方案 `S` 的密钥，并绑定到上下文 `info`。
+    pub fn expand_key_for<K: KeyBasedDerivation, S: SymmetricKeyGenerator>(
+        &self,
+        kdf: &K,
+        info: &[u8],
+    ) -> Result<S::Key, Error> {
+        kdf.derive_key_for::<S>(&self.0, None, Some(info))
+    }
+}
+
+impl ExposeSecretBytes for SharedSecret {
+    fn expose_secret_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ConstantTimeEq for SharedSecret {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.as_slice().ct_eq(other.0.as_slice())
+    }
+}
+
+impl PartialEq for SharedSecret {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for SharedSecret {}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
 
 /// The encapsulated key (ciphertext) produced by a KEM.
 ///
@@ -227,6 +736,25 @@ pub enum KeyAgreementError {
     /// 对方的公钥对于此操作无效。
     #[cfg_attr(feature = "std", error("Invalid peer public key"))]
     InvalidPeerPublicKey,
+
+    /// The peer's key confirmation does not match, e.g. because it used another password.
+    ///
+    /// 对方的密钥确认不匹配，例如因为它使用了另一个口令。
+    #[cfg_attr(feature = "std", error("Key confirmation failed"))]
+    ConfirmationFailed,
+
+    /// The key of a message has already been used or discarded, e.g. because the message is a
+    /// replay.
+    ///
+    /// 消息的密钥已被使用或丢弃，例如因为该消息是重放的。
+    #[cfg_attr(feature = "std", error("Message key unavailable"))]
+    MessageKeyUnavailable,
+
+    /// A message would skip more message keys than a ratchet is allowed to store.
+    ///
+    /// 某条消息将跳过的消息密钥数量超过了棘轮允许存储的数量。
+    #[cfg_attr(feature = "std", error("Too many skipped messages"))]
+    TooManySkippedMessages,
 }
 
 /// A trait for a Key Agreement scheme.
@@ -247,3 +775,153 @@ pub trait KeyAgreement: AsymmetricKeySet {
         public_key: &Self::PublicKey,
     ) -> Result<SharedSecret, Error>;
 }
+
+// --- Key Pair ---
+
+/// A public key bundled with its private key, so the two cannot be mixed up at call sites.
+///
+/// The operations of the scheme `S` are available as methods that pick the right half of the
+/// pair. With the `serde` feature, both keys are serialized together.
+///
+/// 一个与其私钥捆绑在一起的公钥，使两者不会在调用处被混淆。
+///
+/// 方案 `S` 的操作以方法的形式提供，这些方法会选用密钥对中正确的一半。启用 `serde` feature 时，
+/// 两个密钥会被一起序列化。
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyPair<S: AsymmetricKeySet> {
+    public_key: S::PublicKey,
+    private_key: S::PrivateKey,
+}
+
+impl<S: AsymmetricKeySet> KeyPair<S> {
+    /// Bundles an existing public and private key.
+    ///
+    /// The caller is responsible for passing keys that belong together.
+    ///
+    /// 将已有的公钥和私钥捆绑在一起。
+    ///
+    /// 调用者负责传入相互匹配的密钥。
+    pub fn new(public_key: S::PublicKey, private_key: S::PrivateKey) -> Self {
+        Self {
+            public_key,
+            private_key,
+        }
+    }
+
+    /// Returns the public key.
+    ///
+    /// 返回公钥。
+    pub fn public_key(&self) -> &S::PublicKey {
+        &self.public_key
+    }
+
+    /// Returns the private key.
+    ///
+    /// 返回私钥。
+    pub fn private_key(&self) -> &S::PrivateKey {
+        &self.private_key
+    }
+
+    /// Splits the pair into `(public_key, private_key)`.
+    ///
+    /// 将密钥对拆分为 `(public_key, private_key)`。
+    pub fn into_parts(self) -> (S::PublicKey, S::PrivateKey) {
+        (self.public_key, self.private_key)
+    }
+}
+
+impl<S: KeyGenerator> KeyPair<S> {
+    /// Generates a new key pair.
+    ///
+    /// 生成一个新的密钥对。
+    pub fn generate() -> Result<Self, Error> {
+        let (public_key, private_key) = S::generate_keypair()?;
+        Ok(Self::new(public_key, private_key))
+    }
+}
+
+impl<S: PublicKeyDerivation> KeyPair<S> {
+    /// Restores a key pair from its private key, deriving the public key.
+    ///
+    /// 从私钥恢复密钥对，并派生出公钥。
+    pub fn from_private_key(private_key: S::PrivateKey) -> Result<Self, Error> {
+        let public_key = S::derive_public_key(&private_key)?;
+        Ok(Self::new(public_key, private_key))
+    }
+
+    /// Checks that the public key belongs to the private key.
+    ///
+    /// See [`PublicKeyDerivation::validate_keypair`].
+    ///
+    /// 检查公钥是否属于私钥。
+    ///
+    /// 参见 [`PublicKeyDerivation::validate_keypair`]。
+    pub fn validate(&self) -> Result<(), Error> {
+        S::validate_keypair(&self.public_key, &self.private_key)
+    }
+}
+
+impl<S: Signer> KeyPair<S> {
+    /// Signs `message` with the private key.
+    ///
+    /// 使用私钥对 `message` 签名。
+    pub fn sign(&self, message: &[u8]) -> Result<Signature, Error> {
+        S::sign(&self.private_key, message)
+    }
+}
+
+impl<S: Verifier> KeyPair<S> {
+    /// Verifies `signature` over `message` with the public key.
+    ///
+    /// 使用公钥验证 `message` 上的 `signature`。
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> Result<(), Error> {
+        S::verify(&self.public_key, message, signature)
+    }
+}
+
+impl<S: KeyAgreement> KeyPair<S> {
+    /// Derives a shared secret from the private key and the peer's public key.
+    ///
+    /// 从私钥和对方的公钥派生共享密钥。
+    pub fn agree(&self, peer_public_key: &S::PublicKey) -> Result<SharedSecret, Error> {
+        S::agree(&self.private_key, peer_public_key)
+    }
+}
+
+impl<S: Kem> KeyPair<S> {
+    /// Recovers the shared secret from `encapsulated_key` with the private key.
+    ///
+    /// 使用私钥从 `encapsulated_key` 中恢复共享密钥。
+    pub fn decapsulate(
+        &self,
+        encapsulated_key: &S::EncapsulatedKey,
+    ) -> Result<SharedSecret, Error> {
+        S::decapsulate(&self.private_key, encapsulated_key)
+    }
+}
+
+impl<S: AsymmetricKeySet> From<(S::PublicKey, S::PrivateKey)> for KeyPair<S> {
+    fn from((public_key, private_key): (S::PublicKey, S::PrivateKey)) -> Self {
+        Self::new(public_key, private_key)
+    }
+}
+
+impl<S: AsymmetricKeySet> Clone for KeyPair<S> {
+    fn clone(&self) -> Self {
+        Self {
+            public_key: self.public_key.clone(),
+            private_key: self.private_key.clone(),
+        }
+    }
+}
+
+impl<S: AsymmetricKeySet> fmt::Debug for KeyPair<S>
+where
+    S::PublicKey: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyPair")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,440 @@
+//! Defines the trait for fixed-output cryptographic hash functions.
+//!
+//! 定义了固定输出长度的加密哈希函数的 trait。
+use alloc::vec::Vec;
+use crate::{errors::Error, traits::algorithm::Algorithm};
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+/// Defines errors that can occur when configuring a hash function.
+///
+/// 定义了配置哈希函数时可能发生的错误。
+#[cfg_attr(feature = "std", derive(Error))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum HashError {
+    /// The key is longer than the hash function accepts.
+    ///
+    /// 密钥长度超过了哈希函数允许的长度。
+    #[cfg_attr(feature = "std", error("Invalid key length for this hash function"))]
+    InvalidKeyLength,
+
+    /// The salt is longer than the hash function accepts.
+    ///
+    /// 盐长度超过了哈希函数允许的长度。
+    #[cfg_attr(feature = "std", error("Invalid salt length for this hash function"))]
+    InvalidSaltLength,
+
+    /// The personalization string is longer than the hash function accepts.
+    ///
+    /// 个性化字符串长度超过了哈希函数允许的长度。
+    #[cfg_attr(
+        feature = "std",
+        error("Invalid personalization length for this hash function")
+    )]
+    InvalidPersonalizationLength,
+
+    /// The input is not a well-formed multihash.
+    ///
+    /// 输入不是格式正确的 multihash。
+    #[cfg_attr(feature = "std", error("Malformed multihash"))]
+    InvalidMultihash,
+
+    /// The block size is not supported by this hash function.
+    ///
+    /// 此哈希函数不支持该块大小。
+    #[cfg_attr(feature = "std", error("Invalid block size for this hash function"))]
+    InvalidBlockSize,
+
+    /// The message and opening do not match the commitment.
+    ///
+    /// 消息和打开值与承诺不匹配。
+    #[cfg_attr(feature = "std", error("Commitment verification failed"))]
+    CommitmentMismatch,
+
+    /// The leaf index is outside the Merkle tree.
+    ///
+    /// 叶子索引超出了 Merkle 树的范围。
+    #[cfg_attr(feature = "std", error("Leaf index out of range for this Merkle tree"))]
+    InvalidLeafIndex,
+
+    /// The inclusion proof does not lead to the expected Merkle root.
+    ///
+    /// 包含证明没有得出预期的 Merkle 根。
+    #[cfg_attr(feature = "std", error("Merkle inclusion proof verification failed"))]
+    InvalidInclusionProof,
+
+    /// The output buffer is too small for the digest, or the array length differs from it.
+    ///
+    /// 输出缓冲区太小，无法容纳摘要，或者数组长度与摘要长度不同。
+    #[cfg_attr(feature = "std", error("Invalid output length for this hash function"))]
+    InvalidOutputLength,
+}
+
+/// Copies a digest into `output`, returning the number of bytes written.
+///
+/// 将摘要复制到 `output` 中，并返回写入的字节数。
+pub(crate) fn write_digest(digest: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+    let out = output
+        .get_mut(..digest.len())
+        .ok_or(Error::Hash(HashError::InvalidOutputLength))?;
+    out.copy_from_slice(digest);
+    Ok(digest.len())
+}
+
+/// A trait for cryptographic hash functions with a fixed-size digest.
+///
+/// An instance of the implementing type holds the running hash state: feed it with
+/// [`update`](Self::update) and consume it with [`finalize`](Self::finalize). For data that is
+/// already in memory, [`hash`](Self::hash) performs both steps at once.
+///
+/// This is distinct from the sealed `params::Hasher` trait, which describes the hash
+/// *parameter* used by other schemes (e.g. `RsaScheme<KP, Sha256>`).
+///
+/// 具有固定长度摘要的加密哈希函数 trait。
+///
+/// 实现类型的实例保存运行中的哈希状态：使用 [`update`](Self::update) 输入数据，
+/// 并使用 [`finalize`](Self::finalize) 消费它。对于已在内存中的数据，
+/// [`hash`](Self::hash) 一次完成这两个步骤。
+///
+/// 它不同于密封的 `params::Hasher` trait，后者描述其他方案所使用的哈希*参数*
+/// （例如 `RsaScheme<KP, Sha256>`）。
+pub trait HashFunction: Algorithm {
+    /// The size of the digest in bytes.
+    ///
+    /// 摘要的大小（以字节为单位）。
+    const OUTPUT_SIZE: usize;
+
+    /// Absorbs more data into the hash state.
+    ///
+    /// 将更多数据吸收到哈希状态中。
+    fn update(&mut self, data: &[u8]);
+
+    /// Consumes the hash state and returns the digest.
+    ///
+    /// 消费哈希状态并返回摘要。
+    fn finalize(self) -> Vec<u8>;
+
+    /// Computes the digest of `data` in one shot.
+    ///
+    /// 一次性计算 `data` 的摘要。
+    fn hash(data: &[u8]) -> Vec<u8> {
+        let mut hasher = Self::default();
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Consumes the hash state and writes the digest to the start of `output`, returning the
+    /// number of bytes written. Fails with `HashError::InvalidOutputLength` if `output` is
+    /// shorter than [`OUTPUT_SIZE`](Self::OUTPUT_SIZE).
+    ///
+    /// The hash functions of this crate implement it without heap allocation. The default
+    /// implementation goes through [`finalize`](Self::finalize).
+    ///
+    /// 消费哈希状态并将摘要写入 `output` 的开头，返回写入的字节数。如果 `output` 短于
+    /// [`OUTPUT_SIZE`](Self::OUTPUT_SIZE)，则以 `HashError::InvalidOutputLength` 失败。
+    ///
+    /// 本 crate 的哈希函数在实现它时不进行堆分配。默认实现经由 [`finalize`](Self::finalize)。
+    fn finalize_into(self, output: &mut [u8]) -> Result<usize, Error> {
+        write_digest(&self.finalize(), output)
+    }
+
+    /// Consumes the hash state and returns the digest as an array.
+    /// Fails with `HashError::InvalidOutputLength` unless `N` equals
+    /// [`OUTPUT_SIZE`](Self::OUTPUT_SIZE).
+    ///
+    /// 消费哈希状态并以数组形式返回摘要。除非 `N` 等于 [`OUTPUT_SIZE`](Self::OUTPUT_SIZE)，
+    /// 否则以 `HashError::InvalidOutputLength` 失败。
+    fn finalize_array<const N: usize>(self) -> Result<[u8; N], Error> {
+        if N != Self::OUTPUT_SIZE {
+            return Err(Error::Hash(HashError::InvalidOutputLength));
+        }
+        let mut digest = [0u8; N];
+        self.finalize_into(&mut digest)?;
+        Ok(digest)
+    }
+
+    /// Computes the digest of `data` in one shot and writes it to the start of `output`.
+    /// See [`finalize_into`](Self::finalize_into).
+    ///
+    /// 一次性计算 `data` 的摘要并将其写入 `output` 的开头。参见 [`finalize_into`](Self::finalize_into)。
+    fn hash_into(data: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        let mut hasher = Self::default();
+        hasher.update(data);
+        hasher.finalize_into(output)
+    }
+
+    /// Computes the digest of `data` in one shot and returns it as an array.
+    /// See [`finalize_array`](Self::finalize_array).
+    ///
+    /// 一次性计算 `data` 的摘要并以数组形式返回。参见 [`finalize_array`](Self::finalize_array)。
+    fn hash_array<const N: usize>(data: &[u8]) -> Result<[u8; N], Error> {
+        let mut hasher = Self::default();
+        hasher.update(data);
+        hasher.finalize_array()
+    }
+}
+
+/// The size of the buffer used by [`ParallelUpdate::update_reader`], in bytes.
+///
+/// It is large enough for the parallel implementations to split every read across
+/// several threads.
+///
+/// [`ParallelUpdate::update_reader`] 使用的缓冲区大小（以字节为单位）。
+///
+/// 它足够大，使并行实现可以将每次读取拆分到多个线程上。
+#[cfg(feature = "std")]
+pub const READ_BUFFER_SIZE: usize = 8 * 1024 * 1024;
+
+/// A hash state that can absorb large inputs using several threads.
+///
+/// It is implemented by tree-hash-capable algorithms such as BLAKE3 and ParallelHash,
+/// whose result does not depend on how the input is split up. With the `rayon` feature,
+/// [`update_parallel`](Self::update_parallel) spreads the work over the rayon thread
+/// pool; without it, it is equivalent to a sequential update. Either way the result is
+/// the same as feeding the data through the ordinary `update` method.
+///
+/// 可以使用多个线程吸收大型输入的哈希状态。
+///
+/// 它由支持树形哈希的算法（例如 BLAKE3 和 ParallelHash）实现，这些算法的结果不依赖于输入的
+/// 拆分方式。启用 `rayon` 特性时，[`update_parallel`](Self::update_parallel) 会将工作分配到
+/// rayon 线程池上；否则它等同于顺序更新。无论哪种方式，结果都与通过普通的 `update` 方法
+/// 输入数据相同。
+pub trait ParallelUpdate {
+    /// Absorbs `data`, hashing independent parts of it on multiple threads when possible.
+    /// Only inputs of at least several hundred kilobytes benefit from this.
+    ///
+    /// 吸收 `data`，并在可能时在多个线程上对其独立部分进行哈希。
+    /// 只有至少数百 KB 的输入才能从中受益。
+    fn update_parallel(&mut self, data: &[u8]);
+
+    /// Absorbs everything readable from `reader` and returns the number of bytes read.
+    ///
+    /// 吸收可从 `reader` 读取的全部内容，并返回读取的字节数。
+    #[cfg(feature = "std")]
+    fn update_reader<R: std::io::Read>(&mut self, mut reader: R) -> std::io::Result<u64> {
+        let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+        let mut total = 0u64;
+        loop {
+            // Fill the buffer as far as possible so each parallel update gets a large input
+            let mut filled = 0;
+            while filled < buffer.len() {
+                match reader.read(&mut buffer[filled..]) {
+                    Ok(0) => break,
+                    Ok(n) => filled += n,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            if filled == 0 {
+                return Ok(total);
+            }
+            self.update_parallel(&buffer[..filled]);
+            total += filled as u64;
+        }
+    }
+
+    /// Absorbs the contents of the file at `path` and returns its length.
+    ///
+    /// 吸收位于 `path` 的文件内容，并返回其长度。
+    #[cfg(feature = "std")]
+    fn update_file<P: AsRef<std::path::Path>>(&mut self, path: P) -> std::io::Result<u64> {
+        self.update_reader(std::fs::File::open(path)?)
+    }
+}
+
+/// Computes the digest of everything readable from `reader`, using multiple threads
+/// where the algorithm and enabled features allow it.
+///
+/// 计算可从 `reader` 读取的全部内容的摘要，并在算法和已启用特性允许时使用多个线程。
+#[cfg(feature = "std")]
+pub fn hash_reader<H, R>(reader: R) -> std::io::Result<Vec<u8>>
+where
+    H: HashFunction + ParallelUpdate,
+    R: std::io::Read,
+{
+    let mut hasher = H::default();
+    hasher.update_reader(reader)?;
+    Ok(hasher.finalize())
+}
+
+/// Computes the digest of the file at `path`, using multiple threads and a memory map
+/// where the algorithm and enabled features allow it.
+///
+/// 计算位于 `path` 的文件的摘要，并在算法和已启用特性允许时使用多个线程和内存映射。
+#[cfg(feature = "std")]
+pub fn hash_file<H, P>(path: P) -> std::io::Result<Vec<u8>>
+where
+    H: HashFunction + ParallelUpdate,
+    P: AsRef<std::path::Path>,
+{
+    let mut hasher = H::default();
+    hasher.update_file(path)?;
+    Ok(hasher.finalize())
+}
+
+/// The largest number of bytes in a multiformats unsigned varint.
+///
+/// multiformats 无符号 varint 的最大字节数。
+const MAX_VARINT_LEN: usize = 9;
+
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads a minimally encoded unsigned varint and returns it with the rest of the input.
+///
+/// 读取一个最小编码的无符号 varint，并将其与剩余输入一起返回。
+fn read_varint(bytes: &[u8]) -> Result<(u64, &[u8]), Error> {
+    let mut value = 0u64;
+    for (i, &byte) in bytes.iter().enumerate().take(MAX_VARINT_LEN) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            // A trailing zero byte means the encoding was not minimal.
+            if byte == 0 && i > 0 {
+                break;
+            }
+            return Ok((value, &bytes[i + 1..]));
+        }
+    }
+    Err(Error::Hash(HashError::InvalidMultihash))
+}
+
+/// A self-describing digest in the multihash format: `varint(code) || varint(len) || digest`.
+///
+/// The code identifies the hash function, using the values of the multiformats table
+/// (for example `0x12` for SHA-256 and `0x1e` for BLAKE3).
+///
+/// multihash 格式的自描述摘要：`varint(code) || varint(len) || digest`。
+///
+/// 代码标识哈希函数，使用 multiformats 表中的值（例如 SHA-256 为 `0x12`，BLAKE3 为 `0x1e`）。
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Multihash {
+    code: u64,
+    digest: Vec<u8>,
+}
+
+impl Multihash {
+    /// Wraps a digest produced by the hash function identified by `code`.
+    ///
+    /// 包装由 `code` 标识的哈希函数产生的摘要。
+    pub fn new(code: u64, digest: Vec<u8>) -> Self {
+        Self { code, digest }
+    }
+
+    /// Parses a multihash, rejecting trailing bytes and non-minimal varints.
+    ///
+    /// 解析 multihash，拒绝多余的尾随字节和非最小编码的 varint。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let (code, rest) = read_varint(bytes)?;
+        let (len, digest) = read_varint(rest)?;
+        if digest.len() as u64 != len {
+            return Err(Error::Hash(HashError::InvalidMultihash));
+        }
+        Ok(Self::new(code, digest.to_vec()))
+    }
+
+    /// Encodes the multihash.
+    ///
+    /// 编码 multihash。
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(2 * MAX_VARINT_LEN + self.digest.len());
+        write_varint(self.code, &mut out);
+        write_varint(self.digest.len() as u64, &mut out);
+        out.extend_from_slice(&self.digest);
+        out
+    }
+
+    /// Returns the multihash code of the hash function.
+    ///
+    /// 返回哈希函数的 multihash 代码。
+    pub fn code(&self) -> u64 {
+        self.code
+    }
+
+    /// Returns the digest.
+    ///
+    /// 返回摘要。
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+}
+
+/// A hash function with an assigned multihash code.
+///
+/// The code identifies the unkeyed, default-length function, so keyed or personalized
+/// instances (for example a keyed BLAKE2b) should not be encoded as multihashes.
+///
+/// 具有已分配 multihash 代码的哈希函数。
+///
+/// 该代码标识的是无密钥、默认长度的函数，因此带密钥或个性化的实例（例如带密钥的 BLAKE2b）
+/// 不应编码为 multihash。
+pub trait MultihashCode: HashFunction {
+    /// The code of this hash function in the multiformats table.
+    ///
+    /// 此哈希函数在 multiformats 表中的代码。
+    const MULTIHASH_CODE: u64;
+
+    /// Consumes the hash state and returns the digest as a multihash.
+    ///
+    /// 消费哈希状态并以 multihash 形式返回摘要。
+    fn finalize_multihash(self) -> Multihash {
+        Multihash::new(Self::MULTIHASH_CODE, self.finalize())
+    }
+
+    /// Computes the multihash of `data` in one shot.
+    ///
+    /// 一次性计算 `data` 的 multihash。
+    fn hash_multihash(data: &[u8]) -> Multihash {
+        Multihash::new(Self::MULTIHASH_CODE, Self::hash(data))
+    }
+
+    /// Returns whether `multihash` was produced by this hash function over `data`.
+    ///
+    /// 返回 `multihash` 是否由此哈希函数对 `data` 计算得出。
+    fn verify_multihash(data: &[u8], multihash: &Multihash) -> bool {
+        multihash.code() == Self::MULTIHASH_CODE && Self::hash(data) == multihash.digest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varint() {
+        for (value, encoded) in [
+            (0x00u64, &[0x00u8][..]),
+            (0x12, &[0x12]),
+            (0x80, &[0x80, 0x01]),
+            (0xb220, &[0xa0, 0xe4, 0x02]),
+        ] {
+            let mut out = Vec::new();
+            write_varint(value, &mut out);
+            assert_eq!(out, encoded);
+            assert_eq!(read_varint(encoded), Ok((value, &[][..])));
+        }
+
+        // Non-minimal, truncated and overlong encodings are rejected
+        assert!(read_varint(&[0x92, 0x00]).is_err());
+        assert!(read_varint(&[0x80]).is_err());
+        assert!(read_varint(&[0xff; 10]).is_err());
+    }
+
+    #[test]
+    fn test_multihash_parsing() {
+        let multihash = Multihash::new(0x12, vec![0xab; 32]);
+        let bytes = multihash.to_bytes();
+        assert_eq!(&bytes[..2], &[0x12, 0x20]);
+        assert_eq!(Multihash::from_bytes(&bytes), Ok(multihash));
+
+        assert!(Multihash::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(Multihash::from_bytes(&trailing).is_err());
+        assert!(Multihash::from_bytes(&[]).is_err());
+    }
+}
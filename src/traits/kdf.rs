@@ -2,24 +2,36 @@
 //!
 //! 定义了密钥和密码派生函数的 trait。
 
+use alloc::{boxed::Box, vec, vec::Vec};
+use crate::ct::{Choice, ConstantTimeEq};
 use crate::{errors::Error, prelude::Key};
+use crate::traits::key::ExposeSecretBytes;
 use crate::traits::algorithm::Algorithm;
+use crate::traits::symmetric::SymmetricKeyGenerator;
 
-#[cfg(feature = "secrecy")]
-use secrecy::SecretBox;
+#[cfg(any(
+    feature = "pbkdf2-default",
+    feature = "argon2-default",
+    feature = "scrypt-default"
+))]
+use crate::systems::formats::phc::PhcString;
 #[cfg(feature = "std")]
 use thiserror::Error;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::ops::{Deref, DerefMut};
+use core::fmt;
+use core::ops::{Deref, DerefMut};
 use zeroize::Zeroizing;
 
 /// A key derived from a KDF, wrapped in `Zeroizing` for security.
 ///
 /// 从 KDF 派生出的密钥，使用 `Zeroizing` 确保安全。
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct DerivedKey(pub Zeroizing<Vec<u8>>);
+pub struct DerivedKey(
+    #[cfg_attr(feature = "serde", serde(with = "crate::traits::key::serde_bytes"))]
+    pub Zeroizing<Vec<u8>>,
+);
 
 impl DerivedKey {
     pub fn new(key_material: Vec<u8>) -> Self {
@@ -31,6 +43,20 @@ impl DerivedKey {
     }
 }
 
+impl ConstantTimeEq for DerivedKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.as_slice().ct_eq(other.0.as_slice())
+    }
+}
+
+impl PartialEq for DerivedKey {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for DerivedKey {}
+
 impl AsRef<[u8]> for DerivedKey {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
@@ -56,6 +82,12 @@ impl DerefMut for DerivedKey {
     }
 }
 
+impl ExposeSecretBytes for DerivedKey {
+    fn expose_secret_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
 impl Key for DerivedKey {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         Ok(Self(Zeroizing::new(bytes.to_vec())))
@@ -78,11 +110,47 @@ pub enum KdfError {
     #[cfg_attr(feature = "std", error("Key derivation failed"))]
     DerivationFailed,
 
-    /// The requested output length is invalid or too large for this KDF.
+    /// The requested output length is zero or exceeds the maximum of this KDF.
+    ///
+    /// 请求的输出长度为零或超过了此 KDF 的最大值。
+    #[cfg_attr(
+        feature = "std",
+        error("Invalid output length {requested} for this KDF (maximum {max})")
+    )]
+    InvalidOutputLength {
+        /// The requested output length in bytes.
+        ///
+        /// 请求的输出长度（以字节为单位）。
+        requested: usize,
+        /// The largest output length this KDF accepts, in bytes.
+        ///
+        /// 此 KDF 接受的最大输出长度（以字节为单位）。
+        max: usize,
+    },
+
+    /// A cost parameter of this KDF is out of range.
+    ///
+    /// 此 KDF 的某个成本参数超出范围。
+    #[cfg_attr(feature = "std", error("Invalid parameter for this KDF"))]
+    InvalidParameter,
+
+    /// The parameters would require more memory than the configured limit.
+    ///
+    /// 这些参数所需的内存超过了配置的上限。
+    #[cfg_attr(feature = "std", error("KDF parameters exceed the memory limit"))]
+    MemoryLimitExceeded,
+
+    /// The PHC string is malformed, or names an algorithm or parameters this scheme does not support.
+    ///
+    /// PHC 字符串格式错误，或指定了此方案不支持的算法或参数。
+    #[cfg_attr(feature = "std", error("Invalid or unsupported PHC string"))]
+    InvalidPhcString,
+
+    /// The password does not match the stored hash.
     ///
-    /// 提供的输出长度对于此 KDF 无效或过大。
-    #[cfg_attr(feature = "std", error("Invalid output length for this KDF"))]
-    InvalidOutputLength,
+    /// 密码与存储的哈希不匹配。
+    #[cfg_attr(feature = "std", error("Password verification failed"))]
+    VerificationFailed,
 
     /// Salt generation failed.
     ///
@@ -100,6 +168,124 @@ pub enum KdfError {
     UnsupportedInNoStd,
 }
 
+/// Checks that an output length is non-zero and at most `max` bytes.
+///
+/// 检查输出长度是否非零且不超过 `max` 字节。
+pub(crate) fn check_output_len(requested: usize, max: usize) -> Result<(), Error> {
+    if requested == 0 || requested > max {
+        return Err(Error::Kdf(KdfError::InvalidOutputLength { requested, max }));
+    }
+    Ok(())
+}
+
+/// Converts derived key material into the key type of `S`, checking its length.
+///
+/// 将派生的密钥材料转换为 `S` 的密钥类型，并检查其长度。
+pub(crate) fn key_for<S: SymmetricKeyGenerator>(okm: DerivedKey) -> Result<S::Key, Error> {
+    if okm.as_bytes().len() != S::KEY_SIZE {
+        return Err(Error::Kdf(KdfError::InvalidOutputLength {
+            requested: okm.as_bytes().len(),
+            max: S::KEY_SIZE,
+        }));
+    }
+    S::Key::from_bytes(okm.as_bytes())
+}
+
+/// A reader that yields derived bytes on demand, one KDF block at a time.
+///
+/// Reading `n` bytes and then `m` more yields the same bytes as reading `n + m` at once, so
+/// a long keystream can be consumed in pieces without holding all of it in memory.
+///
+/// 一个按需产生派生字节的读取器，每次生成一个 KDF 块。
+///
+/// 先读取 `n` 字节再读取 `m` 字节，与一次读取 `n + m` 字节得到的字节相同，
+/// 因此可以分段消费很长的密钥流，而无需将其全部保存在内存中。
+pub struct KdfReader<'a> {
+    next_block: Box<dyn FnMut() -> Result<Zeroizing<Vec<u8>>, Error> + 'a>,
+    block: Zeroizing<Vec<u8>>,
+    position: usize,
+    consumed: usize,
+    max: usize,
+}
+
+impl<'a> KdfReader<'a> {
+    /// Creates a reader over at most `max` bytes from a function returning the next
+    /// output block.
+    ///
+    /// 由返回下一个输出块的函数创建一个最多读取 `max` 字节的读取器。
+    pub fn new<F>(max: usize, next_block: F) -> Self
+    where
+        F: FnMut() -> Result<Zeroizing<Vec<u8>>, Error> + 'a,
+    {
+        Self {
+            next_block: Box::new(next_block),
+            block: Zeroizing::new(Vec::new()),
+            position: 0,
+            consumed: 0,
+            max,
+        }
+    }
+
+    /// Fills `buffer` with the next derived bytes.
+    ///
+    /// Fails with `KdfError::InvalidOutputLength`, leaving the reader unchanged, if the
+    /// total output read would exceed the maximum of the KDF.
+    ///
+    /// 用接下来的派生字节填充 `buffer`。
+    ///
+    /// 如果读取的总输出将超过 KDF 的最大值，则以 `KdfError::InvalidOutputLength` 失败，
+    /// 且读取器保持不变。
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+        let requested = self.consumed.saturating_add(buffer.len());
+        if requested > self.max {
+            return Err(Error::Kdf(KdfError::InvalidOutputLength {
+                requested,
+                max: self.max,
+            }));
+        }
+        let mut filled = 0;
+        while filled < buffer.len() {
+            if self.position == self.block.len() {
+                self.block = (self.next_block)()?;
+                self.position = 0;
+                if self.block.is_empty() {
+                    return Err(Error::Kdf(KdfError::DerivationFailed));
+                }
+            }
+            let n = (buffer.len() - filled).min(self.block.len() - self.position);
+            buffer[filled..filled + n]
+                .copy_from_slice(&self.block[self.position..self.position + n]);
+            filled += n;
+            self.position += n;
+            self.consumed += n;
+        }
+        Ok(())
+    }
+
+    /// Reads the next `len` bytes as a key.
+    ///
+    /// 将接下来的 `len` 字节读取为一个密钥。
+    pub fn read_key(&mut self, len: usize) -> Result<DerivedKey, Error> {
+        let mut key = DerivedKey::new(vec![0u8; len]);
+        self.read(key.as_mut())?;
+        Ok(key)
+    }
+}
+
+impl fmt::Debug for KdfReader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KdfReader").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for KdfReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        KdfReader::read(self, buf).map_err(std::io::Error::other)?;
+        Ok(buf.len())
+    }
+}
+
 /// A top-level trait for all derivation algorithms (KDFs, PBKDFs, etc.).
 ///
 /// 所有派生算法（KDF、PBKDF 等）的顶层 trait。
@@ -138,6 +324,107 @@ pub trait KeyBasedDerivation: Derivation {
         info: Option<&[u8]>,
         output_len: usize,
     ) -> Result<DerivedKey, Error>;
+
+    /// The largest output length in bytes that [`KeyBasedDerivation::derive`] accepts.
+    /// Requests for more bytes, or for zero bytes, fail with `KdfError::InvalidOutputLength`.
+    ///
+    /// [`KeyBasedDerivation::derive`] 接受的最大输出长度（以字节为单位）。
+    /// 请求更多字节或零字节时以 `KdfError::InvalidOutputLength` 失败。
+    fn max_output_len(&self) -> usize {
+        usize::MAX
+    }
+
+    /// Derives `output_len` bytes from IKM held in a secret wrapper, such as a
+    /// [`SharedSecret`](crate::prelude::SharedSecret), a [`SecretBytes`](crate::prelude::SecretBytes)
+    /// master key or a `secrecy::SecretBox<[u8]>`. The bytes are those returned by
+    /// [`KeyBasedDerivation::derive`] for the exposed IKM.
+    ///
+    /// 从保存在秘密包装器中的 IKM 派生 `output_len` 字节，例如 [`SharedSecret`](crate::prelude::SharedSecret)、
+    /// [`SecretBytes`](crate::prelude::SecretBytes) 主密钥或 `secrecy::SecretBox<[u8]>`。
+    /// 这些字节与对暴露出的 IKM 调用 [`KeyBasedDerivation::derive`] 返回的字节相同。
+    fn derive_from_secret(
+        &self,
+        ikm: &impl ExposeSecretBytes,
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+        output_len: usize,
+    ) -> Result<DerivedKey, Error> {
+        self.derive(ikm.expose_secret_bytes(), salt, info, output_len)
+    }
+
+    /// Derives `output.len()` bytes from Input Keying Material (IKM) into `output`.
+    /// The bytes are those returned by [`KeyBasedDerivation::derive`] for the same length.
+    ///
+    /// HKDF implements it without heap allocation. The default implementation goes through
+    /// [`KeyBasedDerivation::derive`].
+    ///
+    /// 从输入密钥材料 (IKM) 派生 `output.len()` 字节到 `output` 中。
+    /// 这些字节与相同长度下 [`KeyBasedDerivation::derive`] 返回的字节相同。
+    ///
+    /// HKDF 在实现它时不进行堆分配。默认实现经由 [`KeyBasedDerivation::derive`]。
+    fn derive_into(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+        output: &mut [u8],
+    ) -> Result<(), Error> {
+        let okm = self.derive(ikm, salt, info, output.len())?;
+        if okm.as_bytes().len() != output.len() {
+            return Err(Error::Kdf(KdfError::DerivationFailed));
+        }
+        output.copy_from_slice(okm.as_bytes());
+        Ok(())
+    }
+
+    /// Derives a key of `N` bytes as an array that is zeroized on drop.
+    ///
+    /// 派生一个 `N` 字节的密钥，以在释放时清零的数组形式返回。
+    fn derive_array<const N: usize>(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+    ) -> Result<Zeroizing<[u8; N]>, Error> {
+        let mut okm = Zeroizing::new([0u8; N]);
+        self.derive_into(ikm, salt, info, okm.as_mut())?;
+        Ok(okm)
+    }
+
+    /// Derives a key of exactly `S::KEY_SIZE` bytes and returns it as the key type of the
+    /// symmetric scheme `S`, so the length always matches the scheme it is used with.
+    ///
+    /// 派生一个恰好为 `S::KEY_SIZE` 字节的密钥，并以对称方案 `S` 的密钥类型返回，
+    /// 从而使长度始终与所使用的方案相匹配。
+    fn derive_key_for<S: SymmetricKeyGenerator>(
+        &self,
+        ikm: &[u8],
+        salt: Option<&[u8]>,
+        info: Option<&[u8]>,
+    ) -> Result<S::Key, Error> {
+        key_for::<S>(self.derive(ikm, salt, info, S::KEY_SIZE)?)
+    }
+}
+
+/// A trait for key-based KDFs whose output can be read incrementally.
+///
+/// The bytes read from [`StreamingDerivation::reader`] are exactly those returned by
+/// [`KeyBasedDerivation::derive`] with the same inputs, for any output length.
+///
+/// 一个用于可以增量读取输出的基于密钥的 KDF 的 trait。
+///
+/// 对于任意输出长度，从 [`StreamingDerivation::reader`] 读取的字节与使用相同输入调用
+/// [`KeyBasedDerivation::derive`] 返回的字节完全相同。
+pub trait StreamingDerivation: KeyBasedDerivation {
+    /// Returns a reader over the output derived from `ikm`, `salt` and `info`.
+    ///
+    /// 返回一个读取器，用于读取从 `ikm`、`salt` 和 `info` 派生的输出。
+    fn reader<'a>(
+        &self,
+        ikm: &'a [u8],
+        salt: Option<&'a [u8]>,
+        info: Option<&'a [u8]>,
+    ) -> Result<KdfReader<'a>, Error>;
 }
 
 /// A trait for Password-Based Key Derivation Functions (PBKDFs) that derive keys from a low-entropy password.
@@ -145,7 +432,6 @@ pub trait KeyBasedDerivation: Derivation {
 ///
 /// 用于从低熵密码派生密钥的基于密码的密钥派生函数 (PBKDF) 的 trait。
 /// 这些函数通常是计算密集型的，以防止暴力破解攻击。
-#[cfg(all(feature = "secrecy", feature = "getrandom"))]
 pub trait PasswordBasedDerivation: Derivation {
     /// The recommended length for the salt, in bytes.
     ///
@@ -154,7 +440,8 @@ pub trait PasswordBasedDerivation: Derivation {
 
     /// Generates a cryptographically secure salt.
     ///
-    /// This default implementation uses `getrandom` to generate a salt of `RECOMMENDED_SALT_LENGTH`.
+    /// This default implementation uses the crate's RNG for nonces and salts, which is a
+    /// thread-local `FastRng` with the `fast-rng` feature, to generate a salt of `RECOMMENDED_SALT_LENGTH`.
     /// Schemes can override this method if they have specific requirements for salt generation.
     ///
     /// # Returns
@@ -162,21 +449,23 @@ pub trait PasswordBasedDerivation: Derivation {
     ///
     /// 生成一个加密安全的盐。
     ///
-    /// 此默认实现使用 `getrandom` 来生成长度为 `RECOMMENDED_SALT_LENGTH` 的盐。
+    /// 此默认实现使用 crate 用于 nonce 和盐的 RNG（启用 `fast-rng` 特性时为线程本地的 `FastRng`）
+    /// 来生成长度为 `RECOMMENDED_SALT_LENGTH` 的盐。
     /// 如果方案有特定的盐生成要求，可以重写此方法。
     ///
     /// # 返回
     /// 包含生成的盐的 `Vec<u8>`。
     fn generate_salt(&self) -> Result<Vec<u8>, Error> {
         let mut salt = vec![0u8; Self::RECOMMENDED_SALT_LENGTH];
-        getrandom::fill(&mut salt).map_err(|_| Error::Kdf(KdfError::SaltGenerationFailed))?;
+        crate::rng::fill_nonce(&mut salt).map_err(|_| Error::Kdf(KdfError::SaltGenerationFailed))?;
         Ok(salt)
     }
     /// Derives a secure key from a password.
     ///
     /// # Arguments
     /// * `self` - The scheme instance, which may contain configuration like the number of iterations.
-    /// * `password` - The password to derive the key from.
+    /// * `password` - The password to derive the key from, in a secret wrapper such as
+    ///   `secrecy::SecretBox<[u8]>` or [`SecretBytes`](crate::prelude::SecretBytes).
     /// * `salt` - A salt. It is crucial for security and must be unique per password.
     /// * `output_len` - The desired length of the derived key in bytes.
     ///
@@ -187,7 +476,8 @@ pub trait PasswordBasedDerivation: Derivation {
     ///
     /// # 参数
     /// * `self` - 方案的实例，可能包含配置，例如迭代次数。
-    /// * `password` - 用于派生密钥的密码。
+    /// * `password` - 用于派生密钥的密码，保存在秘密包装器中，例如 `secrecy::SecretBox<[u8]>`
+    ///   或 [`SecretBytes`](crate::prelude::SecretBytes)。
     /// * `salt` - 盐。这对安全性至关重要，每个密码都必须是唯一的。
     /// * `output_len` - 期望派生的密钥长度（以字节为单位）。
     ///
@@ -195,10 +485,99 @@ pub trait PasswordBasedDerivation: Derivation {
     /// 派生出的密钥，长度为 `output_len`。
     fn derive(
         &self,
-        password: &SecretBox<[u8]>,
+        password: &impl ExposeSecretBytes,
         salt: &[u8],
         output_len: usize,
     ) -> Result<DerivedKey, Error>;
+
+    /// The largest output length in bytes that [`PasswordBasedDerivation::derive`] accepts.
+    /// Requests for more bytes, or for zero bytes, fail with `KdfError::InvalidOutputLength`.
+    ///
+    /// [`PasswordBasedDerivation::derive`] 接受的最大输出长度（以字节为单位）。
+    /// 请求更多字节或零字节时以 `KdfError::InvalidOutputLength` 失败。
+    fn max_output_len(&self) -> usize {
+        usize::MAX
+    }
+
+    /// Derives a key of exactly `S::KEY_SIZE` bytes from a password and returns it as the
+    /// key type of the symmetric scheme `S`.
+    ///
+    /// 从密码派生一个恰好为 `S::KEY_SIZE` 字节的密钥，并以对称方案 `S` 的密钥类型返回。
+    fn derive_key_for<S: SymmetricKeyGenerator>(
+        &self,
+        password: &impl ExposeSecretBytes,
+        salt: &[u8],
+    ) -> Result<S::Key, Error> {
+        key_for::<S>(self.derive(password, salt, S::KEY_SIZE)?)
+    }
+
+    /// Re-derives a key of `expected_key.len()` bytes from `password` and `salt` and compares
+    /// it with `expected_key` in constant time.
+    ///
+    /// Fails with `KdfError::VerificationFailed` if the keys differ.
+    ///
+    /// 从 `password` 和 `salt` 重新派生一个长度为 `expected_key.len()` 字节的密钥，
+    /// 并以常量时间将其与 `expected_key` 比较。
+    ///
+    /// 如果密钥不同，则以 `KdfError::VerificationFailed` 失败。
+    fn verify(
+        &self,
+        password: &impl ExposeSecretBytes,
+        salt: &[u8],
+        expected_key: &[u8],
+    ) -> Result<(), Error> {
+        let key = self.derive(password, salt, expected_key.len())?;
+        if crate::ct::ct_eq(key.as_bytes(), expected_key) {
+            Ok(())
+        } else {
+            Err(Error::Kdf(KdfError::VerificationFailed))
+        }
+    }
+}
+
+/// A trait for password-based KDFs that can store their output, salt and parameters as a
+/// PHC string, and verify passwords against such strings.
+///
+/// 一个用于基于密码的 KDF 的 trait，它们可以将输出、盐和参数存储为 PHC 字符串，
+/// 并根据此类字符串验证密码。
+#[cfg(any(
+    feature = "pbkdf2-default",
+    feature = "argon2-default",
+    feature = "scrypt-default"
+))]
+pub trait PasswordHasher: PasswordBasedDerivation + Sized {
+    /// The length of the hash stored in PHC strings, in bytes.
+    ///
+    /// 存储在 PHC 字符串中的哈希长度（以字节为单位）。
+    const PHC_OUTPUT_LENGTH: usize = 32;
+
+    /// Encodes a salt and hash produced by this scheme, together with its parameters.
+    ///
+    /// 将此方案生成的盐和哈希连同其参数一起编码。
+    fn to_phc(&self, salt: &[u8], hash: &[u8]) -> PhcString;
+
+    /// Reconstructs the scheme from the algorithm and parameters of a PHC string.
+    /// Fails with `KdfError::InvalidPhcString` if they do not belong to this scheme.
+    ///
+    /// 根据 PHC 字符串的算法和参数重建方案。如果它们不属于此方案，则以
+    /// `KdfError::InvalidPhcString` 失败。
+    fn from_phc(phc: &PhcString) -> Result<Self, Error>;
+
+    /// Hashes a password under a fresh random salt.
+    ///
+    /// 使用新的随机盐对密码进行哈希。
+    fn hash_password(&self, password: &impl ExposeSecretBytes) -> Result<PhcString, Error> {
+        let salt = self.generate_salt()?;
+        let hash = self.derive(password, &salt, Self::PHC_OUTPUT_LENGTH)?;
+        Ok(self.to_phc(&salt, hash.as_bytes()))
+    }
+
+    /// Verifies a password against a PHC string, comparing hashes in constant time.
+    ///
+    /// 根据 PHC 字符串验证密码，并以常量时间比较哈希。
+    fn verify_password(phc: &PhcString, password: &impl ExposeSecretBytes) -> Result<(), Error> {
+        Self::from_phc(phc)?.verify(password, phc.salt(), phc.hash())
+    }
 }
 
 #[cfg(test)]
@@ -211,4 +590,25 @@ mod tests {
         let derived_key = DerivedKey::new(key_data.clone());
         assert_eq!(derived_key.as_bytes(), key_data.as_slice());
     }
+
+    #[test]
+    fn kdf_reader_test() {
+        let mut blocks = 0u8;
+        let mut reader = KdfReader::new(12, move || {
+            blocks += 1;
+            Ok(Zeroizing::new(vec![blocks; 4]))
+        });
+
+        let mut buffer = [0u8; 6];
+        assert!(reader.read(&mut buffer).is_ok());
+        assert_eq!(buffer, [1, 1, 1, 1, 2, 2]);
+        assert!(matches!(reader.read_key(6), Ok(ref key) if key.as_bytes() == [2, 2, 3, 3, 3, 3]));
+        assert_eq!(
+            reader.read(&mut [0u8; 1]).err(),
+            Some(Error::Kdf(KdfError::InvalidOutputLength {
+                requested: 13,
+                max: 12
+            }))
+        );
+    }
 }
@@ -1,6 +1,17 @@
 //! Defines traits for cryptographic keys.
 //!
 //! 定义了加密密钥的核心 trait。
+pub mod encoding;
+pub mod managed;
+pub mod secret_bytes;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_bytes;
+
+use alloc::vec::Vec;
+pub use encoding::*;
+pub use managed::*;
+pub use secret_bytes::*;
+
 use crate::errors::Error;
 use crate::traits::algorithm::Algorithm;
 use zeroize::Zeroize;
@@ -29,6 +40,78 @@ pub enum KeyError {
     /// 提供的密钥编码无效。
     #[cfg_attr(feature = "std", error("Invalid key encoding"))]
     InvalidLength,
+    /// The public key does not belong to the private key.
+    ///
+    /// 公钥与私钥不匹配。
+    #[cfg_attr(feature = "std", error("Public key does not match private key"))]
+    KeyPairMismatch,
+    /// The key was not created from a seed, so the seed cannot be exported.
+    ///
+    /// 该密钥不是由种子创建的，因此无法导出种子。
+    #[cfg_attr(feature = "std", error("Key seed is unavailable"))]
+    SeedUnavailable,
+    /// The key's algorithm is unknown or its feature is not enabled.
+    ///
+    /// 密钥的算法未知或其特性未启用。
+    #[cfg_attr(feature = "std", error("Unsupported key algorithm"))]
+    UnsupportedAlgorithm,
+    /// The key is not allowed to be used for the requested operation.
+    ///
+    /// 该密钥不允许用于所请求的操作。
+    #[cfg_attr(feature = "std", error("Key usage is not allowed"))]
+    UsageNotAllowed,
+    /// The key has passed its expiry time.
+    ///
+    /// 该密钥已超过其过期时间。
+    #[cfg_attr(feature = "std", error("Key has expired"))]
+    Expired,
+    /// A key with the same ID is already present.
+    ///
+    /// 已存在具有相同 ID 的密钥。
+    #[cfg_attr(feature = "std", error("Duplicate key ID"))]
+    DuplicateKeyId,
+    /// The threshold or share count of a secret sharing is out of range.
+    ///
+    /// 秘密共享的门限或份额数超出范围。
+    #[cfg_attr(feature = "std", error("Invalid secret sharing threshold"))]
+    InvalidThreshold,
+    /// Fewer shares than the threshold were given.
+    ///
+    /// 给定的份额少于门限。
+    #[cfg_attr(feature = "std", error("Not enough shares to recover the secret"))]
+    InsufficientShares,
+    /// The shares are inconsistent with each other or a share is malformed.
+    ///
+    /// 份额之间不一致或某个份额格式错误。
+    #[cfg_attr(feature = "std", error("Invalid share"))]
+    InvalidShare,
+    /// The secret recovered from the shares does not match its digest.
+    ///
+    /// 从份额中恢复的秘密与其摘要不匹配。
+    #[cfg_attr(feature = "std", error("Share digest mismatch"))]
+    ShareDigestMismatch,
+    /// The share is not consistent with the dealer's commitments.
+    ///
+    /// 份额与分发者的承诺不一致。
+    #[cfg_attr(feature = "std", error("Share does not match the commitments"))]
+    ShareCommitmentMismatch,
+    /// A participant's proof of knowledge of its secret in a distributed key generation is
+    /// invalid.
+    ///
+    /// 分布式密钥生成中某个参与者对其秘密的知识证明无效。
+    #[cfg_attr(feature = "std", error("Invalid proof of knowledge from participant {0}"))]
+    InvalidProofOfKnowledge(u16),
+    /// The secret share a participant sent in a distributed key generation does not match its
+    /// commitments.
+    ///
+    /// 某个参与者在分布式密钥生成中发送的秘密份额与其承诺不匹配。
+    #[cfg_attr(feature = "std", error("Secret share from participant {0} does not match its commitments"))]
+    InvalidDkgShare(u16),
+    /// Secret bytes could not be placed in locked memory.
+    ///
+    /// 无法将秘密字节放入锁定内存。
+    #[cfg_attr(feature = "std", error("Locked memory is unavailable"))]
+    MemoryLockFailed,
 }
 
 #[cfg(feature = "serde")]
@@ -0,0 +1,159 @@
+//! Hex and Base64 text encodings for keys and signatures.
+//!
+//! [`KeyEncoding`] is implemented for every [`Key`], including [`SymmetricKey`] and
+//! [`Signature`], which are aliases of byte vectors. Decoding uses constant-time hex and Base64
+//! decoders, so secret keys can be parsed without leaking their contents through timing. Public
+//! key types additionally implement `Display` and `FromStr` with lowercase hex.
+//!
+//! 用于密钥和签名的十六进制和 Base64 文本编码。
+//!
+//! [`KeyEncoding`] 为每个 [`Key`] 实现，包括作为字节向量别名的 [`SymmetricKey`] 和
+//! [`Signature`]。解码使用常数时间的十六进制和 Base64 解码器，因此解析秘密密钥时不会通过
+//! 时间泄露其内容。公钥类型还以小写十六进制实现了 `Display` 和 `FromStr`。
+//!
+//! [`SymmetricKey`]: crate::traits::symmetric::SymmetricKey
+//! [`Signature`]: crate::traits::asymmetric::Signature
+
+use alloc::string::String;
+use super::{Key, KeyError};
+use crate::errors::Error;
+use base64ct::{Base64, Encoding};
+use zeroize::Zeroizing;
+
+/// Converts keys to and from hex and standard padded Base64 (RFC 4648).
+///
+/// 在密钥与十六进制和标准带填充 Base64 (RFC 4648) 之间进行转换。
+pub trait KeyEncoding: Key {
+    /// Encodes the key's byte representation as lowercase hex.
+    ///
+    /// 将密钥的字节表示编码为小写十六进制。
+    fn to_hex(&self) -> Result<String, Error> {
+        let bytes = Zeroizing::new(self.to_bytes()?);
+        Ok(base16ct::lower::encode_string(&bytes))
+    }
+
+    /// Decodes a key from hex in either case.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if `s` is not valid hex.
+    ///
+    /// 从大小写均可的十六进制解码密钥。
+    ///
+    /// 如果 `s` 不是有效的十六进制，则以 `KeyError::InvalidEncoding` 失败。
+    fn from_hex(s: &str) -> Result<Self, Error> {
+        let bytes = base16ct::mixed::decode_vec(s)
+            .map(Zeroizing::new)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Encodes the key's byte representation as standard padded Base64.
+    ///
+    /// 将密钥的字节表示编码为标准带填充 Base64。
+    fn to_base64(&self) -> Result<String, Error> {
+        let bytes = Zeroizing::new(self.to_bytes()?);
+        Ok(Base64::encode_string(&bytes))
+    }
+
+    /// Decodes a key from standard padded Base64.
+    ///
+    /// Fails with `KeyError::InvalidEncoding` if `s` is not canonical padded Base64.
+    ///
+    /// 从标准带填充 Base64 解码密钥。
+    ///
+    /// 如果 `s` 不是规范的带填充 Base64，则以 `KeyError::InvalidEncoding` 失败。
+    fn from_base64(s: &str) -> Result<Self, Error> {
+        let bytes = Base64::decode_vec(s)
+            .map(Zeroizing::new)
+            .map_err(|_| Error::Key(KeyError::InvalidEncoding))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+impl<K: Key> KeyEncoding for K {}
+
+/// Implements `Display` and `FromStr` with lowercase hex for a public key type.
+///
+/// 为公钥类型实现使用小写十六进制的 `Display` 和 `FromStr`。
+#[allow(unused_macros)]
+macro_rules! impl_hex_display {
+    ($ty:ident $(<$param:ident: $bound:path>)?) => {
+        impl$(<$param: $bound>)? core::fmt::Display for $ty$(<$param>)? {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let hex = $crate::traits::key::KeyEncoding::to_hex(self)
+                    .map_err(|_| core::fmt::Error)?;
+                f.write_str(&hex)
+            }
+        }
+
+        impl$(<$param: $bound>)? core::str::FromStr for $ty$(<$param>)? {
+            type Err = $crate::errors::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::traits::key::KeyEncoding::from_hex(s)
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_hex_display;
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::asymmetric::Signature;
+    use crate::traits::symmetric::SymmetricKey;
+
+    #[test]
+    fn test_symmetric_key_roundtrip() -> Result<(), Error> {
+        let key = SymmetricKey::new(b"\x00\x01\xfe\xffseal".to_vec());
+        assert_eq!(key.to_hex()?, "0001feff7365616c");
+        assert_eq!(key.to_base64()?, "AAH+/3NlYWw=");
+        assert_eq!(SymmetricKey::from_hex("0001FEFF7365616c")?, key);
+        assert_eq!(SymmetricKey::from_base64("AAH+/3NlYWw=")?, key);
+        Ok(())
+    }
+
+    #[test]
+    fn test_signature_roundtrip() -> Result<(), Error> {
+        let signature: Signature = (0u8..=255).collect();
+        assert_eq!(Signature::from_hex(&signature.to_hex()?)?, signature);
+        assert_eq!(Signature::from_base64(&signature.to_base64()?)?, signature);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rejects_invalid_text() {
+        for bad in ["0", "0g", " 00", "00\n"] {
+            assert!(matches!(
+                SymmetricKey::from_hex(bad),
+                Err(Error::Key(KeyError::InvalidEncoding))
+            ));
+        }
+        // Missing padding, non-canonical trailing bits and the URL-safe alphabet.
+        // 缺少填充、不规范的尾随位以及 URL 安全字母表。
+        for bad in ["AAH+/3NlYWw", "AAH+/3NlYWx=", "AAH-_3NlYWw="] {
+            assert!(matches!(
+                SymmetricKey::from_base64(bad),
+                Err(Error::Key(KeyError::InvalidEncoding))
+            ));
+        }
+    }
+
+    #[cfg(feature = "ecc-default")]
+    #[test]
+    fn test_public_key_display_from_str() -> Result<(), Error> {
+        use crate::prelude::*;
+        use crate::systems::asymmetric::traditional::ecc::Ed25519;
+
+        let (pk, _) = Ed25519::generate_keypair()?;
+        let text = pk.to_string();
+        assert_eq!(text, pk.to_hex()?);
+        let parsed: <Ed25519 as AsymmetricKeySet>::PublicKey = text.parse()?;
+        assert_eq!(parsed.to_bytes()?, pk.to_bytes()?);
+        Ok(())
+    }
+}
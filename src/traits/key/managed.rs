@@ -0,0 +1,517 @@
+//! A key wrapper that carries an ID, timestamps and the operations the key may be used for.
+//!
+//! [`ManagedKey`] keeps a key together with its metadata, and only hands the key to an
+//! operation after checking that the operation is one of its allowed [`KeyUsages`] and that
+//! the key has not expired. Times are Unix timestamps in seconds, so the metadata can be
+//! stored and checked without `std`; the methods that read the system clock require it, and on
+//! `wasm32-unknown-unknown` also the `wasm-js` feature.
+//!
+//! 携带 ID、时间戳以及该密钥可用于哪些操作的密钥包装器。
+//!
+//! [`ManagedKey`] 将密钥与其元数据保存在一起，并且只有在检查操作属于其允许的 [`KeyUsages`]
+//! 且密钥未过期之后，才会将密钥交给该操作。时间是以秒为单位的 Unix 时间戳，因此无需 `std`
+//! 即可存储和检查元数据；读取系统时钟的方法需要 `std`，在 `wasm32-unknown-unknown` 上还需要
+//! `wasm-js` 特性。
+
+use alloc::string::String;
+use crate::errors::Error;
+use crate::traits::key::KeyError;
+use core::ops::BitOr;
+
+#[cfg(all(
+    feature = "std",
+    any(not(all(target_arch = "wasm32", target_os = "unknown")), feature = "wasm-js")
+))]
+use crate::traits::{
+    asymmetric::{Kem, KeyAgreement, SharedSecret, Signature, Signer, Verifier},
+    key::SymmetricKeySet,
+    mac::Mac,
+    symmetric::{AeadDecryptor, AeadEncryptor, AssociatedData},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A set of operations a key may be used for.
+///
+/// Sets are combined with `|`, for example `KeyUsages::SIGN | KeyUsages::WRAP`.
+///
+/// 密钥可用于的一组操作。
+///
+/// 集合可以用 `|` 组合，例如 `KeyUsages::SIGN | KeyUsages::WRAP`。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyUsages(u8);
+
+impl KeyUsages {
+    /// No usage at all.
+    ///
+    /// 不允许任何用途。
+    pub const NONE: Self = Self(0);
+    /// AEAD encryption and decryption.
+    ///
+    /// AEAD 加密和解密。
+    pub const ENCRYPT: Self = Self(1 << 0);
+    /// Signing and verification, including MAC tags.
+    ///
+    /// 签名和验证，包括 MAC 标签。
+    pub const SIGN: Self = Self(1 << 1);
+    /// Wrapping and unwrapping keys, i.e. KEM encapsulation and decapsulation.
+    ///
+    /// 包装和解包密钥，即 KEM 的封装和解封装。
+    pub const WRAP: Self = Self(1 << 2);
+    /// Key agreement.
+    ///
+    /// 密钥协商。
+    pub const DERIVE: Self = Self(1 << 3);
+    /// Every usage.
+    ///
+    /// 所有用途。
+    pub const ALL: Self = Self(0b1111);
+
+    /// Returns the raw bits of the set.
+    ///
+    /// 返回集合的原始位。
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Creates a set from raw bits, or `None` if unknown bits are set.
+    ///
+    /// 从原始位创建集合；如果设置了未知的位，则返回 `None`。
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if bits & !Self::ALL.0 == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Whether every usage in `other` is also in `self`.
+    ///
+    /// `other` 中的每个用途是否都在 `self` 中。
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the usages in either set.
+    ///
+    /// 返回两个集合中任一集合包含的用途。
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOr for KeyUsages {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// A key bundled with its ID, creation time, allowed usages and optional expiry.
+///
+/// The key itself is only reachable through [`ManagedKey::key_for_at`] (or
+/// [`ManagedKey::key_for`] with `std`), which checks the usage and expiry, or by giving up the
+/// metadata with [`ManagedKey::into_inner`]. With `std`, the operations of the crate's traits
+/// are also available as methods that perform the same checks, for example
+/// `key.sign::<Ed25519>(message)`. With the `serde` feature, the key is serialized together
+/// with its metadata.
+///
+/// 一个与其 ID、创建时间、允许的用途和可选过期时间捆绑在一起的密钥。
+///
+/// 密钥本身只能通过 [`ManagedKey::key_for_at`]（或在启用 `std` 时通过 [`ManagedKey::key_for`]）
+/// 访问，它们会检查用途和过期时间；或者通过 [`ManagedKey::into_inner`] 放弃元数据来获取。
+/// 启用 `std` 时，本 crate 各 trait 的操作也以方法的形式提供，并执行相同的检查，例如
+/// `key.sign::<Ed25519>(message)`。启用 `serde` feature 时，密钥会与其元数据一起被序列化。
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ManagedKey<K> {
+    id: String,
+    key: K,
+    usages: KeyUsages,
+    created_at: u64,
+    expires_at: Option<u64>,
+}
+
+impl<K> ManagedKey<K> {
+    /// Wraps `key`, recording `created_at` as its creation time in Unix seconds.
+    ///
+    /// 包装 `key`，并将 `created_at` 记录为其创建时间（Unix 秒）。
+    pub fn new_at(id: impl Into<String>, key: K, usages: KeyUsages, created_at: u64) -> Self {
+        Self {
+            id: id.into(),
+            key,
+            usages,
+            created_at,
+            expires_at: None,
+        }
+    }
+
+    /// Wraps `key`, recording the current system time as its creation time.
+    ///
+    /// 包装 `key`，并将当前系统时间记录为其创建时间。
+    #[cfg(all(
+        feature = "std",
+        any(not(all(target_arch = "wasm32", target_os = "unknown")), feature = "wasm-js")
+    ))]
+    pub fn new(id: impl Into<String>, key: K, usages: KeyUsages) -> Self {
+        Self::new_at(id, key, usages, unix_now())
+    }
+
+    /// Sets the time, in Unix seconds, from which the key may no longer be used.
+    ///
+    /// 设置密钥从何时（Unix 秒）起不能再被使用。
+    pub fn with_expiry(mut self, expires_at: u64) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Returns the key ID.
+    ///
+    /// 返回密钥 ID。
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the allowed usages.
+    ///
+    /// 返回允许的用途。
+    pub fn usages(&self) -> KeyUsages {
+        self.usages
+    }
+
+    /// Returns the creation time in Unix seconds.
+    ///
+    /// 返回创建时间（Unix 秒）。
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    /// Returns the expiry time in Unix seconds, if any.
+    ///
+    /// 返回过期时间（Unix 秒），如果有的话。
+    pub fn expires_at(&self) -> Option<u64> {
+        self.expires_at
+    }
+
+    /// Whether the key has expired at the Unix time `unix_time`.
+    ///
+    /// 在 Unix 时间 `unix_time` 时密钥是否已过期。
+    pub fn is_expired_at(&self, unix_time: u64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| unix_time >= expires_at)
+    }
+
+    /// Returns the key for an operation needing `usage` at the Unix time `unix_time`.
+    ///
+    /// Fails with [`KeyError::UsageNotAllowed`] if `usage` is not allowed, or with
+    /// [`KeyError::Expired`] if the key has expired.
+    ///
+    /// 返回在 Unix 时间 `unix_time` 用于需要 `usage` 的操作的密钥。
+    ///
+    /// 如果不允许 `usage`，则以 [`KeyError::UsageNotAllowed`] 失败；如果密钥已过期，
+    /// 则以 [`KeyError::Expired`] 失败。
+    pub fn key_for_at(&self, usage: KeyUsages, unix_time: u64) -> Result<&K, Error> {
+        if !self.usages.contains(usage) {
+            return Err(Error::Key(KeyError::UsageNotAllowed));
+        }
+        if self.is_expired_at(unix_time) {
+            return Err(Error::Key(KeyError::Expired));
+        }
+        Ok(&self.key)
+    }
+
+    /// Returns the key for an operation needing `usage` at the current system time.
+    ///
+    /// 返回在当前系统时间用于需要 `usage` 的操作的密钥。
+    #[cfg(all(
+        feature = "std",
+        any(not(all(target_arch = "wasm32", target_os = "unknown")), feature = "wasm-js")
+    ))]
+    pub fn key_for(&self, usage: KeyUsages) -> Result<&K, Error> {
+        self.key_for_at(usage, unix_now())
+    }
+
+    /// Discards the metadata and returns the key.
+    ///
+    /// 丢弃元数据并返回密钥。
+    pub fn into_inner(self) -> K {
+        self.key
+    }
+}
+
+#[cfg(all(
+    feature = "std",
+    any(not(all(target_arch = "wasm32", target_os = "unknown")), feature = "wasm-js")
+))]
+impl<K> ManagedKey<K> {
+    /// Signs `message` with the scheme `S`. Requires [`KeyUsages::SIGN`].
+    ///
+    /// 使用方案 `S` 对 `message` 签名。需要 [`KeyUsages::SIGN`]。
+    pub fn sign<S: Signer<PrivateKey = K>>(&self, message: &[u8]) -> Result<Signature, Error> {
+        S::sign(self.key_for(KeyUsages::SIGN)?, message)
+    }
+
+    /// Verifies `signature` over `message` with the scheme `S`. Requires [`KeyUsages::SIGN`].
+    ///
+    /// 使用方案 `S` 验证 `message` 上的 `signature`。需要 [`KeyUsages::SIGN`]。
+    pub fn verify<S: Verifier<PublicKey = K>>(
+        &self,
+        message: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        S::verify(self.key_for(KeyUsages::SIGN)?, message, signature)
+    }
+
+    /// Computes the MAC tag of `message` with `M`. Requires [`KeyUsages::SIGN`].
+    ///
+    /// 使用 `M` 计算 `message` 的 MAC 标签。需要 [`KeyUsages::SIGN`]。
+    pub fn compute_tag<M: Mac + SymmetricKeySet<Key = K>>(
+        &self,
+        message: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        M::compute(self.key_for(KeyUsages::SIGN)?, message)
+    }
+
+    /// Verifies the MAC tag of `message` with `M`. Requires [`KeyUsages::SIGN`].
+    ///
+    /// 使用 `M` 验证 `message` 的 MAC 标签。需要 [`KeyUsages::SIGN`]。
+    pub fn verify_tag<M: Mac + SymmetricKeySet<Key = K>>(
+        &self,
+        message: &[u8],
+        tag: &[u8],
+    ) -> Result<(), Error> {
+        M::verify(self.key_for(KeyUsages::SIGN)?, message, tag)
+    }
+
+    /// Encrypts `plaintext` with the AEAD `A`. Requires [`KeyUsages::ENCRYPT`].
+    ///
+    /// 使用 AEAD `A` 加密 `plaintext`。需要 [`KeyUsages::ENCRYPT`]。
+    pub fn encrypt<A: AeadEncryptor + SymmetricKeySet<Key = K>>(
+        &self,
+        nonce: &[u8],
+        plaintext: &[u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<Vec<u8>, Error> {
+        A::encrypt(self.key_for(KeyUsages::ENCRYPT)?, nonce, plaintext, aad)
+    }
+
+    /// Decrypts `ciphertext_with_tag` with the AEAD `A`. Requires [`KeyUsages::ENCRYPT`].
+    ///
+    /// 使用 AEAD `A` 解密 `ciphertext_with_tag`。需要 [`KeyUsages::ENCRYPT`]。
+    pub fn decrypt<A: AeadDecryptor + SymmetricKeySet<Key = K>>(
+        &self,
+        nonce: &[u8],
+        ciphertext_with_tag: &[u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<Vec<u8>, Error> {
+        A::decrypt(
+            self.key_for(KeyUsages::ENCRYPT)?,
+            nonce,
+            ciphertext_with_tag,
+            aad,
+        )
+    }
+
+    /// Encapsulates a new shared secret with the KEM `S`. Requires [`KeyUsages::WRAP`].
+    ///
+    /// 使用 KEM `S` 封装一个新的共享密钥。需要 [`KeyUsages::WRAP`]。
+    pub fn encapsulate<S: Kem<PublicKey = K>>(
+        &self,
+    ) -> Result<(SharedSecret, S::EncapsulatedKey), Error> {
+        S::encapsulate(self.key_for(KeyUsages::WRAP)?)
+    }
+
+    /// Recovers the shared secret from `encapsulated_key` with the KEM `S`. Requires
+    /// [`KeyUsages::WRAP`].
+    ///
+    /// 使用 KEM `S` 从 `encapsulated_key` 中恢复共享密钥。需要 [`KeyUsages::WRAP`]。
+    pub fn decapsulate<S: Kem<PrivateKey = K>>(
+        &self,
+        encapsulated_key: &S::EncapsulatedKey,
+    ) -> Result<SharedSecret, Error> {
+        S::decapsulate(self.key_for(KeyUsages::WRAP)?, encapsulated_key)
+    }
+
+    /// Derives a shared secret with the peer's public key using the key agreement `S`.
+    /// Requires [`KeyUsages::DERIVE`].
+    ///
+    /// 使用密钥协商 `S` 与对方的公钥派生共享密钥。需要 [`KeyUsages::DERIVE`]。
+    pub fn agree<S: KeyAgreement<PrivateKey = K>>(
+        &self,
+        peer_public_key: &S::PublicKey,
+    ) -> Result<SharedSecret, Error> {
+        S::agree(self.key_for(KeyUsages::DERIVE)?, peer_public_key)
+    }
+}
+
+#[cfg(all(feature = "std", not(all(target_arch = "wasm32", target_os = "unknown"))))]
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// On `wasm32-unknown-unknown` the system clock is read through JavaScript.
+// 在 `wasm32-unknown-unknown` 上通过 JavaScript 读取系统时钟。
+#[cfg(all(feature = "std", target_arch = "wasm32", target_os = "unknown", feature = "wasm-js"))]
+fn unix_now() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(Clone, Debug, Default)]
+    #[cfg(feature = "std")]
+    struct TestMac;
+
+    #[cfg(feature = "std")]
+    impl Algorithm for TestMac {
+        fn name() -> String {
+            "Test-MAC".to_string()
+        }
+        const ID: u32 = 0x06_7f_00_02;
+        const SECURITY_LEVEL: SecurityLevel = SecurityLevel {
+            classical_bits: 256,
+            quantum_category: Some(5),
+            legacy: false,
+            broken: false,
+        };
+    }
+
+    #[cfg(feature = "std")]
+    impl SymmetricKeySet for TestMac {
+        type Key = SymmetricKey;
+    }
+
+    #[cfg(feature = "std")]
+    impl Mac for TestMac {
+        const TAG_SIZE: usize = 4;
+
+        fn compute(key: &Self::Key, message: &[u8]) -> Result<Vec<u8>, Error> {
+            let sum = key.iter().chain(message).fold(0u32, |acc, b| {
+                acc.wrapping_mul(31).wrapping_add(u32::from(*b))
+            });
+            Ok(sum.to_be_bytes().to_vec())
+        }
+    }
+
+    #[test]
+    fn test_usages() {
+        let usages = KeyUsages::SIGN | KeyUsages::WRAP;
+        assert!(usages.contains(KeyUsages::SIGN));
+        assert!(usages.contains(KeyUsages::NONE));
+        assert!(!usages.contains(KeyUsages::ENCRYPT));
+        assert!(!usages.contains(KeyUsages::SIGN | KeyUsages::DERIVE));
+        assert!(KeyUsages::ALL.contains(usages));
+        assert_eq!(KeyUsages::from_bits(usages.bits()), Some(usages));
+        assert_eq!(KeyUsages::from_bits(0x10), None);
+    }
+
+    #[test]
+    fn test_usage_and_expiry_enforced() -> Result<(), Error> {
+        let key = ManagedKey::new_at(
+            "mac-1",
+            SymmetricKey::new(vec![7u8; 32]),
+            KeyUsages::SIGN,
+            1_000,
+        )
+        .with_expiry(2_000);
+        assert_eq!(key.id(), "mac-1");
+        assert_eq!(key.created_at(), 1_000);
+        assert_eq!(key.expires_at(), Some(2_000));
+
+        assert!(key.key_for_at(KeyUsages::SIGN, 1_999).is_ok());
+        assert_eq!(
+            key.key_for_at(KeyUsages::ENCRYPT, 1_500).err(),
+            Some(Error::Key(KeyError::UsageNotAllowed))
+        );
+        assert_eq!(
+            key.key_for_at(KeyUsages::SIGN, 2_000).err(),
+            Some(Error::Key(KeyError::Expired))
+        );
+        assert!(!key.is_expired_at(1_999));
+        assert!(key.is_expired_at(2_000));
+
+        // The current time is far past the expiry.
+        #[cfg(feature = "std")]
+        assert_eq!(
+            key.compute_tag::<TestMac>(b"message").err(),
+            Some(Error::Key(KeyError::Expired))
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_operations_checked() -> Result<(), Error> {
+        let sign_key = ManagedKey::new("mac", SymmetricKey::new(vec![1u8; 32]), KeyUsages::SIGN);
+        let tag = sign_key.compute_tag::<TestMac>(b"message")?;
+        sign_key.verify_tag::<TestMac>(b"message", &tag)?;
+        assert!(sign_key.usages().contains(KeyUsages::SIGN));
+
+        let encrypt_key = ManagedKey::new(
+            "aead",
+            SymmetricKey::new(vec![1u8; 32]),
+            KeyUsages::ENCRYPT | KeyUsages::WRAP,
+        );
+        assert_eq!(
+            encrypt_key.compute_tag::<TestMac>(b"message").err(),
+            Some(Error::Key(KeyError::UsageNotAllowed))
+        );
+        assert_eq!(
+            encrypt_key.verify_tag::<TestMac>(b"message", &tag).err(),
+            Some(Error::Key(KeyError::UsageNotAllowed))
+        );
+        assert_eq!(encrypt_key.into_inner().as_slice(), &[1u8; 32]);
+        Ok(())
+    }
+
+    #[cfg(all(feature = "std", feature = "ecc-default", feature = "aes-gcm-default"))]
+    #[test]
+    fn test_signing_key_cannot_encrypt() -> Result<(), Error> {
+        use crate::systems::aead::aes_gcm::Aes256Gcm;
+        use crate::systems::asymmetric::traditional::ecc::Ed25519;
+
+        let (public_key, private_key) = Ed25519::generate_keypair()?;
+        let signing = ManagedKey::new("ed25519", private_key, KeyUsages::SIGN);
+        let verifying = ManagedKey::new("ed25519.pub", public_key, KeyUsages::SIGN);
+        let signature = signing.sign::<Ed25519>(b"message")?;
+        verifying.verify::<Ed25519>(b"message", &signature)?;
+
+        let aead = ManagedKey::new("aes", Aes256Gcm::generate_key()?, KeyUsages::ENCRYPT);
+        let nonce = [0u8; 12];
+        let ciphertext = aead.encrypt::<Aes256Gcm>(&nonce, b"message", None)?;
+        assert_eq!(
+            aead.decrypt::<Aes256Gcm>(&nonce, &ciphertext, None)?,
+            b"message"
+        );
+
+        let wrong = ManagedKey::new("aes", Aes256Gcm::generate_key()?, KeyUsages::SIGN);
+        assert_eq!(
+            wrong.encrypt::<Aes256Gcm>(&nonce, b"message", None).err(),
+            Some(Error::Key(KeyError::UsageNotAllowed))
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() -> Result<(), serde_json::Error> {
+        let key = ManagedKey::new_at("k", SymmetricKey::new(vec![3u8; 16]), KeyUsages::ALL, 5)
+            .with_expiry(10);
+        let json = serde_json::to_string(&key)?;
+        let restored: ManagedKey<SymmetricKey> = serde_json::from_str(&json)?;
+        assert_eq!(restored.id(), "k");
+        assert_eq!(restored.usages(), KeyUsages::ALL);
+        assert_eq!(restored.created_at(), 5);
+        assert_eq!(restored.expires_at(), Some(10));
+        assert_eq!(restored.into_inner().as_slice(), &[3u8; 16]);
+        Ok(())
+    }
+}
@@ -0,0 +1,485 @@
+//! Storage for secret bytes such as symmetric keys and private keys.
+//!
+//! [`SecretBytes`] is zeroized when dropped. With the `secure-mem` feature on Unix, each value
+//! is additionally kept in its own anonymous memory mapping, outside the heap, that is locked
+//! into RAM with `mlock` so it is never written to swap, and on Linux excluded from core dumps
+//! with `MADV_DONTDUMP`. As in `memsec`, the mapping starts and ends with a `PROT_NONE` guard
+//! page, and the bytes are placed right before the trailing guard page, so a linear overflow
+//! into or out of the value faults instead of reaching other memory. Locking is subject to the
+//! process's `RLIMIT_MEMLOCK`, and every value occupies at least three pages. The infallible
+//! constructors keep a value on the heap when its mapping cannot be set up, and
+//! [`SecretBytes::is_locked`] reports which storage is used; [`SecretBytes::try_locked`] fails
+//! instead, for callers whose threat model requires locking. Other platforms always use the
+//! heap.
+//!
+//! The pages between the guard pages stay readable and writable for the value's whole
+//! lifetime; they are not made inaccessible between uses.
+//!
+//! 用于对称密钥和私钥等秘密字节的存储。
+//!
+//! [`SecretBytes`] 在销毁时被清零。在 Unix 上启用 `secure-mem` 特性时，每个值还会保存在堆之外
+//! 它自己的匿名内存映射中，该映射通过 `mlock` 锁定在内存中，因此永远不会被写入交换区，并且在
+//! Linux 上通过 `MADV_DONTDUMP` 从核心转储中排除。与 `memsec` 一样，映射的首尾各有一个
+//! `PROT_NONE` 保护页，字节紧贴在尾部保护页之前，因此越过该值的线性溢出会触发故障，而不会
+//! 触及其他内存。锁定受进程的 `RLIMIT_MEMLOCK` 限制，且每个值至少占用三页。不会失败的构造函数
+//! 在无法建立映射时将值留在堆上，[`SecretBytes::is_locked`] 报告所使用的存储；
+//! [`SecretBytes::try_locked`] 则会失败，供威胁模型要求锁定的调用者使用。其他平台始终使用堆。
+//!
+//! 保护页之间的页在值的整个生命周期内都保持可读可写；它们在两次使用之间不会被设为不可访问。
+
+use crate::errors::Error;
+use crate::traits::key::KeyError;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+#[cfg(all(feature = "secure-mem", unix))]
+use core::ptr::{self, NonNull};
+#[cfg(all(feature = "secure-mem", unix))]
+use core::slice;
+#[cfg(feature = "secrecy")]
+use secrecy::{ExposeSecret, SecretBox};
+
+/// Read access to secret bytes held in a wrapper that protects them, such as [`SecretBytes`],
+/// [`DerivedKey`](crate::prelude::DerivedKey), [`SharedSecret`](crate::prelude::SharedSecret)
+/// or, with the `secrecy` feature, `secrecy::SecretBox<[u8]>`.
+///
+/// Password-based KDFs take passwords through this trait, key-based KDFs accept IKM through
+/// [`KeyBasedDerivation::derive_from_secret`](crate::prelude::KeyBasedDerivation::derive_from_secret),
+/// and [`SecretBytes::from_secret`] turns any of them into a symmetric key, so a secret keeps
+/// its wrapper until the scheme reads it.
+///
+/// 对保存在保护性包装器中的秘密字节的读取访问，例如 [`SecretBytes`]、
+/// [`DerivedKey`](crate::prelude::DerivedKey)、[`SharedSecret`](crate::prelude::SharedSecret)，
+/// 或在启用 `secrecy` 特性时的 `secrecy::SecretBox<[u8]>`。
+///
+/// 基于密码的 KDF 通过此 trait 接收密码，基于密钥的 KDF 通过
+/// [`KeyBasedDerivation::derive_from_secret`](crate::prelude::KeyBasedDerivation::derive_from_secret)
+/// 接收 IKM，而 [`SecretBytes::from_secret`] 可将其中任意一种转换为对称密钥，因此秘密在方案读取它之前
+/// 始终保留在其包装器中。
+pub trait ExposeSecretBytes {
+    /// Returns the secret bytes. Avoid copying them out of the returned slice.
+    ///
+    /// 返回秘密字节。避免将它们从返回的切片中复制出来。
+    fn expose_secret_bytes(&self) -> &[u8];
+}
+
+impl ExposeSecretBytes for SecretBytes {
+    fn expose_secret_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+impl ExposeSecretBytes for Zeroizing<Vec<u8>> {
+    fn expose_secret_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl ExposeSecretBytes for SecretBox<[u8]> {
+    fn expose_secret_bytes(&self) -> &[u8] {
+        self.expose_secret()
+    }
+}
+
+/// Secret bytes that are zeroized on drop and, with the `secure-mem` feature, kept in locked,
+/// guarded memory that is excluded from swap and core dumps.
+///
+/// It dereferences to `[u8]`. Equality is constant-time with respect to the contents, and the
+/// `Debug` output does not include them.
+///
+/// 在销毁时被清零的秘密字节；启用 `secure-mem` 特性时，保存在不会进入交换区和核心转储、带保护页的
+/// 锁定内存中。
+///
+/// 它解引用为 `[u8]`。相等比较对内容是常量时间的，`Debug` 输出不包含内容。
+pub struct SecretBytes(Storage);
+
+enum Storage {
+    Heap(Zeroizing<Vec<u8>>),
+    #[cfg(all(feature = "secure-mem", unix))]
+    Locked(GuardedMap),
+}
+
+impl SecretBytes {
+    /// Takes ownership of `bytes`. With `secure-mem` the bytes are moved into locked memory and
+    /// the vector is zeroized.
+    ///
+    /// 获取 `bytes` 的所有权。启用 `secure-mem` 时，字节会被移入锁定内存，并且该向量被清零。
+    pub fn new(bytes: Vec<u8>) -> Self {
+        let bytes = Zeroizing::new(bytes);
+        #[cfg(all(feature = "secure-mem", unix))]
+        if let Some(map) = GuardedMap::new(&bytes) {
+            return Self(Storage::Locked(map));
+        }
+        Self(Storage::Heap(bytes))
+    }
+
+    /// Copies `bytes` into a new value.
+    ///
+    /// 将 `bytes` 复制到一个新值中。
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        #[cfg(all(feature = "secure-mem", unix))]
+        if let Some(map) = GuardedMap::new(bytes) {
+            return Self(Storage::Locked(map));
+        }
+        Self(Storage::Heap(Zeroizing::new(bytes.to_vec())))
+    }
+
+    /// Copies `bytes` into locked memory, failing with `KeyError::MemoryLockFailed` instead of
+    /// falling back to the heap. It always fails without the `secure-mem` feature, on platforms
+    /// other than Unix, and for empty input, which cannot be mapped.
+    ///
+    /// 将 `bytes` 复制到锁定内存中；无法锁定时以 `KeyError::MemoryLockFailed` 失败，而不是退回到堆。
+    /// 未启用 `secure-mem` 特性、在 Unix 以外的平台上以及输入为空（无法映射）时，它总是失败。
+    pub fn try_locked(bytes: &[u8]) -> Result<Self, Error> {
+        #[cfg(all(feature = "secure-mem", unix))]
+        if let Some(map) = GuardedMap::new(bytes) {
+            return Ok(Self(Storage::Locked(map)));
+        }
+        #[cfg(not(all(feature = "secure-mem", unix)))]
+        let _ = bytes;
+        Err(Error::Key(KeyError::MemoryLockFailed))
+    }
+
+    /// Copies the bytes of another secret wrapper, for example a `secrecy::SecretBox<[u8]>`,
+    /// into a new value.
+    ///
+    /// 将另一个秘密包装器（例如 `secrecy::SecretBox<[u8]>`）的字节复制到一个新值中。
+    pub fn from_secret(secret: &impl ExposeSecretBytes) -> Self {
+        Self::from_slice(secret.expose_secret_bytes())
+    }
+
+    /// Returns `true` if the bytes are held in locked memory.
+    ///
+    /// 如果字节保存在锁定内存中，则返回 `true`。
+    pub fn is_locked(&self) -> bool {
+        match self.0 {
+            Storage::Heap(_) => false,
+            #[cfg(all(feature = "secure-mem", unix))]
+            Storage::Locked(_) => true,
+        }
+    }
+
+    /// Returns the bytes.
+    ///
+    /// 返回字节。
+    pub fn as_slice(&self) -> &[u8] {
+        match &self.0 {
+            Storage::Heap(bytes) => bytes,
+            #[cfg(all(feature = "secure-mem", unix))]
+            Storage::Locked(map) => map.as_slice(),
+        }
+    }
+
+    /// Returns the bytes mutably.
+    ///
+    /// 以可变方式返回字节。
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        match &mut self.0 {
+            Storage::Heap(bytes) => bytes,
+            #[cfg(all(feature = "secure-mem", unix))]
+            Storage::Locked(map) => map.as_mut_slice(),
+        }
+    }
+}
+
+/// A locked anonymous mapping holding secret bytes between two `PROT_NONE` guard pages:
+///
+/// ```text
+/// guard page || unused padding || bytes || guard page
+/// ```
+///
+/// The mapping is managed through `libc` rather than `memmap2`, because a `memmap2` mapping
+/// can only be read through a slice over all of its pages, guard pages included.
+///
+/// 一个锁定的匿名映射，在两个 `PROT_NONE` 保护页之间保存秘密字节。该映射通过 `libc` 而不是
+/// `memmap2` 管理，因为 `memmap2` 映射只能通过覆盖其所有页（包括保护页）的切片读取。
+#[cfg(all(feature = "secure-mem", unix))]
+struct GuardedMap {
+    base: NonNull<u8>,
+    size: usize,
+    offset: usize,
+    len: usize,
+}
+
+// SAFETY: the mapping is owned by this value alone, like the allocation of a `Box<[u8]>`.
+#[cfg(all(feature = "secure-mem", unix))]
+#[allow(unsafe_code)]
+unsafe impl Send for GuardedMap {}
+
+// SAFETY: shared access only reads the mapping.
+#[cfg(all(feature = "secure-mem", unix))]
+#[allow(unsafe_code)]
+unsafe impl Sync for GuardedMap {}
+
+#[cfg(all(feature = "secure-mem", unix))]
+#[allow(unsafe_code)]
+impl GuardedMap {
+    /// Copies `bytes` into a new guarded, locked mapping, or returns `None` if that is not
+    /// possible. The bytes are only copied once the mapping is locked and guarded.
+    ///
+    /// 将 `bytes` 复制到新的带保护页的锁定映射中；如果无法做到，则返回 `None`。只有在映射被锁定
+    /// 并设置保护页之后才会复制字节。
+    fn new(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+        // SAFETY: `sysconf` only reads a system configuration value.
+        let page = usize::try_from(unsafe { libc::sysconf(libc::_SC_PAGESIZE) }).ok()?;
+        let inner = bytes.len().checked_next_multiple_of(page)?;
+        let size = inner.checked_add(2 * page)?;
+        // SAFETY: a new private anonymous mapping at an address chosen by the kernel does not
+        // overlap any existing memory.
+        let base = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANON,
+                -1,
+                0,
+            )
+        };
+        if base == libc::MAP_FAILED {
+            return None;
+        }
+        // From here on, dropping `guarded` unmaps the pages again.
+        // 从这里开始，销毁 `guarded` 会再次取消这些页的映射。
+        let mut guarded = Self {
+            base: NonNull::new(base.cast())?,
+            size,
+            offset: page + inner - bytes.len(),
+            len: bytes.len(),
+        };
+        let start = guarded.base.as_ptr();
+        // SAFETY: every range below lies within the mapping and is page-aligned, and no
+        // reference into the mapping exists yet.
+        let set_up = unsafe {
+            libc::mlock(start.add(page).cast(), inner) == 0
+                && libc::mprotect(start.cast(), page, libc::PROT_NONE) == 0
+                && libc::mprotect(start.add(page + inner).cast(), page, libc::PROT_NONE) == 0
+        };
+        if !set_up {
+            return None;
+        }
+        #[cfg(target_os = "linux")]
+        // SAFETY: as above; `MADV_DONTDUMP` does not change the contents of the pages.
+        if unsafe { libc::madvise(start.cast(), size, libc::MADV_DONTDUMP) } != 0 {
+            return None;
+        }
+        guarded.as_mut_slice().copy_from_slice(bytes);
+        Some(guarded)
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        // SAFETY: `offset..offset + len` lies in the readable and writable pages between the
+        // guard pages, and the mapping lives as long as `self`.
+        unsafe { slice::from_raw_parts(self.base.as_ptr().add(self.offset), self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: as in `as_slice`; the mutable borrow of `self` makes the slice unique.
+        unsafe { slice::from_raw_parts_mut(self.base.as_ptr().add(self.offset), self.len) }
+    }
+}
+
+#[cfg(all(feature = "secure-mem", unix))]
+#[allow(unsafe_code)]
+impl Drop for GuardedMap {
+    fn drop(&mut self) {
+        // SAFETY: `base` and `size` describe a mapping created by `new`, and no slice into it
+        // outlives `self`. Unmapping also unlocks the pages.
+        unsafe {
+            libc::munmap(self.base.as_ptr().cast(), self.size);
+        }
+    }
+}
+
+impl Zeroize for SecretBytes {
+    fn zeroize(&mut self) {
+        self.as_mut_slice().zeroize();
+        // Release the mapping as well, matching `Vec::zeroize`, which leaves an empty vector.
+        // 同时释放映射，与留下空向量的 `Vec::zeroize` 保持一致。
+        self.0 = Storage::Heap(Zeroizing::new(Vec::new()));
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretBytes {}
+
+impl Clone for SecretBytes {
+    fn clone(&self) -> Self {
+        Self::from_slice(self)
+    }
+}
+
+impl Deref for SecretBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for SecretBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl AsRef<[u8]> for SecretBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl AsMut<[u8]> for SecretBytes {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<Zeroizing<Vec<u8>>> for SecretBytes {
+    fn from(mut bytes: Zeroizing<Vec<u8>>) -> Self {
+        Self::new(core::mem::take(&mut *bytes))
+    }
+}
+
+impl From<&[u8]> for SecretBytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_slice(bytes)
+    }
+}
+
+impl ConstantTimeEq for SecretBytes {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretBytes")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecretBytes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        super::serde_bytes::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretBytes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        super::serde_bytes::deserialize(deserializer)
+    }
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_bytes() {
+        let mut secret = SecretBytes::new(vec![1u8, 2, 3, 4]);
+        assert_eq!(&secret[..], [1, 2, 3, 4]);
+        secret[0] = 9;
+        assert_eq!(secret.as_slice(), [9, 2, 3, 4]);
+
+        let copy = secret.clone();
+        assert_eq!(copy, secret);
+        assert_eq!(copy.is_locked(), secret.is_locked());
+        assert_ne!(copy, SecretBytes::from_slice(&[9, 2, 3, 5]));
+        assert!(!format!("{copy:?}").contains('9'));
+
+        secret.zeroize();
+        assert!(secret.is_empty());
+        assert!(!secret.is_locked());
+
+        let copy = SecretBytes::from_secret(&copy);
+        assert_eq!(copy.expose_secret_bytes(), [9, 2, 3, 4]);
+
+        let empty = SecretBytes::from_slice(&[]);
+        assert!(empty.is_empty());
+        assert!(!empty.is_locked());
+    }
+
+    #[cfg(feature = "secrecy")]
+    #[test]
+    fn test_expose_secret_bytes() {
+        let password = SecretBox::new(alloc::boxed::Box::from(b"password".as_slice()));
+        assert_eq!(password.expose_secret_bytes(), b"password");
+        let key = SecretBytes::from_secret(&password);
+        assert_eq!(key.as_slice(), b"password");
+    }
+
+    #[cfg(not(all(feature = "secure-mem", unix)))]
+    #[test]
+    fn test_heap_storage() {
+        assert!(!SecretBytes::from_slice(&[7u8; 32]).is_locked());
+        assert_eq!(
+            SecretBytes::try_locked(&[7u8; 32]),
+            Err(Error::Key(KeyError::MemoryLockFailed))
+        );
+    }
+
+    #[cfg(all(feature = "secure-mem", unix))]
+    #[test]
+    fn test_locked_storage() {
+        let secret = SecretBytes::from_slice(&[7u8; 32]);
+        // Locking can be refused by `RLIMIT_MEMLOCK`; the bytes must be intact either way.
+        assert_eq!(secret.as_slice(), [7u8; 32]);
+        if secret.is_locked() {
+            assert!(secret.clone().is_locked());
+            let Storage::Locked(guarded) = &secret.0 else {
+                unreachable!()
+            };
+            assert_eq!(guarded.len, 32);
+            // The bytes end right before the trailing guard page
+            let guard = guarded.size - (guarded.offset + guarded.len);
+            assert_eq!((guarded.offset + guarded.len) % guard, 0);
+        }
+
+        // The fail-closed constructor either locks or reports the failure
+        match SecretBytes::try_locked(&[7u8; 32]) {
+            Ok(locked) => {
+                assert!(locked.is_locked());
+                assert_eq!(locked.as_slice(), [7u8; 32]);
+            }
+            Err(err) => assert_eq!(err, Error::Key(KeyError::MemoryLockFailed)),
+        }
+        assert_eq!(
+            SecretBytes::try_locked(&[]).unwrap_err(),
+            Error::Key(KeyError::MemoryLockFailed)
+        );
+    }
+}
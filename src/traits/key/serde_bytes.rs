@@ -0,0 +1,203 @@
+//! Serde representation of key and message bytes.
+//!
+//! Byte fields are written as standard padded Base64 strings when the format is human-readable
+//! (JSON, TOML, YAML), and as raw byte strings when it is binary (bincode, CBOR). Decoding
+//! accepts either form, and also the integer arrays written by earlier versions. Base64 is
+//! decoded in constant time, and decoded bytes are zeroized once consumed.
+//!
+//! Use with `#[serde(with = "crate::traits::key::serde_bytes")]` on `Vec<u8>` and
+//! `Zeroizing<Vec<u8>>` fields, or with the [`option`] submodule on optional ones.
+//!
+//! 密钥和消息字节的 serde 表示。
+//!
+//! 当格式为人类可读格式（JSON、TOML、YAML）时，字节字段被写为标准带填充 Base64 字符串；
+//! 当格式为二进制格式（bincode、CBOR）时，被写为原始字节串。解码接受这两种形式，也接受
+//! 早期版本写出的整数数组。Base64 以常数时间解码，解码出的字节在使用后会被清零。
+//!
+//! 在 `Vec<u8>` 和 `Zeroizing<Vec<u8>>` 字段上使用
+//! `#[serde(with = "crate::traits::key::serde_bytes")]`，可选字段则使用 [`option`] 子模块。
+
+use alloc::vec::Vec;
+use base64ct::{Base64, Encoding};
+use core::fmt;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroizing;
+
+struct BytesRef<'a>(&'a [u8]);
+
+impl Serialize for BytesRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let encoded = Zeroizing::new(Base64::encode_string(self.0));
+            serializer.serialize_str(&encoded)
+        } else {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+}
+
+struct Bytes(Zeroizing<Vec<u8>>);
+
+impl Bytes {
+    fn into_inner<T: From<Vec<u8>>>(mut self) -> T {
+        T::from(core::mem::take(&mut *self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a Base64 string or a byte string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Bytes, E> {
+        Base64::decode_vec(v)
+            .map(|bytes| Bytes(Zeroizing::new(bytes)))
+            .map_err(|_| E::invalid_value(de::Unexpected::Str("<redacted>"), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Bytes, E> {
+        Ok(Bytes(Zeroizing::new(v.to_vec())))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Bytes, E> {
+        Ok(Bytes(Zeroizing::new(v)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Bytes, A::Error> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(seq.size_hint().unwrap_or(0)));
+        while let Some(byte) = seq.next_element::<u8>()? {
+            bytes.push(byte);
+        }
+        Ok(Bytes(bytes))
+    }
+}
+
+/// Serializes a byte field.
+///
+/// 序列化字节字段。
+pub(crate) fn serialize<T: AsRef<[u8]>, S: Serializer>(
+    bytes: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    BytesRef(bytes.as_ref()).serialize(serializer)
+}
+
+/// Deserializes a byte field.
+///
+/// 反序列化字节字段。
+pub(crate) fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    Bytes::deserialize(deserializer).map(Bytes::into_inner)
+}
+
+/// The same representation for optional byte fields.
+///
+/// 可选字节字段的相同表示。
+#[allow(dead_code)]
+pub(crate) mod option {
+    use super::{Bytes, BytesRef};
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes an optional byte field.
+    ///
+    /// 序列化可选字节字段。
+    pub(crate) fn serialize<T: AsRef<[u8]>, S: Serializer>(
+        bytes: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => serializer.serialize_some(&BytesRef(bytes.as_ref())),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserializes an optional byte field.
+    ///
+    /// 反序列化可选字节字段。
+    pub(crate) fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Ok(Option::<Bytes>::deserialize(deserializer)?.map(Bytes::into_inner))
+    }
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::Error;
+    use crate::traits::key::KeyError;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super")]
+        key: Zeroizing<Vec<u8>>,
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "super::option"
+        )]
+        seed: Option<Vec<u8>>,
+    }
+
+    fn err<E>(_: E) -> Error {
+        Error::Key(KeyError::InvalidEncoding)
+    }
+
+    #[test]
+    fn test_human_readable_uses_base64() -> Result<(), Error> {
+        let record = Record {
+            key: Zeroizing::new(vec![0xfb, 0xff, 0x00]),
+            seed: Some(vec![1, 2, 3, 4]),
+        };
+        let json = serde_json::to_string(&record).map_err(err)?;
+        assert_eq!(json, r#"{"key":"+/8A","seed":"AQIDBA=="}"#);
+        assert_eq!(serde_json::from_str::<Record>(&json).map_err(err)?, record);
+
+        // Integer arrays written by earlier versions are still accepted.
+        // 仍然接受早期版本写出的整数数组。
+        let legacy: Record = serde_json::from_str(r#"{"key":[251,255,0]}"#).map_err(err)?;
+        assert_eq!(legacy.key.as_slice(), [0xfb, 0xff, 0x00]);
+        assert_eq!(legacy.seed, None);
+
+        assert!(serde_json::from_str::<Record>(r#"{"key":"+/8"}"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_uses_raw_bytes() -> Result<(), Error> {
+        let record = Record {
+            key: Zeroizing::new(vec![0xaa; 32]),
+            seed: Some(vec![0x55; 4]),
+        };
+        let encoded = bincode::serialize(&record).map_err(err)?;
+        // 8-byte length prefixes followed by the raw bytes, and the `Some` tag.
+        // 8 字节长度前缀后跟原始字节，以及 `Some` 标签。
+        assert_eq!(encoded.len(), 8 + 32 + 1 + 8 + 4);
+        assert_eq!(&encoded[8..40], [0xaa; 32]);
+        assert_eq!(
+            bincode::deserialize::<Record>(&encoded).map_err(err)?,
+            record
+        );
+        Ok(())
+    }
+}
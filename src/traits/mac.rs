@@ -0,0 +1,208 @@
+//! Defines traits for message authentication codes (MACs).
+//!
+//! 定义了消息认证码 (MAC) 的 trait。
+
+use alloc::vec::Vec;
+use crate::{errors::Error, traits::key::SymmetricKeySet};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+/// Defines the errors that can occur during MAC computation and verification.
+///
+/// 定义了在 MAC 计算和验证过程中可能发生的错误。
+#[cfg_attr(feature = "std", derive(Error))]
+#[derive(Debug, PartialEq, Eq)]
+pub enum MacError {
+    /// The provided key has an invalid size for this MAC.
+    ///
+    /// 提供的密钥大小对于此 MAC 无效。
+    #[cfg_attr(feature = "std", error("Invalid key size for this MAC"))]
+    InvalidKeySize,
+
+    /// The provided nonce has an invalid size for this MAC.
+    ///
+    /// 提供的 nonce 大小对于此 MAC 无效。
+    #[cfg_attr(feature = "std", error("Invalid nonce size for this MAC"))]
+    InvalidNonceSize,
+
+    /// The requested tag length is not supported by this MAC.
+    ///
+    /// 此 MAC 不支持所请求的标签长度。
+    #[cfg_attr(feature = "std", error("Invalid tag length for this MAC"))]
+    InvalidTagLength,
+
+    /// A configuration parameter of this MAC is out of range.
+    ///
+    /// 此 MAC 的某个配置参数超出范围。
+    #[cfg_attr(feature = "std", error("Invalid parameter for this MAC"))]
+    InvalidParameter,
+
+    /// The tag does not match the message and key.
+    ///
+    /// 标签与消息和密钥不匹配。
+    #[cfg_attr(feature = "std", error("MAC verification failed"))]
+    VerificationFailed,
+
+    /// The provided output buffer is too small to hold the tag.
+    ///
+    /// 提供的输出缓冲区太小，无法容纳标签。
+    #[cfg_attr(feature = "std", error("Output buffer too small"))]
+    OutputTooSmall,
+
+    /// The system clock reads a time before the Unix epoch.
+    ///
+    /// 系统时钟读到的时间早于 Unix 纪元。
+    #[cfg_attr(feature = "std", error("System time is before the Unix epoch"))]
+    InvalidTime,
+}
+
+/// The largest tag that [`Mac::verify`] recomputes on the stack, in bytes.
+/// Longer tags are recomputed on the heap.
+///
+/// [`Mac::verify`] 在栈上重新计算的最大标签（以字节为单位）。更长的标签在堆上重新计算。
+const STACK_TAG_SIZE: usize = 64;
+
+/// Copies a tag into `output`, returning the number of bytes written.
+///
+/// 将标签复制到 `output` 中，并返回写入的字节数。
+pub(crate) fn write_tag(tag: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+    let out = output
+        .get_mut(..tag.len())
+        .ok_or(Error::Mac(MacError::OutputTooSmall))?;
+    out.copy_from_slice(tag);
+    Ok(tag.len())
+}
+
+/// The shortest truncated tag accepted by [`verify_truncated_tag`], in bytes.
+///
+/// Follows the 32-bit lower bound of NIST SP 800-107. Protocols should prefer 8 bytes or more.
+///
+/// [`verify_truncated_tag`] 接受的最短截断标签（以字节为单位）。
+///
+/// 遵循 NIST SP 800-107 的 32 位下限。协议应优先使用 8 字节或更长的标签。
+pub const MIN_TRUNCATED_TAG_SIZE: usize = 4;
+
+/// Checks that `tag` equals the first `len` bytes of `expected`, in constant time.
+///
+/// `len` must lie between [`MIN_TRUNCATED_TAG_SIZE`] and `expected.len()`, otherwise
+/// `MacError::InvalidTagLength` is returned. A `tag` that is not exactly `len` bytes long,
+/// or whose bytes differ, yields `MacError::VerificationFailed`.
+///
+/// 以常量时间检查 `tag` 是否等于 `expected` 的前 `len` 个字节。
+///
+/// `len` 必须介于 [`MIN_TRUNCATED_TAG_SIZE`] 和 `expected.len()` 之间，否则返回
+/// `MacError::InvalidTagLength`。若 `tag` 的长度不恰好为 `len` 字节或其内容不同，
+/// 则返回 `MacError::VerificationFailed`。
+pub fn verify_truncated_tag(expected: &[u8], tag: &[u8], len: usize) -> Result<(), Error> {
+    if len < MIN_TRUNCATED_TAG_SIZE || len > expected.len() {
+        return Err(Error::Mac(MacError::InvalidTagLength));
+    }
+    if tag.len() == len && bool::from(expected[..len].ct_eq(tag)) {
+        Ok(())
+    } else {
+        Err(Error::Mac(MacError::VerificationFailed))
+    }
+}
+
+/// A trait for message authentication codes.
+///
+/// `verify` recomputes the tag and compares it to the provided one in constant time,
+/// so callers should always use it rather than comparing tags themselves.
+///
+/// 消息认证码的 trait。
+///
+/// `verify` 会重新计算标签并以常量时间与提供的标签进行比较，
+/// 因此调用者应始终使用它，而不是自行比较标签。
+pub trait Mac: SymmetricKeySet {
+    /// The size of the authentication tag in bytes.
+    ///
+    /// 认证标签的大小（以字节为单位）。
+    const TAG_SIZE: usize;
+
+    /// Computes the authentication tag of a message.
+    ///
+    /// 计算消息的认证标签。
+    fn compute(key: &Self::Key, message: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// Computes the authentication tag of a message and writes it to the start of `output`,
+    /// returning the number of bytes written. Fails with `MacError::OutputTooSmall` if
+    /// `output` is shorter than [`TAG_SIZE`](Self::TAG_SIZE).
+    ///
+    /// HMAC and CMAC implement it without heap allocation. The default implementation goes
+    /// through [`compute`](Self::compute).
+    ///
+    /// 计算消息的认证标签并将其写入 `output` 的开头，返回写入的字节数。如果 `output` 短于
+    /// [`TAG_SIZE`](Self::TAG_SIZE)，则以 `MacError::OutputTooSmall` 失败。
+    ///
+    /// HMAC 和 CMAC 在实现它时不进行堆分配。默认实现经由 [`compute`](Self::compute)。
+    fn compute_to_buffer(key: &Self::Key, message: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        write_tag(&Self::compute(key, message)?, output)
+    }
+
+    /// Computes the authentication tag of a message and returns it as an array.
+    /// Fails with `MacError::InvalidTagLength` unless `N` equals [`TAG_SIZE`](Self::TAG_SIZE).
+    ///
+    /// 计算消息的认证标签并以数组形式返回。除非 `N` 等于 [`TAG_SIZE`](Self::TAG_SIZE)，
+    /// 否则以 `MacError::InvalidTagLength` 失败。
+    fn compute_array<const N: usize>(key: &Self::Key, message: &[u8]) -> Result<[u8; N], Error> {
+        if N != Self::TAG_SIZE {
+            return Err(Error::Mac(MacError::InvalidTagLength));
+        }
+        let mut tag = [0u8; N];
+        Self::compute_to_buffer(key, message, &mut tag)?;
+        Ok(tag)
+    }
+
+    /// Verifies the authentication tag of a message in constant time.
+    ///
+    /// Tags of up to 64 bytes are recomputed with [`compute_to_buffer`](Self::compute_to_buffer)
+    /// into a stack buffer.
+    ///
+    /// 以常量时间验证消息的认证标签。
+    ///
+    /// 不超过 64 字节的标签通过 [`compute_to_buffer`](Self::compute_to_buffer) 在栈缓冲区中重新计算。
+    fn verify(key: &Self::Key, message: &[u8], tag: &[u8]) -> Result<(), Error> {
+        let mut buffer = [0u8; STACK_TAG_SIZE];
+        let heap;
+        let expected = if Self::TAG_SIZE <= STACK_TAG_SIZE {
+            let len = Self::compute_to_buffer(key, message, &mut buffer)?;
+            &buffer[..len]
+        } else {
+            heap = Self::compute(key, message)?;
+            heap.as_slice()
+        };
+        if bool::from(expected.ct_eq(tag)) {
+            Ok(())
+        } else {
+            Err(Error::Mac(MacError::VerificationFailed))
+        }
+    }
+
+    /// Computes the authentication tag of a message, truncated to its first `len` bytes.
+    ///
+    /// 计算消息的认证标签，并截断为其前 `len` 个字节。
+    fn compute_truncated(key: &Self::Key, message: &[u8], len: usize) -> Result<Vec<u8>, Error> {
+        if len < MIN_TRUNCATED_TAG_SIZE || len > Self::TAG_SIZE {
+            return Err(Error::Mac(MacError::InvalidTagLength));
+        }
+        let mut tag = Self::compute(key, message)?;
+        tag.truncate(len);
+        Ok(tag)
+    }
+
+    /// Verifies a tag truncated to `len` bytes in constant time.
+    /// See [`verify_truncated_tag`] for the accepted lengths.
+    ///
+    /// 以常量时间验证截断为 `len` 字节的标签。接受的长度参见 [`verify_truncated_tag`]。
+    fn verify_truncated(
+        key: &Self::Key,
+        message: &[u8],
+        tag: &[u8],
+        len: usize,
+    ) -> Result<(), Error> {
+        let expected = Self::compute(key, message)?;
+        verify_truncated_tag(&expected, tag, len)
+    }
+}
@@ -6,6 +6,7 @@ pub mod hash;
 #[cfg(feature = "shake-default")]
 pub mod xof;
 
+use alloc::{string::String, vec::Vec};
 #[cfg(feature = "sha2")]
 pub use hash::*;
 #[cfg(feature = "shake-default")]
@@ -21,7 +22,7 @@ pub use xof::*;
 ///
 /// 此 trait 为定义密码学方案的基本属性（如其名称和唯一标识符）提供了一个通用接口。
 /// 它旨在由代表特定密码算法参数集的标记结构体来实现。
-pub trait SchemeParams: Send + Sync + 'static + Clone + Default + std::fmt::Debug {
+pub trait SchemeParams: Send + Sync + 'static + Clone + Default + core::fmt::Debug {
     /// The unique name of the algorithm (e.g., "AES-128-GCM").
     ///
     /// 算法的唯一名称 (例如, "AES-128-GCM")。
@@ -71,7 +72,7 @@ pub trait Parameterized {
 /// 用于密码学原语（如哈希函数和XOF）的 trait。
 ///
 /// 此 trait 为定义原语的基本属性（如其名称和唯一的标识符偏移量）提供了一个通用接口。
-pub trait PrimitiveParams: Send + Sync + 'static + Clone + Default + std::fmt::Debug {
+pub trait PrimitiveParams: Send + Sync + 'static + Clone + Default + core::fmt::Debug {
     /// The name of the primitive (e.g., "SHA-256").
     ///
     /// 原语的名称（例如，"SHA-256"）。
@@ -11,6 +11,7 @@
 /// Re-exports of `sha2` family hash functions with renamed types to avoid conflicts.
 ///
 /// 重新导出 `sha2` 系列哈希函数，重命名类型以避免冲突。
+use alloc::vec::Vec;
 pub use sha2::{Sha256 as Sha256_, Sha384 as Sha384_, Sha512 as Sha512_};
 
 use crate::{
@@ -29,15 +30,16 @@ use {
         pkcs8::DecodePrivateKey,
         pss::{SigningKey, VerifyingKey},
         Oaep,
+        rand_core::CryptoRngCore,
         signature::SignatureEncoding,
     },
     crate::systems::asymmetric::traditional::rsa::{RsaPrivateKey, RsaPublicKey},
 };
 use digest::Digest;
-use std::convert::TryFrom;
+use core::convert::TryFrom;
 
 #[cfg(feature = "hmac-default")]
-use crate::prelude::KeyError;
+use crate::{prelude::KeyError, traits::mac::write_tag};
 
 mod private {
     pub trait Sealed {}
@@ -49,6 +51,26 @@ mod private {
 /// 一个代表哈希函数的密封 trait。
 /// 它提供与哈希相关的功能。
 pub trait Hasher: private::Sealed + PrimitiveParams {
+    /// The size of the digest produced by the hash function, in bytes.
+    ///
+    /// 哈希函数产生的摘要大小（以字节为单位）。
+    const OUTPUT_SIZE: usize;
+
+    /// The code of the hash function in the multiformats multihash table.
+    ///
+    /// 哈希函数在 multiformats multihash 表中的代码。
+    const MULTIHASH_CODE: u64;
+
+    /// The DER encoding of the hash function's object identifier (OID), including tag and length.
+    ///
+    /// 哈希函数对象标识符 (OID) 的 DER 编码，包含标签和长度。
+    const OID: &'static [u8];
+
+    /// The incremental `digest` implementation backing this hash function.
+    ///
+    /// 支撑此哈希函数的增量式 `digest` 实现。
+    type Digest: Digest + Clone + Default + Send + Sync + 'static + core::fmt::Debug;
+
     /// Hashes the given data.
     ///
     /// 哈希给定的数据。
@@ -60,6 +82,13 @@ pub trait Hasher: private::Sealed + PrimitiveParams {
     #[cfg(feature = "hmac-default")]
     fn hmac(key: &[u8], msg: &[u8]) -> Result<Vec<u8>, Error>;
 
+    /// Computes the HMAC of a message and writes it to the start of `output` without heap
+    /// allocation, returning the number of bytes written.
+    ///
+    /// 计算消息的 HMAC 并在不进行堆分配的情况下将其写入 `output` 的开头，返回写入的字节数。
+    #[cfg(feature = "hmac-default")]
+    fn hmac_into(key: &[u8], msg: &[u8], output: &mut [u8]) -> Result<usize, Error>;
+
     /// Derives a key using PBKDF2-HMAC with the hasher.
     ///
     /// # Arguments
@@ -107,6 +136,25 @@ pub trait Hasher: private::Sealed + PrimitiveParams {
         okm: &mut [u8],
     ) -> Result<(), KdfError>;
 
+    /// Runs the HKDF-Extract step, returning the pseudorandom key (PRK).
+    ///
+    /// 执行 HKDF-Extract 步骤，返回伪随机密钥 (PRK)。
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_extract(salt: Option<&[u8]>, ikm: &[u8]) -> Vec<u8>;
+
+    /// Runs the HKDF-Expand step from an existing pseudorandom key (PRK).
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a `KdfError` if the PRK is shorter than the hash output or
+    /// the output is too long.
+    ///
+    /// 从已有的伪随机密钥 (PRK) 执行 HKDF-Expand 步骤。
+    ///
+    /// # 返回
+    /// 成功时返回 `Ok(())`；如果 PRK 短于哈希输出或输出过长，则返回 `KdfError`。
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_expand_prk(prk: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), KdfError>;
+
     /// Encrypts data using RSA-OAEP with the hasher.
     ///
     /// # Arguments
@@ -167,6 +215,32 @@ pub trait Hasher: private::Sealed + PrimitiveParams {
     #[cfg(feature = "rsa-default")]
     fn rsa_pss_sign(key: &RsaPrivateKey, msg: &[u8]) -> Result<Vec<u8>, Error>;
 
+    /// Signs a message using RSA-PSS with the hasher, drawing the salt from the given RNG.
+    ///
+    /// # Arguments
+    /// * `rng` - The cryptographically secure RNG used to generate the PSS salt.
+    /// * `key` - The RSA private key for signing.
+    /// * `msg` - The message to sign.
+    ///
+    /// # Returns
+    /// The signature bytes, or an error if signing fails.
+    ///
+    /// 使用哈希器通过 RSA-PSS 对消息进行签名，从给定的 RNG 中获取盐值。
+    ///
+    /// # 参数
+    /// * `rng` - 用于生成 PSS 盐值的密码学安全 RNG。
+    /// * `key` - 用于签名的 RSA 私钥。
+    /// * `msg` - 要签名的消息。
+    ///
+    /// # 返回
+    /// 签名字节，如果签名失败则返回错误。
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        key: &RsaPrivateKey,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, Error>;
+
     /// Verifies a signature using RSA-PSS with the hasher.
     ///
     /// # Arguments
@@ -188,6 +262,48 @@ pub trait Hasher: private::Sealed + PrimitiveParams {
     /// 如果签名有效则返回 `Ok(())`，验证失败则返回错误。
     #[cfg(feature = "rsa-default")]
     fn rsa_pss_verify(key: &RsaPublicKey, msg: &[u8], sig: &[u8]) -> Result<(), Error>;
+
+    /// Signs a pre-computed digest using RSA-PSS with the hasher.
+    ///
+    /// # Arguments
+    /// * `key` - The RSA private key for signing.
+    /// * `digest` - The digest of the message, computed with this hasher.
+    ///
+    /// # Returns
+    /// The signature bytes, or an error if signing fails.
+    ///
+    /// 使用哈希器通过 RSA-PSS 对预先计算的摘要进行签名。
+    ///
+    /// # 参数
+    /// * `key` - 用于签名的 RSA 私钥。
+    /// * `digest` - 使用此哈希器计算的消息摘要。
+    ///
+    /// # 返回
+    /// 签名字节，如果签名失败则返回错误。
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_prehash(key: &RsaPrivateKey, digest: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// Verifies an RSA-PSS signature over a pre-computed digest with the hasher.
+    ///
+    /// # Arguments
+    /// * `key` - The RSA public key for verification.
+    /// * `digest` - The digest of the message, computed with this hasher.
+    /// * `sig` - The signature to verify.
+    ///
+    /// # Returns
+    /// `Ok(())` if the signature is valid, or an error if verification fails.
+    ///
+    /// 使用哈希器验证对预先计算摘要的 RSA-PSS 签名。
+    ///
+    /// # 参数
+    /// * `key` - 用于验证的 RSA 公钥。
+    /// * `digest` - 使用此哈希器计算的消息摘要。
+    /// * `sig` - 要验证的签名。
+    ///
+    /// # 返回
+    /// 如果签名有效则返回 `Ok(())`，验证失败则返回错误。
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_verify_prehash(key: &RsaPublicKey, digest: &[u8], sig: &[u8]) -> Result<(), Error>;
 }
 
 /// SHA-256 hash function implementation.
@@ -209,6 +325,14 @@ impl PrimitiveParams for Sha256 {
 }
 
 impl Hasher for Sha256 {
+    const OUTPUT_SIZE: usize = 32;
+    const MULTIHASH_CODE: u64 = 0x12;
+    // 2.16.840.1.101.3.4.2.1
+    const OID: &'static [u8] = &[
+        0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+    ];
+    type Digest = Sha256_;
+
     fn hash(data: &[u8]) -> Vec<u8> {
         Sha256_::digest(data).to_vec()
     }
@@ -222,6 +346,15 @@ impl Hasher for Sha256 {
         Ok(mac.finalize().into_bytes().to_vec())
     }
 
+    #[cfg(feature = "hmac-default")]
+    fn hmac_into(key: &[u8], msg: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        use hmac::{Hmac, Mac};
+
+        let mut mac = Hmac::<Sha256_>::new_from_slice(key).map_err(|_| KeyError::InvalidLength)?;
+        mac.update(msg);
+        write_tag(&mac.finalize().into_bytes(), output)
+    }
+
     #[cfg(feature = "pbkdf2-default")]
     fn pbkdf2_hmac(password: &[u8], salt: &[u8], rounds: u32, okm: &mut [u8]) {
         pbkdf2::pbkdf2_hmac::<Sha256_>(password, salt, rounds, okm);
@@ -236,14 +369,32 @@ impl Hasher for Sha256 {
     ) -> Result<(), KdfError> {
         let hk = Hkdf::<Sha256_>::new(salt, ikm);
         hk.expand(info.unwrap_or_default(), okm)
-            .map_err(|_| KdfError::InvalidOutputLength)
+            .map_err(|_| KdfError::InvalidOutputLength {
+                requested: okm.len(),
+                max: 255 * Self::OUTPUT_SIZE,
+            })
+    }
+
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_extract(salt: Option<&[u8]>, ikm: &[u8]) -> Vec<u8> {
+        Hkdf::<Sha256_>::extract(salt, ikm).0.to_vec()
+    }
+
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_expand_prk(prk: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), KdfError> {
+        let hk = Hkdf::<Sha256_>::from_prk(prk).map_err(|_| KdfError::InvalidParameter)?;
+        hk.expand(info, okm)
+            .map_err(|_| KdfError::InvalidOutputLength {
+                requested: okm.len(),
+                max: 255 * Self::OUTPUT_SIZE,
+            })
     }
 
     #[cfg(feature = "rsa-default")]
     fn rsa_oaep_encrypt(key: &RsaPublicKey, msg: &[u8]) -> Result<Vec<u8>, Error> {
         let padding = Oaep::new::<Sha256_>();
         key.inner()
-            .encrypt(&mut rsa::rand_core::OsRng, padding, msg)
+            .encrypt(&mut crate::rng::SystemRng::new(), padding, msg)
             .map_err(|_| KemError::Encapsulation.into())
     }
 
@@ -259,12 +410,22 @@ impl Hasher for Sha256 {
 
     #[cfg(feature = "rsa-default")]
     fn rsa_pss_sign(key: &RsaPrivateKey, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        Self::rsa_pss_sign_with_rng(&mut crate::rng::SystemRng::new(), key, msg)
+    }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        key: &RsaPrivateKey,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, Error> {
         use rsa::signature::RandomizedSigner;
         let rsa_private_key = rsa::RsaPrivateKey::from_pkcs8_der(key.inner())
             .map_err(|_| Error::Signature(SignatureError::Signing))?;
         let signing_key = SigningKey::<Sha256_>::new(rsa_private_key);
-        let mut rng = rsa::rand_core::OsRng;
-        let signature = signing_key.sign_with_rng(&mut rng, msg);
+        let signature = signing_key
+            .try_sign_with_rng(rng, msg)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
         Ok(signature.to_vec())
     }
 
@@ -278,6 +439,30 @@ impl Hasher for Sha256 {
             .verify(msg, &pss_signature)
             .map_err(|_| SignatureError::Verification.into())
     }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_prehash(key: &RsaPrivateKey, digest: &[u8]) -> Result<Vec<u8>, Error> {
+        use rsa::signature::hazmat::RandomizedPrehashSigner;
+        let rsa_private_key = rsa::RsaPrivateKey::from_pkcs8_der(key.inner())
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let signing_key = SigningKey::<Sha256_>::new(rsa_private_key);
+        let mut rng = crate::rng::SystemRng::new();
+        let signature = signing_key
+            .sign_prehash_with_rng(&mut rng, digest)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        Ok(signature.to_vec())
+    }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_verify_prehash(key: &RsaPublicKey, digest: &[u8], sig: &[u8]) -> Result<(), Error> {
+        use rsa::signature::hazmat::PrehashVerifier;
+        let verifying_key = VerifyingKey::<Sha256_>::new(key.inner().clone());
+        let pss_signature = rsa::pss::Signature::try_from(sig)
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        verifying_key
+            .verify_prehash(digest, &pss_signature)
+            .map_err(|_| SignatureError::Verification.into())
+    }
 }
 
 /// SHA-384 hash function implementation.
@@ -299,6 +484,14 @@ impl PrimitiveParams for Sha384 {
 }
 
 impl Hasher for Sha384 {
+    const OUTPUT_SIZE: usize = 48;
+    const MULTIHASH_CODE: u64 = 0x20;
+    // 2.16.840.1.101.3.4.2.2
+    const OID: &'static [u8] = &[
+        0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02,
+    ];
+    type Digest = Sha384_;
+
     fn hash(data: &[u8]) -> Vec<u8> {
         Sha384_::digest(data).to_vec()
     }
@@ -311,6 +504,15 @@ impl Hasher for Sha384 {
         Ok(mac.finalize().into_bytes().to_vec())
     }
 
+    #[cfg(feature = "hmac-default")]
+    fn hmac_into(key: &[u8], msg: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        use hmac::{Hmac, Mac};
+
+        let mut mac = Hmac::<Sha384_>::new_from_slice(key).map_err(|_| KeyError::InvalidLength)?;
+        mac.update(msg);
+        write_tag(&mac.finalize().into_bytes(), output)
+    }
+
     #[cfg(feature = "pbkdf2-default")]
     fn pbkdf2_hmac(password: &[u8], salt: &[u8], rounds: u32, okm: &mut [u8]) {
         pbkdf2::pbkdf2_hmac::<Sha384_>(password, salt, rounds, okm);
@@ -325,14 +527,32 @@ impl Hasher for Sha384 {
     ) -> Result<(), KdfError> {
         let hk = Hkdf::<Sha384_>::new(salt, ikm);
         hk.expand(info.unwrap_or_default(), okm)
-            .map_err(|_| KdfError::InvalidOutputLength)
+            .map_err(|_| KdfError::InvalidOutputLength {
+                requested: okm.len(),
+                max: 255 * Self::OUTPUT_SIZE,
+            })
+    }
+
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_extract(salt: Option<&[u8]>, ikm: &[u8]) -> Vec<u8> {
+        Hkdf::<Sha384_>::extract(salt, ikm).0.to_vec()
+    }
+
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_expand_prk(prk: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), KdfError> {
+        let hk = Hkdf::<Sha384_>::from_prk(prk).map_err(|_| KdfError::InvalidParameter)?;
+        hk.expand(info, okm)
+            .map_err(|_| KdfError::InvalidOutputLength {
+                requested: okm.len(),
+                max: 255 * Self::OUTPUT_SIZE,
+            })
     }
 
     #[cfg(feature = "rsa-default")]
     fn rsa_oaep_encrypt(key: &RsaPublicKey, msg: &[u8]) -> Result<Vec<u8>, Error> {
         let padding = Oaep::new::<Sha384_>();
         key.inner()
-            .encrypt(&mut rsa::rand_core::OsRng, padding, msg)
+            .encrypt(&mut crate::rng::SystemRng::new(), padding, msg)
             .map_err(|_| KemError::Encapsulation.into())
     }
 
@@ -348,12 +568,22 @@ impl Hasher for Sha384 {
 
     #[cfg(feature = "rsa-default")]
     fn rsa_pss_sign(key: &RsaPrivateKey, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        Self::rsa_pss_sign_with_rng(&mut crate::rng::SystemRng::new(), key, msg)
+    }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        key: &RsaPrivateKey,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, Error> {
         use rsa::signature::RandomizedSigner;
         let rsa_private_key = rsa::RsaPrivateKey::from_pkcs8_der(key.inner())
             .map_err(|_| Error::Signature(SignatureError::Signing))?;
         let signing_key = SigningKey::<Sha384_>::new(rsa_private_key);
-        let mut rng = rsa::rand_core::OsRng;
-        let signature = signing_key.sign_with_rng(&mut rng, msg);
+        let signature = signing_key
+            .try_sign_with_rng(rng, msg)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
         Ok(signature.to_vec())
     }
 
@@ -367,6 +597,30 @@ impl Hasher for Sha384 {
             .verify(msg, &pss_signature)
             .map_err(|_| SignatureError::Verification.into())
     }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_prehash(key: &RsaPrivateKey, digest: &[u8]) -> Result<Vec<u8>, Error> {
+        use rsa::signature::hazmat::RandomizedPrehashSigner;
+        let rsa_private_key = rsa::RsaPrivateKey::from_pkcs8_der(key.inner())
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let signing_key = SigningKey::<Sha384_>::new(rsa_private_key);
+        let mut rng = crate::rng::SystemRng::new();
+        let signature = signing_key
+            .sign_prehash_with_rng(&mut rng, digest)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        Ok(signature.to_vec())
+    }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_verify_prehash(key: &RsaPublicKey, digest: &[u8], sig: &[u8]) -> Result<(), Error> {
+        use rsa::signature::hazmat::PrehashVerifier;
+        let verifying_key = VerifyingKey::<Sha384_>::new(key.inner().clone());
+        let pss_signature = rsa::pss::Signature::try_from(sig)
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        verifying_key
+            .verify_prehash(digest, &pss_signature)
+            .map_err(|_| SignatureError::Verification.into())
+    }
 }
 
 /// SHA-512 hash function implementation.
@@ -388,6 +642,14 @@ impl PrimitiveParams for Sha512 {
 }
 
 impl Hasher for Sha512 {
+    const OUTPUT_SIZE: usize = 64;
+    const MULTIHASH_CODE: u64 = 0x13;
+    // 2.16.840.1.101.3.4.2.3
+    const OID: &'static [u8] = &[
+        0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03,
+    ];
+    type Digest = Sha512_;
+
     fn hash(data: &[u8]) -> Vec<u8> {
         Sha512_::digest(data).to_vec()
     }
@@ -400,6 +662,15 @@ impl Hasher for Sha512 {
         Ok(mac.finalize().into_bytes().to_vec())
     }
 
+    #[cfg(feature = "hmac-default")]
+    fn hmac_into(key: &[u8], msg: &[u8], output: &mut [u8]) -> Result<usize, Error> {
+        use hmac::{Hmac, Mac};
+
+        let mut mac = Hmac::<Sha512_>::new_from_slice(key).map_err(|_| KeyError::InvalidLength)?;
+        mac.update(msg);
+        write_tag(&mac.finalize().into_bytes(), output)
+    }
+
     #[cfg(feature = "pbkdf2-default")]
     fn pbkdf2_hmac(password: &[u8], salt: &[u8], rounds: u32, okm: &mut [u8]) {
         pbkdf2::pbkdf2_hmac::<Sha512_>(password, salt, rounds, okm);
@@ -414,14 +685,32 @@ impl Hasher for Sha512 {
     ) -> Result<(), KdfError> {
         let hk = Hkdf::<Sha512_>::new(salt, ikm);
         hk.expand(info.unwrap_or_default(), okm)
-            .map_err(|_| KdfError::InvalidOutputLength)
+            .map_err(|_| KdfError::InvalidOutputLength {
+                requested: okm.len(),
+                max: 255 * Self::OUTPUT_SIZE,
+            })
+    }
+
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_extract(salt: Option<&[u8]>, ikm: &[u8]) -> Vec<u8> {
+        Hkdf::<Sha512_>::extract(salt, ikm).0.to_vec()
+    }
+
+    #[cfg(feature = "hkdf-default")]
+    fn hkdf_expand_prk(prk: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), KdfError> {
+        let hk = Hkdf::<Sha512_>::from_prk(prk).map_err(|_| KdfError::InvalidParameter)?;
+        hk.expand(info, okm)
+            .map_err(|_| KdfError::InvalidOutputLength {
+                requested: okm.len(),
+                max: 255 * Self::OUTPUT_SIZE,
+            })
     }
 
     #[cfg(feature = "rsa-default")]
     fn rsa_oaep_encrypt(key: &RsaPublicKey, msg: &[u8]) -> Result<Vec<u8>, Error> {
         let padding = Oaep::new::<Sha512_>();
         key.inner()
-            .encrypt(&mut rsa::rand_core::OsRng, padding, msg)
+            .encrypt(&mut crate::rng::SystemRng::new(), padding, msg)
             .map_err(|_| KemError::Encapsulation.into())
     }
 
@@ -437,12 +726,22 @@ impl Hasher for Sha512 {
 
     #[cfg(feature = "rsa-default")]
     fn rsa_pss_sign(key: &RsaPrivateKey, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        Self::rsa_pss_sign_with_rng(&mut crate::rng::SystemRng::new(), key, msg)
+    }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_with_rng<R: CryptoRngCore>(
+        rng: &mut R,
+        key: &RsaPrivateKey,
+        msg: &[u8],
+    ) -> Result<Vec<u8>, Error> {
         use rsa::signature::RandomizedSigner;
         let rsa_private_key = rsa::RsaPrivateKey::from_pkcs8_der(key.inner())
             .map_err(|_| Error::Signature(SignatureError::Signing))?;
         let signing_key = SigningKey::<Sha512_>::new(rsa_private_key);
-        let mut rng = rsa::rand_core::OsRng;
-        let signature = signing_key.sign_with_rng(&mut rng, msg);
+        let signature = signing_key
+            .try_sign_with_rng(rng, msg)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
         Ok(signature.to_vec())
     }
 
@@ -456,4 +755,28 @@ impl Hasher for Sha512 {
             .verify(msg, &pss_signature)
             .map_err(|_| SignatureError::Verification.into())
     }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_sign_prehash(key: &RsaPrivateKey, digest: &[u8]) -> Result<Vec<u8>, Error> {
+        use rsa::signature::hazmat::RandomizedPrehashSigner;
+        let rsa_private_key = rsa::RsaPrivateKey::from_pkcs8_der(key.inner())
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        let signing_key = SigningKey::<Sha512_>::new(rsa_private_key);
+        let mut rng = crate::rng::SystemRng::new();
+        let signature = signing_key
+            .sign_prehash_with_rng(&mut rng, digest)
+            .map_err(|_| Error::Signature(SignatureError::Signing))?;
+        Ok(signature.to_vec())
+    }
+
+    #[cfg(feature = "rsa-default")]
+    fn rsa_pss_verify_prehash(key: &RsaPublicKey, digest: &[u8], sig: &[u8]) -> Result<(), Error> {
+        use rsa::signature::hazmat::PrehashVerifier;
+        let verifying_key = VerifyingKey::<Sha512_>::new(key.inner().clone());
+        let pss_signature = rsa::pss::Signature::try_from(sig)
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        verifying_key
+            .verify_prehash(digest, &pss_signature)
+            .map_err(|_| SignatureError::Verification.into())
+    }
 }
\ No newline at end of file
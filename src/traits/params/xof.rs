@@ -11,6 +11,7 @@
 /// Re-exports of `sha3` family XOFs with renamed types to avoid conflicts.
 ///
 /// 重新导出 `sha3` 系列 XOF，重命名类型以避免冲突。
+use alloc::boxed::Box;
 pub use sha3::{Shake128 as Shake128_, Shake256 as Shake256_};
 
 use digest::{ExtendableOutput, Update};
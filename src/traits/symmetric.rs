@@ -2,24 +2,31 @@
 //!
 //! 定义了对称认证加密（AEAD）操作的 trait。
 pub mod aead;
+pub mod keyring;
+pub mod nonce;
 
+use alloc::{vec, vec::Vec};
 pub use aead::*;
+pub use keyring::*;
+pub use nonce::*;
 
-use crate::{errors::Error, traits::key::{Key, SymmetricKeySet}};
+use crate::{errors::Error, traits::key::{Key, KeyError, SecretBytes, SymmetricKeySet}};
+use rand_core_elliptic_curve::CryptoRngCore;
 use zeroize::Zeroizing;
 
 
 #[cfg(feature = "std")]
 use thiserror::Error;
 
-/// A key for a symmetric cipher.
+/// A key for a symmetric cipher. With the `secure-mem` feature it is kept in locked, guarded
+/// memory, see [`SecretBytes`].
 ///
-/// 对称密码的密钥。
-pub type SymmetricKey = Zeroizing<Vec<u8>>;
+/// 对称密码的密钥。启用 `secure-mem` 特性时，它保存在带保护页的锁定内存中，参见 [`SecretBytes`]。
+pub type SymmetricKey = SecretBytes;
 
 impl Key for SymmetricKey {
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        Ok(Zeroizing::new(bytes.to_vec()))
+        Ok(SecretBytes::from_slice(bytes))
     }
 
     fn to_bytes(&self) -> Result<Vec<u8>, Error> {
@@ -71,6 +78,18 @@ pub enum SymmetricError {
     /// 提供的输出缓冲区太小。
     #[cfg_attr(feature = "std", error("Output buffer is too small"))]
     OutputTooSmall,
+
+    /// No randomness was available to generate a nonce.
+    ///
+    /// 没有可用于生成 nonce 的随机性。
+    #[cfg_attr(feature = "std", error("Nonce generation failed"))]
+    NonceGenerationFailed,
+
+    /// A counter nonce cannot be incremented any further without repeating.
+    ///
+    /// 计数器 nonce 无法在不重复的情况下继续递增。
+    #[cfg_attr(feature = "std", error("Nonce counter exhausted"))]
+    NonceExhausted,
 }
 
 /// A trait for generating symmetric keys.
@@ -85,4 +104,14 @@ pub trait SymmetricKeyGenerator: SymmetricKeySet {
     ///
     /// 生成一个新的对称密钥。
     fn generate_key() -> Result<Self::Key, Error>;
+
+    /// Generates a new symmetric key using randomness drawn from `rng`.
+    ///
+    /// 使用从 `rng` 中获取的随机性生成一个新的对称密钥。
+    fn generate_key_with_rng<R: CryptoRngCore>(rng: &mut R) -> Result<Self::Key, Error> {
+        let mut key = Zeroizing::new(vec![0u8; Self::KEY_SIZE]);
+        rng.try_fill_bytes(&mut key)
+            .map_err(|_| Error::Key(KeyError::GenerationFailed))?;
+        Self::Key::from_bytes(&key)
+    }
 }
\ No newline at end of file
@@ -3,7 +3,9 @@
 //! 定义了对称认证加密（AEAD）操作的 trait。
 
 
-use crate::{errors::Error, traits::{key::SymmetricKeySet, symmetric::SymmetricKeyGenerator, symmetric::SymmetricError}};
+use alloc::{vec, vec::Vec};
+use zeroize::Zeroize;
+use crate::{errors::Error, traits::{key::SymmetricKeySet, symmetric::Nonce, symmetric::SymmetricKeyGenerator, symmetric::SymmetricError}};
 
 
 /// Authenticated associated data (AAD).
@@ -63,13 +65,74 @@ pub trait AeadEncryptor: SymmetricKeySet + AeadCipher {
         // The required buffer size is plaintext length + tag size.
         // 所需缓冲区大小为明文长度 + 标签大小。
         let mut buffer = vec![0u8; plaintext.len() + Self::TAG_SIZE];
-        let bytes_written = Self::encrypt_to_buffer(key, nonce, plaintext, &mut buffer, aad)?;
+        let bytes_written = Self::encrypt_to_buffer(key, nonce, plaintext, &mut buffer, aad)
+            .inspect_err(|_| buffer.zeroize())?;
         buffer.truncate(bytes_written);
         Ok(buffer)
     }
 
+    /// Encrypts a plaintext with a nonce typed for this scheme, producing `[ciphertext || tag]`.
+    /// Unlike [`AeadEncryptor::encrypt`], the nonce size cannot be wrong. The nonce is consumed,
+    /// so reusing it takes an explicit `.clone()`.
+    ///
+    /// 使用为本方案定型的 nonce 加密明文，生成 `[ciphertext || tag]`。与
+    /// [`AeadEncryptor::encrypt`] 不同，nonce 的大小不会出错。nonce 会被消耗，因此重用它需要显式调用
+    /// `.clone()`。
+    fn encrypt_with_nonce(
+        key: &Self::Key,
+        nonce: Nonce<Self>,
+        plaintext: &[u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<Vec<u8>, Error>
+    where
+        Self: Sized,
+    {
+        Self::encrypt(key, nonce.as_bytes(), plaintext, aad)
+    }
+
+    /// Encrypts a plaintext with a nonce typed for this scheme, writing `[ciphertext || tag]` into
+    /// `output`. The buffer counterpart of [`AeadEncryptor::encrypt_with_nonce`].
+    ///
+    /// 使用为本方案定型的 nonce 加密明文，并将 `[ciphertext || tag]` 写入 `output`。
+    /// 是 [`AeadEncryptor::encrypt_with_nonce`] 的缓冲区版本。
+    fn encrypt_to_buffer_with_nonce(
+        key: &Self::Key,
+        nonce: Nonce<Self>,
+        plaintext: &[u8],
+        output: &mut [u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<usize, Error>
+    where
+        Self: Sized,
+    {
+        Self::encrypt_to_buffer(key, nonce.as_bytes(), plaintext, output, aad)
+    }
+
+    /// Encrypts a plaintext with a given nonce, writing the ciphertext with tag into the provided buffer.
+    ///
+    /// The ciphers of this crate encrypt in place inside `output`, without heap allocation.
+    ///
+    /// If encryption fails after `output` has been written to, the written bytes, which may
+    /// include a copy of the plaintext, are zeroized before the error is returned.
+    /// Implementations must uphold this.
+    ///
+    /// # Arguments
+    /// * `key` - The secret key.
+    /// * `nonce` - The nonce for this specific encryption operation. Must be unique for each call with the same key.
+    /// * `plaintext` - The data to encrypt.
+    /// * `output` - The buffer to write the encrypted data (`ciphertext || tag`) to. Must be large enough to hold the ciphertext and the tag.
+    /// * `aad` - Optional associated data to authenticate.
+    ///
+    /// # Returns
+    /// The number of bytes written to `output` if encryption succeeds.
+    ///
     /// 使用给定的 nonce 加密明文，并将带标签的密文写入提供的缓冲区。
     ///
+    /// 本 crate 的密码在 `output` 中原地加密，不进行堆分配。
+    ///
+    /// 如果在写入 `output` 之后加密失败，已写入的字节（其中可能包含明文的副本）会在返回错误之前被清零。
+    /// 实现必须遵守这一点。
+    ///
     /// # 参数
     /// * `key` - 密钥。
     /// * `nonce` - 本次加密操作的 nonce。对于同一密钥的每次调用都必须是唯一的。
@@ -101,7 +164,8 @@ pub trait AeadDecryptor: SymmetricKeySet + AeadCipher {
     /// * `aad` - Optional associated data that was authenticated.
     ///
     /// # Returns
-    /// The original plaintext if decryption and authentication are successful.
+    /// The original plaintext if decryption and authentication are successful. It is not
+    /// zeroized when dropped; wrap it in `Zeroizing` if it is sensitive.
     ///
     /// 解密密文，生成原始明文。
     ///
@@ -112,7 +176,8 @@ pub trait AeadDecryptor: SymmetricKeySet + AeadCipher {
     /// * `aad` - 可选的已认证的关联数据。
     ///
     /// # 返回
-    /// 如果解密和认证成功，则返回原始明文。
+    /// 如果解密和认证成功，则返回原始明文。它在释放时不会被清零；如果明文是敏感的，请将其包装在
+    /// `Zeroizing` 中。
     fn decrypt(
         key: &Self::Key,
         nonce: &[u8],
@@ -126,13 +191,72 @@ pub trait AeadDecryptor: SymmetricKeySet + AeadCipher {
         // 所需缓冲区大小为密文长度 - 标签大小。
         let mut buffer = vec![0u8; ciphertext_with_tag.len() - Self::TAG_SIZE];
         let bytes_written =
-            Self::decrypt_to_buffer(key, nonce, ciphertext_with_tag, &mut buffer, aad)?;
+            Self::decrypt_to_buffer(key, nonce, ciphertext_with_tag, &mut buffer, aad)
+                .inspect_err(|_| buffer.zeroize())?;
         buffer.truncate(bytes_written);
         Ok(buffer)
     }
 
+    /// Decrypts `[ciphertext || tag]` with a nonce typed for this scheme. Unlike
+    /// [`AeadDecryptor::decrypt`], the nonce size cannot be wrong.
+    ///
+    /// 使用为本方案定型的 nonce 解密 `[ciphertext || tag]`。与 [`AeadDecryptor::decrypt`] 不同，
+    /// nonce 的大小不会出错。
+    fn decrypt_with_nonce(
+        key: &Self::Key,
+        nonce: &Nonce<Self>,
+        ciphertext_with_tag: &[u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<Vec<u8>, Error>
+    where
+        Self: Sized,
+    {
+        Self::decrypt(key, nonce.as_bytes(), ciphertext_with_tag, aad)
+    }
+
+    /// Decrypts `[ciphertext || tag]` with a nonce typed for this scheme, writing the plaintext
+    /// into `output`. The buffer counterpart of [`AeadDecryptor::decrypt_with_nonce`].
+    ///
+    /// 使用为本方案定型的 nonce 解密 `[ciphertext || tag]`，并将明文写入 `output`。
+    /// 是 [`AeadDecryptor::decrypt_with_nonce`] 的缓冲区版本。
+    fn decrypt_to_buffer_with_nonce(
+        key: &Self::Key,
+        nonce: &Nonce<Self>,
+        ciphertext_with_tag: &[u8],
+        output: &mut [u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<usize, Error>
+    where
+        Self: Sized,
+    {
+        Self::decrypt_to_buffer(key, nonce.as_bytes(), ciphertext_with_tag, output, aad)
+    }
+
+    /// Decrypts a ciphertext, writing the original plaintext into the provided buffer.
+    ///
+    /// The ciphers of this crate decrypt in place inside `output`, without heap allocation.
+    ///
+    /// If decryption or authentication fails, the bytes written to `output` are zeroized
+    /// before the error is returned, so unauthenticated plaintext is never left in the
+    /// caller's buffer. Implementations must uphold this.
+    ///
+    /// # Arguments
+    /// * `key` - The secret key.
+    /// * `nonce` - The nonce that was used to encrypt the data.
+    /// * `ciphertext_with_tag` - The encrypted data concatenated with the authentication tag.
+    /// * `output` - The buffer to write the decrypted plaintext to. Must be large enough to hold the plaintext.
+    /// * `aad` - Optional associated data that was authenticated.
+    ///
+    /// # Returns
+    /// The number of bytes written to `output` if decryption and authentication succeed.
+    ///
     /// 解密密文，并将原始明文写入提供的缓冲区。
     ///
+    /// 本 crate 的密码在 `output` 中原地解密，不进行堆分配。
+    ///
+    /// 如果解密或认证失败，写入 `output` 的字节会在返回错误之前被清零，因此调用者的缓冲区中
+    /// 永远不会留下未经认证的明文。实现必须遵守这一点。
+    ///
     /// # 参数
     /// * `key` - 密钥。
     /// * `nonce` - 用于加密数据的 nonce。
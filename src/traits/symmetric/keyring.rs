@@ -0,0 +1,374 @@
+//! A set of key generations for an AEAD scheme, for rotating keys without re-encrypting first.
+//!
+//! [`KeyRing`] encrypts with its current key and writes that key's ID into every ciphertext,
+//! so decryption picks the right generation directly. The envelope is
+//!
+//! ```text
+//! 0x01 || id_len (1 byte) || id || nonce || ciphertext || tag
+//! ```
+//!
+//! where the version byte, length and ID are authenticated as associated data, before any
+//! caller-supplied AAD. Blobs written before the ring was introduced, laid out as
+//! `nonce || ciphertext || tag`, are still accepted: if a blob is not an envelope of a known
+//! key, every generation is tried in turn, newest first.
+//!
+//! 一个 AEAD 方案的多代密钥集合，用于在无需先重新加密的情况下轮换密钥。
+//!
+//! [`KeyRing`] 使用其当前密钥加密，并将该密钥的 ID 写入每个密文，因此解密时可以直接选中正确
+//! 的一代密钥。信封格式为
+//!
+//! ```text
+//! 0x01 || id_len (1 字节) || id || nonce || ciphertext || tag
+//! ```
+//!
+//! 其中版本字节、长度和 ID 会在调用者提供的 AAD 之前作为关联数据进行认证。引入密钥环之前写入
+//! 的 `nonce || ciphertext || tag` 格式的数据仍然可以被接受：如果数据不是已知密钥的信封，
+//! 则从最新的一代开始依次尝试每一代密钥。
+
+use alloc::{string::String, vec, vec::Vec};
+use crate::errors::Error;
+use crate::policy::CryptoPolicy;
+use crate::traits::key::KeyError;
+use crate::traits::symmetric::{AeadScheme, AssociatedData, SymmetricError};
+
+/// The version byte that starts every envelope.
+///
+/// 每个信封开头的版本字节。
+const ENVELOPE_VERSION: u8 = 0x01;
+
+/// An AEAD key together with its previous generations.
+///
+/// New data is always encrypted with the current key. Older generations are kept for
+/// decryption until they are retired, typically after [`KeyRing::reencrypt`] has moved all
+/// stored data to the current key.
+///
+/// 一个 AEAD 密钥及其之前的各代密钥。
+///
+/// 新数据总是使用当前密钥加密。旧的各代密钥会被保留用于解密，直到它们被停用，通常是在
+/// [`KeyRing::reencrypt`] 已将所有存储的数据迁移到当前密钥之后。
+#[derive(Clone)]
+pub struct KeyRing<A: AeadScheme> {
+    // Newest first; the first entry is the current key.
+    // 最新的在前；第一个条目是当前密钥。
+    keys: Vec<(String, A::Key)>,
+}
+
+impl<A: AeadScheme> KeyRing<A> {
+    /// Creates a ring whose current key is `key`, identified by `id`.
+    ///
+    /// IDs are written into every ciphertext, so they must be at most 255 bytes long.
+    ///
+    /// 创建一个当前密钥为 `key`、由 `id` 标识的密钥环。
+    ///
+    /// ID 会被写入每个密文，因此其长度最多为 255 字节。
+    pub fn new(id: impl Into<String>, key: A::Key) -> Result<Self, Error> {
+        let id = id.into();
+        check_id(&id)?;
+        Ok(Self {
+            keys: vec![(id, key)],
+        })
+    }
+
+    /// Creates a ring with a freshly generated current key.
+    ///
+    /// 创建一个带有新生成的当前密钥的密钥环。
+    pub fn generate(id: impl Into<String>) -> Result<Self, Error> {
+        Self::new(id, A::generate_key()?)
+    }
+
+    /// Makes `key` the current key, keeping the previous current key for decryption.
+    ///
+    /// Fails with [`KeyError::DuplicateKeyId`] if a generation with the same ID exists.
+    ///
+    /// 将 `key` 设为当前密钥，并保留之前的当前密钥用于解密。
+    ///
+    /// 如果已存在相同 ID 的一代密钥，则以 [`KeyError::DuplicateKeyId`] 失败。
+    pub fn rotate(&mut self, id: impl Into<String>, key: A::Key) -> Result<(), Error> {
+        let id = id.into();
+        check_id(&id)?;
+        if self.keys.iter().any(|(existing, _)| *existing == id) {
+            return Err(Error::Key(KeyError::DuplicateKeyId));
+        }
+        self.keys.insert(0, (id, key));
+        Ok(())
+    }
+
+    /// Makes a freshly generated key the current key.
+    ///
+    /// 将一个新生成的密钥设为当前密钥。
+    pub fn rotate_generated(&mut self, id: impl Into<String>) -> Result<(), Error> {
+        self.rotate(id, A::generate_key()?)
+    }
+
+    /// Removes the previous generation `id`, returning whether it was present.
+    ///
+    /// The current key cannot be retired; rotate to a new key first.
+    ///
+    /// 移除之前的一代密钥 `id`，并返回它是否存在。
+    ///
+    /// 当前密钥不能被停用；请先轮换到新密钥。
+    pub fn retire(&mut self, id: &str) -> bool {
+        match self
+            .keys
+            .iter()
+            .skip(1)
+            .position(|(existing, _)| existing == id)
+        {
+            Some(index) => {
+                self.keys.remove(index + 1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the ID of the current key.
+    ///
+    /// 返回当前密钥的 ID。
+    pub fn current_id(&self) -> &str {
+        &self.keys[0].0
+    }
+
+    /// Returns the IDs of all generations, newest first.
+    ///
+    /// 返回所有各代密钥的 ID，最新的在前。
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.keys.iter().map(|(id, _)| id.as_str())
+    }
+
+    /// Returns the key ID stamped into `envelope`, or `None` if it is not an envelope.
+    ///
+    /// 返回写入 `envelope` 中的密钥 ID；如果它不是信封，则返回 `None`。
+    pub fn key_id_of(envelope: &[u8]) -> Option<&str> {
+        let (header_len, id) = parse_header(envelope)?;
+        (envelope.len() >= header_len + A::NONCE_SIZE + A::TAG_SIZE).then_some(id)
+    }
+
+    /// Encrypts `plaintext` with the current key and a random nonce, returning an envelope.
+    ///
+    /// 使用当前密钥和随机 nonce 加密 `plaintext`，并返回一个信封。
+    pub fn encrypt(&self, plaintext: &[u8], aad: Option<AssociatedData>) -> Result<Vec<u8>, Error> {
+        let (id, key) = &self.keys[0];
+        let mut envelope =
+            Vec::with_capacity(2 + id.len() + A::NONCE_SIZE + plaintext.len() + A::TAG_SIZE);
+        envelope.push(ENVELOPE_VERSION);
+        envelope.push(id.len() as u8);
+        envelope.extend_from_slice(id.as_bytes());
+        let header_len = envelope.len();
+
+        let mut nonce = vec![0u8; A::NONCE_SIZE];
+        crate::rng::fill_nonce(&mut nonce).map_err(|_| Error::Symmetric(SymmetricError::Encryption))?;
+        let full_aad = bind_aad(&envelope, aad);
+        let ciphertext = A::encrypt(key, &nonce, plaintext, Some(&full_aad))?;
+
+        envelope.truncate(header_len);
+        envelope.extend_from_slice(&nonce);
+        envelope.extend_from_slice(&ciphertext);
+        Ok(envelope)
+    }
+
+    /// Decrypts an envelope written by [`KeyRing::encrypt`], or a legacy `nonce || ciphertext`
+    /// blob written with one of the ring's keys.
+    ///
+    /// 解密由 [`KeyRing::encrypt`] 写入的信封，或使用密钥环中某个密钥写入的旧格式
+    /// `nonce || ciphertext` 数据。
+    pub fn decrypt(&self, data: &[u8], aad: Option<AssociatedData>) -> Result<Vec<u8>, Error> {
+        if let Some((header_len, id)) = parse_header(data)
+            && let Some((_, key)) = self.keys.iter().find(|(existing, _)| existing == id)
+            && data.len() >= header_len + A::NONCE_SIZE + A::TAG_SIZE
+        {
+            let (header, body) = data.split_at(header_len);
+            let (nonce, ciphertext) = body.split_at(A::NONCE_SIZE);
+            let full_aad = bind_aad(header, aad);
+            if let Ok(plaintext) = A::decrypt(key, nonce, ciphertext, Some(&full_aad)) {
+                return Ok(plaintext);
+            }
+        }
+
+        // Not an envelope of a known key: try the blob as legacy data under each generation.
+        // 不是已知密钥的信封：将数据视为旧格式，并依次用每一代密钥尝试。
+        if data.len() >= A::NONCE_SIZE + A::TAG_SIZE {
+            let (nonce, ciphertext) = data.split_at(A::NONCE_SIZE);
+            for (_, key) in &self.keys {
+                if let Ok(plaintext) = A::decrypt(key, nonce, ciphertext, aad) {
+                    return Ok(plaintext);
+                }
+            }
+        }
+        Err(Error::Symmetric(SymmetricError::Decryption))
+    }
+
+    /// Like [`KeyRing::encrypt`], but fails with [`Error::PolicyViolation`] if `policy`
+    /// disallows the AEAD `A`.
+    ///
+    /// 与 [`KeyRing::encrypt`] 相同，但如果 `policy` 不允许 AEAD `A`，则以
+    /// [`Error::PolicyViolation`] 失败。
+    pub fn encrypt_with_policy(
+        &self,
+        policy: &CryptoPolicy,
+        plaintext: &[u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<Vec<u8>, Error> {
+        policy.check::<A>()?;
+        self.encrypt(plaintext, aad)
+    }
+
+    /// Like [`KeyRing::decrypt`], but fails with [`Error::PolicyViolation`] if `policy`
+    /// disallows the AEAD `A`.
+    ///
+    /// 与 [`KeyRing::decrypt`] 相同，但如果 `policy` 不允许 AEAD `A`，则以
+    /// [`Error::PolicyViolation`] 失败。
+    pub fn decrypt_with_policy(
+        &self,
+        policy: &CryptoPolicy,
+        data: &[u8],
+        aad: Option<AssociatedData>,
+    ) -> Result<Vec<u8>, Error> {
+        policy.check::<A>()?;
+        self.decrypt(data, aad)
+    }
+
+    /// Whether `data` was written by an older key, or in the legacy format.
+    ///
+    /// 判断 `data` 是否由较旧的密钥写入，或者是旧格式。
+    pub fn needs_reencrypt(&self, data: &[u8]) -> bool {
+        Self::key_id_of(data) != Some(self.current_id())
+    }
+
+    /// Decrypts `data` with whichever generation wrote it and encrypts it again with the
+    /// current key.
+    ///
+    /// 使用写入 `data` 的那一代密钥解密它，并使用当前密钥重新加密。
+    pub fn reencrypt(&self, data: &[u8], aad: Option<AssociatedData>) -> Result<Vec<u8>, Error> {
+        let plaintext = zeroize::Zeroizing::new(self.decrypt(data, aad)?);
+        self.encrypt(&plaintext, aad)
+    }
+}
+
+fn check_id(id: &str) -> Result<(), Error> {
+    if id.len() > u8::MAX as usize {
+        return Err(Error::Key(KeyError::InvalidLength));
+    }
+    Ok(())
+}
+
+/// Splits off the envelope header, returning its length and the key ID.
+///
+/// 分离出信封头部，返回其长度和密钥 ID。
+fn parse_header(data: &[u8]) -> Option<(usize, &str)> {
+    let (&version, rest) = data.split_first()?;
+    let (&id_len, rest) = rest.split_first()?;
+    if version != ENVELOPE_VERSION {
+        return None;
+    }
+    let id = rest.get(..id_len as usize)?;
+    Some((2 + id.len(), core::str::from_utf8(id).ok()?))
+}
+
+fn bind_aad(header: &[u8], aad: Option<AssociatedData>) -> Vec<u8> {
+    let mut full = header.to_vec();
+    full.extend_from_slice(aad.unwrap_or_default());
+    full
+}
+
+#[cfg(all(test, feature = "aes-gcm-default"))]
+mod tests {
+    use super::*;
+    use crate::systems::aead::aes_gcm::Aes256Gcm;
+    use crate::traits::symmetric::{AeadEncryptor, SymmetricKeyGenerator};
+
+    #[test]
+    fn test_rotation() -> Result<(), Error> {
+        let mut ring = KeyRing::<Aes256Gcm>::generate("2024-01")?;
+        let old = ring.encrypt(b"secret", Some(b"row 7"))?;
+        assert_eq!(KeyRing::<Aes256Gcm>::key_id_of(&old), Some("2024-01"));
+
+        ring.rotate_generated("2024-02")?;
+        assert_eq!(ring.current_id(), "2024-02");
+        assert_eq!(ring.ids().collect::<Vec<_>>(), ["2024-02", "2024-01"]);
+        let new = ring.encrypt(b"secret", Some(b"row 7"))?;
+        assert_eq!(KeyRing::<Aes256Gcm>::key_id_of(&new), Some("2024-02"));
+
+        assert_eq!(ring.decrypt(&old, Some(b"row 7"))?, b"secret");
+        assert_eq!(ring.decrypt(&new, Some(b"row 7"))?, b"secret");
+        assert!(ring.decrypt(&new, Some(b"row 8")).is_err());
+        assert!(ring.decrypt(&new, None).is_err());
+
+        assert!(ring.needs_reencrypt(&old));
+        assert!(!ring.needs_reencrypt(&new));
+        let moved = ring.reencrypt(&old, Some(b"row 7"))?;
+        assert!(!ring.needs_reencrypt(&moved));
+
+        assert!(!ring.retire("2024-02"));
+        assert!(ring.retire("2024-01"));
+        assert!(!ring.retire("2024-01"));
+        assert!(ring.decrypt(&old, Some(b"row 7")).is_err());
+        assert_eq!(ring.decrypt(&moved, Some(b"row 7"))?, b"secret");
+
+        assert_eq!(
+            ring.rotate("2024-02", Aes256Gcm::generate_key()?),
+            Err(Error::Key(KeyError::DuplicateKeyId))
+        );
+        assert_eq!(
+            ring.rotate("x".repeat(256), Aes256Gcm::generate_key()?),
+            Err(Error::Key(KeyError::InvalidLength))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_policy() -> Result<(), Error> {
+        use crate::policy::PolicyError;
+        use crate::traits::algorithm::Algorithm;
+
+        let ring = KeyRing::<Aes256Gcm>::generate("a")?;
+        let envelope = ring.encrypt_with_policy(&CryptoPolicy::default(), b"secret", None)?;
+        assert_eq!(
+            ring.decrypt_with_policy(&CryptoPolicy::default(), &envelope, None)?,
+            b"secret"
+        );
+
+        let denied = CryptoPolicy::default().with_denied(Aes256Gcm::ID);
+        let violation = Some(Error::PolicyViolation(PolicyError::AlgorithmDenied));
+        assert_eq!(
+            ring.encrypt_with_policy(&denied, b"secret", None).err(),
+            violation
+        );
+        assert_eq!(
+            ring.decrypt_with_policy(&denied, &envelope, None).err(),
+            violation
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampered_id_rejected() -> Result<(), Error> {
+        let key = Aes256Gcm::generate_key()?;
+        let mut ring = KeyRing::<Aes256Gcm>::new("a", key.clone())?;
+        ring.rotate("b", key)?;
+        let mut envelope = ring.encrypt(b"secret", None)?;
+        // Both generations hold the same key, so only the authenticated ID can tell them apart.
+        envelope[2] = b'a';
+        assert!(ring.decrypt(&envelope, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_legacy_fallback() -> Result<(), Error> {
+        let legacy_key = Aes256Gcm::generate_key()?;
+        let nonce = [9u8; 12];
+        let mut legacy = nonce.to_vec();
+        legacy.extend(Aes256Gcm::encrypt(&legacy_key, &nonce, b"old data", None)?);
+
+        let mut ring = KeyRing::<Aes256Gcm>::new("legacy", legacy_key)?;
+        ring.rotate_generated("v2")?;
+        assert_eq!(KeyRing::<Aes256Gcm>::key_id_of(&legacy), None);
+        assert!(ring.needs_reencrypt(&legacy));
+        assert_eq!(ring.decrypt(&legacy, None)?, b"old data");
+
+        let moved = ring.reencrypt(&legacy, None)?;
+        assert_eq!(KeyRing::<Aes256Gcm>::key_id_of(&moved), Some("v2"));
+        assert!(ring.decrypt(&[0u8; 8], None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+//! Nonces typed by the AEAD scheme they belong to.
+//!
+//! A [`Nonce<S>`] always holds exactly `S::NONCE_SIZE` bytes, so passing it to
+//! [`AeadEncryptor::encrypt_with_nonce`], [`AeadDecryptor::decrypt_with_nonce`] or their
+//! `*_to_buffer_with_nonce` counterparts cannot fail with `SymmetricError::InvalidNonceSize`,
+//! and a nonce of one scheme cannot be passed to another. Sizes are checked at compile time
+//! where the length is known: [`Nonce::from_array`] does not compile for an array of the wrong
+//! length, and a scheme whose nonces are longer than [`MAX_NONCE_SIZE`] cannot be used with
+//! [`Nonce`] at all. Only [`Nonce::from_slice`], for nonces read from storage or the network,
+//! checks the length at runtime.
+//!
+//! A [`Nonce`] is not `Copy`, and the encrypting methods take it by value, so encrypting a
+//! second message under the same nonce needs an explicit `.clone()`.
+//!
+//! 按所属 AEAD 方案区分类型的 nonce。
+//!
+//! [`Nonce<S>`] 始终恰好保存 `S::NONCE_SIZE` 字节，因此将其传给
+//! [`AeadEncryptor::encrypt_with_nonce`]、[`AeadDecryptor::decrypt_with_nonce`] 或它们的
+//! `*_to_buffer_with_nonce` 版本时不会以 `SymmetricError::InvalidNonceSize` 失败，并且一个方案的
+//! nonce 不能传给另一个方案。在长度已知的地方会在编译时检查大小：[`Nonce::from_array`]
+//! 对长度错误的数组无法编译，而 nonce 长于 [`MAX_NONCE_SIZE`] 的方案完全不能与 [`Nonce`]
+//! 一起使用。只有用于从存储或网络读取 nonce 的 [`Nonce::from_slice`] 会在运行时检查长度。
+//!
+//! [`Nonce`] 不是 `Copy` 的，而加密方法按值获取它，因此要在同一 nonce 下加密第二条消息，
+//! 必须显式调用 `.clone()`。
+
+use crate::errors::Error;
+use crate::traits::symmetric::{AeadCipher, SymmetricError};
+#[cfg(doc)]
+use crate::traits::symmetric::{AeadDecryptor, AeadEncryptor};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use rand_core_elliptic_curve::CryptoRngCore;
+
+/// The longest nonce, in bytes, that a [`Nonce`] can hold.
+///
+/// [`Nonce`] 可以保存的最长 nonce（以字节为单位）。
+pub const MAX_NONCE_SIZE: usize = 32;
+
+/// A nonce of exactly `S::NONCE_SIZE` bytes for the AEAD scheme `S`.
+///
+/// It dereferences to `[u8]`, so it can also be passed to the slice-based methods.
+///
+/// 用于 AEAD 方案 `S` 的恰好为 `S::NONCE_SIZE` 字节的 nonce。
+///
+/// 它解引用为 `[u8]`，因此也可以传给基于切片的方法。
+pub struct Nonce<S: AeadCipher> {
+    bytes: [u8; MAX_NONCE_SIZE],
+    _scheme: PhantomData<fn() -> S>,
+}
+
+impl<S: AeadCipher> Nonce<S> {
+    /// An all-zero nonce, the starting point of every constructor.
+    fn zeroed() -> Self {
+        const {
+            assert!(
+                S::NONCE_SIZE <= MAX_NONCE_SIZE,
+                "the nonces of this scheme are longer than MAX_NONCE_SIZE"
+            )
+        };
+        Self {
+            bytes: [0u8; MAX_NONCE_SIZE],
+            _scheme: PhantomData,
+        }
+    }
+
+    /// Creates a nonce from an array of `S::NONCE_SIZE` bytes. An array of any other length
+    /// is rejected at compile time.
+    ///
+    /// 从 `S::NONCE_SIZE` 字节的数组创建 nonce。任何其他长度的数组都会在编译时被拒绝。
+    pub fn from_array<const N: usize>(bytes: [u8; N]) -> Self {
+        const { assert!(N == S::NONCE_SIZE, "the array length is not the nonce size") };
+        let mut nonce = Self::zeroed();
+        nonce.bytes[..N].copy_from_slice(&bytes);
+        nonce
+    }
+
+    /// Creates a nonce from a slice, failing with `SymmetricError::InvalidNonceSize` if it is
+    /// not `S::NONCE_SIZE` bytes long.
+    ///
+    /// 从切片创建 nonce；如果其长度不是 `S::NONCE_SIZE` 字节，则以
+    /// `SymmetricError::InvalidNonceSize` 失败。
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != S::NONCE_SIZE {
+            return Err(Error::Symmetric(SymmetricError::InvalidNonceSize));
+        }
+        let mut nonce = Self::zeroed();
+        nonce.bytes[..S::NONCE_SIZE].copy_from_slice(bytes);
+        Ok(nonce)
+    }
+
+    /// Generates a random nonce with the crate's RNG for nonces, which is a thread-local
+    /// `FastRng` with the `fast-rng` feature. Random nonces of 12 bytes should not be used for
+    /// more than 2^32 messages under one key; use a counter nonce or a scheme with 24-byte
+    /// nonces beyond that.
+    ///
+    /// Fails with `SymmetricError::NonceGenerationFailed` if no entropy is available.
+    ///
+    /// 使用 crate 用于 nonce 的 RNG（启用 `fast-rng` 特性时为线程本地的 `FastRng`）生成随机 nonce。
+    /// 在同一密钥下，12 字节的随机 nonce 不应用于超过 2^32 条消息；超出时请使用计数器 nonce 或
+    /// nonce 为 24 字节的方案。
+    ///
+    /// 如果没有可用的熵，则以 `SymmetricError::NonceGenerationFailed` 失败。
+    pub fn random() -> Result<Self, Error> {
+        let mut nonce = Self::zeroed();
+        crate::rng::fill_nonce(&mut nonce.bytes[..S::NONCE_SIZE])
+            .map_err(|_| Error::Symmetric(SymmetricError::NonceGenerationFailed))?;
+        Ok(nonce)
+    }
+
+    /// Generates a random nonce using randomness drawn from `rng`.
+    ///
+    /// 使用从 `rng` 中获取的随机性生成随机 nonce。
+    pub fn random_with_rng<R: CryptoRngCore>(rng: &mut R) -> Result<Self, Error> {
+        let mut nonce = Self::zeroed();
+        rng.try_fill_bytes(&mut nonce.bytes[..S::NONCE_SIZE])
+            .map_err(|_| Error::Symmetric(SymmetricError::NonceGenerationFailed))?;
+        Ok(nonce)
+    }
+
+    /// Creates a counter nonce: `counter` in big-endian in the last eight bytes, preceded by
+    /// zeros. Advance it with [`Nonce::increment`]. Each counter value must be used only once
+    /// per key, so the counter has to be persisted across restarts.
+    ///
+    /// 创建计数器 nonce：`counter` 以大端序存放在最后八个字节中，前面填充零。使用
+    /// [`Nonce::increment`] 推进它。每个计数器值在每个密钥下只能使用一次，因此计数器必须在重启之间
+    /// 持久保存。
+    pub fn from_counter(counter: u64) -> Self {
+        const {
+            assert!(
+                S::NONCE_SIZE >= 8,
+                "the nonces of this scheme are too short for a counter"
+            )
+        };
+        let mut nonce = Self::zeroed();
+        nonce.bytes[S::NONCE_SIZE - 8..S::NONCE_SIZE].copy_from_slice(&counter.to_be_bytes());
+        nonce
+    }
+
+    /// Increments the nonce as a big-endian integer over all of its bytes. Fails with
+    /// `SymmetricError::NonceExhausted`, leaving the nonce unchanged, if every byte is `0xff`.
+    ///
+    /// 将 nonce 的全部字节作为大端序整数加一。如果每个字节都是 `0xff`，则以
+    /// `SymmetricError::NonceExhausted` 失败，且 nonce 保持不变。
+    pub fn increment(&mut self) -> Result<(), Error> {
+        let bytes = &mut self.bytes[..S::NONCE_SIZE];
+        if bytes.iter().all(|&b| b == u8::MAX) {
+            return Err(Error::Symmetric(SymmetricError::NonceExhausted));
+        }
+        for byte in bytes.iter_mut().rev() {
+            let (next, carry) = byte.overflowing_add(1);
+            *byte = next;
+            if !carry {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the `S::NONCE_SIZE` bytes of the nonce.
+    ///
+    /// 返回 nonce 的 `S::NONCE_SIZE` 字节。
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..S::NONCE_SIZE]
+    }
+}
+
+impl<S: AeadCipher> Clone for Nonce<S> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes,
+            _scheme: PhantomData,
+        }
+    }
+}
+
+impl<S: AeadCipher> PartialEq for Nonce<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<S: AeadCipher> Eq for Nonce<S> {}
+
+impl<S: AeadCipher> Hash for Nonce<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl<S: AeadCipher> fmt::Debug for Nonce<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Nonce").field(&self.as_bytes()).finish()
+    }
+}
+
+impl<S: AeadCipher> Deref for Nonce<S> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<S: AeadCipher> AsRef<[u8]> for Nonce<S> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<S: AeadCipher> TryFrom<&[u8]> for Nonce<S> {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(bytes)
+    }
+}
+
+// ------------------- Tests -------------------
+// ------------------- 测试 -------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestCipher;
+
+    impl AeadCipher for TestCipher {
+        const KEY_SIZE: usize = 32;
+        const NONCE_SIZE: usize = 12;
+        const TAG_SIZE: usize = 16;
+    }
+
+    type TestNonce = Nonce<TestCipher>;
+
+    #[test]
+    fn test_constructors() {
+        let nonce = TestNonce::from_array([7u8; 12]);
+        assert_eq!(nonce.as_bytes(), [7u8; 12]);
+        assert_eq!(nonce.len(), 12);
+        assert_eq!(TestNonce::from_slice(&[7u8; 12]), Ok(nonce));
+        assert_eq!(
+            TestNonce::from_slice(&[7u8; 11]),
+            Err(Error::Symmetric(SymmetricError::InvalidNonceSize))
+        );
+    }
+
+    #[cfg(feature = "getrandom")]
+    #[test]
+    fn test_random() {
+        let random = TestNonce::random();
+        assert!(random.is_ok());
+        assert_ne!(random, TestNonce::random());
+    }
+
+    #[test]
+    fn test_counter() {
+        let mut nonce = TestNonce::from_counter(0x0102);
+        assert_eq!(nonce.as_bytes(), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2]);
+        assert!(nonce.increment().is_ok());
+        assert_eq!(nonce, TestNonce::from_counter(0x0103));
+
+        let mut nonce = TestNonce::from_counter(u64::MAX);
+        assert!(nonce.increment().is_ok());
+        assert_eq!(nonce.as_bytes(), [0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut last = TestNonce::from_array([0xff; 12]);
+        assert_eq!(
+            last.increment(),
+            Err(Error::Symmetric(SymmetricError::NonceExhausted))
+        );
+        assert_eq!(last.as_bytes(), [0xff; 12]);
+    }
+}
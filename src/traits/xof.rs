@@ -2,6 +2,8 @@
 //!
 //! 定义了可扩展输出函数 (XOF) 的 trait。
 #[cfg(feature = "digest")]
+use alloc::boxed::Box;
+#[cfg(feature = "digest")]
 use crate::{errors::Error, prelude::Derivation};
 
 #[cfg(feature = "digest")]